@@ -0,0 +1,217 @@
+//! Rendering [`ScalarChange`]s as sentences.
+
+use std::fmt::Write as _;
+
+use facet_core::{Def, Field, Shape, Type, UserType};
+use facet_reflect::{ChangeSegment, Peek, ScalarChange, scalar_changes};
+
+use crate::{Attr, Change, Templates, Vocabulary};
+
+/// Renders [`ScalarChange`]s as audit log sentences.
+///
+/// Each change is described by a subject built from the titles of the fields
+/// on its path, and its old and new values. A field's title is, in order of
+/// preference: what the vocabulary's [`title`](Vocabulary::title) returns,
+/// `#[facet(audit::title = "...")]`, the first line of its doc comment, or
+/// its name with underscores turned into spaces.
+///
+/// Values under a `#[facet(sensitive)]` or `#[facet(audit::redact)]` field
+/// are never shown, and changes under a `#[facet(audit::skip)]` field are
+/// left out.
+pub struct AuditFormatter {
+    vocabulary: Box<dyn Vocabulary>,
+    by_type: Vec<(&'static Shape, Box<dyn Vocabulary>)>,
+    actor: Option<String>,
+}
+
+impl Default for AuditFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AuditFormatter {
+    /// A formatter writing English sentences ([`Templates::default`]).
+    pub fn new() -> Self {
+        Self::with_vocabulary(Templates::default())
+    }
+
+    /// A formatter writing sentences with `vocabulary`.
+    pub fn with_vocabulary(vocabulary: impl Vocabulary + 'static) -> Self {
+        Self {
+            vocabulary: Box::new(vocabulary),
+            by_type: Vec::new(),
+            actor: None,
+        }
+    }
+
+    /// Uses `vocabulary` for the sentences and values of changes to values
+    /// of type `shape` (or `Option`s of it). Subjects, titles and
+    /// attribution still come from the main vocabulary.
+    pub fn vocabulary_for(
+        mut self,
+        shape: &'static Shape,
+        vocabulary: impl Vocabulary + 'static,
+    ) -> Self {
+        self.by_type.push((shape, Box::new(vocabulary)));
+        self
+    }
+
+    /// Attributes every sentence to `actor` ("… by alice").
+    pub fn actor(mut self, actor: impl Into<String>) -> Self {
+        self.actor = Some(actor.into());
+        self
+    }
+
+    /// Describes every change between `old` and `new`.
+    pub fn describe<'mem, 'facet>(
+        &self,
+        old: Peek<'mem, 'facet>,
+        new: Peek<'mem, 'facet>,
+    ) -> Vec<String> {
+        self.sentences(&scalar_changes(old, new))
+    }
+
+    /// Describes each of `changes`, leaving out skipped ones.
+    pub fn sentences(&self, changes: &[ScalarChange<'_, '_>]) -> Vec<String> {
+        changes
+            .iter()
+            .filter_map(|change| self.sentence(change))
+            .collect()
+    }
+
+    /// Describes one change, or `None` if it is under an
+    /// `#[facet(audit::skip)]` field.
+    pub fn sentence(&self, change: &ScalarChange<'_, '_>) -> Option<String> {
+        if change
+            .fields
+            .iter()
+            .any(|field| field.has_attr(Some("audit"), "skip"))
+        {
+            return None;
+        }
+
+        let subject = self.subject(change);
+        let redacted = change
+            .fields
+            .iter()
+            .any(|field| field.is_sensitive() || field.has_attr(Some("audit"), "redact"));
+        let vocabulary = change
+            .new
+            .or(change.old)
+            .and_then(|value| self.type_vocabulary(value.shape()))
+            .unwrap_or(&*self.vocabulary);
+
+        let sentence = if redacted {
+            vocabulary.sentence(&subject, Change::Redacted)
+        } else {
+            let old = change.old.map(|value| self.value(vocabulary, value));
+            let new = change.new.map(|value| self.value(vocabulary, value));
+            let change = match (&old, &new) {
+                (Some(old), Some(new)) => Change::Changed { old, new },
+                (None, Some(new)) => Change::Added { new },
+                (Some(old), None) => Change::Removed { old },
+                (None, None) => return None,
+            };
+            vocabulary.sentence(&subject, change)
+        };
+
+        Some(match &self.actor {
+            Some(actor) => self.vocabulary.attributed(&sentence, actor),
+            None => sentence,
+        })
+    }
+
+    fn type_vocabulary(&self, shape: &'static Shape) -> Option<&dyn Vocabulary> {
+        let inner = match shape.def {
+            Def::Option(option) => Some(option.t()),
+            _ => None,
+        };
+        self.by_type
+            .iter()
+            .find(|(candidate, _)| *candidate == shape || Some(*candidate) == inner)
+            .map(|(_, vocabulary)| &**vocabulary)
+    }
+
+    fn subject(&self, change: &ScalarChange<'_, '_>) -> String {
+        let mut parts: Vec<String> = Vec::new();
+        let mut fields = change.fields.iter().copied();
+        for segment in change.path.segments() {
+            let qualifier = match segment {
+                ChangeSegment::Field(_) => {
+                    if let Some(field) = fields.next() {
+                        parts.push(self.title(field));
+                    }
+                    continue;
+                }
+                ChangeSegment::Index(index) => format!("#{}", index + 1),
+                ChangeSegment::Key(key) => key.clone(),
+            };
+            match parts.last_mut() {
+                Some(last) => {
+                    let _ = write!(last, " {qualifier}");
+                }
+                None => parts.push(qualifier),
+            }
+        }
+        self.vocabulary.subject(&parts)
+    }
+
+    fn title(&self, field: &'static Field) -> String {
+        if let Some(title) = self.vocabulary.title(field) {
+            return title;
+        }
+        if let Some(Attr::Title(title)) = field
+            .get_attr(Some("audit"), "title")
+            .and_then(|attr| attr.get_as::<Attr>())
+        {
+            return title.to_string();
+        }
+        if let Some(line) = field.doc.first().map(|line| line.trim())
+            && !line.is_empty()
+        {
+            return line.trim_end_matches('.').to_string();
+        }
+        humanize(field.effective_name())
+    }
+
+    fn value(&self, vocabulary: &dyn Vocabulary, value: Peek<'_, '_>) -> String {
+        if let Some(rendered) = vocabulary
+            .value(value)
+            .or_else(|| self.vocabulary.value(value))
+        {
+            return rendered;
+        }
+        if let Ok(option) = value.into_option() {
+            return match option.value() {
+                Some(inner) => self.value(vocabulary, inner),
+                None => "none".to_string(),
+            };
+        }
+        if let Some(s) = value.as_str() {
+            return format!("{s:?}");
+        }
+        if value.shape().is_display() {
+            return value.to_string();
+        }
+        // Unit variants read better as their name than as a debug dump.
+        if let Type::User(UserType::Enum(_)) = value.shape().ty
+            && let Ok(peek_enum) = value.into_enum()
+            && let Ok(variant) = peek_enum.active_variant()
+            && variant.data.fields.is_empty()
+        {
+            return variant.effective_name().to_string();
+        }
+        format!("{value:?}")
+    }
+}
+
+/// `max_retries` -> `Max retries`.
+fn humanize(name: &str) -> String {
+    let spaced = name.replace('_', " ");
+    let mut chars = spaced.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => spaced,
+    }
+}
@@ -0,0 +1,130 @@
+//! Human-readable audit log sentences for changes between facet values.
+//!
+//! [`AuditFormatter`] turns the changes found by
+//! [`scalar_changes`](facet_reflect::scalar_changes) into sentences such as
+//! "Retries changed from 3 to 5 by alice", naming fields by their titles
+//! rather than their Rust names.
+//!
+//! # Example
+//!
+//! ```
+//! use facet::Facet;
+//! use facet_audit::{self as audit, AuditFormatter};
+//! use facet_reflect::Peek;
+//!
+//! #[derive(Facet)]
+//! struct Settings {
+//!     /// Retries
+//!     retries: u32,
+//!     #[facet(audit::title = "Request timeout (s)")]
+//!     timeout_secs: u32,
+//!     #[facet(sensitive)]
+//!     api_key: String,
+//! }
+//!
+//! let before = Settings { retries: 3, timeout_secs: 30, api_key: "old".into() };
+//! let after = Settings { retries: 5, timeout_secs: 60, api_key: "new".into() };
+//!
+//! let lines = AuditFormatter::new()
+//!     .actor("alice")
+//!     .describe(Peek::new(&before), Peek::new(&after));
+//! assert_eq!(
+//!     lines,
+//!     [
+//!         "Retries changed from 3 to 5 by alice",
+//!         "Request timeout (s) changed from 30 to 60 by alice",
+//!         "Api key changed by alice",
+//!     ]
+//! );
+//! ```
+//!
+//! # Titles
+//!
+//! A field's title is the first line of its doc comment, or its name with
+//! underscores turned into spaces, unless it is set with
+//! `#[facet(audit::title = "...")]`. Nested fields are joined into one
+//! subject ("Database › Pool size"), and list elements and map entries are
+//! numbered or keyed ("Servers #2 › Host", `Limits "eu"`).
+//!
+//! # Attributes
+//!
+//! | Attribute | Effect |
+//! |-----------|--------|
+//! | `audit::title = "..."` | The field's title in sentences |
+//! | `audit::redact` | Changes are reported without their values |
+//! | `audit::skip` | Changes are left out entirely |
+//!
+//! Fields marked `#[facet(sensitive)]` are redacted too. Both redaction and
+//! skipping apply to everything nested under the field.
+//!
+//! # Vocabulary
+//!
+//! Wording comes from a [`Vocabulary`]. [`Templates`] holds one sentence
+//! template per kind of change, which is all a translation usually needs;
+//! implement [`Vocabulary`] directly to translate titles too, or to render
+//! values differently. A vocabulary can also be registered for a single type
+//! with [`AuditFormatter::vocabulary_for`]:
+//!
+//! ```
+//! use facet::Facet;
+//! use facet_audit::{AuditFormatter, Change, Vocabulary};
+//! use facet_reflect::Peek;
+//!
+//! struct Toggle;
+//!
+//! impl Vocabulary for Toggle {
+//!     fn sentence(&self, subject: &str, change: Change<'_>) -> String {
+//!         match change {
+//!             Change::Changed { new: "true", .. } => format!("{subject} enabled"),
+//!             Change::Changed { .. } => format!("{subject} disabled"),
+//!             _ => format!("{subject} changed"),
+//!         }
+//!     }
+//! }
+//!
+//! #[derive(Facet)]
+//! struct Flags { dark_mode: bool }
+//!
+//! let lines = AuditFormatter::new()
+//!     .vocabulary_for(bool::SHAPE, Toggle)
+//!     .describe(Peek::new(&Flags { dark_mode: false }), Peek::new(&Flags { dark_mode: true }));
+//! assert_eq!(lines, ["Dark mode enabled"]);
+//! ```
+
+#![warn(missing_docs)]
+
+mod formatter;
+mod vocabulary;
+
+pub use formatter::AuditFormatter;
+pub use vocabulary::{Change, Templates, Vocabulary};
+
+// Define the audit attribute grammar
+facet::define_attr_grammar! {
+    ns "audit";
+    crate_path ::facet_audit;
+
+    /// Audit log attributes for facet fields.
+    ///
+    /// These attributes can be used with `#[facet(audit::...)]` syntax.
+    #[non_exhaustive]
+    pub enum Attr {
+        /// The field's name in audit sentences.
+        ///
+        /// Usage: `#[facet(audit::title = "Retry count")]`
+        #[target(field)]
+        Title(&'static str),
+
+        /// Report changes to the field without their values.
+        ///
+        /// Usage: `#[facet(audit::redact)]`
+        #[target(field)]
+        Redact,
+
+        /// Leave changes to the field out of audit logs.
+        ///
+        /// Usage: `#[facet(audit::skip)]`
+        #[target(field)]
+        Skip,
+    }
+}
@@ -0,0 +1,197 @@
+//! The words audit sentences are made of.
+
+use std::borrow::Cow;
+
+use facet_core::{Def, Field};
+use facet_reflect::Peek;
+
+/// What happened to a value, with its rendered old and new forms.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Change<'a> {
+    /// The value went from `old` to `new`.
+    Changed {
+        /// The value before.
+        old: &'a str,
+        /// The value after.
+        new: &'a str,
+    },
+    /// The value didn't exist before (a new list element or map entry).
+    Added {
+        /// The new value.
+        new: &'a str,
+    },
+    /// The value no longer exists.
+    Removed {
+        /// The value before.
+        old: &'a str,
+    },
+    /// The value changed, but it is sensitive or marked
+    /// `#[facet(audit::redact)]`, so neither side may be shown.
+    Redacted,
+}
+
+/// Turns changes into sentences.
+///
+/// Implement this to translate audit logs, or to phrase changes to one type
+/// differently (see [`AuditFormatter::vocabulary_for`]). Only
+/// [`sentence`](Vocabulary::sentence) is required; [`Templates`] covers the
+/// common case of swapping the wording without writing any code.
+///
+/// [`AuditFormatter::vocabulary_for`]: crate::AuditFormatter::vocabulary_for
+pub trait Vocabulary: Send + Sync {
+    /// The sentence for one change to `subject`.
+    fn sentence(&self, subject: &str, change: Change<'_>) -> String;
+
+    /// Adds who made the change to `sentence`.
+    fn attributed(&self, sentence: &str, actor: &str) -> String {
+        format!("{sentence} by {actor}")
+    }
+
+    /// Joins the titles of the fields on a change's path into its subject.
+    /// `parts` is empty when the compared values themselves changed.
+    fn subject(&self, parts: &[String]) -> String {
+        if parts.is_empty() {
+            return "Value".to_string();
+        }
+        parts.join(" › ")
+    }
+
+    /// The title of `field`, for looking up translated titles. `None` falls
+    /// back to `#[facet(audit::title)]`, then the field's doc comment, then
+    /// its name.
+    fn title(&self, field: &'static Field) -> Option<String> {
+        let _ = field;
+        None
+    }
+
+    /// How to show `value`. `None` leaves it to the formatter, which quotes
+    /// strings and uses `Display` (or `Debug`) for everything else.
+    fn value(&self, value: Peek<'_, '_>) -> Option<String> {
+        let _ = value;
+        None
+    }
+}
+
+/// A [`Vocabulary`] made of sentence templates.
+///
+/// Templates use `{subject}`, `{old}`, `{new}`, `{sentence}` and `{actor}`
+/// placeholders. [`Templates::default`] is English.
+///
+/// ```
+/// use facet_audit::Templates;
+///
+/// let german = Templates {
+///     changed: "{subject} von {old} auf {new} geändert".into(),
+///     added: "{subject} auf {new} gesetzt".into(),
+///     removed: "{subject} entfernt (war {old})".into(),
+///     redacted: "{subject} geändert".into(),
+///     attributed: "{sentence} von {actor}".into(),
+///     root: "Wert".into(),
+///     none: "nichts".into(),
+///     separator: " › ".into(),
+/// };
+/// # let _ = german;
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Templates {
+    /// For [`Change::Changed`].
+    pub changed: Cow<'static, str>,
+    /// For [`Change::Added`].
+    pub added: Cow<'static, str>,
+    /// For [`Change::Removed`].
+    pub removed: Cow<'static, str>,
+    /// For [`Change::Redacted`].
+    pub redacted: Cow<'static, str>,
+    /// Wraps a sentence with the actor who made the change.
+    pub attributed: Cow<'static, str>,
+    /// The subject when the compared values themselves changed.
+    pub root: Cow<'static, str>,
+    /// How `None` is shown.
+    pub none: Cow<'static, str>,
+    /// Between the titles of nested fields.
+    pub separator: Cow<'static, str>,
+}
+
+impl Default for Templates {
+    fn default() -> Self {
+        Self {
+            changed: "{subject} changed from {old} to {new}".into(),
+            added: "{subject} set to {new}".into(),
+            removed: "{subject} removed (was {old})".into(),
+            redacted: "{subject} changed".into(),
+            attributed: "{sentence} by {actor}".into(),
+            root: "Value".into(),
+            none: "none".into(),
+            separator: " › ".into(),
+        }
+    }
+}
+
+impl Vocabulary for Templates {
+    fn sentence(&self, subject: &str, change: Change<'_>) -> String {
+        let (template, old, new) = match change {
+            Change::Changed { old, new } => (&self.changed, old, new),
+            Change::Added { new } => (&self.added, "", new),
+            Change::Removed { old } => (&self.removed, old, ""),
+            Change::Redacted => (&self.redacted, "", ""),
+        };
+        fill(
+            template,
+            &[("subject", subject), ("old", old), ("new", new)],
+        )
+    }
+
+    fn attributed(&self, sentence: &str, actor: &str) -> String {
+        fill(
+            &self.attributed,
+            &[("sentence", sentence), ("actor", actor)],
+        )
+    }
+
+    fn subject(&self, parts: &[String]) -> String {
+        if parts.is_empty() {
+            return self.root.to_string();
+        }
+        parts.join(&self.separator)
+    }
+
+    fn value(&self, value: Peek<'_, '_>) -> Option<String> {
+        if let Def::Option(_) = value.shape().def
+            && let Ok(option) = value.into_option()
+            && option.is_none()
+        {
+            return Some(self.none.to_string());
+        }
+        None
+    }
+}
+
+/// Replaces each `{name}` in `template` in a single pass, so placeholders in
+/// the substituted values are left alone.
+fn fill(template: &str, values: &[(&str, &str)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let value = after.find('}').and_then(|end| {
+            let name = &after[..end];
+            values
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| (*value, end))
+        });
+        match value {
+            Some((value, end)) => {
+                out.push_str(value);
+                rest = &after[end + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
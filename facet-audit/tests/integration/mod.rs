@@ -0,0 +1,2 @@
+mod sentences;
+mod vocabulary;
@@ -0,0 +1,183 @@
+use std::collections::BTreeMap;
+
+use facet::Facet;
+use facet_audit::{self as audit, AuditFormatter};
+use facet_reflect::Peek;
+use facet_testhelpers::test;
+
+#[derive(Facet)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum LogLevel {
+    Quiet,
+    Normal,
+    Verbose,
+}
+
+#[derive(Facet)]
+struct Credentials {
+    user: String,
+    password: String,
+}
+
+#[derive(Facet)]
+struct Database {
+    /// Connection pool size.
+    pool_size: u32,
+    #[facet(sensitive)]
+    credentials: Credentials,
+}
+
+#[derive(Facet)]
+struct Server {
+    host: String,
+    port: u16,
+}
+
+#[derive(Facet)]
+struct Config {
+    #[facet(audit::title = "Service name")]
+    name: String,
+    log_level: LogLevel,
+    proxy: Option<String>,
+    database: Database,
+    servers: Vec<Server>,
+    limits: BTreeMap<String, u32>,
+    #[facet(audit::redact)]
+    license: String,
+    #[facet(audit::skip)]
+    revision: u64,
+}
+
+fn config() -> Config {
+    Config {
+        name: "api".into(),
+        log_level: LogLevel::Normal,
+        proxy: None,
+        database: Database {
+            pool_size: 8,
+            credentials: Credentials {
+                user: "app".into(),
+                password: "hunter2".into(),
+            },
+        },
+        servers: vec![Server {
+            host: "a.example".into(),
+            port: 80,
+        }],
+        limits: BTreeMap::from([("eu".into(), 100)]),
+        license: "AAAA".into(),
+        revision: 1,
+    }
+}
+
+fn describe(old: &Config, new: &Config) -> Vec<String> {
+    AuditFormatter::new().describe(Peek::new(old), Peek::new(new))
+}
+
+#[test]
+fn titles_come_from_attributes_docs_and_names() {
+    let old = config();
+    let mut new = config();
+    new.name = "web".into();
+    new.database.pool_size = 16;
+    new.log_level = LogLevel::Verbose;
+
+    assert_eq!(
+        describe(&old, &new),
+        [
+            r#"Service name changed from "api" to "web""#,
+            "Log level changed from Normal to Verbose",
+            "Database › Connection pool size changed from 8 to 16",
+        ]
+    );
+}
+
+#[test]
+fn list_elements_and_map_entries_are_qualified() {
+    let old = config();
+    let mut new = config();
+    new.servers[0].port = 8080;
+    new.servers.push(Server {
+        host: "b.example".into(),
+        port: 80,
+    });
+    new.limits.insert("eu".into(), 150);
+    new.limits.insert("us".into(), 50);
+
+    assert_eq!(
+        describe(&old, &new),
+        [
+            "Servers #1 › Port changed from 80 to 8080",
+            r#"Servers #2 › Host set to "b.example""#,
+            "Servers #2 › Port set to 80",
+            r#"Limits "eu" changed from 100 to 150"#,
+            r#"Limits "us" set to 50"#,
+        ]
+    );
+
+    assert_eq!(
+        describe(&new, &old)[1],
+        r#"Servers #2 › Host removed (was "b.example")"#
+    );
+}
+
+#[test]
+fn options_show_none() {
+    let old = config();
+    let mut new = config();
+    new.proxy = Some("http://proxy".into());
+
+    assert_eq!(
+        describe(&old, &new),
+        [r#"Proxy changed from none to "http://proxy""#]
+    );
+}
+
+#[test]
+fn sensitive_and_redacted_fields_hide_values() {
+    let old = config();
+    let mut new = config();
+    new.database.credentials.password = "correct horse".into();
+    new.license = "BBBB".into();
+
+    let lines = describe(&old, &new);
+    assert_eq!(
+        lines,
+        [
+            "Database › Credentials › Password changed",
+            "License changed",
+        ]
+    );
+    assert!(lines.iter().all(|line| !line.contains("hunter2")));
+}
+
+#[test]
+fn skipped_fields_are_left_out() {
+    let old = config();
+    let mut new = config();
+    new.revision = 2;
+
+    assert!(describe(&old, &new).is_empty());
+}
+
+#[test]
+fn actor_is_appended() {
+    let old = config();
+    let mut new = config();
+    new.database.pool_size = 4;
+
+    let lines = AuditFormatter::new()
+        .actor("alice")
+        .describe(Peek::new(&old), Peek::new(&new));
+    assert_eq!(
+        lines,
+        ["Database › Connection pool size changed from 8 to 4 by alice"]
+    );
+}
+
+#[test]
+fn root_values() {
+    let lines = AuditFormatter::new().describe(Peek::new(&1u32), Peek::new(&2u32));
+    assert_eq!(lines, ["Value changed from 1 to 2"]);
+}
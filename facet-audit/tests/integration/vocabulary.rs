@@ -0,0 +1,129 @@
+use facet::{Facet, Field};
+use facet_audit::{AuditFormatter, Change, Templates, Vocabulary};
+use facet_reflect::{Peek, scalar_changes};
+use facet_testhelpers::test;
+
+#[derive(Facet)]
+struct Profile {
+    display_name: String,
+    newsletter: Option<bool>,
+    tags: Vec<String>,
+}
+
+fn profile() -> Profile {
+    Profile {
+        display_name: "Ada".into(),
+        newsletter: Some(false),
+        tags: vec!["admin".into()],
+    }
+}
+
+fn german() -> Templates {
+    Templates {
+        changed: "{subject} von {old} auf {new} geändert".into(),
+        added: "{subject} auf {new} gesetzt".into(),
+        removed: "{subject} entfernt (war {old})".into(),
+        redacted: "{subject} geändert".into(),
+        attributed: "{sentence} von {actor}".into(),
+        root: "Wert".into(),
+        none: "nichts".into(),
+        separator: " › ".into(),
+    }
+}
+
+#[test]
+fn templates_translate_sentences() {
+    let old = profile();
+    let mut new = profile();
+    new.display_name = "Grace".into();
+    new.newsletter = None;
+    new.tags.clear();
+
+    let lines = AuditFormatter::with_vocabulary(german())
+        .actor("alice")
+        .describe(Peek::new(&old), Peek::new(&new));
+    assert_eq!(
+        lines,
+        [
+            r#"Display name von "Ada" auf "Grace" geändert von alice"#,
+            "Newsletter von false auf nichts geändert von alice",
+            r#"Tags #1 entfernt (war "admin") von alice"#,
+        ]
+    );
+}
+
+#[test]
+fn placeholders_in_values_are_not_expanded() {
+    let old = profile();
+    let mut new = profile();
+    new.display_name = "{old}".into();
+
+    let lines = AuditFormatter::new().describe(Peek::new(&old), Peek::new(&new));
+    assert_eq!(lines, [r#"Display name changed from "Ada" to "{old}""#]);
+}
+
+/// Translates titles from a lookup table and leaves the rest to `Templates`.
+struct Translated(Templates);
+
+impl Vocabulary for Translated {
+    fn sentence(&self, subject: &str, change: Change<'_>) -> String {
+        self.0.sentence(subject, change)
+    }
+
+    fn title(&self, field: &'static Field) -> Option<String> {
+        match field.name {
+            "display_name" => Some("Anzeigename".into()),
+            _ => None,
+        }
+    }
+
+    fn value(&self, value: Peek<'_, '_>) -> Option<String> {
+        self.0.value(value)
+    }
+}
+
+#[test]
+fn vocabulary_translates_titles() {
+    let old = profile();
+    let mut new = profile();
+    new.display_name = "Grace".into();
+
+    let lines = AuditFormatter::with_vocabulary(Translated(german()))
+        .describe(Peek::new(&old), Peek::new(&new));
+    assert_eq!(lines, [r#"Anzeigename von "Ada" auf "Grace" geändert"#]);
+}
+
+struct Toggle;
+
+impl Vocabulary for Toggle {
+    fn sentence(&self, subject: &str, change: Change<'_>) -> String {
+        match change {
+            Change::Changed { new: "on", .. } => format!("{subject} switched on"),
+            Change::Changed { .. } => format!("{subject} switched off"),
+            _ => format!("{subject} changed"),
+        }
+    }
+
+    fn value(&self, value: Peek<'_, '_>) -> Option<String> {
+        let on = value.get::<bool>().ok()?;
+        Some(if *on { "on" } else { "off" }.into())
+    }
+}
+
+#[test]
+fn per_type_vocabulary() {
+    let old = profile();
+    let mut new = profile();
+    new.newsletter = Some(true);
+    new.display_name = "Grace".into();
+
+    let formatter = AuditFormatter::new().vocabulary_for(bool::SHAPE, Toggle);
+    let changes = scalar_changes(Peek::new(&old), Peek::new(&new));
+    assert_eq!(
+        formatter.sentences(&changes),
+        [
+            r#"Display name changed from "Ada" to "Grace""#,
+            "Newsletter switched on",
+        ]
+    );
+}
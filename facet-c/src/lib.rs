@@ -0,0 +1,930 @@
+//! Generate C and C++ headers from facet type metadata.
+//!
+//! This crate turns the shapes of `#[repr(C)]` types into C declarations with
+//! the exact same memory layout, so code on both sides of an FFI boundary, a
+//! shared memory segment or a binary file format can work from a single
+//! definition.
+//!
+//! Supported types are:
+//!
+//! - `#[repr(C)]` structs and tuple structs,
+//! - `#[repr(transparent)]` newtypes, emitted as a `typedef`,
+//! - fieldless enums with `#[repr(C)]` or a primitive repr (`#[repr(u8)]`, ...),
+//! - data-carrying enums with `#[repr(C)]` or `#[repr(C, u8)]` and the like, emitted as
+//!   a tag followed by a union of the variant payloads,
+//!
+//! with fields made of integers, floats, `bool`, `char` (as `uint32_t`),
+//! fixed-size arrays and other supported types. Anything else (pointers,
+//! `String`, `Vec`, `Option`, types without a defined layout) is an error.
+//!
+//! Every declaration is followed by static assertions on its size, alignment
+//! and field offsets, so a header that drifts from the Rust definition fails
+//! to compile instead of corrupting memory.
+//!
+//! In C++ mode ([`CHeaderGenerator::cpp`]), enums become `enum class`es and
+//! structs get `from_bytes`/`to_bytes` helpers to copy values out of and into
+//! byte buffers.
+//!
+//! # Example
+//!
+//! ```
+//! use facet::Facet;
+//! use facet_c::to_c_header;
+//!
+//! #[derive(Facet)]
+//! #[repr(C)]
+//! struct Vertex {
+//!     position: [f32; 3],
+//!     color: u32,
+//! }
+//!
+//! let header = to_c_header::<Vertex>().unwrap();
+//! assert!(header.contains("typedef struct Vertex {"));
+//! assert!(header.contains("    float position[3];"));
+//! ```
+
+extern crate alloc;
+
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use facet_core::{
+    BaseRepr, Def, EnumRepr, EnumType, Facet, Field, NumericType, PrimitiveType, Shape, StructType,
+    TextualType, Type, UserType,
+};
+
+/// Generate a C header declaring `T` and the types it contains.
+pub fn to_c_header<T: Facet<'static>>() -> Result<String, CHeaderError> {
+    let mut generator = CHeaderGenerator::new();
+    generator.add_type::<T>()?;
+    Ok(generator.finish())
+}
+
+/// Generate a C++ header declaring `T` and the types it contains.
+pub fn to_cpp_header<T: Facet<'static>>() -> Result<String, CHeaderError> {
+    let mut generator = CHeaderGenerator::cpp();
+    generator.add_type::<T>()?;
+    Ok(generator.finish())
+}
+
+/// Error returned when a type can't be declared in C.
+#[derive(Debug, Clone)]
+pub struct CHeaderError {
+    /// The type that couldn't be declared.
+    pub shape: &'static Shape,
+    /// Why not.
+    pub reason: String,
+}
+
+impl core::fmt::Display for CHeaderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "cannot declare {} in C: {}",
+            self.shape.type_identifier, self.reason
+        )
+    }
+}
+
+impl core::error::Error for CHeaderError {}
+
+/// The language of the generated header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    /// C11.
+    C,
+    /// C++11.
+    Cpp,
+}
+
+/// Generator for C and C++ headers.
+///
+/// Use this when you need to declare multiple related types in one header.
+/// Types are declared in dependency order, each one exactly once.
+pub struct CHeaderGenerator {
+    language: Language,
+    include_guard: String,
+    namespace: Option<String>,
+    /// Declarations generated so far, in dependency order
+    declarations: Vec<String>,
+    /// Types already generated (by type identifier)
+    generated: BTreeSet<&'static str>,
+}
+
+impl Default for CHeaderGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A C type, split around the declarator: `float` and `[3]` for `float x[3]`.
+struct CType {
+    name: String,
+    dims: String,
+}
+
+/// A field as it appears in the generated declaration.
+struct CField {
+    name: String,
+    ty: CType,
+    offset: usize,
+    size: usize,
+    align: usize,
+    doc: &'static [&'static str],
+}
+
+impl CHeaderGenerator {
+    /// Create a generator for a C header.
+    pub fn new() -> Self {
+        Self {
+            language: Language::C,
+            include_guard: "FACET_GENERATED_H".into(),
+            namespace: None,
+            declarations: Vec::new(),
+            generated: BTreeSet::new(),
+        }
+    }
+
+    /// Create a generator for a C++ header.
+    pub fn cpp() -> Self {
+        Self {
+            language: Language::Cpp,
+            ..Self::new()
+        }
+    }
+
+    /// Set the include guard macro of a C header (`FACET_GENERATED_H` by
+    /// default). C++ headers use `#pragma once` instead.
+    pub fn include_guard(mut self, guard: impl Into<String>) -> Self {
+        self.include_guard = guard.into();
+        self
+    }
+
+    /// Put the declarations of a C++ header in `namespace`. Ignored for C.
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Add a type to generate.
+    pub fn add_type<T: Facet<'static>>(&mut self) -> Result<(), CHeaderError> {
+        self.add_shape(T::SHAPE)
+    }
+
+    /// Add a shape to generate, along with the types it contains.
+    pub fn add_shape(&mut self, shape: &'static Shape) -> Result<(), CHeaderError> {
+        self.generate_shape(shape)
+    }
+
+    /// Finish generation and return the header.
+    pub fn finish(self) -> String {
+        let mut output = String::new();
+        match self.language {
+            Language::C => {
+                output.push_str("/* Generated by facet-c. Do not edit. */\n\n");
+                writeln!(output, "#ifndef {}", self.include_guard).unwrap();
+                writeln!(output, "#define {}", self.include_guard).unwrap();
+                output
+                    .push_str("\n#include <stdbool.h>\n#include <stddef.h>\n#include <stdint.h>\n");
+            }
+            Language::Cpp => {
+                output.push_str("// Generated by facet-c. Do not edit.\n\n#pragma once\n");
+                output.push_str("\n#include <cstddef>\n#include <cstdint>\n#include <cstring>\n");
+            }
+        }
+
+        let namespace = self.namespace.filter(|_| self.language == Language::Cpp);
+        if let Some(namespace) = &namespace {
+            writeln!(output, "\nnamespace {namespace} {{").unwrap();
+        }
+        for declaration in &self.declarations {
+            output.push('\n');
+            output.push_str(declaration);
+        }
+        if let Some(namespace) = &namespace {
+            writeln!(output, "\n}} // namespace {namespace}").unwrap();
+        }
+
+        if self.language == Language::C {
+            writeln!(output, "\n#endif /* {} */", self.include_guard).unwrap();
+        }
+        output
+    }
+
+    fn generate_shape(&mut self, shape: &'static Shape) -> Result<(), CHeaderError> {
+        if self.generated.contains(shape.type_identifier) {
+            return Ok(());
+        }
+        let error = |reason: &str| CHeaderError {
+            shape,
+            reason: reason.into(),
+        };
+        if !shape.type_params.is_empty() {
+            return Err(error("generic types are not supported"));
+        }
+        if shape.layout.sized_layout().is_err() {
+            return Err(error("type is not sized"));
+        }
+
+        let declaration = match &shape.ty {
+            Type::User(UserType::Struct(st)) => match st.repr.base {
+                BaseRepr::C => self.generate_struct(shape, st)?,
+                BaseRepr::Transparent => self.generate_newtype(shape, st)?,
+                _ => {
+                    return Err(error("struct has no defined layout; add #[repr(C)]"));
+                }
+            },
+            Type::User(UserType::Enum(et)) => self.generate_enum(shape, et)?,
+            _ => return Err(error("only structs and enums can be declared")),
+        };
+        self.generated.insert(shape.type_identifier);
+        self.declarations.push(declaration);
+        Ok(())
+    }
+
+    /// Declares a `#[repr(C)]` struct.
+    fn generate_struct(
+        &mut self,
+        shape: &'static Shape,
+        st: &'static StructType,
+    ) -> Result<String, CHeaderError> {
+        let name = shape.type_identifier;
+        let fields = self.collect_fields(shape, st.fields)?;
+        check_layout(shape, &fields, 0)?;
+
+        let mut out = String::new();
+        write_doc(&mut out, shape.doc, "");
+        match self.language {
+            Language::C => writeln!(out, "typedef struct {name} {{").unwrap(),
+            Language::Cpp => writeln!(out, "struct {name} {{").unwrap(),
+        }
+        for field in &fields {
+            write_doc(&mut out, field.doc, "    ");
+            writeln!(
+                out,
+                "    {} {}{};",
+                field.ty.name, field.name, field.ty.dims
+            )
+            .unwrap();
+        }
+        match self.language {
+            Language::C => writeln!(out, "}} {name};").unwrap(),
+            Language::Cpp => {
+                write_byte_helpers(&mut out, name);
+                out.push_str("};\n");
+            }
+        }
+        self.write_layout_asserts(&mut out, shape);
+        for field in &fields {
+            self.write_offset_assert(&mut out, name, &field.name, field.offset);
+        }
+        Ok(out)
+    }
+
+    /// Declares a `#[repr(transparent)]` newtype as a typedef of its field.
+    fn generate_newtype(
+        &mut self,
+        shape: &'static Shape,
+        st: &'static StructType,
+    ) -> Result<String, CHeaderError> {
+        let fields = self.collect_fields(shape, st.fields)?;
+        let [field] = fields.as_slice() else {
+            return Err(CHeaderError {
+                shape,
+                reason: "transparent struct has no non-zero-sized field".into(),
+            });
+        };
+
+        let mut out = String::new();
+        write_doc(&mut out, shape.doc, "");
+        match self.language {
+            Language::C => writeln!(
+                out,
+                "typedef {} {}{};",
+                field.ty.name, shape.type_identifier, field.ty.dims
+            )
+            .unwrap(),
+            Language::Cpp => {
+                let dims = field.ty.dims.as_str();
+                writeln!(
+                    out,
+                    "using {} = {}{dims};",
+                    shape.type_identifier, field.ty.name
+                )
+                .unwrap()
+            }
+        }
+        self.write_layout_asserts(&mut out, shape);
+        Ok(out)
+    }
+
+    fn generate_enum(
+        &mut self,
+        shape: &'static Shape,
+        et: &'static EnumType,
+    ) -> Result<String, CHeaderError> {
+        let error = |reason: &str| CHeaderError {
+            shape,
+            reason: reason.into(),
+        };
+        let Some(tag_type) = tag_type(et.enum_repr) else {
+            return Err(error(
+                "enum has no defined layout; add #[repr(C)] or a primitive repr",
+            ));
+        };
+        if et.variants.is_empty() {
+            return Err(error("enums without variants have no values"));
+        }
+
+        let mut discriminant = 0;
+        let mut tags = Vec::with_capacity(et.variants.len());
+        for variant in et.variants {
+            discriminant = variant.discriminant.unwrap_or(discriminant);
+            if i32::try_from(discriminant).is_err() {
+                return Err(CHeaderError {
+                    shape,
+                    reason: alloc::format!(
+                        "discriminant of `{}` doesn't fit in a C enum constant",
+                        variant.name
+                    ),
+                });
+            }
+            tags.push((variant, discriminant));
+            discriminant = discriminant.wrapping_add(1);
+        }
+
+        if et
+            .variants
+            .iter()
+            .all(|variant| variant.data.fields.is_empty())
+        {
+            let mut out = String::new();
+            write_doc(&mut out, shape.doc, "");
+            self.write_tag_enum(&mut out, shape.type_identifier, tag_type, &tags);
+            self.write_layout_asserts(&mut out, shape);
+            return Ok(out);
+        }
+
+        if et.repr.base != BaseRepr::C {
+            return Err(error(
+                "enums with data need #[repr(C)] or #[repr(C, <int>)] to have a C layout",
+            ));
+        }
+        self.generate_tagged_union(shape, et, tag_type, &tags)
+    }
+
+    /// Declares a data-carrying `#[repr(C)]` enum: a tag enum, one struct per
+    /// variant with fields, and the enum itself as the tag followed by a union
+    /// of the variant structs.
+    fn generate_tagged_union(
+        &mut self,
+        shape: &'static Shape,
+        et: &'static EnumType,
+        tag_type: &'static str,
+        tags: &[(&'static facet_core::Variant, i64)],
+    ) -> Result<String, CHeaderError> {
+        let name = shape.type_identifier;
+        let tag_size = et.enum_repr.discriminant_size().unwrap_or_default();
+
+        let mut payloads = Vec::new();
+        for variant in et.variants {
+            let fields = self.collect_fields(shape, variant.data.fields)?;
+            if !fields.is_empty() {
+                payloads.push((variant, fields));
+            }
+        }
+
+        // The union of payloads starts at the first offset after the tag that
+        // is aligned for every payload.
+        let union_align = payloads
+            .iter()
+            .flat_map(|(_, fields)| fields.iter().map(|field| field.align))
+            .fold(1, usize::max);
+        let union_offset = tag_size.next_multiple_of(union_align);
+        for (_, fields) in &payloads {
+            check_layout(shape, fields, union_offset)?;
+        }
+
+        let mut out = String::new();
+        let tag_name = alloc::format!("{name}Tag");
+        self.write_tag_enum(&mut out, &tag_name, tag_type, tags);
+
+        for (variant, fields) in &payloads {
+            let payload_name = alloc::format!("{name}{}", variant.name);
+            out.push('\n');
+            match self.language {
+                Language::C => writeln!(out, "typedef struct {payload_name} {{").unwrap(),
+                Language::Cpp => writeln!(out, "struct {payload_name} {{").unwrap(),
+            }
+            for field in fields {
+                write_doc(&mut out, field.doc, "    ");
+                writeln!(
+                    out,
+                    "    {} {}{};",
+                    field.ty.name, field.name, field.ty.dims
+                )
+                .unwrap();
+            }
+            match self.language {
+                Language::C => writeln!(out, "}} {payload_name};").unwrap(),
+                Language::Cpp => out.push_str("};\n"),
+            }
+        }
+
+        out.push('\n');
+        write_doc(&mut out, shape.doc, "");
+        match self.language {
+            Language::C => writeln!(out, "typedef struct {name} {{").unwrap(),
+            Language::Cpp => writeln!(out, "struct {name} {{").unwrap(),
+        }
+        writeln!(out, "    {tag_name} tag;").unwrap();
+        out.push_str("    union {\n");
+        for (variant, _) in &payloads {
+            write_doc(&mut out, variant.doc, "        ");
+            writeln!(
+                out,
+                "        {name}{} {};",
+                variant.name,
+                c_identifier(&snake_case(variant.name))
+            )
+            .unwrap();
+        }
+        out.push_str("    };\n");
+        match self.language {
+            Language::C => writeln!(out, "}} {name};").unwrap(),
+            Language::Cpp => {
+                write_byte_helpers(&mut out, name);
+                out.push_str("};\n");
+            }
+        }
+
+        self.write_layout_asserts(&mut out, shape);
+        self.write_offset_assert(&mut out, name, "tag", 0);
+        for (variant, fields) in &payloads {
+            let member = c_identifier(&snake_case(variant.name));
+            self.write_offset_assert(&mut out, name, &member, union_offset);
+            let payload_name = alloc::format!("{name}{}", variant.name);
+            for field in fields {
+                let offset = field.offset - union_offset;
+                self.write_offset_assert(&mut out, &payload_name, &field.name, offset);
+            }
+        }
+        Ok(out)
+    }
+
+    /// Writes the enum of variant tags: a typedef of the discriminant type
+    /// and constants in C, an `enum class` in C++.
+    fn write_tag_enum(
+        &self,
+        out: &mut String,
+        name: &str,
+        tag_type: &str,
+        tags: &[(&'static facet_core::Variant, i64)],
+    ) {
+        match self.language {
+            Language::C => {
+                let prefix = screaming_snake_case(name);
+                writeln!(out, "typedef {tag_type} {name};").unwrap();
+                out.push_str("enum {\n");
+                for (variant, discriminant) in tags {
+                    write_doc(out, variant.doc, "    ");
+                    let constant = screaming_snake_case(variant.name);
+                    writeln!(out, "    {prefix}_{constant} = {discriminant},").unwrap();
+                }
+                out.push_str("};\n");
+            }
+            Language::Cpp => {
+                writeln!(out, "enum class {name} : {tag_type} {{").unwrap();
+                for (variant, discriminant) in tags {
+                    write_doc(out, variant.doc, "    ");
+                    writeln!(out, "    {} = {discriminant},", c_identifier(variant.name)).unwrap();
+                }
+                out.push_str("};\n");
+            }
+        }
+    }
+
+    /// Resolves the C types of `fields`, declaring the types they contain
+    /// first. Zero-sized fields are left out: they take no space in Rust and
+    /// C has no equivalent.
+    fn collect_fields(
+        &mut self,
+        owner: &'static Shape,
+        fields: &'static [Field],
+    ) -> Result<Vec<CField>, CHeaderError> {
+        let mut result = Vec::with_capacity(fields.len());
+        for field in fields {
+            let field_shape = field.shape();
+            let Ok(layout) = field_shape.layout.sized_layout() else {
+                return Err(CHeaderError {
+                    shape: owner,
+                    reason: alloc::format!("field `{}` is not sized", field.name),
+                });
+            };
+            if layout.size() == 0 {
+                continue;
+            }
+            let Some(ty) = self.c_type(field_shape)? else {
+                return Err(CHeaderError {
+                    shape: owner,
+                    reason: alloc::format!(
+                        "field `{}` has type {}, which has no C equivalent",
+                        field.name,
+                        field_shape
+                    ),
+                });
+            };
+            result.push(CField {
+                name: c_identifier(field.name),
+                ty,
+                offset: field.offset,
+                size: layout.size(),
+                align: layout.align(),
+                doc: field.doc,
+            });
+        }
+        Ok(result)
+    }
+
+    /// Returns the C type for `shape`, or `None` if there is none.
+    fn c_type(&mut self, shape: &'static Shape) -> Result<Option<CType>, CHeaderError> {
+        if let Def::Array(array) = &shape.def {
+            return Ok(self.c_type(array.t)?.map(|inner| CType {
+                name: inner.name,
+                dims: alloc::format!("[{}]{}", array.n, inner.dims),
+            }));
+        }
+
+        let size = shape
+            .layout
+            .sized_layout()
+            .map_or(0, |layout| layout.size());
+        let name = match &shape.ty {
+            Type::Primitive(PrimitiveType::Boolean) => "bool",
+            Type::Primitive(PrimitiveType::Numeric(NumericType::Integer { signed })) => {
+                match (shape.type_identifier, signed, size) {
+                    ("usize", _, _) => "uintptr_t",
+                    ("isize", _, _) => "intptr_t",
+                    (_, false, 1) => "uint8_t",
+                    (_, false, 2) => "uint16_t",
+                    (_, false, 4) => "uint32_t",
+                    (_, false, 8) => "uint64_t",
+                    (_, true, 1) => "int8_t",
+                    (_, true, 2) => "int16_t",
+                    (_, true, 4) => "int32_t",
+                    (_, true, 8) => "int64_t",
+                    _ => return Ok(None),
+                }
+            }
+            Type::Primitive(PrimitiveType::Numeric(NumericType::Float)) => match size {
+                4 => "float",
+                8 => "double",
+                _ => return Ok(None),
+            },
+            Type::Primitive(PrimitiveType::Textual(TextualType::Char)) => "uint32_t",
+            Type::User(UserType::Struct(_) | UserType::Enum(_)) => {
+                self.generate_shape(shape)?;
+                shape.type_identifier
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(CType {
+            name: name.into(),
+            dims: String::new(),
+        }))
+    }
+
+    fn write_layout_asserts(&self, out: &mut String, shape: &'static Shape) {
+        let Ok(layout) = shape.layout.sized_layout() else {
+            return;
+        };
+        let name = shape.type_identifier;
+        let (static_assert, alignof) = match self.language {
+            Language::C => ("_Static_assert", "_Alignof"),
+            Language::Cpp => ("static_assert", "alignof"),
+        };
+        writeln!(
+            out,
+            "{static_assert}(sizeof({name}) == {}, \"size of {name}\");",
+            layout.size()
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "{static_assert}({alignof}({name}) == {}, \"alignment of {name}\");",
+            layout.align()
+        )
+        .unwrap();
+    }
+
+    fn write_offset_assert(&self, out: &mut String, owner: &str, field: &str, offset: usize) {
+        let static_assert = match self.language {
+            Language::C => "_Static_assert",
+            Language::Cpp => "static_assert",
+        };
+        writeln!(
+            out,
+            "{static_assert}(offsetof({owner}, {field}) == {offset}, \"offset of {owner}.{field}\");"
+        )
+        .unwrap();
+    }
+}
+
+/// Checks that laying out `fields` the way a C compiler does, starting at
+/// `base`, puts each one at the offset Rust uses.
+fn check_layout(shape: &'static Shape, fields: &[CField], base: usize) -> Result<(), CHeaderError> {
+    let mut offset = base;
+    for field in fields {
+        offset = offset.next_multiple_of(field.align);
+        if field.offset != offset {
+            return Err(CHeaderError {
+                shape,
+                reason: alloc::format!(
+                    "field `{}` is at offset {}, but C would put it at {offset}",
+                    field.name,
+                    field.offset
+                ),
+            });
+        }
+        offset += field.size;
+    }
+    Ok(())
+}
+
+/// Writes `static from_bytes`/`to_bytes` members for a C++ struct.
+fn write_byte_helpers(out: &mut String, name: &str) {
+    writeln!(
+        out,
+        "\n    static {name} from_bytes(const uint8_t* bytes) {{"
+    )
+    .unwrap();
+    writeln!(out, "        {name} value;").unwrap();
+    writeln!(out, "        std::memcpy(&value, bytes, sizeof({name}));").unwrap();
+    out.push_str("        return value;\n    }\n");
+    out.push_str("\n    void to_bytes(uint8_t* out) const {\n");
+    writeln!(out, "        std::memcpy(out, this, sizeof({name}));").unwrap();
+    out.push_str("    }\n");
+}
+
+fn write_doc(out: &mut String, doc: &[&str], indent: &str) {
+    for line in doc {
+        writeln!(out, "{indent}///{line}").unwrap();
+    }
+}
+
+/// Returns the C type of a discriminant, or `None` if its layout is unspecified.
+fn tag_type(repr: EnumRepr) -> Option<&'static str> {
+    Some(match repr {
+        EnumRepr::Rust | EnumRepr::RustNPO => return None,
+        EnumRepr::U8 => "uint8_t",
+        EnumRepr::U16 => "uint16_t",
+        EnumRepr::U32 => "uint32_t",
+        EnumRepr::U64 => "uint64_t",
+        EnumRepr::USize => "uintptr_t",
+        EnumRepr::I8 => "int8_t",
+        EnumRepr::I16 => "int16_t",
+        EnumRepr::I32 => "int32_t",
+        EnumRepr::I64 => "int64_t",
+        EnumRepr::ISize => "intptr_t",
+    })
+}
+
+/// Turns a Rust field name into a C identifier: tuple fields (`0`) become
+/// `_0`, and C/C++ keywords get a trailing underscore.
+fn c_identifier(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "auto",
+        "bool",
+        "break",
+        "case",
+        "catch",
+        "char",
+        "class",
+        "const",
+        "continue",
+        "default",
+        "delete",
+        "do",
+        "double",
+        "else",
+        "enum",
+        "explicit",
+        "extern",
+        "float",
+        "for",
+        "friend",
+        "goto",
+        "if",
+        "inline",
+        "int",
+        "long",
+        "namespace",
+        "new",
+        "operator",
+        "private",
+        "protected",
+        "public",
+        "register",
+        "restrict",
+        "return",
+        "short",
+        "signed",
+        "sizeof",
+        "static",
+        "struct",
+        "switch",
+        "template",
+        "this",
+        "throw",
+        "try",
+        "typedef",
+        "typename",
+        "union",
+        "unsigned",
+        "using",
+        "virtual",
+        "void",
+        "volatile",
+        "while",
+    ];
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        alloc::format!("_{name}")
+    } else if KEYWORDS.contains(&name) {
+        alloc::format!("{name}_")
+    } else {
+        name.into()
+    }
+}
+
+/// `HttpStatus` -> `http_status`
+fn snake_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 4);
+    let chars: Vec<char> = name.chars().collect();
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() {
+            let prev_lower =
+                i > 0 && (chars[i - 1].is_ascii_lowercase() || chars[i - 1].is_ascii_digit());
+            let next_lower = chars.get(i + 1).is_some_and(|c| c.is_ascii_lowercase());
+            let prev_upper = i > 0 && chars[i - 1].is_ascii_uppercase();
+            if prev_lower || (prev_upper && next_lower) {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// `HttpStatus` -> `HTTP_STATUS`
+fn screaming_snake_case(name: &str) -> String {
+    snake_case(name).to_ascii_uppercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use facet::Facet;
+
+    #[test]
+    fn test_simple_struct() {
+        /// A point in space.
+        #[derive(Facet)]
+        #[repr(C)]
+        struct Vertex {
+            /// Position in world units.
+            position: [f32; 3],
+            color: u32,
+            visible: bool,
+        }
+
+        let header = to_c_header::<Vertex>().unwrap();
+        insta::assert_snapshot!(header);
+    }
+
+    #[test]
+    fn test_nested_types_come_first() {
+        #[derive(Facet)]
+        #[repr(C)]
+        struct Header {
+            magic: [u8; 4],
+            version: u16,
+        }
+
+        #[derive(Facet)]
+        #[repr(transparent)]
+        struct EntityId(u32);
+
+        #[derive(Facet)]
+        #[repr(C)]
+        struct Packet {
+            header: Header,
+            sender: EntityId,
+            payload_len: u64,
+        }
+
+        let header = to_c_header::<Packet>().unwrap();
+        insta::assert_snapshot!(header);
+    }
+
+    #[test]
+    fn test_fieldless_enum() {
+        #[derive(Facet)]
+        #[repr(i16)]
+        #[allow(dead_code)]
+        enum Level {
+            Low = -10,
+            Mid,
+            High = 300,
+        }
+
+        let header = to_c_header::<Level>().unwrap();
+        insta::assert_snapshot!(header);
+    }
+
+    #[test]
+    fn test_data_enum() {
+        #[derive(Facet)]
+        #[repr(C, u8)]
+        #[allow(dead_code)]
+        enum Command {
+            Stop,
+            Move { dx: f32, dy: f32 },
+            Fire(u64),
+        }
+
+        let header = to_c_header::<Command>().unwrap();
+        insta::assert_snapshot!(header);
+    }
+
+    #[test]
+    fn test_cpp_header() {
+        #[derive(Facet)]
+        #[repr(u8)]
+        #[allow(dead_code)]
+        enum Team {
+            Red,
+            Blue,
+        }
+
+        #[derive(Facet)]
+        #[repr(C)]
+        struct Player {
+            id: u32,
+            team: Team,
+            health: f32,
+        }
+
+        let mut generator = CHeaderGenerator::cpp().namespace("game");
+        generator.add_type::<Player>().unwrap();
+        insta::assert_snapshot!(generator.finish());
+    }
+
+    #[test]
+    fn test_rejects_types_without_c_layout() {
+        #[derive(Facet)]
+        struct NotC {
+            x: u32,
+        }
+
+        #[derive(Facet)]
+        #[repr(C)]
+        struct WithString {
+            name: String,
+        }
+
+        #[derive(Facet)]
+        #[repr(u8)]
+        #[allow(dead_code)]
+        enum PrimitiveData {
+            A(u32),
+        }
+
+        let err = to_c_header::<NotC>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "cannot declare NotC in C: struct has no defined layout; add #[repr(C)]"
+        );
+
+        let err = to_c_header::<WithString>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "cannot declare WithString in C: field `name` has type String, which has no C equivalent"
+        );
+
+        let err = to_c_header::<PrimitiveData>().unwrap_err();
+        assert!(err.reason.contains("#[repr(C)]"), "{err}");
+    }
+
+    #[test]
+    fn test_identifiers() {
+        assert_eq!(snake_case("HttpStatus"), "http_status");
+        assert_eq!(snake_case("IOError"), "io_error");
+        assert_eq!(screaming_snake_case("Vec3"), "VEC3");
+        assert_eq!(c_identifier("0"), "_0");
+        assert_eq!(c_identifier("default"), "default_");
+    }
+}
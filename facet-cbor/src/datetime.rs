@@ -0,0 +1,78 @@
+//! Standard date/time tags (RFC 8949 §3.4.1 and §3.4.2).
+//!
+//! Tag 0 wraps an RFC 3339 string and tag 1 a number of seconds since the
+//! Unix epoch. Both are read into the `chrono`, `time` and `jiff` types that
+//! name an instant; those types are written as tag 0.
+
+use alloc::{format, string::String};
+
+use facet_core::Shape;
+
+/// Whether `shape` is a date-time with an offset, which is what tags 0 and 1
+/// describe.
+pub(crate) fn is_instant(shape: &Shape) -> bool {
+    matches!(
+        (shape.module_path, shape.type_identifier),
+        (
+            Some("chrono"),
+            "DateTime<Utc>" | "DateTime<FixedOffset>" | "DateTime<Local>"
+        ) | (Some("time"), "UtcDateTime" | "OffsetDateTime")
+            | (Some("jiff"), "Timestamp")
+    )
+}
+
+/// The RFC 3339 text of an epoch-based date/time, in UTC. `None` for
+/// non-finite numbers and dates outside years 0 to 9999.
+pub(crate) fn epoch_seconds_to_rfc3339(secs: f64) -> Option<String> {
+    if !secs.is_finite() {
+        return None;
+    }
+    let whole = secs.floor();
+    if !(-62_167_219_200.0..=253_402_300_799.0).contains(&whole) {
+        return None;
+    }
+    let nanos = ((secs - whole) * 1e9).round().min(999_999_999.0) as u32;
+    epoch_to_rfc3339(whole as i64, nanos)
+}
+
+/// The RFC 3339 text of the UTC date-time `secs` seconds and `nanos`
+/// nanoseconds after the epoch. `None` outside years 0 to 9999.
+pub(crate) fn epoch_to_rfc3339(secs: i64, nanos: u32) -> Option<String> {
+    let days = secs.div_euclid(86_400);
+    let time = secs.rem_euclid(86_400);
+    let (year, month, day) = civil_from_days(days);
+    if !(0..=9999).contains(&year) {
+        return None;
+    }
+
+    let mut out = format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}",
+        time / 3600,
+        time / 60 % 60,
+        time % 60
+    );
+    if nanos != 0 {
+        let fraction = format!("{nanos:09}");
+        out.push('.');
+        out.push_str(fraction.trim_end_matches('0'));
+    }
+    out.push('Z');
+    Some(out)
+}
+
+/// The proleptic Gregorian date `days` days after 1970-01-01.
+///
+/// See <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
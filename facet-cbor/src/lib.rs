@@ -0,0 +1,285 @@
+//! CBOR (RFC 8949) binary format for facet.
+//!
+//! This crate provides serialization and deserialization for the Concise
+//! Binary Object Representation.
+//!
+//! # Serialization
+//!
+//! ```
+//! use facet::Facet;
+//! use facet_cbor::to_vec;
+//!
+//! #[derive(Facet)]
+//! struct Point { x: i32, y: i32 }
+//!
+//! let bytes = to_vec(&Point { x: 10, y: 20 }).unwrap();
+//! // {"x": 10, "y": 20}
+//! assert_eq!(bytes, [0xa2, 0x61, b'x', 0x0a, 0x61, b'y', 0x14]);
+//! ```
+//!
+//! Structs are written as maps keyed by field name, and byte buffers
+//! (`Vec<u8>`, `&[u8]`) as byte strings. Integers and floats always take
+//! their shortest form, and containers have definite lengths.
+//!
+//! ## Deterministic encoding
+//!
+//! For bytes that get hashed or signed, [`SerializeOptions::deterministic`]
+//! writes the deterministic encoding of RFC 8949 §4.2.1: on top of the
+//! above, map entries are sorted by their encoded keys, so the output
+//! doesn't depend on field declaration or map iteration order.
+//!
+//! ```
+//! use std::collections::HashMap;
+//! use facet_cbor::{SerializeOptions, to_vec_with_options};
+//!
+//! let a: HashMap<&str, u8> = [("b", 2), ("a", 1)].into_iter().collect();
+//! let b: HashMap<&str, u8> = [("a", 1), ("b", 2)].into_iter().collect();
+//!
+//! let options = SerializeOptions::new().deterministic();
+//! assert_eq!(
+//!     to_vec_with_options(&a, &options).unwrap(),
+//!     to_vec_with_options(&b, &options).unwrap(),
+//! );
+//! ```
+//!
+//! # Tags
+//!
+//! The standard tags that map onto Rust types are understood in both
+//! directions:
+//!
+//! - **Date/times** (tags 0 and 1): date/times with an offset from
+//!   `chrono`, `time` and `jiff` are written as RFC 3339 strings under tag 0,
+//!   and read from tag 0 strings or tag 1 epoch seconds.
+//! - **Bignums** (tags 2 and 3): `u128` and `i128` values beyond the 64-bit
+//!   range are written as bignums, and bignums of up to 128 bits are read
+//!   back.
+//!
+//! Other tags are read through, as if the tagged item were untagged.
+//!
+//! # Deserialization
+//!
+//! There are four deserialization functions:
+//!
+//! - [`from_slice`]: Deserializes into owned types (`T: Facet<'static>`)
+//! - [`from_slice_borrowed`]: Deserializes with zero-copy borrowing from the input buffer
+//! - [`from_slice_into`]: Deserializes into an existing `Partial` (type-erased, owned)
+//! - [`from_slice_into_borrowed`]: Deserializes into an existing `Partial` (type-erased, zero-copy)
+//!
+//! ```
+//! use facet::Facet;
+//! use facet_cbor::from_slice;
+//!
+//! #[derive(Facet, Debug, PartialEq)]
+//! struct Point { x: i32, y: i32 }
+//!
+//! // CBOR encoding of {"x": 10, "y": 20}
+//! let bytes = &[0xa2, 0x61, b'x', 0x0a, 0x61, b'y', 0x14];
+//! let point: Point = from_slice(bytes).unwrap();
+//! assert_eq!(point, Point { x: 10, y: 20 });
+//! ```
+//!
+//! Indefinite-length items are accepted, as are integer map keys (read as
+//! their decimal strings). Numbers go through the same coercions as in
+//! every other facet format: an integer fits any integer type it's in range
+//! for, whatever width it was written with.
+
+// Note: unsafe code is used for lifetime transmutes in from_slice_into
+// when BORROW=false, mirroring the approach used in facet-json.
+
+extern crate alloc;
+
+mod datetime;
+mod parser;
+mod serializer;
+
+pub use parser::CborParser;
+pub use serializer::{
+    CborSerializeError, CborSerializer, SerializeOptions, to_vec, to_vec_with_options, to_writer,
+    to_writer_with_options,
+};
+
+// Re-export DeserializeError for convenience
+pub use facet_format::DeserializeError;
+
+/// Deserialize a value from CBOR bytes into an owned type.
+///
+/// This is the recommended default for most use cases. The input does not need
+/// to outlive the result, making it suitable for deserializing from temporary
+/// buffers (e.g., HTTP request bodies).
+///
+/// Types containing `&str` or `&[u8]` fields cannot be deserialized with this
+/// function; use `String`/`Vec<u8>` or `Cow<str>`/`Cow<[u8]>` instead. For
+/// zero-copy deserialization into borrowed types, use [`from_slice_borrowed`].
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+/// use facet_cbor::from_slice;
+///
+/// #[derive(Facet, Debug, PartialEq)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// // CBOR encoding of {"x": 10, "y": 20}
+/// let bytes = &[0xa2, 0x61, b'x', 0x0a, 0x61, b'y', 0x14];
+/// let point: Point = from_slice(bytes).unwrap();
+/// assert_eq!(point.x, 10);
+/// assert_eq!(point.y, 20);
+/// ```
+pub fn from_slice<T>(input: &[u8]) -> Result<T, DeserializeError>
+where
+    T: facet_core::Facet<'static>,
+{
+    use facet_format::FormatDeserializer;
+    let mut parser = CborParser::new(input);
+    let mut de = FormatDeserializer::new_owned(&mut parser);
+    de.deserialize()
+}
+
+/// Deserialize a value from CBOR bytes, allowing zero-copy borrowing.
+///
+/// This variant requires the input to outlive the result (`'input: 'facet`),
+/// enabling zero-copy deserialization of byte slices as `&[u8]` or `Cow<[u8]>`.
+///
+/// Use this when you need maximum performance and can guarantee the input
+/// buffer outlives the deserialized value. For most use cases, prefer
+/// [`from_slice`] which doesn't have lifetime requirements.
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+/// use facet_cbor::from_slice_borrowed;
+///
+/// #[derive(Facet, Debug, PartialEq)]
+/// struct Message<'a> {
+///     id: u32,
+///     data: &'a [u8],
+/// }
+///
+/// // CBOR encoding of {"id": 1, "data": h'ABCDEF'}
+/// let bytes = &[0xa2, 0x62, b'i', b'd', 0x01, 0x64, b'd', b'a', b't', b'a', 0x43, 0xAB, 0xCD, 0xEF];
+/// let msg: Message = from_slice_borrowed(bytes).unwrap();
+/// assert_eq!(msg.id, 1);
+/// assert_eq!(msg.data, &[0xAB, 0xCD, 0xEF]);
+/// ```
+pub fn from_slice_borrowed<'input, 'facet, T>(input: &'input [u8]) -> Result<T, DeserializeError>
+where
+    T: facet_core::Facet<'facet>,
+    'input: 'facet,
+{
+    use facet_format::FormatDeserializer;
+    let mut parser = CborParser::new(input);
+    let mut de = FormatDeserializer::new(&mut parser);
+    de.deserialize()
+}
+
+/// Deserialize CBOR bytes into an existing Partial.
+///
+/// This is useful for reflection-based deserialization where you don't have
+/// a concrete type `T` at compile time, only its Shape metadata. The Partial
+/// must already be allocated for the target type.
+///
+/// This version produces owned strings (no borrowing from input).
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+/// use facet_cbor::from_slice_into;
+/// use facet_reflect::Partial;
+///
+/// #[derive(Facet, Debug, PartialEq)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// // CBOR encoding of {"x": 10, "y": 20}
+/// let bytes = &[0xa2, 0x61, b'x', 0x0a, 0x61, b'y', 0x14];
+/// let partial = Partial::alloc_owned::<Point>().unwrap();
+/// let partial = from_slice_into(bytes, partial).unwrap();
+/// let value = partial.build().unwrap();
+/// let point: Point = value.materialize().unwrap();
+/// assert_eq!(point.x, 10);
+/// assert_eq!(point.y, 20);
+/// ```
+pub fn from_slice_into<'facet>(
+    input: &[u8],
+    partial: facet_reflect::Partial<'facet, false>,
+) -> Result<facet_reflect::Partial<'facet, false>, DeserializeError> {
+    use facet_format::{FormatDeserializer, MetaSource};
+    let mut parser = CborParser::new(input);
+    let mut de = FormatDeserializer::new_owned(&mut parser);
+
+    // SAFETY: The deserializer expects Partial<'input, false> where 'input is the
+    // lifetime of the CBOR bytes. Since BORROW=false, no data is borrowed from the
+    // input, so the actual 'facet lifetime of the Partial is independent of 'input.
+    // We transmute to satisfy the type system, then transmute back after deserialization.
+    #[allow(unsafe_code)]
+    let partial: facet_reflect::Partial<'_, false> = unsafe {
+        core::mem::transmute::<
+            facet_reflect::Partial<'facet, false>,
+            facet_reflect::Partial<'_, false>,
+        >(partial)
+    };
+
+    let partial = de.deserialize_into(partial, MetaSource::FromEvents)?;
+
+    // SAFETY: Same reasoning - no borrowed data since BORROW=false.
+    #[allow(unsafe_code)]
+    let partial: facet_reflect::Partial<'facet, false> = unsafe {
+        core::mem::transmute::<
+            facet_reflect::Partial<'_, false>,
+            facet_reflect::Partial<'facet, false>,
+        >(partial)
+    };
+
+    Ok(partial)
+}
+
+/// Deserialize CBOR bytes into an existing Partial, allowing zero-copy borrowing.
+///
+/// This variant requires the input to outlive the Partial's lifetime (`'input: 'facet`),
+/// enabling zero-copy deserialization of byte slices as `&[u8]` or `Cow<[u8]>`.
+///
+/// This is useful for reflection-based deserialization where you don't have
+/// a concrete type `T` at compile time, only its Shape metadata.
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+/// use facet_cbor::from_slice_into_borrowed;
+/// use facet_reflect::Partial;
+///
+/// #[derive(Facet, Debug, PartialEq)]
+/// struct Message<'a> {
+///     id: u32,
+///     data: &'a [u8],
+/// }
+///
+/// // CBOR encoding of {"id": 1, "data": h'ABCDEF'}
+/// let bytes = &[0xa2, 0x62, b'i', b'd', 0x01, 0x64, b'd', b'a', b't', b'a', 0x43, 0xAB, 0xCD, 0xEF];
+/// let partial = Partial::alloc::<Message>().unwrap();
+/// let partial = from_slice_into_borrowed(bytes, partial).unwrap();
+/// let value = partial.build().unwrap();
+/// let msg: Message = value.materialize().unwrap();
+/// assert_eq!(msg.id, 1);
+/// assert_eq!(msg.data, &[0xAB, 0xCD, 0xEF]);
+/// ```
+pub fn from_slice_into_borrowed<'input, 'facet>(
+    input: &'input [u8],
+    partial: facet_reflect::Partial<'facet, true>,
+) -> Result<facet_reflect::Partial<'facet, true>, DeserializeError>
+where
+    'input: 'facet,
+{
+    use facet_format::{FormatDeserializer, MetaSource};
+    let mut parser = CborParser::new(input);
+    let mut de = FormatDeserializer::new(&mut parser);
+    de.deserialize_into(partial, MetaSource::FromEvents)
+}
@@ -0,0 +1,575 @@
+//! CBOR parser implementing FormatParser.
+
+extern crate alloc;
+
+use alloc::{borrow::Cow, format, string::ToString, vec::Vec};
+
+use facet_format::{
+    ContainerKind, DeserializeErrorKind, FieldKey, FieldLocationHint, FormatParser, ParseError,
+    ParseEvent, ParseEventKind, SavePoint, ScalarValue,
+};
+use facet_reflect::Span;
+
+use crate::datetime;
+
+// Major types (the top three bits of the initial byte)
+pub(crate) const MAJOR_UNSIGNED: u8 = 0;
+pub(crate) const MAJOR_NEGATIVE: u8 = 1;
+pub(crate) const MAJOR_BYTES: u8 = 2;
+pub(crate) const MAJOR_TEXT: u8 = 3;
+pub(crate) const MAJOR_ARRAY: u8 = 4;
+pub(crate) const MAJOR_MAP: u8 = 5;
+pub(crate) const MAJOR_TAG: u8 = 6;
+pub(crate) const MAJOR_SIMPLE: u8 = 7;
+
+// Additional information values
+const INFO_U8: u8 = 24;
+const INFO_U16: u8 = 25;
+const INFO_U32: u8 = 26;
+const INFO_U64: u8 = 27;
+const INFO_INDEFINITE: u8 = 31;
+
+// Simple values and floats (major type 7)
+const SIMPLE_FALSE: u8 = 20;
+const SIMPLE_TRUE: u8 = 21;
+const SIMPLE_NULL: u8 = 22;
+const SIMPLE_UNDEFINED: u8 = 23;
+const FLOAT16: u8 = 25;
+const FLOAT32: u8 = 26;
+const FLOAT64: u8 = 27;
+
+/// The "break" stop code ending indefinite-length items.
+const BREAK: u8 = 0xff;
+
+// Tags with special handling
+pub(crate) const TAG_DATETIME_STRING: u64 = 0;
+pub(crate) const TAG_POSITIVE_BIGNUM: u64 = 2;
+pub(crate) const TAG_NEGATIVE_BIGNUM: u64 = 3;
+
+/// CBOR parser for deserialization.
+///
+/// Tags are read through: a tagged item produces the same events as the
+/// untagged one, except for bignums (tags 2 and 3), which become 128-bit
+/// integers. Date/times need no special casing on the wire: tag 0 wraps a
+/// string and tag 1 a number, and numbers read into a date/time type are
+/// taken as seconds since the Unix epoch.
+pub struct CborParser<'de> {
+    input: &'de [u8],
+    pos: usize,
+    /// Start of the item the next event is produced from
+    start: usize,
+    /// Stack tracking nested containers and their remaining items
+    stack: Vec<ContextState>,
+    /// Cached event for peek_event
+    event_peek: Option<ParseEvent<'de>>,
+    /// Counter for save points
+    save_counter: u64,
+    /// Parser states captured by `save`, by save point
+    saved_states: Vec<(u64, SavedState<'de>)>,
+}
+
+/// Everything `restore` needs to read the input again from a save point.
+#[derive(Clone)]
+struct SavedState<'de> {
+    pos: usize,
+    start: usize,
+    stack: Vec<ContextState>,
+    event_peek: Option<ParseEvent<'de>>,
+}
+
+/// Containers being read. `remaining` is `None` for indefinite-length
+/// containers, which end at a break.
+#[derive(Debug, Clone, Copy)]
+enum ContextState {
+    /// Inside a map, waiting for a key (remaining pairs)
+    MapKey { remaining: Option<u64> },
+    /// Inside a map, waiting for a value (remaining pairs after this one)
+    MapValue { remaining: Option<u64> },
+    /// Inside an array (remaining items)
+    Array { remaining: Option<u64> },
+}
+
+/// The initial byte of an item and its argument.
+#[derive(Debug, Clone, Copy)]
+struct Head {
+    major: u8,
+    info: u8,
+    /// The count, length, value or tag number; the raw bits for floats
+    arg: u64,
+}
+
+impl Head {
+    const fn is_indefinite(self) -> bool {
+        self.info == INFO_INDEFINITE
+    }
+}
+
+fn invalid(pos: usize, message: impl Into<Cow<'static, str>>) -> ParseError {
+    ParseError::new(
+        Span::new(pos, 1),
+        DeserializeErrorKind::InvalidValue {
+            message: message.into(),
+        },
+    )
+}
+
+impl<'de> CborParser<'de> {
+    /// Create a new CBOR parser from input bytes.
+    pub const fn new(input: &'de [u8]) -> Self {
+        Self {
+            input,
+            pos: 0,
+            start: 0,
+            stack: Vec::new(),
+            event_peek: None,
+            save_counter: 0,
+            saved_states: Vec::new(),
+        }
+    }
+
+    /// Peek at the next byte without consuming it.
+    fn peek_byte(&self) -> Result<u8, ParseError> {
+        self.input
+            .get(self.pos)
+            .copied()
+            .ok_or_else(|| invalid(self.pos, "unexpected end of input"))
+    }
+
+    /// Read N bytes as a slice.
+    fn read_bytes(&mut self, n: u64) -> Result<&'de [u8], ParseError> {
+        let end = usize::try_from(n)
+            .ok()
+            .and_then(|n| self.pos.checked_add(n))
+            .filter(|&end| end <= self.input.len())
+            .ok_or_else(|| invalid(self.pos, "unexpected end of input"))?;
+        let slice = &self.input[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    /// Read an initial byte and the argument that follows it.
+    fn read_head(&mut self) -> Result<Head, ParseError> {
+        let pos = self.pos;
+        let initial = self.peek_byte()?;
+        self.pos += 1;
+        let major = initial >> 5;
+        let info = initial & 0x1f;
+        let arg = match info {
+            0..=23 => info as u64,
+            INFO_U8 => self.read_bytes(1)?[0] as u64,
+            INFO_U16 => {
+                let b = self.read_bytes(2)?;
+                u16::from_be_bytes([b[0], b[1]]) as u64
+            }
+            INFO_U32 => {
+                let b = self.read_bytes(4)?;
+                u32::from_be_bytes([b[0], b[1], b[2], b[3]]) as u64
+            }
+            INFO_U64 => {
+                let b = self.read_bytes(8)?;
+                u64::from_be_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]])
+            }
+            INFO_INDEFINITE
+                if matches!(
+                    major,
+                    MAJOR_BYTES | MAJOR_TEXT | MAJOR_ARRAY | MAJOR_MAP | MAJOR_SIMPLE
+                ) =>
+            {
+                0
+            }
+            _ => {
+                return Err(invalid(
+                    pos,
+                    format!("malformed CBOR initial byte 0x{initial:02x}"),
+                ));
+            }
+        };
+        Ok(Head { major, info, arg })
+    }
+
+    /// Read a head, skipping any tags before it. Returns the innermost tag.
+    fn read_tagged_head(&mut self) -> Result<(Option<u64>, Head), ParseError> {
+        let mut tag = None;
+        loop {
+            let head = self.read_head()?;
+            if head.major != MAJOR_TAG {
+                return Ok((tag, head));
+            }
+            tag = Some(head.arg);
+        }
+    }
+
+    /// Whether the next byte is a break, consuming it if so.
+    fn eat_break(&mut self) -> Result<bool, ParseError> {
+        if self.peek_byte()? == BREAK {
+            self.pos += 1;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Read the payload of a byte or text string whose head was just read.
+    /// Indefinite-length strings are joined from their chunks.
+    fn read_string_payload(&mut self, head: Head) -> Result<Cow<'de, [u8]>, ParseError> {
+        if !head.is_indefinite() {
+            return self.read_bytes(head.arg).map(Cow::Borrowed);
+        }
+        let mut joined = Vec::new();
+        while !self.eat_break()? {
+            let pos = self.pos;
+            let chunk = self.read_head()?;
+            if chunk.major != head.major || chunk.is_indefinite() {
+                return Err(invalid(
+                    pos,
+                    "indefinite-length string chunks must be definite strings of the same type",
+                ));
+            }
+            joined.extend_from_slice(self.read_bytes(chunk.arg)?);
+        }
+        Ok(Cow::Owned(joined))
+    }
+
+    /// Read the payload of a text string whose head was just read.
+    fn read_text_payload(&mut self, head: Head) -> Result<Cow<'de, str>, ParseError> {
+        let start = self.pos;
+        let bytes = self.read_string_payload(head)?;
+        let invalid_utf8 = |bytes: &[u8]| {
+            let mut context = [0u8; 16];
+            let context_len = bytes.len().min(16);
+            context[..context_len].copy_from_slice(&bytes[..context_len]);
+            ParseError::new(
+                Span::new(start, self.pos - start),
+                DeserializeErrorKind::InvalidUtf8 {
+                    context,
+                    context_len: context_len as u8,
+                },
+            )
+        };
+        match bytes {
+            Cow::Borrowed(bytes) => core::str::from_utf8(bytes)
+                .map(Cow::Borrowed)
+                .map_err(|_| invalid_utf8(bytes)),
+            Cow::Owned(bytes) => match alloc::string::String::from_utf8(bytes) {
+                Ok(s) => Ok(Cow::Owned(s)),
+                Err(e) => Err(invalid_utf8(e.as_bytes())),
+            },
+        }
+    }
+
+    /// Decode a bignum's byte string, whose head was just read, into a
+    /// 128-bit integer.
+    fn read_bignum(&mut self, tag: u64, head: Head) -> Result<ScalarValue<'de>, ParseError> {
+        let pos = self.start;
+        if head.major != MAJOR_BYTES {
+            return Err(invalid(pos, "bignum tag must wrap a byte string"));
+        }
+        let bytes = self.read_string_payload(head)?;
+        let digits = match bytes.iter().position(|&b| b != 0) {
+            Some(first) => &bytes[first..],
+            None => &[][..],
+        };
+        if digits.len() > 16 {
+            return Err(invalid(pos, "bignum doesn't fit in 128 bits"));
+        }
+        let n = digits
+            .iter()
+            .fold(0u128, |n, &digit| (n << 8) | digit as u128);
+
+        if tag == TAG_POSITIVE_BIGNUM {
+            return Ok(match u64::try_from(n) {
+                Ok(n) => ScalarValue::U64(n),
+                Err(_) => ScalarValue::U128(n),
+            });
+        }
+        // A negative bignum encodes -1 - n
+        if n <= i64::MAX as u128 {
+            Ok(ScalarValue::I64(-1 - n as i64))
+        } else if n <= i128::MAX as u128 {
+            Ok(ScalarValue::I128(-1 - n as i128))
+        } else {
+            Err(invalid(pos, "negative bignum doesn't fit in 128 bits"))
+        }
+    }
+
+    /// Read a map key, which facet sees as a string. Integer keys are
+    /// accepted and turned into their decimal form.
+    fn read_key(&mut self) -> Result<Cow<'de, str>, ParseError> {
+        let pos = self.pos;
+        let (_tag, head) = self.read_tagged_head()?;
+        match head.major {
+            MAJOR_TEXT => self.read_text_payload(head),
+            MAJOR_UNSIGNED => Ok(Cow::Owned(head.arg.to_string())),
+            MAJOR_NEGATIVE => Ok(Cow::Owned((-1 - head.arg as i128).to_string())),
+            _ => Err(invalid(pos, "map keys must be text strings or integers")),
+        }
+    }
+
+    /// Finish processing a value and update parent container state.
+    fn finish_value(&mut self) {
+        if let Some(context) = self.stack.last_mut() {
+            match context {
+                ContextState::MapValue { remaining } => {
+                    // Finished a value, go back to expecting a key (or end)
+                    *context = ContextState::MapKey {
+                        remaining: *remaining,
+                    };
+                }
+                ContextState::MapKey { remaining } | ContextState::Array { remaining } => {
+                    if let Some(remaining) = remaining {
+                        *remaining = remaining.saturating_sub(1);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Finish a scalar value and produce its event.
+    fn scalar_event(&mut self, scalar: ScalarValue<'de>) -> Option<ParseEvent<'de>> {
+        self.finish_value();
+        Some(self.event(ParseEventKind::Scalar(scalar)))
+    }
+
+    /// Produce the next parse event.
+    fn produce_event(&mut self) -> Result<Option<ParseEvent<'de>>, ParseError> {
+        self.start = self.pos;
+
+        // Check if we need to emit container end events
+        if let Some(&context) = self.stack.last() {
+            let ended = match context {
+                ContextState::MapKey { remaining: Some(0) }
+                | ContextState::Array { remaining: Some(0) } => true,
+                ContextState::MapKey { remaining: None }
+                | ContextState::Array { remaining: None } => self.eat_break()?,
+                _ => false,
+            };
+            if ended {
+                self.stack.pop();
+                self.finish_value();
+                let kind = match context {
+                    ContextState::Array { .. } => ParseEventKind::SequenceEnd,
+                    _ => ParseEventKind::StructEnd,
+                };
+                return Ok(Some(self.event(kind)));
+            }
+        }
+
+        // Check if we're at EOF
+        if self.pos >= self.input.len() {
+            return Ok(None);
+        }
+
+        if let Some(&ContextState::MapKey { remaining }) = self.stack.last() {
+            let key = self.read_key()?;
+            if let Some(state) = self.stack.last_mut() {
+                *state = ContextState::MapValue {
+                    remaining: remaining.map(|n| n - 1),
+                };
+            }
+            return Ok(Some(self.event(ParseEventKind::FieldKey(FieldKey::new(
+                key,
+                FieldLocationHint::KeyValue,
+            )))));
+        }
+
+        let (tag, head) = self.read_tagged_head()?;
+        if let Some(tag @ (TAG_POSITIVE_BIGNUM | TAG_NEGATIVE_BIGNUM)) = tag {
+            let scalar = self.read_bignum(tag, head)?;
+            return Ok(self.scalar_event(scalar));
+        }
+
+        match head.major {
+            MAJOR_UNSIGNED => Ok(self.scalar_event(ScalarValue::U64(head.arg))),
+            MAJOR_NEGATIVE => {
+                let scalar = match i64::try_from(head.arg) {
+                    Ok(n) => ScalarValue::I64(-1 - n),
+                    Err(_) => ScalarValue::I128(-1 - head.arg as i128),
+                };
+                Ok(self.scalar_event(scalar))
+            }
+            MAJOR_BYTES => {
+                let bytes = self.read_string_payload(head)?;
+                Ok(self.scalar_event(ScalarValue::Bytes(bytes)))
+            }
+            MAJOR_TEXT => {
+                let s = self.read_text_payload(head)?;
+                Ok(self.scalar_event(ScalarValue::Str(s)))
+            }
+            MAJOR_ARRAY => {
+                let remaining = (!head.is_indefinite()).then_some(head.arg);
+                self.stack.push(ContextState::Array { remaining });
+                Ok(Some(self.event(ParseEventKind::SequenceStart(
+                    ContainerKind::Array,
+                ))))
+            }
+            MAJOR_MAP => {
+                let remaining = (!head.is_indefinite()).then_some(head.arg);
+                self.stack.push(ContextState::MapKey { remaining });
+                Ok(Some(
+                    self.event(ParseEventKind::StructStart(ContainerKind::Object)),
+                ))
+            }
+            _ => {
+                let scalar = self.simple_value(head)?;
+                Ok(self.scalar_event(scalar))
+            }
+        }
+    }
+
+    /// Decode a simple value or float (major type 7).
+    fn simple_value(&self, head: Head) -> Result<ScalarValue<'de>, ParseError> {
+        match head.info {
+            SIMPLE_FALSE => Ok(ScalarValue::Bool(false)),
+            SIMPLE_TRUE => Ok(ScalarValue::Bool(true)),
+            SIMPLE_NULL | SIMPLE_UNDEFINED => Ok(ScalarValue::Null),
+            FLOAT16 => Ok(ScalarValue::F64(f16_to_f64(head.arg as u16))),
+            FLOAT32 => Ok(ScalarValue::F64(f32::from_bits(head.arg as u32) as f64)),
+            FLOAT64 => Ok(ScalarValue::F64(f64::from_bits(head.arg))),
+            INFO_INDEFINITE => Err(invalid(self.start, "unexpected break")),
+            _ => Err(invalid(
+                self.start,
+                format!("unsupported CBOR simple value {}", head.arg),
+            )),
+        }
+    }
+
+    /// Skip a complete item (used for skip_value).
+    fn skip_value_internal(&mut self) -> Result<(), ParseError> {
+        let (_tag, head) = self.read_tagged_head()?;
+        match head.major {
+            MAJOR_BYTES | MAJOR_TEXT => {
+                self.read_string_payload(head)?;
+            }
+            MAJOR_ARRAY | MAJOR_MAP => {
+                let per_entry = if head.major == MAJOR_MAP { 2 } else { 1 };
+                if head.is_indefinite() {
+                    while !self.eat_break()? {
+                        for _ in 0..per_entry {
+                            self.skip_value_internal()?;
+                        }
+                    }
+                } else {
+                    for _ in 0..head.arg {
+                        for _ in 0..per_entry {
+                            self.skip_value_internal()?;
+                        }
+                    }
+                }
+            }
+            MAJOR_SIMPLE if head.is_indefinite() => {
+                return Err(invalid(self.pos - 1, "unexpected break"));
+            }
+            // Integers, simple values and floats are all in the head
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Create an event spanning the item read since `self.start`.
+    #[inline]
+    fn event(&self, kind: ParseEventKind<'de>) -> ParseEvent<'de> {
+        ParseEvent::new(kind, Span::new(self.start, self.pos - self.start))
+    }
+}
+
+/// Widen an IEEE 754 half-precision float.
+fn f16_to_f64(bits: u16) -> f64 {
+    let sign = if bits & 0x8000 != 0 { -1.0 } else { 1.0 };
+    let exponent = (bits >> 10) & 0x1f;
+    let mantissa = (bits & 0x3ff) as f64;
+    sign * match exponent {
+        0 => mantissa * 2f64.powi(-24),
+        0x1f if mantissa == 0.0 => f64::INFINITY,
+        0x1f => f64::NAN,
+        _ => (1.0 + mantissa / 1024.0) * 2f64.powi(exponent as i32 - 15),
+    }
+}
+
+impl<'de> FormatParser<'de> for CborParser<'de> {
+    fn next_event(&mut self) -> Result<Option<ParseEvent<'de>>, ParseError> {
+        if let Some(event) = self.event_peek.take() {
+            return Ok(Some(event));
+        }
+        self.produce_event()
+    }
+
+    fn peek_event(&mut self) -> Result<Option<ParseEvent<'de>>, ParseError> {
+        if let Some(event) = self.event_peek.clone() {
+            return Ok(Some(event));
+        }
+        let event = self.produce_event()?;
+        if let Some(ref e) = event {
+            self.event_peek = Some(e.clone());
+        }
+        Ok(event)
+    }
+
+    fn skip_value(&mut self) -> Result<(), ParseError> {
+        debug_assert!(
+            self.event_peek.is_none(),
+            "skip_value called while an event is buffered"
+        );
+        self.skip_value_internal()?;
+        self.finish_value();
+        Ok(())
+    }
+
+    fn opaque_scalar_event(
+        &self,
+        shape: &'static facet_core::Shape,
+        _field: Option<&facet_core::Field>,
+        mut event: ParseEvent<'de>,
+    ) -> Result<ParseEvent<'de>, ParseError> {
+        if !datetime::is_instant(shape) {
+            return Ok(event);
+        }
+        // Epoch-based date/times (tag 1) are numbers of seconds
+        let text = match event.kind {
+            ParseEventKind::Scalar(ScalarValue::U64(n)) => i64::try_from(n)
+                .ok()
+                .and_then(|n| datetime::epoch_to_rfc3339(n, 0)),
+            ParseEventKind::Scalar(ScalarValue::I64(n)) => datetime::epoch_to_rfc3339(n, 0),
+            ParseEventKind::Scalar(ScalarValue::F64(n)) => datetime::epoch_seconds_to_rfc3339(n),
+            _ => return Ok(event),
+        };
+        let text = text.ok_or_else(|| {
+            ParseError::new(
+                event.span,
+                DeserializeErrorKind::InvalidValue {
+                    message: "epoch date/time is out of range".into(),
+                },
+            )
+        })?;
+        event.kind = ParseEventKind::Scalar(ScalarValue::Str(Cow::Owned(text)));
+        Ok(event)
+    }
+
+    fn save(&mut self) -> SavePoint {
+        // The input is in memory, so replaying is reading it again from the
+        // saved position with the saved container stack
+        self.save_counter += 1;
+        let state = SavedState {
+            pos: self.pos,
+            start: self.start,
+            stack: self.stack.clone(),
+            event_peek: self.event_peek.clone(),
+        };
+        self.saved_states.push((self.save_counter, state));
+        SavePoint::new(self.save_counter)
+    }
+
+    fn restore(&mut self, save_point: SavePoint) {
+        let idx = self
+            .saved_states
+            .iter()
+            .position(|(id, _)| *id == save_point.0);
+        debug_assert!(idx.is_some(), "restore called with unknown save point");
+        if let Some(idx) = idx {
+            let (_, state) = self.saved_states.remove(idx);
+            self.pos = state.pos;
+            self.start = state.start;
+            self.stack = state.stack;
+            self.event_peek = state.event_peek;
+        }
+    }
+}
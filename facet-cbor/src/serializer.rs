@@ -0,0 +1,521 @@
+//! CBOR serializer implementing FormatSerializer.
+
+extern crate alloc;
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use facet_core::ScalarType;
+use facet_format::{FormatSerializer, ScalarValue, SerializeError};
+use facet_reflect::Peek;
+
+use crate::datetime;
+use crate::parser::{
+    MAJOR_ARRAY, MAJOR_BYTES, MAJOR_MAP, MAJOR_NEGATIVE, MAJOR_TAG, MAJOR_TEXT, MAJOR_UNSIGNED,
+    TAG_DATETIME_STRING, TAG_NEGATIVE_BIGNUM, TAG_POSITIVE_BIGNUM,
+};
+
+// Simple values and float heads (major type 7)
+const CBOR_FALSE: u8 = 0xf4;
+const CBOR_TRUE: u8 = 0xf5;
+const CBOR_NULL: u8 = 0xf6;
+const CBOR_FLOAT16: u8 = 0xf9;
+const CBOR_FLOAT32: u8 = 0xfa;
+const CBOR_FLOAT64: u8 = 0xfb;
+
+/// The canonical NaN: a half-precision quiet NaN.
+const CANONICAL_NAN: [u8; 3] = [CBOR_FLOAT16, 0x7e, 0x00];
+
+/// Options for CBOR serialization.
+#[derive(Debug, Clone, Default)]
+pub struct SerializeOptions {
+    /// Write deterministically encoded CBOR (RFC 8949 §4.2.1), so that equal
+    /// values always produce identical bytes (default: false).
+    ///
+    /// On top of the preferred serialization that is always used (shortest
+    /// integer and float forms, definite lengths), map entries are sorted by
+    /// the bytewise order of their encoded keys and NaN is written as the
+    /// half-precision `0xf97e00`. Use this when the bytes get hashed or
+    /// signed.
+    pub deterministic: bool,
+}
+
+impl SerializeOptions {
+    /// Create new default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Write deterministically encoded CBOR.
+    pub const fn deterministic(mut self) -> Self {
+        self.deterministic = true;
+        self
+    }
+}
+
+/// CBOR serializer error.
+#[derive(Debug)]
+pub struct CborSerializeError {
+    message: String,
+}
+
+impl core::fmt::Display for CborSerializeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for CborSerializeError {}
+
+/// CBOR serializer.
+pub struct CborSerializer {
+    out: Vec<u8>,
+    /// Stack tracking whether we're in a map or array, and item counts
+    stack: Vec<ContainerState>,
+    options: SerializeOptions,
+}
+
+#[derive(Debug)]
+enum ContainerState {
+    /// A struct or map. `entries` holds where each entry starts and how long
+    /// its encoded key is, for sorting in deterministic mode.
+    Map {
+        head_pos: usize,
+        entries: Vec<(usize, usize)>,
+    },
+    Array {
+        count: usize,
+        head_pos: usize,
+    },
+}
+
+/// The head of an item: its major type and argument, in the shortest form.
+fn head(major: u8, arg: u64) -> ([u8; 9], usize) {
+    let major = major << 5;
+    let mut buf = [0u8; 9];
+    let len = match arg {
+        0..=23 => {
+            buf[0] = major | arg as u8;
+            1
+        }
+        24..=0xff => {
+            buf[0] = major | 24;
+            buf[1] = arg as u8;
+            2
+        }
+        0x100..=0xffff => {
+            buf[0] = major | 25;
+            buf[1..3].copy_from_slice(&(arg as u16).to_be_bytes());
+            3
+        }
+        0x1_0000..=0xffff_ffff => {
+            buf[0] = major | 26;
+            buf[1..5].copy_from_slice(&(arg as u32).to_be_bytes());
+            5
+        }
+        _ => {
+            buf[0] = major | 27;
+            buf[1..9].copy_from_slice(&arg.to_be_bytes());
+            9
+        }
+    };
+    (buf, len)
+}
+
+/// Narrow `n` to IEEE 754 half precision, if that loses nothing.
+fn f64_to_f16(n: f64) -> Option<u16> {
+    let bits = n.to_bits();
+    let sign = ((bits >> 48) & 0x8000) as u16;
+    let exponent = ((bits >> 52) & 0x7ff) as i32;
+    let mantissa = bits & 0x000f_ffff_ffff_ffff;
+
+    if exponent == 0x7ff {
+        // Infinities survive; NaN payloads generally don't
+        return (mantissa == 0).then_some(sign | 0x7c00);
+    }
+    if exponent == 0 && mantissa == 0 {
+        return Some(sign);
+    }
+
+    let unbiased = exponent - 1023;
+    match unbiased {
+        // Normal half-precision range: 10 mantissa bits survive
+        -14..=15 => {
+            if mantissa & ((1 << 42) - 1) != 0 {
+                return None;
+            }
+            let half_exponent = (unbiased + 15) as u16;
+            Some(sign | (half_exponent << 10) | (mantissa >> 42) as u16)
+        }
+        // Subnormal half-precision range
+        -24..=-15 => {
+            let shift = 42 + (-14 - unbiased) as u64;
+            let full = mantissa | (1 << 52);
+            if full & ((1 << shift) - 1) != 0 {
+                return None;
+            }
+            Some(sign | (full >> shift) as u16)
+        }
+        _ => None,
+    }
+}
+
+impl CborSerializer {
+    /// Create a new CBOR serializer.
+    pub const fn new() -> Self {
+        Self::with_options(SerializeOptions {
+            deterministic: false,
+        })
+    }
+
+    /// Create a new CBOR serializer with the given options.
+    pub const fn with_options(options: SerializeOptions) -> Self {
+        Self {
+            out: Vec::new(),
+            stack: Vec::new(),
+            options,
+        }
+    }
+
+    /// Consume the serializer and return the output bytes.
+    pub fn finish(mut self) -> Vec<u8> {
+        // Patch up any remaining container counts (shouldn't happen with well-formed input)
+        while let Some(state) = self.stack.pop() {
+            match state {
+                ContainerState::Map { head_pos, entries } => {
+                    self.patch_count(MAJOR_MAP, head_pos, entries.len());
+                }
+                ContainerState::Array { count, head_pos } => {
+                    self.patch_count(MAJOR_ARRAY, head_pos, count);
+                }
+            }
+        }
+        self.out
+    }
+
+    fn write_head(&mut self, major: u8, arg: u64) {
+        let (buf, len) = head(major, arg);
+        self.out.extend_from_slice(&buf[..len]);
+    }
+
+    fn write_u64(&mut self, n: u64) {
+        self.write_head(MAJOR_UNSIGNED, n);
+    }
+
+    fn write_i64(&mut self, n: i64) {
+        if n >= 0 {
+            self.write_head(MAJOR_UNSIGNED, n as u64);
+        } else {
+            // Negative integers encode -1 - n
+            self.write_head(MAJOR_NEGATIVE, !(n as u64));
+        }
+    }
+
+    fn write_u128(&mut self, n: u128) {
+        match u64::try_from(n) {
+            Ok(n) => self.write_u64(n),
+            Err(_) => self.write_bignum(TAG_POSITIVE_BIGNUM, n),
+        }
+    }
+
+    fn write_i128(&mut self, n: i128) {
+        if n >= 0 {
+            self.write_u128(n as u128);
+        } else {
+            // Negative integers encode -1 - n
+            let magnitude = !(n as u128);
+            match u64::try_from(magnitude) {
+                Ok(magnitude) => self.write_head(MAJOR_NEGATIVE, magnitude),
+                Err(_) => self.write_bignum(TAG_NEGATIVE_BIGNUM, magnitude),
+            }
+        }
+    }
+
+    /// Write a bignum: `tag` around the big-endian bytes of `n`, without
+    /// leading zeros.
+    fn write_bignum(&mut self, tag: u64, n: u128) {
+        let bytes = n.to_be_bytes();
+        let first = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+        self.write_head(MAJOR_TAG, tag);
+        self.write_bytes(&bytes[first..]);
+    }
+
+    /// Write a float in the shortest form that preserves its value.
+    fn write_f64(&mut self, n: f64) {
+        if n.is_nan() && self.options.deterministic {
+            self.out.extend_from_slice(&CANONICAL_NAN);
+        } else if let Some(half) = f64_to_f16(n) {
+            self.out.push(CBOR_FLOAT16);
+            self.out.extend_from_slice(&half.to_be_bytes());
+        } else if (n as f32) as f64 == n {
+            self.out.push(CBOR_FLOAT32);
+            self.out.extend_from_slice(&(n as f32).to_be_bytes());
+        } else {
+            self.out.push(CBOR_FLOAT64);
+            self.out.extend_from_slice(&n.to_be_bytes());
+        }
+    }
+
+    fn write_str(&mut self, s: &str) {
+        self.write_head(MAJOR_TEXT, s.len() as u64);
+        self.out.extend_from_slice(s.as_bytes());
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        self.write_head(MAJOR_BYTES, bytes.len() as u64);
+        self.out.extend_from_slice(bytes);
+    }
+
+    fn write_null(&mut self) {
+        self.out.push(CBOR_NULL);
+    }
+
+    fn write_bool(&mut self, v: bool) {
+        self.out.push(if v { CBOR_TRUE } else { CBOR_FALSE });
+    }
+
+    /// Replace the one-byte placeholder head at `head_pos` with the real
+    /// count, widening it if needed.
+    fn patch_count(&mut self, major: u8, head_pos: usize, count: usize) {
+        let (buf, len) = head(major, count as u64);
+        self.out
+            .splice(head_pos..head_pos + 1, buf[..len].iter().copied());
+    }
+
+    /// Sort the entries of the map whose head is at `head_pos` by their
+    /// encoded keys.
+    fn sort_entries(&mut self, head_pos: usize, entries: &[(usize, usize)]) {
+        let body_start = head_pos + 1;
+        let body = self.out.split_off(body_start);
+        let mut spans: Vec<(usize, usize, usize)> = entries
+            .iter()
+            .enumerate()
+            .map(|(i, &(start, key_len))| {
+                let end = entries
+                    .get(i + 1)
+                    .map_or(body_start + body.len(), |next| next.0);
+                (start - body_start, end - body_start, key_len)
+            })
+            .collect();
+        spans.sort_by(|a, b| body[a.0..a.0 + a.2].cmp(&body[b.0..b.0 + b.2]));
+        for (start, end, _) in spans {
+            self.out.extend_from_slice(&body[start..end]);
+        }
+    }
+
+    /// Record a value emission in the current array, if any.
+    fn bump_count_for_value(&mut self) {
+        if let Some(ContainerState::Array { count, .. }) = self.stack.last_mut() {
+            *count += 1;
+        }
+    }
+
+    /// Write a placeholder head for a container; its count gets patched in
+    /// at the end.
+    fn begin_container(&mut self, major: u8) -> usize {
+        self.bump_count_for_value();
+        let head_pos = self.out.len();
+        self.out.push(major << 5);
+        head_pos
+    }
+}
+
+impl Default for CborSerializer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FormatSerializer for CborSerializer {
+    type Error = CborSerializeError;
+
+    fn begin_struct(&mut self) -> Result<(), Self::Error> {
+        let head_pos = self.begin_container(MAJOR_MAP);
+        self.stack.push(ContainerState::Map {
+            head_pos,
+            entries: Vec::new(),
+        });
+        Ok(())
+    }
+
+    fn field_key(&mut self, key: &str) -> Result<(), Self::Error> {
+        let start = self.out.len();
+        self.write_str(key);
+        let key_len = self.out.len() - start;
+        match self.stack.last_mut() {
+            Some(ContainerState::Map { entries, .. }) => {
+                entries.push((start, key_len));
+                Ok(())
+            }
+            _ => Err(CborSerializeError {
+                message: "field_key called outside of a map".into(),
+            }),
+        }
+    }
+
+    fn end_struct(&mut self) -> Result<(), Self::Error> {
+        match self.stack.pop() {
+            Some(ContainerState::Map { head_pos, entries }) => {
+                if self.options.deterministic {
+                    self.sort_entries(head_pos, &entries);
+                }
+                self.patch_count(MAJOR_MAP, head_pos, entries.len());
+                Ok(())
+            }
+            _ => Err(CborSerializeError {
+                message: "end_struct called without matching begin_struct".into(),
+            }),
+        }
+    }
+
+    fn begin_seq(&mut self) -> Result<(), Self::Error> {
+        let head_pos = self.begin_container(MAJOR_ARRAY);
+        self.stack
+            .push(ContainerState::Array { count: 0, head_pos });
+        Ok(())
+    }
+
+    fn end_seq(&mut self) -> Result<(), Self::Error> {
+        match self.stack.pop() {
+            Some(ContainerState::Array { count, head_pos }) => {
+                self.patch_count(MAJOR_ARRAY, head_pos, count);
+                Ok(())
+            }
+            _ => Err(CborSerializeError {
+                message: "end_seq called without matching begin_seq".into(),
+            }),
+        }
+    }
+
+    fn scalar(&mut self, scalar: ScalarValue<'_>) -> Result<(), Self::Error> {
+        self.bump_count_for_value();
+
+        match scalar {
+            ScalarValue::Null | ScalarValue::Unit => self.write_null(),
+            ScalarValue::Bool(v) => self.write_bool(v),
+            ScalarValue::Char(c) => {
+                let mut buf = [0u8; 4];
+                self.write_str(c.encode_utf8(&mut buf));
+            }
+            ScalarValue::U64(n) => self.write_u64(n),
+            ScalarValue::I64(n) => self.write_i64(n),
+            ScalarValue::U128(n) => self.write_u128(n),
+            ScalarValue::I128(n) => self.write_i128(n),
+            ScalarValue::F64(n) => self.write_f64(n),
+            ScalarValue::Str(s) => self.write_str(&s),
+            ScalarValue::Bytes(bytes) => self.write_bytes(&bytes),
+            _ => {
+                return Err(CborSerializeError {
+                    message: "unsupported scalar value kind".into(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn serialize_opaque_scalar(
+        &mut self,
+        shape: &'static facet_core::Shape,
+        value: Peek<'_, '_>,
+    ) -> Result<bool, Self::Error> {
+        // 128-bit integers beyond the 64-bit range become bignums (tags 2
+        // and 3) instead of the strings other binary formats fall back to
+        match shape.scalar_type() {
+            Some(ScalarType::U128) => {
+                if let Ok(n) = value.get::<u128>() {
+                    self.bump_count_for_value();
+                    self.write_u128(*n);
+                    return Ok(true);
+                }
+            }
+            Some(ScalarType::I128) => {
+                if let Ok(n) = value.get::<i128>() {
+                    self.bump_count_for_value();
+                    self.write_i128(*n);
+                    return Ok(true);
+                }
+            }
+            _ => {}
+        }
+
+        // Date/times with an offset are standard date/time strings (tag 0)
+        if datetime::is_instant(shape) && shape.vtable.has_display() {
+            self.bump_count_for_value();
+            self.write_head(MAJOR_TAG, TAG_DATETIME_STRING);
+            self.write_str(&value.to_string());
+            return Ok(true);
+        }
+
+        Ok(false)
+    }
+
+    fn serialize_byte_sequence(&mut self, bytes: &[u8]) -> Result<bool, Self::Error> {
+        self.bump_count_for_value();
+        self.write_bytes(bytes);
+        Ok(true)
+    }
+}
+
+/// Serialize a value to CBOR bytes.
+pub fn to_vec<'facet, T>(value: &T) -> Result<Vec<u8>, SerializeError<CborSerializeError>>
+where
+    T: facet_core::Facet<'facet>,
+{
+    let mut ser = CborSerializer::new();
+    facet_format::serialize_root(&mut ser, Peek::new(value))?;
+    Ok(ser.finish())
+}
+
+/// Serialize a value to CBOR bytes with custom options.
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+/// use facet_cbor::{SerializeOptions, to_vec_with_options};
+///
+/// #[derive(Facet)]
+/// struct Claims { sub: String, iat: u32 }
+///
+/// let claims = Claims { sub: "me".into(), iat: 7 };
+/// let bytes = to_vec_with_options(&claims, &SerializeOptions::new().deterministic()).unwrap();
+/// // {"iat": 7, "sub": "me"}: keys in bytewise order of their encodings
+/// assert_eq!(bytes, [0xa2, 0x63, b'i', b'a', b't', 0x07, 0x63, b's', b'u', b'b', 0x62, b'm', b'e']);
+/// ```
+pub fn to_vec_with_options<'facet, T>(
+    value: &T,
+    options: &SerializeOptions,
+) -> Result<Vec<u8>, SerializeError<CborSerializeError>>
+where
+    T: facet_core::Facet<'facet>,
+{
+    let mut ser = CborSerializer::with_options(options.clone());
+    facet_format::serialize_root(&mut ser, Peek::new(value))?;
+    Ok(ser.finish())
+}
+
+/// Serialize a value to CBOR bytes using a writer.
+pub fn to_writer<'facet, T, W>(writer: &mut W, value: &T) -> Result<(), std::io::Error>
+where
+    T: facet_core::Facet<'facet>,
+    W: std::io::Write,
+{
+    to_writer_with_options(writer, value, &SerializeOptions::default())
+}
+
+/// Serialize a value to CBOR bytes using a writer, with custom options.
+pub fn to_writer_with_options<'facet, T, W>(
+    writer: &mut W,
+    value: &T,
+    options: &SerializeOptions,
+) -> Result<(), std::io::Error>
+where
+    T: facet_core::Facet<'facet>,
+    W: std::io::Write,
+{
+    let bytes =
+        to_vec_with_options(value, options).map_err(|e| std::io::Error::other(e.to_string()))?;
+    writer.write_all(&bytes)
+}
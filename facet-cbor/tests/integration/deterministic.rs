@@ -0,0 +1,115 @@
+//! Deterministic encoding (RFC 8949 §4.2.1).
+
+use std::collections::{BTreeMap, HashMap};
+
+use facet::Facet;
+use facet_cbor::{SerializeOptions, from_slice, to_vec, to_vec_with_options};
+
+fn deterministic<'a, T: Facet<'a>>(value: &T) -> Vec<u8> {
+    to_vec_with_options(value, &SerializeOptions::new().deterministic()).unwrap()
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct Claims {
+    sub: String,
+    aud: String,
+    exp: u64,
+    nested: Inner,
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct Inner {
+    zeta: bool,
+    alpha: bool,
+}
+
+#[test]
+fn struct_fields_are_sorted_by_encoded_key() {
+    let value = Claims {
+        sub: "s".to_string(),
+        aud: "a".to_string(),
+        exp: 1,
+        nested: Inner {
+            zeta: true,
+            alpha: false,
+        },
+    };
+    let bytes = deterministic(&value);
+
+    // Shorter keys sort first, since their encoded heads are smaller: "sub"
+    // comes before "nested", and "zeta" before "alpha"
+    let mut expected = vec![0xa4];
+    expected.extend_from_slice(&[0x63, b'a', b'u', b'd', 0x61, b'a']);
+    expected.extend_from_slice(&[0x63, b'e', b'x', b'p', 0x01]);
+    expected.extend_from_slice(&[0x63, b's', b'u', b'b', 0x61, b's']);
+    expected.extend_from_slice(&[0x66, b'n', b'e', b's', b't', b'e', b'd', 0xa2]);
+    expected.extend_from_slice(&[0x64, b'z', b'e', b't', b'a', 0xf5]);
+    expected.extend_from_slice(&[0x65, b'a', b'l', b'p', b'h', b'a', 0xf4]);
+    assert_eq!(bytes, expected);
+
+    assert_eq!(from_slice::<Claims>(&bytes).unwrap(), value);
+}
+
+#[test]
+fn default_mode_keeps_declaration_order() {
+    let bytes = to_vec(&Claims {
+        sub: "s".to_string(),
+        aud: "a".to_string(),
+        exp: 1,
+        nested: Inner {
+            zeta: true,
+            alpha: false,
+        },
+    })
+    .unwrap();
+    assert_eq!(&bytes[1..5], &[0x63, b's', b'u', b'b']);
+}
+
+#[test]
+fn map_iteration_order_does_not_matter() {
+    let keys = (0..100).map(|i| format!("key{i}"));
+    let forward: HashMap<String, u32> = keys.clone().zip(0..).collect();
+    let sorted: BTreeMap<String, u32> = keys.zip(0..).collect();
+
+    let bytes = deterministic(&forward);
+    assert_eq!(bytes, deterministic(&sorted));
+    // A map of more than 23 entries has a one-byte count after its head
+    assert_eq!(&bytes[..2], &[0xb8, 100]);
+    assert_eq!(from_slice::<HashMap<String, u32>>(&bytes).unwrap(), forward);
+}
+
+#[test]
+fn nan_is_canonical() {
+    let nan = f64::from_bits(0x7ff8_0000_0000_0001);
+    assert_eq!(deterministic(&nan), [0xf9, 0x7e, 0x00]);
+    assert_eq!(to_vec(&nan).unwrap()[0], 0xfb);
+}
+
+#[test]
+fn wide_nested_values_keep_their_entries_intact() {
+    // Children whose heads get widened after their entry was recorded
+    let value: BTreeMap<String, Vec<u8>> = [
+        ("b".to_string(), vec![0; 300]),
+        ("a".to_string(), vec![1; 30]),
+    ]
+    .into_iter()
+    .collect();
+    let bytes = deterministic(&value);
+    assert_eq!(
+        from_slice::<BTreeMap<String, Vec<u8>>>(&bytes).unwrap(),
+        value
+    );
+
+    let lists: HashMap<String, Vec<u16>> = [
+        ("zz".to_string(), (0..500).collect()),
+        ("a".to_string(), (0..40).collect()),
+    ]
+    .into_iter()
+    .collect();
+    let bytes = deterministic(&lists);
+    assert_eq!(&bytes[..3], &[0xa2, 0x61, b'a']);
+    assert_eq!(
+        from_slice::<HashMap<String, Vec<u16>>>(&bytes).unwrap(),
+        lists
+    );
+}
@@ -0,0 +1,4 @@
+mod deterministic;
+mod rfc_vectors;
+mod roundtrip;
+mod tags;
@@ -0,0 +1,120 @@
+//! Encoding examples from RFC 8949 Appendix A.
+
+use std::collections::HashMap;
+
+use facet_cbor::{from_slice, to_vec};
+
+#[test]
+fn integers() {
+    assert_eq!(to_vec(&0u64).unwrap(), [0x00]);
+    assert_eq!(to_vec(&23u64).unwrap(), [0x17]);
+    assert_eq!(to_vec(&24u64).unwrap(), [0x18, 0x18]);
+    assert_eq!(to_vec(&1000u64).unwrap(), [0x19, 0x03, 0xe8]);
+    assert_eq!(to_vec(&1000000u64).unwrap(), [0x1a, 0x00, 0x0f, 0x42, 0x40]);
+    assert_eq!(
+        to_vec(&u64::MAX).unwrap(),
+        [0x1b, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]
+    );
+    assert_eq!(to_vec(&-1i64).unwrap(), [0x20]);
+    assert_eq!(to_vec(&-100i64).unwrap(), [0x38, 0x63]);
+    assert_eq!(to_vec(&-1000i64).unwrap(), [0x39, 0x03, 0xe7]);
+}
+
+#[test]
+fn floats() {
+    assert_eq!(to_vec(&0.0f64).unwrap(), [0xf9, 0x00, 0x00]);
+    assert_eq!(to_vec(&-0.0f64).unwrap(), [0xf9, 0x80, 0x00]);
+    assert_eq!(to_vec(&65504.0f64).unwrap(), [0xf9, 0x7b, 0xff]);
+    assert_eq!(to_vec(&f64::INFINITY).unwrap(), [0xf9, 0x7c, 0x00]);
+    assert_eq!(to_vec(&-4.0f64).unwrap(), [0xf9, 0xc4, 0x00]);
+
+    assert_eq!(
+        from_slice::<f64>(&[0xf9, 0x00, 0x01]).unwrap(),
+        5.960464477539063e-8
+    );
+    assert_eq!(
+        from_slice::<f64>(&[0xf9, 0x04, 0x00]).unwrap(),
+        0.00006103515625
+    );
+    assert_eq!(
+        from_slice::<f64>(&[0xfa, 0x47, 0xc3, 0x50, 0x00]).unwrap(),
+        100000.0
+    );
+    assert!(from_slice::<f64>(&[0xf9, 0x7e, 0x00]).unwrap().is_nan());
+}
+
+#[test]
+fn simple_values() {
+    assert_eq!(to_vec(&false).unwrap(), [0xf4]);
+    assert_eq!(to_vec(&true).unwrap(), [0xf5]);
+    assert_eq!(to_vec(&None::<u8>).unwrap(), [0xf6]);
+    // undefined reads as null
+    assert_eq!(from_slice::<Option<u8>>(&[0xf7]).unwrap(), None);
+}
+
+#[test]
+fn strings() {
+    assert_eq!(to_vec(&"").unwrap(), [0x60]);
+    assert_eq!(to_vec(&"IETF").unwrap(), [0x64, 0x49, 0x45, 0x54, 0x46]);
+    assert_eq!(to_vec(&"\u{00fc}").unwrap(), [0x62, 0xc3, 0xbc]);
+}
+
+#[test]
+fn arrays_and_maps() {
+    assert_eq!(
+        to_vec(&vec![vec![1u8], vec![2, 3]]).unwrap(),
+        [0x82, 0x41, 0x01, 0x42, 0x02, 0x03]
+    );
+    assert_eq!(
+        to_vec(&vec![vec![1u16], vec![2, 3]]).unwrap(),
+        [0x82, 0x81, 0x01, 0x82, 0x02, 0x03]
+    );
+
+    let map: HashMap<String, u8> = from_slice(&[0xa2, 0x61, 0x61, 0x01, 0x61, 0x62, 0x02]).unwrap();
+    assert_eq!(map.len(), 2);
+    assert_eq!(map["a"], 1);
+    assert_eq!(map["b"], 2);
+}
+
+#[test]
+fn indefinite_lengths() {
+    // (_ h'0102', h'030405')
+    let bytes: Vec<u8> =
+        from_slice(&[0x5f, 0x42, 0x01, 0x02, 0x43, 0x03, 0x04, 0x05, 0xff]).unwrap();
+    assert_eq!(bytes, [1, 2, 3, 4, 5]);
+
+    // (_ "strea", "ming")
+    let text: String = from_slice(&[
+        0x7f, 0x65, 0x73, 0x74, 0x72, 0x65, 0x61, 0x64, 0x6d, 0x69, 0x6e, 0x67, 0xff,
+    ])
+    .unwrap();
+    assert_eq!(text, "streaming");
+
+    // [_ 1, [2, 3], [_ 4, 5]]
+    let nested: (u8, Vec<u8>, Vec<u8>) =
+        from_slice(&[0x9f, 0x01, 0x82, 0x02, 0x03, 0x9f, 0x04, 0x05, 0xff, 0xff]).unwrap();
+    assert_eq!(nested, (1, vec![2, 3], vec![4, 5]));
+
+    // {_ "a": 1, "b": [_ 2, 3]}
+    let map: HashMap<String, Vec<u8>> = from_slice(&[
+        0xbf, 0x61, 0x61, 0x81, 0x01, 0x61, 0x62, 0x9f, 0x02, 0x03, 0xff, 0xff,
+    ])
+    .unwrap();
+    assert_eq!(map["a"], [1]);
+    assert_eq!(map["b"], [2, 3]);
+}
+
+#[test]
+fn integer_map_keys() {
+    // {1: 2, 3: 4}
+    let map: HashMap<u8, u8> = from_slice(&[0xa2, 0x01, 0x02, 0x03, 0x04]).unwrap();
+    assert_eq!(map[&1], 2);
+    assert_eq!(map[&3], 4);
+}
+
+#[test]
+fn truncated_input_is_an_error() {
+    assert!(from_slice::<u32>(&[0x1a, 0x00, 0x0f]).is_err());
+    assert!(from_slice::<Vec<u8>>(&[0x82, 0x01]).is_err());
+    assert!(from_slice::<String>(&[0x7f, 0x61, 0x61]).is_err());
+}
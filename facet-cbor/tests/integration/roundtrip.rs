@@ -0,0 +1,168 @@
+//! Round trips through facet-cbor's serializer and parser.
+
+use std::collections::HashMap;
+
+use facet::Facet;
+use facet_cbor::{from_slice, from_slice_borrowed, to_vec};
+
+#[derive(Debug, Facet, PartialEq)]
+struct SimpleStruct {
+    a: u32,
+    b: String,
+    c: bool,
+}
+
+#[test]
+fn simple_struct_roundtrip() {
+    let value = SimpleStruct {
+        a: 123,
+        b: "hello".to_string(),
+        c: true,
+    };
+    let bytes = to_vec(&value).unwrap();
+    assert_eq!(from_slice::<SimpleStruct>(&bytes).unwrap(), value);
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct Nested {
+    name: String,
+    inner: SimpleStruct,
+    items: Vec<i64>,
+    scores: HashMap<String, f64>,
+    maybe: Option<u8>,
+}
+
+#[test]
+fn nested_roundtrip() {
+    let value = Nested {
+        name: "outer".to_string(),
+        inner: SimpleStruct {
+            a: 70_000,
+            b: "x".repeat(300),
+            c: false,
+        },
+        items: vec![0, -1, 23, 24, -25, i64::MIN, i64::MAX],
+        scores: [("a".to_string(), 1.5), ("b".to_string(), 0.1)]
+            .into_iter()
+            .collect(),
+        maybe: None,
+    };
+    let bytes = to_vec(&value).unwrap();
+    assert_eq!(from_slice::<Nested>(&bytes).unwrap(), value);
+}
+
+#[test]
+fn long_containers_widen_their_heads() {
+    let value: Vec<u16> = (0..1000).collect();
+    let bytes = to_vec(&value).unwrap();
+    // array(1000): 0x99 0x03 0xe8
+    assert_eq!(&bytes[..3], &[0x99, 0x03, 0xe8]);
+    assert_eq!(from_slice::<Vec<u16>>(&bytes).unwrap(), value);
+}
+
+#[derive(Debug, Facet, PartialEq)]
+#[repr(u8)]
+enum Shape {
+    Unit,
+    Circle(f32),
+    Rect { w: u32, h: u32 },
+}
+
+#[test]
+fn enum_roundtrip() {
+    for value in [Shape::Unit, Shape::Circle(2.5), Shape::Rect { w: 3, h: 4 }] {
+        let bytes = to_vec(&value).unwrap();
+        assert_eq!(from_slice::<Shape>(&bytes).unwrap(), value);
+    }
+}
+
+#[test]
+fn bytes_are_byte_strings() {
+    let value = vec![1u8, 2, 3];
+    let bytes = to_vec(&value).unwrap();
+    assert_eq!(bytes, [0x43, 1, 2, 3]);
+    assert_eq!(from_slice::<Vec<u8>>(&bytes).unwrap(), value);
+}
+
+#[test]
+fn borrowed_bytes_and_str() {
+    #[derive(Debug, Facet, PartialEq)]
+    struct Borrowed<'a> {
+        name: &'a str,
+        data: &'a [u8],
+    }
+
+    let value = Borrowed {
+        name: "hi",
+        data: &[0xab, 0xcd],
+    };
+    let bytes = to_vec(&value).unwrap();
+    let back: Borrowed = from_slice_borrowed(&bytes).unwrap();
+    assert_eq!(back, value);
+}
+
+#[test]
+fn floats_use_the_shortest_exact_form() {
+    assert_eq!(to_vec(&1.5f64).unwrap(), [0xf9, 0x3e, 0x00]);
+    assert_eq!(
+        to_vec(&100000.0f64).unwrap(),
+        [0xfa, 0x47, 0xc3, 0x50, 0x00]
+    );
+    assert_eq!(
+        to_vec(&1.1f64).unwrap(),
+        [0xfb, 0x3f, 0xf1, 0x99, 0x99, 0x99, 0x99, 0x99, 0x9a]
+    );
+    for n in [
+        0.0,
+        -0.0,
+        1.5,
+        65504.0,
+        5.960464477539063e-8,
+        1.0e300,
+        f64::INFINITY,
+    ] {
+        let bytes = to_vec(&n).unwrap();
+        assert_eq!(from_slice::<f64>(&bytes).unwrap().to_bits(), n.to_bits());
+    }
+}
+
+#[test]
+fn integers_coerce_across_widths() {
+    // Written as a u64, read into any integer type it fits
+    let bytes = to_vec(&200u64).unwrap();
+    assert_eq!(from_slice::<u8>(&bytes).unwrap(), 200);
+    assert_eq!(from_slice::<i16>(&bytes).unwrap(), 200);
+    assert!(from_slice::<i8>(&bytes).is_err());
+}
+
+#[derive(Debug, Facet, PartialEq)]
+#[facet(untagged)]
+#[repr(u8)]
+enum Shape {
+    Circle { radius: f64 },
+    Rect { width: u32, height: u32 },
+    Label(String),
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct Drawing {
+    shapes: Vec<Shape>,
+    title: String,
+}
+
+#[test]
+fn untagged_enum_roundtrip() {
+    let value = Drawing {
+        shapes: vec![
+            Shape::Rect {
+                width: 3,
+                height: 4,
+            },
+            Shape::Circle { radius: 1.5 },
+            Shape::Label("origin".to_string()),
+        ],
+        title: "shapes".to_string(),
+    };
+    let bytes = to_vec(&value).unwrap();
+    assert_eq!(from_slice::<Drawing>(&bytes).unwrap(), value);
+}
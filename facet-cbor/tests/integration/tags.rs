@@ -0,0 +1,161 @@
+//! Standard tags: date/times (0 and 1) and bignums (2 and 3).
+
+use facet::Facet;
+use facet_cbor::{from_slice, to_vec};
+
+/// 1363896240, the tag 1 example from RFC 8949 Appendix A.
+const EPOCH: [u8; 6] = [0xc1, 0x1a, 0x51, 0x4b, 0x67, 0xb0];
+
+/// 0("2013-03-21T20:04:00Z")
+fn tagged_datetime() -> Vec<u8> {
+    let mut bytes = vec![0xc0, 0x74];
+    bytes.extend_from_slice(b"2013-03-21T20:04:00Z");
+    bytes
+}
+
+#[test]
+fn chrono_datetimes() {
+    use chrono::{DateTime, NaiveDate, Utc};
+
+    let expected = NaiveDate::from_ymd_opt(2013, 3, 21)
+        .unwrap()
+        .and_hms_opt(20, 4, 0)
+        .unwrap()
+        .and_utc();
+
+    assert_eq!(to_vec(&expected).unwrap(), tagged_datetime());
+    assert_eq!(
+        from_slice::<DateTime<Utc>>(&tagged_datetime()).unwrap(),
+        expected
+    );
+    assert_eq!(from_slice::<DateTime<Utc>>(&EPOCH).unwrap(), expected);
+}
+
+#[test]
+fn jiff_timestamps() {
+    let expected: jiff::Timestamp = "2013-03-21T20:04:00Z".parse().unwrap();
+
+    assert_eq!(to_vec(&expected).unwrap(), tagged_datetime());
+    assert_eq!(
+        from_slice::<jiff::Timestamp>(&tagged_datetime()).unwrap(),
+        expected
+    );
+    assert_eq!(from_slice::<jiff::Timestamp>(&EPOCH).unwrap(), expected);
+
+    // 1(1363896240.5)
+    let fractional = [0xc1, 0xfb, 0x41, 0xd4, 0x52, 0xd9, 0xec, 0x20, 0x00, 0x00];
+    let expected: jiff::Timestamp = "2013-03-21T20:04:00.5Z".parse().unwrap();
+    assert_eq!(
+        from_slice::<jiff::Timestamp>(&fractional).unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn time_offset_datetimes() {
+    use time::OffsetDateTime;
+    use time::macros::datetime;
+
+    let expected = datetime!(2013-03-21 20:04:00 UTC);
+    assert_eq!(
+        from_slice::<OffsetDateTime>(&tagged_datetime()).unwrap(),
+        expected
+    );
+    assert_eq!(from_slice::<OffsetDateTime>(&EPOCH).unwrap(), expected);
+
+    let bytes = to_vec(&expected).unwrap();
+    assert_eq!(bytes[0], 0xc0);
+    assert_eq!(from_slice::<OffsetDateTime>(&bytes).unwrap(), expected);
+}
+
+#[test]
+fn epoch_reads_as_a_number_into_integers() {
+    assert_eq!(from_slice::<i64>(&EPOCH).unwrap(), 1363896240);
+}
+
+#[test]
+fn datetime_fields_roundtrip() {
+    #[derive(Debug, Facet, PartialEq)]
+    struct Event {
+        name: String,
+        at: jiff::Timestamp,
+        seen: Option<jiff::Timestamp>,
+    }
+
+    let value = Event {
+        name: "launch".to_string(),
+        at: "2024-01-02T03:04:05.25Z".parse().unwrap(),
+        seen: None,
+    };
+    let bytes = to_vec(&value).unwrap();
+    assert_eq!(from_slice::<Event>(&bytes).unwrap(), value);
+}
+
+#[test]
+fn bignums_beyond_64_bits() {
+    // 18446744073709551616 = 2(h'010000000000000000')
+    let two_to_64 = [0xc2, 0x49, 0x01, 0, 0, 0, 0, 0, 0, 0, 0];
+    assert_eq!(to_vec(&(1u128 << 64)).unwrap(), two_to_64);
+    assert_eq!(from_slice::<u128>(&two_to_64).unwrap(), 1u128 << 64);
+
+    // -18446744073709551617 = 3(h'010000000000000000')
+    let minus = [0xc3, 0x49, 0x01, 0, 0, 0, 0, 0, 0, 0, 0];
+    assert_eq!(to_vec(&(-(1i128 << 64) - 1)).unwrap(), minus);
+    assert_eq!(from_slice::<i128>(&minus).unwrap(), -(1i128 << 64) - 1);
+
+    for n in [u128::MAX, u64::MAX as u128 + 1, 0, 7] {
+        assert_eq!(from_slice::<u128>(&to_vec(&n).unwrap()).unwrap(), n);
+    }
+    for n in [
+        i128::MIN,
+        i128::MAX,
+        -(1i128 << 64),
+        i64::MIN as i128 - 1,
+        -1,
+    ] {
+        assert_eq!(from_slice::<i128>(&to_vec(&n).unwrap()).unwrap(), n);
+    }
+}
+
+#[test]
+fn wide_integers_in_64_bit_range_stay_plain() {
+    assert_eq!(to_vec(&1000u128).unwrap(), [0x19, 0x03, 0xe8]);
+    // -18446744073709551616 fits a negative integer head
+    assert_eq!(
+        to_vec(&-(1i128 << 64)).unwrap(),
+        [0x3b, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]
+    );
+}
+
+#[test]
+fn small_bignums_read_into_narrow_integers() {
+    // 2(h'0100') is 256
+    assert_eq!(from_slice::<u16>(&[0xc2, 0x42, 0x01, 0x00]).unwrap(), 256);
+    // Leading zero bytes don't count against the 128-bit limit
+    let mut padded = vec![0xc2, 0x51, 0x00];
+    padded.extend_from_slice(&[0xff; 16]);
+    assert_eq!(from_slice::<u128>(&padded).unwrap(), u128::MAX);
+
+    let mut too_big = vec![0xc2, 0x51, 0x01];
+    too_big.extend_from_slice(&[0x00; 16]);
+    assert!(from_slice::<u128>(&too_big).is_err());
+}
+
+#[test]
+fn other_tags_are_read_through() {
+    // 32("http://www.example.com")
+    let mut uri = vec![0xd8, 0x20, 0x76];
+    uri.extend_from_slice(b"http://www.example.com");
+    assert_eq!(
+        from_slice::<String>(&uri).unwrap(),
+        "http://www.example.com"
+    );
+
+    // The self-described CBOR magic number, 55799, in front of a map
+    #[derive(Debug, Facet, PartialEq)]
+    struct Point {
+        x: u8,
+    }
+    let bytes = [0xd9, 0xd9, 0xf7, 0xa1, 0x61, b'x', 0x01];
+    assert_eq!(from_slice::<Point>(&bytes).unwrap(), Point { x: 1 });
+}
@@ -0,0 +1,3 @@
+//! Consolidated integration tests for facet-cbor.
+
+mod integration;
@@ -114,6 +114,33 @@ unsafe extern "C" fn btreemap_get_value_ptr<K: Eq + Ord + 'static, V: 'static>(
     }
 }
 
+unsafe extern "C" fn btreemap_get_value_ptr_mut<K: Eq + Ord + 'static, V: 'static>(
+    ptr: PtrMut,
+    key: PtrConst,
+) -> *mut u8 {
+    unsafe {
+        ptr.as_mut::<BTreeMap<K, V>>()
+            .get_mut(key.get())
+            .map_or(core::ptr::null_mut(), |v| v as *mut V as *mut u8)
+    }
+}
+
+unsafe extern "C" fn btreemap_remove<K: Eq + Ord + 'static, V: 'static>(
+    ptr: PtrMut,
+    key: PtrConst,
+    out: PtrUninit,
+) -> bool {
+    unsafe {
+        match ptr.as_mut::<BTreeMap<K, V>>().remove(key.get::<K>()) {
+            Some(value) => {
+                out.put(value);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
 unsafe extern "C" fn btreemap_iter_init<K: 'static, V: 'static>(ptr: PtrConst) -> PtrMut {
     unsafe {
         let map = ptr.get::<BTreeMap<K, V>>();
@@ -205,6 +232,8 @@ where
                 .len(btreemap_len::<K, V>)
                 .contains_key(btreemap_contains_key::<K, V>)
                 .get_value_ptr(btreemap_get_value_ptr::<K, V>)
+                .get_value_ptr_mut(Some(btreemap_get_value_ptr_mut::<K, V>))
+                .remove(Some(btreemap_remove::<K, V>))
                 .iter_vtable(IterVTable {
                     init_with_value: Some(btreemap_iter_init::<K, V>),
                     next: btreemap_iter_next::<K, V>,
@@ -298,7 +298,7 @@ unsafe impl<'a, T: Facet<'a>> Facet<'a> for Option<T> {
                                     "Some",
                                     &const { [FieldBuilder::new("0", crate::shape_of::<T>, 0).build()] },
                                 )
-                                .discriminant(0)
+                                .discriminant(1)
                                 .build(),
                             ]
                         },
@@ -0,0 +1,143 @@
+#![cfg(feature = "bigdecimal")]
+
+use alloc::string::String;
+use bigdecimal::BigDecimal;
+
+use crate::{
+    Def, Facet, OxPtrConst, OxPtrUninit, ParseError, PtrConst, Shape, ShapeBuilder, TryFromOutcome,
+    Type, UserType, VTableIndirect,
+};
+
+unsafe fn try_from_bigdecimal(
+    target: OxPtrUninit,
+    src_shape: &'static Shape,
+    src: PtrConst,
+) -> TryFromOutcome {
+    unsafe {
+        // Handle &str (Copy type, use get)
+        if src_shape.id == <&str as Facet>::SHAPE.id {
+            let source_str: &str = src.get::<&str>();
+            match source_str.parse::<BigDecimal>() {
+                Ok(val) => {
+                    target.put(val);
+                    TryFromOutcome::Converted
+                }
+                Err(_) => TryFromOutcome::Failed("BigDecimal parsing failed".into()),
+            }
+        }
+        // Handle String (consume via read)
+        else if src_shape.id == <String as Facet>::SHAPE.id {
+            let source_str = src.read::<String>();
+            match source_str.parse::<BigDecimal>() {
+                Ok(val) => {
+                    target.put(val);
+                    TryFromOutcome::Converted
+                }
+                Err(_) => TryFromOutcome::Failed("BigDecimal parsing failed".into()),
+            }
+        }
+        // Note: We intentionally do NOT support f64/f32 conversion because it defeats
+        // the purpose of BigDecimal (avoiding floating-point precision issues).
+        // Formats should pass strings to BigDecimal::parse instead.
+        else {
+            TryFromOutcome::Unsupported
+        }
+    }
+}
+
+unsafe fn parse_bigdecimal(s: &str, target: OxPtrUninit) -> Option<Result<(), ParseError>> {
+    unsafe {
+        let parsed = s
+            .parse::<BigDecimal>()
+            .map_err(|_| ParseError::from_str("BigDecimal parsing failed"));
+        Some(match parsed {
+            Ok(val) => {
+                target.put(val);
+                Ok(())
+            }
+            Err(e) => Err(e),
+        })
+    }
+}
+
+unsafe fn display_bigdecimal(
+    source: OxPtrConst,
+    f: &mut core::fmt::Formatter<'_>,
+) -> Option<core::fmt::Result> {
+    unsafe {
+        let decimal = source.get::<BigDecimal>();
+        Some(write!(f, "{decimal}"))
+    }
+}
+
+unsafe fn partial_eq_bigdecimal(a: OxPtrConst, b: OxPtrConst) -> Option<bool> {
+    unsafe {
+        let a = a.get::<BigDecimal>();
+        let b = b.get::<BigDecimal>();
+        Some(a == b)
+    }
+}
+
+unsafe fn partial_ord_bigdecimal(
+    a: OxPtrConst,
+    b: OxPtrConst,
+) -> Option<Option<core::cmp::Ordering>> {
+    unsafe {
+        let a = a.get::<BigDecimal>();
+        let b = b.get::<BigDecimal>();
+        Some(a.partial_cmp(b))
+    }
+}
+
+unsafe fn ord_bigdecimal(a: OxPtrConst, b: OxPtrConst) -> Option<core::cmp::Ordering> {
+    unsafe {
+        let a = a.get::<BigDecimal>();
+        let b = b.get::<BigDecimal>();
+        Some(a.cmp(b))
+    }
+}
+
+unsafe fn hash_bigdecimal(value: OxPtrConst, state: &mut crate::HashProxy<'_>) -> Option<()> {
+    unsafe {
+        use core::hash::Hash;
+        let decimal = value.get::<BigDecimal>();
+        decimal.hash(state);
+        Some(())
+    }
+}
+
+unsafe fn debug_bigdecimal(
+    source: OxPtrConst,
+    f: &mut core::fmt::Formatter<'_>,
+) -> Option<core::fmt::Result> {
+    unsafe {
+        let decimal = source.get::<BigDecimal>();
+        Some(write!(f, "{decimal:?}"))
+    }
+}
+
+const BIGDECIMAL_VTABLE: VTableIndirect = VTableIndirect {
+    display: Some(display_bigdecimal),
+    debug: Some(debug_bigdecimal),
+    try_from: Some(try_from_bigdecimal),
+    parse: Some(parse_bigdecimal),
+    partial_eq: Some(partial_eq_bigdecimal),
+    partial_cmp: Some(partial_ord_bigdecimal),
+    cmp: Some(ord_bigdecimal),
+    hash: Some(hash_bigdecimal),
+    ..VTableIndirect::EMPTY
+};
+
+unsafe impl Facet<'_> for BigDecimal {
+    const SHAPE: &'static Shape = &const {
+        ShapeBuilder::for_sized::<BigDecimal>("BigDecimal")
+            .module_path("bigdecimal")
+            .ty(Type::User(UserType::Opaque))
+            .def(Def::Scalar)
+            .vtable_indirect(&BIGDECIMAL_VTABLE)
+            .eq()
+            .send()
+            .sync()
+            .build()
+    };
+}
@@ -17,7 +17,7 @@ unsafe fn display_datetime_utc(
     unsafe {
         let dt = source.get::<DateTime<Utc>>();
         use chrono::SecondsFormat;
-        let s = dt.to_rfc3339_opts(SecondsFormat::Secs, true);
+        let s = dt.to_rfc3339_opts(SecondsFormat::AutoSi, true);
         Some(write!(f, "{s}"))
     }
 }
@@ -97,7 +97,7 @@ unsafe fn display_datetime_fixed_offset(
         Some(write!(
             f,
             "{}",
-            dt.to_rfc3339_opts(SecondsFormat::Secs, true)
+            dt.to_rfc3339_opts(SecondsFormat::AutoSi, true)
         ))
     }
 }
@@ -179,7 +179,7 @@ unsafe fn display_datetime_local(
         Some(write!(
             f,
             "{}",
-            dt.to_rfc3339_opts(SecondsFormat::Secs, true)
+            dt.to_rfc3339_opts(SecondsFormat::AutoSi, true)
         ))
     }
 }
@@ -255,7 +255,7 @@ unsafe fn display_naive_datetime(
 ) -> Option<core::fmt::Result> {
     unsafe {
         let dt = source.get::<NaiveDateTime>();
-        let formatted = dt.format("%Y-%m-%dT%H:%M:%S").to_string();
+        let formatted = dt.format("%Y-%m-%dT%H:%M:%S%.f").to_string();
         Some(write!(f, "{formatted}"))
     }
 }
@@ -270,6 +270,8 @@ unsafe fn try_from_naive_datetime(
             let source_str = src.read::<String>();
             match NaiveDateTime::parse_from_str(&source_str, "%Y-%m-%dT%H:%M:%S")
                 .or_else(|_| NaiveDateTime::parse_from_str(&source_str, "%Y-%m-%d %H:%M:%S"))
+                .or_else(|_| NaiveDateTime::parse_from_str(&source_str, "%Y-%m-%dT%H:%M:%S%.f"))
+                .or_else(|_| NaiveDateTime::parse_from_str(&source_str, "%Y-%m-%d %H:%M:%S%.f"))
             {
                 Ok(val) => {
                     target.put(val);
@@ -287,6 +289,8 @@ unsafe fn parse_naive_datetime(s: &str, target: OxPtrUninit) -> Option<Result<()
     unsafe {
         let parsed = NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S")
             .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S"))
+            .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f"))
+            .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f"))
             .map_err(|_| ParseError::from_str("could not parse date"));
         Some(match parsed {
             Ok(val) => {
@@ -408,7 +412,7 @@ unsafe fn display_naive_time(
 ) -> Option<core::fmt::Result> {
     unsafe {
         let dt = source.get::<NaiveTime>();
-        let formatted = dt.format("%H:%M:%S").to_string();
+        let formatted = dt.format("%H:%M:%S%.f").to_string();
         Some(write!(f, "{formatted}"))
     }
 }
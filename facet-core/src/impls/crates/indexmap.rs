@@ -120,6 +120,36 @@ unsafe extern "C" fn indexmap_get_value_ptr<K: Eq + core::hash::Hash, V, S: Buil
     }
 }
 
+unsafe extern "C" fn indexmap_get_value_ptr_mut<K: Eq + core::hash::Hash, V, S: BuildHasher>(
+    ptr: PtrMut,
+    key: PtrConst,
+) -> *mut u8 {
+    unsafe {
+        ptr.as_mut::<IndexMap<K, V, S>>()
+            .get_mut(key.get::<K>())
+            .map_or(core::ptr::null_mut(), |v| {
+                NonNull::from(v).as_ptr() as *mut u8
+            })
+    }
+}
+
+/// Removes with `shift_remove` so the remaining entries keep their insertion order.
+unsafe extern "C" fn indexmap_remove<K: Eq + core::hash::Hash, V, S: BuildHasher>(
+    ptr: PtrMut,
+    key: PtrConst,
+    out: PtrUninit,
+) -> bool {
+    unsafe {
+        match ptr.as_mut::<IndexMap<K, V, S>>().shift_remove(key.get::<K>()) {
+            Some(value) => {
+                out.put(value);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
 unsafe extern "C" fn indexmap_iter_init<K, V, S>(ptr: PtrConst) -> PtrMut {
     unsafe {
         let map = ptr.get::<IndexMap<K, V, S>>();
@@ -294,6 +324,8 @@ where
                 .len(indexmap_len::<K, V, S>)
                 .contains_key(indexmap_contains_key::<K, V, S>)
                 .get_value_ptr(indexmap_get_value_ptr::<K, V, S>)
+                .get_value_ptr_mut(Some(indexmap_get_value_ptr_mut::<K, V, S>))
+                .remove(Some(indexmap_remove::<K, V, S>))
                 .iter_vtable(IterVTable {
                     init_with_value: Some(indexmap_iter_init::<K, V, S>),
                     next: indexmap_iter_next::<K, V>,
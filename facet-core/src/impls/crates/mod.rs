@@ -1,3 +1,4 @@
+mod bigdecimal;
 mod bstr;
 mod bytes;
 mod bytestring;
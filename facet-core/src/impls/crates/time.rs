@@ -1,7 +1,9 @@
 #![cfg(feature = "time")]
 
 use alloc::string::String;
-use time::{OffsetDateTime, UtcDateTime};
+use time::format_description::BorrowedFormatItem;
+use time::macros::format_description;
+use time::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcDateTime};
 
 use crate::{
     Def, Facet, OxPtrConst, OxPtrUninit, ParseError, PtrConst, Shape, ShapeBuilder, TryFromOutcome,
@@ -167,6 +169,263 @@ unsafe impl Facet<'_> for OffsetDateTime {
     };
 }
 
+// Date, Time and PrimitiveDateTime implementations
+//
+// These are the local date, local time and local date-time of RFC 3339 (and
+// TOML): no offset, `T` between date and time, and fractional seconds only
+// when there are any.
+
+const DATE_FORMAT: &[BorrowedFormatItem<'_>] = format_description!("[year]-[month]-[day]");
+const TIME_FORMAT: &[BorrowedFormatItem<'_>] = format_description!("[hour]:[minute]:[second]");
+const TIME_FRACTION_FORMAT: &[BorrowedFormatItem<'_>] =
+    format_description!("[hour]:[minute]:[second].[subsecond]");
+const TIME_PARSE_FORMAT: &[BorrowedFormatItem<'_>] =
+    format_description!("[hour]:[minute]:[second][optional [.[subsecond]]]");
+
+fn time_format(time: Time) -> &'static [BorrowedFormatItem<'static>] {
+    if time.nanosecond() == 0 {
+        TIME_FORMAT
+    } else {
+        TIME_FRACTION_FORMAT
+    }
+}
+
+fn parse_primitive(s: &str) -> Result<PrimitiveDateTime, time::error::Parse> {
+    let (date, time) = s.split_at(s.find(['T', 't', ' ']).unwrap_or(s.len()));
+    let date = Date::parse(date, DATE_FORMAT)?;
+    let time = Time::parse(time.get(1..).unwrap_or_default(), TIME_PARSE_FORMAT)?;
+    Ok(PrimitiveDateTime::new(date, time))
+}
+
+unsafe fn date_display(
+    source: OxPtrConst,
+    f: &mut core::fmt::Formatter<'_>,
+) -> Option<core::fmt::Result> {
+    unsafe {
+        let date = source.get::<Date>();
+        Some(match date.format(DATE_FORMAT) {
+            Ok(s) => write!(f, "{s}"),
+            Err(_) => write!(f, "<invalid Date>"),
+        })
+    }
+}
+
+unsafe fn date_try_from(
+    target: OxPtrUninit,
+    src_shape: &'static Shape,
+    src: PtrConst,
+) -> TryFromOutcome {
+    unsafe {
+        if src_shape.id == <String as Facet>::SHAPE.id {
+            let source_str = src.read::<String>();
+            match Date::parse(&source_str, DATE_FORMAT) {
+                Ok(val) => {
+                    target.put(val);
+                    TryFromOutcome::Converted
+                }
+                Err(_) => TryFromOutcome::Failed("could not parse date".into()),
+            }
+        } else {
+            TryFromOutcome::Unsupported
+        }
+    }
+}
+
+unsafe fn date_parse(s: &str, target: OxPtrUninit) -> Option<Result<(), ParseError>> {
+    unsafe {
+        let parsed =
+            Date::parse(s, DATE_FORMAT).map_err(|_| ParseError::from_str("could not parse date"));
+        Some(match parsed {
+            Ok(val) => {
+                target.put(val);
+                Ok(())
+            }
+            Err(e) => Err(e),
+        })
+    }
+}
+
+unsafe fn date_partial_eq(a: OxPtrConst, b: OxPtrConst) -> Option<bool> {
+    unsafe {
+        let a = a.get::<Date>();
+        let b = b.get::<Date>();
+        Some(a == b)
+    }
+}
+
+const DATE_VTABLE: VTableIndirect = VTableIndirect {
+    display: Some(date_display),
+    try_from: Some(date_try_from),
+    parse: Some(date_parse),
+    partial_eq: Some(date_partial_eq),
+    ..VTableIndirect::EMPTY
+};
+
+unsafe impl Facet<'_> for Date {
+    const SHAPE: &'static Shape = &const {
+        ShapeBuilder::for_sized::<Date>("Date")
+            .module_path("time")
+            .ty(Type::User(UserType::Opaque))
+            .def(Def::Scalar)
+            .vtable_indirect(&DATE_VTABLE)
+            .build()
+    };
+}
+
+unsafe fn time_display(
+    source: OxPtrConst,
+    f: &mut core::fmt::Formatter<'_>,
+) -> Option<core::fmt::Result> {
+    unsafe {
+        let time = *source.get::<Time>();
+        Some(match time.format(time_format(time)) {
+            Ok(s) => write!(f, "{s}"),
+            Err(_) => write!(f, "<invalid Time>"),
+        })
+    }
+}
+
+unsafe fn time_try_from(
+    target: OxPtrUninit,
+    src_shape: &'static Shape,
+    src: PtrConst,
+) -> TryFromOutcome {
+    unsafe {
+        if src_shape.id == <String as Facet>::SHAPE.id {
+            let source_str = src.read::<String>();
+            match Time::parse(&source_str, TIME_PARSE_FORMAT) {
+                Ok(val) => {
+                    target.put(val);
+                    TryFromOutcome::Converted
+                }
+                Err(_) => TryFromOutcome::Failed("could not parse time".into()),
+            }
+        } else {
+            TryFromOutcome::Unsupported
+        }
+    }
+}
+
+unsafe fn time_parse(s: &str, target: OxPtrUninit) -> Option<Result<(), ParseError>> {
+    unsafe {
+        let parsed = Time::parse(s, TIME_PARSE_FORMAT)
+            .map_err(|_| ParseError::from_str("could not parse time"));
+        Some(match parsed {
+            Ok(val) => {
+                target.put(val);
+                Ok(())
+            }
+            Err(e) => Err(e),
+        })
+    }
+}
+
+unsafe fn time_partial_eq(a: OxPtrConst, b: OxPtrConst) -> Option<bool> {
+    unsafe {
+        let a = a.get::<Time>();
+        let b = b.get::<Time>();
+        Some(a == b)
+    }
+}
+
+const TIME_VTABLE: VTableIndirect = VTableIndirect {
+    display: Some(time_display),
+    try_from: Some(time_try_from),
+    parse: Some(time_parse),
+    partial_eq: Some(time_partial_eq),
+    ..VTableIndirect::EMPTY
+};
+
+unsafe impl Facet<'_> for Time {
+    const SHAPE: &'static Shape = &const {
+        ShapeBuilder::for_sized::<Time>("Time")
+            .module_path("time")
+            .ty(Type::User(UserType::Opaque))
+            .def(Def::Scalar)
+            .vtable_indirect(&TIME_VTABLE)
+            .build()
+    };
+}
+
+unsafe fn primitive_display(
+    source: OxPtrConst,
+    f: &mut core::fmt::Formatter<'_>,
+) -> Option<core::fmt::Result> {
+    unsafe {
+        let pdt = *source.get::<PrimitiveDateTime>();
+        Some(
+            match (
+                pdt.date().format(DATE_FORMAT),
+                pdt.time().format(time_format(pdt.time())),
+            ) {
+                (Ok(date), Ok(time)) => write!(f, "{date}T{time}"),
+                _ => write!(f, "<invalid PrimitiveDateTime>"),
+            },
+        )
+    }
+}
+
+unsafe fn primitive_try_from(
+    target: OxPtrUninit,
+    src_shape: &'static Shape,
+    src: PtrConst,
+) -> TryFromOutcome {
+    unsafe {
+        if src_shape.id == <String as Facet>::SHAPE.id {
+            let source_str = src.read::<String>();
+            match parse_primitive(&source_str) {
+                Ok(val) => {
+                    target.put(val);
+                    TryFromOutcome::Converted
+                }
+                Err(_) => TryFromOutcome::Failed("could not parse date".into()),
+            }
+        } else {
+            TryFromOutcome::Unsupported
+        }
+    }
+}
+
+unsafe fn primitive_parse(s: &str, target: OxPtrUninit) -> Option<Result<(), ParseError>> {
+    unsafe {
+        let parsed = parse_primitive(s).map_err(|_| ParseError::from_str("could not parse date"));
+        Some(match parsed {
+            Ok(val) => {
+                target.put(val);
+                Ok(())
+            }
+            Err(e) => Err(e),
+        })
+    }
+}
+
+unsafe fn primitive_partial_eq(a: OxPtrConst, b: OxPtrConst) -> Option<bool> {
+    unsafe {
+        let a = a.get::<PrimitiveDateTime>();
+        let b = b.get::<PrimitiveDateTime>();
+        Some(a == b)
+    }
+}
+
+const PRIMITIVE_VTABLE: VTableIndirect = VTableIndirect {
+    display: Some(primitive_display),
+    try_from: Some(primitive_try_from),
+    parse: Some(primitive_parse),
+    partial_eq: Some(primitive_partial_eq),
+    ..VTableIndirect::EMPTY
+};
+
+unsafe impl Facet<'_> for PrimitiveDateTime {
+    const SHAPE: &'static Shape = &const {
+        ShapeBuilder::for_sized::<PrimitiveDateTime>("PrimitiveDateTime")
+            .module_path("time")
+            .ty(Type::User(UserType::Opaque))
+            .def(Def::Scalar)
+            .vtable_indirect(&PRIMITIVE_VTABLE)
+            .build()
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use core::fmt;
@@ -217,4 +476,39 @@ mod tests {
             OffsetDateTime::SHAPE.deallocate_uninit(target).unwrap();
         }
     }
+
+    #[test]
+    fn parse_and_display_local_types() {
+        facet_testhelpers::setup();
+
+        fn round_trip<T: Facet<'static>>(input: &str, expected: &str) {
+            struct DisplayWrapper(&'static crate::Shape, PtrConst);
+
+            impl fmt::Display for DisplayWrapper {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    unsafe { self.0.call_display(self.1, f).unwrap() }
+                }
+            }
+
+            let target = T::SHAPE.allocate().unwrap();
+            unsafe {
+                T::SHAPE.call_parse(input, target).unwrap().unwrap();
+            }
+            let s = format!(
+                "{}",
+                DisplayWrapper(T::SHAPE, unsafe { target.assume_init().as_const() })
+            );
+            assert_eq!(s, expected);
+            unsafe {
+                T::SHAPE.call_drop_in_place(target.assume_init());
+                T::SHAPE.deallocate_uninit(target).unwrap();
+            }
+        }
+
+        round_trip::<time::Date>("1979-05-27", "1979-05-27");
+        round_trip::<time::Time>("07:32:00", "07:32:00");
+        round_trip::<time::Time>("00:32:00.999999", "00:32:00.999999");
+        round_trip::<time::PrimitiveDateTime>("1979-05-27 07:32:00", "1979-05-27T07:32:00");
+        round_trip::<time::PrimitiveDateTime>("1979-05-27T00:32:00.5", "1979-05-27T00:32:00.5");
+    }
 }
@@ -118,6 +118,35 @@ unsafe extern "C" fn hashmap_get_value_ptr<K: Eq + core::hash::Hash, V, S: Build
     }
 }
 
+unsafe extern "C" fn hashmap_get_value_ptr_mut<K: Eq + core::hash::Hash, V, S: BuildHasher>(
+    ptr: PtrMut,
+    key: PtrConst,
+) -> *mut u8 {
+    unsafe {
+        ptr.as_mut::<HashMap<K, V, S>>()
+            .get_mut(key.get::<K>())
+            .map_or(core::ptr::null_mut(), |v| {
+                NonNull::from(v).as_ptr() as *mut u8
+            })
+    }
+}
+
+unsafe extern "C" fn hashmap_remove<K: Eq + core::hash::Hash, V, S: BuildHasher>(
+    ptr: PtrMut,
+    key: PtrConst,
+    out: PtrUninit,
+) -> bool {
+    unsafe {
+        match ptr.as_mut::<HashMap<K, V, S>>().remove(key.get::<K>()) {
+            Some(value) => {
+                out.put(value);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
 /// Build a HashMap from a contiguous slice of (K, V) pairs.
 unsafe extern "C" fn hashmap_from_pair_slice<
     K: Eq + core::hash::Hash,
@@ -210,6 +239,8 @@ where
                 .len(hashmap_len::<K, V, S>)
                 .contains_key(hashmap_contains_key::<K, V, S>)
                 .get_value_ptr(hashmap_get_value_ptr::<K, V, S>)
+                .get_value_ptr_mut(Some(hashmap_get_value_ptr_mut::<K, V, S>))
+                .remove(Some(hashmap_remove::<K, V, S>))
                 .iter_vtable(IterVTable {
                     init_with_value: Some(hashmap_iter_init::<K, V, S>),
                     next: hashmap_iter_next::<K, V>,
@@ -111,6 +111,27 @@ pub type MapContainsKeyFn = unsafe extern "C" fn(map: PtrConst, key: PtrConst) -
 /// The `map` parameter must point to aligned, initialized memory of the correct type.
 pub type MapGetValuePtrFn = unsafe extern "C" fn(map: PtrConst, key: PtrConst) -> *const u8;
 
+/// Get a mutable pointer to the value for a given key, returns null if not found
+///
+/// # Safety
+///
+/// The `map` parameter must point to aligned, initialized memory of the correct type.
+/// The returned pointer is only valid until the map is next mutated.
+pub type MapGetValuePtrMutFn = unsafe extern "C" fn(map: PtrMut, key: PtrConst) -> *mut u8;
+
+/// Remove the entry for a given key, moving its value into `out`.
+///
+/// Returns `true` if an entry was removed (and `out` was written to), `false` if
+/// the key was not present (in which case `out` is left uninitialized). The removed
+/// key is dropped.
+///
+/// # Safety
+///
+/// - `map` must point to aligned, initialized memory of the correct type.
+/// - `out` must point to uninitialized memory large enough for one value of
+///   the map's value type and with the value's alignment.
+pub type MapRemoveFn = unsafe extern "C" fn(map: PtrMut, key: PtrConst, out: PtrUninit) -> bool;
+
 /// Build a map from a contiguous slice of (K, V) pairs.
 ///
 /// This is an optimization for bulk deserialization that avoids per-entry vtable
@@ -154,6 +175,12 @@ vtable_def! {
         /// cf. [`MapGetValuePtrFn`]
         pub get_value_ptr: MapGetValuePtrFn,
 
+        /// cf. [`MapGetValuePtrMutFn`] - optional, enables in-place value editing
+        pub get_value_ptr_mut: Option<MapGetValuePtrMutFn>,
+
+        /// cf. [`MapRemoveFn`] - optional, enables entry removal
+        pub remove: Option<MapRemoveFn>,
+
         /// Virtual table for map iterator operations
         pub iter_vtable: IterVTable<(PtrConst, PtrConst)>,
 
@@ -2,7 +2,7 @@
 
 use core::any::TypeId;
 
-use crate::{ConstTypeId, Shape};
+use crate::{ConstTypeId, Def, Shape};
 
 /// All scalar types supported out of the box by facet.
 ///
@@ -183,3 +183,155 @@ impl Shape {
         None
     }
 }
+
+/// How a scalar is represented, as code generators and solvers see it.
+///
+/// Where [`ScalarType`] names the Rust type (`String`, `Cow<str>` and `&str`
+/// are three different types), `ScalarKind` names the value: all three are
+/// [`ScalarKind::Str`]. Prefer it over matching on [`Shape::type_identifier`],
+/// which only works for the exact spelling of a type's name and can't tell a
+/// user type called `u8` from the primitive.
+///
+/// # Example
+///
+/// ```
+/// use facet_core::{Facet, ScalarKind};
+///
+/// assert_eq!(String::SHAPE.scalar_kind(), Some(ScalarKind::Str));
+/// assert_eq!(<&str>::SHAPE.scalar_kind(), Some(ScalarKind::Str));
+/// assert_eq!(<Vec<u8>>::SHAPE.scalar_kind(), Some(ScalarKind::Bytes));
+/// assert_eq!(<Vec<u16>>::SHAPE.scalar_kind(), None);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[non_exhaustive]
+pub enum ScalarKind {
+    /// `bool`.
+    Bool,
+    /// `char`.
+    Char,
+    /// Text: `str`, `&str`, `String`, `Cow<str>`.
+    Str,
+    /// A byte buffer: `[u8]`, `Vec<u8>`, and other lists of `u8`.
+    Bytes,
+    /// `u8`.
+    U8,
+    /// `u16`.
+    U16,
+    /// `u32`.
+    U32,
+    /// `u64`.
+    U64,
+    /// `u128`.
+    U128,
+    /// `usize`.
+    USize,
+    /// `i8`.
+    I8,
+    /// `i16`.
+    I16,
+    /// `i32`.
+    I32,
+    /// `i64`.
+    I64,
+    /// `i128`.
+    I128,
+    /// `isize`.
+    ISize,
+    /// `f32`.
+    F32,
+    /// `f64`.
+    F64,
+}
+
+impl ScalarKind {
+    /// Whether this is one of the integer kinds.
+    pub const fn is_integer(self) -> bool {
+        matches!(
+            self,
+            Self::U8
+                | Self::U16
+                | Self::U32
+                | Self::U64
+                | Self::U128
+                | Self::USize
+                | Self::I8
+                | Self::I16
+                | Self::I32
+                | Self::I64
+                | Self::I128
+                | Self::ISize
+        )
+    }
+
+    /// Whether this is `F32` or `F64`.
+    pub const fn is_float(self) -> bool {
+        matches!(self, Self::F32 | Self::F64)
+    }
+}
+
+impl Shape {
+    /// Get the scalar kind if this shape is a primitive, a string type, or a
+    /// byte buffer.
+    ///
+    /// Other scalars (network addresses, dates, UUIDs, ...) return `None`;
+    /// code that handles those still has to look at the type itself.
+    pub fn scalar_kind(&self) -> Option<ScalarKind> {
+        match self.def {
+            Def::List(list) if list.t().is_type::<u8>() => return Some(ScalarKind::Bytes),
+            Def::Slice(slice) if slice.t().is_type::<u8>() => return Some(ScalarKind::Bytes),
+            _ => {}
+        }
+
+        Some(match self.scalar_type()? {
+            ScalarType::Bool => ScalarKind::Bool,
+            ScalarType::Char => ScalarKind::Char,
+            ScalarType::Str => ScalarKind::Str,
+            #[cfg(feature = "alloc")]
+            ScalarType::String | ScalarType::CowStr => ScalarKind::Str,
+            ScalarType::U8 => ScalarKind::U8,
+            ScalarType::U16 => ScalarKind::U16,
+            ScalarType::U32 => ScalarKind::U32,
+            ScalarType::U64 => ScalarKind::U64,
+            ScalarType::U128 => ScalarKind::U128,
+            ScalarType::USize => ScalarKind::USize,
+            ScalarType::I8 => ScalarKind::I8,
+            ScalarType::I16 => ScalarKind::I16,
+            ScalarType::I32 => ScalarKind::I32,
+            ScalarType::I64 => ScalarKind::I64,
+            ScalarType::I128 => ScalarKind::I128,
+            ScalarType::ISize => ScalarKind::ISize,
+            ScalarType::F32 => ScalarKind::F32,
+            ScalarType::F64 => ScalarKind::F64,
+            _ => return None,
+        })
+    }
+}
+
+/// Parse a boolean written the ways config files and environment variables
+/// tend to: `true`/`false`, `yes`/`no`, `on`/`off` or `1`/`0`, ignoring ASCII
+/// case and surrounding whitespace.
+///
+/// This is the coercion behind `#[facet(lenient_bool)]`; format crates that
+/// coerce booleans on their own should use it too, so every format agrees on
+/// what counts as a boolean.
+///
+/// # Example
+///
+/// ```
+/// use facet_core::parse_lenient_bool;
+///
+/// assert_eq!(parse_lenient_bool("Yes"), Some(true));
+/// assert_eq!(parse_lenient_bool(" off "), Some(false));
+/// assert_eq!(parse_lenient_bool("maybe"), None);
+/// ```
+pub fn parse_lenient_bool(value: &str) -> Option<bool> {
+    let value = value.trim();
+    let is = |word: &str| value.eq_ignore_ascii_case(word);
+    if is("true") || is("yes") || is("on") || is("1") {
+        Some(true)
+    } else if is("false") || is("no") || is("off") || is("0") {
+        Some(false)
+    } else {
+        None
+    }
+}
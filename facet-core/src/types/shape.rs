@@ -9,8 +9,8 @@ pub use shape_builder::*;
 use core::alloc::Layout;
 
 use crate::{
-    Attr, ConstTypeId, DeclId, Def, Facet, MAX_VARIANCE_DEPTH, MarkerTraits, TruthyFn, Type,
-    TypeOps, UserType, VTableErased, Variance, VarianceDesc, VariancePosition,
+    Attr, ChildDefaults, ConstTypeId, DeclId, Def, Facet, MAX_VARIANCE_DEPTH, MarkerTraits,
+    TruthyFn, Type, TypeOps, UserType, VTableErased, Variance, VarianceDesc, VariancePosition,
 };
 
 /// Stack-based visited set for variance computation.
@@ -94,6 +94,10 @@ crate::bitflags! {
         ///
         /// Set by `#[facet(metadata_container)]`.
         const METADATA_CONTAINER = 1 << 3;
+
+        /// Some field (of the struct, or of one of the enum's variants) has
+        /// `#[facet(order = N)]`, so serialization order must be computed.
+        const ORDERED_FIELDS = 1 << 4;
     }
 }
 
@@ -155,6 +159,9 @@ pub struct Shape {
 
     /// Type name without generic parameters (e.g. `Vec`, not `Vec<String>`).
     /// For the full name with generics, use `vtable.type_name`.
+    ///
+    /// This is meant for display. To tell which primitive or string type a
+    /// shape is, use [`Shape::scalar_kind`] rather than comparing names.
     pub type_identifier: &'static str,
 
     /// Module path where this type is defined (e.g. `"std::collections"`).
@@ -230,6 +237,16 @@ pub struct Shape {
     /// These take precedence over the format-agnostic `proxy` field when the format matches.
     pub format_proxies: &'static [crate::FormatProxy],
 
+    /// Container-level conversion used when deserializing.
+    /// Set by `#[facet(from = OtherType)]`: the value is deserialized as
+    /// `OtherType` and converted with `From`. Only `convert_in` is used.
+    pub from_conversion: Option<&'static crate::ProxyDef>,
+
+    /// Container-level conversion used when serializing.
+    /// Set by `#[facet(into = OtherType)]`: the value is cloned, converted to
+    /// `OtherType` with `From` and serialized as that. Only `convert_out` is used.
+    pub into_conversion: Option<&'static crate::ProxyDef>,
+
     /// Container-level opaque adapter for custom opaque serialization/deserialization.
     /// Set by `#[facet(opaque = AdapterType)]` on the container.
     pub opaque_adapter: Option<&'static crate::OpaqueAdapterDef>,
@@ -263,6 +280,10 @@ pub struct Shape {
     /// Set by `#[facet(rename = "name")]`. When present, serializers/deserializers
     /// should use this name instead of the type's actual name.
     pub rename: Option<&'static str>,
+
+    /// Conventions for the types this one references.
+    /// Set by `#[facet(defaults_for_children(...))]`.
+    pub child_defaults: Option<ChildDefaults>,
 }
 
 impl PartialOrd for Shape {
@@ -306,6 +327,19 @@ impl Shape {
         }
     }
 
+    /// The [`ChildDefaults`] in force for the types this shape references,
+    /// given those in force for this shape: its own, if it declares any.
+    #[inline]
+    pub const fn defaults_for_children(
+        &self,
+        inherited: Option<ChildDefaults>,
+    ) -> Option<ChildDefaults> {
+        match self.child_defaults {
+            Some(defaults) => Some(defaults),
+            None => inherited,
+        }
+    }
+
     /// Check if this shape is of the given type
     #[inline]
     pub fn is_shape(&self, other: &Shape) -> bool {
@@ -516,6 +550,16 @@ impl Shape {
         self.flags.contains(ShapeFlags::METADATA_CONTAINER)
     }
 
+    /// Returns true if some field of this struct, or of one of this enum's
+    /// variants, has `#[facet(order = N)]`.
+    ///
+    /// This checks the `ORDERED_FIELDS` flag (O(1)); when it's false, fields
+    /// serialize in declaration order.
+    #[inline]
+    pub const fn has_ordered_fields(&self) -> bool {
+        self.flags.contains(ShapeFlags::ORDERED_FIELDS)
+    }
+
     /// Returns true if this enum has cow-like semantics.
     ///
     /// Cow-like enums have `Borrowed` and `Owned` variants that are semantically
@@ -529,6 +573,51 @@ impl Shape {
         }
     }
 
+    /// Returns the shape whose fields (or variants) a `#[facet(flatten)]` field
+    /// of this shape contributes to its parent, and whether an `Option` was
+    /// unwrapped on the way.
+    ///
+    /// `Option<T>` and smart pointers (`Box<T>`, `Arc<T>`, `Rc<T>`, ...) are
+    /// unwrapped in any combination, so `Option<Arc<T>>` flattens like `T`,
+    /// with every flattened field optional.
+    pub const fn flatten_target(&'static self) -> (&'static Shape, bool) {
+        let mut shape = self;
+        let mut optional = false;
+        loop {
+            match shape.def {
+                Def::Option(option_def) => {
+                    shape = option_def.t;
+                    optional = true;
+                }
+                Def::Pointer(pointer_def) => match pointer_def.pointee() {
+                    Some(pointee) => shape = pointee,
+                    None => return (shape, optional),
+                },
+                _ => return (shape, optional),
+            }
+        }
+    }
+
+    /// Returns true if this shape, or the value it wraps, implements `Display`.
+    ///
+    /// Smart pointers and transparent wrappers are unwrapped in any
+    /// combination before looking at the vtable, the way `Peek::innermost_peek`
+    /// does.
+    pub const fn innermost_has_display(&'static self) -> bool {
+        let mut shape = self;
+        loop {
+            if let Def::Pointer(pointer_def) = shape.def
+                && let Some(pointee) = pointer_def.pointee()
+            {
+                shape = pointee;
+            } else if let Some(inner) = shape.inner {
+                shape = inner;
+            } else {
+                return shape.vtable.has_display();
+            }
+        }
+    }
+
     /// Returns the tag field name for internally/adjacently tagged enums.
     ///
     /// This is the direct field access (O(1)), not an attribute lookup.
@@ -564,6 +653,21 @@ impl Shape {
         })
     }
 
+    /// Returns the sample values given with `#[facet(example = "...")]`, in
+    /// declaration order.
+    ///
+    /// Each example is the JSON text of a valid value of this type.
+    #[inline]
+    pub fn examples(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.attributes.iter().filter_map(|attr| {
+            if attr.ns.is_none() && attr.key == "example" {
+                attr.get_as::<&'static str>().copied()
+            } else {
+                None
+            }
+        })
+    }
+
     /// Gets the format-specific proxy definition for the given format, if present.
     ///
     /// # Arguments
@@ -602,6 +706,24 @@ impl Shape {
         self.proxy
     }
 
+    /// Gets the proxy to deserialize through for the given format.
+    ///
+    /// This is [`Self::effective_proxy`], falling back to the `#[facet(from = ...)]`
+    /// conversion.
+    #[inline]
+    pub fn deserialize_proxy(&self, format: Option<&str>) -> Option<&'static crate::ProxyDef> {
+        self.effective_proxy(format).or(self.from_conversion)
+    }
+
+    /// Gets the proxy to serialize through for the given format.
+    ///
+    /// This is [`Self::effective_proxy`], falling back to the `#[facet(into = ...)]`
+    /// conversion.
+    #[inline]
+    pub fn serialize_proxy(&self, format: Option<&str>) -> Option<&'static crate::ProxyDef> {
+        self.effective_proxy(format).or(self.into_conversion)
+    }
+
     /// Returns true if this shape has any proxy (format-specific or format-agnostic).
     #[inline]
     pub fn has_any_proxy(&self) -> bool {
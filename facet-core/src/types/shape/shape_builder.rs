@@ -1,9 +1,10 @@
 use alloc::alloc::Layout;
 
 use crate::{
-    Attr, ConstParam, ConstTypeId, DeclId, Def, FormatProxy, MarkerTraits, OpaqueAdapterDef,
-    ProxyDef, Shape, ShapeFlags, ShapeLayout, Type, TypeNameFn, TypeOps, TypeOpsDirect,
-    TypeOpsIndirect, TypeParam, VTableDirect, VTableErased, VTableIndirect, VarianceDesc,
+    Attr, ChildDefaults, ConstParam, ConstTypeId, DeclId, Def, FormatProxy, MarkerTraits,
+    OpaqueAdapterDef, ProxyDef, Shape, ShapeFlags, ShapeLayout, Type, TypeNameFn, TypeOps,
+    TypeOpsDirect, TypeOpsIndirect, TypeParam, VTableDirect, VTableErased, VTableIndirect,
+    VarianceDesc,
 };
 
 /// Builder for creating [`Shape`] instances.
@@ -45,6 +46,8 @@ const EMPTY_VESSEL: Shape = Shape {
     type_name: None,
     proxy: None,
     format_proxies: &[],
+    from_conversion: None,
+    into_conversion: None,
     opaque_adapter: None,
     // Default to bivariant - types with no lifetime parameters impose no
     // constraints on lifetimes. Types that need specific variance must set it explicitly.
@@ -53,6 +56,7 @@ const EMPTY_VESSEL: Shape = Shape {
     tag: None,
     content: None,
     rename: None,
+    child_defaults: None,
 };
 
 impl Shape {
@@ -362,6 +366,20 @@ impl ShapeBuilder {
         self
     }
 
+    /// Set the conversion to deserialize through (`#[facet(from = OtherType)]`).
+    #[inline]
+    pub const fn from_conversion(mut self, conversion: &'static ProxyDef) -> Self {
+        self.shape.from_conversion = Some(conversion);
+        self
+    }
+
+    /// Set the conversion to serialize through (`#[facet(into = OtherType)]`).
+    #[inline]
+    pub const fn into_conversion(mut self, conversion: &'static ProxyDef) -> Self {
+        self.shape.into_conversion = Some(conversion);
+        self
+    }
+
     /// Set the container-level opaque adapter for custom opaque serde.
     #[inline]
     pub const fn opaque_adapter(mut self, adapter: &'static OpaqueAdapterDef) -> Self {
@@ -417,6 +435,13 @@ impl ShapeBuilder {
         self
     }
 
+    /// Set the conventions the types this one references inherit.
+    #[inline]
+    pub const fn child_defaults(mut self, defaults: ChildDefaults) -> Self {
+        self.shape.child_defaults = Some(defaults);
+        self
+    }
+
     /// Mark this enum as numeric.
     ///
     /// Numeric enums serialize to the underlying discriminant
@@ -445,6 +470,13 @@ impl ShapeBuilder {
         self
     }
 
+    /// Mark this type as having fields with `#[facet(order = N)]`.
+    #[inline]
+    pub const fn ordered_fields(mut self) -> Self {
+        self.shape.flags = self.shape.flags.union(ShapeFlags::ORDERED_FIELDS);
+        self
+    }
+
     /// Build the Shape.
     ///
     /// If `ty` was not explicitly set (still `Type::Undefined`), it will be
@@ -47,12 +47,15 @@ impl fmt::Debug for Shape {
             type_name: _,
             proxy: _,
             format_proxies: _,
+            from_conversion: _,
+            into_conversion: _,
             opaque_adapter: _,
             variance: _,
             flags: _,
             tag: _,
             content: _,
             rename: _,
+            child_defaults: _,
         } = self;
 
         if f.alternate() {
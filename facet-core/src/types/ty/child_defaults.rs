@@ -0,0 +1,219 @@
+//! Conventions a container hands down to the types it references.
+//!
+//! `#[facet(rename_all = "...")]` is folded into each field's name by the
+//! derive, so it can only describe the type it's written on. With
+//! `#[facet(defaults_for_children(rename_all = "..."))]`, a container also
+//! gives a rule to the structs it reaches through its fields, and consumers
+//! apply it while walking down from the container.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{Field, Shape, Type, UserType};
+
+/// A case conversion rule, as named in `#[facet(rename_all = "...")]`.
+///
+/// The derive applies `rename_all` at compile time; this is the same
+/// conversion, for names only known to be renamed at runtime.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RenameRule {
+    /// `foo_bar` -> `FooBar`
+    PascalCase,
+    /// `foo_bar` -> `fooBar`
+    CamelCase,
+    /// `fooBar` -> `foo_bar`
+    SnakeCase,
+    /// `foo_bar` -> `FOO_BAR`
+    ScreamingSnakeCase,
+    /// `foo_bar` -> `foo-bar`
+    KebabCase,
+    /// `foo_bar` -> `FOO-BAR`
+    ScreamingKebabCase,
+    /// `foo_bar` -> `foobar`
+    Lowercase,
+    /// `foo_bar` -> `FOOBAR`
+    Uppercase,
+}
+
+impl RenameRule {
+    /// Parse a rule by the name `rename_all` accepts, e.g. `"camelCase"`.
+    pub fn parse(rule: &str) -> Option<Self> {
+        Some(match rule {
+            "PascalCase" => Self::PascalCase,
+            "camelCase" => Self::CamelCase,
+            "snake_case" => Self::SnakeCase,
+            "SCREAMING_SNAKE_CASE" => Self::ScreamingSnakeCase,
+            "kebab-case" => Self::KebabCase,
+            "SCREAMING-KEBAB-CASE" => Self::ScreamingKebabCase,
+            "lowercase" => Self::Lowercase,
+            "UPPERCASE" => Self::Uppercase,
+            _ => return None,
+        })
+    }
+
+    /// Convert `name` to this case.
+    pub fn apply(self, name: &str) -> String {
+        let words = split_into_words(name);
+        match self {
+            Self::PascalCase => words.iter().map(|w| capitalize(w)).collect(),
+            Self::CamelCase => words
+                .iter()
+                .enumerate()
+                .map(|(i, w)| {
+                    if i == 0 {
+                        w.to_lowercase()
+                    } else {
+                        capitalize(w)
+                    }
+                })
+                .collect(),
+            Self::SnakeCase => join(&words, "_", str::to_lowercase),
+            Self::ScreamingSnakeCase => join(&words, "_", str::to_uppercase),
+            Self::KebabCase => join(&words, "-", str::to_lowercase),
+            Self::ScreamingKebabCase => join(&words, "-", str::to_uppercase),
+            Self::Lowercase => join(&words, "", str::to_lowercase),
+            Self::Uppercase => join(&words, "", str::to_uppercase),
+        }
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+fn join(words: &[&str], separator: &str, case: fn(&str) -> String) -> String {
+    words
+        .iter()
+        .map(|w| case(w))
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// Split a name into words at `_`, `-` and whitespace, and at case changes:
+/// `fooBar` and `HTTPServer` are two words each.
+fn split_into_words(name: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let mut start = None;
+    let mut chars = name.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if c == '_' || c == '-' || c.is_whitespace() {
+            if let Some(s) = start.take() {
+                words.push(&name[s..i]);
+            }
+            continue;
+        }
+        if let Some(s) = start
+            && c.is_uppercase()
+        {
+            let prev = name[s..i].chars().next_back().unwrap_or(c);
+            let next_is_lower = chars.peek().is_some_and(|&(_, n)| n.is_lowercase());
+            if prev.is_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_uppercase() && next_is_lower)
+            {
+                words.push(&name[s..i]);
+                start = Some(i);
+            }
+        }
+        start.get_or_insert(i);
+    }
+    if let Some(s) = start {
+        words.push(&name[s..]);
+    }
+    words
+}
+
+/// Conventions a container gives the types it references, set with
+/// `#[facet(defaults_for_children(rename_all = "..."))]`.
+///
+/// They reach every struct below the container, through fields and through
+/// `Option`, lists, maps and pointers, until a type that declares its own
+/// `defaults_for_children`. A struct with its own `rename_all` keeps it, and
+/// a field with `#[facet(rename = "...")]` keeps its name. The container's
+/// own fields follow its own `rename_all`, not these defaults.
+///
+/// Only `rename_all` can be inherited: a type is always deserializable from
+/// what it serializes to, which inherited skipping would break.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub struct ChildDefaults {
+    /// Case conversion for the field names of structs below the container.
+    pub rename_all: Option<RenameRule>,
+}
+
+impl ChildDefaults {
+    /// Defaults that change nothing.
+    pub const fn new() -> Self {
+        Self { rename_all: None }
+    }
+
+    /// Set the case conversion for field names.
+    pub const fn rename_all(mut self, rule: RenameRule) -> Self {
+        self.rename_all = Some(rule);
+        self
+    }
+
+    /// The name `field` of `owner` takes under these defaults, if they
+    /// rename it. `owner` is the struct declaring the field.
+    ///
+    /// Names are computed once and shared, so they can be used wherever
+    /// [`Field::effective_name`] is.
+    pub fn field_name(&self, owner: &Shape, field: &Field) -> Option<&'static str> {
+        let rule = self.rename_all?;
+        if field.rename.is_some()
+            || !matches!(owner.ty, Type::User(UserType::Struct(_)))
+            || owner.has_builtin_attr("rename_all")
+        {
+            return None;
+        }
+        Some(intern(rule, field.name))
+    }
+}
+
+impl Default for ChildDefaults {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `rule` applied to `name`, leaked once per distinct pair.
+#[cfg(feature = "std")]
+#[allow(clippy::std_instead_of_core)]
+fn intern(rule: RenameRule, name: &'static str) -> &'static str {
+    use std::collections::HashMap;
+    use std::sync::{Mutex, OnceLock, PoisonError};
+
+    static NAMES: OnceLock<Mutex<HashMap<(RenameRule, &'static str), &'static str>>> =
+        OnceLock::new();
+    let mut names = NAMES
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    *names.entry((rule, name)).or_insert_with(|| {
+        let renamed = rule.apply(name);
+        if renamed == name {
+            name
+        } else {
+            renamed.leak()
+        }
+    })
+}
+
+/// Without `std` there is no lock to share names behind, so each call leaks
+/// the name it computes (unless the rule leaves it unchanged).
+#[cfg(not(feature = "std"))]
+fn intern(rule: RenameRule, name: &'static str) -> &'static str {
+    let renamed = rule.apply(name);
+    if renamed == name {
+        name
+    } else {
+        renamed.leak()
+    }
+}
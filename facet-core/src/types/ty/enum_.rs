@@ -118,6 +118,26 @@ impl Variant {
             || self.has_attr(Some("xml"), "text")
     }
 
+    /// Returns true if this variant has the `#[facet(xml::cdata)]` attribute.
+    ///
+    /// When deserializing XML, `<![CDATA[...]]>` sections select this variant instead
+    /// of the text variant; when serializing, its content is written back as a CDATA
+    /// section rather than as escaped text.
+    #[inline]
+    pub fn is_cdata(&self) -> bool {
+        self.has_attr(Some("xml"), "cdata")
+    }
+
+    /// Returns true if this variant has the `#[facet(xml::processing_instruction)]` attribute.
+    ///
+    /// When deserializing XML, processing instructions (`<?target data?>`) select this
+    /// variant, whose first two fields receive the target and the data. Without such a
+    /// variant they are skipped.
+    #[inline]
+    pub fn is_processing_instruction(&self) -> bool {
+        self.has_attr(Some("xml"), "processing_instruction")
+    }
+
     /// Returns true if this variant has the `#[facet(custom_element)]`,
     /// `#[facet(html::custom_element)]` or `#[facet(xml::custom_element)]` attribute.
     ///
@@ -190,6 +210,46 @@ pub enum EnumRepr {
 }
 
 impl EnumRepr {
+    /// Returns the name of the discriminant's primitive type (`"u8"`, `"i32"`, ...),
+    /// or `None` for [`EnumRepr::Rust`] and [`EnumRepr::RustNPO`], which have no
+    /// discriminant with a defined layout.
+    pub const fn type_name(self) -> Option<&'static str> {
+        match self {
+            EnumRepr::Rust | EnumRepr::RustNPO => None,
+            EnumRepr::U8 => Some("u8"),
+            EnumRepr::U16 => Some("u16"),
+            EnumRepr::U32 => Some("u32"),
+            EnumRepr::U64 => Some("u64"),
+            EnumRepr::USize => Some("usize"),
+            EnumRepr::I8 => Some("i8"),
+            EnumRepr::I16 => Some("i16"),
+            EnumRepr::I32 => Some("i32"),
+            EnumRepr::I64 => Some("i64"),
+            EnumRepr::ISize => Some("isize"),
+        }
+    }
+
+    /// Returns the size of the discriminant in bytes, or `None` if the layout
+    /// is unspecified (see [`Self::type_name`]).
+    pub const fn discriminant_size(self) -> Option<usize> {
+        match self {
+            EnumRepr::Rust | EnumRepr::RustNPO => None,
+            EnumRepr::U8 | EnumRepr::I8 => Some(1),
+            EnumRepr::U16 | EnumRepr::I16 => Some(2),
+            EnumRepr::U32 | EnumRepr::I32 => Some(4),
+            EnumRepr::U64 | EnumRepr::I64 => Some(8),
+            EnumRepr::USize | EnumRepr::ISize => Some(core::mem::size_of::<usize>()),
+        }
+    }
+
+    /// Returns true if the discriminant is a signed integer.
+    pub const fn is_signed(self) -> bool {
+        matches!(
+            self,
+            EnumRepr::I8 | EnumRepr::I16 | EnumRepr::I32 | EnumRepr::I64 | EnumRepr::ISize
+        )
+    }
+
     /// Returns the enum representation for the given discriminant type
     ///
     /// NOTE: only supports unsigned discriminants
@@ -77,6 +77,10 @@ crate::bitflags! {
         /// Field has a recursive type that needs lazy shape resolution.
         /// Set by `#[facet(recursive_type)]`.
         const RECURSIVE_TYPE = 1 << 6;
+
+        /// Field has a place in serialization order, so its struct's fields
+        /// need sorting. Set by `#[facet(order = N)]`.
+        const ORDERED = 1 << 7;
     }
 }
 
@@ -266,6 +270,16 @@ impl Field {
         self.has_attr(Some("xml"), "doctype")
     }
 
+    /// Returns true if this field captures the processing instructions before the
+    /// root element (for XML documents).
+    ///
+    /// Checks for `xml::processing_instruction` attribute.
+    /// Used to keep instructions like `<?xml-stylesheet ...?>` across a round trip.
+    #[inline]
+    pub fn is_processing_instruction(&self) -> bool {
+        self.has_attr(Some("xml"), "processing_instruction")
+    }
+
     /// Returns true if this field stores metadata.
     ///
     /// Metadata fields are excluded from structural hashing and equality.
@@ -322,6 +336,28 @@ impl Field {
         self.metadata
     }
 
+    /// Returns the field's place in serialization order, set by
+    /// `#[facet(order = N)]`, or 0 if it has none.
+    ///
+    /// See [`StructType::fields_in_order`](super::StructType::fields_in_order).
+    #[inline]
+    pub fn order(&self) -> i64 {
+        if !self.flags.contains(FieldFlags::ORDERED) {
+            return 0;
+        }
+        self.get_builtin_attr("order")
+            .and_then(|attr| attr.get_as::<i64>().copied())
+            .unwrap_or(0)
+    }
+
+    /// Returns the name of the sibling field whose value prefixes this
+    /// flattened field's keys, set by `#[facet(flatten_prefix_from = "...")]`.
+    #[inline]
+    pub fn flatten_prefix_from(&self) -> Option<&'static str> {
+        self.get_builtin_attr("flatten_prefix_from")
+            .and_then(|attr| attr.get_as::<&'static str>().copied())
+    }
+
     /// Returns true if this field should be skipped during deserialization.
     ///
     /// This checks the `SKIP` and `SKIP_DESERIALIZING` flags (O(1)).
@@ -343,6 +379,20 @@ impl Field {
             None => self.name,
         }
     }
+
+    /// Like [`Field::effective_name`], but renamed by the
+    /// [`ChildDefaults`](super::ChildDefaults) in force for `owner`, the struct
+    /// declaring this field, if any.
+    #[inline]
+    pub fn effective_name_in(
+        &self,
+        owner: &Shape,
+        inherited: Option<super::ChildDefaults>,
+    ) -> &'static str {
+        inherited
+            .and_then(|defaults| defaults.field_name(owner, self))
+            .unwrap_or_else(|| self.effective_name())
+    }
 }
 
 /// A function that, if present, determines whether field should be included in the serialization
@@ -8,6 +8,9 @@ pub use field::*;
 mod proxy;
 pub use proxy::*;
 
+mod child_defaults;
+pub use child_defaults::*;
+
 mod opaque_adapter;
 pub use opaque_adapter::*;
 
@@ -1,4 +1,4 @@
-use super::{Field, Repr};
+use super::{Field, FieldFlags, Repr};
 
 /// Common fields for struct-like types
 #[derive(Clone, Copy, Debug)]
@@ -23,8 +23,96 @@ impl StructType {
         kind: StructKind::Unit,
         fields: &[],
     };
+
+    /// Iterates over the fields in serialization order, each with its index
+    /// in [`Self::fields`].
+    ///
+    /// Fields are sorted by [`Field::order`] (`#[facet(order = N)]`, 0 when
+    /// absent), ties keeping declaration order. Without any `order` attribute
+    /// this is declaration order. Memory layout and `fields` are unaffected.
+    ///
+    /// ```
+    /// use facet::Facet;
+    /// use facet_core::{Type, UserType};
+    ///
+    /// #[derive(Facet)]
+    /// struct Upload {
+    ///     #[facet(order = 1)]
+    ///     data: Vec<u8>,
+    ///     name: String,
+    ///     #[facet(order = -1)]
+    ///     id: u64,
+    /// }
+    ///
+    /// let Type::User(UserType::Struct(st)) = Upload::SHAPE.ty else { unreachable!() };
+    /// let names: Vec<_> = st.fields_in_order().map(|(_, f)| f.name).collect();
+    /// assert_eq!(names, ["id", "name", "data"]);
+    /// ```
+    #[inline]
+    pub fn fields_in_order(&self) -> FieldsInOrder {
+        FieldsInOrder::new(self.fields)
+    }
 }
 
+/// Iterator over a struct's fields in serialization order, returned by
+/// [`StructType::fields_in_order`].
+#[derive(Clone, Debug)]
+pub struct FieldsInOrder {
+    fields: &'static [Field],
+    /// Whether any field has an `order`; if not, this is declaration order.
+    sorted: bool,
+    /// `(order, index)` of the last field yielded.
+    last: Option<(i64, usize)>,
+    remaining: usize,
+}
+
+impl FieldsInOrder {
+    /// Iterate over `fields`, a struct's or variant's fields, in serialization order.
+    pub fn new(fields: &'static [Field]) -> Self {
+        Self {
+            fields,
+            sorted: fields.iter().any(|f| f.flags.contains(FieldFlags::ORDERED)),
+            last: None,
+            remaining: fields.len(),
+        }
+    }
+}
+
+impl Iterator for FieldsInOrder {
+    type Item = (usize, &'static Field);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let index = if self.sorted {
+            // Structs have few fields, so find the next one by scanning
+            // rather than allocating a sorted copy
+            let (_, index) = self
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(index, field)| (field.order(), index))
+                .filter(|key| self.last.is_none_or(|last| *key > last))
+                .min()?;
+            index
+        } else {
+            self.last.map_or(0, |(_, index)| index + 1)
+        };
+        let field = &self.fields[index];
+        self.last = Some((field.order(), index));
+        self.remaining -= 1;
+        Some((index, field))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for FieldsInOrder {}
+
 /// Describes the kind of struct (useful for deserializing)
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 #[repr(C)]
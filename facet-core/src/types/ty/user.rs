@@ -78,6 +78,15 @@ impl Repr {
         Self::C
     }
 
+    /// Build packed C representation (`#[repr(C, packed)]` or `packed(N)`)
+    #[inline]
+    pub const fn c_packed() -> Self {
+        Self {
+            base: BaseRepr::C,
+            packed: true,
+        }
+    }
+
     /// Builds transparent representation
     #[inline]
     pub const fn transparent() -> Self {
@@ -1,5 +1,6 @@
 mod list_from_raw_parts;
 mod pointer;
+mod scalar_kind;
 mod simd;
 mod type_name;
 mod vtable;
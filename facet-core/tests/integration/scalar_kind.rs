@@ -0,0 +1,37 @@
+use std::borrow::Cow;
+
+use facet::Facet;
+use facet_core::ScalarKind;
+use facet_testhelpers::test;
+
+#[test]
+fn string_types_share_a_kind() {
+    assert_eq!(<&str>::SHAPE.scalar_kind(), Some(ScalarKind::Str));
+    assert_eq!(String::SHAPE.scalar_kind(), Some(ScalarKind::Str));
+    assert_eq!(<Cow<'_, str>>::SHAPE.scalar_kind(), Some(ScalarKind::Str));
+    assert_eq!(char::SHAPE.scalar_kind(), Some(ScalarKind::Char));
+}
+
+#[test]
+fn numbers_keep_their_width() {
+    assert_eq!(u8::SHAPE.scalar_kind(), Some(ScalarKind::U8));
+    assert_eq!(i128::SHAPE.scalar_kind(), Some(ScalarKind::I128));
+    assert_eq!(usize::SHAPE.scalar_kind(), Some(ScalarKind::USize));
+    assert_eq!(f32::SHAPE.scalar_kind(), Some(ScalarKind::F32));
+    assert!(ScalarKind::I16.is_integer());
+    assert!(!ScalarKind::F64.is_integer());
+    assert!(ScalarKind::F64.is_float());
+}
+
+#[test]
+fn byte_buffers_are_bytes() {
+    assert_eq!(<Vec<u8>>::SHAPE.scalar_kind(), Some(ScalarKind::Bytes));
+    assert_eq!(<[u8]>::SHAPE.scalar_kind(), Some(ScalarKind::Bytes));
+    assert_eq!(<Vec<i8>>::SHAPE.scalar_kind(), None);
+}
+
+#[test]
+fn other_scalars_have_no_kind() {
+    assert_eq!(<()>::SHAPE.scalar_kind(), None);
+    assert_eq!(std::path::PathBuf::SHAPE.scalar_kind(), None);
+}
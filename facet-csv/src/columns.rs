@@ -0,0 +1,77 @@
+//! Checking that a type can be a CSV record.
+
+use alloc::format;
+use alloc::string::String;
+
+use facet_core::{Def, Field, Shape, StructKind, Type, UserType};
+use facet_format::{DeserializeError, DeserializeErrorKind};
+use facet_reflect::Span;
+
+/// The fields of `shape`, if it is a struct whose fields can all be CSV
+/// columns. Otherwise, a message saying which field can't be, and why.
+pub(crate) fn record_fields(shape: &'static Shape) -> Result<&'static [Field], String> {
+    let Type::User(UserType::Struct(struct_type)) = shape.ty else {
+        return Err(format!(
+            "CSV records must be structs, but `{shape}` is not a struct"
+        ));
+    };
+    for field in struct_type.fields {
+        if let Some(kind) = non_scalar_kind(field.shape()) {
+            return Err(format!(
+                "field `{}` of `{shape}` has type `{}`, which is {kind}; \
+                 CSV columns can only hold scalar values",
+                field.name,
+                field.shape(),
+            ));
+        }
+    }
+    Ok(struct_type.fields)
+}
+
+/// [`record_fields`], with the message as a deserialization error.
+pub(crate) fn check_record(shape: &'static Shape) -> Result<&'static [Field], DeserializeError> {
+    record_fields(shape).map_err(|message| {
+        DeserializeErrorKind::Unsupported {
+            message: message.into(),
+        }
+        .with_span(Span::new(0, 0))
+    })
+}
+
+/// The names of the columns of a record with these fields, in order.
+pub(crate) fn header_names(fields: &'static [Field]) -> impl Iterator<Item = &'static str> {
+    fields
+        .iter()
+        .filter(|field| !field.should_skip_serializing_unconditional())
+        .map(|field| field.effective_name())
+}
+
+/// What `shape` is, if it can't be a single CSV field.
+fn non_scalar_kind(shape: &'static Shape) -> Option<&'static str> {
+    match shape.def {
+        Def::Scalar => return None,
+        Def::Option(option) => return non_scalar_kind(option.t()),
+        Def::Pointer(pointer) => return pointer.pointee().and_then(non_scalar_kind),
+        Def::List(_) | Def::Array(_) | Def::Slice(_) => return Some("a list"),
+        Def::Map(_) => return Some("a map"),
+        Def::Set(_) => return Some("a set"),
+        _ => {}
+    }
+
+    if let Some(inner) = shape.inner {
+        return non_scalar_kind(inner);
+    }
+
+    match shape.ty {
+        Type::User(UserType::Struct(struct_type)) => match struct_type.kind {
+            StructKind::Tuple | StructKind::TupleStruct => Some("a tuple"),
+            _ => Some("a struct"),
+        },
+        Type::User(UserType::Enum(enum_type)) => enum_type
+            .variants
+            .iter()
+            .any(|variant| !variant.data.fields.is_empty())
+            .then_some("an enum with data"),
+        _ => None,
+    }
+}
@@ -1,31 +1,73 @@
 //! CSV parser and serializer using facet-format.
 //!
-//! **Note:** CSV is a fundamentally different format from JSON/XML/YAML.
-//! While those formats are tree-structured and map naturally to nested types,
-//! CSV is a flat, row-based format where each row represents a single record
-//! and each column represents a field.
+//! CSV is a flat, row-based format: each record is a struct, and each column
+//! holds one of its fields. Fields must be scalars (numbers, strings,
+//! booleans, unit enums, and `Option`s of those); lists, maps, nested structs
+//! and enums with data are rejected up front with an error naming the field.
 //!
-//! This crate provides basic CSV support via the `FormatParser` trait, but
-//! has significant limitations:
+//! # Records and headers
 //!
-//! - No support for nested structures (CSV is inherently flat)
-//! - No support for arrays/sequences as field values
-//! - No support for enums beyond unit variants (encoded as strings)
-//! - All values are strings and must be parseable to target types
+//! [`from_str_records`] reads a whole document, one record at a time. The
+//! first line is a header naming the columns: columns are matched to fields
+//! by their (possibly renamed) name or alias, in any order, and an empty
+//! column or a missing one reads as `None` for `Option` fields.
+//! [`to_string_records`] and [`CsvWriter`] write a header from the field
+//! names, followed by one line per record.
 //!
-//! For more sophisticated CSV handling, consider a dedicated CSV library.
+//! ```
+//! use facet::Facet;
+//! use facet_csv::{from_str_records, to_string_records};
+//!
+//! #[derive(Facet, Debug, PartialEq)]
+//! struct Row {
+//!     #[facet(rename = "Name")]
+//!     name: String,
+//!     score: Option<u32>,
+//! }
+//!
+//! let rows = [
+//!     Row { name: "Ada".into(), score: Some(3) },
+//!     Row { name: "Line\nbreak".into(), score: None },
+//! ];
+//! let csv = to_string_records(&rows).unwrap();
+//! assert_eq!(csv, "Name,score\nAda,3\n\"Line\nbreak\",\n");
+//!
+//! let back: Vec<Row> = from_str_records(&csv).collect::<Result<_, _>>().unwrap();
+//! assert_eq!(back, rows);
+//! ```
+//!
+//! Quoted fields follow RFC 4180: they may contain delimiters and line
+//! breaks, and `""` stands for a quote.
+//!
+//! # Options
+//!
+//! [`DeserializeOptions`] and [`SerializeOptions`] set the delimiter (with
+//! `tsv()` shorthands for tab-separated values) and whether there is a
+//! header. [`SerializeOptions`] also sets when fields are quoted, see
+//! [`QuoteStyle`].
+//!
+//! # Single records
+//!
+//! [`from_str`] and [`to_string`] read and write a single record, without a
+//! header, with columns in field order.
 
 #![forbid(unsafe_code)]
 
 extern crate alloc;
 
+mod columns;
 mod error;
 mod parser;
+mod records;
 mod serializer;
 
 pub use error::{CsvError, CsvErrorKind};
 pub use parser::CsvParser;
-pub use serializer::{CsvSerializeError, CsvSerializer, to_string, to_vec, to_writer};
+pub use records::{DeserializeOptions, Records, from_str_records, from_str_records_with_options};
+pub use serializer::{
+    CsvSerializeError, CsvSerializer, CsvWriter, QuoteStyle, SerializeOptions, to_string,
+    to_string_records, to_string_records_with_options, to_vec, to_vec_with_options, to_writer,
+};
 
 // Re-export DeserializeError for convenience
 pub use facet_format::DeserializeError;
@@ -57,6 +99,7 @@ where
     T: facet_core::Facet<'static>,
 {
     use facet_format::FormatDeserializer;
+    columns::check_record(T::SHAPE)?;
     let mut parser = CsvParser::new(input);
     let mut de = FormatDeserializer::new_owned(&mut parser);
     de.deserialize_root()
@@ -87,6 +130,7 @@ where
     'input: 'facet,
 {
     use facet_format::FormatDeserializer;
+    columns::check_record(T::SHAPE)?;
     let mut parser = CsvParser::new(input);
     let mut de = FormatDeserializer::new(&mut parser);
     de.deserialize_root()
@@ -3,6 +3,7 @@
 extern crate alloc;
 
 use alloc::borrow::Cow;
+use alloc::string::String;
 use alloc::vec::Vec;
 
 use facet_format::{
@@ -21,11 +22,12 @@ enum ParserState {
 }
 
 /// A parsed field with its byte offset and length.
-#[derive(Debug, Clone, Copy)]
-struct FieldSpan<'de> {
-    value: &'de str,
-    offset: usize,
-    len: usize,
+#[derive(Debug, Clone)]
+pub(crate) struct FieldSpan<'de> {
+    /// The field's text, with quotes removed and `""` unescaped.
+    pub(crate) value: Cow<'de, str>,
+    pub(crate) offset: usize,
+    pub(crate) len: usize,
 }
 
 /// CSV parser that emits FormatParser events.
@@ -48,20 +50,35 @@ pub struct CsvParser<'de> {
     pending_struct_fields: Option<usize>,
     /// Pending scalar type hint from `hint_scalar_type`.
     pending_scalar_type: Option<ScalarTypeHint>,
+    /// Whether `hint_option` was called for the current field.
+    pending_option: bool,
+    /// A malformed row, reported on the first event.
+    error: Option<ParseError>,
 }
 
 impl<'de> CsvParser<'de> {
     /// Create a new CSV parser for a single row.
     pub fn new(input: &'de str) -> Self {
-        let trimmed = input.trim();
         // Calculate the offset of the trimmed content within the original input
         let trim_offset = input.len() - input.trim_start().len();
-        let fields = if trimmed.is_empty() {
-            Vec::new()
+        let trimmed = input.trim_end();
+        let (fields, error) = if trimmed.trim_start().is_empty() {
+            (Vec::new(), None)
         } else {
-            parse_csv_row_with_spans(trimmed, trim_offset)
+            match split_record(trimmed, trim_offset, b',') {
+                Ok((fields, _)) => (fields, None),
+                Err(err) => (Vec::new(), Some(err)),
+            }
         };
 
+        let mut parser = Self::from_fields(input, fields);
+        parser.error = error;
+        parser
+    }
+
+    /// Create a parser for one record whose fields, in struct field order,
+    /// have already been split out of `input`.
+    pub(crate) fn from_fields(input: &'de str, fields: Vec<FieldSpan<'de>>) -> Self {
         Self {
             input,
             fields,
@@ -70,6 +87,8 @@ impl<'de> CsvParser<'de> {
             peeked: None,
             pending_struct_fields: None,
             pending_scalar_type: None,
+            pending_option: false,
+            error: None,
         }
     }
 
@@ -91,12 +110,35 @@ impl<'de> CsvParser<'de> {
 
     /// Generate the next event based on current state.
     fn generate_next_event(&mut self) -> Result<ParseEvent<'de>, ParseError> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+
+        // An empty field is `None`; anything else is the `Some` payload
+        if core::mem::take(&mut self.pending_option) {
+            if self.field_index > 0 && self.field_index <= self.fields.len() {
+                let kind = if self.fields[self.field_index - 1].value.is_empty() {
+                    ParseEventKind::Scalar(ScalarValue::Null)
+                } else {
+                    ParseEventKind::OptionSome
+                };
+                return Ok(self.event(kind));
+            } else {
+                return Err(ParseError::new(
+                    Span::new(self.input.len(), 0),
+                    DeserializeErrorKind::UnexpectedEof {
+                        expected: "field for option hint",
+                    },
+                ));
+            }
+        }
+
         // Check if we have a pending scalar type hint
         if let Some(hint) = self.pending_scalar_type.take() {
             if self.field_index > 0 && self.field_index <= self.fields.len() {
                 let field = &self.fields[self.field_index - 1];
                 return Ok(self.event(ParseEventKind::Scalar(parse_scalar_with_hint(
-                    field.value,
+                    &field.value,
                     hint,
                 ))));
             } else {
@@ -151,61 +193,129 @@ impl<'de> CsvParser<'de> {
     }
 }
 
-/// Parse a CSV row into fields with spans, handling quoted fields.
-fn parse_csv_row_with_spans(input: &str, base_offset: usize) -> Vec<FieldSpan<'_>> {
-    let mut fields = Vec::new();
-    let mut in_quotes = false;
-    let mut field_start = 0;
+/// Splits the record starting at byte `start` of `input` into fields,
+/// returning them with the offset just past the record's line ending.
+///
+/// Fields follow RFC 4180: a field that starts with `"` is quoted, may
+/// contain the delimiter and line breaks, and escapes `"` as `""`. Spaces
+/// around fields are trimmed.
+pub(crate) fn split_record(
+    input: &str,
+    start: usize,
+    delimiter: u8,
+) -> Result<(Vec<FieldSpan<'_>>, usize), ParseError> {
     let bytes = input.as_bytes();
+    let mut fields = Vec::new();
+    let mut pos = start;
 
-    for (i, &b) in bytes.iter().enumerate() {
-        match b {
-            b'"' => {
-                in_quotes = !in_quotes;
+    loop {
+        while pos < bytes.len() && is_blank(bytes[pos], delimiter) {
+            pos += 1;
+        }
+
+        let field = if bytes.get(pos) == Some(&b'"') {
+            let (field, after) = quoted_field(input, pos)?;
+            pos = after;
+            while pos < bytes.len() && is_blank(bytes[pos], delimiter) {
+                pos += 1;
+            }
+            if pos < bytes.len() && bytes[pos] != delimiter && !is_line_end(bytes[pos]) {
+                let found = input[pos..].chars().next().unwrap_or_default();
+                return Err(ParseError::new(
+                    Span::new(pos, found.len_utf8()),
+                    DeserializeErrorKind::UnexpectedChar {
+                        ch: found,
+                        expected: "delimiter or end of line after closing quote",
+                    },
+                ));
+            }
+            field
+        } else {
+            let field_start = pos;
+            while pos < bytes.len() && bytes[pos] != delimiter && !is_line_end(bytes[pos]) {
+                pos += 1;
             }
-            b',' if !in_quotes => {
-                let field = &input[field_start..i];
-                let (value, value_offset) = unquote_field_with_offset(field, field_start);
-                fields.push(FieldSpan {
-                    value,
-                    offset: base_offset + value_offset,
-                    len: value.len(),
-                });
-                field_start = i + 1;
+            let value = input[field_start..pos].trim_end();
+            FieldSpan {
+                value: Cow::Borrowed(value),
+                offset: field_start,
+                len: value.len(),
             }
-            _ => {}
+        };
+        fields.push(field);
+
+        match bytes.get(pos) {
+            Some(&b) if b == delimiter => pos += 1,
+            Some(b'\r') if bytes.get(pos + 1) == Some(&b'\n') => return Ok((fields, pos + 2)),
+            Some(b'\r' | b'\n') => return Ok((fields, pos + 1)),
+            _ => return Ok((fields, pos)),
         }
     }
+}
 
-    // Add the last field
-    let field = &input[field_start..];
-    let (value, value_offset) = unquote_field_with_offset(field, field_start);
-    fields.push(FieldSpan {
-        value,
-        offset: base_offset + value_offset,
-        len: value.len(),
-    });
+/// Reads the quoted field whose opening quote is at `start`, returning it and
+/// the offset just past its closing quote.
+fn quoted_field(input: &str, start: usize) -> Result<(FieldSpan<'_>, usize), ParseError> {
+    let content_start = start + 1;
+    let mut unescaped: Option<String> = None;
+    let mut chunk_start = content_start;
+    let mut pos = content_start;
+    let bytes = input.as_bytes();
 
-    fields
+    loop {
+        match bytes[pos..].iter().position(|&b| b == b'"') {
+            None => {
+                return Err(ParseError::new(
+                    Span::new(start, input.len() - start),
+                    DeserializeErrorKind::UnexpectedEof {
+                        expected: "closing quote",
+                    },
+                ));
+            }
+            Some(offset) => {
+                let quote = pos + offset;
+                if bytes.get(quote + 1) == Some(&b'"') {
+                    // An escaped quote: keep one of the two
+                    unescaped
+                        .get_or_insert_with(String::new)
+                        .push_str(&input[chunk_start..=quote]);
+                    pos = quote + 2;
+                    chunk_start = pos;
+                } else {
+                    let value = match unescaped {
+                        Some(mut owned) => {
+                            owned.push_str(&input[chunk_start..quote]);
+                            Cow::Owned(owned)
+                        }
+                        None => Cow::Borrowed(&input[content_start..quote]),
+                    };
+                    let field = FieldSpan {
+                        value,
+                        offset: content_start,
+                        len: quote - content_start,
+                    };
+                    return Ok((field, quote + 1));
+                }
+            }
+        }
+    }
 }
 
-/// Remove surrounding quotes from a field if present, returning value and offset.
-fn unquote_field_with_offset(field: &str, field_start: usize) -> (&str, usize) {
-    let trim_start = field.len() - field.trim_start().len();
-    let trimmed = field.trim();
-    if trimmed.starts_with('"') && trimmed.ends_with('"') && trimmed.len() >= 2 {
-        // +1 for the opening quote
-        (&trimmed[1..trimmed.len() - 1], field_start + trim_start + 1)
-    } else {
-        (trimmed, field_start + trim_start)
-    }
+/// Spaces and tabs, unless the delimiter is one of them.
+fn is_blank(b: u8, delimiter: u8) -> bool {
+    (b == b' ' || b == b'\t') && b != delimiter
+}
+
+fn is_line_end(b: u8) -> bool {
+    b == b'\n' || b == b'\r'
 }
 
 /// Parse a scalar value with the given type hint.
-fn parse_scalar_with_hint(value: &str, hint: ScalarTypeHint) -> ScalarValue<'_> {
+fn parse_scalar_with_hint<'de>(value: &Cow<'de, str>, hint: ScalarTypeHint) -> ScalarValue<'de> {
+    let text = value.as_ref();
     match hint {
         ScalarTypeHint::Bool => {
-            let val = matches!(value, "true" | "TRUE" | "1" | "yes" | "YES");
+            let val = matches!(text, "true" | "TRUE" | "1" | "yes" | "YES");
             ScalarValue::Bool(val)
         }
         ScalarTypeHint::U8
@@ -213,18 +323,18 @@ fn parse_scalar_with_hint(value: &str, hint: ScalarTypeHint) -> ScalarValue<'_>
         | ScalarTypeHint::U32
         | ScalarTypeHint::U64
         | ScalarTypeHint::Usize => {
-            if let Ok(n) = value.parse::<u64>() {
+            if let Ok(n) = text.parse::<u64>() {
                 ScalarValue::U64(n)
             } else {
                 // Fall back to string if parsing fails
-                ScalarValue::Str(Cow::Borrowed(value))
+                ScalarValue::Str(value.clone())
             }
         }
         ScalarTypeHint::U128 => {
-            if let Ok(n) = value.parse::<u128>() {
+            if let Ok(n) = text.parse::<u128>() {
                 ScalarValue::U128(n)
             } else {
-                ScalarValue::Str(Cow::Borrowed(value))
+                ScalarValue::Str(value.clone())
             }
         }
         ScalarTypeHint::I8
@@ -232,35 +342,35 @@ fn parse_scalar_with_hint(value: &str, hint: ScalarTypeHint) -> ScalarValue<'_>
         | ScalarTypeHint::I32
         | ScalarTypeHint::I64
         | ScalarTypeHint::Isize => {
-            if let Ok(n) = value.parse::<i64>() {
+            if let Ok(n) = text.parse::<i64>() {
                 ScalarValue::I64(n)
             } else {
-                ScalarValue::Str(Cow::Borrowed(value))
+                ScalarValue::Str(value.clone())
             }
         }
         ScalarTypeHint::I128 => {
-            if let Ok(n) = value.parse::<i128>() {
+            if let Ok(n) = text.parse::<i128>() {
                 ScalarValue::I128(n)
             } else {
-                ScalarValue::Str(Cow::Borrowed(value))
+                ScalarValue::Str(value.clone())
             }
         }
         ScalarTypeHint::F32 | ScalarTypeHint::F64 => {
-            if let Ok(n) = value.parse::<f64>() {
+            if let Ok(n) = text.parse::<f64>() {
                 ScalarValue::F64(n)
             } else {
-                ScalarValue::Str(Cow::Borrowed(value))
+                ScalarValue::Str(value.clone())
             }
         }
-        ScalarTypeHint::String | ScalarTypeHint::Char => ScalarValue::Str(Cow::Borrowed(value)),
+        ScalarTypeHint::String | ScalarTypeHint::Char => ScalarValue::Str(value.clone()),
         ScalarTypeHint::Bytes => {
             // Bytes in CSV are typically base64 or hex encoded
             // For now, just return as string and let the deserializer handle it
-            ScalarValue::Str(Cow::Borrowed(value))
+            ScalarValue::Str(value.clone())
         }
         // Unknown future hint kinds: fall back to the raw string representation,
         // mirroring the parse-failure fallback used by every other arm.
-        _ => ScalarValue::Str(Cow::Borrowed(value)),
+        _ => ScalarValue::Str(value.clone()),
     }
 }
 
@@ -316,6 +426,17 @@ impl<'de> FormatParser<'de> for CsvParser<'de> {
         }
     }
 
+    fn hint_option(&mut self) {
+        self.pending_option = true;
+        // Clear any peeked OrderedField placeholder
+        if matches!(
+            self.peeked.as_ref().map(|e| &e.kind),
+            Some(ParseEventKind::OrderedField)
+        ) {
+            self.peeked = None;
+        }
+    }
+
     fn hint_scalar_type(&mut self, hint: ScalarTypeHint) {
         self.pending_scalar_type = Some(hint);
         // Clear any peeked OrderedField placeholder
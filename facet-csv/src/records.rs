@@ -0,0 +1,279 @@
+//! Reading CSV documents record by record.
+
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use facet_core::{Def, Facet, Field};
+use facet_format::{DeserializeError, DeserializeErrorKind, FormatDeserializer};
+use facet_reflect::Span;
+
+use crate::columns::check_record;
+use crate::parser::{CsvParser, FieldSpan, split_record};
+
+/// Options for reading CSV documents.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeserializeOptions {
+    /// The byte between fields (default: `,`).
+    pub delimiter: u8,
+    /// Whether the first record names the columns (default: `true`).
+    ///
+    /// With a header, columns are matched to fields by name (honoring
+    /// renames and aliases) and may come in any order; unknown columns are
+    /// ignored, and missing columns are only allowed for `Option` fields.
+    /// Without one, columns are matched to fields by position.
+    pub has_headers: bool,
+}
+
+impl Default for DeserializeOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DeserializeOptions {
+    /// Comma-separated, with a header.
+    pub const fn new() -> Self {
+        Self {
+            delimiter: b',',
+            has_headers: true,
+        }
+    }
+
+    /// Tab-separated, with a header.
+    pub const fn tsv() -> Self {
+        Self::new().delimiter(b'\t')
+    }
+
+    /// Sets the byte between fields.
+    pub const fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets whether the first record names the columns.
+    pub const fn has_headers(mut self, has_headers: bool) -> Self {
+        self.has_headers = has_headers;
+        self
+    }
+}
+
+/// An iterator over the records of a CSV document, created by
+/// [`from_str_records`].
+///
+/// Each record is deserialized on demand. A record that fails to deserialize
+/// yields an error and reading goes on with the next one; a malformed
+/// document (such as an unterminated quote) or a type that can't be a record
+/// yields one error and ends the iteration.
+pub struct Records<'de, T> {
+    input: &'de str,
+    pos: usize,
+    options: DeserializeOptions,
+    /// For each struct field, the column it is read from.
+    columns: Option<Vec<Option<usize>>>,
+    done: bool,
+    _marker: PhantomData<fn() -> T>,
+}
+
+/// Reads the records of a CSV document, one `T` per line after the header.
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+/// use facet_csv::from_str_records;
+///
+/// #[derive(Facet, Debug, PartialEq)]
+/// struct Person {
+///     name: String,
+///     #[facet(rename = "years")]
+///     age: u32,
+///     email: Option<String>,
+/// }
+///
+/// let csv = "years,name,email\n30,Alice,\n25,\"Bob, Jr.\",bob@example.com\n";
+/// let people: Vec<Person> = from_str_records(csv).collect::<Result<_, _>>().unwrap();
+/// assert_eq!(
+///     people,
+///     [
+///         Person { name: "Alice".into(), age: 30, email: None },
+///         Person { name: "Bob, Jr.".into(), age: 25, email: Some("bob@example.com".into()) },
+///     ]
+/// );
+/// ```
+pub fn from_str_records<T>(input: &str) -> Records<'_, T>
+where
+    T: Facet<'static>,
+{
+    from_str_records_with_options(input, DeserializeOptions::default())
+}
+
+/// Reads the records of a CSV document with custom options.
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+/// use facet_csv::{DeserializeOptions, from_str_records_with_options};
+///
+/// #[derive(Facet, Debug, PartialEq)]
+/// struct Point { x: i32, y: i32 }
+///
+/// let tsv = "1\t2\n3\t4\n";
+/// let options = DeserializeOptions::tsv().has_headers(false);
+/// let points: Vec<Point> = from_str_records_with_options(tsv, options)
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+/// assert_eq!(points, [Point { x: 1, y: 2 }, Point { x: 3, y: 4 }]);
+/// ```
+pub fn from_str_records_with_options<T>(input: &str, options: DeserializeOptions) -> Records<'_, T>
+where
+    T: Facet<'static>,
+{
+    Records {
+        input,
+        pos: 0,
+        options,
+        columns: None,
+        done: false,
+        _marker: PhantomData,
+    }
+}
+
+impl<'de, T> Records<'de, T>
+where
+    T: Facet<'static>,
+{
+    /// The next non-blank record, with its fields and span.
+    fn next_record(&mut self) -> Result<Option<(Vec<FieldSpan<'de>>, Span)>, DeserializeError> {
+        loop {
+            if self.pos >= self.input.len() {
+                return Ok(None);
+            }
+            let start = self.pos;
+            let (fields, end) = split_record(self.input, start, self.options.delimiter)
+                .map_err(DeserializeError::from)?;
+            self.pos = end;
+
+            let blank = fields.len() == 1
+                && fields[0].value.is_empty()
+                && self.input[start..end].trim().is_empty();
+            if !blank {
+                let span = Span::new(start, self.input[start..end].trim_end().len());
+                return Ok(Some((fields, span)));
+            }
+        }
+    }
+
+    /// Works out which column each field is read from.
+    fn resolve_columns(&mut self) -> Result<Vec<Option<usize>>, DeserializeError> {
+        let fields = check_record(T::SHAPE)?;
+
+        if !self.options.has_headers {
+            return Ok((0..fields.len()).map(Some).collect());
+        }
+
+        let Some((header, span)) = self.next_record()? else {
+            return Ok(Vec::new());
+        };
+        fields
+            .iter()
+            .map(|field| {
+                let column = header
+                    .iter()
+                    .position(|name| names_field(field, &name.value));
+                if column.is_none() && !matches!(field.shape().def, Def::Option(_)) {
+                    return Err(DeserializeErrorKind::MissingField {
+                        field: field.effective_name(),
+                        container_shape: T::SHAPE,
+                    }
+                    .with_span(span));
+                }
+                Ok(column)
+            })
+            .collect()
+    }
+
+    fn deserialize(
+        &self,
+        columns: &[Option<usize>],
+        record: Vec<FieldSpan<'de>>,
+        span: Span,
+    ) -> Result<T, DeserializeError> {
+        let mut fields = Vec::with_capacity(columns.len());
+        for column in columns {
+            let field = match column {
+                Some(index) if *index < record.len() => record[*index].clone(),
+                Some(index) => {
+                    return Err(DeserializeErrorKind::InvalidValue {
+                        message: alloc::format!(
+                            "record has {} fields, but column {} was expected",
+                            record.len(),
+                            index + 1
+                        )
+                        .into(),
+                    }
+                    .with_span(span));
+                }
+                None => empty_field(span),
+            };
+            fields.push(field);
+        }
+
+        let mut parser = CsvParser::from_fields(self.input, fields);
+        let mut de = FormatDeserializer::new_owned(&mut parser);
+        de.deserialize_root()
+    }
+}
+
+impl<'de, T> Iterator for Records<'de, T>
+where
+    T: Facet<'static>,
+{
+    type Item = Result<T, DeserializeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let columns = match self.columns.take() {
+            Some(columns) => columns,
+            None => match self.resolve_columns() {
+                Ok(columns) => columns,
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(err));
+                }
+            },
+        };
+
+        let result = match self.next_record() {
+            Ok(Some((record, span))) => Some(self.deserialize(&columns, record, span)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(err) => {
+                self.done = true;
+                Some(Err(err))
+            }
+        };
+        self.columns = Some(columns);
+        result
+    }
+}
+
+/// Whether a header cell names `field`.
+fn names_field(field: &Field, name: &str) -> bool {
+    field.effective_name() == name || field.alias == Some(name)
+}
+
+/// The value of a column a record doesn't have.
+fn empty_field<'de>(span: Span) -> FieldSpan<'de> {
+    FieldSpan {
+        value: Cow::Borrowed(""),
+        offset: span.offset as usize,
+        len: 0,
+    }
+}
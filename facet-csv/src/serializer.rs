@@ -2,6 +2,8 @@
 
 extern crate alloc;
 
+use alloc::borrow::Cow;
+use alloc::format;
 use alloc::string::String;
 use alloc::vec::Vec;
 
@@ -9,34 +11,120 @@ use facet_core::Facet;
 use facet_format::{FormatSerializer, ScalarValue, SerializeError, serialize_root};
 use facet_reflect::Peek;
 
+use crate::columns::{header_names, record_fields};
+
 /// Error type for CSV serialization.
 #[derive(Debug)]
 pub struct CsvSerializeError {
-    msg: &'static str,
+    msg: Cow<'static, str>,
+}
+
+impl CsvSerializeError {
+    fn new(msg: impl Into<Cow<'static, str>>) -> Self {
+        Self { msg: msg.into() }
+    }
 }
 
 impl core::fmt::Display for CsvSerializeError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.write_str(self.msg)
+        f.write_str(&self.msg)
     }
 }
 
 impl std::error::Error for CsvSerializeError {}
 
+/// When fields are quoted on output.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum QuoteStyle {
+    /// Quote fields that contain the delimiter, a quote or a line break.
+    #[default]
+    Necessary,
+    /// Quote every field.
+    Always,
+    /// Quote every field that isn't a number or a boolean.
+    NonNumeric,
+    /// Never quote. Fields containing the delimiter, quotes or line breaks
+    /// are written as they are, so the output may not read back.
+    Never,
+}
+
+/// Options for writing CSV.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SerializeOptions {
+    /// The byte between fields (default: `,`).
+    pub delimiter: u8,
+    /// When fields are quoted (default: [`QuoteStyle::Necessary`]).
+    pub quote_style: QuoteStyle,
+    /// Whether [`CsvWriter`] and [`to_string_records`] start with a header
+    /// row of field names (default: `true`). Single-record functions never
+    /// write one.
+    pub header: bool,
+}
+
+impl Default for SerializeOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SerializeOptions {
+    /// Comma-separated, quoting only where necessary, with a header.
+    pub const fn new() -> Self {
+        Self {
+            delimiter: b',',
+            quote_style: QuoteStyle::Necessary,
+            header: true,
+        }
+    }
+
+    /// Tab-separated, quoting only where necessary, with a header.
+    pub const fn tsv() -> Self {
+        Self::new().delimiter(b'\t')
+    }
+
+    /// Sets the byte between fields.
+    pub const fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets when fields are quoted.
+    pub const fn quote_style(mut self, quote_style: QuoteStyle) -> Self {
+        self.quote_style = quote_style;
+        self
+    }
+
+    /// Sets whether a header row is written.
+    pub const fn header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+}
+
 /// CSV serializer implementing FormatSerializer.
 pub struct CsvSerializer {
     out: Vec<u8>,
+    options: SerializeOptions,
     in_struct: bool,
     first_field: bool,
+    /// The field being written, for error messages.
+    current_field: Option<String>,
 }
 
 impl CsvSerializer {
     /// Create a new CSV serializer.
     pub const fn new() -> Self {
+        Self::with_options(SerializeOptions::new())
+    }
+
+    /// Create a new CSV serializer with custom options.
+    pub const fn with_options(options: SerializeOptions) -> Self {
         Self {
             out: Vec::new(),
+            options,
             in_struct: false,
             first_field: true,
+            current_field: None,
         }
     }
 
@@ -45,27 +133,65 @@ impl CsvSerializer {
         self.out
     }
 
-    fn write_csv_escaped(&mut self, s: &str) {
-        // Check if we need to quote the field
-        let needs_quoting =
-            s.contains(',') || s.contains('"') || s.contains('\n') || s.contains('\r');
-
-        if needs_quoting {
-            self.out.push(b'"');
-            for c in s.chars() {
-                if c == '"' {
-                    self.out.extend_from_slice(b"\"\"");
-                } else {
-                    let mut buf = [0u8; 4];
-                    let len = c.encode_utf8(&mut buf).len();
-                    self.out.extend_from_slice(&buf[..len]);
-                }
+    /// Writes a header row naming `names`.
+    pub(crate) fn write_header<'a>(&mut self, names: impl Iterator<Item = &'a str>) {
+        for (i, name) in names.enumerate() {
+            if i > 0 {
+                self.out.push(self.options.delimiter);
             }
-            self.out.push(b'"');
+            self.write_text(name);
+        }
+        self.out.push(b'\n');
+    }
+
+    /// Writes a number or boolean.
+    fn write_plain(&mut self, s: &str) {
+        if self.options.quote_style == QuoteStyle::Always {
+            self.write_quoted(s);
+        } else {
+            self.out.extend_from_slice(s.as_bytes());
+        }
+    }
+
+    /// Writes a string.
+    fn write_text(&mut self, s: &str) {
+        let quote = match self.options.quote_style {
+            QuoteStyle::Always | QuoteStyle::NonNumeric => true,
+            QuoteStyle::Never => false,
+            QuoteStyle::Necessary => s
+                .bytes()
+                .any(|b| b == self.options.delimiter || b == b'"' || b == b'\n' || b == b'\r'),
+        };
+        if quote {
+            self.write_quoted(s);
         } else {
             self.out.extend_from_slice(s.as_bytes());
         }
     }
+
+    fn write_quoted(&mut self, s: &str) {
+        self.out.push(b'"');
+        for c in s.chars() {
+            if c == '"' {
+                self.out.extend_from_slice(b"\"\"");
+            } else {
+                let mut buf = [0u8; 4];
+                let len = c.encode_utf8(&mut buf).len();
+                self.out.extend_from_slice(&buf[..len]);
+            }
+        }
+        self.out.push(b'"');
+    }
+
+    /// The error for a nested value of kind `what` in the current field.
+    fn nested(&self, what: &str) -> CsvSerializeError {
+        match &self.current_field {
+            Some(field) => CsvSerializeError::new(format!(
+                "field `{field}` is {what}; CSV columns can only hold scalar values"
+            )),
+            None => CsvSerializeError::new(format!("CSV records must be structs, not {what}")),
+        }
+    }
 }
 
 impl Default for CsvSerializer {
@@ -79,42 +205,38 @@ impl FormatSerializer for CsvSerializer {
 
     fn begin_struct(&mut self) -> Result<(), Self::Error> {
         if self.in_struct {
-            return Err(CsvSerializeError {
-                msg: "CSV does not support nested structures",
-            });
+            return Err(self.nested("a struct or map"));
         }
         self.in_struct = true;
         self.first_field = true;
         Ok(())
     }
 
-    fn field_key(&mut self, _key: &str) -> Result<(), Self::Error> {
+    fn field_key(&mut self, key: &str) -> Result<(), Self::Error> {
         // CSV doesn't output field names, just values
-        // But we need to add comma separators between fields
+        // But we need to add delimiters between fields
         if !self.first_field {
-            self.out.push(b',');
+            self.out.push(self.options.delimiter);
         }
         self.first_field = false;
+        self.current_field = Some(key.into());
         Ok(())
     }
 
     fn end_struct(&mut self) -> Result<(), Self::Error> {
         self.in_struct = false;
+        self.current_field = None;
         // Add newline at end of row
         self.out.push(b'\n');
         Ok(())
     }
 
     fn begin_seq(&mut self) -> Result<(), Self::Error> {
-        Err(CsvSerializeError {
-            msg: "CSV does not support sequences",
-        })
+        Err(self.nested("a sequence"))
     }
 
     fn end_seq(&mut self) -> Result<(), Self::Error> {
-        Err(CsvSerializeError {
-            msg: "CSV does not support sequences",
-        })
+        Err(self.nested("a sequence"))
     }
 
     fn scalar(&mut self, scalar: ScalarValue<'_>) -> Result<(), Self::Error> {
@@ -122,64 +244,49 @@ impl FormatSerializer for CsvSerializer {
             ScalarValue::Null | ScalarValue::Unit => {
                 // Empty field for null
             }
-            ScalarValue::Bool(v) => {
-                if v {
-                    self.out.extend_from_slice(b"true");
-                } else {
-                    self.out.extend_from_slice(b"false");
-                }
-            }
+            ScalarValue::Bool(v) => self.write_plain(if v { "true" } else { "false" }),
             ScalarValue::Char(c) => {
                 let mut buf = [0u8; 4];
-                self.write_csv_escaped(c.encode_utf8(&mut buf));
+                self.write_text(c.encode_utf8(&mut buf));
             }
             ScalarValue::I64(v) => {
                 #[cfg(feature = "fast")]
-                self.out
-                    .extend_from_slice(itoa::Buffer::new().format(v).as_bytes());
+                self.write_plain(itoa::Buffer::new().format(v));
                 #[cfg(not(feature = "fast"))]
-                self.out.extend_from_slice(v.to_string().as_bytes());
+                self.write_plain(&v.to_string());
             }
             ScalarValue::U64(v) => {
                 #[cfg(feature = "fast")]
-                self.out
-                    .extend_from_slice(itoa::Buffer::new().format(v).as_bytes());
+                self.write_plain(itoa::Buffer::new().format(v));
                 #[cfg(not(feature = "fast"))]
-                self.out.extend_from_slice(v.to_string().as_bytes());
+                self.write_plain(&v.to_string());
             }
             ScalarValue::I128(v) => {
                 #[cfg(feature = "fast")]
-                self.out
-                    .extend_from_slice(itoa::Buffer::new().format(v).as_bytes());
+                self.write_plain(itoa::Buffer::new().format(v));
                 #[cfg(not(feature = "fast"))]
-                self.out.extend_from_slice(v.to_string().as_bytes());
+                self.write_plain(&v.to_string());
             }
             ScalarValue::U128(v) => {
                 #[cfg(feature = "fast")]
-                self.out
-                    .extend_from_slice(itoa::Buffer::new().format(v).as_bytes());
+                self.write_plain(itoa::Buffer::new().format(v));
                 #[cfg(not(feature = "fast"))]
-                self.out.extend_from_slice(v.to_string().as_bytes());
+                self.write_plain(&v.to_string());
             }
             ScalarValue::F64(v) => {
                 #[cfg(feature = "fast")]
-                self.out
-                    .extend_from_slice(zmij::Buffer::new().format(v).as_bytes());
+                self.write_plain(zmij::Buffer::new().format(v));
                 #[cfg(not(feature = "fast"))]
-                self.out.extend_from_slice(v.to_string().as_bytes());
+                self.write_plain(&v.to_string());
             }
             ScalarValue::Str(s) => {
-                self.write_csv_escaped(&s);
+                self.write_text(&s);
             }
             ScalarValue::Bytes(_) => {
-                return Err(CsvSerializeError {
-                    msg: "CSV does not support binary data",
-                });
+                return Err(self.nested("binary data"));
             }
             _ => {
-                return Err(CsvSerializeError {
-                    msg: "unsupported scalar value kind",
-                });
+                return Err(CsvSerializeError::new("unsupported scalar value kind"));
             }
         }
         Ok(())
@@ -191,7 +298,21 @@ pub fn to_vec<'facet, T>(value: &T) -> Result<Vec<u8>, SerializeError<CsvSeriali
 where
     T: Facet<'facet> + ?Sized,
 {
-    let mut serializer = CsvSerializer::new();
+    to_vec_with_options(value, SerializeOptions::default())
+}
+
+/// Serialize a value to CSV bytes with custom options.
+///
+/// The value is written as a single record, without a header.
+pub fn to_vec_with_options<'facet, T>(
+    value: &T,
+    options: SerializeOptions,
+) -> Result<Vec<u8>, SerializeError<CsvSerializeError>>
+where
+    T: Facet<'facet> + ?Sized,
+{
+    record_fields(T::SHAPE).map_err(|msg| SerializeError::Unsupported(msg.into()))?;
+    let mut serializer = CsvSerializer::with_options(options);
     serialize_root(&mut serializer, Peek::new(value))?;
     Ok(serializer.finish())
 }
@@ -211,6 +332,150 @@ where
     W: std::io::Write,
     T: Facet<'facet> + ?Sized,
 {
-    let bytes = to_vec(value).map_err(|e| std::io::Error::other(alloc::format!("{:?}", e)))?;
+    let bytes = to_vec(value).map_err(io_error)?;
     writer.write_all(&bytes)
 }
+
+/// Serialize records to a CSV string, starting with a header row.
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+/// use facet_csv::to_string_records;
+///
+/// #[derive(Facet)]
+/// struct Person {
+///     name: String,
+///     #[facet(rename = "years")]
+///     age: u32,
+/// }
+///
+/// let people = [
+///     Person { name: "Alice".into(), age: 30 },
+///     Person { name: "Bob, Jr.".into(), age: 25 },
+/// ];
+/// let csv = to_string_records(&people).unwrap();
+/// assert_eq!(csv, "name,years\nAlice,30\n\"Bob, Jr.\",25\n");
+/// ```
+pub fn to_string_records<'facet, T>(
+    records: &[T],
+) -> Result<String, SerializeError<CsvSerializeError>>
+where
+    T: Facet<'facet>,
+{
+    to_string_records_with_options(records, SerializeOptions::default())
+}
+
+/// Serialize records to a CSV string with custom options.
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+/// use facet_csv::{QuoteStyle, SerializeOptions, to_string_records_with_options};
+///
+/// #[derive(Facet)]
+/// struct Point { x: i32, label: String }
+///
+/// let options = SerializeOptions::tsv().quote_style(QuoteStyle::NonNumeric);
+/// let tsv = to_string_records_with_options(&[Point { x: 1, label: "a".into() }], options).unwrap();
+/// assert_eq!(tsv, "\"x\"\t\"label\"\n1\t\"a\"\n");
+/// ```
+pub fn to_string_records_with_options<'facet, T>(
+    records: &[T],
+    options: SerializeOptions,
+) -> Result<String, SerializeError<CsvSerializeError>>
+where
+    T: Facet<'facet>,
+{
+    let fields = record_fields(T::SHAPE).map_err(|msg| SerializeError::Unsupported(msg.into()))?;
+    let header = options.header;
+    let mut serializer = CsvSerializer::with_options(options);
+    if header {
+        serializer.write_header(header_names(fields));
+    }
+    for record in records {
+        serialize_root(&mut serializer, Peek::new(record))?;
+    }
+    Ok(String::from_utf8(serializer.finish()).expect("CSV output should always be valid UTF-8"))
+}
+
+/// Writes records to an [`std::io::Write`] one at a time.
+///
+/// The header row, if enabled, is written before the first record.
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+/// use facet_csv::CsvWriter;
+///
+/// #[derive(Facet)]
+/// struct Reading { sensor: String, value: f64 }
+///
+/// let mut writer = CsvWriter::new(Vec::new());
+/// writer.write(&Reading { sensor: "t1".into(), value: 20.5 }).unwrap();
+/// writer.write(&Reading { sensor: "t2".into(), value: 19.25 }).unwrap();
+/// let csv = String::from_utf8(writer.into_inner()).unwrap();
+/// assert_eq!(csv, "sensor,value\nt1,20.5\nt2,19.25\n");
+/// ```
+pub struct CsvWriter<W> {
+    writer: W,
+    options: SerializeOptions,
+    header_written: bool,
+}
+
+impl<W: std::io::Write> CsvWriter<W> {
+    /// Writes comma-separated records with a header to `writer`.
+    pub fn new(writer: W) -> Self {
+        Self::with_options(writer, SerializeOptions::default())
+    }
+
+    /// Writes records to `writer` with custom options.
+    pub fn with_options(writer: W, options: SerializeOptions) -> Self {
+        Self {
+            writer,
+            options,
+            header_written: false,
+        }
+    }
+
+    /// Writes one record.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `T` can't be a CSV record (see the
+    /// [crate docs](crate)) or if writing fails.
+    pub fn write<'facet, T>(&mut self, record: &T) -> std::io::Result<()>
+    where
+        T: Facet<'facet>,
+    {
+        let fields = record_fields(T::SHAPE).map_err(std::io::Error::other)?;
+        let mut serializer = CsvSerializer::with_options(self.options.clone());
+        if self.options.header && !self.header_written {
+            serializer.write_header(header_names(fields));
+        }
+        serialize_root(&mut serializer, Peek::new(record)).map_err(io_error)?;
+        self.writer.write_all(&serializer.finish())?;
+        self.header_written = true;
+        Ok(())
+    }
+
+    /// Flushes the underlying writer.
+    pub fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+fn io_error(err: SerializeError<CsvSerializeError>) -> std::io::Error {
+    match err {
+        SerializeError::Backend(err) => std::io::Error::other(err.to_string()),
+        other => std::io::Error::other(other.to_string()),
+    }
+}
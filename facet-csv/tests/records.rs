@@ -0,0 +1,132 @@
+//! Tests for reading CSV documents record by record.
+
+use facet::Facet;
+use facet_csv::{DeserializeError, DeserializeOptions, from_str, from_str_records};
+use facet_format::DeserializeErrorKind;
+
+#[derive(Facet, Debug, PartialEq)]
+struct Person {
+    name: String,
+    #[facet(rename = "years")]
+    age: u32,
+    email: Option<String>,
+}
+
+fn read<T: Facet<'static>>(csv: &str) -> Result<Vec<T>, DeserializeError> {
+    from_str_records(csv).collect()
+}
+
+#[test]
+fn header_columns_match_renamed_fields_in_any_order() {
+    let csv = "email,years,name\nalice@example.com,30,Alice\n,25,Bob\n";
+    let people: Vec<Person> = read(csv).unwrap();
+    assert_eq!(
+        people,
+        [
+            Person {
+                name: "Alice".into(),
+                age: 30,
+                email: Some("alice@example.com".into()),
+            },
+            Person {
+                name: "Bob".into(),
+                age: 25,
+                email: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn missing_option_column_reads_as_none() {
+    let people: Vec<Person> = read("name,years,nickname\nAlice,30,Al\n").unwrap();
+    assert_eq!(people[0].email, None);
+}
+
+#[test]
+fn missing_required_column_is_an_error() {
+    let err = read::<Person>("name,email\nAlice,\n").unwrap_err();
+    assert!(matches!(
+        err.kind,
+        DeserializeErrorKind::MissingField { field: "years", .. }
+    ));
+}
+
+#[test]
+fn quoted_fields_span_lines_and_escape_quotes() {
+    let csv = "name,years\r\n\"Alice \"\"Al\"\" Smith\",30\r\n\"two\nlines\",25\r\n";
+    let people: Vec<Person> = read(csv).unwrap();
+    assert_eq!(people[0].name, "Alice \"Al\" Smith");
+    assert_eq!(people[1].name, "two\nlines");
+    assert_eq!(people[1].age, 25);
+}
+
+#[test]
+fn unterminated_quote_ends_iteration() {
+    let mut records = from_str_records::<Person>("name,years\n\"Alice,30\n");
+    assert!(records.next().unwrap().is_err());
+    assert!(records.next().is_none());
+}
+
+#[test]
+fn bad_record_does_not_stop_the_rest() {
+    let results: Vec<Result<Person, _>> =
+        from_str_records("name,years\nAlice,thirty\nBob,25\n").collect();
+    assert_eq!(results.len(), 2);
+    assert!(results[0].is_err());
+    assert_eq!(results[1].as_ref().unwrap().name, "Bob");
+}
+
+#[test]
+fn short_record_is_an_error() {
+    let err = read::<Person>("name,years\nAlice\n").unwrap_err();
+    assert!(err.to_string().contains("record has 1 fields"), "{err}");
+}
+
+#[test]
+fn blank_lines_are_skipped() {
+    let people: Vec<Person> = read("name,years\n\nAlice,30\n\n").unwrap();
+    assert_eq!(people.len(), 1);
+}
+
+#[test]
+fn tsv_without_headers() {
+    #[derive(Facet, Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    let options = DeserializeOptions::tsv().has_headers(false);
+    let points: Vec<Point> = facet_csv::from_str_records_with_options("1\t2\n-3\t4", options)
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(points, [Point { x: 1, y: 2 }, Point { x: -3, y: 4 }]);
+}
+
+#[test]
+fn nested_fields_are_rejected_by_name() {
+    #[derive(Facet, Debug)]
+    struct Inner {
+        a: u32,
+    }
+
+    #[derive(Facet, Debug)]
+    struct WithList {
+        name: String,
+        tags: Vec<String>,
+    }
+
+    #[derive(Facet, Debug)]
+    struct WithStruct {
+        inner: Inner,
+    }
+
+    let err = read::<WithList>("name,tags\na,b\n").unwrap_err();
+    assert!(err.to_string().contains("field `tags`"), "{err}");
+    assert!(err.to_string().contains("which is a list"), "{err}");
+
+    let err = from_str::<WithStruct>("1").unwrap_err();
+    assert!(err.to_string().contains("field `inner`"), "{err}");
+    assert!(err.to_string().contains("which is a struct"), "{err}");
+}
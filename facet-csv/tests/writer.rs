@@ -0,0 +1,153 @@
+//! Tests for writing CSV documents.
+
+use facet::Facet;
+use facet_csv::{
+    CsvWriter, QuoteStyle, SerializeOptions, from_str_records, to_string, to_string_records,
+    to_string_records_with_options,
+};
+
+#[derive(Facet, Debug, PartialEq)]
+#[repr(u8)]
+enum Status {
+    Active,
+    Retired,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Employee {
+    #[facet(rename = "Full name")]
+    name: String,
+    id: u32,
+    status: Status,
+    manager: Option<String>,
+}
+
+fn employees() -> Vec<Employee> {
+    vec![
+        Employee {
+            name: "Ada \"Countess\" Lovelace".into(),
+            id: 1,
+            status: Status::Retired,
+            manager: None,
+        },
+        Employee {
+            name: "Grace Hopper".into(),
+            id: 2,
+            status: Status::Active,
+            manager: Some("Ada".into()),
+        },
+    ]
+}
+
+#[test]
+fn header_uses_renamed_field_names() {
+    let csv = to_string_records(&employees()).unwrap();
+    assert_eq!(
+        csv,
+        "Full name,id,status,manager\n\
+         \"Ada \"\"Countess\"\" Lovelace\",1,Retired,\n\
+         Grace Hopper,2,Active,Ada\n"
+    );
+}
+
+#[test]
+fn records_round_trip() {
+    let original = employees();
+    let csv = to_string_records(&original).unwrap();
+    let parsed: Vec<Employee> = from_str_records(&csv).collect::<Result<_, _>>().unwrap();
+    assert_eq!(parsed, original);
+}
+
+#[test]
+fn multiline_fields_round_trip() {
+    let original = vec![Employee {
+        name: "first\nsecond, third".into(),
+        id: 7,
+        status: Status::Active,
+        manager: None,
+    }];
+    let csv = to_string_records(&original).unwrap();
+    assert!(csv.contains("\"first\nsecond, third\""));
+    let parsed: Vec<Employee> = from_str_records(&csv).collect::<Result<_, _>>().unwrap();
+    assert_eq!(parsed, original);
+}
+
+#[test]
+fn quote_styles() {
+    let rows = &employees()[1..];
+    let write = |style| {
+        let options = SerializeOptions::new().quote_style(style).header(false);
+        to_string_records_with_options(rows, options).unwrap()
+    };
+
+    assert_eq!(write(QuoteStyle::Necessary), "Grace Hopper,2,Active,Ada\n");
+    assert_eq!(
+        write(QuoteStyle::Always),
+        "\"Grace Hopper\",\"2\",\"Active\",\"Ada\"\n"
+    );
+    assert_eq!(
+        write(QuoteStyle::NonNumeric),
+        "\"Grace Hopper\",2,\"Active\",\"Ada\"\n"
+    );
+    assert_eq!(write(QuoteStyle::Never), "Grace Hopper,2,Active,Ada\n");
+}
+
+#[test]
+fn tsv_quotes_tabs_not_commas() {
+    #[derive(Facet)]
+    struct Note {
+        text: String,
+    }
+
+    let notes = [
+        Note {
+            text: "a, b".into(),
+        },
+        Note {
+            text: "a\tb".into(),
+        },
+    ];
+    let tsv = to_string_records_with_options(&notes, SerializeOptions::tsv()).unwrap();
+    assert_eq!(tsv, "text\na, b\n\"a\tb\"\n");
+}
+
+#[test]
+fn writer_writes_header_once() {
+    let mut writer = CsvWriter::new(Vec::new());
+    for employee in employees() {
+        writer.write(&employee).unwrap();
+    }
+    let csv = String::from_utf8(writer.into_inner()).unwrap();
+    assert_eq!(csv.lines().count(), 3);
+    assert!(csv.starts_with("Full name,id,status,manager\n"));
+}
+
+#[test]
+fn writer_without_header() {
+    let options = SerializeOptions::new().delimiter(b';').header(false);
+    let mut writer = CsvWriter::with_options(Vec::new(), options);
+    writer.write(&employees()[1]).unwrap();
+    let csv = String::from_utf8(writer.into_inner()).unwrap();
+    assert_eq!(csv, "Grace Hopper;2;Active;Ada\n");
+}
+
+#[test]
+fn nested_fields_are_rejected_by_name() {
+    #[derive(Facet)]
+    struct Team {
+        name: String,
+        members: Vec<String>,
+    }
+
+    let team = Team {
+        name: "core".into(),
+        members: vec!["a".into()],
+    };
+
+    let err = to_string(&team).unwrap_err().to_string();
+    assert!(err.contains("field `members`"), "{err}");
+    assert!(err.contains("which is a list"), "{err}");
+
+    let err = CsvWriter::new(Vec::new()).write(&team).unwrap_err();
+    assert!(err.to_string().contains("field `members`"), "{err}");
+}
@@ -64,6 +64,16 @@ pub(crate) struct NestedFlattenedMapInfo {
     pub parent_is_option: bool,
 }
 
+/// Info about a map field marked with `xml::key`, which collects repeated child
+/// elements keyed by one of their attributes.
+#[derive(Clone)]
+pub(crate) struct KeyedMapInfo {
+    /// Info about the map field
+    pub info: FieldInfo,
+    /// Name of the attribute holding each entry's key
+    pub key_attribute: &'static str,
+}
+
 /// Precomputed field lookup map for a struct.
 ///
 /// This separates "what fields does this struct have" from the parsing loop,
@@ -78,6 +88,9 @@ pub(crate) struct StructFieldMap {
     /// Fields marked with `xml::elements` or `html::elements`, keyed by expected element name.
     /// Each field collects child elements matching its singularized name (or rename).
     pub elements_fields: HashMap<String, FieldInfo>,
+    /// Map fields marked with `xml::key`, keyed by element name (lowerCamelCase name
+    /// or rename, plus the singularized form).
+    pub keyed_maps: HashMap<String, KeyedMapInfo>,
     /// The field marked with `xml::attribute` as a catch-all (collects all unmatched attribute values)
     pub attributes_field: Option<FieldInfo>,
     /// The field marked with `xml::text` (collects text content)
@@ -86,6 +99,9 @@ pub(crate) struct StructFieldMap {
     pub tag_field: Option<FieldInfo>,
     /// The field marked with `xml::doctype` (captures DOCTYPE declaration)
     pub doctype_field: Option<FieldInfo>,
+    /// The field marked with `xml::processing_instruction` (collects processing
+    /// instructions before the root element)
+    pub processing_instruction_field: Option<FieldInfo>,
     /// The field marked with `#[facet(other)]` (fallback when root doesn't match)
     pub other_field: Option<FieldInfo>,
     /// For tuple structs: fields in order for positional matching.
@@ -159,10 +175,12 @@ impl StructFieldMap {
         let mut attribute_fields: HashMap<String, Vec<FieldInfo>> = HashMap::new();
         let mut element_fields: HashMap<String, Vec<FieldInfo>> = HashMap::new();
         let mut elements_fields: HashMap<String, FieldInfo> = HashMap::new();
+        let mut keyed_maps: HashMap<String, KeyedMapInfo> = HashMap::new();
         let mut attributes_field = None;
         let mut text_field = None;
         let mut tag_field = None;
         let mut doctype_field = None;
+        let mut processing_instruction_field = None;
         let mut other_field = None;
         let mut flattened_children: HashMap<String, Vec<FlattenedChildInfo>> = HashMap::new();
         let mut flattened_attributes: HashMap<String, Vec<FlattenedChildInfo>> = HashMap::new();
@@ -421,6 +439,32 @@ impl StructFieldMap {
                     let element_key = singularize(&field_dom_key(field.name, None, rename_all));
                     elements_fields.insert(element_key, info);
                 };
+            } else if let Some(key_attribute) = field
+                .get_attr(Some("xml"), "key")
+                .and_then(|attr| attr.get_as::<&str>().copied())
+            {
+                // xml::key - each matching child element is one map entry, keyed by an attribute.
+                // Element name follows the flat list model: field name or rename, and the
+                // singularized form (e.g., field "servers" matches <server>)
+                let keyed = KeyedMapInfo {
+                    info: FieldInfo {
+                        idx,
+                        field,
+                        is_list: false,
+                        is_array: false,
+                        is_set: false,
+                        is_tuple: false,
+                        namespace: namespace.or(ns_all),
+                    },
+                    key_attribute,
+                };
+                if field.rename.is_none() {
+                    let singular_key = singularize(&element_key);
+                    if singular_key != element_key {
+                        keyed_maps.insert(singular_key, keyed.clone());
+                    }
+                }
+                keyed_maps.insert(element_key.into_owned(), keyed);
             } else if field.is_text() {
                 let info = FieldInfo {
                     idx,
@@ -454,6 +498,17 @@ impl StructFieldMap {
                     namespace,
                 };
                 doctype_field = Some(info);
+            } else if field.is_processing_instruction() {
+                let info = FieldInfo {
+                    idx,
+                    field,
+                    is_list,
+                    is_array,
+                    is_set,
+                    is_tuple,
+                    namespace,
+                };
+                processing_instruction_field = Some(info);
             } else {
                 // Check if this field is marked as "other" - if so, register it as the fallback
                 // for tag mismatches, but ALSO register it as a normal element field so it
@@ -542,10 +597,12 @@ impl StructFieldMap {
             attribute_fields,
             element_fields,
             elements_fields,
+            keyed_maps,
             attributes_field,
             text_field,
             tag_field,
             doctype_field,
+            processing_instruction_field,
             other_field,
             tuple_fields,
             flattened_children,
@@ -601,6 +658,13 @@ impl StructFieldMap {
         })
     }
 
+    /// Find an `xml::key` map field by tag name and namespace.
+    pub fn find_keyed_map(&self, tag: &str, namespace: Option<&str>) -> Option<&KeyedMapInfo> {
+        self.keyed_maps
+            .get(tag)
+            .filter(|keyed| keyed.info.namespace.is_none() || keyed.info.namespace == namespace)
+    }
+
     /// Find a flattened child field by tag name and namespace.
     ///
     /// Returns `Some` if the name matches a child field from a flattened struct.
@@ -675,6 +739,14 @@ impl StructFieldMap {
             .filter(move |info| seen.insert(info.idx))
             .map(|info| (info.idx, info))
     }
+
+    /// Iterate over `xml::key` map fields, once per field.
+    pub fn keyed_map_fields(&self) -> impl Iterator<Item = &KeyedMapInfo> {
+        let mut seen = std::collections::HashSet::new();
+        self.keyed_maps
+            .values()
+            .filter(move |keyed| seen.insert(keyed.info.idx))
+    }
 }
 
 /// Check if a flattened field is an enum type.
@@ -826,7 +898,7 @@ fn get_item_type_proxy_enum(
     let item_shape = get_item_shape(shape)?;
 
     // Check if the item type has a proxy
-    let proxy_def = item_shape.effective_proxy(format_ns)?;
+    let proxy_def = item_shape.deserialize_proxy(format_ns)?;
     let proxy_shape = proxy_def.shape;
 
     // Check if the proxy type is an enum
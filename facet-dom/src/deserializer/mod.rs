@@ -115,7 +115,7 @@ where
         // Check for container-level proxy (e.g., #[facet(xml::proxy = ProxyType)] on the type)
         // If present, we deserialize into the proxy type, then convert via TryFrom.
         // The expected_name is preserved - it controls the XML element name, not the type.
-        if wip.shape().effective_proxy(format_ns).is_some() {
+        if wip.shape().deserialize_proxy(format_ns).is_some() {
             let (proxy_wip, found) = wip
                 .begin_custom_deserialization_from_shape_with_format(format_ns)
                 .map_err(DomDeserializeError::Reflect)?;
@@ -336,11 +336,15 @@ where
                 } else {
                     // For tagged enums, match the element tag against variant names.
                     // Compute effective element name: use rename attribute if present,
-                    // otherwise convert to lowerCamelCase.
+                    // otherwise convert to lowerCamelCase. CDATA and processing
+                    // instruction variants never come from elements.
                     enum_def
                         .variants
                         .iter()
                         .position(|v| {
+                            if v.is_cdata() || v.is_processing_instruction() {
+                                return false;
+                            }
                             let effective_name: Cow<'_, str> = if v.rename.is_some() {
                                 Cow::Borrowed(v.effective_name())
                             } else {
@@ -409,9 +413,14 @@ where
                     }
                 }
             }
-            DomEvent::Text(_) => {
+            DomEvent::Text(_) | DomEvent::CData(_) => {
+                let cdata = event.is_cdata();
                 let text = self.parser.expect_text()?;
-                wip = self.deserialize_text_into_enum(wip, text)?;
+                wip = self.deserialize_text_into_enum(wip, text, cdata)?;
+            }
+            DomEvent::ProcessingInstruction { .. } => {
+                let (target, data) = self.parser.expect_processing_instruction()?;
+                wip = self.deserialize_processing_instruction_into_enum(wip, target, data)?;
             }
             other => {
                 return Err(DomDeserializeError::TypeMismatch {
@@ -424,7 +433,8 @@ where
         Ok(wip)
     }
 
-    /// Deserialize text content into an enum by selecting the `#[xml::text]` variant.
+    /// Deserialize text content into an enum by selecting the `#[xml::text]` variant,
+    /// or the `#[xml::cdata]` one for a CDATA section if the enum has it.
     ///
     /// # Parser State Contract
     ///
@@ -439,6 +449,7 @@ where
         &mut self,
         mut wip: Partial<'de, BORROW>,
         text: Cow<'de, str>,
+        cdata: bool,
     ) -> Result<Partial<'de, BORROW>, DomDeserializeError<P::Error>> {
         let enum_def = match &wip.shape().ty {
             Type::User(UserType::Enum(def)) => def,
@@ -447,7 +458,14 @@ where
             }
         };
 
-        let text_variant_idx = match enum_def.variants.iter().position(|v| v.is_text()) {
+        let cdata_variant_idx = if cdata {
+            enum_def.variants.iter().position(|v| v.is_cdata())
+        } else {
+            None
+        };
+        let text_variant_idx = match cdata_variant_idx
+            .or_else(|| enum_def.variants.iter().position(|v| v.is_text()))
+        {
             Some(idx) => idx,
             None => {
                 // No text variant - either error (XML) or silently discard (HTML)
@@ -484,6 +502,67 @@ where
         Ok(wip)
     }
 
+    /// Deserialize a processing instruction into an enum by selecting the
+    /// `#[xml::processing_instruction]` variant.
+    ///
+    /// # Parser State Contract
+    ///
+    /// **Entry:** The instruction has already been consumed from the parser (passed as arguments).
+    ///
+    /// **Exit:** No parser state change.
+    fn deserialize_processing_instruction_into_enum(
+        &mut self,
+        mut wip: Partial<'de, BORROW>,
+        target: Cow<'de, str>,
+        data: Cow<'de, str>,
+    ) -> Result<Partial<'de, BORROW>, DomDeserializeError<P::Error>> {
+        let Type::User(UserType::Enum(enum_def)) = &wip.shape().ty else {
+            return Err(DomDeserializeError::Unsupported(
+                "processing instructions can only be kept in enums".into(),
+            ));
+        };
+        let Some(variant_idx) = enum_def
+            .variants
+            .iter()
+            .position(|v| v.is_processing_instruction())
+        else {
+            return Err(DomDeserializeError::Unsupported(
+                "enum has no processing_instruction variant".into(),
+            ));
+        };
+
+        let variant = &enum_def.variants[variant_idx];
+        wip = wip.select_nth_variant(variant_idx)?;
+
+        if variant.data.kind == StructKind::TupleStruct && variant.data.fields.len() == 1 {
+            // Newtype variant like ProcessingInstruction(ProcessingInstruction)
+            wip = wip.begin_nth_field(0)?;
+            wip = self.set_processing_instruction(wip, target, data)?;
+            wip = wip.end()?;
+        } else {
+            wip = self.set_processing_instruction(wip, target, data)?;
+        }
+
+        Ok(wip)
+    }
+
+    /// Set a processing instruction into a struct (or struct variant) whose first
+    /// two fields are its target and data.
+    pub(crate) fn set_processing_instruction(
+        &mut self,
+        mut wip: Partial<'de, BORROW>,
+        target: Cow<'de, str>,
+        data: Cow<'de, str>,
+    ) -> Result<Partial<'de, BORROW>, DomDeserializeError<P::Error>> {
+        wip = self
+            .set_string_value(wip.begin_nth_field(0)?, target)?
+            .end()?;
+        wip = self
+            .set_string_value(wip.begin_nth_field(1)?, data)?
+            .end()?;
+        Ok(wip)
+    }
+
     /// Deserialize RawMarkup by capturing raw source from the parser.
     fn deserialize_raw_markup(
         &mut self,
@@ -542,7 +621,7 @@ where
         let event = self.parser.peek_event_or_eof("Text or NodeStart")?;
         trace!(event = ?event, "peeked event in deserialize_scalar");
         match event {
-            DomEvent::Text(_) => {
+            DomEvent::Text(_) | DomEvent::CData(_) => {
                 trace!("deserialize_scalar: matched Text arm");
                 let text = self.parser.expect_text()?;
                 // Use set_string_value_with_proxy for format-specific proxy support
@@ -594,7 +673,7 @@ where
                     let event = self.parser.peek_event_or_eof("Text or ChildrenEnd")?;
                     trace!(event = ?event, "deserialize_scalar: in text content loop");
                     match event {
-                        DomEvent::Text(_) => {
+                        DomEvent::Text(_) | DomEvent::CData(_) => {
                             let text = self.parser.expect_text()?;
                             trace!(text = %text, "deserialize_scalar: got text");
                             text_content.push_str(&text);
@@ -612,6 +691,9 @@ where
                         DomEvent::Comment(_) => {
                             let _comment = self.parser.expect_comment()?;
                         }
+                        DomEvent::ProcessingInstruction { .. } => {
+                            let _pi = self.parser.expect_processing_instruction()?;
+                        }
                         other => {
                             return Err(DomDeserializeError::TypeMismatch {
                                 expected: "Text or ChildrenEnd",
@@ -779,13 +861,13 @@ where
                     // Deserialize the value (element content)
                     wip = wip.begin_value()?.deserialize_with(self)?.end()?;
                 }
-                DomEvent::Text(_) | DomEvent::Comment(_) => {
-                    // Skip whitespace text and comments between map entries
-                    if matches!(event, DomEvent::Text(_)) {
-                        self.parser.expect_text()?;
-                    } else {
-                        self.parser.expect_comment()?;
-                    }
+                DomEvent::Text(_)
+                | DomEvent::CData(_)
+                | DomEvent::Comment(_)
+                | DomEvent::ProcessingInstruction { .. } => {
+                    // Skip whitespace text, comments and processing instructions
+                    // between map entries
+                    self.parser.next_event_or_eof("map entry element")?;
                 }
                 _ => {
                     return Err(DomDeserializeError::TypeMismatch {
@@ -954,7 +1036,7 @@ where
             wip = self.set_string_value(wip, value)?;
             wip = wip.end()?;
             Ok(wip)
-        } else if wip.shape().effective_proxy(format_ns).is_some() {
+        } else if wip.shape().deserialize_proxy(format_ns).is_some() {
             // The target shape has a container-level proxy
             // Use begin_custom_deserialization_from_shape_with_format
             let (new_wip, _) =
@@ -12,8 +12,8 @@ use crate::{AttributeRecord, DomEvent, DomParser, DomParserExt};
 
 use super::PartialDeserializeExt;
 use super::field_map::{
-    FieldInfo, FlattenedChildInfo, StructFieldMap, get_item_type_default_element_name,
-    get_item_type_rename,
+    FieldInfo, FlattenedChildInfo, KeyedMapInfo, StructFieldMap,
+    get_item_type_default_element_name, get_item_type_rename,
 };
 
 /// State for a flat sequence field being deserialized.
@@ -75,6 +75,9 @@ pub(crate) struct StructDeserializer<'de, 'p, const BORROW: bool, P: DomParser<'
     /// Which flattened attribute maps have been initialized
     started_flattened_attr_maps: HashSet<usize>,
 
+    /// Which `xml::key` maps have received at least one entry
+    started_keyed_maps: HashSet<usize>,
+
     /// Whether we've ever started the flattened enum list (for `Vec<Enum>` with flatten)
     flattened_enum_list_started: bool,
 
@@ -119,6 +122,7 @@ impl<'de, 'p, const BORROW: bool, P: DomParser<'de>> StructDeserializer<'de, 'p,
             attributes_list_started: false,
             started_flattened_maps: HashSet::new(),
             started_flattened_attr_maps: HashSet::new(),
+            started_keyed_maps: HashSet::new(),
             flattened_enum_list_started: false,
             flattened_enum_list_active: false,
             deny_unknown_fields,
@@ -143,43 +147,50 @@ impl<'de, 'p, const BORROW: bool, P: DomParser<'de>> StructDeserializer<'de, 'p,
             self.using_deferred = true;
         }
 
-        // Handle DOCTYPE if present and if struct has a doctype field
-        let has_doctype_field = self.field_map.doctype_field.is_some();
-        if has_doctype_field {
-            // Peek to see if there's a Doctype event
-            if let Ok(event) = self.parser().peek_event()
-                && let Some(DomEvent::Doctype(doctype_content)) = event
-            {
-                // Clone the content before consuming the event
-                let doctype = doctype_content.to_string();
-                // Consume the Doctype event
-                let _ = self
-                    .parser()
-                    .next_event()
-                    .map_err(DomDeserializeError::Parser)?;
-                let idx = self.field_map.doctype_field.as_ref().unwrap().idx;
-                trace!(
-                    "→ .{} (doctype)",
-                    self.field_map.doctype_field.as_ref().unwrap().field.name
-                );
-                wip = self
-                    .dom_deser
-                    .set_string_value(wip.begin_nth_field(idx)?, Cow::Owned(doctype))?
-                    .end()?;
-            }
-        } else {
-            // No doctype field - skip any DOCTYPE events
-            while let Ok(event) = self.parser().peek_event() {
-                if let Some(DomEvent::Doctype(_)) = event {
+        // Handle what comes before the element: a DOCTYPE, kept if the struct has a
+        // doctype field, and processing instructions, kept if it has a
+        // processing_instruction field
+        let mut processing_instructions = Vec::new();
+        while let Ok(Some(event)) = self.parser().peek_event() {
+            match event {
+                DomEvent::Doctype(doctype_content) => {
+                    // Clone the content before consuming the event
+                    let doctype = doctype_content.to_string();
+                    // Consume the Doctype event
                     let _ = self
                         .parser()
                         .next_event()
                         .map_err(DomDeserializeError::Parser)?;
-                } else {
-                    break;
+                    if let Some(info) = &self.field_map.doctype_field {
+                        trace!("→ .{} (doctype)", info.field.name);
+                        let field = wip.begin_nth_field(info.idx)?;
+                        wip = self
+                            .dom_deser
+                            .set_string_value(field, Cow::Owned(doctype))?
+                            .end()?;
+                    }
+                }
+                DomEvent::ProcessingInstruction { .. } => {
+                    processing_instructions.push(self.parser().expect_processing_instruction()?);
+                }
+                DomEvent::Comment(_) => {
+                    self.parser().expect_comment()?;
                 }
+                _ => break,
             }
         }
+        if let Some(info) = &self.field_map.processing_instruction_field {
+            trace!("→ .{} (processing instructions)", info.field.name);
+            wip = wip.begin_nth_field(info.idx)?.init_list()?;
+            for (target, data) in processing_instructions {
+                wip = wip.begin_list_item()?;
+                wip = self
+                    .dom_deser
+                    .set_processing_instruction(wip, target, data)?
+                    .end()?;
+            }
+            wip = wip.end()?;
+        }
 
         self.tag = self.parser().expect_node_start()?;
 
@@ -247,132 +258,8 @@ impl<'de, 'p, const BORROW: bool, P: DomParser<'de>> StructDeserializer<'de, 'p,
                 .peek_event_or_eof("Attribute or ChildrenStart")?
             {
                 DomEvent::Attribute { .. } => {
-                    let AttributeRecord {
-                        name,
-                        value,
-                        namespace,
-                    } = self.parser().expect_attribute()?;
-                    if let Some(info) = self
-                        .field_map
-                        .find_attribute(&name, namespace.as_ref().map(|c| c.as_ref()))
-                    {
-                        trace!("→ .{}", info.field.name);
-                        // Use set_string_value_with_proxy to handle field-level proxies
-                        wip = self
-                            .dom_deser
-                            .set_string_value_with_proxy(wip.begin_nth_field(info.idx)?, value)?
-                            .end()?;
-                    } else if let Some(flattened) = self
-                        .field_map
-                        .find_flattened_attribute(&name, namespace.as_ref().map(|c| c.as_ref()))
-                        .cloned()
-                    {
-                        // Handle attribute from a flattened struct (e.g., GlobalAttrs)
-                        trace!("→ (flatten).{}", flattened.child_info.field.name);
-
-                        // Navigate into the flattened parent, then to the child field
-                        let parent_idx = flattened.parent_idx;
-                        let child_idx = flattened.child_idx;
-                        let parent_wip = wip.begin_nth_field(parent_idx)?;
-                        let parent_wip = if flattened.parent_is_option {
-                            parent_wip.begin_some()?
-                        } else {
-                            parent_wip
-                        };
-                        wip = self
-                            .dom_deser
-                            .set_string_value_with_proxy(
-                                parent_wip.begin_nth_field(child_idx)?,
-                                value,
-                            )?
-                            .end()?;
-                        if flattened.parent_is_option {
-                            wip = wip.end()?;
-                        }
-                        wip = wip.end()?;
-                    } else if let Some(info) = &self.field_map.attributes_field {
-                        // Catch-all Vec<String> for all attribute values
-                        if !self.attributes_list_started {
-                            trace!("→ .{}[]", info.field.name);
-                            wip = wip.begin_nth_field(info.idx)?.init_list()?;
-                            self.attributes_list_started = true;
-                        }
-                        wip = wip.begin_list_item()?;
-                        wip = self.dom_deser.set_string_value(wip, value)?.end()?;
-                    } else {
-                        // Try to add to flattened attribute map (direct or nested)
-                        let mut handled = false;
-
-                        // First try direct flattened attr maps
-                        if !self.field_map.flattened_attr_maps.is_empty() {
-                            let map_info = self.field_map.flattened_attr_maps.iter().find(|info| {
-                                info.namespace.is_none()
-                                    || info.namespace == namespace.as_ref().map(|c| c.as_ref())
-                            });
-
-                            if let Some(info) = map_info {
-                                trace!("→ .{}[{}]", info.field.name, name);
-                                self.started_flattened_attr_maps.insert(info.idx);
-                                wip = wip
-                                    .begin_nth_field(info.idx)?
-                                    .init_map()?
-                                    .begin_key()?
-                                    .set::<String>(name.to_string())?
-                                    .end()?
-                                    .begin_value()?
-                                    .set::<String>(value.to_string())?
-                                    .end()?
-                                    .end()?;
-                                handled = true;
-                            }
-                        }
-
-                        // Then try nested flattened attr maps (e.g., flattened struct with flattened HashMap inside)
-                        if !handled && !self.field_map.nested_flattened_attr_maps.is_empty() {
-                            let nested_info = self
-                                .field_map
-                                .nested_flattened_attr_maps
-                                .iter()
-                                .find(|info| {
-                                    info.child_info.namespace.is_none()
-                                        || info.child_info.namespace
-                                            == namespace.as_ref().map(|c| c.as_ref())
-                                });
-
-                            if let Some(info) = nested_info {
-                                trace!("→ (flatten).{}[{}]", info.child_info.field.name, name);
-
-                                // Navigate to parent field, then child field
-                                wip = wip.begin_nth_field(info.parent_idx)?;
-                                if info.parent_is_option {
-                                    wip = wip.begin_some()?;
-                                }
-                                // Always call init_map() - in deferred mode it's idempotent
-                                wip = wip
-                                    .begin_nth_field(info.child_idx)?
-                                    .init_map()?
-                                    .begin_key()?
-                                    .set::<String>(name.to_string())?
-                                    .end()?
-                                    .begin_value()?
-                                    .set::<String>(value.to_string())?
-                                    .end()?
-                                    .end()?;
-                                // End parent (and option if needed)
-                                if info.parent_is_option {
-                                    wip = wip.end()?;
-                                }
-                                wip = wip.end()?;
-                                handled = true;
-                            }
-                        }
-
-                        if !handled && self.deny_unknown_fields {
-                            return Err(DomDeserializeError::UnknownAttribute {
-                                name: name.to_string(),
-                            });
-                        }
-                    }
+                    let attribute = self.parser().expect_attribute()?;
+                    wip = self.apply_attribute(wip, attribute)?;
                 }
                 DomEvent::ChildrenStart => {
                     break;
@@ -392,6 +279,137 @@ impl<'de, 'p, const BORROW: bool, P: DomParser<'de>> StructDeserializer<'de, 'p,
         Ok(wip)
     }
 
+    /// Apply a single attribute of this element to the matching field.
+    fn apply_attribute(
+        &mut self,
+        mut wip: Partial<'de, BORROW>,
+        attribute: AttributeRecord<'de>,
+    ) -> Result<Partial<'de, BORROW>, DomDeserializeError<P::Error>> {
+        let AttributeRecord {
+            name,
+            value,
+            namespace,
+        } = attribute;
+        if let Some(info) = self
+            .field_map
+            .find_attribute(&name, namespace.as_ref().map(|c| c.as_ref()))
+        {
+            trace!("→ .{}", info.field.name);
+            // Use set_string_value_with_proxy to handle field-level proxies
+            wip = self
+                .dom_deser
+                .set_string_value_with_proxy(wip.begin_nth_field(info.idx)?, value)?
+                .end()?;
+        } else if let Some(flattened) = self
+            .field_map
+            .find_flattened_attribute(&name, namespace.as_ref().map(|c| c.as_ref()))
+            .cloned()
+        {
+            // Handle attribute from a flattened struct (e.g., GlobalAttrs)
+            trace!("→ (flatten).{}", flattened.child_info.field.name);
+
+            // Navigate into the flattened parent, then to the child field
+            let parent_idx = flattened.parent_idx;
+            let child_idx = flattened.child_idx;
+            let parent_wip = wip.begin_nth_field(parent_idx)?;
+            let parent_wip = if flattened.parent_is_option {
+                parent_wip.begin_some()?
+            } else {
+                parent_wip
+            };
+            wip = self
+                .dom_deser
+                .set_string_value_with_proxy(parent_wip.begin_nth_field(child_idx)?, value)?
+                .end()?;
+            if flattened.parent_is_option {
+                wip = wip.end()?;
+            }
+            wip = wip.end()?;
+        } else if let Some(info) = &self.field_map.attributes_field {
+            // Catch-all Vec<String> for all attribute values
+            if !self.attributes_list_started {
+                trace!("→ .{}[]", info.field.name);
+                wip = wip.begin_nth_field(info.idx)?.init_list()?;
+                self.attributes_list_started = true;
+            }
+            wip = wip.begin_list_item()?;
+            wip = self.dom_deser.set_string_value(wip, value)?.end()?;
+        } else {
+            // Try to add to flattened attribute map (direct or nested)
+            let mut handled = false;
+
+            // First try direct flattened attr maps
+            if !self.field_map.flattened_attr_maps.is_empty() {
+                let map_info = self.field_map.flattened_attr_maps.iter().find(|info| {
+                    info.namespace.is_none()
+                        || info.namespace == namespace.as_ref().map(|c| c.as_ref())
+                });
+
+                if let Some(info) = map_info {
+                    trace!("→ .{}[{}]", info.field.name, name);
+                    self.started_flattened_attr_maps.insert(info.idx);
+                    wip = wip
+                        .begin_nth_field(info.idx)?
+                        .init_map()?
+                        .begin_key()?
+                        .set::<String>(name.to_string())?
+                        .end()?
+                        .begin_value()?
+                        .set::<String>(value.to_string())?
+                        .end()?
+                        .end()?;
+                    handled = true;
+                }
+            }
+
+            // Then try nested flattened attr maps (e.g., flattened struct with flattened HashMap inside)
+            if !handled && !self.field_map.nested_flattened_attr_maps.is_empty() {
+                let nested_info = self
+                    .field_map
+                    .nested_flattened_attr_maps
+                    .iter()
+                    .find(|info| {
+                        info.child_info.namespace.is_none()
+                            || info.child_info.namespace == namespace.as_ref().map(|c| c.as_ref())
+                    });
+
+                if let Some(info) = nested_info {
+                    trace!("→ (flatten).{}[{}]", info.child_info.field.name, name);
+
+                    // Navigate to parent field, then child field
+                    wip = wip.begin_nth_field(info.parent_idx)?;
+                    if info.parent_is_option {
+                        wip = wip.begin_some()?;
+                    }
+                    // Always call init_map() - in deferred mode it's idempotent
+                    wip = wip
+                        .begin_nth_field(info.child_idx)?
+                        .init_map()?
+                        .begin_key()?
+                        .set::<String>(name.to_string())?
+                        .end()?
+                        .begin_value()?
+                        .set::<String>(value.to_string())?
+                        .end()?
+                        .end()?;
+                    // End parent (and option if needed)
+                    if info.parent_is_option {
+                        wip = wip.end()?;
+                    }
+                    wip = wip.end()?;
+                    handled = true;
+                }
+            }
+
+            if !handled && self.deny_unknown_fields {
+                return Err(DomDeserializeError::UnknownAttribute {
+                    name: name.to_string(),
+                });
+            }
+        }
+        Ok(wip)
+    }
+
     fn process_children(
         &mut self,
         mut wip: Partial<'de, BORROW>,
@@ -401,9 +419,12 @@ impl<'de, 'p, const BORROW: bool, P: DomParser<'de>> StructDeserializer<'de, 'p,
                 DomEvent::ChildrenEnd => {
                     break;
                 }
-                DomEvent::Text(_) => {
+                DomEvent::Text(_) | DomEvent::CData(_) => {
                     wip = self.handle_text(wip)?;
                 }
+                DomEvent::ProcessingInstruction { .. } => {
+                    wip = self.handle_processing_instruction(wip)?;
+                }
                 DomEvent::NodeStart { tag, namespace } => {
                     let tag = tag.clone();
                     let namespace = namespace.clone();
@@ -431,6 +452,16 @@ impl<'de, 'p, const BORROW: bool, P: DomParser<'de>> StructDeserializer<'de, 'p,
         }
     }
 
+    /// Check if an enum shape has a processing instruction variant.
+    fn enum_has_processing_instruction_variant(shape: &Shape) -> bool {
+        match &shape.ty {
+            Type::User(UserType::Enum(def)) => {
+                def.variants.iter().any(|v| v.is_processing_instruction())
+            }
+            _ => false,
+        }
+    }
+
     /// Get the inner element shape from a list/vec field shape.
     fn get_list_element_shape(shape: &Shape) -> Option<&'static Shape> {
         match &shape.def {
@@ -443,6 +474,7 @@ impl<'de, 'p, const BORROW: bool, P: DomParser<'de>> StructDeserializer<'de, 'p,
         &mut self,
         mut wip: Partial<'de, BORROW>,
     ) -> Result<Partial<'de, BORROW>, DomDeserializeError<P::Error>> {
+        let cdata = matches!(self.parser().peek_event(), Ok(Some(DomEvent::CData(_))));
         let text = self.parser().expect_text()?;
 
         if !self.active_elements_lists.is_empty() {
@@ -463,7 +495,7 @@ impl<'de, 'p, const BORROW: bool, P: DomParser<'de>> StructDeserializer<'de, 'p,
                 wip = wip.begin_list_item()?;
                 wip = self
                     .dom_deser
-                    .deserialize_text_into_enum(wip, text)?
+                    .deserialize_text_into_enum(wip, text, cdata)?
                     .end()?;
             }
             // else: lenient mode and no text variant - silently discard
@@ -516,12 +548,14 @@ impl<'de, 'p, const BORROW: bool, P: DomParser<'de>> StructDeserializer<'de, 'p,
                 wip = wip.begin_list_item()?;
                 wip = self
                     .dom_deser
-                    .deserialize_text_into_enum(wip, text)?
+                    .deserialize_text_into_enum(wip, text, cdata)?
                     .end()?;
             } else {
                 // Single enum field with text
                 wip = wip.begin_nth_field(field_idx)?;
-                wip = self.dom_deser.deserialize_text_into_enum(wip, text)?;
+                wip = self
+                    .dom_deser
+                    .deserialize_text_into_enum(wip, text, cdata)?;
                 wip = wip.end()?;
             }
         } else if self.struct_def.kind == StructKind::TupleStruct
@@ -536,6 +570,51 @@ impl<'de, 'p, const BORROW: bool, P: DomParser<'de>> StructDeserializer<'de, 'p,
         Ok(wip)
     }
 
+    /// Keep a processing instruction in a flattened enum list with an
+    /// `xml::processing_instruction` variant, or skip it.
+    fn handle_processing_instruction(
+        &mut self,
+        mut wip: Partial<'de, BORROW>,
+    ) -> Result<Partial<'de, BORROW>, DomDeserializeError<P::Error>> {
+        let (target, data) = self.parser().expect_processing_instruction()?;
+
+        let Some(enum_info) = &self.field_map.flattened_enum else {
+            return Ok(wip);
+        };
+        let can_accept = self.active_elements_lists.is_empty()
+            && enum_info.field_info.is_list
+            && Self::get_list_element_shape(enum_info.field_info.field.shape())
+                .is_some_and(Self::enum_has_processing_instruction_variant);
+        if !can_accept {
+            return Ok(wip);
+        }
+
+        let field_idx = enum_info.field_idx;
+        if !self.flattened_enum_list_started {
+            trace!(
+                field_idx,
+                "starting flattened enum list for processing instruction"
+            );
+            wip = wip.begin_nth_field(field_idx)?.init_list()?;
+            self.flattened_enum_list_started = true;
+            self.flattened_enum_list_active = true;
+        } else if !self.flattened_enum_list_active {
+            trace!(
+                field_idx,
+                "re-entering flattened enum list for processing instruction"
+            );
+            wip = wip.begin_nth_field(field_idx)?.init_list()?;
+            self.flattened_enum_list_active = true;
+        }
+
+        wip = wip.begin_list_item()?;
+        wip = self
+            .dom_deser
+            .deserialize_processing_instruction_into_enum(wip, target, data)?
+            .end()?;
+        Ok(wip)
+    }
+
     fn handle_child_element(
         &mut self,
         wip: Partial<'de, BORROW>,
@@ -563,6 +642,8 @@ impl<'de, 'p, const BORROW: bool, P: DomParser<'de>> StructDeserializer<'de, 'p,
             } else {
                 self.handle_scalar_element(wip, info.idx)
             }
+        } else if let Some(keyed) = self.field_map.find_keyed_map(tag, namespace).cloned() {
+            self.handle_keyed_map_entry(wip, &keyed)
         } else if self.field_map.is_tuple() && tag == "item" {
             // Legacy support for <item> elements in tuple structs (deprecated)
             self.handle_tuple_item(wip)
@@ -1037,6 +1118,126 @@ impl<'de, 'p, const BORROW: bool, P: DomParser<'de>> StructDeserializer<'de, 'p,
         }
     }
 
+    /// Deserialize one `xml::key` map entry: the key comes from an attribute, and the
+    /// rest of the element becomes the value.
+    fn handle_keyed_map_entry(
+        &mut self,
+        mut wip: Partial<'de, BORROW>,
+        keyed: &KeyedMapInfo,
+    ) -> Result<Partial<'de, BORROW>, DomDeserializeError<P::Error>> {
+        let idx = keyed.info.idx;
+        wip = self.leave_active_sequence(wip)?;
+
+        let tag = self.parser().expect_node_start()?;
+
+        // Attributes arrive before children, and the key must be set before the value,
+        // so buffer the attributes until the key is found and replay the rest.
+        let mut attributes = Vec::new();
+        while let DomEvent::Attribute { .. } = self
+            .parser()
+            .peek_event_or_eof("Attribute or ChildrenStart")?
+        {
+            attributes.push(self.parser().expect_attribute()?);
+        }
+        let key = match attributes
+            .iter()
+            .position(|attr| attr.name == keyed.key_attribute)
+        {
+            Some(pos) => attributes.remove(pos).value,
+            None => {
+                return Err(DomDeserializeError::MissingAttribute {
+                    name: keyed.key_attribute,
+                });
+            }
+        };
+        trace!(idx, field_name = %keyed.info.field.name, key = %key, "adding to keyed map");
+
+        self.started_keyed_maps.insert(idx);
+        wip = wip.begin_nth_field(idx)?.init_map()?.begin_key()?;
+        wip = self
+            .dom_deser
+            .set_string_value(wip, key)?
+            .end()?
+            .begin_value()?;
+        wip = self.deserialize_keyed_map_value(wip, tag, attributes)?;
+        wip = wip.end()?.end()?;
+        Ok(wip)
+    }
+
+    /// Deserialize the value of an `xml::key` map entry after its `NodeStart` and
+    /// attributes have been consumed. The value must be a struct.
+    fn deserialize_keyed_map_value(
+        &mut self,
+        mut wip: Partial<'de, BORROW>,
+        tag: Cow<'de, str>,
+        attributes: Vec<AttributeRecord<'de>>,
+    ) -> Result<Partial<'de, BORROW>, DomDeserializeError<P::Error>> {
+        let shape = wip.shape();
+        let format_ns = self.dom_deser.parser.format_namespace();
+        let Type::User(UserType::Struct(struct_def)) = &shape.ty else {
+            return Err(DomDeserializeError::Unsupported(format!(
+                "xml::key map values must be structs, got {}",
+                shape.type_identifier
+            )));
+        };
+        if shape.deserialize_proxy(format_ns).is_some() {
+            return Err(DomDeserializeError::Unsupported(format!(
+                "xml::key map values can't use a proxy ({})",
+                shape.type_identifier
+            )));
+        }
+
+        let ns_all = shape
+            .attributes
+            .iter()
+            .find(|attr| attr.ns == Some("xml") && attr.key == "ns_all")
+            .and_then(|attr| attr.get_as::<&str>().copied());
+        let deny_unknown_fields = shape.has_deny_unknown_fields_attr();
+
+        let mut inner_deser = StructDeserializer::new(
+            self.dom_deser,
+            struct_def,
+            ns_all,
+            None,
+            Cow::Owned(tag.to_string()),
+            deny_unknown_fields,
+        );
+        inner_deser.tag = tag;
+
+        if inner_deser.field_map.has_flatten && !wip.is_deferred() {
+            trace!("enabling deferred mode for keyed map value with flatten");
+            wip = wip.begin_deferred()?;
+            inner_deser.using_deferred = true;
+        }
+
+        if let Some(info) = &inner_deser.field_map.tag_field {
+            let idx = info.idx;
+            trace!("→ .{}", info.field.name);
+            let tag = inner_deser.tag.clone();
+            wip = inner_deser
+                .dom_deser
+                .set_string_value(wip.begin_nth_field(idx)?, tag)?
+                .end()?;
+        }
+
+        for attribute in attributes {
+            wip = inner_deser.apply_attribute(wip, attribute)?;
+        }
+        wip = inner_deser.process_attributes(wip)?;
+
+        inner_deser.parser().expect_children_start()?;
+        wip = inner_deser.process_children(wip)?;
+        wip = inner_deser.cleanup(wip)?;
+        inner_deser.parser().expect_children_end()?;
+        inner_deser.parser().expect_node_end()?;
+
+        if inner_deser.using_deferred {
+            wip = wip.finish_deferred()?;
+        }
+
+        Ok(wip)
+    }
+
     fn read_element_text(&mut self) -> Result<String, DomDeserializeError<P::Error>> {
         loop {
             match self
@@ -1065,7 +1266,9 @@ impl<'de, 'p, const BORROW: bool, P: DomParser<'de>> StructDeserializer<'de, 'p,
         loop {
             match self.parser().peek_event_or_eof("text or ChildrenEnd")? {
                 DomEvent::ChildrenEnd => break,
-                DomEvent::Text(_) => text.push_str(&self.parser().expect_text()?),
+                DomEvent::Text(_) | DomEvent::CData(_) => {
+                    text.push_str(&self.parser().expect_text()?)
+                }
                 _ => self
                     .parser()
                     .skip_node()
@@ -1304,6 +1507,18 @@ impl<'de, 'p, const BORROW: bool, P: DomParser<'de>> StructDeserializer<'de, 'p,
             }
         }
 
+        // Initialize any xml::key maps that never received an entry as empty
+        let empty_keyed_maps: Vec<_> = self
+            .field_map
+            .keyed_map_fields()
+            .filter(|keyed| !self.started_keyed_maps.contains(&keyed.info.idx))
+            .map(|keyed| keyed.info.idx)
+            .collect();
+        for idx in empty_keyed_maps {
+            trace!(idx, "initializing empty keyed map");
+            wip = wip.begin_nth_field(idx)?.init_map()?.end()?;
+        }
+
         // Handle attributes catch-all field finalization
         if let Some(info) = &self.field_map.attributes_field {
             if self.attributes_list_started {
@@ -64,6 +64,12 @@ pub enum DomEvent<'a> {
     /// Only valid between `ChildrenStart` and `ChildrenEnd`.
     Text(Cow<'a, str>),
 
+    /// A CDATA section (XML): text that was written as `<![CDATA[...]]>`.
+    ///
+    /// Reads as `Text` wherever text is expected, but lets enums with an
+    /// `xml::cdata` variant keep the section as such.
+    CData(Cow<'a, str>),
+
     /// A comment (usually ignored during deserialization).
     Comment(Cow<'a, str>),
 
@@ -100,6 +106,11 @@ impl<'a> DomEvent<'a> {
         matches!(self, DomEvent::Text(_))
     }
 
+    /// Returns true if this is a `CData` event.
+    pub fn is_cdata(&self) -> bool {
+        matches!(self, DomEvent::CData(_))
+    }
+
     /// Returns true if this is `ChildrenStart`.
     pub fn is_children_start(&self) -> bool {
         matches!(self, DomEvent::ChildrenStart)
@@ -182,6 +193,16 @@ impl std::fmt::Display for TraceFmt<'_, '_> {
                     )
                 }
             }
+            DomEvent::CData(t) => {
+                let preview: String = t.chars().take(40).collect();
+                write!(
+                    f,
+                    "CData {}{}{}",
+                    "<![CDATA[".green(),
+                    preview.green(),
+                    "]]>".green()
+                )
+            }
             DomEvent::Comment(c) => {
                 let preview: String = c.chars().take(20).collect();
                 write!(
@@ -17,6 +17,7 @@ mod parser_ext;
 mod raw_markup;
 mod serializer;
 mod tracing_macros;
+mod url_rewrite;
 
 pub use deserializer::*;
 pub use error::*;
@@ -25,3 +26,4 @@ pub use parser::*;
 pub use parser_ext::*;
 pub use raw_markup::*;
 pub use serializer::*;
+pub use url_rewrite::*;
@@ -56,3 +56,62 @@ pub trait DomParser<'de> {
         Ok(None)
     }
 }
+
+/// The events of a [`DomParser`] as an iterator.
+///
+/// For consumers that want the event stream itself rather than a typed
+/// value: building a custom tree, or scanning a document too large to
+/// materialize. The iterator ends after the last event, or after the first
+/// error.
+pub struct DomEvents<P> {
+    parser: P,
+    done: bool,
+}
+
+impl<P> DomEvents<P> {
+    /// Iterate over the events of `parser`.
+    pub fn new(parser: P) -> Self {
+        Self {
+            parser,
+            done: false,
+        }
+    }
+
+    /// The underlying parser.
+    pub fn parser(&self) -> &P {
+        &self.parser
+    }
+
+    /// Unwrap the underlying parser.
+    pub fn into_inner(self) -> P {
+        self.parser
+    }
+}
+
+impl<'de, P: DomParser<'de>> DomEvents<P> {
+    /// Skip the rest of the node just started, descendants included: call it
+    /// right after a `NodeStart` to leave a subtree unread.
+    pub fn skip_node(&mut self) -> Result<(), P::Error> {
+        self.parser.skip_node()
+    }
+
+    /// Get the current span in the source document, if the parser tracks it.
+    pub fn current_span(&self) -> Option<facet_reflect::Span> {
+        self.parser.current_span()
+    }
+}
+
+impl<'de, P: DomParser<'de>> Iterator for DomEvents<P> {
+    type Item = Result<DomEvent<'de>, P::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let event = self.parser.next_event().transpose();
+        self.done = !matches!(event, Some(Ok(_)));
+        event
+    }
+}
+
+impl<'de, P: DomParser<'de>> core::iter::FusedIterator for DomEvents<P> {}
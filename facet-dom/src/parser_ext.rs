@@ -78,9 +78,11 @@ pub trait DomParserExt<'de>: DomParser<'de> {
     }
 
     /// Expect and consume a Text event, returning the text content.
+    ///
+    /// A CDATA section counts as text.
     fn expect_text(&mut self) -> Result<Cow<'de, str>, DomDeserializeError<Self::Error>> {
         match self.next_event_or_eof("Text")? {
-            DomEvent::Text(text) => Ok(text),
+            DomEvent::Text(text) | DomEvent::CData(text) => Ok(text),
             other => Err(DomDeserializeError::TypeMismatch {
                 expected: "Text",
                 got: format!("{other:?}"),
@@ -109,6 +111,19 @@ pub trait DomParserExt<'de>: DomParser<'de> {
         }
     }
 
+    /// Expect and consume a ProcessingInstruction event, returning (target, data).
+    fn expect_processing_instruction(
+        &mut self,
+    ) -> Result<(Cow<'de, str>, Cow<'de, str>), DomDeserializeError<Self::Error>> {
+        match self.next_event_or_eof("ProcessingInstruction")? {
+            DomEvent::ProcessingInstruction { target, data } => Ok((target, data)),
+            other => Err(DomDeserializeError::TypeMismatch {
+                expected: "ProcessingInstruction",
+                got: format!("{other:?}"),
+            }),
+        }
+    }
+
     /// Expect and consume a Comment event, returning the comment text.
     fn expect_comment(&mut self) -> Result<Cow<'de, str>, DomDeserializeError<Self::Error>> {
         match self.next_event_or_eof("Comment")? {
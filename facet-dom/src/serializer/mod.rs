@@ -71,6 +71,22 @@ pub trait DomSerializer {
         Ok(())
     }
 
+    /// Emit a CDATA section (XML), for a variant marked `#[facet(xml::cdata)]`.
+    ///
+    /// Formats without CDATA sections can rely on the default, which emits
+    /// the content as text.
+    fn cdata(&mut self, content: &str) -> Result<(), Self::Error> {
+        self.text(content)
+    }
+
+    /// Emit a processing instruction (`<?target data?>`).
+    ///
+    /// This is called for variants and root fields marked with
+    /// `#[facet(xml::processing_instruction)]`.
+    fn processing_instruction(&mut self, _target: &str, _data: &str) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
     /// Emit a DOCTYPE declaration (XML/HTML).
     ///
     /// This is called before the root element when a field marked with
@@ -125,6 +141,12 @@ pub trait DomSerializer {
         false
     }
 
+    /// If the current field is a map whose entries are elements keyed by an
+    /// attribute (`xml::key`), returns the name of that attribute.
+    fn map_key_attribute(&self) -> Option<&'static str> {
+        None
+    }
+
     /// Check if the current field is a "tag" field (stores the element's tag name).
     fn is_tag_field(&self) -> bool {
         false
@@ -211,6 +233,20 @@ fn serialize_value<S>(
     value: Peek<'_, '_>,
     element_name: Option<&str>,
 ) -> Result<(), DomSerializeError<S::Error>>
+where
+    S: DomSerializer,
+{
+    serialize_value_with_key(serializer, value, element_name, None)
+}
+
+/// Internal: serialize a value, optionally with an element name. A struct
+/// gets `map_key` as its first attribute (for `xml::key` map entries).
+fn serialize_value_with_key<S>(
+    serializer: &mut S,
+    value: Peek<'_, '_>,
+    element_name: Option<&str>,
+    map_key: Option<(&str, Peek<'_, '_>)>,
+) -> Result<(), DomSerializeError<S::Error>>
 where
     S: DomSerializer,
 {
@@ -221,7 +257,7 @@ where
     // Check for container-level proxy (format-specific or format-agnostic)
     if value
         .shape()
-        .effective_proxy(serializer.format_namespace())
+        .serialize_proxy(serializer.format_namespace())
         .is_some()
     {
         return serialize_via_proxy(serializer, value, element_name);
@@ -388,7 +424,21 @@ where
         };
         trace!(tag = %tag, "element_start");
 
-        // Emit doctype before element_start if present
+        // Emit processing instructions and doctype before element_start if present
+        for (field_item, field_value) in &fields {
+            if !field_item.flattened
+                && field_item
+                    .field
+                    .is_some_and(|field| field.is_processing_instruction())
+            {
+                let list = field_value
+                    .into_list_like()
+                    .map_err(DomSerializeError::Reflect)?;
+                for item in list.iter() {
+                    serialize_processing_instruction(serializer, item)?;
+                }
+            }
+        }
         if let Some(ref doctype_value) = doctype_field_value {
             trace!(doctype = %doctype_value, "emitting doctype");
             serializer
@@ -400,6 +450,12 @@ where
             .element_start(&tag, None)
             .map_err(DomSerializeError::Backend)?;
 
+        if let Some((key_attribute, key)) = map_key {
+            serializer
+                .attribute(key_attribute, key, None)
+                .map_err(DomSerializeError::Backend)?;
+        }
+
         // Fields were already collected above when checking for tag field
         trace!(field_count = fields.len(), "collected fields for serialize");
 
@@ -432,7 +488,7 @@ where
                 let proxy_def = field_item
                     .field
                     .and_then(|f| f.effective_proxy(format_ns))
-                    .or_else(|| field_value.shape().effective_proxy(format_ns));
+                    .or_else(|| field_value.shape().serialize_proxy(format_ns));
 
                 if let Some(proxy_def) = proxy_def {
                     match field_value.custom_serialization_with_proxy(proxy_def) {
@@ -476,8 +532,14 @@ where
                 continue;
             }
 
-            // Skip doctype fields - the value was already emitted as DOCTYPE
-            if serializer.is_doctype_field() {
+            // Skip doctype and processing instruction fields - they were already
+            // emitted before the element
+            if serializer.is_doctype_field()
+                || (!field_item.flattened
+                    && field_item
+                        .field
+                        .is_some_and(|field| field.is_processing_instruction()))
+            {
                 serializer.clear_field_state();
                 continue;
             }
@@ -509,8 +571,21 @@ where
                 serializer.clear_field_state();
                 continue;
             }
+            if field_item.is_cdata_variant {
+                if let Some(s) = value_to_string(*field_value, serializer) {
+                    serializer.cdata(&s).map_err(DomSerializeError::Backend)?;
+                }
+                serializer.clear_field_state();
+                continue;
+            }
+            if field_item.is_processing_instruction_variant {
+                serialize_processing_instruction(serializer, *field_value)?;
+                serializer.clear_field_state();
+                continue;
+            }
 
             // Compute field element name: rename > lowerCamelCase(field.name)
+            let map_key_attribute = serializer.map_key_attribute();
             let field_element_name: Option<Cow<'_, str>> =
                 if is_elements && explicit_rename.is_none() {
                     None // Items determine their own element names
@@ -532,9 +607,11 @@ where
             let proxy_def = field_item
                 .field
                 .and_then(|f| f.effective_proxy(format_ns))
-                .or_else(|| field_value.shape().effective_proxy(format_ns));
+                .or_else(|| field_value.shape().serialize_proxy(format_ns));
 
-            if let Some(proxy_def) = proxy_def {
+            if let (Some(key_attribute), Some(tag)) = (map_key_attribute, &field_element_name) {
+                serialize_keyed_map(serializer, *field_value, tag, key_attribute)?;
+            } else if let Some(proxy_def) = proxy_def {
                 // Use custom_serialization_with_proxy for proxy
                 match field_value.custom_serialization_with_proxy(proxy_def) {
                     Ok(proxy_peek) => {
@@ -633,6 +710,15 @@ where
                 }
                 return Ok(());
             }
+            if variant.is_cdata() {
+                if let Some(s) = value_to_string(inner, serializer) {
+                    serializer.cdata(&s).map_err(DomSerializeError::Backend)?;
+                }
+                return Ok(());
+            }
+            if variant.is_processing_instruction() {
+                return serialize_processing_instruction(serializer, inner);
+            }
 
             if untagged {
                 return serialize_value(serializer, inner, element_name);
@@ -669,6 +755,11 @@ where
             return Ok(());
         }
 
+        // Processing instruction variant with its target and data as fields
+        if variant.is_processing_instruction() {
+            return serialize_processing_instruction(serializer, value);
+        }
+
         // Struct variant
         // Use effective_name() to honor rename_all on enum
         let variant_name: Cow<'_, str> = if variant.rename.is_some() {
@@ -861,7 +952,7 @@ where
             let proxy_def = field_item
                 .field
                 .and_then(|f| f.effective_proxy(format_ns))
-                .or_else(|| field_value.shape().effective_proxy(format_ns));
+                .or_else(|| field_value.shape().serialize_proxy(format_ns));
 
             if let Some(proxy_def) = proxy_def {
                 match field_value.custom_serialization_with_proxy(proxy_def) {
@@ -929,6 +1020,18 @@ where
             serializer.clear_field_state();
             continue;
         }
+        if field_item.is_cdata_variant {
+            if let Some(s) = value_to_string(*field_value, serializer) {
+                serializer.cdata(&s).map_err(DomSerializeError::Backend)?;
+            }
+            serializer.clear_field_state();
+            continue;
+        }
+        if field_item.is_processing_instruction_variant {
+            serialize_processing_instruction(serializer, *field_value)?;
+            serializer.clear_field_state();
+            continue;
+        }
 
         // Compute field element name
         let is_elements = serializer.is_elements_field();
@@ -951,7 +1054,7 @@ where
         let proxy_def = field_item
             .field
             .and_then(|f| f.effective_proxy(format_ns))
-            .or_else(|| field_value.shape().effective_proxy(format_ns));
+            .or_else(|| field_value.shape().serialize_proxy(format_ns));
 
         if let Some(proxy_def) = proxy_def {
             match field_value.custom_serialization_with_proxy(proxy_def) {
@@ -977,6 +1080,75 @@ where
 }
 
 /// Serialize through a proxy type.
+/// Serialize a processing instruction from a struct (or struct variant) whose
+/// first two fields are its target and data.
+fn serialize_processing_instruction<S>(
+    serializer: &mut S,
+    value: Peek<'_, '_>,
+) -> Result<(), DomSerializeError<S::Error>>
+where
+    S: DomSerializer,
+{
+    let value = deref_if_pointer(value).innermost_peek();
+    let (target, data) = if let Ok(struct_) = value.into_struct() {
+        (struct_.field(0).ok(), struct_.field(1).ok())
+    } else if let Ok(enum_) = value.into_enum() {
+        (enum_.field(0).ok().flatten(), enum_.field(1).ok().flatten())
+    } else {
+        (None, None)
+    };
+
+    let target = target.and_then(|v| value_to_string(v, serializer));
+    let data = data.and_then(|v| value_to_string(v, serializer));
+    let (Some(target), Some(data)) = (target, data) else {
+        return Err(DomSerializeError::Unsupported(Cow::Owned(alloc::format!(
+            "processing instructions need a target and a data field, got {}",
+            value.shape().type_identifier
+        ))));
+    };
+    serializer
+        .processing_instruction(&target, &data)
+        .map_err(DomSerializeError::Backend)
+}
+
+/// Serialize an `xml::key` map: one `tag` element per entry, with the key
+/// written as the `key_attribute` attribute of the entry's struct.
+fn serialize_keyed_map<S>(
+    serializer: &mut S,
+    value: Peek<'_, '_>,
+    tag: &str,
+    key_attribute: &str,
+) -> Result<(), DomSerializeError<S::Error>>
+where
+    S: DomSerializer,
+{
+    let map = deref_if_pointer(value)
+        .innermost_peek()
+        .into_map()
+        .map_err(DomSerializeError::Reflect)?;
+
+    for (key, val) in map.iter() {
+        let val = deref_if_pointer(val).innermost_peek();
+        let is_struct = val
+            .into_struct()
+            .is_ok_and(|struct_| struct_.ty().kind != StructKind::Tuple);
+        if !is_struct
+            || val
+                .shape()
+                .serialize_proxy(serializer.format_namespace())
+                .is_some()
+        {
+            return Err(DomSerializeError::Unsupported(Cow::Owned(alloc::format!(
+                "xml::key map values must be structs, got {}",
+                val.shape().type_identifier
+            ))));
+        }
+        serialize_value_with_key(serializer, val, Some(tag), Some((key_attribute, key)))?;
+    }
+
+    Ok(())
+}
+
 fn serialize_via_proxy<S>(
     serializer: &mut S,
     value: Peek<'_, '_>,
@@ -1030,48 +1202,18 @@ fn value_to_string<S: DomSerializer>(value: Peek<'_, '_>, serializer: &S) -> Opt
     }
 
     if let Some(scalar_type) = value.scalar_type() {
-        let s = match scalar_type {
-            ScalarType::Unit => return Some("null".into()),
-            ScalarType::Bool => if *value.get::<bool>().ok()? {
-                "true"
-            } else {
-                "false"
+        return Some(match scalar_type {
+            ScalarType::Unit => "null".into(),
+            ScalarType::F32 | ScalarType::F64 => {
+                serializer.format_float(write_scalar::float_value(value)?)
             }
-            .into(),
-            ScalarType::Char => value.get::<char>().ok()?.to_string(),
-            ScalarType::Str | ScalarType::String | ScalarType::CowStr => {
-                value.as_str()?.to_string()
-            }
-            ScalarType::F32 => serializer.format_float(*value.get::<f32>().ok()? as f64),
-            ScalarType::F64 => serializer.format_float(*value.get::<f64>().ok()?),
-            ScalarType::U8 => value.get::<u8>().ok()?.to_string(),
-            ScalarType::U16 => value.get::<u16>().ok()?.to_string(),
-            ScalarType::U32 => value.get::<u32>().ok()?.to_string(),
-            ScalarType::U64 => value.get::<u64>().ok()?.to_string(),
-            ScalarType::U128 => value.get::<u128>().ok()?.to_string(),
-            ScalarType::USize => value.get::<usize>().ok()?.to_string(),
-            ScalarType::I8 => value.get::<i8>().ok()?.to_string(),
-            ScalarType::I16 => value.get::<i16>().ok()?.to_string(),
-            ScalarType::I32 => value.get::<i32>().ok()?.to_string(),
-            ScalarType::I64 => value.get::<i64>().ok()?.to_string(),
-            ScalarType::I128 => value.get::<i128>().ok()?.to_string(),
-            ScalarType::ISize => value.get::<isize>().ok()?.to_string(),
-            #[cfg(feature = "net")]
-            ScalarType::IpAddr => value.get::<core::net::IpAddr>().ok()?.to_string(),
-            #[cfg(feature = "net")]
-            ScalarType::Ipv4Addr => value.get::<core::net::Ipv4Addr>().ok()?.to_string(),
-            #[cfg(feature = "net")]
-            ScalarType::Ipv6Addr => value.get::<core::net::Ipv6Addr>().ok()?.to_string(),
-            #[cfg(feature = "net")]
-            ScalarType::SocketAddr => value.get::<core::net::SocketAddr>().ok()?.to_string(),
-            _ => return None,
-        };
-        return Some(s);
+            _ => value.as_display().to_string(),
+        });
     }
 
     // Try Display for Def::Scalar types (SmolStr, etc.)
     if matches!(value.shape().def, Def::Scalar) && value.shape().vtable.has_display() {
-        return Some(alloc::format!("{}", value));
+        return Some(value.as_display().to_string());
     }
 
     None
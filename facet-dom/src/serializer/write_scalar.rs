@@ -10,6 +10,11 @@ use facet_reflect::Peek;
 use super::DomSerializer;
 
 /// Extension trait for writing scalar values directly to output.
+///
+/// Scalars are spelled by [`Peek::as_display`], the same as in `facet-pretty`
+/// and diff output. Floats go through [`WriteScalar::write_float`] (or
+/// [`DomSerializer::format_float`] for attribute values) so serializers can
+/// pick their own precision.
 pub trait WriteScalar: DomSerializer {
     /// Format a scalar value to a string (for attributes).
     ///
@@ -29,82 +34,26 @@ pub trait WriteScalar: DomSerializer {
         }
 
         if let Some(scalar_type) = value.scalar_type() {
+            if let Some(s) = value.as_str() {
+                return Some(s.to_string());
+            }
             let mut buf = ScalarBuffer::new();
-            let written = match scalar_type {
-                ScalarType::Unit => {
-                    buf.push_str("null");
-                    true
-                }
-                ScalarType::Bool => {
-                    if let Ok(b) = value.get::<bool>() {
-                        buf.push_str(if *b { "true" } else { "false" });
-                        true
-                    } else {
-                        false
-                    }
-                }
-                ScalarType::Char => {
-                    if let Ok(c) = value.get::<char>() {
-                        buf.push(*c);
-                        true
-                    } else {
-                        false
-                    }
-                }
-                ScalarType::Str | ScalarType::String | ScalarType::CowStr => {
-                    if let Some(s) = value.as_str() {
-                        return Some(s.to_string());
-                    }
-                    false
+            match scalar_type {
+                ScalarType::Unit => buf.push_str("null"),
+                ScalarType::F32 | ScalarType::F64 => {
+                    self.write_float(float_value(value)?, &mut buf)
                 }
-                ScalarType::F32 => {
-                    if let Ok(v) = value.get::<f32>() {
-                        self.write_float(*v as f64, &mut buf);
-                        true
-                    } else {
-                        false
-                    }
+                _ => {
+                    let _ = write!(buf, "{}", value.as_display());
                 }
-                ScalarType::F64 => {
-                    if let Ok(v) = value.get::<f64>() {
-                        self.write_float(*v, &mut buf);
-                        true
-                    } else {
-                        false
-                    }
-                }
-                ScalarType::U8 => write_int!(buf, value, u8),
-                ScalarType::U16 => write_int!(buf, value, u16),
-                ScalarType::U32 => write_int!(buf, value, u32),
-                ScalarType::U64 => write_int!(buf, value, u64),
-                ScalarType::U128 => write_int!(buf, value, u128),
-                ScalarType::USize => write_int!(buf, value, usize),
-                ScalarType::I8 => write_int!(buf, value, i8),
-                ScalarType::I16 => write_int!(buf, value, i16),
-                ScalarType::I32 => write_int!(buf, value, i32),
-                ScalarType::I64 => write_int!(buf, value, i64),
-                ScalarType::I128 => write_int!(buf, value, i128),
-                ScalarType::ISize => write_int!(buf, value, isize),
-                #[cfg(feature = "net")]
-                ScalarType::IpAddr => write_int!(buf, value, core::net::IpAddr),
-                #[cfg(feature = "net")]
-                ScalarType::Ipv4Addr => write_int!(buf, value, core::net::Ipv4Addr),
-                #[cfg(feature = "net")]
-                ScalarType::Ipv6Addr => write_int!(buf, value, core::net::Ipv6Addr),
-                #[cfg(feature = "net")]
-                ScalarType::SocketAddr => write_int!(buf, value, core::net::SocketAddr),
-                _ => false,
-            };
-
-            if written {
-                return Some(buf.as_str().to_string());
             }
+            return Some(buf.as_str().to_string());
         }
 
         // Try Display for Def::Scalar types (SmolStr, etc.)
         if matches!(value.shape().def, Def::Scalar) && value.shape().vtable.has_display() {
             let mut buf = ScalarBuffer::new();
-            let _ = write!(buf, "{}", value);
+            let _ = write!(buf, "{}", value.as_display());
             return Some(buf.as_str().to_string());
         }
 
@@ -127,84 +76,31 @@ pub trait WriteScalar: DomSerializer {
         }
 
         if let Some(scalar_type) = value.scalar_type() {
+            if let Some(s) = value.as_str() {
+                self.text(s)?;
+                return Ok(true);
+            }
             let mut buf = ScalarBuffer::new();
-            let written = match scalar_type {
-                ScalarType::Unit => {
-                    buf.push_str("null");
-                    true
-                }
-                ScalarType::Bool => {
-                    if let Ok(b) = value.get::<bool>() {
-                        buf.push_str(if *b { "true" } else { "false" });
-                        true
-                    } else {
-                        false
-                    }
-                }
-                ScalarType::Char => {
-                    if let Ok(c) = value.get::<char>() {
-                        buf.push(*c);
-                        true
-                    } else {
-                        false
-                    }
+            match scalar_type {
+                ScalarType::Unit => buf.push_str("null"),
+                ScalarType::F32 | ScalarType::F64 => {
+                    let Some(float) = float_value(value) else {
+                        return Ok(false);
+                    };
+                    self.write_float(float, &mut buf);
                 }
-                ScalarType::Str | ScalarType::String | ScalarType::CowStr => {
-                    if let Some(s) = value.as_str() {
-                        self.text(s)?;
-                        return Ok(true);
-                    }
-                    false
+                _ => {
+                    let _ = write!(buf, "{}", value.as_display());
                 }
-                ScalarType::F32 => {
-                    if let Ok(v) = value.get::<f32>() {
-                        self.write_float(*v as f64, &mut buf);
-                        true
-                    } else {
-                        false
-                    }
-                }
-                ScalarType::F64 => {
-                    if let Ok(v) = value.get::<f64>() {
-                        self.write_float(*v, &mut buf);
-                        true
-                    } else {
-                        false
-                    }
-                }
-                ScalarType::U8 => write_int!(buf, value, u8),
-                ScalarType::U16 => write_int!(buf, value, u16),
-                ScalarType::U32 => write_int!(buf, value, u32),
-                ScalarType::U64 => write_int!(buf, value, u64),
-                ScalarType::U128 => write_int!(buf, value, u128),
-                ScalarType::USize => write_int!(buf, value, usize),
-                ScalarType::I8 => write_int!(buf, value, i8),
-                ScalarType::I16 => write_int!(buf, value, i16),
-                ScalarType::I32 => write_int!(buf, value, i32),
-                ScalarType::I64 => write_int!(buf, value, i64),
-                ScalarType::I128 => write_int!(buf, value, i128),
-                ScalarType::ISize => write_int!(buf, value, isize),
-                #[cfg(feature = "net")]
-                ScalarType::IpAddr => write_display!(buf, value, core::net::IpAddr),
-                #[cfg(feature = "net")]
-                ScalarType::Ipv4Addr => write_display!(buf, value, core::net::Ipv4Addr),
-                #[cfg(feature = "net")]
-                ScalarType::Ipv6Addr => write_display!(buf, value, core::net::Ipv6Addr),
-                #[cfg(feature = "net")]
-                ScalarType::SocketAddr => write_display!(buf, value, core::net::SocketAddr),
-                _ => false,
-            };
-
-            if written {
-                self.text(buf.as_str())?;
-                return Ok(true);
             }
+            self.text(buf.as_str())?;
+            return Ok(true);
         }
 
         // Try Display for Def::Scalar types (SmolStr, etc.)
         if matches!(value.shape().def, Def::Scalar) && value.shape().vtable.has_display() {
             let mut buf = ScalarBuffer::new();
-            let _ = write!(buf, "{}", value);
+            let _ = write!(buf, "{}", value.as_display());
             self.text(buf.as_str())?;
             return Ok(true);
         }
@@ -221,31 +117,23 @@ pub trait WriteScalar: DomSerializer {
 // Blanket implementation for all DomSerializers
 impl<T: DomSerializer> WriteScalar for T {}
 
-macro_rules! write_int {
-    ($buf:expr, $value:expr, $ty:ty) => {{
-        if let Ok(v) = $value.get::<$ty>() {
-            let _ = write!($buf, "{}", v);
-            true
-        } else {
-            false
-        }
-    }};
-}
-use write_int;
-
-#[cfg(feature = "net")]
-macro_rules! write_display {
-    ($buf:expr, $value:expr, $ty:ty) => {{
-        if let Ok(v) = $value.get::<$ty>() {
-            let _ = write!($buf, "{}", v);
-            true
-        } else {
-            false
+/// Read an `f32` or `f64` for the float formatting hooks, which take `f64`.
+///
+/// An `f32` is widened through its shortest decimal spelling, so `0.1_f32`
+/// arrives as `0.1` rather than `0.10000000149011612`, and the default hooks
+/// print it the way [`Peek::as_display`] does.
+pub(crate) fn float_value(value: Peek<'_, '_>) -> Option<f64> {
+    match value.scalar_type()? {
+        ScalarType::F64 => value.get::<f64>().ok().copied(),
+        ScalarType::F32 => {
+            let float = *value.get::<f32>().ok()?;
+            let mut buf = ScalarBuffer::new();
+            let _ = write!(buf, "{float}");
+            Some(buf.as_str().parse().unwrap_or(float as f64))
         }
-    }};
+        _ => None,
+    }
 }
-#[cfg(feature = "net")]
-use write_display;
 
 /// Buffer for formatting scalar values without heap allocation for small values.
 /// Uses a small inline buffer, falling back to heap for larger values.
@@ -291,12 +179,6 @@ impl ScalarBuffer {
             self.overflow = Some(heap);
         }
     }
-
-    fn push(&mut self, c: char) {
-        let mut buf = [0u8; 4];
-        let s = c.encode_utf8(&mut buf);
-        self.push_str(s);
-    }
 }
 
 impl Default for ScalarBuffer {
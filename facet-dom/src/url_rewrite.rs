@@ -0,0 +1,494 @@
+//! URL rewriting for DOM event streams.
+//!
+//! [`UrlRewriter`] wraps any [`DomParser`] and passes every URL found in a
+//! URL-bearing attribute (see [`is_url_attribute`]) through a callback before
+//! the deserializer sees it. `srcset` values are split into their image
+//! candidates (see [`parse_srcset`]) so the callback only ever sees single URLs,
+//! and written back with [`format_srcset`].
+//!
+//! Typical uses are making relative links absolute for static site generation,
+//! or prefixing asset URLs with a CDN origin when proxying documents.
+//!
+//! On the way out, [`UrlCollector`] wraps any [`DomSerializer`] and reports the
+//! same URLs as they are written, so a build tool can fingerprint or copy the
+//! assets a document references while rendering it.
+
+use std::borrow::Cow;
+
+use facet_reflect::Peek;
+
+use crate::{DomEvent, DomParser, DomSerializer, WriteScalar};
+
+/// Attributes whose value is a single URL.
+const URL_ATTRIBUTES: &[&str] = &["src", "href", "poster", "formaction"];
+
+/// Returns true if attribute `name` holds URLs (`src`, `href`, `srcset`,
+/// `poster`, `formaction`).
+pub fn is_url_attribute(name: &str) -> bool {
+    name == "srcset" || URL_ATTRIBUTES.contains(&name)
+}
+
+/// A URL found in an attribute, as passed to the [`UrlRewriter`] and
+/// [`UrlCollector`] callbacks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UrlAttribute<'a> {
+    /// Tag of the element carrying the attribute (e.g. `img`).
+    pub tag: &'a str,
+    /// Attribute name (e.g. `src` or `srcset`).
+    pub name: &'a str,
+    /// The URL itself; for `srcset`, a single candidate's URL.
+    pub url: &'a str,
+}
+
+/// One image candidate of a `srcset` attribute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SrcsetCandidate<'a> {
+    /// The candidate's URL.
+    pub url: &'a str,
+    /// Its descriptor (`2x`, `640w`, ...), or an empty string if it has none.
+    pub descriptor: &'a str,
+}
+
+/// Splits a `srcset` attribute value into its image candidates.
+///
+/// This follows the HTML "parse a srcset attribute" algorithm: URLs may
+/// themselves contain commas (only trailing commas end a URL), and commas
+/// inside parentheses in a descriptor don't separate candidates. Empty
+/// candidates are dropped.
+///
+/// ```
+/// use facet_dom::{SrcsetCandidate, parse_srcset};
+///
+/// let candidates = parse_srcset("a.png, b,c.png 2x,d.png");
+/// assert_eq!(
+///     candidates,
+///     [
+///         SrcsetCandidate { url: "a.png", descriptor: "" },
+///         SrcsetCandidate { url: "b,c.png", descriptor: "2x" },
+///         SrcsetCandidate { url: "d.png", descriptor: "" },
+///     ]
+/// );
+/// ```
+pub fn parse_srcset(input: &str) -> Vec<SrcsetCandidate<'_>> {
+    let bytes = input.as_bytes();
+    let mut candidates = Vec::new();
+    let mut pos = 0;
+
+    loop {
+        // Candidates are separated by whitespace and commas.
+        while pos < bytes.len() && (bytes[pos].is_ascii_whitespace() || bytes[pos] == b',') {
+            pos += 1;
+        }
+        if pos >= bytes.len() {
+            break;
+        }
+
+        // The URL runs up to the next whitespace...
+        let url_start = pos;
+        while pos < bytes.len() && !bytes[pos].is_ascii_whitespace() {
+            pos += 1;
+        }
+        let url = &input[url_start..pos];
+
+        // ...unless it ends with commas, which end the candidate instead.
+        if url.ends_with(',') {
+            candidates.push(SrcsetCandidate {
+                url: url.trim_end_matches(','),
+                descriptor: "",
+            });
+            continue;
+        }
+
+        // Descriptors run up to the next comma outside parentheses.
+        let descriptor_start = pos;
+        let mut in_parens = false;
+        while pos < bytes.len() {
+            match bytes[pos] {
+                b'(' => in_parens = true,
+                b')' => in_parens = false,
+                b',' if !in_parens => break,
+                _ => {}
+            }
+            pos += 1;
+        }
+        candidates.push(SrcsetCandidate {
+            url,
+            descriptor: input[descriptor_start..pos]
+                .trim_matches(|c: char| c.is_ascii_whitespace()),
+        });
+    }
+
+    candidates
+}
+
+/// Writes image candidates back out as a `srcset` attribute value.
+///
+/// The inverse of [`parse_srcset`]: candidates are separated by `", "`, and
+/// each URL is followed by its descriptor, if it has one.
+///
+/// ```
+/// use facet_dom::{SrcsetCandidate, format_srcset, parse_srcset};
+///
+/// let mut candidates = parse_srcset("small.png 480w,large.png 1080w");
+/// candidates[1].url = "/cdn/large.png";
+/// assert_eq!(
+///     format_srcset(candidates),
+///     "small.png 480w, /cdn/large.png 1080w"
+/// );
+/// ```
+pub fn format_srcset<'a>(candidates: impl IntoIterator<Item = SrcsetCandidate<'a>>) -> String {
+    let mut out = String::new();
+    for candidate in candidates {
+        if !out.is_empty() {
+            out.push_str(", ");
+        }
+        out.push_str(candidate.url);
+        if !candidate.descriptor.is_empty() {
+            out.push(' ');
+            out.push_str(candidate.descriptor);
+        }
+    }
+    out
+}
+
+/// A [`DomParser`] adapter that rewrites URLs in attributes.
+///
+/// The callback receives each URL with its element and attribute, and returns
+/// `Some(new_url)` to replace it or `None` to keep it as is. Raw markup captured
+/// through [`DomParser::capture_raw_node`] is passed through unchanged.
+///
+/// ```ignore
+/// use facet_dom::UrlRewriter;
+///
+/// let parser = UrlRewriter::new(html_parser, |attr| {
+///     attr.url
+///         .starts_with('/')
+///         .then(|| format!("https://cdn.example.com{}", attr.url))
+/// });
+/// ```
+pub struct UrlRewriter<'de, P, F> {
+    inner: P,
+    rewrite: F,
+    /// Tag of the element whose attributes are being read.
+    tag: String,
+    peeked: Option<DomEvent<'de>>,
+}
+
+impl<'de, P, F> UrlRewriter<'de, P, F>
+where
+    P: DomParser<'de>,
+    F: FnMut(UrlAttribute<'_>) -> Option<String>,
+{
+    /// Wraps `inner`, rewriting URLs with `rewrite`.
+    pub fn new(inner: P, rewrite: F) -> Self {
+        Self {
+            inner,
+            rewrite,
+            tag: String::new(),
+            peeked: None,
+        }
+    }
+
+    /// Returns the wrapped parser.
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    fn read_next(&mut self) -> Result<Option<DomEvent<'de>>, P::Error> {
+        let event = match self.inner.next_event()? {
+            Some(DomEvent::NodeStart { tag, namespace }) => {
+                self.tag.clear();
+                self.tag.push_str(&tag);
+                DomEvent::NodeStart { tag, namespace }
+            }
+            Some(DomEvent::Attribute {
+                name,
+                value,
+                namespace,
+            }) if is_url_attribute(&name) => {
+                let value = self.rewrite_value(&name, value);
+                DomEvent::Attribute {
+                    name,
+                    value,
+                    namespace,
+                }
+            }
+            Some(event) => event,
+            None => return Ok(None),
+        };
+        Ok(Some(event))
+    }
+
+    fn rewrite_value(&mut self, name: &str, value: Cow<'de, str>) -> Cow<'de, str> {
+        let tag = self.tag.as_str();
+        if name != "srcset" {
+            let rewritten = (self.rewrite)(UrlAttribute {
+                tag,
+                name,
+                url: value.trim(),
+            });
+            return rewritten.map_or(value, Cow::Owned);
+        }
+
+        let rewritten = {
+            let candidates = parse_srcset(&value);
+            let urls: Vec<Option<String>> = candidates
+                .iter()
+                .map(|candidate| {
+                    (self.rewrite)(UrlAttribute {
+                        tag,
+                        name,
+                        url: candidate.url,
+                    })
+                })
+                .collect();
+            (!urls.iter().all(Option::is_none)).then(|| {
+                format_srcset(candidates.iter().zip(&urls).map(|(candidate, url)| {
+                    SrcsetCandidate {
+                        url: url.as_deref().unwrap_or(candidate.url),
+                        descriptor: candidate.descriptor,
+                    }
+                }))
+            })
+        };
+        rewritten.map_or(value, Cow::Owned)
+    }
+}
+
+impl<'de, P, F> DomParser<'de> for UrlRewriter<'de, P, F>
+where
+    P: DomParser<'de>,
+    F: FnMut(UrlAttribute<'_>) -> Option<String>,
+{
+    type Error = P::Error;
+
+    fn next_event(&mut self) -> Result<Option<DomEvent<'de>>, Self::Error> {
+        if let Some(event) = self.peeked.take() {
+            return Ok(Some(event));
+        }
+        self.read_next()
+    }
+
+    fn peek_event(&mut self) -> Result<Option<&DomEvent<'de>>, Self::Error> {
+        if self.peeked.is_none() {
+            self.peeked = self.read_next()?;
+        }
+        Ok(self.peeked.as_ref())
+    }
+
+    fn skip_node(&mut self) -> Result<(), Self::Error> {
+        // Events may already be buffered here, so skip through our own stream
+        // rather than delegating to the inner parser.
+        let mut depth = 0usize;
+        loop {
+            match self.next_event()? {
+                Some(DomEvent::NodeStart { .. }) => depth += 1,
+                Some(DomEvent::NodeEnd) if depth == 0 => break,
+                Some(DomEvent::NodeEnd) => depth -= 1,
+                Some(_) => {}
+                None => break,
+            }
+        }
+        Ok(())
+    }
+
+    fn current_span(&self) -> Option<facet_reflect::Span> {
+        self.inner.current_span()
+    }
+
+    fn is_lenient(&self) -> bool {
+        self.inner.is_lenient()
+    }
+
+    fn format_namespace(&self) -> Option<&'static str> {
+        self.inner.format_namespace()
+    }
+
+    fn capture_raw_node(&mut self) -> Result<Option<Cow<'de, str>>, Self::Error> {
+        if self.peeked.is_some() {
+            // The inner parser is already past the node start.
+            return Ok(None);
+        }
+        self.inner.capture_raw_node()
+    }
+}
+
+/// A [`DomSerializer`] adapter that reports the URLs it writes.
+///
+/// The callback receives every URL emitted in a URL-bearing attribute (see
+/// [`is_url_attribute`]) with its element and attribute, in document order;
+/// each `srcset` candidate is reported on its own. The output itself is left
+/// untouched, so collecting costs no second traversal of the document.
+///
+/// ```ignore
+/// use facet_dom::UrlCollector;
+/// use facet_reflect::Peek;
+///
+/// let mut assets = Vec::new();
+/// let mut serializer = UrlCollector::new(XmlSerializer::new(), |attr| {
+///     if attr.tag == "img" {
+///         assets.push(attr.url.to_owned());
+///     }
+/// });
+/// facet_dom::serialize(&mut serializer, Peek::new(&page))?;
+/// let xml = serializer.into_inner().finish();
+/// ```
+pub struct UrlCollector<S, F> {
+    inner: S,
+    collect: F,
+    /// Tag of the element whose attributes are being written.
+    tag: String,
+}
+
+impl<S, F> UrlCollector<S, F>
+where
+    S: DomSerializer,
+    F: FnMut(UrlAttribute<'_>),
+{
+    /// Wraps `inner`, passing the URLs it writes to `collect`.
+    pub fn new(inner: S, collect: F) -> Self {
+        Self {
+            inner,
+            collect,
+            tag: String::new(),
+        }
+    }
+
+    /// Returns the wrapped serializer.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    fn report(&mut self, name: &str, value: &str) {
+        let tag = self.tag.as_str();
+        if name == "srcset" {
+            for candidate in parse_srcset(value) {
+                (self.collect)(UrlAttribute {
+                    tag,
+                    name,
+                    url: candidate.url,
+                });
+            }
+        } else {
+            let url = value.trim();
+            if !url.is_empty() {
+                (self.collect)(UrlAttribute { tag, name, url });
+            }
+        }
+    }
+}
+
+impl<S, F> DomSerializer for UrlCollector<S, F>
+where
+    S: DomSerializer,
+    F: FnMut(UrlAttribute<'_>),
+{
+    type Error = S::Error;
+
+    fn element_start(&mut self, tag: &str, namespace: Option<&str>) -> Result<(), Self::Error> {
+        self.tag.clear();
+        self.tag.push_str(tag);
+        self.inner.element_start(tag, namespace)
+    }
+
+    fn attribute(
+        &mut self,
+        name: &str,
+        value: Peek<'_, '_>,
+        namespace: Option<&str>,
+    ) -> Result<(), Self::Error> {
+        if is_url_attribute(name)
+            && let Some(url) = self.inner.format_scalar(value)
+        {
+            self.report(name, &url);
+        }
+        self.inner.attribute(name, value, namespace)
+    }
+
+    fn children_start(&mut self) -> Result<(), Self::Error> {
+        self.inner.children_start()
+    }
+
+    fn children_end(&mut self) -> Result<(), Self::Error> {
+        self.inner.children_end()
+    }
+
+    fn element_end(&mut self, tag: &str) -> Result<(), Self::Error> {
+        self.inner.element_end(tag)
+    }
+
+    fn text(&mut self, content: &str) -> Result<(), Self::Error> {
+        self.inner.text(content)
+    }
+
+    fn comment(&mut self, content: &str) -> Result<(), Self::Error> {
+        self.inner.comment(content)
+    }
+
+    fn cdata(&mut self, content: &str) -> Result<(), Self::Error> {
+        self.inner.cdata(content)
+    }
+
+    fn processing_instruction(&mut self, target: &str, data: &str) -> Result<(), Self::Error> {
+        self.inner.processing_instruction(target, data)
+    }
+
+    fn doctype(&mut self, content: &str) -> Result<(), Self::Error> {
+        self.inner.doctype(content)
+    }
+
+    fn struct_metadata(&mut self, shape: &facet_core::Shape) -> Result<(), Self::Error> {
+        self.inner.struct_metadata(shape)
+    }
+
+    fn field_metadata(&mut self, field: &facet_reflect::FieldItem) -> Result<(), Self::Error> {
+        self.inner.field_metadata(field)
+    }
+
+    fn variant_metadata(
+        &mut self,
+        variant: &'static facet_core::Variant,
+    ) -> Result<(), Self::Error> {
+        self.inner.variant_metadata(variant)
+    }
+
+    fn is_attribute_field(&self) -> bool {
+        self.inner.is_attribute_field()
+    }
+
+    fn is_text_field(&self) -> bool {
+        self.inner.is_text_field()
+    }
+
+    fn is_elements_field(&self) -> bool {
+        self.inner.is_elements_field()
+    }
+
+    fn map_key_attribute(&self) -> Option<&'static str> {
+        self.inner.map_key_attribute()
+    }
+
+    fn is_tag_field(&self) -> bool {
+        self.inner.is_tag_field()
+    }
+
+    fn is_doctype_field(&self) -> bool {
+        self.inner.is_doctype_field()
+    }
+
+    fn clear_field_state(&mut self) {
+        self.inner.clear_field_state()
+    }
+
+    fn format_float(&self, value: f64) -> String {
+        self.inner.format_float(value)
+    }
+
+    fn serialize_none(&mut self) -> Result<(), Self::Error> {
+        self.inner.serialize_none()
+    }
+
+    fn format_namespace(&self) -> Option<&'static str> {
+        self.inner.format_namespace()
+    }
+}
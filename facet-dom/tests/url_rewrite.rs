@@ -0,0 +1,205 @@
+//! Tests for URL rewriting over DOM event streams.
+
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::convert::Infallible;
+
+use facet_dom::{DomEvent, DomParser, SrcsetCandidate, UrlRewriter, format_srcset, parse_srcset};
+
+/// A parser that replays a fixed list of events.
+struct Replay(VecDeque<DomEvent<'static>>);
+
+impl DomParser<'static> for Replay {
+    type Error = Infallible;
+
+    fn next_event(&mut self) -> Result<Option<DomEvent<'static>>, Self::Error> {
+        Ok(self.0.pop_front())
+    }
+
+    fn peek_event(&mut self) -> Result<Option<&DomEvent<'static>>, Self::Error> {
+        Ok(self.0.front())
+    }
+
+    fn skip_node(&mut self) -> Result<(), Self::Error> {
+        unreachable!("UrlRewriter skips through its own event stream")
+    }
+}
+
+fn node(tag: &'static str, attrs: &[(&'static str, &'static str)]) -> Vec<DomEvent<'static>> {
+    let mut events = vec![DomEvent::NodeStart {
+        tag: Cow::Borrowed(tag),
+        namespace: None,
+    }];
+    events.extend(attrs.iter().map(|&(name, value)| DomEvent::Attribute {
+        name: Cow::Borrowed(name),
+        value: Cow::Borrowed(value),
+        namespace: None,
+    }));
+    events.push(DomEvent::ChildrenStart);
+    events.push(DomEvent::ChildrenEnd);
+    events.push(DomEvent::NodeEnd);
+    events
+}
+
+fn attributes<'de>(parser: &mut impl DomParser<'de>) -> Vec<(String, String)> {
+    let mut out = Vec::new();
+    while let Ok(Some(event)) = parser.next_event() {
+        if let DomEvent::Attribute { name, value, .. } = event {
+            out.push((name.into_owned(), value.into_owned()));
+        }
+    }
+    out
+}
+
+fn absolutize(url: &str) -> Option<String> {
+    url.starts_with('/')
+        .then(|| format!("https://cdn.example.com{url}"))
+}
+
+#[test]
+fn test_rewrites_url_attributes_only() {
+    let mut events = node("a", &[("href", "/about"), ("title", "/not-a-url")]);
+    events.extend(node("img", &[("src", "/logo.png"), ("alt", "logo")]));
+    events.extend(node("video", &[("poster", "/poster.jpg")]));
+    events.extend(node(
+        "button",
+        &[("formaction", "https://other.example/submit")],
+    ));
+
+    let mut parser = UrlRewriter::new(Replay(events.into()), |attr| absolutize(attr.url));
+    assert_eq!(
+        attributes(&mut parser),
+        [
+            ("href", "https://cdn.example.com/about"),
+            ("title", "/not-a-url"),
+            ("src", "https://cdn.example.com/logo.png"),
+            ("alt", "logo"),
+            ("poster", "https://cdn.example.com/poster.jpg"),
+            ("formaction", "https://other.example/submit"),
+        ]
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+    );
+}
+
+#[test]
+fn test_rewrites_each_srcset_candidate() {
+    let events = node(
+        "img",
+        &[(
+            "srcset",
+            " /small.png 480w,/large.png   1080w , https://x.test/a.png 2x",
+        )],
+    );
+    let mut seen = Vec::new();
+    let mut parser = UrlRewriter::new(Replay(events.into()), |attr| {
+        seen.push((attr.tag.to_string(), attr.url.to_string()));
+        absolutize(attr.url)
+    });
+    assert_eq!(
+        attributes(&mut parser),
+        [(
+            "srcset".to_string(),
+            "https://cdn.example.com/small.png 480w, https://cdn.example.com/large.png 1080w, \
+             https://x.test/a.png 2x"
+                .to_string()
+        )]
+    );
+    drop(parser);
+    assert_eq!(
+        seen,
+        [
+            ("img", "/small.png"),
+            ("img", "/large.png"),
+            ("img", "https://x.test/a.png"),
+        ]
+        .map(|(tag, url)| (tag.to_string(), url.to_string()))
+    );
+}
+
+#[test]
+fn test_unchanged_srcset_is_left_verbatim() {
+    let events = node("img", &[("srcset", "a.png 1x,b.png 2x")]);
+    let mut parser = UrlRewriter::new(Replay(events.into()), |_| None);
+    assert_eq!(
+        attributes(&mut parser),
+        [("srcset".to_string(), "a.png 1x,b.png 2x".to_string())]
+    );
+}
+
+#[test]
+fn test_skip_node_after_peek() {
+    let mut events = node("div", &[]);
+    events.splice(2..2, node("img", &[("src", "/skipped.png")]));
+    events.extend(node("a", &[("href", "/kept")]));
+
+    let mut parser = UrlRewriter::new(Replay(events.into()), |attr| absolutize(attr.url));
+    assert!(matches!(
+        parser.next_event(),
+        Ok(Some(DomEvent::NodeStart { .. }))
+    ));
+    parser.peek_event().unwrap();
+    parser.skip_node().unwrap();
+    assert_eq!(
+        attributes(&mut parser),
+        [(
+            "href".to_string(),
+            "https://cdn.example.com/kept".to_string()
+        )]
+    );
+}
+
+#[test]
+fn test_parse_srcset_grammar() {
+    assert!(parse_srcset("").is_empty());
+    assert!(parse_srcset(" , ,").is_empty());
+    assert_eq!(
+        parse_srcset("a.png,b.png"),
+        [SrcsetCandidate {
+            url: "a.png,b.png",
+            descriptor: ""
+        }]
+    );
+    assert_eq!(
+        parse_srcset("a.png,, b.png 100w"),
+        [
+            SrcsetCandidate {
+                url: "a.png",
+                descriptor: ""
+            },
+            SrcsetCandidate {
+                url: "b.png",
+                descriptor: "100w"
+            },
+        ]
+    );
+    assert_eq!(
+        parse_srcset("a.png future(1, 2), b.png"),
+        [
+            SrcsetCandidate {
+                url: "a.png",
+                descriptor: "future(1, 2)"
+            },
+            SrcsetCandidate {
+                url: "b.png",
+                descriptor: ""
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_format_srcset_round_trips() {
+    for srcset in [
+        "a.png",
+        "a.png 1x, b.png 2x",
+        "b,c.png 2x, d.png",
+        "a.png future(1, 2), b.png 100w",
+    ] {
+        assert_eq!(format_srcset(parse_srcset(srcset)), srcset);
+    }
+    assert_eq!(
+        format_srcset(parse_srcset(" a.png  1x ,, b.png")),
+        "a.png 1x, b.png"
+    );
+    assert_eq!(format_srcset([]), "");
+}
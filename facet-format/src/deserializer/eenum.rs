@@ -806,8 +806,9 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
             false
         };
 
-        // Track currently open path segments for flatten handling: (field_name, is_option)
-        let mut open_segments: Vec<(&str, bool)> = Vec::new();
+        // Track currently open path segments for flatten handling:
+        // (field_name, number of Option/pointer frames entered inside the field)
+        let mut open_segments: Vec<(&str, usize)> = Vec::new();
 
         loop {
             let event = self.expect_event("value")?;
@@ -840,21 +841,30 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
 
                             // Close segments that are no longer needed (in reverse order)
                             while open_segments.len() > common_len {
-                                let (_, is_option) = open_segments.pop().unwrap();
-                                if is_option {
+                                let (_, wrappers) = open_segments.pop().unwrap();
+                                for _ in 0..wrappers {
                                     wip = wip.end()?;
                                 }
                                 wip = wip.end()?;
                             }
 
                             // Open new segments
-                            for &field_name in &path[common_len..] {
+                            for (depth, &field_name) in path.iter().enumerate().skip(common_len) {
                                 wip = wip.begin_field(field_name)?;
-                                let is_option = matches!(wip.shape().def, Def::Option(_));
-                                if is_option {
+                                let mut wrappers = 0;
+                                if matches!(wip.shape().def, Def::Option(_)) {
                                     wip = wip.begin_some()?;
+                                    wrappers += 1;
                                 }
-                                open_segments.push((field_name, is_option));
+                                // A flattened struct behind a smart pointer is built in place;
+                                // the leaf value is left to deserialize_into.
+                                if depth + 1 < path.len()
+                                    && matches!(wip.shape().def, Def::Pointer(_))
+                                {
+                                    wip = wip.begin_smart_ptr()?;
+                                    wrappers += 1;
+                                }
+                                open_segments.push((field_name, wrappers));
                             }
 
                             // Deserialize the value
@@ -862,8 +872,8 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
 
                             // Close the leaf field we just deserialized into
                             // (but keep parent segments open for potential sibling fields)
-                            if let Some((_, is_option)) = open_segments.pop() {
-                                if is_option {
+                            if let Some((_, wrappers)) = open_segments.pop() {
+                                for _ in 0..wrappers {
                                     wip = wip.end()?;
                                 }
                                 wip = wip.end()?;
@@ -907,8 +917,8 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
         }
 
         // Close any remaining open segments
-        while let Some((_, is_option)) = open_segments.pop() {
-            if is_option {
+        while let Some((_, wrappers)) = open_segments.pop() {
+            for _ in 0..wrappers {
                 wip = wip.end()?;
             }
             wip = wip.end()?;
@@ -1733,6 +1743,11 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
             StructKind::Struct => {
                 // Struct variant - expect object with fields
                 let event = self.expect_event("value")?;
+                if matches!(event.kind, ParseEventKind::SequenceStart(_))
+                    && self.parser.accepts_positional_structs()
+                {
+                    return self.deserialize_positional_fields(wip, variant_fields);
+                }
                 if !matches!(event.kind, ParseEventKind::StructStart(_)) {
                     return Err(DeserializeError {
                         span: Some(self.last_span),
@@ -1757,7 +1772,7 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
                 let mut ordered_field_index = 0usize;
 
                 // Track currently open path segments for flatten handling
-                let mut open_segments: Vec<(&str, bool)> = Vec::new();
+                let mut open_segments: Vec<(&str, usize)> = Vec::new();
 
                 // Track which top-level fields have been touched
                 let mut touched_fields: std::collections::BTreeSet<&str> =
@@ -1799,26 +1814,37 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
                                         .count();
 
                                     while open_segments.len() > common_len {
-                                        let (_, is_option) = open_segments.pop().unwrap();
-                                        if is_option {
+                                        let (_, wrappers) = open_segments.pop().unwrap();
+                                        for _ in 0..wrappers {
                                             wip = wip.end()?;
                                         }
                                         wip = wip.end()?;
                                     }
 
-                                    for &field_name in &path[common_len..] {
+                                    for (depth, &field_name) in
+                                        path.iter().enumerate().skip(common_len)
+                                    {
                                         wip = wip.begin_field(field_name)?;
-                                        let is_option = matches!(wip.shape().def, Def::Option(_));
-                                        if is_option {
+                                        let mut wrappers = 0;
+                                        if matches!(wip.shape().def, Def::Option(_)) {
                                             wip = wip.begin_some()?;
+                                            wrappers += 1;
+                                        }
+                                        // A flattened struct behind a smart pointer is built in place;
+                                        // the leaf value is left to deserialize_into.
+                                        if depth + 1 < path.len()
+                                            && matches!(wip.shape().def, Def::Pointer(_))
+                                        {
+                                            wip = wip.begin_smart_ptr()?;
+                                            wrappers += 1;
                                         }
-                                        open_segments.push((field_name, is_option));
+                                        open_segments.push((field_name, wrappers));
                                     }
 
                                     wip = self.deserialize_into(wip, MetaSource::FromEvents)?;
 
-                                    if let Some((_, is_option)) = open_segments.pop() {
-                                        if is_option {
+                                    if let Some((_, wrappers)) = open_segments.pop() {
+                                        for _ in 0..wrappers {
                                             wip = wip.end()?;
                                         }
                                         wip = wip.end()?;
@@ -1857,8 +1883,8 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
                 }
 
                 // Close any remaining open segments
-                while let Some((_, is_option)) = open_segments.pop() {
-                    if is_option {
+                while let Some((_, wrappers)) = open_segments.pop() {
+                    for _ in 0..wrappers {
                         wip = wip.end()?;
                     }
                     wip = wip.end()?;
@@ -2023,7 +2049,8 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
             }
             ParseEventKind::StructStart(_) => {
                 // For struct input, use solve_variant for proper field-based matching
-                let solve_result = self.solve_variant(shape).map_err(|e| match e {
+                let inherited = wip.plan_node().and_then(|node| node.inherited);
+                let solve_result = self.solve_variant(shape, inherited).map_err(|e| match e {
                     crate::SolveVariantError::Parser(e) => {
                         // Convert ParseError to DeserializeError, adding path
                         DeserializeError::from(e).set_path(wip.path())
@@ -2050,7 +2077,7 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
                         .resolution()
                         .variant_selections()
                         .first()
-                        .map(|vs| vs.variant_name)
+                        .map(|vs| vs.serialized_name)
                         .ok_or_else(|| {
                             self.mk_err(
                                 &wip,
@@ -2265,12 +2292,8 @@ fn find_field_path(fields: &'static [Field], key: &str) -> Option<Vec<&'static s
 
         // If this is a flattened field, search recursively
         if field.is_flattened() {
-            let shape = field.shape();
-            // Unwrap Option if present
-            let inner_shape = match shape.def {
-                Def::Option(opt) => opt.t,
-                _ => shape,
-            };
+            // Unwrap Option and smart pointers if present
+            let (inner_shape, _) = field.shape().flatten_target();
 
             if let Type::User(UserType::Struct(inner_struct)) = inner_shape.ty
                 && let Some(mut inner_path) = find_field_path(inner_struct.fields, key)
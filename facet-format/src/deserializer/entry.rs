@@ -39,6 +39,49 @@ pub enum MetaSource<'a> {
     FromEvents,
 }
 
+/// A wrapper layer between a frame and the value actually read from the input.
+///
+/// Wrappers nest in any order: `Transparent(Newtype(Proxied))`,
+/// `Option<Box<Transparent(T)>>`, a proxy whose proxy type is itself a
+/// newtype. They are always unwrapped one hop at a time, outermost first, from
+/// the frame's precomputed [`DeserStrategy`], so the order is the one TypePlan
+/// defines for a single type (field proxy, container proxy, pointer, metadata
+/// container, transparent convert, transparent newtype) and no hop is skipped.
+/// Values and map keys both go through [`WrapperHop::of`].
+///
+/// Options, pointers and metadata containers aren't hops here: they need
+/// format-specific handling (`null`, `&str`, spans) before recursing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum WrapperHop {
+    /// The field being filled has `#[facet(proxy = X)]`.
+    FieldProxy,
+    /// The type itself has `#[facet(proxy = X)]`.
+    ContainerProxy,
+    /// A type with an inner value and `try_from`, like `NonZero<T>`.
+    TransparentConvert,
+    /// A `#[facet(transparent)]` or `#[repr(transparent)]` newtype, entered
+    /// through its field so that field's own proxy still applies. The value is
+    /// read without a sequence wrapper (`value = 42` for `Wrapper(i32)` in
+    /// TOML), and before any tuple hints reach non-self-describing parsers.
+    TransparentField,
+}
+
+impl WrapperHop {
+    /// The hop to take from a frame with this strategy, if it is a wrapper.
+    pub(crate) fn of(strategy: Option<&DeserStrategy>) -> Option<Self> {
+        match strategy? {
+            DeserStrategy::FieldProxy => Some(Self::FieldProxy),
+            DeserStrategy::ContainerProxy => Some(Self::ContainerProxy),
+            DeserStrategy::TransparentConvert { .. } => Some(Self::TransparentConvert),
+            DeserStrategy::Tuple {
+                is_single_field_transparent: true,
+                ..
+            } => Some(Self::TransparentField),
+            _ => None,
+        }
+    }
+}
+
 impl<'a> From<&'a ValueMeta<'a>> for MetaSource<'a> {
     fn from(meta: &'a ValueMeta<'a>) -> Self {
         MetaSource::Explicit(meta)
@@ -120,21 +163,17 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
         let strategy = wip.deser_strategy();
         trace!(?strategy, "deserialize_into: using precomputed strategy");
 
-        match strategy {
-            Some(DeserStrategy::ContainerProxy) => self.deserialize_container_proxy(wip, meta),
-
-            Some(DeserStrategy::FieldProxy) => self.deserialize_field_proxy(wip, meta),
+        if let Some(hop) = WrapperHop::of(strategy) {
+            trace!(?hop, "deserialize_into: stepping through wrapper");
+            return self.deserialize_through(wip, hop, meta);
+        }
 
+        match strategy {
             Some(DeserStrategy::Pointer { .. }) => {
                 trace!("deserialize_into: dispatching to deserialize_pointer");
                 self.deserialize_pointer(wip, meta)
             }
 
-            Some(DeserStrategy::TransparentConvert { .. }) => {
-                trace!("deserialize_into: dispatching via begin_inner (transparent convert)");
-                self.deserialize_transparent_convert(wip, meta)
-            }
-
             Some(DeserStrategy::Scalar {
                 scalar_type,
                 is_from_str,
@@ -150,14 +189,10 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
                 self.deserialize_struct(wip)
             }
 
-            Some(DeserStrategy::Tuple {
-                field_count,
-                is_single_field_transparent,
-            }) => {
+            Some(DeserStrategy::Tuple { field_count, .. }) => {
                 let field_count = *field_count;
-                let is_single_field_transparent = *is_single_field_transparent;
                 trace!("deserialize_into: dispatching to deserialize_tuple");
-                self.deserialize_tuple(wip, field_count, is_single_field_transparent)
+                self.deserialize_tuple(wip, field_count)
             }
 
             Some(DeserStrategy::Enum) => {
@@ -223,40 +258,41 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
         }
     }
 
-    #[inline(never)]
-    fn deserialize_container_proxy(
-        &mut self,
+    /// Step into a wrapper layer. The caller deserializes the wrapped value
+    /// and calls `end()` to convert it back into the wrapper.
+    ///
+    /// Returns false, with the frame unchanged, for a container whose only
+    /// proxies are for other formats.
+    fn begin_wrapper(
+        &self,
         wip: Partial<'input, BORROW>,
-        meta: MetaSource<'input>,
-    ) -> Result<Partial<'input, BORROW>, DeserializeError> {
+        hop: WrapperHop,
+    ) -> Result<(Partial<'input, BORROW>, bool), DeserializeError> {
         let format_ns = self.parser.format_namespace();
-        let (wip, _) = wip.begin_custom_deserialization_from_shape_with_format(format_ns)?;
-        Ok(wip.with(|w| self.deserialize_into(w, meta))?.end()?)
+        Ok(match hop {
+            WrapperHop::FieldProxy => (
+                wip.begin_custom_deserialization_with_format(format_ns)?,
+                true,
+            ),
+            WrapperHop::ContainerProxy => {
+                wip.begin_custom_deserialization_from_shape_with_format(format_ns)?
+            }
+            WrapperHop::TransparentConvert => (wip.begin_inner()?, true),
+            WrapperHop::TransparentField => (wip.begin_nth_field(0)?, true),
+        })
     }
 
     #[inline(never)]
-    fn deserialize_field_proxy(
+    fn deserialize_through(
         &mut self,
         wip: Partial<'input, BORROW>,
+        hop: WrapperHop,
         meta: MetaSource<'input>,
     ) -> Result<Partial<'input, BORROW>, DeserializeError> {
-        let format_ns = self.parser.format_namespace();
-        let wip = wip.begin_custom_deserialization_with_format(format_ns)?;
+        let (wip, _) = self.begin_wrapper(wip, hop)?;
         Ok(wip.with(|w| self.deserialize_into(w, meta))?.end()?)
     }
 
-    #[inline(never)]
-    fn deserialize_transparent_convert(
-        &mut self,
-        wip: Partial<'input, BORROW>,
-        meta: MetaSource<'input>,
-    ) -> Result<Partial<'input, BORROW>, DeserializeError> {
-        Ok(wip
-            .begin_inner()?
-            .with(|w| self.deserialize_into(w, meta))?
-            .end()?)
-    }
-
     #[inline(never)]
     fn deserialize_opaque(
         &mut self,
@@ -689,29 +725,12 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
         &mut self,
         mut wip: Partial<'input, BORROW>,
         field_count: usize,
-        is_single_field_transparent: bool,
     ) -> Result<Partial<'input, BORROW>, DeserializeError> {
         let _guard = SpanGuard::new(self.last_span);
 
-        // Special case: transparent newtypes (marked with #[facet(transparent)] or
-        // #[repr(transparent)]) can accept values directly without a sequence wrapper.
-        // This enables patterns like:
-        //   #[facet(transparent)]
-        //   struct Wrapper(i32);
-        //   toml: "value = 42"  ->  Wrapper(42)
-        // Plain tuple structs without the transparent attribute use array syntax.
-        //
-        // IMPORTANT: This check must come BEFORE hint_struct_fields() because transparent
-        // newtypes don't consume struct events - they deserialize the inner value directly.
-        // If we hint struct fields first, non-self-describing parsers will expect to emit
-        // StructStart, causing "unexpected token: got struct start" errors.
-        if is_single_field_transparent {
-            // Unwrap into field 0 and deserialize directly
-            return Ok(wip
-                .begin_nth_field(0)?
-                .with(|w| self.deserialize_into(w, MetaSource::FromEvents))?
-                .end()?);
-        }
+        // Transparent newtypes never get here: they accept their field's value
+        // directly, without a sequence wrapper, and are unwrapped as a
+        // `WrapperHop` before any tuple hints are sent to the parser.
 
         // Hint to non-self-describing parsers how many fields to expect
         // Tuples are like positional structs, so we use hint_struct_fields
@@ -937,6 +956,11 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
             ParseEventKind::SequenceStart(_) => {
                 trace!("deserialize_list: got sequence start");
             }
+            // Self-describing formats with a native byte string type (MessagePack's
+            // `bin`) emit it as bytes without needing `hint_byte_sequence`
+            ParseEventKind::Scalar(ScalarValue::Bytes(bytes)) if is_byte_vec => {
+                return self.set_bytes_value(wip, bytes);
+            }
             ParseEventKind::StructStart(kind) => {
                 return Err(DeserializeError {
                     span: Some(self.last_span),
@@ -1226,6 +1250,43 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
         Ok(wip)
     }
 
+    /// Coerce a scalar for a `#[facet(lenient_bool)]` field, using the shared
+    /// [`facet_core::parse_lenient_bool`] rules. Other events are left to the
+    /// usual handling.
+    fn lenient_bool(
+        &self,
+        wip: &Partial<'input, BORROW>,
+        kind: ParseEventKind<'input>,
+    ) -> Result<ParseEventKind<'input>, DeserializeError> {
+        let (parsed, got) = match &kind {
+            ParseEventKind::Scalar(ScalarValue::Str(s)) => {
+                (facet_core::parse_lenient_bool(s), format!("{s:?}"))
+            }
+            ParseEventKind::Scalar(ScalarValue::I64(n)) => {
+                let text = n.to_string();
+                (facet_core::parse_lenient_bool(&text), text)
+            }
+            ParseEventKind::Scalar(ScalarValue::U64(n)) => {
+                let text = n.to_string();
+                (facet_core::parse_lenient_bool(&text), text)
+            }
+            _ => return Ok(kind),
+        };
+        match parsed {
+            Some(b) => Ok(ParseEventKind::Scalar(ScalarValue::Bool(b))),
+            None => Err(DeserializeError {
+                span: Some(self.last_span),
+                path: Some(wip.path()),
+                kind: DeserializeErrorKind::InvalidValue {
+                    message: format!(
+                        "expected a boolean (true/false, yes/no, on/off or 1/0), got {got}"
+                    )
+                    .into(),
+                },
+            }),
+        }
+    }
+
     pub(crate) fn deserialize_scalar(
         &mut self,
         mut wip: Partial<'input, BORROW>,
@@ -1262,7 +1323,22 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
             }
         }
 
-        let event = self.expect_event("value")?;
+        let mut event = self.expect_event("value")?;
+        if !self.is_non_self_describing() {
+            event = match scalar_type {
+                None => self
+                    .parser
+                    .opaque_scalar_event(wip.shape(), wip.nearest_field(), event)?,
+                Some(scalar_type) => self.parser.primitive_scalar_event(scalar_type, event)?,
+            };
+        }
+        if scalar_type == Some(ScalarType::Bool)
+            && wip
+                .nearest_field()
+                .is_some_and(|f| f.has_builtin_attr("lenient_bool"))
+        {
+            event.kind = self.lenient_bool(&wip, event.kind)?;
+        }
 
         match event.kind {
             ParseEventKind::Scalar(scalar) => {
@@ -1330,7 +1406,7 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
     /// - String types: set directly
     /// - Enum unit variants: use select_variant_named
     /// - Integer types: parse the string as a number
-    /// - Transparent newtypes: descend into the inner type
+    /// - Proxies and transparent newtypes: step through them as for values (see `WrapperHop`)
     /// - Option types: None key becomes None, Some(key) recurses into inner type
     /// - Metadata containers (like `Documented<T>`): populate doc/tag metadata and recurse into value
     ///
@@ -1347,15 +1423,16 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
 
         trace!(shape_name = %shape, shape_def = ?shape.def, ?key, ?meta, "deserialize_map_key");
 
-        let format_ns = self.parser.format_namespace();
-        let (next_wip, began_proxy) =
-            wip.begin_custom_deserialization_from_shape_with_format(format_ns)?;
-        if began_proxy {
-            return Ok(next_wip
-                .with(|w| self.deserialize_map_key(w, key, meta))?
-                .end()?);
+        // Same unwrap order as values, so a newtype key keeps its field proxy
+        if let Some(hop) = WrapperHop::of(wip.deser_strategy()) {
+            let (next_wip, entered) = self.begin_wrapper(wip, hop)?;
+            if entered {
+                return Ok(next_wip
+                    .with(|w| self.deserialize_map_key(w, key, meta))?
+                    .end()?);
+            }
+            wip = next_wip;
         }
-        wip = next_wip;
 
         // Handle metadata containers (like `Documented<T>` or `ObjectKey`): populate metadata and recurse into value
         if shape.is_metadata_container() {
@@ -1427,8 +1504,8 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
                 },
             })?;
 
-        // For transparent types (like UserId(String)), we need to use begin_inner
-        // to set the inner value. But NOT for pointer types like &str or Cow<str>
+        // Other types with an inner value (scalars with `inner` but no `try_from`)
+        // are set through begin_inner. But NOT pointer types like &str or Cow<str>,
         // which are handled directly.
         let is_pointer = matches!(shape.def, Def::Pointer(_));
         if shape.inner.is_some() && !is_pointer {
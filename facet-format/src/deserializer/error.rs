@@ -387,6 +387,26 @@ pub enum DeserializeErrorKind {
         target_type: &'static str,
     },
 
+    /// Number in range for the target type, but not exactly representable.
+    ///
+    /// **Level:** Deserializer (`FormatDeserializer`)
+    ///
+    /// Integer fields accept floats with no fractional part (`1.0`), but not
+    /// others.
+    ///
+    /// ```text
+    /// // Deserializing into u8
+    /// 1.5
+    /// ^^^
+    /// number `1.5` not exactly representable as u8
+    /// ```
+    NumberNotExact {
+        /// The numeric value as a string.
+        value: Cow<'static, str>,
+        /// The target type that couldn't represent the value.
+        target_type: &'static str,
+    },
+
     /// Invalid value for the target type.
     ///
     /// **Level:** Deserializer (`FormatDeserializer`)
@@ -634,6 +654,12 @@ impl fmt::Display for DeserializeErrorKind {
             DeserializeErrorKind::NumberOutOfRange { value, target_type } => {
                 write!(f, "number `{value}` out of range for {target_type}")
             }
+            DeserializeErrorKind::NumberNotExact { value, target_type } => {
+                write!(
+                    f,
+                    "number `{value}` not exactly representable as {target_type}"
+                )
+            }
             DeserializeErrorKind::InvalidValue { message } => {
                 write!(f, "invalid value: {message}")
             }
@@ -125,13 +125,14 @@
 
 use std::collections::VecDeque;
 use std::marker::PhantomData;
-use std::sync::Arc;
+use std::time::Instant;
 
 use facet_core::{Facet, Shape};
 use facet_reflect::{HeapValue, Partial, Span};
 use facet_solver::{FieldInfo, KeyResult, SatisfyResult, Schema, Solver};
 
-use crate::{FormatParser, ParseEvent, type_plan_cache::cached_type_plan_arc};
+use crate::metrics::MetricsRecorder;
+use crate::{FormatParser, ParseEvent, ParseMetrics, type_plan_cache::cached_type_plan_arc};
 
 mod error;
 pub use entry::MetaSource;
@@ -164,6 +165,10 @@ mod struct_with_flatten;
 /// Path navigation for flattened struct deserialization
 mod path_navigator;
 
+/// Deserialization in slices, suspended between sequence items
+mod resumable;
+pub use resumable::{ResumableSequence, Step};
+
 /// Default size of the event buffer for batched parsing.
 pub const DEFAULT_EVENT_BUFFER_SIZE: usize = 512;
 
@@ -174,6 +179,7 @@ pub const DEFAULT_EVENT_BUFFER_SIZE: usize = 512;
 struct DeserializerSavePoint<'input> {
     parser_save_point: crate::SavePoint,
     event_buffer: VecDeque<ParseEvent<'input>>,
+    metrics: Option<MetricsRecorder>,
 }
 
 /// Generic deserializer that drives a format-specific parser directly into `Partial`.
@@ -208,6 +214,9 @@ pub struct FormatDeserializer<'parser, 'input, const BORROW: bool> {
     /// immediately, which buffered events would defeat.
     bypass_event_buffer: bool,
 
+    /// Cost accounting, when enabled with [`Self::with_metrics`].
+    metrics: Option<MetricsRecorder>,
+
     _marker: PhantomData<&'input ()>,
 }
 
@@ -231,6 +240,7 @@ impl<'parser, 'input> FormatDeserializer<'parser, 'input, true> {
             buffer_capacity,
             is_non_self_describing,
             bypass_event_buffer,
+            metrics: None,
             _marker: PhantomData,
         }
     }
@@ -256,6 +266,7 @@ impl<'parser, 'input> FormatDeserializer<'parser, 'input, false> {
             buffer_capacity,
             is_non_self_describing,
             bypass_event_buffer,
+            metrics: None,
             _marker: PhantomData,
         }
     }
@@ -267,6 +278,41 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
         self.parser
     }
 
+    /// Collect [`ParseMetrics`] for everything this deserializer reads.
+    ///
+    /// Off by default: counting costs a little on every event.
+    pub fn with_metrics(mut self) -> Self {
+        self.metrics = Some(MetricsRecorder::default());
+        self
+    }
+
+    /// What has been read so far, if [`Self::with_metrics`] was enabled.
+    pub fn metrics(&self) -> Option<ParseMetrics> {
+        self.metrics.map(|recorder| recorder.metrics)
+    }
+
+    /// Account for a consumed event.
+    #[inline]
+    fn record_event(&mut self, event: &ParseEvent<'input>) {
+        if let Some(recorder) = &mut self.metrics {
+            recorder.event(event, BORROW);
+        }
+    }
+
+    /// Start timing solver work, if metrics are being collected.
+    #[inline]
+    fn start_solver_timer(&self) -> Option<Instant> {
+        self.metrics.as_ref().map(|_| Instant::now())
+    }
+
+    /// Add the time since [`Self::start_solver_timer`] to the solver time.
+    #[inline]
+    fn stop_solver_timer(&mut self, started: Option<Instant>) {
+        if let (Some(started), Some(recorder)) = (started, &mut self.metrics) {
+            recorder.metrics.solver_time += started.elapsed();
+        }
+    }
+
     /// Save deserializer state (both parser position AND event buffer).
     ///
     /// This must be used instead of calling `parser.save()` directly, because
@@ -276,6 +322,7 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
         DeserializerSavePoint {
             parser_save_point: self.parser.save(),
             event_buffer: self.event_buffer.clone(),
+            metrics: self.metrics,
         }
     }
 
@@ -283,6 +330,9 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
     fn restore(&mut self, save_point: DeserializerSavePoint<'input>) {
         self.parser.restore(save_point.parser_save_point);
         self.event_buffer = save_point.event_buffer;
+        if let (Some(recorder), Some(saved)) = (&mut self.metrics, save_point.metrics) {
+            recorder.restore(saved);
+        }
     }
 }
 
@@ -469,6 +519,7 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
             })?;
             trace!(?event, expected, "expect_event (direct): got event");
             self.last_span = event.span;
+            self.record_event(&event);
             return Ok(event);
         }
 
@@ -483,6 +534,7 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
 
         trace!(?event, expected, "expect_event: got event");
         self.last_span = event.span;
+        self.record_event(&event);
         Ok(event)
     }
 
@@ -644,6 +696,7 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
             let event = self.parser.next_event()?;
             if let Some(ref event) = event {
                 self.last_span = event.span;
+                self.record_event(event);
             }
             return Ok(event);
         }
@@ -658,28 +711,55 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
         };
 
         self.last_span = event.span;
+        self.record_event(&event);
         Ok(Some(event))
     }
 
     /// Attempt to solve which enum variant matches the input.
     ///
-    /// This uses save/restore to read ahead and determine the variant without
-    /// consuming the events permanently. After this returns, the position
-    /// is restored so the actual deserialization can proceed.
+    /// The object's events are usually already in the event buffer, and most
+    /// untagged variants are told apart by their first distinguishing key, so
+    /// the buffer is scanned in place first. Only when it runs out before the
+    /// solver settles does this read ahead with save/restore, so that the
+    /// actual deserialization can proceed from the start of the object.
+    ///
+    /// `inherited` is the `#[facet(defaults_for_children)]` handed down to the enum.
     pub(crate) fn solve_variant(
         &mut self,
         shape: &'static facet_core::Shape,
+        inherited: Option<facet_core::ChildDefaults>,
+    ) -> Result<Option<crate::SolveOutcome>, crate::SolveVariantError> {
+        let started = self.start_solver_timer();
+        let result = self.probe_variant(shape, inherited);
+        self.stop_solver_timer(started);
+        result
+    }
+
+    /// [`Self::solve_variant`] without the solver timing.
+    fn probe_variant(
+        &mut self,
+        shape: &'static facet_core::Shape,
+        inherited: Option<facet_core::ChildDefaults>,
     ) -> Result<Option<crate::SolveOutcome>, crate::SolveVariantError> {
-        let schema = Arc::new(Schema::build_auto(shape)?);
-        let mut solver = Solver::new(&schema);
+        let schema = Schema::cached_inheriting(shape, inherited)?;
+
+        let outcome = |resolution_index: Option<usize>| {
+            resolution_index.map(|resolution_index| crate::SolveOutcome {
+                schema: schema.clone(),
+                resolution_index,
+            })
+        };
+
+        let mut probe = VariantProbe::new(&schema);
+        for event in &self.event_buffer {
+            if let Some(result) = probe.feed(event) {
+                return Ok(outcome(result));
+            }
+        }
 
         // Save deserializer state (parser position AND event buffer)
         let save_point = self.save();
-
-        let mut depth = 0i32;
-        let mut in_struct = false;
-        let mut expecting_value = false;
-        let mut pending_ambiguous: Option<(String, Vec<(&FieldInfo, u64)>)> = None;
+        let mut probe = VariantProbe::new(&schema);
 
         let result = loop {
             let event = self.next_event_opt().map_err(|e| {
@@ -695,90 +775,15 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
                 return Ok(None);
             };
 
-            if expecting_value && depth == 1 && in_struct {
-                expecting_value = false;
-                if let Some((key, fields)) = pending_ambiguous.take()
-                    && let crate::ParseEventKind::Scalar(scalar) = &event.kind
-                {
-                    let satisfied_shapes = select_best_ambiguous_scalar_shapes(scalar, &fields);
-                    match solver.satisfy_at_path(&[key.as_str()], &satisfied_shapes) {
-                        SatisfyResult::Solved(handle) => break Some(handle),
-                        SatisfyResult::NoMatch => break None,
-                        SatisfyResult::Continue => {}
-                        // A solver result added since this match was written: keep
-                        // scanning (the loop still terminates on struct end / EOF).
-                        _ => {}
-                    }
-                }
-            }
-
-            match event.kind {
-                crate::ParseEventKind::StructStart(_) => {
-                    depth += 1;
-                    if depth == 1 {
-                        in_struct = true;
-                    }
-                }
-                crate::ParseEventKind::StructEnd => {
-                    depth -= 1;
-                    if depth == 0 {
-                        // Done with top-level struct
-                        break None;
-                    }
-                }
-                crate::ParseEventKind::SequenceStart(_) => {
-                    depth += 1;
-                }
-                crate::ParseEventKind::SequenceEnd => {
-                    depth -= 1;
-                }
-                crate::ParseEventKind::FieldKey(ref key) => {
-                    if depth == 1 && in_struct {
-                        // Top-level field - feed to solver
-                        if let Some(name) = key.name() {
-                            match solver.see_key(name.clone()) {
-                                KeyResult::Solved(handle) => {
-                                    break Some(handle);
-                                }
-                                KeyResult::Ambiguous { fields } => {
-                                    pending_ambiguous = Some((name.to_string(), fields));
-                                }
-                                KeyResult::Unknown | KeyResult::Unambiguous { .. } => {
-                                    pending_ambiguous = None;
-                                }
-                                // A key result added since this match was written.
-                                _ => {
-                                    pending_ambiguous = None;
-                                }
-                            }
-                        }
-                        expecting_value = true;
-                    }
-                }
-                crate::ParseEventKind::Scalar(_)
-                | crate::ParseEventKind::OrderedField
-                | crate::ParseEventKind::VariantTag(_) => {
-                    if expecting_value {
-                        expecting_value = false;
-                    }
-                }
-                crate::ParseEventKind::OptionSome => {}
+            if let Some(result) = probe.feed(&event) {
+                break result;
             }
         };
 
         // Restore deserializer state regardless of outcome
         self.restore(save_point);
 
-        match result {
-            Some(handle) => {
-                let idx = handle.index();
-                Ok(Some(crate::SolveOutcome {
-                    schema,
-                    resolution_index: idx,
-                }))
-            }
-            None => Ok(None),
-        }
+        Ok(outcome(result))
     }
 
     /// Make an error using the last span, the current path of the given wip.
@@ -795,6 +800,105 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
     }
 }
 
+/// Feeds the top-level keys of an object to the solver one event at a time,
+/// along with the scalar values of keys that several variants declare with
+/// different types.
+struct VariantProbe<'a> {
+    solver: Solver<'a>,
+    depth: i32,
+    in_struct: bool,
+    expecting_value: bool,
+    pending_ambiguous: Option<(String, Vec<(&'a FieldInfo, u64)>)>,
+}
+
+impl<'a> VariantProbe<'a> {
+    fn new(schema: &'a Schema) -> Self {
+        Self {
+            solver: Solver::new(schema),
+            depth: 0,
+            in_struct: false,
+            expecting_value: false,
+            pending_ambiguous: None,
+        }
+    }
+
+    /// Returns `Some` once the outcome is known: the index of the matching
+    /// resolution, or `None` if no variant matched by the end of the object.
+    fn feed(&mut self, event: &ParseEvent<'a>) -> Option<Option<usize>> {
+        if self.expecting_value && self.depth == 1 && self.in_struct {
+            self.expecting_value = false;
+            if let Some((key, fields)) = self.pending_ambiguous.take()
+                && let crate::ParseEventKind::Scalar(scalar) = &event.kind
+            {
+                let satisfied_shapes = select_best_ambiguous_scalar_shapes(scalar, &fields);
+                match self
+                    .solver
+                    .satisfy_at_path(&[key.as_str()], &satisfied_shapes)
+                {
+                    SatisfyResult::Solved(handle) => return Some(Some(handle.index())),
+                    SatisfyResult::NoMatch => return Some(None),
+                    SatisfyResult::Continue => {}
+                    // A solver result added since this match was written: keep
+                    // scanning (the probe still ends on struct end / EOF).
+                    _ => {}
+                }
+            }
+        }
+
+        match &event.kind {
+            crate::ParseEventKind::StructStart(_) => {
+                self.depth += 1;
+                if self.depth == 1 {
+                    self.in_struct = true;
+                }
+            }
+            crate::ParseEventKind::StructEnd => {
+                self.depth -= 1;
+                if self.depth == 0 {
+                    // Done with top-level struct
+                    return Some(None);
+                }
+            }
+            crate::ParseEventKind::SequenceStart(_) => {
+                self.depth += 1;
+            }
+            crate::ParseEventKind::SequenceEnd => {
+                self.depth -= 1;
+            }
+            crate::ParseEventKind::FieldKey(key) => {
+                if self.depth == 1 && self.in_struct {
+                    // Top-level field - feed to solver
+                    if let Some(name) = key.name() {
+                        match self.solver.see_key(name.clone()) {
+                            KeyResult::Solved(handle) => return Some(Some(handle.index())),
+                            KeyResult::Ambiguous { fields } => {
+                                self.pending_ambiguous = Some((name.to_string(), fields));
+                            }
+                            KeyResult::Unknown | KeyResult::Unambiguous { .. } => {
+                                self.pending_ambiguous = None;
+                            }
+                            // A key result added since this match was written.
+                            _ => {
+                                self.pending_ambiguous = None;
+                            }
+                        }
+                    }
+                    self.expecting_value = true;
+                }
+            }
+            crate::ParseEventKind::Scalar(_)
+            | crate::ParseEventKind::OrderedField
+            | crate::ParseEventKind::VariantTag(_) => {
+                if self.expecting_value {
+                    self.expecting_value = false;
+                }
+            }
+            crate::ParseEventKind::OptionSome => {}
+        }
+        None
+    }
+}
+
 fn select_best_ambiguous_scalar_shapes(
     scalar: &crate::ScalarValue<'_>,
     fields: &[(&FieldInfo, u64)],
@@ -14,8 +14,9 @@ use crate::{DeserializeError, SpanGuard};
 struct OpenSegment {
     /// The field name of this segment.
     name: &'static str,
-    /// Whether this segment is wrapped in an Option (and we entered Some).
-    is_option: bool,
+    /// How many wrapper frames (Option's Some, smart pointer's pointee) were
+    /// entered inside this segment's field frame.
+    wrappers: usize,
 }
 
 /// Navigates through nested flattened structures by managing open/close of path segments.
@@ -23,7 +24,7 @@ struct OpenSegment {
 /// This abstraction handles the complexity of:
 /// - Finding common prefixes between current position and target path
 /// - Closing segments that are no longer needed
-/// - Opening new segments, handling Options along the way
+/// - Opening new segments, handling Options and smart pointers along the way
 /// - Selecting enum variants when required by the resolution
 pub(crate) struct PathNavigator<'input, const BORROW: bool> {
     /// The work-in-progress partial. Stored as Option to allow taking ownership temporarily.
@@ -147,7 +148,7 @@ impl<'input, const BORROW: bool> PathNavigator<'input, BORROW> {
         })
     }
 
-    /// Open a single segment, handling Options and variant selection.
+    /// Open a single segment, handling Options, smart pointers and variant selection.
     fn open_segment(
         &mut self,
         name: &'static str,
@@ -157,9 +158,15 @@ impl<'input, const BORROW: bool> PathNavigator<'input, BORROW> {
 
         let mut wip = self.take_wip();
         wip = wip.begin_field(name)?;
-        let is_option = matches!(wip.shape().def, Def::Option(_));
-        if is_option {
+        let mut wrappers = 0;
+        if matches!(wip.shape().def, Def::Option(_)) {
             wip = wip.begin_some()?;
+            wrappers += 1;
+        }
+        // A flatten container behind `Box<T>`, `Arc<T>`, ... is built in place.
+        if matches!(wip.shape().def, Def::Pointer(_)) {
+            wip = wip.begin_smart_ptr()?;
+            wrappers += 1;
         }
 
         // Check if we need to select a variant at this point
@@ -192,13 +199,13 @@ impl<'input, const BORROW: bool> PathNavigator<'input, BORROW> {
                     "open_segment: selecting variant '{}' at path {:?}",
                     vs.variant_name, current_path
                 );
-                wip = wip.select_variant_named(vs.variant_name)?;
+                wip = wip.select_variant_named(vs.serialized_name)?;
                 break;
             }
         }
 
         self.return_wip(wip);
-        self.open_segments.push(OpenSegment { name, is_option });
+        self.open_segments.push(OpenSegment { name, wrappers });
         Ok(())
     }
 
@@ -223,7 +230,7 @@ impl<'input, const BORROW: bool> PathNavigator<'input, BORROW> {
             let seg = self.open_segments.pop().unwrap();
 
             let mut wip = self.take_wip();
-            if seg.is_option {
+            for _ in 0..seg.wrappers {
                 wip = wip.end()?;
             }
             wip = wip.end()?;
@@ -242,7 +249,7 @@ impl<'input, const BORROW: bool> PathNavigator<'input, BORROW> {
         if let Some(final_seg) = nav_result.final_segment {
             self.open_segments.push(OpenSegment {
                 name: final_seg,
-                is_option: nav_result.final_is_option,
+                wrappers: usize::from(nav_result.final_is_option),
             });
         }
     }
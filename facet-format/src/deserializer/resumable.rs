@@ -0,0 +1,222 @@
+//! Streaming a top-level sequence in slices, suspending between its items.
+
+use std::marker::PhantomData;
+
+use facet_core::{Def, Facet};
+use facet_reflect::{HeapValue, Partial};
+
+use super::entry::MetaSource;
+use super::{DeserializeError, DeserializeErrorKind, FormatDeserializer, SpanGuard};
+use crate::ParseEventKind;
+use crate::type_plan_cache::cached_type_plan_arc;
+
+/// Outcome of [`ResumableSequence::step`].
+#[derive(Debug)]
+pub enum Step<T> {
+    /// The budget ran out before the value was complete; call `step` again.
+    Pending,
+    /// The value is complete.
+    Done(T),
+}
+
+/// Deserializes a top-level sequence a few items at a time, for callers that
+/// can't block for the whole document (a single-threaded async runtime, a
+/// game loop with a frame budget).
+///
+/// When the target is a list (e.g. `Vec<Record>`), each [`step`](Self::step)
+/// deserializes at most `budget` items, then returns [`Step::Pending`] with the
+/// in-progress list kept in this struct. Only the items of the top-level list
+/// are sliced: each item, however large, is deserialized in one step, and so is
+/// a target that isn't a list (a struct, a map, `Vec<u8>`). A budget of `0`
+/// counts as `1`, so every step makes progress.
+///
+/// The parser isn't owned: pass the same [`FormatDeserializer`] to every step.
+/// After an error, the in-progress value is dropped and further steps fail.
+///
+/// ```
+/// use std::collections::VecDeque;
+///
+/// use facet_format::{
+///     ContainerKind, FormatDeserializer, FormatParser, ParseError, ParseEvent, ParseEventKind,
+///     ResumableSequence, SavePoint, ScalarValue, Step,
+/// };
+/// use facet_reflect::Span;
+///
+/// /// Replays `[1, 2, 3, 4, 5]`, standing in for a format's parser.
+/// struct Replay(VecDeque<ParseEvent<'static>>);
+///
+/// impl FormatParser<'static> for Replay {
+///     fn next_event(&mut self) -> Result<Option<ParseEvent<'static>>, ParseError> {
+///         Ok(self.0.pop_front())
+///     }
+///     fn peek_event(&mut self) -> Result<Option<ParseEvent<'static>>, ParseError> {
+///         Ok(self.0.front().cloned())
+///     }
+///     fn skip_value(&mut self) -> Result<(), ParseError> {
+///         unreachable!("every item is read")
+///     }
+///     fn save(&mut self) -> SavePoint {
+///         unreachable!("no untagged enums here")
+///     }
+///     fn restore(&mut self, _save_point: SavePoint) {
+///         unreachable!("no untagged enums here")
+///     }
+/// }
+///
+/// let event = |kind| ParseEvent::new(kind, Span::new(0, 0));
+/// let mut events = VecDeque::from([event(ParseEventKind::SequenceStart(ContainerKind::Array))]);
+/// events.extend((1..=5).map(|n| event(ParseEventKind::Scalar(ScalarValue::U64(n)))));
+/// events.push_back(event(ParseEventKind::SequenceEnd));
+///
+/// let mut parser = Replay(events);
+/// let mut de = FormatDeserializer::new_owned(&mut parser);
+/// let mut stream = ResumableSequence::<Vec<u32>, false>::new_owned().unwrap();
+///
+/// let mut slices = 0;
+/// let numbers = loop {
+///     slices += 1;
+///     if let Step::Done(numbers) = stream.step(&mut de, 2).unwrap() {
+///         break numbers;
+///     }
+///     // ...render a frame, poll other tasks...
+/// };
+/// assert_eq!(numbers, [1, 2, 3, 4, 5]);
+/// assert_eq!(slices, 3);
+/// ```
+pub struct ResumableSequence<'input, T, const BORROW: bool> {
+    wip: Option<Partial<'input, BORROW>>,
+    started: bool,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<'input, T, const BORROW: bool> ResumableSequence<'input, T, BORROW> {
+    fn with_partial(wip: Partial<'input, BORROW>) -> Self {
+        Self {
+            wip: Some(wip),
+            started: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Whether a previous step finished the value or failed.
+    pub const fn is_finished(&self) -> bool {
+        self.wip.is_none()
+    }
+
+    /// Advance by up to `budget` sequence items (at least one). Returns the
+    /// complete partial once the input for the value has been consumed.
+    fn advance(
+        &mut self,
+        de: &mut FormatDeserializer<'_, 'input, BORROW>,
+        budget: usize,
+    ) -> Result<Option<Partial<'input, BORROW>>, DeserializeError> {
+        let mut wip = self.wip.take().ok_or_else(|| {
+            DeserializeErrorKind::Bug {
+                error: "step called after the value was finished".into(),
+                context: "resuming deserialization",
+            }
+            .with_span(de.last_span)
+        })?;
+
+        if !self.started {
+            self.started = true;
+            // `Vec<u8>` may be read as a single bytes event, so it goes
+            // through the regular path along with every non-list type.
+            let sliceable =
+                matches!(&wip.shape().def, Def::List(list) if !list.t().is_type::<u8>());
+            if !sliceable {
+                return de.deserialize_into(wip, MetaSource::FromEvents).map(Some);
+            }
+
+            de.parser.hint_sequence();
+            let event = de.expect_event("value")?;
+            if !matches!(event.kind, ParseEventKind::SequenceStart(_)) {
+                return Err(DeserializeError {
+                    span: Some(de.last_span),
+                    path: Some(wip.path()),
+                    kind: DeserializeErrorKind::UnexpectedToken {
+                        expected: "sequence start",
+                        got: event.kind_name().into(),
+                    },
+                });
+            }
+            wip = wip.init_list()?;
+        }
+
+        for _ in 0..budget.max(1) {
+            let event = de.expect_peek("value")?;
+            if matches!(event.kind, ParseEventKind::SequenceEnd) {
+                de.expect_event("value")?;
+                return Ok(Some(wip));
+            }
+            wip = wip
+                .begin_list_item()?
+                .with(|w| de.deserialize_into(w, MetaSource::FromEvents))?
+                .end()?;
+        }
+
+        self.wip = Some(wip);
+        Ok(None)
+    }
+}
+
+impl<'input, T> ResumableSequence<'input, T, true>
+where
+    T: Facet<'input>,
+{
+    /// Start deserializing a `T` that may borrow from the input.
+    pub fn new() -> Result<Self, DeserializeError> {
+        let wip = Partial::alloc_with_plan(cached_type_plan_arc::<T>()?)?;
+        Ok(Self::with_partial(wip))
+    }
+
+    /// Deserialize up to `budget` more items; see the type-level docs.
+    pub fn step(
+        &mut self,
+        de: &mut FormatDeserializer<'_, 'input, true>,
+        budget: usize,
+    ) -> Result<Step<T>, DeserializeError> {
+        let Some(partial) = self.advance(de, budget)? else {
+            return Ok(Step::Pending);
+        };
+        let _guard = SpanGuard::new(de.last_span);
+        let heap_value = partial.build()?;
+        Ok(Step::Done(heap_value.materialize::<T>()?))
+    }
+}
+
+impl<'input, T> ResumableSequence<'input, T, false>
+where
+    T: Facet<'static>,
+{
+    /// Start deserializing a `T` that owns all its data.
+    pub fn new_owned() -> Result<Self, DeserializeError> {
+        let wip = Partial::alloc_owned_with_plan(cached_type_plan_arc::<T>()?)?;
+        // SAFETY: alloc_owned_with_plan produces Partial<'static, false>, but deserialize_into
+        // expects 'input. Since BORROW=false means we never borrow from input anyway,
+        // this is safe.
+        #[allow(unsafe_code)]
+        let wip: Partial<'input, false> = unsafe { core::mem::transmute(wip) };
+        Ok(Self::with_partial(wip))
+    }
+
+    /// Deserialize up to `budget` more items; see the type-level docs.
+    pub fn step(
+        &mut self,
+        de: &mut FormatDeserializer<'_, 'input, false>,
+        budget: usize,
+    ) -> Result<Step<T>, DeserializeError> {
+        let Some(partial) = self.advance(de, budget)? else {
+            return Ok(Step::Pending);
+        };
+        let _guard = SpanGuard::new(de.last_span);
+        let heap_value = partial.build()?;
+
+        // SAFETY: HeapValue<'input, false> contains no borrowed data because BORROW=false.
+        // The transmute only changes the phantom lifetime marker.
+        #[allow(unsafe_code)]
+        let heap_value: HeapValue<'static, false> = unsafe { core::mem::transmute(heap_value) };
+
+        Ok(Step::Done(heap_value.materialize::<T>()?))
+    }
+}
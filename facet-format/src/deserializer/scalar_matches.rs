@@ -1,5 +1,6 @@
 use crate::ScalarValue;
-use facet_core::Def;
+use facet_core::{Def, NumericType, PrimitiveType, Type};
+use facet_reflect::{Peek, coerce_scalar};
 
 /// Check if a scalar value matches a target shape.
 ///
@@ -22,65 +23,9 @@ pub(crate) fn scalar_matches_shape(
     match scalar {
         ScalarValue::Bool(_) => matches!(scalar_type, ScalarType::Bool),
         ScalarValue::Char(_) => matches!(scalar_type, ScalarType::Char),
-        ScalarValue::I64(val) => {
-            // I64 matches signed types directly
-            if matches!(
-                scalar_type,
-                ScalarType::I8
-                    | ScalarType::I16
-                    | ScalarType::I32
-                    | ScalarType::I64
-                    | ScalarType::I128
-                    | ScalarType::ISize
-            ) {
-                return true;
-            }
-
-            // I64 can also match unsigned types if the value is non-negative and in range
-            // This handles TOML's requirement to represent all integers as i64
-            if *val >= 0 {
-                let uval = *val as u64;
-                match scalar_type {
-                    ScalarType::U8 => uval <= u8::MAX as u64,
-                    ScalarType::U16 => uval <= u16::MAX as u64,
-                    ScalarType::U32 => uval <= u32::MAX as u64,
-                    ScalarType::U64 | ScalarType::U128 | ScalarType::USize => true,
-                    _ => false,
-                }
-            } else {
-                false
-            }
-        }
-        ScalarValue::U64(val) => {
-            // U64 matches unsigned types directly
-            if matches!(
-                scalar_type,
-                ScalarType::U8
-                    | ScalarType::U16
-                    | ScalarType::U32
-                    | ScalarType::U64
-                    | ScalarType::U128
-                    | ScalarType::USize
-            ) {
-                return true;
-            }
-
-            // U64 can also match signed types if the value fits in the signed range
-            // This handles JSON's representation of positive integers as u64
-            if *val <= i64::MAX as u64 {
-                match scalar_type {
-                    ScalarType::I8 => *val <= i8::MAX as u64,
-                    ScalarType::I16 => *val <= i16::MAX as u64,
-                    ScalarType::I32 => *val <= i32::MAX as u64,
-                    ScalarType::I64 | ScalarType::I128 | ScalarType::ISize => true,
-                    _ => false,
-                }
-            } else {
-                false
-            }
+        ScalarValue::I64(_) | ScalarValue::U64(_) | ScalarValue::U128(_) | ScalarValue::I128(_) => {
+            integer_fits(scalar, shape)
         }
-        ScalarValue::U128(_) => matches!(scalar_type, ScalarType::U128 | ScalarType::I128),
-        ScalarValue::I128(_) => matches!(scalar_type, ScalarType::I128 | ScalarType::U128),
         ScalarValue::F64(_) => matches!(scalar_type, ScalarType::F32 | ScalarType::F64),
         ScalarValue::Str(s) => {
             // String scalars match string types directly
@@ -134,6 +79,25 @@ pub(crate) fn scalar_matches_shape(
     }
 }
 
+/// Whether an integer scalar fits an integer shape, as decided by
+/// [`coerce_scalar`] (so TOML's `i64` matches a `u8` variant when in range).
+fn integer_fits(scalar: &ScalarValue<'_>, shape: &'static facet_core::Shape) -> bool {
+    if !matches!(
+        shape.ty,
+        Type::Primitive(PrimitiveType::Numeric(NumericType::Integer { .. }))
+    ) {
+        return false;
+    }
+    let fits = match scalar {
+        ScalarValue::I64(n) => coerce_scalar(Peek::new(n), shape),
+        ScalarValue::U64(n) => coerce_scalar(Peek::new(n), shape),
+        ScalarValue::I128(n) => coerce_scalar(Peek::new(n), shape),
+        ScalarValue::U128(n) => coerce_scalar(Peek::new(n), shape),
+        _ => return false,
+    };
+    fits.is_ok()
+}
+
 /// Return how strong a scalar-to-shape match is.
 ///
 /// - `0`: direct type match (preferred)
@@ -2,8 +2,8 @@ extern crate alloc;
 
 use std::borrow::Cow;
 
-use facet_core::{NumericType, PrimitiveType, ScalarType, Type, UserType};
-use facet_reflect::{Partial, Span};
+use facet_core::{Facet, NumericType, PrimitiveType, ScalarType, Type, UserType};
+use facet_reflect::{CoerceError, Partial, Peek, Span, coerce_scalar};
 
 use crate::{DeserializeError, DeserializeErrorKind, FormatDeserializer, ScalarValue};
 
@@ -21,12 +21,42 @@ fn number_out_of_range(
     }
 }
 
-fn narrow_int<T, U>(value: U, target_type: &'static str) -> Result<T, DeserializeError>
+fn number_not_exact(value: impl core::fmt::Display, target_type: &'static str) -> DeserializeError {
+    DeserializeError {
+        span: None,
+        path: None,
+        kind: DeserializeErrorKind::NumberNotExact {
+            value: value.to_string().into(),
+            target_type,
+        },
+    }
+}
+
+/// Set a parsed scalar through [`coerce_scalar`], so every format agrees on
+/// which numbers fit which fields.
+///
+/// Targets that aren't primitive scalars get `value` as-is (which covers
+/// `try_from` conversions), except that `String` targets get it formatted.
+#[allow(clippy::result_large_err)]
+fn set_coerced<'input, const BORROW: bool, T>(
+    wip: Partial<'input, BORROW>,
+    value: T,
+) -> Result<Partial<'input, BORROW>, SetScalarResult<'input, BORROW>>
 where
-    T: TryFrom<U>,
-    U: Copy + core::fmt::Display,
+    T: Facet<'input> + core::fmt::Display,
 {
-    T::try_from(value).map_err(|_| number_out_of_range(value, target_type))
+    let shape = wip.shape();
+    match coerce_scalar(Peek::new(&value), shape) {
+        Ok(coerced) => Ok(coerced.set(wip)?),
+        Err(CoerceError::Unsupported) if shape.scalar_type() == Some(ScalarType::String) => {
+            Ok(wip.set(alloc::string::ToString::to_string(&value))?)
+        }
+        Err(CoerceError::Unsupported) => Ok(wip.set(value)?),
+        Err(CoerceError::OutOfRange) => {
+            Err(number_out_of_range(value, shape.type_identifier).into())
+        }
+        Err(CoerceError::Inexact) => Err(number_not_exact(value, shape.type_identifier).into()),
+    }
 }
 
 /// Set a scalar value into a `Partial`, handling type coercion.
@@ -43,7 +73,6 @@ pub(crate) fn set_scalar_inner<'input, const BORROW: bool>(
     scalar: ScalarValue<'input>,
 ) -> Result<Partial<'input, BORROW>, SetScalarResult<'input, BORROW>> {
     let shape = wip.shape();
-    let scalar_type = shape.scalar_type();
 
     match scalar {
         ScalarValue::Null => {
@@ -52,117 +81,31 @@ pub(crate) fn set_scalar_inner<'input, const BORROW: bool>(
         ScalarValue::Bool(b) => {
             wip = wip.set(b)?;
         }
-        ScalarValue::Char(c) => {
-            wip = wip.set(c)?;
-        }
-        ScalarValue::I64(n) => {
-            match scalar_type {
-                // Handle signed types
-                Some(ScalarType::I8) => wip = wip.set(narrow_int::<i8, _>(n, "i8")?)?,
-                Some(ScalarType::I16) => wip = wip.set(narrow_int::<i16, _>(n, "i16")?)?,
-                Some(ScalarType::I32) => wip = wip.set(narrow_int::<i32, _>(n, "i32")?)?,
-                Some(ScalarType::I64) => wip = wip.set(n)?,
-                Some(ScalarType::I128) => wip = wip.set(n as i128)?,
-                Some(ScalarType::ISize) => wip = wip.set(narrow_int::<isize, _>(n, "isize")?)?,
-                // Handle unsigned types (I64 can fit in unsigned if non-negative)
-                Some(ScalarType::U8) => wip = wip.set(narrow_int::<u8, _>(n, "u8")?)?,
-                Some(ScalarType::U16) => wip = wip.set(narrow_int::<u16, _>(n, "u16")?)?,
-                Some(ScalarType::U32) => wip = wip.set(narrow_int::<u32, _>(n, "u32")?)?,
-                Some(ScalarType::U64) => wip = wip.set(narrow_int::<u64, _>(n, "u64")?)?,
-                Some(ScalarType::U128) => wip = wip.set(narrow_int::<u128, _>(n, "u128")?)?,
-                Some(ScalarType::USize) => wip = wip.set(narrow_int::<usize, _>(n, "usize")?)?,
-                // Handle floats
-                Some(ScalarType::F32) => wip = wip.set(n as f32)?,
-                Some(ScalarType::F64) => wip = wip.set(n as f64)?,
-                Some(ScalarType::String) => {
-                    wip = wip.set(alloc::string::ToString::to_string(&n))?
-                }
-                _ => wip = wip.set(n)?,
-            }
-        }
-        ScalarValue::U64(n) => {
-            match scalar_type {
-                // Handle unsigned types
-                Some(ScalarType::U8) => wip = wip.set(narrow_int::<u8, _>(n, "u8")?)?,
-                Some(ScalarType::U16) => wip = wip.set(narrow_int::<u16, _>(n, "u16")?)?,
-                Some(ScalarType::U32) => wip = wip.set(narrow_int::<u32, _>(n, "u32")?)?,
-                Some(ScalarType::U64) => wip = wip.set(n)?,
-                Some(ScalarType::U128) => wip = wip.set(n as u128)?,
-                Some(ScalarType::USize) => wip = wip.set(narrow_int::<usize, _>(n, "usize")?)?,
-                // Handle signed types (U64 can fit in signed if small enough)
-                Some(ScalarType::I8) => wip = wip.set(narrow_int::<i8, _>(n, "i8")?)?,
-                Some(ScalarType::I16) => wip = wip.set(narrow_int::<i16, _>(n, "i16")?)?,
-                Some(ScalarType::I32) => wip = wip.set(narrow_int::<i32, _>(n, "i32")?)?,
-                Some(ScalarType::I64) => wip = wip.set(narrow_int::<i64, _>(n, "i64")?)?,
-                Some(ScalarType::I128) => wip = wip.set(n as i128)?,
-                Some(ScalarType::ISize) => wip = wip.set(narrow_int::<isize, _>(n, "isize")?)?,
-                // Handle floats
-                Some(ScalarType::F32) => wip = wip.set(n as f32)?,
-                Some(ScalarType::F64) => wip = wip.set(n as f64)?,
-                Some(ScalarType::String) => {
-                    wip = wip.set(alloc::string::ToString::to_string(&n))?
-                }
-                _ => wip = wip.set(n)?,
-            }
-        }
-        ScalarValue::U128(n) => match scalar_type {
-            Some(ScalarType::U8) => wip = wip.set(narrow_int::<u8, _>(n, "u8")?)?,
-            Some(ScalarType::U16) => wip = wip.set(narrow_int::<u16, _>(n, "u16")?)?,
-            Some(ScalarType::U32) => wip = wip.set(narrow_int::<u32, _>(n, "u32")?)?,
-            Some(ScalarType::U64) => wip = wip.set(narrow_int::<u64, _>(n, "u64")?)?,
-            Some(ScalarType::U128) => wip = wip.set(n)?,
-            Some(ScalarType::USize) => wip = wip.set(narrow_int::<usize, _>(n, "usize")?)?,
-            Some(ScalarType::I8) => wip = wip.set(narrow_int::<i8, _>(n, "i8")?)?,
-            Some(ScalarType::I16) => wip = wip.set(narrow_int::<i16, _>(n, "i16")?)?,
-            Some(ScalarType::I32) => wip = wip.set(narrow_int::<i32, _>(n, "i32")?)?,
-            Some(ScalarType::I64) => wip = wip.set(narrow_int::<i64, _>(n, "i64")?)?,
-            Some(ScalarType::I128) => wip = wip.set(narrow_int::<i128, _>(n, "i128")?)?,
-            Some(ScalarType::ISize) => wip = wip.set(narrow_int::<isize, _>(n, "isize")?)?,
-            Some(ScalarType::F32) => wip = wip.set(n as f32)?,
-            Some(ScalarType::F64) => wip = wip.set(n as f64)?,
-            Some(ScalarType::String) => wip = wip.set(alloc::string::ToString::to_string(&n))?,
-            _ => wip = wip.set(n)?,
-        },
-        ScalarValue::I128(n) => match scalar_type {
-            Some(ScalarType::I8) => wip = wip.set(narrow_int::<i8, _>(n, "i8")?)?,
-            Some(ScalarType::I16) => wip = wip.set(narrow_int::<i16, _>(n, "i16")?)?,
-            Some(ScalarType::I32) => wip = wip.set(narrow_int::<i32, _>(n, "i32")?)?,
-            Some(ScalarType::I64) => wip = wip.set(narrow_int::<i64, _>(n, "i64")?)?,
-            Some(ScalarType::I128) => wip = wip.set(n)?,
-            Some(ScalarType::ISize) => wip = wip.set(narrow_int::<isize, _>(n, "isize")?)?,
-            Some(ScalarType::U8) => wip = wip.set(narrow_int::<u8, _>(n, "u8")?)?,
-            Some(ScalarType::U16) => wip = wip.set(narrow_int::<u16, _>(n, "u16")?)?,
-            Some(ScalarType::U32) => wip = wip.set(narrow_int::<u32, _>(n, "u32")?)?,
-            Some(ScalarType::U64) => wip = wip.set(narrow_int::<u64, _>(n, "u64")?)?,
-            Some(ScalarType::U128) => wip = wip.set(narrow_int::<u128, _>(n, "u128")?)?,
-            Some(ScalarType::USize) => wip = wip.set(narrow_int::<usize, _>(n, "usize")?)?,
-            Some(ScalarType::F32) => wip = wip.set(n as f32)?,
-            Some(ScalarType::F64) => wip = wip.set(n as f64)?,
-            Some(ScalarType::String) => wip = wip.set(alloc::string::ToString::to_string(&n))?,
-            _ => wip = wip.set(n)?,
-        },
+        ScalarValue::Char(c) => wip = set_coerced(wip, c)?,
+        ScalarValue::I64(n) => wip = set_coerced(wip, n)?,
+        ScalarValue::U64(n) => wip = set_coerced(wip, n)?,
+        ScalarValue::U128(n) => wip = set_coerced(wip, n)?,
+        ScalarValue::I128(n) => wip = set_coerced(wip, n)?,
         ScalarValue::F64(n) => {
-            match scalar_type {
-                Some(ScalarType::F32) => wip = wip.set(n as f32)?,
-                Some(ScalarType::F64) => wip = wip.set(n)?,
-                _ if shape.vtable.has_try_from() && shape.inner.is_some() => {
-                    // For opaque types with try_from (like NotNan, OrderedFloat), use
-                    // begin_inner() + set + end() to trigger conversion
-                    let inner_shape = shape.inner.unwrap();
-                    wip = wip.begin_inner()?;
-                    if inner_shape.is_type::<f32>() {
-                        wip = wip.set(n as f32)?;
-                    } else {
-                        wip = wip.set(n)?;
-                    }
-                    wip = wip.end()?;
-                }
-                _ if shape.vtable.has_parse() => {
-                    // For types that support parsing (like Decimal), convert to string
-                    // and use parse_from_str to preserve their parsing semantics
-                    wip = wip.parse_from_str(&alloc::string::ToString::to_string(&n))?;
+            if matches!(shape.ty, Type::Primitive(PrimitiveType::Numeric(_))) {
+                wip = set_coerced(wip, n)?;
+            } else if shape.vtable.has_try_from() && shape.inner.is_some() {
+                // For opaque types with try_from (like NotNan, OrderedFloat), use
+                // begin_inner() + set + end() to trigger conversion
+                let inner_shape = shape.inner.unwrap();
+                wip = wip.begin_inner()?;
+                if inner_shape.is_type::<f32>() {
+                    wip = wip.set(n as f32)?;
+                } else {
+                    wip = wip.set(n)?;
                 }
-                _ => wip = wip.set(n)?,
+                wip = wip.end()?;
+            } else if shape.vtable.has_parse() {
+                // For types that support parsing (like Decimal), convert to string
+                // and use parse_from_str to preserve their parsing semantics
+                wip = wip.parse_from_str(&alloc::string::ToString::to_string(&n))?;
+            } else {
+                wip = wip.set(n)?;
             }
         }
         ScalarValue::Str(s) => {
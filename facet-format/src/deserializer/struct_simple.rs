@@ -1,4 +1,4 @@
-use facet_core::{StructType, Type, UserType};
+use facet_core::{Field, StructType, Type, UserType};
 use facet_reflect::Partial;
 
 use crate::{
@@ -98,6 +98,12 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
 
         let event = self.expect_event("value")?;
 
+        if matches!(event.kind, ParseEventKind::SequenceStart(_))
+            && self.parser.accepts_positional_structs()
+        {
+            return self.deserialize_positional_fields(wip, struct_def.fields);
+        }
+
         if !matches!(event.kind, ParseEventKind::StructStart(_)) {
             return Err(self.mk_err(
                 &wip,
@@ -230,4 +236,42 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
 
         Ok(wip)
     }
+
+    /// Deserialize the fields of a struct (or struct variant) from a sequence,
+    /// for formats that [accept positional structs](crate::FormatParser::accepts_positional_structs).
+    ///
+    /// The `SequenceStart` has already been consumed. Elements are matched to
+    /// the fields that get serialized, in declaration order; extra elements
+    /// are skipped, and fields past the end of the sequence are left for
+    /// defaults to fill in.
+    pub(crate) fn deserialize_positional_fields(
+        &mut self,
+        mut wip: Partial<'input, BORROW>,
+        fields: &'static [Field],
+    ) -> Result<Partial<'input, BORROW>, DeserializeError> {
+        let mut positions = fields
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| !field.should_skip_serializing_unconditional());
+
+        loop {
+            let event = self.expect_peek("value")?;
+            if matches!(event.kind, ParseEventKind::SequenceEnd) {
+                self.expect_event("value")?;
+                break;
+            }
+
+            match positions.next() {
+                Some((idx, field)) if !field.should_skip_deserializing() => {
+                    wip = wip
+                        .begin_nth_field(idx)?
+                        .with(|w| self.deserialize_into_inner(w, MetaSource::FromEvents))?
+                        .end()?;
+                }
+                _ => self.skip_value()?,
+            }
+        }
+
+        Ok(wip)
+    }
 }
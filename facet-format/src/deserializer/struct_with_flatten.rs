@@ -1,7 +1,7 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
-use facet_core::{Characteristic, Def};
-use facet_reflect::{FieldCategory, FieldInfo, Partial, VariantSelection};
+use facet_core::{Characteristic, Def, Type, UserType};
+use facet_reflect::{FieldCategory, FieldInfo, FieldPath, Partial, Peek, VariantSelection};
 use facet_solver::PathSegment;
 
 use super::entry::MetaSource;
@@ -60,7 +60,9 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
         }
 
         // Build the schema for this type - this recursively expands all flatten fields
-        let schema = Schema::build_auto(wip.shape()).map_err(|e| {
+        let solver_timer = self.start_solver_timer();
+        let inherited = wip.plan_node().and_then(|node| node.inherited);
+        let schema = Schema::cached_inheriting(wip.shape(), inherited).map_err(|e| {
             self.mk_err(
                 &wip,
                 DeserializeErrorKind::Solver {
@@ -99,7 +101,9 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
         }
 
         // Get the resolved configuration
-        let config_handle = solver.finish().map_err(|e| {
+        let solved = solver.finish();
+        self.stop_solver_timer(solver_timer);
+        let config_handle = solved.map_err(|e| {
             self.mk_err(
                 &wip,
                 DeserializeErrorKind::Solver {
@@ -137,6 +141,11 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
 
         let variant_selections = resolution.variant_selections();
 
+        // For `flatten_prefix_from` groups: the prefix their keys carried, and
+        // the display forms of the fields the prefixes come from
+        let mut prefixes: BTreeMap<&FieldPath, String> = BTreeMap::new();
+        let mut prefix_sources: BTreeMap<&FieldPath, String> = BTreeMap::new();
+
         loop {
             let event = self.expect_event("value")?;
             nav.set_span(self.last_span);
@@ -166,6 +175,17 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
                                 .is_some_and(|tag| tag == field_info.serialized_name);
 
                             if is_internally_tagged_tag {
+                                // The path carries the Rust variant name; the tag
+                                // value in the input is its serialized name.
+                                let variant_name = match field_info.value_shape.ty {
+                                    Type::User(UserType::Enum(enum_type)) => enum_type
+                                        .variants
+                                        .iter()
+                                        .find(|v| v.name == variant_name)
+                                        .map_or(variant_name, |v| v.effective_name()),
+                                    _ => variant_name,
+                                };
+
                                 // Read and validate the tag value
                                 let tag_event =
                                     self.expect_event("internally-tagged enum tag value")?;
@@ -220,9 +240,18 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
                             let wip = self.deserialize_variant_struct_fields(wip)?;
                             nav.return_wip(wip);
                         } else {
-                            // Regular field: deserialize into it
+                            // Regular field: deserialize into it, remembering its
+                            // value if it's the prefix of a group of keys
                             let wip = nav.take_wip();
-                            let wip = self.deserialize_into(wip, MetaSource::FromEvents)?;
+                            let mut wip = self.deserialize_into(wip, MetaSource::FromEvents)?;
+                            if resolution
+                                .dynamic_prefixes()
+                                .iter()
+                                .any(|group| group.source == field_info.path)
+                                && let Some(value) = display_form(&mut wip)
+                            {
+                                prefix_sources.insert(&field_info.path, value);
+                            }
                             nav.return_wip(wip);
                         }
 
@@ -232,6 +261,40 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
                         continue;
                     }
 
+                    // Keys like "<prefix>.<name>" of a `flatten_prefix_from` group
+                    if let Some((prefix, group, field_info)) =
+                        resolution.field_by_prefixed_key(key_name, |group| {
+                            prefixes
+                                .get(&group.path)
+                                .or_else(|| prefix_sources.get(&group.source))
+                                .map(String::as_str)
+                        })
+                    {
+                        if let Some(seen) = prefixes.get(&group.path)
+                            && seen != prefix
+                        {
+                            return Err(self.mk_err(
+                                nav.wip(),
+                                DeserializeErrorKind::InvalidValue {
+                                    message: format!(
+                                        "key '{key_name}' has prefix '{prefix}', \
+                                         but earlier keys of the group had '{seen}'"
+                                    )
+                                    .into(),
+                                },
+                            ));
+                        }
+                        prefixes.insert(&group.path, prefix.to_owned());
+
+                        let nav_result = nav.navigate_to(&field_info.path, variant_selections)?;
+                        let wip = nav.take_wip();
+                        let wip = self.deserialize_into(wip, MetaSource::FromEvents)?;
+                        nav.return_wip(wip);
+                        nav.set_span(self.last_span);
+                        nav.close_final(nav_result.final_is_option)?;
+                        continue;
+                    }
+
                     // Check if we have a catch-all map for unknown fields
                     if let Some(catch_all_info) = resolution.catch_all_map(FieldCategory::Flat) {
                         self.insert_into_catch_all_map(
@@ -268,6 +331,25 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
             }
         }
 
+        // Prefixed keys must agree with the field their prefix comes from
+        for group in resolution.dynamic_prefixes() {
+            if let (Some(prefix), Some(source)) =
+                (prefixes.get(&group.path), prefix_sources.get(&group.source))
+                && prefix != source
+            {
+                return Err(self.mk_err(
+                    nav.wip(),
+                    DeserializeErrorKind::InvalidValue {
+                        message: format!(
+                            "keys are prefixed with '{prefix}', but '{}' is '{source}'",
+                            group.source
+                        )
+                        .into(),
+                    },
+                ));
+            }
+        }
+
         // Close any remaining open segments
         nav.set_span(self.last_span);
         nav.close_all()?;
@@ -363,17 +445,22 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
             })
             .collect();
 
-        // Track opened segments so we can close them
-        let mut opened_options: Vec<bool> = Vec::new();
+        // Track how many Option/pointer frames each opened segment entered
+        let mut opened_wrappers: Vec<usize> = Vec::new();
 
         // Navigate to the catch-all field
-        for &segment in &field_segments {
+        for (depth, &segment) in field_segments.iter().enumerate() {
             wip = wip.begin_field(segment)?;
-            let is_option = matches!(wip.shape().def, Def::Option(_));
-            if is_option {
+            let mut wrappers = 0;
+            if matches!(wip.shape().def, Def::Option(_)) {
                 wip = wip.begin_some()?;
+                wrappers += 1;
             }
-            opened_options.push(is_option);
+            if depth + 1 < field_segments.len() && matches!(wip.shape().def, Def::Pointer(_)) {
+                wip = wip.begin_smart_ptr()?;
+                wrappers += 1;
+            }
+            opened_wrappers.push(wrappers);
         }
 
         // Initialize as empty based on the field's type
@@ -389,8 +476,8 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
         }
 
         // Close segments in reverse order
-        for is_option in opened_options.into_iter().rev() {
-            if is_option {
+        for wrappers in opened_wrappers.into_iter().rev() {
+            for _ in 0..wrappers {
                 wip = wip.end()?;
             }
             wip = wip.end()?;
@@ -399,3 +486,19 @@ impl<'parser, 'input, const BORROW: bool> FormatDeserializer<'parser, 'input, BO
         Ok(wip)
     }
 }
+
+/// The text the value just deserialized into `wip` is written as when it is
+/// the source of a `flatten_prefix_from` prefix: a string as is, anything else
+/// through `Display`, after unwrapping pointers and transparent wrappers.
+fn display_form<const BORROW: bool>(wip: &mut Partial<'_, BORROW>) -> Option<String> {
+    let data = wip.initialized_data_ptr()?;
+    // SAFETY: `initialized_data_ptr` only returns a pointer to a fully
+    // initialized value of the current frame's shape.
+    #[allow(unsafe_code)]
+    let peek = unsafe { Peek::unchecked_new(data, wip.shape()) }.innermost_peek();
+    match peek.as_str() {
+        Some(s) => Some(s.to_owned()),
+        None if peek.shape().vtable.has_display() => Some(peek.to_string()),
+        None => None,
+    }
+}
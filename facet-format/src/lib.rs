@@ -46,6 +46,7 @@ pub(crate) use trace;
 mod deserializer;
 mod event;
 mod evidence;
+mod metrics;
 mod parser;
 mod serializer;
 mod solver;
@@ -53,17 +54,20 @@ mod type_plan_cache;
 mod visitor;
 
 pub use deserializer::{
-    DeserializeError, DeserializeErrorKind, FormatDeserializer, MetaSource, ParseError, SpanGuard,
+    DeserializeError, DeserializeErrorKind, FormatDeserializer, MetaSource, ParseError,
+    ResumableSequence, SpanGuard, Step,
 };
 pub use event::{
     ContainerKind, FieldKey, FieldLocationHint, ParseEvent, ParseEventKind, ScalarValue, ValueMeta,
     ValueMetaBuilder, ValueTypeHint,
 };
 pub use evidence::FieldEvidence;
+pub use metrics::ParseMetrics;
 pub use parser::{EnumVariantHint, FormatParser, SavePoint, ScalarTypeHint};
 pub use serializer::{
     DynamicValueEncoding, DynamicValueTag, EnumVariantEncoding, FieldOrdering, FormatSerializer,
-    MapEncoding, SerializeError, StructFieldMode, serialize_root, serialize_value_with_shape,
+    MapEncoding, SerializeError, StructFieldMode, scalar_value, serialize_root,
+    serialize_value_with_shape,
 };
 pub use solver::{SolveOutcome, SolveVariantError, solve_variant};
 pub use visitor::{FieldMatch, StructFieldTracker};
@@ -0,0 +1,74 @@
+//! Per-call cost accounting for [`FormatDeserializer`](crate::FormatDeserializer).
+
+use std::borrow::Cow;
+use std::time::Duration;
+
+use crate::{ParseEvent, ParseEventKind, ScalarValue};
+
+/// What one deserialization call cost.
+///
+/// Collected by a [`FormatDeserializer`](crate::FormatDeserializer) when
+/// [`with_metrics`](crate::FormatDeserializer::with_metrics) is enabled, so
+/// every format built on it reports the same numbers. Events read ahead to
+/// pick a variant or resolve flattened fields and then rewound are only
+/// counted once.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ParseMetrics {
+    /// Bytes of input consumed, up to the end of the last event read.
+    pub bytes_read: usize,
+    /// Values read: scalars, and the start of each struct or sequence.
+    pub nodes_created: usize,
+    /// String and byte buffers allocated for scalars: those the parser had to
+    /// unescape, plus, when deserializing into owned values, every string
+    /// copied out of the input.
+    pub allocations: usize,
+    /// Deepest nesting of structs and sequences (the root container is 1).
+    pub max_depth: usize,
+    /// Time spent resolving untagged enums and flattened fields.
+    pub solver_time: Duration,
+}
+
+/// Running counters behind [`ParseMetrics`].
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct MetricsRecorder {
+    pub(crate) metrics: ParseMetrics,
+    depth: usize,
+}
+
+impl MetricsRecorder {
+    /// Account for one consumed event.
+    pub(crate) fn event(&mut self, event: &ParseEvent<'_>, borrow: bool) {
+        let m = &mut self.metrics;
+        m.bytes_read = m.bytes_read.max(event.span.end());
+        match &event.kind {
+            ParseEventKind::StructStart(_) | ParseEventKind::SequenceStart(_) => {
+                m.nodes_created += 1;
+                self.depth += 1;
+                m.max_depth = m.max_depth.max(self.depth);
+            }
+            ParseEventKind::StructEnd | ParseEventKind::SequenceEnd => {
+                self.depth = self.depth.saturating_sub(1);
+            }
+            ParseEventKind::Scalar(scalar) => {
+                m.nodes_created += 1;
+                let allocates = match scalar {
+                    ScalarValue::Str(Cow::Owned(_)) | ScalarValue::Bytes(Cow::Owned(_)) => true,
+                    ScalarValue::Str(_) | ScalarValue::Bytes(_) => !borrow,
+                    _ => false,
+                };
+                if allocates {
+                    m.allocations += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Rewind to `saved`, keeping the solver time spent since.
+    pub(crate) fn restore(&mut self, saved: Self) {
+        let solver_time = self.metrics.solver_time;
+        *self = saved;
+        self.metrics.solver_time = solver_time;
+    }
+}
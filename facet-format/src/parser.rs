@@ -143,6 +143,16 @@ pub trait FormatParser<'de> {
         false
     }
 
+    /// Whether a sequence may stand in for a struct, its elements being the
+    /// serialized fields in declaration order.
+    ///
+    /// Self-describing formats with a compact positional struct encoding
+    /// (MessagePack's array form) return true, so that either encoding is
+    /// accepted where a struct or struct variant is expected.
+    fn accepts_positional_structs(&self) -> bool {
+        false
+    }
+
     /// Hint to the parser that a struct with the given number of fields is expected.
     ///
     /// For non-self-describing formats, this allows the parser to emit the correct
@@ -292,6 +302,43 @@ pub trait FormatParser<'de> {
         false
     }
 
+    /// Adjust a scalar event read for an opaque type (one with no
+    /// [`ScalarType`](facet_core::ScalarType)), before it is applied.
+    ///
+    /// Self-describing formats decide a value's type from the wire, so a
+    /// schema-driven hint like [`hint_opaque_scalar`](Self::hint_opaque_scalar)
+    /// would arrive too late once events are buffered. This is called with the
+    /// event itself instead; JSON uses it to hand decimal types the exact text
+    /// of a number rather than a lossy `f64`. `field` is the nearest enclosing
+    /// struct field, for formats whose handling is configurable per field.
+    ///
+    /// The default returns the event unchanged.
+    fn opaque_scalar_event(
+        &self,
+        _shape: &'static facet_core::Shape,
+        _field: Option<&facet_core::Field>,
+        event: crate::ParseEvent<'de>,
+    ) -> Result<crate::ParseEvent<'de>, ParseError> {
+        Ok(event)
+    }
+
+    /// Adjust a scalar event read for a type with a
+    /// [`ScalarType`](facet_core::ScalarType), before it is applied.
+    ///
+    /// The counterpart of [`opaque_scalar_event`](Self::opaque_scalar_event)
+    /// for primitives. JSON uses it in big-number mode to read integers
+    /// written with a fraction or exponent (`1.5e30`) from their text instead
+    /// of through `f64`.
+    ///
+    /// The default returns the event unchanged.
+    fn primitive_scalar_event(
+        &self,
+        _scalar_type: facet_core::ScalarType,
+        event: crate::ParseEvent<'de>,
+    ) -> Result<crate::ParseEvent<'de>, ParseError> {
+        Ok(event)
+    }
+
     /// Returns the source span of the most recently consumed event.
     ///
     /// This is used for error reporting - when a deserialization error occurs,
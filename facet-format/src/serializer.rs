@@ -6,7 +6,8 @@ use core::fmt::Debug;
 use core::fmt::Write as _;
 
 use facet_core::{
-    Def, DynDateTimeKind, DynValueKind, ScalarType, Shape, StructKind, Type, UserType,
+    ChildDefaults, Def, DynDateTimeKind, DynValueKind, ScalarType, Shape, StructKind, Type,
+    UserType,
 };
 use facet_reflect::{
     HasFields as _, Peek, PeekListLike, PeekMap, PeekOption, PeekResult, PeekSet, ReflectError,
@@ -14,6 +15,72 @@ use facet_reflect::{
 
 use crate::ScalarValue;
 
+/// The [`ScalarValue`] that [`FormatSerializer::typed_scalar`] passes to
+/// [`FormatSerializer::scalar`] by default.
+///
+/// Serializers that override `typed_scalar` for a few scalar types can use
+/// this for the rest. Note that `f32` is widened to `f64` here, which prints
+/// more digits than the `f32` needs (`0.1f32` is `0.10000000149011612` as an
+/// `f64`); text formats that care should handle `ScalarType::F32` themselves.
+pub fn scalar_value<'mem>(scalar_type: ScalarType, value: Peek<'mem, '_>) -> ScalarValue<'mem> {
+    match scalar_type {
+        ScalarType::Unit => ScalarValue::Null,
+        ScalarType::Bool => ScalarValue::Bool(*value.get::<bool>().unwrap()),
+        ScalarType::Char => ScalarValue::Char(*value.get::<char>().unwrap()),
+        ScalarType::Str | ScalarType::String | ScalarType::CowStr => {
+            ScalarValue::Str(Cow::Borrowed(value.as_str().unwrap()))
+        }
+        ScalarType::F32 => ScalarValue::F64(*value.get::<f32>().unwrap() as f64),
+        ScalarType::F64 => ScalarValue::F64(*value.get::<f64>().unwrap()),
+        ScalarType::U8 => ScalarValue::U64(*value.get::<u8>().unwrap() as u64),
+        ScalarType::U16 => ScalarValue::U64(*value.get::<u16>().unwrap() as u64),
+        ScalarType::U32 => ScalarValue::U64(*value.get::<u32>().unwrap() as u64),
+        ScalarType::U64 => ScalarValue::U64(*value.get::<u64>().unwrap()),
+        ScalarType::U128 => {
+            let n = *value.get::<u128>().unwrap();
+            ScalarValue::Str(Cow::Owned(alloc::string::ToString::to_string(&n)))
+        }
+        ScalarType::USize => ScalarValue::U64(*value.get::<usize>().unwrap() as u64),
+        ScalarType::I8 => ScalarValue::I64(*value.get::<i8>().unwrap() as i64),
+        ScalarType::I16 => ScalarValue::I64(*value.get::<i16>().unwrap() as i64),
+        ScalarType::I32 => ScalarValue::I64(*value.get::<i32>().unwrap() as i64),
+        ScalarType::I64 => ScalarValue::I64(*value.get::<i64>().unwrap()),
+        ScalarType::I128 => {
+            let n = *value.get::<i128>().unwrap();
+            ScalarValue::Str(Cow::Owned(alloc::string::ToString::to_string(&n)))
+        }
+        ScalarType::ISize => ScalarValue::I64(*value.get::<isize>().unwrap() as i64),
+        #[cfg(feature = "net")]
+        ScalarType::IpAddr => {
+            let addr = *value.get::<core::net::IpAddr>().unwrap();
+            ScalarValue::Str(Cow::Owned(alloc::string::ToString::to_string(&addr)))
+        }
+        #[cfg(feature = "net")]
+        ScalarType::Ipv4Addr => {
+            let addr = *value.get::<core::net::Ipv4Addr>().unwrap();
+            ScalarValue::Str(Cow::Owned(alloc::string::ToString::to_string(&addr)))
+        }
+        #[cfg(feature = "net")]
+        ScalarType::Ipv6Addr => {
+            let addr = *value.get::<core::net::Ipv6Addr>().unwrap();
+            ScalarValue::Str(Cow::Owned(alloc::string::ToString::to_string(&addr)))
+        }
+        #[cfg(feature = "net")]
+        ScalarType::SocketAddr => {
+            let addr = *value.get::<core::net::SocketAddr>().unwrap();
+            ScalarValue::Str(Cow::Owned(alloc::string::ToString::to_string(&addr)))
+        }
+        _ => {
+            // For unknown scalar types, try to get a string representation
+            if let Some(s) = value.as_str() {
+                ScalarValue::Str(Cow::Borrowed(s))
+            } else {
+                ScalarValue::Null
+            }
+        }
+    }
+}
+
 /// Extract a string from a Peek value, handling metadata containers.
 ///
 /// For metadata containers like `Spanned<String>` or `Documented<String>`,
@@ -317,6 +384,18 @@ pub trait FormatSerializer {
         self.serialize_opaque_scalar(shape, value)
     }
 
+    /// Emit a placeholder for a pointer back to one of the value's ancestors.
+    ///
+    /// Cyclic data (e.g. an `Rc` cycle) can't be written out in full. When the
+    /// traversal reaches a pointer whose target is already being serialized,
+    /// it calls this with the path of that target (`<root>` for the root
+    /// value). Return `Ok(true)` after writing a reference of your own (for
+    /// example `{"$ref": "<root>.children[0]"}`), or `Ok(false)` to fail with
+    /// [`SerializeError::CycleDetected`].
+    fn serialize_cycle_reference(&mut self, _target_path: &str) -> Result<bool, Self::Error> {
+        Ok(false)
+    }
+
     /// Emit a dynamic value type tag.
     ///
     /// Formats that use [`DynamicValueEncoding::Tagged`] should override this.
@@ -381,70 +460,13 @@ pub trait FormatSerializer {
     ///
     /// Text formats can ignore the type and use the normalized `ScalarValue`.
     ///
-    /// Default: normalizes to `ScalarValue` and calls `scalar()`.
+    /// Default: normalizes with [`scalar_value`] and calls `scalar()`.
     fn typed_scalar(
         &mut self,
         scalar_type: ScalarType,
         value: Peek<'_, '_>,
     ) -> Result<(), Self::Error> {
-        // Default implementation: normalize to ScalarValue and call scalar()
-        let scalar = match scalar_type {
-            ScalarType::Unit => ScalarValue::Null,
-            ScalarType::Bool => ScalarValue::Bool(*value.get::<bool>().unwrap()),
-            ScalarType::Char => ScalarValue::Char(*value.get::<char>().unwrap()),
-            ScalarType::Str | ScalarType::String | ScalarType::CowStr => {
-                ScalarValue::Str(Cow::Borrowed(value.as_str().unwrap()))
-            }
-            ScalarType::F32 => ScalarValue::F64(*value.get::<f32>().unwrap() as f64),
-            ScalarType::F64 => ScalarValue::F64(*value.get::<f64>().unwrap()),
-            ScalarType::U8 => ScalarValue::U64(*value.get::<u8>().unwrap() as u64),
-            ScalarType::U16 => ScalarValue::U64(*value.get::<u16>().unwrap() as u64),
-            ScalarType::U32 => ScalarValue::U64(*value.get::<u32>().unwrap() as u64),
-            ScalarType::U64 => ScalarValue::U64(*value.get::<u64>().unwrap()),
-            ScalarType::U128 => {
-                let n = *value.get::<u128>().unwrap();
-                ScalarValue::Str(Cow::Owned(alloc::string::ToString::to_string(&n)))
-            }
-            ScalarType::USize => ScalarValue::U64(*value.get::<usize>().unwrap() as u64),
-            ScalarType::I8 => ScalarValue::I64(*value.get::<i8>().unwrap() as i64),
-            ScalarType::I16 => ScalarValue::I64(*value.get::<i16>().unwrap() as i64),
-            ScalarType::I32 => ScalarValue::I64(*value.get::<i32>().unwrap() as i64),
-            ScalarType::I64 => ScalarValue::I64(*value.get::<i64>().unwrap()),
-            ScalarType::I128 => {
-                let n = *value.get::<i128>().unwrap();
-                ScalarValue::Str(Cow::Owned(alloc::string::ToString::to_string(&n)))
-            }
-            ScalarType::ISize => ScalarValue::I64(*value.get::<isize>().unwrap() as i64),
-            #[cfg(feature = "net")]
-            ScalarType::IpAddr => {
-                let addr = *value.get::<core::net::IpAddr>().unwrap();
-                ScalarValue::Str(Cow::Owned(alloc::string::ToString::to_string(&addr)))
-            }
-            #[cfg(feature = "net")]
-            ScalarType::Ipv4Addr => {
-                let addr = *value.get::<core::net::Ipv4Addr>().unwrap();
-                ScalarValue::Str(Cow::Owned(alloc::string::ToString::to_string(&addr)))
-            }
-            #[cfg(feature = "net")]
-            ScalarType::Ipv6Addr => {
-                let addr = *value.get::<core::net::Ipv6Addr>().unwrap();
-                ScalarValue::Str(Cow::Owned(alloc::string::ToString::to_string(&addr)))
-            }
-            #[cfg(feature = "net")]
-            ScalarType::SocketAddr => {
-                let addr = *value.get::<core::net::SocketAddr>().unwrap();
-                ScalarValue::Str(Cow::Owned(alloc::string::ToString::to_string(&addr)))
-            }
-            _ => {
-                // For unknown scalar types, try to get a string representation
-                if let Some(s) = value.as_str() {
-                    ScalarValue::Str(Cow::Borrowed(s))
-                } else {
-                    ScalarValue::Null
-                }
-            }
-        };
-        self.scalar(scalar)
+        self.scalar(scalar_value(scalar_type, value))
     }
 
     /// Begin serializing `Option::Some(value)`.
@@ -592,6 +614,12 @@ pub enum SerializeError<E: Debug> {
     Unsupported(Cow<'static, str>),
     /// Internal invariant violation.
     Internal(Cow<'static, str>),
+    /// The value contains a pointer back to one of its ancestors, so
+    /// serializing it would never end.
+    CycleDetected {
+        /// Path of the pointer that closes the cycle.
+        path: String,
+    },
 }
 
 impl<E: Debug> core::fmt::Display for SerializeError<E> {
@@ -601,6 +629,12 @@ impl<E: Debug> core::fmt::Display for SerializeError<E> {
             SerializeError::Reflect(err) => write!(f, "{err}"),
             SerializeError::Unsupported(msg) => f.write_str(msg.as_ref()),
             SerializeError::Internal(msg) => f.write_str(msg.as_ref()),
+            SerializeError::CycleDetected { path } => {
+                write!(
+                    f,
+                    "cycle detected: `{path}` points back to one of its ancestors"
+                )
+            }
         }
     }
 }
@@ -634,6 +668,14 @@ pub struct SerializeContext<'s, S: FormatSerializer> {
     serializer: &'s mut S,
     path: alloc::vec::Vec<PathSegment>,
     current_field: Option<facet_core::Field>,
+    /// Addresses and types of the pointer targets currently being serialized,
+    /// with the path length at which each was entered. Used to detect cycles.
+    pointees: alloc::vec::Vec<(usize, facet_core::ConstTypeId, usize)>,
+    /// `#[facet(defaults_for_children)]` in force for the fields of the value
+    /// being serialized
+    field_defaults: Option<ChildDefaults>,
+    /// ...and for the values it holds
+    child_defaults: Option<ChildDefaults>,
 }
 
 impl<'s, S: FormatSerializer> SerializeContext<'s, S> {
@@ -643,6 +685,9 @@ impl<'s, S: FormatSerializer> SerializeContext<'s, S> {
             serializer,
             path: alloc::vec::Vec::new(),
             current_field: None,
+            pointees: alloc::vec::Vec::new(),
+            field_defaults: None,
+            child_defaults: None,
         }
     }
 
@@ -658,6 +703,17 @@ impl<'s, S: FormatSerializer> SerializeContext<'s, S> {
         out
     }
 
+    /// Run `f` inside a value of type `shape`: its fields are named by the
+    /// defaults handed down to it, and the values it holds also inherit its own.
+    fn entering<T>(&mut self, shape: &Shape, f: impl FnOnce(&mut Self) -> T) -> T {
+        let outer = (self.field_defaults, self.child_defaults);
+        self.field_defaults = self.child_defaults;
+        self.child_defaults = shape.defaults_for_children(self.child_defaults);
+        let out = f(self);
+        (self.field_defaults, self.child_defaults) = outer;
+        out
+    }
+
     /// Push a path segment onto the context.
     fn push(&mut self, segment: PathSegment) {
         self.path.push(segment);
@@ -670,11 +726,15 @@ impl<'s, S: FormatSerializer> SerializeContext<'s, S> {
 
     /// Get the current path as a string.
     fn path_string(&self) -> String {
-        if self.path.is_empty() {
+        Self::render_path(&self.path)
+    }
+
+    fn render_path(path: &[PathSegment]) -> String {
+        if path.is_empty() {
             "<root>".into()
         } else {
             let mut s = String::new();
-            for seg in &self.path {
+            for seg in path {
                 let _ = write!(s, "{}", seg);
             }
             s
@@ -704,9 +764,77 @@ impl<'s, S: FormatSerializer> SerializeContext<'s, S> {
         &mut self,
         value: Peek<'mem, 'facet>,
     ) -> Result<(), SerializeError<S::Error>> {
-        // Dereference pointers (Box, Arc, etc.) to get the underlying value
+        if !matches!(value.shape().def, Def::Pointer(_)) {
+            return self.entering(value.shape(), |this| this.serialize_pointee(value));
+        }
+
+        // Dereference pointers (Box, Arc, etc.) to get the underlying value,
+        // refusing to enter one that is already being serialized.
         let value = deref_if_pointer(value);
+        let key = (value.data().as_byte_ptr().addr(), value.shape().id);
+        if let Some(&(_, _, depth)) = self.pointees.iter().find(|(ptr, id, _)| (*ptr, *id) == key) {
+            let target_path = Self::render_path(&self.path[..depth]);
+            if self
+                .serializer
+                .serialize_cycle_reference(&target_path)
+                .map_err(SerializeError::Backend)?
+            {
+                return Ok(());
+            }
+            return Err(SerializeError::CycleDetected {
+                path: self.path_string(),
+            });
+        }
+
+        self.pointees.push((key.0, key.1, self.path.len()));
+        let result = self.entering(value.shape(), |this| this.serialize_pointee(value));
+        self.pointees.pop();
+        result
+    }
 
+    /// Step through pointers and transparent newtypes down to the value to
+    /// write, like [`Peek::innermost_peek`], but stop at any layer with a proxy
+    /// so that no proxy is skipped: hops are taken in the same order as when
+    /// deserializing. When a newtype's field has its own proxy, the field is
+    /// returned along with that proxy.
+    fn unwrap_transparent<'mem, 'facet>(
+        &self,
+        mut value: Peek<'mem, 'facet>,
+    ) -> (Peek<'mem, 'facet>, Option<&'static facet_core::ProxyDef>) {
+        let format_ns = self.serializer.format_namespace();
+        loop {
+            if value.shape().serialize_proxy(format_ns).is_some() {
+                return (value, None);
+            }
+            if let Ok(ptr) = value.into_pointer()
+                && let Some(target) = ptr.borrow_inner()
+            {
+                value = target;
+                continue;
+            }
+            if value.shape().inner.is_some()
+                && value.shape().is_transparent()
+                && let Ok(struct_) = value.into_struct()
+                && let Some((field_item, field_value)) =
+                    struct_.fields_for_binary_serialize().next()
+            {
+                let field_proxy = field_item.field.and_then(|f| f.effective_proxy(format_ns));
+                if field_proxy.is_some() {
+                    return (field_value, field_proxy);
+                }
+                value = field_value;
+                continue;
+            }
+            return (value.innermost_peek(), None);
+        }
+    }
+
+    /// Serialize a value that is not a pointer (pointers are dereferenced by
+    /// [`Self::serialize_impl`]).
+    fn serialize_pointee<'mem, 'facet>(
+        &mut self,
+        value: Peek<'mem, 'facet>,
+    ) -> Result<(), SerializeError<S::Error>> {
         // Check for raw serialization type (e.g., RawJson) BEFORE innermost_peek
         if self.serializer.raw_serialize_shape() == Some(value.shape()) {
             if let Ok(struct_) = value.into_struct()
@@ -732,7 +860,10 @@ impl<'s, S: FormatSerializer> SerializeContext<'s, S> {
             return Ok(());
         }
 
-        let value = value.innermost_peek();
+        let (value, field_proxy) = self.unwrap_transparent(value);
+        if let Some(proxy_def) = field_proxy {
+            return self.serialize_via_proxy(value, proxy_def);
+        }
 
         // Check for metadata containers
         if value.shape().is_metadata_container()
@@ -755,7 +886,7 @@ impl<'s, S: FormatSerializer> SerializeContext<'s, S> {
         // Check for container-level proxy
         if let Some(proxy_def) = value
             .shape()
-            .effective_proxy(self.serializer.format_namespace())
+            .serialize_proxy(self.serializer.format_namespace())
         {
             return self.serialize_via_proxy(value, proxy_def);
         }
@@ -953,7 +1084,7 @@ impl<'s, S: FormatSerializer> SerializeContext<'s, S> {
     ) -> Result<Cow<'mem, str>, SerializeError<S::Error>> {
         if let Some(proxy_def) = key
             .shape()
-            .effective_proxy(self.serializer.format_namespace())
+            .serialize_proxy(self.serializer.format_namespace())
         {
             let proxy = key
                 .custom_serialization_with_proxy(proxy_def)
@@ -1106,7 +1237,10 @@ impl<'s, S: FormatSerializer> SerializeContext<'s, S> {
         let mut fields: alloc::vec::Vec<_> = if field_mode == StructFieldMode::Unnamed {
             struct_.fields_for_binary_serialize().collect()
         } else {
-            struct_.fields_for_serialize().collect()
+            struct_
+                .fields_for_serialize()
+                .with_child_defaults(self.field_defaults)
+                .collect()
         };
 
         sort_fields_if_needed(self.serializer, &mut fields);
@@ -1180,7 +1314,10 @@ impl<'s, S: FormatSerializer> SerializeContext<'s, S> {
                 .map_err(SerializeError::Backend)?;
 
             if let Ok(inner_struct) = field_value.into_struct() {
-                for (inner_item, inner_value) in inner_struct.fields_for_serialize() {
+                for (inner_item, inner_value) in inner_struct
+                    .fields_for_serialize()
+                    .with_child_defaults(self.child_defaults)
+                {
                     if field_mode == StructFieldMode::Named {
                         self.serializer
                             .field_key(inner_item.effective_name())
@@ -1193,7 +1330,10 @@ impl<'s, S: FormatSerializer> SerializeContext<'s, S> {
                     self.pop();
                 }
             } else if let Ok(enum_peek) = field_value.into_enum() {
-                for (inner_item, inner_value) in enum_peek.fields_for_serialize() {
+                for (inner_item, inner_value) in enum_peek
+                    .fields_for_serialize()
+                    .with_child_defaults(self.child_defaults)
+                {
                     if field_mode == StructFieldMode::Named {
                         self.serializer
                             .field_key(inner_item.effective_name())
@@ -1239,7 +1379,10 @@ impl<'s, S: FormatSerializer> SerializeContext<'s, S> {
                     .serialize_none()
                     .map_err(SerializeError::Backend)?;
             } else {
-                self.serialize_variant_after_tag(enum_peek, variant)?;
+                // The enum is flattened, but still a child of this struct
+                self.entering(enum_peek.shape(), |this| {
+                    this.serialize_variant_after_tag(enum_peek, variant)
+                })?;
             }
             self.pop();
             return Ok(());
@@ -1270,7 +1413,10 @@ impl<'s, S: FormatSerializer> SerializeContext<'s, S> {
             let mut fields: alloc::vec::Vec<_> = if field_mode == StructFieldMode::Unnamed {
                 struct_.fields_for_binary_serialize().collect()
             } else {
-                struct_.fields_for_serialize().collect()
+                struct_
+                    .fields_for_serialize()
+                    .with_child_defaults(self.child_defaults)
+                    .collect()
             };
             sort_fields_if_needed(self.serializer, &mut fields);
             for (field_item, field_value) in fields {
@@ -1336,7 +1482,10 @@ impl<'s, S: FormatSerializer> SerializeContext<'s, S> {
                         if field_mode == StructFieldMode::Unnamed {
                             inner_enum.fields_for_binary_serialize().collect()
                         } else {
-                            inner_enum.fields_for_serialize().collect()
+                            inner_enum
+                                .fields_for_serialize()
+                                .with_child_defaults(self.child_defaults)
+                                .collect()
                         };
                     sort_fields_if_needed(self.serializer, &mut inner_fields);
 
@@ -1528,7 +1677,10 @@ impl<'s, S: FormatSerializer> SerializeContext<'s, S> {
                             if field_mode == StructFieldMode::Unnamed {
                                 enum_.fields_for_binary_serialize().collect()
                             } else {
-                                enum_.fields_for_serialize().collect()
+                                enum_
+                                    .fields_for_serialize()
+                                    .with_child_defaults(self.field_defaults)
+                                    .collect()
                             };
                         sort_fields_if_needed(self.serializer, &mut fields);
                         for (field_item, field_value) in fields {
@@ -1636,7 +1788,10 @@ impl<'s, S: FormatSerializer> SerializeContext<'s, S> {
                 let mut fields: alloc::vec::Vec<_> = if field_mode == StructFieldMode::Unnamed {
                     enum_.fields_for_binary_serialize().collect()
                 } else {
-                    enum_.fields_for_serialize().collect()
+                    enum_
+                        .fields_for_serialize()
+                        .with_child_defaults(self.field_defaults)
+                        .collect()
                 };
                 sort_fields_if_needed(self.serializer, &mut fields);
                 for (field_item, field_value) in fields {
@@ -1723,7 +1878,11 @@ impl<'s, S: FormatSerializer> SerializeContext<'s, S> {
             > = if field_mode == StructFieldMode::Unnamed {
                 alloc::boxed::Box::new(enum_.fields_for_binary_serialize())
             } else {
-                alloc::boxed::Box::new(enum_.fields_for_serialize())
+                alloc::boxed::Box::new(
+                    enum_
+                        .fields_for_serialize()
+                        .with_child_defaults(self.field_defaults),
+                )
             };
             for (field_item, field_value) in fields_iter {
                 if let Some(field) = field_item.field
@@ -1840,7 +1999,11 @@ impl<'s, S: FormatSerializer> SerializeContext<'s, S> {
                 > = if field_mode == StructFieldMode::Unnamed {
                     alloc::boxed::Box::new(enum_.fields_for_binary_serialize())
                 } else {
-                    alloc::boxed::Box::new(enum_.fields_for_serialize())
+                    alloc::boxed::Box::new(
+                        enum_
+                            .fields_for_serialize()
+                            .with_child_defaults(self.field_defaults),
+                    )
                 };
                 let mut fields: alloc::vec::Vec<_> = fields_iter
                     .filter(|(field_item, _)| {
@@ -1986,7 +2149,10 @@ impl<'s, S: FormatSerializer> SerializeContext<'s, S> {
                 let mut fields: alloc::vec::Vec<_> = if field_mode == StructFieldMode::Unnamed {
                     enum_.fields_for_binary_serialize().collect()
                 } else {
-                    enum_.fields_for_serialize().collect()
+                    enum_
+                        .fields_for_serialize()
+                        .with_child_defaults(self.field_defaults)
+                        .collect()
                 };
                 sort_fields_if_needed(self.serializer, &mut fields);
                 for (field_item, field_value) in fields {
@@ -2072,7 +2238,10 @@ impl<'s, S: FormatSerializer> SerializeContext<'s, S> {
                 let mut fields: alloc::vec::Vec<_> = if field_mode == StructFieldMode::Unnamed {
                     enum_.fields_for_binary_serialize().collect()
                 } else {
-                    enum_.fields_for_serialize().collect()
+                    enum_
+                        .fields_for_serialize()
+                        .with_child_defaults(self.field_defaults)
+                        .collect()
                 };
                 sort_fields_if_needed(self.serializer, &mut fields);
                 for (field_item, field_value) in fields {
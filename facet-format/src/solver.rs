@@ -60,7 +60,7 @@ pub fn solve_variant<'de>(
     shape: &'static Shape,
     parser: &mut dyn FormatParser<'de>,
 ) -> Result<Option<SolveOutcome>, SolveVariantError> {
-    let schema = Arc::new(Schema::build_auto(shape)?);
+    let schema = Schema::cached(shape)?;
     let mut solver = Solver::new(&schema);
 
     // Save position and start recording events
@@ -0,0 +1,200 @@
+//! INI and properties file support for facet.
+//!
+//! This crate reads and writes the `key = value` configuration files that
+//! predate TOML and YAML: Windows-style `.ini` files, `.properties` files,
+//! `.cfg` files and their many dialects.
+//!
+//! # Deserialization
+//!
+//! ```
+//! use facet::Facet;
+//! use facet_ini::from_str;
+//!
+//! #[derive(Facet, Debug, PartialEq)]
+//! struct Server { host: String, port: u16 }
+//!
+//! #[derive(Facet, Debug, PartialEq)]
+//! struct Config {
+//!     name: String,
+//!     mirrors: Vec<String>,
+//!     server: Server,
+//! }
+//!
+//! let ini = "
+//! ; top-level keys come before any section
+//! name = demo
+//! mirrors = https://a.example
+//! mirrors = https://b.example
+//!
+//! [server]
+//! host = localhost
+//! port = 8080
+//! ";
+//!
+//! let config: Config = from_str(ini).unwrap();
+//! assert_eq!(config.server, Server { host: "localhost".into(), port: 8080 });
+//! assert_eq!(config.mirrors, ["https://a.example", "https://b.example"]);
+//! ```
+//!
+//! The document maps onto a struct (or map) like this:
+//!
+//! - **Keys** are fields. The key runs up to the first `=` or `:`, and the
+//!   value is the rest of the line with surrounding whitespace trimmed.
+//!   Values are text, parsed into whatever type the field has.
+//! - **Sections** are nested structs: `[server]` holds the fields of the
+//!   `server` field. Dotted names nest further, so `[server.tls]` is the
+//!   `tls` field of `server`. A section given twice is one section.
+//! - **Repeated keys** fill lists: `mirrors` above is a `Vec<String>`. A key
+//!   given once fills a one-item list, and a key with nothing after the `=`
+//!   an empty one.
+//! - **Quotes** keep whitespace: `'...'` is taken literally, and `"..."`
+//!   understands the escapes `\n`, `\r`, `\t`, `\0`, `\\`, `\"` and `\'`.
+//! - **Comments** are lines starting with `;` or `#`. There are no
+//!   comments at the end of a line: `url = http://example.com/#top` keeps
+//!   its `#top`.
+//!
+//! Missing keys are `None` for `Option` fields and use the field's default
+//! with `#[facet(default)]`, as in every other facet format.
+//!
+//! ## Sections as enums
+//!
+//! Sections go through the same flatten and variant resolution as every
+//! other format, so a section can pick an enum variant: by name, when the
+//! enum is flattened into the document, or by the keys it holds, when the
+//! enum is `#[facet(untagged)]`.
+//!
+//! ```
+//! use facet::Facet;
+//! use facet_ini::from_str;
+//!
+//! #[derive(Facet, Debug, PartialEq)]
+//! #[facet(rename_all = "snake_case")]
+//! #[repr(u8)]
+//! enum Storage {
+//!     Sqlite { path: String },
+//!     Postgres { url: String, pool: u32 },
+//! }
+//!
+//! #[derive(Facet, Debug, PartialEq)]
+//! struct Config {
+//!     name: String,
+//!     #[facet(flatten)]
+//!     storage: Storage,
+//! }
+//!
+//! let ini = "
+//! name = demo
+//!
+//! [postgres]
+//! url = postgres://localhost/demo
+//! pool = 4
+//! ";
+//!
+//! let config: Config = from_str(ini).unwrap();
+//! assert_eq!(
+//!     config.storage,
+//!     Storage::Postgres { url: "postgres://localhost/demo".into(), pool: 4 },
+//! );
+//! ```
+//!
+//! # Serialization
+//!
+//! [`to_string`] writes the scalar fields of a struct as keys, then its
+//! struct and map fields as sections, lists as repeated keys, and leaves out
+//! `None` fields. Values that would read back differently bare are quoted.
+//!
+//! ```
+//! use facet::Facet;
+//! use facet_ini::to_string;
+//!
+//! #[derive(Facet)]
+//! struct Tls { cert: String }
+//!
+//! #[derive(Facet)]
+//! struct Server { port: u16, tls: Tls }
+//!
+//! #[derive(Facet)]
+//! struct Config { motd: String, server: Server }
+//!
+//! let config = Config {
+//!     motd: "  hello  ".into(),
+//!     server: Server { port: 443, tls: Tls { cert: "site.pem".into() } },
+//! };
+//! assert_eq!(
+//!     to_string(&config).unwrap(),
+//!     "motd = \"  hello  \"\n\n[server]\nport = 443\n\n[server.tls]\ncert = site.pem\n",
+//! );
+//! ```
+//!
+//! INI has no room for lists of structs or lists of lists; serializing them
+//! is an error.
+
+extern crate alloc;
+
+mod parser;
+mod serializer;
+
+pub use parser::IniParser;
+pub use serializer::{IniSerializeError, IniSerializer, to_string, to_writer};
+
+// Re-export DeserializeError for convenience
+pub use facet_format::DeserializeError;
+
+/// Deserialize a value from an INI document into an owned type.
+///
+/// Types containing `&str` fields cannot be deserialized with this function;
+/// use `String` or `Cow<str>` instead. For zero-copy deserialization into
+/// borrowed types, use [`from_str_borrowed`].
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use facet_ini::from_str;
+///
+/// let ini = "[colors]\nbg = black\nfg = white\n";
+/// let sections: HashMap<String, HashMap<String, String>> = from_str(ini).unwrap();
+/// assert_eq!(sections["colors"]["fg"], "white");
+/// ```
+pub fn from_str<T>(input: &str) -> Result<T, DeserializeError>
+where
+    T: facet_core::Facet<'static>,
+{
+    use facet_format::FormatDeserializer;
+    let mut parser = IniParser::new(input);
+    let mut de = FormatDeserializer::new_owned(&mut parser);
+    de.deserialize()
+}
+
+/// Deserialize a value from an INI document, allowing zero-copy borrowing.
+///
+/// This variant requires the input to outlive the result (`'input: 'facet`),
+/// so that `&str` fields can point into it. Values with escape sequences
+/// can't be borrowed; use `Cow<str>` for fields that may hold them.
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+/// use facet_ini::from_str_borrowed;
+///
+/// #[derive(Facet, Debug, PartialEq)]
+/// struct Entry<'a> {
+///     name: &'a str,
+///     path: &'a str,
+/// }
+///
+/// let ini = "name = notes\npath = /srv/notes\n";
+/// let entry: Entry = from_str_borrowed(ini).unwrap();
+/// assert_eq!(entry, Entry { name: "notes", path: "/srv/notes" });
+/// ```
+pub fn from_str_borrowed<'input, 'facet, T>(input: &'input str) -> Result<T, DeserializeError>
+where
+    T: facet_core::Facet<'facet>,
+    'input: 'facet,
+{
+    use facet_format::FormatDeserializer;
+    let mut parser = IniParser::new(input);
+    let mut de = FormatDeserializer::new(&mut parser);
+    de.deserialize()
+}
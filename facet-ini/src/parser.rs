@@ -0,0 +1,446 @@
+//! INI parser implementing FormatParser.
+
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use facet_format::{
+    ContainerKind, DeserializeErrorKind, FieldKey, FieldLocationHint, FormatParser, ParseError,
+    ParseEvent, ParseEventKind, SavePoint, ScalarValue,
+};
+use facet_reflect::Span;
+
+/// A section: its keys and subsections, in the order they first appeared.
+#[derive(Debug, Default)]
+struct Section<'de> {
+    entries: Vec<Entry<'de>>,
+}
+
+#[derive(Debug)]
+struct Entry<'de> {
+    key: &'de str,
+    span: Span,
+    node: Node<'de>,
+}
+
+#[derive(Debug)]
+enum Node<'de> {
+    /// Every value given for a key, in order.
+    Values(Vec<Value<'de>>),
+    Section(Section<'de>),
+}
+
+#[derive(Debug)]
+struct Value<'de> {
+    text: Cow<'de, str>,
+    span: Span,
+    /// Nothing after the `=`, not even quotes: an empty list where a
+    /// sequence is expected.
+    bare_empty: bool,
+}
+
+impl<'de> Section<'de> {
+    /// The subsection at `path`, created if needed.
+    fn section_mut(&mut self, path: &[(&'de str, Span)]) -> Result<&mut Section<'de>, ParseError> {
+        let Some(((name, span), rest)) = path.split_first() else {
+            return Ok(self);
+        };
+        let index = match self.entries.iter().position(|e| e.key == *name) {
+            Some(index) => index,
+            None => {
+                self.entries.push(Entry {
+                    key: name,
+                    span: *span,
+                    node: Node::Section(Section::default()),
+                });
+                self.entries.len() - 1
+            }
+        };
+        match &mut self.entries[index].node {
+            Node::Section(section) => section.section_mut(rest),
+            Node::Values(_) => Err(key_and_section(name, *span)),
+        }
+    }
+
+    /// Add a value for `key`, after any given before.
+    fn push_value(
+        &mut self,
+        key: &'de str,
+        span: Span,
+        value: Value<'de>,
+    ) -> Result<(), ParseError> {
+        match self.entries.iter_mut().find(|e| e.key == key) {
+            Some(Entry {
+                node: Node::Values(values),
+                ..
+            }) => values.push(value),
+            Some(_) => return Err(key_and_section(key, span)),
+            None => self.entries.push(Entry {
+                key,
+                span,
+                node: Node::Values(alloc::vec![value]),
+            }),
+        }
+        Ok(())
+    }
+
+    /// Lay the section out as events, between `StructStart` and `StructEnd`.
+    fn emit(self, span: Span, tape: &mut Vec<(ParseEvent<'de>, bool)>) {
+        tape.push((
+            ParseEvent::new(ParseEventKind::StructStart(ContainerKind::Object), span),
+            false,
+        ));
+        for entry in self.entries {
+            let key = FieldKey::new(entry.key, FieldLocationHint::KeyValue);
+            tape.push((
+                ParseEvent::new(ParseEventKind::FieldKey(key), entry.span),
+                false,
+            ));
+            match entry.node {
+                Node::Section(section) => section.emit(entry.span, tape),
+                Node::Values(mut values) if values.len() == 1 => {
+                    let value = values.pop().unwrap();
+                    tape.push((value.scalar(), value.bare_empty));
+                }
+                Node::Values(values) => {
+                    tape.push((
+                        ParseEvent::new(
+                            ParseEventKind::SequenceStart(ContainerKind::Array),
+                            entry.span,
+                        ),
+                        false,
+                    ));
+                    for value in values {
+                        tape.push((value.scalar(), false));
+                    }
+                    tape.push((
+                        ParseEvent::new(ParseEventKind::SequenceEnd, entry.span),
+                        false,
+                    ));
+                }
+            }
+        }
+        tape.push((ParseEvent::new(ParseEventKind::StructEnd, span), false));
+    }
+}
+
+impl<'de> Value<'de> {
+    fn scalar(self) -> ParseEvent<'de> {
+        ParseEvent::new(
+            ParseEventKind::Scalar(ScalarValue::Str(self.text)),
+            self.span,
+        )
+    }
+}
+
+fn key_and_section(key: &str, span: Span) -> ParseError {
+    ParseError::new(
+        span,
+        DeserializeErrorKind::InvalidValue {
+            message: format!("`{key}` is used both as a key and as a section").into(),
+        },
+    )
+}
+
+fn syntax_error(span: Span, message: &'static str) -> ParseError {
+    ParseError::new(
+        span,
+        DeserializeErrorKind::InvalidValue {
+            message: message.into(),
+        },
+    )
+}
+
+/// Parse a whole document into its root section.
+fn parse_document(input: &str) -> Result<Section<'_>, ParseError> {
+    let mut root = Section::default();
+    let mut current: Vec<(&str, Span)> = Vec::new();
+    let mut line_start = 0;
+
+    for raw in input.split_inclusive('\n') {
+        let offset = line_start;
+        line_start += raw.len();
+
+        let line = raw.trim_end_matches(['\n', '\r']);
+        let trimmed = line.trim_start();
+        let start = offset + (line.len() - trimmed.len());
+        let content = trimmed.trim_end();
+        if content.is_empty() || content.starts_with([';', '#']) {
+            continue;
+        }
+        let span = Span::new(start, content.len());
+
+        if let Some(header) = content.strip_prefix('[') {
+            let Some(name) = header.strip_suffix(']') else {
+                return Err(syntax_error(span, "section header is missing its `]`"));
+            };
+            current = section_path(name, start + 1)?;
+            root.section_mut(&current)?;
+            continue;
+        }
+
+        let Some(separator) = content.find(['=', ':']) else {
+            return Err(syntax_error(span, "expected `key = value` or `[section]`"));
+        };
+        let key = content[..separator].trim_end();
+        if key.is_empty() {
+            return Err(syntax_error(span, "key is empty"));
+        }
+        let after = &content[separator + 1..];
+        let text = after.trim_start();
+        let value_start = start + separator + 1 + (after.len() - text.len());
+        let value = parse_value(text, value_start)?;
+        root.section_mut(&current)?
+            .push_value(key, Span::new(start, key.len()), value)?;
+    }
+
+    Ok(root)
+}
+
+/// Split a section name on `.` into the path of nested sections it names.
+fn section_path(name: &str, offset: usize) -> Result<Vec<(&str, Span)>, ParseError> {
+    let mut path = Vec::new();
+    let mut segment_start = offset;
+    for segment in name.split('.') {
+        let trimmed = segment.trim();
+        let lead = segment.len() - segment.trim_start().len();
+        if trimmed.is_empty() {
+            return Err(syntax_error(
+                Span::new(offset, name.len()),
+                "section name has an empty segment",
+            ));
+        }
+        path.push((trimmed, Span::new(segment_start + lead, trimmed.len())));
+        segment_start += segment.len() + 1;
+    }
+    Ok(path)
+}
+
+/// Read a value: bare text, `'literal'`, or `"escaped"`.
+fn parse_value(text: &str, offset: usize) -> Result<Value<'_>, ParseError> {
+    let span = Span::new(offset, text.len());
+
+    if let Some(quoted) = text.strip_prefix('"') {
+        // Only allocated once an escape sequence turns up
+        let mut unescaped: Option<String> = None;
+        let mut chars = quoted.char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    let rest = &quoted[i + 1..];
+                    if !rest.is_empty() {
+                        return Err(ParseError::new(
+                            Span::new(offset + i + 2, rest.len()),
+                            DeserializeErrorKind::UnexpectedChar {
+                                ch: rest.chars().next().unwrap_or_default(),
+                                expected: "end of line after closing quote",
+                            },
+                        ));
+                    }
+                    let text = match unescaped {
+                        Some(owned) => Cow::Owned(owned),
+                        None => Cow::Borrowed(&quoted[..i]),
+                    };
+                    return Ok(Value {
+                        text,
+                        span: Span::new(offset + 1, i),
+                        bare_empty: false,
+                    });
+                }
+                '\\' => {
+                    let out = unescaped.get_or_insert_with(|| String::from(&quoted[..i]));
+                    match chars.next() {
+                        Some((_, 'n')) => out.push('\n'),
+                        Some((_, 'r')) => out.push('\r'),
+                        Some((_, 't')) => out.push('\t'),
+                        Some((_, '0')) => out.push('\0'),
+                        Some((_, c @ ('\\' | '"' | '\''))) => out.push(c),
+                        Some((_, c)) => {
+                            return Err(ParseError::new(
+                                Span::new(offset + 1 + i, 1 + c.len_utf8()),
+                                DeserializeErrorKind::UnexpectedChar {
+                                    ch: c,
+                                    expected: "escape sequence",
+                                },
+                            ));
+                        }
+                        None => break,
+                    }
+                }
+                c => {
+                    if let Some(out) = &mut unescaped {
+                        out.push(c);
+                    }
+                }
+            }
+        }
+        return Err(ParseError::new(
+            span,
+            DeserializeErrorKind::UnexpectedEof {
+                expected: "closing quote",
+            },
+        ));
+    }
+
+    if text.len() >= 2 && text.starts_with('\'') && text.ends_with('\'') {
+        return Ok(Value {
+            text: Cow::Borrowed(&text[1..text.len() - 1]),
+            span: Span::new(offset + 1, text.len() - 2),
+            bare_empty: false,
+        });
+    }
+
+    Ok(Value {
+        text: Cow::Borrowed(text),
+        span,
+        bare_empty: text.is_empty(),
+    })
+}
+
+/// INI parser that emits FormatParser events.
+///
+/// The document is read up front into a tree of sections, so that a section
+/// given more than once, or a key repeated anywhere in its section, comes out
+/// as a single struct field. The tree is then laid out as events: the
+/// document is a struct, each section a struct field of the section that
+/// contains it, and each key a string scalar, or a sequence of them if it was
+/// given more than once.
+///
+/// A key given once still fills a list: when the deserializer expects a
+/// sequence, the parser wraps the single value in one. An empty value (`ids =`)
+/// fills a list with nothing.
+pub struct IniParser<'de> {
+    input: &'de str,
+    /// Every event of the document, flagged when it's a bare empty value.
+    tape: Vec<(ParseEvent<'de>, bool)>,
+    pos: usize,
+    /// A syntax error, reported on the first event.
+    error: Option<ParseError>,
+}
+
+impl<'de> IniParser<'de> {
+    /// Create a new INI parser for `input`.
+    pub fn new(input: &'de str) -> Self {
+        let mut tape = Vec::new();
+        let error = match parse_document(input) {
+            Ok(root) => {
+                root.emit(Span::new(0, input.len()), &mut tape);
+                None
+            }
+            Err(err) => Some(err),
+        };
+        Self {
+            input,
+            tape,
+            pos: 0,
+            error,
+        }
+    }
+
+    /// Wrap a single value about to be read in a sequence.
+    fn wrap_scalar_in_sequence(&mut self) {
+        let Some((event, bare_empty)) = self.tape.get(self.pos) else {
+            return;
+        };
+        if !matches!(event.kind, ParseEventKind::Scalar(_)) {
+            return;
+        }
+        let span = event.span;
+        let start = (
+            ParseEvent::new(ParseEventKind::SequenceStart(ContainerKind::Array), span),
+            false,
+        );
+        let end = (ParseEvent::new(ParseEventKind::SequenceEnd, span), false);
+        if *bare_empty {
+            self.tape.splice(self.pos..=self.pos, [start, end]);
+        } else {
+            self.tape.insert(self.pos + 1, end);
+            self.tape.insert(self.pos, start);
+        }
+    }
+
+    fn check_error(&mut self) -> Result<(), ParseError> {
+        match self.error.take() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'de> FormatParser<'de> for IniParser<'de> {
+    fn next_event(&mut self) -> Result<Option<ParseEvent<'de>>, ParseError> {
+        self.check_error()?;
+        let event = self.tape.get(self.pos).map(|(event, _)| event.clone());
+        if event.is_some() {
+            self.pos += 1;
+        }
+        Ok(event)
+    }
+
+    fn peek_event(&mut self) -> Result<Option<ParseEvent<'de>>, ParseError> {
+        self.check_error()?;
+        Ok(self.tape.get(self.pos).map(|(event, _)| event.clone()))
+    }
+
+    fn skip_value(&mut self) -> Result<(), ParseError> {
+        self.check_error()?;
+        let mut depth = 0usize;
+        loop {
+            let Some((event, _)) = self.tape.get(self.pos) else {
+                return Err(ParseError::new(
+                    Span::new(self.input.len(), 0),
+                    DeserializeErrorKind::UnexpectedEof {
+                        expected: "value to skip",
+                    },
+                ));
+            };
+            self.pos += 1;
+            match event.kind {
+                ParseEventKind::StructStart(_) | ParseEventKind::SequenceStart(_) => depth += 1,
+                ParseEventKind::StructEnd | ParseEventKind::SequenceEnd => {
+                    depth = depth.saturating_sub(1)
+                }
+                _ => {}
+            }
+            if depth == 0 {
+                return Ok(());
+            }
+        }
+    }
+
+    fn save(&mut self) -> SavePoint {
+        // Events are only ever inserted at the read position, so a position
+        // saved earlier still points at the same event
+        SavePoint::new(self.pos as u64)
+    }
+
+    fn restore(&mut self, save_point: SavePoint) {
+        self.pos = save_point.0 as usize;
+    }
+
+    fn needs_container_hints(&self) -> bool {
+        // A key given once reads as a scalar until `hint_sequence` says a
+        // list is expected, so events must not be buffered ahead of hints
+        true
+    }
+
+    fn hint_sequence(&mut self) {
+        self.wrap_scalar_in_sequence();
+    }
+
+    fn hint_array(&mut self, _len: usize) {
+        self.wrap_scalar_in_sequence();
+    }
+
+    fn input(&self) -> Option<&'de [u8]> {
+        Some(self.input.as_bytes())
+    }
+
+    fn current_span(&self) -> Option<Span> {
+        let last = self.pos.checked_sub(1)?;
+        self.tape.get(last).map(|(event, _)| event.span)
+    }
+}
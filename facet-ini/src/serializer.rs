@@ -0,0 +1,386 @@
+//! INI serializer implementing FormatSerializer.
+
+extern crate alloc;
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use facet_format::{FormatSerializer, ScalarValue, SerializeError};
+use facet_reflect::Peek;
+
+/// INI serializer error.
+#[derive(Debug)]
+pub struct IniSerializeError {
+    message: String,
+}
+
+impl IniSerializeError {
+    fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl core::fmt::Display for IniSerializeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for IniSerializeError {}
+
+/// A section being collected: sections can't be written until all of their
+/// keys are known, since keys have to come before any subsection.
+#[derive(Debug, Default)]
+struct Section {
+    entries: Vec<(String, Value)>,
+}
+
+#[derive(Debug)]
+enum Value {
+    Scalar(String),
+    /// Written as the same key repeated.
+    List(Vec<String>),
+    Section(Section),
+}
+
+#[derive(Debug)]
+enum Frame {
+    /// A section, with the key its next value goes under.
+    Section {
+        section: Section,
+        key: Option<String>,
+    },
+    List(Vec<String>),
+}
+
+/// INI serializer.
+///
+/// The value must be a struct or map. Its scalar fields are written as
+/// `key = value` lines at the top of the document, and its struct and map
+/// fields as `[sections]`, nested ones named by their dotted path
+/// (`[server.tls]`). Lists of scalars are written as the same key repeated.
+/// `None` fields are left out.
+pub struct IniSerializer {
+    stack: Vec<Frame>,
+    root: Option<Section>,
+}
+
+impl IniSerializer {
+    /// Create a new INI serializer.
+    pub fn new() -> Self {
+        Self {
+            stack: Vec::new(),
+            root: None,
+        }
+    }
+
+    /// Finish serialization and return the document.
+    pub fn finish(self) -> Result<String, IniSerializeError> {
+        let root = self
+            .root
+            .ok_or_else(|| IniSerializeError::new("an INI document must be a struct or map"))?;
+        let mut out = String::new();
+        write_keys(&mut out, &root)?;
+        let mut path = Vec::new();
+        write_sections(&mut out, &root, &mut path)?;
+        Ok(out)
+    }
+
+    /// Store a finished value under the pending key of the enclosing section.
+    fn attach(&mut self, value: Value) -> Result<(), IniSerializeError> {
+        match self.stack.last_mut() {
+            Some(Frame::Section { section, key }) => {
+                let key = key
+                    .take()
+                    .ok_or_else(|| IniSerializeError::new("value written without a key"))?;
+                section.entries.push((key, value));
+                Ok(())
+            }
+            Some(Frame::List(_)) => Err(IniSerializeError::new(
+                "INI lists can only hold scalars, not sections or other lists",
+            )),
+            None => Err(IniSerializeError::new(
+                "an INI document must be a struct or map",
+            )),
+        }
+    }
+}
+
+impl Default for IniSerializer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FormatSerializer for IniSerializer {
+    type Error = IniSerializeError;
+
+    fn begin_struct(&mut self) -> Result<(), Self::Error> {
+        if let Some(Frame::List(_)) = self.stack.last() {
+            return Err(IniSerializeError::new(
+                "INI lists can only hold scalars, not sections or other lists",
+            ));
+        }
+        self.stack.push(Frame::Section {
+            section: Section::default(),
+            key: None,
+        });
+        Ok(())
+    }
+
+    fn field_key(&mut self, name: &str) -> Result<(), Self::Error> {
+        match self.stack.last_mut() {
+            Some(Frame::Section { key, .. }) => {
+                *key = Some(name.to_string());
+                Ok(())
+            }
+            _ => Err(IniSerializeError::new(
+                "field_key called outside of a section",
+            )),
+        }
+    }
+
+    fn end_struct(&mut self) -> Result<(), Self::Error> {
+        match self.stack.pop() {
+            Some(Frame::Section { section, .. }) => {
+                if self.stack.is_empty() {
+                    self.root = Some(section);
+                    Ok(())
+                } else {
+                    self.attach(Value::Section(section))
+                }
+            }
+            _ => Err(IniSerializeError::new(
+                "end_struct called without matching begin_struct",
+            )),
+        }
+    }
+
+    fn begin_seq(&mut self) -> Result<(), Self::Error> {
+        match self.stack.last() {
+            Some(Frame::Section { .. }) => {
+                self.stack.push(Frame::List(Vec::new()));
+                Ok(())
+            }
+            Some(Frame::List(_)) => Err(IniSerializeError::new(
+                "INI lists can only hold scalars, not sections or other lists",
+            )),
+            None => Err(IniSerializeError::new(
+                "an INI document must be a struct or map",
+            )),
+        }
+    }
+
+    fn end_seq(&mut self) -> Result<(), Self::Error> {
+        match self.stack.pop() {
+            Some(Frame::List(items)) => self.attach(Value::List(items)),
+            _ => Err(IniSerializeError::new(
+                "end_seq called without matching begin_seq",
+            )),
+        }
+    }
+
+    fn scalar(&mut self, scalar: ScalarValue<'_>) -> Result<(), Self::Error> {
+        let text = match scalar {
+            ScalarValue::Null => None,
+            ScalarValue::Unit => Some(String::new()),
+            ScalarValue::Bool(v) => Some(v.to_string()),
+            ScalarValue::Char(c) => Some(c.to_string()),
+            ScalarValue::I64(n) => Some(n.to_string()),
+            ScalarValue::U64(n) => Some(n.to_string()),
+            ScalarValue::I128(n) => Some(n.to_string()),
+            ScalarValue::U128(n) => Some(n.to_string()),
+            ScalarValue::F64(n) => Some(n.to_string()),
+            ScalarValue::Str(s) => Some(s.into_owned()),
+            _ => {
+                return Err(IniSerializeError::new(
+                    "INI values are text; byte strings can't be written",
+                ));
+            }
+        };
+
+        match (self.stack.last_mut(), text) {
+            (Some(Frame::List(items)), Some(text)) => {
+                items.push(text);
+                Ok(())
+            }
+            (Some(Frame::List(_)), None) => Err(IniSerializeError::new(
+                "INI lists can't hold missing values",
+            )),
+            // A missing value is a missing key
+            (Some(Frame::Section { key, .. }), None) => {
+                *key = None;
+                Ok(())
+            }
+            (Some(Frame::Section { .. }), Some(text)) => self.attach(Value::Scalar(text)),
+            (None, _) => Err(IniSerializeError::new(
+                "an INI document must be a struct or map",
+            )),
+        }
+    }
+}
+
+/// Write the `key = value` lines of a section.
+fn write_keys(out: &mut String, section: &Section) -> Result<(), IniSerializeError> {
+    for (key, value) in &section.entries {
+        match value {
+            Value::Scalar(text) => {
+                check_name(key, false)?;
+                write_line(out, key, text, false);
+            }
+            Value::List(items) if items.is_empty() => {
+                check_name(key, false)?;
+                // A bare empty value reads back as an empty list
+                out.push_str(key);
+                out.push_str(" =\n");
+            }
+            Value::List(items) => {
+                check_name(key, false)?;
+                for item in items {
+                    write_line(out, key, item, true);
+                }
+            }
+            Value::Section(_) => {}
+        }
+    }
+    Ok(())
+}
+
+/// Write the subsections of a section, each followed by its own.
+fn write_sections<'a>(
+    out: &mut String,
+    section: &'a Section,
+    path: &mut Vec<&'a str>,
+) -> Result<(), IniSerializeError> {
+    for (name, value) in &section.entries {
+        let Value::Section(child) = value else {
+            continue;
+        };
+        check_name(name, true)?;
+        path.push(name);
+
+        // Sections that only hold sections get no header of their own
+        let has_keys = child
+            .entries
+            .iter()
+            .any(|(_, v)| !matches!(v, Value::Section(_)));
+        if has_keys || child.entries.is_empty() {
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            out.push('[');
+            out.push_str(&path.join("."));
+            out.push_str("]\n");
+            write_keys(out, child)?;
+        }
+        write_sections(out, child, path)?;
+
+        path.pop();
+    }
+    Ok(())
+}
+
+fn write_line(out: &mut String, key: &str, text: &str, in_list: bool) {
+    out.push_str(key);
+    if text.is_empty() && !in_list {
+        out.push_str(" =\n");
+        return;
+    }
+    out.push_str(" = ");
+    if needs_quotes(text) {
+        out.push('"');
+        for c in text.chars() {
+            match c {
+                '"' => out.push_str("\\\""),
+                '\\' => out.push_str("\\\\"),
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '\t' => out.push_str("\\t"),
+                '\0' => out.push_str("\\0"),
+                c => out.push(c),
+            }
+        }
+        out.push('"');
+    } else {
+        out.push_str(text);
+    }
+    out.push('\n');
+}
+
+/// Whether a value would read back differently if written bare: because
+/// it's empty (in a list), padded with whitespace, spans lines, or looks
+/// quoted.
+fn needs_quotes(text: &str) -> bool {
+    text.is_empty()
+        || text.trim() != text
+        || text.starts_with(['"', '\''])
+        || text.contains(['\n', '\r', '\0'])
+}
+
+/// Check that a key or section name can be written so that it reads back.
+fn check_name(name: &str, section: bool) -> Result<(), IniSerializeError> {
+    let forbidden: &[char] = if section {
+        &['=', ':', '[', ']', '.', '\n', '\r']
+    } else {
+        &['=', ':', '\n', '\r']
+    };
+    let valid = !name.is_empty()
+        && name.trim() == name
+        && !name.contains(forbidden)
+        && !name.starts_with(['[', ';', '#']);
+    if valid {
+        Ok(())
+    } else {
+        let what = if section { "section name" } else { "key" };
+        Err(IniSerializeError::new(format!(
+            "{name:?} can't be written as an INI {what}"
+        )))
+    }
+}
+
+/// Serialize a value to an INI document.
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+/// use facet_ini::to_string;
+///
+/// #[derive(Facet)]
+/// struct Server { host: String, port: u16 }
+///
+/// #[derive(Facet)]
+/// struct Config { name: String, server: Server }
+///
+/// let config = Config {
+///     name: "demo".into(),
+///     server: Server { host: "localhost".into(), port: 8080 },
+/// };
+/// assert_eq!(
+///     to_string(&config).unwrap(),
+///     "name = demo\n\n[server]\nhost = localhost\nport = 8080\n",
+/// );
+/// ```
+pub fn to_string<'facet, T>(value: &T) -> Result<String, SerializeError<IniSerializeError>>
+where
+    T: facet_core::Facet<'facet>,
+{
+    let mut ser = IniSerializer::new();
+    facet_format::serialize_root(&mut ser, Peek::new(value))?;
+    ser.finish().map_err(SerializeError::Backend)
+}
+
+/// Serialize a value as an INI document to a writer.
+pub fn to_writer<'facet, T, W>(writer: &mut W, value: &T) -> Result<(), std::io::Error>
+where
+    T: facet_core::Facet<'facet>,
+    W: std::io::Write,
+{
+    let text = to_string(value).map_err(|e| std::io::Error::other(e.to_string()))?;
+    writer.write_all(text.as_bytes())
+}
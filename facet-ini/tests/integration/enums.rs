@@ -0,0 +1,146 @@
+//! Sections resolved to enum variants through flatten and the solver.
+
+use facet::Facet;
+use facet_ini::{from_str, to_string};
+
+#[derive(Facet, Debug, PartialEq)]
+#[facet(rename_all = "snake_case")]
+#[repr(u8)]
+enum Storage {
+    Sqlite { path: String },
+    Postgres { url: String, pool: u32 },
+}
+
+#[derive(Facet, Debug, PartialEq)]
+#[facet(rename_all = "snake_case")]
+#[repr(u8)]
+enum Transport {
+    Tcp { port: u16 },
+    Unix { path: String },
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Service {
+    name: String,
+    #[facet(flatten)]
+    storage: Storage,
+    #[facet(flatten)]
+    transport: Transport,
+}
+
+#[test]
+fn flattened_enums_pick_variants_by_section_name() {
+    let service: Service = from_str(
+        "
+name = api
+
+[unix]
+path = /run/api.sock
+
+[sqlite]
+path = /var/lib/api.db
+",
+    )
+    .unwrap();
+    assert_eq!(
+        service,
+        Service {
+            name: "api".into(),
+            storage: Storage::Sqlite {
+                path: "/var/lib/api.db".into(),
+            },
+            transport: Transport::Unix {
+                path: "/run/api.sock".into(),
+            },
+        }
+    );
+}
+
+#[test]
+fn flattened_enums_round_trip() {
+    let service = Service {
+        name: "api".into(),
+        storage: Storage::Postgres {
+            url: "postgres://db/api".into(),
+            pool: 8,
+        },
+        transport: Transport::Tcp { port: 8080 },
+    };
+    let ini = to_string(&service).unwrap();
+    assert_eq!(
+        ini,
+        "name = api\n\n[postgres]\nurl = postgres://db/api\npool = 8\n\n[tcp]\nport = 8080\n"
+    );
+    assert_eq!(from_str::<Service>(&ini).unwrap(), service);
+}
+
+#[derive(Facet, Debug, PartialEq)]
+#[facet(untagged)]
+#[repr(u8)]
+enum Auth {
+    Token { token: String },
+    Password { user: String, password: String },
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Client {
+    auth: Auth,
+}
+
+#[test]
+fn untagged_enum_section_resolved_by_its_keys() {
+    let client: Client = from_str("[auth]\nuser = ada\npassword = hunter2\n").unwrap();
+    assert_eq!(
+        client.auth,
+        Auth::Password {
+            user: "ada".into(),
+            password: "hunter2".into(),
+        }
+    );
+
+    let client: Client = from_str("[auth]\ntoken = abc\n").unwrap();
+    assert_eq!(
+        client.auth,
+        Auth::Token {
+            token: "abc".into()
+        }
+    );
+}
+
+#[derive(Facet, Debug, PartialEq)]
+#[facet(tag = "kind", rename_all = "snake_case")]
+#[repr(u8)]
+enum Output {
+    File { path: String },
+    Syslog { facility: String },
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Logging {
+    level: Level,
+    output: Output,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+#[facet(rename_all = "lowercase")]
+#[repr(u8)]
+enum Level {
+    Info,
+    Debug,
+}
+
+#[test]
+fn internally_tagged_section_and_unit_variants() {
+    let logging = Logging {
+        level: Level::Debug,
+        output: Output::Syslog {
+            facility: "local0".into(),
+        },
+    };
+    let ini = to_string(&logging).unwrap();
+    assert_eq!(
+        ini,
+        "level = debug\n\n[output]\nkind = syslog\nfacility = local0\n"
+    );
+    assert_eq!(from_str::<Logging>(&ini).unwrap(), logging);
+}
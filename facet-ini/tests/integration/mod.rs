@@ -0,0 +1,3 @@
+mod enums;
+mod parse;
+mod roundtrip;
@@ -0,0 +1,260 @@
+//! Reading INI documents: syntax, sections, repeated keys.
+
+use std::collections::{BTreeMap, HashMap};
+
+use facet::Facet;
+use facet_ini::{from_str, from_str_borrowed};
+
+#[derive(Facet, Debug, PartialEq)]
+struct Owner {
+    name: String,
+    organization: String,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Database {
+    server: String,
+    port: u16,
+    file: String,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Classic {
+    owner: Owner,
+    database: Database,
+}
+
+#[test]
+fn classic_ini_file() {
+    let ini = "\
+; last modified 1 April 2001 by John Doe
+[owner]
+name = John Doe
+organization = Acme Widgets Inc.
+
+[database]
+# use IP address in case network name resolution is not working
+server = 192.0.2.62
+port = 143
+file = \"payroll.dat\"
+";
+    let doc: Classic = from_str(ini).unwrap();
+    assert_eq!(
+        doc,
+        Classic {
+            owner: Owner {
+                name: "John Doe".into(),
+                organization: "Acme Widgets Inc.".into(),
+            },
+            database: Database {
+                server: "192.0.2.62".into(),
+                port: 143,
+                file: "payroll.dat".into(),
+            },
+        }
+    );
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Flat {
+    host: String,
+    port: u16,
+    debug: bool,
+    ratio: f64,
+}
+
+#[test]
+fn properties_style_colons_and_crlf() {
+    let doc: Flat =
+        from_str("host: example.com\r\nport=80\r\n  debug = true\r\nratio:0.5\r\n").unwrap();
+    assert_eq!(
+        doc,
+        Flat {
+            host: "example.com".into(),
+            port: 80,
+            debug: true,
+            ratio: 0.5,
+        }
+    );
+}
+
+#[test]
+fn values_keep_separators_and_hashes_after_the_first() {
+    let doc: HashMap<String, String> =
+        from_str("url = http://example.com/a=b#top\nnote: ratio: 1:2\n").unwrap();
+    assert_eq!(doc["url"], "http://example.com/a=b#top");
+    assert_eq!(doc["note"], "ratio: 1:2");
+}
+
+#[test]
+fn quoted_values() {
+    let doc: HashMap<String, String> = from_str(
+        r#"
+padded = "  spaced  "
+escaped = "a \"quote\"\tand\\n"
+literal = 'no \n escapes'
+empty = ""
+"#,
+    )
+    .unwrap();
+    assert_eq!(doc["padded"], "  spaced  ");
+    assert_eq!(doc["escaped"], "a \"quote\"\tand\\n");
+    assert_eq!(doc["literal"], r"no \n escapes");
+    assert_eq!(doc["empty"], "");
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Mirrors {
+    mirrors: Vec<String>,
+    ports: Vec<u16>,
+    tags: Vec<String>,
+}
+
+#[test]
+fn repeated_keys_fill_lists() {
+    let doc: Mirrors = from_str(
+        "mirrors = a.example\nports = 80\nmirrors = b.example\nmirrors = c.example\ntags =\n",
+    )
+    .unwrap();
+    assert_eq!(
+        doc,
+        Mirrors {
+            mirrors: vec!["a.example".into(), "b.example".into(), "c.example".into()],
+            ports: vec![80],
+            tags: vec![],
+        }
+    );
+}
+
+#[test]
+fn empty_quoted_value_is_a_one_item_list() {
+    #[derive(Facet, Debug, PartialEq)]
+    struct Tags {
+        tags: Vec<String>,
+    }
+
+    let doc: Tags = from_str("tags = \"\"\n").unwrap();
+    assert_eq!(doc.tags, [""]);
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Tls {
+    cert: String,
+    key: String,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Server {
+    port: u16,
+    tls: Tls,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Nested {
+    server: Server,
+}
+
+#[test]
+fn dotted_and_reopened_sections() {
+    let doc: Nested = from_str(
+        "
+[server.tls]
+cert = site.pem
+
+[server]
+port = 443
+
+[ server . tls ]
+key = site.key
+",
+    )
+    .unwrap();
+    assert_eq!(
+        doc,
+        Nested {
+            server: Server {
+                port: 443,
+                tls: Tls {
+                    cert: "site.pem".into(),
+                    key: "site.key".into(),
+                },
+            },
+        }
+    );
+}
+
+#[test]
+fn sections_into_maps() {
+    let doc: BTreeMap<String, BTreeMap<String, u32>> =
+        from_str("[limits]\ncpu = 2\nmemory = 512\n\n[quotas]\nfiles = 100\n").unwrap();
+    assert_eq!(doc["limits"]["memory"], 512);
+    assert_eq!(doc["quotas"]["files"], 100);
+}
+
+#[test]
+fn missing_keys_and_defaults() {
+    #[derive(Facet, Debug, PartialEq)]
+    struct Settings {
+        name: String,
+        nickname: Option<String>,
+        #[facet(default)]
+        retries: u32,
+    }
+
+    let doc: Settings = from_str("name = x\n").unwrap();
+    assert_eq!(
+        doc,
+        Settings {
+            name: "x".into(),
+            nickname: None,
+            retries: 0,
+        }
+    );
+}
+
+#[test]
+fn borrows_unescaped_values() {
+    #[derive(Facet, Debug, PartialEq)]
+    struct Borrowed<'a> {
+        name: &'a str,
+        quoted: &'a str,
+    }
+
+    let doc: Borrowed = from_str_borrowed("name = abc\nquoted = \" x \"\n").unwrap();
+    assert_eq!(
+        doc,
+        Borrowed {
+            name: "abc",
+            quoted: " x ",
+        }
+    );
+}
+
+#[test]
+fn syntax_errors() {
+    for bad in [
+        "[unterminated\n",
+        "no separator here\n",
+        "= value\n",
+        "[a..b]\n",
+        "key = \"unterminated\n",
+        "key = \"closed\" trailing\n",
+        "key = \"bad \\q escape\"\n",
+    ] {
+        assert!(
+            from_str::<HashMap<String, String>>(bad).is_err(),
+            "{bad:?} should not parse"
+        );
+    }
+}
+
+#[test]
+fn key_and_section_with_the_same_name_is_an_error() {
+    let err = from_str::<HashMap<String, String>>("server = x\n[server]\nport = 1\n").unwrap_err();
+    assert!(err.to_string().contains("server"), "{err}");
+}
+
+#[test]
+fn repeated_key_for_a_scalar_field_is_an_error() {
+    assert!(from_str::<Flat>("host = a\nhost = b\nport = 1\ndebug = false\nratio = 1\n").is_err());
+}
@@ -0,0 +1,139 @@
+//! Round trips through facet-ini's serializer and parser.
+
+use std::collections::BTreeMap;
+
+use facet::Facet;
+use facet_format::SerializeError;
+use facet_ini::{from_str, to_string};
+
+#[derive(Facet, Debug, PartialEq)]
+struct Tls {
+    cert: String,
+    ciphers: Vec<String>,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Server {
+    host: String,
+    port: u16,
+    tls: Option<Tls>,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Config {
+    name: String,
+    verbose: bool,
+    ratio: f64,
+    nickname: Option<String>,
+    ids: Vec<u32>,
+    server: Server,
+    env: BTreeMap<String, String>,
+}
+
+fn sample() -> Config {
+    Config {
+        name: "demo".into(),
+        verbose: true,
+        ratio: 0.25,
+        nickname: None,
+        ids: vec![1, 2, 3],
+        server: Server {
+            host: "localhost".into(),
+            port: 8443,
+            tls: Some(Tls {
+                cert: "site.pem".into(),
+                ciphers: vec!["AES256".into(), "CHACHA20".into()],
+            }),
+        },
+        env: BTreeMap::from([
+            ("HOME".into(), "/home/demo".into()),
+            ("PS1".into(), "$ ".into()),
+        ]),
+    }
+}
+
+#[test]
+fn writes_keys_then_sections() {
+    assert_eq!(
+        to_string(&sample()).unwrap(),
+        "\
+name = demo
+verbose = true
+ratio = 0.25
+ids = 1
+ids = 2
+ids = 3
+
+[server]
+host = localhost
+port = 8443
+
+[server.tls]
+cert = site.pem
+ciphers = AES256
+ciphers = CHACHA20
+
+[env]
+HOME = /home/demo
+PS1 = \"$ \"
+"
+    );
+}
+
+#[test]
+fn config_round_trip() {
+    let config = sample();
+    let ini = to_string(&config).unwrap();
+    assert_eq!(from_str::<Config>(&ini).unwrap(), config);
+}
+
+#[test]
+fn awkward_strings_round_trip() {
+    #[derive(Facet, Debug, PartialEq)]
+    struct Texts {
+        empty: String,
+        padded: String,
+        multiline: String,
+        quoted: String,
+        single: String,
+        list: Vec<String>,
+        none: Vec<String>,
+    }
+
+    let texts = Texts {
+        empty: String::new(),
+        padded: " x ".into(),
+        multiline: "one\ntwo\r\n\tthree".into(),
+        quoted: "\"already\" \\ quoted".into(),
+        single: "'single'".into(),
+        list: vec![String::new(), "; not a comment".into()],
+        none: vec![],
+    };
+    let ini = to_string(&texts).unwrap();
+    assert_eq!(from_str::<Texts>(&ini).unwrap(), texts);
+}
+
+#[test]
+fn unrepresentable_values_are_errors() {
+    #[derive(Facet)]
+    struct Point {
+        x: i32,
+    }
+
+    #[derive(Facet)]
+    struct Shapes {
+        points: Vec<Point>,
+    }
+
+    let err = to_string(&Shapes {
+        points: vec![Point { x: 1 }],
+    })
+    .unwrap_err();
+    assert!(matches!(err, SerializeError::Backend(_)), "{err:?}");
+
+    let err = to_string(&vec![1, 2]).unwrap_err();
+    assert!(matches!(err, SerializeError::Backend(_)), "{err:?}");
+
+    let keys = BTreeMap::from([("a = b".to_string(), 1)]);
+    assert!(to_string(&keys).is_err());
+}
@@ -28,6 +28,7 @@ use alloc::vec::Vec;
 
 use facet::Facet;
 use facet_core::{Def, Field, Shape, StructKind, Type, UserType};
+use facet_json::RawJson;
 
 /// A JSON Schema definition.
 ///
@@ -94,6 +95,9 @@ pub struct JsonSchema {
     /// Constant value
     #[facet(rename = "const")]
     pub const_: Option<String>,
+
+    /// Sample values, from `#[facet(example = "...")]`
+    pub examples: Option<Vec<RawJson<'static>>>,
 }
 
 /// JSON Schema type
@@ -161,6 +165,7 @@ impl JsonSchema {
             description: None,
             title: None,
             const_: None,
+            examples: None,
         }
     }
 
@@ -240,7 +245,7 @@ impl SchemaContext {
         // NOTE: We check Def BEFORE shape.inner because types like Vec<T> set
         // .inner() for type parameter propagation but should still be treated
         // as List, not as transparent wrappers.
-        match &shape.def {
+        let mut schema = match &shape.def {
             Def::Scalar => self.schema_for_scalar(shape, description),
             Def::Option(opt) => {
                 // Option<T> becomes anyOf: [schema(T), {type: "null"}]
@@ -317,7 +322,15 @@ impl SchemaContext {
                     }
                 }
             }
+        };
+
+        // Examples declared on a wrapper replace those of the type it wraps
+        let examples: Vec<RawJson<'static>> = shape.examples().map(example_to_json).collect();
+        if !examples.is_empty() {
+            schema.examples = Some(examples);
         }
+
+        schema
     }
 
     fn schema_for_scalar(
@@ -561,6 +574,17 @@ impl SchemaContext {
     }
 }
 
+/// Examples are written as JSON; one that doesn't parse is emitted as a string.
+fn example_to_json(example: &'static str) -> RawJson<'static> {
+    if facet_json::from_str_strict::<RawJson<'static>>(example).is_ok() {
+        RawJson::new(example)
+    } else {
+        RawJson::from_owned(
+            facet_json::to_string(example).expect("string serialization should not fail"),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -692,6 +716,52 @@ mod tests {
         insta::assert_snapshot!(schema);
     }
 
+    #[test]
+    fn test_examples() {
+        #[derive(Facet)]
+        #[facet(example = r#"{"name": "alice", "age": 30}"#)]
+        #[facet(example = r#"{"name": "bob", "age": 41}"#)]
+        struct User {
+            name: String,
+            age: u32,
+        }
+
+        #[derive(Facet)]
+        #[facet(transparent, example = "not json")]
+        struct Nickname(String);
+
+        #[derive(Facet)]
+        struct Account {
+            owner: User,
+            nickname: Nickname,
+            id: u64,
+        }
+
+        let schema = schema_for::<Account>();
+        let properties = schema.properties.expect("account should have properties");
+
+        let owner = properties["owner"].examples.as_ref().unwrap();
+        let owner: Vec<&str> = owner.iter().map(RawJson::as_str).collect();
+        assert_eq!(
+            owner,
+            [
+                r#"{"name": "alice", "age": 30}"#,
+                r#"{"name": "bob", "age": 41}"#
+            ]
+        );
+
+        let nickname = properties["nickname"].examples.as_ref().unwrap();
+        assert_eq!(nickname[0].as_str(), r#""not json""#);
+
+        assert!(properties["id"].examples.is_none());
+
+        let json = to_schema::<User>();
+        assert!(
+            json.contains(r#"{"name": "alice", "age": 30}"#),
+            "examples should be embedded as JSON: {json}"
+        );
+    }
+
     #[test]
     fn test_deserialize_schema_type_as_string() {
         let schema: JsonSchema =
@@ -0,0 +1,167 @@
+//! Benchmark untagged enum deserialization on an activity-feed payload.
+//!
+//! Every event shares its leading keys (`id`, `actor`, `repo`, `created_at`)
+//! and is told apart by a later key (`commits`, `issue`, `starred_at`,
+//! `release`). `facet_json` finds that key in the already-buffered events;
+//! `facet_json_read_ahead` shrinks the event buffer to a single event, which
+//! forces the save/restore read-ahead that used to run for every element.
+//!
+//! Run with:
+//!   cargo bench -p facet-json --bench untagged
+
+use std::sync::LazyLock;
+
+use divan::{Bencher, black_box};
+use facet::Facet;
+use facet_format::FormatDeserializer;
+use facet_json::JsonParser;
+use serde::Deserialize;
+
+fn main() {
+    divan::main();
+}
+
+// =============================================================================
+// Types
+// =============================================================================
+
+#[derive(Debug, Deserialize, Facet)]
+#[serde(untagged)]
+#[facet(untagged)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Event {
+    Push {
+        id: u64,
+        actor: Actor,
+        repo: String,
+        created_at: String,
+        #[serde(rename = "ref")]
+        #[facet(rename = "ref")]
+        git_ref: String,
+        commits: Vec<Commit>,
+    },
+    Issue {
+        id: u64,
+        actor: Actor,
+        repo: String,
+        created_at: String,
+        action: String,
+        issue: Issue,
+    },
+    Star {
+        id: u64,
+        actor: Actor,
+        repo: String,
+        created_at: String,
+        starred_at: String,
+    },
+    Release {
+        id: u64,
+        actor: Actor,
+        repo: String,
+        created_at: String,
+        release: Release,
+    },
+}
+
+#[derive(Debug, Deserialize, Facet)]
+#[allow(dead_code)]
+struct Actor {
+    login: String,
+    avatar_url: String,
+}
+
+#[derive(Debug, Deserialize, Facet)]
+#[allow(dead_code)]
+struct Commit {
+    sha: String,
+    message: String,
+    distinct: bool,
+}
+
+#[derive(Debug, Deserialize, Facet)]
+#[allow(dead_code)]
+struct Issue {
+    number: u64,
+    title: String,
+    labels: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Facet)]
+#[allow(dead_code)]
+struct Release {
+    tag_name: String,
+    prerelease: bool,
+    assets: Vec<String>,
+}
+
+// =============================================================================
+// Data
+// =============================================================================
+
+/// 2000 events, cycling through the four variants.
+static FEED: LazyLock<String> = LazyLock::new(|| {
+    let events: Vec<String> = (0..2000u64).map(event_json).collect();
+    format!("[{}]", events.join(","))
+});
+
+fn event_json(id: u64) -> String {
+    let head = format!(
+        r#""id":{id},"actor":{{"login":"user{id}","avatar_url":"https://avatars.example.com/u/{id}"}},"repo":"org/repo{}","created_at":"2024-05-01T12:00:00Z""#,
+        id % 37
+    );
+    match id % 4 {
+        0 => {
+            let commits: Vec<String> = (0..3)
+                .map(|n| {
+                    format!(r#"{{"sha":"{id:08x}{n:032x}","message":"fix #{n}","distinct":true}}"#)
+                })
+                .collect();
+            format!(
+                r#"{{{head},"ref":"refs/heads/main","commits":[{}]}}"#,
+                commits.join(",")
+            )
+        }
+        1 => format!(
+            r#"{{{head},"action":"opened","issue":{{"number":{id},"title":"Crash on startup","labels":["bug","p1"]}}}}"#
+        ),
+        2 => format!(r#"{{{head},"starred_at":"2024-05-01T12:00:01Z"}}"#),
+        _ => format!(
+            r#"{{{head},"release":{{"tag_name":"v{id}.0.0","prerelease":false,"assets":["app.tar.gz","app.zip"]}}}}"#
+        ),
+    }
+}
+
+// =============================================================================
+// Benchmarks
+// =============================================================================
+
+#[divan::bench]
+fn serde_json(bencher: Bencher) {
+    let data = FEED.as_str();
+    bencher.bench(|| {
+        let result: Vec<Event> = black_box(serde_json::from_str(black_box(data)).unwrap());
+        black_box(result)
+    });
+}
+
+#[divan::bench]
+fn facet_json(bencher: Bencher) {
+    let data = FEED.as_str();
+    bencher.bench(|| {
+        let result: Vec<Event> = black_box(facet_json::from_str(black_box(data)).unwrap());
+        black_box(result)
+    });
+}
+
+#[divan::bench]
+fn facet_json_read_ahead(bencher: Bencher) {
+    let data = FEED.as_str();
+    bencher.bench(|| {
+        let mut parser = JsonParser::<true>::new(black_box(data.as_bytes()));
+        let mut de = FormatDeserializer::with_buffer_capacity_owned(&mut parser, 1);
+        let result: Vec<Event> = black_box(de.deserialize_root().unwrap());
+        black_box(result)
+    });
+}
@@ -0,0 +1,98 @@
+//! Big-number mode: reading and writing numbers without going through `f64`.
+//!
+//! JSON puts no limit on the size or precision of a number, but by default a
+//! number that isn't a plain integer of at most 128 bits is parsed as `f64`,
+//! and `u128`/`i128` are written as strings. In big-number mode (see
+//! [`JsonParser::big_numbers`](crate::JsonParser::big_numbers) and
+//! [`SerializeOptions::big_numbers`](crate::SerializeOptions::big_numbers)),
+//! integer types read numbers like `1e20` from their text, types that parse
+//! from a string receive a number's text, and 128-bit integers are written as
+//! numbers.
+
+use facet_core::ScalarType;
+use facet_format::ScalarValue;
+
+/// Why [`exact_integer`] couldn't produce an integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum NotAnInteger {
+    /// The number has non-zero digits after the decimal point (`1.5`).
+    Fractional,
+    /// The number doesn't fit in an `i128` or `u128`.
+    OutOfRange,
+}
+
+/// Returns true for the integer scalar types.
+pub(crate) fn is_integer(scalar_type: ScalarType) -> bool {
+    matches!(
+        scalar_type,
+        ScalarType::U8
+            | ScalarType::U16
+            | ScalarType::U32
+            | ScalarType::U64
+            | ScalarType::U128
+            | ScalarType::USize
+            | ScalarType::I8
+            | ScalarType::I16
+            | ScalarType::I32
+            | ScalarType::I64
+            | ScalarType::I128
+            | ScalarType::ISize
+    )
+}
+
+/// The integer written by `text`, a valid JSON number, computed from its
+/// digits rather than through `f64`: `1.5e3` is 1500 and `2.50e1` is 25.
+pub(crate) fn exact_integer(text: &str) -> Result<ScalarValue<'static>, NotAnInteger> {
+    let (negative, unsigned) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text),
+    };
+    let (mantissa, exponent) = match unsigned.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (
+            mantissa,
+            exponent
+                .parse::<i64>()
+                .map_err(|_| NotAnInteger::OutOfRange)?,
+        ),
+        None => (unsigned, 0),
+    };
+    let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    // The value is `digits * 10^scale`; digits shifted out by a negative
+    // scale must all be zero.
+    let digits = int_part.bytes().chain(frac_part.bytes());
+    let scale = exponent.saturating_sub(frac_part.len() as i64);
+    let total = int_part.len() + frac_part.len();
+    let kept = if scale < 0 {
+        total.saturating_sub(usize::try_from(scale.unsigned_abs()).unwrap_or(usize::MAX))
+    } else {
+        total
+    };
+    if digits.clone().skip(kept).any(|digit| digit != b'0') {
+        return Err(NotAnInteger::Fractional);
+    }
+
+    let mut value = 0u128;
+    for digit in digits.take(kept) {
+        value = value
+            .checked_mul(10)
+            .and_then(|value| value.checked_add(u128::from(digit - b'0')))
+            .ok_or(NotAnInteger::OutOfRange)?;
+    }
+    // Zero stays zero however large the exponent; anything else overflows
+    // within 39 steps.
+    if value != 0 {
+        for _ in 0..scale.max(0) {
+            value = value.checked_mul(10).ok_or(NotAnInteger::OutOfRange)?;
+        }
+    }
+
+    if negative {
+        0i128
+            .checked_sub_unsigned(value)
+            .map(ScalarValue::I128)
+            .ok_or(NotAnInteger::OutOfRange)
+    } else {
+        Ok(ScalarValue::U128(value))
+    }
+}
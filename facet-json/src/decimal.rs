@@ -0,0 +1,78 @@
+//! Exact handling of decimal types (`rust_decimal::Decimal`,
+//! `bigdecimal::BigDecimal`).
+//!
+//! Going through `f64` corrupts values like `0.1` or anything with more than
+//! ~17 significant digits, which is unacceptable for money. Instead, the parser
+//! hands decimal types the source text of a JSON number, and the serializer
+//! writes their `Display` output, either as a string (the default) or as a bare
+//! number (see [`DecimalFormat`]).
+//!
+//! Decimal support for a type is enabled through its facet feature
+//! (`rust_decimal` or `bigdecimal`); detection here is by shape only.
+
+use facet_core::Shape;
+
+/// Which JSON forms a decimal type can be read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DecimalMode {
+    /// Accept both numbers (`24.99`) and strings (`"24.99"`).
+    #[default]
+    Lenient,
+    /// Only accept numbers; strings are an error.
+    Strict,
+}
+
+/// How decimal types are written by the serializer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DecimalFormat {
+    /// As a string (`"24.99"`), which every JSON consumer reads losslessly.
+    #[default]
+    String,
+    /// As a number (`24.99`). Consumers that parse numbers as `f64` may lose
+    /// precision.
+    Number,
+}
+
+/// Returns true if `shape` is a decimal type read and written exactly.
+pub(crate) fn is_decimal(shape: &Shape) -> bool {
+    matches!(
+        (shape.module_path, shape.type_identifier),
+        (Some("rust_decimal"), "Decimal") | (Some("bigdecimal"), "BigDecimal")
+    )
+}
+
+/// Returns true if `text` is a valid JSON number.
+pub(crate) fn is_json_number(text: &str) -> bool {
+    let bytes = text.as_bytes();
+    let mut pos = usize::from(bytes.first() == Some(&b'-'));
+    let digits = |pos: &mut usize| {
+        let start = *pos;
+        while bytes.get(*pos).is_some_and(u8::is_ascii_digit) {
+            *pos += 1;
+        }
+        *pos - start
+    };
+
+    let int_start = pos;
+    match digits(&mut pos) {
+        0 => return false,
+        len if len > 1 && bytes[int_start] == b'0' => return false,
+        _ => {}
+    }
+    if bytes.get(pos) == Some(&b'.') {
+        pos += 1;
+        if digits(&mut pos) == 0 {
+            return false;
+        }
+    }
+    if matches!(bytes.get(pos), Some(b'e' | b'E')) {
+        pos += 1;
+        if matches!(bytes.get(pos), Some(b'+' | b'-')) {
+            pos += 1;
+        }
+        if digits(&mut pos) == 0 {
+            return false;
+        }
+    }
+    pos == bytes.len()
+}
@@ -0,0 +1,267 @@
+//! Float formatting for the serializer.
+//!
+//! Digits always come from a shortest round-trip algorithm (`zmij` with the
+//! `fast` feature, `core::fmt` otherwise; `core::fmt` for `f32`), computed
+//! for the value's own type, and are laid out by the rules of
+//! ECMAScript's `Number.prototype.toString`, so the output is the same with and
+//! without `fast` and matches what JavaScript would write for the same value
+//! (up to the `.0` on integral values, see [`FloatFormat`]).
+
+use alloc::vec::Vec;
+
+/// How `f32` and `f64` values are written by the serializer.
+///
+/// Non-finite values are always written as `null`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum FloatFormat {
+    /// The shortest digits that read back as the same value, with integral
+    /// values keeping a `.0` so they stay recognizable as floats: `1.0`, `0.1`,
+    /// `1e+21`, `1e-7`.
+    #[default]
+    Shortest,
+    /// Exactly what ECMAScript's `JSON.stringify` writes: like
+    /// [`Shortest`](Self::Shortest), but integral values have no `.0` and
+    /// negative zero is written as `0`.
+    Ecmascript,
+    /// A fixed number of digits after the decimal point, rounded (`1.50` for
+    /// `Fixed(2)`). Never uses an exponent.
+    Fixed(u8),
+}
+
+/// Exponents from which ECMAScript switches to exponential notation.
+const MAX_PLAIN_EXPONENT: i32 = 21;
+const MIN_PLAIN_EXPONENT: i32 = -6;
+
+/// Writes a finite `value` to `out`.
+pub(crate) fn write_f64(out: &mut Vec<u8>, value: f64, format: FloatFormat) {
+    debug_assert!(value.is_finite());
+
+    if let FloatFormat::Fixed(precision) = format {
+        let precision = usize::from(precision);
+        out.extend_from_slice(alloc::format!("{value:.precision$}").as_bytes());
+        return;
+    }
+    write_shortest(out, value == 0.0, value.is_sign_negative(), format, || {
+        Decimal::shortest(value.abs())
+    });
+}
+
+/// Writes a finite `value` to `out`, with the digits of the `f32` itself
+/// rather than of its `f64` widening: `0.1`, not `0.10000000149011612`.
+pub(crate) fn write_f32(out: &mut Vec<u8>, value: f32, format: FloatFormat) {
+    debug_assert!(value.is_finite());
+
+    if let FloatFormat::Fixed(precision) = format {
+        let precision = usize::from(precision);
+        out.extend_from_slice(alloc::format!("{value:.precision$}").as_bytes());
+        return;
+    }
+    write_shortest(out, value == 0.0, value.is_sign_negative(), format, || {
+        Decimal::shortest_f32(value.abs())
+    });
+}
+
+/// Lays out the shortest digits of a finite number in ECMAScript style.
+fn write_shortest(
+    out: &mut Vec<u8>,
+    zero: bool,
+    negative: bool,
+    format: FloatFormat,
+    decimal: impl FnOnce() -> Decimal,
+) {
+    let keep_point = format == FloatFormat::Shortest;
+    if zero {
+        match (keep_point, negative) {
+            (true, true) => out.extend_from_slice(b"-0.0"),
+            (true, false) => out.extend_from_slice(b"0.0"),
+            (false, _) => out.push(b'0'),
+        }
+        return;
+    }
+
+    if negative {
+        out.push(b'-');
+    }
+    let decimal = decimal();
+    let digits = decimal.digits();
+    let (k, n) = (digits.len() as i32, decimal.point);
+
+    if k <= n && n <= MAX_PLAIN_EXPONENT {
+        // 123000
+        out.extend_from_slice(digits);
+        out.resize(out.len() + (n - k) as usize, b'0');
+        if keep_point {
+            out.extend_from_slice(b".0");
+        }
+    } else if 0 < n && n <= MAX_PLAIN_EXPONENT {
+        // 123.45
+        out.extend_from_slice(&digits[..n as usize]);
+        out.push(b'.');
+        out.extend_from_slice(&digits[n as usize..]);
+    } else if MIN_PLAIN_EXPONENT < n && n <= 0 {
+        // 0.00012345
+        out.extend_from_slice(b"0.");
+        out.resize(out.len() + (-n) as usize, b'0');
+        out.extend_from_slice(digits);
+    } else {
+        // 1.2345e+21, 1e-7
+        out.push(digits[0]);
+        if k > 1 {
+            out.push(b'.');
+            out.extend_from_slice(&digits[1..]);
+        }
+        let exponent = n - 1;
+        out.push(b'e');
+        out.push(if exponent < 0 { b'-' } else { b'+' });
+        write_u32(out, exponent.unsigned_abs());
+    }
+}
+
+fn write_u32(out: &mut Vec<u8>, mut n: u32) {
+    let start = out.len();
+    loop {
+        out.push(b'0' + (n % 10) as u8);
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+    out[start..].reverse();
+}
+
+/// Room for the 17 significant digits of a shortest `f64`, with some slack.
+const MAX_DIGITS: usize = 24;
+
+/// A positive number as `0.d1d2...dk × 10^point`, without leading or trailing
+/// zeros in the digits.
+struct Decimal {
+    digits: [u8; MAX_DIGITS],
+    len: usize,
+    point: i32,
+}
+
+impl Decimal {
+    /// The shortest round-trip digits of a finite, positive `value`.
+    fn shortest(value: f64) -> Self {
+        #[cfg(feature = "fast")]
+        {
+            Self::parse(zmij::Buffer::new().format(value).as_bytes())
+        }
+        #[cfg(not(feature = "fast"))]
+        {
+            use core::fmt::Write;
+
+            let mut buf = StackBuf::default();
+            // `LowerExp` prints the shortest round-trip digits, like `Display`,
+            // but without spelling out hundreds of zeros for large exponents.
+            let _ = write!(buf, "{value:e}");
+            Self::parse(buf.as_bytes())
+        }
+    }
+
+    /// The shortest round-trip digits of a finite, positive `f32`.
+    fn shortest_f32(value: f32) -> Self {
+        use core::fmt::Write;
+
+        let mut buf = StackBuf::default();
+        // `f32`'s `LowerExp` prints the shortest digits that read back as the
+        // same `f32`.
+        let _ = write!(buf, "{value:e}");
+        Self::parse(buf.as_bytes())
+    }
+
+    /// Reads a plain (`123.45`) or exponential (`1.2345e2`, `1E-7`) decimal.
+    fn parse(text: &[u8]) -> Self {
+        let mut decimal = Self {
+            digits: [0; MAX_DIGITS],
+            len: 0,
+            point: 0,
+        };
+        let mut seen_point = false;
+        let mut integer_digits = 0i32;
+        let mut leading_zeros = 0i32;
+        let mut pending_zeros = 0usize;
+        let mut exponent = 0i32;
+
+        for (index, &byte) in text.iter().enumerate() {
+            match byte {
+                b'.' => seen_point = true,
+                b'e' | b'E' => {
+                    exponent = parse_exponent(&text[index + 1..]);
+                    break;
+                }
+                b'0'..=b'9' => {
+                    if !seen_point {
+                        integer_digits += 1;
+                    }
+                    if byte == b'0' {
+                        if decimal.len == 0 {
+                            leading_zeros += 1;
+                        } else {
+                            pending_zeros += 1;
+                        }
+                        continue;
+                    }
+                    for _ in 0..pending_zeros {
+                        decimal.push(b'0');
+                    }
+                    pending_zeros = 0;
+                    decimal.push(byte);
+                }
+                _ => {}
+            }
+        }
+
+        decimal.point = integer_digits + exponent - leading_zeros;
+        decimal
+    }
+
+    fn push(&mut self, digit: u8) {
+        if self.len < MAX_DIGITS {
+            self.digits[self.len] = digit;
+            self.len += 1;
+        }
+    }
+
+    fn digits(&self) -> &[u8] {
+        &self.digits[..self.len]
+    }
+}
+
+fn parse_exponent(text: &[u8]) -> i32 {
+    let (negative, digits) = match text.split_first() {
+        Some((b'-', rest)) => (true, rest),
+        Some((b'+', rest)) => (false, rest),
+        _ => (false, text),
+    };
+    let magnitude = digits
+        .iter()
+        .fold(0i32, |acc, d| acc * 10 + i32::from(d - b'0'));
+    if negative { -magnitude } else { magnitude }
+}
+
+/// A fixed-size `fmt::Write` target, big enough for `{:e}` of any `f64`.
+#[derive(Default)]
+struct StackBuf {
+    buf: [u8; 32],
+    len: usize,
+}
+
+impl StackBuf {
+    fn as_bytes(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+}
+
+impl core::fmt::Write for StackBuf {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        if end > self.buf.len() {
+            return Err(core::fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
@@ -0,0 +1,758 @@
+//! [JSON Pointer](https://www.rfc-editor.org/rfc/rfc6901) access to Facet values.
+//!
+//! A pointer like `/servers/0/port` addresses a value by the keys and indices
+//! it would have in the value's JSON form, without going through JSON at all:
+//! [`get`] walks a [`Peek`], [`set`] walks a [`Poke`] and overwrites the target
+//! in place. This is enough to apply targeted config overrides or to build
+//! JSON Merge Patch on top of typed values.
+//!
+//! Keys resolve as follows:
+//!
+//! - struct fields by their serialized name (`#[facet(rename)]` applies);
+//!   skipped fields can't be addressed, and flattened ones are found where
+//!   they end up in JSON, including under a `flatten_prefix_from` prefix;
+//! - lists, arrays and slices by index, with `-` appending on [`set`];
+//! - maps with string keys by key, inserting on [`set`] when the key is new;
+//! - dynamic values (such as `facet_value::Value`) by object key or array index;
+//! - enums by their tagging: an externally tagged enum by the name of its
+//!   active variant, then that variant's content (`/backend/Disk/path`); an
+//!   adjacently tagged one by its content key; internally tagged and
+//!   untagged ones directly by their content. A newtype variant's content is
+//!   its field.
+//!
+//! `Option`s are looked through when the pointer continues past them, and
+//! smart pointers are too on [`get`]. Only a `Box` can be written through.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use facet_core::{Def, DynValueKind, Facet, Field, Shape, StructKind, Type, UserType, Variant};
+use facet_reflect::{Peek, Poke, ReflectError};
+
+use crate::key_order::push_segment;
+
+/// Why a JSON Pointer couldn't be resolved or written.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum JsonPointerError {
+    /// The pointer is neither empty nor starts with `/`, or has a `~` that
+    /// isn't followed by `0` or `1`.
+    Syntax {
+        /// The pointer as given.
+        pointer: String,
+    },
+    /// Nothing is at `path`: the key is missing, the index is out of range,
+    /// or an `Option` on the way is `None`.
+    NotFound {
+        /// The pointer up to and including the token that didn't resolve.
+        path: String,
+    },
+    /// A token addressing a sequence isn't an index (`01`, `x`, or `-`
+    /// outside of [`set`]).
+    InvalidIndex {
+        /// The pointer up to and including the bad token.
+        path: String,
+    },
+    /// The value at `path` has no children that a token can address, or
+    /// can't be written through.
+    NotAContainer {
+        /// The pointer up to the value.
+        path: String,
+        /// The shape of the value.
+        shape: &'static Shape,
+    },
+    /// The value given to [`set`] doesn't have the shape of its target.
+    WrongShape {
+        /// The pointer to the target.
+        path: String,
+        /// The shape of the target.
+        expected: &'static Shape,
+        /// The shape of the value given.
+        actual: &'static Shape,
+    },
+    /// The container at `path` refused the write.
+    Reflect {
+        /// The pointer to the container.
+        path: String,
+        /// The underlying error.
+        error: ReflectError,
+    },
+}
+
+impl fmt::Display for JsonPointerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Syntax { pointer } => write!(f, "invalid JSON Pointer {pointer:?}"),
+            Self::NotFound { path } => write!(f, "nothing at {path:?}"),
+            Self::InvalidIndex { path } => write!(f, "invalid array index at {path:?}"),
+            Self::NotAContainer { path, shape } => {
+                write!(f, "{shape} at {path:?} can't be addressed into")
+            }
+            Self::WrongShape {
+                path,
+                expected,
+                actual,
+            } => write!(f, "expected {expected} at {path:?}, got {actual}"),
+            Self::Reflect { path, error } => write!(f, "at {path:?}: {error}"),
+        }
+    }
+}
+
+impl core::error::Error for JsonPointerError {}
+
+/// Look up the value at `pointer`.
+///
+/// The empty pointer addresses `peek` itself.
+///
+/// ```
+/// use facet::Facet;
+/// use facet_reflect::Peek;
+///
+/// #[derive(Facet)]
+/// struct Config {
+///     servers: Vec<Server>,
+/// }
+///
+/// #[derive(Facet)]
+/// struct Server {
+///     port: u16,
+/// }
+///
+/// let config = Config {
+///     servers: vec![Server { port: 8080 }],
+/// };
+/// let port = facet_json::json_pointer::get(Peek::new(&config), "/servers/0/port").unwrap();
+/// assert_eq!(port.get::<u16>().unwrap(), &8080);
+/// ```
+pub fn get<'mem, 'facet>(
+    peek: Peek<'mem, 'facet>,
+    pointer: &str,
+) -> Result<Peek<'mem, 'facet>, JsonPointerError> {
+    let tokens = parse(pointer)?;
+    let mut current = peek;
+    let mut depth = 0;
+    // Whether `current` is an enum whose tag the previous token named
+    let mut content = false;
+
+    while depth < tokens.len() {
+        let token = tokens[depth].as_str();
+        let not_found = || JsonPointerError::NotFound {
+            path: prefix(&tokens, depth + 1),
+        };
+        let shape = current.shape();
+
+        current = match shape.def {
+            Def::Option(_) => {
+                // Looked through: the same token applies to the inner value.
+                current = current
+                    .into_option()
+                    .ok()
+                    .and_then(|option| option.value())
+                    .ok_or_else(not_found)?;
+                continue;
+            }
+            Def::Pointer(_) => {
+                current = current
+                    .into_pointer()
+                    .ok()
+                    .and_then(|pointer| pointer.borrow_inner())
+                    .ok_or_else(|| not_a_container(&tokens, depth, shape))?;
+                continue;
+            }
+            Def::List(_) | Def::Array(_) | Def::Slice(_) => {
+                let index = parse_index(&tokens, depth)?;
+                current
+                    .into_list_like()
+                    .ok()
+                    .and_then(|list| list.get(index))
+                    .ok_or_else(not_found)?
+            }
+            Def::Map(_) => current
+                .into_map()
+                .ok()
+                .and_then(|map| {
+                    map.iter()
+                        .find(|(key, _)| key.as_str() == Some(token))
+                        .map(|(_, value)| value)
+                })
+                .ok_or_else(not_found)?,
+            Def::DynamicValue(_) => {
+                let value = current
+                    .into_dynamic_value()
+                    .map_err(|_| not_a_container(&tokens, depth, shape))?;
+                match value.kind() {
+                    DynValueKind::Object => value.object_get(token).ok_or_else(not_found)?,
+                    DynValueKind::Array => value
+                        .array_get(parse_index(&tokens, depth)?)
+                        .ok_or_else(not_found)?,
+                    _ => return Err(not_a_container(&tokens, depth, shape)),
+                }
+            }
+            _ => {
+                let route = route(current, token, content)
+                    .map_err(|miss| miss.into_error(&tokens, depth, shape))?;
+                for step in route.steps {
+                    current = peek_step(current, step).ok_or_else(not_found)?;
+                }
+                content = route.content;
+                if route.consumed {
+                    depth += 1;
+                }
+                continue;
+            }
+        };
+        content = false;
+        depth += 1;
+    }
+
+    Ok(current)
+}
+
+/// Overwrite the value at `pointer` with `value`.
+///
+/// `value` must have the shape of its target, except that a `T` may be
+/// written to an `Option<T>` or a `Box<T>`. The last token may also name a
+/// new map key, or be `-` to append to a list.
+///
+/// ```
+/// use facet::Facet;
+/// use facet_reflect::Poke;
+///
+/// #[derive(Facet)]
+/// struct Config {
+///     name: String,
+///     timeout: Option<u32>,
+/// }
+///
+/// let mut config = Config {
+///     name: "api".into(),
+///     timeout: None,
+/// };
+/// facet_json::json_pointer::set(Poke::new(&mut config), "/timeout", 30u32).unwrap();
+/// assert_eq!(config.timeout, Some(30));
+/// ```
+pub fn set<'facet, T: Facet<'facet>>(
+    poke: Poke<'_, 'facet>,
+    pointer: &str,
+    value: T,
+) -> Result<(), JsonPointerError> {
+    let tokens = parse(pointer)?;
+    set_at(poke, &tokens, 0, false, value)
+}
+
+fn set_at<'facet, T: Facet<'facet>>(
+    mut poke: Poke<'_, 'facet>,
+    tokens: &[String],
+    depth: usize,
+    content: bool,
+    value: T,
+) -> Result<(), JsonPointerError> {
+    let shape = poke.shape();
+    let reflect = |error| JsonPointerError::Reflect {
+        path: prefix(tokens, depth),
+        error,
+    };
+
+    let Some(token) = tokens.get(depth).map(String::as_str) else {
+        if shape == T::SHAPE {
+            return poke.set(value).map_err(reflect);
+        }
+        match shape.def {
+            Def::Option(option) if option.t() == T::SHAPE => {
+                let mut option = poke.into_option().map_err(reflect)?;
+                return option.set_some(value).map_err(reflect);
+            }
+            Def::Pointer(pointer) if pointer.pointee() == Some(T::SHAPE) => {
+                let mut pointer = poke.into_pointer().map_err(reflect)?;
+                if let Some(inner) = pointer.borrow_inner_mut() {
+                    return set_at(inner, tokens, depth, false, value);
+                }
+            }
+            _ => {}
+        }
+        return Err(JsonPointerError::WrongShape {
+            path: prefix(tokens, depth),
+            expected: shape,
+            actual: T::SHAPE,
+        });
+    };
+    let is_last = depth + 1 == tokens.len();
+    let not_found = || JsonPointerError::NotFound {
+        path: prefix(tokens, depth + 1),
+    };
+
+    match shape.def {
+        Def::Option(_) => {
+            let mut option = poke.into_option().map_err(reflect)?;
+            let inner = option.value_mut().ok_or_else(not_found)?;
+            set_at(inner, tokens, depth, false, value)
+        }
+        Def::Pointer(_) => {
+            let mut pointer = poke.into_pointer().map_err(reflect)?;
+            let inner = pointer
+                .borrow_inner_mut()
+                .ok_or_else(|| not_a_container(tokens, depth, shape))?;
+            set_at(inner, tokens, depth, false, value)
+        }
+        Def::List(_) | Def::Array(_) | Def::Slice(_) => {
+            let mut list = poke.into_list_like().map_err(reflect)?;
+            if token == "-" && is_last {
+                return list.push(value).map_err(reflect);
+            }
+            let index = parse_index(tokens, depth)?;
+            let item = list.get_mut(index).ok_or_else(not_found)?;
+            set_at(item, tokens, depth + 1, false, value)
+        }
+        Def::Map(map_def) => {
+            if map_def.k() != String::SHAPE {
+                return Err(not_a_container(tokens, depth, shape));
+            }
+            let mut map = poke.into_map().map_err(reflect)?;
+            let key = token.to_string();
+            if is_last {
+                return map.insert(key, value).map_err(reflect);
+            }
+            let entry = map.get_mut(&key).map_err(reflect)?.ok_or_else(not_found)?;
+            set_at(entry, tokens, depth + 1, false, value)
+        }
+        Def::DynamicValue(_) => {
+            let mut dynamic = poke.into_dynamic_value().map_err(reflect)?;
+            match dynamic.kind() {
+                DynValueKind::Object if is_last => {
+                    dynamic.insert_object_entry(token, value).map_err(reflect)
+                }
+                DynValueKind::Object => {
+                    let entry = dynamic.object_get_mut(token).ok_or_else(not_found)?;
+                    set_at(entry, tokens, depth + 1, false, value)
+                }
+                DynValueKind::Array if token == "-" && is_last => {
+                    dynamic.push_array_element(value).map_err(reflect)
+                }
+                _ => Err(not_a_container(tokens, depth, shape)),
+            }
+        }
+        _ => {
+            let route = route(poke.as_peek(), token, content)
+                .map_err(|miss| miss.into_error(tokens, depth, shape))?;
+            let next = if route.consumed { depth + 1 } else { depth };
+            set_along(
+                poke,
+                &route.steps,
+                tokens,
+                depth,
+                next,
+                route.content,
+                value,
+            )
+        }
+    }
+}
+
+/// Follow `steps` from `poke`, then carry on with the token at `next`.
+fn set_along<'facet, T: Facet<'facet>>(
+    poke: Poke<'_, 'facet>,
+    steps: &[Step],
+    tokens: &[String],
+    depth: usize,
+    next: usize,
+    content: bool,
+    value: T,
+) -> Result<(), JsonPointerError> {
+    let Some((&step, rest)) = steps.split_first() else {
+        return set_at(poke, tokens, next, content, value);
+    };
+    let shape = poke.shape();
+    let reflect = |error| JsonPointerError::Reflect {
+        path: prefix(tokens, depth),
+        error,
+    };
+    let not_found = || JsonPointerError::NotFound {
+        path: prefix(tokens, depth + 1),
+    };
+
+    match step {
+        Step::Field(index) => {
+            if let Type::User(UserType::Struct(_)) = shape.ty {
+                let mut poke_struct = poke.into_struct().map_err(reflect)?;
+                let field = poke_struct.field(index).map_err(reflect)?;
+                set_along(field, rest, tokens, depth, next, content, value)
+            } else {
+                let mut poke_enum = poke
+                    .into_enum()
+                    .map_err(|_| not_a_container(tokens, depth, shape))?;
+                let field = poke_enum
+                    .field(index)
+                    .ok()
+                    .flatten()
+                    .ok_or_else(not_found)?;
+                set_along(field, rest, tokens, depth, next, content, value)
+            }
+        }
+        Step::OptionSome => {
+            let mut option = poke.into_option().map_err(reflect)?;
+            let inner = option.value_mut().ok_or_else(not_found)?;
+            set_along(inner, rest, tokens, depth, next, content, value)
+        }
+        Step::Deref => {
+            let mut pointer = poke.into_pointer().map_err(reflect)?;
+            let inner = pointer
+                .borrow_inner_mut()
+                .ok_or_else(|| not_a_container(tokens, depth, shape))?;
+            set_along(inner, rest, tokens, depth, next, content, value)
+        }
+        Step::Index(index) => {
+            let mut list = poke.into_list_like().map_err(reflect)?;
+            let item = list.get_mut(index).ok_or_else(not_found)?;
+            set_along(item, rest, tokens, depth, next, content, value)
+        }
+    }
+}
+
+/// Where a token leads inside a struct or enum, following the layout
+/// [`fields_for_serialize`](facet_reflect::HasFields::fields_for_serialize)
+/// gives it in JSON.
+struct Route {
+    /// How to get from the struct or enum to the value the token leads to.
+    steps: Vec<Step>,
+    /// Whether the token was used up. If not, it applies to that value too:
+    /// a flattened map takes it as a key, a newtype variant passes it on to
+    /// its field.
+    consumed: bool,
+    /// Whether the value is an enum whose tag the token named, so the next
+    /// token addresses the content of its active variant.
+    content: bool,
+}
+
+#[derive(Clone, Copy)]
+enum Step {
+    /// A struct field, or a field of an enum's active variant.
+    Field(usize),
+    /// The value of a `Some`.
+    OptionSome,
+    /// The pointee of a smart pointer.
+    Deref,
+    /// An item of a list.
+    Index(usize),
+}
+
+/// Why a token doesn't lead anywhere.
+enum Miss {
+    NotFound,
+    NotAContainer,
+}
+
+impl Miss {
+    fn into_error(
+        self,
+        tokens: &[String],
+        depth: usize,
+        shape: &'static Shape,
+    ) -> JsonPointerError {
+        match self {
+            Self::NotFound => JsonPointerError::NotFound {
+                path: prefix(tokens, depth + 1),
+            },
+            Self::NotAContainer => not_a_container(tokens, depth, shape),
+        }
+    }
+}
+
+/// Resolve `token` against a struct, or an enum: against its tag or, with
+/// `content`, the content of its active variant.
+fn route(peek: Peek<'_, '_>, token: &str, content: bool) -> Result<Route, Miss> {
+    let shape = peek.shape();
+    match shape.ty {
+        Type::User(UserType::Struct(struct_type)) => {
+            let peek_struct = peek.into_struct().map_err(|_| Miss::NotAContainer)?;
+            fields_route(
+                struct_type.fields,
+                &|index| peek_struct.field(index).ok(),
+                token,
+                false,
+            )
+            .ok_or(Miss::NotFound)
+        }
+        Type::User(UserType::Enum(_)) => {
+            let peek_enum = peek.into_enum().map_err(|_| Miss::NotAContainer)?;
+            let variant = peek_enum
+                .active_variant()
+                .map_err(|_| Miss::NotAContainer)?;
+            let fields = variant.data.fields;
+            let has_content = variant.data.kind != StructKind::Unit;
+
+            if !content && !is_untagged(shape, variant) {
+                let tag = shape.get_tag_attr();
+                match (tag, shape.get_content_attr()) {
+                    // Written as a number
+                    (None, _) if shape.is_numeric() => return Err(Miss::NotAContainer),
+                    // Externally tagged: `{"Variant": content}`, or `"Variant"`
+                    (None, _) if !has_content => return Err(Miss::NotAContainer),
+                    (None, _) => {
+                        return if token == variant.effective_name() {
+                            Ok(Route::tag())
+                        } else {
+                            Err(Miss::NotFound)
+                        };
+                    }
+                    // Adjacently tagged: `{"tag": "Variant", "content": content}`
+                    (Some(_), Some(content_key)) => {
+                        return if has_content && token == content_key {
+                            Ok(Route::tag())
+                        } else {
+                            Err(Miss::NotFound)
+                        };
+                    }
+                    // Internally tagged: the tag sits among the content's keys
+                    (Some(_), None) => {}
+                }
+            }
+
+            if !has_content {
+                Err(Miss::NotAContainer)
+            } else if is_newtype(fields) {
+                Ok(Route {
+                    steps: alloc::vec![Step::Field(0)],
+                    consumed: false,
+                    content: false,
+                })
+            } else {
+                fields_route(
+                    fields,
+                    &|index| peek_enum.field(index).ok().flatten(),
+                    token,
+                    false,
+                )
+                .ok_or(Miss::NotFound)
+            }
+        }
+        _ => Err(Miss::NotAContainer),
+    }
+}
+
+impl Route {
+    /// The token named an enum's tag.
+    fn tag() -> Self {
+        Self {
+            steps: Vec::new(),
+            consumed: true,
+            content: true,
+        }
+    }
+}
+
+/// Find `key` among `fields`, whose values `value` gives, looking into
+/// flattened fields. Named fields win over flattened maps, which take any key.
+///
+/// Under a `flatten_prefix_from` prefix (`prefixed`), keys have had the
+/// prefix stripped, and only flattened structs are looked into.
+fn fields_route<'mem, 'facet>(
+    fields: &'static [Field],
+    value: &dyn Fn(usize) -> Option<Peek<'mem, 'facet>>,
+    key: &str,
+    prefixed: bool,
+) -> Option<Route> {
+    let mut catch_all = None;
+
+    for (index, field) in fields.iter().enumerate() {
+        if field.should_skip_serializing_unconditional() {
+            continue;
+        }
+        if !field.is_flattened() {
+            if field.effective_name() == key {
+                return Some(Route {
+                    steps: alloc::vec![Step::Field(index)],
+                    consumed: true,
+                    content: false,
+                });
+            }
+            continue;
+        }
+
+        let Some(field_value) = value(index) else {
+            continue;
+        };
+        let (key, prefixed) = match field.flatten_prefix_from() {
+            Some(source) if !prefixed => {
+                let Some(rest) = flatten_prefix(fields, value, source)
+                    .and_then(|prefix| key.strip_prefix(prefix.as_str())?.strip_prefix('.'))
+                else {
+                    continue;
+                };
+                (rest, true)
+            }
+            _ => (key, prefixed),
+        };
+        let Some(mut route) = flattened_route(field_value, key, prefixed) else {
+            continue;
+        };
+        route.steps.insert(0, Step::Field(index));
+        if route.consumed {
+            return Some(route);
+        }
+        catch_all.get_or_insert(route);
+    }
+
+    catch_all
+}
+
+/// Find `key` in the value of a flattened field.
+fn flattened_route(mut value: Peek<'_, '_>, key: &str, prefixed: bool) -> Option<Route> {
+    let mut steps = Vec::new();
+    loop {
+        if let Ok(pointer) = value.into_pointer() {
+            value = pointer.borrow_inner()?;
+            steps.push(Step::Deref);
+        } else if let Ok(option) = value.into_option() {
+            // `None` is left out of the JSON entirely
+            value = option.value()?;
+            steps.push(Step::OptionSome);
+        } else {
+            break;
+        }
+    }
+    let tagged = |steps: Vec<Step>| Route {
+        steps,
+        consumed: true,
+        content: true,
+    };
+
+    let shape = value.shape();
+    match (shape.def, shape.ty) {
+        (_, Type::User(UserType::Struct(struct_type))) => {
+            let peek_struct = value.into_struct().ok()?;
+            let mut route = fields_route(
+                struct_type.fields,
+                &|index| peek_struct.field(index).ok(),
+                key,
+                prefixed,
+            )?;
+            steps.append(&mut route.steps);
+            route.steps = steps;
+            Some(route)
+        }
+        _ if prefixed => None,
+        // Keyed by the variant name; unit variants are left out
+        (_, Type::User(UserType::Enum(_))) => {
+            let variant = value.into_enum().ok()?.active_variant().ok()?;
+            (variant.data.kind != StructKind::Unit && variant.effective_name() == key)
+                .then(|| tagged(steps))
+        }
+        (Def::Map(_), _) => Some(Route {
+            steps,
+            consumed: false,
+            content: false,
+        }),
+        // A list of enums: each item keyed by its variant name
+        (Def::List(_), _) => {
+            let index = value.into_list_like().ok()?.iter().position(|item| {
+                item.into_enum()
+                    .ok()
+                    .and_then(|item| item.active_variant().ok())
+                    .is_some_and(|variant| variant.effective_name() == key)
+            })?;
+            steps.push(Step::Index(index));
+            Some(tagged(steps))
+        }
+        _ => None,
+    }
+}
+
+/// The key prefix held by the `flatten_prefix_from` field named `source`.
+fn flatten_prefix<'mem, 'facet>(
+    fields: &'static [Field],
+    value: &dyn Fn(usize) -> Option<Peek<'mem, 'facet>>,
+    source: &str,
+) -> Option<String> {
+    let index = fields.iter().position(|field| field.name == source)?;
+    let peek = value(index)?.innermost_peek();
+    match peek.as_str() {
+        Some(prefix) => Some(prefix.to_string()),
+        None => peek.shape().vtable.has_display().then(|| peek.to_string()),
+    }
+}
+
+/// Take one step from `peek`.
+fn peek_step<'mem, 'facet>(peek: Peek<'mem, 'facet>, step: Step) -> Option<Peek<'mem, 'facet>> {
+    match step {
+        Step::Field(index) => match peek.shape().ty {
+            Type::User(UserType::Struct(_)) => peek.into_struct().ok()?.field(index).ok(),
+            _ => peek.into_enum().ok()?.field(index).ok().flatten(),
+        },
+        Step::OptionSome => peek.into_option().ok()?.value(),
+        Step::Deref => peek.into_pointer().ok()?.borrow_inner(),
+        Step::Index(index) => peek.into_list_like().ok()?.get(index),
+    }
+}
+
+/// Enums written as their active variant's content, without a tag.
+fn is_untagged(shape: &Shape, variant: &Variant) -> bool {
+    shape.is_untagged()
+        || shape.is_cow()
+        || variant.has_builtin_attr("untagged")
+        || (variant.is_other() && !variant.data.fields.iter().any(Field::is_variant_tag))
+}
+
+/// Split a pointer into its unescaped reference tokens.
+fn parse(pointer: &str) -> Result<Vec<String>, JsonPointerError> {
+    let syntax = || JsonPointerError::Syntax {
+        pointer: pointer.to_string(),
+    };
+    if pointer.is_empty() {
+        return Ok(Vec::new());
+    }
+    let rest = pointer.strip_prefix('/').ok_or_else(syntax)?;
+
+    rest.split('/')
+        .map(|raw| {
+            let mut token = String::with_capacity(raw.len());
+            let mut chars = raw.chars();
+            while let Some(c) = chars.next() {
+                match c {
+                    '~' => match chars.next() {
+                        Some('0') => token.push('~'),
+                        Some('1') => token.push('/'),
+                        _ => return Err(syntax()),
+                    },
+                    c => token.push(c),
+                }
+            }
+            Ok(token)
+        })
+        .collect()
+}
+
+/// The pointer made of the first `len` tokens, escaped again.
+fn prefix(tokens: &[String], len: usize) -> String {
+    let mut path = String::new();
+    for token in &tokens[..len] {
+        push_segment(&mut path, token);
+    }
+    path
+}
+
+/// An array index: `0`, or digits without a leading zero.
+fn parse_index(tokens: &[String], depth: usize) -> Result<usize, JsonPointerError> {
+    let token = tokens[depth].as_str();
+    let well_formed = !token.is_empty()
+        && token.bytes().all(|b| b.is_ascii_digit())
+        && (token == "0" || !token.starts_with('0'));
+    well_formed
+        .then(|| token.parse().ok())
+        .flatten()
+        .ok_or_else(|| JsonPointerError::InvalidIndex {
+            path: prefix(tokens, depth + 1),
+        })
+}
+
+/// A tuple variant with a single field, serialized as that field's value.
+fn is_newtype(fields: &[Field]) -> bool {
+    matches!(fields, [field] if field.name == "0")
+}
+
+fn not_a_container(tokens: &[String], depth: usize, shape: &'static Shape) -> JsonPointerError {
+    JsonPointerError::NotAContainer {
+        path: prefix(tokens, depth),
+        shape,
+    }
+}
@@ -0,0 +1,217 @@
+//! Opt-in preservation of object key order across a deserialize/serialize round trip.
+//!
+//! Serialization normally emits struct fields in declaration order, followed by any
+//! flattened catch-all map entries. For user-authored files that means a round trip
+//! can shuffle keys around even when no value changed. [`KeyOrder`] is a side table
+//! that records the key order of every object in the original document, keyed by the
+//! object's [JSON Pointer](https://www.rfc-editor.org/rfc/rfc6901) path. Passing it to
+//! [`SerializeOptions::preserve_key_order`](crate::SerializeOptions::preserve_key_order)
+//! replays that order on output.
+//!
+//! Keys that were not in the original document (for example, fields that were added
+//! after loading) are written after the recorded ones, in their usual order.
+
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+use facet_format::{DeserializeError, FormatDeserializer, FormatParser, ParseEventKind};
+
+use crate::JsonParser;
+
+/// Key order of every object in a JSON document, keyed by JSON Pointer path.
+///
+/// The root object's path is the empty string; `{"a": {"b": [{"c": 1}]}}` records
+/// orders for `""`, `"/a"` and `"/a/b/0"`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KeyOrder {
+    orders: BTreeMap<String, Vec<String>>,
+}
+
+impl KeyOrder {
+    /// Create an empty key order table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the key order of every object in a JSON string.
+    pub fn record_str(input: &str) -> Result<Self, DeserializeError> {
+        let mut parser = JsonParser::<true>::new(input.as_bytes());
+        Self::record(&mut parser)
+    }
+
+    /// Record the key order of every object in JSON bytes.
+    pub fn record_slice(input: &[u8]) -> Result<Self, DeserializeError> {
+        let mut parser = JsonParser::<false>::new(input);
+        Self::record(&mut parser)
+    }
+
+    fn record<'de>(parser: &mut impl FormatParser<'de>) -> Result<Self, DeserializeError> {
+        let mut table = Self::new();
+        let mut stack: Vec<Frame> = Vec::new();
+
+        while let Some(event) = parser.next_event()? {
+            match event.kind {
+                ParseEventKind::StructStart(_) => {
+                    let path = value_path(&mut stack);
+                    stack.push(Frame::Object {
+                        path,
+                        keys: Vec::new(),
+                    });
+                }
+                ParseEventKind::FieldKey(key) => {
+                    if let Some(Frame::Object { keys, .. }) = stack.last_mut() {
+                        keys.push(key.name().map(|name| name.to_string()).unwrap_or_default());
+                    }
+                }
+                ParseEventKind::StructEnd => {
+                    if let Some(Frame::Object { path, keys }) = stack.pop() {
+                        table.orders.insert(path, keys);
+                    }
+                }
+                ParseEventKind::SequenceStart(_) => {
+                    let path = value_path(&mut stack);
+                    stack.push(Frame::Array {
+                        path,
+                        next_index: 0,
+                    });
+                }
+                ParseEventKind::SequenceEnd => {
+                    stack.pop();
+                }
+                ParseEventKind::Scalar(_) => {
+                    value_path(&mut stack);
+                }
+                ParseEventKind::OrderedField
+                | ParseEventKind::OptionSome
+                | ParseEventKind::VariantTag(_) => {}
+            }
+        }
+
+        Ok(table)
+    }
+
+    /// Recorded key order for the object at `path`, if any.
+    pub fn get(&self, path: &str) -> Option<&[String]> {
+        self.orders.get(path).map(Vec::as_slice)
+    }
+
+    /// Set the key order for the object at `path`, replacing any recorded order.
+    pub fn insert(&mut self, path: impl Into<String>, keys: Vec<String>) {
+        self.orders.insert(path.into(), keys);
+    }
+
+    /// Number of objects with a recorded key order.
+    pub fn len(&self) -> usize {
+        self.orders.len()
+    }
+
+    /// Returns true if no key order has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.orders.is_empty()
+    }
+}
+
+/// An open container while recording.
+enum Frame {
+    Object { path: String, keys: Vec<String> },
+    Array { path: String, next_index: usize },
+}
+
+/// Path of the value that is about to start, derived from its parent.
+fn value_path(stack: &mut [Frame]) -> String {
+    match stack.last_mut() {
+        None => String::new(),
+        Some(Frame::Object { path, keys }) => {
+            let mut child = path.clone();
+            push_segment(&mut child, keys.last().map_or("", String::as_str));
+            child
+        }
+        Some(Frame::Array { path, next_index }) => {
+            let mut child = path.clone();
+            push_segment(&mut child, &next_index.to_string());
+            *next_index += 1;
+            child
+        }
+    }
+}
+
+/// Append a JSON Pointer reference token to `path`, escaping `~` and `/`.
+pub(crate) fn push_segment(path: &mut String, segment: &str) {
+    path.push('/');
+    for c in segment.chars() {
+        match c {
+            '~' => path.push_str("~0"),
+            '/' => path.push_str("~1"),
+            c => path.push(c),
+        }
+    }
+}
+
+/// Deserialize a value from a JSON string, also recording its key order.
+///
+/// Pass the returned [`KeyOrder`] to
+/// [`SerializeOptions::preserve_key_order`](crate::SerializeOptions::preserve_key_order)
+/// to write the value back with its keys in their original order.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use facet::Facet;
+/// use facet_json::{SerializeOptions, from_str_with_key_order, to_string_with_options};
+///
+/// #[derive(Facet)]
+/// struct Config {
+///     name: String,
+///     #[facet(flatten)]
+///     extra: HashMap<String, u32>,
+/// }
+///
+/// let json = r#"{"retries":3,"name":"app","timeout":30}"#;
+/// let (config, order): (Config, _) = from_str_with_key_order(json).unwrap();
+/// let options = SerializeOptions::default().preserve_key_order(order);
+/// assert_eq!(to_string_with_options(&config, &options).unwrap(), json);
+/// ```
+pub fn from_str_with_key_order<T>(input: &str) -> Result<(T, KeyOrder), DeserializeError>
+where
+    T: facet_core::Facet<'static>,
+{
+    let order = KeyOrder::record_str(input)?;
+    let mut parser = JsonParser::<true>::new(input.as_bytes());
+    let mut de = FormatDeserializer::new_owned(&mut parser);
+    Ok((de.deserialize_root()?, order))
+}
+
+/// Deserialize a value from JSON bytes, also recording its key order.
+///
+/// See [`from_str_with_key_order`].
+pub fn from_slice_with_key_order<T>(input: &[u8]) -> Result<(T, KeyOrder), DeserializeError>
+where
+    T: facet_core::Facet<'static>,
+{
+    let order = KeyOrder::record_slice(input)?;
+    let mut parser = JsonParser::<false>::new(input);
+    let mut de = FormatDeserializer::new_owned(&mut parser);
+    Ok((de.deserialize_root()?, order))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_nested_paths() {
+        let order =
+            KeyOrder::record_str(r#"{"b":1,"a":{"z":[{"y":1,"x":2}],"w~/":true}}"#).unwrap();
+        assert_eq!(order.get(""), Some(&["b".to_string(), "a".to_string()][..]));
+        assert_eq!(
+            order.get("/a"),
+            Some(&["z".to_string(), "w~/".to_string()][..])
+        );
+        assert_eq!(
+            order.get("/a/z/0"),
+            Some(&["y".to_string(), "x".to_string()][..])
+        );
+        assert_eq!(order.len(), 3);
+    }
+}
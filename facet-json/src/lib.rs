@@ -45,11 +45,20 @@ use facet_reflect::Partial;
 #[allow(unused_imports)]
 pub(crate) use trace;
 
+mod big_number;
+mod decimal;
 mod error;
+mod float;
+pub mod json_pointer;
+mod key_order;
+mod metrics;
 mod parser;
 mod raw_json;
+mod reader;
 mod scanner;
 mod serializer;
+mod timestamp;
+mod trailing;
 mod weavy_deser;
 
 #[cfg(feature = "axum")]
@@ -58,15 +67,27 @@ mod axum;
 #[cfg(feature = "axum")]
 pub use axum::{Json, JsonRejection};
 
+pub use decimal::{DecimalFormat, DecimalMode};
 pub use error::JsonError;
+pub use float::FloatFormat;
+pub use json_pointer::JsonPointerError;
+pub use key_order::{KeyOrder, from_slice_with_key_order, from_str_with_key_order};
+pub use metrics::{from_slice_with_metrics, from_str_with_metrics};
 pub use parser::JsonParser;
 pub use raw_json::RawJson;
+#[cfg(feature = "async")]
+pub use reader::{AsyncStreamDeserializer, from_async_reader};
+pub use reader::{LineDeserializer, LineError, StreamDeserializer, from_lines, from_reader};
 pub use serializer::{
     BytesFormat, HexBytesOptions, JsonSerializeError, JsonSerializer, SerializeOptions,
-    peek_to_string, peek_to_string_pretty, peek_to_string_with_options, peek_to_writer_std,
-    peek_to_writer_std_pretty, peek_to_writer_std_with_options, to_string, to_string_pretty,
-    to_string_with_options, to_vec, to_vec_pretty, to_vec_with_options, to_writer_std,
-    to_writer_std_pretty, to_writer_std_with_options,
+    StringEscape, peek_to_string, peek_to_string_pretty, peek_to_string_with_options,
+    peek_to_writer_std, peek_to_writer_std_pretty, peek_to_writer_std_with_options, to_string,
+    to_string_pretty, to_string_with_options, to_vec, to_vec_pretty, to_vec_with_options,
+    to_writer_std, to_writer_std_pretty, to_writer_std_with_options,
+};
+pub use timestamp::{TimestampFormat, TimestampProfile};
+pub use trailing::{
+    from_slice_strict, from_slice_with_remainder, from_str_strict, from_str_with_remainder,
 };
 pub use weavy_deser::{
     JsonWeavyActiveBackend, JsonWeavyExecutionMode, JsonWeavyJitFallbackRecord,
@@ -75,7 +96,39 @@ pub use weavy_deser::{
 };
 
 // Re-export DeserializeError for convenience
-pub use facet_format::DeserializeError;
+pub use facet_format::{DeserializeError, ParseMetrics};
+
+// JSON extension attributes for use with #[facet(json::attr)] syntax.
+//
+// After importing `use facet_json as json;`, users can write:
+//   #[facet(json::timestamp_accept = "rfc3339, epoch_millis")]
+//   #[facet(json::timestamp_format = "epoch_millis")]
+//
+// `json::proxy` is handled by the derive macro itself and isn't part of this
+// grammar.
+facet::define_attr_grammar! {
+    ns "json";
+    crate_path ::facet_json;
+
+    /// JSON attribute types for field configuration.
+    pub enum Attr {
+        /// Which forms a timestamp field is read from, overriding the parser's
+        /// [`TimestampProfile`].
+        ///
+        /// Usage: `#[facet(json::timestamp_accept = "rfc3339, epoch_millis")]`
+        ///
+        /// A comma-separated list of `rfc3339`, `epoch_seconds`,
+        /// `epoch_millis`, `relaxed_iso` or `any`.
+        TimestampAccept(&'static str),
+        /// How a timestamp field is written, overriding
+        /// [`SerializeOptions::timestamp_format`].
+        ///
+        /// Usage: `#[facet(json::timestamp_format = "epoch_millis")]`
+        ///
+        /// One of `rfc3339`, `epoch_seconds` or `epoch_millis`.
+        TimestampFormat(&'static str),
+    }
+}
 
 /// Deserialize a value from a JSON string into an owned type.
 ///
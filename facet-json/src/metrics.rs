@@ -0,0 +1,54 @@
+//! Deserialization with per-call cost accounting.
+//!
+//! The counting itself lives in the shared [`FormatDeserializer`] (see
+//! [`FormatDeserializer::with_metrics`]), so every format reports the same
+//! [`ParseMetrics`]; these are the JSON entry points.
+
+use facet_format::{DeserializeError, FormatDeserializer, ParseMetrics};
+
+use crate::JsonParser;
+
+/// Deserialize a value from a JSON string, also reporting what it cost.
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+/// use facet_json::from_str_with_metrics;
+///
+/// #[derive(Facet)]
+/// struct Order {
+///     id: u32,
+///     items: Vec<String>,
+/// }
+///
+/// let json = r#"{"id": 7, "items": ["tea", "cake"]}"#;
+/// let (order, metrics): (Order, _) = from_str_with_metrics(json).unwrap();
+/// assert_eq!(order.items.len(), 2);
+/// assert_eq!(metrics.bytes_read, json.len());
+/// assert_eq!(metrics.nodes_created, 5);
+/// assert_eq!(metrics.max_depth, 2);
+/// ```
+pub fn from_str_with_metrics<T>(input: &str) -> Result<(T, ParseMetrics), DeserializeError>
+where
+    T: facet_core::Facet<'static>,
+{
+    // TRUSTED_UTF8 = true: input came from &str, so it's valid UTF-8
+    let mut parser = JsonParser::<true>::new(input.as_bytes());
+    let mut de = FormatDeserializer::new_owned(&mut parser).with_metrics();
+    let value = de.deserialize_root()?;
+    Ok((value, de.metrics().unwrap_or_default()))
+}
+
+/// Deserialize a value from JSON bytes, also reporting what it cost.
+///
+/// See [`from_str_with_metrics`].
+pub fn from_slice_with_metrics<T>(input: &[u8]) -> Result<(T, ParseMetrics), DeserializeError>
+where
+    T: facet_core::Facet<'static>,
+{
+    let mut parser = JsonParser::<false>::new(input);
+    let mut de = FormatDeserializer::new_owned(&mut parser).with_metrics();
+    let value = de.deserialize_root()?;
+    Ok((value, de.metrics().unwrap_or_default()))
+}
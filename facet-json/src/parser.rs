@@ -10,7 +10,11 @@ use facet_format::{
 };
 use facet_reflect::Span;
 
+use crate::big_number::{NotAnInteger, exact_integer, is_integer};
+use crate::decimal::is_decimal;
 use crate::scanner::{self, ParsedNumber, ScanError, ScanErrorKind, Scanner, Token as ScanToken};
+use crate::timestamp::{self, TimestampKind, timestamp_kind};
+use crate::{DecimalMode, TimestampProfile};
 
 /// Convert a ScanError to a ParseError.
 fn scan_error_to_parse_error(err: ScanError) -> ParseError {
@@ -316,6 +320,12 @@ pub struct JsonParser<'de, const TRUSTED_UTF8: bool = false> {
     save_counter: u64,
     /// Saved states for restore functionality.
     saved_states: Vec<(u64, ParserState<'de>)>,
+    /// Which JSON forms decimal types are accepted from.
+    decimal_mode: DecimalMode,
+    /// Which JSON forms timestamp types are accepted from.
+    timestamp_profile: TimestampProfile,
+    /// Whether numbers are read in big-number mode.
+    big_numbers: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -427,6 +437,9 @@ impl<'de, const TRUSTED_UTF8: bool> JsonParser<'de, TRUSTED_UTF8> {
             },
             save_counter: 0,
             saved_states: Vec::new(),
+            decimal_mode: DecimalMode::default(),
+            timestamp_profile: TimestampProfile::default(),
+            big_numbers: false,
         }
     }
 
@@ -446,7 +459,181 @@ impl<'de, const TRUSTED_UTF8: bool> JsonParser<'de, TRUSTED_UTF8> {
             },
             save_counter: 0,
             saved_states: Vec::new(),
+            decimal_mode: DecimalMode::default(),
+            timestamp_profile: TimestampProfile::default(),
+            big_numbers: false,
+        }
+    }
+
+    /// Set which JSON forms decimal types are accepted from (see [`DecimalMode`]).
+    pub const fn decimal_mode(mut self, mode: DecimalMode) -> Self {
+        self.decimal_mode = mode;
+        self
+    }
+
+    /// Set which JSON forms timestamp types are accepted from (see
+    /// [`TimestampProfile`]). Fields with a `json::timestamp_accept` attribute
+    /// use their own profile instead.
+    pub const fn timestamp_profile(mut self, profile: TimestampProfile) -> Self {
+        self.timestamp_profile = profile;
+        self
+    }
+
+    /// Enable big-number mode, where numbers are never read through `f64`
+    /// when the target type can hold them exactly:
+    ///
+    /// - integer types read numbers written with a fraction or exponent
+    ///   (`1e20`, `12.0`) from their digits, and reject ones with a fractional
+    ///   part instead of truncating them;
+    /// - integers too large for 128 bits are accepted, so types that parse
+    ///   from text can receive them;
+    /// - opaque types that parse from a string (decimals, big integers)
+    ///   receive a number's source text.
+    ///
+    /// Pair with [`SerializeOptions::big_numbers`](crate::SerializeOptions::big_numbers)
+    /// to write `u128` and `i128` back as numbers.
+    pub const fn big_numbers(mut self, enabled: bool) -> Self {
+        self.big_numbers = enabled;
+        self
+    }
+
+    /// Hand a number to a decimal type as its source text, so it is parsed
+    /// exactly instead of going through `f64`.
+    fn decimal_event(&self, event: ParseEvent<'de>) -> Result<ParseEvent<'de>, ParseError> {
+        match event.kind {
+            ParseEventKind::Scalar(ScalarValue::Str(_))
+                if self.decimal_mode == DecimalMode::Strict =>
+            {
+                Err(ParseError::new(
+                    event.span,
+                    DeserializeErrorKind::UnexpectedToken {
+                        got: "string".into(),
+                        expected: "decimal number",
+                    },
+                ))
+            }
+            _ => self.number_text_event(event),
+        }
+    }
+
+    /// Replace a number event with the number's source text.
+    fn number_text_event(&self, mut event: ParseEvent<'de>) -> Result<ParseEvent<'de>, ParseError> {
+        if let ParseEventKind::Scalar(
+            ScalarValue::I64(_)
+            | ScalarValue::U64(_)
+            | ScalarValue::I128(_)
+            | ScalarValue::U128(_)
+            | ScalarValue::F64(_),
+        ) = event.kind
+        {
+            let start = event.span.offset as usize;
+            let end = start + event.span.len as usize;
+            let text = self.number_text(start, end, event.span)?;
+            event.kind = ParseEventKind::Scalar(ScalarValue::Str(Cow::Borrowed(text)));
         }
+        Ok(event)
+    }
+
+    /// Read an integer written with a fraction or exponent from its digits.
+    fn exact_integer_event(
+        &self,
+        mut event: ParseEvent<'de>,
+    ) -> Result<ParseEvent<'de>, ParseError> {
+        let start = event.span.offset as usize;
+        let end = start + event.span.len as usize;
+        let text = self.number_text(start, end, event.span)?;
+        let value = exact_integer(text).map_err(|reason| {
+            let message = match reason {
+                NotAnInteger::Fractional => format!("number {text} has a fractional part"),
+                NotAnInteger::OutOfRange => format!("number {text} is out of range"),
+            };
+            ParseError::new(
+                event.span,
+                DeserializeErrorKind::InvalidValue {
+                    message: message.into(),
+                },
+            )
+        })?;
+        event.kind = ParseEventKind::Scalar(value);
+        Ok(event)
+    }
+
+    /// Rewrite an epoch number or a relaxed ISO 8601 string to the RFC 3339
+    /// text a timestamp type parses, if the field's profile (or the parser's)
+    /// accepts it.
+    fn timestamp_event(
+        &self,
+        kind: TimestampKind,
+        field: Option<&facet_core::Field>,
+        mut event: ParseEvent<'de>,
+    ) -> Result<ParseEvent<'de>, ParseError> {
+        let profile = timestamp::field_profile(field, self.timestamp_profile).map_err(|names| {
+            ParseError::new(
+                event.span,
+                DeserializeErrorKind::InvalidValue {
+                    message: format!(
+                        "unknown timestamp form in `json::timestamp_accept = {names:?}`"
+                    )
+                    .into(),
+                },
+            )
+        })?;
+        let unexpected = |got: &'static str, expected: &'static str| {
+            ParseError::new(
+                event.span,
+                DeserializeErrorKind::UnexpectedToken {
+                    got: got.into(),
+                    expected,
+                },
+            )
+        };
+
+        let text = match &event.kind {
+            ParseEventKind::Scalar(
+                ScalarValue::I64(_)
+                | ScalarValue::U64(_)
+                | ScalarValue::I128(_)
+                | ScalarValue::U128(_)
+                | ScalarValue::F64(_),
+            ) => {
+                if !profile.epoch_seconds && !profile.epoch_millis {
+                    return Err(unexpected("number", "timestamp string"));
+                }
+                let start = event.span.offset as usize;
+                let end = start + event.span.len as usize;
+                let number = self.number_text(start, end, event.span)?;
+                timestamp::epoch_to_text(number, profile, kind).ok_or_else(|| {
+                    ParseError::new(
+                        event.span,
+                        DeserializeErrorKind::InvalidValue {
+                            message: format!("epoch timestamp {number} is out of range").into(),
+                        },
+                    )
+                })?
+            }
+            ParseEventKind::Scalar(ScalarValue::Str(s)) => {
+                match profile
+                    .relaxed_iso
+                    .then(|| timestamp::relaxed_to_text(s, kind))
+                    .flatten()
+                {
+                    Some(text) => text,
+                    None if profile.rfc3339 => return Ok(event),
+                    None if profile.relaxed_iso => {
+                        return Err(ParseError::new(
+                            event.span,
+                            DeserializeErrorKind::InvalidValue {
+                                message: format!("could not parse timestamp {s:?}").into(),
+                            },
+                        ));
+                    }
+                    None => return Err(unexpected("string", "epoch timestamp")),
+                }
+            }
+            _ => return Ok(event),
+        };
+        event.kind = ParseEventKind::Scalar(ScalarValue::Str(Cow::Owned(text)));
+        Ok(event)
     }
 
     /// Scan and materialize the next token directly.
@@ -599,13 +786,20 @@ impl<'de, const TRUSTED_UTF8: bool> JsonParser<'de, TRUSTED_UTF8> {
         end: usize,
         hint: scanner::NumberHint,
     ) -> Result<ParsedNumber, ParseError> {
-        if TRUSTED_UTF8 {
+        let parsed = if TRUSTED_UTF8 {
             // SAFETY: Input came from &str, so it's valid UTF-8
             unsafe { scanner::parse_number_unchecked(self.input, start, end, hint) }
         } else {
             scanner::parse_number(self.input, start, end, hint)
+        };
+        match parsed {
+            // An integer too large for 128 bits. Its event still spans the
+            // exact text, which is what types that parse from text read.
+            Err(_) if self.big_numbers && hint != scanner::NumberHint::Float => {
+                self.parse_number(start, end, scanner::NumberHint::Float)
+            }
+            parsed => parsed.map_err(scan_error_to_parse_error),
         }
-        .map_err(scan_error_to_parse_error)
     }
 
     #[inline]
@@ -1437,6 +1631,14 @@ impl<'de, const TRUSTED_UTF8: bool> JsonParser<'de, TRUSTED_UTF8> {
         self.state.scanner_pos
     }
 
+    /// Offset just past the root value, once it has been fully read.
+    ///
+    /// The parser never reads past the end of the root value, so whatever
+    /// follows it is left untouched.
+    pub(crate) const fn root_end(&self) -> usize {
+        self.state.scanner_pos
+    }
+
     pub(crate) fn read_scalar_token(&mut self) -> Result<(JsonScalarToken<'de>, Span), ParseError> {
         if let Some(event) = self.state.event_peek.take() {
             self.state.peek_start_offset = None;
@@ -2523,6 +2725,38 @@ impl<'de, const TRUSTED_UTF8: bool> FormatParser<'de> for JsonParser<'de, TRUSTE
         Some(crate::RawJson::SHAPE)
     }
 
+    fn opaque_scalar_event(
+        &self,
+        shape: &'static facet_core::Shape,
+        field: Option<&facet_core::Field>,
+        event: ParseEvent<'de>,
+    ) -> Result<ParseEvent<'de>, ParseError> {
+        if is_decimal(shape) {
+            self.decimal_event(event)
+        } else if let Some(kind) = timestamp_kind(shape) {
+            self.timestamp_event(kind, field, event)
+        } else if self.big_numbers && shape.vtable.has_parse() && shape.inner.is_none() {
+            self.number_text_event(event)
+        } else {
+            Ok(event)
+        }
+    }
+
+    fn primitive_scalar_event(
+        &self,
+        scalar_type: facet_core::ScalarType,
+        event: ParseEvent<'de>,
+    ) -> Result<ParseEvent<'de>, ParseError> {
+        if self.big_numbers
+            && is_integer(scalar_type)
+            && matches!(event.kind, ParseEventKind::Scalar(ScalarValue::F64(_)))
+        {
+            self.exact_integer_event(event)
+        } else {
+            Ok(event)
+        }
+    }
+
     fn input(&self) -> Option<&'de [u8]> {
         Some(self.input)
     }
@@ -1,13 +1,17 @@
 //! Raw JSON value that defers parsing.
 //!
 //! [`RawJson`] captures unparsed JSON text, allowing you to delay or skip
-//! deserialization of parts of a JSON document.
+//! deserialization of parts of a JSON document. Serializing it writes the
+//! captured text back verbatim, and [`RawJson::parse`] deserializes it once
+//! the type is known.
 
 use alloc::borrow::Cow;
 use alloc::string::String;
 use core::fmt;
 use facet::Facet;
 
+use crate::DeserializeError;
+
 /// A raw JSON value that has not been parsed.
 ///
 /// This type captures the raw JSON text for a value, deferring parsing until
@@ -59,6 +63,48 @@ impl<'a> RawJson<'a> {
     pub fn into_owned(self) -> RawJson<'static> {
         RawJson(Cow::Owned(self.0.into_owned()))
     }
+
+    /// Deserialize the captured JSON, for values whose type is only known
+    /// later, like JSON-RPC `params` once the method has been looked at.
+    ///
+    /// ```
+    /// use facet::Facet;
+    /// use facet_json::RawJson;
+    ///
+    /// #[derive(Facet)]
+    /// struct Request<'a> {
+    ///     method: String,
+    ///     params: RawJson<'a>,
+    /// }
+    ///
+    /// #[derive(Facet, Debug, PartialEq)]
+    /// struct Subtract {
+    ///     minuend: i64,
+    ///     subtrahend: i64,
+    /// }
+    ///
+    /// let json = r#"{"method": "subtract", "params": {"minuend": 42, "subtrahend": 23}}"#;
+    /// let request: Request = facet_json::from_str_borrowed(json).unwrap();
+    /// assert_eq!(request.method, "subtract");
+    ///
+    /// let params: Subtract = request.params.parse().unwrap();
+    /// assert_eq!(params, Subtract { minuend: 42, subtrahend: 23 });
+    /// ```
+    pub fn parse<T>(&self) -> Result<T, DeserializeError>
+    where
+        T: Facet<'static>,
+    {
+        crate::from_str(self.as_str())
+    }
+
+    /// Like [`RawJson::parse`], but the result may borrow strings from the
+    /// captured text.
+    pub fn parse_borrowed<'s, T>(&'s self) -> Result<T, DeserializeError>
+    where
+        T: Facet<'s>,
+    {
+        crate::from_str_borrowed(self.as_str())
+    }
 }
 
 impl fmt::Debug for RawJson<'_> {
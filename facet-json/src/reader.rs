@@ -0,0 +1,617 @@
+//! Deserialization from `std::io::Read` and, with the `async` feature, tokio's
+//! `AsyncRead`.
+//!
+//! [`JsonParser`](crate::JsonParser) works on a byte slice, so reading from a
+//! stream is a question of how much of it to hold in memory. [`from_reader`]
+//! reads the whole document. [`StreamDeserializer`] is for large payloads made
+//! of many values, either newline-delimited (NDJSON) or the elements of one
+//! top-level array: a small scanner finds where each value ends, and only that
+//! value is buffered while it is deserialized. [`from_lines`] is for JSON Lines
+//! proper, where every line is one value and errors are reported per line.
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::Range;
+use std::io::{BufRead, BufReader, Read};
+
+use facet_core::Facet;
+use facet_format::{DeserializeError, DeserializeErrorKind};
+use facet_reflect::Span;
+
+/// Bytes requested from the reader at a time.
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// Deserialize a single JSON document read from `reader`.
+///
+/// The whole document is read into memory first; to process a large stream of
+/// values one at a time, use [`StreamDeserializer`].
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+///
+/// #[derive(Facet, Debug, PartialEq)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// let file = std::io::Cursor::new(br#"{"x": 1, "y": 2}"#);
+/// let point: Point = facet_json::from_reader(file).unwrap();
+/// assert_eq!(point, Point { x: 1, y: 2 });
+/// ```
+pub fn from_reader<T, R>(mut reader: R) -> Result<T, DeserializeError>
+where
+    T: Facet<'static>,
+    R: Read,
+{
+    let mut input = Vec::new();
+    reader.read_to_end(&mut input).map_err(io_error)?;
+    crate::from_slice(&input)
+}
+
+/// Deserialize a single JSON document read from an async `reader`.
+///
+/// Like [`from_reader`], the whole document is read into memory first; see
+/// [`AsyncStreamDeserializer`] for streams of values.
+#[cfg(feature = "async")]
+pub async fn from_async_reader<T, R>(mut reader: R) -> Result<T, DeserializeError>
+where
+    T: Facet<'static>,
+    R: tokio::io::AsyncRead + Unpin,
+{
+    use tokio::io::AsyncReadExt;
+
+    let mut input = Vec::new();
+    reader.read_to_end(&mut input).await.map_err(io_error)?;
+    crate::from_slice(&input)
+}
+
+/// An iterator over the JSON values in a reader, deserialized one at a time.
+///
+/// Only the value being deserialized is held in memory, so this works for
+/// payloads much larger than memory as long as each value fits.
+///
+/// An error in one value (a missing field, a wrong type) is returned for that
+/// value and iteration goes on with the next one. Malformed JSON between
+/// values and I/O errors end the iteration.
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+/// use facet_json::StreamDeserializer;
+///
+/// #[derive(Facet, Debug, PartialEq)]
+/// struct Event {
+///     id: u32,
+/// }
+///
+/// let ndjson = "{\"id\": 1}\n{\"id\": 2}\n";
+/// let events: Vec<Event> = StreamDeserializer::values(ndjson.as_bytes())
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+/// assert_eq!(events, [Event { id: 1 }, Event { id: 2 }]);
+///
+/// let array = r#"[{"id": 3}, {"id": 4}]"#;
+/// let events: Vec<Event> = StreamDeserializer::array(array.as_bytes())
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+/// assert_eq!(events, [Event { id: 3 }, Event { id: 4 }]);
+/// ```
+pub struct StreamDeserializer<R, T> {
+    reader: R,
+    splitter: Splitter,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<R, T> StreamDeserializer<R, T>
+where
+    R: Read,
+    T: Facet<'static>,
+{
+    /// Values separated by whitespace, such as NDJSON.
+    pub fn values(reader: R) -> Self {
+        Self::new(reader, Layout::Values)
+    }
+
+    /// The elements of a single top-level array.
+    pub fn array(reader: R) -> Self {
+        Self::new(reader, Layout::Array)
+    }
+
+    fn new(reader: R, layout: Layout) -> Self {
+        Self {
+            reader,
+            splitter: Splitter::new(layout),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<R, T> Iterator for StreamDeserializer<R, T>
+where
+    R: Read,
+    T: Facet<'static>,
+{
+    type Item = Result<T, DeserializeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.splitter.split() {
+                Ok(Split::Value(range)) => return Some(self.splitter.deserialize(range)),
+                Ok(Split::End) => return None,
+                Ok(Split::NeedInput) => {
+                    if let Err(err) = self.splitter.read_from(&mut self.reader) {
+                        return Some(Err(err));
+                    }
+                }
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
+
+/// The async counterpart of [`StreamDeserializer`], for tokio's `AsyncRead`.
+#[cfg(feature = "async")]
+pub struct AsyncStreamDeserializer<R, T> {
+    reader: R,
+    splitter: Splitter,
+    _marker: PhantomData<fn() -> T>,
+}
+
+#[cfg(feature = "async")]
+impl<R, T> AsyncStreamDeserializer<R, T>
+where
+    R: tokio::io::AsyncRead + Unpin,
+    T: Facet<'static>,
+{
+    /// Values separated by whitespace, such as NDJSON.
+    pub fn values(reader: R) -> Self {
+        Self::new(reader, Layout::Values)
+    }
+
+    /// The elements of a single top-level array.
+    pub fn array(reader: R) -> Self {
+        Self::new(reader, Layout::Array)
+    }
+
+    fn new(reader: R, layout: Layout) -> Self {
+        Self {
+            reader,
+            splitter: Splitter::new(layout),
+            _marker: PhantomData,
+        }
+    }
+
+    /// The next value, or `None` at the end of the stream.
+    pub async fn next_value(&mut self) -> Option<Result<T, DeserializeError>> {
+        loop {
+            match self.splitter.split() {
+                Ok(Split::Value(range)) => return Some(self.splitter.deserialize(range)),
+                Ok(Split::End) => return None,
+                Ok(Split::NeedInput) => {
+                    if let Err(err) = self.splitter.read_from_async(&mut self.reader).await {
+                        return Some(Err(err));
+                    }
+                }
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
+
+/// Deserialize newline-delimited JSON (JSON Lines) read from `reader`, one
+/// value per line.
+///
+/// Blank lines are skipped. Each line is deserialized on its own, so a line
+/// that is malformed or doesn't match `T` produces a [`LineError`] for that
+/// line and iteration goes on with the next one; only an I/O error ends it.
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+///
+/// #[derive(Facet, Debug, PartialEq)]
+/// struct Event {
+///     id: u32,
+/// }
+///
+/// let log = "{\"id\": 1}\n{\"id\": \"two\"}\n\n{\"id\": 3}\n";
+/// let mut events = facet_json::from_lines::<Event, _>(log.as_bytes());
+///
+/// assert_eq!(events.next().unwrap().unwrap(), Event { id: 1 });
+/// assert_eq!(events.next().unwrap().unwrap_err().line, 2);
+/// assert_eq!(events.next().unwrap().unwrap(), Event { id: 3 });
+/// assert!(events.next().is_none());
+/// ```
+pub fn from_lines<T, R>(reader: R) -> LineDeserializer<R, T>
+where
+    T: Facet<'static>,
+    R: Read,
+{
+    LineDeserializer {
+        reader: BufReader::new(reader),
+        line: Vec::new(),
+        line_number: 0,
+        done: false,
+        _marker: PhantomData,
+    }
+}
+
+/// An iterator over the lines of a JSON Lines stream, returned by
+/// [`from_lines`].
+pub struct LineDeserializer<R, T> {
+    reader: BufReader<R>,
+    line: Vec<u8>,
+    line_number: usize,
+    done: bool,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<R, T> LineDeserializer<R, T> {
+    /// The 1-based number of the last line read.
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+}
+
+impl<R, T> Iterator for LineDeserializer<R, T>
+where
+    R: Read,
+    T: Facet<'static>,
+{
+    type Item = Result<T, LineError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            self.line.clear();
+            match self.reader.read_until(b'\n', &mut self.line) {
+                Ok(0) => self.done = true,
+                Ok(_) => {
+                    self.line_number += 1;
+                    if self.line.iter().all(u8::is_ascii_whitespace) {
+                        continue;
+                    }
+                    let line = self.line_number;
+                    return Some(
+                        crate::from_slice(&self.line).map_err(|error| LineError { line, error }),
+                    );
+                }
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(LineError {
+                        line: self.line_number + 1,
+                        error: io_error(err),
+                    }));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// An error in one line of a JSON Lines stream.
+#[derive(Debug)]
+pub struct LineError {
+    /// The 1-based line number.
+    pub line: usize,
+    /// What went wrong. Its span is relative to the start of the line, so the
+    /// offset is the (0-based, byte) column.
+    pub error: DeserializeError,
+}
+
+impl fmt::Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.error.span {
+            Some(span) => write!(
+                f,
+                "line {}, column {}: {}",
+                self.line,
+                span.offset + 1,
+                self.error
+            ),
+            None => write!(f, "line {}: {}", self.line, self.error),
+        }
+    }
+}
+
+impl std::error::Error for LineError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+fn io_error(error: std::io::Error) -> DeserializeError {
+    DeserializeError {
+        span: None,
+        path: None,
+        kind: DeserializeErrorKind::Io {
+            message: error.to_string().into(),
+        },
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Layout {
+    Values,
+    Array,
+}
+
+/// Where a [`Layout::Array`] stream is, between elements.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ArrayState {
+    /// Expecting `[`.
+    Open,
+    /// After `[`: an element or `]`.
+    First,
+    /// After `,`: an element.
+    Element,
+    /// After an element: `,` or `]`.
+    Separator,
+    /// After `]`: only whitespace.
+    Closed,
+}
+
+/// What kind of value is being scanned, which decides where it ends.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ValueKind {
+    /// An object or array: ends at the bracket that brings `depth` to zero.
+    Container,
+    /// A string: ends at the closing quote.
+    String,
+    /// A number or literal: ends at whitespace or punctuation.
+    Bare,
+}
+
+/// Scanner state for a value whose end hasn't been seen yet.
+struct ValueScan {
+    start: usize,
+    kind: ValueKind,
+    depth: u32,
+    in_string: bool,
+    escaped: bool,
+}
+
+enum Split {
+    /// A complete value in the buffer.
+    Value(Range<usize>),
+    /// The buffer ends before the next value does.
+    NeedInput,
+    /// No more values.
+    End,
+}
+
+/// Finds the boundaries of top-level values in a byte stream that arrives in
+/// chunks. Knows nothing about I/O: callers feed it and ask for values.
+struct Splitter {
+    layout: Layout,
+    array: ArrayState,
+    buf: Vec<u8>,
+    /// Bytes dropped from the front of `buf`, for spans relative to the stream.
+    consumed: usize,
+    /// Next byte of `buf` to scan.
+    pos: usize,
+    value: Option<ValueScan>,
+    eof: bool,
+    /// Set after a scanning or I/O error; the stream can't be resynchronized.
+    failed: bool,
+}
+
+impl Splitter {
+    fn new(layout: Layout) -> Self {
+        Self {
+            layout,
+            array: ArrayState::Open,
+            buf: Vec::new(),
+            consumed: 0,
+            pos: 0,
+            value: None,
+            eof: false,
+            failed: false,
+        }
+    }
+
+    fn read_from(&mut self, reader: &mut impl Read) -> Result<(), DeserializeError> {
+        let len = self.buf.len();
+        self.buf.resize(len + CHUNK_SIZE, 0);
+        loop {
+            match reader.read(&mut self.buf[len..]) {
+                Ok(n) => {
+                    self.filled(len, n);
+                    return Ok(());
+                }
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(err) => {
+                    self.buf.truncate(len);
+                    self.failed = true;
+                    return Err(io_error(err));
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "async")]
+    async fn read_from_async(
+        &mut self,
+        reader: &mut (impl tokio::io::AsyncRead + Unpin),
+    ) -> Result<(), DeserializeError> {
+        use tokio::io::AsyncReadExt;
+
+        let len = self.buf.len();
+        self.buf.resize(len + CHUNK_SIZE, 0);
+        match reader.read(&mut self.buf[len..]).await {
+            Ok(n) => {
+                self.filled(len, n);
+                Ok(())
+            }
+            Err(err) => {
+                self.buf.truncate(len);
+                self.failed = true;
+                Err(io_error(err))
+            }
+        }
+    }
+
+    fn filled(&mut self, len: usize, n: usize) {
+        self.buf.truncate(len + n);
+        if n == 0 {
+            self.eof = true;
+        }
+    }
+
+    /// Deserializes the value at `range` and drops it from the buffer.
+    fn deserialize<T: Facet<'static>>(
+        &mut self,
+        range: Range<usize>,
+    ) -> Result<T, DeserializeError> {
+        let base = self.consumed + range.start;
+        let result = crate::from_slice(&self.buf[range.clone()]).map_err(|mut err| {
+            err.span = err
+                .span
+                .map(|span| Span::new(base + span.offset as usize, span.len as usize));
+            err
+        });
+        self.buf.drain(..range.end);
+        self.consumed += range.end;
+        self.pos -= range.end;
+        result
+    }
+
+    fn split(&mut self) -> Result<Split, DeserializeError> {
+        if self.failed {
+            return Ok(Split::End);
+        }
+        let result = self.split_inner();
+        if result.is_err() {
+            self.failed = true;
+        }
+        result
+    }
+
+    fn split_inner(&mut self) -> Result<Split, DeserializeError> {
+        loop {
+            if let Some(scan) = &mut self.value {
+                let end = match scan_value(scan, &self.buf, &mut self.pos) {
+                    Some(end) => end,
+                    None if !self.eof => return Ok(Split::NeedInput),
+                    None if scan.kind == ValueKind::Bare => self.buf.len(),
+                    None => return Err(self.error_at_end("end of value")),
+                };
+                let start = scan.start;
+                self.value = None;
+                self.array = ArrayState::Separator;
+                return Ok(Split::Value(start..end));
+            }
+
+            let Some(&byte) = self.buf.get(self.pos) else {
+                if !self.eof {
+                    return Ok(Split::NeedInput);
+                }
+                return match (self.layout, self.array) {
+                    (Layout::Values, _) | (Layout::Array, ArrayState::Closed) => Ok(Split::End),
+                    (Layout::Array, ArrayState::Open) => Err(self.error_at_end("`[`")),
+                    (Layout::Array, _) => Err(self.error_at_end("`]`")),
+                };
+            };
+
+            if byte.is_ascii_whitespace() {
+                self.pos += 1;
+                continue;
+            }
+
+            if self.layout == Layout::Array {
+                let next = match (self.array, byte) {
+                    (ArrayState::Open, b'[') => Some(ArrayState::First),
+                    (ArrayState::Open, _) => return Err(self.unexpected(byte, "`[`")),
+                    (ArrayState::First, b']') => Some(ArrayState::Closed),
+                    (ArrayState::Separator, b',') => Some(ArrayState::Element),
+                    (ArrayState::Separator, b']') => Some(ArrayState::Closed),
+                    (ArrayState::Separator, _) => return Err(self.unexpected(byte, "`,` or `]`")),
+                    (ArrayState::Closed, _) => return Err(self.unexpected(byte, "end of input")),
+                    (ArrayState::First | ArrayState::Element, _) => None,
+                };
+                if let Some(next) = next {
+                    self.array = next;
+                    self.pos += 1;
+                    continue;
+                }
+            }
+
+            let kind = match byte {
+                b'{' | b'[' => ValueKind::Container,
+                b'"' => ValueKind::String,
+                b',' | b']' | b'}' | b':' => return Err(self.unexpected(byte, "value")),
+                _ => ValueKind::Bare,
+            };
+            self.value = Some(ValueScan {
+                start: self.pos,
+                kind,
+                depth: 1,
+                in_string: kind == ValueKind::String,
+                escaped: false,
+            });
+            if kind != ValueKind::Bare {
+                self.pos += 1;
+            }
+        }
+    }
+
+    fn unexpected(&self, byte: u8, expected: &'static str) -> DeserializeError {
+        DeserializeErrorKind::UnexpectedChar {
+            ch: char::from(byte),
+            expected,
+        }
+        .with_span(Span::new(self.consumed + self.pos, 1))
+    }
+
+    fn error_at_end(&self, expected: &'static str) -> DeserializeError {
+        DeserializeErrorKind::UnexpectedEof { expected }
+            .with_span(Span::new(self.consumed + self.buf.len(), 0))
+    }
+}
+
+/// Scans `buf` from `pos` for the end of the value described by `scan`,
+/// returning the offset just past it.
+fn scan_value(scan: &mut ValueScan, buf: &[u8], pos: &mut usize) -> Option<usize> {
+    while let Some(&byte) = buf.get(*pos) {
+        if scan.kind == ValueKind::Bare {
+            if byte.is_ascii_whitespace() || b",:]}[{\"".contains(&byte) {
+                return Some(*pos);
+            }
+            *pos += 1;
+            continue;
+        }
+
+        *pos += 1;
+        if scan.in_string {
+            if scan.escaped {
+                scan.escaped = false;
+            } else if byte == b'\\' {
+                scan.escaped = true;
+            } else if byte == b'"' {
+                scan.in_string = false;
+                if scan.kind == ValueKind::String {
+                    return Some(*pos);
+                }
+            }
+            continue;
+        }
+
+        match byte {
+            b'"' => scan.in_string = true,
+            b'{' | b'[' => scan.depth += 1,
+            b'}' | b']' => {
+                scan.depth -= 1;
+                if scan.depth == 0 {
+                    return Some(*pos);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
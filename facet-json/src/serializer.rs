@@ -1,11 +1,17 @@
 extern crate alloc;
 
-use alloc::{string::String, vec::Vec};
+use alloc::{string::String, sync::Arc, vec::Vec};
 
-use facet_core::Facet;
-use facet_format::{FormatSerializer, ScalarValue, SerializeError, serialize_root};
+use facet_core::{Facet, ScalarType};
+use facet_format::{FormatSerializer, ScalarValue, SerializeError, scalar_value, serialize_root};
 use facet_reflect::Peek;
 
+use crate::decimal::{is_decimal, is_json_number};
+use crate::float::{write_f32, write_f64};
+use crate::key_order::push_segment;
+use crate::timestamp::{self, timestamp_kind};
+use crate::{DecimalFormat, FloatFormat, KeyOrder, TimestampFormat};
+
 /// Options for JSON serialization.
 #[derive(Debug, Clone)]
 pub struct SerializeOptions {
@@ -17,6 +23,53 @@ pub struct SerializeOptions {
 
     /// How byte sequences (`Vec<u8>`, `[u8; N]`, `bytes::Bytes`, etc.) are serialized.
     pub bytes_format: BytesFormat,
+
+    /// Recorded object key order to replay (default: `None`, keys are written in
+    /// declaration order).
+    pub key_order: Option<Arc<KeyOrder>>,
+
+    /// How decimal types (`rust_decimal::Decimal`, `bigdecimal::BigDecimal`)
+    /// are serialized (default: as strings).
+    pub decimal_format: DecimalFormat,
+
+    /// How timestamp types (`chrono`, `time` and `jiff` date-times) are
+    /// serialized (default: RFC 3339 strings). Fields with a
+    /// `json::timestamp_format` attribute use their own format instead.
+    pub timestamp_format: TimestampFormat,
+
+    /// How `f32` and `f64` values are written (default: shortest round-trip).
+    pub float_format: FloatFormat,
+
+    /// Write object keys in lexicographic order (default: false). Keys with a
+    /// recorded order in [`key_order`](Self::key_order) keep that order.
+    pub sort_keys: bool,
+
+    /// Which characters in strings are written as `\u` escapes.
+    pub string_escape: StringEscape,
+
+    /// End the output with a newline (default: false).
+    pub trailing_newline: bool,
+
+    /// Write `u128` and `i128` as JSON numbers rather than strings (default:
+    /// false). Consumers that parse numbers as `f64` lose precision above
+    /// 2^53; see [`JsonParser::big_numbers`](crate::JsonParser::big_numbers)
+    /// for reading them back.
+    pub big_numbers: bool,
+}
+
+/// Which characters in strings and keys are escaped.
+///
+/// `"`, `\` and control characters are always escaped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum StringEscape {
+    /// Only what JSON requires; everything else is written as UTF-8.
+    #[default]
+    Minimal,
+    /// Also escape every non-ASCII character, using surrogate pairs outside the
+    /// Basic Multilingual Plane (`"é"` becomes `"\u00e9"`), so the output is
+    /// pure ASCII. This is what Python's `json.dumps` does by default.
+    Ascii,
 }
 
 impl Default for SerializeOptions {
@@ -25,6 +78,14 @@ impl Default for SerializeOptions {
             pretty: false,
             indent: "  ",
             bytes_format: BytesFormat::default(),
+            key_order: None,
+            decimal_format: DecimalFormat::default(),
+            timestamp_format: TimestampFormat::default(),
+            float_format: FloatFormat::default(),
+            sort_keys: false,
+            string_escape: StringEscape::default(),
+            trailing_newline: false,
+            big_numbers: false,
         }
     }
 }
@@ -125,6 +186,78 @@ impl SerializeOptions {
         self.bytes_format = BytesFormat::Hex(options);
         self
     }
+
+    /// Configure how decimal types are serialized.
+    pub const fn decimal_format(mut self, decimal_format: DecimalFormat) -> Self {
+        self.decimal_format = decimal_format;
+        self
+    }
+
+    /// Serialize decimal types as JSON numbers, written exactly as their
+    /// `Display` output (e.g. `24.99` rather than `"24.99"`).
+    pub const fn decimals_as_numbers(mut self) -> Self {
+        self.decimal_format = DecimalFormat::Number;
+        self
+    }
+
+    /// Configure how timestamp types are serialized.
+    pub const fn timestamp_format(mut self, timestamp_format: TimestampFormat) -> Self {
+        self.timestamp_format = timestamp_format;
+        self
+    }
+
+    /// Configure how floats are written.
+    pub const fn float_format(mut self, float_format: FloatFormat) -> Self {
+        self.float_format = float_format;
+        self
+    }
+
+    /// Write floats with a fixed number of digits after the decimal point.
+    ///
+    /// Shorthand for `float_format(FloatFormat::Fixed(precision))`.
+    pub const fn float_precision(mut self, precision: u8) -> Self {
+        self.float_format = FloatFormat::Fixed(precision);
+        self
+    }
+
+    /// Write object keys, including map keys, in lexicographic order.
+    pub const fn sort_keys(mut self) -> Self {
+        self.sort_keys = true;
+        self
+    }
+
+    /// Configure which characters in strings are escaped.
+    pub const fn string_escape(mut self, string_escape: StringEscape) -> Self {
+        self.string_escape = string_escape;
+        self
+    }
+
+    /// Escape every non-ASCII character, so the output is pure ASCII.
+    pub const fn ascii_only(mut self) -> Self {
+        self.string_escape = StringEscape::Ascii;
+        self
+    }
+
+    /// End the output with a newline.
+    pub const fn trailing_newline(mut self) -> Self {
+        self.trailing_newline = true;
+        self
+    }
+
+    /// Write `u128` and `i128` as JSON numbers, digit for digit.
+    pub const fn big_numbers(mut self) -> Self {
+        self.big_numbers = true;
+        self
+    }
+
+    /// Write object keys in the order recorded by a [`KeyOrder`].
+    ///
+    /// Keys missing from the recorded order are written after the recorded ones.
+    /// See [`from_str_with_key_order`](crate::from_str_with_key_order).
+    pub fn preserve_key_order(mut self, key_order: impl Into<Arc<KeyOrder>>) -> Self {
+        self.key_order = Some(key_order.into());
+        self
+    }
 }
 
 #[derive(Debug)]
@@ -146,11 +279,28 @@ enum Ctx {
     Seq { first: bool },
 }
 
+/// Bookkeeping for one open container while replaying a [`KeyOrder`].
+struct OrderFrame {
+    /// JSON Pointer of this container.
+    path: String,
+    /// Whether this container is an object (as opposed to an array).
+    object: bool,
+    /// Offset in the output right after the opening `{` or `[`.
+    body_start: usize,
+    /// Key and output offset (just past the separating comma) of each object entry.
+    entries: Vec<(String, usize)>,
+    /// Number of elements written so far (arrays only).
+    len: usize,
+}
+
 /// JSON serializer with configurable formatting options.
 pub struct JsonSerializer {
     out: Vec<u8>,
     stack: Vec<Ctx>,
     options: SerializeOptions,
+    /// Parallel to `stack`; only populated when `options.key_order` or
+    /// `options.sort_keys` is set.
+    order_frames: Vec<OrderFrame>,
 }
 
 impl JsonSerializer {
@@ -165,11 +315,15 @@ impl JsonSerializer {
             out: Vec::new(),
             stack: Vec::new(),
             options,
+            order_frames: Vec::new(),
         }
     }
 
     /// Consume the serializer and return the output bytes.
-    pub fn finish(self) -> Vec<u8> {
+    pub fn finish(mut self) -> Vec<u8> {
+        if self.options.trailing_newline {
+            self.out.push(b'\n');
+        }
         self.out
     }
 
@@ -195,6 +349,9 @@ impl JsonSerializer {
                     self.out.push(b',');
                 }
                 *first = false;
+                if let Some(frame) = self.order_frames.last_mut() {
+                    frame.len += 1;
+                }
                 self.write_indent();
             }
             Some(Ctx::Struct { .. }) => {
@@ -287,6 +444,12 @@ impl JsonSerializer {
             c if c.is_ascii() => {
                 self.out.push(c as u8);
             }
+            c if self.options.string_escape == StringEscape::Ascii => {
+                let mut units = [0u16; 2];
+                for unit in c.encode_utf16(&mut units) {
+                    self.write_unicode_escape(*unit);
+                }
+            }
             c => {
                 let mut buf = [0u8; 4];
                 let len = c.encode_utf8(&mut buf).len();
@@ -295,6 +458,14 @@ impl JsonSerializer {
         }
     }
 
+    /// Writes `\uXXXX` with lowercase hex digits.
+    fn write_unicode_escape(&mut self, unit: u16) {
+        let [hi, lo] = unit.to_be_bytes();
+        self.out.extend_from_slice(b"\\u");
+        self.write_hex_byte(hi);
+        self.write_hex_byte(lo);
+    }
+
     #[inline]
     fn write_hex_byte(&mut self, byte: u8) {
         let hi = byte >> 4;
@@ -372,6 +543,88 @@ impl JsonSerializer {
             BytesFormat::Hex(options) => self.write_bytes_hex(bytes, options),
         }
     }
+
+    /// Start tracking a container that was just opened, if key order replay
+    /// or key sorting is on.
+    fn push_order_frame(&mut self, object: bool) {
+        if self.options.key_order.is_none() && !self.options.sort_keys {
+            return;
+        }
+        let path = match self.order_frames.last() {
+            None => String::new(),
+            Some(parent) => {
+                let mut path = parent.path.clone();
+                if parent.object {
+                    let key = parent.entries.last().map_or("", |(key, _)| key.as_str());
+                    push_segment(&mut path, key);
+                } else {
+                    push_segment(&mut path, &(parent.len - 1).to_string());
+                }
+                path
+            }
+        };
+        self.order_frames.push(OrderFrame {
+            path,
+            object,
+            body_start: self.out.len(),
+            entries: Vec::new(),
+            len: 0,
+        });
+    }
+
+    /// Stop tracking the object being closed, rewriting its entries in their
+    /// recorded order if there is one, or in sorted order if keys are sorted.
+    fn replay_key_order(&mut self) {
+        let Some(frame) = self.order_frames.pop() else {
+            return;
+        };
+        let recorded = self
+            .options
+            .key_order
+            .as_deref()
+            .and_then(|key_order| key_order.get(&frame.path));
+        if frame.entries.len() < 2 || (recorded.is_none() && !self.options.sort_keys) {
+            return;
+        }
+
+        let body = self.out.split_off(frame.body_start);
+        let chunks: Vec<(&str, &[u8])> = frame
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(i, (key, start))| {
+                // The next entry's offset sits just past its separating comma.
+                let end = frame
+                    .entries
+                    .get(i + 1)
+                    .map_or(body.len(), |(_, next)| next - frame.body_start - 1);
+                (key.as_str(), &body[start - frame.body_start..end])
+            })
+            .collect();
+
+        let mut written = alloc::vec![false; chunks.len()];
+        let mut order = Vec::with_capacity(chunks.len());
+        for key in recorded.into_iter().flatten() {
+            if let Some(i) = (0..chunks.len()).find(|&i| !written[i] && chunks[i].0 == key.as_str())
+            {
+                written[i] = true;
+                order.push(i);
+            }
+        }
+        let unrecorded = order.len();
+        order.extend((0..chunks.len()).filter(|&i| !written[i]));
+        if self.options.sort_keys {
+            // Stable, so duplicate keys keep their relative order
+            order[unrecorded..].sort_by_key(|&i| chunks[i].0);
+        }
+
+        for (n, i) in order.into_iter().enumerate() {
+            if n != 0 {
+                self.out.push(b',');
+            }
+            self.out.extend_from_slice(chunks[i].1);
+        }
+    }
 }
 
 /// Check if any byte in the u128 equals the target byte.
@@ -410,6 +663,7 @@ impl FormatSerializer for JsonSerializer {
         self.before_value()?;
         self.out.push(b'{');
         self.stack.push(Ctx::Struct { first: true });
+        self.push_order_frame(true);
         Ok(())
     }
 
@@ -420,6 +674,9 @@ impl FormatSerializer for JsonSerializer {
                     self.out.push(b',');
                 }
                 *first = false;
+                if let Some(frame) = self.order_frames.last_mut() {
+                    frame.entries.push((key.to_string(), self.out.len()));
+                }
                 self.write_indent();
                 self.write_json_string(key);
                 self.out.push(b':');
@@ -437,6 +694,7 @@ impl FormatSerializer for JsonSerializer {
     fn end_struct(&mut self) -> Result<(), Self::Error> {
         match self.stack.pop() {
             Some(Ctx::Struct { first }) => {
+                self.replay_key_order();
                 // Only add newline/indent before closing brace if struct was non-empty
                 if !first {
                     self.write_indent();
@@ -454,12 +712,14 @@ impl FormatSerializer for JsonSerializer {
         self.before_value()?;
         self.out.push(b'[');
         self.stack.push(Ctx::Seq { first: true });
+        self.push_order_frame(false);
         Ok(())
     }
 
     fn end_seq(&mut self) -> Result<(), Self::Error> {
         match self.stack.pop() {
             Some(Ctx::Seq { first }) => {
+                self.order_frames.pop();
                 // Only add newline/indent before closing bracket if seq was non-empty
                 if !first {
                     self.write_indent();
@@ -521,11 +781,7 @@ impl FormatSerializer for JsonSerializer {
                 if v.is_nan() || v.is_infinite() {
                     self.out.extend_from_slice(b"null");
                 } else {
-                    #[cfg(feature = "fast")]
-                    self.out
-                        .extend_from_slice(zmij::Buffer::new().format(v).as_bytes());
-                    #[cfg(not(feature = "fast"))]
-                    self.out.extend_from_slice(v.to_string().as_bytes());
+                    write_f64(&mut self.out, v, self.options.float_format);
                 }
             }
             ScalarValue::Str(s) => self.write_json_string(&s),
@@ -539,6 +795,27 @@ impl FormatSerializer for JsonSerializer {
         Ok(())
     }
 
+    fn typed_scalar(
+        &mut self,
+        scalar_type: ScalarType,
+        value: Peek<'_, '_>,
+    ) -> Result<(), Self::Error> {
+        // The default widens `f32` to `f64`, whose shortest digits are those
+        // of the widened value.
+        if let ScalarType::F32 = scalar_type
+            && let Ok(&v) = value.get::<f32>()
+        {
+            self.before_value()?;
+            if v.is_finite() {
+                write_f32(&mut self.out, v, self.options.float_format);
+            } else {
+                self.out.extend_from_slice(b"null");
+            }
+            return Ok(());
+        }
+        self.scalar(scalar_value(scalar_type, value))
+    }
+
     fn serialize_byte_sequence(&mut self, bytes: &[u8]) -> Result<bool, Self::Error> {
         self.before_value()?;
         self.write_bytes_with_options(bytes);
@@ -560,6 +837,64 @@ impl FormatSerializer for JsonSerializer {
         Ok(())
     }
 
+    fn serialize_opaque_scalar(
+        &mut self,
+        shape: &'static facet_core::Shape,
+        value: Peek<'_, '_>,
+    ) -> Result<bool, Self::Error> {
+        if self.options.big_numbers {
+            let inner = value.innermost_peek();
+            let number = if let Ok(&n) = inner.get::<u128>() {
+                Some(ScalarValue::U128(n))
+            } else if let Ok(&n) = inner.get::<i128>() {
+                Some(ScalarValue::I128(n))
+            } else {
+                None
+            };
+            if let Some(number) = number {
+                self.scalar(number)?;
+                return Ok(true);
+            }
+        }
+        if self.options.decimal_format != DecimalFormat::Number || !is_decimal(shape) {
+            return Ok(false);
+        }
+        let text = value.to_string();
+        if !is_json_number(&text) {
+            // Not representable as a number (e.g. `NaN`); fall back to a string.
+            return Ok(false);
+        }
+        self.raw_scalar(&text)?;
+        Ok(true)
+    }
+
+    fn serialize_opaque_scalar_with_field(
+        &mut self,
+        field: Option<&facet_core::Field>,
+        shape: &'static facet_core::Shape,
+        value: Peek<'_, '_>,
+    ) -> Result<bool, Self::Error> {
+        if timestamp_kind(shape).is_none() {
+            return self.serialize_opaque_scalar(shape, value);
+        }
+        let format =
+            timestamp::field_format(field, self.options.timestamp_format).map_err(|_| {
+                JsonSerializeError {
+                    msg: "unknown format in `json::timestamp_format` attribute",
+                }
+            })?;
+        if format == TimestampFormat::Rfc3339 {
+            return Ok(false);
+        }
+        let Some(number) = timestamp::text_to_epoch(&value.to_string(), format) else {
+            return Err(JsonSerializeError {
+                msg: "timestamp could not be converted to an epoch",
+            });
+        };
+        self.raw_scalar(&number)?;
+        Ok(true)
+    }
+
     fn format_namespace(&self) -> Option<&'static str> {
         Some("json")
     }
@@ -0,0 +1,561 @@
+//! Tolerant parsing and configurable output for timestamp types (`chrono`,
+//! `time` and `jiff` date-times).
+//!
+//! Timestamp types parse RFC 3339 strings on their own. Real-world JSON is
+//! messier: APIs send Unix epochs in seconds or milliseconds, or "ISO-ish"
+//! strings like `2024-01-02 03:04:05`. A [`TimestampProfile`] lists which of
+//! those forms the parser accepts; accepted values are rewritten to RFC 3339
+//! text before the type parses them. On the way out, a [`TimestampFormat`]
+//! picks the canonical form the serializer writes.
+//!
+//! Both can be set for the whole document (see [`JsonParser::timestamp_profile`]
+//! and [`SerializeOptions::timestamp_format`]) or per field:
+//!
+//! ```ignore
+//! use facet_json as json;
+//!
+//! #[derive(Facet)]
+//! struct Event {
+//!     #[facet(json::timestamp_accept = "rfc3339, epoch_millis")]
+//!     #[facet(json::timestamp_format = "epoch_millis")]
+//!     at: chrono::DateTime<chrono::Utc>,
+//! }
+//! ```
+//!
+//! Date-times without an offset (`NaiveDateTime`, `jiff::civil::DateTime`)
+//! are treated as UTC when converted to or from an epoch.
+//!
+//! [`JsonParser::timestamp_profile`]: crate::JsonParser::timestamp_profile
+//! [`SerializeOptions::timestamp_format`]: crate::SerializeOptions::timestamp_format
+
+use alloc::format;
+use alloc::string::String;
+use core::fmt::Write as _;
+
+use facet_core::{Field, Shape};
+
+/// Which JSON forms a timestamp can be read from.
+///
+/// Profiles combine with [`union`](Self::union), e.g.
+/// `TimestampProfile::RFC3339.union(TimestampProfile::EPOCH_MILLIS)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimestampProfile {
+    /// Strings in the type's own format (RFC 3339 for date-times), passed to
+    /// the type unchanged.
+    pub rfc3339: bool,
+    /// Numbers of seconds since the Unix epoch, optionally with a fraction
+    /// (`1704164645`, `1704164645.25`).
+    pub epoch_seconds: bool,
+    /// Numbers of milliseconds since the Unix epoch (`1704164645250`).
+    pub epoch_millis: bool,
+    /// ISO 8601 strings RFC 3339 rejects: a space or lowercase `t` between
+    /// date and time, no seconds, a `,` before the fraction, an offset
+    /// without a colon (`+0100`, `+01`), no offset at all (read as UTC), or
+    /// just a date (read as midnight).
+    pub relaxed_iso: bool,
+}
+
+impl Default for TimestampProfile {
+    fn default() -> Self {
+        Self::RFC3339
+    }
+}
+
+impl TimestampProfile {
+    /// Accept nothing; only useful as a starting point for [`union`](Self::union).
+    pub const NONE: Self = Self {
+        rfc3339: false,
+        epoch_seconds: false,
+        epoch_millis: false,
+        relaxed_iso: false,
+    };
+
+    /// Accept RFC 3339 strings only (the default).
+    pub const RFC3339: Self = Self {
+        rfc3339: true,
+        ..Self::NONE
+    };
+
+    /// Accept epoch seconds only.
+    pub const EPOCH_SECONDS: Self = Self {
+        epoch_seconds: true,
+        ..Self::NONE
+    };
+
+    /// Accept epoch milliseconds only.
+    pub const EPOCH_MILLIS: Self = Self {
+        epoch_millis: true,
+        ..Self::NONE
+    };
+
+    /// Accept relaxed ISO 8601 strings (which include RFC 3339 ones).
+    pub const RELAXED_ISO: Self = Self {
+        relaxed_iso: true,
+        ..Self::NONE
+    };
+
+    /// Accept every supported form.
+    ///
+    /// Numbers are read as milliseconds if their magnitude is at least
+    /// 10<sup>11</sup> (March 1973 in milliseconds, year 5138 in seconds),
+    /// and as seconds otherwise.
+    pub const ANY: Self = Self {
+        rfc3339: true,
+        epoch_seconds: true,
+        epoch_millis: true,
+        relaxed_iso: true,
+    };
+
+    /// Accept the forms accepted by either profile.
+    pub const fn union(self, other: Self) -> Self {
+        Self {
+            rfc3339: self.rfc3339 || other.rfc3339,
+            epoch_seconds: self.epoch_seconds || other.epoch_seconds,
+            epoch_millis: self.epoch_millis || other.epoch_millis,
+            relaxed_iso: self.relaxed_iso || other.relaxed_iso,
+        }
+    }
+
+    /// Parse a comma-separated list of form names, as written in
+    /// `#[facet(json::timestamp_accept = "...")]`.
+    fn from_names(names: &str) -> Option<Self> {
+        let mut profile = Self::NONE;
+        for name in names.split(',').map(str::trim) {
+            profile = profile.union(match name {
+                "rfc3339" => Self::RFC3339,
+                "epoch_seconds" => Self::EPOCH_SECONDS,
+                "epoch_millis" => Self::EPOCH_MILLIS,
+                "relaxed_iso" => Self::RELAXED_ISO,
+                "any" => Self::ANY,
+                _ => return None,
+            });
+        }
+        Some(profile)
+    }
+}
+
+/// How timestamps are written by the serializer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimestampFormat {
+    /// As the type's own string form, RFC 3339 for date-times
+    /// (`"2024-01-02T03:04:05Z"`).
+    #[default]
+    Rfc3339,
+    /// As a number of seconds since the Unix epoch (`1704164645`). Sub-second
+    /// precision is dropped.
+    EpochSeconds,
+    /// As a number of milliseconds since the Unix epoch (`1704164645250`).
+    EpochMillis,
+}
+
+impl TimestampFormat {
+    /// Parse a format name, as written in
+    /// `#[facet(json::timestamp_format = "...")]`.
+    fn from_name(name: &str) -> Option<Self> {
+        match name.trim() {
+            "rfc3339" => Some(Self::Rfc3339),
+            "epoch_seconds" => Some(Self::EpochSeconds),
+            "epoch_millis" => Some(Self::EpochMillis),
+            _ => None,
+        }
+    }
+}
+
+/// What a timestamp type's own parser expects after the date and time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TimestampKind {
+    /// An offset (`Z`, `+01:00`).
+    Instant,
+    /// An offset and a time zone annotation (`+01:00[Europe/Paris]`).
+    Zoned,
+    /// Nothing: a civil date-time.
+    Naive,
+}
+
+/// Returns the kind of `shape` if it is a timestamp type.
+pub(crate) fn timestamp_kind(shape: &Shape) -> Option<TimestampKind> {
+    match (shape.module_path?, shape.type_identifier) {
+        ("chrono", "DateTime<Utc>" | "DateTime<FixedOffset>" | "DateTime<Local>")
+        | ("time", "UtcDateTime" | "OffsetDateTime")
+        | ("jiff", "Timestamp") => Some(TimestampKind::Instant),
+        ("jiff", "Zoned") => Some(TimestampKind::Zoned),
+        ("chrono", "NaiveDateTime") | ("jiff", "DateTime") => Some(TimestampKind::Naive),
+        _ => None,
+    }
+}
+
+/// The profile for a field: its `json::timestamp_accept` attribute, or
+/// `default`. `Err` carries the attribute's value if it doesn't parse.
+pub(crate) fn field_profile(
+    field: Option<&Field>,
+    default: TimestampProfile,
+) -> Result<TimestampProfile, &'static str> {
+    match field_attr(field, "timestamp_accept") {
+        Some(names) => TimestampProfile::from_names(names).ok_or(names),
+        None => Ok(default),
+    }
+}
+
+/// The output format for a field: its `json::timestamp_format` attribute, or
+/// `default`. `Err` carries the attribute's value if it doesn't parse.
+pub(crate) fn field_format(
+    field: Option<&Field>,
+    default: TimestampFormat,
+) -> Result<TimestampFormat, &'static str> {
+    match field_attr(field, "timestamp_format") {
+        Some(name) => TimestampFormat::from_name(name).ok_or(name),
+        None => Ok(default),
+    }
+}
+
+fn field_attr(field: Option<&Field>, key: &str) -> Option<&'static str> {
+    field?
+        .get_attr(Some("json"), key)?
+        .get_as::<&'static str>()
+        .copied()
+}
+
+/// Rewrite the text of a JSON number, read as an epoch under `profile`, to
+/// the string form of a timestamp of `kind`. `None` if the profile accepts no
+/// epochs, or the number is not a representable timestamp.
+pub(crate) fn epoch_to_text(
+    number: &str,
+    profile: TimestampProfile,
+    kind: TimestampKind,
+) -> Option<String> {
+    let millis = match (profile.epoch_seconds, profile.epoch_millis) {
+        (false, false) => return None,
+        (true, false) => false,
+        (false, true) => true,
+        (true, true) => {
+            let digits = number.trim_start_matches('-');
+            let int_len = digits.find('.').unwrap_or(digits.len());
+            // 10^11 has 12 digits.
+            int_len >= 12
+        }
+    };
+    let nanos = parse_epoch(number, if millis { 1_000_000 } else { 1_000_000_000 })?;
+    let secs = i64::try_from(nanos.div_euclid(1_000_000_000)).ok()?;
+    let parts = Parts::from_epoch(secs, nanos.rem_euclid(1_000_000_000) as u32)?;
+    parts.render(kind)
+}
+
+/// Rewrite a relaxed ISO 8601 string to the string form of a timestamp of
+/// `kind`. `None` if it doesn't parse, or carries an offset `kind` can't hold.
+pub(crate) fn relaxed_to_text(text: &str, kind: TimestampKind) -> Option<String> {
+    Parts::parse(text)?.render(kind)
+}
+
+/// The epoch number for a timestamp's string form, as JSON number text.
+/// `None` for [`TimestampFormat::Rfc3339`] or text that doesn't parse.
+pub(crate) fn text_to_epoch(text: &str, format: TimestampFormat) -> Option<String> {
+    let (secs, nanos) = Parts::parse(text)?.to_epoch();
+    Some(match format {
+        TimestampFormat::Rfc3339 => return None,
+        TimestampFormat::EpochSeconds => format!("{secs}"),
+        TimestampFormat::EpochMillis => {
+            format!(
+                "{}",
+                i128::from(secs) * 1000 + i128::from(nanos / 1_000_000)
+            )
+        }
+    })
+}
+
+/// Parse a JSON number (without exponent) into nanoseconds, where one unit
+/// of the number is `unit` nanoseconds. Digits finer than a nanosecond are
+/// dropped.
+fn parse_epoch(number: &str, unit: i128) -> Option<i128> {
+    let (negative, digits) = match number.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, number),
+    };
+    let (int, frac) = digits.split_once('.').unwrap_or((digits, ""));
+    if int.is_empty() || !int.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    if !frac.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let mut nanos = int.parse::<i128>().ok()?.checked_mul(unit)?;
+    let mut scale = unit;
+    for digit in frac.bytes() {
+        scale /= 10;
+        if scale == 0 {
+            break;
+        }
+        nanos += i128::from(digit - b'0') * scale;
+    }
+    Some(if negative { -nanos } else { nanos })
+}
+
+/// The components of an ISO 8601 date-time.
+#[derive(Debug, Clone, Copy)]
+struct Parts<'a> {
+    year: i64,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    nanos: u32,
+    /// Offset from UTC in minutes; `None` if the text had none.
+    offset: Option<i32>,
+    /// A trailing `[...]` time zone annotation, brackets included.
+    annotation: Option<&'a str>,
+}
+
+impl<'a> Parts<'a> {
+    /// Parse relaxed ISO 8601 text (see [`TimestampProfile::relaxed_iso`]).
+    fn parse(text: &'a str) -> Option<Self> {
+        let (text, annotation) = match text.find('[') {
+            Some(start) if text.ends_with(']') => (&text[..start], Some(&text[start..])),
+            _ => (text, None),
+        };
+        let mut cur = Cursor(text.as_bytes());
+
+        let year = i64::from(cur.digits(4)?);
+        cur.expect(b'-')?;
+        let month = cur.digits(2)?;
+        cur.expect(b'-')?;
+        let day = cur.digits(2)?;
+
+        let mut parts = Parts {
+            year,
+            month,
+            day,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            nanos: 0,
+            offset: None,
+            annotation,
+        };
+        if cur.0.is_empty() {
+            return parts.validate();
+        }
+
+        if !matches!(cur.next()?, b'T' | b't' | b' ') {
+            return None;
+        }
+        parts.hour = cur.digits(2)?;
+        cur.expect(b':')?;
+        parts.minute = cur.digits(2)?;
+        if cur.eat(b':') {
+            parts.second = cur.digits(2)?;
+            if cur.eat(b'.') || cur.eat(b',') {
+                parts.nanos = cur.fraction()?;
+            }
+        }
+
+        parts.offset = match cur.next() {
+            None => None,
+            Some(b'Z' | b'z') => Some(0),
+            Some(sign @ (b'+' | b'-')) => {
+                let hours = cur.digits(2)?;
+                let minutes = if cur.eat(b':') || cur.0.len() == 2 {
+                    cur.digits(2)?
+                } else if cur.0.is_empty() {
+                    0
+                } else {
+                    return None;
+                };
+                if hours > 23 || minutes > 59 {
+                    return None;
+                }
+                let offset = (hours * 60 + minutes) as i32;
+                Some(if sign == b'-' { -offset } else { offset })
+            }
+            _ => return None,
+        };
+        if !cur.0.is_empty() {
+            return None;
+        }
+        parts.validate()
+    }
+
+    fn validate(self) -> Option<Self> {
+        let days_in_month = match self.month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if is_leap_year(self.year) => 29,
+            2 => 28,
+            _ => return None,
+        };
+        // Leap seconds (`:60`) are left for the type to accept or reject.
+        let valid = (1..=days_in_month).contains(&self.day)
+            && self.hour < 24
+            && self.minute < 60
+            && self.second <= 60;
+        valid.then_some(self)
+    }
+
+    /// The UTC date-time `secs` seconds and `nanos` nanoseconds after the
+    /// epoch, with no offset. `None` outside years 0 to 9999.
+    fn from_epoch(secs: i64, nanos: u32) -> Option<Self> {
+        let days = secs.div_euclid(86_400);
+        let time = secs.rem_euclid(86_400) as u32;
+        let (year, month, day) = civil_from_days(days);
+        if !(0..=9999).contains(&year) {
+            return None;
+        }
+        Some(Parts {
+            year,
+            month,
+            day,
+            hour: time / 3600,
+            minute: time / 60 % 60,
+            second: time % 60,
+            nanos,
+            offset: None,
+            annotation: None,
+        })
+    }
+
+    /// Seconds and nanoseconds since the epoch; no offset counts as UTC.
+    fn to_epoch(self) -> (i64, u32) {
+        let days = days_from_civil(self.year, self.month, self.day);
+        let secs = days * 86_400 + i64::from(self.hour * 3600 + self.minute * 60 + self.second)
+            - i64::from(self.offset.unwrap_or(0)) * 60;
+        (secs, self.nanos)
+    }
+
+    /// RFC 3339 text, with the suffix a timestamp of `kind` expects. `None`
+    /// for an offset or annotation on a naive date-time.
+    fn render(self, kind: TimestampKind) -> Option<String> {
+        let mut out = format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        );
+        if self.nanos != 0 {
+            let fraction = format!("{:09}", self.nanos);
+            out.push('.');
+            out.push_str(fraction.trim_end_matches('0'));
+        }
+
+        let offset = self.offset.unwrap_or(0);
+        let write_offset = |out: &mut String| {
+            if offset == 0 {
+                out.push_str("+00:00");
+            } else {
+                let sign = if offset < 0 { '-' } else { '+' };
+                let offset = offset.unsigned_abs();
+                let _ = write!(out, "{sign}{:02}:{:02}", offset / 60, offset % 60);
+            }
+        };
+        match kind {
+            TimestampKind::Naive => {
+                if self.offset.is_some() || self.annotation.is_some() {
+                    return None;
+                }
+            }
+            TimestampKind::Instant => {
+                if offset == 0 {
+                    out.push('Z');
+                } else {
+                    write_offset(&mut out);
+                }
+            }
+            TimestampKind::Zoned => {
+                write_offset(&mut out);
+                match self.annotation {
+                    Some(annotation) => out.push_str(annotation),
+                    None if offset == 0 => out.push_str("[UTC]"),
+                    None => {
+                        out.push('[');
+                        write_offset(&mut out);
+                        out.push(']');
+                    }
+                }
+            }
+        }
+        Some(out)
+    }
+}
+
+struct Cursor<'a>(&'a [u8]);
+
+impl Cursor<'_> {
+    fn next(&mut self) -> Option<u8> {
+        let (&first, rest) = self.0.split_first()?;
+        self.0 = rest;
+        Some(first)
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        let matched = self.0.first() == Some(&byte);
+        if matched {
+            self.0 = &self.0[1..];
+        }
+        matched
+    }
+
+    fn expect(&mut self, byte: u8) -> Option<()> {
+        self.eat(byte).then_some(())
+    }
+
+    /// Exactly `count` ASCII digits.
+    fn digits(&mut self, count: usize) -> Option<u32> {
+        let digits = self.0.get(..count)?;
+        let mut value = 0;
+        for &digit in digits {
+            if !digit.is_ascii_digit() {
+                return None;
+            }
+            value = value * 10 + u32::from(digit - b'0');
+        }
+        self.0 = &self.0[count..];
+        Some(value)
+    }
+
+    /// One or more digits of a fraction of a second, as nanoseconds.
+    fn fraction(&mut self) -> Option<u32> {
+        let len = self.0.iter().take_while(|b| b.is_ascii_digit()).count();
+        if len == 0 {
+            return None;
+        }
+        let mut nanos = 0;
+        let mut scale = 100_000_000;
+        for &digit in &self.0[..len] {
+            nanos += u32::from(digit - b'0') * scale;
+            scale /= 10;
+        }
+        self.0 = &self.0[len..];
+        Some(nanos)
+    }
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian date.
+///
+/// See <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The proleptic Gregorian date `days` days after 1970-01-01.
+///
+/// See <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
@@ -0,0 +1,129 @@
+//! What to do with input after the first JSON value.
+//!
+//! [`from_str`](crate::from_str) and friends stop reading once the value they
+//! were asked for is complete, and ignore whatever follows it. The functions
+//! here make that choice explicit: [`from_str_strict`] rejects anything but
+//! whitespace after the value, and [`from_str_with_remainder`] hands back the
+//! unread tail so that several values written back to back (`{"a":1}{"a":2}`,
+//! or `1 2 3`) can be read from one buffer without newline framing.
+
+use facet_core::Facet;
+use facet_format::{DeserializeError, DeserializeErrorKind, FormatDeserializer};
+use facet_reflect::Span;
+
+use crate::JsonParser;
+
+/// Deserialize the first JSON value in a string, returning it along with the
+/// rest of the input.
+///
+/// The remainder starts right after the value, so it may begin with
+/// whitespace; an empty remainder means the input held exactly one value.
+/// Calling this again on the remainder reads the next value.
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+///
+/// #[derive(Facet, Debug, PartialEq)]
+/// struct Ping {
+///     seq: u32,
+/// }
+///
+/// let mut input = r#"{"seq": 1} {"seq": 2}{"seq": 3}"#;
+/// let mut pings = Vec::new();
+/// while !input.trim_start().is_empty() {
+///     let (ping, rest) = facet_json::from_str_with_remainder::<Ping>(input).unwrap();
+///     pings.push(ping.seq);
+///     input = rest;
+/// }
+/// assert_eq!(pings, [1, 2, 3]);
+/// ```
+pub fn from_str_with_remainder<T>(input: &str) -> Result<(T, &str), DeserializeError>
+where
+    T: Facet<'static>,
+{
+    // TRUSTED_UTF8 = true: input came from &str, so it's valid UTF-8
+    let mut parser = JsonParser::<true>::new(input.as_bytes());
+    let (value, end) = deserialize_prefix(&mut parser)?;
+    // A JSON value always ends on an ASCII byte, so this is a char boundary.
+    Ok((value, &input[end..]))
+}
+
+/// Deserialize the first JSON value in a byte slice, returning it along with
+/// the rest of the input.
+///
+/// See [`from_str_with_remainder`].
+pub fn from_slice_with_remainder<T>(input: &[u8]) -> Result<(T, &[u8]), DeserializeError>
+where
+    T: Facet<'static>,
+{
+    let mut parser = JsonParser::<false>::new(input);
+    let (value, end) = deserialize_prefix(&mut parser)?;
+    Ok((value, &input[end..]))
+}
+
+/// Deserialize a JSON string that must hold exactly one value.
+///
+/// Like [`from_str`](crate::from_str), except that anything other than
+/// whitespace after the value is an error instead of being ignored.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(facet_json::from_str_strict::<u32>("42\n").unwrap(), 42);
+/// assert!(facet_json::from_str_strict::<u32>("42 43").is_err());
+/// ```
+pub fn from_str_strict<T>(input: &str) -> Result<T, DeserializeError>
+where
+    T: Facet<'static>,
+{
+    let (value, rest) = from_str_with_remainder(input)?;
+    let offset = input.len() - rest.len();
+    match rest.char_indices().find(|&(_, ch)| !is_json_whitespace(ch)) {
+        Some((pos, ch)) => Err(trailing_error(ch, offset + pos, ch.len_utf8())),
+        None => Ok(value),
+    }
+}
+
+/// Deserialize JSON bytes that must hold exactly one value.
+///
+/// See [`from_str_strict`].
+pub fn from_slice_strict<T>(input: &[u8]) -> Result<T, DeserializeError>
+where
+    T: Facet<'static>,
+{
+    let (value, rest) = from_slice_with_remainder(input)?;
+    let offset = input.len() - rest.len();
+    match rest
+        .iter()
+        .position(|&byte| !is_json_whitespace(char::from(byte)))
+    {
+        Some(pos) => Err(trailing_error(char::from(rest[pos]), offset + pos, 1)),
+        None => Ok(value),
+    }
+}
+
+/// Deserialize one value, returning it and the offset just past it.
+fn deserialize_prefix<T, const TRUSTED_UTF8: bool>(
+    parser: &mut JsonParser<'_, TRUSTED_UTF8>,
+) -> Result<(T, usize), DeserializeError>
+where
+    T: Facet<'static>,
+{
+    let value = FormatDeserializer::new_owned(parser).deserialize_root()?;
+    Ok((value, parser.root_end()))
+}
+
+/// The four whitespace characters of RFC 8259.
+const fn is_json_whitespace(ch: char) -> bool {
+    matches!(ch, ' ' | '\t' | '\n' | '\r')
+}
+
+fn trailing_error(ch: char, offset: usize, len: usize) -> DeserializeError {
+    DeserializeErrorKind::UnexpectedChar {
+        ch,
+        expected: "end of input",
+    }
+    .with_span(Span::new(offset, len))
+}
@@ -23,14 +23,14 @@ use core::sync::atomic::{AtomicU8, Ordering};
 
 use facet_core::{
     ArrayDef, Characteristic, Def, DefaultInPlaceFn, DefaultSource, DynamicValueDef, EnumRepr,
-    EnumType, Facet, Field, KnownPointer, ListDef, MapDef, OptionDef, PointerDef, ProxyDef,
-    PtrConst, PtrMut, PtrUninit, ScalarType, SetDef, Shape, StructKind, TryFromOutcome, Type,
-    UserType, Variant,
+    EnumType, Facet, Field, KnownPointer, ListDef, MapDef, NumericType, OptionDef, PointerDef,
+    PrimitiveType, ProxyDef, PtrConst, PtrMut, PtrUninit, ScalarType, SetDef, Shape, StructKind,
+    TryFromOutcome, Type, UserType, Variant,
 };
 use facet_format::{
     DeserializeError, DeserializeErrorKind, FormatParser, ParseError, ParseEventKind, ScalarValue,
 };
-use facet_reflect::Span;
+use facet_reflect::{Peek, Span, coerce_scalar};
 use weavy::mem::runtime::{
     HandleGuard, InitializedLedger, RawAllocError, RawArrayBuilder, ScratchSession, ScratchSlot,
 };
@@ -2365,7 +2365,7 @@ impl Lowering {
         &mut self,
         shape: &'static Shape,
     ) -> Result<Program<SymbolicOp>, DeserializeError> {
-        if let Some(proxy) = shape.effective_proxy(Some(JSON_FORMAT_NAMESPACE)) {
+        if let Some(proxy) = shape.deserialize_proxy(Some(JSON_FORMAT_NAMESPACE)) {
             return self.lower_proxy(shape, proxy);
         }
 
@@ -3827,59 +3827,9 @@ fn scalar_matches_shape(scalar: &ScalarValue<'_>, shape: &'static Shape) -> bool
     match scalar {
         ScalarValue::Bool(_) => matches!(scalar_type, ScalarType::Bool),
         ScalarValue::Char(_) => matches!(scalar_type, ScalarType::Char),
-        ScalarValue::I64(value) => {
-            if matches!(
-                scalar_type,
-                ScalarType::I8
-                    | ScalarType::I16
-                    | ScalarType::I32
-                    | ScalarType::I64
-                    | ScalarType::I128
-                    | ScalarType::ISize
-            ) {
-                return true;
-            }
-
-            if *value < 0 {
-                return false;
-            }
-
-            let unsigned = *value as u64;
-            match scalar_type {
-                ScalarType::U8 => unsigned <= u8::MAX as u64,
-                ScalarType::U16 => unsigned <= u16::MAX as u64,
-                ScalarType::U32 => unsigned <= u32::MAX as u64,
-                ScalarType::U64 | ScalarType::U128 | ScalarType::USize => true,
-                _ => false,
-            }
-        }
-        ScalarValue::U64(value) => {
-            if matches!(
-                scalar_type,
-                ScalarType::U8
-                    | ScalarType::U16
-                    | ScalarType::U32
-                    | ScalarType::U64
-                    | ScalarType::U128
-                    | ScalarType::USize
-            ) {
-                return true;
-            }
-
-            if *value > i64::MAX as u64 {
-                return false;
-            }
-
-            match scalar_type {
-                ScalarType::I8 => *value <= i8::MAX as u64,
-                ScalarType::I16 => *value <= i16::MAX as u64,
-                ScalarType::I32 => *value <= i32::MAX as u64,
-                ScalarType::I64 | ScalarType::I128 | ScalarType::ISize => true,
-                _ => false,
-            }
+        ScalarValue::I64(_) | ScalarValue::U64(_) | ScalarValue::U128(_) | ScalarValue::I128(_) => {
+            integer_fits(scalar, shape)
         }
-        ScalarValue::U128(_) => matches!(scalar_type, ScalarType::U128 | ScalarType::I128),
-        ScalarValue::I128(_) => matches!(scalar_type, ScalarType::I128 | ScalarType::U128),
         ScalarValue::F64(_) => matches!(scalar_type, ScalarType::F32 | ScalarType::F64),
         ScalarValue::Str(value) => {
             if matches!(
@@ -3897,6 +3847,25 @@ fn scalar_matches_shape(scalar: &ScalarValue<'_>, shape: &'static Shape) -> bool
     }
 }
 
+/// Whether an integer scalar fits an integer shape, as decided by
+/// [`coerce_scalar`].
+fn integer_fits(scalar: &ScalarValue<'_>, shape: &'static Shape) -> bool {
+    if !matches!(
+        shape.ty,
+        Type::Primitive(PrimitiveType::Numeric(NumericType::Integer { .. }))
+    ) {
+        return false;
+    }
+    let fits = match scalar {
+        ScalarValue::I64(n) => coerce_scalar(Peek::new(n), shape),
+        ScalarValue::U64(n) => coerce_scalar(Peek::new(n), shape),
+        ScalarValue::I128(n) => coerce_scalar(Peek::new(n), shape),
+        ScalarValue::U128(n) => coerce_scalar(Peek::new(n), shape),
+        _ => return false,
+    };
+    fits.is_ok()
+}
+
 fn scalar_match_quality(scalar: &ScalarValue<'_>, shape: &'static Shape) -> Option<u8> {
     if !scalar_matches_shape(scalar, shape) {
         return None;
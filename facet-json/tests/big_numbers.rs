@@ -0,0 +1,95 @@
+//! Big-number mode: integers and decimals that don't survive a trip through
+//! `f64`.
+
+use std::str::FromStr;
+
+use bigdecimal::BigDecimal;
+use facet::Facet;
+use facet_format::FormatDeserializer;
+use facet_json::{JsonParser, SerializeOptions};
+use facet_testhelpers::test;
+
+#[derive(Facet, Debug, PartialEq)]
+struct Wide {
+    big_u: u128,
+    big_i: i128,
+    id: u64,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Ledger {
+    balance: BigDecimal,
+}
+
+fn from_str_big<T: Facet<'static>>(input: &str) -> Result<T, facet_json::DeserializeError> {
+    let mut parser = JsonParser::<true>::new(input.as_bytes()).big_numbers(true);
+    FormatDeserializer::new_owned(&mut parser).deserialize_root()
+}
+
+#[test]
+fn integers_with_fraction_or_exponent_are_exact() {
+    // 2^53 + 1 is the first integer f64 can't represent.
+    let wide: Wide = from_str_big(
+        r#"{"big_u": 1.2345678901234567890123e22, "big_i": -4.20e1, "id": 9007199254740993.0}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        wide,
+        Wide {
+            big_u: 12_345_678_901_234_567_890_123,
+            big_i: -42,
+            id: 9_007_199_254_740_993,
+        }
+    );
+}
+
+#[test]
+fn fractional_and_oversized_integers_are_rejected() {
+    // Through f64 this would quietly become 1.
+    let err = from_str_big::<Wide>(r#"{"big_u": 1.000000000000000000001, "big_i": 0, "id": 0}"#)
+        .unwrap_err();
+    assert!(err.to_string().contains("fractional part"), "{err}");
+
+    let err = from_str_big::<Wide>(r#"{"big_u": 1e40, "big_i": 0, "id": 0}"#).unwrap_err();
+    assert!(err.to_string().contains("out of range"), "{err}");
+
+    assert!(
+        from_str_big::<Wide>(r#"{"big_u": 0, "big_i": 0, "id": 1.8446744073709551616e19}"#)
+            .is_err()
+    );
+}
+
+#[test]
+fn integers_beyond_128_bits_reach_decimals() {
+    let text = "123456789012345678901234567890123456789012345678901234567890";
+    let input = format!(r#"{{"balance": {text}}}"#);
+
+    let ledger: Ledger = from_str_big(&input).unwrap();
+    assert_eq!(ledger.balance, BigDecimal::from_str(text).unwrap());
+
+    // Without big-number mode the integer doesn't even parse.
+    assert!(facet_json::from_str::<Ledger>(&input).is_err());
+}
+
+#[test]
+fn wide_integers_serialize_as_numbers() {
+    let wide = Wide {
+        big_u: u128::MAX,
+        big_i: i128::MIN,
+        id: u64::MAX,
+    };
+
+    assert_eq!(
+        facet_json::to_string(&wide).unwrap(),
+        r#"{"big_u":"340282366920938463463374607431768211455","big_i":"-170141183460469231731687303715884105728","id":18446744073709551615}"#
+    );
+
+    let json =
+        facet_json::to_string_with_options(&wide, &SerializeOptions::default().big_numbers())
+            .unwrap();
+    assert_eq!(
+        json,
+        r#"{"big_u":340282366920938463463374607431768211455,"big_i":-170141183460469231731687303715884105728,"id":18446744073709551615}"#
+    );
+    assert_eq!(from_str_big::<Wide>(&json).unwrap(), wide);
+}
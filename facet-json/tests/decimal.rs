@@ -0,0 +1,88 @@
+//! Exact round-trips for decimal types (`rust_decimal::Decimal`,
+//! `bigdecimal::BigDecimal`).
+
+use std::str::FromStr;
+
+use bigdecimal::BigDecimal;
+use facet::Facet;
+use facet_format::FormatDeserializer;
+use facet_json::{DecimalMode, JsonParser, SerializeOptions};
+use facet_testhelpers::test;
+use rust_decimal::Decimal;
+
+#[derive(Facet, Debug, PartialEq)]
+struct Invoice {
+    amount: Decimal,
+    tax: Option<Decimal>,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Ledger {
+    balance: BigDecimal,
+}
+
+fn from_str_strict<T: Facet<'static>>(input: &str) -> Result<T, facet_json::DeserializeError> {
+    let mut parser = JsonParser::<true>::new(input.as_bytes()).decimal_mode(DecimalMode::Strict);
+    FormatDeserializer::new_owned(&mut parser).deserialize_root()
+}
+
+#[test]
+fn decimal_from_number_is_exact() {
+    // 0.1 + 0.2 style values and more digits than f64 can hold.
+    let invoice: Invoice =
+        facet_json::from_str(r#"{"amount": 0.30000000000000000001, "tax": 19.99}"#).unwrap();
+    assert_eq!(
+        invoice.amount,
+        Decimal::from_str("0.30000000000000000001").unwrap()
+    );
+    assert_eq!(invoice.tax, Some(Decimal::from_str("19.99").unwrap()));
+}
+
+#[test]
+fn decimal_from_string_or_integer() {
+    let invoice: Invoice = facet_json::from_str(r#"{"amount": "24.99", "tax": 3}"#).unwrap();
+    assert_eq!(invoice.amount, Decimal::new(2499, 2));
+    assert_eq!(invoice.tax, Some(Decimal::new(3, 0)));
+}
+
+#[test]
+fn decimal_strict_mode_rejects_strings() {
+    let invoice: Invoice = from_str_strict(r#"{"amount": 24.99, "tax": null}"#).unwrap();
+    assert_eq!(invoice.amount, Decimal::new(2499, 2));
+    assert_eq!(invoice.tax, None);
+
+    assert!(from_str_strict::<Invoice>(r#"{"amount": "24.99", "tax": null}"#).is_err());
+}
+
+#[test]
+fn bigdecimal_keeps_every_digit() {
+    let text = "123456789012345678901234567890.123456789012345678901234567890";
+    let ledger: Ledger = facet_json::from_str(&format!(r#"{{"balance": {text}}}"#)).unwrap();
+    assert_eq!(ledger.balance, BigDecimal::from_str(text).unwrap());
+
+    let json = facet_json::to_string_with_options(
+        &ledger,
+        &SerializeOptions::default().decimals_as_numbers(),
+    )
+    .unwrap();
+    assert_eq!(json, format!(r#"{{"balance":{text}}}"#));
+}
+
+#[test]
+fn decimal_serializes_as_string_by_default() {
+    let invoice = Invoice {
+        amount: Decimal::new(2499, 2),
+        tax: None,
+    };
+    assert_eq!(
+        facet_json::to_string(&invoice).unwrap(),
+        r#"{"amount":"24.99","tax":null}"#
+    );
+    let json = facet_json::to_string_with_options(
+        &invoice,
+        &SerializeOptions::default().decimals_as_numbers(),
+    )
+    .unwrap();
+    assert_eq!(json, r#"{"amount":24.99,"tax":null}"#);
+    assert_eq!(facet_json::from_str::<Invoice>(&json).unwrap(), invoice);
+}
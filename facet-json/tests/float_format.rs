@@ -0,0 +1,111 @@
+//! Float output modes of the serializer.
+
+use facet::Facet;
+use facet_json::{FloatFormat, SerializeOptions};
+use facet_testhelpers::test;
+
+#[derive(Facet, Debug, PartialEq)]
+struct Sample {
+    values: Vec<f64>,
+}
+
+fn write(values: &[f64], format: FloatFormat) -> String {
+    let sample = Sample {
+        values: values.to_vec(),
+    };
+    let options = SerializeOptions::new().float_format(format);
+    facet_json::to_string_with_options(&sample, &options).unwrap()
+}
+
+const VALUES: &[f64] = &[
+    1.0, 0.1, -2.5, 100.0, 1e20, 1e21, 1.5e-7, 0.000001, 5e-324, -0.0,
+];
+
+#[test]
+fn shortest_is_the_default() {
+    let sample = Sample {
+        values: VALUES.to_vec(),
+    };
+    assert_eq!(
+        facet_json::to_string(&sample).unwrap(),
+        write(VALUES, FloatFormat::Shortest)
+    );
+    assert_eq!(
+        write(VALUES, FloatFormat::Shortest),
+        r#"{"values":[1.0,0.1,-2.5,100.0,100000000000000000000.0,1e+21,1.5e-7,0.000001,5e-324,-0.0]}"#
+    );
+}
+
+#[test]
+fn ecmascript_matches_json_stringify() {
+    // Expected strings are the output of `JSON.stringify` for the same values.
+    assert_eq!(
+        write(VALUES, FloatFormat::Ecmascript),
+        r#"{"values":[1,0.1,-2.5,100,100000000000000000000,1e+21,1.5e-7,0.000001,5e-324,0]}"#
+    );
+}
+
+#[test]
+fn fixed_precision() {
+    assert_eq!(
+        write(&[1.0, 0.126, -2.5, 1e21], FloatFormat::Fixed(2)),
+        r#"{"values":[1.00,0.13,-2.50,1000000000000000000000.00]}"#
+    );
+}
+
+#[test]
+fn shortest_output_round_trips() {
+    let values = [
+        0.1 + 0.2,
+        1.0 / 3.0,
+        f64::MAX,
+        f64::MIN_POSITIVE,
+        123456.789e300,
+    ];
+    for format in [FloatFormat::Shortest, FloatFormat::Ecmascript] {
+        let json = write(&values, format);
+        let back: Sample = facet_json::from_str(&json).unwrap();
+        assert_eq!(back.values, values, "{json}");
+    }
+}
+
+#[test]
+fn non_finite_values_are_null() {
+    assert_eq!(
+        write(&[f64::NAN, f64::INFINITY], FloatFormat::Shortest),
+        r#"{"values":[null,null]}"#
+    );
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Single {
+    values: Vec<f32>,
+}
+
+fn write_f32(values: &[f32], format: FloatFormat) -> String {
+    let sample = Single {
+        values: values.to_vec(),
+    };
+    let options = SerializeOptions::new().float_format(format);
+    facet_json::to_string_with_options(&sample, &options).unwrap()
+}
+
+#[test]
+fn f32_uses_its_own_shortest_digits() {
+    let values = [0.1f32, 1.0, -2.5, 16777216.0, 1e-7, f32::MAX, -0.0];
+    assert_eq!(
+        write_f32(&values, FloatFormat::Shortest),
+        r#"{"values":[0.1,1.0,-2.5,16777216.0,1e-7,3.4028235e+38,-0.0]}"#
+    );
+    assert_eq!(
+        write_f32(&values, FloatFormat::Ecmascript),
+        r#"{"values":[0.1,1,-2.5,16777216,1e-7,3.4028235e+38,0]}"#
+    );
+    assert_eq!(
+        write_f32(&[0.1, f32::NAN], FloatFormat::Fixed(3)),
+        r#"{"values":[0.100,null]}"#
+    );
+
+    let back: Single = facet_json::from_str(&write_f32(&values, FloatFormat::Shortest)).unwrap();
+    assert_eq!(back.values, values);
+}
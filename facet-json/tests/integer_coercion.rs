@@ -0,0 +1,49 @@
+//! Floats read into integer fields.
+
+use facet::Facet;
+use facet_format::DeserializeErrorKind;
+use facet_testhelpers::test;
+
+#[derive(Facet, Debug, PartialEq)]
+struct Counts {
+    small: u8,
+    signed: i32,
+}
+
+#[test]
+fn integral_floats_are_accepted() {
+    let counts: Counts = facet_json::from_str(r#"{"small": 1.0, "signed": -2e1}"#).unwrap();
+    assert_eq!(
+        counts,
+        Counts {
+            small: 1,
+            signed: -20
+        }
+    );
+}
+
+#[test]
+fn fractional_floats_are_not_exact() {
+    let err = facet_json::from_str::<Counts>(r#"{"small": 1.5, "signed": 0}"#).unwrap_err();
+    assert!(
+        matches!(
+            err.kind,
+            DeserializeErrorKind::NumberNotExact {
+                target_type: "u8",
+                ..
+            }
+        ),
+        "{err}"
+    );
+    assert!(
+        err.to_string()
+            .contains("number `1.5` not exactly representable as u8"),
+        "{err}"
+    );
+
+    let err = facet_json::from_str::<Counts>(r#"{"small": 256.0, "signed": 0}"#).unwrap_err();
+    assert!(
+        matches!(err.kind, DeserializeErrorKind::NumberOutOfRange { .. }),
+        "{err}"
+    );
+}
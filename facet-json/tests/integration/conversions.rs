@@ -0,0 +1,110 @@
+//! Tests for container-level `#[facet(from = ...)]` and `#[facet(into = ...)]`.
+
+use super::json_backend::from_str;
+use facet::Facet;
+use facet_json::to_string;
+use facet_testhelpers::test;
+
+/// The wire form of a version: `"1.2"`.
+#[derive(Facet, Debug)]
+#[facet(transparent)]
+struct VersionString(String);
+
+#[derive(Facet, Debug, Clone, PartialEq)]
+#[facet(from = VersionString, into = VersionString)]
+struct Version {
+    major: u32,
+    minor: u32,
+}
+
+impl From<VersionString> for Version {
+    fn from(wire: VersionString) -> Self {
+        let (major, minor) = wire.0.split_once('.').unwrap_or((&wire.0, "0"));
+        Version {
+            major: major.parse().unwrap_or_default(),
+            minor: minor.parse().unwrap_or_default(),
+        }
+    }
+}
+
+impl From<Version> for VersionString {
+    fn from(version: Version) -> Self {
+        VersionString(format!("{}.{}", version.major, version.minor))
+    }
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Release {
+    name: String,
+    version: Version,
+    supports: Vec<Version>,
+}
+
+#[test]
+fn from_and_into_round_trip() {
+    let release = Release {
+        name: "facet".into(),
+        version: Version { major: 1, minor: 2 },
+        supports: vec![
+            Version { major: 1, minor: 0 },
+            Version { major: 0, minor: 9 },
+        ],
+    };
+
+    let json = to_string(&release).unwrap();
+    assert_eq!(
+        json,
+        r#"{"name":"facet","version":"1.2","supports":["1.0","0.9"]}"#
+    );
+    assert_eq!(from_str::<Release>(&json).unwrap(), release);
+    assert_eq!(
+        from_str::<Version>(r#""3""#).unwrap(),
+        Version { major: 3, minor: 0 }
+    );
+}
+
+/// Deserialized from a plain list, serialized as itself.
+#[derive(Facet, Debug, PartialEq)]
+#[facet(from = Vec<String>)]
+struct Tags {
+    sorted: Vec<String>,
+}
+
+impl From<Vec<String>> for Tags {
+    fn from(mut tags: Vec<String>) -> Self {
+        tags.sort();
+        tags.dedup();
+        Tags { sorted: tags }
+    }
+}
+
+#[test]
+fn from_only_changes_deserialization() {
+    let tags: Tags = from_str(r#"["b","a","b"]"#).unwrap();
+    assert_eq!(tags.sorted, ["a", "b"]);
+    assert_eq!(to_string(&tags).unwrap(), r#"{"sorted":["a","b"]}"#);
+}
+
+/// Serialized as a number, deserialized from its variant names.
+#[derive(Facet, Debug, Clone, Copy, PartialEq)]
+#[facet(into = u8)]
+#[repr(u8)]
+enum Level {
+    Low,
+    High,
+}
+
+impl From<Level> for u8 {
+    fn from(level: Level) -> Self {
+        match level {
+            Level::Low => 0,
+            Level::High => 10,
+        }
+    }
+}
+
+#[test]
+fn into_only_changes_serialization() {
+    assert_eq!(to_string(&[Level::Low, Level::High]).unwrap(), "[0,10]");
+    assert_eq!(from_str::<Level>(r#""High""#).unwrap(), Level::High);
+}
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use facet::Facet;
+use facet_format::SerializeError;
+
+#[derive(Facet)]
+struct Node {
+    name: &'static str,
+    #[facet(recursive_type)]
+    next: Option<&'static Node>,
+}
+
+static FIRST: Node = Node {
+    name: "first",
+    next: Some(&SECOND),
+};
+
+static SECOND: Node = Node {
+    name: "second",
+    next: Some(&FIRST),
+};
+
+#[derive(Facet)]
+struct Root {
+    head: &'static Node,
+}
+
+#[test]
+fn cyclic_pointers_are_an_error() {
+    let root = Root { head: &FIRST };
+    let err = facet_json::to_string(&root).unwrap_err();
+    let SerializeError::CycleDetected { path } = &err else {
+        panic!("expected CycleDetected, got {err:?}");
+    };
+    assert_eq!(path, ".head.next.next");
+    assert!(err.to_string().contains("cycle detected"));
+}
+
+#[derive(Facet)]
+struct Shared {
+    left: Arc<String>,
+    right: Arc<String>,
+}
+
+#[test]
+fn shared_pointers_are_not_cycles() {
+    let value = Arc::new("same".to_string());
+    let shared = Shared {
+        left: value.clone(),
+        right: value,
+    };
+    assert_eq!(
+        facet_json::to_string(&shared).unwrap(),
+        r#"{"left":"same","right":"same"}"#
+    );
+}
@@ -0,0 +1,189 @@
+//! Tests for `#[facet(defaults_for_children(...))]`.
+
+use facet::Facet;
+use facet_json::{from_str, to_string};
+use facet_testhelpers::test;
+
+#[derive(Facet, Debug, PartialEq)]
+struct Pool {
+    max_connections: u32,
+    idle_timeout: u32,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Replica {
+    host_name: String,
+    #[facet(rename = "weight_pct")]
+    weight_percent: u8,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+#[facet(rename_all = "kebab-case")]
+struct Logging {
+    log_level: String,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+#[facet(defaults_for_children(rename_all = "camelCase"))]
+struct Config {
+    pool_settings: Pool,
+    replicas: Vec<Replica>,
+    fallback_pool: Option<Box<Pool>>,
+    logging: Logging,
+}
+
+fn config() -> Config {
+    Config {
+        pool_settings: Pool {
+            max_connections: 10,
+            idle_timeout: 30,
+        },
+        replicas: vec![Replica {
+            host_name: "db2".to_string(),
+            weight_percent: 50,
+        }],
+        fallback_pool: Some(Box::new(Pool {
+            max_connections: 1,
+            idle_timeout: 5,
+        })),
+        logging: Logging {
+            log_level: "info".to_string(),
+        },
+    }
+}
+
+const CONFIG_JSON: &str = concat!(
+    r#"{"pool_settings":{"maxConnections":10,"idleTimeout":30},"#,
+    r#""replicas":[{"hostName":"db2","weight_pct":50}],"#,
+    r#""fallback_pool":{"maxConnections":1,"idleTimeout":5},"#,
+    r#""logging":{"log-level":"info"}}"#,
+);
+
+#[test]
+fn children_inherit_the_rule_but_the_container_does_not() {
+    assert_eq!(to_string(&config()).unwrap(), CONFIG_JSON);
+}
+
+#[test]
+fn inherited_names_round_trip() {
+    let parsed: Config = from_str(CONFIG_JSON).unwrap();
+    assert_eq!(parsed, config());
+}
+
+#[test]
+fn the_rule_does_not_leak_to_other_uses_of_the_type() {
+    let pool = Pool {
+        max_connections: 10,
+        idle_timeout: 30,
+    };
+    let json = to_string(&pool).unwrap();
+    assert_eq!(json, r#"{"max_connections":10,"idle_timeout":30}"#);
+    assert_eq!(from_str::<Pool>(&json).unwrap(), pool);
+
+    // The same type inside and outside the defaults, in one document
+    #[derive(Facet, Debug, PartialEq)]
+    struct Both {
+        plain: Pool,
+        config: Config,
+    }
+    let both = Both {
+        plain: pool,
+        config: config(),
+    };
+    let json = to_string(&both).unwrap();
+    assert_eq!(
+        json,
+        format!(r#"{{"plain":{{"max_connections":10,"idle_timeout":30}},"config":{CONFIG_JSON}}}"#)
+    );
+    assert_eq!(from_str::<Both>(&json).unwrap(), both);
+}
+
+#[derive(Facet, Debug, PartialEq)]
+#[facet(defaults_for_children(rename_all = "SCREAMING_SNAKE_CASE"))]
+struct Section {
+    pool_settings: Pool,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+#[facet(defaults_for_children(rename_all = "camelCase"))]
+struct Outer {
+    section: Section,
+}
+
+#[test]
+fn nearer_defaults_replace_inherited_ones() {
+    let outer = Outer {
+        section: Section {
+            pool_settings: Pool {
+                max_connections: 2,
+                idle_timeout: 3,
+            },
+        },
+    };
+    let json = to_string(&outer).unwrap();
+    assert_eq!(
+        json,
+        r#"{"section":{"poolSettings":{"MAX_CONNECTIONS":2,"IDLE_TIMEOUT":3}}}"#
+    );
+    assert_eq!(from_str::<Outer>(&json).unwrap(), outer);
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Tls {
+    cert_path: String,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+#[facet(defaults_for_children(rename_all = "camelCase"))]
+struct Server {
+    listen_addr: String,
+    #[facet(flatten)]
+    tls: Tls,
+}
+
+#[test]
+fn flattened_children_inherit_the_rule() {
+    let server = Server {
+        listen_addr: "0.0.0.0".to_string(),
+        tls: Tls {
+            cert_path: "/etc/cert.pem".to_string(),
+        },
+    };
+    let json = to_string(&server).unwrap();
+    assert_eq!(
+        json,
+        r#"{"listen_addr":"0.0.0.0","certPath":"/etc/cert.pem"}"#
+    );
+    assert_eq!(from_str::<Server>(&json).unwrap(), server);
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct TreeNode {
+    node_label: String,
+    child_nodes: Vec<TreeNode>,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+#[facet(defaults_for_children(rename_all = "camelCase"))]
+struct Tree {
+    root_node: TreeNode,
+}
+
+#[test]
+fn recursive_children_inherit_the_rule_at_every_depth() {
+    let tree = Tree {
+        root_node: TreeNode {
+            node_label: "a".to_string(),
+            child_nodes: vec![TreeNode {
+                node_label: "b".to_string(),
+                child_nodes: vec![],
+            }],
+        },
+    };
+    let json = to_string(&tree).unwrap();
+    assert_eq!(
+        json,
+        r#"{"root_node":{"nodeLabel":"a","childNodes":[{"nodeLabel":"b","childNodes":[]}]}}"#
+    );
+    assert_eq!(from_str::<Tree>(&json).unwrap(), tree);
+}
@@ -0,0 +1,81 @@
+//! Tests for `#[facet(order = N)]` on fields.
+
+use facet::Facet;
+use facet_json::{from_str, to_string};
+use facet_testhelpers::test;
+
+#[derive(Facet, Debug, PartialEq)]
+struct Upload {
+    #[facet(order = 10)]
+    data: Vec<u32>,
+    name: String,
+    size: u64,
+    #[facet(order = -1)]
+    id: u32,
+}
+
+#[test]
+fn fields_are_written_in_order() {
+    let upload = Upload {
+        data: vec![1, 2],
+        name: "a.bin".to_string(),
+        size: 2,
+        id: 7,
+    };
+    let json = to_string(&upload).unwrap();
+    assert_eq!(json, r#"{"id":7,"name":"a.bin","size":2,"data":[1,2]}"#);
+
+    let parsed: Upload = from_str(&json).unwrap();
+    assert_eq!(parsed, upload);
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Common {
+    #[facet(order = -1)]
+    kind: String,
+    created: u64,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Record {
+    title: String,
+    #[facet(flatten)]
+    common: Common,
+}
+
+#[test]
+fn order_applies_within_flattened_structs() {
+    let record = Record {
+        title: "x".to_string(),
+        common: Common {
+            kind: "note".to_string(),
+            created: 1,
+        },
+    };
+    // The flattened fields stay together where the flattened field is
+    assert_eq!(
+        to_string(&record).unwrap(),
+        r#"{"title":"x","kind":"note","created":1}"#
+    );
+}
+
+#[derive(Facet, Debug, PartialEq)]
+#[repr(u8)]
+enum Event {
+    Upload {
+        path: String,
+        #[facet(order = -1)]
+        id: u32,
+    },
+}
+
+#[test]
+fn order_applies_to_variant_fields() {
+    let event = Event::Upload {
+        path: "/tmp".to_string(),
+        id: 3,
+    };
+    let json = to_string(&event).unwrap();
+    assert_eq!(json, r#"{"Upload":{"id":3,"path":"/tmp"}}"#);
+    assert_eq!(from_str::<Event>(&json).unwrap(), event);
+}
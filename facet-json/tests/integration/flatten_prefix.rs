@@ -0,0 +1,160 @@
+//! Tests for `#[facet(flatten_prefix_from = "...")]`.
+
+use facet::Facet;
+use facet_json::{from_str, to_string};
+use facet_testhelpers::test;
+
+#[derive(Facet, Debug, PartialEq)]
+struct Metrics {
+    cpu: f64,
+    #[facet(rename = "mem")]
+    memory: u64,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Instance {
+    name: String,
+    #[facet(flatten, flatten_prefix_from = "name")]
+    metrics: Metrics,
+    region: String,
+}
+
+fn web1() -> Instance {
+    Instance {
+        name: "web1".to_string(),
+        metrics: Metrics {
+            cpu: 0.5,
+            memory: 2048,
+        },
+        region: "eu".to_string(),
+    }
+}
+
+#[test]
+fn keys_carry_the_sibling_value() {
+    let json = to_string(&web1()).unwrap();
+    assert_eq!(
+        json,
+        r#"{"name":"web1","web1.cpu":0.5,"web1.mem":2048,"region":"eu"}"#
+    );
+
+    let parsed: Instance = from_str(&json).unwrap();
+    assert_eq!(parsed, web1());
+}
+
+#[test]
+fn prefix_may_come_before_its_source() {
+    let parsed: Instance =
+        from_str(r#"{"web1.mem":2048,"region":"eu","web1.cpu":0.5,"name":"web1"}"#).unwrap();
+    assert_eq!(parsed, web1());
+}
+
+#[test]
+fn prefixes_may_contain_dots() {
+    let parsed: Instance =
+        from_str(r#"{"name":"db.eu.1","db.eu.1.cpu":1.0,"db.eu.1.mem":1,"region":"eu"}"#).unwrap();
+    assert_eq!(parsed.name, "db.eu.1");
+    assert_eq!(parsed.metrics.memory, 1);
+}
+
+#[test]
+fn prefix_must_match_the_source() {
+    let err =
+        from_str::<Instance>(r#"{"name":"web1","web2.cpu":0.5,"web2.mem":2048,"region":"eu"}"#)
+            .unwrap_err();
+    assert!(err.to_string().contains("web2"), "{err}");
+
+    let err =
+        from_str::<Instance>(r#"{"name":"web1","web1.cpu":0.5,"web2.mem":2048,"region":"eu"}"#)
+            .unwrap_err();
+    assert!(err.to_string().contains("web2"), "{err}");
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Fleet {
+    id: u32,
+    #[facet(flatten, flatten_prefix_from = "id")]
+    totals: Totals,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Totals {
+    hosts: u32,
+    #[facet(flatten)]
+    metrics: Metrics,
+}
+
+#[test]
+fn nested_flattened_structs_share_the_prefix() {
+    let fleet = Fleet {
+        id: 3,
+        totals: Totals {
+            hosts: 2,
+            metrics: Metrics {
+                cpu: 1.5,
+                memory: 8,
+            },
+        },
+    };
+    let json = to_string(&fleet).unwrap();
+    assert_eq!(json, r#"{"id":3,"3.hosts":2,"3.cpu":1.5,"3.mem":8}"#);
+
+    let parsed: Fleet = from_str(&json).unwrap();
+    assert_eq!(parsed, fleet);
+}
+
+#[test]
+fn numeric_prefix_must_match_the_source() {
+    let err = from_str::<Fleet>(r#"{"id":3,"4.hosts":2,"4.cpu":1.5,"4.mem":8}"#).unwrap_err();
+    assert!(err.to_string().contains("'4'"), "{err}");
+
+    // The source is compared by its display form, not its JSON spelling
+    let parsed: Fleet = from_str(r#"{"id":3,"3.hosts":2,"3.cpu":1.5,"3.mem":8}"#).unwrap();
+    assert_eq!(parsed.id, 3);
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Pair {
+    host: String,
+    #[facet(flatten, flatten_prefix_from = "host")]
+    local: Metrics,
+    region: String,
+    #[facet(flatten, flatten_prefix_from = "region")]
+    remote: Metrics,
+}
+
+#[test]
+fn groups_of_the_same_type_are_told_apart_by_prefix() {
+    let pair = Pair {
+        host: "web1".to_string(),
+        local: Metrics {
+            cpu: 0.5,
+            memory: 2048,
+        },
+        region: "eu".to_string(),
+        remote: Metrics {
+            cpu: 1.5,
+            memory: 4096,
+        },
+    };
+    let json = to_string(&pair).unwrap();
+    assert_eq!(
+        json,
+        r#"{"host":"web1","web1.cpu":0.5,"web1.mem":2048,"region":"eu","eu.cpu":1.5,"eu.mem":4096}"#
+    );
+    assert_eq!(from_str::<Pair>(&json).unwrap(), pair);
+
+    // Keys before their sources are assigned in order, then checked
+    let parsed: Pair = from_str(
+        r#"{"web1.cpu":0.5,"eu.cpu":1.5,"web1.mem":2048,"eu.mem":4096,"host":"web1","region":"eu"}"#,
+    )
+    .unwrap();
+    assert_eq!(parsed, pair);
+
+    // Sources first: keys go to the group with the matching prefix
+    let parsed: Pair = from_str(
+        r#"{"host":"web1","region":"eu","eu.cpu":1.5,"eu.mem":4096,"web1.cpu":0.5,"web1.mem":2048}"#,
+    )
+    .unwrap();
+    assert_eq!(parsed, pair);
+}
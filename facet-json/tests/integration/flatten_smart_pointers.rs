@@ -0,0 +1,101 @@
+//! `#[facet(flatten)]` through `Box<T>`, `Arc<T>` and `Option<Arc<T>>`.
+
+use std::sync::Arc;
+
+use facet::Facet;
+use facet_testhelpers::test;
+
+#[derive(Facet, Debug, PartialEq)]
+struct Coords {
+    lat: f64,
+    lon: f64,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct BoxedPlace {
+    name: String,
+    #[facet(flatten)]
+    coords: Box<Coords>,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct SharedPlace {
+    name: String,
+    #[facet(flatten)]
+    coords: Arc<Coords>,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct MaybePlace {
+    name: String,
+    #[facet(flatten)]
+    coords: Option<Arc<Coords>>,
+}
+
+#[test]
+fn flatten_box_round_trip() {
+    let place = BoxedPlace {
+        name: "home".into(),
+        coords: Box::new(Coords { lat: 1.5, lon: 2.5 }),
+    };
+    let json = facet_json::to_string(&place).unwrap();
+    assert_eq!(json, r#"{"name":"home","lat":1.5,"lon":2.5}"#);
+    assert_eq!(facet_json::from_str::<BoxedPlace>(&json).unwrap(), place);
+}
+
+#[test]
+fn flatten_arc_round_trip() {
+    let place = SharedPlace {
+        name: "home".into(),
+        coords: Arc::new(Coords { lat: 1.5, lon: 2.5 }),
+    };
+    let json = facet_json::to_string(&place).unwrap();
+    assert_eq!(json, r#"{"name":"home","lat":1.5,"lon":2.5}"#);
+    assert_eq!(facet_json::from_str::<SharedPlace>(&json).unwrap(), place);
+}
+
+#[test]
+fn flatten_option_arc_round_trip() {
+    let place = MaybePlace {
+        name: "home".into(),
+        coords: Some(Arc::new(Coords { lat: 1.5, lon: 2.5 })),
+    };
+    let json = facet_json::to_string(&place).unwrap();
+    assert_eq!(json, r#"{"name":"home","lat":1.5,"lon":2.5}"#);
+    assert_eq!(facet_json::from_str::<MaybePlace>(&json).unwrap(), place);
+}
+
+#[test]
+fn flatten_option_arc_none_round_trip() {
+    let place = MaybePlace {
+        name: "nowhere".into(),
+        coords: None,
+    };
+    let json = facet_json::to_string(&place).unwrap();
+    assert_eq!(json, r#"{"name":"nowhere"}"#);
+    assert_eq!(facet_json::from_str::<MaybePlace>(&json).unwrap(), place);
+}
+
+#[test]
+fn flatten_arc_fields_interleaved_with_parent_fields() {
+    let place: SharedPlace =
+        facet_json::from_str(r#"{"lat":1.5,"name":"home","lon":2.5}"#).unwrap();
+    assert_eq!(
+        place,
+        SharedPlace {
+            name: "home".into(),
+            coords: Arc::new(Coords { lat: 1.5, lon: 2.5 }),
+        }
+    );
+}
+
+#[test]
+fn flatten_target_unwraps_options_and_pointers() {
+    let (shape, optional) = <Option<Arc<Coords>>>::SHAPE.flatten_target();
+    assert_eq!(shape, Coords::SHAPE);
+    assert!(optional);
+
+    let (shape, optional) = <Box<Coords>>::SHAPE.flatten_target();
+    assert_eq!(shape, Coords::SHAPE);
+    assert!(!optional);
+}
@@ -267,3 +267,48 @@ fn test_scalar_newtype_variant_errors() {
         "Error message should mention the issue and suggest using content attribute: {err}"
     );
 }
+
+#[test]
+fn test_deserialize_flattened_enum_with_renamed_tag() {
+    #[derive(Facet, Debug, PartialEq)]
+    #[facet(tag = "kind")]
+    #[repr(C)]
+    pub enum Transport {
+        #[facet(rename = "tcp")]
+        Tcp { host: String, port: u16 },
+        #[facet(rename = "unix")]
+        Unix { path: String },
+    }
+
+    #[derive(Facet, Debug, PartialEq)]
+    pub struct Listener {
+        pub name: String,
+        #[facet(flatten)]
+        pub transport: Transport,
+    }
+
+    let json = r#"{"name":"api","kind":"tcp","host":"localhost","port":8080}"#;
+    let parsed: Listener = from_json(json).expect("Failed to deserialize JSON");
+    assert_eq!(
+        parsed.transport,
+        Transport::Tcp {
+            host: "localhost".to_string(),
+            port: 8080
+        }
+    );
+    assert_eq!(to_string(&parsed).unwrap(), json);
+
+    let parsed: Listener = from_json(r#"{"kind":"unix","path":"/run/api.sock","name":"api"}"#)
+        .expect("Failed to deserialize JSON");
+    assert_eq!(
+        parsed.transport,
+        Transport::Unix {
+            path: "/run/api.sock".to_string()
+        }
+    );
+
+    // The Rust variant name is not a valid tag value once renamed.
+    assert!(
+        from_json::<Listener>(r#"{"name":"api","kind":"Unix","path":"/run/api.sock"}"#).is_err()
+    );
+}
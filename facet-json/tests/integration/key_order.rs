@@ -0,0 +1,83 @@
+//! Tests for opt-in key order preservation on round trips.
+
+use facet::Facet;
+use facet_json::{
+    KeyOrder, SerializeOptions, from_str_with_key_order, to_string, to_string_with_options,
+};
+use facet_testhelpers::test;
+use std::collections::HashMap;
+
+#[derive(Facet, Debug, PartialEq)]
+struct Server {
+    host: String,
+    port: u16,
+    #[facet(flatten)]
+    extra: HashMap<String, String>,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Config {
+    name: String,
+    servers: Vec<Server>,
+    #[facet(default)]
+    enabled: bool,
+}
+
+#[test]
+fn round_trip_is_byte_stable() {
+    let json = r#"{"servers":[{"zone":"eu","port":80,"host":"a","rack":"r1"}],"name":"prod","enabled":true}"#;
+    let (config, order): (Config, _) = from_str_with_key_order(json).unwrap();
+
+    let options = SerializeOptions::default().preserve_key_order(order);
+    assert_eq!(to_string_with_options(&config, &options).unwrap(), json);
+}
+
+#[test]
+fn default_serialization_is_unaffected() {
+    let json = r#"{"servers":[],"name":"prod","enabled":false}"#;
+    let (config, _order): (Config, _) = from_str_with_key_order(json).unwrap();
+    assert_eq!(
+        to_string(&config).unwrap(),
+        r#"{"name":"prod","servers":[],"enabled":false}"#
+    );
+}
+
+#[test]
+fn new_keys_come_after_recorded_ones() {
+    let json = r#"{"servers":[],"name":"prod"}"#;
+    let (config, order): (Config, _) = from_str_with_key_order(json).unwrap();
+
+    let options = SerializeOptions::default().preserve_key_order(order);
+    assert_eq!(
+        to_string_with_options(&config, &options).unwrap(),
+        r#"{"servers":[],"name":"prod","enabled":false}"#
+    );
+}
+
+#[test]
+fn pretty_output_keeps_indentation() {
+    let json = "{\n  \"port\": 8080,\n  \"host\": \"localhost\"\n}";
+    let (server, order): (Server, _) = from_str_with_key_order(json).unwrap();
+
+    let options = SerializeOptions::default()
+        .pretty()
+        .preserve_key_order(order);
+    assert_eq!(to_string_with_options(&server, &options).unwrap(), json);
+}
+
+#[test]
+fn manual_order_by_path() {
+    let server = Server {
+        host: "localhost".into(),
+        port: 8080,
+        extra: HashMap::new(),
+    };
+    let mut order = KeyOrder::new();
+    order.insert("", vec!["port".into(), "host".into()]);
+
+    let options = SerializeOptions::default().preserve_key_order(order);
+    assert_eq!(
+        to_string_with_options(&server, &options).unwrap(),
+        r#"{"port":8080,"host":"localhost"}"#
+    );
+}
@@ -2,8 +2,14 @@
 pub(crate) mod json_backend;
 
 mod bstr;
+mod conversions;
+mod cycles;
+mod defaults_for_children;
+mod field_order;
 mod flatten_defaults;
 mod flatten_in_externally_tagged_enum;
+mod flatten_prefix;
+mod flatten_smart_pointers;
 mod format_specific_proxy;
 mod int_map_keys;
 mod issue_1236;
@@ -28,6 +34,7 @@ mod issue_2264;
 mod issue_2341_newtype_as_json_object_key;
 mod issue_2342_json_facet_other_deep;
 mod issue_2363_bool_string_number_field_proxy;
+mod key_order;
 mod list_deferred_processing;
 mod metadata_container_flatten_map;
 mod mixed_tagged_untagged;
@@ -38,6 +45,7 @@ mod opaque_proxy_enum;
 mod opaque_proxy_struct;
 mod option_enum_test;
 mod out_of_order;
+mod parse_metrics;
 mod raw_json;
 mod recursive_stack;
 mod rename;
@@ -45,5 +53,8 @@ mod smolstr_flatten_map;
 mod string_like_map_keys;
 mod tagging;
 mod tendril;
+mod trailing;
+mod untagged_buffered;
 mod weavy_deser;
 mod weavy_oracle_fuzz;
+mod wrapper_chains;
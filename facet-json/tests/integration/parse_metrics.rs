@@ -0,0 +1,68 @@
+use facet::Facet;
+use facet_json::{from_slice_with_metrics, from_str_with_metrics};
+use facet_testhelpers::test;
+
+#[derive(Facet, Debug, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Path {
+    name: String,
+    points: Vec<Point>,
+}
+
+#[test]
+fn counts_nodes_depth_and_bytes() {
+    let json = r#"{"name":"a\"b","points":[{"x":1,"y":2},{"x":3,"y":4}]}"#;
+    let (path, metrics) = from_str_with_metrics::<Path>(json).unwrap();
+    assert_eq!(path.points.len(), 2);
+
+    assert_eq!(metrics.bytes_read, json.len());
+    // root, name, points, two points with two fields each
+    assert_eq!(metrics.nodes_created, 9);
+    assert_eq!(metrics.max_depth, 3);
+    // the one string
+    assert_eq!(metrics.allocations, 1);
+}
+
+#[test]
+fn slices_report_the_same_metrics() {
+    let json = r#"{"name":"p","points":[]}"#;
+    let (_, from_str) = from_str_with_metrics::<Path>(json).unwrap();
+    let (_, from_slice) = from_slice_with_metrics::<Path>(json.as_bytes()).unwrap();
+    assert_eq!(from_str, from_slice);
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Base {
+    id: u32,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Flattened {
+    #[facet(flatten)]
+    base: Base,
+    label: String,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+#[facet(untagged)]
+#[repr(u8)]
+enum Figure {
+    Circle { radius: u32 },
+    Square { side: u32 },
+}
+
+#[test]
+fn read_ahead_is_counted_once() {
+    let (_, flat) = from_str_with_metrics::<Flattened>(r#"{"id":1,"label":"x"}"#).unwrap();
+    assert_eq!(flat.nodes_created, 3);
+    assert_eq!(flat.max_depth, 1);
+
+    let (figure, untagged) = from_str_with_metrics::<Figure>(r#"{"side":2}"#).unwrap();
+    assert_eq!(figure, Figure::Square { side: 2 });
+    assert_eq!(untagged.nodes_created, 2);
+}
@@ -206,6 +206,62 @@ fn raw_json_into_owned() {
     assert_eq!(owned.as_str(), r#"{"key": "value"}"#);
 }
 
+#[test]
+fn raw_json_passes_through_verbatim() {
+    #[derive(Facet, Debug)]
+    struct Envelope<'a> {
+        id: u32,
+        params: RawJson<'a>,
+    }
+
+    let json = r#"{"id": 7, "params": [1,  2.50, {"b": 1, "a": null}]}"#;
+    let envelope: Envelope = from_str_borrowed(json).unwrap();
+    assert_eq!(
+        to_string(&envelope).unwrap(),
+        r#"{"id":7,"params":[1,  2.50, {"b": 1, "a": null}]}"#
+    );
+}
+
+// ── Lazy parsing tests ──
+
+#[test]
+fn raw_json_parses_later() {
+    #[derive(Facet, Debug)]
+    struct Request<'a> {
+        method: String,
+        params: RawJson<'a>,
+    }
+
+    #[derive(Facet, Debug, PartialEq)]
+    struct Greet<'a> {
+        name: &'a str,
+        times: u8,
+    }
+
+    let json = r#"{"method": "greet", "params": {"name": "world", "times": 2}}"#;
+    let request: Request = from_str_borrowed(json).unwrap();
+    assert_eq!(request.method, "greet");
+
+    let greet: Greet = request.params.parse_borrowed().unwrap();
+    assert_eq!(
+        greet,
+        Greet {
+            name: "world",
+            times: 2
+        }
+    );
+
+    #[derive(Facet, Debug, PartialEq)]
+    struct OwnedGreet {
+        name: String,
+        times: u8,
+    }
+
+    let owned: OwnedGreet = request.params.parse().unwrap();
+    assert_eq!(owned.name, "world");
+    assert!(request.params.parse::<Vec<u32>>().is_err());
+}
+
 // ── Top-level RawJson tests ──
 
 #[test]
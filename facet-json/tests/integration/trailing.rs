@@ -0,0 +1,81 @@
+//! Tests for reading several values from one buffer and rejecting trailing data.
+
+use facet::Facet;
+use facet_json::{
+    from_slice_strict, from_slice_with_remainder, from_str, from_str_strict,
+    from_str_with_remainder,
+};
+use facet_testhelpers::test;
+
+#[derive(Facet, Debug, PartialEq)]
+struct Event {
+    kind: String,
+    tags: Vec<String>,
+}
+
+#[test]
+fn remainder_starts_right_after_the_value() {
+    let input = r#"{"kind":"a","tags":["x"]}  {"kind":"b","tags":[]}"#;
+    let (first, rest) = from_str_with_remainder::<Event>(input).unwrap();
+    assert_eq!(first.kind, "a");
+    assert_eq!(rest, r#"  {"kind":"b","tags":[]}"#);
+
+    let (second, rest) = from_str_with_remainder::<Event>(rest).unwrap();
+    assert_eq!(second.kind, "b");
+    assert_eq!(rest, "");
+}
+
+#[test]
+fn concatenated_values_without_separators() {
+    let mut input: &[u8] = br#"[1,2][3]"hi"true null 42"#;
+
+    let (numbers, rest) = from_slice_with_remainder::<Vec<u32>>(input).unwrap();
+    assert_eq!(numbers, [1, 2]);
+    input = rest;
+    let (numbers, rest) = from_slice_with_remainder::<Vec<u32>>(input).unwrap();
+    assert_eq!(numbers, [3]);
+    input = rest;
+    let (text, rest) = from_slice_with_remainder::<String>(input).unwrap();
+    assert_eq!(text, "hi");
+    input = rest;
+    let (flag, rest) = from_slice_with_remainder::<bool>(input).unwrap();
+    assert!(flag);
+    input = rest;
+    let (nothing, rest) = from_slice_with_remainder::<Option<u8>>(input).unwrap();
+    assert_eq!(nothing, None);
+    let (answer, rest) = from_slice_with_remainder::<u64>(rest).unwrap();
+    assert_eq!(answer, 42);
+    assert!(rest.is_empty());
+}
+
+#[test]
+fn remainder_keeps_non_ascii_text() {
+    let (value, rest) = from_str_with_remainder::<String>("\"é\"ü").unwrap();
+    assert_eq!(value, "é");
+    assert_eq!(rest, "ü");
+}
+
+#[test]
+fn strict_rejects_trailing_data() {
+    assert_eq!(from_str_strict::<u32>(" 7 \r\n\t").unwrap(), 7);
+    assert_eq!(from_slice_strict::<u32>(b"7\n").unwrap(), 7);
+
+    let err = from_str_strict::<Event>(r#"{"kind":"a","tags":[]} x"#).unwrap_err();
+    assert!(err.to_string().contains("'x'"), "{err}");
+    let span = err.span.unwrap();
+    assert_eq!((span.offset, span.len), (23, 1));
+
+    assert!(from_slice_strict::<Vec<u8>>(b"[1][2]").is_err());
+    assert!(from_str_strict::<String>("\"a\"\u{a0}").is_err());
+}
+
+#[test]
+fn default_functions_still_ignore_trailing_data() {
+    assert_eq!(from_str::<u32>("1 2").unwrap(), 1);
+}
+
+#[test]
+fn errors_in_the_value_are_reported_as_usual() {
+    assert!(from_str_with_remainder::<Event>(r#"{"kind":1} {}"#).is_err());
+    assert!(from_str_with_remainder::<u32>("   ").is_err());
+}
@@ -0,0 +1,74 @@
+//! Untagged enums resolve the same whether the distinguishing key is found in
+//! the deserializer's event buffer or by reading ahead past it.
+
+use facet::Facet;
+use facet_format::FormatDeserializer;
+use facet_json::JsonParser;
+use facet_testhelpers::test;
+
+#[derive(Debug, Facet, PartialEq)]
+#[facet(untagged)]
+#[repr(u8)]
+enum Shape {
+    Circle { id: u32, radius: f64 },
+    Rect { id: u32, width: f64, height: f64 },
+    Label { id: u32, text: String },
+}
+
+#[derive(Debug, Facet, PartialEq)]
+#[facet(untagged)]
+#[repr(u8)]
+enum Value {
+    Count { value: u64 },
+    Name { value: String },
+}
+
+fn from_str_with_capacity<T: Facet<'static>>(input: &str, capacity: usize) -> T {
+    let mut parser = JsonParser::<true>::new(input.as_bytes());
+    let mut de = FormatDeserializer::with_buffer_capacity_owned(&mut parser, capacity);
+    de.deserialize_root().unwrap()
+}
+
+#[test]
+fn distinguishing_key_after_shared_keys() {
+    let input = r#"[
+        {"id": 1, "radius": 2.5},
+        {"id": 2, "width": 3.0, "height": 4.0},
+        {"id": 3, "text": "hi"},
+        {"id": 4, "radius": 1.0}
+    ]"#;
+    let expected = vec![
+        Shape::Circle { id: 1, radius: 2.5 },
+        Shape::Rect {
+            id: 2,
+            width: 3.0,
+            height: 4.0,
+        },
+        Shape::Label {
+            id: 3,
+            text: "hi".into(),
+        },
+        Shape::Circle { id: 4, radius: 1.0 },
+    ];
+
+    for capacity in [1, 2, 3, 512] {
+        let shapes: Vec<Shape> = from_str_with_capacity(input, capacity);
+        assert_eq!(shapes, expected, "buffer capacity {capacity}");
+    }
+}
+
+#[test]
+fn shared_key_resolved_by_value_type() {
+    let input = r#"[{"value": 7}, {"value": "seven"}]"#;
+    let expected = vec![
+        Value::Count { value: 7 },
+        Value::Name {
+            value: "seven".into(),
+        },
+    ];
+
+    for capacity in [1, 2, 512] {
+        let values: Vec<Value> = from_str_with_capacity(input, capacity);
+        assert_eq!(values, expected, "buffer capacity {capacity}");
+    }
+}
@@ -0,0 +1,296 @@
+//! Newtype chains mixing `#[facet(transparent)]` and proxies, nested inside
+//! options and smart pointers, as values and as object keys.
+//!
+//! Each hop must be taken in order, outermost first: a transparent newtype
+//! over a proxied field must not skip the field's proxy, and a proxy whose
+//! proxy type is a transparent newtype must not skip the newtype.
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::num::{NonZeroU32, ParseIntError};
+use std::rc::Rc;
+use std::sync::Arc;
+
+use facet::Facet;
+use facet_testhelpers::test;
+
+/// Written as a `0x`-prefixed hex string.
+#[derive(Facet, Debug, Clone, PartialEq)]
+#[facet(transparent)]
+struct HexProxy(String);
+
+fn parse_hex(proxy: HexProxy) -> Result<u32, ParseIntError> {
+    u32::from_str_radix(proxy.0.trim_start_matches("0x"), 16)
+}
+
+/// Container proxy, whose proxy is a transparent newtype.
+#[derive(Facet, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[facet(proxy = HexProxy)]
+struct Hex(u32);
+
+impl TryFrom<HexProxy> for Hex {
+    type Error = ParseIntError;
+    fn try_from(proxy: HexProxy) -> Result<Self, Self::Error> {
+        parse_hex(proxy).map(Hex)
+    }
+}
+
+impl From<&Hex> for HexProxy {
+    fn from(hex: &Hex) -> Self {
+        HexProxy(format!("0x{:x}", hex.0))
+    }
+}
+
+/// Transparent newtype over a field proxy.
+#[derive(Facet, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[facet(transparent)]
+struct Code(#[facet(proxy = HexProxy)] u32);
+
+impl TryFrom<HexProxy> for u32 {
+    type Error = ParseIntError;
+    fn try_from(proxy: HexProxy) -> Result<Self, Self::Error> {
+        parse_hex(proxy)
+    }
+}
+
+impl From<&u32> for HexProxy {
+    fn from(value: &u32) -> Self {
+        HexProxy(format!("0x{value:x}"))
+    }
+}
+
+/// Transparent over transparent over a field proxy.
+#[derive(Facet, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[facet(transparent)]
+struct Wrapped(Code);
+
+/// Transparent over a container proxy over a transparent newtype.
+#[derive(Facet, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[facet(transparent)]
+struct WrappedHex(Hex);
+
+/// Plain field type, read through a `NonZeroU32` proxy.
+#[derive(Facet, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Slot(u32);
+
+impl From<NonZeroU32> for Slot {
+    fn from(proxy: NonZeroU32) -> Self {
+        Slot(proxy.get())
+    }
+}
+
+impl TryFrom<&Slot> for NonZeroU32 {
+    type Error = &'static str;
+    fn try_from(slot: &Slot) -> Result<Self, Self::Error> {
+        NonZeroU32::new(slot.0).ok_or("slot 0 is reserved")
+    }
+}
+
+/// Transparent newtype over a field proxy whose proxy type is itself a
+/// transparent conversion (`NonZeroU32` from `u32`).
+#[derive(Facet, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[facet(transparent)]
+struct SlotId(#[facet(proxy = NonZeroU32)] Slot);
+
+#[derive(Facet, Debug, PartialEq)]
+struct Values {
+    hex: Hex,
+    code: Code,
+    wrapped: Wrapped,
+    wrapped_hex: WrappedHex,
+    some_wrapped: Option<Wrapped>,
+    none_wrapped_hex: Option<WrappedHex>,
+    boxed: Box<WrappedHex>,
+    shared: Arc<Code>,
+    counted: Rc<Wrapped>,
+    some_boxed: Option<Box<Code>>,
+    list: Vec<Option<Wrapped>>,
+}
+
+const VALUES: &str = concat!(
+    r#"{"hex":"0xff","code":"0x10","wrapped":"0x20","wrapped_hex":"0x30","#,
+    r#""some_wrapped":"0x40","none_wrapped_hex":null,"boxed":"0x50","shared":"0x60","#,
+    r#""counted":"0x70","some_boxed":"0x80","list":["0x1",null,"0x2"]}"#,
+);
+
+fn values() -> Values {
+    Values {
+        hex: Hex(0xff),
+        code: Code(0x10),
+        wrapped: Wrapped(Code(0x20)),
+        wrapped_hex: WrappedHex(Hex(0x30)),
+        some_wrapped: Some(Wrapped(Code(0x40))),
+        none_wrapped_hex: None,
+        boxed: Box::new(WrappedHex(Hex(0x50))),
+        shared: Arc::new(Code(0x60)),
+        counted: Rc::new(Wrapped(Code(0x70))),
+        some_boxed: Some(Box::new(Code(0x80))),
+        list: vec![Some(Wrapped(Code(1))), None, Some(Wrapped(Code(2)))],
+    }
+}
+
+#[test]
+fn every_hop_is_taken_for_values() {
+    let parsed: Values = facet_json::from_str(VALUES).unwrap();
+    assert_eq!(parsed, values());
+}
+
+#[test]
+fn every_hop_is_taken_when_serializing() {
+    assert_eq!(facet_json::to_string(&values()).unwrap(), VALUES);
+}
+
+#[test]
+fn top_level_chains() {
+    let wrapped: Wrapped = facet_json::from_str(r#""0xab""#).unwrap();
+    assert_eq!(wrapped, Wrapped(Code(0xab)));
+
+    let wrapped_hex: Option<Box<WrappedHex>> = facet_json::from_str(r#""0xcd""#).unwrap();
+    assert_eq!(wrapped_hex, Some(Box::new(WrappedHex(Hex(0xcd)))));
+
+    let none: Option<Arc<Wrapped>> = facet_json::from_str("null").unwrap();
+    assert_eq!(none, None);
+}
+
+#[test]
+fn proxy_errors_surface_through_the_chain() {
+    let err = facet_json::from_str::<Wrapped>(r#""0xzz""#).unwrap_err();
+    assert!(err.to_string().contains("invalid digit"), "{err}");
+}
+
+#[test]
+fn every_hop_is_taken_for_object_keys() {
+    let by_code: BTreeMap<Wrapped, u32> = facet_json::from_str(r#"{"0x1":1,"0xa":10}"#).unwrap();
+    assert_eq!(
+        by_code,
+        BTreeMap::from([(Wrapped(Code(1)), 1), (Wrapped(Code(10)), 10)])
+    );
+
+    let by_hex: BTreeMap<WrappedHex, Option<Box<Code>>> =
+        facet_json::from_str(r#"{"0xff":"0x1","0x0":null}"#).unwrap();
+    assert_eq!(
+        by_hex,
+        BTreeMap::from([
+            (WrappedHex(Hex(0xff)), Some(Box::new(Code(1)))),
+            (WrappedHex(Hex(0)), None),
+        ])
+    );
+}
+
+#[test]
+fn transparent_conversions_under_a_field_proxy_are_checked() {
+    let slot: Option<Arc<SlotId>> = facet_json::from_str("7").unwrap();
+    assert_eq!(slot, Some(Arc::new(SlotId(Slot(7)))));
+
+    // `NonZeroU32`'s own conversion runs before the field proxy's
+    assert!(facet_json::from_str::<Option<Arc<SlotId>>>("0").is_err());
+    assert!(facet_json::to_string(&SlotId(Slot(0))).is_err());
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Holder<T> {
+    value: T,
+}
+
+/// Round-trip `value` through `json`, on its own and as a struct field.
+fn round_trip<T>(value: T, json: &str)
+where
+    T: Facet<'static> + Debug + PartialEq,
+{
+    assert_eq!(facet_json::to_string(&value).unwrap(), json);
+    assert_eq!(facet_json::from_str::<T>(json).unwrap(), value);
+
+    let held = Holder { value };
+    let json = format!(r#"{{"value":{json}}}"#);
+    assert_eq!(facet_json::to_string(&held).unwrap(), json);
+    assert_eq!(facet_json::from_str::<Holder<T>>(&json).unwrap(), held);
+}
+
+/// For each chain, every combination of `Option` and pointer around it.
+macro_rules! wrapper_matrix {
+    ($($chain:ident: $ty:ty = $value:expr, $json:literal;)*) => {$(
+        mod $chain {
+            use super::*;
+
+            fn value() -> $ty {
+                $value
+            }
+
+            #[test]
+            fn bare() {
+                round_trip(value(), $json);
+            }
+
+            #[test]
+            fn option() {
+                round_trip(Some(value()), $json);
+                round_trip(None::<$ty>, "null");
+            }
+
+            #[test]
+            fn boxed() {
+                round_trip(Box::new(value()), $json);
+            }
+
+            #[test]
+            fn arc() {
+                round_trip(Arc::new(value()), $json);
+            }
+
+            #[test]
+            fn rc() {
+                round_trip(Rc::new(value()), $json);
+            }
+
+            #[test]
+            fn option_of_box() {
+                round_trip(Some(Box::new(value())), $json);
+                round_trip(None::<Box<$ty>>, "null");
+            }
+
+            #[test]
+            fn option_of_arc() {
+                round_trip(Some(Arc::new(value())), $json);
+                round_trip(None::<Arc<$ty>>, "null");
+            }
+
+            #[test]
+            fn box_of_option() {
+                round_trip(Box::new(Some(value())), $json);
+                round_trip(Box::new(None::<$ty>), "null");
+            }
+
+            #[test]
+            fn arc_of_option() {
+                round_trip(Arc::new(Some(value())), $json);
+                round_trip(Arc::new(None::<$ty>), "null");
+            }
+
+            #[test]
+            fn rc_of_option_of_box() {
+                round_trip(Rc::new(Some(Box::new(value()))), $json);
+                round_trip(Rc::new(None::<Box<$ty>>), "null");
+            }
+
+            #[test]
+            fn list_of_options() {
+                let json = concat!("[", $json, ",null]");
+                round_trip(vec![Some(value()), None], json);
+                round_trip(vec![Some(Arc::new(value())), None], json);
+            }
+        }
+    )*};
+}
+
+wrapper_matrix! {
+    // Container proxy whose proxy is transparent
+    hex: Hex = Hex(0xab), r#""0xab""#;
+    // Transparent over a field proxy
+    code: Code = Code(0xab), r#""0xab""#;
+    // Transparent over transparent over a field proxy
+    wrapped: Wrapped = Wrapped(Code(0xab)), r#""0xab""#;
+    // Transparent over a container proxy over a transparent newtype
+    wrapped_hex: WrappedHex = WrappedHex(Hex(0xab)), r#""0xab""#;
+    // Transparent over a field proxy over `NonZeroU32`
+    slot_id: SlotId = SlotId(Slot(7)), "7";
+}
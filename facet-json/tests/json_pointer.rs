@@ -0,0 +1,462 @@
+//! JSON Pointer get/set over typed values.
+
+use std::collections::HashMap;
+
+use facet::Facet;
+use facet_json::JsonPointerError;
+use facet_json::json_pointer::{get, set};
+use facet_reflect::{Peek, Poke};
+use facet_testhelpers::test;
+
+#[derive(Facet, Debug, PartialEq)]
+struct Config {
+    name: String,
+    #[facet(rename = "log-level")]
+    log_level: String,
+    timeout: Option<u32>,
+    servers: Vec<Server>,
+    labels: HashMap<String, String>,
+    backend: Backend,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Server {
+    host: String,
+    port: u16,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Backend {
+    Memory,
+    Disk { path: String },
+    Remote(Server),
+}
+
+fn config() -> Config {
+    Config {
+        name: "api".into(),
+        log_level: "info".into(),
+        timeout: None,
+        servers: vec![
+            Server {
+                host: "a".into(),
+                port: 80,
+            },
+            Server {
+                host: "b".into(),
+                port: 81,
+            },
+        ],
+        labels: HashMap::from([
+            ("a/b".into(), "slash".into()),
+            ("m~n".into(), "tilde".into()),
+        ]),
+        backend: Backend::Disk {
+            path: "/var/data".into(),
+        },
+    }
+}
+
+#[test]
+fn get_nested_values() {
+    let config = config();
+    let root = Peek::new(&config);
+
+    assert_eq!(get(root, "").unwrap().shape(), Config::SHAPE);
+    assert_eq!(get(root, "/name").unwrap().as_str(), Some("api"));
+    assert_eq!(get(root, "/log-level").unwrap().as_str(), Some("info"));
+    assert_eq!(
+        get(root, "/servers/1/port").unwrap().get::<u16>().unwrap(),
+        &81
+    );
+    assert_eq!(get(root, "/labels/a~1b").unwrap().as_str(), Some("slash"));
+    assert_eq!(get(root, "/labels/m~0n").unwrap().as_str(), Some("tilde"));
+    assert_eq!(
+        get(root, "/backend/Disk/path").unwrap().as_str(),
+        Some("/var/data")
+    );
+}
+
+#[test]
+fn get_looks_through_options_and_newtype_variants() {
+    #[derive(Facet)]
+    struct Outer {
+        inner: Option<Server>,
+        backend: Backend,
+    }
+
+    let outer = Outer {
+        inner: Some(Server {
+            host: "h".into(),
+            port: 1,
+        }),
+        backend: Backend::Remote(Server {
+            host: "r".into(),
+            port: 2,
+        }),
+    };
+    let root = Peek::new(&outer);
+    assert_eq!(get(root, "/inner/host").unwrap().as_str(), Some("h"));
+    assert_eq!(
+        get(root, "/backend/Remote/port")
+            .unwrap()
+            .get::<u16>()
+            .unwrap(),
+        &2
+    );
+}
+
+#[test]
+fn get_reports_missing_values() {
+    let config = config();
+    let root = Peek::new(&config);
+
+    assert!(matches!(
+        get(root, "/servers/5/port"),
+        Err(JsonPointerError::NotFound { path }) if path == "/servers/5"
+    ));
+    assert!(matches!(
+        get(root, "/nope"),
+        Err(JsonPointerError::NotFound { path }) if path == "/nope"
+    ));
+    assert!(matches!(
+        get(root, "/timeout"),
+        Ok(peek) if peek.shape() == <Option<u32>>::SHAPE
+    ));
+    assert!(matches!(
+        get(root, "/timeout/x"),
+        Err(JsonPointerError::NotFound { .. })
+    ));
+    assert!(matches!(
+        get(root, "/servers/01"),
+        Err(JsonPointerError::InvalidIndex { path }) if path == "/servers/01"
+    ));
+    assert!(matches!(
+        get(root, "/name/0"),
+        Err(JsonPointerError::NotAContainer { path, .. }) if path == "/name"
+    ));
+    assert!(matches!(
+        get(root, "name"),
+        Err(JsonPointerError::Syntax { .. })
+    ));
+    assert!(matches!(
+        get(root, "/labels/~2"),
+        Err(JsonPointerError::Syntax { .. })
+    ));
+}
+
+#[test]
+fn set_overwrites_in_place() {
+    let mut config = config();
+
+    set(Poke::new(&mut config), "/servers/0/port", 8080u16).unwrap();
+    set(Poke::new(&mut config), "/log-level", String::from("debug")).unwrap();
+    set(
+        Poke::new(&mut config),
+        "/backend/Disk/path",
+        String::from("/tmp"),
+    )
+    .unwrap();
+
+    assert_eq!(config.servers[0].port, 8080);
+    assert_eq!(config.log_level, "debug");
+    assert_eq!(
+        config.backend,
+        Backend::Disk {
+            path: "/tmp".into()
+        }
+    );
+}
+
+#[test]
+fn set_fills_options_and_grows_containers() {
+    let mut config = config();
+
+    set(Poke::new(&mut config), "/timeout", 30u32).unwrap();
+    set(
+        Poke::new(&mut config),
+        "/servers/-",
+        Server {
+            host: "c".into(),
+            port: 82,
+        },
+    )
+    .unwrap();
+    set(Poke::new(&mut config), "/labels/env", String::from("prod")).unwrap();
+    set(Poke::new(&mut config), "/labels/a~1b", String::from("new")).unwrap();
+
+    assert_eq!(config.timeout, Some(30));
+    assert_eq!(config.servers.len(), 3);
+    assert_eq!(config.servers[2].host, "c");
+    assert_eq!(config.labels["env"], "prod");
+    assert_eq!(config.labels["a/b"], "new");
+}
+
+#[test]
+fn set_replaces_whole_subtrees() {
+    let mut config = config();
+    set(Poke::new(&mut config), "/backend", Backend::Memory).unwrap();
+    assert_eq!(config.backend, Backend::Memory);
+}
+
+#[test]
+fn set_rejects_mismatched_values() {
+    let mut config = config();
+
+    assert!(matches!(
+        set(Poke::new(&mut config), "/servers/0/port", 8080u32),
+        Err(JsonPointerError::WrongShape { path, .. }) if path == "/servers/0/port"
+    ));
+    assert!(matches!(
+        set(Poke::new(&mut config), "/servers/9/port", 1u16),
+        Err(JsonPointerError::NotFound { path }) if path == "/servers/9"
+    ));
+    assert!(matches!(
+        set(Poke::new(&mut config), "/servers/-/port", 1u16),
+        Err(JsonPointerError::InvalidIndex { .. })
+    ));
+    assert_eq!(config.servers[0].port, 80);
+}
+
+#[test]
+fn enums_are_addressed_by_their_tagging() {
+    #[derive(Facet, Debug, PartialEq)]
+    #[facet(tag = "type")]
+    #[repr(u8)]
+    #[allow(dead_code)]
+    enum Internal {
+        Start { at: u32 },
+        Stop,
+    }
+
+    #[derive(Facet, Debug, PartialEq)]
+    #[facet(tag = "t", content = "c")]
+    #[repr(u8)]
+    #[allow(dead_code)]
+    enum Adjacent {
+        Move { x: i32 },
+        Wait(u32),
+    }
+
+    #[derive(Facet, Debug, PartialEq)]
+    #[facet(untagged)]
+    #[repr(u8)]
+    #[allow(dead_code)]
+    enum Untagged {
+        Point { x: i32, y: i32 },
+        Label(String),
+    }
+
+    #[derive(Facet, Debug, PartialEq)]
+    struct Events {
+        backend: Backend,
+        internal: Internal,
+        adjacent: Adjacent,
+        untagged: Untagged,
+    }
+
+    let mut events = Events {
+        backend: Backend::Memory,
+        internal: Internal::Start { at: 1 },
+        adjacent: Adjacent::Move { x: 2 },
+        untagged: Untagged::Point { x: 3, y: 4 },
+    };
+    let root = Peek::new(&events);
+
+    // `"Memory"`: a unit variant has nothing to address.
+    assert!(matches!(
+        get(root, "/backend/Memory"),
+        Err(JsonPointerError::NotAContainer { path, .. }) if path == "/backend"
+    ));
+    assert_eq!(get(root, "/internal/at").unwrap().get::<u32>().unwrap(), &1);
+    assert!(matches!(
+        get(root, "/internal/Start"),
+        Err(JsonPointerError::NotFound { .. })
+    ));
+    assert_eq!(
+        get(root, "/adjacent/c/x").unwrap().get::<i32>().unwrap(),
+        &2
+    );
+    assert!(matches!(
+        get(root, "/adjacent/x"),
+        Err(JsonPointerError::NotFound { path }) if path == "/adjacent/x"
+    ));
+    assert_eq!(get(root, "/untagged/y").unwrap().get::<i32>().unwrap(), &4);
+
+    set(Poke::new(&mut events), "/internal/at", 10u32).unwrap();
+    set(Poke::new(&mut events), "/adjacent/c/x", 20i32).unwrap();
+    set(Poke::new(&mut events), "/untagged/y", 40i32).unwrap();
+    assert_eq!(events.internal, Internal::Start { at: 10 });
+    assert_eq!(events.adjacent, Adjacent::Move { x: 20 });
+    assert_eq!(events.untagged, Untagged::Point { x: 3, y: 40 });
+
+    // The variant key of an externally tagged enum must be the active one.
+    let config = config();
+    assert!(matches!(
+        get(Peek::new(&config), "/backend/Remote/host"),
+        Err(JsonPointerError::NotFound { path }) if path == "/backend/Remote"
+    ));
+    assert!(matches!(
+        get(Peek::new(&config), "/backend/path"),
+        Err(JsonPointerError::NotFound { path }) if path == "/backend/path"
+    ));
+}
+
+#[derive(Facet, Debug, PartialEq, Default)]
+struct Common {
+    id: u32,
+    #[facet(rename = "created-at")]
+    created_at: u64,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Record {
+    #[facet(flatten)]
+    common: Common,
+    #[facet(flatten)]
+    backend: Backend,
+    name: String,
+    #[facet(flatten)]
+    extra: HashMap<String, String>,
+}
+
+fn record() -> Record {
+    Record {
+        common: Common {
+            id: 7,
+            created_at: 100,
+        },
+        backend: Backend::Disk {
+            path: "/data".into(),
+        },
+        name: "r".into(),
+        extra: HashMap::from([("note".into(), "hi".into())]),
+    }
+}
+
+#[test]
+fn flattened_fields_are_found_where_json_has_them() {
+    let record = record();
+    let root = Peek::new(&record);
+
+    assert_eq!(get(root, "/id").unwrap().get::<u32>().unwrap(), &7);
+    assert_eq!(
+        get(root, "/created-at").unwrap().get::<u64>().unwrap(),
+        &100
+    );
+    assert_eq!(get(root, "/Disk/path").unwrap().as_str(), Some("/data"));
+    assert_eq!(get(root, "/note").unwrap().as_str(), Some("hi"));
+    assert_eq!(get(root, "/name").unwrap().as_str(), Some("r"));
+    for missing in ["/common", "/backend", "/extra"] {
+        assert!(
+            matches!(get(root, missing), Err(JsonPointerError::NotFound { .. })),
+            "{missing}"
+        );
+    }
+}
+
+#[test]
+fn set_reaches_flattened_fields() {
+    let mut record = record();
+
+    set(Poke::new(&mut record), "/id", 8u32).unwrap();
+    set(Poke::new(&mut record), "/Disk/path", String::from("/tmp")).unwrap();
+    set(Poke::new(&mut record), "/note", String::from("bye")).unwrap();
+    set(Poke::new(&mut record), "/new", String::from("key")).unwrap();
+
+    assert_eq!(record.common.id, 8);
+    assert_eq!(
+        record.backend,
+        Backend::Disk {
+            path: "/tmp".into()
+        }
+    );
+    assert_eq!(record.extra["note"], "bye");
+    assert_eq!(record.extra["new"], "key");
+}
+
+#[test]
+fn skipped_fields_are_not_addressable() {
+    #[derive(Facet, Debug)]
+    struct Cached {
+        value: u32,
+        #[facet(skip)]
+        cache: u32,
+    }
+
+    let mut cached = Cached { value: 1, cache: 2 };
+    assert!(matches!(
+        get(Peek::new(&cached), "/cache"),
+        Err(JsonPointerError::NotFound { path }) if path == "/cache"
+    ));
+    assert!(matches!(
+        set(Poke::new(&mut cached), "/cache", 3u32),
+        Err(JsonPointerError::NotFound { path }) if path == "/cache"
+    ));
+    set(Poke::new(&mut cached), "/value", 4u32).unwrap();
+    assert_eq!((cached.value, cached.cache), (4, 2));
+}
+
+#[test]
+fn flattened_fields_under_a_prefix() {
+    #[derive(Facet, Debug, PartialEq)]
+    struct Instance {
+        name: String,
+        #[facet(flatten, flatten_prefix_from = "name")]
+        common: Common,
+    }
+
+    let mut instance = Instance {
+        name: "web1".into(),
+        common: Common::default(),
+    };
+    assert_eq!(
+        get(Peek::new(&instance), "/web1.created-at")
+            .unwrap()
+            .get::<u64>()
+            .unwrap(),
+        &0
+    );
+    assert!(matches!(
+        get(Peek::new(&instance), "/id"),
+        Err(JsonPointerError::NotFound { .. })
+    ));
+
+    set(Poke::new(&mut instance), "/web1.id", 3u32).unwrap();
+    assert_eq!(instance.common.id, 3);
+}
+
+#[test]
+fn set_writes_through_boxes() {
+    #[derive(Facet, Debug)]
+    struct Boxed {
+        server: Box<Server>,
+        port: Box<u16>,
+        shared: std::sync::Arc<Server>,
+    }
+
+    let mut boxed = Boxed {
+        server: Box::new(Server {
+            host: "a".into(),
+            port: 1,
+        }),
+        port: Box::new(2),
+        shared: std::sync::Arc::new(Server {
+            host: "s".into(),
+            port: 3,
+        }),
+    };
+
+    set(Poke::new(&mut boxed), "/server/port", 10u16).unwrap();
+    set(Poke::new(&mut boxed), "/port", 20u16).unwrap();
+    assert!(matches!(
+        set(Poke::new(&mut boxed), "/shared/port", 30u16),
+        Err(JsonPointerError::NotAContainer { path, .. }) if path == "/shared"
+    ));
+
+    assert_eq!(boxed.server.port, 10);
+    assert_eq!(*boxed.port, 20);
+    assert_eq!(boxed.shared.port, 3);
+}
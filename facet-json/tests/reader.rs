@@ -0,0 +1,157 @@
+//! Deserialization from `io::Read` and `AsyncRead`.
+
+use std::io::Read;
+
+use facet::Facet;
+use facet_json::StreamDeserializer;
+use facet_testhelpers::test;
+
+#[derive(Facet, Debug, PartialEq)]
+struct Record {
+    id: u32,
+    name: String,
+}
+
+fn record(id: u32, name: &str) -> Record {
+    Record {
+        id,
+        name: name.into(),
+    }
+}
+
+/// Hands out one byte per read, so every value straddles read boundaries.
+struct Trickle<'a>(&'a [u8]);
+
+impl Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let Some((&first, rest)) = self.0.split_first() else {
+            return Ok(0);
+        };
+        buf[0] = first;
+        self.0 = rest;
+        Ok(1)
+    }
+}
+
+#[test]
+fn from_reader_reads_one_document() {
+    let input = br#"{"id": 1, "name": "a"}"#;
+    let value: Record = facet_json::from_reader(Trickle(input)).unwrap();
+    assert_eq!(value, record(1, "a"));
+}
+
+#[test]
+fn ndjson_values() {
+    let input = "{\"id\": 1, \"name\": \"a\"}\n{\"id\": 2, \"name\": \"b}\\\"\"}\n\n";
+    let records: Vec<Record> = StreamDeserializer::values(Trickle(input.as_bytes()))
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(records, [record(1, "a"), record(2, "b}\"")]);
+}
+
+#[test]
+fn bare_values_split_on_whitespace() {
+    let numbers: Vec<i64> = StreamDeserializer::values(Trickle(b"1 -20\n300"))
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(numbers, [1, -20, 300]);
+}
+
+#[test]
+fn array_elements() {
+    let input = br#" [ {"id": 1, "name": "[a]"} , {"id": 2, "name": "b"} ] "#;
+    let records: Vec<Record> = StreamDeserializer::array(Trickle(input))
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(records, [record(1, "[a]"), record(2, "b")]);
+
+    let empty: Vec<Record> = StreamDeserializer::array(Trickle(b"[]"))
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn a_bad_value_does_not_end_the_stream() {
+    let input = "{\"id\": 1, \"name\": \"a\"}\n{\"id\": \"two\"}\n{\"id\": 3, \"name\": \"c\"}";
+    let results: Vec<_> = StreamDeserializer::<_, Record>::values(input.as_bytes()).collect();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().unwrap(), &record(1, "a"));
+    assert!(results[1].is_err());
+    assert_eq!(results[2].as_ref().unwrap(), &record(3, "c"));
+}
+
+#[test]
+fn malformed_structure_ends_the_stream() {
+    let input = br#"[{"id": 1, "name": "a"} {"id": 2, "name": "b"}]"#;
+    let results: Vec<_> = StreamDeserializer::<_, Record>::array(&input[..]).collect();
+    assert_eq!(results.len(), 2);
+    assert!(results[0].is_ok());
+    let err = results[1].as_ref().unwrap_err();
+    assert!(err.to_string().contains("`,` or `]`"), "{err}");
+    assert_eq!(err.span.unwrap().offset, 24);
+
+    let results: Vec<_> = StreamDeserializer::<_, Record>::array(&br#"[{"id": 1"#[..]).collect();
+    assert_eq!(results.len(), 1);
+    assert!(results[0].is_err());
+}
+
+#[test]
+fn json_lines() {
+    let input = b"{\"id\": 1, \"name\": \"a\"}\r\n\n  \n{\"id\": 2, \"name\": \"b\"}";
+    let records: Vec<Record> = facet_json::from_lines(Trickle(input))
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(records, [record(1, "a"), record(2, "b")]);
+}
+
+#[test]
+fn json_lines_errors_carry_line_and_column() {
+    let input = concat!(
+        "{\"id\": 1, \"name\": \"a\"}\n",
+        "{\"id\": 2, \"name\": 3}\n",
+        "\n",
+        "{\"id\": 4, \"name\": \"d\"\n",
+        "{\"id\": 5, \"name\": \"e\"}\n",
+    );
+    let mut lines = facet_json::from_lines::<Record, _>(input.as_bytes());
+
+    assert_eq!(lines.next().unwrap().unwrap(), record(1, "a"));
+
+    let err = lines.next().unwrap().unwrap_err();
+    assert_eq!(err.line, 2);
+    assert_eq!(err.error.span.unwrap().offset, 18);
+    assert!(err.to_string().starts_with("line 2, column 19: "), "{err}");
+
+    // A malformed line doesn't end the stream; blank lines still count.
+    let err = lines.next().unwrap().unwrap_err();
+    assert_eq!(err.line, 4);
+    assert_eq!(lines.line_number(), 4);
+
+    assert_eq!(lines.next().unwrap().unwrap(), record(5, "e"));
+    assert!(lines.next().is_none());
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn async_reader() {
+    use facet_json::AsyncStreamDeserializer;
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    runtime.block_on(async {
+        let value: Record = facet_json::from_async_reader(&br#"{"id": 1, "name": "a"}"#[..])
+            .await
+            .unwrap();
+        assert_eq!(value, record(1, "a"));
+
+        let input = br#"[{"id": 1, "name": "a"}, {"id": 2, "name": "b"}]"#;
+        let mut stream = AsyncStreamDeserializer::<_, Record>::array(&input[..]);
+        let mut records = Vec::new();
+        while let Some(result) = stream.next_value().await {
+            records.push(result.unwrap());
+        }
+        assert_eq!(records, [record(1, "a"), record(2, "b")]);
+    });
+}
@@ -0,0 +1,100 @@
+//! Deserializing a large top-level array in slices.
+
+use facet::Facet;
+use facet_format::{FormatDeserializer, ResumableSequence, Step};
+use facet_json::JsonParser;
+use facet_testhelpers::test;
+
+#[derive(Facet, Debug, PartialEq)]
+struct Record {
+    id: u32,
+    name: String,
+}
+
+fn records_json(len: u32) -> String {
+    let items: Vec<String> = (0..len)
+        .map(|id| format!(r#"{{"id": {id}, "name": "record-{id}"}}"#))
+        .collect();
+    format!("[{}]", items.join(", "))
+}
+
+#[test]
+fn sequence_is_deserialized_in_slices() {
+    let json = records_json(10);
+    let mut parser = JsonParser::<false>::new(json.as_bytes());
+    let mut de = FormatDeserializer::new_owned(&mut parser);
+    let mut resumable = ResumableSequence::<Vec<Record>, false>::new_owned().unwrap();
+
+    let mut pending = 0;
+    let records = loop {
+        match resumable.step(&mut de, 3).unwrap() {
+            Step::Pending => pending += 1,
+            Step::Done(records) => break records,
+        }
+    };
+    assert_eq!(pending, 3);
+    assert!(resumable.is_finished());
+    assert_eq!(records.len(), 10);
+    assert_eq!(
+        records[9],
+        Record {
+            id: 9,
+            name: "record-9".into()
+        }
+    );
+}
+
+#[test]
+fn borrowed_items() {
+    let json = r#"["a", "b", "c"]"#;
+    let mut parser = JsonParser::<true>::new(json.as_bytes());
+    let mut de = FormatDeserializer::new(&mut parser);
+    let mut resumable = ResumableSequence::<Vec<&str>, true>::new().unwrap();
+
+    assert!(matches!(resumable.step(&mut de, 2).unwrap(), Step::Pending));
+    match resumable.step(&mut de, 2).unwrap() {
+        Step::Done(items) => assert_eq!(items, ["a", "b", "c"]),
+        Step::Pending => panic!("expected the sequence to be complete"),
+    }
+}
+
+#[test]
+fn non_sequences_finish_in_one_step() {
+    let json = r#"{"id": 1, "name": "one"}"#;
+    let mut parser = JsonParser::<false>::new(json.as_bytes());
+    let mut de = FormatDeserializer::new_owned(&mut parser);
+    let mut resumable = ResumableSequence::<Record, false>::new_owned().unwrap();
+
+    match resumable.step(&mut de, 0).unwrap() {
+        Step::Done(record) => assert_eq!(record.name, "one"),
+        Step::Pending => panic!("expected a single step"),
+    }
+}
+
+#[test]
+fn zero_budget_still_makes_progress() {
+    let json = "[1, 2]";
+    let mut parser = JsonParser::<false>::new(json.as_bytes());
+    let mut de = FormatDeserializer::new_owned(&mut parser);
+    let mut resumable = ResumableSequence::<Vec<u32>, false>::new_owned().unwrap();
+
+    assert!(matches!(resumable.step(&mut de, 0).unwrap(), Step::Pending));
+    assert!(matches!(resumable.step(&mut de, 0).unwrap(), Step::Pending));
+    match resumable.step(&mut de, 0).unwrap() {
+        Step::Done(numbers) => assert_eq!(numbers, [1, 2]),
+        Step::Pending => panic!("expected the sequence to be complete"),
+    }
+}
+
+#[test]
+fn errors_end_the_deserialization() {
+    let json = r#"[{"id": 1, "name": "one"}, {"id": "two", "name": "two"}]"#;
+    let mut parser = JsonParser::<false>::new(json.as_bytes());
+    let mut de = FormatDeserializer::new_owned(&mut parser);
+    let mut resumable = ResumableSequence::<Vec<Record>, false>::new_owned().unwrap();
+
+    assert!(matches!(resumable.step(&mut de, 1).unwrap(), Step::Pending));
+    assert!(resumable.step(&mut de, 1).is_err());
+    assert!(resumable.is_finished());
+    assert!(resumable.step(&mut de, 1).is_err());
+}
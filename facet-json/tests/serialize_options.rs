@@ -0,0 +1,116 @@
+//! Output options for matching other JSON writers byte-for-byte.
+
+use std::collections::HashMap;
+
+use facet::Facet;
+use facet_json::{SerializeOptions, StringEscape, to_string_with_options};
+use facet_testhelpers::test;
+
+#[derive(Facet, Debug, PartialEq)]
+struct Package {
+    version: String,
+    name: String,
+    meta: Meta,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Meta {
+    tags: Vec<String>,
+    author: String,
+}
+
+fn package() -> Package {
+    Package {
+        version: "1.0.0".into(),
+        name: "café".into(),
+        meta: Meta {
+            tags: vec!["b".into(), "a".into()],
+            author: "Zoë 🦀".into(),
+        },
+    }
+}
+
+#[test]
+fn sort_keys_sorts_every_object() {
+    let options = SerializeOptions::new().sort_keys();
+    assert_eq!(
+        to_string_with_options(&package(), &options).unwrap(),
+        r#"{"meta":{"author":"Zoë 🦀","tags":["b","a"]},"name":"café","version":"1.0.0"}"#
+    );
+}
+
+#[test]
+fn sort_keys_pretty() {
+    let options = SerializeOptions::new().indent("    ").sort_keys();
+    assert_eq!(
+        to_string_with_options(&package(), &options).unwrap(),
+        r#"{
+    "meta": {
+        "author": "Zoë 🦀",
+        "tags": [
+            "b",
+            "a"
+        ]
+    },
+    "name": "café",
+    "version": "1.0.0"
+}"#
+    );
+}
+
+#[test]
+fn sort_keys_sorts_map_keys() {
+    let map: HashMap<String, u32> = [("zeta", 1), ("alpha", 2), ("mu", 3)]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v))
+        .collect();
+    let options = SerializeOptions::new().sort_keys();
+    assert_eq!(
+        to_string_with_options(&map, &options).unwrap(),
+        r#"{"alpha":2,"mu":3,"zeta":1}"#
+    );
+}
+
+#[test]
+fn ascii_escape_matches_python() {
+    // json.dumps(..., separators=(",", ":"), sort_keys=True)
+    let options = SerializeOptions::new()
+        .sort_keys()
+        .string_escape(StringEscape::Ascii);
+    assert_eq!(
+        to_string_with_options(&package(), &options).unwrap(),
+        r#"{"meta":{"author":"Zo\u00eb \ud83e\udd80","tags":["b","a"]},"name":"caf\u00e9","version":"1.0.0"}"#
+    );
+}
+
+#[test]
+fn ascii_escape_applies_to_keys_and_chars() {
+    let map: HashMap<String, char> = [("ключ".to_string(), 'é')].into_iter().collect();
+    let options = SerializeOptions::new().ascii_only();
+    assert_eq!(
+        to_string_with_options(&map, &options).unwrap(),
+        r#"{"\u043a\u043b\u044e\u0447":"\u00e9"}"#
+    );
+}
+
+#[test]
+fn trailing_newline() {
+    let options = SerializeOptions::new().trailing_newline();
+    assert_eq!(
+        to_string_with_options(&[1, 2], &options).unwrap(),
+        "[1,2]\n"
+    );
+
+    let mut buffer = Vec::new();
+    facet_json::to_writer_std_with_options(&mut buffer, &[1, 2], &options.pretty()).unwrap();
+    assert_eq!(buffer, b"[\n  1,\n  2\n]\n");
+}
+
+#[test]
+fn float_precision() {
+    let options = SerializeOptions::new().float_precision(2);
+    assert_eq!(
+        to_string_with_options(&[1.0, 0.126, 2.0 / 3.0], &options).unwrap(),
+        "[1.00,0.13,0.67]"
+    );
+}
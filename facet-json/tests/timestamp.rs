@@ -0,0 +1,174 @@
+//! Acceptance profiles and output formats for timestamp types.
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use facet::Facet;
+use facet_format::FormatDeserializer;
+use facet_json as json;
+use facet_json::{JsonParser, SerializeOptions, TimestampFormat, TimestampProfile};
+use facet_testhelpers::test;
+
+#[derive(Facet, Debug, PartialEq)]
+struct Event {
+    at: DateTime<Utc>,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Wall {
+    at: NaiveDateTime,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Offset {
+    at: DateTime<FixedOffset>,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Annotated {
+    #[facet(json::timestamp_accept = "epoch_millis")]
+    #[facet(json::timestamp_format = "epoch_millis")]
+    created: DateTime<Utc>,
+    updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Misconfigured {
+    #[facet(json::timestamp_accept = "unix")]
+    at: DateTime<Utc>,
+}
+
+fn from_str_with<T: Facet<'static>>(
+    input: &str,
+    profile: TimestampProfile,
+) -> Result<T, facet_json::DeserializeError> {
+    let mut parser = JsonParser::<true>::new(input.as_bytes()).timestamp_profile(profile);
+    FormatDeserializer::new_owned(&mut parser).deserialize_root()
+}
+
+fn at() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap()
+}
+
+#[test]
+fn rfc3339_is_the_default() {
+    let event: Event = facet_json::from_str(r#"{"at": "2024-01-02T03:04:05Z"}"#).unwrap();
+    assert_eq!(event.at, at());
+
+    assert!(facet_json::from_str::<Event>(r#"{"at": 1704164645}"#).is_err());
+    assert!(facet_json::from_str::<Event>(r#"{"at": "2024-01-02 03:04:05"}"#).is_err());
+}
+
+#[test]
+fn epoch_seconds_and_millis() {
+    let event: Event =
+        from_str_with(r#"{"at": 1704164645}"#, TimestampProfile::EPOCH_SECONDS).unwrap();
+    assert_eq!(event.at, at());
+
+    let event: Event =
+        from_str_with(r#"{"at": 1704164645000}"#, TimestampProfile::EPOCH_MILLIS).unwrap();
+    assert_eq!(event.at, at());
+
+    // Without `rfc3339`, strings are rejected.
+    assert!(
+        from_str_with::<Event>(
+            r#"{"at": "2024-01-02T03:04:05Z"}"#,
+            TimestampProfile::EPOCH_SECONDS
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn any_profile_guesses_epoch_unit_by_magnitude() {
+    for input in [
+        r#"{"at": 1704164645}"#,
+        r#"{"at": 1704164645000}"#,
+        r#"{"at": "2024-01-02T03:04:05Z"}"#,
+        r#"{"at": "2024-01-02 03:04:05"}"#,
+    ] {
+        let event: Event = from_str_with(input, TimestampProfile::ANY).unwrap();
+        assert_eq!(event.at, at(), "{input}");
+    }
+}
+
+#[test]
+fn relaxed_iso_strings() {
+    let profile = TimestampProfile::RELAXED_ISO;
+    for input in [
+        r#"{"at": "2024-01-02 03:04:05"}"#,
+        r#"{"at": "2024-01-02t03:04:05z"}"#,
+        r#"{"at": "2024-01-02T04:04:05+0100"}"#,
+        r#"{"at": "2024-01-02T05:04:05+02"}"#,
+    ] {
+        let event: Event = from_str_with(input, profile).unwrap();
+        assert_eq!(event.at, at(), "{input}");
+    }
+
+    let event: Event = from_str_with(r#"{"at": "2024-01-02"}"#, profile).unwrap();
+    assert_eq!(event.at, Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap());
+
+    // The offset is kept for types that store one.
+    let offset: Offset = from_str_with(r#"{"at": "2024-01-02 04:04:05+0100"}"#, profile).unwrap();
+    assert_eq!(offset.at.offset().local_minus_utc(), 3600);
+    assert_eq!(offset.at, at());
+
+    assert!(from_str_with::<Event>(r#"{"at": "2024-13-02 03:04:05"}"#, profile).is_err());
+}
+
+#[test]
+fn naive_date_times_read_epochs_as_utc() {
+    let wall: Wall = from_str_with(
+        r#"{"at": 1704164645}"#,
+        TimestampProfile::RFC3339.union(TimestampProfile::EPOCH_SECONDS),
+    )
+    .unwrap();
+    let expected = NaiveDate::from_ymd_opt(2024, 1, 2)
+        .unwrap()
+        .and_hms_opt(3, 4, 5)
+        .unwrap();
+    assert_eq!(wall.at, expected);
+}
+
+#[test]
+fn serialize_as_epoch() {
+    let event = Event { at: at() };
+    assert_eq!(
+        facet_json::to_string(&event).unwrap(),
+        r#"{"at":"2024-01-02T03:04:05Z"}"#
+    );
+
+    let options = SerializeOptions::new().timestamp_format(TimestampFormat::EpochSeconds);
+    let json = facet_json::to_string_with_options(&event, &options).unwrap();
+    assert_eq!(json, r#"{"at":1704164645}"#);
+
+    let back: Event = from_str_with(&json, TimestampProfile::EPOCH_SECONDS).unwrap();
+    assert_eq!(back, event);
+}
+
+#[test]
+fn field_attributes_override_global_settings() {
+    let value: Annotated =
+        facet_json::from_str(r#"{"created": 1704164645000, "updated": "2024-01-02T03:04:05Z"}"#)
+            .unwrap();
+    assert_eq!(value.created, at());
+    assert_eq!(value.updated, Some(at()));
+
+    // The field only accepts epoch milliseconds, even if the parser accepts more.
+    assert!(
+        from_str_with::<Annotated>(
+            r#"{"created": "2024-01-02T03:04:05Z", "updated": null}"#,
+            TimestampProfile::ANY
+        )
+        .is_err()
+    );
+
+    assert_eq!(
+        facet_json::to_string(&value).unwrap(),
+        r#"{"created":1704164645000,"updated":"2024-01-02T03:04:05Z"}"#
+    );
+}
+
+#[test]
+fn unknown_attribute_value_is_an_error() {
+    let err = facet_json::from_str::<Misconfigured>(r#"{"at": 1704164645}"#).unwrap_err();
+    assert!(err.to_string().contains("timestamp_accept"), "{err}");
+}
@@ -0,0 +1,758 @@
+//! Generate Kotlin data classes from facet type metadata.
+//!
+//! This crate uses facet's reflection capabilities to generate Kotlin
+//! declarations annotated for [kotlinx.serialization], so that Kotlin clients
+//! (e.g. Android apps) read and write the same JSON as the Rust types they
+//! mirror:
+//!
+//! - structs become `@Serializable data class`es, with a `@SerialName` on every
+//!   property whose serialized name differs from its Kotlin name,
+//! - enums with only unit variants become `enum class`es,
+//! - internally tagged enums (`#[facet(tag = "...")]`) become sealed interfaces
+//!   with one nested class per variant and a `@JsonClassDiscriminator`.
+//!
+//! Other enums with data (externally or adjacently tagged, untagged) have no
+//! kotlinx.serialization equivalent without a hand-written serializer, and
+//! are emitted as a `typealias` to `JsonElement`.
+//!
+//! [kotlinx.serialization]: https://github.com/Kotlin/kotlinx.serialization
+//!
+//! # Example
+//!
+//! ```
+//! use facet::Facet;
+//! use facet_kotlin::to_kotlin;
+//!
+//! #[derive(Facet)]
+//! struct User {
+//!     name: String,
+//!     age: u32,
+//!     email: Option<String>,
+//! }
+//!
+//! let kt = to_kotlin::<User>();
+//! assert!(kt.contains("data class User("));
+//! assert!(kt.contains("    val email: String? = null,"));
+//! ```
+
+extern crate alloc;
+
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use facet_core::{Def, EnumType, Facet, Field, ScalarKind, Shape, StructKind, Type, UserType};
+
+/// Generate Kotlin definitions for a single type.
+///
+/// Returns a string containing the Kotlin declarations for the type and the
+/// types it references.
+pub fn to_kotlin<T: Facet<'static>>() -> String {
+    let mut generator = KotlinGenerator::new();
+    generator.add_shape(T::SHAPE);
+    generator.finish()
+}
+
+/// Generator for Kotlin type definitions.
+///
+/// Use this when you need to generate multiple related types.
+pub struct KotlinGenerator {
+    output: String,
+    /// Package declaration for the generated file
+    package: Option<String>,
+    /// Imports needed by the generated code
+    imports: BTreeSet<&'static str>,
+    /// Types already generated (by type identifier)
+    generated: BTreeSet<&'static str>,
+    /// Types queued for generation
+    queue: Vec<&'static Shape>,
+    /// Indentation level
+    indent: usize,
+}
+
+impl Default for KotlinGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A property of a generated class.
+struct Property {
+    /// Rust name of the field
+    name: &'static str,
+    /// Name in the serialized form
+    serial_name: &'static str,
+    /// Kotlin type, including `?` for nullable properties
+    ty: String,
+    nullable: bool,
+    doc: &'static [&'static str],
+}
+
+impl KotlinGenerator {
+    /// Create a new Kotlin generator.
+    pub const fn new() -> Self {
+        Self {
+            output: String::new(),
+            package: None,
+            imports: BTreeSet::new(),
+            generated: BTreeSet::new(),
+            queue: Vec::new(),
+            indent: 0,
+        }
+    }
+
+    /// Set the package of the generated file.
+    pub fn package(mut self, package: impl Into<String>) -> Self {
+        self.package = Some(package.into());
+        self
+    }
+
+    /// Add a type to generate.
+    pub fn add_type<T: Facet<'static>>(&mut self) {
+        self.add_shape(T::SHAPE);
+    }
+
+    /// Add a shape to generate.
+    pub fn add_shape(&mut self, shape: &'static Shape) {
+        if !self.generated.contains(shape.type_identifier) {
+            self.queue.push(shape);
+        }
+    }
+
+    /// Finish generation and return the Kotlin code.
+    pub fn finish(mut self) -> String {
+        // Process queue until empty
+        while let Some(shape) = self.queue.pop() {
+            if self.generated.contains(shape.type_identifier) {
+                continue;
+            }
+            self.generated.insert(shape.type_identifier);
+            self.generate_shape(shape);
+        }
+
+        let mut header = String::new();
+        if let Some(package) = &self.package {
+            writeln!(header, "package {package}\n").unwrap();
+        }
+        for import in &self.imports {
+            writeln!(header, "import {import}").unwrap();
+        }
+        if !self.imports.is_empty() {
+            header.push('\n');
+        }
+        header.push_str(self.output.trim_end());
+        header.push('\n');
+        header
+    }
+
+    fn write_indent(&mut self) {
+        for _ in 0..self.indent {
+            self.output.push_str("    ");
+        }
+    }
+
+    fn write_doc(&mut self, doc: &[&str]) {
+        if let [line] = doc {
+            // Short doc: collapse onto a single line, e.g. `/** Okay */`.
+            self.write_indent();
+            self.output.push_str("/**");
+            self.output.push_str(line);
+            self.output.push_str(" */\n");
+        } else if !doc.is_empty() {
+            self.write_indent();
+            self.output.push_str("/**\n");
+            for line in doc {
+                self.write_indent();
+                self.output.push_str(" *");
+                self.output.push_str(line);
+                self.output.push('\n');
+            }
+            self.write_indent();
+            self.output.push_str(" */\n");
+        }
+    }
+
+    fn write_line(&mut self, line: &str) {
+        self.write_indent();
+        self.output.push_str(line);
+        self.output.push('\n');
+    }
+
+    fn generate_shape(&mut self, shape: &'static Shape) {
+        // Handle transparent wrappers - generate the inner type instead
+        if let Some(inner) = shape.inner {
+            let inner_type = self.type_for_shape(inner);
+            self.write_doc(shape.doc);
+            writeln!(
+                self.output,
+                "typealias {} = {}\n",
+                shape.type_identifier, inner_type
+            )
+            .unwrap();
+            return;
+        }
+
+        self.write_doc(shape.doc);
+
+        // Handle proxy types - use the proxy's shape for generation
+        // but keep the original type name
+        let ty_shape = shape.proxy.map_or(shape, |proxy_def| proxy_def.shape);
+        match &ty_shape.ty {
+            Type::User(UserType::Struct(st)) if st.kind == StructKind::Struct => {
+                self.generate_data_class(shape.type_identifier, st.fields, None);
+            }
+            Type::User(UserType::Struct(st)) if st.kind == StructKind::Unit => {
+                self.import("kotlinx.serialization.Serializable");
+                writeln!(
+                    self.output,
+                    "@Serializable\nobject {}",
+                    shape.type_identifier
+                )
+                .unwrap();
+            }
+            Type::User(UserType::Enum(en)) => self.generate_enum(shape, en),
+            Type::User(UserType::Struct(_)) => {
+                // Tuple structs serialize as arrays, which Kotlin can't type
+                self.import("kotlinx.serialization.json.JsonArray");
+                writeln!(
+                    self.output,
+                    "typealias {} = JsonArray",
+                    shape.type_identifier
+                )
+                .unwrap();
+            }
+            _ => {
+                // Anything else: alias whatever type the value maps to
+                let type_str = self.type_for_shape(ty_shape);
+                writeln!(
+                    self.output,
+                    "typealias {} = {}",
+                    shape.type_identifier, type_str
+                )
+                .unwrap();
+            }
+        }
+        self.output.push('\n');
+    }
+
+    /// Writes a `@Serializable data class` (or a plain `class` if it has no
+    /// properties), optionally implementing `supertype` under `serial_name`.
+    fn generate_data_class(
+        &mut self,
+        name: &str,
+        fields: &'static [Field],
+        supertype: Option<(&str, &str)>,
+    ) {
+        self.import("kotlinx.serialization.Serializable");
+        let mut properties = Vec::new();
+        self.collect_properties(fields, false, &mut Vec::new(), &mut properties);
+        let implements = supertype.map_or(String::new(), |(ty, _)| alloc::format!(" : {ty}"));
+
+        self.write_line("@Serializable");
+        if let Some((_, serial_name)) = supertype {
+            self.write_line(&alloc::format!("@SerialName(\"{serial_name}\")"));
+        }
+        if properties.is_empty() {
+            self.write_line(&alloc::format!("class {name}{implements}"));
+            return;
+        }
+
+        self.write_line(&alloc::format!("data class {name}("));
+        self.indent += 1;
+        for property in properties {
+            self.write_doc(property.doc);
+            let kotlin_name = property_name(property.name);
+            if kotlin_name != property.serial_name {
+                self.import("kotlinx.serialization.SerialName");
+                self.write_line(&alloc::format!("@SerialName(\"{}\")", property.serial_name));
+            }
+            let default = if property.nullable { " = null" } else { "" };
+            self.write_line(&alloc::format!(
+                "val {}: {}{default},",
+                kotlin_identifier(&kotlin_name),
+                property.ty
+            ));
+        }
+        self.indent -= 1;
+        self.write_line(&alloc::format!("){implements}"));
+    }
+
+    /// Collect the properties of a class, inlining flattened structs.
+    fn collect_properties(
+        &mut self,
+        fields: &'static [Field],
+        force_nullable: bool,
+        flatten_stack: &mut Vec<&'static str>,
+        properties: &mut Vec<Property>,
+    ) {
+        for field in fields {
+            if field.should_skip_serializing_unconditional() {
+                continue;
+            }
+            let field_shape = field.shape.get();
+            if field.is_flattened() {
+                let (inner_shape, is_optional) = unwrap_to_inner_shape(field_shape);
+                if let Type::User(UserType::Struct(st)) = &inner_shape.ty {
+                    if flatten_stack.contains(&inner_shape.type_identifier) {
+                        continue;
+                    }
+                    flatten_stack.push(inner_shape.type_identifier);
+                    self.collect_properties(
+                        st.fields,
+                        force_nullable || is_optional,
+                        flatten_stack,
+                        properties,
+                    );
+                    flatten_stack.pop();
+                    continue;
+                }
+            }
+
+            let (ty, nullable) = match &field_shape.def {
+                Def::Option(opt) => (self.type_for_shape(opt.t), true),
+                _ => (self.type_for_shape(field_shape), force_nullable),
+            };
+            properties.push(Property {
+                name: field.name,
+                serial_name: field.effective_name(),
+                ty: if nullable {
+                    alloc::format!("{ty}?")
+                } else {
+                    ty
+                },
+                nullable,
+                doc: field.doc,
+            });
+        }
+    }
+
+    fn generate_enum(&mut self, shape: &'static Shape, enum_type: &EnumType) {
+        let all_unit = enum_type
+            .variants
+            .iter()
+            .all(|v| matches!(v.data.kind, StructKind::Unit));
+
+        if let (Some(tag), None) = (shape.tag, shape.content) {
+            self.generate_sealed_interface(shape, enum_type, tag);
+        } else if all_unit && shape.tag.is_none() {
+            self.import("kotlinx.serialization.Serializable");
+            writeln!(
+                self.output,
+                "@Serializable\nenum class {} {{",
+                shape.type_identifier
+            )
+            .unwrap();
+            self.indent += 1;
+            for variant in enum_type.variants {
+                self.write_doc(variant.doc);
+                if variant.effective_name() != variant.name {
+                    self.import("kotlinx.serialization.SerialName");
+                    self.write_line(&alloc::format!(
+                        "@SerialName(\"{}\")",
+                        variant.effective_name()
+                    ));
+                }
+                self.write_line(&alloc::format!("{},", kotlin_identifier(variant.name)));
+            }
+            self.indent -= 1;
+            self.output.push_str("}\n");
+        } else {
+            // No built-in kotlinx.serialization representation
+            self.import("kotlinx.serialization.json.JsonElement");
+            let representation = if shape.is_untagged() {
+                "Untagged"
+            } else if shape.tag.is_some() {
+                "Adjacently tagged"
+            } else {
+                "Externally tagged"
+            };
+            writeln!(
+                self.output,
+                "// {representation} enum: kotlinx.serialization has no built-in equivalent.\n\
+                 typealias {} = JsonElement",
+                shape.type_identifier
+            )
+            .unwrap();
+        }
+    }
+
+    /// Internally tagged enum: a sealed interface with one nested class per
+    /// variant, discriminated by the tag property.
+    fn generate_sealed_interface(
+        &mut self,
+        shape: &'static Shape,
+        enum_type: &EnumType,
+        tag: &str,
+    ) {
+        let name = shape.type_identifier;
+        self.import("kotlinx.serialization.ExperimentalSerializationApi");
+        self.import("kotlinx.serialization.SerialName");
+        self.import("kotlinx.serialization.Serializable");
+        self.import("kotlinx.serialization.json.JsonClassDiscriminator");
+        writeln!(
+            self.output,
+            "@OptIn(ExperimentalSerializationApi::class)\n\
+             @Serializable\n\
+             @JsonClassDiscriminator(\"{tag}\")\n\
+             sealed interface {name} {{"
+        )
+        .unwrap();
+        self.indent += 1;
+
+        for (i, variant) in enum_type.variants.iter().enumerate() {
+            if i > 0 {
+                self.output.push('\n');
+            }
+            self.write_doc(variant.doc);
+            let class_name = kotlin_identifier(variant.name);
+
+            // The fields of a newtype variant's struct sit next to the tag.
+            let fields = match variant.data.kind {
+                StructKind::TupleStruct if variant.data.fields.len() == 1 => {
+                    let (inner, _) = unwrap_to_inner_shape(variant.data.fields[0].shape.get());
+                    match &inner.ty {
+                        Type::User(UserType::Struct(st)) if st.kind == StructKind::Struct => {
+                            st.fields
+                        }
+                        _ => variant.data.fields,
+                    }
+                }
+                _ => variant.data.fields,
+            };
+
+            if fields.is_empty() {
+                self.write_line("@Serializable");
+                self.write_line(&alloc::format!(
+                    "@SerialName(\"{}\")",
+                    variant.effective_name()
+                ));
+                self.write_line(&alloc::format!("data object {class_name} : {name}"));
+            } else {
+                let supertype = (name, variant.effective_name());
+                self.generate_data_class(&class_name, fields, Some(supertype));
+            }
+        }
+
+        self.indent -= 1;
+        self.output.push_str("}\n");
+    }
+
+    fn import(&mut self, import: &'static str) {
+        self.imports.insert(import);
+    }
+
+    fn type_for_shape(&mut self, shape: &'static Shape) -> String {
+        // Check Def first - these take precedence over transparent wrappers
+        match &shape.def {
+            Def::Scalar => self.scalar_type(shape),
+            Def::Option(opt) => {
+                alloc::format!("{}?", self.type_for_shape(opt.t))
+            }
+            Def::List(list) => {
+                alloc::format!("List<{}>", self.type_for_shape(list.t))
+            }
+            Def::Array(arr) => {
+                alloc::format!("List<{}>", self.type_for_shape(arr.t))
+            }
+            Def::Set(set) => {
+                alloc::format!("Set<{}>", self.type_for_shape(set.t))
+            }
+            Def::Map(map) => {
+                let key = self.type_for_shape(map.k);
+                alloc::format!("Map<{key}, {}>", self.type_for_shape(map.v))
+            }
+            Def::Pointer(ptr) => {
+                // Smart pointers are transparent
+                match ptr.pointee {
+                    Some(pointee) => self.type_for_shape(pointee),
+                    None => self.json_element(),
+                }
+            }
+            _ => match &shape.ty {
+                Type::User(UserType::Struct(st)) if st.kind == StructKind::Tuple => {
+                    // Tuples serialize as arrays, which Kotlin can't type
+                    self.import("kotlinx.serialization.json.JsonArray");
+                    "JsonArray".into()
+                }
+                Type::User(UserType::Struct(_) | UserType::Enum(_)) => {
+                    self.add_shape(shape);
+                    shape.type_identifier.into()
+                }
+                _ => match shape.inner {
+                    Some(inner) => self.type_for_shape(inner),
+                    None => self.json_element(),
+                },
+            },
+        }
+    }
+
+    fn json_element(&mut self) -> String {
+        self.import("kotlinx.serialization.json.JsonElement");
+        "JsonElement".into()
+    }
+
+    fn scalar_type(&mut self, shape: &'static Shape) -> String {
+        let by_kind = match shape.scalar_kind() {
+            Some(ScalarKind::Str | ScalarKind::Char) => Some("String"),
+            Some(ScalarKind::Bool) => Some("Boolean"),
+            Some(ScalarKind::I8) => Some("Byte"),
+            Some(ScalarKind::I16) => Some("Short"),
+            Some(ScalarKind::I32) => Some("Int"),
+            Some(ScalarKind::I64 | ScalarKind::ISize) => Some("Long"),
+            Some(ScalarKind::U8) => Some("UByte"),
+            Some(ScalarKind::U16) => Some("UShort"),
+            Some(ScalarKind::U32) => Some("UInt"),
+            Some(ScalarKind::U64 | ScalarKind::USize) => Some("ULong"),
+            Some(ScalarKind::F32) => Some("Float"),
+            Some(ScalarKind::F64) => Some("Double"),
+            // 128-bit integers have no Kotlin counterpart.
+            Some(_) => return self.json_element(),
+            None => None,
+        };
+        if let Some(ty) = by_kind {
+            return ty.into();
+        }
+
+        // Scalars without a kind are recognized by name.
+        let ty = match shape.type_identifier {
+            "String" | "str" | "&str" | "Cow" | "char" => "String",
+            "bool" => "Boolean",
+            "i8" => "Byte",
+            "i16" => "Short",
+            "i32" => "Int",
+            "i64" | "isize" => "Long",
+            "u8" => "UByte",
+            "u16" => "UShort",
+            "u32" => "UInt",
+            "u64" | "usize" => "ULong",
+            "f32" => "Float",
+            "f64" => "Double",
+
+            // chrono types
+            "NaiveDate"
+            | "NaiveDateTime"
+            | "NaiveTime"
+            | "DateTime<Utc>"
+            | "DateTime<FixedOffset>"
+            | "DateTime<Local>"
+                if shape.module_path == Some("chrono") =>
+            {
+                "String"
+            }
+
+            // Unknown scalar (including 128-bit integers)
+            _ => return self.json_element(),
+        };
+        ty.into()
+    }
+}
+
+/// Unwrap through options, pointers, transparent wrappers, and proxies to get the effective shape.
+///
+/// Returns the unwrapped shape along with a flag indicating whether an `Option` was encountered.
+fn unwrap_to_inner_shape(shape: &'static Shape) -> (&'static Shape, bool) {
+    let (shape, is_optional) = shape.flatten_target();
+    if let Some(inner) = shape.inner {
+        let (inner, inner_optional) = unwrap_to_inner_shape(inner);
+        return (inner, is_optional || inner_optional);
+    }
+    if let Some(proxy_def) = shape.proxy {
+        let (proxy, proxy_optional) = unwrap_to_inner_shape(proxy_def.shape);
+        return (proxy, is_optional || proxy_optional);
+    }
+    (shape, is_optional)
+}
+
+/// Kotlin property name for a Rust field name: `user_id` -> `userId`, and
+/// tuple fields (`0`) -> `_0`.
+fn property_name(name: &str) -> String {
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        return alloc::format!("_{name}");
+    }
+    let mut out = String::with_capacity(name.len());
+    let mut upper = false;
+    for c in name.chars() {
+        if c == '_' && !out.is_empty() {
+            upper = true;
+        } else if upper {
+            out.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Escapes Kotlin hard keywords with backticks.
+fn kotlin_identifier(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "as",
+        "break",
+        "class",
+        "continue",
+        "do",
+        "else",
+        "false",
+        "for",
+        "fun",
+        "if",
+        "in",
+        "interface",
+        "is",
+        "null",
+        "object",
+        "package",
+        "return",
+        "super",
+        "this",
+        "throw",
+        "true",
+        "try",
+        "typealias",
+        "typeof",
+        "val",
+        "var",
+        "when",
+        "while",
+    ];
+    if KEYWORDS.contains(&name) {
+        alloc::format!("`{name}`")
+    } else {
+        name.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::collections::BTreeMap;
+    use facet::Facet;
+
+    #[test]
+    fn test_simple_struct() {
+        /// A registered user.
+        #[derive(Facet)]
+        struct User {
+            /// Display name.
+            name: String,
+            user_id: u64,
+            email: Option<String>,
+            tags: Vec<String>,
+        }
+
+        let kt = to_kotlin::<User>();
+        insta::assert_snapshot!(kt);
+    }
+
+    #[test]
+    fn test_renamed_fields() {
+        #[derive(Facet)]
+        #[facet(rename_all = "kebab-case")]
+        struct Settings {
+            dark_mode: bool,
+            #[facet(rename = "fontScale")]
+            font_scale: f32,
+            retries: i32,
+        }
+
+        let kt = to_kotlin::<Settings>();
+        insta::assert_snapshot!(kt);
+    }
+
+    #[test]
+    fn test_unit_enum() {
+        #[derive(Facet)]
+        #[repr(u8)]
+        #[facet(rename_all = "snake_case")]
+        #[allow(dead_code)]
+        enum Status {
+            Active,
+            OnHold,
+            Closed,
+        }
+
+        let kt = to_kotlin::<Status>();
+        insta::assert_snapshot!(kt);
+    }
+
+    #[test]
+    fn test_nested_types_and_package() {
+        #[derive(Facet)]
+        struct Address {
+            city: String,
+        }
+
+        #[derive(Facet)]
+        struct Customer {
+            address: Address,
+            scores: BTreeMap<String, f64>,
+        }
+
+        let mut generator = KotlinGenerator::new().package("com.example.api");
+        generator.add_type::<Customer>();
+        insta::assert_snapshot!(generator.finish());
+    }
+
+    #[test]
+    fn test_internally_tagged_enum() {
+        #[derive(Facet)]
+        struct Point {
+            x: i32,
+            y: i32,
+        }
+
+        #[derive(Facet)]
+        #[repr(C)]
+        #[facet(tag = "kind", rename_all = "snake_case")]
+        #[allow(dead_code)]
+        enum Event {
+            Click(Point),
+            KeyPress { key_code: u32 },
+            Close,
+        }
+
+        let kt = to_kotlin::<Event>();
+        insta::assert_snapshot!(kt);
+    }
+
+    #[test]
+    fn test_externally_tagged_enum_with_data() {
+        #[derive(Facet)]
+        #[repr(u8)]
+        #[allow(dead_code)]
+        enum Message {
+            Text(String),
+            Ping,
+        }
+
+        let kt = to_kotlin::<Message>();
+        insta::assert_snapshot!(kt);
+    }
+
+    #[test]
+    fn test_flatten() {
+        #[derive(Facet)]
+        struct Meta {
+            created_at: String,
+        }
+
+        #[derive(Facet)]
+        struct Document {
+            title: String,
+            #[facet(flatten)]
+            meta: Meta,
+        }
+
+        let kt = to_kotlin::<Document>();
+        insta::assert_snapshot!(kt);
+    }
+
+    #[test]
+    fn test_identifiers() {
+        assert_eq!(property_name("user_id"), "userId");
+        assert_eq!(property_name("0"), "_0");
+        assert_eq!(kotlin_identifier("in"), "`in`");
+    }
+}
@@ -317,22 +317,16 @@ impl LuaGenerator {
     }
 
     fn unwrap_to_inner_shape(shape: &'static Shape) -> (&'static Shape, bool) {
-        if let Def::Option(opt) = &shape.def {
-            let (inner, _) = Self::unwrap_to_inner_shape(opt.t);
-            return (inner, true);
-        }
-        if let Def::Pointer(ptr) = &shape.def
-            && let Some(pointee) = ptr.pointee
-        {
-            return Self::unwrap_to_inner_shape(pointee);
-        }
+        let (shape, is_optional) = shape.flatten_target();
         if let Some(inner) = shape.inner {
-            return Self::unwrap_to_inner_shape(inner);
+            let (inner, inner_optional) = Self::unwrap_to_inner_shape(inner);
+            return (inner, is_optional || inner_optional);
         }
         if let Some(proxy_def) = shape.proxy {
-            return Self::unwrap_to_inner_shape(proxy_def.shape);
+            let (proxy, proxy_optional) = Self::unwrap_to_inner_shape(proxy_def.shape);
+            return (proxy, is_optional || proxy_optional);
         }
-        (shape, false)
+        (shape, is_optional)
     }
 
     /// Build a LuaLS tuple type: `[T1, T2]` (fixed length, positionally typed).
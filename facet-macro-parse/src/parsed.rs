@@ -601,6 +601,9 @@ pub struct PAttrs {
     /// rename_all rule (if any)
     pub rename_all: Option<RenameRule>,
 
+    /// rename_all rule from `#[facet(defaults_for_children(...))]` (if any)
+    pub child_rename_all: Option<RenameRule>,
+
     /// Field/variant-level rename (if any)
     pub rename: Option<String>,
 
@@ -627,6 +630,7 @@ impl PAttrs {
         let mut facet_attrs: Vec<PFacetAttr> = Vec::new();
         let mut repr: Option<PRepr> = None;
         let mut rename_all: Option<RenameRule> = None;
+        let mut child_rename_all: Option<RenameRule> = None;
         let mut rename: Option<String> = None;
         let mut crate_path: Option<TokenStream> = None;
         let mut errors: Vec<CompileError> = Vec::new();
@@ -730,6 +734,37 @@ impl PAttrs {
                             });
                         }
                     }
+                    "defaults_for_children" => {
+                        // #[facet(defaults_for_children(rename_all = "camelCase"))]
+                        // The grammar checks the keys; only the rule is left to check.
+                        let tokens: Vec<_> = attr.args.clone().into_iter().collect();
+                        for window in tokens.windows(3) {
+                            if let [
+                                proc_macro2::TokenTree::Ident(key),
+                                proc_macro2::TokenTree::Punct(eq),
+                                proc_macro2::TokenTree::Literal(lit),
+                            ] = window
+                                && key == "rename_all"
+                                && eq.as_char() == '='
+                            {
+                                let s = lit.to_string();
+                                let rule_str = s.trim_matches('"');
+                                if let Some(rule) = RenameRule::parse(rule_str) {
+                                    child_rename_all = Some(rule);
+                                } else {
+                                    errors.push(CompileError {
+                                        message: format!(
+                                            "unknown #[facet(defaults_for_children(rename_all = \"...\"))] \
+                                             rule: `{rule_str}`. Valid options: camelCase, snake_case, \
+                                             kebab-case, PascalCase, SCREAMING_SNAKE_CASE, \
+                                             SCREAMING-KEBAB-CASE, lowercase, UPPERCASE"
+                                        ),
+                                        span: lit.span(),
+                                    });
+                                }
+                            }
+                        }
+                    }
                     "crate" => {
                         // Store the crate path tokens directly
                         crate_path = Some(attr.args.clone());
@@ -764,6 +799,7 @@ impl PAttrs {
             facet: facet_attrs,
             repr: repr.unwrap_or(PRepr::Rust(None)),
             rename_all,
+            child_rename_all,
             rename,
             crate_path,
             errors,
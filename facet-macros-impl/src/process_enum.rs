@@ -1,6 +1,7 @@
 use super::*;
 use crate::process_struct::{
-    TraitSources, collect_trailing_shape_checks, gen_field_from_pfield, gen_trait_bounds,
+    TraitSources, collect_flatten_prefix_checks, collect_trailing_shape_checks,
+    gen_child_defaults_call, gen_container_conversions, gen_field_from_pfield, gen_trait_bounds,
     gen_type_ops, gen_vtable, phantom_attr_use,
 };
 use proc_macro2::Literal;
@@ -132,7 +133,7 @@ pub(crate) fn process_enum(parsed: Enum) -> TokenStream {
     // Collect phantom use statements for IDE hover support on attribute names.
     // These link attribute spans to their facet::builtin::Attr variants.
     let mut phantom_attr_uses: Vec<TokenStream> = Vec::new();
-    let mut trailing_shape_checks: Vec<TokenStream> = Vec::new();
+    let mut shape_checks: Vec<TokenStream> = Vec::new();
     // Container-level attributes
     for attr in &pe.container.attrs.facet {
         if let Some(phantom) = phantom_attr_use(attr, &facet_crate) {
@@ -153,7 +154,11 @@ pub(crate) fn process_enum(parsed: Enum) -> TokenStream {
             PVariantKind::Struct { fields } => fields,
         };
         match collect_trailing_shape_checks(fields, &facet_crate) {
-            Ok(checks) => trailing_shape_checks.extend(checks),
+            Ok(checks) => shape_checks.extend(checks),
+            Err(err) => return err,
+        }
+        match collect_flatten_prefix_checks(fields, &facet_crate) {
+            Ok(checks) => shape_checks.extend(checks),
             Err(err) => return err,
         }
         for field in fields {
@@ -311,6 +316,8 @@ pub(crate) fn process_enum(parsed: Enum) -> TokenStream {
                         | "traits"
                         | "auto_traits" // deprecated but still recognized
                         | "proxy"
+                        | "from"
+                        | "into"
                         | "truthy"
                         | "skip_all_unless_truthy"
                         | "where"
@@ -339,6 +346,9 @@ pub(crate) fn process_enum(parsed: Enum) -> TokenStream {
         }
     };
 
+    // Conventions handed down to child types
+    let child_defaults_call = gen_child_defaults_call(&pe.container.attrs, &facet_crate);
+
     // Tag field name for internally/adjacently tagged enums - returns builder call only if present
     let tag_call = {
         if let Some(tag) = pe.container.attrs.get_builtin_args("tag") {
@@ -428,6 +438,20 @@ pub(crate) fn process_enum(parsed: Enum) -> TokenStream {
         quote! {}
     };
 
+    // Ordered fields flag - set if any variant has a field with `order`
+    let has_ordered_fields = pe.variants.iter().any(|variant| {
+        let fields: &[PStructField] = match &variant.kind {
+            PVariantKind::Unit => &[],
+            PVariantKind::Tuple { fields } | PVariantKind::Struct { fields } => fields,
+        };
+        fields.iter().any(|f| f.attrs.has_builtin("order"))
+    });
+    let ordered_fields_call = if has_ordered_fields {
+        quote! { .ordered_fields() }
+    } else {
+        quote! {}
+    };
+
     // Container-level proxy from PEnum - generates ProxyDef with conversion functions.
     // Generic and lifetime parameters are not available inside `const {}` blocks, so
     // we define the proxy helpers in an inherent impl and reference them from SHAPE.
@@ -511,6 +535,10 @@ pub(crate) fn process_enum(parsed: Enum) -> TokenStream {
         }
     };
 
+    // Container-level `from` / `into` conversions
+    let (conversion_inherent_impl, conversion_call) =
+        gen_container_conversions(&pe.container, &where_clauses, &facet_crate);
+
     // Container-level opaque adapter from PEnum.
     let opaque_adapter_call = {
         if pe
@@ -1403,7 +1431,7 @@ pub(crate) fn process_enum(parsed: Enum) -> TokenStream {
         unsafe impl #bgp_def #facet_crate::Facet<'ʄ> for #enum_name #bgp_without_bounds #where_clauses {
             const SHAPE: &'static #facet_crate::Shape = &const {
                 use #facet_crate::𝟋::*;
-                #(#trailing_shape_checks)*
+                #(#shape_checks)*
                 #(#shadow_struct_defs)*
                 #fields
                 𝟋ShpB::for_sized::<Self>(#enum_name_str)
@@ -1420,12 +1448,15 @@ pub(crate) fn process_enum(parsed: Enum) -> TokenStream {
                     #doc_call
                     #attributes_call
                     #type_tag_call
+                    #child_defaults_call
                     #tag_call
                     #content_call
                     #untagged_call
                     #is_numeric_call
                     #pod_call
+                    #ordered_fields_call
                     #proxy_call
+                    #conversion_call
                     #opaque_adapter_call
                     #variance_call
                     .build()
@@ -1437,6 +1468,9 @@ pub(crate) fn process_enum(parsed: Enum) -> TokenStream {
         // proxy inherent impl
         #proxy_inherent_impl
 
+        // conversion inherent impls (`from` / `into`)
+        #conversion_inherent_impl
+
         // opaque adapter inherent impl
         #opaque_adapter_inherent_impl
 
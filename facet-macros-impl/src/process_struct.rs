@@ -142,6 +142,21 @@ pub(crate) fn phantom_attr_use(
     Some(quote! { { use #facet_crate::builtin::Attr::#variant_ident as _; } })
 }
 
+/// Generate the `.child_defaults(...)` builder call for
+/// `#[facet(defaults_for_children(...))]`, if present.
+pub(crate) fn gen_child_defaults_call(attrs: &PAttrs, facet_crate: &TokenStream) -> TokenStream {
+    match attrs.child_rename_all {
+        Some(rule) => {
+            let rule =
+                proc_macro2::Ident::new(&format!("{rule:?}"), proc_macro2::Span::call_site());
+            quote! {
+                .child_defaults(#facet_crate::ChildDefaults::new().rename_all(#facet_crate::RenameRule::#rule))
+            }
+        }
+        None => quote! {},
+    }
+}
+
 pub(crate) fn collect_trailing_shape_checks(
     fields: &[PStructField],
     facet_crate: &TokenStream,
@@ -186,6 +201,70 @@ pub(crate) fn collect_trailing_shape_checks(
     Ok(trailing_shape_checks)
 }
 
+/// Validates `#[facet(flatten_prefix_from = "...")]` on `fields`.
+///
+/// The attribute must sit on a flattened field and name one of its siblings;
+/// both are checked here. Whether the sibling can be written as text and the
+/// flattened field is a struct depends on their shapes, so those become
+/// checks in the `SHAPE` const.
+pub(crate) fn collect_flatten_prefix_checks(
+    fields: &[PStructField],
+    facet_crate: &TokenStream,
+) -> std::result::Result<Vec<TokenStream>, TokenStream> {
+    let mut checks = Vec::new();
+
+    for (idx, field) in fields.iter().enumerate() {
+        let Some(attr) = field
+            .attrs
+            .facet
+            .iter()
+            .find(|a| a.is_builtin() && a.key_str() == "flatten_prefix_from")
+        else {
+            continue;
+        };
+        let span = attr.key.span();
+        if !field.attrs.has_builtin("flatten") {
+            return Err(quote_spanned! { span =>
+                compile_error!("`#[facet(flatten_prefix_from)]` requires `#[facet(flatten)]` on the same field");
+            });
+        }
+        let source = attr.args.to_string().trim().trim_matches('"').to_string();
+        let Some(sibling) = fields
+            .iter()
+            .enumerate()
+            .find(|(i, f)| *i != idx && f.name.original == source)
+            .map(|(_, f)| f)
+        else {
+            let message =
+                format!("`#[facet(flatten_prefix_from)]` names no sibling field `{source}`");
+            return Err(quote_spanned! { span => compile_error!(#message); });
+        };
+
+        let sibling_type = &sibling.ty;
+        let field_type = &field.ty;
+        let not_text = format!(
+            "`#[facet(flatten_prefix_from)]` requires `{source}` to hold a string or a `Display` type"
+        );
+        let not_struct = format!(
+            "`#[facet(flatten_prefix_from)]` can only prefix a flattened struct, not `{}`",
+            field.name.original
+        );
+        checks.push(quote_spanned! { span =>
+            if !<#sibling_type as #facet_crate::Facet<'ʄ>>::SHAPE.innermost_has_display() {
+                panic!(#not_text);
+            }
+            if !matches!(
+                <#field_type as #facet_crate::Facet<'ʄ>>::SHAPE.flatten_target().0.ty,
+                #facet_crate::Type::User(#facet_crate::UserType::Struct(_))
+            ) {
+                panic!(#not_struct);
+            }
+        });
+    }
+
+    Ok(checks)
+}
+
 /// Generates the vtable for a type based on trait sources.
 ///
 /// Uses a layered approach for each trait:
@@ -821,6 +900,7 @@ pub(crate) fn gen_field_from_pfield(
     // Everything else goes into the attributes slice.
     //
     // Flag attrs: sensitive, flatten, child, skip, skip_serializing, skip_deserializing
+    // (order sets ORDERED and also keeps its value in the attributes slice)
     // Field attrs: rename, alias
     // Note: default also sets HAS_DEFAULT flag (handled below)
 
@@ -895,6 +975,18 @@ pub(crate) fn gen_field_from_pfield(
                         default_value = Some(DefaultKind::Custom(args.clone()));
                     }
                 }
+                "order" => {
+                    // The flag spares unordered structs the attribute lookups
+                    flags.push(quote! { 𝟋FF::ORDERED });
+                    let ext_attr = emit_attr_for_field(
+                        attr,
+                        field_name_raw,
+                        field_type,
+                        facet_crate,
+                        needs_const_dispatch,
+                    );
+                    attribute_list.push(quote! { #ext_attr });
+                }
                 "recursive_type" => {
                     // recursive_type sets a flag
                     flags.push(quote! { 𝟋FF::RECURSIVE_TYPE });
@@ -1371,6 +1463,144 @@ pub(crate) fn gen_field_from_pfield(
     }
 }
 
+/// Generates the container-level `#[facet(from = ...)]` and `#[facet(into = ...)]`
+/// conversions: an inherent impl holding the conversion functions, and the builder
+/// calls that record them on the shape.
+///
+/// Like the container proxy, the functions live in an inherent impl so the type's
+/// generic parameters are in scope, and `SHAPE` refers to them through `<Self>`.
+pub(crate) fn gen_container_conversions(
+    container: &PContainer,
+    where_clauses: &TokenStream,
+    facet_crate: &TokenStream,
+) -> (TokenStream, TokenStream) {
+    let find = |key: &str| {
+        container
+            .attrs
+            .facet
+            .iter()
+            .find(|a| a.is_builtin() && a.key_str() == key)
+            .map(|a| &a.args)
+    };
+    let from_type = find("from");
+    let into_type = find("into");
+    if from_type.is_none() && into_type.is_none() {
+        return (quote! {}, quote! {});
+    }
+
+    let type_ident = &container.name;
+    let bgp_display = container.bgp.display_without_bounds();
+    let helper_bgp = container
+        .bgp
+        .with_lifetime(LifetimeName(format_ident!("ʄ")));
+    let bgp_def_for_helper = helper_bgp.display_with_bounds();
+    let where_with = |other: &TokenStream| {
+        let additional_clauses = quote! { #other: #facet_crate::Facet<'ʄ> };
+        if where_clauses.is_empty() {
+            quote! { where #additional_clauses }
+        } else {
+            quote! { #where_clauses, #additional_clauses }
+        }
+    };
+
+    let mut impls = TokenStream::new();
+    let mut calls = TokenStream::new();
+
+    if let Some(from_type) = from_type {
+        let from_where = where_with(from_type);
+        impls.extend(quote! {
+            #[doc(hidden)]
+            impl #bgp_def_for_helper #type_ident #bgp_display
+            #from_where
+            {
+                #[doc(hidden)]
+                unsafe fn __facet_from_convert_in(
+                    from_ptr: #facet_crate::PtrConst,
+                    target_ptr: #facet_crate::PtrUninit,
+                ) -> ::core::result::Result<#facet_crate::PtrMut, #facet_crate::𝟋::𝟋Str> {
+                    let from: #from_type = from_ptr.read();
+                    let value = <#type_ident #bgp_display as ::core::convert::From<#from_type>>::from(from);
+                    #facet_crate::𝟋::𝟋Ok(target_ptr.put(value))
+                }
+
+                #[doc(hidden)]
+                unsafe fn __facet_from_convert_out(
+                    _target_ptr: #facet_crate::PtrConst,
+                    _from_ptr: #facet_crate::PtrUninit,
+                ) -> ::core::result::Result<#facet_crate::PtrMut, #facet_crate::𝟋::𝟋Str> {
+                    extern crate alloc as __alloc;
+                    #facet_crate::𝟋::𝟋Err(__alloc::string::String::from(
+                        "`#[facet(from = ...)]` is only used for deserialization",
+                    ))
+                }
+
+                #[doc(hidden)]
+                const fn __facet_from_shape() -> &'static #facet_crate::Shape {
+                    <#from_type as #facet_crate::Facet>::SHAPE
+                }
+            }
+        });
+        calls.extend(quote! {
+            .from_conversion(&const {
+                #facet_crate::ProxyDef {
+                    shape: <Self>::__facet_from_shape(),
+                    convert_in: <Self>::__facet_from_convert_in,
+                    convert_out: <Self>::__facet_from_convert_out,
+                }
+            })
+        });
+    }
+
+    if let Some(into_type) = into_type {
+        let into_where = where_with(into_type);
+        impls.extend(quote! {
+            #[doc(hidden)]
+            impl #bgp_def_for_helper #type_ident #bgp_display
+            #into_where
+            {
+                #[doc(hidden)]
+                unsafe fn __facet_into_convert_in(
+                    _into_ptr: #facet_crate::PtrConst,
+                    _target_ptr: #facet_crate::PtrUninit,
+                ) -> ::core::result::Result<#facet_crate::PtrMut, #facet_crate::𝟋::𝟋Str> {
+                    extern crate alloc as __alloc;
+                    #facet_crate::𝟋::𝟋Err(__alloc::string::String::from(
+                        "`#[facet(into = ...)]` is only used for serialization",
+                    ))
+                }
+
+                #[doc(hidden)]
+                unsafe fn __facet_into_convert_out(
+                    target_ptr: #facet_crate::PtrConst,
+                    into_ptr: #facet_crate::PtrUninit,
+                ) -> ::core::result::Result<#facet_crate::PtrMut, #facet_crate::𝟋::𝟋Str> {
+                    let target: &#type_ident #bgp_display = target_ptr.get();
+                    let into = <#into_type as ::core::convert::From<#type_ident #bgp_display>>::from(
+                        ::core::clone::Clone::clone(target),
+                    );
+                    #facet_crate::𝟋::𝟋Ok(into_ptr.put(into))
+                }
+
+                #[doc(hidden)]
+                const fn __facet_into_shape() -> &'static #facet_crate::Shape {
+                    <#into_type as #facet_crate::Facet>::SHAPE
+                }
+            }
+        });
+        calls.extend(quote! {
+            .into_conversion(&const {
+                #facet_crate::ProxyDef {
+                    shape: <Self>::__facet_into_shape(),
+                    convert_in: <Self>::__facet_into_convert_in,
+                    convert_out: <Self>::__facet_into_convert_out,
+                }
+            })
+        });
+    }
+
+    (impls, calls)
+}
+
 /// Processes a regular struct to implement Facet
 ///
 /// Example input:
@@ -1551,10 +1781,14 @@ pub(crate) fn process_struct(parsed: Struct) -> TokenStream {
         PStructKind::UnitStruct => &[],
     };
 
-    let trailing_shape_checks = match collect_trailing_shape_checks(fields, &facet_crate) {
+    let mut shape_checks = match collect_trailing_shape_checks(fields, &facet_crate) {
         Ok(checks) => checks,
         Err(err) => return err,
     };
+    match collect_flatten_prefix_checks(fields, &facet_crate) {
+        Ok(checks) => shape_checks.extend(checks),
+        Err(err) => return err,
+    }
 
     // MVP validation: adapter form is container-only for now.
     for field in fields {
@@ -1923,6 +2157,7 @@ pub(crate) fn process_struct(parsed: Struct) -> TokenStream {
                 // - traits: compile-time directive for vtable generation
                 // - auto_traits: deprecated, now the default (kept for backward compat)
                 // - proxy: sets Shape::proxy for container-level proxy
+                // - from / into: set Shape::from_conversion / Shape::into_conversion
                 // - ns::proxy: sets Shape::format_proxies for format-specific container-level proxy
                 // - where: compile-time directive for custom generic bounds
                 if attr.is_builtin() {
@@ -1937,6 +2172,8 @@ pub(crate) fn process_struct(parsed: Struct) -> TokenStream {
                             | "traits"
                             | "auto_traits" // deprecated but still recognized
                             | "proxy"
+                            | "from"
+                            | "into"
                             | "truthy"
                             | "skip_all_unless_truthy"
                             | "where"
@@ -1975,6 +2212,13 @@ pub(crate) fn process_struct(parsed: Struct) -> TokenStream {
         quote! {}
     };
 
+    // Ordered fields flag - spares serializers sorting structs without `order`
+    let ordered_fields_call = if fields.iter().any(|f| f.attrs.has_builtin("order")) {
+        quote! { .ordered_fields() }
+    } else {
+        quote! {}
+    };
+
     // Type tag from PStruct - returns builder call only if present
     let type_tag_call = {
         if let Some(type_tag) = ps.container.attrs.get_builtin_args("type_tag") {
@@ -1984,6 +2228,9 @@ pub(crate) fn process_struct(parsed: Struct) -> TokenStream {
         }
     };
 
+    // Conventions handed down to child types
+    let child_defaults_call = gen_child_defaults_call(&ps.container.attrs, &facet_crate);
+
     // Container-level proxy from PStruct - generates ProxyDef with conversion functions
     //
     // The challenge: Generic type parameters aren't available inside `const { }` blocks.
@@ -2087,6 +2334,10 @@ pub(crate) fn process_struct(parsed: Struct) -> TokenStream {
         }
     };
 
+    // Container-level `from` / `into` conversions
+    let (conversion_inherent_impl, conversion_call) =
+        gen_container_conversions(&ps.container, &where_clauses, &facet_crate);
+
     // Container-level opaque adapter from PStruct.
     let (opaque_adapter_inherent_impl, opaque_adapter_call) = {
         if let Some(attr) = ps
@@ -2523,7 +2774,7 @@ pub(crate) fn process_struct(parsed: Struct) -> TokenStream {
             const __SHAPE_DATA: #facet_crate::Shape = {
                 use #facet_crate::𝟋::*;
 
-                #(#trailing_shape_checks)*
+                #(#shape_checks)*
 
                 𝟋ShpB::for_sized::<Self>(#struct_name_str)
                     .module_path(::core::module_path!())
@@ -2539,13 +2790,16 @@ pub(crate) fn process_struct(parsed: Struct) -> TokenStream {
                     #doc_call
                     #attributes_call
                     #type_tag_call
+                    #child_defaults_call
                     #proxy_call
+                    #conversion_call
                     #opaque_adapter_call
                     #format_proxies_call
                     #inner_call
                     #variance_call
                     #pod_call
                     #metadata_container_call
+                    #ordered_fields_call
                     .build()
             };
         }
@@ -2581,6 +2835,9 @@ pub(crate) fn process_struct(parsed: Struct) -> TokenStream {
         // Proxy inherent impl (outside the Facet impl so generic params are in scope)
         #proxy_inherent_impl
 
+        // Conversion inherent impls (`from` / `into`)
+        #conversion_inherent_impl
+
         // Opaque adapter inherent impl
         #opaque_adapter_inherent_impl
 
@@ -15,6 +15,24 @@
 //! let bytes = to_vec(&point).unwrap();
 //! ```
 //!
+//! Structs are written as maps keyed by field name. For a smaller encoding
+//! that relies on both sides agreeing on field order, write them as arrays of
+//! field values instead:
+//!
+//! ```
+//! use facet::Facet;
+//! use facet_msgpack::{SerializeOptions, to_vec_with_options};
+//!
+//! #[derive(Facet)]
+//! struct Point { x: i32, y: i32 }
+//!
+//! let options = SerializeOptions::new().compact();
+//! let bytes = to_vec_with_options(&Point { x: 10, y: 20 }, &options).unwrap();
+//! assert_eq!(bytes, [0x92, 0x0a, 0x14]);
+//! ```
+//!
+//! Byte buffers (`Vec<u8>`, `&[u8]`) are written as MsgPack `bin` values.
+//!
 //! # Deserialization
 //!
 //! There are two deserialization functions:
@@ -38,6 +56,9 @@
 //! assert_eq!(point.y, 20);
 //! ```
 //!
+//! Structs are read from either encoding, maps or arrays, and `Vec<u8>` from
+//! either `bin` or an array of integers.
+//!
 //! Deserialization is driven by `facet-format` over the MessagePack parser, so
 //! all supported `Facet` shapes use the same parser/deserializer path.
 
@@ -58,7 +79,10 @@ pub use error::MsgPackError;
 #[cfg(feature = "axum")]
 pub use axum::{MsgPack, MsgPackRejection, MsgPackSerializeRejection};
 pub use parser::MsgPackParser;
-pub use serializer::{MsgPackSerializeError, MsgPackSerializer, to_vec, to_writer};
+pub use serializer::{
+    MsgPackSerializeError, MsgPackSerializer, SerializeOptions, StructEncoding, to_vec,
+    to_vec_with_options, to_writer, to_writer_with_options,
+};
 
 // Re-export DeserializeError for convenience
 pub use facet_format::DeserializeError;
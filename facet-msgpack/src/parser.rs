@@ -756,6 +756,11 @@ impl<'de> FormatParser<'de> for MsgPackParser<'de> {
         Ok(())
     }
 
+    fn accepts_positional_structs(&self) -> bool {
+        // Structs written with `StructEncoding::Array` are plain arrays
+        true
+    }
+
     fn save(&mut self) -> SavePoint {
         // MsgPack is self-describing but save/restore would need full state cloning
         // For now, unimplemented - can be added if needed for solver support
@@ -5,7 +5,49 @@ extern crate alloc;
 use alloc::{string::String, vec::Vec};
 use core::fmt::Write as _;
 
-use facet_format::{FormatSerializer, ScalarValue, SerializeError};
+use facet_format::{FormatSerializer, ScalarValue, SerializeError, StructFieldMode};
+
+/// How structs and struct variants are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StructEncoding {
+    /// As a map from field name to value (default).
+    ///
+    /// Readers match fields by name, so fields can be added, removed or
+    /// reordered without breaking existing data.
+    #[default]
+    Map,
+    /// As an array of field values, in declaration order.
+    ///
+    /// Smaller and faster to read, but readers must agree on the field
+    /// order. This is what `rmp_serde::to_vec` writes.
+    Array,
+}
+
+/// Options for MsgPack serialization.
+#[derive(Debug, Clone, Default)]
+pub struct SerializeOptions {
+    /// How structs are written (default: `Map`)
+    pub struct_encoding: StructEncoding,
+}
+
+impl SerializeOptions {
+    /// Create new default options (structs as maps).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Write structs as arrays of field values.
+    pub const fn compact(mut self) -> Self {
+        self.struct_encoding = StructEncoding::Array;
+        self
+    }
+
+    /// Set how structs are written.
+    pub const fn struct_encoding(mut self, encoding: StructEncoding) -> Self {
+        self.struct_encoding = encoding;
+        self
+    }
+}
 
 /// MsgPack serializer error.
 #[derive(Debug)]
@@ -26,20 +68,40 @@ pub struct MsgPackSerializer {
     out: Vec<u8>,
     /// Stack tracking whether we're in a struct or sequence, and item counts
     stack: Vec<ContainerState>,
+    options: SerializeOptions,
 }
 
 #[derive(Debug)]
 enum ContainerState {
-    Struct { count: usize, count_pos: usize },
-    Seq { count: usize, count_pos: usize },
+    /// A struct or map. With `StructEncoding::Array`, a struct whose fields
+    /// were written without keys becomes an array of its `values`; otherwise
+    /// it's a map of `count` entries.
+    Struct {
+        count: usize,
+        values: usize,
+        count_pos: usize,
+        is_map: bool,
+    },
+    Seq {
+        count: usize,
+        count_pos: usize,
+    },
 }
 
 impl MsgPackSerializer {
     /// Create a new MsgPack serializer.
     pub const fn new() -> Self {
+        Self::with_options(SerializeOptions {
+            struct_encoding: StructEncoding::Map,
+        })
+    }
+
+    /// Create a new MsgPack serializer with the given options.
+    pub const fn with_options(options: SerializeOptions) -> Self {
         Self {
             out: Vec::new(),
             stack: Vec::new(),
+            options,
         }
     }
 
@@ -48,7 +110,9 @@ impl MsgPackSerializer {
         // Patch up any remaining container counts (shouldn't happen with well-formed input)
         while let Some(state) = self.stack.pop() {
             match state {
-                ContainerState::Struct { count, count_pos } => {
+                ContainerState::Struct {
+                    count, count_pos, ..
+                } => {
                     self.patch_map_count(count_pos, count);
                 }
                 ContainerState::Seq { count, count_pos } => {
@@ -250,10 +314,25 @@ impl MsgPackSerializer {
         }
     }
 
-    /// Record a value emission in the current sequence, if any.
-    fn bump_seq_count_for_value(&mut self) {
-        if let Some(ContainerState::Seq { count, .. }) = self.stack.last_mut() {
-            *count += 1;
+    /// Start a struct or map, to be written as a map unless it turns out to
+    /// be a keyless struct in array mode.
+    fn begin_keyed(&mut self, is_map: bool) {
+        self.bump_count_for_value();
+        let count_pos = self.begin_map();
+        self.stack.push(ContainerState::Struct {
+            count: 0,
+            values: 0,
+            count_pos,
+            is_map,
+        });
+    }
+
+    /// Record a value emission in the current container, if any.
+    fn bump_count_for_value(&mut self) {
+        match self.stack.last_mut() {
+            Some(ContainerState::Seq { count, .. }) => *count += 1,
+            Some(ContainerState::Struct { values, .. }) => *values += 1,
+            None => {}
         }
     }
 }
@@ -268,12 +347,7 @@ impl FormatSerializer for MsgPackSerializer {
     type Error = MsgPackSerializeError;
 
     fn begin_struct(&mut self) -> Result<(), Self::Error> {
-        self.bump_seq_count_for_value();
-        let count_pos = self.begin_map();
-        self.stack.push(ContainerState::Struct {
-            count: 0,
-            count_pos,
-        });
+        self.begin_keyed(false);
         Ok(())
     }
 
@@ -288,7 +362,32 @@ impl FormatSerializer for MsgPackSerializer {
 
     fn end_struct(&mut self) -> Result<(), Self::Error> {
         match self.stack.pop() {
-            Some(ContainerState::Struct { count, count_pos }) => {
+            // In array mode, struct fields come without keys; keys only
+            // appear in the maps that wrap enum variants.
+            Some(ContainerState::Struct {
+                count: 0,
+                values,
+                count_pos,
+                is_map: false,
+            }) if self.options.struct_encoding == StructEncoding::Array => {
+                self.patch_array_count(count_pos, values);
+                Ok(())
+            }
+            Some(ContainerState::Struct {
+                count,
+                values,
+                count_pos,
+                ..
+            }) => {
+                // Keyless fields mixed into a map come from internally or
+                // adjacently tagged struct variants, which have no array form.
+                if self.options.struct_encoding == StructEncoding::Array && count != values {
+                    return Err(MsgPackSerializeError {
+                        message:
+                            "tagged struct variants can't be written with StructEncoding::Array"
+                                .into(),
+                    });
+                }
                 self.patch_map_count(count_pos, count);
                 Ok(())
             }
@@ -298,8 +397,13 @@ impl FormatSerializer for MsgPackSerializer {
         }
     }
 
+    fn begin_map_with_len(&mut self, _len: usize) -> Result<(), Self::Error> {
+        self.begin_keyed(true);
+        Ok(())
+    }
+
     fn begin_seq(&mut self) -> Result<(), Self::Error> {
-        self.bump_seq_count_for_value();
+        self.bump_count_for_value();
         let count_pos = self.begin_array();
         self.stack.push(ContainerState::Seq {
             count: 0,
@@ -321,7 +425,7 @@ impl FormatSerializer for MsgPackSerializer {
     }
 
     fn scalar(&mut self, scalar: ScalarValue<'_>) -> Result<(), Self::Error> {
-        self.bump_seq_count_for_value();
+        self.bump_count_for_value();
 
         match scalar {
             ScalarValue::Null | ScalarValue::Unit => self.write_nil(),
@@ -356,6 +460,19 @@ impl FormatSerializer for MsgPackSerializer {
         Ok(())
     }
 
+    fn serialize_byte_sequence(&mut self, bytes: &[u8]) -> Result<bool, Self::Error> {
+        self.bump_count_for_value();
+        self.write_bin(bytes);
+        Ok(true)
+    }
+
+    fn struct_field_mode(&self) -> StructFieldMode {
+        match self.options.struct_encoding {
+            StructEncoding::Map => StructFieldMode::Named,
+            StructEncoding::Array => StructFieldMode::Unnamed,
+        }
+    }
+
     fn is_self_describing(&self) -> bool {
         false
     }
@@ -371,12 +488,53 @@ where
     Ok(ser.finish())
 }
 
+/// Serialize a value to MsgPack bytes with custom options.
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+/// use facet_msgpack::{SerializeOptions, to_vec_with_options};
+///
+/// #[derive(Facet)]
+/// struct Point { x: i32, y: i32 }
+///
+/// let bytes = to_vec_with_options(&Point { x: 10, y: 20 }, &SerializeOptions::new().compact()).unwrap();
+/// // [10, 20]
+/// assert_eq!(bytes, [0x92, 0x0a, 0x14]);
+/// ```
+pub fn to_vec_with_options<'facet, T>(
+    value: &T,
+    options: &SerializeOptions,
+) -> Result<Vec<u8>, SerializeError<MsgPackSerializeError>>
+where
+    T: facet_core::Facet<'facet>,
+{
+    let mut ser = MsgPackSerializer::with_options(options.clone());
+    facet_format::serialize_root(&mut ser, facet_reflect::Peek::new(value))?;
+    Ok(ser.finish())
+}
+
 /// Serialize a value to MsgPack bytes using a writer.
 pub fn to_writer<'facet, T, W>(writer: &mut W, value: &T) -> Result<(), std::io::Error>
 where
     T: facet_core::Facet<'facet>,
     W: std::io::Write,
 {
-    let bytes = to_vec(value).map_err(|e| std::io::Error::other(e.to_string()))?;
+    to_writer_with_options(writer, value, &SerializeOptions::default())
+}
+
+/// Serialize a value to MsgPack bytes using a writer, with custom options.
+pub fn to_writer_with_options<'facet, T, W>(
+    writer: &mut W,
+    value: &T,
+    options: &SerializeOptions,
+) -> Result<(), std::io::Error>
+where
+    T: facet_core::Facet<'facet>,
+    W: std::io::Write,
+{
+    let bytes =
+        to_vec_with_options(value, options).map_err(|e| std::io::Error::other(e.to_string()))?;
     writer.write_all(&bytes)
 }
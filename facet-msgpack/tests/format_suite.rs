@@ -351,8 +351,9 @@ impl FormatSuite for MsgPackSlice {
     }
 
     fn bytes_vec_u8() -> CaseSpec {
-        // serde serializes Vec<u8> as binary, facet expects array of integers
-        CaseSpec::skip("Vec<u8> representation differs: serde=binary, facet=array")
+        // Input generated by rmp-serde: an array of integers, which facet reads
+        // as well as `bin`
+        CaseSpec::from_bytes_vec(msgpack::bytes_vec_u8_bytes())
     }
 
     fn array_fixed_size() -> CaseSpec {
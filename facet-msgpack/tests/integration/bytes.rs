@@ -0,0 +1,75 @@
+//! Tests for byte buffers, written as MsgPack `bin`.
+
+use facet::Facet;
+use facet_msgpack::{from_slice, from_slice_borrowed, to_vec};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Facet, PartialEq, Serialize, Deserialize)]
+struct Blob {
+    #[serde(with = "serde_bytes")]
+    data: Vec<u8>,
+}
+
+#[test]
+fn test_vec_u8_is_bin() {
+    let bytes = to_vec(&vec![0xDEu8, 0xAD, 0xBE, 0xEF]).unwrap();
+    assert_eq!(bytes, [0xc4, 0x04, 0xDE, 0xAD, 0xBE, 0xEF]);
+
+    let result: Vec<u8> = from_slice(&bytes).unwrap();
+    assert_eq!(result, [0xDE, 0xAD, 0xBE, 0xEF]);
+}
+
+#[test]
+fn test_large_vec_u8_uses_bin16() {
+    let data = vec![7u8; 300];
+    let bytes = to_vec(&data).unwrap();
+    assert_eq!(&bytes[..3], [0xc5, 0x01, 0x2c]);
+    assert_eq!(bytes.len(), 303);
+
+    let result: Vec<u8> = from_slice(&bytes).unwrap();
+    assert_eq!(result, data);
+}
+
+#[test]
+fn test_borrowed_slice_is_bin() {
+    #[derive(Debug, Facet, PartialEq)]
+    struct Frame<'a> {
+        payload: &'a [u8],
+    }
+
+    let frame = Frame {
+        payload: &[1, 2, 3],
+    };
+    let bytes = to_vec(&frame).unwrap();
+    assert_eq!(
+        bytes,
+        [
+            0x81, 0xa7, b'p', b'a', b'y', b'l', b'o', b'a', b'd', 0xc4, 0x03, 1, 2, 3
+        ]
+    );
+
+    let result: Frame = from_slice_borrowed(&bytes).unwrap();
+    assert_eq!(result, frame);
+}
+
+#[test]
+fn test_bin_compat_with_rmp() {
+    let blob = Blob {
+        data: vec![0, 1, 2, 255],
+    };
+
+    let bytes = to_vec(&blob).unwrap();
+    let result: Blob = rmp_serde::from_slice(&bytes).unwrap();
+    assert_eq!(result, blob);
+
+    let rmp_bytes = rmp_serde::to_vec_named(&blob).unwrap();
+    assert_eq!(rmp_bytes, bytes);
+}
+
+#[test]
+fn test_vec_u8_from_integer_array() {
+    // Older writers (and serde without serde_bytes) use an array of integers
+    let bytes = [0x93, 0x01, 0x02, 0xcc, 0xff];
+    let result: Vec<u8> = from_slice(&bytes).unwrap();
+    assert_eq!(result, [1, 2, 255]);
+}
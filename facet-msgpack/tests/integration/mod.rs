@@ -1 +1,3 @@
+mod bytes;
 mod roundtrip;
+mod struct_encoding;
@@ -0,0 +1,168 @@
+//! Tests for writing structs as maps or as arrays of field values.
+
+use facet::Facet;
+use facet_msgpack::{SerializeOptions, StructEncoding, from_slice, to_vec, to_vec_with_options};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Facet, PartialEq, Serialize, Deserialize)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, Facet, PartialEq, Serialize, Deserialize)]
+struct Shape {
+    name: String,
+    points: Vec<Point>,
+    closed: Option<bool>,
+}
+
+#[derive(Debug, Facet, PartialEq, Serialize, Deserialize)]
+#[repr(u8)]
+enum Event {
+    Start,
+    Move(Point),
+    Resize { width: u32, height: u32 },
+}
+
+fn compact() -> SerializeOptions {
+    SerializeOptions::new().compact()
+}
+
+#[test]
+fn test_default_is_map() {
+    let point = Point { x: 1, y: 2 };
+    assert_eq!(
+        SerializeOptions::default().struct_encoding,
+        StructEncoding::Map
+    );
+    assert_eq!(
+        to_vec(&point).unwrap(),
+        [0x82, 0xa1, b'x', 0x01, 0xa1, b'y', 0x02]
+    );
+}
+
+#[test]
+fn test_array_encoding_bytes() {
+    let point = Point { x: 1, y: 2 };
+    assert_eq!(
+        to_vec_with_options(&point, &compact()).unwrap(),
+        [0x92, 0x01, 0x02]
+    );
+}
+
+#[test]
+fn test_array_encoding_roundtrip() {
+    let shape = Shape {
+        name: "triangle".to_string(),
+        points: vec![
+            Point { x: 0, y: 0 },
+            Point { x: 4, y: 0 },
+            Point { x: 0, y: 3 },
+        ],
+        closed: None,
+    };
+
+    let compact_bytes = to_vec_with_options(&shape, &compact()).unwrap();
+    let map_bytes = to_vec(&shape).unwrap();
+    assert!(compact_bytes.len() < map_bytes.len());
+
+    let result: Shape = from_slice(&compact_bytes).unwrap();
+    assert_eq!(result, shape);
+}
+
+#[test]
+fn test_array_encoding_enums() {
+    for event in [
+        Event::Start,
+        Event::Move(Point { x: 3, y: 4 }),
+        Event::Resize {
+            width: 640,
+            height: 480,
+        },
+    ] {
+        let bytes = to_vec_with_options(&event, &compact()).unwrap();
+        let result: Event = from_slice(&bytes).unwrap();
+        assert_eq!(result, event);
+    }
+}
+
+#[test]
+fn test_array_encoding_compat_with_rmp() {
+    let shape = Shape {
+        name: "line".to_string(),
+        points: vec![Point { x: 1, y: 1 }, Point { x: 5, y: 5 }],
+        closed: Some(false),
+    };
+
+    // rmp_serde::to_vec writes structs as arrays
+    let bytes = to_vec_with_options(&shape, &compact()).unwrap();
+    assert_eq!(bytes, rmp_serde::to_vec(&shape).unwrap());
+    let result: Shape = rmp_serde::from_slice(&bytes).unwrap();
+    assert_eq!(result, shape);
+
+    let event = Event::Resize {
+        width: 2,
+        height: 3,
+    };
+    let rmp_bytes = rmp_serde::to_vec(&event).unwrap();
+    let result: Event = from_slice(&rmp_bytes).unwrap();
+    assert_eq!(result, event);
+}
+
+#[test]
+fn test_maps_stay_maps() {
+    use std::collections::BTreeMap;
+
+    let mut map = BTreeMap::new();
+    map.insert("a".to_string(), Point { x: 1, y: 2 });
+    let empty: BTreeMap<String, u32> = BTreeMap::new();
+
+    let bytes = to_vec_with_options(&map, &compact()).unwrap();
+    assert_eq!(bytes, [0x81, 0xa1, b'a', 0x92, 0x01, 0x02]);
+    let result: BTreeMap<String, Point> = from_slice(&bytes).unwrap();
+    assert_eq!(result, map);
+
+    assert_eq!(to_vec_with_options(&empty, &compact()).unwrap(), [0x80]);
+}
+
+#[test]
+fn test_skipped_fields_keep_positions() {
+    #[derive(Debug, Facet, PartialEq)]
+    struct Record {
+        id: u32,
+        #[facet(skip, default)]
+        cached: u32,
+        label: String,
+    }
+
+    let record = Record {
+        id: 7,
+        cached: 99,
+        label: "seven".to_string(),
+    };
+    let bytes = to_vec_with_options(&record, &compact()).unwrap();
+    let result: Record = from_slice(&bytes).unwrap();
+    assert_eq!(
+        result,
+        Record {
+            id: 7,
+            cached: 0,
+            label: "seven".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_internally_tagged_is_an_error() {
+    #[derive(Debug, Facet)]
+    #[facet(tag = "type")]
+    #[repr(u8)]
+    #[allow(dead_code)]
+    enum Tagged {
+        Circle { radius: u32 },
+    }
+
+    assert!(to_vec_with_options(&Tagged::Circle { radius: 1 }, &compact()).is_err());
+    assert!(to_vec(&Tagged::Circle { radius: 1 }).is_ok());
+}
@@ -278,7 +278,7 @@ impl Path {
                     current_variant_idx = None;
                 }
                 PathStep::Proxy => {
-                    if let Some(proxy_def) = current_shape.effective_proxy(None) {
+                    if let Some(proxy_def) = current_shape.deserialize_proxy(None) {
                         current_shape = proxy_def.shape;
                     }
                     current_variant_idx = None;
@@ -351,7 +351,7 @@ impl Path {
                     current_variant_idx = None;
                 }
                 PathStep::Proxy => {
-                    let proxy_def = current_shape.effective_proxy(None)?;
+                    let proxy_def = current_shape.deserialize_proxy(None)?;
                     current_shape = proxy_def.shape;
                     current_variant_idx = None;
                 }
@@ -38,6 +38,29 @@
 //!
 //! Deserialization is driven by `facet-format` over the postcard parser, so all
 //! supported `Facet` shapes use the same parser/deserializer path.
+//!
+//! # Tagged postcard
+//!
+//! Plain postcard identifies struct fields by position, so both ends must
+//! agree on the exact field list. [`to_vec_tagged`] and [`from_slice_tagged`]
+//! write every named struct field with its name and length instead: readers
+//! match fields by name, skip the ones they don't know and default the ones
+//! that are missing, so messages keep decoding as types evolve.
+//!
+//! ```
+//! use facet::Facet;
+//! use facet_postcard::{from_slice_tagged, to_vec_tagged};
+//!
+//! #[derive(Facet)]
+//! struct Old { id: u32, name: String }
+//!
+//! #[derive(Facet, Debug, PartialEq)]
+//! struct New { name: String, id: u32, tags: Option<Vec<String>> }
+//!
+//! let bytes = to_vec_tagged(&Old { id: 1, name: "a".into() }).unwrap();
+//! let new: New = from_slice_tagged(&bytes).unwrap();
+//! assert_eq!(new, New { name: "a".into(), id: 1, tags: None });
+//! ```
 
 // Note: unsafe code is used for lifetime transmutes in from_slice_into
 // when BORROW=false, mirroring the approach used in facet-json.
@@ -49,6 +72,7 @@ mod parser;
 mod raw_postcard;
 mod serialize;
 mod shape_deser;
+mod tagged;
 
 #[cfg(feature = "axum")]
 mod axum;
@@ -63,6 +87,7 @@ pub use serialize::{
     to_vec_with_shape, to_writer_fallible,
 };
 pub use shape_deser::from_slice_with_shape;
+pub use tagged::{from_slice_tagged, peek_to_vec_tagged, to_vec_tagged};
 
 // Re-export DeserializeError for convenience
 pub use facet_format::DeserializeError;
@@ -171,6 +196,17 @@ impl<'input> Deserializer<'input> {
         de.deserialize()
     }
 
+    /// Deserialize tagged postcard, as written by [`to_vec_tagged`], into an
+    /// owned typed value.
+    pub fn deserialize_tagged<T>(self) -> Result<T, DeserializeError>
+    where
+        T: facet_core::Facet<'static>,
+    {
+        let partial = facet_reflect::Partial::alloc_owned::<T>()?;
+        let (partial, _) = tagged::decode_prefix(self.input, self.config, partial)?;
+        Ok(partial.build()?.materialize::<T>()?)
+    }
+
     /// Deserialize into a dynamic `Value` using a runtime shape.
     pub fn deserialize_with_shape(
         self,
@@ -187,6 +223,16 @@ impl<'input> Deserializer<'input> {
         self,
         partial: facet_reflect::Partial<'facet, false>,
     ) -> Result<facet_reflect::Partial<'facet, false>, DeserializeError> {
+        self.deserialize_prefix_into(partial)
+            .map(|(partial, _)| partial)
+    }
+
+    /// Deserialize one value from the start of the input into an existing
+    /// owned `Partial`, returning the number of bytes it took up.
+    pub(crate) fn deserialize_prefix_into<'facet>(
+        self,
+        partial: facet_reflect::Partial<'facet, false>,
+    ) -> Result<(facet_reflect::Partial<'facet, false>, usize), DeserializeError> {
         use facet_format::{FormatDeserializer, MetaSource};
         let mut parser = self.parser();
         let mut de = FormatDeserializer::new_owned(&mut parser);
@@ -209,7 +255,7 @@ impl<'input> Deserializer<'input> {
             >(partial)
         };
 
-        Ok(partial)
+        Ok((partial, parser.position()))
     }
 
     /// Deserialize into an existing borrowed `Partial`.
@@ -150,6 +150,11 @@ impl<'de> PostcardParser<'de> {
         }
     }
 
+    /// Number of input bytes consumed so far.
+    pub(crate) const fn position(&self) -> usize {
+        self.pos
+    }
+
     /// Read a single byte, advancing position.
     fn read_byte(&mut self) -> Result<u8, ParseError> {
         if self.pos >= self.input.len() {
@@ -1102,7 +1102,10 @@ impl<'a, W: PostcardWriter<'a>> FormatSerializer for PostcardSerializer<'a, W> {
 }
 
 /// Write an unsigned varint (LEB128-like encoding used by postcard)
-fn write_varint<W: Writer>(mut value: u64, writer: &mut W) -> Result<(), SerializeError> {
+pub(crate) fn write_varint<W: Writer>(
+    mut value: u64,
+    writer: &mut W,
+) -> Result<(), SerializeError> {
     loop {
         let mut byte = (value & 0x7F) as u8;
         value >>= 7;
@@ -0,0 +1,429 @@
+//! Tagged postcard: structs carry their field names, so messages keep
+//! decoding after fields are added, removed or reordered.
+//!
+//! The encoding is postcard's, except for structs with named fields
+//! (including struct variants of enums). Those are written as the number of
+//! fields, then for each field its name (a postcard string), the length of
+//! its value in bytes (a varint) and the value itself.
+//!
+//! When decoding, fields are matched by name (or alias) in any order,
+//! unknown fields are skipped using their length, and missing fields are
+//! filled in from `Option` (as `None`) or `#[facet(default)]`. Everything
+//! else, including enum variants (which are still identified by index),
+//! tuples and scalars, is positional as usual.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use facet_core::{Def, Facet, Field, Shape, StructKind, Type, UserType};
+use facet_format::{DeserializeError, DeserializeErrorKind};
+use facet_reflect::{HasFields, Partial, Peek, Span};
+
+use crate::serialize::{peek_to_vec, write_varint};
+use crate::{DeserializeConfig, Deserializer, SerializeError};
+
+/// Serializes any Facet type to tagged postcard bytes.
+///
+/// Tagged messages are bigger than plain postcard ones, since every struct
+/// field carries its name and length, but a reader whose types have gained,
+/// lost or reordered fields can still decode them with
+/// [`from_slice_tagged`]. Plain and tagged postcard are not interchangeable.
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+/// use facet_postcard::{from_slice_tagged, to_vec_tagged};
+///
+/// #[derive(Facet)]
+/// struct SensorV1 {
+///     id: u32,
+///     celsius: f32,
+///     label: String,
+/// }
+///
+/// #[derive(Facet, Debug, PartialEq)]
+/// struct SensorV2 {
+///     celsius: f32,
+///     id: u32,
+///     humidity: Option<f32>,
+/// }
+///
+/// let bytes = to_vec_tagged(&SensorV1 { id: 7, celsius: 21.5, label: "attic".into() }).unwrap();
+/// let sensor: SensorV2 = from_slice_tagged(&bytes).unwrap();
+/// assert_eq!(sensor, SensorV2 { celsius: 21.5, id: 7, humidity: None });
+/// ```
+pub fn to_vec_tagged<'facet, T>(value: &T) -> Result<Vec<u8>, SerializeError>
+where
+    T: Facet<'facet> + ?Sized,
+{
+    peek_to_vec_tagged(Peek::new(value))
+}
+
+/// Serializes a [`Peek`] to tagged postcard bytes.
+///
+/// This is the type-erased counterpart of [`to_vec_tagged`].
+pub fn peek_to_vec_tagged(peek: Peek<'_, '_>) -> Result<Vec<u8>, SerializeError> {
+    let mut out = Vec::new();
+    encode(peek, &mut out)?;
+    Ok(out)
+}
+
+/// Deserializes tagged postcard bytes, as written by [`to_vec_tagged`], into
+/// an owned type.
+///
+/// See [`to_vec_tagged`] for an example.
+pub fn from_slice_tagged<T>(input: &[u8]) -> Result<T, DeserializeError>
+where
+    T: Facet<'static>,
+{
+    Deserializer::new(input).deserialize_tagged()
+}
+
+/// Decodes a tagged value from the start of `input` into `wip`, returning
+/// the number of bytes it took up.
+pub(crate) fn decode_prefix<'facet>(
+    input: &[u8],
+    config: DeserializeConfig,
+    wip: Partial<'facet, false>,
+) -> Result<(Partial<'facet, false>, usize), DeserializeError> {
+    let mut decoder = Decoder {
+        input,
+        pos: 0,
+        config,
+    };
+    let wip = decoder.value(wip)?;
+    Ok((wip, decoder.pos))
+}
+
+/// Whether `shape` is encoded the same way in tagged and plain postcard, so
+/// it can be left to the plain codec.
+fn is_positional(shape: &'static Shape) -> bool {
+    match shape.def {
+        Def::Option(option) => return is_positional(option.t()),
+        Def::List(list) => return is_positional(list.t()),
+        Def::Map(map) => return is_positional(map.k()) && is_positional(map.v()),
+        Def::Undefined => {}
+        _ => return true,
+    }
+
+    if shape.proxy.is_some()
+        || shape.from_conversion.is_some()
+        || shape.into_conversion.is_some()
+        || shape.inner.is_some()
+        || shape.is_cow()
+    {
+        return true;
+    }
+    !matches!(
+        shape.ty,
+        Type::User(UserType::Struct(_) | UserType::Enum(_))
+    )
+}
+
+/// The first field that tagged postcard can't handle, and why.
+fn find_unsupported_field(fields: &'static [Field]) -> Option<(&'static Field, &'static str)> {
+    fields.iter().find_map(|field| {
+        if field.is_flattened() {
+            Some((field, "flattened"))
+        } else if field.proxy().is_some() {
+            Some((field, "proxied"))
+        } else {
+            None
+        }
+    })
+}
+
+fn reflect_error(err: impl core::fmt::Display) -> SerializeError {
+    SerializeError::Custom(format!("{err}"))
+}
+
+fn encode(value: Peek<'_, '_>, out: &mut Vec<u8>) -> Result<(), SerializeError> {
+    let shape = value.shape();
+    if is_positional(shape) {
+        out.extend_from_slice(&peek_to_vec(value)?);
+        return Ok(());
+    }
+
+    match shape.def {
+        Def::Option(_) => match value.into_option().map_err(reflect_error)?.value() {
+            Some(inner) => {
+                out.push(1);
+                encode(inner, out)
+            }
+            None => {
+                out.push(0);
+                Ok(())
+            }
+        },
+        Def::List(_) => {
+            let list = value.into_list_like().map_err(reflect_error)?;
+            write_varint(list.len() as u64, out)?;
+            for item in list.iter() {
+                encode(item, out)?;
+            }
+            Ok(())
+        }
+        Def::Map(_) => {
+            let map = value.into_map().map_err(reflect_error)?;
+            write_varint(map.len() as u64, out)?;
+            for (key, value) in map.iter() {
+                encode(key, out)?;
+                encode(value, out)?;
+            }
+            Ok(())
+        }
+        _ => match shape.ty {
+            Type::User(UserType::Struct(struct_type)) => {
+                let struct_ = value.into_struct().map_err(reflect_error)?;
+                encode_fields(struct_type.kind, struct_type.fields, &struct_, out)
+            }
+            Type::User(UserType::Enum(_)) => {
+                let enum_ = value.into_enum().map_err(reflect_error)?;
+                let variant = enum_.active_variant().map_err(reflect_error)?;
+                let index = enum_.variant_index().map_err(reflect_error)?;
+                write_varint(index as u64, out)?;
+                encode_fields(variant.data.kind, variant.data.fields, &enum_, out)
+            }
+            _ => Err(SerializeError::Custom(format!(
+                "tagged postcard cannot serialize `{shape}`"
+            ))),
+        },
+    }
+}
+
+fn encode_fields<'mem, 'facet>(
+    kind: StructKind,
+    fields: &'static [Field],
+    value: &impl HasFields<'mem, 'facet>,
+    out: &mut Vec<u8>,
+) -> Result<(), SerializeError> {
+    if let Some((field, why)) = find_unsupported_field(fields) {
+        return Err(SerializeError::Custom(format!(
+            "tagged postcard does not support {why} field `{}`",
+            field.effective_name()
+        )));
+    }
+
+    if kind != StructKind::Struct {
+        for (_, field_value) in value.fields_for_binary_serialize() {
+            encode(field_value, out)?;
+        }
+        return Ok(());
+    }
+
+    let fields: Vec<_> = value.fields_for_serialize().collect();
+    write_varint(fields.len() as u64, out)?;
+    for (item, field_value) in fields {
+        let name = item.effective_name();
+        write_varint(name.len() as u64, out)?;
+        out.extend_from_slice(name.as_bytes());
+
+        let mut encoded = Vec::new();
+        encode(field_value, &mut encoded)?;
+        write_varint(encoded.len() as u64, out)?;
+        out.extend_from_slice(&encoded);
+    }
+    Ok(())
+}
+
+struct Decoder<'a> {
+    /// The input, cut off at the end of the value being decoded.
+    input: &'a [u8],
+    pos: usize,
+    config: DeserializeConfig,
+}
+
+impl<'a> Decoder<'a> {
+    fn value<'facet>(
+        &mut self,
+        wip: Partial<'facet, false>,
+    ) -> Result<Partial<'facet, false>, DeserializeError> {
+        let shape = wip.shape();
+        if is_positional(shape) {
+            let input = &self.input[self.pos..];
+            let (wip, len) = Deserializer::with_config(input, self.config)
+                .deserialize_prefix_into(wip)
+                .map_err(|err| self.shift(err))?;
+            self.pos += len;
+            return Ok(wip);
+        }
+
+        match shape.def {
+            Def::Option(_) => match self.byte()? {
+                0 => Ok(wip.set_default()?),
+                1 => Ok(self.value(wip.begin_some()?)?.end()?),
+                _ => Err(self.invalid(self.pos - 1, "invalid option discriminant")),
+            },
+            Def::List(_) => {
+                let len = self.collection_len()?;
+                let mut wip = wip.init_list_with_capacity(len.min(self.input.len() - self.pos))?;
+                for _ in 0..len {
+                    wip = self.value(wip.begin_list_item()?)?.end()?;
+                }
+                Ok(wip)
+            }
+            Def::Map(_) => {
+                let len = self.collection_len()?;
+                let mut wip = wip.init_map()?;
+                for _ in 0..len {
+                    wip = self.value(wip.begin_key()?)?.end()?;
+                    wip = self.value(wip.begin_value()?)?.end()?;
+                }
+                Ok(wip)
+            }
+            _ => match shape.ty {
+                Type::User(UserType::Struct(struct_type)) => {
+                    self.fields(wip, shape, struct_type.kind, struct_type.fields)
+                }
+                Type::User(UserType::Enum(enum_type)) => {
+                    let start = self.pos;
+                    let index = self.varint()?;
+                    let Some(variant) = enum_type.variants.get(index as usize) else {
+                        return Err(self.invalid(start, "invalid enum discriminant"));
+                    };
+                    let wip = wip.select_nth_variant(index as usize)?;
+                    self.fields(wip, shape, variant.data.kind, variant.data.fields)
+                }
+                _ => Err(DeserializeErrorKind::Unsupported {
+                    message: format!("tagged postcard cannot deserialize `{shape}`").into(),
+                }
+                .with_span(Span::new(self.pos, 0))),
+            },
+        }
+    }
+
+    fn fields<'facet>(
+        &mut self,
+        mut wip: Partial<'facet, false>,
+        shape: &'static Shape,
+        kind: StructKind,
+        fields: &'static [Field],
+    ) -> Result<Partial<'facet, false>, DeserializeError> {
+        if let Some((field, why)) = find_unsupported_field(fields) {
+            return Err(DeserializeErrorKind::Unsupported {
+                message: format!(
+                    "tagged postcard does not support {why} field `{}`",
+                    field.effective_name()
+                )
+                .into(),
+            }
+            .with_span(Span::new(self.pos, 0)));
+        }
+
+        if kind != StructKind::Struct {
+            for (index, field) in fields.iter().enumerate() {
+                if !field.should_skip_serializing_unconditional() {
+                    wip = self.value(wip.begin_nth_field(index)?)?.end()?;
+                }
+            }
+            return Ok(wip);
+        }
+
+        let count = self.collection_len()?;
+        for _ in 0..count {
+            let start = self.pos;
+            let name = self.str()?;
+            let len = self.varint()?;
+            let end = match usize::try_from(len) {
+                Ok(len) if len <= self.input.len() - self.pos => self.pos + len,
+                _ => return Err(self.eof("field value")),
+            };
+
+            let Some(index) = fields
+                .iter()
+                .position(|field| field.effective_name() == name || field.alias == Some(name))
+            else {
+                if shape.has_deny_unknown_fields_attr() {
+                    return Err(DeserializeErrorKind::UnknownField {
+                        field: String::from(name).into(),
+                        suggestion: None,
+                    }
+                    .with_span(Span::new(start, end - start)));
+                }
+                self.pos = end;
+                continue;
+            };
+
+            let mut field = Decoder {
+                input: &self.input[..end],
+                pos: self.pos,
+                config: self.config,
+            };
+            wip = field.value(wip.begin_nth_field(index)?)?.end()?;
+            if field.pos != end {
+                return Err(
+                    self.invalid(field.pos, "field value is shorter than its encoded length")
+                );
+            }
+            self.pos = end;
+        }
+        Ok(wip)
+    }
+
+    fn byte(&mut self) -> Result<u8, DeserializeError> {
+        let Some(&byte) = self.input.get(self.pos) else {
+            return Err(self.eof("byte"));
+        };
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    fn varint(&mut self) -> Result<u64, DeserializeError> {
+        let start = self.pos;
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            value |= u64::from(byte & 0x7F) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(self.invalid(start, "varint overflow"))
+    }
+
+    fn collection_len(&mut self) -> Result<usize, DeserializeError> {
+        let start = self.pos;
+        let len = self.varint()?;
+        if len > self.config.get_max_collection_elements() {
+            return Err(self.invalid(start, "collection length exceeds maximum"));
+        }
+        usize::try_from(len).map_err(|_| self.invalid(start, "collection length exceeds maximum"))
+    }
+
+    fn str(&mut self) -> Result<&'a str, DeserializeError> {
+        let start = self.pos;
+        let len = self.varint()?;
+        let input = self.input;
+        let bytes = match usize::try_from(len) {
+            Ok(len) if len <= input.len() - self.pos => &input[self.pos..self.pos + len],
+            _ => return Err(self.eof("field name")),
+        };
+        self.pos += bytes.len();
+        core::str::from_utf8(bytes).map_err(|_| self.invalid(start, "invalid UTF-8"))
+    }
+
+    fn invalid(&self, pos: usize, message: &'static str) -> DeserializeError {
+        DeserializeErrorKind::InvalidValue {
+            message: message.into(),
+        }
+        .with_span(Span::new(pos, 1))
+    }
+
+    fn eof(&self, expected: &'static str) -> DeserializeError {
+        DeserializeErrorKind::UnexpectedEof { expected }.with_span(Span::new(self.input.len(), 0))
+    }
+
+    /// Moves the span of an error from the plain codec, which only sees the
+    /// input from the current position on, to the whole input.
+    fn shift(&self, mut err: DeserializeError) -> DeserializeError {
+        if let Some(span) = err.span {
+            err.span = Some(Span::new(
+                span.offset as usize + self.pos,
+                span.len as usize,
+            ));
+        }
+        err
+    }
+}
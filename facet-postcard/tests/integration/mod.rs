@@ -5,4 +5,5 @@ mod issue_2098;
 mod issue_2108;
 mod issue_2118;
 mod opaque_adapter;
+mod tagged;
 mod vox_wire;
@@ -0,0 +1,174 @@
+use std::collections::BTreeMap;
+
+use facet::Facet;
+use facet_postcard::{from_slice_tagged, to_vec, to_vec_tagged};
+use facet_testhelpers::test;
+
+#[derive(Facet, Debug, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+#[repr(u8)]
+enum Figure {
+    Dot,
+    Circle(u32),
+    Rect { w: u32, h: u32 },
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Drawing {
+    name: String,
+    origin: Point,
+    points: Vec<Point>,
+    anchor: Option<Point>,
+    layers: BTreeMap<String, Point>,
+    figures: Vec<Figure>,
+    ids: Vec<u32>,
+}
+
+#[test]
+fn named_fields_carry_their_name_and_length() {
+    let bytes = to_vec_tagged(&Point { x: 10, y: -1 }).unwrap();
+    assert_eq!(bytes, [2, 1, b'x', 1, 0x14, 1, b'y', 1, 0x01]);
+}
+
+#[test]
+fn values_without_named_structs_match_plain_postcard() {
+    let value = (vec![1u32, 300], Some("hi".to_string()), Figure::Circle(3));
+    assert_eq!(to_vec_tagged(&value).unwrap(), to_vec(&value).unwrap());
+}
+
+#[test]
+fn round_trip() {
+    let drawing = Drawing {
+        name: "plan".into(),
+        origin: Point { x: 0, y: 0 },
+        points: vec![Point { x: 1, y: 2 }, Point { x: -3, y: 4 }],
+        anchor: Some(Point { x: 5, y: 5 }),
+        layers: BTreeMap::from([("top".into(), Point { x: 9, y: 9 })]),
+        figures: vec![Figure::Dot, Figure::Circle(2), Figure::Rect { w: 3, h: 4 }],
+        ids: vec![7, 8],
+    };
+    let bytes = to_vec_tagged(&drawing).unwrap();
+    assert_eq!(from_slice_tagged::<Drawing>(&bytes).unwrap(), drawing);
+}
+
+#[derive(Facet)]
+struct UserV1 {
+    id: u64,
+    name: String,
+    legacy_flags: u32,
+    home: Point,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct PointV2 {
+    y: i32,
+    x: i32,
+    #[facet(default)]
+    z: i32,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct UserV2 {
+    home: PointV2,
+    #[facet(alias = "name")]
+    display_name: String,
+    id: u64,
+    email: Option<String>,
+}
+
+#[test]
+fn reader_skips_unknown_fields_and_defaults_missing_ones() {
+    let bytes = to_vec_tagged(&UserV1 {
+        id: 42,
+        name: "ada".into(),
+        legacy_flags: 0xFFFF,
+        home: Point { x: 1, y: 2 },
+    })
+    .unwrap();
+
+    let user: UserV2 = from_slice_tagged(&bytes).unwrap();
+    assert_eq!(
+        user,
+        UserV2 {
+            home: PointV2 { y: 2, x: 1, z: 0 },
+            display_name: "ada".into(),
+            id: 42,
+            email: None,
+        }
+    );
+}
+
+#[derive(Facet)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum EventV1 {
+    Ping,
+    Move { x: i32, y: i32, speed: u8 },
+}
+
+#[derive(Facet, Debug, PartialEq)]
+#[repr(u8)]
+enum EventV2 {
+    Ping,
+    Move { y: i32, x: i32 },
+}
+
+#[test]
+fn struct_variants_are_tagged_too() {
+    let bytes = to_vec_tagged(&EventV1::Move {
+        x: 3,
+        y: 4,
+        speed: 9,
+    })
+    .unwrap();
+    assert_eq!(
+        from_slice_tagged::<EventV2>(&bytes).unwrap(),
+        EventV2::Move { y: 4, x: 3 }
+    );
+}
+
+#[derive(Facet, Debug)]
+struct Strict {
+    id: u64,
+}
+
+#[derive(Facet, Debug)]
+#[facet(deny_unknown_fields)]
+struct StrictDeny {
+    id: u64,
+}
+
+#[test]
+fn missing_required_fields_are_errors() {
+    let bytes = to_vec_tagged(&Point { x: 1, y: 2 }).unwrap();
+    let err = from_slice_tagged::<Strict>(&bytes).unwrap_err();
+    assert!(err.to_string().contains("id"), "{err}");
+}
+
+#[test]
+fn deny_unknown_fields_is_honored() {
+    let bytes = to_vec_tagged(&UserV1 {
+        id: 1,
+        name: "a".into(),
+        legacy_flags: 0,
+        home: Point { x: 0, y: 0 },
+    })
+    .unwrap();
+    assert!(from_slice_tagged::<StrictDeny>(&bytes).is_err());
+}
+
+#[test]
+fn truncated_input_is_an_error() {
+    let bytes = to_vec_tagged(&Point { x: 10, y: 20 }).unwrap();
+    for len in 0..bytes.len() {
+        assert!(
+            from_slice_tagged::<Point>(&bytes[..len]).is_err(),
+            "{len} bytes"
+        );
+    }
+}
@@ -0,0 +1,50 @@
+//! Summaries for byte buffers too large to print in full.
+
+use alloc::string::String;
+use core::fmt::{self, Write};
+
+use sha2::{Digest, Sha256};
+
+/// Number of leading hash bytes shown in a summary.
+const HASH_PREFIX_LEN: usize = 8;
+
+/// Summarize a byte buffer by its size and the start of its SHA-256 hash.
+///
+/// Two buffers with the same summary are almost certainly identical, so this
+/// is enough to tell large blobs apart without dumping them.
+///
+/// ```
+/// assert_eq!(
+///     facet_pretty::blob_summary(&[0u8; 1536]),
+///     "<1.5 KiB, sha256:80422bc3d307b4a2…>"
+/// );
+/// ```
+pub fn blob_summary(bytes: &[u8]) -> String {
+    let mut out = String::new();
+    write!(out, "<{}, sha256:", ByteSize(bytes.len())).unwrap();
+    for byte in &Sha256::digest(bytes)[..HASH_PREFIX_LEN] {
+        write!(out, "{byte:02x}").unwrap();
+    }
+    out.push_str("…>");
+    out
+}
+
+/// A byte count in binary units: `512 B`, `1.5 KiB`, `1.2 MiB`.
+struct ByteSize(usize);
+
+impl fmt::Display for ByteSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+        let mut value = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+        write!(f, "{value:.1} {}", UNITS[unit])
+    }
+}
@@ -42,11 +42,13 @@
 
 extern crate alloc;
 
+mod blob;
 mod color;
 mod display;
 mod printer;
 mod shape;
 
+pub use blob::blob_summary;
 pub use color::*;
 pub use display::*;
 pub use printer::*;
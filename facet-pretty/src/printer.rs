@@ -17,6 +17,7 @@ use facet_reflect::{Peek, ValueId};
 
 use owo_colors::OwoColorize;
 
+use crate::blob::blob_summary;
 #[allow(deprecated)]
 use crate::color::ColorGenerator;
 use crate::color::{Palette, Theme};
@@ -130,6 +131,12 @@ pub struct PrettyPrinter {
     max_content_len: Option<usize>,
     /// Maximum number of collection entries/fields before truncating (None = no limit)
     max_collection_len: Option<usize>,
+    /// Number of bytes per hex dump row
+    bytes_per_line: usize,
+    /// Whether hex dump rows end with their bytes as ASCII
+    ascii_sidebar: bool,
+    /// Byte buffers longer than this are summarized by size and hash (None = never)
+    blob_summary_above: Option<usize>,
 }
 
 impl Default for PrettyPrinter {
@@ -151,6 +158,9 @@ impl PrettyPrinter {
             show_doc_comments: false,
             max_content_len: None,
             max_collection_len: None,
+            bytes_per_line: 16,
+            ascii_sidebar: false,
+            blob_summary_above: None,
         }
     }
 
@@ -238,6 +248,32 @@ impl PrettyPrinter {
         self
     }
 
+    /// Set the number of bytes per row when printing byte buffers as hex dumps
+    pub const fn with_bytes_per_line(mut self, bytes_per_line: usize) -> Self {
+        self.bytes_per_line = if bytes_per_line == 0 {
+            1
+        } else {
+            bytes_per_line
+        };
+        self
+    }
+
+    /// End each hex dump row with its bytes as ASCII, `.` standing in for
+    /// anything unprintable, like `xxd` does
+    pub const fn with_ascii_sidebar(mut self, ascii_sidebar: bool) -> Self {
+        self.ascii_sidebar = ascii_sidebar;
+        self
+    }
+
+    /// Summarize byte buffers longer than `max_len` instead of dumping them
+    ///
+    /// The summary shows the size and the start of the SHA-256 hash, see
+    /// [`blob_summary`].
+    pub const fn with_blob_summary_above(mut self, max_len: usize) -> Self {
+        self.blob_summary_above = Some(max_len);
+        self
+    }
+
     /// Format a value to a string
     pub fn format<'a, T: ?Sized + Facet<'a>>(&self, value: &T) -> String {
         let value = Peek::new(value);
@@ -612,92 +648,14 @@ impl PrettyPrinter {
 
                 if !list.is_empty() {
                     if list.def().t().is_type::<u8>() && self.list_u8_as_bytes {
-                        let total_len = list.len();
-                        let truncate = self.max_content_len.is_some_and(|max| total_len > max);
-
-                        self.write_punctuation(f, " [")?;
-
-                        if truncate {
-                            let max = self.max_content_len.unwrap();
-                            let half = max / 2;
-                            let start_count = half;
-                            let end_count = half;
-
-                            // Show beginning
-                            for (idx, item) in list.iter().enumerate().take(start_count) {
-                                if !short && idx % 16 == 0 {
-                                    writeln!(f)?;
-                                    self.indent(f, format_depth + 1)?;
-                                }
-                                write!(f, " ")?;
-                                let byte = *item.get::<u8>().unwrap();
-                                if self.use_colors() {
-                                    let mut hasher = DefaultHasher::new();
-                                    byte.hash(&mut hasher);
-                                    let hash = hasher.finish();
-                                    let rgb = self.palette().accent(hash);
-                                    write!(f, "{}", format!("{byte:02x}").color(rgb))?;
-                                } else {
-                                    write!(f, "{byte:02x}")?;
-                                }
-                            }
-
-                            // Show ellipsis
-                            let omitted = total_len - start_count - end_count;
-                            if !short {
-                                writeln!(f)?;
-                                self.indent(f, format_depth + 1)?;
-                                writeln!(f, " ...({omitted} bytes)...")?;
-                                self.indent(f, format_depth + 1)?;
-                            } else {
-                                write!(f, " ...({omitted} bytes)...")?;
-                            }
-
-                            // Show end
-                            for (idx, item) in list.iter().enumerate().skip(total_len - end_count) {
-                                let display_idx = start_count + 1 + (idx - (total_len - end_count));
-                                if !short && display_idx.is_multiple_of(16) {
-                                    writeln!(f)?;
-                                    self.indent(f, format_depth + 1)?;
-                                }
-                                write!(f, " ")?;
-                                let byte = *item.get::<u8>().unwrap();
-                                if self.use_colors() {
-                                    let mut hasher = DefaultHasher::new();
-                                    byte.hash(&mut hasher);
-                                    let hash = hasher.finish();
-                                    let rgb = self.palette().accent(hash);
-                                    write!(f, "{}", format!("{byte:02x}").color(rgb))?;
-                                } else {
-                                    write!(f, "{byte:02x}")?;
-                                }
-                            }
+                        let bytes: Vec<u8> =
+                            list.iter().map(|item| *item.get::<u8>().unwrap()).collect();
+                        if self.blob_summary_above.is_some_and(|max| bytes.len() > max) {
+                            write!(f, " ")?;
+                            self.write_comment(f, &blob_summary(&bytes))?;
                         } else {
-                            for (idx, item) in list.iter().enumerate() {
-                                if !short && idx % 16 == 0 {
-                                    writeln!(f)?;
-                                    self.indent(f, format_depth + 1)?;
-                                }
-                                write!(f, " ")?;
-
-                                let byte = *item.get::<u8>().unwrap();
-                                if self.use_colors() {
-                                    let mut hasher = DefaultHasher::new();
-                                    byte.hash(&mut hasher);
-                                    let hash = hasher.finish();
-                                    let rgb = self.palette().accent(hash);
-                                    write!(f, "{}", format!("{byte:02x}").color(rgb))?;
-                                } else {
-                                    write!(f, "{byte:02x}")?;
-                                }
-                            }
-                        }
-
-                        if !short {
-                            writeln!(f)?;
-                            self.indent(f, format_depth)?;
+                            self.format_hex_dump(f, &bytes, format_depth, short)?;
                         }
-                        self.write_punctuation(f, "]")?;
                     } else {
                         // Check if elements are simple scalars - render inline if so
                         let elem_shape = list.def().t();
@@ -1421,10 +1379,8 @@ impl PrettyPrinter {
 
         impl fmt::Display for DisplayWrapper<'_, '_> {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                if self.0.shape().is_display() {
-                    write!(f, "{}", self.0)?;
-                } else if self.0.shape().is_debug() {
-                    write!(f, "{:?}", self.0)?;
+                if self.0.shape().is_display() || self.0.shape().is_debug() {
+                    write!(f, "{}", self.0.as_display())?;
                 } else {
                     write!(f, "{}", self.0.shape())?;
                     write!(f, "(…)")?;
@@ -1554,8 +1510,91 @@ impl PrettyPrinter {
         }
     }
 
+    /// Write a byte buffer as a bracketed hex dump, `bytes_per_line` bytes per row
+    fn format_hex_dump(
+        &self,
+        f: &mut dyn Write,
+        bytes: &[u8],
+        format_depth: usize,
+        short: bool,
+    ) -> fmt::Result {
+        let (head, omitted, tail) = match self.max_content_len {
+            Some(max) if bytes.len() > max => {
+                let half = max / 2;
+                (
+                    &bytes[..half],
+                    bytes.len() - 2 * half,
+                    &bytes[bytes.len() - half..],
+                )
+            }
+            _ => (bytes, 0, &bytes[..0]),
+        };
+
+        self.write_punctuation(f, " [")?;
+        self.write_hex_rows(f, head, format_depth, short)?;
+        if omitted != 0 {
+            if !short {
+                writeln!(f)?;
+                self.indent(f, format_depth + 1)?;
+            }
+            write!(f, " ...({omitted} bytes)...")?;
+            self.write_hex_rows(f, tail, format_depth, short)?;
+        }
+        if !short {
+            writeln!(f)?;
+            self.indent(f, format_depth)?;
+        }
+        self.write_punctuation(f, "]")
+    }
+
+    fn write_hex_rows(
+        &self,
+        f: &mut dyn Write,
+        bytes: &[u8],
+        format_depth: usize,
+        short: bool,
+    ) -> fmt::Result {
+        for row in bytes.chunks(self.bytes_per_line) {
+            if !short {
+                writeln!(f)?;
+                self.indent(f, format_depth + 1)?;
+            }
+            for &byte in row {
+                write!(f, " ")?;
+                if self.use_colors() {
+                    let mut hasher = DefaultHasher::new();
+                    byte.hash(&mut hasher);
+                    let rgb = self.palette().accent(hasher.finish());
+                    write!(f, "{}", format!("{byte:02x}").color(rgb))?;
+                } else {
+                    write!(f, "{byte:02x}")?;
+                }
+            }
+            if self.ascii_sidebar && !short {
+                let padding = 3 * (self.bytes_per_line - row.len()) + 2;
+                write!(f, "{:padding$}", "")?;
+                let ascii: String = row
+                    .iter()
+                    .map(|&b| {
+                        if b.is_ascii_graphic() || b == b' ' {
+                            b as char
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect();
+                self.write_comment(f, &format!("|{ascii}|"))?;
+            }
+        }
+        Ok(())
+    }
+
     /// Format bytes for dynamic values
     fn format_bytes(&self, f: &mut dyn Write, bytes: &[u8]) -> fmt::Result {
+        if self.blob_summary_above.is_some_and(|max| bytes.len() > max) {
+            return self.write_comment(f, &blob_summary(bytes));
+        }
+
         write!(f, "b\"")?;
 
         match self.max_content_len {
@@ -2168,11 +2207,9 @@ impl PrettyPrinter {
     }
 
     fn format_scalar_to_output(&self, value: Peek<'_, '_>, out: &mut impl Write) -> fmt::Result {
-        // Use Display or Debug trait to format scalar values
-        if value.shape().is_display() {
-            write!(out, "{}", value)
-        } else if value.shape().is_debug() {
-            write!(out, "{:?}", value)
+        // Same spelling as format_scalar, without the colors
+        if value.shape().is_display() || value.shape().is_debug() {
+            write!(out, "{}", value.as_display())
         } else {
             write!(out, "{}(…)", value.shape())
         }
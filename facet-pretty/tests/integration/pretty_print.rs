@@ -168,6 +168,37 @@ fn test_vec_u8() {
     ");
 }
 
+#[test]
+fn test_vec_u8_rows_with_ascii_sidebar() {
+    let printer = PrettyPrinter::new()
+        .with_colors(false.into())
+        .with_bytes_per_line(4)
+        .with_ascii_sidebar(true);
+    let bytes = b"Hi!\x00\x7fxyz\n".to_vec();
+    assert_snapshot!(printer.format(&bytes), @"
+    Vec<u8> [
+       48 69 21 00  |Hi!.|
+       7f 78 79 7a  |.xyz|
+       0a           |.|
+    ]
+    ");
+}
+
+#[test]
+fn test_vec_u8_blob_summary() {
+    let printer = PrettyPrinter::new()
+        .with_colors(false.into())
+        .with_blob_summary_above(1024);
+    let blob: Vec<u8> = vec![0u8; 2048];
+    assert_snapshot!(
+        printer.format(&blob),
+        @"Vec<u8> <2.0 KiB, sha256:e5a00aa9991ac8a5…>"
+    );
+    // At or below the threshold, bytes are dumped as usual
+    let small: Vec<u8> = vec![0u8; 1024];
+    assert!(printer.format(&small).contains("00 00 00"));
+}
+
 #[test]
 fn test_byte_slice() {
     let printer = PrettyPrinter::new().with_colors(false.into());
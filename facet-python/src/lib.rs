@@ -27,7 +27,7 @@ use alloc::string::String;
 use alloc::vec::Vec;
 use core::fmt::Write;
 
-use facet_core::{Def, Facet, Field, Shape, StructKind, Type, UserType};
+use facet_core::{Def, Facet, Field, ScalarKind, Shape, StructKind, Type, UserType};
 
 /// Check if a field name is a Python reserved keyword using binary search
 fn is_python_keyword(name: &str) -> bool {
@@ -516,27 +516,19 @@ impl PythonGenerator {
     /// Returns `(inner_shape, was_optional)` where `was_optional` is `true` if an
     /// `Option` layer was encountered.
     fn unwrap_to_inner_shape(shape: &'static Shape) -> (&'static Shape, bool) {
-        // Option<T> — mark optional and recurse on T.
-        if let Def::Option(opt) = &shape.def {
-            let (inner, _) = Self::unwrap_to_inner_shape(opt.t);
-            return (inner, true);
-        }
-        // Arc<T>, Box<T>, etc. — unwrap the pointee.
-        if let Def::Pointer(ptr) = &shape.def
-            && let Some(pointee) = ptr.pointee
-        {
-            return Self::unwrap_to_inner_shape(pointee);
-        }
+        // Option<T> and pointers (Arc, Box, etc.)
+        let (shape, is_optional) = shape.flatten_target();
         // Transparent wrappers (#[facet(transparent)]).
         if let Some(inner) = shape.inner {
-            let (inner_shape, is_optional) = Self::unwrap_to_inner_shape(inner);
-            return (inner_shape, is_optional);
+            let (inner_shape, inner_optional) = Self::unwrap_to_inner_shape(inner);
+            return (inner_shape, is_optional || inner_optional);
         }
         // Proxy types — follow the proxy chain.
         if let Some(proxy_def) = shape.proxy {
-            return Self::unwrap_to_inner_shape(proxy_def.shape);
+            let (proxy_shape, proxy_optional) = Self::unwrap_to_inner_shape(proxy_def.shape);
+            return (proxy_shape, is_optional || proxy_optional);
         }
-        (shape, false)
+        (shape, is_optional)
     }
 
     /// Collect visible fields, inlining `#[facet(flatten)]` ones.
@@ -1088,6 +1080,15 @@ impl PythonGenerator {
 
     /// Get the Python type for a scalar shape.
     fn scalar_type(&mut self, shape: &'static Shape) -> String {
+        match shape.scalar_kind() {
+            Some(ScalarKind::Str | ScalarKind::Char) => return "str".to_string(),
+            Some(ScalarKind::Bool) => return "bool".to_string(),
+            Some(kind) if kind.is_integer() => return "int".to_string(),
+            Some(kind) if kind.is_float() => return "float".to_string(),
+            _ => {}
+        }
+
+        // Scalars without a kind are recognized by name.
         match shape.type_identifier {
             // Strings
             "String" | "str" | "&str" | "Cow" => "str".to_string(),
@@ -0,0 +1,477 @@
+//! Flat lists of changed leaves between two values.
+//!
+//! [`scalar_changes`] is the lightweight sibling of a full tree diff: it walks
+//! both values side by side and reports every leaf that differs, with its
+//! path and the old and new values. Elements are matched by position (lists)
+//! or key (maps), so nothing is ever reported as moved. That makes the result
+//! easy to render as an audit log line such as
+//! `settings.retries: 3 -> 5`.
+
+use alloc::{format, string::String, vec::Vec};
+use core::fmt;
+
+use facet_core::{Def, Field, Type, UserType};
+
+use crate::{
+    Peek, PeekEdge, PeekEnum, PeekListLike, PeekMap, PeekSet, PeekStruct, PeekVisitor,
+    VisitControl, deep_eq, walk,
+};
+
+/// One step of a [`ChangePath`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ChangeSegment {
+    /// A field of a struct, tuple or enum variant, by name (`"0"`, `"1"`, …
+    /// for tuple fields).
+    Field(&'static str),
+    /// An element of a list, array or slice.
+    Index(usize),
+    /// A map entry. String keys are stored quoted, other keys as their
+    /// `Display` (or `Debug`) form.
+    Key(String),
+}
+
+/// Where a [`ScalarChange`] happened, relative to the compared values.
+///
+/// Displays as `settings.retries`, `servers[2].host` or `limits["eu"]`; the
+/// root itself displays as `<root>`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChangePath(Vec<ChangeSegment>);
+
+impl ChangePath {
+    /// The steps from the root to the changed value.
+    pub fn segments(&self) -> &[ChangeSegment] {
+        &self.0
+    }
+
+    /// Whether this is the path of the compared values themselves.
+    pub fn is_root(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Display for ChangePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return f.write_str("<root>");
+        }
+        for (i, segment) in self.0.iter().enumerate() {
+            match segment {
+                ChangeSegment::Field(name) if i == 0 => f.write_str(name)?,
+                ChangeSegment::Field(name) => write!(f, ".{name}")?,
+                ChangeSegment::Index(index) => write!(f, "[{index}]")?,
+                ChangeSegment::Key(key) => write!(f, "[{key}]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A leaf that differs between the two values given to [`scalar_changes`].
+///
+/// `old` is `None` when the leaf was added (a new list element or map
+/// entry), and `new` is `None` when it was removed.
+#[derive(Clone, Debug)]
+pub struct ScalarChange<'mem, 'facet> {
+    /// Where the change happened.
+    pub path: ChangePath,
+    /// The definitions of the fields along `path`, outermost first, for
+    /// looking up attributes and doc comments.
+    pub fields: Vec<&'static Field>,
+    /// The value before the change.
+    pub old: Option<Peek<'mem, 'facet>>,
+    /// The value after the change.
+    pub new: Option<Peek<'mem, 'facet>>,
+}
+
+/// Lists every leaf that differs between `old` and `new`.
+///
+/// Structs are compared field by field, enums field by field while they are
+/// in the same variant, lists element by element and maps entry by entry (by
+/// key). Options, results and smart pointers are looked through. Elements
+/// and entries present on one side only are reported leaf by leaf, with
+/// `None` on the other side.
+///
+/// Where the two sides can't be lined up — a different enum variant, `Some`
+/// against `None`, `Ok` against `Err`, differing sets, or different shapes —
+/// a single change holding both whole values is reported. Leaves are compared
+/// with [`deep_eq`], so opaque values without `PartialEq` always count as
+/// changed.
+///
+/// Changes come out in traversal order: fields in declaration order, list
+/// elements by index, and map entries in the old map's iteration order
+/// followed by entries only the new map has.
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+/// use facet_reflect::{Peek, scalar_changes};
+///
+/// #[derive(Facet)]
+/// struct Settings { retries: u32, verbose: bool }
+///
+/// #[derive(Facet)]
+/// struct Config { name: String, settings: Settings }
+///
+/// let before = Config { name: "api".into(), settings: Settings { retries: 3, verbose: false } };
+/// let after = Config { name: "api".into(), settings: Settings { retries: 5, verbose: false } };
+///
+/// let changes = scalar_changes(Peek::new(&before), Peek::new(&after));
+/// assert_eq!(changes.len(), 1);
+/// assert_eq!(changes[0].path.to_string(), "settings.retries");
+/// assert_eq!(changes[0].old.unwrap().to_string(), "3");
+/// assert_eq!(changes[0].new.unwrap().to_string(), "5");
+/// ```
+pub fn scalar_changes<'mem, 'facet>(
+    old: Peek<'mem, 'facet>,
+    new: Peek<'mem, 'facet>,
+) -> Vec<ScalarChange<'mem, 'facet>> {
+    let mut collector = Collector {
+        path: Vec::new(),
+        fields: Vec::new(),
+        changes: Vec::new(),
+    };
+    collector.compare(old, new);
+    collector.changes
+}
+
+struct Collector<'mem, 'facet> {
+    path: Vec<ChangeSegment>,
+    fields: Vec<&'static Field>,
+    changes: Vec<ScalarChange<'mem, 'facet>>,
+}
+
+impl<'mem, 'facet> Collector<'mem, 'facet> {
+    fn record(&mut self, old: Option<Peek<'mem, 'facet>>, new: Option<Peek<'mem, 'facet>>) {
+        self.changes.push(ScalarChange {
+            path: ChangePath(self.path.clone()),
+            fields: self.fields.clone(),
+            old,
+            new,
+        });
+    }
+
+    fn enter_field(&mut self, field: &'static Field) {
+        self.path.push(ChangeSegment::Field(field.name));
+        self.fields.push(field);
+    }
+
+    fn leave_field(&mut self) {
+        self.path.pop();
+        self.fields.pop();
+    }
+
+    fn compare(&mut self, old: Peek<'mem, 'facet>, new: Peek<'mem, 'facet>) {
+        if old.shape() != new.shape() {
+            self.record(Some(old), Some(new));
+            return;
+        }
+
+        // Options, results and smart pointers are transparent, even though
+        // options and results are also described as enums.
+        match old.shape().def {
+            Def::Option(_) => {
+                if let (Ok(old_opt), Ok(new_opt)) = (old.into_option(), new.into_option()) {
+                    match (old_opt.value(), new_opt.value()) {
+                        (Some(old_inner), Some(new_inner)) => self.compare(old_inner, new_inner),
+                        (None, None) => {}
+                        _ => self.record(Some(old), Some(new)),
+                    }
+                    return;
+                }
+            }
+
+            Def::Result(_) => {
+                if let (Ok(old_res), Ok(new_res)) = (old.into_result(), new.into_result()) {
+                    match (old_res.ok(), new_res.ok(), old_res.err(), new_res.err()) {
+                        (Some(old_ok), Some(new_ok), _, _) => self.compare(old_ok, new_ok),
+                        (_, _, Some(old_err), Some(new_err)) => self.compare(old_err, new_err),
+                        _ => self.record(Some(old), Some(new)),
+                    }
+                    return;
+                }
+            }
+
+            Def::Pointer(_) => {
+                if let (Ok(old_ptr), Ok(new_ptr)) = (old.into_pointer(), new.into_pointer())
+                    && let (Some(old_inner), Some(new_inner)) =
+                        (old_ptr.borrow_inner(), new_ptr.borrow_inner())
+                {
+                    self.compare(old_inner, new_inner);
+                    return;
+                }
+            }
+
+            _ => {}
+        }
+
+        match old.shape().ty {
+            Type::User(UserType::Struct(struct_type)) => {
+                if let (Ok(old_struct), Ok(new_struct)) = (old.into_struct(), new.into_struct()) {
+                    for (index, field) in struct_type.fields.iter().enumerate() {
+                        if let (Ok(old_field), Ok(new_field)) =
+                            (old_struct.field(index), new_struct.field(index))
+                        {
+                            self.enter_field(field);
+                            self.compare(old_field, new_field);
+                            self.leave_field();
+                        }
+                    }
+                    return;
+                }
+            }
+
+            Type::User(UserType::Enum(_)) => {
+                if let (Ok(old_enum), Ok(new_enum)) = (old.into_enum(), new.into_enum()) {
+                    match (old_enum.variant_index(), new_enum.variant_index()) {
+                        (Ok(old_index), Ok(new_index)) if old_index == new_index => {
+                            let Ok(variant) = old_enum.active_variant() else {
+                                return;
+                            };
+                            for (index, field) in variant.data.fields.iter().enumerate() {
+                                if let (Ok(Some(old_field)), Ok(Some(new_field))) =
+                                    (old_enum.field(index), new_enum.field(index))
+                                {
+                                    self.enter_field(field);
+                                    self.compare(old_field, new_field);
+                                    self.leave_field();
+                                }
+                            }
+                        }
+                        _ => self.record(Some(old), Some(new)),
+                    }
+                    return;
+                }
+            }
+
+            _ => {}
+        }
+
+        match old.shape().def {
+            Def::List(_) | Def::Array(_) | Def::Slice(_) => {
+                if let (Ok(old_list), Ok(new_list)) = (old.into_list_like(), new.into_list_like()) {
+                    for index in 0..old_list.len().max(new_list.len()) {
+                        self.path.push(ChangeSegment::Index(index));
+                        match (old_list.get(index), new_list.get(index)) {
+                            (Some(old_item), Some(new_item)) => self.compare(old_item, new_item),
+                            (Some(old_item), None) => self.one_sided(old_item, Side::Old),
+                            (None, Some(new_item)) => self.one_sided(new_item, Side::New),
+                            (None, None) => {}
+                        }
+                        self.path.pop();
+                    }
+                    return;
+                }
+            }
+
+            Def::Map(_) => {
+                if let (Ok(old_map), Ok(new_map)) = (old.into_map(), new.into_map()) {
+                    for (key, old_value) in old_map.iter() {
+                        self.path.push(ChangeSegment::Key(render_key(key)));
+                        match new_map.get_peek(key) {
+                            Ok(Some(new_value)) => self.compare(old_value, new_value),
+                            _ => self.one_sided(old_value, Side::Old),
+                        }
+                        self.path.pop();
+                    }
+                    for (key, new_value) in new_map.iter() {
+                        if !matches!(old_map.contains_key_peek(key), Ok(true)) {
+                            self.path.push(ChangeSegment::Key(render_key(key)));
+                            self.one_sided(new_value, Side::New);
+                            self.path.pop();
+                        }
+                    }
+                    return;
+                }
+            }
+
+            _ => {}
+        }
+
+        if !deep_eq(old, new) {
+            self.record(Some(old), Some(new));
+        }
+    }
+
+    /// Records every leaf of `value`, which only one side has, under the
+    /// current path.
+    fn one_sided(&mut self, value: Peek<'mem, 'facet>, side: Side) {
+        let mut visitor = OneSided {
+            collector: self,
+            side,
+        };
+        let _ = walk(value, &mut visitor);
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Side {
+    Old,
+    New,
+}
+
+/// Flattens a value present on one side only into leaf changes, keeping the
+/// collector's path in step with the walk.
+struct OneSided<'a, 'mem, 'facet> {
+    collector: &'a mut Collector<'mem, 'facet>,
+    side: Side,
+}
+
+impl<'mem, 'facet> OneSided<'_, 'mem, 'facet> {
+    /// Called on entering a container: pushes its path segment, and skips the
+    /// insides of map keys, which are already part of the path of their value.
+    fn enter(&mut self, edge: PeekEdge<'mem, 'facet>) -> VisitControl {
+        if matches!(edge, PeekEdge::MapKey) {
+            return VisitControl::SkipChildren;
+        }
+        self.push(edge);
+        VisitControl::Continue
+    }
+
+    /// Pushes the path segment for `edge`, if it has one.
+    fn push(&mut self, edge: PeekEdge<'mem, 'facet>) {
+        match edge {
+            PeekEdge::Field { field, .. } => self.collector.enter_field(field),
+            PeekEdge::Index(index) => self.collector.path.push(ChangeSegment::Index(index)),
+            PeekEdge::MapValue(key) => self
+                .collector
+                .path
+                .push(ChangeSegment::Key(render_key(key))),
+            PeekEdge::Root
+            | PeekEdge::MapKey
+            | PeekEdge::Some
+            | PeekEdge::Ok
+            | PeekEdge::Err
+            | PeekEdge::Pointee => {}
+        }
+    }
+
+    /// Undoes [`Self::push`].
+    fn pop(&mut self, edge: PeekEdge<'mem, 'facet>) {
+        match edge {
+            PeekEdge::Field { .. } => self.collector.leave_field(),
+            PeekEdge::Index(_) | PeekEdge::MapValue(_) => {
+                self.collector.path.pop();
+            }
+            _ => {}
+        }
+    }
+
+    fn record(&mut self, value: Peek<'mem, 'facet>) {
+        match self.side {
+            Side::Old => self.collector.record(Some(value), None),
+            Side::New => self.collector.record(None, Some(value)),
+        }
+    }
+}
+
+impl<'mem, 'facet> PeekVisitor<'mem, 'facet> for OneSided<'_, 'mem, 'facet> {
+    fn enter_struct(
+        &mut self,
+        _: PeekStruct<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        self.enter(edge)
+    }
+
+    fn leave_struct(
+        &mut self,
+        _: PeekStruct<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        self.pop(edge);
+        VisitControl::Continue
+    }
+
+    fn enter_enum(
+        &mut self,
+        _: PeekEnum<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        self.enter(edge)
+    }
+
+    fn leave_enum(
+        &mut self,
+        _: PeekEnum<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        self.pop(edge);
+        VisitControl::Continue
+    }
+
+    fn enter_list(
+        &mut self,
+        _: PeekListLike<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        self.enter(edge)
+    }
+
+    fn leave_list(
+        &mut self,
+        _: PeekListLike<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        self.pop(edge);
+        VisitControl::Continue
+    }
+
+    fn enter_map(
+        &mut self,
+        _: PeekMap<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        self.enter(edge)
+    }
+
+    fn leave_map(
+        &mut self,
+        _: PeekMap<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        self.pop(edge);
+        VisitControl::Continue
+    }
+
+    // Sets have no stable positions, so a set is one leaf.
+    fn enter_set(
+        &mut self,
+        set: PeekSet<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        if matches!(edge, PeekEdge::MapKey) {
+            return VisitControl::SkipChildren;
+        }
+        self.push(edge);
+        self.record(set.value);
+        self.pop(edge);
+        VisitControl::SkipChildren
+    }
+
+    fn visit_scalar(
+        &mut self,
+        value: Peek<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        // Keys are already part of the path of their value.
+        if matches!(edge, PeekEdge::MapKey) {
+            return VisitControl::Continue;
+        }
+        self.push(edge);
+        self.record(value);
+        self.pop(edge);
+        VisitControl::Continue
+    }
+}
+
+/// The path form of a map key: strings quoted, everything else displayed.
+fn render_key(key: Peek<'_, '_>) -> String {
+    if let Some(s) = key.as_str() {
+        format!("{s:?}")
+    } else if key.shape().is_display() {
+        format!("{key}")
+    } else {
+        format!("{key:?}")
+    }
+}
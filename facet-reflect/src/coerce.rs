@@ -0,0 +1,264 @@
+//! Scalar coercions shared by the format deserializers and [`convert`](crate::convert).
+//!
+//! Parsers disagree on how they hand out numbers: TOML only has `i64`, JSON
+//! splits integers into `u64` and `i64`, YAML guesses from the text. Whether
+//! such a number fits into the field it is meant for (a `u8`, an `f32`, ...)
+//! is decided here, so that every format accepts and rejects the same values.
+
+use alloc::string::{String, ToString};
+
+use facet_core::Shape;
+
+use crate::{Partial, Peek, ReflectError, ScalarType};
+
+/// Why [`coerce_scalar`] refused a conversion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoerceError {
+    /// The value doesn't fit in the destination type (`300` into a `u8`,
+    /// `-1` into a `u32`).
+    OutOfRange,
+    /// The value would lose precision: a float with a fractional part going
+    /// into an integer or, for [`coerce_scalar_exact`], any rounding at all.
+    Inexact,
+    /// There is no coercion between these two types.
+    Unsupported,
+}
+
+impl core::fmt::Display for CoerceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::OutOfRange => f.write_str("number out of range"),
+            Self::Inexact => f.write_str("number not exactly representable"),
+            Self::Unsupported => f.write_str("no conversion between these types"),
+        }
+    }
+}
+
+impl core::error::Error for CoerceError {}
+
+/// A scalar produced by [`coerce_scalar`], ready to be [set](Self::set) into a
+/// [`Partial`] of the destination type.
+#[derive(Debug, Clone, PartialEq)]
+#[allow(missing_docs)]
+pub enum Coerced {
+    Bool(bool),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    USize(usize),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(i128),
+    ISize(isize),
+    F32(f32),
+    F64(f64),
+    Char(char),
+    String(String),
+}
+
+impl Coerced {
+    /// Sets the value into `wip`, whose shape must be the destination shape
+    /// the value was coerced to.
+    pub fn set<'facet, const BORROW: bool>(
+        self,
+        wip: Partial<'facet, BORROW>,
+    ) -> Result<Partial<'facet, BORROW>, ReflectError> {
+        match self {
+            Self::Bool(v) => wip.set(v),
+            Self::U8(v) => wip.set(v),
+            Self::U16(v) => wip.set(v),
+            Self::U32(v) => wip.set(v),
+            Self::U64(v) => wip.set(v),
+            Self::U128(v) => wip.set(v),
+            Self::USize(v) => wip.set(v),
+            Self::I8(v) => wip.set(v),
+            Self::I16(v) => wip.set(v),
+            Self::I32(v) => wip.set(v),
+            Self::I64(v) => wip.set(v),
+            Self::I128(v) => wip.set(v),
+            Self::ISize(v) => wip.set(v),
+            Self::F32(v) => wip.set(v),
+            Self::F64(v) => wip.set(v),
+            Self::Char(v) => wip.set(v),
+            Self::String(v) => wip.set(v),
+        }
+    }
+}
+
+/// Converts the scalar `src` into a value of the scalar type `dst`.
+///
+/// - Integers convert between all widths as long as the value is in range.
+/// - Integers convert into floats, and `f64` into `f32`, rounding to the
+///   nearest float.
+/// - Floats convert into integers when they have no fractional part and are
+///   in range.
+/// - A `char` converts into a `String`, and a string of exactly one
+///   character converts into a `char`; any string type converts into `String`.
+///
+/// Booleans only convert into booleans. Anything else, including non-scalar
+/// shapes on either side, is [`CoerceError::Unsupported`].
+///
+/// ```
+/// use facet_core::Facet;
+/// use facet_reflect::{CoerceError, Coerced, Peek, coerce_scalar};
+///
+/// // TOML hands out every integer as an `i64`.
+/// let n: i64 = 200;
+/// assert_eq!(coerce_scalar(Peek::new(&n), u8::SHAPE), Ok(Coerced::U8(200)));
+/// assert_eq!(
+///     coerce_scalar(Peek::new(&n), i8::SHAPE),
+///     Err(CoerceError::OutOfRange)
+/// );
+/// ```
+pub fn coerce_scalar(src: Peek<'_, '_>, dst: &'static Shape) -> Result<Coerced, CoerceError> {
+    coerce(src, dst, false)
+}
+
+/// Like [`coerce_scalar`], but a conversion into a float must also be exact:
+/// `0.1_f64` doesn't convert into an `f32`, and `2^53 + 1` doesn't convert
+/// into an `f64`.
+pub fn coerce_scalar_exact(src: Peek<'_, '_>, dst: &'static Shape) -> Result<Coerced, CoerceError> {
+    coerce(src, dst, true)
+}
+
+fn coerce(src: Peek<'_, '_>, dst: &'static Shape, exact: bool) -> Result<Coerced, CoerceError> {
+    let (Some(src_type), Some(dst_type)) = (src.scalar_type(), ScalarType::try_from_shape(dst))
+    else {
+        return Err(CoerceError::Unsupported);
+    };
+
+    if let Some(number) = Number::read(src, src_type) {
+        return number.coerce(dst_type, exact);
+    }
+
+    match (src_type, dst_type) {
+        (ScalarType::Bool, ScalarType::Bool) => src
+            .get::<bool>()
+            .map(|b| Coerced::Bool(*b))
+            .map_err(|_| CoerceError::Unsupported),
+        (ScalarType::Char, ScalarType::Char | ScalarType::String) => {
+            let c = *src.get::<char>().map_err(|_| CoerceError::Unsupported)?;
+            Ok(match dst_type {
+                ScalarType::Char => Coerced::Char(c),
+                _ => Coerced::String(c.to_string()),
+            })
+        }
+        (_, ScalarType::Char) => {
+            let mut chars = src.as_str().ok_or(CoerceError::Unsupported)?.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Ok(Coerced::Char(c)),
+                _ => Err(CoerceError::Unsupported),
+            }
+        }
+        (_, ScalarType::String) => src
+            .as_str()
+            .map(|s| Coerced::String(s.to_string()))
+            .ok_or(CoerceError::Unsupported),
+        _ => Err(CoerceError::Unsupported),
+    }
+}
+
+/// 2^127, the exclusive upper bound of `i128` as a float.
+const I128_BOUND: f64 = 170_141_183_460_469_231_731_687_303_715_884_105_728.0;
+
+/// A number read from any primitive numeric type.
+#[derive(Clone, Copy)]
+enum Number {
+    Signed(i128),
+    Unsigned(u128),
+    Float(f64),
+}
+
+impl Number {
+    fn read(src: Peek<'_, '_>, ty: ScalarType) -> Option<Self> {
+        Some(match ty {
+            ScalarType::U8 => Self::Unsigned((*src.get::<u8>().ok()?).into()),
+            ScalarType::U16 => Self::Unsigned((*src.get::<u16>().ok()?).into()),
+            ScalarType::U32 => Self::Unsigned((*src.get::<u32>().ok()?).into()),
+            ScalarType::U64 => Self::Unsigned((*src.get::<u64>().ok()?).into()),
+            ScalarType::U128 => Self::Unsigned(*src.get::<u128>().ok()?),
+            ScalarType::USize => Self::Unsigned(*src.get::<usize>().ok()? as u128),
+            ScalarType::I8 => Self::Signed((*src.get::<i8>().ok()?).into()),
+            ScalarType::I16 => Self::Signed((*src.get::<i16>().ok()?).into()),
+            ScalarType::I32 => Self::Signed((*src.get::<i32>().ok()?).into()),
+            ScalarType::I64 => Self::Signed((*src.get::<i64>().ok()?).into()),
+            ScalarType::I128 => Self::Signed(*src.get::<i128>().ok()?),
+            ScalarType::ISize => Self::Signed(*src.get::<isize>().ok()? as i128),
+            ScalarType::F32 => Self::Float((*src.get::<f32>().ok()?).into()),
+            ScalarType::F64 => Self::Float(*src.get::<f64>().ok()?),
+            _ => return None,
+        })
+    }
+
+    /// The number as a float, rounded to nearest unless `exact` is set.
+    fn to_f64(self, exact: bool) -> Result<f64, CoerceError> {
+        let (f, is_exact) = match self {
+            Self::Float(f) => (f, true),
+            Self::Signed(n) => {
+                let f = n as f64;
+                (f, f < I128_BOUND && f as i128 == n)
+            }
+            Self::Unsigned(n) => {
+                let f = n as f64;
+                (f, f < 2.0 * I128_BOUND && f as u128 == n)
+            }
+        };
+        if exact && !is_exact {
+            return Err(CoerceError::Inexact);
+        }
+        Ok(f)
+    }
+
+    /// The number as an integer, if it has no fractional part.
+    fn to_i128(self) -> Result<i128, CoerceError> {
+        match self {
+            Self::Signed(n) => Ok(n),
+            Self::Unsigned(n) => i128::try_from(n).map_err(|_| CoerceError::OutOfRange),
+            Self::Float(f) if f.is_nan() => Err(CoerceError::Inexact),
+            Self::Float(f) if f < -I128_BOUND || f >= I128_BOUND => Err(CoerceError::OutOfRange),
+            Self::Float(f) if f as i128 as f64 == f => Ok(f as i128),
+            Self::Float(_) => Err(CoerceError::Inexact),
+        }
+    }
+
+    fn coerce(self, ty: ScalarType, exact: bool) -> Result<Coerced, CoerceError> {
+        macro_rules! integer {
+            ($t:ty, $variant:ident) => {{
+                let value = match self {
+                    Self::Unsigned(n) => <$t>::try_from(n).ok(),
+                    _ => <$t>::try_from(self.to_i128()?).ok(),
+                };
+                value.map(Coerced::$variant).ok_or(CoerceError::OutOfRange)
+            }};
+        }
+
+        match ty {
+            ScalarType::U8 => integer!(u8, U8),
+            ScalarType::U16 => integer!(u16, U16),
+            ScalarType::U32 => integer!(u32, U32),
+            ScalarType::U64 => integer!(u64, U64),
+            ScalarType::U128 => integer!(u128, U128),
+            ScalarType::USize => integer!(usize, USize),
+            ScalarType::I8 => integer!(i8, I8),
+            ScalarType::I16 => integer!(i16, I16),
+            ScalarType::I32 => integer!(i32, I32),
+            ScalarType::I64 => integer!(i64, I64),
+            ScalarType::I128 => integer!(i128, I128),
+            ScalarType::ISize => integer!(isize, ISize),
+            ScalarType::F64 => self.to_f64(exact).map(Coerced::F64),
+            ScalarType::F32 => {
+                let f = self.to_f64(exact)?;
+                let value = f as f32;
+                if exact && f64::from(value) != f && !f.is_nan() {
+                    return Err(CoerceError::Inexact);
+                }
+                Ok(Coerced::F32(value))
+            }
+            _ => Err(CoerceError::Unsupported),
+        }
+    }
+}
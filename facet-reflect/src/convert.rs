@@ -0,0 +1,264 @@
+//! Structural conversion between two `Facet` types.
+//!
+//! [`convert`] copies a value of one type into another by matching struct
+//! fields and enum variants by name, which covers the common case of migrating
+//! between two versions of a configuration type that share most of their
+//! fields.
+
+use facet_core::{Def, Facet, Field, Type, UserType};
+use facet_path::Path;
+
+use crate::deep::clone_into;
+use crate::{CoerceError, Partial, Peek, ReflectError, ReflectErrorKind, coerce_scalar_exact};
+
+/// Converts `src` into a value of type `Dst`, matching fields by name.
+///
+/// The value is walked structurally, pairing up parts of `Src` and `Dst`:
+///
+/// - Values of identical types are cloned (see [`deep_clone`](crate::deep_clone)).
+/// - Struct fields and enum variants are matched by their effective (renamed)
+///   name; a destination field may also be matched through its alias.
+///   Destination fields without a counterpart are left to their defaults
+///   (`Option` fields become `None`, `#[facet(default)]` fields get their
+///   default), and source fields without a counterpart are dropped.
+/// - A `T` converts into an `Option<U>` (as `Some`), and a `Some` converts
+///   into a non-optional `U`. Smart pointers are looked through on both sides.
+/// - Scalars go through [`coerce_scalar_exact`]: numbers convert between all
+///   integer and float types as long as the value is represented exactly,
+///   string types convert into `String`, and chars into and from strings.
+/// - Lists, arrays and slices convert element by element into lists, arrays
+///   and sets; maps and sets convert entry by entry.
+///
+/// Any other pairing, a lossy numeric conversion, a `None` going into a
+/// non-optional field, or a missing required field is an error.
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+/// use facet_reflect::convert;
+///
+/// #[derive(Facet)]
+/// struct ConfigV1 {
+///     name: String,
+///     port: u16,
+///     verbose: bool,
+/// }
+///
+/// #[derive(Facet, Debug, PartialEq)]
+/// struct ConfigV2 {
+///     name: String,
+///     port: u32,
+///     #[facet(rename = "verbose")]
+///     debug: Option<bool>,
+///     timeout_secs: Option<u64>,
+/// }
+///
+/// let v1 = ConfigV1 { name: "app".into(), port: 8080, verbose: true };
+/// let v2: ConfigV2 = convert(&v1).unwrap();
+/// assert_eq!(
+///     v2,
+///     ConfigV2 { name: "app".into(), port: 8080, debug: Some(true), timeout_secs: None }
+/// );
+/// ```
+pub fn convert<'facet, Src, Dst>(src: &Src) -> Result<Dst, ReflectError>
+where
+    Src: Facet<'facet>,
+    Dst: Facet<'facet>,
+{
+    convert_peek(Peek::new(src))
+}
+
+/// [`convert`] from a [`Peek`].
+pub(crate) fn convert_peek<'facet, Dst: Facet<'facet>>(
+    src: Peek<'_, 'facet>,
+) -> Result<Dst, ReflectError> {
+    let wip = Partial::alloc::<Dst>()?;
+    convert_into(wip, src)?.build()?.materialize().map_err(|e| {
+        ReflectError::new(
+            ReflectErrorKind::WrongShape {
+                expected: e.expected,
+                actual: e.actual,
+            },
+            Path::new(Dst::SHAPE),
+        )
+    })
+}
+
+fn convert_into<'facet>(
+    mut wip: Partial<'facet, true>,
+    src: Peek<'_, 'facet>,
+) -> Result<Partial<'facet, true>, ReflectError> {
+    let dst_shape = wip.shape();
+    if src.shape == dst_shape {
+        return clone_into(wip, src);
+    }
+
+    // Options, results and smart pointers first: options and results are
+    // also described as enums.
+    match (src.shape.def, dst_shape.def) {
+        (Def::Option(_), Def::Option(_)) => {
+            return match src.into_option()?.value() {
+                Some(inner) => convert_into(wip.begin_some()?, inner)?.end(),
+                None => wip.set_default(),
+            };
+        }
+        (Def::Option(_), _) => {
+            return match src.into_option()?.value() {
+                Some(inner) => convert_into(wip, inner),
+                None => Err(mismatch(
+                    &wip,
+                    "convert: cannot convert None into a non-optional value",
+                )),
+            };
+        }
+        (_, Def::Option(_)) => return convert_into(wip.begin_some()?, src)?.end(),
+
+        (Def::Result(_), Def::Result(_)) => {
+            let src_result = src.into_result()?;
+            return match (src_result.ok(), src_result.err()) {
+                (Some(ok), _) => convert_into(wip.begin_ok()?, ok)?.end(),
+                (_, Some(err)) => convert_into(wip.begin_err()?, err)?.end(),
+                (None, None) => unreachable!("a result is either ok or err"),
+            };
+        }
+
+        (Def::Pointer(_), _) => {
+            if let Some(inner) = src.into_pointer()?.borrow_inner() {
+                return convert_into(wip, inner);
+            }
+        }
+        (_, Def::Pointer(_)) => return convert_into(wip.begin_smart_ptr()?, src)?.end(),
+
+        _ => {}
+    }
+
+    match (src.shape.ty, dst_shape.ty) {
+        (Type::User(UserType::Struct(src_struct)), Type::User(UserType::Struct(dst_struct))) => {
+            for (index, field) in dst_struct.fields.iter().enumerate() {
+                let Some(src_field) = src_struct.fields.iter().find(|f| names_match(f, field))
+                else {
+                    continue;
+                };
+                let field_peek = unsafe {
+                    Peek::unchecked_new(src.data.field(src_field.offset), src_field.shape())
+                };
+                wip = convert_into(wip.begin_nth_field(index)?, field_peek)?.end()?;
+            }
+            return Ok(wip);
+        }
+
+        (Type::User(UserType::Enum(_)), Type::User(UserType::Enum(dst_enum))) => {
+            let src_enum = src.into_enum()?;
+            let Ok(src_variant) = src_enum.active_variant() else {
+                return Err(mismatch(
+                    &wip,
+                    "convert: could not read the active variant of the source",
+                ));
+            };
+            let Some((variant_index, dst_variant)) = dst_enum
+                .variants
+                .iter()
+                .enumerate()
+                .find(|(_, v)| v.effective_name() == src_variant.effective_name())
+            else {
+                return Err(mismatch(&wip, "convert: no variant with a matching name"));
+            };
+            let src_fields = src_variant.data.fields;
+            wip = wip.select_nth_variant(variant_index)?;
+            for (index, field) in dst_variant.data.fields.iter().enumerate() {
+                let Some(src_index) = src_fields.iter().position(|f| names_match(f, field)) else {
+                    continue;
+                };
+                if let Ok(Some(field_peek)) = src_enum.field(src_index) {
+                    wip = convert_into(wip.begin_nth_field(index)?, field_peek)?.end()?;
+                }
+            }
+            return Ok(wip);
+        }
+
+        _ => {}
+    }
+
+    match (src.shape.def, dst_shape.def) {
+        (Def::List(_) | Def::Array(_) | Def::Slice(_), Def::List(_)) => {
+            let src_list = src.into_list_like()?;
+            wip = wip.init_list_with_capacity(src_list.len())?;
+            for elem in src_list.iter() {
+                wip = convert_into(wip.begin_list_item()?, elem)?.end()?;
+            }
+            Ok(wip)
+        }
+
+        (Def::List(_) | Def::Array(_) | Def::Slice(_), Def::Array(array_def)) => {
+            let src_list = src.into_list_like()?;
+            if src_list.len() != array_def.n {
+                return Err(mismatch(
+                    &wip,
+                    "convert: source length differs from array length",
+                ));
+            }
+            wip = wip.init_array()?;
+            for (index, elem) in src_list.iter().enumerate() {
+                wip = convert_into(wip.begin_nth_field(index)?, elem)?.end()?;
+            }
+            Ok(wip)
+        }
+
+        (Def::List(_) | Def::Array(_) | Def::Slice(_), Def::Set(_)) => {
+            wip = wip.init_set()?;
+            for elem in src.into_list_like()?.iter() {
+                wip = convert_into(wip.begin_set_item()?, elem)?.end()?;
+            }
+            Ok(wip)
+        }
+
+        (Def::Set(_), Def::Set(_)) => {
+            wip = wip.init_set()?;
+            for elem in src.into_set()?.iter() {
+                wip = convert_into(wip.begin_set_item()?, elem)?.end()?;
+            }
+            Ok(wip)
+        }
+
+        (Def::Map(_), Def::Map(_)) => {
+            wip = wip.init_map()?;
+            for (key, value) in src.into_map()?.iter() {
+                wip = convert_into(wip.begin_key()?, key)?.end()?;
+                wip = convert_into(wip.begin_value()?, value)?.end()?;
+            }
+            Ok(wip)
+        }
+
+        _ => convert_scalar(wip, src),
+    }
+}
+
+/// Whether `src` is the counterpart of destination field `dst`.
+fn names_match(src: &Field, dst: &Field) -> bool {
+    let name = src.effective_name();
+    name == dst.effective_name() || dst.alias == Some(name)
+}
+
+fn mismatch(wip: &Partial<'_, true>, operation: &'static str) -> ReflectError {
+    wip.err(ReflectErrorKind::OperationFailed {
+        shape: wip.shape(),
+        operation,
+    })
+}
+
+fn convert_scalar<'facet>(
+    wip: Partial<'facet, true>,
+    src: Peek<'_, 'facet>,
+) -> Result<Partial<'facet, true>, ReflectError> {
+    match coerce_scalar_exact(src, wip.shape()) {
+        Ok(value) => value.set(wip),
+        Err(CoerceError::OutOfRange) => Err(mismatch(&wip, "convert: number out of range")),
+        Err(CoerceError::Inexact) => {
+            Err(mismatch(&wip, "convert: number not exactly representable"))
+        }
+        Err(CoerceError::Unsupported) => {
+            Err(mismatch(&wip, "convert: no conversion between these types"))
+        }
+    }
+}
@@ -0,0 +1,491 @@
+//! Structural deep clone, deep equality and hashing over reflected values.
+//!
+//! [`deep_clone`] and [`deep_eq`] work for any `Facet` type by walking its shape,
+//! so diff/patch tooling and test helpers don't need `Clone` or `PartialEq`
+//! bounds on user types. Whenever a type does implement `Clone` (resp.
+//! `PartialEq`), that implementation is used for the whole subtree.
+//!
+//! [`hash_value`] does the same for `Hash`, but always walks structs and enums
+//! itself so that skip attributes are honored. It is therefore not consistent
+//! with [`deep_eq`] for types whose `PartialEq` is looser than their fields.
+
+use core::hash::{Hash, Hasher};
+
+use facet_core::{Def, HashProxy, Type, UserType};
+
+use crate::{HasFields, HeapValue, Partial, Peek, ReflectError, ReflectErrorKind, ScalarType};
+
+/// Compares two values structurally.
+///
+/// Values of different shapes are never equal. For each node, the type's own
+/// `PartialEq` is used when available; otherwise structs and enums are compared
+/// field by field, lists element by element, and maps and sets by lookup (so
+/// iteration order does not matter). Values that are neither comparable nor
+/// structurally traversable (opaque types without `PartialEq`) compare unequal.
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+/// use facet_reflect::{Peek, deep_eq};
+///
+/// // No `PartialEq` derive needed
+/// #[derive(Facet)]
+/// struct Point { x: i32, y: i32 }
+///
+/// let a = Point { x: 1, y: 2 };
+/// let b = Point { x: 1, y: 2 };
+/// assert!(deep_eq(Peek::new(&a), Peek::new(&b)));
+/// ```
+pub fn deep_eq<'facet>(a: Peek<'_, 'facet>, b: Peek<'_, 'facet>) -> bool {
+    if a.shape != b.shape {
+        return false;
+    }
+
+    if let Some(result) = unsafe { a.shape.call_partial_eq(a.data, b.data) } {
+        return result;
+    }
+
+    match a.shape.ty {
+        Type::User(UserType::Struct(struct_type)) => struct_type.fields.iter().all(|field| {
+            let a_field = unsafe { Peek::unchecked_new(a.data.field(field.offset), field.shape()) };
+            let b_field = unsafe { Peek::unchecked_new(b.data.field(field.offset), field.shape()) };
+            deep_eq(a_field, b_field)
+        }),
+
+        Type::User(UserType::Enum(_)) => {
+            let (Ok(a_enum), Ok(b_enum)) = (a.into_enum(), b.into_enum()) else {
+                return false;
+            };
+            match (a_enum.variant_index(), b_enum.variant_index()) {
+                (Ok(a_index), Ok(b_index)) if a_index == b_index => a_enum
+                    .fields()
+                    .zip(b_enum.fields())
+                    .all(|((_, a_field), (_, b_field))| deep_eq(a_field, b_field)),
+                _ => false,
+            }
+        }
+
+        _ => match a.shape.def {
+            Def::List(_) | Def::Array(_) | Def::Slice(_) => {
+                let (Ok(a_list), Ok(b_list)) = (a.into_list_like(), b.into_list_like()) else {
+                    return false;
+                };
+                a_list.len() == b_list.len()
+                    && a_list
+                        .iter()
+                        .zip(b_list.iter())
+                        .all(|(a_elem, b_elem)| deep_eq(a_elem, b_elem))
+            }
+
+            Def::Map(_) => {
+                let (Ok(a_map), Ok(b_map)) = (a.into_map(), b.into_map()) else {
+                    return false;
+                };
+                a_map.len() == b_map.len()
+                    && a_map.iter().all(|(key, a_value)| {
+                        matches!(b_map.get_peek(key), Ok(Some(b_value)) if deep_eq(a_value, b_value))
+                    })
+            }
+
+            Def::Set(_) => {
+                let (Ok(a_set), Ok(b_set)) = (a.into_set(), b.into_set()) else {
+                    return false;
+                };
+                a_set.len() == b_set.len()
+                    && a_set
+                        .iter()
+                        .all(|elem| matches!(b_set.contains_peek(elem), Ok(true)))
+            }
+
+            Def::Option(_) => {
+                let (Ok(a_opt), Ok(b_opt)) = (a.into_option(), b.into_option()) else {
+                    return false;
+                };
+                match (a_opt.value(), b_opt.value()) {
+                    (Some(a_inner), Some(b_inner)) => deep_eq(a_inner, b_inner),
+                    (None, None) => true,
+                    _ => false,
+                }
+            }
+
+            Def::Result(_) => {
+                let (Ok(a_res), Ok(b_res)) = (a.into_result(), b.into_result()) else {
+                    return false;
+                };
+                match (a_res.ok(), b_res.ok(), a_res.err(), b_res.err()) {
+                    (Some(a_ok), Some(b_ok), _, _) => deep_eq(a_ok, b_ok),
+                    (_, _, Some(a_err), Some(b_err)) => deep_eq(a_err, b_err),
+                    _ => false,
+                }
+            }
+
+            Def::Pointer(_) => {
+                let (Ok(a_ptr), Ok(b_ptr)) = (a.into_pointer(), b.into_pointer()) else {
+                    return false;
+                };
+                match (a_ptr.borrow_inner(), b_ptr.borrow_inner()) {
+                    (Some(a_inner), Some(b_inner)) => deep_eq(a_inner, b_inner),
+                    _ => false,
+                }
+            }
+
+            _ => false,
+        },
+    }
+}
+
+/// Clones a value structurally into a new heap allocation.
+///
+/// For each node, the type's own `Clone` is used when available; otherwise the
+/// value is rebuilt through a [`Partial`], recursing into struct and enum
+/// fields, list and array elements, map entries, set items, options, results
+/// and smart pointers. Returns an error for values that can be neither cloned
+/// nor traversed (opaque types without `Clone`, slices, dynamic values).
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+/// use facet_reflect::{Peek, deep_clone};
+///
+/// // No `Clone` derive needed
+/// #[derive(Facet, Debug, PartialEq)]
+/// struct Config { name: String, ports: Vec<u16> }
+///
+/// let config = Config { name: "app".into(), ports: vec![80, 443] };
+/// let copy: Config = deep_clone(Peek::new(&config)).unwrap().materialize().unwrap();
+/// assert_eq!(copy, config);
+/// ```
+pub fn deep_clone<'facet>(peek: Peek<'_, 'facet>) -> Result<HeapValue<'facet, true>, ReflectError> {
+    // SAFETY: the shape comes from a live value, so it describes a real type.
+    let wip = unsafe { Partial::alloc_shape(peek.shape)? };
+    clone_into(wip, peek)?.build()
+}
+
+pub(crate) fn clone_into<'facet>(
+    mut wip: Partial<'facet, true>,
+    src: Peek<'_, 'facet>,
+) -> Result<Partial<'facet, true>, ReflectError> {
+    let shape = src.shape;
+
+    if shape.is_clone() {
+        // SAFETY: `call_clone_into` fully initializes `dst` when it returns `Some`.
+        return unsafe {
+            wip.set_from_function(|dst| {
+                shape.call_clone_into(src.data, dst.assume_init()).ok_or(
+                    ReflectErrorKind::OperationFailed {
+                        shape,
+                        operation: "deep_clone: clone_into",
+                    },
+                )
+            })
+        };
+    }
+
+    match shape.ty {
+        Type::User(UserType::Struct(struct_type)) => {
+            for (index, field) in struct_type.fields.iter().enumerate() {
+                let field_peek =
+                    unsafe { Peek::unchecked_new(src.data.field(field.offset), field.shape()) };
+                wip = clone_into(wip.begin_nth_field(index)?, field_peek)?.end()?;
+            }
+            return Ok(wip);
+        }
+
+        Type::User(UserType::Enum(_)) => {
+            let src_enum = src.into_enum()?;
+            let variant_index = src_enum.variant_index().map_err(|_| {
+                wip.err(ReflectErrorKind::OperationFailed {
+                    shape,
+                    operation: "deep_clone: could not determine active variant",
+                })
+            })?;
+            wip = wip.select_nth_variant(variant_index)?;
+            for (index, (_, field_peek)) in src_enum.fields().enumerate() {
+                wip = clone_into(wip.begin_nth_field(index)?, field_peek)?.end()?;
+            }
+            return Ok(wip);
+        }
+
+        _ => {}
+    }
+
+    match shape.def {
+        Def::List(_) => {
+            let src_list = src.into_list_like()?;
+            wip = wip.init_list_with_capacity(src_list.len())?;
+            for elem in src_list.iter() {
+                wip = clone_into(wip.begin_list_item()?, elem)?.end()?;
+            }
+            Ok(wip)
+        }
+
+        Def::Array(_) => {
+            let src_array = src.into_list_like()?;
+            wip = wip.init_array()?;
+            for (index, elem) in src_array.iter().enumerate() {
+                wip = clone_into(wip.begin_nth_field(index)?, elem)?.end()?;
+            }
+            Ok(wip)
+        }
+
+        Def::Map(_) => {
+            wip = wip.init_map()?;
+            for (key, value) in src.into_map()?.iter() {
+                wip = clone_into(wip.begin_key()?, key)?.end()?;
+                wip = clone_into(wip.begin_value()?, value)?.end()?;
+            }
+            Ok(wip)
+        }
+
+        Def::Set(_) => {
+            wip = wip.init_set()?;
+            for elem in src.into_set()?.iter() {
+                wip = clone_into(wip.begin_set_item()?, elem)?.end()?;
+            }
+            Ok(wip)
+        }
+
+        Def::Option(_) => match src.into_option()?.value() {
+            Some(inner) => clone_into(wip.begin_some()?, inner)?.end(),
+            None => wip.set_default(),
+        },
+
+        Def::Result(_) => {
+            let src_result = src.into_result()?;
+            match (src_result.ok(), src_result.err()) {
+                (Some(ok), _) => clone_into(wip.begin_ok()?, ok)?.end(),
+                (_, Some(err)) => clone_into(wip.begin_err()?, err)?.end(),
+                (None, None) => unreachable!("a result is either ok or err"),
+            }
+        }
+
+        Def::Pointer(_) => match src.into_pointer()?.borrow_inner() {
+            Some(inner) => clone_into(wip.begin_smart_ptr()?, inner)?.end(),
+            None => Err(wip.err(ReflectErrorKind::OperationFailed {
+                shape,
+                operation: "deep_clone: pointer does not expose its pointee",
+            })),
+        },
+
+        _ => Err(wip.err(ReflectErrorKind::OperationFailed {
+            shape,
+            operation: "deep_clone: type is neither Clone nor structurally traversable",
+        })),
+    }
+}
+
+/// Hashes a value structurally, without requiring `Hash` on user types.
+///
+/// Meant for content-addressed caching: two values of the same shape whose
+/// serialized contents are the same hash the same. Structs and enums are
+/// hashed field by field (with field and variant names), skipping metadata
+/// fields and fields marked `#[facet(skip)]` or `#[facet(skip_serializing)]`.
+/// Maps and sets are hashed independently of iteration order, so two
+/// `HashMap`s with the same entries hash the same. Options, results and smart
+/// pointers hash their payload. Other values use their `Hash` implementation;
+/// floats hash their bit pattern, with `-0.0` hashed as `0.0`.
+///
+/// This is not the equivalence [`deep_eq`] checks: `deep_eq` defers to a
+/// type's own `PartialEq` where one exists, and `hash_value` never does, so
+/// values that a custom `PartialEq` considers equal may still hash
+/// differently. Don't use it as the hash half of a `deep_eq`-keyed table.
+///
+/// Returns an error for values that have no `Hash` implementation and can't
+/// be traversed (opaque types, for instance).
+///
+/// # Example
+///
+/// ```
+/// use std::hash::{DefaultHasher, Hasher};
+///
+/// use facet::Facet;
+/// use facet_reflect::{Peek, hash_value};
+///
+/// // No `Hash` derive needed
+/// #[derive(Facet)]
+/// struct Request {
+///     url: String,
+///     #[facet(skip_serializing)]
+///     attempt: u32,
+/// }
+///
+/// let key = |request: &Request| {
+///     let mut hasher = DefaultHasher::new();
+///     hash_value(Peek::new(request), &mut hasher).unwrap();
+///     hasher.finish()
+/// };
+/// let first = Request { url: "/index".into(), attempt: 1 };
+/// let retry = Request { url: "/index".into(), attempt: 2 };
+/// assert_eq!(key(&first), key(&retry));
+/// ```
+pub fn hash_value<H: Hasher>(value: Peek<'_, '_>, hasher: &mut H) -> Result<(), ReflectError> {
+    // Options and results are also described as enums, so handle them first.
+    match value.shape.def {
+        Def::Option(_) => {
+            return match value.into_option()?.value() {
+                Some(inner) => {
+                    1u8.hash(hasher);
+                    hash_value(inner, hasher)
+                }
+                None => {
+                    0u8.hash(hasher);
+                    Ok(())
+                }
+            };
+        }
+
+        Def::Result(_) => {
+            let result = value.into_result()?;
+            if let Some(ok) = result.ok() {
+                0u8.hash(hasher);
+                return hash_value(ok, hasher);
+            }
+            if let Some(err) = result.err() {
+                1u8.hash(hasher);
+                return hash_value(err, hasher);
+            }
+        }
+
+        Def::Pointer(_) => {
+            if let Some(inner) = value.into_pointer()?.borrow_inner() {
+                return hash_value(inner, hasher);
+            }
+        }
+
+        _ => {}
+    }
+
+    match value.shape.ty {
+        Type::User(UserType::Struct(struct_type)) => {
+            for field in struct_type.fields {
+                if field.is_metadata() || field.should_skip_serializing_unconditional() {
+                    continue;
+                }
+                field.name.hash(hasher);
+                let child =
+                    unsafe { Peek::unchecked_new(value.data.field(field.offset), field.shape()) };
+                hash_value(child, hasher)?;
+            }
+            return Ok(());
+        }
+
+        Type::User(UserType::Enum(_)) => {
+            let peek_enum = value.into_enum()?;
+            let unreadable = |_| {
+                value.err(ReflectErrorKind::OperationFailed {
+                    shape: value.shape,
+                    operation: "hash_value: could not read the active variant",
+                })
+            };
+            let variant = peek_enum.active_variant().map_err(unreadable)?;
+            variant.name.hash(hasher);
+            for (index, field) in variant.data.fields.iter().enumerate() {
+                if field.is_metadata() || field.should_skip_serializing_unconditional() {
+                    continue;
+                }
+                if let Some(child) = peek_enum.field(index).map_err(unreadable)? {
+                    field.name.hash(hasher);
+                    hash_value(child, hasher)?;
+                }
+            }
+            return Ok(());
+        }
+
+        _ => {}
+    }
+
+    match value.shape.def {
+        Def::List(_) | Def::Array(_) | Def::Slice(_) => {
+            let list = value.into_list_like()?;
+            list.len().hash(hasher);
+            for item in list.iter() {
+                hash_value(item, hasher)?;
+            }
+            Ok(())
+        }
+
+        Def::Map(_) => {
+            let map = value.into_map()?;
+            let mut entries = 0u64;
+            for (key, item) in map.iter() {
+                let mut entry = Fnv1a::default();
+                hash_value(key, &mut entry)?;
+                hash_value(item, &mut entry)?;
+                entries = entries.wrapping_add(entry.finish());
+            }
+            map.len().hash(hasher);
+            entries.hash(hasher);
+            Ok(())
+        }
+
+        Def::Set(_) => {
+            let set = value.into_set()?;
+            let mut items = 0u64;
+            for item in set.iter() {
+                let mut entry = Fnv1a::default();
+                hash_value(item, &mut entry)?;
+                items = items.wrapping_add(entry.finish());
+            }
+            set.len().hash(hasher);
+            items.hash(hasher);
+            Ok(())
+        }
+
+        Def::DynamicValue(_) => {
+            value.into_dynamic_value()?.structural_hash_inner(hasher);
+            Ok(())
+        }
+
+        _ => hash_scalar(value, hasher),
+    }
+}
+
+fn hash_scalar<H: Hasher>(value: Peek<'_, '_>, hasher: &mut H) -> Result<(), ReflectError> {
+    match value.scalar_type() {
+        Some(ScalarType::F32) => {
+            let v = *value.get::<f32>()?;
+            (if v == 0.0 { 0.0f32 } else { v }).to_bits().hash(hasher);
+            return Ok(());
+        }
+        Some(ScalarType::F64) => {
+            let v = *value.get::<f64>()?;
+            (if v == 0.0 { 0.0f64 } else { v }).to_bits().hash(hasher);
+            return Ok(());
+        }
+        _ => {}
+    }
+
+    let mut proxy = HashProxy::new(hasher);
+    if unsafe { value.shape.call_hash(value.data, &mut proxy) }.is_some() {
+        return Ok(());
+    }
+    Err(value.err(ReflectErrorKind::OperationFailed {
+        shape: value.shape,
+        operation: "hash_value: type is neither hashable nor traversable",
+    }))
+}
+
+/// FNV-1a, used to digest map entries and set items on their own so they can
+/// be combined independently of iteration order.
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
@@ -0,0 +1,227 @@
+//! Field offsets, sizes and alignment of struct types, for FFI and zero-copy
+//! readers.
+//!
+//! [`TypeLayout::of`] reads a struct's layout out of its [`Shape`] and checks
+//! that it is self-consistent (every field inside the struct, properly aligned,
+//! no overlaps). [`TypeLayout::check_repr_c`] additionally proves that the
+//! offsets are the ones a C compiler would pick, which is what code reading the
+//! same memory from C, or from a memory-mapped file, relies on.
+//!
+//! [`TypeLayout::write_c_defines`] writes those numbers out as C preprocessor
+//! constants, so non-Rust code can locate fields without a Rust toolchain.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use facet_core::{BaseRepr, Repr, Shape, Type, UserType};
+
+/// Error returned when a shape's layout can't be exported or isn't `repr(C)`.
+#[derive(Debug, Clone)]
+pub struct LayoutError {
+    /// The shape whose layout was requested.
+    pub shape: &'static Shape,
+    /// What is wrong with it.
+    pub reason: String,
+}
+
+impl core::fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "invalid layout for {}: {}", self.shape, self.reason)
+    }
+}
+
+impl core::error::Error for LayoutError {}
+
+/// Where one field of a struct lives in memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldLayout {
+    /// The field's name (`"0"`, `"1"`, ... for tuple structs).
+    pub name: &'static str,
+    /// The field's shape.
+    pub shape: &'static Shape,
+    /// Offset from the start of the struct, in bytes.
+    pub offset: usize,
+    /// Size in bytes.
+    pub size: usize,
+    /// Alignment in bytes.
+    pub align: usize,
+}
+
+/// The validated memory layout of a struct type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeLayout {
+    /// The struct's shape.
+    pub shape: &'static Shape,
+    /// Size in bytes, including trailing padding.
+    pub size: usize,
+    /// Alignment in bytes.
+    pub align: usize,
+    /// The struct's `#[repr]`.
+    pub repr: Repr,
+    /// Fields in declaration order.
+    pub fields: Vec<FieldLayout>,
+}
+
+impl TypeLayout {
+    /// Reads the layout of a sized struct type, checking that every field lies
+    /// within the struct, is aligned (unless the struct is packed), and doesn't
+    /// overlap another field.
+    pub fn of(shape: &'static Shape) -> Result<Self, LayoutError> {
+        let error = |reason: String| LayoutError { shape, reason };
+        let Type::User(UserType::Struct(struct_type)) = shape.ty else {
+            return Err(error("only structs have a field layout".into()));
+        };
+        let layout = shape
+            .layout
+            .sized_layout()
+            .map_err(|_| error("type is not sized".into()))?;
+
+        let mut fields = Vec::with_capacity(struct_type.fields.len());
+        for field in struct_type.fields {
+            let field_shape = field.shape();
+            let field_layout = field_shape
+                .layout
+                .sized_layout()
+                .map_err(|_| error(alloc::format!("field `{}` is not sized", field.name)))?;
+            let field = FieldLayout {
+                name: field.name,
+                shape: field_shape,
+                offset: field.offset,
+                size: field_layout.size(),
+                align: field_layout.align(),
+            };
+            if field.offset + field.size > layout.size() {
+                return Err(error(alloc::format!(
+                    "field `{}` ends at byte {}, past the end of the {}-byte struct",
+                    field.name,
+                    field.offset + field.size,
+                    layout.size()
+                )));
+            }
+            if !struct_type.repr.packed && field.offset % field.align != 0 {
+                return Err(error(alloc::format!(
+                    "field `{}` at offset {} is not {}-byte aligned",
+                    field.name,
+                    field.offset,
+                    field.align
+                )));
+            }
+            fields.push(field);
+        }
+
+        let mut by_offset: Vec<&FieldLayout> = fields.iter().filter(|f| f.size > 0).collect();
+        by_offset.sort_by_key(|field| field.offset);
+        for pair in by_offset.windows(2) {
+            if pair[0].offset + pair[0].size > pair[1].offset {
+                return Err(error(alloc::format!(
+                    "fields `{}` and `{}` overlap",
+                    pair[0].name,
+                    pair[1].name
+                )));
+            }
+        }
+
+        Ok(Self {
+            shape,
+            size: layout.size(),
+            align: layout.align(),
+            repr: struct_type.repr,
+            fields,
+        })
+    }
+
+    /// Returns the layout of the field called `name`.
+    pub fn field(&self, name: &str) -> Option<&FieldLayout> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Checks that the struct is `#[repr(C)]` and that its offsets and size are
+    /// exactly those of the C layout algorithm: fields in declaration order,
+    /// each at the next offset aligned for it, and the total size rounded up
+    /// to the struct's alignment.
+    ///
+    /// [`Repr`] doesn't record `align(N)` or the `N` of `packed(N)`, so the
+    /// struct's own alignment is taken from its shape: it must be at least
+    /// that of every field, unless the struct is packed, in which case it caps
+    /// the alignment of each field. A failure means the shape's offsets are not
+    /// the C ones, e.g. a hand-written shape that doesn't match the type.
+    pub fn check_repr_c(&self) -> Result<(), LayoutError> {
+        let error = |reason: String| LayoutError {
+            shape: self.shape,
+            reason,
+        };
+        if self.repr.base != BaseRepr::C {
+            return Err(error("type is not #[repr(C)]".into()));
+        }
+
+        let mut offset = 0;
+        let mut align = 1;
+        for field in &self.fields {
+            let field_align = if self.repr.packed {
+                field.align.min(self.align)
+            } else {
+                field.align
+            };
+            offset = offset.next_multiple_of(field_align);
+            if field.offset != offset {
+                return Err(error(alloc::format!(
+                    "field `{}` is at offset {}, but the C layout puts it at {offset}",
+                    field.name,
+                    field.offset
+                )));
+            }
+            offset += field.size;
+            align = align.max(field_align);
+        }
+
+        if self.align < align {
+            return Err(error(alloc::format!(
+                "type is {}-byte aligned, but its fields need {align}",
+                self.align
+            )));
+        }
+        let size = offset.next_multiple_of(self.align);
+        if self.size != size {
+            return Err(error(alloc::format!(
+                "type is {} bytes, but the C layout makes it {size}",
+                self.size
+            )));
+        }
+        Ok(())
+    }
+
+    /// Writes the layout as C preprocessor constants:
+    ///
+    /// ```c
+    /// #define POINT_SIZE 8
+    /// #define POINT_ALIGN 4
+    /// #define POINT_X_OFFSET 0
+    /// #define POINT_X_SIZE 4
+    /// ```
+    ///
+    /// Names are the type identifier and field names in upper case.
+    pub fn write_c_defines(&self, out: &mut String) {
+        let prefix = c_macro_name(self.shape.type_identifier);
+        let _ = writeln!(out, "#define {prefix}_SIZE {}", self.size);
+        let _ = writeln!(out, "#define {prefix}_ALIGN {}", self.align);
+        for field in &self.fields {
+            let name = c_macro_name(field.name);
+            let _ = writeln!(out, "#define {prefix}_{name}_OFFSET {}", field.offset);
+            let _ = writeln!(out, "#define {prefix}_{name}_SIZE {}", field.size);
+        }
+    }
+}
+
+/// Upper-cases `name`, replacing anything that can't appear in a C identifier.
+fn c_macro_name(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
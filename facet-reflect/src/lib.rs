@@ -16,8 +16,9 @@
 //!
 //!   * Read-only access to already-initialized values (via [`Peek`])
 //!   * Construction of values from scratch (via [`Partial`])
+//!   * In-place editing of existing values (via [`Poke`])
 //!
-//! This allows, respectively, serialization and deserialization, without risking breaking
+//! The first two allow, respectively, serialization and deserialization, without risking breaking
 //! invariants in types that implement `Facet`.
 //!
 #![doc = include_str!("../readme-footer.md")]
@@ -35,6 +36,31 @@ mod partial;
 #[cfg(feature = "alloc")]
 pub use partial::*;
 
+#[cfg(feature = "alloc")]
+mod deep;
+#[cfg(feature = "alloc")]
+pub use deep::*;
+
+#[cfg(feature = "alloc")]
+mod changes;
+#[cfg(feature = "alloc")]
+pub use changes::*;
+
+#[cfg(feature = "alloc")]
+mod coerce;
+#[cfg(feature = "alloc")]
+pub use coerce::*;
+
+#[cfg(feature = "alloc")]
+mod convert;
+#[cfg(feature = "alloc")]
+pub use convert::*;
+
+#[cfg(feature = "alloc")]
+mod layout;
+#[cfg(feature = "alloc")]
+pub use layout::*;
+
 #[cfg(feature = "alloc")]
 mod resolution;
 #[cfg(feature = "alloc")]
@@ -101,6 +101,35 @@
 //! # }
 //! ```
 //!
+//! # Suspending and Resuming
+//!
+//! A `Partial` can be stored between calls, for instance in a streaming deserializer's
+//! parser state, and pick up where it left off when the next chunk of input arrives.
+//! Whether it can outlive the input depends on `BORROW`: a `Partial<'facet, false>`
+//! (from [`Partial::alloc_owned`]) only holds owned data, so each chunk can be dropped
+//! once it has been read, while a `Partial<'input, true>` may hold borrows from the
+//! input (a `&'input str` field, say), so every chunk it borrows from has to live for
+//! `'input`, until the value is built.
+//!
+//! [`Partial::path`] tells where construction currently stands, and
+//! [`Partial::missing_fields`] lists what the current struct still needs, without
+//! consuming the partial the way a failed [`Partial::end`] would.
+//!
+//! ```no_run
+//! # use facet::Facet;
+//! # use facet_reflect::{Partial, ReflectError};
+//! # #[derive(Facet)] struct Point { x: i32, y: i32 }
+//! # fn example() -> Result<(), ReflectError> {
+//! // First chunk: only `x` is available.
+//! let partial = Partial::alloc::<Point>()?.set_field("x", 1i32)?;
+//! assert_eq!(partial.missing_fields()?, ["y"]);
+//!
+//! // ...later, once more input has arrived:
+//! let point = partial.set_field("y", 2i32)?.build()?.materialize::<Point>()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! # Safety and Memory Management
 //!
 //! The `Partial` type ensures memory safety by:
@@ -155,11 +184,12 @@ mod iset;
 mod rope;
 pub(crate) mod typeplan;
 pub use typeplan::{
-    DeserStrategy, EnumPlan, FieldDefault, FieldPlan, FillRule, NodeId, StructPlan, TypePlan,
-    TypePlanCore, TypePlanNode, TypePlanNodeKind, VariantPlanMeta,
+    DeserStrategy, EnumPlan, FieldDefault, FieldPlan, FillRule, NodeId, NodeKey, StructPlan,
+    TypePlan, TypePlanCore, TypePlanNode, TypePlanNodeKind, VariantPlanMeta,
 };
 
 mod partial_api;
+pub(crate) use partial_api::validate_invariants;
 
 use crate::{ReflectErrorKind, TrackerKind, trace};
 use facet_core::Facet;
@@ -19,6 +19,7 @@ use facet_core::{
 
 pub(crate) mod alloc;
 mod build;
+pub(crate) use build::validate_invariants;
 mod eenum;
 mod fields;
 mod internal;
@@ -73,6 +73,11 @@ fn shape_subtree_has_invariants(
     has_invariants
 }
 
+/// Check the invariants of `value` and everything in it.
+pub(crate) fn validate_invariants(value: Peek<'_, '_>) -> Result<(), (&'static Shape, String)> {
+    validate_invariants_recursive(value, &mut HashSet::new(), &mut HashMap::new())
+}
+
 fn validate_invariants_recursive<'mem, 'facet>(
     value: Peek<'mem, 'facet>,
     visited: &mut HashSet<crate::ValueId>,
@@ -107,6 +107,25 @@ impl<const BORROW: bool> Partial<'_, BORROW> {
         Ok(false)
     }
 
+    /// Names of the fields of the current struct or enum variant that haven't been set yet.
+    ///
+    /// Unlike [`Partial::end`], this doesn't consume the partial, so a streaming
+    /// deserializer that runs out of input mid-struct can see what is still missing,
+    /// keep the partial around, and resume once more input arrives. Fields with a
+    /// default are listed too: defaults are only filled in when the frame ends.
+    pub fn missing_fields(&self) -> Result<Vec<&'static str>, ReflectError> {
+        if self.frames().is_empty() {
+            return Err(self.err(ReflectErrorKind::NoActiveFrame));
+        }
+        let mut missing = Vec::new();
+        for (index, field) in self.get_fields()?.iter().enumerate() {
+            if !self.is_field_set(index)? {
+                missing.push(field.name);
+            }
+        }
+        Ok(missing)
+    }
+
     /// Selects a field (by name) of a struct or enum data.
     ///
     /// For enums, the variant needs to be selected first, see [Self::select_nth_variant]
@@ -367,8 +367,8 @@ impl<const BORROW: bool> Partial<'_, BORROW> {
             return Ok((self, false));
         };
 
-        // Use effective_proxy for format-aware resolution of the actual ProxyDef
-        let Some(proxy_def) = target_shape.effective_proxy(format_namespace) else {
+        // Use deserialize_proxy for format-aware resolution of the actual ProxyDef
+        let Some(proxy_def) = target_shape.deserialize_proxy(format_namespace) else {
             return Ok((self, false));
         };
 
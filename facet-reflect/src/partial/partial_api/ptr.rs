@@ -1,5 +1,6 @@
 use super::*;
 use crate::AllocatedShape;
+use facet_path::PathStep;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 // Smart pointers
@@ -35,6 +36,46 @@ impl<const BORROW: bool> Partial<'_, BORROW> {
             }
         };
 
+        // In deferred mode, check if we have a stored frame for the pointee (re-entry case,
+        // e.g. a flattened `Box<T>` whose fields are interleaved with the parent's).
+        // The path for the pointee includes Deref to distinguish it from the pointer itself.
+        if self.is_deferred() {
+            let mut check_path = self.derive_path();
+            let frame = self.frames().last().unwrap();
+            if !matches!(
+                frame.tracker,
+                Tracker::SmartPointer {
+                    building_inner: true,
+                    ..
+                }
+            ) {
+                check_path.push(PathStep::Deref);
+            }
+
+            if let FrameMode::Deferred {
+                stack,
+                stored_frames,
+                ..
+            } = &mut self.mode
+                && let Some(mut stored_frame) = stored_frames.remove(&check_path)
+            {
+                crate::trace!("begin_smart_ptr: Restoring stored frame for path {check_path:?}");
+
+                let frame = stack.last_mut().unwrap();
+                frame.tracker = Tracker::SmartPointer {
+                    building_inner: true,
+                    pending_inner: None,
+                };
+
+                // Clear the restored frame's current_child - we haven't entered any of its
+                // children yet in this new traversal.
+                stored_frame.tracker.clear_current_child();
+
+                stack.push(stored_frame);
+                return Ok(self);
+            }
+        }
+
         // Handle re-initialization if the smart pointer is already initialized
         self.prepare_for_reinitialization();
 
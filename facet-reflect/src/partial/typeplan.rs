@@ -12,8 +12,9 @@ use alloc::sync::Arc;
 use alloc::vec::Vec;
 
 use facet_core::{
-    Characteristic, ConstTypeId, Def, DefaultInPlaceFn, DefaultSource, EnumType, Field, ProxyDef,
-    ScalarType, SequenceType, Shape, StructType, Type, UserType, ValidatorFn, Variant,
+    Characteristic, ChildDefaults, ConstTypeId, Def, DefaultInPlaceFn, DefaultSource, EnumType,
+    Field, ProxyDef, ScalarType, SequenceType, Shape, StructType, Type, UserType, ValidatorFn,
+    Variant,
 };
 use hashbrown::HashMap;
 use smallvec::SmallVec;
@@ -81,8 +82,8 @@ pub struct TypePlanCore {
     field_entries: Arena<FieldLookupEntry>,
     /// Arena of bucket tuples for prefix-based lookup
     buckets: Arena<(u64, u32, u32)>,
-    /// Sorted lookup table for resolving BackRef nodes by TypeId.
-    node_lookup: Vec<(ConstTypeId, NodeId)>,
+    /// Sorted lookup table for resolving BackRef nodes by key.
+    node_lookup: Vec<(NodeKey, NodeId)>,
     /// Root node index
     root: NodeId,
 }
@@ -103,6 +104,9 @@ pub struct TypePlanNode {
     /// Contains nodes for all proxies (format-agnostic and format-specific),
     /// allowing runtime lookup based on the deserializer's format namespace.
     pub proxies: ProxyNodes,
+    /// `#[facet(defaults_for_children)]` handed down to this node, which
+    /// [`FieldPlan::effective_name`] already accounts for
+    pub inherited: Option<ChildDefaults>,
 }
 
 /// Precomputed proxy node info - stores TypePlan nodes for all proxies on a type/field.
@@ -191,7 +195,7 @@ pub enum DeserStrategy {
     /// Container-level proxy: the type itself has `#[facet(proxy = X)]` or format-specific proxies.
     ///
     /// The actual proxy definition and node are looked up at runtime via:
-    /// - `shape.effective_proxy(format_namespace)` for the ProxyDef
+    /// - `shape.deserialize_proxy(format_namespace)` for the ProxyDef
     /// - `node.proxies.node_for(format_namespace)` for the TypePlan node
     ContainerProxy,
     /// Field-level proxy: the field has `#[facet(proxy = X)]` but the type doesn't.
@@ -278,8 +282,8 @@ pub enum DeserStrategy {
     MetadataContainer,
     /// BackRef to recursive type - resolved via TypePlan::resolve_backref()
     BackRef {
-        /// The TypeId of the target node
-        target_type_id: ConstTypeId,
+        /// The key of the target node
+        target: NodeKey,
     },
 }
 
@@ -337,7 +341,18 @@ pub enum TypePlanNodeKind {
 
     /// Back-reference to an ancestor node (for recursive types)
     /// Resolved via TypePlan::resolve_backref()
-    BackRef(ConstTypeId),
+    BackRef(NodeKey),
+}
+
+/// What a reusable plan node is built for: a type, under the
+/// `#[facet(defaults_for_children)]` handed down to it. The same type can be
+/// reached under different defaults, and then its fields have different names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeKey {
+    /// The type
+    pub type_id: ConstTypeId,
+    /// Defaults inherited from the containers above
+    pub inherited: Option<ChildDefaults>,
 }
 
 /// Precomputed plan for struct deserialization.
@@ -977,10 +992,12 @@ struct TypePlanBuilder {
     buckets: Arena<(u64, u32, u32)>,
     /// Types we're currently building (for cycle detection).
     /// Added when we START building a node.
-    building: hashbrown::HashSet<ConstTypeId>,
-    /// Finished nodes, keyed by TypeId.
+    building: hashbrown::HashSet<NodeKey>,
+    /// Finished nodes, keyed by type and inherited defaults.
     /// Added when we FINISH building a node.
-    finished: HashMap<ConstTypeId, NodeId>,
+    finished: HashMap<NodeKey, NodeId>,
+    /// Defaults handed down to the node being built
+    inherited: Option<ChildDefaults>,
 }
 
 impl TypePlanBuilder {
@@ -994,13 +1011,14 @@ impl TypePlanBuilder {
             buckets: Arena::new(),
             building: hashbrown::HashSet::new(),
             finished: HashMap::new(),
+            inherited: None,
         }
     }
 
     /// Finalize the builder into a TypePlanCore.
     fn finish(self, root: NodeId) -> TypePlanCore {
         let mut node_lookup: Vec<_> = self.finished.into_iter().collect();
-        node_lookup.sort_by_key(|(id, _)| *id);
+        node_lookup.sort_by_key(|(key, _)| *key);
 
         TypePlanCore {
             nodes: self.nodes,
@@ -1029,25 +1047,27 @@ impl TypePlanBuilder {
         shape: &'static Shape,
         field_proxies: Option<FieldProxies>,
     ) -> Result<NodeId, AllocError> {
-        let type_id = shape.id;
+        let key = NodeKey {
+            type_id: shape.id,
+            inherited: self.inherited,
+        };
         let is_plain_node = field_proxies.is_none();
 
         // Reuse an already-built node when no field-level proxy makes this call
         // site distinct. Without this, types reachable via N distinct
         // non-cyclic paths are rebuilt N times which explodes combinatorially.
-        if is_plain_node && let Some(&idx) = self.finished.get(&type_id) {
+        if is_plain_node && let Some(&idx) = self.finished.get(&key) {
             return Ok(idx);
         }
 
         // Check if we're currently building this type (cycle detected)
-        if is_plain_node && self.building.contains(&type_id) {
-            // Create a BackRef node with just the TypeId - resolved later via lookup
+        if is_plain_node && self.building.contains(&key) {
+            // Create a BackRef node with just the key - resolved later via lookup
             let backref_node = TypePlanNode {
                 shape,
-                kind: TypePlanNodeKind::BackRef(type_id),
-                strategy: DeserStrategy::BackRef {
-                    target_type_id: type_id,
-                },
+                kind: TypePlanNodeKind::BackRef(key),
+                strategy: DeserStrategy::BackRef { target: key },
+                inherited: key.inherited,
                 has_default: shape.is(Characteristic::Default),
                 proxies: ProxyNodes::default(), // BackRefs resolve to target, proxies come from there
             };
@@ -1057,14 +1077,18 @@ impl TypePlanBuilder {
 
         // Mark this type as being built (for cycle detection)
         if is_plain_node {
-            self.building.insert(type_id);
+            self.building.insert(key);
         }
 
         // Build proxy nodes for ALL proxies (generic + all format-specific)
         let (proxies, has_container_proxy, has_field_proxy) =
             self.build_all_proxy_nodes(shape, field_proxies.as_ref())?;
 
-        let (kind, children) = self.build_kind(shape)?;
+        // What this node holds inherits its defaults too
+        self.inherited = shape.defaults_for_children(key.inherited);
+        let built = self.build_kind(shape, key.inherited);
+        self.inherited = key.inherited;
+        let (kind, children) = built?;
 
         let strategy = self.compute_strategy(
             shape,
@@ -1081,13 +1105,14 @@ impl TypePlanBuilder {
             strategy,
             has_default: shape.is(Characteristic::Default),
             proxies,
+            inherited: key.inherited,
         };
         let idx = self.nodes.alloc(node);
 
         // Done building - move from building set to finished map
         if is_plain_node {
-            self.building.remove(&type_id);
-            self.finished.insert(type_id, idx);
+            self.building.remove(&key);
+            self.finished.insert(key, idx);
         }
 
         Ok(idx)
@@ -1118,8 +1143,8 @@ impl TypePlanBuilder {
         }
 
         // Container-level proxies (from the shape itself)
-        // Build generic proxy node
-        if let Some(generic_proxy) = shape.proxy {
+        // Build generic proxy node; `#[facet(from = X)]` deserializes the same way
+        if let Some(generic_proxy) = shape.proxy.or(shape.from_conversion) {
             proxies.generic = Some(self.build_node(generic_proxy.shape)?);
         }
 
@@ -1267,16 +1292,16 @@ impl TypePlanBuilder {
                     operation: "transparent wrapper requires try_from for deserialization",
                 });
             }
-            TypePlanNodeKind::BackRef(type_id) => DeserStrategy::BackRef {
-                target_type_id: *type_id,
-            },
+            TypePlanNodeKind::BackRef(key) => DeserStrategy::BackRef { target: *key },
         })
     }
 
     /// Build the TypePlanNodeKind for a shape and return child node indices for compute_strategy.
+    /// `inherited` names the fields of `shape` itself.
     fn build_kind(
         &mut self,
         shape: &'static Shape,
+        inherited: Option<ChildDefaults>,
     ) -> Result<(TypePlanNodeKind, Vec<NodeId>), AllocError> {
         let mut children = Vec::new();
 
@@ -1340,7 +1365,7 @@ impl TypePlanBuilder {
                 // Check Type for struct/enum/slice - these have Def::Undefined but meaningful ty
                 match &shape.ty {
                     Type::User(UserType::Struct(struct_type)) => {
-                        let struct_plan = self.build_struct_plan(shape, struct_type)?;
+                        let struct_plan = self.build_struct_plan(shape, struct_type, inherited)?;
                         // Struct fields store their NodeIds in FieldPlan, no children needed
                         return Ok((TypePlanNodeKind::Struct(struct_plan), Vec::new()));
                     }
@@ -1388,6 +1413,7 @@ impl TypePlanBuilder {
         &mut self,
         shape: &'static Shape,
         struct_def: &'static StructType,
+        inherited: Option<ChildDefaults>,
     ) -> Result<StructPlan, AllocError> {
         let mut field_plans = Vec::with_capacity(struct_def.fields.len());
 
@@ -1404,8 +1430,15 @@ impl TypePlanBuilder {
             let fill_rule = Self::determine_fill_rule(field, container_has_default);
 
             // Create unified field plan
-            field_plans
-                .push(self.create_field_plan(index, field, child_node, fill_rule, validators));
+            let effective_name = field.effective_name_in(shape, inherited);
+            field_plans.push(self.create_field_plan(
+                index,
+                field,
+                effective_name,
+                child_node,
+                fill_rule,
+                validators,
+            ));
         }
 
         // Allocate fields into arena
@@ -1432,12 +1465,12 @@ impl TypePlanBuilder {
         &mut self,
         index: usize,
         field: &'static Field,
+        effective_name: &'static str,
         type_node: NodeId,
         fill_rule: FillRule,
         validators: ValidatorRange,
     ) -> FieldPlan {
         let name = field.name;
-        let effective_name = field.effective_name();
         let alias = field.alias;
         let is_flattened = field.is_flattened();
 
@@ -1682,9 +1715,16 @@ impl TypePlanBuilder {
                 let validators = self.extract_validators(field);
                 let fill_rule = Self::determine_fill_rule(field, false);
 
-                // Create unified field plan
-                field_plans
-                    .push(self.create_field_plan(index, field, child_node, fill_rule, validators));
+                // Create unified field plan (variant fields aren't renamed by inherited
+                // defaults, which only reach structs)
+                field_plans.push(self.create_field_plan(
+                    index,
+                    field,
+                    field.effective_name(),
+                    child_node,
+                    fill_rule,
+                    validators,
+                ));
             }
 
             let has_flatten = field_plans.iter().any(|f| f.is_flattened);
@@ -1889,12 +1929,12 @@ impl TypePlanCore {
         self.validators.get_slice(range)
     }
 
-    /// Look up a node by TypeId using binary search on the sorted lookup table.
+    /// Look up a node by key using binary search on the sorted lookup table.
     #[inline]
-    fn lookup_node(&self, type_id: &ConstTypeId) -> Option<NodeId> {
+    fn lookup_node(&self, key: &NodeKey) -> Option<NodeId> {
         let idx = self
             .node_lookup
-            .binary_search_by_key(type_id, |(id, _)| *id)
+            .binary_search_by_key(key, |(key, _)| *key)
             .ok()?;
         Some(self.node_lookup[idx].1)
     }
@@ -1941,8 +1981,8 @@ impl TypePlanCore {
             DeserStrategy::List { item_node, .. } | DeserStrategy::Array { item_node, .. } => {
                 Some(self.node(*item_node))
             }
-            DeserStrategy::BackRef { target_type_id } => {
-                let target = self.lookup_node(target_type_id)?;
+            DeserStrategy::BackRef { target } => {
+                let target = self.lookup_node(target)?;
                 self.list_item_node(self.node(target))
             }
             _ => None,
@@ -1954,8 +1994,8 @@ impl TypePlanCore {
     pub fn set_item_node(&self, parent: &TypePlanNode) -> Option<&TypePlanNode> {
         match &parent.strategy {
             DeserStrategy::Set { item_node } => Some(self.node(*item_node)),
-            DeserStrategy::BackRef { target_type_id } => {
-                let target = self.lookup_node(target_type_id)?;
+            DeserStrategy::BackRef { target } => {
+                let target = self.lookup_node(target)?;
                 self.set_item_node(self.node(target))
             }
             _ => None,
@@ -1967,8 +2007,8 @@ impl TypePlanCore {
     pub fn map_key_node(&self, parent: &TypePlanNode) -> Option<&TypePlanNode> {
         match &parent.strategy {
             DeserStrategy::Map { key_node, .. } => Some(self.node(*key_node)),
-            DeserStrategy::BackRef { target_type_id } => {
-                let target = self.lookup_node(target_type_id)?;
+            DeserStrategy::BackRef { target } => {
+                let target = self.lookup_node(target)?;
                 self.map_key_node(self.node(target))
             }
             _ => None,
@@ -1980,8 +2020,8 @@ impl TypePlanCore {
     pub fn map_value_node(&self, parent: &TypePlanNode) -> Option<&TypePlanNode> {
         match &parent.strategy {
             DeserStrategy::Map { value_node, .. } => Some(self.node(*value_node)),
-            DeserStrategy::BackRef { target_type_id } => {
-                let target = self.lookup_node(target_type_id)?;
+            DeserStrategy::BackRef { target } => {
+                let target = self.lookup_node(target)?;
                 self.map_value_node(self.node(target))
             }
             _ => None,
@@ -1993,8 +2033,8 @@ impl TypePlanCore {
     pub fn option_inner_node(&self, parent: &TypePlanNode) -> Option<&TypePlanNode> {
         match &parent.strategy {
             DeserStrategy::Option { some_node } => Some(self.node(*some_node)),
-            DeserStrategy::BackRef { target_type_id } => {
-                let target = self.lookup_node(target_type_id)?;
+            DeserStrategy::BackRef { target } => {
+                let target = self.lookup_node(target)?;
                 self.option_inner_node(self.node(target))
             }
             _ => None,
@@ -2006,8 +2046,8 @@ impl TypePlanCore {
     pub fn result_ok_node(&self, parent: &TypePlanNode) -> Option<&TypePlanNode> {
         match &parent.strategy {
             DeserStrategy::Result { ok_node, .. } => Some(self.node(*ok_node)),
-            DeserStrategy::BackRef { target_type_id } => {
-                let target = self.lookup_node(target_type_id)?;
+            DeserStrategy::BackRef { target } => {
+                let target = self.lookup_node(target)?;
                 self.result_ok_node(self.node(target))
             }
             _ => None,
@@ -2019,8 +2059,8 @@ impl TypePlanCore {
     pub fn result_err_node(&self, parent: &TypePlanNode) -> Option<&TypePlanNode> {
         match &parent.strategy {
             DeserStrategy::Result { err_node, .. } => Some(self.node(*err_node)),
-            DeserStrategy::BackRef { target_type_id } => {
-                let target = self.lookup_node(target_type_id)?;
+            DeserStrategy::BackRef { target } => {
+                let target = self.lookup_node(target)?;
                 self.result_err_node(self.node(target))
             }
             _ => None,
@@ -2032,8 +2072,8 @@ impl TypePlanCore {
     pub fn pointer_pointee_node(&self, parent: &TypePlanNode) -> Option<&TypePlanNode> {
         match &parent.strategy {
             DeserStrategy::Pointer { pointee_node } => Some(self.node(*pointee_node)),
-            DeserStrategy::BackRef { target_type_id } => {
-                let target = self.lookup_node(target_type_id)?;
+            DeserStrategy::BackRef { target } => {
+                let target = self.lookup_node(target)?;
                 self.pointer_pointee_node(self.node(target))
             }
             _ => None,
@@ -2057,8 +2097,8 @@ impl TypePlanCore {
     #[inline]
     pub fn resolve_backref<'a>(&'a self, node: &'a TypePlanNode) -> &'a TypePlanNode {
         match &node.kind {
-            TypePlanNodeKind::BackRef(type_id) => self.node(
-                self.lookup_node(type_id)
+            TypePlanNodeKind::BackRef(key) => self.node(
+                self.lookup_node(key)
                     .expect("BackRef target must exist in node_lookup"),
             ),
             _ => node,
@@ -2153,8 +2193,8 @@ impl TypePlanCore {
             DeserStrategy::List { item_node, .. } | DeserStrategy::Array { item_node, .. } => {
                 Some(*item_node)
             }
-            DeserStrategy::BackRef { target_type_id } => {
-                let target = self.lookup_node(target_type_id)?;
+            DeserStrategy::BackRef { target } => {
+                let target = self.lookup_node(target)?;
                 self.list_item_node_id(target)
             }
             _ => None,
@@ -2167,8 +2207,8 @@ impl TypePlanCore {
         let parent = self.node(parent_id);
         match &parent.strategy {
             DeserStrategy::Set { item_node } => Some(*item_node),
-            DeserStrategy::BackRef { target_type_id } => {
-                let target = self.lookup_node(target_type_id)?;
+            DeserStrategy::BackRef { target } => {
+                let target = self.lookup_node(target)?;
                 self.set_item_node_id(target)
             }
             _ => None,
@@ -2181,8 +2221,8 @@ impl TypePlanCore {
         let parent = self.node(parent_id);
         match &parent.strategy {
             DeserStrategy::Map { key_node, .. } => Some(*key_node),
-            DeserStrategy::BackRef { target_type_id } => {
-                let target = self.lookup_node(target_type_id)?;
+            DeserStrategy::BackRef { target } => {
+                let target = self.lookup_node(target)?;
                 self.map_key_node_id(target)
             }
             _ => None,
@@ -2195,8 +2235,8 @@ impl TypePlanCore {
         let parent = self.node(parent_id);
         match &parent.strategy {
             DeserStrategy::Map { value_node, .. } => Some(*value_node),
-            DeserStrategy::BackRef { target_type_id } => {
-                let target = self.lookup_node(target_type_id)?;
+            DeserStrategy::BackRef { target } => {
+                let target = self.lookup_node(target)?;
                 self.map_value_node_id(target)
             }
             _ => None,
@@ -2209,8 +2249,8 @@ impl TypePlanCore {
         let parent = self.node(parent_id);
         match &parent.strategy {
             DeserStrategy::Option { some_node, .. } => Some(*some_node),
-            DeserStrategy::BackRef { target_type_id } => {
-                let target = self.lookup_node(target_type_id)?;
+            DeserStrategy::BackRef { target } => {
+                let target = self.lookup_node(target)?;
                 self.option_some_node_id(target)
             }
             _ => None,
@@ -2225,8 +2265,8 @@ impl TypePlanCore {
             DeserStrategy::Result {
                 ok_node, err_node, ..
             } => Some((*ok_node, *err_node)),
-            DeserStrategy::BackRef { target_type_id } => {
-                let target = self.lookup_node(target_type_id)?;
+            DeserStrategy::BackRef { target } => {
+                let target = self.lookup_node(target)?;
                 self.result_nodes_id(target)
             }
             _ => None,
@@ -2239,8 +2279,8 @@ impl TypePlanCore {
         let parent = self.node(parent_id);
         match &parent.strategy {
             DeserStrategy::Pointer { pointee_node, .. } => Some(*pointee_node),
-            DeserStrategy::BackRef { target_type_id } => {
-                let target = self.lookup_node(target_type_id)?;
+            DeserStrategy::BackRef { target } => {
+                let target = self.lookup_node(target)?;
                 self.pointer_inner_node_id(target)
             }
             _ => None,
@@ -2469,9 +2509,9 @@ mod tests {
 
                 // This should be a BackRef pointing to the root
                 match &pointee_node.kind {
-                    TypePlanNodeKind::BackRef(type_id) => {
-                        // type_id should match the root's type
-                        assert_eq!(type_id, &plan.root().shape.id);
+                    TypePlanNodeKind::BackRef(key) => {
+                        // key should match the root's type
+                        assert_eq!(key.type_id, plan.root().shape.id);
                     }
                     _ => panic!(
                         "Expected BackRef for recursive type, got {:?}",
@@ -0,0 +1,151 @@
+//! Writing values as text: one canonical spelling for scalars, shared by the
+//! pretty printer, diff renderers and DOM serializers.
+
+use core::fmt;
+
+use facet_core::{DynValueKind, ScalarType};
+
+use super::Peek;
+
+/// How [`Peek::as_display_with`] writes values.
+///
+/// The defaults give the plain spelling: strings and chars as they are,
+/// floats in the shortest form that reads back to the same value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DisplayOptions {
+    quote_text: bool,
+    float_precision: Option<usize>,
+}
+
+impl DisplayOptions {
+    /// The default options.
+    pub const fn new() -> Self {
+        Self {
+            quote_text: false,
+            float_precision: None,
+        }
+    }
+
+    /// Wrap strings in `"` and chars in `'`, Rust style. The content itself
+    /// is written as is, without escaping.
+    pub const fn quote_text(mut self, quote_text: bool) -> Self {
+        self.quote_text = quote_text;
+        self
+    }
+
+    /// Write floats with this many digits after the decimal point, or in the
+    /// shortest round-tripping form for `None`.
+    pub const fn float_precision(mut self, precision: Option<usize>) -> Self {
+        self.float_precision = precision;
+        self
+    }
+}
+
+/// A value formatted as text through [`fmt::Display`]. Returned by
+/// [`Peek::as_display`].
+///
+/// Transparent wrappers and smart pointers are looked through, so a
+/// `NonZero<u64>` or `Arc<str>` reads the same as what it holds. Scalars are
+/// written by kind: strings and chars as their content, `f32` as an `f32`
+/// (never widened to `f64` first) and everything else through its `Display`
+/// implementation, falling back to `Debug`, then to the shape name in `⟨⟩`.
+#[derive(Clone, Copy)]
+pub struct PeekDisplay<'mem, 'facet> {
+    peek: Peek<'mem, 'facet>,
+    options: DisplayOptions,
+}
+
+impl<'mem, 'facet> Peek<'mem, 'facet> {
+    /// Format this value as text, the same way everywhere.
+    ///
+    /// ```
+    /// use facet_reflect::{DisplayOptions, Peek};
+    ///
+    /// assert_eq!(Peek::new(&0.1_f32).as_display().to_string(), "0.1");
+    /// assert_eq!(Peek::new(&"hi").as_display().to_string(), "hi");
+    ///
+    /// let quoted = DisplayOptions::new().quote_text(true);
+    /// assert_eq!(Peek::new(&'x').as_display_with(quoted).to_string(), "'x'");
+    /// ```
+    pub fn as_display(&self) -> PeekDisplay<'mem, 'facet> {
+        self.as_display_with(DisplayOptions::new())
+    }
+
+    /// Like [`Peek::as_display`], with explicit options.
+    pub fn as_display_with(&self, options: DisplayOptions) -> PeekDisplay<'mem, 'facet> {
+        PeekDisplay {
+            peek: *self,
+            options,
+        }
+    }
+}
+
+impl PeekDisplay<'_, '_> {
+    fn write_text(&self, f: &mut fmt::Formatter<'_>, text: &str, quote: char) -> fmt::Result {
+        if self.options.quote_text {
+            write!(f, "{quote}{text}{quote}")
+        } else {
+            f.write_str(text)
+        }
+    }
+
+    fn write_float(&self, f: &mut fmt::Formatter<'_>, value: impl fmt::Display) -> fmt::Result {
+        match self.options.float_precision {
+            Some(precision) => write!(f, "{value:.precision$}"),
+            None => write!(f, "{value}"),
+        }
+    }
+}
+
+impl fmt::Display for PeekDisplay<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let peek = self.peek.innermost_peek();
+
+        if let Some(text) = peek.as_str() {
+            return self.write_text(f, text, '"');
+        }
+        match peek.scalar_type() {
+            Some(ScalarType::Char) => {
+                if let Ok(c) = peek.get::<char>() {
+                    let mut buf = [0; 4];
+                    return self.write_text(f, c.encode_utf8(&mut buf), '\'');
+                }
+            }
+            Some(ScalarType::F32) => {
+                if let Ok(value) = peek.get::<f32>() {
+                    return self.write_float(f, value);
+                }
+            }
+            Some(ScalarType::F64) => {
+                if let Ok(value) = peek.get::<f64>() {
+                    return self.write_float(f, value);
+                }
+            }
+            _ => {}
+        }
+        if let Ok(dynamic) = peek.into_dynamic_value()
+            && dynamic.kind() == DynValueKind::String
+            && let Some(text) = dynamic.as_str()
+        {
+            return self.write_text(f, text, '"');
+        }
+
+        let shape = peek.shape();
+        if shape.is_display() {
+            write!(f, "{peek}")
+        } else if shape.is_debug() {
+            write!(f, "{peek:?}")
+        } else {
+            write!(f, "⟨{shape}⟩")
+        }
+    }
+}
+
+impl fmt::Debug for PeekDisplay<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PeekDisplay")
+            .field("shape", &self.peek.shape())
+            .field("options", &self.options)
+            .finish()
+    }
+}
@@ -116,6 +116,26 @@ impl<'mem, 'facet> PeekEnum<'mem, 'facet> {
         }
     }
 
+    /// Returns the discriminant of the active variant.
+    ///
+    /// Unlike [`discriminant()`](Self::discriminant), this works for every
+    /// representation: enums with a primitive `#[repr]` have it read from
+    /// memory, while [`EnumRepr::Rust`] and [`EnumRepr::RustNPO`] enums (such
+    /// as `Option<T>`), which have no discriminant with a defined layout,
+    /// report the active variant's declared [`Variant::discriminant`], or its
+    /// index when it declares none. Binary formats can write this value,
+    /// sized by [`EnumRepr::discriminant_size`], instead of a variant name.
+    #[inline]
+    pub fn active_discriminant(self) -> Result<i64, VariantError> {
+        match self.ty.enum_repr {
+            EnumRepr::Rust | EnumRepr::RustNPO => {
+                let index = self.variant_index()?;
+                Ok(self.ty.variants[index].discriminant.unwrap_or(index as i64))
+            }
+            _ => Ok(self.discriminant()),
+        }
+    }
+
     /// Returns the variant index for this enum value
     #[inline]
     pub fn variant_index(self) -> Result<usize, VariantError> {
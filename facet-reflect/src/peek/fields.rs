@@ -1,7 +1,7 @@
 use core::ops::Range;
 
 use alloc::borrow::Cow;
-use facet_core::{Field, Type, UserType, Variant};
+use facet_core::{ChildDefaults, Field, Type, UserType, Variant};
 
 use crate::Peek;
 use alloc::{string::String, vec, vec::Vec};
@@ -42,6 +42,38 @@ fn extract_string_from_peek<'mem, 'facet>(peek: Peek<'mem, 'facet>) -> Option<St
     None
 }
 
+/// The key prefix held by the `flatten_prefix_from` source field: a string,
+/// or anything that displays as one.
+///
+/// `#[derive(Facet)]` rejects a missing or non-`Display` source, so only a
+/// hand-written shape can hit the panics below.
+fn sibling_prefix(fields: &FieldIter<'_, '_>, source: &str) -> String {
+    let Some(peek) = fields.sibling(source) else {
+        panic!("flatten_prefix_from names a missing field `{source}`")
+    };
+    let peek = peek.innermost_peek();
+    if let Some(s) = extract_string_from_peek(peek) {
+        s
+    } else if peek.shape().vtable.has_display() {
+        use alloc::string::ToString;
+        peek.to_string()
+    } else {
+        panic!("flatten_prefix_from field `{source}` must hold a string")
+    }
+}
+
+/// Dereference smart pointers (`Box<T>`, `Arc<T>`, ...) around a flattened value,
+/// so that `#[facet(flatten)]` sees through them the way
+/// [`Shape::flatten_target`](facet_core::Shape::flatten_target) does.
+fn deref_flattened<'mem, 'facet>(mut peek: Peek<'mem, 'facet>) -> Peek<'mem, 'facet> {
+    while let Ok(pointer) = peek.into_pointer()
+        && let Some(target) = pointer.borrow_inner()
+    {
+        peek = target;
+    }
+    peek
+}
+
 /// A field item with runtime state for serialization.
 ///
 /// This wraps a static `Field` with additional runtime state that can be modified
@@ -65,6 +97,14 @@ pub struct FieldItem {
     /// Whether this is a text variant (html::text or xml::text) from a flattened enum.
     /// When true, the value should be serialized as raw text without an element wrapper.
     pub is_text_variant: bool,
+
+    /// Whether this is a CDATA variant (xml::cdata) from a flattened enum.
+    /// When true, the value should be serialized as a CDATA section.
+    pub is_cdata_variant: bool,
+
+    /// Whether this is a processing instruction variant (xml::processing_instruction)
+    /// from a flattened enum. When true, the value holds the instruction's target and data.
+    pub is_processing_instruction_variant: bool,
 }
 
 impl FieldItem {
@@ -80,6 +120,8 @@ impl FieldItem {
             field: Some(field),
             flattened: false,
             is_text_variant: false,
+            is_cdata_variant: false,
+            is_processing_instruction_variant: false,
         }
     }
 
@@ -95,6 +137,8 @@ impl FieldItem {
             field: Some(field),
             flattened: true,
             is_text_variant: variant.is_text(),
+            is_cdata_variant: variant.is_cdata(),
+            is_processing_instruction_variant: variant.is_processing_instruction(),
         }
     }
 
@@ -107,9 +151,35 @@ impl FieldItem {
             field: None,
             flattened: true,
             is_text_variant: false,
+            is_cdata_variant: false,
+            is_processing_instruction_variant: false,
         }
     }
 
+    /// Create an item for a field of a `#[facet(flatten_prefix_from = "...")]`
+    /// struct, keyed `"<prefix>.<name>"`
+    #[inline]
+    pub fn prefixed(field: Field, prefix: &str) -> Self {
+        Self::new(field).with_prefix(prefix)
+    }
+
+    /// A field item named by inherited [`ChildDefaults`], if they rename it.
+    fn inheriting(field: Field, renamed: Option<&'static str>) -> Self {
+        let mut item = Self::new(field);
+        if let Some(name) = renamed {
+            item.rename = Some(Cow::Borrowed(name));
+        }
+        item
+    }
+
+    fn with_prefix(mut self, prefix: &str) -> Self {
+        self.rename = Some(Cow::Owned(alloc::format!(
+            "{prefix}.{}",
+            self.effective_name()
+        )));
+        self
+    }
+
     /// Returns the effective name for this field item, preferring the rename over the original name
     #[inline]
     pub fn effective_name(&self) -> &str {
@@ -132,10 +202,13 @@ pub trait HasFields<'mem, 'facet> {
     ///
     /// This respects `#[facet(skip_serializing_if = ...)]` and `#[facet(skip_all_unless_truthy)]`
     /// predicates, which is correct for self-describing formats like JSON where skipped fields
-    /// can be reconstructed from the schema.
+    /// can be reconstructed from the schema. Fields come in `#[facet(order = N)]` order
+    /// (see [`StructType::fields_in_order`](facet_core::StructType::fields_in_order)).
     fn fields_for_serialize(&self) -> FieldsForSerializeIter<'mem, 'facet> {
         FieldsForSerializeIter {
-            stack: vec![FieldsForSerializeIterState::Fields(self.fields())],
+            stack: vec![FieldsForSerializeIterState::Fields(
+                self.fields().in_serialization_order(),
+            )],
             skip_predicates: true,
         }
     }
@@ -143,7 +216,8 @@ pub trait HasFields<'mem, 'facet> {
     /// Iterates over fields for serialization to positional binary formats.
     ///
     /// Unlike [`fields_for_serialize`](Self::fields_for_serialize), this ignores
-    /// `skip_serializing_if` predicates (including those from `skip_all_unless_truthy`).
+    /// `skip_serializing_if` predicates (including those from `skip_all_unless_truthy`)
+    /// and `#[facet(order = N)]`, yielding fields in declaration order.
     /// This is necessary for binary formats like postcard where fields are identified by
     /// position rather than name - skipping fields would cause a mismatch between
     /// serialized and expected field positions during deserialization.
@@ -162,6 +236,11 @@ pub trait HasFields<'mem, 'facet> {
 pub struct FieldIter<'mem, 'facet> {
     state: FieldIterState<'mem, 'facet>,
     range: Range<usize>,
+    /// Field index for each position in `range`, when iterating in
+    /// serialization order rather than declaration order
+    order: Option<Vec<usize>>,
+    /// Defaults handed down from the containers above this one
+    inherited: Option<ChildDefaults>,
 }
 
 enum FieldIterState<'mem, 'facet> {
@@ -179,6 +258,8 @@ impl<'mem, 'facet> FieldIter<'mem, 'facet> {
         Self {
             range: 0..struct_.ty.fields.len(),
             state: FieldIterState::Struct(struct_),
+            order: None,
+            inherited: None,
         }
     }
 
@@ -197,6 +278,8 @@ impl<'mem, 'facet> FieldIter<'mem, 'facet> {
                 peek_enum: enum_,
                 fields,
             },
+            order: None,
+            inherited: None,
         }
     }
 
@@ -205,10 +288,92 @@ impl<'mem, 'facet> FieldIter<'mem, 'facet> {
         Self {
             range: 0..tuple.len(),
             state: FieldIterState::Tuple(tuple),
+            order: None,
+            inherited: None,
+        }
+    }
+
+    /// Iterate in `#[facet(order = N)]` order instead of declaration order.
+    ///
+    /// The derive flags shapes with ordered fields, so other types skip this
+    /// in O(1); for the rest, the order is sorted once, here.
+    pub(crate) fn in_serialization_order(mut self) -> Self {
+        let fields = match self.state {
+            FieldIterState::Struct(peek_struct)
+                if peek_struct.value.shape().has_ordered_fields() =>
+            {
+                peek_struct.ty.fields
+            }
+            FieldIterState::Enum { peek_enum, .. }
+                if peek_enum.value.shape().has_ordered_fields() =>
+            {
+                match peek_enum.active_variant() {
+                    Ok(variant) => variant.data.fields,
+                    Err(_) => return self,
+                }
+            }
+            // Declaration order; tuples have no attributes to order by
+            _ => return self,
+        };
+        let mut order: Vec<usize> = (0..fields.len()).collect();
+        // Stable, so ties keep declaration order
+        order.sort_by_key(|&index| fields[index].order());
+        self.order = Some(order);
+        self
+    }
+
+    /// Name fields by the [`ChildDefaults`] handed down from above.
+    pub(crate) const fn inheriting(mut self, inherited: Option<ChildDefaults>) -> Self {
+        self.inherited = inherited;
+        self
+    }
+
+    /// The name `field` takes under the inherited defaults, if they rename it.
+    fn inherited_name(&self, field: &Field) -> Option<&'static str> {
+        match self.state {
+            FieldIterState::Struct(peek_struct) => {
+                self.inherited?.field_name(peek_struct.value.shape(), field)
+            }
+            FieldIterState::Tuple(_) | FieldIterState::Enum { .. } => None,
+        }
+    }
+
+    /// The defaults in force for values this one holds, including the
+    /// structs flattened into it.
+    fn child_defaults(&self) -> Option<ChildDefaults> {
+        match self.state {
+            FieldIterState::Struct(peek_struct) => peek_struct
+                .value
+                .shape()
+                .defaults_for_children(self.inherited),
+            FieldIterState::Enum { peek_enum, .. } => peek_enum
+                .value
+                .shape()
+                .defaults_for_children(self.inherited),
+            FieldIterState::Tuple(_) => self.inherited,
         }
     }
 
-    fn get_field_by_index(&self, index: usize) -> Option<(Field, Peek<'mem, 'facet>)> {
+    /// The value of the field with this (Rust) name, wherever the iteration is.
+    fn sibling(&self, name: &str) -> Option<Peek<'mem, 'facet>> {
+        let fields: &[Field] = match self.state {
+            FieldIterState::Struct(peek_struct) => peek_struct.ty.fields,
+            FieldIterState::Tuple(peek_tuple) => peek_tuple.ty.fields,
+            FieldIterState::Enum { fields, .. } => fields,
+        };
+        let index = fields.iter().position(|f| f.name == name)?;
+        match self.state {
+            FieldIterState::Struct(peek_struct) => peek_struct.field(index).ok(),
+            FieldIterState::Tuple(peek_tuple) => peek_tuple.field(index),
+            FieldIterState::Enum { peek_enum, .. } => peek_enum.field(index).ok().flatten(),
+        }
+    }
+
+    fn get_field_by_index(&self, position: usize) -> Option<(Field, Peek<'mem, 'facet>)> {
+        let index = match &self.order {
+            Some(order) => order[position],
+            None => position,
+        };
         match self.state {
             FieldIterState::Struct(peek_struct) => {
                 let field = peek_struct.ty.fields.get(index).copied()?;
@@ -304,9 +469,70 @@ enum FieldsForSerializeIterState<'mem, 'facet> {
     FlattenedOption {
         field: Field,
         inner: Peek<'mem, 'facet>,
+        defaults: Option<ChildDefaults>,
+    },
+    /// Fields of a `#[facet(flatten_prefix_from = "...")]` struct, keyed
+    /// `"<prefix>.<name>"`
+    Prefixed {
+        prefix: String,
+        fields: FieldIter<'mem, 'facet>,
     },
 }
 
+impl<'mem, 'facet> FieldsForSerializeIter<'mem, 'facet> {
+    /// Name fields by the [`ChildDefaults`] in force for this value, as
+    /// handed down by the containers it was reached from (see
+    /// [`Shape::defaults_for_children`](facet_core::Shape::defaults_for_children)).
+    pub fn with_child_defaults(mut self, inherited: Option<ChildDefaults>) -> Self {
+        for state in &mut self.stack {
+            if let FieldsForSerializeIterState::Fields(fields)
+            | FieldsForSerializeIterState::Prefixed { fields, .. } = state
+            {
+                fields.inherited = inherited;
+            }
+        }
+        self
+    }
+
+    fn should_skip(&self, field: &Field, peek: Peek<'mem, 'facet>) -> bool {
+        // For binary formats (skip_predicates = false), only check unconditional flags.
+        // For text formats (skip_predicates = true), also evaluate predicates.
+        if self.skip_predicates {
+            let data = peek.data();
+            unsafe { field.should_skip_serializing(data) }
+        } else {
+            field.should_skip_serializing_unconditional()
+        }
+    }
+
+    /// Queue the fields of a flattened struct (or `Some` struct) under a key prefix.
+    fn push_prefixed(
+        &mut self,
+        field: Field,
+        peek: Peek<'mem, 'facet>,
+        prefix: String,
+        defaults: Option<ChildDefaults>,
+    ) {
+        let mut peek = deref_flattened(peek);
+        if let Ok(opt_peek) = peek.into_option() {
+            match opt_peek.value() {
+                Some(inner) => peek = deref_flattened(inner),
+                None => return,
+            }
+        }
+        match peek.innermost_peek().into_struct() {
+            Ok(struct_peek) => self.stack.push(FieldsForSerializeIterState::Prefixed {
+                prefix,
+                fields: FieldIter::new_struct(struct_peek)
+                    .in_serialization_order()
+                    .inheriting(defaults),
+            }),
+            // `#[derive(Facet)]` only allows prefixing flattened structs
+            Err(_) => panic!("cannot flatten a {} under a key prefix", field.shape()),
+        }
+    }
+}
+
 impl<'mem, 'facet> Iterator for FieldsForSerializeIter<'mem, 'facet> {
     type Item = (FieldItem, Peek<'mem, 'facet>);
 
@@ -399,12 +625,19 @@ impl<'mem, 'facet> Iterator for FieldsForSerializeIter<'mem, 'facet> {
                     // List exhausted, continue to next state
                     continue;
                 }
-                FieldsForSerializeIterState::FlattenedOption { field, inner } => {
+                FieldsForSerializeIterState::FlattenedOption {
+                    field,
+                    inner,
+                    defaults,
+                } => {
                     // Process the inner value of Some(inner) as if it were a flattened field
                     // Try to flatten it further (struct, enum, map, etc.)
+                    let inner = deref_flattened(inner);
                     if let Ok(struct_peek) = inner.into_struct() {
                         self.stack.push(FieldsForSerializeIterState::Fields(
-                            FieldIter::new_struct(struct_peek),
+                            FieldIter::new_struct(struct_peek)
+                                .in_serialization_order()
+                                .inheriting(defaults),
                         ));
                         continue;
                     } else if let Ok(enum_peek) = inner.into_enum() {
@@ -449,27 +682,52 @@ impl<'mem, 'facet> Iterator for FieldsForSerializeIter<'mem, 'facet> {
                     // Can't flatten this type - skip it
                     continue;
                 }
+                FieldsForSerializeIterState::Prefixed { prefix, mut fields } => {
+                    let Some((field, peek)) = fields.next() else {
+                        continue;
+                    };
+                    let renamed = fields.inherited_name(&field);
+                    let defaults = fields.child_defaults();
+                    self.stack.push(FieldsForSerializeIterState::Prefixed {
+                        prefix: prefix.clone(),
+                        fields,
+                    });
+
+                    if self.should_skip(&field, peek) {
+                        continue;
+                    }
+                    if field.is_flattened() {
+                        // Nested flattened structs share the prefix
+                        self.push_prefixed(field, peek, prefix, defaults);
+                        continue;
+                    }
+                    let item = FieldItem::inheriting(field, renamed).with_prefix(&prefix);
+                    return Some((item, peek));
+                }
                 FieldsForSerializeIterState::Fields(mut fields) => {
                     let Some((field, peek)) = fields.next() else {
                         continue;
                     };
+                    // The prefix comes from a sibling, so read it while we have them
+                    let prefix = match field.flatten_prefix_from() {
+                        Some(source) if field.is_flattened() => {
+                            Some(sibling_prefix(&fields, source))
+                        }
+                        _ => None,
+                    };
+                    let renamed = fields.inherited_name(&field);
+                    let defaults = fields.child_defaults();
                     self.stack.push(FieldsForSerializeIterState::Fields(fields));
 
                     // Check if we should skip this field.
-                    // For binary formats (skip_predicates = false), only check unconditional flags.
-                    // For text formats (skip_predicates = true), also evaluate predicates.
-                    let should_skip = if self.skip_predicates {
-                        let data = peek.data();
-                        unsafe { field.should_skip_serializing(data) }
-                    } else {
-                        field.should_skip_serializing_unconditional()
-                    };
-
-                    if should_skip {
+                    if self.should_skip(&field, peek) {
                         continue;
                     }
 
-                    if field.is_flattened() {
+                    if let Some(prefix) = prefix {
+                        self.push_prefixed(field, peek, prefix, defaults);
+                    } else if field.is_flattened() {
+                        let peek = deref_flattened(peek);
                         // Check for Option<T> first - Option now has UserType::Enum but should
                         // be flattened by unwrapping Some(inner) and flattening inner, or skipping None
                         if let Ok(opt_peek) = peek.into_option() {
@@ -480,13 +738,16 @@ impl<'mem, 'facet> Iterator for FieldsForSerializeIter<'mem, 'facet> {
                                     .push(FieldsForSerializeIterState::FlattenedOption {
                                         field,
                                         inner,
+                                        defaults,
                                     });
                             }
                             // None - skip this field entirely
                             continue;
                         } else if let Ok(struct_peek) = peek.innermost_peek().into_struct() {
                             self.stack.push(FieldsForSerializeIterState::Fields(
-                                FieldIter::new_struct(struct_peek),
+                                FieldIter::new_struct(struct_peek)
+                                    .in_serialization_order()
+                                    .inheriting(defaults),
                             ))
                         } else if let Ok(enum_peek) = peek.into_enum() {
                             // normally we'd serialize to something like:
@@ -555,7 +816,9 @@ impl<'mem, 'facet> Iterator for FieldsForSerializeIter<'mem, 'facet> {
                             if let Some(inner_peek) = option_peek.value() {
                                 if let Ok(struct_peek) = inner_peek.into_struct() {
                                     self.stack.push(FieldsForSerializeIterState::Fields(
-                                        FieldIter::new_struct(struct_peek),
+                                        FieldIter::new_struct(struct_peek)
+                                            .in_serialization_order()
+                                            .inheriting(defaults),
                                     ))
                                 } else if let Ok(enum_peek) = inner_peek.into_enum() {
                                     let variant = enum_peek
@@ -621,7 +884,7 @@ impl<'mem, 'facet> Iterator for FieldsForSerializeIter<'mem, 'facet> {
                             panic!("cannot flatten a {}", field.shape())
                         }
                     } else {
-                        return Some((FieldItem::new(field), peek));
+                        return Some((FieldItem::inheriting(field, renamed), peek));
                     }
                 }
             }
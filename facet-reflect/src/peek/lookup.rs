@@ -0,0 +1,334 @@
+//! Dotted-path lookups with scalar coercion, for reading loosely typed trees.
+//!
+//! Configuration often arrives as a dynamic value or a map of maps, and
+//! reading one setting out of it means a `match` per level plus one for the
+//! scalar at the end. [`Peek::lookup`] navigates a path like
+//! `servers.0.port` in one call, and [`Peek::get_path_as`] (with its
+//! shorthands [`Peek::get_str`], [`Peek::get_u64`], ...) converts what it
+//! finds using the same rules as [`convert`](crate::convert).
+
+use alloc::string::{String, ToString};
+use core::fmt;
+
+use facet_core::{Def, DynValueKind, Facet, Shape, Type, UserType};
+
+use crate::convert::convert_peek;
+use crate::{Peek, ReflectError};
+
+/// Why [`Peek::lookup`] or one of the typed getters failed.
+///
+/// Every variant carries the path up to the segment that failed, so the
+/// message points at the setting rather than at the whole tree.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum LookupError {
+    /// Nothing is at `path`: a missing key or field, an index out of range,
+    /// or a `None` (or dynamic `null`) on the way.
+    Missing {
+        /// The path up to and including the segment that didn't resolve.
+        path: String,
+    },
+    /// The value at `path` is a scalar, so the next segment can't address
+    /// into it.
+    NotAContainer {
+        /// The path up to the value.
+        path: String,
+        /// The shape of the value.
+        shape: &'static Shape,
+    },
+    /// The value at `path` isn't of a kind that converts to the requested
+    /// type, like an object requested as a string.
+    WrongType {
+        /// The path to the value.
+        path: String,
+        /// The requested type.
+        expected: &'static Shape,
+        /// The shape of the value found.
+        actual: &'static Shape,
+    },
+    /// Converting the value at `path` failed, for example because a number
+    /// is out of range for the requested type.
+    Convert {
+        /// The path to the value.
+        path: String,
+        /// The requested type.
+        expected: &'static Shape,
+        /// The underlying error.
+        source: ReflectError,
+    },
+}
+
+impl fmt::Display for LookupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing { path } => write!(f, "nothing at {path:?}"),
+            Self::NotAContainer { path, shape } => {
+                write!(f, "{shape} at {path:?} has no keys or indices")
+            }
+            Self::WrongType {
+                path,
+                expected,
+                actual,
+            } => write!(f, "expected {expected} at {path:?}, found {actual}"),
+            Self::Convert {
+                path,
+                expected,
+                source,
+            } => write!(f, "can't read {path:?} as {expected}: {source}"),
+        }
+    }
+}
+
+impl core::error::Error for LookupError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Convert { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl<'mem, 'facet> Peek<'mem, 'facet> {
+    /// Navigate to the value at a dotted `path`, such as `server.tls.cert`
+    /// or `servers.0.port`.
+    ///
+    /// Each segment addresses a struct field (by its serialized name, so
+    /// `#[facet(rename)]` applies) or a field of an enum's active variant, a
+    /// map entry with a string key, an object entry of a dynamic value, or,
+    /// when it is a number, an element of a list, array or dynamic array.
+    /// `Option`s and smart pointers on the way are looked through. The empty
+    /// path addresses `self`.
+    ///
+    /// Segments can't contain dots; use [`Peek::at_path`] for keys that do.
+    pub fn lookup(self, path: &str) -> Result<Peek<'mem, 'facet>, LookupError> {
+        let mut current = self;
+        if path.is_empty() {
+            return Ok(current);
+        }
+
+        let mut end = 0;
+        for segment in path.split('.') {
+            let parent_path = &path[..end.saturating_sub(1)];
+            end += segment.len() + 1;
+            let missing = || LookupError::Missing {
+                path: path[..end - 1].to_string(),
+            };
+
+            let container = look_through(current).ok_or_else(missing)?;
+            current = match child(container, segment) {
+                Child::Found(peek) => peek,
+                Child::Missing => return Err(missing()),
+                Child::NotAContainer => {
+                    return Err(LookupError::NotAContainer {
+                        path: parent_path.to_string(),
+                        shape: container.shape(),
+                    });
+                }
+            };
+        }
+        Ok(current)
+    }
+
+    /// Look up `path` and convert the value there into a `T`.
+    ///
+    /// Values convert as with [`convert`](crate::convert): numbers convert
+    /// between all numeric types as long as the value is represented
+    /// exactly, string types convert into `String`, and structs convert
+    /// field by field. Scalars inside dynamic values convert the same way.
+    /// A `None` or dynamic `null` at the end of the path converts into
+    /// `None` if `T` is an `Option`, and is [`LookupError::Missing`]
+    /// otherwise.
+    ///
+    /// ```
+    /// use facet::Facet;
+    /// use facet_reflect::Peek;
+    /// use std::collections::HashMap;
+    ///
+    /// #[derive(Facet)]
+    /// struct Server {
+    ///     host: String,
+    ///     port: u16,
+    /// }
+    ///
+    /// let mut config = HashMap::new();
+    /// config.insert("servers".to_string(), vec![Server { host: "db".into(), port: 5432 }]);
+    ///
+    /// let peek = Peek::new(&config);
+    /// assert_eq!(peek.get_path_as::<u32>("servers.0.port").unwrap(), 5432);
+    /// assert_eq!(peek.get_str("servers.0.host").unwrap(), "db");
+    /// assert_eq!(
+    ///     peek.get_u64("servers.1.port").unwrap_err().to_string(),
+    ///     r#"nothing at "servers.1""#
+    /// );
+    /// ```
+    pub fn get_path_as<T: Facet<'facet>>(self, path: &str) -> Result<T, LookupError> {
+        let found = self.lookup(path)?;
+        let expected = T::SHAPE;
+        let convert_error = |source| LookupError::Convert {
+            path: path.to_string(),
+            expected,
+            source,
+        };
+
+        if found.shape() == expected {
+            return convert_peek(found).map_err(convert_error);
+        }
+        let Some(value) = look_through(found) else {
+            if let Def::Option(_) = expected.def {
+                return convert_peek(Peek::new(&None::<()>)).map_err(convert_error);
+            }
+            return Err(LookupError::Missing {
+                path: path.to_string(),
+            });
+        };
+        if value.shape() == expected {
+            return convert_peek(value).map_err(convert_error);
+        }
+
+        let Def::DynamicValue(_) = value.shape().def else {
+            return convert_peek(value).map_err(convert_error);
+        };
+        let wrong_type = || LookupError::WrongType {
+            path: path.to_string(),
+            expected,
+            actual: value.shape(),
+        };
+        let dynamic = value.into_dynamic_value().map_err(|_| wrong_type())?;
+        let converted = match dynamic.kind() {
+            DynValueKind::Bool => dynamic.as_bool().map(|b| convert_peek(Peek::new(&b))),
+            DynValueKind::Number => {
+                if let Some(n) = dynamic.as_u64() {
+                    Some(convert_peek(Peek::new(&n)))
+                } else if let Some(n) = dynamic.as_i64() {
+                    Some(convert_peek(Peek::new(&n)))
+                } else {
+                    dynamic.as_f64().map(|n| convert_peek(Peek::new(&n)))
+                }
+            }
+            DynValueKind::String => dynamic
+                .as_str()
+                .map(|s| convert_peek(Peek::new(&s.to_string()))),
+            _ => None,
+        };
+        converted.ok_or_else(wrong_type)?.map_err(convert_error)
+    }
+
+    /// The string at `path`; see [`Peek::lookup`].
+    ///
+    /// Borrows from the value, so unlike `get_path_as::<String>` nothing is
+    /// copied, but only string types (and dynamic strings) qualify.
+    pub fn get_str(self, path: &str) -> Result<&'mem str, LookupError> {
+        let found = self.lookup(path)?;
+        let value = look_through(found).ok_or_else(|| LookupError::Missing {
+            path: path.to_string(),
+        })?;
+        let string = match value.into_dynamic_value() {
+            Ok(dynamic) => dynamic.as_str(),
+            Err(_) => value.as_str(),
+        };
+        string.ok_or_else(|| LookupError::WrongType {
+            path: path.to_string(),
+            expected: str::SHAPE,
+            actual: value.shape(),
+        })
+    }
+
+    /// The number at `path` as a `u64`; see [`Peek::get_path_as`].
+    pub fn get_u64(self, path: &str) -> Result<u64, LookupError> {
+        self.get_path_as(path)
+    }
+
+    /// The number at `path` as an `i64`; see [`Peek::get_path_as`].
+    pub fn get_i64(self, path: &str) -> Result<i64, LookupError> {
+        self.get_path_as(path)
+    }
+
+    /// The number at `path` as an `f64`; see [`Peek::get_path_as`].
+    pub fn get_f64(self, path: &str) -> Result<f64, LookupError> {
+        self.get_path_as(path)
+    }
+
+    /// The boolean at `path`; see [`Peek::get_path_as`].
+    pub fn get_bool(self, path: &str) -> Result<bool, LookupError> {
+        self.get_path_as(path)
+    }
+}
+
+/// The value behind any `Option`s and smart pointers, or `None` if one of
+/// them (or a dynamic value) is empty.
+fn look_through<'mem, 'facet>(mut peek: Peek<'mem, 'facet>) -> Option<Peek<'mem, 'facet>> {
+    loop {
+        peek = match peek.shape().def {
+            Def::Option(_) => peek.into_option().ok()?.value()?,
+            Def::Pointer(_) => match peek.into_pointer().ok()?.borrow_inner() {
+                Some(inner) => inner,
+                None => return Some(peek),
+            },
+            Def::DynamicValue(_) => {
+                let dynamic = peek.into_dynamic_value().ok()?;
+                return (!dynamic.is_null()).then_some(peek);
+            }
+            _ => return Some(peek),
+        };
+    }
+}
+
+enum Child<'mem, 'facet> {
+    Found(Peek<'mem, 'facet>),
+    Missing,
+    NotAContainer,
+}
+
+/// The child of `container` addressed by `segment`.
+fn child<'mem, 'facet>(container: Peek<'mem, 'facet>, segment: &str) -> Child<'mem, 'facet> {
+    let found = |peek: Option<Peek<'mem, 'facet>>| match peek {
+        Some(peek) => Child::Found(peek),
+        None => Child::Missing,
+    };
+    let index = || segment.parse::<usize>().ok();
+
+    match container.shape().def {
+        Def::Map(_) => found(container.into_map().ok().and_then(|map| {
+            map.iter()
+                .find(|(key, _)| key.as_str() == Some(segment))
+                .map(|(_, value)| value)
+        })),
+        Def::List(_) | Def::Array(_) | Def::Slice(_) => found(
+            container
+                .into_list_like()
+                .ok()
+                .and_then(|list| list.get(index()?)),
+        ),
+        Def::DynamicValue(_) => match container.into_dynamic_value() {
+            Ok(dynamic) => match dynamic.kind() {
+                DynValueKind::Object => found(dynamic.object_get(segment)),
+                DynValueKind::Array => found(index().and_then(|i| dynamic.array_get(i))),
+                _ => Child::NotAContainer,
+            },
+            Err(_) => Child::NotAContainer,
+        },
+        _ => match container.shape().ty {
+            Type::User(UserType::Struct(struct_type)) => found(
+                struct_type
+                    .fields
+                    .iter()
+                    .position(|field| field.effective_name() == segment)
+                    .and_then(|i| container.into_struct().ok()?.field(i).ok()),
+            ),
+            Type::User(UserType::Enum(_)) => {
+                let Ok(peek_enum) = container.into_enum() else {
+                    return Child::NotAContainer;
+                };
+                found(peek_enum.active_variant().ok().and_then(|variant| {
+                    let i = variant
+                        .data
+                        .fields
+                        .iter()
+                        .position(|field| field.effective_name() == segment)?;
+                    peek_enum.field(i).ok().flatten()
+                }))
+            }
+            _ => Child::NotAContainer,
+        },
+    }
+}
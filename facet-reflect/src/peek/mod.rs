@@ -42,6 +42,17 @@ pub use tuple::*;
 mod dynamic_value;
 pub use dynamic_value::*;
 
+mod walk;
+pub use walk::*;
+
+mod display;
+pub use display::*;
+
+#[cfg(feature = "alloc")]
+mod lookup;
+#[cfg(feature = "alloc")]
+pub use lookup::*;
+
 #[cfg(feature = "alloc")]
 mod owned;
 #[cfg(feature = "alloc")]
@@ -40,7 +40,7 @@ impl<'mem, 'facet> IntoIterator for &'mem PeekSet<'mem, 'facet> {
 /// Lets you read from a set
 #[derive(Clone, Copy)]
 pub struct PeekSet<'mem, 'facet> {
-    value: Peek<'mem, 'facet>,
+    pub(crate) value: Peek<'mem, 'facet>,
 
     def: SetDef,
 }
@@ -1041,7 +1041,8 @@ impl<'mem, 'facet> Peek<'mem, 'facet> {
     /// with support for format-specific proxies.
     ///
     /// If `format_namespace` is provided (e.g., `Some("xml")`), looks for a format-specific
-    /// proxy first, falling back to the format-agnostic proxy.
+    /// proxy first, falling back to the format-agnostic proxy, then to the
+    /// `#[facet(into = ...)]` conversion.
     ///
     /// Returns `None` if no applicable proxy is found.
     #[cfg(feature = "alloc")]
@@ -1049,7 +1050,7 @@ impl<'mem, 'facet> Peek<'mem, 'facet> {
         &self,
         format_namespace: Option<&str>,
     ) -> Result<Option<OwnedPeek<'mem>>, ReflectError> {
-        let Some(proxy_def) = self.shape.effective_proxy(format_namespace) else {
+        let Some(proxy_def) = self.shape.serialize_proxy(format_namespace) else {
             return Ok(None);
         };
 
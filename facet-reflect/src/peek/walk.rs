@@ -0,0 +1,341 @@
+//! Depth-first traversal of a [`Peek`] with enter/leave hooks.
+
+use core::ops::ControlFlow;
+
+use facet_core::{Def, Field, Type, UserType};
+
+use super::{PeekEnum, PeekListLike, PeekMap, PeekSet, PeekStruct};
+use crate::Peek;
+
+/// What [`walk`] should do after a [`PeekVisitor`] hook returns.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VisitControl {
+    /// Keep walking, descending into this node's children.
+    #[default]
+    Continue,
+    /// Don't descend into this node's children. The matching `leave_*` hook
+    /// is still called. Same as `Continue` when returned from a `leave_*` hook
+    /// or from [`PeekVisitor::visit_scalar`].
+    SkipChildren,
+    /// Stop the walk immediately; no further hooks are called.
+    Stop,
+}
+
+/// Where a node sits relative to its parent.
+#[derive(Clone, Copy, Debug)]
+pub enum PeekEdge<'mem, 'facet> {
+    /// The value [`walk`] was called on.
+    Root,
+    /// A field of a struct, tuple, or enum variant.
+    Field {
+        /// Position of the field in its struct or variant.
+        index: usize,
+        /// The field definition.
+        field: &'static Field,
+    },
+    /// An element of a list, array, slice or set.
+    Index(usize),
+    /// A map key.
+    MapKey,
+    /// A map value, with the key it is stored under.
+    MapValue(Peek<'mem, 'facet>),
+    /// The payload of `Some`.
+    Some,
+    /// The payload of `Ok`.
+    Ok,
+    /// The payload of `Err`.
+    Err,
+    /// The pointee of a smart pointer.
+    Pointee,
+}
+
+/// Callbacks for [`walk`].
+///
+/// Every hook has a default implementation that returns
+/// [`VisitControl::Continue`], so visitors only override what they need.
+/// Structs (including tuples and unit structs), enums, list-likes, maps and
+/// sets get an `enter_*`/`leave_*` pair around their children; everything
+/// else that has no children is a scalar. Options, results and smart pointers
+/// are transparent: their payload is visited directly, with a
+/// [`PeekEdge::Some`], [`PeekEdge::Ok`], [`PeekEdge::Err`] or
+/// [`PeekEdge::Pointee`] edge (and `None` is visited as a scalar).
+#[allow(unused_variables)]
+pub trait PeekVisitor<'mem, 'facet> {
+    /// Called before the fields of a struct, tuple or unit struct.
+    fn enter_struct(
+        &mut self,
+        value: PeekStruct<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    /// Called after the fields of a struct.
+    fn leave_struct(
+        &mut self,
+        value: PeekStruct<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    /// Called before the fields of the active variant of an enum.
+    fn enter_enum(
+        &mut self,
+        value: PeekEnum<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    /// Called after the fields of an enum's active variant.
+    fn leave_enum(
+        &mut self,
+        value: PeekEnum<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    /// Called before the elements of a list, array or slice.
+    fn enter_list(
+        &mut self,
+        value: PeekListLike<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    /// Called after the elements of a list, array or slice.
+    fn leave_list(
+        &mut self,
+        value: PeekListLike<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    /// Called before the entries of a map.
+    fn enter_map(
+        &mut self,
+        value: PeekMap<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    /// Called after the entries of a map.
+    fn leave_map(
+        &mut self,
+        value: PeekMap<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    /// Called before the items of a set.
+    fn enter_set(
+        &mut self,
+        value: PeekSet<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    /// Called after the items of a set.
+    fn leave_set(
+        &mut self,
+        value: PeekSet<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    /// Called for values without children: primitives, strings, opaque types,
+    /// `None`, and anything else `walk` doesn't traverse.
+    fn visit_scalar(
+        &mut self,
+        value: Peek<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        VisitControl::Continue
+    }
+}
+
+/// Walks `value` depth-first, calling `visitor`'s hooks for every node.
+///
+/// Returns [`ControlFlow::Break`] if a hook returned [`VisitControl::Stop`].
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+/// use facet_reflect::{Peek, PeekEdge, PeekVisitor, VisitControl, walk};
+///
+/// #[derive(Facet)]
+/// struct Server { host: String, ports: Vec<u16> }
+///
+/// /// Counts scalars, without looking inside lists.
+/// struct Scalars(usize);
+///
+/// impl<'mem, 'facet> PeekVisitor<'mem, 'facet> for Scalars {
+///     fn enter_list(
+///         &mut self,
+///         _: facet_reflect::PeekListLike<'mem, 'facet>,
+///         _: PeekEdge<'mem, 'facet>,
+///     ) -> VisitControl {
+///         VisitControl::SkipChildren
+///     }
+///
+///     fn visit_scalar(
+///         &mut self,
+///         _: Peek<'mem, 'facet>,
+///         _: PeekEdge<'mem, 'facet>,
+///     ) -> VisitControl {
+///         self.0 += 1;
+///         VisitControl::Continue
+///     }
+/// }
+///
+/// let server = Server { host: "localhost".into(), ports: vec![80, 443] };
+/// let mut scalars = Scalars(0);
+/// let _ = walk(Peek::new(&server), &mut scalars);
+/// assert_eq!(scalars.0, 1);
+/// ```
+pub fn walk<'mem, 'facet, V>(value: Peek<'mem, 'facet>, visitor: &mut V) -> ControlFlow<()>
+where
+    V: PeekVisitor<'mem, 'facet> + ?Sized,
+{
+    walk_node(value, PeekEdge::Root, visitor)
+}
+
+/// Turns a hook's answer into whether to descend, or a break.
+fn descend(control: VisitControl) -> ControlFlow<(), bool> {
+    match control {
+        VisitControl::Continue => ControlFlow::Continue(true),
+        VisitControl::SkipChildren => ControlFlow::Continue(false),
+        VisitControl::Stop => ControlFlow::Break(()),
+    }
+}
+
+fn walk_node<'mem, 'facet, V>(
+    value: Peek<'mem, 'facet>,
+    edge: PeekEdge<'mem, 'facet>,
+    visitor: &mut V,
+) -> ControlFlow<()>
+where
+    V: PeekVisitor<'mem, 'facet> + ?Sized,
+{
+    // Options, results and smart pointers are transparent, even though
+    // options and results are also described as enums.
+    match value.shape().def {
+        Def::Option(_) => {
+            if let Ok(option) = value.into_option() {
+                let Some(inner) = option.value() else {
+                    descend(visitor.visit_scalar(value, edge))?;
+                    return ControlFlow::Continue(());
+                };
+                return walk_node(inner, PeekEdge::Some, visitor);
+            }
+        }
+
+        Def::Result(_) => {
+            if let Ok(result) = value.into_result() {
+                if let Some(ok) = result.ok() {
+                    return walk_node(ok, PeekEdge::Ok, visitor);
+                }
+                if let Some(err) = result.err() {
+                    return walk_node(err, PeekEdge::Err, visitor);
+                }
+            }
+        }
+
+        Def::Pointer(_) => {
+            if let Ok(pointer) = value.into_pointer()
+                && let Some(inner) = pointer.borrow_inner()
+            {
+                return walk_node(inner, PeekEdge::Pointee, visitor);
+            }
+        }
+
+        _ => {}
+    }
+
+    match value.shape().ty {
+        Type::User(UserType::Struct(_)) => {
+            if let Ok(peek_struct) = value.into_struct() {
+                if descend(visitor.enter_struct(peek_struct, edge))? {
+                    for (index, field) in peek_struct.ty().fields.iter().enumerate() {
+                        if let Ok(child) = peek_struct.field(index) {
+                            walk_node(child, PeekEdge::Field { index, field }, visitor)?;
+                        }
+                    }
+                }
+                descend(visitor.leave_struct(peek_struct, edge))?;
+                return ControlFlow::Continue(());
+            }
+        }
+
+        Type::User(UserType::Enum(_)) => {
+            if let Ok(peek_enum) = value.into_enum() {
+                if descend(visitor.enter_enum(peek_enum, edge))?
+                    && let Ok(variant) = peek_enum.active_variant()
+                {
+                    for (index, field) in variant.data.fields.iter().enumerate() {
+                        if let Ok(Some(child)) = peek_enum.field(index) {
+                            walk_node(child, PeekEdge::Field { index, field }, visitor)?;
+                        }
+                    }
+                }
+                descend(visitor.leave_enum(peek_enum, edge))?;
+                return ControlFlow::Continue(());
+            }
+        }
+
+        _ => {}
+    }
+
+    match value.shape().def {
+        Def::List(_) | Def::Array(_) | Def::Slice(_) => {
+            if let Ok(list) = value.into_list_like() {
+                if descend(visitor.enter_list(list, edge))? {
+                    for (index, item) in list.iter().enumerate() {
+                        walk_node(item, PeekEdge::Index(index), visitor)?;
+                    }
+                }
+                descend(visitor.leave_list(list, edge))?;
+                return ControlFlow::Continue(());
+            }
+        }
+
+        Def::Map(_) => {
+            if let Ok(map) = value.into_map() {
+                if descend(visitor.enter_map(map, edge))? {
+                    for (key, item) in map.iter() {
+                        walk_node(key, PeekEdge::MapKey, visitor)?;
+                        walk_node(item, PeekEdge::MapValue(key), visitor)?;
+                    }
+                }
+                descend(visitor.leave_map(map, edge))?;
+                return ControlFlow::Continue(());
+            }
+        }
+
+        Def::Set(_) => {
+            if let Ok(set) = value.into_set() {
+                if descend(visitor.enter_set(set, edge))? {
+                    for (index, item) in set.iter().enumerate() {
+                        walk_node(item, PeekEdge::Index(index), visitor)?;
+                    }
+                }
+                descend(visitor.leave_set(set, edge))?;
+                return ControlFlow::Continue(());
+            }
+        }
+
+        _ => {}
+    }
+
+    descend(visitor.visit_scalar(value, edge))?;
+    ControlFlow::Continue(())
+}
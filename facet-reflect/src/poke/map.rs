@@ -1,8 +1,8 @@
-use core::mem::ManuallyDrop;
+use core::{marker::PhantomData, mem::ManuallyDrop, ptr::NonNull};
 
-use facet_core::{Facet, MapDef};
+use facet_core::{Facet, MapDef, PtrUninit};
 
-use crate::{HeapValue, ReflectError, ReflectErrorKind};
+use crate::{Guard, HeapValue, ReflectError, ReflectErrorKind};
 
 use super::Poke;
 
@@ -102,6 +102,107 @@ impl<'mem, 'facet> PokeMap<'mem, 'facet> {
         }))
     }
 
+    /// Get a value from the map for the given key, as a mutable `Poke`
+    ///
+    /// Returns an error if the underlying map type does not support in-place
+    /// value access.
+    #[inline]
+    pub fn get_mut(
+        &mut self,
+        key: &impl Facet<'facet>,
+    ) -> Result<Option<Poke<'_, 'facet>>, ReflectError> {
+        self.get_mut_peek(crate::Peek::new(key))
+    }
+
+    /// Get a value from the map for the given key (using a `Peek`), as a mutable `Poke`
+    pub fn get_mut_peek(
+        &mut self,
+        key: crate::Peek<'_, 'facet>,
+    ) -> Result<Option<Poke<'_, 'facet>>, ReflectError> {
+        if self.def.k() != key.shape() {
+            return Err(self.err(ReflectErrorKind::WrongShape {
+                expected: self.def.k(),
+                actual: key.shape(),
+            }));
+        }
+        let get_value_ptr_mut = self.def.vtable.get_value_ptr_mut.ok_or_else(|| {
+            self.err(ReflectErrorKind::OperationFailed {
+                shape: self.value.shape(),
+                operation: "get_mut: map type does not support mutable value access",
+            })
+        })?;
+
+        let value_ptr = unsafe { get_value_ptr_mut(self.value.data_mut(), key.data()) };
+        if value_ptr.is_null() {
+            return Ok(None);
+        }
+        let value_ptr = facet_core::PtrMut::new(value_ptr);
+        Ok(Some(unsafe { Poke::from_raw_parts(value_ptr, self.def.v()) }))
+    }
+
+    /// Remove the entry for the given key, returning its value if it was present.
+    ///
+    /// Returns an error if the underlying map type does not support removal.
+    #[inline]
+    pub fn remove(
+        &mut self,
+        key: &impl Facet<'facet>,
+    ) -> Result<Option<HeapValue<'facet, true>>, ReflectError> {
+        self.remove_peek(crate::Peek::new(key))
+    }
+
+    /// Remove the entry for the given key (using a `Peek`), returning its value if it was present.
+    pub fn remove_peek(
+        &mut self,
+        key: crate::Peek<'_, 'facet>,
+    ) -> Result<Option<HeapValue<'facet, true>>, ReflectError> {
+        if self.def.k() != key.shape() {
+            return Err(self.err(ReflectErrorKind::WrongShape {
+                expected: self.def.k(),
+                actual: key.shape(),
+            }));
+        }
+        let remove_fn = self.def.vtable.remove.ok_or_else(|| {
+            self.err(ReflectErrorKind::OperationFailed {
+                shape: self.value.shape(),
+                operation: "remove: map type does not support remove",
+            })
+        })?;
+        let value_shape = self.def.v();
+        let layout = value_shape.layout.sized_layout().map_err(|_| {
+            self.err(ReflectErrorKind::Unsized {
+                shape: value_shape,
+                operation: "remove",
+            })
+        })?;
+        let ptr = if layout.size() == 0 {
+            NonNull::<u8>::dangling()
+        } else {
+            let raw = unsafe { alloc::alloc::alloc(layout) };
+            match NonNull::new(raw) {
+                Some(p) => p,
+                None => alloc::alloc::handle_alloc_error(layout),
+            }
+        };
+        let out = PtrUninit::new(ptr.as_ptr());
+        let removed = unsafe { remove_fn(self.value.data_mut(), key.data(), out) };
+        if !removed {
+            if layout.size() != 0 {
+                unsafe { alloc::alloc::dealloc(ptr.as_ptr(), layout) };
+            }
+            return Ok(None);
+        }
+        Ok(Some(HeapValue {
+            guard: Some(Guard {
+                ptr,
+                layout,
+                should_dealloc: layout.size() != 0,
+            }),
+            shape: value_shape,
+            phantom: PhantomData,
+        }))
+    }
+
     /// Insert a key-value pair into the map.
     ///
     /// Both key and value must have shapes matching the map's key and value types.
@@ -235,6 +336,27 @@ mod tests {
         assert_eq!(*v.get::<i32>().unwrap(), 10);
     }
 
+    #[test]
+    fn poke_map_get_mut_and_remove() {
+        let mut m: BTreeMap<String, i32> = BTreeMap::new();
+        m.insert(String::from("a"), 10);
+        m.insert(String::from("b"), 20);
+        let poke = Poke::new(&mut m);
+        let mut map = poke.into_map().unwrap();
+
+        let key = String::from("a");
+        map.get_mut(&key).unwrap().unwrap().set(11i32).unwrap();
+        assert!(map.get_mut(&String::from("zzz")).unwrap().is_none());
+
+        let removed = map.remove(&String::from("b")).unwrap().unwrap();
+        assert_eq!(removed.materialize::<i32>().unwrap(), 20);
+        assert!(map.remove(&String::from("b")).unwrap().is_none());
+        assert_eq!(map.len(), 1);
+
+        assert_eq!(m.get("a"), Some(&11));
+        assert!(!m.contains_key("b"));
+    }
+
     #[test]
     fn poke_map_insert_from_heap() {
         let mut m: BTreeMap<String, i32> = BTreeMap::new();
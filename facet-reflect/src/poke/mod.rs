@@ -52,3 +52,8 @@ pub use ndarray::*;
 
 mod dynamic_value;
 pub use dynamic_value::*;
+
+#[cfg(feature = "alloc")]
+mod update;
+#[cfg(feature = "alloc")]
+pub use update::*;
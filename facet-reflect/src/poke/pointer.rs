@@ -1,14 +1,15 @@
-use facet_core::PointerDef;
+use facet_core::{KnownPointer, PointerDef};
 
 use super::Poke;
 
 /// Poke-side wrapper over a pointer value, for symmetry with [`PeekPointer`](crate::PeekPointer).
 ///
 /// The current [`PointerVTable`](facet_core::PointerVTable) exposes no mutating operations
-/// on the pointee, so this type is effectively read-only: it offers
+/// on the pointee, so this type is mostly read-only: it offers
 /// [`borrow_inner`](Self::borrow_inner) (returning a [`Peek`](crate::Peek)) plus conversions
-/// back to [`Poke`] / [`PeekPointer`](crate::PeekPointer). Mutating methods will be added
-/// once the core vtable grows the corresponding hooks.
+/// back to [`Poke`] / [`PeekPointer`](crate::PeekPointer). Only a `Box`, which owns its
+/// pointee, can be written through, with [`borrow_inner_mut`](Self::borrow_inner_mut).
+/// Other mutating methods will be added once the core vtable grows the corresponding hooks.
 pub struct PokePointer<'mem, 'facet> {
     pub(crate) value: Poke<'mem, 'facet>,
     pub(crate) def: PointerDef,
@@ -39,6 +40,23 @@ impl<'mem, 'facet> PokePointer<'mem, 'facet> {
         Some(unsafe { crate::Peek::unchecked_new(inner_ptr, pointee_shape) })
     }
 
+    /// Borrows the pointee of a `Box` as a `Poke`.
+    ///
+    /// Returns `None` for every other pointer: `Rc`, `Arc` and the like share
+    /// their pointee, so a mutable borrow of the pointer doesn't make it
+    /// exclusive.
+    #[inline]
+    pub fn borrow_inner_mut(&mut self) -> Option<Poke<'_, 'facet>> {
+        if self.def.known != Some(KnownPointer::Box) {
+            return None;
+        }
+        let borrow_fn = self.def.vtable.borrow_fn?;
+        let pointee_shape = self.def.pointee()?;
+        let inner_ptr = unsafe { borrow_fn(self.value.data()) };
+        // SAFETY: the box owns its pointee, and we hold the box mutably.
+        Some(unsafe { Poke::from_raw_parts(inner_ptr.into_mut(), pointee_shape) })
+    }
+
     /// Converts this back into the underlying `Poke`.
     #[inline]
     pub const fn into_inner(self) -> Poke<'mem, 'facet> {
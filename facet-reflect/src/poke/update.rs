@@ -0,0 +1,220 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use facet_core::{Facet, PtrMut, Shape};
+use facet_path::Path;
+
+use crate::{ReflectError, ReflectErrorKind, partial::validate_invariants};
+
+use super::Poke;
+
+/// A batch of replacements to apply at once with [`Poke::update_many`].
+///
+/// Each update replaces the value at a [`Path`], as
+/// [`Poke::at_path_mut`] followed by [`Poke::set`] would. Staged values are
+/// moved into the batch, and dropped with it if it's never applied.
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+/// use facet_path::{Path, PathStep};
+/// use facet_reflect::{Poke, Updates};
+///
+/// #[derive(Facet)]
+/// struct Form {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// let mut form = Form { name: "Ann".into(), age: 30 };
+///
+/// let mut name = Path::new(Form::SHAPE);
+/// name.push(PathStep::Field(0));
+/// let mut age = Path::new(Form::SHAPE);
+/// age.push(PathStep::Field(1));
+///
+/// let updates = Updates::new()
+///     .set(name, String::from("Bob"))
+///     .set(age, 31u32);
+/// Poke::new(&mut form).update_many(updates).unwrap();
+///
+/// assert_eq!(form.name, "Bob");
+/// assert_eq!(form.age, 31);
+/// ```
+pub struct Updates<'facet> {
+    staged: Vec<Staged>,
+    /// Invariant with respect to 'facet, like `Poke`
+    _marker: PhantomData<fn(&'facet ()) -> &'facet ()>,
+}
+
+/// One staged update: a heap-allocated value for the location at `path`.
+struct Staged {
+    path: Path,
+    data: PtrMut,
+    shape: &'static Shape,
+    size: usize,
+}
+
+impl<'facet> Updates<'facet> {
+    /// Creates an empty batch.
+    pub const fn new() -> Self {
+        Self {
+            staged: Vec::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Stages replacing the value at `path` with `value`.
+    pub fn set<T: Facet<'facet>>(mut self, path: Path, value: T) -> Self {
+        let shape = T::SHAPE;
+        let Ok(data) = shape.allocate() else {
+            unreachable!("values passed by value are sized")
+        };
+        // SAFETY: `data` was just allocated for a `T`.
+        let data = unsafe { data.put(value) };
+        self.staged.push(Staged {
+            path,
+            data,
+            shape,
+            size: core::mem::size_of::<T>(),
+        });
+        self
+    }
+
+    /// The paths this batch updates, in the order they were staged.
+    ///
+    /// Handy for notifying whatever observes those locations once the batch
+    /// has been applied.
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.staged.iter().map(|staged| &staged.path)
+    }
+
+    /// Returns the number of staged updates.
+    pub const fn len(&self) -> usize {
+        self.staged.len()
+    }
+
+    /// Returns true if nothing is staged.
+    pub const fn is_empty(&self) -> bool {
+        self.staged.is_empty()
+    }
+}
+
+impl Default for Updates<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for Updates<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.paths()).finish()
+    }
+}
+
+impl Drop for Updates<'_> {
+    fn drop(&mut self) {
+        for staged in &self.staged {
+            // SAFETY: `data` holds an initialized value of `shape`, allocated
+            // with `Shape::allocate` in `set`.
+            unsafe {
+                staged.shape.call_drop_in_place(staged.data);
+                let _ = staged.shape.deallocate_mut(staged.data);
+            }
+        }
+    }
+}
+
+impl<'mem, 'facet> Poke<'mem, 'facet> {
+    /// Applies a batch of updates, checking invariants once at the end.
+    ///
+    /// Every path is resolved and type-checked before anything is written,
+    /// so a bad update leaves the value untouched. The values are then
+    /// written in order and the invariants of the whole value are checked a
+    /// single time; if they fail, every write is undone and the error is
+    /// returned. Either all updates are applied or none are.
+    ///
+    /// Unlike [`PokeStruct::set_field`](super::PokeStruct::set_field), this
+    /// doesn't require `#[facet(pod)]`, since invariants are checked instead.
+    ///
+    /// Several updates to the same path are fine (the last one wins), but a
+    /// path can't lead into a value that another update replaces.
+    pub fn update_many(&mut self, updates: Updates<'facet>) -> Result<(), ReflectError> {
+        let mut targets = Vec::with_capacity(updates.staged.len());
+        for (index, staged) in updates.staged.iter().enumerate() {
+            let steps = staged.path.steps();
+            let overlaps = updates.staged[..index].iter().any(|other| {
+                let other = other.path.steps();
+                steps != other && (steps.starts_with(other) || other.starts_with(steps))
+            });
+            if overlaps {
+                return Err(ReflectError::new(
+                    ReflectErrorKind::InvalidOperation {
+                        operation: "update_many",
+                        reason: "an update's path leads into a value another update replaces",
+                    },
+                    staged.path.clone(),
+                ));
+            }
+
+            // SAFETY: reborrows our own data; the view doesn't outlive this loop.
+            let root = unsafe { Poke::from_raw_parts(self.data, self.shape) };
+            let target = root.at_path_mut(&staged.path).map_err(|error| {
+                ReflectError::new(
+                    ReflectErrorKind::OperationFailedOwned {
+                        shape: self.shape,
+                        operation: alloc::format!("update_many: {error}"),
+                    },
+                    staged.path.clone(),
+                )
+            })?;
+            if target.shape != staged.shape {
+                return Err(ReflectError::new(
+                    ReflectErrorKind::WrongShape {
+                        expected: target.shape,
+                        actual: staged.shape,
+                    },
+                    staged.path.clone(),
+                ));
+            }
+            targets.push(target.data);
+        }
+
+        // Swap the new values in; the batch now holds the old ones and drops
+        // them when it goes out of scope.
+        for (target, staged) in targets.iter().zip(&updates.staged) {
+            // SAFETY: both sides hold initialized values of `staged.shape`,
+            // and the staged value lives in its own allocation.
+            unsafe { swap(*target, staged) };
+        }
+
+        if let Err((shape, message)) = validate_invariants(self.as_peek()) {
+            // Undo in reverse so repeated paths end up with their original
+            // value.
+            for (target, staged) in targets.iter().zip(&updates.staged).rev() {
+                // SAFETY: as above.
+                unsafe { swap(*target, staged) };
+            }
+            return Err(self.err(ReflectErrorKind::UserInvariantFailed { message, shape }));
+        }
+
+        Ok(())
+    }
+}
+
+/// Exchange the value at `target` with the one staged.
+///
+/// # Safety
+///
+/// `target` must hold an initialized value of `staged.shape` that doesn't
+/// overlap the staged allocation.
+unsafe fn swap(target: PtrMut, staged: &Staged) {
+    unsafe {
+        core::ptr::swap_nonoverlapping(
+            target.as_mut_byte_ptr(),
+            staged.data.as_mut_byte_ptr(),
+            staged.size,
+        );
+    }
+}
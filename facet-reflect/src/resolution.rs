@@ -15,7 +15,7 @@ use alloc::string::ToString;
 use alloc::vec::Vec;
 use core::fmt;
 
-use facet_core::{Field, Shape};
+use facet_core::{Field, Shape, Variant};
 
 /// Category of a field for format-aware field lookup.
 ///
@@ -286,6 +286,17 @@ pub struct VariantSelection {
     pub enum_name: &'static str,
     /// Name of the selected variant (e.g., "Text")
     pub variant_name: &'static str,
+    /// Name the variant goes by in serialized input, e.g. the tag value of an
+    /// internally-tagged enum. Differs from `variant_name` when the variant
+    /// has `#[facet(rename = "...")]` or the enum has `rename_all`.
+    pub serialized_name: &'static str,
+}
+
+impl VariantSelection {
+    /// Whether the variant is known under a different name in serialized input.
+    pub fn is_renamed(&self) -> bool {
+        self.serialized_name != self.variant_name
+    }
 }
 
 /// Information about a single field in a resolution.
@@ -333,6 +344,22 @@ impl FieldInfo {
 
 impl Eq for FieldInfo {}
 
+/// Fields whose keys carry a runtime prefix, from a flattened struct field
+/// marked `#[facet(flatten_prefix_from = "...")]`.
+///
+/// In the input they appear as `"<prefix>.<name>"`, where the prefix is the
+/// value of another field, so they can't be looked up by name alone; see
+/// [`Resolution::field_by_prefixed_key`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DynamicPrefix {
+    /// Path to the field whose value is the prefix
+    pub source: FieldPath,
+    /// Path to the flattened field holding the prefixed fields
+    pub path: FieldPath,
+    /// The prefixed fields, keyed by their name after the prefix
+    pub fields: BTreeMap<&'static str, FieldInfo>,
+}
+
 /// Result of matching input fields against a resolution.
 #[derive(Debug)]
 #[non_exhaustive]
@@ -382,6 +409,9 @@ pub struct Resolution {
     /// For flat formats, `FieldCategory::Flat` captures all unknown keys.
     /// For DOM formats, can have separate catch-alls for `Attribute` vs `Element`.
     catch_all_maps: BTreeMap<FieldCategory, FieldInfo>,
+
+    /// Groups of fields whose keys carry a runtime prefix.
+    dynamic_prefixes: Vec<DynamicPrefix>,
 }
 
 /// Error when building a resolution.
@@ -415,6 +445,7 @@ impl Resolution {
             known_paths: BTreeSet::new(),
             dom_known_paths: BTreeSet::new(),
             catch_all_maps: BTreeMap::new(),
+            dynamic_prefixes: Vec::new(),
         }
     }
 
@@ -433,6 +464,56 @@ impl Resolution {
         &self.catch_all_maps
     }
 
+    /// Add a group of fields whose keys carry a runtime prefix.
+    pub fn add_dynamic_prefix(&mut self, prefix: DynamicPrefix) {
+        self.dynamic_prefixes.push(prefix);
+    }
+
+    /// Get the groups of fields whose keys carry a runtime prefix.
+    pub fn dynamic_prefixes(&self) -> &[DynamicPrefix] {
+        &self.dynamic_prefixes
+    }
+
+    /// Find the prefixed field a key like `"web1.cpu"` refers to.
+    ///
+    /// Returns the prefix (`"web1"`), the group it belongs to and the field.
+    /// Prefixes may themselves contain dots: the key is split at the first dot
+    /// that leaves a known field name after it.
+    ///
+    /// Several groups can hold a field of that name, e.g. two flattened
+    /// structs of the same type under different prefixes. `prefix_of` gives
+    /// the prefix a group is already known to have (from its source field or
+    /// its earlier keys), if any: the group whose prefix matches is picked,
+    /// then the first one with no known prefix yet, then the first one.
+    pub fn field_by_prefixed_key<'k, 'p>(
+        &self,
+        key: &'k str,
+        prefix_of: impl Fn(&DynamicPrefix) -> Option<&'p str>,
+    ) -> Option<(&'k str, &DynamicPrefix, &FieldInfo)> {
+        if self.dynamic_prefixes.is_empty() {
+            return None;
+        }
+        key.match_indices('.').find_map(|(dot, _)| {
+            let (prefix, name) = (&key[..dot], &key[dot + 1..]);
+            let mut first = None;
+            let mut unclaimed = None;
+            for group in &self.dynamic_prefixes {
+                let Some(info) = group.fields.get(name) else {
+                    continue;
+                };
+                match prefix_of(group) {
+                    Some(known) if known == prefix => return Some((prefix, group, info)),
+                    Some(_) => {}
+                    None => {
+                        unclaimed.get_or_insert((prefix, group, info));
+                    }
+                }
+                first.get_or_insert((prefix, group, info));
+            }
+            unclaimed.or(first)
+        })
+    }
+
     /// Add a key path (for depth-aware probing, flat format).
     pub fn add_key_path(&mut self, path: KeyPath) {
         self.known_paths.insert(path);
@@ -471,12 +552,13 @@ impl Resolution {
         &mut self,
         path: FieldPath,
         enum_name: &'static str,
-        variant_name: &'static str,
+        variant: &'static Variant,
     ) {
         self.variant_selections.push(VariantSelection {
             path,
             enum_name,
-            variant_name,
+            variant_name: variant.name,
+            serialized_name: variant.effective_name(),
         });
     }
 
@@ -514,6 +596,8 @@ impl Resolution {
         for (cat, info) in &other.catch_all_maps {
             self.catch_all_maps.insert(*cat, info.clone());
         }
+        self.dynamic_prefixes
+            .extend(other.dynamic_prefixes.iter().cloned());
         Ok(())
     }
 
@@ -524,6 +608,11 @@ impl Resolution {
         for info in self.fields.values_mut() {
             info.required = false;
         }
+        for group in &mut self.dynamic_prefixes {
+            for info in group.fields.values_mut() {
+                info.required = false;
+            }
+        }
     }
 
     /// Check if this resolution matches the input fields.
@@ -544,17 +633,21 @@ impl Resolution {
             }
         }
 
-        // Check for unknown fields
-        let unknown: Vec<String> = input_fields
-            .iter()
-            .filter(|f| {
-                !self
-                    .fields
-                    .values()
-                    .any(|info| info.serialized_name == f.as_ref())
-            })
-            .map(|s| s.to_string())
-            .collect();
+        // Check for unknown fields; a flattened catch-all map takes them all
+        let unknown: Vec<String> = if self.catch_all_maps.contains_key(&FieldCategory::Flat) {
+            Vec::new()
+        } else {
+            input_fields
+                .iter()
+                .filter(|f| {
+                    !self
+                        .fields
+                        .values()
+                        .any(|info| info.serialized_name == f.as_ref())
+                })
+                .map(|s| s.to_string())
+                .collect()
+        };
 
         if !missing_required.is_empty() || !unknown.is_empty() {
             MatchResult::NoMatch {
@@ -571,7 +664,8 @@ impl Resolution {
     /// Get a human-readable description of this resolution.
     ///
     /// Returns something like `MessagePayload::Text` or `Auth::Token + Transport::Tcp`
-    /// for resolutions with multiple variant selections.
+    /// for resolutions with multiple variant selections. Renamed variants also
+    /// show their serialized name, as in `Transport::Tcp ("tcp")`.
     pub fn describe(&self) -> String {
         if self.variant_selections.is_empty() {
             String::from("(no variants)")
@@ -579,7 +673,16 @@ impl Resolution {
             let parts: Vec<_> = self
                 .variant_selections
                 .iter()
-                .map(|vs| format!("{}::{}", vs.enum_name, vs.variant_name))
+                .map(|vs| {
+                    if vs.is_renamed() {
+                        format!(
+                            "{}::{} ({:?})",
+                            vs.enum_name, vs.variant_name, vs.serialized_name
+                        )
+                    } else {
+                        format!("{}::{}", vs.enum_name, vs.variant_name)
+                    }
+                })
                 .collect();
             parts.join(" + ")
         }
@@ -0,0 +1,172 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use facet::Facet;
+use facet_reflect::convert;
+use facet_testhelpers::test;
+
+#[derive(Facet, Debug, PartialEq)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum ModeV1 {
+    Fast,
+    Careful { retries: u8 },
+}
+
+#[derive(Facet, Debug, PartialEq)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum ModeV2 {
+    Careful {
+        retries: u32,
+        backoff_ms: Option<u64>,
+    },
+    Fast,
+    Paranoid,
+}
+
+#[derive(Facet)]
+struct ConfigV1 {
+    name: String,
+    port: u16,
+    hosts: Vec<String>,
+    mode: ModeV1,
+    limits: BTreeMap<String, u32>,
+    ratio: f32,
+    removed: bool,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct ConfigV2 {
+    #[facet(rename = "name")]
+    service_name: String,
+    port: Option<u64>,
+    hosts: BTreeSet<String>,
+    mode: ModeV2,
+    limits: BTreeMap<String, i64>,
+    ratio: f64,
+    added: Option<String>,
+}
+
+#[test]
+fn convert_maps_fields_by_name() {
+    let v1 = ConfigV1 {
+        name: "api".to_string(),
+        port: 8080,
+        hosts: vec!["b".to_string(), "a".to_string()],
+        mode: ModeV1::Careful { retries: 3 },
+        limits: BTreeMap::from([("rps".to_string(), 100)]),
+        ratio: 0.5,
+        removed: true,
+    };
+    let v2: ConfigV2 = convert(&v1).unwrap();
+    assert_eq!(
+        v2,
+        ConfigV2 {
+            service_name: "api".to_string(),
+            port: Some(8080),
+            hosts: BTreeSet::from(["a".to_string(), "b".to_string()]),
+            mode: ModeV2::Careful {
+                retries: 3,
+                backoff_ms: None
+            },
+            limits: BTreeMap::from([("rps".to_string(), 100)]),
+            ratio: 0.5,
+            added: None,
+        }
+    );
+}
+
+#[test]
+fn convert_numbers_exactly_or_not_at_all() {
+    assert_eq!(convert::<u64, u8>(&255).unwrap(), 255);
+    assert_eq!(convert::<i32, u16>(&7).unwrap(), 7);
+    assert_eq!(convert::<f64, i64>(&-3.0).unwrap(), -3);
+    assert_eq!(convert::<u32, f32>(&16_777_216).unwrap(), 16_777_216.0);
+
+    assert!(convert::<u64, u8>(&256).is_err());
+    assert!(convert::<i32, u32>(&-1).is_err());
+    assert!(convert::<f64, i64>(&1.5).is_err());
+    assert!(convert::<u32, f32>(&16_777_217).is_err());
+    assert!(convert::<f64, f32>(&0.1).is_err());
+}
+
+#[test]
+fn convert_unwraps_some_but_not_none() {
+    #[derive(Facet)]
+    struct Maybe {
+        value: Option<u8>,
+    }
+
+    #[derive(Facet, Debug, PartialEq)]
+    struct Required {
+        value: u16,
+    }
+
+    let required: Required = convert(&Maybe { value: Some(4) }).unwrap();
+    assert_eq!(required, Required { value: 4 });
+    assert!(convert::<Maybe, Required>(&Maybe { value: None }).is_err());
+}
+
+#[test]
+fn convert_reports_missing_fields_and_variants() {
+    #[derive(Facet)]
+    struct Sparse {
+        name: String,
+    }
+
+    #[derive(Facet, Debug)]
+    #[allow(dead_code)]
+    struct Dense {
+        name: String,
+        id: u64,
+    }
+
+    let sparse = Sparse {
+        name: "x".to_string(),
+    };
+    assert!(convert::<Sparse, Dense>(&sparse).is_err());
+
+    #[derive(Facet, Debug)]
+    #[repr(u8)]
+    #[allow(dead_code)]
+    enum OnlyFast {
+        Fast,
+    }
+    let careful = ModeV1::Careful { retries: 1 };
+    assert!(convert::<ModeV1, OnlyFast>(&careful).is_err());
+}
+
+#[test]
+fn coerce_scalar_rounds_floats_unless_exact() {
+    use facet_reflect::{CoerceError, Coerced, Peek, coerce_scalar, coerce_scalar_exact};
+
+    let tenth = 0.1_f64;
+    assert_eq!(
+        coerce_scalar(Peek::new(&tenth), f32::SHAPE),
+        Ok(Coerced::F32(0.1))
+    );
+    assert_eq!(
+        coerce_scalar_exact(Peek::new(&tenth), f32::SHAPE),
+        Err(CoerceError::Inexact)
+    );
+    assert_eq!(
+        coerce_scalar(Peek::new(&tenth), u8::SHAPE),
+        Err(CoerceError::Inexact)
+    );
+
+    let c = 'x';
+    assert_eq!(
+        coerce_scalar(Peek::new(&c), String::SHAPE),
+        Ok(Coerced::String("x".into()))
+    );
+    let s = String::from("y");
+    assert_eq!(
+        coerce_scalar(Peek::new(&s), char::SHAPE),
+        Ok(Coerced::Char('y'))
+    );
+    let s = String::from("yz");
+    assert_eq!(
+        coerce_scalar(Peek::new(&s), char::SHAPE),
+        Err(CoerceError::Unsupported)
+    );
+}
@@ -1,6 +1,7 @@
 #![cfg(feature = "std")]
 
 mod array_building;
+mod convert;
 mod deferred;
 mod deserialize;
 mod empty_tuples;
@@ -19,6 +20,7 @@ mod pointer;
 mod pointer_complex;
 mod put_vec_leak;
 mod result_building;
+mod resume;
 mod set;
 mod smartptr_deferred;
 mod struct_leak;
@@ -0,0 +1,184 @@
+use facet::Facet;
+use facet_reflect::Partial;
+use facet_testhelpers::{IPanic, test};
+
+#[derive(Facet, Debug, PartialEq)]
+struct Inner {
+    a: u32,
+    b: u32,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Outer {
+    name: String,
+    inner: Inner,
+    flag: bool,
+}
+
+/// A stand-in for a streaming deserializer: it owns the in-progress value
+/// between calls to `feed`.
+struct Stream {
+    partial: Option<Partial<'static, false>>,
+}
+
+impl Stream {
+    fn feed(&mut self, f: impl FnOnce(Partial<'static, false>) -> Partial<'static, false>) {
+        let partial = self.partial.take().unwrap();
+        self.partial = Some(f(partial));
+    }
+}
+
+#[test]
+fn missing_fields_tracks_progress() -> Result<(), IPanic> {
+    let mut partial = Partial::alloc_owned::<Outer>()?;
+    assert_eq!(partial.missing_fields()?, ["name", "inner", "flag"]);
+
+    partial = partial.set_field("name", String::from("n"))?;
+    partial = partial.begin_field("inner")?;
+    assert_eq!(partial.missing_fields()?, ["a", "b"]);
+
+    partial = partial.set_field("a", 1u32)?;
+    assert_eq!(partial.missing_fields()?, ["b"]);
+    Ok(())
+}
+
+#[test]
+fn missing_fields_rejects_non_struct_frames() -> Result<(), IPanic> {
+    let partial = Partial::alloc_owned::<u32>()?;
+    assert!(partial.missing_fields().is_err());
+    Ok(())
+}
+
+#[test]
+fn partial_can_be_suspended_between_chunks() -> Result<(), IPanic> {
+    let mut stream = Stream {
+        partial: Some(Partial::alloc_owned::<Outer>()?),
+    };
+
+    // Chunk 1 ends in the middle of `inner`.
+    stream.feed(|p| {
+        p.set_field("name", String::from("config"))
+            .unwrap()
+            .begin_field("inner")
+            .unwrap()
+            .set_field("a", 1u32)
+            .unwrap()
+    });
+    assert_eq!(stream.partial.as_ref().unwrap().missing_fields()?, ["b"]);
+
+    // Chunk 2 finishes `inner` and the rest of `Outer`.
+    stream.feed(|p| {
+        p.set_field("b", 2u32)
+            .unwrap()
+            .end()
+            .unwrap()
+            .set_field("flag", true)
+            .unwrap()
+    });
+
+    let value = stream
+        .partial
+        .take()
+        .unwrap()
+        .build()?
+        .materialize::<Outer>()?;
+    assert_eq!(
+        value,
+        Outer {
+            name: "config".to_string(),
+            inner: Inner { a: 1, b: 2 },
+            flag: true,
+        }
+    );
+    Ok(())
+}
+
+/// Reads `key=value` pairs, the way a deserializer would read one chunk.
+fn parse(chunk: &str) -> Vec<(&str, &str)> {
+    chunk
+        .split(';')
+        .filter_map(|pair| pair.split_once('='))
+        .collect()
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Owned {
+    name: String,
+    region: String,
+}
+
+#[test]
+fn owned_partial_outlives_each_chunk() -> Result<(), IPanic> {
+    let mut partial = Partial::alloc_owned::<Owned>()?;
+
+    {
+        let chunk = String::from("name=web1");
+        for (key, value) in parse(&chunk) {
+            partial = partial.set_field(key, value.to_string())?;
+        }
+        // `chunk` is dropped here; the partial only holds owned data.
+    }
+    assert_eq!(partial.missing_fields()?, ["region"]);
+
+    let chunk = String::from("region=eu");
+    for (key, value) in parse(&chunk) {
+        partial = partial.set_field(key, value.to_string())?;
+    }
+    drop(chunk);
+
+    let value = partial.build()?.materialize::<Owned>()?;
+    assert_eq!(
+        value,
+        Owned {
+            name: "web1".to_string(),
+            region: "eu".to_string(),
+        }
+    );
+    Ok(())
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Borrowed<'a> {
+    name: &'a str,
+    region: &'a str,
+}
+
+#[test]
+fn borrowing_partial_needs_every_chunk_alive() -> Result<(), IPanic> {
+    // A borrowing partial holds `&'input str`s into the chunks, so both have to
+    // outlive it: dropping `first` before `build` would not compile.
+    let first = String::from("name=web1");
+    let second = String::from("region=eu");
+
+    let mut partial = Partial::alloc::<Borrowed<'_>>()?;
+    for (key, value) in parse(&first) {
+        partial = partial.set_field(key, value)?;
+    }
+    assert_eq!(partial.missing_fields()?, ["region"]);
+    for (key, value) in parse(&second) {
+        partial = partial.set_field(key, value)?;
+    }
+
+    let value = partial.build()?.materialize::<Borrowed<'_>>()?;
+    assert_eq!(
+        value,
+        Borrowed {
+            name: "web1",
+            region: "eu",
+        }
+    );
+    // The built value still points into both chunks.
+    assert!(
+        first
+            .as_bytes()
+            .as_ptr_range()
+            .contains(&value.name.as_ptr())
+    );
+    assert!(
+        second
+            .as_bytes()
+            .as_ptr_range()
+            .contains(&value.region.as_ptr())
+    );
+    Ok(())
+}
@@ -0,0 +1,197 @@
+use std::collections::{BTreeMap, HashSet};
+
+use facet::Facet;
+use facet_reflect::{ChangeSegment, Peek, scalar_changes};
+use facet_testhelpers::test;
+
+#[derive(Facet, Debug)]
+struct Server {
+    host: String,
+    port: u16,
+}
+
+#[derive(Facet, Debug)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Mode {
+    Off,
+    Fixed { rate: u32 },
+    Auto(u32),
+}
+
+#[derive(Facet, Debug)]
+struct Settings {
+    retries: u32,
+    mode: Mode,
+    proxy: Option<String>,
+}
+
+#[derive(Facet, Debug)]
+struct Config {
+    name: String,
+    settings: Settings,
+    servers: Vec<Server>,
+    limits: BTreeMap<String, u32>,
+    tags: HashSet<String>,
+}
+
+fn config() -> Config {
+    Config {
+        name: "api".into(),
+        settings: Settings {
+            retries: 3,
+            mode: Mode::Fixed { rate: 10 },
+            proxy: None,
+        },
+        servers: vec![
+            Server {
+                host: "a.example".into(),
+                port: 80,
+            },
+            Server {
+                host: "b.example".into(),
+                port: 80,
+            },
+        ],
+        limits: BTreeMap::from([("eu".into(), 100), ("us".into(), 200)]),
+        tags: HashSet::from(["prod".into()]),
+    }
+}
+
+/// Renders each change as `path: old -> new`, with `-` for a missing side.
+fn render(old: &Config, new: &Config) -> Vec<String> {
+    let side = |peek: Option<Peek<'_, '_>>| match peek {
+        Some(peek) => format!("{peek}"),
+        None => "-".to_string(),
+    };
+    scalar_changes(Peek::new(old), Peek::new(new))
+        .into_iter()
+        .map(|change| {
+            format!(
+                "{}: {} -> {}",
+                change.path,
+                side(change.old),
+                side(change.new)
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn identical_values_have_no_changes() {
+    assert!(render(&config(), &config()).is_empty());
+}
+
+#[test]
+fn nested_fields_and_enum_payloads() {
+    let old = config();
+    let mut new = config();
+    new.settings.retries = 5;
+    new.settings.mode = Mode::Fixed { rate: 20 };
+    new.servers[1].host = "c.example".into();
+
+    assert_eq!(
+        render(&old, &new),
+        [
+            "settings.retries: 3 -> 5",
+            "settings.mode.rate: 10 -> 20",
+            "servers[1].host: b.example -> c.example",
+        ]
+    );
+}
+
+#[test]
+fn map_entries_by_key() {
+    let old = config();
+    let mut new = config();
+    new.limits.insert("eu".into(), 150);
+    new.limits.remove("us");
+    new.limits.insert("ap".into(), 50);
+
+    assert_eq!(
+        render(&old, &new),
+        [
+            r#"limits["eu"]: 100 -> 150"#,
+            r#"limits["us"]: 200 -> -"#,
+            r#"limits["ap"]: - -> 50"#,
+        ]
+    );
+}
+
+#[test]
+fn extra_list_elements_are_flattened() {
+    let old = config();
+    let mut new = config();
+    new.servers.push(Server {
+        host: "d.example".into(),
+        port: 443,
+    });
+
+    assert_eq!(
+        render(&old, &new),
+        [
+            "servers[2].host: - -> d.example",
+            "servers[2].port: - -> 443",
+        ]
+    );
+
+    assert_eq!(
+        render(&new, &old),
+        [
+            "servers[2].host: d.example -> -",
+            "servers[2].port: 443 -> -",
+        ]
+    );
+}
+
+#[test]
+fn mismatches_are_reported_whole() {
+    let old = config();
+    let mut new = config();
+    new.settings.mode = Mode::Auto(10);
+    new.settings.proxy = Some("http://proxy".into());
+    new.tags.insert("eu".into());
+
+    let changes = scalar_changes(Peek::new(&old), Peek::new(&new));
+    let paths: Vec<String> = changes.iter().map(|c| c.path.to_string()).collect();
+    assert_eq!(paths, ["settings.mode", "settings.proxy", "tags"]);
+
+    let mode = &changes[0];
+    assert_eq!(
+        mode.old
+            .unwrap()
+            .into_enum()
+            .unwrap()
+            .variant_name_active()
+            .unwrap(),
+        "Fixed"
+    );
+    assert_eq!(
+        mode.new
+            .unwrap()
+            .into_enum()
+            .unwrap()
+            .variant_name_active()
+            .unwrap(),
+        "Auto"
+    );
+    assert_eq!(
+        changes[0].path.segments(),
+        [
+            ChangeSegment::Field("settings"),
+            ChangeSegment::Field("mode")
+        ]
+    );
+    let field_names: Vec<&str> = changes[0].fields.iter().map(|f| f.name).collect();
+    assert_eq!(field_names, ["settings", "mode"]);
+}
+
+#[test]
+fn root_scalars() {
+    let changes = scalar_changes(Peek::new(&1u32), Peek::new(&2u32));
+    assert_eq!(changes.len(), 1);
+    assert!(changes[0].path.is_root());
+    assert_eq!(changes[0].path.to_string(), "<root>");
+
+    assert!(scalar_changes(Peek::new(&1u32), Peek::new(&1u32)).is_empty());
+}
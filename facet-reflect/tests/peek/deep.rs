@@ -0,0 +1,126 @@
+use std::collections::{BTreeMap, HashSet};
+
+use facet::Facet;
+use facet_reflect::{Peek, deep_clone, deep_eq};
+use facet_testhelpers::test;
+
+// None of these derive Clone or PartialEq.
+#[derive(Facet, Debug)]
+struct Inner {
+    label: String,
+    weight: f64,
+}
+
+#[derive(Facet, Debug)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Shape {
+    Empty,
+    Circle { radius: u32 },
+    Tagged(Inner),
+}
+
+#[derive(Facet, Debug)]
+struct Document {
+    title: String,
+    shapes: Vec<Shape>,
+    index: BTreeMap<String, Inner>,
+    tags: HashSet<String>,
+    parent: Option<Box<Inner>>,
+    pair: (u8, Inner),
+}
+
+fn sample() -> Document {
+    Document {
+        title: "doc".into(),
+        shapes: vec![
+            Shape::Empty,
+            Shape::Circle { radius: 3 },
+            Shape::Tagged(Inner {
+                label: "t".into(),
+                weight: 0.5,
+            }),
+        ],
+        index: BTreeMap::from([(
+            "a".into(),
+            Inner {
+                label: "a".into(),
+                weight: 1.0,
+            },
+        )]),
+        tags: HashSet::from(["x".into(), "y".into()]),
+        parent: Some(Box::new(Inner {
+            label: "p".into(),
+            weight: 2.0,
+        })),
+        pair: (
+            7,
+            Inner {
+                label: "q".into(),
+                weight: 3.0,
+            },
+        ),
+    }
+}
+
+#[test]
+fn deep_eq_equal_values() {
+    let a = sample();
+    let b = sample();
+    assert!(deep_eq(Peek::new(&a), Peek::new(&b)));
+}
+
+#[test]
+fn deep_eq_detects_nested_differences() {
+    let a = sample();
+
+    let mut b = sample();
+    b.index.get_mut("a").unwrap().weight = 1.5;
+    assert!(!deep_eq(Peek::new(&a), Peek::new(&b)));
+
+    let mut b = sample();
+    b.shapes[1] = Shape::Circle { radius: 4 };
+    assert!(!deep_eq(Peek::new(&a), Peek::new(&b)));
+
+    let mut b = sample();
+    b.shapes[0] = Shape::Circle { radius: 3 };
+    assert!(!deep_eq(Peek::new(&a), Peek::new(&b)));
+
+    let mut b = sample();
+    b.parent = None;
+    assert!(!deep_eq(Peek::new(&a), Peek::new(&b)));
+
+    let mut b = sample();
+    b.tags.insert("z".into());
+    assert!(!deep_eq(Peek::new(&a), Peek::new(&b)));
+}
+
+#[test]
+fn deep_eq_different_shapes() {
+    assert!(!deep_eq(Peek::new(&1u32), Peek::new(&1u64)));
+}
+
+#[test]
+fn deep_clone_round_trips() {
+    let original = sample();
+    let copy: Document = deep_clone(Peek::new(&original))
+        .unwrap()
+        .materialize()
+        .unwrap();
+
+    assert!(deep_eq(Peek::new(&original), Peek::new(&copy)));
+    assert_eq!(format!("{copy:?}"), format!("{original:?}"));
+}
+
+#[test]
+fn deep_clone_is_independent() {
+    let original = sample();
+    let mut copy: Document = deep_clone(Peek::new(&original))
+        .unwrap()
+        .materialize()
+        .unwrap();
+
+    copy.title.push('!');
+    assert_eq!(original.title, "doc");
+    assert!(!deep_eq(Peek::new(&original), Peek::new(&copy)));
+}
@@ -0,0 +1,65 @@
+use std::num::NonZeroU64;
+use std::sync::Arc;
+
+use facet::Facet;
+use facet_reflect::{DisplayOptions, Peek};
+use facet_testhelpers::test;
+
+fn show<'a, T: Facet<'a>>(value: &'a T) -> String {
+    Peek::new(value).as_display().to_string()
+}
+
+fn show_with<'a, T: Facet<'a>>(value: &'a T, options: DisplayOptions) -> String {
+    Peek::new(value).as_display_with(options).to_string()
+}
+
+#[test]
+fn scalars_have_one_spelling() {
+    assert_eq!(show(&42_u64), "42");
+    assert_eq!(show(&-7_i8), "-7");
+    assert_eq!(show(&true), "true");
+    assert_eq!(show(&'é'), "é");
+    assert_eq!(show(&"text"), "text");
+    assert_eq!(show(&String::from("owned")), "owned");
+
+    // f32 is never widened to f64 on the way
+    assert_eq!(show(&0.1_f32), "0.1");
+    assert_eq!(show(&0.1_f64), "0.1");
+    assert_eq!(show(&1e21_f64), "1000000000000000000000");
+}
+
+#[test]
+fn wrappers_are_looked_through() {
+    #[derive(Facet)]
+    #[facet(transparent)]
+    struct Meters(f32);
+
+    assert_eq!(show(&NonZeroU64::new(9).unwrap()), "9");
+    assert_eq!(show(&Arc::<str>::from("shared")), "shared");
+    assert_eq!(show(&Box::new(2.5_f32)), "2.5");
+    assert_eq!(show(&Meters(0.3)), "0.3");
+}
+
+#[test]
+fn options_quote_text_and_fix_precision() {
+    let quoted = DisplayOptions::new().quote_text(true);
+    assert_eq!(show_with(&"a b", quoted), "\"a b\"");
+    assert_eq!(show_with(&'x', quoted), "'x'");
+    assert_eq!(show_with(&3_u8, quoted), "3");
+
+    let cents = DisplayOptions::new().float_precision(Some(2));
+    assert_eq!(show_with(&1.005_f64, cents), "1.00");
+    assert_eq!(show_with(&2.0_f32, cents), "2.00");
+    assert_eq!(show_with(&7_i32, cents), "7");
+}
+
+#[test]
+fn values_without_display_fall_back_to_the_shape() {
+    #[derive(Facet)]
+    #[allow(dead_code)]
+    struct Opaque {
+        x: i32,
+    }
+
+    assert_eq!(show(&Opaque { x: 1 }), "⟨Opaque⟩");
+}
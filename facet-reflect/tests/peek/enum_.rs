@@ -1,7 +1,10 @@
 use core::convert::Infallible;
 
 use facet::Facet;
-use facet_core::Opaque;
+use facet_core::{
+    EnumRepr, EnumType, FieldBuilder, Opaque, Repr, Shape, ShapeBuilder, Type, UserType,
+    VariantBuilder,
+};
 use facet_reflect::Peek;
 use facet_testhelpers::test;
 
@@ -158,3 +161,119 @@ fn option_of_zst_no_segfault() {
     // by reading memory for a type that has size 0
     let _ = peek_enum.discriminant();
 }
+
+#[derive(Facet)]
+#[repr(i16)]
+#[allow(dead_code)]
+enum Level {
+    Low = -10,
+    Mid,
+    High = 300,
+}
+
+#[test]
+fn active_discriminant_reads_repr() {
+    let peek_enum = Peek::new(&Level::Low).into_enum().unwrap();
+    assert_eq!(peek_enum.active_discriminant().unwrap(), -10);
+    assert_eq!(
+        Peek::new(&Level::Mid)
+            .into_enum()
+            .unwrap()
+            .active_discriminant()
+            .unwrap(),
+        -9
+    );
+    assert_eq!(
+        Peek::new(&Level::High)
+            .into_enum()
+            .unwrap()
+            .active_discriminant()
+            .unwrap(),
+        300
+    );
+
+    let repr = peek_enum.enum_repr();
+    assert_eq!(repr.type_name(), Some("i16"));
+    assert_eq!(repr.discriminant_size(), Some(2));
+    assert!(repr.is_signed());
+}
+
+#[test]
+fn active_discriminant_of_option_is_variant_index() {
+    let none: Option<u32> = None;
+    let some: Option<u32> = Some(7);
+    let niche: Option<&u32> = Some(&7);
+
+    assert_eq!(
+        Peek::new(&none)
+            .into_enum()
+            .unwrap()
+            .active_discriminant()
+            .unwrap(),
+        0
+    );
+    assert_eq!(
+        Peek::new(&some)
+            .into_enum()
+            .unwrap()
+            .active_discriminant()
+            .unwrap(),
+        1
+    );
+
+    let niche = Peek::new(&niche).into_enum().unwrap();
+    assert_eq!(niche.active_discriminant().unwrap(), 1);
+    assert_eq!(niche.enum_repr().type_name(), None);
+    assert_eq!(niche.enum_repr().discriminant_size(), None);
+}
+
+/// `#[derive(Facet)]` requires a primitive `#[repr]`, so an enum with
+/// [`EnumRepr::RustNPO`] and declared discriminants has a hand-written shape.
+/// `Found` declares one, `Missing` doesn't.
+#[allow(dead_code)]
+enum Lookup {
+    Found(&'static u32),
+    Missing,
+}
+
+unsafe impl Facet<'_> for Lookup {
+    const SHAPE: &'static Shape = &const {
+        ShapeBuilder::for_sized::<Lookup>("Lookup")
+            .ty(Type::User(UserType::Enum(EnumType {
+                repr: Repr::default(),
+                enum_repr: EnumRepr::RustNPO,
+                variants: &const {
+                    [
+                        VariantBuilder::tuple(
+                            "Found",
+                            &const {
+                                [
+                                    FieldBuilder::new("0", facet_core::shape_of::<&'static u32>, 0)
+                                        .build(),
+                                ]
+                            },
+                        )
+                        .discriminant(200)
+                        .build(),
+                        VariantBuilder::unit("Missing").build(),
+                    ]
+                },
+                is_cow: false,
+            })))
+            .build()
+    };
+}
+
+#[test]
+fn active_discriminant_prefers_declared_discriminant() {
+    static VALUE: u32 = 7;
+
+    let found = Peek::new(&Lookup::Found(&VALUE)).into_enum().unwrap();
+    assert_eq!(found.variant_name_active().unwrap(), "Found");
+    assert_eq!(found.active_discriminant().unwrap(), 200);
+
+    // No declared discriminant: the variant index.
+    let missing = Peek::new(&Lookup::Missing).into_enum().unwrap();
+    assert_eq!(missing.variant_name_active().unwrap(), "Missing");
+    assert_eq!(missing.active_discriminant().unwrap(), 1);
+}
@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hasher};
+
+use facet::Facet;
+use facet_reflect::{Peek, deep_eq, hash_value};
+use facet_testhelpers::test;
+
+fn hash_of<'a, T: Facet<'a>>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    hash_value(Peek::new(value), &mut hasher).unwrap();
+    hasher.finish()
+}
+
+// None of these derive Hash.
+#[derive(Facet)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Mode {
+    Fast,
+    Careful { retries: u8 },
+}
+
+#[derive(Facet)]
+struct Job {
+    name: String,
+    weight: f64,
+    mode: Mode,
+    env: HashMap<String, String>,
+    parent: Option<Box<Job>>,
+    #[facet(skip_serializing)]
+    started_at: u64,
+}
+
+fn job(env: &[(&str, &str)]) -> Job {
+    Job {
+        name: "build".to_string(),
+        weight: 0.0,
+        mode: Mode::Careful { retries: 3 },
+        env: env
+            .iter()
+            .map(|&(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+        parent: None,
+        started_at: 0,
+    }
+}
+
+#[test]
+fn equal_values_hash_equal() {
+    assert_eq!(hash_of(&job(&[("A", "1")])), hash_of(&job(&[("A", "1")])));
+}
+
+#[test]
+fn differing_values_hash_differently() {
+    let base = hash_of(&job(&[]));
+    let mut renamed = job(&[]);
+    renamed.name = "test".to_string();
+    assert_ne!(base, hash_of(&renamed));
+
+    let mut fast = job(&[]);
+    fast.mode = Mode::Fast;
+    assert_ne!(base, hash_of(&fast));
+
+    let mut nested = job(&[]);
+    nested.parent = Some(Box::new(job(&[])));
+    assert_ne!(base, hash_of(&nested));
+}
+
+#[test]
+fn skipped_fields_are_ignored() {
+    let mut later = job(&[]);
+    later.started_at = 1_700_000_000;
+    assert_eq!(hash_of(&job(&[])), hash_of(&later));
+}
+
+#[test]
+fn map_order_does_not_matter() {
+    let entries: Vec<(String, String)> =
+        (0..32).map(|i| (format!("K{i}"), i.to_string())).collect();
+    let forward: HashMap<_, _> = entries.iter().cloned().collect();
+    let mut backward = HashMap::with_capacity(64);
+    backward.extend(entries.iter().rev().cloned());
+    assert_eq!(hash_of(&forward), hash_of(&backward));
+
+    let mut changed = forward.clone();
+    changed.insert("K0".to_string(), "other".to_string());
+    assert_ne!(hash_of(&forward), hash_of(&changed));
+}
+
+#[test]
+fn signed_zero_hashes_like_zero() {
+    let mut negative = job(&[]);
+    negative.weight = -0.0;
+    assert_eq!(hash_of(&job(&[])), hash_of(&negative));
+}
+
+// Compares case-insensitively, which `hash_value` doesn't know about.
+#[derive(Facet, Debug)]
+#[facet(traits(PartialEq))]
+struct Label(String);
+
+impl PartialEq for Label {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_ignore_ascii_case(&other.0)
+    }
+}
+
+#[test]
+fn custom_partial_eq_is_not_consulted() {
+    let lower = Label("release".to_string());
+    let upper = Label("RELEASE".to_string());
+    assert!(deep_eq(Peek::new(&lower), Peek::new(&upper)));
+    assert_ne!(hash_of(&lower), hash_of(&upper));
+    assert_eq!(hash_of(&lower), hash_of(&Label("release".to_string())));
+}
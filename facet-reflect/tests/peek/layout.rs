@@ -0,0 +1,157 @@
+use core::mem::offset_of;
+
+use facet::Facet;
+use facet_core::{
+    FieldBuilder, Repr, Shape, ShapeBuilder, StructKind, StructTypeBuilder, Type, UserType,
+    shape_of,
+};
+use facet_reflect::TypeLayout;
+use facet_testhelpers::test;
+
+#[derive(Facet)]
+#[repr(C)]
+struct Header {
+    tag: u8,
+    length: u32,
+    flags: u16,
+}
+
+#[derive(Facet)]
+#[repr(C)]
+struct Pair(u64, u8);
+
+#[derive(Facet)]
+struct NotC {
+    a: u8,
+    b: u64,
+}
+
+#[test]
+fn layout_matches_repr_c() {
+    let layout = TypeLayout::of(Header::SHAPE).unwrap();
+    assert_eq!((layout.size, layout.align), (12, 4));
+
+    let offsets: Vec<_> = layout
+        .fields
+        .iter()
+        .map(|field| (field.name, field.offset, field.size, field.align))
+        .collect();
+    assert_eq!(
+        offsets,
+        [("tag", 0, 1, 1), ("length", 4, 4, 4), ("flags", 8, 2, 2)]
+    );
+    assert_eq!(layout.field("flags").unwrap().offset, 8);
+    layout.check_repr_c().unwrap();
+}
+
+#[test]
+fn tuple_struct_layout() {
+    let layout = TypeLayout::of(Pair::SHAPE).unwrap();
+    assert_eq!(layout.field("1").unwrap().offset, 8);
+    assert_eq!(layout.size, 16);
+    layout.check_repr_c().unwrap();
+}
+
+#[test]
+fn repr_rust_is_not_repr_c() {
+    let layout = TypeLayout::of(NotC::SHAPE).unwrap();
+    let err = layout.check_repr_c().unwrap_err();
+    assert!(err.to_string().contains("not #[repr(C)]"), "{err}");
+}
+
+#[test]
+fn only_sized_structs_have_a_layout() {
+    assert!(TypeLayout::of(u32::SHAPE).is_err());
+    assert!(TypeLayout::of(<Option<u8>>::SHAPE).is_err());
+}
+
+#[test]
+fn c_defines() {
+    let mut out = String::new();
+    TypeLayout::of(Header::SHAPE)
+        .unwrap()
+        .write_c_defines(&mut out);
+    assert_eq!(
+        out,
+        "#define HEADER_SIZE 12\n\
+         #define HEADER_ALIGN 4\n\
+         #define HEADER_TAG_OFFSET 0\n\
+         #define HEADER_TAG_SIZE 1\n\
+         #define HEADER_LENGTH_OFFSET 4\n\
+         #define HEADER_LENGTH_SIZE 4\n\
+         #define HEADER_FLAGS_OFFSET 8\n\
+         #define HEADER_FLAGS_SIZE 2\n"
+    );
+}
+
+// `#[derive(Facet)]` rejects `align(N)` and `packed`, so these shapes are
+// written by hand.
+
+#[repr(C, align(16))]
+struct Aligned {
+    x: u32,
+}
+
+unsafe impl Facet<'_> for Aligned {
+    const SHAPE: &'static Shape =
+        &const {
+            ShapeBuilder::for_sized::<Aligned>("Aligned")
+                .ty(Type::User(UserType::Struct(
+                    StructTypeBuilder::new(
+                        StructKind::Struct,
+                        &const {
+                            [
+                                FieldBuilder::new("x", shape_of::<u32>, offset_of!(Aligned, x))
+                                    .build(),
+                            ]
+                        },
+                    )
+                    .repr(Repr::c())
+                    .build(),
+                )))
+                .build()
+        };
+}
+
+#[repr(C, packed(2))]
+struct Packed2 {
+    tag: u8,
+    value: u32,
+}
+
+unsafe impl Facet<'_> for Packed2 {
+    const SHAPE: &'static Shape = &const {
+        ShapeBuilder::for_sized::<Packed2>("Packed2")
+            .ty(Type::User(UserType::Struct(
+                StructTypeBuilder::new(
+                    StructKind::Struct,
+                    &const {
+                        [
+                            FieldBuilder::new("tag", shape_of::<u8>, offset_of!(Packed2, tag))
+                                .build(),
+                            FieldBuilder::new("value", shape_of::<u32>, offset_of!(Packed2, value))
+                                .build(),
+                        ]
+                    },
+                )
+                .repr(Repr::c_packed())
+                .build(),
+            )))
+            .build()
+    };
+}
+
+#[test]
+fn over_aligned_struct_is_repr_c() {
+    let layout = TypeLayout::of(Aligned::SHAPE).unwrap();
+    assert_eq!((layout.size, layout.align), (16, 16));
+    layout.check_repr_c().unwrap();
+}
+
+#[test]
+fn packed_n_caps_field_alignment() {
+    let layout = TypeLayout::of(Packed2::SHAPE).unwrap();
+    assert_eq!((layout.size, layout.align), (6, 2));
+    assert_eq!(layout.field("value").unwrap().offset, 2);
+    layout.check_repr_c().unwrap();
+}
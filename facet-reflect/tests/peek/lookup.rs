@@ -0,0 +1,96 @@
+use facet::Facet;
+use facet_reflect::{LookupError, Peek};
+use facet_testhelpers::test;
+use std::collections::HashMap;
+
+#[derive(Facet)]
+struct Config {
+    name: String,
+    #[facet(rename = "listen-port")]
+    port: u16,
+    tls: Option<Tls>,
+    limits: HashMap<String, i64>,
+    backends: Vec<Backend>,
+}
+
+#[derive(Facet)]
+struct Tls {
+    cert: Box<str>,
+}
+
+#[derive(Facet)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Backend {
+    Local { path: String },
+    Remote { url: String, weight: Option<u8> },
+}
+
+fn config() -> Config {
+    Config {
+        name: "api".into(),
+        port: 8080,
+        tls: Some(Tls {
+            cert: "/etc/cert.pem".into(),
+        }),
+        limits: HashMap::from([("rps".to_string(), 500), ("burst".to_string(), -1)]),
+        backends: vec![
+            Backend::Local {
+                path: "/srv".into(),
+            },
+            Backend::Remote {
+                url: "https://b".into(),
+                weight: None,
+            },
+        ],
+    }
+}
+
+#[test]
+fn lookups_navigate_fields_maps_lists_and_variants() {
+    let config = config();
+    let peek = Peek::new(&config);
+
+    assert_eq!(peek.get_str("name").unwrap(), "api");
+    assert_eq!(peek.get_u64("listen-port").unwrap(), 8080);
+    assert_eq!(peek.get_str("tls.cert").unwrap(), "/etc/cert.pem");
+    assert_eq!(peek.get_i64("limits.burst").unwrap(), -1);
+    assert_eq!(peek.get_f64("limits.rps").unwrap(), 500.0);
+    assert_eq!(peek.get_str("backends.0.path").unwrap(), "/srv");
+    assert_eq!(peek.get_str("backends.1.url").unwrap(), "https://b");
+    assert_eq!(
+        peek.get_path_as::<Option<u8>>("backends.1.weight").unwrap(),
+        None
+    );
+    assert_eq!(peek.lookup("").unwrap().shape(), Peek::new(&config).shape());
+}
+
+#[test]
+fn lookup_errors_name_the_path() {
+    let config = config();
+    let peek = Peek::new(&config);
+
+    let err = peek.get_str("backends.2.url").unwrap_err();
+    assert!(
+        matches!(&err, LookupError::Missing { path } if path == "backends.2"),
+        "{err}"
+    );
+
+    let err = peek.get_str("name.first").unwrap_err();
+    assert!(
+        matches!(&err, LookupError::NotAContainer { path, .. } if path == "name"),
+        "{err}"
+    );
+
+    let err = peek.get_str("listen-port").unwrap_err();
+    assert!(matches!(err, LookupError::WrongType { .. }), "{err}");
+
+    let err = peek.get_path_as::<u8>("limits.rps").unwrap_err();
+    assert!(
+        matches!(&err, LookupError::Convert { path, .. } if path == "limits.rps"),
+        "{err}"
+    );
+
+    let err = peek.get_bool("backends.1.weight").unwrap_err();
+    assert!(matches!(err, LookupError::Missing { .. }), "{err}");
+}
@@ -1,9 +1,15 @@
 mod at_path;
+mod changes;
 mod covariance;
+mod deep;
+mod display;
 mod dst;
 mod enum_;
+mod hash;
+mod layout;
 mod list;
 mod list_like;
+mod lookup;
 mod map;
 mod ndarray;
 mod option;
@@ -14,3 +20,4 @@ mod serialize;
 mod set;
 mod struct_;
 mod value;
+mod walk;
@@ -1,6 +1,6 @@
 use facet_testhelpers::{IPanic, test};
 
-use facet::{Facet, Opaque};
+use facet::{Facet, FieldFlags, Opaque, Type, UserType};
 use facet_reflect::{HasFields, Peek, ReflectErrorKind};
 
 #[test]
@@ -475,3 +475,80 @@ fn peek_custom_serialize_zst() -> Result<(), IPanic> {
 
     Ok(())
 }
+
+#[test]
+fn fields_for_serialize_follows_field_order() {
+    #[derive(Facet)]
+    struct Upload {
+        #[facet(order = 1)]
+        data: Vec<u8>,
+        name: &'static str,
+        #[facet(order = -1)]
+        id: u32,
+    }
+
+    let upload = Upload {
+        data: vec![1],
+        name: "a",
+        id: 7,
+    };
+    let peek_struct = Peek::new(&upload).into_struct().unwrap();
+
+    let names: Vec<_> = peek_struct
+        .fields_for_serialize()
+        .map(|(item, _)| item.name)
+        .collect();
+    assert_eq!(names, ["id", "name", "data"]);
+
+    // Positional formats keep declaration order
+    let names: Vec<_> = peek_struct
+        .fields_for_binary_serialize()
+        .map(|(item, _)| item.name)
+        .collect();
+    assert_eq!(names, ["data", "name", "id"]);
+
+    // The values still belong to their fields
+    let (_, id) = peek_struct.fields_for_serialize().next().unwrap();
+    assert_eq!(*id.get::<u32>().unwrap(), 7);
+}
+
+#[test]
+fn field_order_is_flagged_at_derive_time() {
+    #[derive(Facet)]
+    struct Plain {
+        a: u32,
+        b: u32,
+    }
+
+    #[derive(Facet)]
+    struct Ordered {
+        a: u32,
+        #[facet(order = -1)]
+        b: u32,
+    }
+
+    #[derive(Facet)]
+    #[repr(u8)]
+    #[allow(dead_code)]
+    enum Event {
+        Ping,
+        Upload {
+            path: String,
+            #[facet(order = -1)]
+            id: u32,
+        },
+    }
+
+    assert!(!Plain::SHAPE.has_ordered_fields());
+    assert!(Ordered::SHAPE.has_ordered_fields());
+    assert!(Event::SHAPE.has_ordered_fields());
+
+    // Fields without `order` answer without an attribute lookup
+    let Type::User(UserType::Struct(st)) = Ordered::SHAPE.ty else {
+        unreachable!()
+    };
+    assert!(!st.fields[0].flags.contains(FieldFlags::ORDERED));
+    assert_eq!(st.fields[0].order(), 0);
+    assert!(st.fields[1].flags.contains(FieldFlags::ORDERED));
+    assert_eq!(st.fields[1].order(), -1);
+}
@@ -0,0 +1,212 @@
+use std::collections::BTreeMap;
+use std::ops::ControlFlow;
+
+use facet::Facet;
+use facet_reflect::{
+    Peek, PeekEdge, PeekEnum, PeekListLike, PeekMap, PeekStruct, PeekVisitor, VisitControl, walk,
+};
+use facet_testhelpers::test;
+
+/// Records every hook as a line like `enter struct root` or `scalar port = 80`.
+#[derive(Default)]
+struct Trace {
+    events: Vec<String>,
+    skip_lists: bool,
+    stop_after_scalars: Option<usize>,
+}
+
+fn label(edge: PeekEdge<'_, '_>) -> String {
+    match edge {
+        PeekEdge::Root => "root".to_string(),
+        PeekEdge::Field { field, .. } => field.name.to_string(),
+        PeekEdge::Index(index) => format!("[{index}]"),
+        PeekEdge::MapKey => "key".to_string(),
+        PeekEdge::MapValue(key) => format!("[{key}]"),
+        PeekEdge::Some => "some".to_string(),
+        PeekEdge::Ok => "ok".to_string(),
+        PeekEdge::Err => "err".to_string(),
+        PeekEdge::Pointee => "pointee".to_string(),
+    }
+}
+
+impl<'mem, 'facet> PeekVisitor<'mem, 'facet> for Trace {
+    fn enter_struct(
+        &mut self,
+        _: PeekStruct<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        self.events.push(format!("enter struct {}", label(edge)));
+        VisitControl::Continue
+    }
+
+    fn leave_struct(
+        &mut self,
+        _: PeekStruct<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        self.events.push(format!("leave struct {}", label(edge)));
+        VisitControl::Continue
+    }
+
+    fn enter_enum(
+        &mut self,
+        value: PeekEnum<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        let variant = value.variant_name_active().unwrap();
+        self.events
+            .push(format!("enter enum {} {variant}", label(edge)));
+        VisitControl::Continue
+    }
+
+    fn leave_enum(
+        &mut self,
+        _: PeekEnum<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        self.events.push(format!("leave enum {}", label(edge)));
+        VisitControl::Continue
+    }
+
+    fn enter_list(
+        &mut self,
+        _: PeekListLike<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        self.events.push(format!("enter list {}", label(edge)));
+        if self.skip_lists {
+            VisitControl::SkipChildren
+        } else {
+            VisitControl::Continue
+        }
+    }
+
+    fn leave_list(
+        &mut self,
+        _: PeekListLike<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        self.events.push(format!("leave list {}", label(edge)));
+        VisitControl::Continue
+    }
+
+    fn enter_map(
+        &mut self,
+        _: PeekMap<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        self.events.push(format!("enter map {}", label(edge)));
+        VisitControl::Continue
+    }
+
+    fn leave_map(
+        &mut self,
+        _: PeekMap<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        self.events.push(format!("leave map {}", label(edge)));
+        VisitControl::Continue
+    }
+
+    fn visit_scalar(
+        &mut self,
+        value: Peek<'mem, 'facet>,
+        edge: PeekEdge<'mem, 'facet>,
+    ) -> VisitControl {
+        self.events
+            .push(format!("scalar {} = {value:?}", label(edge)));
+        match &mut self.stop_after_scalars {
+            Some(0) => VisitControl::Stop,
+            Some(remaining) => {
+                *remaining -= 1;
+                VisitControl::Continue
+            }
+            None => VisitControl::Continue,
+        }
+    }
+}
+
+#[derive(Facet)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Protocol {
+    Tcp { port: u16 },
+    Unix,
+}
+
+#[derive(Facet)]
+struct Server {
+    host: String,
+    ports: Vec<u16>,
+    backup: Option<u16>,
+    fallback: Option<u16>,
+    protocol: Protocol,
+    labels: BTreeMap<String, u8>,
+}
+
+fn server() -> Server {
+    Server {
+        host: "localhost".to_string(),
+        ports: vec![80, 443],
+        backup: Some(8080),
+        fallback: None,
+        protocol: Protocol::Tcp { port: 22 },
+        labels: BTreeMap::from([("tier".to_string(), 1)]),
+    }
+}
+
+#[test]
+fn walk_visits_in_document_order() {
+    let server = server();
+    let mut trace = Trace::default();
+    assert_eq!(
+        walk(Peek::new(&server), &mut trace),
+        ControlFlow::Continue(())
+    );
+    assert_eq!(
+        trace.events,
+        vec![
+            "enter struct root",
+            "scalar host = \"localhost\"",
+            "enter list ports",
+            "scalar [0] = 80",
+            "scalar [1] = 443",
+            "leave list ports",
+            "scalar some = 8080",
+            "scalar fallback = None",
+            "enter enum protocol Tcp",
+            "scalar port = 22",
+            "leave enum protocol",
+            "enter map labels",
+            "scalar key = \"tier\"",
+            "scalar [tier] = 1",
+            "leave map labels",
+            "leave struct root",
+        ]
+    );
+}
+
+#[test]
+fn walk_skip_children_still_leaves() {
+    let server = server();
+    let mut trace = Trace {
+        skip_lists: true,
+        ..Trace::default()
+    };
+    let _ = walk(Peek::new(&server), &mut trace);
+    assert!(trace.events.contains(&"enter list ports".to_string()));
+    assert!(trace.events.contains(&"leave list ports".to_string()));
+    assert!(!trace.events.iter().any(|event| event.contains("[0]")));
+}
+
+#[test]
+fn walk_stop_ends_the_walk() {
+    let server = server();
+    let mut trace = Trace {
+        stop_after_scalars: Some(1),
+        ..Trace::default()
+    };
+    assert_eq!(walk(Peek::new(&server), &mut trace), ControlFlow::Break(()));
+    assert_eq!(trace.events.last().unwrap(), "scalar [0] = 80");
+    assert!(!trace.events.iter().any(|event| event.starts_with("leave")));
+}
@@ -2,4 +2,5 @@ mod at_path;
 mod enum_;
 mod struct_;
 mod tuple;
+mod update;
 mod value;
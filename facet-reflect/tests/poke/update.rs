@@ -0,0 +1,138 @@
+use facet::Facet;
+use facet_path::{Path, PathStep};
+use facet_reflect::{Poke, ReflectErrorKind, Updates};
+use facet_testhelpers::test;
+
+#[derive(Facet, Debug, PartialEq)]
+#[facet(invariants = Range::invariants)]
+struct Range {
+    min: u32,
+    max: u32,
+    label: String,
+}
+
+impl Range {
+    fn invariants(&self) -> bool {
+        self.min <= self.max
+    }
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Settings {
+    range: Range,
+    tags: Vec<String>,
+}
+
+fn path(steps: &[PathStep]) -> Path {
+    let mut path = Path::new(Settings::SHAPE);
+    for step in steps {
+        path.push(*step);
+    }
+    path
+}
+
+fn settings() -> Settings {
+    Settings {
+        range: Range {
+            min: 0,
+            max: 10,
+            label: "small".into(),
+        },
+        tags: vec!["a".into(), "b".into()],
+    }
+}
+
+#[test]
+fn update_many_applies_all_updates() {
+    let mut value = settings();
+    // Moving both bounds past the old max would break the invariant halfway
+    // through if the updates were checked one at a time.
+    let updates = Updates::new()
+        .set(path(&[PathStep::Field(0), PathStep::Field(0)]), 20u32)
+        .set(path(&[PathStep::Field(0), PathStep::Field(1)]), 30u32)
+        .set(
+            path(&[PathStep::Field(1), PathStep::Index(1)]),
+            String::from("z"),
+        );
+    assert_eq!(updates.len(), 3);
+
+    Poke::new(&mut value).update_many(updates).unwrap();
+    assert_eq!(
+        value,
+        Settings {
+            range: Range {
+                min: 20,
+                max: 30,
+                label: "small".into(),
+            },
+            tags: vec!["a".into(), "z".into()],
+        }
+    );
+}
+
+#[test]
+fn update_many_rolls_back_when_invariants_fail() {
+    let mut value = settings();
+    let updates = Updates::new()
+        .set(
+            path(&[PathStep::Field(0), PathStep::Field(2)]),
+            String::from("big"),
+        )
+        .set(path(&[PathStep::Field(0), PathStep::Field(0)]), 50u32);
+
+    let err = Poke::new(&mut value).update_many(updates).unwrap_err();
+    assert!(matches!(
+        err.kind,
+        ReflectErrorKind::UserInvariantFailed { .. }
+    ));
+    assert_eq!(value, settings());
+}
+
+#[test]
+fn update_many_checks_every_update_before_writing() {
+    let mut value = settings();
+
+    let updates = Updates::new()
+        .set(path(&[PathStep::Field(0), PathStep::Field(0)]), 5u32)
+        .set(path(&[PathStep::Field(0), PathStep::Field(1)]), 5i64);
+    let err = Poke::new(&mut value).update_many(updates).unwrap_err();
+    assert!(matches!(err.kind, ReflectErrorKind::WrongShape { .. }));
+
+    let updates = Updates::new()
+        .set(path(&[PathStep::Field(0), PathStep::Field(0)]), 5u32)
+        .set(
+            path(&[PathStep::Field(1), PathStep::Index(7)]),
+            String::from("x"),
+        );
+    let err = Poke::new(&mut value).update_many(updates).unwrap_err();
+    assert!(matches!(
+        err.kind,
+        ReflectErrorKind::OperationFailedOwned { .. }
+    ));
+
+    let updates = Updates::new()
+        .set(path(&[PathStep::Field(1)]), Vec::<String>::new())
+        .set(
+            path(&[PathStep::Field(1), PathStep::Index(0)]),
+            String::from("x"),
+        );
+    let err = Poke::new(&mut value).update_many(updates).unwrap_err();
+    assert!(matches!(
+        err.kind,
+        ReflectErrorKind::InvalidOperation { .. }
+    ));
+
+    assert_eq!(value, settings());
+}
+
+#[test]
+fn update_many_same_path_last_wins() {
+    let mut value = settings();
+    let label = path(&[PathStep::Field(0), PathStep::Field(2)]);
+    let updates = Updates::new()
+        .set(label.clone(), String::from("first"))
+        .set(label, String::from("second"));
+
+    Poke::new(&mut value).update_many(updates).unwrap();
+    assert_eq!(value.range.label, "second");
+}
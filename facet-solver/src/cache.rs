@@ -0,0 +1,122 @@
+//! Process-wide cache of built schemas.
+
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+
+use facet_core::{ChildDefaults, Shape};
+
+use crate::{Format, Schema, SchemaError, SchemaOptions};
+
+/// A cache of schemas built with [`Schema::build_with_options`], keyed by
+/// shape, format and the defaults the shape inherits.
+///
+/// Building a schema enumerates every combination of variants of flattened
+/// enums, which is wasted work when the same type is deserialized over and
+/// over. Shapes are `'static` and never change, so a schema built once can be
+/// shared by every later deserialization of the type.
+///
+/// Most code uses the process-wide cache through [`Schema::cached`] or
+/// [`SchemaCache::global`]. Entries live as long as the cache; call
+/// [`SchemaCache::clear`] to drop them.
+///
+/// Entries are keyed by the shape's address. The same type can occasionally
+/// have more than one shape (e.g. across crates), which costs an extra build
+/// but never returns a schema for the wrong type.
+#[derive(Debug, Default)]
+pub struct SchemaCache {
+    schemas: RwLock<HashMap<(usize, Format, Option<ChildDefaults>), Arc<Schema>>>,
+}
+
+impl SchemaCache {
+    /// Create an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The process-wide cache.
+    pub fn global() -> &'static Self {
+        static GLOBAL: OnceLock<SchemaCache> = OnceLock::new();
+        GLOBAL.get_or_init(Self::new)
+    }
+
+    /// Return the schema for `shape` in `format`, building it on first use.
+    ///
+    /// Build errors are returned as-is and not cached.
+    pub fn get_or_build(
+        &self,
+        shape: &'static Shape,
+        format: Format,
+    ) -> Result<Arc<Schema>, SchemaError> {
+        self.get_or_build_inheriting(shape, format, None)
+    }
+
+    /// Like [`Self::get_or_build`], for a shape reached under the
+    /// `#[facet(defaults_for_children)]` of the containers above it
+    /// (see [`SchemaOptions::inherited`]).
+    pub fn get_or_build_inheriting(
+        &self,
+        shape: &'static Shape,
+        format: Format,
+        inherited: Option<ChildDefaults>,
+    ) -> Result<Arc<Schema>, SchemaError> {
+        let key = (core::ptr::from_ref(shape).addr(), format, inherited);
+        if let Some(schema) = self
+            .schemas
+            .read()
+            .unwrap_or_else(|poison| poison.into_inner())
+            .get(&key)
+        {
+            return Ok(schema.clone());
+        }
+
+        // Build without holding the lock; if another thread got there first,
+        // keep its schema so every caller shares one instance.
+        let options = SchemaOptions::new().format(format).inherited(inherited);
+        let schema = Arc::new(Schema::build_with_options(shape, options)?);
+        Ok(self
+            .schemas
+            .write()
+            .unwrap_or_else(|poison| poison.into_inner())
+            .entry(key)
+            .or_insert(schema)
+            .clone())
+    }
+
+    /// Number of cached schemas.
+    pub fn len(&self) -> usize {
+        self.schemas
+            .read()
+            .unwrap_or_else(|poison| poison.into_inner())
+            .len()
+    }
+
+    /// Returns true if no schema is cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drop every cached schema. Schemas already handed out stay valid.
+    pub fn clear(&self) {
+        self.schemas
+            .write()
+            .unwrap_or_else(|poison| poison.into_inner())
+            .clear();
+    }
+}
+
+impl Schema {
+    /// Like [`Schema::build_auto`], but returns a schema shared through the
+    /// process-wide [`SchemaCache`], so it is only built once per type.
+    pub fn cached(shape: &'static Shape) -> Result<Arc<Schema>, SchemaError> {
+        SchemaCache::global().get_or_build(shape, Format::Flat)
+    }
+
+    /// Like [`Schema::cached`], for a shape reached under the
+    /// `#[facet(defaults_for_children)]` of the containers above it.
+    pub fn cached_inheriting(
+        shape: &'static Shape,
+        inherited: Option<ChildDefaults>,
+    ) -> Result<Arc<Schema>, SchemaError> {
+        SchemaCache::global().get_or_build_inheriting(shape, Format::Flat, inherited)
+    }
+}
@@ -265,20 +265,28 @@ use alloc::vec;
 use alloc::vec::Vec;
 use core::fmt;
 
-use facet_core::{Def, Field, Shape, StructType, Type, UserType, Variant};
+use facet_core::{ChildDefaults, Def, Field, Shape, StructType, Type, UserType, Variant};
 
 // Re-export resolution types from facet-reflect
 pub use facet_reflect::{
-    DuplicateFieldError, FieldCategory, FieldInfo, FieldKey, FieldPath, KeyPath, MatchResult,
-    PathSegment, Resolution, VariantSelection,
+    DuplicateFieldError, DynamicPrefix, FieldCategory, FieldInfo, FieldKey, FieldPath, KeyPath,
+    MatchResult, PathSegment, Resolution, VariantSelection,
 };
 
+mod superset;
+pub use superset::{IntegerRange, ValueSuperset};
+
+#[cfg(feature = "std")]
+mod cache;
+#[cfg(feature = "std")]
+pub use cache::SchemaCache;
+
 /// Format determines how fields are categorized and indexed in the schema.
 ///
 /// Different serialization formats have different concepts of "fields":
 /// - Flat formats (JSON, TOML, YAML) treat all fields as key-value pairs
 /// - DOM formats (XML, HTML) distinguish attributes, elements, and text content
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 #[non_exhaustive]
 pub enum Format {
     /// Flat key-value formats (JSON, TOML, YAML, etc.)
@@ -296,19 +304,123 @@ pub enum Format {
     Dom,
 }
 
+/// How the solver matches input keys against field names.
+///
+/// Config files written by hand often mix `maxConnections`, `max_connections`
+/// and `max-connections`. With a normalizing strategy, the solver compares keys
+/// and field names after canonicalizing both, so any of those spellings finds
+/// the `max_connections` field. Error messages still show the key as it was
+/// written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum KeyNormalization {
+    /// Keys must match serialized field names exactly.
+    #[default]
+    Exact,
+    /// Keys match regardless of ASCII case (`MaxConnections` finds `maxconnections`).
+    CaseInsensitive,
+    /// Keys match regardless of ASCII case, `_` and `-`
+    /// (`maxConnections`, `max_connections` and `max-connections` are the same key).
+    CaseAndSeparatorInsensitive,
+}
+
+impl KeyNormalization {
+    /// Canonicalize `key` under this strategy, or return `None` for [`KeyNormalization::Exact`].
+    pub fn normalize(self, key: &str) -> Option<String> {
+        match self {
+            KeyNormalization::Exact => None,
+            KeyNormalization::CaseInsensitive => Some(key.to_ascii_lowercase()),
+            KeyNormalization::CaseAndSeparatorInsensitive => Some(
+                key.chars()
+                    .filter(|c| !matches!(c, '_' | '-'))
+                    .map(|c| c.to_ascii_lowercase())
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// How many resolutions a schema may have before building it fails with
+/// [`SchemaError::TooManyResolutions`].
+///
+/// Every flattened enum multiplies the resolution count by its number of
+/// variants, so a handful of them is enough to make building take seconds.
+pub const DEFAULT_MAX_RESOLUTIONS: usize = 4096;
+
+/// Options for [`Schema::build_with_options`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SchemaOptions {
+    /// The format to build the schema for.
+    pub format: Format,
+    /// How input keys are matched against field names.
+    pub key_normalization: KeyNormalization,
+    /// Upper bound on the number of resolutions, see [`DEFAULT_MAX_RESOLUTIONS`].
+    pub max_resolutions: usize,
+    /// `#[facet(defaults_for_children)]` the shape inherits from the
+    /// containers it is reached from.
+    pub inherited: Option<ChildDefaults>,
+}
+
+impl Default for SchemaOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SchemaOptions {
+    /// Default options: flat format, exact key matching, and
+    /// [`DEFAULT_MAX_RESOLUTIONS`].
+    pub const fn new() -> Self {
+        Self {
+            format: Format::Flat,
+            key_normalization: KeyNormalization::Exact,
+            max_resolutions: DEFAULT_MAX_RESOLUTIONS,
+            inherited: None,
+        }
+    }
+
+    /// Set the maximum number of resolutions.
+    pub const fn max_resolutions(mut self, max_resolutions: usize) -> Self {
+        self.max_resolutions = max_resolutions;
+        self
+    }
+
+    /// Set the format.
+    pub const fn format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Set the key normalization strategy.
+    pub const fn key_normalization(mut self, key_normalization: KeyNormalization) -> Self {
+        self.key_normalization = key_normalization;
+        self
+    }
+
+    /// Set the defaults the shape inherits, which rename its fields (and
+    /// those of the structs below it) the way serializers do.
+    pub const fn inherited(mut self, inherited: Option<ChildDefaults>) -> Self {
+        self.inherited = inherited;
+        self
+    }
+}
+
 /// Cached schema for a type that may contain flattened fields.
 ///
 /// This is computed once per Shape and can be cached forever since
 /// type information is static.
 #[derive(Debug)]
 pub struct Schema {
-    /// The shape this schema is for (kept for future caching key)
-    #[allow(dead_code)]
+    /// The shape this schema is for.
     shape: &'static Shape,
 
     /// The format this schema was built for.
     format: Format,
 
+    /// The defaults the shape was analyzed under.
+    inherited: Option<ChildDefaults>,
+
     /// All possible resolutions of this type.
     /// For types with no enums in flatten paths, this has exactly 1 entry.
     /// For types with enums, this has one entry per valid combination of variants.
@@ -316,12 +428,31 @@ pub struct Schema {
 
     /// Inverted index for Flat format: field_name → bitmask of configuration indices.
     /// Bit i is set if `resolutions[i]` contains this field.
+    /// Field aliases are indexed alongside the serialized names.
     /// Uses a `Vec<u64>` to support arbitrary numbers of resolutions.
     field_to_resolutions: BTreeMap<&'static str, ResolutionSet>,
 
     /// Inverted index for Dom format: (category, name) → bitmask of configuration indices.
     /// Only populated when format is Dom.
     dom_field_to_resolutions: BTreeMap<(FieldCategory, &'static str), ResolutionSet>,
+
+    /// Resolutions with a flattened map (or dynamic value) that takes any key
+    /// no field claims, per category. These resolutions are also merged into
+    /// every entry of the inverted indexes above, so a key that is a field in
+    /// one resolution doesn't rule out the resolutions that would sink it.
+    catch_all_resolutions: BTreeMap<FieldCategory, ResolutionSet>,
+
+    /// Alias → serialized name, for fields with `#[facet(alias = "...")]`.
+    /// Aliases that are also the serialized name of some field are left out,
+    /// so a field's real name always wins.
+    aliases: BTreeMap<&'static str, &'static str>,
+
+    /// How input keys are matched against field names.
+    key_normalization: KeyNormalization,
+
+    /// Normalized field name or alias → serialized field name.
+    /// Empty when `key_normalization` is `Exact`.
+    normalized_names: BTreeMap<String, &'static str>,
 }
 
 /// Handle that identifies a specific resolution inside a schema.
@@ -404,6 +535,15 @@ impl ResolutionSet {
         }
     }
 
+    /// Add every configuration of another set, in place.
+    fn union_with(&mut self, other: &ResolutionSet) {
+        self.count = 0;
+        for (a, b) in self.bits.iter_mut().zip(other.bits.iter()) {
+            *a |= *b;
+            self.count += a.count_ones() as usize;
+        }
+    }
+
     /// Intersect with another config set in place.
     fn intersect_with(&mut self, other: &ResolutionSet) {
         self.count = 0;
@@ -423,12 +563,12 @@ impl ResolutionSet {
     }
 
     /// Get the number of resolutions in the set.
-    const fn len(&self) -> usize {
+    pub const fn len(&self) -> usize {
         self.count
     }
 
     /// Check if empty.
-    const fn is_empty(&self) -> bool {
+    pub const fn is_empty(&self) -> bool {
         self.count == 0
     }
 
@@ -442,8 +582,15 @@ impl ResolutionSet {
         None
     }
 
-    /// Iterate over configuration indices in the set.
-    fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+    /// Check whether the resolution at index `idx` is in the set.
+    pub fn contains(&self, idx: usize) -> bool {
+        self.bits
+            .get(idx / 64)
+            .is_some_and(|word| word & (1u64 << (idx % 64)) != 0)
+    }
+
+    /// Iterate over resolution indices in the set, lowest first.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
         self.bits.iter().enumerate().flat_map(|(word_idx, &word)| {
             (0..64).filter_map(move |bit| {
                 if word & (1u64 << bit) != 0 {
@@ -520,8 +667,53 @@ pub struct CandidateFailure {
     /// Fields in the input that don't exist in this candidate
     pub unknown_fields: Vec<String>,
     /// Number of unknown fields that have "did you mean?" suggestions for this candidate
-    /// Higher = more likely the user intended this variant
     pub suggestion_matches: usize,
+    /// How plausibly the user intended this candidate; failures are sorted by it
+    pub score: CandidateScore,
+}
+
+/// How closely the input fits one candidate, used to rank candidates when
+/// nothing matches.
+///
+/// Candidates are ordered by [`weighted`](Self::weighted), highest first:
+/// each required field present in the input counts for the candidate, each
+/// input key it has no field for counts against it, and a key that looks
+/// like a misspelling of one of its fields wins back part of that penalty,
+/// in proportion to how similar the two are. Ties go to the candidate
+/// missing fewer required fields, then to the one listed first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct CandidateScore {
+    /// Required fields of the candidate that the input provides
+    pub matched_required: usize,
+    /// Required fields of the candidate that the input lacks
+    pub missing_required: usize,
+    /// Input keys the candidate has no field (or catch-all map) for
+    pub unmatched_keys: usize,
+    /// Sum, over the unmatched keys, of the similarity to the closest field
+    /// of the candidate, in percent (0 when nothing is similar enough, or
+    /// without the `suggestions` feature)
+    pub similarity: usize,
+}
+
+impl CandidateScore {
+    /// Weight of each required field the input provides.
+    pub const MATCHED_REQUIRED_WEIGHT: i64 = 100;
+    /// Weight of each input key the candidate doesn't know.
+    pub const UNMATCHED_KEY_WEIGHT: i64 = 50;
+
+    /// The combined score. Higher means the user more plausibly meant this
+    /// candidate.
+    pub fn weighted(&self) -> i64 {
+        self.matched_required as i64 * Self::MATCHED_REQUIRED_WEIGHT
+            - self.unmatched_keys as i64 * Self::UNMATCHED_KEY_WEIGHT
+            + self.similarity as i64
+    }
+
+    /// Sort key: weighted score, then fewer missing required fields.
+    fn closeness(&self) -> (i64, core::cmp::Reverse<usize>) {
+        (self.weighted(), core::cmp::Reverse(self.missing_required))
+    }
 }
 
 /// Suggestion for a field that might have been misspelled.
@@ -542,6 +734,40 @@ pub struct FieldSuggestion {
 pub enum SchemaError {
     /// A field name appears from multiple sources (parent struct and flattened struct)
     DuplicateField(DuplicateFieldError),
+    /// Two different field names are the same key under the requested
+    /// [`KeyNormalization`], e.g. `max_connections` and `maxConnections`.
+    NormalizedKeyCollision {
+        /// The normalized key both names map to
+        normalized: String,
+        /// The first field name
+        first: &'static str,
+        /// The second field name
+        second: &'static str,
+    },
+    /// The same alias names differently named fields, e.g. in two variants
+    /// of a flattened enum, so a key spelled that way has no single field.
+    AliasCollision {
+        /// The alias both fields declare
+        alias: &'static str,
+        /// The first field's serialized name
+        first: &'static str,
+        /// The second field's serialized name
+        second: &'static str,
+    },
+    /// The combinations of flattened enum variants exceed the resolution budget.
+    TooManyResolutions {
+        /// The budget that was exceeded
+        limit: usize,
+        /// The flattened type whose expansion went over it
+        type_name: &'static str,
+    },
+    /// A `#[facet(flatten_prefix_from = "...")]` field can't be prefixed.
+    InvalidFlattenPrefix {
+        /// The flattened field
+        field: &'static str,
+        /// What's wrong with it
+        reason: &'static str,
+    },
 }
 
 impl From<DuplicateFieldError> for SchemaError {
@@ -562,6 +788,41 @@ impl fmt::Display for SchemaError {
                     err.field_name, err.first_path, err.second_path
                 )
             }
+            SchemaError::NormalizedKeyCollision {
+                normalized,
+                first,
+                second,
+            } => {
+                write!(
+                    f,
+                    "Fields '{first}' and '{second}' both match key '{normalized}' \
+                     under the requested key normalization."
+                )
+            }
+            SchemaError::AliasCollision {
+                alias,
+                first,
+                second,
+            } => {
+                write!(
+                    f,
+                    "Fields '{first}' and '{second}' both declare the alias '{alias}'."
+                )
+            }
+            SchemaError::TooManyResolutions { limit, type_name } => {
+                write!(
+                    f,
+                    "Flattening '{type_name}' makes more than {limit} combinations of \
+                     enum variants. Flatten fewer enums, or raise the limit with \
+                     SchemaOptions::max_resolutions."
+                )
+            }
+            SchemaError::InvalidFlattenPrefix { field, reason } => {
+                write!(
+                    f,
+                    "Cannot prefix the keys of flattened field '{field}': {reason}"
+                )
+            }
         }
     }
 }
@@ -686,25 +947,91 @@ impl std::error::Error for SolverError {}
 /// This is used to disambiguate when a value could satisfy multiple types.
 /// For example, the value `42` fits both `u8` and `u16`, but `u8` is more
 /// specific (lower score), so it should be preferred.
-/// Compute a specificity score for a shape.
 ///
-/// Lower score = more specific type. Used for type-based disambiguation
-/// where we want to try more specific types first (e.g., u8 before u16).
+/// From most to least specific:
+///
+/// | Score | Types |
+/// |-------|-------|
+/// | 0 | `()` |
+/// | 1 | `bool` |
+/// | 8–128 | integers and floats, by width (`usize`/`isize` count as 64 bits) |
+/// | 200 | `char` |
+/// | 300 | other scalars that parse from a validated string: dates and times, UUIDs, network addresses, ... |
+/// | 400 | paths (`PathBuf`, `Utf8PathBuf`, ...) |
+/// | 500 | strings |
+/// | 600 | fixed-size arrays and tuples |
+/// | 650 | sets |
+/// | 700 | lists |
+/// | 800 | maps |
+/// | 900 | structs |
+/// | 950 | enums |
+/// | 1000 | anything else |
+///
+/// `Option`s, smart pointers and transparent wrappers score as the type they
+/// wrap. Formats that know better (e.g. TOML, which has native datetimes) can
+/// override scores with [`Solver::with_score_fn`].
 pub fn specificity_score(shape: &'static Shape) -> u64 {
-    // Use type_identifier to determine specificity
-    // Smaller integer types are more specific
-    match shape.type_identifier {
-        "u8" | "i8" => 8,
-        "u16" | "i16" => 16,
-        "u32" | "i32" | "f32" => 32,
-        "u64" | "i64" | "f64" => 64,
-        "u128" | "i128" => 128,
-        "usize" | "isize" => 64, // Treat as 64-bit
-        // Other types get a high score (less specific)
+    use facet_core::{ScalarKind, ScalarType, StructKind};
+
+    match &shape.def {
+        Def::Option(opt) => return specificity_score(opt.t),
+        Def::Pointer(ptr) => {
+            if let Some(pointee) = ptr.pointee {
+                return specificity_score(pointee);
+            }
+        }
+        Def::Array(_) => return 600,
+        Def::Set(_) => return 650,
+        Def::List(_) | Def::Slice(_) => return 700,
+        Def::Map(_) => return 800,
+        Def::Scalar => {
+            if shape.scalar_type() == Some(ScalarType::Unit) {
+                return 0;
+            }
+            return match shape.scalar_kind() {
+                Some(ScalarKind::Bool) => 1,
+                Some(ScalarKind::U8 | ScalarKind::I8) => 8,
+                Some(ScalarKind::U16 | ScalarKind::I16) => 16,
+                Some(ScalarKind::U32 | ScalarKind::I32 | ScalarKind::F32) => 32,
+                Some(
+                    ScalarKind::U64
+                    | ScalarKind::I64
+                    | ScalarKind::F64
+                    | ScalarKind::USize
+                    | ScalarKind::ISize,
+                ) => 64,
+                Some(ScalarKind::U128 | ScalarKind::I128) => 128,
+                Some(ScalarKind::Char) => 200,
+                Some(ScalarKind::Str) => 500,
+                _ => match shape.type_identifier {
+                    "PathBuf" | "Path" | "Utf8PathBuf" | "Utf8Path" => 400,
+                    _ => 300,
+                },
+            };
+        }
+        _ => {}
+    }
+
+    if let Some(inner) = shape.inner {
+        return specificity_score(inner);
+    }
+    match &shape.ty {
+        Type::User(UserType::Struct(st)) => match st.kind {
+            StructKind::Unit => 0,
+            StructKind::Tuple | StructKind::TupleStruct => 600,
+            StructKind::Struct => 900,
+        },
+        Type::User(UserType::Enum(_)) => 950,
         _ => 1000,
     }
 }
 
+/// A format-specific specificity score, overriding [`specificity_score`].
+///
+/// Return `None` to fall back to [`specificity_score`]. Lower scores are
+/// more specific.
+pub type ScoreFn = fn(&'static Shape) -> Option<u64>;
+
 // ============================================================================
 // Solver (State Machine)
 // ============================================================================
@@ -735,6 +1062,21 @@ pub enum KeyResult<'a> {
         fields: Vec<(&'a FieldInfo, u64)>,
     },
 
+    /// Like [`KeyResult::Ambiguous`], returned instead of it by a
+    /// [`Solver::streaming`] solver.
+    ///
+    /// Deserializers that can't rewind should read the value into something
+    /// that holds any value described by `superset` (e.g. the widest integer
+    /// in range, or a buffered string-or-map), then call `satisfy()` with the
+    /// fields that value fits, as for `Ambiguous`.
+    Buffer {
+        /// The unique fields across remaining candidates, with specificity
+        /// scores, as in `Ambiguous`.
+        fields: Vec<(&'a FieldInfo, u64)>,
+        /// What the value may be: the union of what the fields accept.
+        superset: ValueSuperset,
+    },
+
     /// This key disambiguated to exactly one configuration.
     Solved(ResolutionHandle<'a>),
 
@@ -816,7 +1158,16 @@ pub struct Solver<'a> {
     candidates: ResolutionSet,
     /// Set of seen keys for required field checking.
     /// For Flat format, stores FieldKey::Flat. For Dom format, stores FieldKey::Dom.
+    /// Under key normalization, keys that match a field are stored under the
+    /// field's serialized name.
     seen_keys: BTreeSet<FieldKey<'a>>,
+    /// Serialized field name → key as written in the input, for keys that
+    /// only matched after normalization.
+    spellings: BTreeMap<&'static str, Cow<'a, str>>,
+    /// Whether to return `Buffer` instead of `Ambiguous`.
+    streaming: bool,
+    /// Format-specific specificity scores
+    score_fn: Option<ScoreFn>,
 }
 
 impl<'a> Solver<'a> {
@@ -826,6 +1177,130 @@ impl<'a> Solver<'a> {
             schema,
             candidates: ResolutionSet::full(schema.resolutions.len()),
             seen_keys: BTreeSet::new(),
+            spellings: BTreeMap::new(),
+            streaming: false,
+            score_fn: None,
+        }
+    }
+
+    /// Use `score_fn` to score the specificity of ambiguous fields, falling
+    /// back to [`specificity_score`] where it returns `None`.
+    ///
+    /// ```rust
+    /// use facet::Facet;
+    /// use facet_solver::{Schema, Solver};
+    ///
+    /// #[derive(Facet)]
+    /// struct Empty {}
+    ///
+    /// // This format has native timestamps: prefer them over anything else.
+    /// fn score(shape: &'static facet::Shape) -> Option<u64> {
+    ///     (shape.type_identifier == "Timestamp").then_some(0)
+    /// }
+    ///
+    /// let schema = Schema::build(Empty::SHAPE).unwrap();
+    /// let solver = Solver::new(&schema).with_score_fn(score);
+    /// # let _ = solver;
+    /// ```
+    pub const fn with_score_fn(mut self, score_fn: ScoreFn) -> Self {
+        self.score_fn = Some(score_fn);
+        self
+    }
+
+    /// Specificity score of `shape`, through the score function if any.
+    fn score(&self, shape: &'static Shape) -> u64 {
+        self.score_fn
+            .and_then(|score_fn| score_fn(shape))
+            .unwrap_or_else(|| specificity_score(shape))
+    }
+
+    /// Switch to streaming mode, for deserializers that can't rewind the
+    /// input to look at a value twice.
+    ///
+    /// Where candidates disagree on a key's type, a streaming solver returns
+    /// [`KeyResult::Buffer`] instead of [`KeyResult::Ambiguous`], describing
+    /// every kind of value the key may hold so the deserializer can buffer just
+    /// that value instead of the whole object.
+    ///
+    /// ```rust
+    /// use facet::Facet;
+    /// use facet_solver::{KeyResult, Schema, Solver};
+    ///
+    /// #[derive(Facet)]
+    /// struct Small { value: u8 }
+    ///
+    /// #[derive(Facet)]
+    /// struct Large { value: i64 }
+    ///
+    /// #[derive(Facet)]
+    /// #[repr(u8)]
+    /// enum Reading {
+    ///     Small(Small),
+    ///     Large(Large),
+    /// }
+    ///
+    /// #[derive(Facet)]
+    /// struct Sensor {
+    ///     #[facet(flatten)]
+    ///     reading: Reading,
+    /// }
+    ///
+    /// let schema = Schema::build_auto(Sensor::SHAPE).unwrap();
+    /// let mut solver = Solver::new(&schema).streaming();
+    /// match solver.see_key("value") {
+    ///     KeyResult::Buffer { superset, .. } => {
+    ///         assert!(superset.is_numeric());
+    ///         let range = superset.integer.unwrap();
+    ///         assert_eq!(range.min, i64::MIN as i128);
+    ///         assert_eq!(range.max, i64::MAX as u128);
+    ///     }
+    ///     _ => panic!("expected Buffer"),
+    /// }
+    /// ```
+    pub const fn streaming(mut self) -> Self {
+        self.streaming = true;
+        self
+    }
+
+    /// `Ambiguous`, or `Buffer` in streaming mode.
+    fn ambiguous(
+        &self,
+        fields: Vec<(&'a FieldInfo, u64)>,
+        shapes: &[&'static Shape],
+    ) -> KeyResult<'a> {
+        if self.streaming {
+            KeyResult::Buffer {
+                fields,
+                superset: ValueSuperset::of_shapes(shapes.iter().copied()),
+            }
+        } else {
+            KeyResult::Ambiguous { fields }
+        }
+    }
+
+    /// Map `key` to the serialized field name it matches, through aliases and
+    /// the schema's key normalization, remembering how it was spelled.
+    fn canonicalize(&mut self, key: FieldKey<'a>) -> FieldKey<'a> {
+        if self.schema.key_normalization == KeyNormalization::Exact
+            && self.schema.aliases.is_empty()
+        {
+            return key;
+        }
+        let Some(canonical) = self.schema.canonical_key(key.name()) else {
+            return key;
+        };
+        if canonical == key.name() {
+            return key;
+        }
+        match key {
+            FieldKey::Flat(name) => {
+                self.spellings.insert(canonical, name);
+                FieldKey::Flat(Cow::Borrowed(canonical))
+            }
+            FieldKey::Dom(category, name) => {
+                self.spellings.insert(canonical, name);
+                FieldKey::Dom(category, Cow::Borrowed(canonical))
+            }
         }
     }
 
@@ -860,6 +1335,7 @@ impl<'a> Solver<'a> {
 
     /// Internal implementation of key lookup.
     fn see_key_internal(&mut self, key: FieldKey<'a>) -> KeyResult<'a> {
+        let key = self.canonicalize(key);
         self.seen_keys.insert(key.clone());
 
         // Key-based filtering - use appropriate index based on format
@@ -947,13 +1423,12 @@ impl<'a> Solver<'a> {
         } else {
             // Different types - need disambiguation
             // Attach specificity scores so caller can pick most specific when multiple match
+            let shapes: Vec<_> = unique_fields.iter().map(|f| f.value_shape).collect();
             let fields_with_scores: Vec<_> = unique_fields
                 .into_iter()
-                .map(|f| (f, specificity_score(f.value_shape)))
+                .map(|f| (f, self.score(f.value_shape)))
                 .collect();
-            KeyResult::Ambiguous {
-                fields: fields_with_scores,
-            }
+            self.ambiguous(fields_with_scores, &shapes)
         }
     }
 
@@ -1127,6 +1602,7 @@ impl<'a> Solver<'a> {
     /// This filters the candidates to only those resolutions where at least one
     /// variant selection has the given variant name. This is useful for explicit
     /// type disambiguation via annotations (e.g., type annotations in various formats).
+    /// Both the Rust variant name and its `#[facet(rename)]` are accepted.
     ///
     /// Returns `true` if at least one candidate remains after filtering, `false` if
     /// no candidates match the variant name (in which case candidates are unchanged).
@@ -1167,17 +1643,19 @@ impl<'a> Solver<'a> {
     /// assert_eq!(solver.candidates().len(), 1);
     /// ```
     pub fn hint_variant(&mut self, variant_name: &str) -> bool {
-        // Build a set of configs that have this variant name
+        self.retain_variant(|vs| {
+            vs.variant_name == variant_name || vs.serialized_name == variant_name
+        })
+    }
+
+    /// Keep only the candidates with at least one variant selection matching
+    /// `pred`, unless that would leave none.
+    fn retain_variant(&mut self, pred: impl Fn(&VariantSelection) -> bool) -> bool {
         let mut matching = ResolutionSet::empty(self.schema.resolutions.len());
 
         for idx in self.candidates.iter() {
             let config = &self.schema.resolutions[idx];
-            // Check if any variant selection matches the given name
-            if config
-                .variant_selections()
-                .iter()
-                .any(|vs| vs.variant_name == variant_name)
-            {
+            if config.variant_selections().iter().any(&pred) {
                 matching.insert(idx);
             }
         }
@@ -1198,9 +1676,12 @@ impl<'a> Solver<'a> {
     /// is actually the tag field for an internally-tagged enum in at least one
     /// candidate resolution before applying the hint.
     ///
+    /// The tag value is matched against the variant's serialized name, so a
+    /// variant with `#[facet(rename = "tcp")]` is selected by `"tcp"`.
+    ///
     /// Returns `true` if the hint was applied (field was a valid tag field and
     /// at least one candidate matches), `false` otherwise.
-    pub fn hint_variant_for_tag(&mut self, tag_field_name: &str, variant_name: &str) -> bool {
+    pub fn hint_variant_for_tag(&mut self, tag_field_name: &str, tag_value: &str) -> bool {
         // First check if any candidate has this field as an internally-tagged enum tag field
         let is_tag_field = self.candidates.iter().any(|idx| {
             let config = &self.schema.resolutions[idx];
@@ -1220,7 +1701,7 @@ impl<'a> Solver<'a> {
         }
 
         // Now apply the variant hint
-        self.hint_variant(variant_name)
+        self.retain_variant(|vs| vs.serialized_name == tag_value)
     }
 
     /// Mark a key as seen without filtering candidates.
@@ -1229,7 +1710,8 @@ impl<'a> Solver<'a> {
     /// parsing (e.g., type annotations). Call this after `hint_variant` to mark
     /// the variant name as seen so that `finish()` doesn't report it as missing.
     pub fn mark_seen(&mut self, key: impl Into<FieldKey<'a>>) {
-        self.seen_keys.insert(key.into());
+        let key = self.canonicalize(key.into());
+        self.seen_keys.insert(key);
     }
 
     /// Report a key at a nested path. Returns what to do next.
@@ -1282,9 +1764,8 @@ impl<'a> Solver<'a> {
     /// }
     /// ```
     pub fn probe_key(&mut self, path: &[&str], key: &str) -> KeyResult<'a> {
-        // Build full path
-        let mut full_path: Vec<&str> = path.to_vec();
-        full_path.push(key);
+        // Build full path, each segment spelled as the schema names it
+        let full_path = self.schema.canonical_path(path, key);
 
         // Filter candidates to only those that have this key path
         let mut new_candidates = ResolutionSet::empty(self.schema.resolutions.len());
@@ -1337,17 +1818,14 @@ impl<'a> Solver<'a> {
                         let config = &self.schema.resolutions[*idx];
                         // For nested paths, we need the parent field
                         // e.g., for ["payload", "value"], get the "payload" field
-                        let field = if path.is_empty() {
-                            config.field_by_name(key)
-                        } else {
-                            // Return the top-level field that contains this path
-                            config.field_by_name(path[0])
-                        }?;
-                        Some((field, specificity_score(shape)))
+                        // For a top-level key this is the key's own field
+                        let field = config.field_by_name(full_path[0])?;
+                        Some((field, self.score(shape)))
                     })
                     .collect();
 
-                KeyResult::Ambiguous { fields }
+                let shapes: Vec<_> = unique_shapes.iter().map(|(shape, _)| *shape).collect();
+                self.ambiguous(fields, &shapes)
             }
         }
     }
@@ -1361,23 +1839,36 @@ impl<'a> Solver<'a> {
         // Start with the top-level field
         let top_field = config.field_by_name(path[0])?;
         let mut current_shape = top_field.value_shape;
+        // Defaults handed down to the top-level field's value (taken from the
+        // root, even when the field was flattened in from a child)
+        let mut inherited = self
+            .schema
+            .shape
+            .defaults_for_children(self.schema.inherited);
 
         // Navigate through nested structs
         for &key in &path[1..] {
-            current_shape = self.get_field_shape(current_shape, key)?;
+            let parent = current_shape;
+            current_shape = self.get_field_shape(parent, inherited, key)?;
+            inherited = parent.defaults_for_children(inherited);
         }
 
         Some(current_shape)
     }
 
     /// Get the shape of a field within a struct shape.
-    fn get_field_shape(&self, shape: &'static Shape, field_name: &str) -> Option<&'static Shape> {
+    fn get_field_shape(
+        &self,
+        shape: &'static Shape,
+        inherited: Option<ChildDefaults>,
+        field_name: &str,
+    ) -> Option<&'static Shape> {
         use facet_core::{StructType, Type, UserType};
 
         match shape.ty {
             Type::User(UserType::Struct(StructType { fields, .. })) => {
                 for field in fields {
-                    if field.effective_name() == field_name {
+                    if field.effective_name_in(shape, inherited) == field_name {
                         return Some(field.shape());
                     }
                 }
@@ -1418,11 +1909,114 @@ impl<'a> Solver<'a> {
     /// as the sole viable configuration.
     #[allow(clippy::result_large_err)] // SolverError intentionally contains detailed diagnostic info
     pub fn finish(self) -> Result<ResolutionHandle<'a>, SolverError> {
+        self.finish_borrowed().map_err(SolveError::into_detailed)
+    }
+
+    /// Finish solving, like [`finish`](Self::finish), but without building
+    /// diagnostics on failure.
+    ///
+    /// The returned [`SolveError`] only refers into the schema (resolution
+    /// indices, `&'static str` field names) and doesn't allocate. Use this
+    /// where failures are expected and usually discarded, such as trying the
+    /// variants of an untagged enum in turn; call
+    /// [`SolveError::into_detailed`] when the full report is needed after all.
+    #[allow(clippy::result_large_err)] // moves the solver's state, but doesn't allocate
+    pub fn finish_borrowed(self) -> Result<ResolutionHandle<'a>, SolveError<'a>> {
+        let schema = self.schema;
+        let has_required = |idx: usize| {
+            schema.resolutions[idx]
+                .required_field_names()
+                .iter()
+                .all(|f| self.seen_keys.iter().any(|k| k.name() == *f))
+        };
+        let (first, second) = {
+            let mut viable = self.candidates.iter().filter(|&idx| has_required(idx));
+            (viable.next(), viable.next())
+        };
+
+        let failure = match (first, second) {
+            (Some(idx), None) => return Ok(ResolutionHandle::from_schema(schema, idx)),
+            (Some(first), Some(second)) => SolveFailure::Ambiguous { first, second },
+            (None, _) => {
+                let candidate = closest_candidate(self.candidates.iter(), schema, &self.seen_keys);
+                let missing = candidate.and_then(|idx| {
+                    schema.resolutions[idx]
+                        .required_field_names()
+                        .iter()
+                        .find(|f| !self.seen_keys.iter().any(|k| k.name() == **f))
+                        .copied()
+                });
+                SolveFailure::NoMatch { candidate, missing }
+            }
+        };
+        Err(SolveError {
+            failure,
+            solver: self,
+        })
+    }
+}
+
+/// Why [`Solver::finish_borrowed`] failed.
+///
+/// Resolutions are identified by their index in [`Schema::resolutions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SolveFailure {
+    /// No resolution has all of its required fields.
+    NoMatch {
+        /// The resolution still consistent with the input that scores best
+        /// (see [`CandidateScore`]), or `None` if no candidate is left.
+        candidate: Option<usize>,
+        /// A required field of `candidate` that the input lacks.
+        missing: Option<&'static str>,
+    },
+    /// Several resolutions have all of their required fields.
+    Ambiguous {
+        /// The first matching resolution.
+        first: usize,
+        /// The second matching resolution.
+        second: usize,
+    },
+}
+
+/// Allocation-free error from [`Solver::finish_borrowed`].
+///
+/// Holds the solver's state so that [`into_detailed`](Self::into_detailed)
+/// can build the same [`SolverError`] that [`Solver::finish`] returns.
+pub struct SolveError<'a> {
+    failure: SolveFailure,
+    solver: Solver<'a>,
+}
+
+impl<'a> SolveError<'a> {
+    /// What went wrong.
+    pub const fn failure(&self) -> SolveFailure {
+        self.failure
+    }
+
+    /// The schema that resolution indices refer to.
+    pub const fn schema(&self) -> &'a Schema {
+        self.solver.schema
+    }
+
+    /// Build the full diagnostic error: missing fields with their paths,
+    /// per-candidate failures and "did you mean?" suggestions.
+    pub fn into_detailed(self) -> SolverError {
         let Solver {
             schema,
             candidates,
             seen_keys,
-        } = self;
+            spellings,
+            streaming: _,
+            score_fn: _,
+        } = self.solver;
+
+        // Report keys as they were written in the input
+        let spelled = |name: &str| -> String {
+            spellings
+                .get(name)
+                .map_or_else(|| name.to_string(), |s| s.to_string())
+        };
 
         // Compute all known fields across all resolutions (for unknown field detection)
         let all_known_fields: BTreeSet<&'static str> = schema
@@ -1431,10 +2025,16 @@ impl<'a> Solver<'a> {
             .flat_map(|r| r.fields().values().map(|f| f.serialized_name))
             .collect();
 
-        // Find unknown fields (fields in input that don't exist in ANY resolution)
+        // Find unknown fields (fields in input that don't exist in ANY resolution,
+        // and that no resolution's catch-all map would take)
         let unknown_fields: Vec<String> = seen_keys
             .iter()
-            .filter(|k| !all_known_fields.contains(k.name()))
+            .filter(|k| {
+                !all_known_fields.contains(k.name())
+                    && !schema
+                        .catch_all_resolutions
+                        .contains_key(&catch_all_category(k, schema.format))
+            })
             .map(|k| k.name().to_string())
             .collect();
 
@@ -1442,25 +2042,19 @@ impl<'a> Solver<'a> {
         let suggestions = compute_suggestions(&unknown_fields, &all_known_fields);
 
         if candidates.is_empty() {
-            // Build per-candidate failure info for all resolutions
-            let mut candidate_failures: Vec<CandidateFailure> = schema
-                .resolutions
-                .iter()
-                .map(|config| build_candidate_failure(config, &seen_keys))
-                .collect();
-
-            // Sort by closeness (best match first)
-            sort_candidates_by_closeness(&mut candidate_failures);
+            // Every resolution was ruled out; rank all of them
+            let (candidate_failures, closest) =
+                rank_candidate_failures(0..schema.resolutions.len(), schema, &seen_keys);
 
-            return Err(SolverError::NoMatch {
-                input_fields: seen_keys.iter().map(|k| k.name().to_string()).collect(),
+            return SolverError::NoMatch {
+                input_fields: seen_keys.iter().map(|k| spelled(k.name())).collect(),
                 missing_required: Vec::new(),
                 missing_required_detailed: Vec::new(),
                 unknown_fields,
-                closest_resolution: None,
+                closest_resolution: closest.map(|idx| schema.resolutions[idx].describe()),
                 candidate_failures,
                 suggestions,
-            });
+            };
         }
 
         // Filter candidates to only those that have all required fields satisfied
@@ -1477,24 +2071,11 @@ impl<'a> Solver<'a> {
 
         match viable.len() {
             0 => {
-                // No viable candidates - build per-candidate failure info
-                let mut candidate_failures: Vec<CandidateFailure> = candidates
-                    .iter()
-                    .map(|idx| {
-                        let config = &schema.resolutions[idx];
-                        build_candidate_failure(config, &seen_keys)
-                    })
-                    .collect();
-
-                // Sort by closeness (best match first)
-                sort_candidates_by_closeness(&mut candidate_failures);
-
-                // For backwards compatibility, also populate the "closest" fields
-                // Now use the first (closest) candidate after sorting
-                let closest_name = candidate_failures.first().map(|f| f.variant_name.clone());
-                let closest_config = closest_name
-                    .as_ref()
-                    .and_then(|name| schema.resolutions.iter().find(|r| r.describe() == *name));
+                // No viable candidates - build per-candidate failure info,
+                // best match first, and report the best one as the closest
+                let (candidate_failures, closest) =
+                    rank_candidate_failures(candidates.iter(), schema, &seen_keys);
+                let closest_config = closest.map(|idx| &schema.resolutions[idx]);
 
                 let (missing, missing_detailed, closest_resolution) =
                     if let Some(config) = closest_config {
@@ -1514,19 +2095,21 @@ impl<'a> Solver<'a> {
                         (Vec::new(), Vec::new(), None)
                     };
 
-                Err(SolverError::NoMatch {
-                    input_fields: seen_keys.iter().map(|s| s.to_string()).collect(),
+                SolverError::NoMatch {
+                    input_fields: seen_keys
+                        .iter()
+                        .map(|k| match k.category() {
+                            Some(cat) => alloc::format!("{cat:?}:{}", spelled(k.name())),
+                            None => spelled(k.name()),
+                        })
+                        .collect(),
                     missing_required: missing,
                     missing_required_detailed: missing_detailed,
                     unknown_fields,
                     closest_resolution,
                     candidate_failures,
                     suggestions,
-                })
-            }
-            1 => {
-                // Exactly one viable candidate - success!
-                Ok(ResolutionHandle::from_schema(schema, viable[0]))
+                }
             }
             _ => {
                 // Multiple viable candidates - ambiguous!
@@ -1534,19 +2117,62 @@ impl<'a> Solver<'a> {
                 let candidates: Vec<String> = configs.iter().map(|c| c.describe()).collect();
                 let disambiguating_fields = find_disambiguating_fields(&configs);
 
-                Err(SolverError::Ambiguous {
+                SolverError::Ambiguous {
                     candidates,
                     disambiguating_fields,
-                })
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Debug for SolveError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SolveError")
+            .field("failure", &self.failure)
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Display for SolveError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let resolutions = &self.solver.schema.resolutions;
+        match self.failure {
+            SolveFailure::NoMatch {
+                candidate: Some(idx),
+                missing,
+            } => {
+                write!(
+                    f,
+                    "No matching configuration (closest: {}",
+                    resolutions[idx].describe()
+                )?;
+                if let Some(missing) = missing {
+                    write!(f, ", missing field '{missing}'")?;
+                }
+                write!(f, ")")
             }
+            SolveFailure::NoMatch {
+                candidate: None, ..
+            } => write!(f, "No configuration has all of the fields seen"),
+            SolveFailure::Ambiguous { first, second } => write!(
+                f,
+                "Ambiguous: multiple resolutions match, including {} and {}",
+                resolutions[first].describe(),
+                resolutions[second].describe()
+            ),
         }
     }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for SolveError<'_> {}
+
 /// Build a CandidateFailure for a resolution given the seen keys.
 fn build_candidate_failure<'a>(
     config: &Resolution,
     seen_keys: &BTreeSet<FieldKey<'a>>,
+    format: Format,
 ) -> CandidateFailure {
     let missing_fields: Vec<MissingFieldInfo> = config
         .required_field_names()
@@ -1558,100 +2184,124 @@ fn build_candidate_failure<'a>(
 
     let unknown_fields: Vec<String> = seen_keys
         .iter()
-        .filter(|k| config.field_by_key(k).is_none())
+        .filter(|k| {
+            config.field_by_key(k).is_none()
+                && config
+                    .catch_all_map(catch_all_category(k, format))
+                    .is_none()
+        })
         .map(|k| k.name().to_string())
         .collect();
 
-    // Compute closeness score for ranking
-    let suggestion_matches = compute_closeness_score(&unknown_fields, &missing_fields, config);
+    let score = score_candidate(config, seen_keys, format);
+    let suggestion_matches = unknown_fields
+        .iter()
+        .filter(|k| closest_field_similarity(k, config) > 0)
+        .count();
 
     CandidateFailure {
         variant_name: config.describe(),
         missing_fields,
         unknown_fields,
         suggestion_matches,
+        score,
     }
 }
 
-/// Compute a closeness score for ranking candidates.
-/// Higher score = more likely the user intended this variant.
-///
-/// The score considers:
-/// - Typo matches: unknown fields that are similar to known fields (weighted by similarity)
-/// - Field coverage: if we fixed typos, would we have all required fields?
-/// - Missing fields: fewer missing = better
-/// - Unknown fields: fewer truly unknown (no suggestion) = better
-#[cfg(feature = "suggestions")]
-fn compute_closeness_score(
-    unknown_fields: &[String],
-    missing_fields: &[MissingFieldInfo],
+/// Score how closely the seen keys fit a resolution.
+fn score_candidate(
     config: &Resolution,
-) -> usize {
-    const SIMILARITY_THRESHOLD: f64 = 0.6;
-
-    // Score components (scaled to integers for easy comparison)
-    let mut typo_score: usize = 0;
-    let mut fields_that_would_match: usize = 0;
-
-    // For each unknown field, find best matching known field
-    for unknown in unknown_fields {
-        let mut best_similarity = 0.0f64;
-        let mut best_match: Option<&str> = None;
-
-        for info in config.fields().values() {
-            let similarity = strsim::jaro_winkler(unknown, info.serialized_name);
-            if similarity >= SIMILARITY_THRESHOLD && similarity > best_similarity {
-                best_similarity = similarity;
-                best_match = Some(info.serialized_name);
-            }
+    seen_keys: &BTreeSet<FieldKey<'_>>,
+    format: Format,
+) -> CandidateScore {
+    let required = config.required_field_names();
+    let matched_required = required
+        .iter()
+        .filter(|f| seen_keys.iter().any(|k| k.name() == **f))
+        .count();
+
+    let mut unmatched_keys = 0;
+    let mut similarity = 0;
+    for key in seen_keys {
+        if config.field_by_key(key).is_none()
+            && config
+                .catch_all_map(catch_all_category(key, format))
+                .is_none()
+        {
+            unmatched_keys += 1;
+            similarity += closest_field_similarity(key.name(), config);
         }
+    }
 
-        if let Some(_matched_field) = best_match {
-            // Weight by similarity: 0.6 -> 60 points, 1.0 -> 100 points
-            typo_score += (best_similarity * 100.0) as usize;
-            fields_that_would_match += 1;
+    CandidateScore {
+        matched_required,
+        missing_required: required.len() - matched_required,
+        unmatched_keys,
+        similarity,
+    }
+}
+
+/// Pick the best-scoring resolution among `candidates`.
+fn closest_candidate(
+    candidates: impl Iterator<Item = usize>,
+    schema: &Schema,
+    seen_keys: &BTreeSet<FieldKey<'_>>,
+) -> Option<usize> {
+    let mut best: Option<(usize, CandidateScore)> = None;
+    for idx in candidates {
+        let score = score_candidate(&schema.resolutions[idx], seen_keys, schema.format);
+        if best.is_none_or(|(_, best_score)| score.closeness() > best_score.closeness()) {
+            best = Some((idx, score));
         }
     }
+    best.map(|(idx, _)| idx)
+}
 
-    // Calculate how many required fields would be satisfied if typos were fixed
-    let required_count = config.required_field_names().len();
-    let currently_missing = missing_fields.len();
-    let would_be_missing = currently_missing.saturating_sub(fields_that_would_match);
+/// Build the failures for `candidates`, best match first, along with the
+/// index of the best one.
+fn rank_candidate_failures(
+    candidates: impl Iterator<Item = usize>,
+    schema: &Schema,
+    seen_keys: &BTreeSet<FieldKey<'_>>,
+) -> (Vec<CandidateFailure>, Option<usize>) {
+    let mut ranked: Vec<(usize, CandidateFailure)> = candidates
+        .map(|idx| {
+            let config = &schema.resolutions[idx];
+            (
+                idx,
+                build_candidate_failure(config, seen_keys, schema.format),
+            )
+        })
+        .collect();
+    // Stable, so equally scored candidates keep schema order
+    ranked.sort_by_key(|(_, failure)| core::cmp::Reverse(failure.score.closeness()));
 
-    // Coverage score: percentage of required fields that would be present
-    let coverage_score = required_count
-        .saturating_sub(would_be_missing)
-        .saturating_mul(100)
-        .checked_div(required_count)
-        .unwrap_or(100);
+    let closest = ranked.first().map(|(idx, _)| *idx);
+    (ranked.into_iter().map(|(_, f)| f).collect(), closest)
+}
 
-    // Penalty for truly unknown fields (no typo suggestion)
-    let truly_unknown = unknown_fields.len().saturating_sub(fields_that_would_match);
-    let unknown_penalty = truly_unknown * 10;
+/// Similarity, in percent, between an unknown key and the closest field of
+/// a resolution, or 0 if no field is similar enough to suggest.
+#[cfg(feature = "suggestions")]
+fn closest_field_similarity(unknown: &str, config: &Resolution) -> usize {
+    const SIMILARITY_THRESHOLD: f64 = 0.6;
 
-    // Combine scores: typo matches are most important, then coverage, then penalties
-    // Each typo match can give up to 100 points, so scale coverage to match
-    typo_score + coverage_score.saturating_sub(unknown_penalty)
+    let best = config
+        .fields()
+        .values()
+        .map(|info| strsim::jaro_winkler(unknown, info.serialized_name))
+        .filter(|similarity| *similarity >= SIMILARITY_THRESHOLD)
+        .fold(0.0f64, f64::max);
+    // 0.6 -> 60, 1.0 -> 100
+    (best * 100.0) as usize
 }
 
-/// Compute closeness score (no-op without suggestions feature).
+/// Similarity to the closest field (always 0 without the `suggestions` feature).
 #[cfg(not(feature = "suggestions"))]
-fn compute_closeness_score(
-    _unknown_fields: &[String],
-    _missing_fields: &[MissingFieldInfo],
-    _config: &Resolution,
-) -> usize {
+fn closest_field_similarity(_unknown: &str, _config: &Resolution) -> usize {
     0
 }
 
-/// Sort candidate failures by closeness (best match first).
-fn sort_candidates_by_closeness(failures: &mut [CandidateFailure]) {
-    failures.sort_by(|a, b| {
-        // Higher suggestion_matches (closeness score) first
-        b.suggestion_matches.cmp(&a.suggestion_matches)
-    });
-}
-
 /// Compute "did you mean?" suggestions for unknown fields.
 #[cfg(feature = "suggestions")]
 fn compute_suggestions(
@@ -1768,20 +2418,28 @@ pub enum ProbeResult<'a> {
 pub struct ProbingSolver<'a> {
     /// Remaining candidate resolutions
     candidates: Vec<&'a Resolution>,
+    /// Schema to normalize keys with, if built from one
+    schema: Option<&'a Schema>,
 }
 
 impl<'a> ProbingSolver<'a> {
     /// Create a new probing solver from a schema.
+    ///
+    /// Keys are matched under the schema's [`KeyNormalization`].
     pub fn new(schema: &'a Schema) -> Self {
         Self {
             candidates: schema.resolutions.iter().collect(),
+            schema: Some(schema),
         }
     }
 
     /// Create a new probing solver from resolutions directly.
+    ///
+    /// Keys must match field names exactly.
     pub fn from_resolutions(configs: &'a [Resolution]) -> Self {
         Self {
             candidates: configs.iter().collect(),
+            schema: None,
         }
     }
 
@@ -1793,8 +2451,14 @@ impl<'a> ProbingSolver<'a> {
     /// Returns what to do next.
     pub fn probe_key(&mut self, path: &[&str], key: &str) -> ProbeResult<'a> {
         // Build the full key path (runtime strings, compared against static schema)
-        let mut full_path: Vec<&str> = path.to_vec();
-        full_path.push(key);
+        let full_path = match self.schema {
+            Some(schema) => schema.canonical_path(path, key),
+            None => {
+                let mut full_path: Vec<&str> = path.to_vec();
+                full_path.push(key);
+                full_path
+            }
+        };
 
         // Filter to candidates that have this key path
         self.candidates.retain(|c| c.has_key_path(&full_path));
@@ -2350,6 +3014,265 @@ impl Schema {
             .with_format(format);
         builder.into_schema()
     }
+
+    /// Build a schema with auto-detected enum representation and the given options.
+    ///
+    /// With a [`KeyNormalization`] other than `Exact`, solvers for this schema
+    /// match keys after normalizing them. Returns
+    /// [`SchemaError::NormalizedKeyCollision`] if two field names become the
+    /// same key.
+    ///
+    /// ```rust
+    /// use facet::Facet;
+    /// use facet_solver::{KeyNormalization, KeyResult, Schema, SchemaOptions, Solver};
+    ///
+    /// #[derive(Facet)]
+    /// struct Pool {
+    ///     max_connections: u32,
+    /// }
+    ///
+    /// let options =
+    ///     SchemaOptions::new().key_normalization(KeyNormalization::CaseAndSeparatorInsensitive);
+    /// let schema = Schema::build_with_options(Pool::SHAPE, options).unwrap();
+    /// assert_eq!(schema.canonical_key("max-connections"), Some("max_connections"));
+    ///
+    /// let mut solver = Solver::new(&schema);
+    /// assert!(!matches!(solver.see_key("maxConnections"), KeyResult::Unknown));
+    /// assert!(solver.finish().is_ok());
+    /// ```
+    pub fn build_with_options(
+        shape: &'static Shape,
+        options: SchemaOptions,
+    ) -> Result<Self, SchemaError> {
+        let mut schema = SchemaBuilder::new(shape, EnumRepr::Flattened)
+            .with_auto_detect()
+            .with_format(options.format)
+            .with_max_resolutions(options.max_resolutions)
+            .with_inherited(options.inherited)
+            .into_schema()?;
+        schema.key_normalization = options.key_normalization;
+
+        // Nested keys are matched too, so probing can normalize whole paths
+        let spellings = schema
+            .resolutions
+            .iter()
+            .flat_map(|r| r.fields().values())
+            .map(|f| (f.serialized_name, f.serialized_name))
+            .chain(
+                schema
+                    .resolutions
+                    .iter()
+                    .flat_map(|r| r.known_paths().iter().flatten())
+                    .map(|&name| (name, name)),
+            )
+            .chain(schema.aliases.iter().map(|(&alias, &name)| (alias, name)));
+        for (spelling, name) in spellings {
+            let Some(normalized) = options.key_normalization.normalize(spelling) else {
+                continue;
+            };
+            match schema.normalized_names.get(&normalized) {
+                Some(&existing) if existing != name => {
+                    return Err(SchemaError::NormalizedKeyCollision {
+                        normalized,
+                        first: existing,
+                        second: name,
+                    });
+                }
+                Some(_) => {}
+                None => {
+                    schema.normalized_names.insert(normalized, name);
+                }
+            }
+        }
+        Ok(schema)
+    }
+
+    /// Get the key normalization strategy this schema was built with.
+    pub const fn key_normalization(&self) -> KeyNormalization {
+        self.key_normalization
+    }
+
+    /// Returns the serialized field name that `key` matches under this
+    /// schema's key normalization, or `None` if it matches no field.
+    /// A field's alias maps to the field's serialized name. With a
+    /// normalization other than `Exact`, keys of nested structs match too.
+    ///
+    /// Deserializers use this to map a key as written to the field it
+    /// designates.
+    pub fn canonical_key(&self, key: &str) -> Option<&'static str> {
+        match self.key_normalization.normalize(key) {
+            Some(normalized) => self.normalized_names.get(&normalized).copied(),
+            None => match self.aliases.get(key) {
+                Some(&name) => Some(name),
+                None => self
+                    .field_to_resolutions
+                    .get_key_value(key)
+                    .map(|(&name, _)| name),
+            },
+        }
+    }
+
+    /// `path` followed by `key`, each segment mapped through
+    /// [`Self::canonical_key`] (and kept as written if it matches nothing).
+    fn canonical_path<'k>(&self, path: &[&'k str], key: &'k str) -> Vec<&'k str> {
+        path.iter()
+            .chain(core::iter::once(&key))
+            .map(|&segment| self.canonical_key(segment).unwrap_or(segment))
+            .collect()
+    }
+
+    /// Every serialized field of this schema, across all resolutions.
+    ///
+    /// This is the solver's view of the wire format, for tools such as
+    /// documentation generators: flattened fields appear under their own
+    /// names, and each field says which resolutions (combinations of
+    /// flattened enum variants) accept it. A field is listed once however
+    /// many resolutions share it; fields that share a name but come from
+    /// different places, such as two variants of a flattened enum, are
+    /// listed separately. Fields are sorted by serialized name, then path.
+    /// Flattened catch-all maps aren't listed.
+    ///
+    /// ```rust
+    /// use facet::Facet;
+    /// use facet_solver::Schema;
+    ///
+    /// #[derive(Facet)]
+    /// struct Server {
+    ///     /// Port to listen on.
+    ///     port: u16,
+    ///     #[facet(alias = "host")]
+    ///     hostname: Option<String>,
+    /// }
+    ///
+    /// let schema = Schema::build_auto(Server::SHAPE).unwrap();
+    /// let fields: Vec<_> = schema.fields().collect();
+    ///
+    /// assert_eq!(fields[0].name(), "hostname");
+    /// assert_eq!(fields[0].alias(), Some("host"));
+    /// assert!(!fields[0].is_required());
+    ///
+    /// assert_eq!(fields[1].name(), "port");
+    /// assert_eq!(fields[1].docs(), [" Port to listen on."]);
+    /// assert!(fields[1].in_every_resolution());
+    /// ```
+    pub fn fields(&self) -> impl Iterator<Item = SchemaField<'_>> {
+        let count = self.resolutions.len();
+        let mut fields: BTreeMap<(&FieldKey<'static>, &FieldPath), SchemaField<'_>> =
+            BTreeMap::new();
+        for (idx, resolution) in self.resolutions.iter().enumerate() {
+            for (key, info) in resolution.fields() {
+                let field = fields
+                    .entry((key, &info.path))
+                    .or_insert_with(|| SchemaField {
+                        info,
+                        alias: info
+                            .field
+                            .alias
+                            .filter(|alias| self.aliases.contains_key(alias)),
+                        required: true,
+                        resolutions: ResolutionSet::empty(count),
+                        in_every_resolution: false,
+                    });
+                field.required &= info.required;
+                field.resolutions.insert(idx);
+            }
+        }
+        fields.into_values().map(move |mut field| {
+            field.in_every_resolution = field.resolutions.len() == count;
+            field
+        })
+    }
+
+    /// Whether resolution `idx` has a flattened catch-all map that would
+    /// accept `key` when none of its fields do.
+    pub fn has_catch_all(&self, idx: usize, key: &FieldKey<'_>) -> bool {
+        self.resolutions[idx]
+            .catch_all_map(catch_all_category(key, self.format))
+            .is_some()
+    }
+}
+
+/// A serialized field, as listed by [`Schema::fields`].
+#[derive(Debug, Clone)]
+pub struct SchemaField<'a> {
+    info: &'a FieldInfo,
+    alias: Option<&'static str>,
+    required: bool,
+    resolutions: ResolutionSet,
+    in_every_resolution: bool,
+}
+
+impl<'a> SchemaField<'a> {
+    /// The name of the field in serialized input.
+    pub const fn name(&self) -> &'static str {
+        self.info.serialized_name
+    }
+
+    /// Where the field lives in the Rust type, through flattened structs and
+    /// enum variants.
+    pub const fn path(&self) -> &'a FieldPath {
+        &self.info.path
+    }
+
+    /// The shape of the field's value.
+    pub const fn shape(&self) -> &'static Shape {
+        self.info.value_shape
+    }
+
+    /// Whether the field is an attribute, element, etc. (DOM formats) or a
+    /// plain key (flat formats).
+    pub const fn category(&self) -> FieldCategory {
+        self.info.category
+    }
+
+    /// The field's doc comment, one entry per line.
+    pub const fn docs(&self) -> &'static [&'static str] {
+        self.info.field.doc
+    }
+
+    /// The other name the field is accepted under (`#[facet(alias = "...")]`),
+    /// unless another field already goes by that name.
+    pub const fn alias(&self) -> Option<&'static str> {
+        self.alias
+    }
+
+    /// Whether input must provide the field (it isn't an `Option` and has no
+    /// default) in every resolution that contains it.
+    ///
+    /// A required field that only some resolutions contain is only required
+    /// once input has picked one of those; see
+    /// [`in_every_resolution`](Self::in_every_resolution).
+    pub const fn is_required(&self) -> bool {
+        self.required
+    }
+
+    /// The resolutions that contain this field, as indices into
+    /// [`Schema::resolutions`].
+    pub const fn resolutions(&self) -> &ResolutionSet {
+        &self.resolutions
+    }
+
+    /// Whether every resolution contains this field, i.e. it doesn't depend
+    /// on which variant of a flattened enum input picks.
+    pub const fn in_every_resolution(&self) -> bool {
+        self.in_every_resolution
+    }
+
+    /// The full field information, from the first resolution that contains
+    /// the field.
+    pub const fn info(&self) -> &'a FieldInfo {
+        self.info
+    }
+}
+
+/// Category of the catch-all map that would take `key` in a schema built
+/// for `format`.
+fn catch_all_category(key: &FieldKey<'_>, format: Format) -> FieldCategory {
+    match (key, format) {
+        (FieldKey::Dom(category, _), Format::Dom) => *category,
+        (FieldKey::Flat(_), Format::Dom) => FieldCategory::Element,
+        _ => FieldCategory::Flat,
+    }
 }
 
 struct SchemaBuilder {
@@ -2360,6 +3283,10 @@ struct SchemaBuilder {
     auto_detect_enum_repr: bool,
     /// The format to build the schema for.
     format: Format,
+    /// Building fails once there are more resolutions than this.
+    max_resolutions: usize,
+    /// `#[facet(defaults_for_children)]` handed down to `shape`.
+    inherited: Option<ChildDefaults>,
 }
 
 impl SchemaBuilder {
@@ -2369,7 +3296,37 @@ impl SchemaBuilder {
             enum_repr,
             auto_detect_enum_repr: false,
             format: Format::Flat,
+            max_resolutions: DEFAULT_MAX_RESOLUTIONS,
+            inherited: None,
+        }
+    }
+
+    const fn with_inherited(mut self, inherited: Option<ChildDefaults>) -> Self {
+        self.inherited = inherited;
+        self
+    }
+
+    const fn with_max_resolutions(mut self, max_resolutions: usize) -> Self {
+        self.max_resolutions = max_resolutions;
+        self
+    }
+
+    /// Add a resolution produced while expanding `shape`, failing instead
+    /// of growing past the budget.
+    fn push_resolution(
+        &self,
+        result: &mut Vec<Resolution>,
+        resolution: Resolution,
+        shape: &'static Shape,
+    ) -> Result<(), SchemaError> {
+        if result.len() >= self.max_resolutions {
+            return Err(SchemaError::TooManyResolutions {
+                limit: self.max_resolutions,
+                type_name: shape.type_identifier,
+            });
         }
+        result.push(resolution);
+        Ok(())
     }
 
     const fn with_auto_detect(mut self) -> Self {
@@ -2383,27 +3340,29 @@ impl SchemaBuilder {
     }
 
     fn analyze(&self) -> Result<Vec<Resolution>, SchemaError> {
-        self.analyze_shape(self.shape, FieldPath::empty(), Vec::new())
+        self.analyze_shape(self.shape, self.inherited, FieldPath::empty(), Vec::new())
     }
 
     /// Analyze a shape and return all possible resolutions.
     /// Returns a Vec because enums create multiple resolutions.
     ///
+    /// - `inherited`: The defaults handed down to `shape`
     /// - `current_path`: The internal field path (for FieldInfo)
     /// - `key_prefix`: The serialized key path prefix (for known_paths)
     fn analyze_shape(
         &self,
         shape: &'static Shape,
+        inherited: Option<ChildDefaults>,
         current_path: FieldPath,
         key_prefix: KeyPath,
     ) -> Result<Vec<Resolution>, SchemaError> {
         match shape.ty {
             Type::User(UserType::Struct(struct_type)) => {
-                self.analyze_struct(struct_type, current_path, key_prefix)
+                self.analyze_struct(struct_type, shape, inherited, current_path, key_prefix)
             }
             Type::User(UserType::Enum(enum_type)) => {
                 // Enum at root level: create one configuration per variant
-                self.analyze_enum(shape, enum_type, current_path, key_prefix)
+                self.analyze_enum(shape, enum_type, inherited, current_path, key_prefix)
             }
             _ => {
                 // For non-struct types at root level, return single empty config
@@ -2420,6 +3379,7 @@ impl SchemaBuilder {
         &self,
         shape: &'static Shape,
         enum_type: facet_core::EnumType,
+        inherited: Option<ChildDefaults>,
         current_path: FieldPath,
         key_prefix: KeyPath,
     ) -> Result<Vec<Resolution>, SchemaError> {
@@ -2430,19 +3390,24 @@ impl SchemaBuilder {
             let mut config = Resolution::new();
 
             // Record this variant selection
-            config.add_variant_selection(current_path.clone(), enum_name, variant.name);
+            config.add_variant_selection(current_path.clone(), enum_name, variant);
 
             let variant_path = current_path.push_variant("", variant.name);
 
             // Get resolutions from the variant's content
-            let variant_configs =
-                self.analyze_variant_content(variant, &variant_path, &key_prefix)?;
+            let variant_configs = self.analyze_variant_content(
+                variant,
+                shape,
+                inherited,
+                &variant_path,
+                &key_prefix,
+            )?;
 
             // Merge each variant config into the base
             for variant_config in variant_configs {
                 let mut final_config = config.clone();
                 final_config.merge(&variant_config)?;
-                result.push(final_config);
+                self.push_resolution(&mut result, final_config, shape)?;
             }
         }
 
@@ -2456,6 +3421,8 @@ impl SchemaBuilder {
     fn analyze_struct(
         &self,
         struct_type: StructType,
+        shape: &'static Shape,
+        inherited: Option<ChildDefaults>,
         current_path: FieldPath,
         key_prefix: KeyPath,
     ) -> Result<Vec<Resolution>, SchemaError> {
@@ -2464,8 +3431,25 @@ impl SchemaBuilder {
 
         // Process each field, potentially multiplying resolutions
         for field in struct_type.fields {
-            configs =
-                self.analyze_field_into_configs(field, &current_path, &key_prefix, configs)?;
+            if let Some(source) = field.flatten_prefix_from()
+                && !struct_type
+                    .fields
+                    .iter()
+                    .any(|f| f.name == source && !f.is_flattened())
+            {
+                return Err(SchemaError::InvalidFlattenPrefix {
+                    field: field.name,
+                    reason: "the field named by `flatten_prefix_from` is not a sibling",
+                });
+            }
+            configs = self.analyze_field_into_configs(
+                field,
+                shape,
+                inherited,
+                &current_path,
+                &key_prefix,
+                configs,
+            )?;
         }
 
         Ok(configs)
@@ -2474,11 +3458,15 @@ impl SchemaBuilder {
     /// Process a field and return updated resolutions.
     /// If the field is a flattened enum, this may multiply the number of configs.
     ///
+    /// - `container`: The struct or enum declaring the field (for defaults)
+    /// - `inherited`: The defaults handed down to `container`
     /// - `parent_path`: The internal field path to the parent (for FieldInfo)
     /// - `key_prefix`: The serialized key path prefix (for known_paths)
     fn analyze_field_into_configs(
         &self,
         field: &'static Field,
+        container: &'static Shape,
+        inherited: Option<ChildDefaults>,
         parent_path: &FieldPath,
         key_prefix: &KeyPath,
         mut configs: Vec<Resolution>,
@@ -2487,18 +3475,26 @@ impl SchemaBuilder {
 
         if is_flatten {
             // Flattened: inner keys bubble up to current level (same key_prefix)
-            self.analyze_flattened_field_into_configs(field, parent_path, key_prefix, configs)
+            self.analyze_flattened_field_into_configs(
+                field,
+                container,
+                inherited,
+                parent_path,
+                key_prefix,
+                configs,
+            )
         } else {
             // Regular field: add to ALL current configs
             let field_path = parent_path.push_field(field.name);
-            let required = !field.has_default() && !is_option_type(field.shape());
+            let required = is_required(field, container);
+            let name = field.effective_name_in(container, inherited);
 
             // Build the key path for this field (uses effective_name for wire format)
             let mut field_key_path = key_prefix.clone();
-            field_key_path.push(field.effective_name());
+            field_key_path.push(name);
 
             let field_info = FieldInfo {
-                serialized_name: field.effective_name(),
+                serialized_name: name,
                 path: field_path,
                 required,
                 value_shape: field.shape(),
@@ -2519,8 +3515,12 @@ impl SchemaBuilder {
             // If the field's value is a struct, recurse to collect nested key paths
             // (for probing, not for flattening - these are nested in serialized format)
             // This may fork resolutions if the nested struct contains flattened enums!
-            configs =
-                self.collect_nested_key_paths_for_shape(field.shape(), &field_key_path, configs)?;
+            configs = self.collect_nested_key_paths_for_shape(
+                field.shape(),
+                container.defaults_for_children(inherited),
+                &field_key_path,
+                configs,
+            )?;
 
             Ok(configs)
         }
@@ -2532,13 +3532,18 @@ impl SchemaBuilder {
     fn collect_nested_key_paths_for_shape(
         &self,
         shape: &'static Shape,
+        inherited: Option<ChildDefaults>,
         key_prefix: &KeyPath,
         configs: Vec<Resolution>,
     ) -> Result<Vec<Resolution>, SchemaError> {
         match shape.ty {
-            Type::User(UserType::Struct(struct_type)) => {
-                self.collect_nested_key_paths_for_struct(struct_type, key_prefix, configs)
-            }
+            Type::User(UserType::Struct(struct_type)) => self.collect_nested_key_paths_for_struct(
+                struct_type,
+                shape,
+                inherited,
+                key_prefix,
+                configs,
+            ),
             _ => Ok(configs),
         }
     }
@@ -2547,20 +3552,23 @@ impl SchemaBuilder {
     fn collect_nested_key_paths_for_struct(
         &self,
         struct_type: StructType,
+        shape: &'static Shape,
+        inherited: Option<ChildDefaults>,
         key_prefix: &KeyPath,
         mut configs: Vec<Resolution>,
     ) -> Result<Vec<Resolution>, SchemaError> {
+        let children = shape.defaults_for_children(inherited);
         for field in struct_type.fields {
             let is_flatten = field.is_flattened();
             let mut field_key_path = key_prefix.clone();
 
             if is_flatten {
                 // Flattened field: keys bubble up to current level, may fork configs
-                configs =
-                    self.collect_nested_key_paths_for_flattened(field, key_prefix, configs)?;
+                configs = self
+                    .collect_nested_key_paths_for_flattened(field, children, key_prefix, configs)?;
             } else {
                 // Regular field: add key path and recurse
-                field_key_path.push(field.effective_name());
+                field_key_path.push(field.effective_name_in(shape, inherited));
 
                 for config in &mut configs {
                     config.add_key_path(field_key_path.clone());
@@ -2569,6 +3577,7 @@ impl SchemaBuilder {
                 // Recurse into nested structs
                 configs = self.collect_nested_key_paths_for_shape(
                     field.shape(),
+                    children,
                     &field_key_path,
                     configs,
                 )?;
@@ -2579,18 +3588,27 @@ impl SchemaBuilder {
 
     /// Handle flattened fields when collecting nested key paths.
     /// This may fork resolutions for flattened enums.
+    ///
+    /// `inherited` is the defaults handed down to the flattened value.
     fn collect_nested_key_paths_for_flattened(
         &self,
         field: &'static Field,
+        inherited: Option<ChildDefaults>,
         key_prefix: &KeyPath,
         configs: Vec<Resolution>,
     ) -> Result<Vec<Resolution>, SchemaError> {
-        let shape = field.shape();
+        let (shape, _) = field.shape().flatten_target();
 
         match shape.ty {
             Type::User(UserType::Struct(struct_type)) => {
                 // Flattened struct: recurse with same key_prefix
-                self.collect_nested_key_paths_for_struct(struct_type, key_prefix, configs)
+                self.collect_nested_key_paths_for_struct(
+                    struct_type,
+                    shape,
+                    inherited,
+                    key_prefix,
+                    configs,
+                )
             }
             Type::User(UserType::Enum(enum_type)) => {
                 // Flattened enum: fork resolutions
@@ -2616,6 +3634,7 @@ impl SchemaBuilder {
                             let mut updated_config = config;
                             updated_config = self.collect_variant_key_paths(
                                 variant,
+                                shape.defaults_for_children(inherited),
                                 key_prefix,
                                 updated_config,
                             )?;
@@ -2634,9 +3653,12 @@ impl SchemaBuilder {
     }
 
     /// Collect key paths from an enum variant's content.
+    ///
+    /// `inherited` is the defaults handed down to the values the variant holds.
     fn collect_variant_key_paths(
         &self,
         variant: &'static Variant,
+        inherited: Option<ChildDefaults>,
         key_prefix: &KeyPath,
         mut config: Resolution,
     ) -> Result<Resolution, SchemaError> {
@@ -2649,6 +3671,8 @@ impl SchemaBuilder {
             if let Type::User(UserType::Struct(inner_struct)) = inner_shape.ty {
                 let configs = self.collect_nested_key_paths_for_struct(
                     inner_struct,
+                    inner_shape,
+                    inherited,
                     key_prefix,
                     vec![config],
                 )?;
@@ -2663,6 +3687,7 @@ impl SchemaBuilder {
             if is_flatten {
                 let configs = self.collect_nested_key_paths_for_flattened(
                     variant_field,
+                    inherited,
                     key_prefix,
                     vec![config],
                 )?;
@@ -2674,6 +3699,7 @@ impl SchemaBuilder {
 
                 let configs = self.collect_nested_key_paths_for_shape(
                     variant_field.shape(),
+                    inherited,
                     &field_key_path,
                     vec![config],
                 )?;
@@ -2686,36 +3712,52 @@ impl SchemaBuilder {
     /// Collect ONLY key paths from a variant's content (no fields added).
     /// Used for externally-tagged enums where variant content is nested and
     /// will be parsed separately by the deserializer.
+    ///
+    /// `inherited` is the defaults handed down to the values the variant holds.
     fn collect_variant_key_paths_only(
         &self,
         variant: &'static Variant,
+        inherited: Option<ChildDefaults>,
         key_prefix: &KeyPath,
         config: &mut Resolution,
     ) -> Result<(), SchemaError> {
-        Self::collect_variant_fields_key_paths_only(variant, key_prefix, config);
+        Self::collect_variant_fields_key_paths_only(variant, inherited, key_prefix, config);
         Ok(())
     }
 
     /// Recursively collect key paths from a struct (no fields added).
     fn collect_struct_key_paths_only(
         struct_type: StructType,
+        shape: &'static Shape,
+        inherited: Option<ChildDefaults>,
         key_prefix: &KeyPath,
         config: &mut Resolution,
     ) {
+        let children = shape.defaults_for_children(inherited);
         for field in struct_type.fields {
             let is_flatten = field.is_flattened();
 
             if is_flatten {
                 // Flattened field: keys bubble up to current level
-                Self::collect_shape_key_paths_only(field.shape(), key_prefix, config);
+                Self::collect_shape_key_paths_only(
+                    field.shape().flatten_target().0,
+                    children,
+                    key_prefix,
+                    config,
+                );
             } else {
                 // Regular field: add its key path
                 let mut field_key_path = key_prefix.clone();
-                field_key_path.push(field.effective_name());
+                field_key_path.push(field.effective_name_in(shape, inherited));
                 config.add_key_path(field_key_path.clone());
 
                 // Recurse into nested types
-                Self::collect_shape_key_paths_only(field.shape(), &field_key_path, config);
+                Self::collect_shape_key_paths_only(
+                    field.shape(),
+                    children,
+                    &field_key_path,
+                    config,
+                );
             }
         }
     }
@@ -2723,18 +3765,28 @@ impl SchemaBuilder {
     /// Recursively collect key paths from a shape (struct or enum).
     fn collect_shape_key_paths_only(
         shape: &'static Shape,
+        inherited: Option<ChildDefaults>,
         key_prefix: &KeyPath,
         config: &mut Resolution,
     ) {
         match shape.ty {
             Type::User(UserType::Struct(inner_struct)) => {
-                Self::collect_struct_key_paths_only(inner_struct, key_prefix, config);
+                Self::collect_struct_key_paths_only(
+                    inner_struct,
+                    shape,
+                    inherited,
+                    key_prefix,
+                    config,
+                );
             }
             Type::User(UserType::Enum(enum_type)) => {
                 // For enums, collect key paths from ALL variants
                 // (we don't know which variant will be selected)
+                let children = shape.defaults_for_children(inherited);
                 for variant in enum_type.variants {
-                    Self::collect_variant_fields_key_paths_only(variant, key_prefix, config);
+                    Self::collect_variant_fields_key_paths_only(
+                        variant, children, key_prefix, config,
+                    );
                 }
             }
             _ => {}
@@ -2744,13 +3796,14 @@ impl SchemaBuilder {
     /// Collect key paths from a variant's fields (not the variant itself).
     fn collect_variant_fields_key_paths_only(
         variant: &'static Variant,
+        inherited: Option<ChildDefaults>,
         key_prefix: &KeyPath,
         config: &mut Resolution,
     ) {
         // Check if this is a newtype variant (single unnamed field)
         if variant.data.fields.len() == 1 && variant.data.fields[0].name == "0" {
             let inner_field = &variant.data.fields[0];
-            Self::collect_shape_key_paths_only(inner_field.shape(), key_prefix, config);
+            Self::collect_shape_key_paths_only(inner_field.shape(), inherited, key_prefix, config);
             return;
         }
 
@@ -2761,7 +3814,12 @@ impl SchemaBuilder {
             config.add_key_path(field_key_path.clone());
 
             // Recurse into nested types
-            Self::collect_shape_key_paths_only(variant_field.shape(), &field_key_path, config);
+            Self::collect_shape_key_paths_only(
+                variant_field.shape(),
+                inherited,
+                &field_key_path,
+                config,
+            );
         }
     }
 
@@ -2770,32 +3828,49 @@ impl SchemaBuilder {
     /// For flattened fields, the inner keys bubble up to the current level,
     /// so we pass the same key_prefix (not key_prefix + field.name).
     ///
-    /// If the field is `Option<T>`, we unwrap to get T and mark all resulting
-    /// fields as optional (since the entire flattened block can be omitted).
+    /// Smart pointers (`Box<T>`, `Arc<T>`, ...) are unwrapped to `T`. If the field
+    /// is `Option<T>`, we unwrap to get T and mark all resulting fields as optional
+    /// (since the entire flattened block can be omitted).
     fn analyze_flattened_field_into_configs(
         &self,
         field: &'static Field,
+        container: &'static Shape,
+        inherited: Option<ChildDefaults>,
         parent_path: &FieldPath,
         key_prefix: &KeyPath,
         configs: Vec<Resolution>,
     ) -> Result<Vec<Resolution>, SchemaError> {
         let field_path = parent_path.push_field(field.name);
-        let original_shape = field.shape();
+        let name = field.effective_name_in(container, inherited);
+        // The flattened value is still a child of the container
+        let children = container.defaults_for_children(inherited);
 
-        // Check if this is Option<T> - if so, unwrap and mark all fields optional
-        let (shape, is_optional_flatten) = match unwrap_option_type(original_shape) {
-            Some(inner) => (inner, true),
-            None => (original_shape, false),
-        };
+        // Unwrap Option<T> and smart pointers; if there was an Option, mark all fields optional.
+        // Neither adds a path segment: the navigator enters them when it opens the field.
+        let (shape, is_optional_flatten) = field.shape().flatten_target();
 
         let (shape, field_path) = unwrap_transparent_with_path(shape, field_path);
 
+        if field.flatten_prefix_from().is_some()
+            && !matches!(shape.ty, Type::User(UserType::Struct(_)))
+        {
+            return Err(SchemaError::InvalidFlattenPrefix {
+                field: field.name,
+                reason: "only flattened structs can be prefixed",
+            });
+        }
+
         match shape.ty {
             Type::User(UserType::Struct(struct_type)) => {
                 // Flatten a struct: get its resolutions and merge into each of ours
                 // Key prefix stays the same - inner keys bubble up
-                let mut struct_configs =
-                    self.analyze_struct(struct_type, field_path, key_prefix.clone())?;
+                let mut struct_configs = self.analyze_struct(
+                    struct_type,
+                    shape,
+                    children,
+                    field_path.clone(),
+                    key_prefix.clone(),
+                )?;
 
                 // If the flatten field was Option<T>, mark all inner fields as optional
                 if is_optional_flatten {
@@ -2804,6 +3879,22 @@ impl SchemaBuilder {
                     }
                 }
 
+                // Prefixed keys aren't known ahead of time: keep the inner
+                // fields apart instead of merging them
+                if let Some(source) = field.flatten_prefix_from() {
+                    let group = dynamic_prefix_group(
+                        field,
+                        parent_path.push_field(source),
+                        field_path,
+                        &struct_configs,
+                    )?;
+                    let mut configs = configs;
+                    for config in &mut configs {
+                        config.add_dynamic_prefix(group.clone());
+                    }
+                    return Ok(configs);
+                }
+
                 // Each of our configs combines with each struct config
                 // (usually struct_configs has 1 element unless it contains enums)
                 let mut result = Vec::new();
@@ -2811,7 +3902,7 @@ impl SchemaBuilder {
                     for struct_config in &struct_configs {
                         let mut merged = base_config.clone();
                         merged.merge(struct_config)?;
-                        result.push(merged);
+                        self.push_resolution(&mut result, merged, shape)?;
                     }
                 }
                 Ok(result)
@@ -2833,7 +3924,7 @@ impl SchemaBuilder {
                 for base_config in configs {
                     for variant in enum_type.variants {
                         let mut forked = base_config.clone();
-                        forked.add_variant_selection(field_path.clone(), enum_name, variant.name);
+                        forked.add_variant_selection(field_path.clone(), enum_name, variant);
 
                         let variant_path = field_path.push_variant(field.name, variant.name);
 
@@ -2864,11 +3955,12 @@ impl SchemaBuilder {
                                 // Only add them to known_paths for depth-aware probing.
                                 self.collect_variant_key_paths_only(
                                     variant,
+                                    shape.defaults_for_children(children),
                                     &variant_key_prefix,
                                     &mut forked,
                                 )?;
 
-                                result.push(forked);
+                                self.push_resolution(&mut result, forked, shape)?;
                             }
                             EnumRepr::Flattened => {
                                 // For flattened/untagged enums, the variant's fields appear at the
@@ -2879,6 +3971,8 @@ impl SchemaBuilder {
                                 // Key prefix stays the same - inner keys bubble up
                                 let mut variant_configs = self.analyze_variant_content(
                                     variant,
+                                    shape,
+                                    children,
                                     &variant_path,
                                     key_prefix,
                                 )?;
@@ -2894,7 +3988,7 @@ impl SchemaBuilder {
                                 for variant_config in variant_configs {
                                     let mut final_config = forked.clone();
                                     final_config.merge(&variant_config)?;
-                                    result.push(final_config);
+                                    self.push_resolution(&mut result, final_config, shape)?;
                                 }
                             }
                             EnumRepr::InternallyTagged { tag } => {
@@ -2923,6 +4017,8 @@ impl SchemaBuilder {
                                 // Key prefix stays the same - inner keys are at the same level
                                 let mut variant_configs = self.analyze_variant_content(
                                     variant,
+                                    shape,
+                                    children,
                                     &variant_path,
                                     key_prefix,
                                 )?;
@@ -2938,7 +4034,7 @@ impl SchemaBuilder {
                                 for variant_config in variant_configs {
                                     let mut final_config = forked.clone();
                                     final_config.merge(&variant_config)?;
-                                    result.push(final_config);
+                                    self.push_resolution(&mut result, final_config, shape)?;
                                 }
                             }
                             EnumRepr::AdjacentlyTagged { tag, content } => {
@@ -2971,11 +4067,12 @@ impl SchemaBuilder {
                                 // Collect key paths for probing
                                 self.collect_variant_key_paths_only(
                                     variant,
+                                    shape.defaults_for_children(children),
                                     &content_key_prefix,
                                     &mut forked,
                                 )?;
 
-                                result.push(forked);
+                                self.push_resolution(&mut result, forked, shape)?;
                             }
                         }
                     }
@@ -2989,7 +4086,7 @@ impl SchemaBuilder {
                     // be deserialized from field name strings is the deserializer's problem,
                     // not the solver's.
                     let field_info = FieldInfo {
-                        serialized_name: field.effective_name(),
+                        serialized_name: name,
                         path: field_path,
                         required: false, // Catch-all maps are never required
                         value_shape: shape,
@@ -3017,7 +4114,7 @@ impl SchemaBuilder {
                 // Check if this is a DynamicValue type (like facet_value::Value) - also a catch-all
                 if matches!(&shape.def, Def::DynamicValue(_)) {
                     let field_info = FieldInfo {
-                        serialized_name: field.effective_name(),
+                        serialized_name: name,
                         path: field_path,
                         required: false, // Catch-all dynamic values are never required
                         value_shape: shape,
@@ -3043,14 +4140,14 @@ impl SchemaBuilder {
                 // Can't flatten other types - treat as regular field
                 // For Option<T> flatten, also consider optionality from the wrapper
                 let required =
-                    !field.has_default() && !is_option_type(shape) && !is_optional_flatten;
+                    is_required(field, container) && !is_option_type(shape) && !is_optional_flatten;
 
                 // For non-flattenable types, add the field with its key path
                 let mut field_key_path = key_prefix.clone();
-                field_key_path.push(field.effective_name());
+                field_key_path.push(name);
 
                 let field_info = FieldInfo {
-                    serialized_name: field.effective_name(),
+                    serialized_name: name,
                     path: field_path,
                     required,
                     value_shape: shape,
@@ -3074,11 +4171,14 @@ impl SchemaBuilder {
 
     /// Analyze a variant's content and return resolutions.
     ///
+    /// - `inherited`: The defaults handed down to the enum
     /// - `variant_path`: The internal field path (for FieldInfo)
     /// - `key_prefix`: The serialized key path prefix (for known_paths)
     fn analyze_variant_content(
         &self,
         variant: &'static Variant,
+        enum_shape: &'static Shape,
+        inherited: Option<ChildDefaults>,
         variant_path: &FieldPath,
         key_prefix: &KeyPath,
     ) -> Result<Vec<Resolution>, SchemaError> {
@@ -3110,15 +4210,27 @@ impl SchemaBuilder {
 
             if let Type::User(UserType::Struct(inner_struct)) = effective_shape.ty {
                 let inner_path = variant_path.push_field("0");
-                return self.analyze_struct(inner_struct, inner_path, key_prefix.clone());
+                return self.analyze_struct(
+                    inner_struct,
+                    effective_shape,
+                    enum_shape.defaults_for_children(inherited),
+                    inner_path,
+                    key_prefix.clone(),
+                );
             }
         }
 
         // Named fields or multiple fields - analyze as a pseudo-struct
         let mut configs = vec![Resolution::new()];
         for variant_field in variant.data.fields {
-            configs =
-                self.analyze_field_into_configs(variant_field, variant_path, key_prefix, configs)?;
+            configs = self.analyze_field_into_configs(
+                variant_field,
+                enum_shape,
+                inherited,
+                variant_path,
+                key_prefix,
+                configs,
+            )?;
         }
         Ok(configs)
     }
@@ -3138,26 +4250,101 @@ impl SchemaBuilder {
             }
         }
 
+        // Aliases resolve to the same configurations as the field they name,
+        // unless another field already uses the alias as its real name. An
+        // alias must name one field, whichever resolution declares it.
+        let mut aliases: BTreeMap<&'static str, &'static str> = BTreeMap::new();
+        for config in &resolutions {
+            for field_info in config.fields().values() {
+                let Some(alias) = field_info.field.alias else {
+                    continue;
+                };
+                if field_to_resolutions.contains_key(alias) {
+                    continue;
+                }
+                match aliases.get(alias) {
+                    Some(&first) if first != field_info.serialized_name => {
+                        return Err(SchemaError::AliasCollision {
+                            alias,
+                            first,
+                            second: field_info.serialized_name,
+                        });
+                    }
+                    Some(_) => {}
+                    None => {
+                        aliases.insert(alias, field_info.serialized_name);
+                    }
+                }
+            }
+        }
+        for (idx, config) in resolutions.iter().enumerate() {
+            for field_info in config.fields().values() {
+                if let Some(alias) = field_info.field.alias
+                    && aliases.contains_key(alias)
+                {
+                    field_to_resolutions
+                        .entry(alias)
+                        .or_insert_with(|| ResolutionSet::empty(num_resolutions))
+                        .insert(idx);
+                }
+            }
+        }
+
         // Build DOM inverted index: (category, name) → bitmask of config indices
         let mut dom_field_to_resolutions: BTreeMap<(FieldCategory, &'static str), ResolutionSet> =
             BTreeMap::new();
         if self.format == Format::Dom {
             for (idx, config) in resolutions.iter().enumerate() {
                 for field_info in config.fields().values() {
-                    dom_field_to_resolutions
-                        .entry((field_info.category, field_info.serialized_name))
-                        .or_insert_with(|| ResolutionSet::empty(num_resolutions))
-                        .insert(idx);
+                    let names = core::iter::once(field_info.serialized_name).chain(
+                        field_info
+                            .field
+                            .alias
+                            .filter(|alias| aliases.contains_key(alias)),
+                    );
+                    for name in names {
+                        dom_field_to_resolutions
+                            .entry((field_info.category, name))
+                            .or_insert_with(|| ResolutionSet::empty(num_resolutions))
+                            .insert(idx);
+                    }
                 }
             }
         }
 
+        // Resolutions with a catch-all accept every key of its category, so
+        // they stay candidates whichever field a key names.
+        let mut catch_all_resolutions: BTreeMap<FieldCategory, ResolutionSet> = BTreeMap::new();
+        for (idx, config) in resolutions.iter().enumerate() {
+            for category in config.catch_all_maps().keys() {
+                catch_all_resolutions
+                    .entry(*category)
+                    .or_insert_with(|| ResolutionSet::empty(num_resolutions))
+                    .insert(idx);
+            }
+        }
+        if let Some(sinks) = catch_all_resolutions.get(&FieldCategory::Flat) {
+            for set in field_to_resolutions.values_mut() {
+                set.union_with(sinks);
+            }
+        }
+        for ((category, _), set) in &mut dom_field_to_resolutions {
+            if let Some(sinks) = catch_all_resolutions.get(category) {
+                set.union_with(sinks);
+            }
+        }
+
         Ok(Schema {
             shape: self.shape,
             format: self.format,
+            inherited: self.inherited,
             resolutions,
             field_to_resolutions,
             dom_field_to_resolutions,
+            catch_all_resolutions,
+            aliases,
+            key_normalization: KeyNormalization::Exact,
+            normalized_names: BTreeMap::new(),
         })
     }
 }
@@ -3167,12 +4354,21 @@ const fn is_option_type(shape: &'static Shape) -> bool {
     matches!(shape.def, Def::Option(_))
 }
 
-/// If shape is `Option<T>`, returns `Some(T's shape)`. Otherwise returns `None`.
-const fn unwrap_option_type(shape: &'static Shape) -> Option<&'static Shape> {
-    match shape.def {
-        Def::Option(option_def) => Some(option_def.t),
-        _ => None,
-    }
+/// Whether the input must provide `field`, declared in `container` (a struct,
+/// or the enum of a variant).
+///
+/// Follows the rules the deserializer uses to fill in missing fields: a field
+/// with `#[facet(default)]` or `#[facet(default = ...)]`, an `Option`, `()`,
+/// and, when the container itself carries `#[facet(default)]`, any field whose
+/// type implements `Default` can be left out. Collections, which the
+/// deserializer also defaults to empty, stay required so that their keys keep
+/// telling variants apart.
+fn is_required(field: &'static Field, container: &'static Shape) -> bool {
+    let shape = field.shape();
+    !(field.has_default()
+        || is_option_type(shape)
+        || shape.is_type::<()>()
+        || (container.has_default_attr() && shape.is_default()))
 }
 
 /// Unwrap transparent wrappers and proxies to get the effective shape for field matching.
@@ -3223,3 +4419,35 @@ fn unwrap_transparent_with_path(
     }
     (shape, path)
 }
+
+/// Gather the fields of a flattened struct marked `flatten_prefix_from` into
+/// a [`DynamicPrefix`] group.
+fn dynamic_prefix_group(
+    field: &'static Field,
+    source: FieldPath,
+    path: FieldPath,
+    struct_configs: &[Resolution],
+) -> Result<DynamicPrefix, SchemaError> {
+    let invalid = |reason| SchemaError::InvalidFlattenPrefix {
+        field: field.name,
+        reason,
+    };
+    let [inner] = struct_configs else {
+        return Err(invalid("the flattened struct contains flattened enums"));
+    };
+    if !inner.catch_all_maps().is_empty() || !inner.dynamic_prefixes().is_empty() {
+        return Err(invalid(
+            "the flattened struct contains a flattened map or another prefixed field",
+        ));
+    }
+
+    Ok(DynamicPrefix {
+        source,
+        path,
+        fields: inner
+            .fields()
+            .values()
+            .map(|info| (info.serialized_name, info.clone()))
+            .collect(),
+    })
+}
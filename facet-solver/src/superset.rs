@@ -0,0 +1,227 @@
+//! Conservative descriptions of the values a set of shapes accepts.
+//!
+//! When candidates disagree on a key's type, the usual answer is to look at
+//! the value and [`Solver::satisfy`](crate::Solver::satisfy) the fields it
+//! fits. Formats that can rewind (or that buffered the whole object up front)
+//! can do that freely. Streaming formats — large NDJSON objects, SAX-style XML —
+//! can't, and buffering the whole object just in case defeats the point.
+//!
+//! A [`ValueSuperset`] tells such a deserializer what to read the one
+//! ambiguous value into: e.g. "an integer between `i16::MIN` and `u32::MAX`",
+//! or "a string or a map". It never rules out a value that one of the shapes
+//! would accept, so the deserializer can buffer just that value, then satisfy
+//! the solver with it.
+
+use facet_core::{Def, ScalarType, Shape, StructKind, Type, UserType};
+
+/// Range of integers accepted by at least one shape.
+///
+/// Bounds are inclusive. `min` is signed and `max` unsigned, so the union of
+/// e.g. `i8` and `u64` is representable exactly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntegerRange {
+    /// Smallest accepted value.
+    pub min: i128,
+    /// Largest accepted value.
+    pub max: u128,
+}
+
+impl IntegerRange {
+    /// The range of values of an integer scalar type, or `None` for other types.
+    pub const fn of_scalar(scalar: ScalarType) -> Option<Self> {
+        let (min, max) = match scalar {
+            ScalarType::U8 => (0, u8::MAX as u128),
+            ScalarType::U16 => (0, u16::MAX as u128),
+            ScalarType::U32 => (0, u32::MAX as u128),
+            ScalarType::U64 => (0, u64::MAX as u128),
+            ScalarType::U128 => (0, u128::MAX),
+            ScalarType::USize => (0, usize::MAX as u128),
+            ScalarType::I8 => (i8::MIN as i128, i8::MAX as u128),
+            ScalarType::I16 => (i16::MIN as i128, i16::MAX as u128),
+            ScalarType::I32 => (i32::MIN as i128, i32::MAX as u128),
+            ScalarType::I64 => (i64::MIN as i128, i64::MAX as u128),
+            ScalarType::I128 => (i128::MIN, i128::MAX as u128),
+            ScalarType::ISize => (isize::MIN as i128, isize::MAX as u128),
+            _ => return None,
+        };
+        Some(Self { min, max })
+    }
+
+    /// The smallest range containing both ranges.
+    pub const fn union(self, other: Self) -> Self {
+        Self {
+            min: if self.min < other.min {
+                self.min
+            } else {
+                other.min
+            },
+            max: if self.max > other.max {
+                self.max
+            } else {
+                other.max
+            },
+        }
+    }
+
+    /// Whether negative values are in range.
+    pub const fn is_signed(&self) -> bool {
+        self.min < 0
+    }
+}
+
+/// The kinds of value accepted by at least one of a set of shapes.
+///
+/// Built with [`ValueSuperset::of`] or [`ValueSuperset::of_shapes`], and
+/// returned by the solver in [`KeyResult::Buffer`](crate::KeyResult::Buffer).
+/// Scalars without a kind of their own (timestamps, UUIDs, network
+/// addresses, ...) count as strings, which is how text formats write them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ValueSuperset {
+    /// `null` / a missing value (`Option`, unit structs).
+    pub null: bool,
+    /// Booleans.
+    pub bool: bool,
+    /// Integers in this range.
+    pub integer: Option<IntegerRange>,
+    /// Floating-point numbers.
+    pub float: bool,
+    /// Strings.
+    pub string: bool,
+    /// Sequences (lists, sets, arrays, tuples).
+    pub sequence: bool,
+    /// Maps and objects (structs, maps, externally tagged enum variants).
+    pub map: bool,
+    /// Some shape accepts values the solver can't classify (dynamic values,
+    /// untagged enums, opaque types); the whole value must be buffered.
+    pub any: bool,
+}
+
+impl ValueSuperset {
+    /// The kinds of value `shape` accepts.
+    pub fn of(shape: &'static Shape) -> Self {
+        let mut superset = Self::default();
+        superset.add(shape);
+        superset
+    }
+
+    /// The kinds of value accepted by at least one of `shapes`.
+    pub fn of_shapes(shapes: impl IntoIterator<Item = &'static Shape>) -> Self {
+        let mut superset = Self::default();
+        for shape in shapes {
+            superset.add(shape);
+        }
+        superset
+    }
+
+    /// The union of both supersets.
+    pub fn union(self, other: Self) -> Self {
+        Self {
+            null: self.null || other.null,
+            bool: self.bool || other.bool,
+            integer: match (self.integer, other.integer) {
+                (Some(a), Some(b)) => Some(a.union(b)),
+                (a, b) => a.or(b),
+            },
+            float: self.float || other.float,
+            string: self.string || other.string,
+            sequence: self.sequence || other.sequence,
+            map: self.map || other.map,
+            any: self.any || other.any,
+        }
+    }
+
+    /// Whether the value is a number: only integers and floats are accepted.
+    ///
+    /// A deserializer can parse such a value as the widest number it may be
+    /// (e.g. an `i128`/`u128` within [`Self::integer`], or an `f64`) without
+    /// keeping its text around.
+    pub fn is_numeric(&self) -> bool {
+        (self.integer.is_some() || self.float)
+            && !(self.null || self.bool || self.string || self.sequence || self.map || self.any)
+    }
+
+    /// Whether the value is a scalar: no sequence, map or unclassified shape
+    /// accepts it.
+    pub fn is_scalar(&self) -> bool {
+        !(self.sequence || self.map || self.any)
+    }
+
+    fn add(&mut self, shape: &'static Shape) {
+        match &shape.def {
+            Def::Option(opt) => {
+                self.null = true;
+                self.add(opt.t);
+                return;
+            }
+            Def::Pointer(ptr) => {
+                match ptr.pointee {
+                    Some(pointee) => self.add(pointee),
+                    None => self.any = true,
+                }
+                return;
+            }
+            Def::List(_) | Def::Array(_) | Def::Slice(_) | Def::Set(_) => {
+                self.sequence = true;
+                return;
+            }
+            Def::Map(_) => {
+                self.map = true;
+                return;
+            }
+            Def::Scalar => {
+                self.add_scalar(shape);
+                return;
+            }
+            _ => {}
+        }
+
+        // Transparent wrappers and proxies are written as the type they wrap
+        if let Some(inner) = shape.inner {
+            return self.add(inner);
+        }
+        if let Some(proxy) = shape.proxy {
+            return self.add(proxy.shape);
+        }
+
+        match &shape.ty {
+            Type::User(UserType::Struct(st)) => match st.kind {
+                StructKind::Unit => self.null = true,
+                StructKind::Struct => self.map = true,
+                StructKind::TupleStruct | StructKind::Tuple => self.sequence = true,
+            },
+            Type::User(UserType::Enum(en)) => {
+                if shape.is_untagged() {
+                    self.any = true;
+                } else {
+                    // Unit variants are written as strings unless there's a tag
+                    // field to hold their name.
+                    let is_unit = |v: &facet_core::Variant| v.data.kind == StructKind::Unit;
+                    let has_unit = en.variants.iter().any(is_unit);
+                    let has_data = !en.variants.iter().all(is_unit);
+                    self.string |= has_unit && shape.tag.is_none();
+                    self.map |= has_data || shape.tag.is_some();
+                }
+            }
+            _ => self.any = true,
+        }
+    }
+
+    fn add_scalar(&mut self, shape: &'static Shape) {
+        match shape.scalar_type() {
+            Some(ScalarType::Unit) => self.null = true,
+            Some(ScalarType::Bool) => self.bool = true,
+            Some(ScalarType::F32 | ScalarType::F64) => self.float = true,
+            Some(scalar) => match IntegerRange::of_scalar(scalar) {
+                Some(range) => {
+                    self.integer = Some(match self.integer {
+                        Some(existing) => existing.union(range),
+                        None => range,
+                    });
+                }
+                None => self.string = true,
+            },
+            // Opaque scalars (timestamps, UUIDs, ...) parse from strings
+            None => self.string = true,
+        }
+    }
+}
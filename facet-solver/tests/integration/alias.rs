@@ -0,0 +1,195 @@
+//! Field alias tests.
+
+use facet::Facet;
+use facet_solver::{
+    KeyNormalization, KeyResult, Schema, SchemaError, SchemaOptions, Solver, SolverError,
+};
+use facet_testhelpers::test;
+
+#[derive(Facet)]
+struct Database {
+    url: String,
+    #[facet(alias = "pool_size")]
+    max_connections: u32,
+}
+
+#[derive(Facet)]
+struct Files {
+    #[facet(alias = "dir")]
+    root: String,
+}
+
+#[derive(Facet)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Backend {
+    Database(Database),
+    Files(Files),
+}
+
+#[derive(Facet)]
+struct Storage {
+    name: String,
+    #[facet(flatten)]
+    backend: Backend,
+}
+
+#[test]
+fn test_alias_maps_to_serialized_name() {
+    let schema = Schema::build_auto(Storage::SHAPE).unwrap();
+    assert_eq!(schema.canonical_key("pool_size"), Some("max_connections"));
+    assert_eq!(
+        schema.canonical_key("max_connections"),
+        Some("max_connections")
+    );
+    assert_eq!(schema.canonical_key("dir"), Some("root"));
+    assert_eq!(schema.canonical_key("nope"), None);
+}
+
+#[test]
+fn test_alias_disambiguates_like_its_field() {
+    let schema = Schema::build_auto(Storage::SHAPE).unwrap();
+    let mut solver = Solver::new(&schema);
+    solver.see_key("name");
+    match solver.see_key("dir") {
+        KeyResult::Solved(handle) => {
+            assert!(handle.resolution().field_by_name("root").is_some());
+        }
+        _ => panic!("expected Solved"),
+    }
+    assert!(solver.finish().is_ok());
+}
+
+#[test]
+fn test_alias_satisfies_required_field() {
+    let schema = Schema::build_auto(Storage::SHAPE).unwrap();
+    let mut solver = Solver::new(&schema);
+    solver.see_key("name");
+    solver.see_key("url");
+    solver.see_key("pool_size");
+    let handle = solver.finish().unwrap();
+    assert!(handle.resolution().field_by_name("url").is_some());
+}
+
+#[test]
+fn test_errors_report_alias_as_written() {
+    let schema = Schema::build_auto(Storage::SHAPE).unwrap();
+    let mut solver = Solver::new(&schema);
+    solver.see_key("name");
+    solver.see_key("pool_size");
+    match solver.finish() {
+        Err(SolverError::NoMatch {
+            input_fields,
+            missing_required,
+            ..
+        }) => {
+            assert!(
+                input_fields.contains(&"pool_size".to_string()),
+                "{input_fields:?}"
+            );
+            assert_eq!(missing_required, vec!["url"]);
+        }
+        Err(other) => panic!("expected NoMatch, got {other}"),
+        Ok(_) => panic!("expected NoMatch, got a resolution"),
+    }
+}
+
+#[test]
+fn test_field_name_wins_over_alias() {
+    #[derive(Facet)]
+    struct Shadowed {
+        #[facet(alias = "b")]
+        a: u32,
+        b: u32,
+    }
+
+    let schema = Schema::build_auto(Shadowed::SHAPE).unwrap();
+    assert_eq!(schema.canonical_key("b"), Some("b"));
+}
+
+#[test]
+fn test_alias_under_key_normalization() {
+    let schema = Schema::build_with_options(
+        Storage::SHAPE,
+        SchemaOptions::new().key_normalization(KeyNormalization::CaseAndSeparatorInsensitive),
+    )
+    .unwrap();
+    assert_eq!(schema.canonical_key("poolSize"), Some("max_connections"));
+}
+
+#[test]
+fn test_alias_shared_by_different_fields_is_rejected() {
+    #[derive(Facet)]
+    struct Http {
+        #[facet(alias = "target")]
+        url: String,
+    }
+
+    #[derive(Facet)]
+    struct Socket {
+        #[facet(alias = "target")]
+        path: String,
+    }
+
+    #[derive(Facet)]
+    #[repr(u8)]
+    #[allow(dead_code)]
+    enum Endpoint {
+        Http(Http),
+        Socket(Socket),
+    }
+
+    #[derive(Facet)]
+    struct Client {
+        #[facet(flatten)]
+        endpoint: Endpoint,
+    }
+
+    match Schema::build_auto(Client::SHAPE) {
+        Err(SchemaError::AliasCollision {
+            alias,
+            first,
+            second,
+        }) => {
+            assert_eq!(alias, "target");
+            let mut fields = [first, second];
+            fields.sort();
+            assert_eq!(fields, ["path", "url"]);
+        }
+        Err(other) => panic!("expected AliasCollision, got {other}"),
+        Ok(_) => panic!("expected AliasCollision, got a schema"),
+    }
+}
+
+#[test]
+fn test_alias_shared_by_same_named_fields_is_allowed() {
+    #[derive(Facet)]
+    struct Primary {
+        #[facet(alias = "addr")]
+        host: String,
+    }
+
+    #[derive(Facet)]
+    struct Replica {
+        #[facet(alias = "addr")]
+        host: String,
+        lag: u32,
+    }
+
+    #[derive(Facet)]
+    #[repr(u8)]
+    #[allow(dead_code)]
+    enum Role {
+        Primary(Primary),
+        Replica(Replica),
+    }
+
+    #[derive(Facet)]
+    struct Node {
+        #[facet(flatten)]
+        role: Role,
+    }
+
+    let schema = Schema::build_auto(Node::SHAPE).unwrap();
+    assert_eq!(schema.canonical_key("addr"), Some("host"));
+}
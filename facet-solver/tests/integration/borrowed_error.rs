@@ -0,0 +1,118 @@
+//! Tests for the allocation-free `finish_borrowed` error.
+
+use facet::Facet;
+use facet_solver::{Schema, SolveFailure, Solver, SolverError};
+use facet_testhelpers::test;
+
+#[derive(Facet)]
+struct Http {
+    url: String,
+}
+
+#[derive(Facet)]
+struct Git {
+    url: String,
+    branch: String,
+}
+
+#[derive(Facet)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Source {
+    Http(Http),
+    Git(Git),
+}
+
+#[derive(Facet)]
+struct Package {
+    name: String,
+    #[facet(flatten)]
+    source: Source,
+}
+
+#[derive(Facet)]
+struct Left {
+    side: String,
+}
+
+#[derive(Facet)]
+struct Right {
+    side: String,
+}
+
+#[derive(Facet)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Either {
+    Left(Left),
+    Right(Right),
+}
+
+#[derive(Facet)]
+struct Wrapper {
+    #[facet(flatten)]
+    inner: Either,
+}
+
+#[test]
+fn test_finish_borrowed_success_matches_finish() {
+    let schema = Schema::build_auto(Package::SHAPE).unwrap();
+    let mut solver = Solver::new(&schema);
+    solver.see_key("name");
+    solver.see_key("url");
+    let handle = solver.finish_borrowed().unwrap();
+    assert!(handle.resolution().field_by_name("branch").is_none());
+}
+
+#[test]
+fn test_no_match_names_missing_field() {
+    let schema = Schema::build_auto(Package::SHAPE).unwrap();
+    let mut solver = Solver::new(&schema);
+    solver.see_key("name");
+    let err = solver.finish_borrowed().unwrap_err();
+
+    let SolveFailure::NoMatch { candidate, missing } = err.failure() else {
+        panic!("expected NoMatch, got {err:?}");
+    };
+    let candidate = candidate.expect("candidates remain");
+    assert!(
+        err.schema().resolutions()[candidate]
+            .field_by_name("url")
+            .is_some()
+    );
+    assert_eq!(missing, Some("url"));
+    assert!(err.to_string().contains("missing field 'url'"), "{err}");
+}
+
+#[test]
+fn test_ambiguous_reports_two_resolutions() {
+    let schema = Schema::build_auto(Wrapper::SHAPE).unwrap();
+    let mut solver = Solver::new(&schema);
+    solver.see_key("side");
+    let err = solver.finish_borrowed().unwrap_err();
+    let SolveFailure::Ambiguous { first, second } = err.failure() else {
+        panic!("expected Ambiguous, got {err:?}");
+    };
+    assert_ne!(first, second);
+}
+
+#[test]
+fn test_into_detailed_matches_finish() {
+    let schema = Schema::build_auto(Package::SHAPE).unwrap();
+
+    let mut solver = Solver::new(&schema);
+    solver.see_key("name");
+    let detailed = solver.finish_borrowed().unwrap_err().into_detailed();
+
+    let mut solver = Solver::new(&schema);
+    solver.see_key("name");
+    let direct = solver.finish().unwrap_err();
+
+    assert_eq!(detailed.to_string(), direct.to_string());
+    match detailed {
+        SolverError::NoMatch {
+            missing_required, ..
+        } => assert_eq!(missing_required, vec!["url"]),
+        other => panic!("expected NoMatch, got {other:?}"),
+    }
+}
@@ -0,0 +1,74 @@
+//! The resolution budget for types with many flattened enums.
+
+use facet::Facet;
+use facet_solver::{DEFAULT_MAX_RESOLUTIONS, Schema, SchemaError, SchemaOptions};
+use facet_testhelpers::test;
+
+#[derive(Facet)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Auth {
+    Token { token: String },
+    Password { user: String, password: String },
+    Anonymous { anonymous: bool },
+    Certificate { cert: String },
+}
+
+#[derive(Facet)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Transport {
+    Tcp { host: String },
+    Unix { socket: String },
+    Pipe { pipe: String },
+    Memory { memory: bool },
+}
+
+#[derive(Facet)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Codec {
+    Json { pretty: bool },
+    Cbor { canonical: bool },
+    Raw { raw: bool },
+    Text { charset: String },
+}
+
+#[derive(Facet)]
+#[allow(dead_code)]
+struct Connection {
+    #[facet(flatten)]
+    auth: Auth,
+    #[facet(flatten)]
+    transport: Transport,
+    #[facet(flatten)]
+    codec: Codec,
+}
+
+#[test]
+fn default_budget_allows_reasonable_schemas() {
+    const { assert!(DEFAULT_MAX_RESOLUTIONS >= 64) };
+    let schema = Schema::build(Connection::SHAPE).unwrap();
+    assert_eq!(schema.resolutions().len(), 64);
+}
+
+#[test]
+fn exceeding_the_budget_is_an_error() {
+    let options = SchemaOptions::new().max_resolutions(16);
+    let err = Schema::build_with_options(Connection::SHAPE, options).unwrap_err();
+    match &err {
+        SchemaError::TooManyResolutions { limit, type_name } => {
+            assert_eq!(*limit, 16);
+            assert_eq!(*type_name, "Codec");
+        }
+        other => panic!("expected TooManyResolutions, got {other:?}"),
+    }
+    assert!(err.to_string().contains("max_resolutions"), "{err}");
+}
+
+#[test]
+fn budget_equal_to_the_count_is_enough() {
+    let options = SchemaOptions::new().max_resolutions(64);
+    let schema = Schema::build_with_options(Connection::SHAPE, options).unwrap();
+    assert_eq!(schema.resolutions().len(), 64);
+}
@@ -0,0 +1,68 @@
+//! SchemaCache tests.
+
+use std::sync::Arc;
+
+use facet::Facet;
+use facet_solver::{Format, Schema, SchemaCache};
+use facet_testhelpers::test;
+
+#[derive(Facet)]
+struct Text {
+    content: String,
+}
+
+#[derive(Facet)]
+struct Binary {
+    data: Vec<u8>,
+}
+
+#[derive(Facet)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Payload {
+    Text(Text),
+    Binary(Binary),
+}
+
+#[derive(Facet)]
+struct Message {
+    id: u64,
+    #[facet(flatten)]
+    payload: Payload,
+}
+
+#[test]
+fn test_cache_reuses_schema() {
+    let cache = SchemaCache::new();
+    assert!(cache.is_empty());
+
+    let first = cache.get_or_build(Message::SHAPE, Format::Flat).unwrap();
+    let second = cache.get_or_build(Message::SHAPE, Format::Flat).unwrap();
+    assert!(Arc::ptr_eq(&first, &second));
+    assert_eq!(first.resolutions().len(), 2);
+    assert_eq!(cache.len(), 1);
+}
+
+#[test]
+fn test_cache_keys_on_format() {
+    let cache = SchemaCache::new();
+    let flat = cache.get_or_build(Message::SHAPE, Format::Flat).unwrap();
+    let dom = cache.get_or_build(Message::SHAPE, Format::Dom).unwrap();
+    assert!(!Arc::ptr_eq(&flat, &dom));
+    assert_eq!(dom.format(), Format::Dom);
+    assert_eq!(cache.len(), 2);
+
+    cache.clear();
+    assert!(cache.is_empty());
+    // Schemas handed out before clearing remain usable.
+    assert_eq!(flat.resolutions().len(), 2);
+}
+
+#[test]
+fn test_schema_cached_uses_global_cache() {
+    let first = Schema::cached(Message::SHAPE).unwrap();
+    let second = SchemaCache::global()
+        .get_or_build(Message::SHAPE, Format::Flat)
+        .unwrap();
+    assert!(Arc::ptr_eq(&first, &second));
+}
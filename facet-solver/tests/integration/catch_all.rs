@@ -0,0 +1,135 @@
+//! Flattened maps that collect every key no field claims.
+
+use std::collections::BTreeMap;
+
+use facet::Facet;
+use facet_solver::{FieldKey, KeyResult, Schema, Solver, SolverError};
+use facet_testhelpers::test;
+
+#[derive(Facet)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Backend {
+    Postgres { dsn: String },
+    Sqlite { path: String },
+}
+
+#[derive(Facet)]
+#[allow(dead_code)]
+struct Database {
+    #[facet(flatten)]
+    backend: Backend,
+    #[facet(flatten)]
+    extra: BTreeMap<String, String>,
+}
+
+#[derive(Facet)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Target {
+    Remote {
+        host: String,
+        #[facet(flatten)]
+        options: BTreeMap<String, String>,
+    },
+    Local {
+        path: String,
+    },
+}
+
+#[derive(Facet)]
+#[allow(dead_code)]
+struct Deploy {
+    #[facet(flatten)]
+    target: Target,
+}
+
+#[test]
+fn every_resolution_is_marked() {
+    let schema = Schema::build(Database::SHAPE).unwrap();
+    assert_eq!(schema.resolutions().len(), 2);
+    for idx in 0..schema.resolutions().len() {
+        assert!(schema.has_catch_all(idx, &FieldKey::flat("anything")));
+    }
+}
+
+#[test]
+fn unknown_keys_are_not_reported() {
+    let schema = Schema::build(Database::SHAPE).unwrap();
+
+    let mut solver = Solver::new(&schema);
+    solver.see_key("pool_size");
+    match solver.finish() {
+        Err(SolverError::NoMatch {
+            unknown_fields,
+            candidate_failures,
+            ..
+        }) => {
+            assert!(unknown_fields.is_empty(), "{unknown_fields:?}");
+            for failure in &candidate_failures {
+                assert!(failure.unknown_fields.is_empty(), "{failure:?}");
+            }
+        }
+        other => panic!("expected NoMatch, got {other:?}"),
+    }
+
+    let mut solver = Solver::new(&schema);
+    solver.see_key("pool_size");
+    solver.see_key("dsn");
+    let resolution = solver.finish().unwrap().resolution();
+    assert_eq!(resolution.describe(), "Backend::Postgres");
+}
+
+#[test]
+fn catch_all_keeps_resolution_alive_for_other_fields() {
+    let schema = Schema::build(Deploy::SHAPE).unwrap();
+
+    // `path` is a field of `Local`, but `Remote` would sink it into `options`.
+    let mut solver = Solver::new(&schema);
+    assert!(!matches!(solver.see_key("path"), KeyResult::Solved(_)));
+    assert_eq!(solver.candidates().len(), 2);
+    assert!(matches!(solver.see_key("host"), KeyResult::Solved(_)));
+    assert_eq!(
+        solver.finish().unwrap().resolution().describe(),
+        "Target::Remote"
+    );
+
+    // Without `host`, only `Local` has its required fields.
+    let mut solver = Solver::new(&schema);
+    solver.see_key("path");
+    assert_eq!(
+        solver.finish().unwrap().resolution().describe(),
+        "Target::Local"
+    );
+}
+
+#[test]
+fn keys_are_still_unknown_without_a_catch_all() {
+    let schema = Schema::build(Deploy::SHAPE).unwrap();
+    let local = schema
+        .resolutions()
+        .iter()
+        .position(|r| r.describe() == "Target::Local")
+        .unwrap();
+    assert!(!schema.has_catch_all(local, &FieldKey::flat("retries")));
+
+    let mut solver = Solver::new(&schema);
+    solver.see_key("retries");
+    match solver.finish() {
+        Err(SolverError::NoMatch {
+            candidate_failures, ..
+        }) => {
+            let local = candidate_failures
+                .iter()
+                .find(|f| f.variant_name == "Target::Local")
+                .unwrap();
+            assert_eq!(local.unknown_fields, ["retries"]);
+            let remote = candidate_failures
+                .iter()
+                .find(|f| f.variant_name == "Target::Remote")
+                .unwrap();
+            assert!(remote.unknown_fields.is_empty());
+        }
+        other => panic!("expected NoMatch, got {other:?}"),
+    }
+}
@@ -0,0 +1,134 @@
+//! Ranking candidates when nothing matches.
+
+use facet::Facet;
+use facet_solver::{CandidateScore, Schema, SolveFailure, Solver, SolverError};
+use facet_testhelpers::test;
+
+#[derive(Facet)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Remote {
+    Http {
+        host: String,
+        api_token: String,
+    },
+    Ssh {
+        host: String,
+        user: String,
+        key_file: String,
+    },
+}
+
+#[derive(Facet)]
+#[allow(dead_code)]
+struct Deploy {
+    #[facet(flatten)]
+    remote: Remote,
+}
+
+fn no_match(keys: &[&'static str]) -> SolverError {
+    let schema = Schema::build_auto(Deploy::SHAPE).unwrap();
+    let mut solver = Solver::new(&schema);
+    for key in keys {
+        solver.see_key(*key);
+    }
+    match solver.finish() {
+        Err(err) => err,
+        Ok(handle) => panic!("expected an error, got {}", handle.resolution().describe()),
+    }
+}
+
+#[test]
+fn misspelled_key_points_at_its_variant() {
+    // Ssh is listed second, but "key_fil" is a near miss of its "key_file"
+    let err = no_match(&["host", "key_fil"]);
+    let SolverError::NoMatch {
+        closest_resolution,
+        missing_required,
+        candidate_failures,
+        ..
+    } = &err
+    else {
+        panic!("expected NoMatch, got {err:?}");
+    };
+    assert!(
+        closest_resolution.as_deref().unwrap().contains("Ssh"),
+        "{err}"
+    );
+    assert_eq!(missing_required, &["key_file", "user"]);
+
+    let best = &candidate_failures[0];
+    assert!(best.variant_name.contains("Ssh"), "{err}");
+    assert_eq!(best.score.matched_required, 1);
+    assert_eq!(best.score.missing_required, 2);
+    assert_eq!(best.score.unmatched_keys, 1);
+    assert!(best.score.similarity >= 90, "{:?}", best.score);
+    assert!(best.score.weighted() > candidate_failures[1].score.weighted());
+}
+
+#[test]
+fn borrowed_error_agrees_with_the_detailed_one() {
+    let schema = Schema::build_auto(Deploy::SHAPE).unwrap();
+    let mut solver = Solver::new(&schema);
+    solver.see_key("host");
+    solver.see_key("key_fil");
+    let err = solver.finish_borrowed().unwrap_err();
+
+    let SolveFailure::NoMatch { candidate, missing } = err.failure() else {
+        panic!("expected NoMatch, got {err:?}");
+    };
+    let closest = &err.schema().resolutions()[candidate.unwrap()];
+    assert!(closest.field_by_name("key_file").is_some());
+    assert_eq!(missing, Some("key_file"));
+
+    let detailed = err.into_detailed();
+    let SolverError::NoMatch {
+        closest_resolution, ..
+    } = &detailed
+    else {
+        panic!("expected NoMatch, got {detailed:?}");
+    };
+    assert_eq!(closest_resolution.as_deref(), Some(&*closest.describe()));
+}
+
+#[test]
+fn ties_go_to_the_candidate_missing_fewer_fields() {
+    // Both variants only have "host"; Http needs one more field, Ssh two
+    let err = no_match(&["host"]);
+    let SolverError::NoMatch {
+        closest_resolution,
+        candidate_failures,
+        ..
+    } = &err
+    else {
+        panic!("expected NoMatch, got {err:?}");
+    };
+    assert!(
+        closest_resolution.as_deref().unwrap().contains("Http"),
+        "{err}"
+    );
+    let scores: Vec<CandidateScore> = candidate_failures.iter().map(|f| f.score).collect();
+    assert_eq!(scores[0].weighted(), scores[1].weighted());
+    assert!(scores[0].missing_required < scores[1].missing_required);
+}
+
+#[test]
+fn unknown_keys_count_against_a_candidate() {
+    // "user" rules out Http, but a pile of keys Ssh doesn't know about
+    // still shows up in its score
+    let err = no_match(&["user", "region", "zone"]);
+    let SolverError::NoMatch {
+        candidate_failures, ..
+    } = &err
+    else {
+        panic!("expected NoMatch, got {err:?}");
+    };
+    let ssh = &candidate_failures[0].score;
+    assert_eq!(ssh.matched_required, 1);
+    assert_eq!(ssh.unmatched_keys, 2);
+    assert_eq!(
+        ssh.weighted(),
+        CandidateScore::MATCHED_REQUIRED_WEIGHT - 2 * CandidateScore::UNMATCHED_KEY_WEIGHT
+            + ssh.similarity as i64
+    );
+}
@@ -0,0 +1,59 @@
+//! Fields the deserializer fills with defaults are not required.
+
+use facet::Facet;
+use facet_solver::{Schema, Solver, SolverError};
+use facet_testhelpers::test;
+
+#[derive(Facet, Default)]
+#[facet(default)]
+#[allow(dead_code)]
+struct LocalOptions {
+    path: String,
+    cache: bool,
+}
+
+#[derive(Facet)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Storage {
+    Local(LocalOptions),
+    S3 { bucket: String, region: String },
+}
+
+#[derive(Facet)]
+#[allow(dead_code)]
+struct Config {
+    name: String,
+    #[facet(flatten)]
+    storage: Storage,
+}
+
+fn solve(keys: &[&'static str]) -> Result<String, SolverError> {
+    let schema = Schema::build_auto(Config::SHAPE).unwrap();
+    let mut solver = Solver::new(&schema);
+    for key in keys {
+        solver.see_key(*key);
+    }
+    solver.finish().map(|handle| handle.resolution().describe())
+}
+
+#[test]
+fn container_default_makes_variant_fields_optional() {
+    let resolution = solve(&["name", "path"]).unwrap();
+    assert!(resolution.contains("Local"), "{resolution}");
+
+    let resolution = solve(&["name"]).unwrap();
+    assert!(resolution.contains("Local"), "{resolution}");
+}
+
+#[test]
+fn fields_without_container_default_stay_required() {
+    let err = solve(&["name", "bucket"]).unwrap_err();
+    let SolverError::NoMatch {
+        missing_required, ..
+    } = &err
+    else {
+        panic!("expected NoMatch, got {err:?}");
+    };
+    assert_eq!(missing_required, &["region"]);
+}
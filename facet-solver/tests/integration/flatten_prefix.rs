@@ -0,0 +1,84 @@
+//! Flattened structs whose keys carry a runtime prefix.
+
+use facet::Facet;
+use facet_solver::{FieldPath, Schema, SchemaError};
+use facet_testhelpers::test;
+
+#[derive(Facet)]
+#[allow(dead_code)]
+struct Metrics {
+    cpu: f64,
+    memory: u64,
+}
+
+#[derive(Facet)]
+#[allow(dead_code)]
+struct Instance {
+    name: String,
+    #[facet(flatten, flatten_prefix_from = "name")]
+    metrics: Metrics,
+}
+
+#[test]
+fn prefixed_fields_are_kept_apart() {
+    let schema = Schema::build(Instance::SHAPE).unwrap();
+    let resolution = &schema.resolutions()[0];
+
+    // Only `name` is known by its plain key
+    assert!(resolution.field_by_name("name").is_some());
+    assert!(resolution.field_by_name("cpu").is_none());
+
+    let [group] = resolution.dynamic_prefixes() else {
+        panic!("expected one group");
+    };
+    assert_eq!(group.source, FieldPath::empty().push_field("name"));
+    assert_eq!(group.fields.len(), 2);
+
+    let (prefix, _, info) = resolution
+        .field_by_prefixed_key("web.1.cpu", |_| None)
+        .unwrap();
+    assert_eq!(prefix, "web.1");
+    assert_eq!(info.serialized_name, "cpu");
+    assert!(resolution.field_by_prefixed_key("cpu", |_| None).is_none());
+    assert!(
+        resolution
+            .field_by_prefixed_key("web1.disk", |_| None)
+            .is_none()
+    );
+}
+
+#[derive(Facet)]
+#[allow(dead_code)]
+struct NoSource {
+    label: String,
+    #[facet(flatten, flatten_prefix_from = "name")]
+    metrics: Metrics,
+}
+
+#[derive(Facet)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Probe {
+    Http { url: String },
+    Tcp { port: u16 },
+}
+
+#[derive(Facet)]
+#[allow(dead_code)]
+struct PrefixedEnum {
+    name: String,
+    #[facet(flatten, flatten_prefix_from = "name")]
+    probe: Probe,
+}
+
+#[test]
+fn invalid_prefixes_are_rejected() {
+    for shape in [NoSource::SHAPE, PrefixedEnum::SHAPE] {
+        match Schema::build(shape) {
+            Err(SchemaError::InvalidFlattenPrefix { field, .. }) => {
+                assert!(field == "metrics" || field == "probe", "{field}");
+            }
+            other => panic!("expected InvalidFlattenPrefix for {shape}, got {other:?}"),
+        }
+    }
+}
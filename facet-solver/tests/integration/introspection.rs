@@ -0,0 +1,108 @@
+//! Tests for listing a schema's fields with `Schema::fields`.
+
+use facet::Facet;
+use facet_solver::{FieldCategory, Schema};
+use facet_testhelpers::test;
+
+#[derive(Facet)]
+struct Database {
+    /// Connection string.
+    url: String,
+    #[facet(alias = "pool_size")]
+    max_connections: Option<u32>,
+}
+
+#[derive(Facet)]
+struct Files {
+    /// Directory holding the data.
+    root: String,
+}
+
+#[derive(Facet)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Backend {
+    Database(Database),
+    Files(Files),
+}
+
+#[derive(Facet)]
+struct Storage {
+    /// Name of the store.
+    name: String,
+    #[facet(flatten)]
+    backend: Backend,
+}
+
+#[test]
+fn test_fields_are_listed_once_by_name() {
+    let schema = Schema::build_auto(Storage::SHAPE).unwrap();
+    let names: Vec<_> = schema.fields().map(|field| field.name()).collect();
+    assert_eq!(names, ["max_connections", "name", "root", "url"]);
+}
+
+#[test]
+fn test_fields_report_metadata() {
+    let schema = Schema::build_auto(Storage::SHAPE).unwrap();
+    let fields: Vec<_> = schema.fields().collect();
+
+    let max_connections = &fields[0];
+    assert_eq!(max_connections.alias(), Some("pool_size"));
+    assert!(!max_connections.is_required());
+    assert_eq!(max_connections.shape(), <Option<u32>>::SHAPE);
+    assert!(max_connections.docs().is_empty());
+
+    let name = &fields[1];
+    assert_eq!(name.alias(), None);
+    assert!(name.is_required());
+    assert_eq!(name.shape(), String::SHAPE);
+    assert_eq!(name.docs(), [" Name of the store."]);
+    assert_eq!(name.category(), FieldCategory::Flat);
+    assert_eq!(name.path().depth(), 1);
+
+    let url = &fields[3];
+    assert!(url.is_required());
+    assert_eq!(url.docs(), [" Connection string."]);
+    assert!(url.path().depth() > name.path().depth());
+}
+
+#[test]
+fn test_fields_report_their_resolutions() {
+    let schema = Schema::build_auto(Storage::SHAPE).unwrap();
+    let resolutions = schema.resolutions();
+    assert_eq!(resolutions.len(), 2);
+
+    for field in schema.fields() {
+        let containing: Vec<_> = field.resolutions().iter().collect();
+        for (idx, resolution) in resolutions.iter().enumerate() {
+            assert_eq!(
+                containing.contains(&idx),
+                resolution.field_by_name(field.name()).is_some(),
+                "{} in resolution {idx}",
+                field.name()
+            );
+            assert_eq!(field.resolutions().contains(idx), containing.contains(&idx));
+        }
+        assert_eq!(field.in_every_resolution(), field.name() == "name");
+    }
+
+    let root = schema
+        .fields()
+        .find(|field| field.name() == "root")
+        .unwrap();
+    assert_eq!(root.resolutions().len(), 1);
+}
+
+#[test]
+fn test_shadowed_alias_is_not_reported() {
+    #[derive(Facet)]
+    struct Shadowed {
+        #[facet(alias = "b")]
+        a: u32,
+        b: u32,
+    }
+
+    let schema = Schema::build_auto(Shadowed::SHAPE).unwrap();
+    let aliases: Vec<_> = schema.fields().map(|field| field.alias()).collect();
+    assert_eq!(aliases, [None, None]);
+}
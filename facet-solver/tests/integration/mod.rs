@@ -1,4 +1,17 @@
+mod alias;
+mod borrowed_error;
+mod budget;
+mod cache;
+mod catch_all;
+mod closest;
+mod defaults;
+mod flatten_prefix;
+mod introspection;
+mod normalization;
 mod path;
 mod probing;
 mod solver;
+mod specificity;
+mod streaming;
+mod tag_rename;
 mod witness;
@@ -0,0 +1,208 @@
+//! Key normalization tests.
+
+use facet::Facet;
+use facet_solver::{
+    KeyNormalization, KeyResult, ProbeResult, ProbingSolver, Schema, SchemaError, SchemaOptions,
+    Solver, SolverError,
+};
+use facet_testhelpers::test;
+
+#[derive(Facet)]
+struct Pool {
+    max_connections: u32,
+    idle_timeout: u32,
+}
+
+#[derive(Facet)]
+struct Server {
+    host: String,
+    #[facet(flatten)]
+    pool: Pool,
+}
+
+fn schema(shape: &'static facet::Shape, normalization: KeyNormalization) -> Schema {
+    Schema::build_with_options(shape, SchemaOptions::new().key_normalization(normalization))
+        .unwrap()
+}
+
+#[test]
+fn test_exact_is_default() {
+    let schema = Schema::build_with_options(Pool::SHAPE, SchemaOptions::default()).unwrap();
+    assert_eq!(schema.key_normalization(), KeyNormalization::Exact);
+    assert_eq!(
+        schema.canonical_key("max_connections"),
+        Some("max_connections")
+    );
+    assert_eq!(schema.canonical_key("maxConnections"), None);
+
+    let mut solver = Solver::new(&schema);
+    assert!(matches!(
+        solver.see_key("maxConnections"),
+        KeyResult::Unknown
+    ));
+}
+
+#[test]
+fn test_case_insensitive() {
+    let schema = schema(Pool::SHAPE, KeyNormalization::CaseInsensitive);
+    assert_eq!(
+        schema.canonical_key("MAX_CONNECTIONS"),
+        Some("max_connections")
+    );
+    assert_eq!(schema.canonical_key("max-connections"), None);
+}
+
+#[test]
+fn test_case_and_separator_insensitive() {
+    let schema = schema(Server::SHAPE, KeyNormalization::CaseAndSeparatorInsensitive);
+    for key in [
+        "max_connections",
+        "maxConnections",
+        "max-connections",
+        "MaxConnections",
+    ] {
+        assert_eq!(schema.canonical_key(key), Some("max_connections"), "{key}");
+    }
+
+    let mut solver = Solver::new(&schema);
+    assert!(!matches!(solver.see_key("Host"), KeyResult::Unknown));
+    assert!(!matches!(
+        solver.see_key("maxConnections"),
+        KeyResult::Unknown
+    ));
+    assert!(!matches!(
+        solver.see_key("idle-timeout"),
+        KeyResult::Unknown
+    ));
+    assert!(solver.finish().is_ok());
+}
+
+#[test]
+fn test_errors_keep_original_spelling() {
+    #[derive(Facet)]
+    #[repr(u8)]
+    #[allow(dead_code)]
+    enum Backend {
+        Local { root_dir: String },
+        Remote { base_url: String },
+    }
+
+    #[derive(Facet)]
+    struct Storage {
+        #[facet(flatten)]
+        backend: Backend,
+    }
+
+    let schema = schema(
+        Storage::SHAPE,
+        KeyNormalization::CaseAndSeparatorInsensitive,
+    );
+    let mut solver = Solver::new(&schema);
+    assert!(!matches!(solver.see_key("rootDir"), KeyResult::Unknown));
+    assert!(!matches!(solver.see_key("base-url"), KeyResult::Unknown));
+    match solver.finish() {
+        Err(SolverError::NoMatch {
+            input_fields,
+            unknown_fields,
+            ..
+        }) => {
+            assert!(
+                input_fields.contains(&"rootDir".to_string()),
+                "{input_fields:?}"
+            );
+            assert!(
+                input_fields.contains(&"base-url".to_string()),
+                "{input_fields:?}"
+            );
+            assert!(unknown_fields.is_empty());
+        }
+        Err(other) => panic!("expected NoMatch, got {other}"),
+        Ok(_) => panic!("expected NoMatch, got a resolution"),
+    }
+}
+
+#[test]
+fn test_normalized_collision_is_rejected() {
+    #[derive(Facet)]
+    struct Clash {
+        #[facet(rename = "maxConnections")]
+        a: u32,
+        max_connections: u32,
+    }
+
+    let result = Schema::build_with_options(
+        Clash::SHAPE,
+        SchemaOptions::new().key_normalization(KeyNormalization::CaseAndSeparatorInsensitive),
+    );
+    assert!(matches!(
+        result,
+        Err(SchemaError::NormalizedKeyCollision { ref normalized, .. }) if normalized == "maxconnections"
+    ));
+
+    // Exact matching keeps both fields apart.
+    assert!(Schema::build_with_options(Clash::SHAPE, SchemaOptions::new()).is_ok());
+}
+
+#[test]
+fn test_probe_key_normalizes_nested_paths() {
+    #[derive(Facet)]
+    struct Service {
+        service_name: String,
+        pool: Pool,
+    }
+
+    #[derive(Facet)]
+    struct Queue {
+        queue_name: String,
+        pool: Pool,
+    }
+
+    #[derive(Facet)]
+    #[repr(u8)]
+    #[allow(dead_code)]
+    enum Kind {
+        Service(Service),
+        Queue(Queue),
+    }
+
+    #[derive(Facet)]
+    struct Deployment {
+        #[facet(flatten)]
+        kind: Kind,
+    }
+
+    let schema = schema(
+        Deployment::SHAPE,
+        KeyNormalization::CaseAndSeparatorInsensitive,
+    );
+
+    let mut solver = Solver::new(&schema);
+    assert!(matches!(
+        solver.probe_key(&["Pool"], "maxConnections"),
+        KeyResult::Unambiguous { shape } if shape == u32::SHAPE
+    ));
+    assert!(matches!(
+        solver.probe_key(&[], "serviceName"),
+        KeyResult::Solved(_)
+    ));
+
+    let mut solver = ProbingSolver::new(&schema);
+    assert!(matches!(
+        solver.probe_key(&["pool"], "max-connections"),
+        ProbeResult::KeepGoing
+    ));
+    match solver.probe_key(&[], "QUEUE_NAME") {
+        ProbeResult::Solved(resolution) => {
+            assert!(resolution.has_key_path(&["pool", "max_connections"]));
+        }
+        _ => panic!("expected Solved"),
+    }
+
+    // Without normalization, nested keys must match exactly
+    let exact = schema(Deployment::SHAPE, KeyNormalization::Exact);
+    let mut solver = ProbingSolver::new(&exact);
+    assert!(matches!(
+        solver.probe_key(&["pool"], "maxConnections"),
+        ProbeResult::NoMatch
+    ));
+}
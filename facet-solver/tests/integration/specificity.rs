@@ -0,0 +1,117 @@
+//! Specificity scoring tests.
+
+use std::collections::{BTreeSet, HashMap};
+use std::path::PathBuf;
+
+use facet::Facet;
+use facet_core::Shape;
+use facet_solver::{KeyResult, Schema, Solver, specificity_score};
+use facet_testhelpers::test;
+
+#[test]
+fn test_numbers_by_width() {
+    assert!(specificity_score(u8::SHAPE) < specificity_score(u16::SHAPE));
+    assert!(specificity_score(i32::SHAPE) < specificity_score(i64::SHAPE));
+    assert_eq!(
+        specificity_score(usize::SHAPE),
+        specificity_score(u64::SHAPE)
+    );
+    assert!(specificity_score(bool::SHAPE) < specificity_score(u8::SHAPE));
+}
+
+#[test]
+fn test_strings_and_collections() {
+    let char_score = specificity_score(char::SHAPE);
+    let path = specificity_score(PathBuf::SHAPE);
+    let string = specificity_score(String::SHAPE);
+    let set = specificity_score(<BTreeSet<u32>>::SHAPE);
+    let list = specificity_score(<Vec<u32>>::SHAPE);
+    let map = specificity_score(<HashMap<String, u32>>::SHAPE);
+
+    assert!(specificity_score(u128::SHAPE) < char_score);
+    assert!(char_score < path);
+    assert!(path < string);
+    assert!(string < set);
+    assert!(set < list);
+    assert!(list < map);
+}
+
+#[test]
+fn test_wrappers_score_as_inner() {
+    assert_eq!(
+        specificity_score(<Option<u8>>::SHAPE),
+        specificity_score(u8::SHAPE)
+    );
+    assert_eq!(
+        specificity_score(<Box<String>>::SHAPE),
+        specificity_score(String::SHAPE)
+    );
+}
+
+#[derive(Facet)]
+struct AsPath {
+    location: PathBuf,
+}
+
+#[derive(Facet)]
+struct AsList {
+    location: Vec<String>,
+}
+
+#[derive(Facet)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Location {
+    Path(AsPath),
+    List(AsList),
+}
+
+#[derive(Facet)]
+struct Config {
+    #[facet(flatten)]
+    location: Location,
+}
+
+fn score_of(result: KeyResult<'_>, type_identifier: &str) -> u64 {
+    let KeyResult::Ambiguous { fields } = result else {
+        panic!("expected Ambiguous");
+    };
+    fields
+        .iter()
+        .find(|(f, _)| f.value_shape.type_identifier == type_identifier)
+        .map(|(_, score)| *score)
+        .unwrap()
+}
+
+#[test]
+fn test_solver_uses_default_scores() {
+    let schema = Schema::build_auto(Config::SHAPE).unwrap();
+    let mut solver = Solver::new(&schema);
+    let result = solver.see_key("location");
+    assert_eq!(
+        score_of(result, "PathBuf"),
+        specificity_score(PathBuf::SHAPE)
+    );
+}
+
+#[test]
+fn test_score_fn_overrides() {
+    fn lists_first(shape: &'static Shape) -> Option<u64> {
+        (shape.type_identifier == "Vec").then_some(0)
+    }
+
+    let schema = Schema::build_auto(Config::SHAPE).unwrap();
+    let mut solver = Solver::new(&schema).with_score_fn(lists_first);
+    let result = solver.see_key("location");
+    let KeyResult::Ambiguous { fields } = result else {
+        panic!("expected Ambiguous");
+    };
+    let best = fields.iter().min_by_key(|(_, score)| *score).unwrap();
+    assert_eq!(best.0.value_shape.type_identifier, "Vec");
+    // Shapes the hook doesn't know keep their default score
+    let path = fields
+        .iter()
+        .find(|(f, _)| f.value_shape.type_identifier == "PathBuf")
+        .unwrap();
+    assert_eq!(path.1, specificity_score(PathBuf::SHAPE));
+}
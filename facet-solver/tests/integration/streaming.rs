@@ -0,0 +1,135 @@
+//! Streaming mode and value superset tests.
+
+use std::collections::HashMap;
+
+use facet::Facet;
+use facet_solver::{IntegerRange, KeyResult, SatisfyResult, Schema, Solver, ValueSuperset};
+use facet_testhelpers::test;
+
+#[derive(Facet)]
+struct Small {
+    value: u8,
+}
+
+#[derive(Facet)]
+struct Signed {
+    value: i16,
+}
+
+#[derive(Facet)]
+struct Named {
+    value: String,
+}
+
+#[derive(Facet)]
+struct Nested {
+    value: HashMap<String, u32>,
+}
+
+#[derive(Facet)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Numbers {
+    Small(Small),
+    Signed(Signed),
+}
+
+#[derive(Facet)]
+struct NumberHolder {
+    #[facet(flatten)]
+    numbers: Numbers,
+}
+
+#[derive(Facet)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Mixed {
+    Named(Named),
+    Nested(Nested),
+}
+
+#[derive(Facet)]
+struct MixedHolder {
+    #[facet(flatten)]
+    mixed: Mixed,
+}
+
+#[test]
+fn test_default_mode_stays_ambiguous() {
+    let schema = Schema::build_auto(NumberHolder::SHAPE).unwrap();
+    let mut solver = Solver::new(&schema);
+    assert!(matches!(
+        solver.see_key("value"),
+        KeyResult::Ambiguous { .. }
+    ));
+}
+
+#[test]
+fn test_widest_integer() {
+    let schema = Schema::build_auto(NumberHolder::SHAPE).unwrap();
+    let mut solver = Solver::new(&schema).streaming();
+    match solver.see_key("value") {
+        KeyResult::Buffer { fields, superset } => {
+            assert_eq!(fields.len(), 2);
+            assert!(superset.is_numeric());
+            assert_eq!(
+                superset.integer,
+                Some(IntegerRange {
+                    min: i16::MIN as i128,
+                    max: i16::MAX as u128,
+                })
+            );
+            assert!(superset.integer.unwrap().is_signed());
+        }
+        _ => panic!("expected Buffer"),
+    }
+}
+
+#[test]
+fn test_string_or_map() {
+    let schema = Schema::build_auto(MixedHolder::SHAPE).unwrap();
+    let mut solver = Solver::new(&schema).streaming();
+    let fields = match solver.see_key("value") {
+        KeyResult::Buffer { fields, superset } => {
+            assert!(superset.string);
+            assert!(superset.map);
+            assert!(!superset.sequence);
+            assert!(superset.integer.is_none());
+            assert!(!superset.is_scalar());
+            fields
+        }
+        _ => panic!("expected Buffer"),
+    };
+
+    // The buffered value turned out to be a string
+    let named: Vec<_> = fields
+        .iter()
+        .filter(|(f, _)| *f.value_shape == *String::SHAPE)
+        .map(|(f, _)| *f)
+        .collect();
+    assert!(matches!(solver.satisfy(&named), SatisfyResult::Solved(_)));
+}
+
+#[test]
+fn test_superset_of_shapes() {
+    let superset = ValueSuperset::of(<Option<Vec<u32>>>::SHAPE);
+    assert!(superset.null);
+    assert!(superset.sequence);
+    assert!(superset.integer.is_none());
+
+    let superset = ValueSuperset::of_shapes([f64::SHAPE, u64::SHAPE]);
+    assert!(superset.is_numeric());
+    assert!(superset.float);
+    assert_eq!(
+        superset.integer,
+        Some(IntegerRange {
+            min: 0,
+            max: u64::MAX as u128
+        })
+    );
+
+    let superset = ValueSuperset::of(bool::SHAPE).union(ValueSuperset::of(i8::SHAPE));
+    assert!(superset.bool);
+    assert!(superset.is_scalar());
+    assert!(!superset.is_numeric());
+}
@@ -0,0 +1,96 @@
+//! Internally-tagged enums whose variants are renamed.
+
+use facet::Facet;
+use facet_solver::{Schema, Solver};
+use facet_testhelpers::test;
+
+#[derive(Facet)]
+#[repr(u8)]
+#[facet(tag = "kind")]
+#[allow(dead_code)]
+enum Transport {
+    #[facet(rename = "tcp")]
+    Tcp {
+        host: String,
+        port: u16,
+    },
+    #[facet(rename = "unix")]
+    Unix {
+        path: String,
+    },
+    Pipe {
+        path: String,
+    },
+}
+
+#[derive(Facet)]
+struct Listener {
+    name: String,
+    #[facet(flatten)]
+    transport: Transport,
+}
+
+#[test]
+fn tag_value_matches_renamed_variant() {
+    let schema = Schema::build_auto(Listener::SHAPE).unwrap();
+
+    let mut solver = Solver::new(&schema);
+    assert!(solver.hint_variant_for_tag("kind", "unix"));
+    assert_eq!(solver.candidates().len(), 1);
+    assert_eq!(
+        solver.candidates()[0].resolution().describe(),
+        r#"Transport::Unix ("unix")"#
+    );
+}
+
+#[test]
+fn tag_value_does_not_match_rust_name_of_renamed_variant() {
+    let schema = Schema::build_auto(Listener::SHAPE).unwrap();
+
+    let mut solver = Solver::new(&schema);
+    assert!(!solver.hint_variant_for_tag("kind", "Unix"));
+    assert_eq!(solver.candidates().len(), 3);
+
+    // Variants without a rename keep matching their Rust name.
+    assert!(solver.hint_variant_for_tag("kind", "Pipe"));
+    assert_eq!(
+        solver.candidates()[0].resolution().describe(),
+        "Transport::Pipe"
+    );
+}
+
+#[test]
+fn hint_variant_accepts_either_name() {
+    let schema = Schema::build_auto(Listener::SHAPE).unwrap();
+
+    let mut solver = Solver::new(&schema);
+    assert!(solver.hint_variant("Tcp"));
+    assert_eq!(solver.candidates().len(), 1);
+
+    let mut solver = Solver::new(&schema);
+    assert!(solver.hint_variant("tcp"));
+    assert_eq!(solver.candidates().len(), 1);
+}
+
+#[test]
+fn variant_selection_records_serialized_name() {
+    let schema = Schema::build_auto(Listener::SHAPE).unwrap();
+
+    let mut selections: Vec<_> = schema
+        .resolutions()
+        .iter()
+        .map(|r| {
+            let vs = &r.variant_selections()[0];
+            (vs.variant_name, vs.serialized_name, vs.is_renamed())
+        })
+        .collect();
+    selections.sort();
+    assert_eq!(
+        selections,
+        [
+            ("Pipe", "Pipe", false),
+            ("Tcp", "tcp", true),
+            ("Unix", "unix", true),
+        ]
+    );
+}
@@ -0,0 +1,1266 @@
+//! Generate Swift `Codable` types from facet type metadata.
+//!
+//! This crate uses facet's reflection capabilities to generate Swift
+//! declarations that encode and decode the same JSON as the Rust types they
+//! mirror, for iOS and macOS clients:
+//!
+//! - structs become `struct`s conforming to `Codable`, with a `CodingKeys` enum
+//!   whenever a serialized name differs from the Swift property name,
+//! - enums with only unit variants become `String`-backed enums,
+//! - enums with data become enums with associated values, with hand-written
+//!   `init(from:)` and `encode(to:)` that follow the enum's tagging
+//!   (externally, internally or adjacently tagged, or untagged),
+//! - tuple structs become structs with `_0`, `_1`, ... properties, coded as
+//!   JSON arrays.
+//!
+//! Types with no Swift equivalent (e.g. 128-bit integers) become `JSONValue`,
+//! a small enum emitted alongside the generated types.
+//!
+//! # Example
+//!
+//! ```
+//! use facet::Facet;
+//! use facet_swift::to_swift;
+//!
+//! #[derive(Facet)]
+//! struct User {
+//!     name: String,
+//!     user_id: u64,
+//!     email: Option<String>,
+//! }
+//!
+//! let swift = to_swift::<User>();
+//! assert!(swift.contains("struct User: Codable {"));
+//! assert!(swift.contains("    var email: String?"));
+//! assert!(swift.contains("        case userId = \"user_id\""));
+//! ```
+
+extern crate alloc;
+
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use facet_core::{
+    Def, EnumType, Facet, Field, ScalarKind, Shape, StructKind, Type, UserType, Variant,
+};
+
+/// Generate Swift definitions for a single type.
+///
+/// Returns a string containing the Swift declarations for the type and the
+/// types it references.
+pub fn to_swift<T: Facet<'static>>() -> String {
+    let mut generator = SwiftGenerator::new();
+    generator.add_shape(T::SHAPE);
+    generator.finish()
+}
+
+/// Generator for Swift type definitions.
+///
+/// Use this when you need to generate multiple related types.
+pub struct SwiftGenerator {
+    output: String,
+    /// Helper declarations needed by the generated code
+    support: BTreeSet<Support>,
+    /// Types already generated (by type identifier)
+    generated: BTreeSet<&'static str>,
+    /// Types queued for generation
+    queue: Vec<&'static Shape>,
+    /// Indentation level
+    indent: usize,
+}
+
+impl Default for SwiftGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Helper declarations emitted once, after the generated types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Support {
+    /// `AnyCodingKey`, a coding key for arbitrary strings.
+    AnyCodingKey,
+    /// `JSONValue`, an arbitrary JSON value.
+    JsonValue,
+}
+
+/// A stored property of a generated struct, or a labeled associated value.
+struct Property {
+    /// Swift name of the property
+    name: String,
+    /// Name in the serialized form
+    serial_name: &'static str,
+    /// Swift type, without the `?` of optional properties
+    ty: String,
+    optional: bool,
+    doc: &'static [&'static str],
+}
+
+impl Property {
+    fn declared_type(&self) -> String {
+        if self.optional {
+            alloc::format!("{}?", self.ty)
+        } else {
+            self.ty.clone()
+        }
+    }
+}
+
+/// The associated values of an enum case.
+enum Payload {
+    Unit,
+    /// A single unlabeled value
+    Newtype(String),
+    /// Several unlabeled values, coded as an array
+    Tuple(Vec<String>),
+    /// Labeled values, coded as an object
+    Struct(Vec<Property>),
+}
+
+/// An enum case, ready to be written.
+struct Case {
+    /// Swift case name
+    name: String,
+    /// Serialized variant name
+    serial_name: &'static str,
+    payload: Payload,
+    doc: &'static [&'static str],
+}
+
+impl Case {
+    /// The `.name(let a, let b)` pattern binding the case's values.
+    fn pattern(&self) -> String {
+        let bindings: Vec<String> = match &self.payload {
+            Payload::Unit => return alloc::format!(".{}", self.name),
+            Payload::Newtype(_) => alloc::vec!["let value".into()],
+            Payload::Tuple(types) => (0..types.len())
+                .map(|i| alloc::format!("let _{i}"))
+                .collect(),
+            Payload::Struct(properties) => properties
+                .iter()
+                .map(|p| alloc::format!("let {}", swift_identifier(&p.name)))
+                .collect(),
+        };
+        alloc::format!(".{}({})", self.name, bindings.join(", "))
+    }
+}
+
+/// How an enum is laid out in JSON.
+#[derive(Clone, Copy)]
+enum Tagging<'a> {
+    /// `{"Variant": payload}`, or `"Variant"` for unit variants
+    External,
+    /// `{"tag": "Variant", ...fields}`
+    Internal { tag: &'a str },
+    /// `{"tag": "Variant", "content": payload}`
+    Adjacent { tag: &'a str, content: &'a str },
+    /// Just the payload
+    Untagged,
+}
+
+/// Where a case's payload is read from or written to.
+enum Location<'a> {
+    /// Under `key` in the keyed container `container`
+    Keyed { container: &'a str, key: String },
+    /// The value itself; struct payloads use `container` if given
+    Inline { container: Option<&'a str> },
+}
+
+impl SwiftGenerator {
+    /// Create a new Swift generator.
+    pub const fn new() -> Self {
+        Self {
+            output: String::new(),
+            support: BTreeSet::new(),
+            generated: BTreeSet::new(),
+            queue: Vec::new(),
+            indent: 0,
+        }
+    }
+
+    /// Add a type to generate.
+    pub fn add_type<T: Facet<'static>>(&mut self) {
+        self.add_shape(T::SHAPE);
+    }
+
+    /// Add a shape to generate.
+    pub fn add_shape(&mut self, shape: &'static Shape) {
+        if !self.generated.contains(shape.type_identifier) {
+            self.queue.push(shape);
+        }
+    }
+
+    /// Finish generation and return the Swift code.
+    pub fn finish(mut self) -> String {
+        // Process queue until empty
+        while let Some(shape) = self.queue.pop() {
+            if self.generated.contains(shape.type_identifier) {
+                continue;
+            }
+            self.generated.insert(shape.type_identifier);
+            self.generate_shape(shape);
+        }
+
+        for support in core::mem::take(&mut self.support) {
+            self.output.push_str(match support {
+                Support::AnyCodingKey => ANY_CODING_KEY,
+                Support::JsonValue => JSON_VALUE,
+            });
+            self.output.push('\n');
+        }
+
+        let mut out = String::from("import Foundation\n\n");
+        out.push_str(self.output.trim_end());
+        out.push('\n');
+        out
+    }
+
+    fn write_indent(&mut self) {
+        for _ in 0..self.indent {
+            self.output.push_str("    ");
+        }
+    }
+
+    fn write_doc(&mut self, doc: &[&str]) {
+        for line in doc {
+            self.write_indent();
+            self.output.push_str("///");
+            self.output.push_str(line);
+            self.output.push('\n');
+        }
+    }
+
+    fn write_line(&mut self, line: &str) {
+        self.write_indent();
+        self.output.push_str(line);
+        self.output.push('\n');
+    }
+
+    fn generate_shape(&mut self, shape: &'static Shape) {
+        // Handle transparent wrappers - generate the inner type instead
+        if let Some(inner) = shape.inner {
+            let inner_type = self.type_for_shape(inner);
+            self.write_doc(shape.doc);
+            writeln!(
+                self.output,
+                "typealias {} = {}\n",
+                shape.type_identifier, inner_type
+            )
+            .unwrap();
+            return;
+        }
+
+        self.write_doc(shape.doc);
+
+        // Handle proxy types - use the proxy's shape for generation
+        // but keep the original type name
+        let ty_shape = shape.proxy.map_or(shape, |proxy_def| proxy_def.shape);
+        match &ty_shape.ty {
+            Type::User(UserType::Struct(st)) if st.kind == StructKind::Struct => {
+                self.generate_struct(shape.type_identifier, st.fields);
+            }
+            Type::User(UserType::Struct(st)) if st.kind == StructKind::Unit => {
+                writeln!(
+                    self.output,
+                    "struct {}: Codable {{}}",
+                    shape.type_identifier
+                )
+                .unwrap();
+            }
+            Type::User(UserType::Struct(st)) => {
+                self.generate_tuple_struct(shape.type_identifier, st.fields);
+            }
+            Type::User(UserType::Enum(en)) => self.generate_enum(shape, en),
+            _ => {
+                // Anything else: alias whatever type the value maps to
+                let type_str = self.type_for_shape(ty_shape);
+                writeln!(
+                    self.output,
+                    "typealias {} = {}",
+                    shape.type_identifier, type_str
+                )
+                .unwrap();
+            }
+        }
+        self.output.push('\n');
+    }
+
+    /// A struct with synthesized `Codable`, plus `CodingKeys` if any
+    /// serialized name differs from its property name.
+    fn generate_struct(&mut self, name: &str, fields: &'static [Field]) {
+        let mut properties = Vec::new();
+        self.collect_properties(fields, false, &mut Vec::new(), &mut properties);
+
+        self.write_line(&alloc::format!("struct {name}: Codable {{"));
+        self.indent += 1;
+        for property in &properties {
+            self.write_doc(property.doc);
+            self.write_line(&alloc::format!(
+                "var {}: {}",
+                swift_identifier(&property.name),
+                property.declared_type()
+            ));
+        }
+
+        if properties.iter().any(|p| p.name != p.serial_name) {
+            self.output.push('\n');
+            self.write_line("enum CodingKeys: String, CodingKey {");
+            self.indent += 1;
+            for property in &properties {
+                let case = swift_identifier(&property.name);
+                if property.name == property.serial_name {
+                    self.write_line(&alloc::format!("case {case}"));
+                } else {
+                    self.write_line(&alloc::format!(
+                        "case {case} = \"{}\"",
+                        property.serial_name
+                    ));
+                }
+            }
+            self.indent -= 1;
+            self.write_line("}");
+        }
+        self.indent -= 1;
+        self.write_line("}");
+    }
+
+    /// Tuple structs serialize as arrays, so they get their own coders. These
+    /// live in an extension to keep the memberwise initializer.
+    fn generate_tuple_struct(&mut self, name: &str, fields: &'static [Field]) {
+        let types: Vec<String> = fields
+            .iter()
+            .map(|field| self.type_for_shape(field.shape.get()))
+            .collect();
+
+        self.write_line(&alloc::format!("struct {name}: Codable {{"));
+        self.indent += 1;
+        for (i, ty) in types.iter().enumerate() {
+            self.write_line(&alloc::format!("var _{i}: {ty}"));
+        }
+        self.indent -= 1;
+        self.write_line("}");
+        self.output.push('\n');
+
+        self.write_line(&alloc::format!("extension {name} {{"));
+        self.indent += 1;
+        self.write_line("init(from decoder: Decoder) throws {");
+        self.indent += 1;
+        self.write_line("var items = try decoder.unkeyedContainer()");
+        for (i, ty) in types.iter().enumerate() {
+            self.write_line(&alloc::format!("_{i} = try items.decode({ty}.self)"));
+        }
+        self.indent -= 1;
+        self.write_line("}");
+        self.output.push('\n');
+        self.write_line("func encode(to encoder: Encoder) throws {");
+        self.indent += 1;
+        self.write_line("var items = encoder.unkeyedContainer()");
+        for i in 0..types.len() {
+            self.write_line(&alloc::format!("try items.encode(_{i})"));
+        }
+        self.indent -= 1;
+        self.write_line("}");
+        self.indent -= 1;
+        self.write_line("}");
+    }
+
+    /// Collect the properties of a struct, inlining flattened structs.
+    fn collect_properties(
+        &mut self,
+        fields: &'static [Field],
+        force_optional: bool,
+        flatten_stack: &mut Vec<&'static str>,
+        properties: &mut Vec<Property>,
+    ) {
+        for field in fields {
+            if field.should_skip_serializing_unconditional() {
+                continue;
+            }
+            let field_shape = field.shape.get();
+            if field.is_flattened() {
+                let (inner_shape, is_optional) = unwrap_to_inner_shape(field_shape);
+                if let Type::User(UserType::Struct(st)) = &inner_shape.ty {
+                    if flatten_stack.contains(&inner_shape.type_identifier) {
+                        continue;
+                    }
+                    flatten_stack.push(inner_shape.type_identifier);
+                    self.collect_properties(
+                        st.fields,
+                        force_optional || is_optional,
+                        flatten_stack,
+                        properties,
+                    );
+                    flatten_stack.pop();
+                    continue;
+                }
+            }
+
+            let (ty, optional) = match &field_shape.def {
+                Def::Option(opt) => (self.type_for_shape(opt.t), true),
+                _ => (self.type_for_shape(field_shape), force_optional),
+            };
+            properties.push(Property {
+                name: property_name(field.name),
+                serial_name: field.effective_name(),
+                ty,
+                optional,
+                doc: field.doc,
+            });
+        }
+    }
+
+    fn generate_enum(&mut self, shape: &'static Shape, enum_type: &EnumType) {
+        let all_unit = enum_type
+            .variants
+            .iter()
+            .all(|v| matches!(v.data.kind, StructKind::Unit));
+        let tagging = match (shape.tag, shape.content) {
+            (Some(tag), Some(content)) => Tagging::Adjacent { tag, content },
+            (Some(tag), None) => Tagging::Internal { tag },
+            _ if shape.is_untagged() => Tagging::Untagged,
+            _ => Tagging::External,
+        };
+
+        if all_unit && matches!(tagging, Tagging::External) {
+            // Unit variants serialize as their names: a String-backed enum
+            writeln!(
+                self.output,
+                "enum {}: String, Codable {{",
+                shape.type_identifier
+            )
+            .unwrap();
+            self.indent += 1;
+            for variant in enum_type.variants {
+                self.write_doc(variant.doc);
+                let case = swift_identifier(&case_name(variant.name));
+                if case == variant.effective_name() {
+                    self.write_line(&alloc::format!("case {case}"));
+                } else {
+                    self.write_line(&alloc::format!(
+                        "case {case} = \"{}\"",
+                        variant.effective_name()
+                    ));
+                }
+            }
+            self.indent -= 1;
+            self.output.push_str("}\n");
+            return;
+        }
+
+        self.support.insert(Support::AnyCodingKey);
+        let cases: Vec<Case> = enum_type
+            .variants
+            .iter()
+            .map(|variant| self.case_for_variant(variant))
+            .collect();
+
+        writeln!(self.output, "enum {}: Codable {{", shape.type_identifier).unwrap();
+        self.indent += 1;
+        for case in &cases {
+            self.write_doc(case.doc);
+            let values = match &case.payload {
+                Payload::Unit => String::new(),
+                Payload::Newtype(ty) => alloc::format!("({ty})"),
+                Payload::Tuple(types) => alloc::format!("({})", types.join(", ")),
+                Payload::Struct(properties) => {
+                    let values: Vec<String> = properties
+                        .iter()
+                        .map(|p| {
+                            alloc::format!("{}: {}", swift_identifier(&p.name), p.declared_type())
+                        })
+                        .collect();
+                    alloc::format!("({})", values.join(", "))
+                }
+            };
+            self.write_line(&alloc::format!("case {}{values}", case.name));
+        }
+        self.output.push('\n');
+        self.generate_enum_decoder(shape.type_identifier, &cases, tagging);
+        self.output.push('\n');
+        self.generate_enum_encoder(&cases, tagging);
+        self.indent -= 1;
+        self.output.push_str("}\n");
+    }
+
+    fn case_for_variant(&mut self, variant: &'static Variant) -> Case {
+        let fields = variant.data.fields;
+        let payload = match variant.data.kind {
+            _ if fields.is_empty() => Payload::Unit,
+            StructKind::Struct => {
+                let mut properties = Vec::new();
+                self.collect_properties(fields, false, &mut Vec::new(), &mut properties);
+                Payload::Struct(properties)
+            }
+            _ if fields.len() == 1 => Payload::Newtype(self.type_for_shape(fields[0].shape.get())),
+            _ => Payload::Tuple(
+                fields
+                    .iter()
+                    .map(|field| self.type_for_shape(field.shape.get()))
+                    .collect(),
+            ),
+        };
+        Case {
+            name: swift_identifier(&case_name(variant.name)),
+            serial_name: variant.effective_name(),
+            payload,
+            doc: variant.doc,
+        }
+    }
+
+    fn generate_enum_decoder(&mut self, name: &str, cases: &[Case], tagging: Tagging<'_>) {
+        self.write_line("init(from decoder: Decoder) throws {");
+        self.indent += 1;
+        match tagging {
+            Tagging::External => {
+                if cases.iter().any(|c| matches!(c.payload, Payload::Unit)) {
+                    self.write_line(
+                        "if let tag = try? decoder.singleValueContainer().decode(String.self) {",
+                    );
+                    self.indent += 1;
+                    self.write_line("switch tag {");
+                    for case in cases.iter().filter(|c| matches!(c.payload, Payload::Unit)) {
+                        self.write_line(&alloc::format!("case \"{}\":", case.serial_name));
+                        self.indent += 1;
+                        self.write_line(&alloc::format!("self = .{}", case.name));
+                        self.indent -= 1;
+                    }
+                    self.write_unknown_variant("tag");
+                    self.write_line("}");
+                    self.write_line("return");
+                    self.indent -= 1;
+                    self.write_line("}");
+                }
+                self.write_line(
+                    "let container = try decoder.container(keyedBy: AnyCodingKey.self)",
+                );
+                self.write_line("guard let key = container.allKeys.first else {");
+                self.indent += 1;
+                self.write_line(&alloc::format!(
+                    "throw DecodingError.dataCorrupted(.init(codingPath: decoder.codingPath, debugDescription: \"expected a variant of {name}\"))"
+                ));
+                self.indent -= 1;
+                self.write_line("}");
+                self.write_line("switch key.stringValue {");
+                for case in cases.iter().filter(|c| !matches!(c.payload, Payload::Unit)) {
+                    self.write_line(&alloc::format!("case \"{}\":", case.serial_name));
+                    self.indent += 1;
+                    self.write_decode(
+                        case,
+                        Location::Keyed {
+                            container: "container",
+                            key: "key".into(),
+                        },
+                    );
+                    self.indent -= 1;
+                }
+                self.write_unknown_variant("key.stringValue");
+                self.write_line("}");
+            }
+            Tagging::Internal { tag } | Tagging::Adjacent { tag, .. } => {
+                self.write_line(
+                    "let container = try decoder.container(keyedBy: AnyCodingKey.self)",
+                );
+                self.write_line(&alloc::format!(
+                    "let tag = try container.decode(String.self, forKey: AnyCodingKey(\"{tag}\"))"
+                ));
+                self.write_line("switch tag {");
+                for case in cases {
+                    self.write_line(&alloc::format!("case \"{}\":", case.serial_name));
+                    self.indent += 1;
+                    let location = match tagging {
+                        Tagging::Adjacent { content, .. } => Location::Keyed {
+                            container: "container",
+                            key: alloc::format!("AnyCodingKey(\"{content}\")"),
+                        },
+                        _ => Location::Inline {
+                            container: Some("container"),
+                        },
+                    };
+                    self.write_decode(case, location);
+                    self.indent -= 1;
+                }
+                self.write_unknown_variant("tag");
+                self.write_line("}");
+            }
+            Tagging::Untagged => {
+                // Try each variant in order, like the Rust deserializer does
+                for case in cases {
+                    if let Payload::Unit = case.payload {
+                        self.write_line(
+                            "if (try? decoder.singleValueContainer().decodeNil()) == true {",
+                        );
+                        self.indent += 1;
+                        self.write_line(&alloc::format!("self = .{}", case.name));
+                    } else {
+                        self.write_line("do {");
+                        self.indent += 1;
+                        self.write_decode(case, Location::Inline { container: None });
+                    }
+                    self.write_line("return");
+                    self.indent -= 1;
+                    if let Payload::Unit = case.payload {
+                        self.write_line("}");
+                    } else {
+                        self.write_line("} catch {}");
+                    }
+                }
+                self.write_line(&alloc::format!(
+                    "throw DecodingError.dataCorrupted(.init(codingPath: decoder.codingPath, debugDescription: \"data did not match any variant of {name}\"))"
+                ));
+            }
+        }
+        self.indent -= 1;
+        self.write_line("}");
+    }
+
+    fn write_unknown_variant(&mut self, tag: &str) {
+        self.write_line("default:");
+        self.indent += 1;
+        self.write_line(&alloc::format!(
+            "throw DecodingError.dataCorrupted(.init(codingPath: decoder.codingPath, debugDescription: \"unknown variant \\({tag})\"))"
+        ));
+        self.indent -= 1;
+    }
+
+    /// Writes the statements decoding `case`'s payload from `location` into `self`.
+    fn write_decode(&mut self, case: &Case, location: Location<'_>) {
+        let name = &case.name;
+        match &case.payload {
+            Payload::Unit => self.write_line(&alloc::format!("self = .{name}")),
+            Payload::Newtype(ty) => {
+                let value = match location {
+                    Location::Keyed { container, key } => {
+                        alloc::format!("try {container}.decode({ty}.self, forKey: {key})")
+                    }
+                    Location::Inline { .. } => {
+                        alloc::format!("try decoder.singleValueContainer().decode({ty}.self)")
+                    }
+                };
+                self.write_line(&alloc::format!("self = .{name}({value})"));
+            }
+            Payload::Tuple(types) => {
+                self.write_line(&match location {
+                    Location::Keyed { container, key } => alloc::format!(
+                        "var items = try {container}.nestedUnkeyedContainer(forKey: {key})"
+                    ),
+                    Location::Inline { .. } => "var items = try decoder.unkeyedContainer()".into(),
+                });
+                let values: Vec<String> = types
+                    .iter()
+                    .map(|ty| alloc::format!("try items.decode({ty}.self)"))
+                    .collect();
+                self.write_line(&alloc::format!("self = .{name}({})", values.join(", ")));
+            }
+            Payload::Struct(properties) => {
+                let fields = match location {
+                    Location::Keyed { container, key } => {
+                        self.write_line(&alloc::format!(
+                            "let fields = try {container}.nestedContainer(keyedBy: AnyCodingKey.self, forKey: {key})"
+                        ));
+                        "fields"
+                    }
+                    Location::Inline {
+                        container: Some(container),
+                    } => container,
+                    Location::Inline { container: None } => {
+                        self.write_line(
+                            "let fields = try decoder.container(keyedBy: AnyCodingKey.self)",
+                        );
+                        "fields"
+                    }
+                };
+                self.write_line(&alloc::format!("self = .{name}("));
+                self.indent += 1;
+                for (i, property) in properties.iter().enumerate() {
+                    let method = if property.optional {
+                        "decodeIfPresent"
+                    } else {
+                        "decode"
+                    };
+                    let separator = if i + 1 < properties.len() { "," } else { "" };
+                    self.write_line(&alloc::format!(
+                        "{}: try {fields}.{method}({}.self, forKey: AnyCodingKey(\"{}\")){separator}",
+                        swift_identifier(&property.name),
+                        property.ty,
+                        property.serial_name
+                    ));
+                }
+                self.indent -= 1;
+                self.write_line(")");
+            }
+        }
+    }
+
+    fn generate_enum_encoder(&mut self, cases: &[Case], tagging: Tagging<'_>) {
+        self.write_line("func encode(to encoder: Encoder) throws {");
+        self.indent += 1;
+        if let Tagging::Internal { .. } | Tagging::Adjacent { .. } = tagging {
+            self.write_line("var container = encoder.container(keyedBy: AnyCodingKey.self)");
+        }
+        self.write_line("switch self {");
+        for case in cases {
+            self.write_line(&alloc::format!("case {}:", case.pattern()));
+            self.indent += 1;
+            match tagging {
+                Tagging::External => {
+                    if let Payload::Unit = case.payload {
+                        self.write_line("var container = encoder.singleValueContainer()");
+                        self.write_line(&alloc::format!(
+                            "try container.encode(\"{}\")",
+                            case.serial_name
+                        ));
+                    } else {
+                        self.write_line(
+                            "var container = encoder.container(keyedBy: AnyCodingKey.self)",
+                        );
+                        self.write_encode(
+                            case,
+                            Location::Keyed {
+                                container: "container",
+                                key: alloc::format!("AnyCodingKey(\"{}\")", case.serial_name),
+                            },
+                        );
+                    }
+                }
+                Tagging::Internal { tag } => {
+                    self.write_line(&alloc::format!(
+                        "try container.encode(\"{}\", forKey: AnyCodingKey(\"{tag}\"))",
+                        case.serial_name
+                    ));
+                    self.write_encode(
+                        case,
+                        Location::Inline {
+                            container: Some("container"),
+                        },
+                    );
+                }
+                Tagging::Adjacent { tag, content } => {
+                    self.write_line(&alloc::format!(
+                        "try container.encode(\"{}\", forKey: AnyCodingKey(\"{tag}\"))",
+                        case.serial_name
+                    ));
+                    self.write_encode(
+                        case,
+                        Location::Keyed {
+                            container: "container",
+                            key: alloc::format!("AnyCodingKey(\"{content}\")"),
+                        },
+                    );
+                }
+                Tagging::Untagged => {
+                    if let Payload::Unit = case.payload {
+                        self.write_line("var container = encoder.singleValueContainer()");
+                        self.write_line("try container.encodeNil()");
+                    } else {
+                        self.write_encode(case, Location::Inline { container: None });
+                    }
+                }
+            }
+            self.indent -= 1;
+        }
+        self.write_line("}");
+        self.indent -= 1;
+        self.write_line("}");
+    }
+
+    /// Writes the statements encoding the values bound by `case.pattern()` to `location`.
+    fn write_encode(&mut self, case: &Case, location: Location<'_>) {
+        match &case.payload {
+            Payload::Unit => {}
+            Payload::Newtype(_) => self.write_line(&match location {
+                Location::Keyed { container, key } => {
+                    alloc::format!("try {container}.encode(value, forKey: {key})")
+                }
+                Location::Inline { .. } => "try value.encode(to: encoder)".into(),
+            }),
+            Payload::Tuple(types) => {
+                self.write_line(&match location {
+                    Location::Keyed { container, key } => alloc::format!(
+                        "var items = {container}.nestedUnkeyedContainer(forKey: {key})"
+                    ),
+                    Location::Inline { .. } => "var items = encoder.unkeyedContainer()".into(),
+                });
+                for i in 0..types.len() {
+                    self.write_line(&alloc::format!("try items.encode(_{i})"));
+                }
+            }
+            Payload::Struct(properties) => {
+                let fields = match location {
+                    Location::Keyed { container, key } => {
+                        self.write_line(&alloc::format!(
+                            "var fields = {container}.nestedContainer(keyedBy: AnyCodingKey.self, forKey: {key})"
+                        ));
+                        "fields"
+                    }
+                    Location::Inline {
+                        container: Some(container),
+                    } => container,
+                    Location::Inline { container: None } => {
+                        self.write_line(
+                            "var fields = encoder.container(keyedBy: AnyCodingKey.self)",
+                        );
+                        "fields"
+                    }
+                };
+                for property in properties {
+                    let method = if property.optional {
+                        "encodeIfPresent"
+                    } else {
+                        "encode"
+                    };
+                    self.write_line(&alloc::format!(
+                        "try {fields}.{method}({}, forKey: AnyCodingKey(\"{}\"))",
+                        swift_identifier(&property.name),
+                        property.serial_name
+                    ));
+                }
+            }
+        }
+    }
+
+    fn type_for_shape(&mut self, shape: &'static Shape) -> String {
+        // Check Def first - these take precedence over transparent wrappers
+        match &shape.def {
+            Def::Scalar => self.scalar_type(shape),
+            Def::Option(opt) => {
+                alloc::format!("{}?", self.type_for_shape(opt.t))
+            }
+            Def::List(list) => {
+                alloc::format!("[{}]", self.type_for_shape(list.t))
+            }
+            Def::Array(arr) => {
+                alloc::format!("[{}]", self.type_for_shape(arr.t))
+            }
+            Def::Set(set) => {
+                alloc::format!("Set<{}>", self.type_for_shape(set.t))
+            }
+            Def::Map(map) => {
+                let key = self.type_for_shape(map.k);
+                alloc::format!("[{key}: {}]", self.type_for_shape(map.v))
+            }
+            Def::Pointer(ptr) => {
+                // Smart pointers are transparent
+                match ptr.pointee {
+                    Some(pointee) => self.type_for_shape(pointee),
+                    None => self.json_value(),
+                }
+            }
+            _ => match &shape.ty {
+                Type::User(UserType::Struct(st)) if st.kind == StructKind::Tuple => {
+                    // Tuples serialize as arrays of mixed types
+                    alloc::format!("[{}]", self.json_value())
+                }
+                Type::User(UserType::Struct(_) | UserType::Enum(_)) => {
+                    self.add_shape(shape);
+                    shape.type_identifier.into()
+                }
+                _ => match shape.inner {
+                    Some(inner) => self.type_for_shape(inner),
+                    None => self.json_value(),
+                },
+            },
+        }
+    }
+
+    fn json_value(&mut self) -> String {
+        self.support.insert(Support::JsonValue);
+        "JSONValue".into()
+    }
+
+    fn scalar_type(&mut self, shape: &'static Shape) -> String {
+        let by_kind = match shape.scalar_kind() {
+            // `Character` isn't Codable
+            Some(ScalarKind::Str | ScalarKind::Char) => Some("String"),
+            Some(ScalarKind::Bool) => Some("Bool"),
+            Some(ScalarKind::I8) => Some("Int8"),
+            Some(ScalarKind::I16) => Some("Int16"),
+            Some(ScalarKind::I32) => Some("Int32"),
+            Some(ScalarKind::I64) => Some("Int64"),
+            Some(ScalarKind::ISize) => Some("Int"),
+            Some(ScalarKind::U8) => Some("UInt8"),
+            Some(ScalarKind::U16) => Some("UInt16"),
+            Some(ScalarKind::U32) => Some("UInt32"),
+            Some(ScalarKind::U64) => Some("UInt64"),
+            Some(ScalarKind::USize) => Some("UInt"),
+            Some(ScalarKind::F32) => Some("Float"),
+            Some(ScalarKind::F64) => Some("Double"),
+            // 128-bit integers have no Swift counterpart.
+            Some(_) => return self.json_value(),
+            None => None,
+        };
+        if let Some(ty) = by_kind {
+            return ty.into();
+        }
+
+        // Scalars without a kind are recognized by name.
+        let ty = match shape.type_identifier {
+            // `Character` isn't Codable
+            "String" | "str" | "&str" | "Cow" | "char" => "String",
+            "bool" => "Bool",
+            "i8" => "Int8",
+            "i16" => "Int16",
+            "i32" => "Int32",
+            "i64" => "Int64",
+            "isize" => "Int",
+            "u8" => "UInt8",
+            "u16" => "UInt16",
+            "u32" => "UInt32",
+            "u64" => "UInt64",
+            "usize" => "UInt",
+            "f32" => "Float",
+            "f64" => "Double",
+
+            // chrono types
+            "NaiveDate"
+            | "NaiveDateTime"
+            | "NaiveTime"
+            | "DateTime<Utc>"
+            | "DateTime<FixedOffset>"
+            | "DateTime<Local>"
+                if shape.module_path == Some("chrono") =>
+            {
+                "String"
+            }
+
+            // Unknown scalar (including 128-bit integers)
+            _ => return self.json_value(),
+        };
+        ty.into()
+    }
+}
+
+const ANY_CODING_KEY: &str = r#"/// A coding key for arbitrary strings, used by the enum coders above.
+struct AnyCodingKey: CodingKey {
+    var stringValue: String
+    var intValue: Int? { nil }
+
+    init(_ stringValue: String) { self.stringValue = stringValue }
+    init?(stringValue: String) { self.stringValue = stringValue }
+    init?(intValue: Int) { return nil }
+}
+"#;
+
+const JSON_VALUE: &str = r#"/// An arbitrary JSON value, for Rust types with no Swift equivalent.
+enum JSONValue: Codable, Equatable {
+    case null
+    case bool(Bool)
+    case number(Double)
+    case string(String)
+    case array([JSONValue])
+    case object([String: JSONValue])
+
+    init(from decoder: Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if container.decodeNil() {
+            self = .null
+        } else if let value = try? container.decode(Bool.self) {
+            self = .bool(value)
+        } else if let value = try? container.decode(Double.self) {
+            self = .number(value)
+        } else if let value = try? container.decode(String.self) {
+            self = .string(value)
+        } else if let value = try? container.decode([JSONValue].self) {
+            self = .array(value)
+        } else {
+            self = .object(try container.decode([String: JSONValue].self))
+        }
+    }
+
+    func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .null: try container.encodeNil()
+        case .bool(let value): try container.encode(value)
+        case .number(let value): try container.encode(value)
+        case .string(let value): try container.encode(value)
+        case .array(let value): try container.encode(value)
+        case .object(let value): try container.encode(value)
+        }
+    }
+}
+"#;
+
+/// Unwrap through options, pointers, transparent wrappers, and proxies to get the effective shape.
+///
+/// Returns the unwrapped shape along with a flag indicating whether an `Option` was encountered.
+fn unwrap_to_inner_shape(shape: &'static Shape) -> (&'static Shape, bool) {
+    let (shape, is_optional) = shape.flatten_target();
+    if let Some(inner) = shape.inner {
+        let (inner, inner_optional) = unwrap_to_inner_shape(inner);
+        return (inner, is_optional || inner_optional);
+    }
+    if let Some(proxy_def) = shape.proxy {
+        let (proxy, proxy_optional) = unwrap_to_inner_shape(proxy_def.shape);
+        return (proxy, is_optional || proxy_optional);
+    }
+    (shape, is_optional)
+}
+
+/// Swift property name for a Rust field name: `user_id` -> `userId`, and
+/// tuple fields (`0`) -> `_0`.
+fn property_name(name: &str) -> String {
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        return alloc::format!("_{name}");
+    }
+    let mut out = String::with_capacity(name.len());
+    let mut upper = false;
+    for c in name.chars() {
+        if c == '_' && !out.is_empty() {
+            upper = true;
+        } else if upper {
+            out.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Swift case name for a Rust variant name: `KeyPress` -> `keyPress`.
+fn case_name(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+/// Escapes Swift keywords with backticks.
+fn swift_identifier(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "as",
+        "associatedtype",
+        "break",
+        "case",
+        "catch",
+        "class",
+        "continue",
+        "default",
+        "defer",
+        "deinit",
+        "do",
+        "else",
+        "enum",
+        "extension",
+        "fallthrough",
+        "false",
+        "fileprivate",
+        "for",
+        "func",
+        "guard",
+        "if",
+        "import",
+        "in",
+        "init",
+        "inout",
+        "internal",
+        "is",
+        "let",
+        "nil",
+        "open",
+        "operator",
+        "private",
+        "protocol",
+        "public",
+        "repeat",
+        "rethrows",
+        "return",
+        "self",
+        "static",
+        "struct",
+        "subscript",
+        "super",
+        "switch",
+        "throw",
+        "throws",
+        "true",
+        "try",
+        "typealias",
+        "var",
+        "where",
+        "while",
+    ];
+    if KEYWORDS.contains(&name) {
+        alloc::format!("`{name}`")
+    } else {
+        name.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::collections::BTreeMap;
+    use facet::Facet;
+
+    #[test]
+    fn test_simple_struct() {
+        /// A registered user.
+        #[derive(Facet)]
+        struct User {
+            /// Display name.
+            name: String,
+            user_id: u64,
+            email: Option<String>,
+            tags: Vec<String>,
+        }
+
+        let swift = to_swift::<User>();
+        insta::assert_snapshot!(swift);
+    }
+
+    #[test]
+    fn test_renamed_fields() {
+        #[derive(Facet)]
+        #[facet(rename_all = "kebab-case")]
+        struct Settings {
+            dark_mode: bool,
+            #[facet(rename = "fontScale")]
+            font_scale: f32,
+            retries: i32,
+        }
+
+        let swift = to_swift::<Settings>();
+        insta::assert_snapshot!(swift);
+    }
+
+    #[test]
+    fn test_unit_enum() {
+        #[derive(Facet)]
+        #[repr(u8)]
+        #[facet(rename_all = "snake_case")]
+        #[allow(dead_code)]
+        enum Status {
+            Active,
+            OnHold,
+            Closed,
+        }
+
+        let swift = to_swift::<Status>();
+        insta::assert_snapshot!(swift);
+    }
+
+    #[test]
+    fn test_nested_types() {
+        #[derive(Facet)]
+        struct Address {
+            city: String,
+        }
+
+        #[derive(Facet)]
+        struct Customer {
+            address: Address,
+            scores: BTreeMap<String, f64>,
+            id: u128,
+        }
+
+        let mut generator = SwiftGenerator::new();
+        generator.add_type::<Customer>();
+        insta::assert_snapshot!(generator.finish());
+    }
+
+    #[test]
+    fn test_externally_tagged_enum() {
+        #[derive(Facet)]
+        #[repr(u8)]
+        #[allow(dead_code)]
+        enum Figure {
+            Circle(f64),
+            Rect { width: f64, height: f64 },
+            Segment(i32, i32),
+            Empty,
+        }
+
+        let swift = to_swift::<Figure>();
+        insta::assert_snapshot!(swift);
+    }
+
+    #[test]
+    fn test_internally_tagged_enum() {
+        #[derive(Facet)]
+        struct Point {
+            x: i32,
+            y: i32,
+        }
+
+        #[derive(Facet)]
+        #[repr(C)]
+        #[facet(tag = "kind", rename_all = "snake_case")]
+        #[allow(dead_code)]
+        enum Event {
+            Click(Point),
+            KeyPress {
+                key_code: u32,
+                modifier: Option<String>,
+            },
+            Close,
+        }
+
+        let swift = to_swift::<Event>();
+        insta::assert_snapshot!(swift);
+    }
+
+    #[test]
+    fn test_adjacently_tagged_enum() {
+        #[derive(Facet)]
+        #[repr(u8)]
+        #[facet(tag = "t", content = "c")]
+        #[allow(dead_code)]
+        enum Message {
+            Text(String),
+            Ping,
+        }
+
+        let swift = to_swift::<Message>();
+        insta::assert_snapshot!(swift);
+    }
+
+    #[test]
+    fn test_untagged_enum() {
+        #[derive(Facet)]
+        #[repr(u8)]
+        #[facet(untagged)]
+        #[allow(dead_code)]
+        enum Value {
+            Number(f64),
+            Named { name: String },
+            Missing,
+        }
+
+        let swift = to_swift::<Value>();
+        insta::assert_snapshot!(swift);
+    }
+
+    #[test]
+    fn test_tuple_struct_and_flatten() {
+        #[derive(Facet)]
+        struct Span(u32, u32);
+
+        #[derive(Facet)]
+        struct Meta {
+            created_at: String,
+        }
+
+        #[derive(Facet)]
+        struct Document {
+            title: String,
+            span: Span,
+            #[facet(flatten)]
+            meta: Meta,
+        }
+
+        let swift = to_swift::<Document>();
+        insta::assert_snapshot!(swift);
+    }
+
+    #[test]
+    fn test_identifiers() {
+        assert_eq!(property_name("user_id"), "userId");
+        assert_eq!(property_name("0"), "_0");
+        assert_eq!(case_name("KeyPress"), "keyPress");
+        assert_eq!(swift_identifier("default"), "`default`");
+    }
+}
@@ -0,0 +1,771 @@
+//! Comment- and format-preserving editing of TOML documents.
+//!
+//! [`TomlDocument`] keeps the original text and edits it in place: merging a
+//! value rewrites only the values that changed and adds the keys that are
+//! missing, so comments, whitespace and key order survive.
+
+use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use facet_format::{DeserializeError, SerializeError};
+use facet_reflect::Peek;
+use toml_parser::{
+    Raw, Source,
+    decoder::ScalarKind,
+    parser::{Event, EventKind, RecursionGuard, parse_document},
+};
+
+use crate::parser::TomlParseErrorCollector;
+use crate::serializer::{
+    Entry as TableEntry, Item, SerializeOptions, TomlSerializer, Writer, write_inline, write_key,
+};
+use crate::{TomlError, TomlErrorKind};
+
+/// A TOML document that can be updated from a value without losing its
+/// comments, whitespace or key order.
+///
+/// This is meant for tools that edit files people also edit by hand, such as
+/// `Cargo.toml`: read the document, deserialize it, change the value, and
+/// [`merge`](Self::merge) it back.
+///
+/// ```
+/// use facet::Facet;
+/// use facet_toml::TomlDocument;
+///
+/// #[derive(Facet)]
+/// struct Package {
+///     name: String,
+///     version: String,
+/// }
+///
+/// #[derive(Facet)]
+/// struct Manifest {
+///     package: Package,
+/// }
+///
+/// let mut doc = TomlDocument::parse(
+///     "# The package\n[package]\nname = \"demo\" # keep me\nversion = \"0.1.0\"\n",
+/// )
+/// .unwrap();
+///
+/// let mut manifest: Manifest = doc.deserialize().unwrap();
+/// manifest.package.version = "0.2.0".into();
+/// doc.merge(&manifest).unwrap();
+///
+/// assert_eq!(
+///     doc.as_str(),
+///     "# The package\n[package]\nname = \"demo\" # keep me\nversion = \"0.2.0\"\n",
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TomlDocument {
+    source: String,
+}
+
+impl TomlDocument {
+    /// Parse a document, checking that it is valid TOML.
+    pub fn parse(source: &str) -> Result<Self, TomlError> {
+        parse_events(source)?;
+        Ok(Self {
+            source: source.to_string(),
+        })
+    }
+
+    /// The document text.
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Consume the document and return its text.
+    pub fn into_string(self) -> String {
+        self.source
+    }
+
+    /// Deserialize the document into an owned value.
+    pub fn deserialize<T>(&self) -> Result<T, DeserializeError>
+    where
+        T: facet_core::Facet<'static>,
+    {
+        crate::from_str(&self.source)
+    }
+
+    /// Merge a value into the document, touching only what changed.
+    ///
+    /// The value is serialized as with [`to_string`](crate::to_string) and
+    /// laid over the document:
+    ///
+    /// - A key the document already has keeps its place; its value is only
+    ///   rewritten if it differs (so `'demo'` stays `'demo'` when the value
+    ///   is still `demo`). Inline tables are merged key by key, other values
+    ///   are replaced whole.
+    /// - A missing key is added at the end of its table, and a missing table
+    ///   gets a new section after its parent's.
+    /// - Arrays of tables (`[[bin]]`) are merged element by element; extra
+    ///   elements are appended and surplus ones removed.
+    /// - Keys the document has but the value doesn't, including `None`
+    ///   fields, are left alone.
+    ///
+    /// Fails, leaving the document unchanged, if the value has a plain value
+    /// where the document has a table.
+    pub fn merge<'facet, T>(&mut self, value: &T) -> Result<(), TomlError>
+    where
+        T: facet_core::Facet<'facet>,
+    {
+        let mut serializer = TomlSerializer::new();
+        facet_format::serialize_root(&mut serializer, Peek::new(value)).map_err(|e| {
+            let message = match e {
+                SerializeError::Backend(e) => e.to_string(),
+                e => e.to_string(),
+            };
+            TomlError::without_span(TomlErrorKind::Serialize(message))
+        })?;
+        let table = serializer.into_table();
+
+        let index = Index::build(&self.source)?;
+        let options = SerializeOptions::default().pretty();
+        let mut merge = Merge {
+            source: &self.source,
+            index: &index,
+            options: &options,
+            edits: Vec::new(),
+        };
+        merge.table(&[], None, &table)?;
+        let edited = merge.apply();
+
+        // Only keep the edits if they left a valid document.
+        parse_events(&edited)?;
+        self.source = edited;
+        Ok(())
+    }
+}
+
+impl core::fmt::Display for TomlDocument {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl core::str::FromStr for TomlDocument {
+    type Err = TomlError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+fn parse_events(source: &str) -> Result<Vec<Event>, TomlError> {
+    let tokens: Vec<_> = Source::new(source).lex().collect();
+    let mut events: Vec<Event> = Vec::new();
+    let mut guarded = RecursionGuard::new(&mut events, 128);
+    let mut error_collector = TomlParseErrorCollector::new();
+
+    parse_document(&tokens, &mut guarded, &mut error_collector);
+
+    match error_collector.take_error() {
+        Some((message, span)) => Err(TomlError::new(TomlErrorKind::Parse(message), span)),
+        None => Ok(events),
+    }
+}
+
+fn raw<'s>(source: &'s str, event: &Event) -> Raw<'s> {
+    let span = event.span();
+    Raw::new_unchecked(&source[span.start()..span.end()], event.encoding(), span)
+}
+
+fn decode_key(source: &str, event: &Event) -> String {
+    let mut key: Cow<'_, str> = Cow::Borrowed("");
+    raw(source, event).decode_key(&mut key, &mut ());
+    key.into_owned()
+}
+
+// ============================================================================
+// Document index
+// ============================================================================
+
+/// One step of a path through the document.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Seg {
+    Key(String),
+    /// An element of an array of tables.
+    Index(usize),
+}
+
+/// A table header and the lines under it; the root table is the section
+/// before the first header.
+#[derive(Debug)]
+struct Section {
+    path: Vec<Seg>,
+    /// Start of the header.
+    start: usize,
+    /// End of the header line or of the last key/value line, where new keys
+    /// for this table go.
+    content_end: usize,
+}
+
+/// A key/value pair, at any depth (including inside inline tables).
+#[derive(Debug)]
+struct Entry {
+    path: Vec<Seg>,
+    value: Range<usize>,
+    /// End of the line the key/value pair ends on.
+    line_end: usize,
+    /// Set for inline tables whose keys can be edited one by one.
+    inline_table: Option<InlineTable>,
+}
+
+#[derive(Debug)]
+struct InlineTable {
+    /// Just after the last value, or after `{` if there is none.
+    insert_at: usize,
+    empty: bool,
+}
+
+/// Where every table and key/value pair of a document is.
+struct Index {
+    sections: Vec<Section>,
+    entries: Vec<Entry>,
+}
+
+impl Index {
+    fn build(source: &str) -> Result<Self, TomlError> {
+        let mut scanner = Scanner {
+            source,
+            events: parse_events(source)?,
+            pos: 0,
+        };
+        let mut sections = vec![Section {
+            path: Vec::new(),
+            start: 0,
+            content_end: 0,
+        }];
+        let mut entries = Vec::new();
+        // How many elements each array of tables has so far.
+        let mut arrays: BTreeMap<Vec<Seg>, usize> = BTreeMap::new();
+
+        while let Some(i) = scanner.next() {
+            match scanner.events[i].kind() {
+                kind @ (EventKind::StdTableOpen | EventKind::ArrayTableOpen) => {
+                    let array = matches!(kind, EventKind::ArrayTableOpen);
+                    let start = scanner.events[i].span().start();
+                    let keys = scanner.dotted_key();
+                    scanner.next(); // `]` or `]]`
+                    sections.push(Section {
+                        path: header_path(keys, array, &mut arrays),
+                        start,
+                        content_end: scanner.line_end(),
+                    });
+                }
+                EventKind::SimpleKey => {
+                    scanner.pos = i;
+                    let keys = scanner.dotted_key();
+                    scanner.next(); // `=`
+                    let section = sections
+                        .last_mut()
+                        .expect("the root section is always there");
+                    let mut path = section.path.clone();
+                    path.extend(keys.into_iter().map(Seg::Key));
+                    let first = entries.len();
+                    scanner.value(path, &mut entries);
+                    section.content_end = scanner.line_end();
+                    for entry in &mut entries[first..] {
+                        entry.line_end = section.content_end;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(Self { sections, entries })
+    }
+
+    fn entry(&self, path: &[Seg]) -> Option<&Entry> {
+        self.entries.iter().find(|entry| entry.path == path)
+    }
+
+    /// Whether the document has a table at `path`, through a header, a
+    /// dotted key or anything under it.
+    fn defines_table(&self, path: &[Seg]) -> bool {
+        self.sections
+            .iter()
+            .any(|section| section.path.starts_with(path))
+            || self
+                .entries
+                .iter()
+                .any(|entry| entry.path.len() > path.len() && entry.path.starts_with(path))
+    }
+
+    /// The number of `[[path]]` elements in the document.
+    fn array_len(&self, path: &[Seg]) -> usize {
+        self.sections
+            .iter()
+            .filter(|section| section.path.starts_with(path))
+            .filter_map(|section| match section.path[path.len()..] {
+                [Seg::Index(i)] => Some(i + 1),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The section new keys of the table at `path` go in, and the dotted
+    /// key prefix that leads from it to the table.
+    fn owner<'p>(&self, path: &'p [Seg]) -> (&Section, Vec<&'p str>) {
+        for len in (0..=path.len()).rev() {
+            let (head, rest) = path.split_at(len);
+            let Some(section) = self.sections.iter().find(|section| section.path == head) else {
+                continue;
+            };
+            let keys: Option<Vec<&str>> = rest
+                .iter()
+                .map(|seg| match seg {
+                    Seg::Key(key) => Some(key.as_str()),
+                    Seg::Index(_) => None,
+                })
+                .collect();
+            if let Some(keys) = keys {
+                return (section, keys);
+            }
+        }
+        (&self.sections[0], Vec::new())
+    }
+
+    /// Where a new dotted key under `path` goes in `section`: after the
+    /// last key already under `path` there, or at the end of the section.
+    fn dotted_end(&self, section: &Section, path: &[Seg]) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| {
+                entry.path.len() > path.len()
+                    && entry.path.starts_with(path)
+                    && (section.start..=section.content_end).contains(&entry.line_end)
+            })
+            .map(|entry| entry.line_end)
+            .max()
+            .unwrap_or(section.content_end)
+    }
+
+    /// Where new sections under `path` go: after the last line of the last
+    /// section under it.
+    fn group_end(&self, mut path: &[Seg]) -> usize {
+        loop {
+            let end = self
+                .sections
+                .iter()
+                .filter(|section| section.path.starts_with(path))
+                .map(|section| section.content_end)
+                .max();
+            match end {
+                Some(end) => return end,
+                // Every section is under the root, so this terminates.
+                None => path = &path[..path.len() - 1],
+            }
+        }
+    }
+}
+
+/// The logical path of a table header, counting `[[array]]` elements.
+fn header_path(keys: Vec<String>, array: bool, arrays: &mut BTreeMap<Vec<Seg>, usize>) -> Vec<Seg> {
+    let mut path = Vec::new();
+    let last = keys.len().saturating_sub(1);
+    for (i, key) in keys.into_iter().enumerate() {
+        path.push(Seg::Key(key));
+        if array && i == last {
+            let count = arrays.entry(path.clone()).or_insert(0);
+            path.push(Seg::Index(*count));
+            *count += 1;
+        } else if let Some(&count) = arrays.get(&path) {
+            // `[a.b]` after `[[a]]` belongs to the latest `a`.
+            path.push(Seg::Index(count - 1));
+        }
+    }
+    path
+}
+
+/// Walks the parser events of a document.
+struct Scanner<'a> {
+    source: &'a str,
+    events: Vec<Event>,
+    pos: usize,
+}
+
+impl Scanner<'_> {
+    /// Index of the next event that isn't whitespace, a comment or a newline.
+    fn peek(&self) -> Option<usize> {
+        (self.pos..self.events.len()).find(|&i| {
+            !matches!(
+                self.events[i].kind(),
+                EventKind::Whitespace | EventKind::Comment | EventKind::Newline
+            )
+        })
+    }
+
+    fn next(&mut self) -> Option<usize> {
+        let i = self.peek()?;
+        self.pos = i + 1;
+        Some(i)
+    }
+
+    /// The end of the current line, past any trailing comment.
+    fn line_end(&self) -> usize {
+        for event in &self.events[self.pos..] {
+            match event.kind() {
+                EventKind::Whitespace | EventKind::Comment => {}
+                EventKind::Newline => return event.span().end(),
+                _ => return event.span().start(),
+            }
+        }
+        self.source.len()
+    }
+
+    fn dotted_key(&mut self) -> Vec<String> {
+        let mut keys = Vec::new();
+        while let Some(i) = self.peek() {
+            match self.events[i].kind() {
+                EventKind::SimpleKey => keys.push(decode_key(self.source, &self.events[i])),
+                EventKind::KeySep => {}
+                _ => break,
+            }
+            self.pos = i + 1;
+        }
+        keys
+    }
+
+    /// Record the value at `path` (and, for inline tables, the values in
+    /// it), returning its span.
+    fn value(&mut self, path: Vec<Seg>, entries: &mut Vec<Entry>) -> Range<usize> {
+        let Some(i) = self.next() else {
+            return self.source.len()..self.source.len();
+        };
+        let span = self.events[i].span();
+        let (start, mut end) = (span.start(), span.end());
+
+        match self.events[i].kind() {
+            EventKind::InlineTableOpen => {
+                let slot = entries.len();
+                entries.push(Entry {
+                    path: path.clone(),
+                    value: start..end,
+                    line_end: 0,
+                    inline_table: None,
+                });
+                let mut insert_at = end;
+                let mut empty = true;
+                let mut dotted = false;
+                while let Some(j) = self.peek() {
+                    match self.events[j].kind() {
+                        EventKind::InlineTableClose => {
+                            self.pos = j + 1;
+                            end = self.events[j].span().end();
+                            break;
+                        }
+                        EventKind::SimpleKey => {
+                            let keys = self.dotted_key();
+                            dotted |= keys.len() > 1;
+                            self.next(); // `=`
+                            let mut child = path.clone();
+                            child.extend(keys.into_iter().map(Seg::Key));
+                            insert_at = self.value(child, entries).end;
+                            empty = false;
+                        }
+                        _ => self.pos = j + 1,
+                    }
+                }
+                entries[slot].value = start..end;
+                // Dotted keys inside inline tables are rare; such tables are
+                // replaced whole rather than edited.
+                if !dotted {
+                    entries[slot].inline_table = Some(InlineTable { insert_at, empty });
+                }
+            }
+            EventKind::ArrayOpen => {
+                let mut depth = 1;
+                while let Some(j) = self.next() {
+                    match self.events[j].kind() {
+                        EventKind::ArrayOpen | EventKind::InlineTableOpen => depth += 1,
+                        EventKind::ArrayClose | EventKind::InlineTableClose => {
+                            depth -= 1;
+                            if depth == 0 {
+                                end = self.events[j].span().end();
+                                break;
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                entries.push(Entry {
+                    path,
+                    value: start..end,
+                    line_end: 0,
+                    inline_table: None,
+                });
+            }
+            _ => entries.push(Entry {
+                path,
+                value: start..end,
+                line_end: 0,
+                inline_table: None,
+            }),
+        }
+        start..end
+    }
+}
+
+// ============================================================================
+// Merging
+// ============================================================================
+
+struct Merge<'a> {
+    source: &'a str,
+    index: &'a Index,
+    options: &'a SerializeOptions,
+    /// Replacements, with empty ranges for insertions.
+    edits: Vec<(Range<usize>, String)>,
+}
+
+impl<'a> Merge<'a> {
+    /// Merge `entries` into the table at `path`, which is an inline table
+    /// in the document if `inline` is set.
+    fn table(
+        &mut self,
+        path: &[Seg],
+        inline: Option<&InlineTable>,
+        entries: &[TableEntry],
+    ) -> Result<(), TomlError> {
+        let index: &'a Index = self.index;
+        let mut added: Vec<String> = Vec::new();
+
+        for TableEntry { key, item, .. } in entries {
+            let mut child = path.to_vec();
+            child.push(Seg::Key(key.clone()));
+
+            if let Some(entry) = index.entry(&child) {
+                match (item, &entry.inline_table) {
+                    (Item::Table(sub, _), Some(table)) => self.table(&child, Some(table), sub)?,
+                    _ => self.replace(entry.value.clone(), item),
+                }
+            } else if inline.is_some() {
+                let mut pair = String::new();
+                write_key(&mut pair, key);
+                pair.push_str(" = ");
+                write_inline(&mut pair, item);
+                added.push(pair);
+            } else if index.defines_table(&child) {
+                match item {
+                    Item::Table(sub, _) => self.table(&child, None, sub)?,
+                    Item::Array(items, _) if index.array_len(&child) > 0 => {
+                        self.array_of_tables(&child, items)?
+                    }
+                    _ => return Err(table_conflict(&child)),
+                }
+            } else {
+                self.insert(path, key, item);
+            }
+        }
+
+        if let Some(table) = inline
+            && !added.is_empty()
+        {
+            let text = if table.empty {
+                format!(" {} ", added.join(", "))
+            } else {
+                format!(", {}", added.join(", "))
+            };
+            self.edits.push((table.insert_at..table.insert_at, text));
+        }
+        Ok(())
+    }
+
+    fn array_of_tables(&mut self, path: &[Seg], items: &[Item]) -> Result<(), TomlError> {
+        let existing = self.index.array_len(path);
+        for (i, item) in items.iter().enumerate() {
+            let Item::Table(sub, _) = item else {
+                return Err(table_conflict(path));
+            };
+            if i < existing {
+                let mut element = path.to_vec();
+                element.push(Seg::Index(i));
+                self.table(&element, None, sub)?;
+            } else {
+                let mut writer = Writer::new(self.options);
+                let mut header = header_keys(path);
+                writer.write_header(&header, true);
+                writer.write_table(&mut header, sub);
+                let at = self.index.group_end(path);
+                self.insert_lines(at, writer.out, true);
+            }
+        }
+
+        for i in items.len()..existing {
+            let mut element = path.to_vec();
+            element.push(Seg::Index(i));
+            for section in &self.index.sections {
+                if section.path.starts_with(&element) {
+                    self.edits
+                        .push((section.start..section.content_end, String::new()));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Replace a value, unless the document already says the same thing.
+    fn replace(&mut self, range: Range<usize>, item: &Item) {
+        let mut text = String::new();
+        write_inline(&mut text, item);
+        if !same_value(&self.source[range.clone()], &text) {
+            self.edits.push((range, text));
+        }
+    }
+
+    /// Add `key` to the table at `path`, which the document doesn't have yet.
+    fn insert(&mut self, path: &[Seg], key: &str, item: &Item) {
+        let mut writer = Writer::new(self.options);
+        if writer.is_inline(item) {
+            let (section, prefix) = self.index.owner(path);
+            let at = if prefix.is_empty() {
+                section.content_end
+            } else {
+                self.index.dotted_end(section, path)
+            };
+            let mut line = String::new();
+            for part in prefix {
+                write_key(&mut line, part);
+                line.push('.');
+            }
+            write_key(&mut line, key);
+            line.push_str(" = ");
+            write_inline(&mut line, item);
+            line.push('\n');
+            self.insert_lines(at, line, false);
+        } else {
+            let mut header = header_keys(path);
+            writer.write_section(&mut header, key, &[], item);
+            let at = self.index.group_end(path);
+            self.insert_lines(at, writer.out, true);
+        }
+    }
+
+    /// Insert whole lines at `at`, set apart by a blank line if `separate`.
+    fn insert_lines(&mut self, at: usize, lines: String, separate: bool) {
+        let mut text = String::new();
+        if at > 0 && !self.source[..at].ends_with('\n') {
+            text.push('\n');
+        }
+        if separate && at > 0 {
+            text.push('\n');
+        }
+        text.push_str(&lines);
+        self.edits.push((at..at, text));
+    }
+
+    fn apply(mut self) -> String {
+        // Stable, so insertions at the same spot stay in order.
+        self.edits.sort_by_key(|(range, _)| range.start);
+        let mut out = String::with_capacity(self.source.len());
+        let mut cursor = 0;
+        for (range, text) in self.edits {
+            out.push_str(&self.source[cursor..range.start]);
+            out.push_str(&text);
+            cursor = range.end;
+        }
+        out.push_str(&self.source[cursor..]);
+        out
+    }
+}
+
+/// The keys of a table header for `path`; headers name the latest element
+/// of an array of tables, so indices are dropped.
+fn header_keys(path: &[Seg]) -> Vec<String> {
+    path.iter()
+        .filter_map(|seg| match seg {
+            Seg::Key(key) => {
+                let mut rendered = String::new();
+                write_key(&mut rendered, key);
+                Some(rendered)
+            }
+            Seg::Index(_) => None,
+        })
+        .collect()
+}
+
+fn table_conflict(path: &[Seg]) -> TomlError {
+    let mut dotted = String::new();
+    for seg in path {
+        match seg {
+            Seg::Key(key) => {
+                if !dotted.is_empty() {
+                    dotted.push('.');
+                }
+                write_key(&mut dotted, key);
+            }
+            Seg::Index(i) => dotted.push_str(&format!("[{i}]")),
+        }
+    }
+    TomlError::without_span(TomlErrorKind::InvalidValue {
+        message: format!("`{dotted}` is a table in the document but not in the merged value"),
+    })
+}
+
+/// A value token with scalars decoded, so that `'a'` and `"a"`, or `1_000`
+/// and `1000`, compare equal.
+#[derive(PartialEq)]
+enum Token {
+    Punct(char),
+    Key(String),
+    Str(String),
+    Bool(bool),
+    Integer(i64),
+    Float(u64),
+    DateTime(String),
+}
+
+/// Whether two value texts mean the same thing.
+fn same_value(a: &str, b: &str) -> bool {
+    match (value_tokens(a), value_tokens(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => false,
+    }
+}
+
+fn value_tokens(value: &str) -> Option<Vec<Token>> {
+    let source = format!("v = {value}");
+    let events = parse_events(&source).ok()?;
+    let mut tokens = Vec::new();
+    let value_events = events
+        .iter()
+        .skip_while(|event| !matches!(event.kind(), EventKind::KeyValSep))
+        .skip(1);
+    for event in value_events {
+        let token = match event.kind() {
+            EventKind::Scalar => {
+                let mut decoded: Cow<'_, str> = Cow::Borrowed("");
+                match raw(&source, event).decode_scalar(&mut decoded, &mut ()) {
+                    ScalarKind::String => Token::Str(decoded.into_owned()),
+                    ScalarKind::Boolean(b) => Token::Bool(b),
+                    ScalarKind::Integer(radix) => Token::Integer(
+                        i64::from_str_radix(&decoded.replace('_', ""), radix.value()).ok()?,
+                    ),
+                    ScalarKind::Float => {
+                        Token::Float(decoded.replace('_', "").parse::<f64>().ok()?.to_bits())
+                    }
+                    ScalarKind::DateTime => Token::DateTime(decoded.into_owned()),
+                }
+            }
+            EventKind::SimpleKey => Token::Key(decode_key(&source, event)),
+            EventKind::KeySep => Token::Punct('.'),
+            EventKind::KeyValSep => Token::Punct('='),
+            EventKind::ArrayOpen => Token::Punct('['),
+            EventKind::ArrayClose => Token::Punct(']'),
+            EventKind::InlineTableOpen => Token::Punct('{'),
+            EventKind::InlineTableClose => Token::Punct('}'),
+            // Whitespace, comments, newlines and commas.
+            _ => continue,
+        };
+        tokens.push(token);
+    }
+    Some(tokens)
+}
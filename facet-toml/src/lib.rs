@@ -23,21 +23,111 @@
 //! assert_eq!(config.name, "my-app");
 //! assert_eq!(config.port, 8080);
 //! ```
+//!
+//! # Serialization
+//!
+//! Nested structs become `[section]` tables and sequences of structs become
+//! arrays of tables, written after the plain values they sit next to. Small
+//! structs of plain values are written as inline tables, and date/time types
+//! as TOML datetimes.
+//!
+//! TOML datetimes map to the `chrono`, `time` and `jiff` types (enable the
+//! `chrono`, `time` or `jiff02` feature): offset date-times to
+//! `DateTime<Utc>`/`OffsetDateTime`/`Timestamp`, local date-times to
+//! `NaiveDateTime`/`PrimitiveDateTime`/`civil::DateTime`, and local dates and
+//! times to the matching date and time types.
+//!
+//! ```
+//! use facet::Facet;
+//!
+//! #[derive(Facet)]
+//! struct Server {
+//!     host: String,
+//!     port: u16,
+//! }
+//!
+//! #[derive(Facet)]
+//! struct Config {
+//!     name: String,
+//!     servers: Vec<Server>,
+//!     version: u32,
+//! }
+//!
+//! let config = Config {
+//!     name: "my-app".into(),
+//!     servers: vec![Server { host: "localhost".into(), port: 8080 }],
+//!     version: 2,
+//! };
+//!
+//! let toml = facet_toml::to_string_pretty(&config).unwrap();
+//! assert_eq!(
+//!     toml,
+//!     "name = \"my-app\"\nversion = 2\n\n[[servers]]\nhost = \"localhost\"\nport = 8080\n"
+//! );
+//! ```
+//!
+//! ## Per-field layout
+//!
+//! `#[facet(toml::inline)]` and `#[facet(toml::table)]` pin a field to an
+//! inline table or a `[section]` (or arrays of either), overriding the
+//! options and the size heuristic. Both forms read back the same way, so the
+//! attributes only change how a file is written.
+//!
+//! ```
+//! use facet::Facet;
+//! use facet_toml as toml;
+//!
+//! #[derive(Facet, Debug, PartialEq)]
+//! struct Point { x: i32, y: i32 }
+//!
+//! #[derive(Facet, Debug, PartialEq)]
+//! struct Shape {
+//!     #[facet(toml::table)]
+//!     origin: Point,
+//!     #[facet(toml::inline)]
+//!     points: Vec<Point>,
+//! }
+//!
+//! let shape = Shape {
+//!     origin: Point { x: 0, y: 0 },
+//!     points: vec![Point { x: 1, y: 2 }],
+//! };
+//! let text = facet_toml::to_string(&shape).unwrap();
+//! assert_eq!(text, "points = [{ x = 1, y = 2 }]\n[origin]\nx = 0\ny = 0\n");
+//! assert_eq!(facet_toml::from_str::<Shape>(&text).unwrap(), shape);
+//! ```
+//!
+//! # Editing documents
+//!
+//! [`TomlDocument`] merges a value into existing TOML text, rewriting only the
+//! keys that changed and keeping comments, whitespace and key order, for
+//! tools that update hand-written files such as `Cargo.toml`.
+//!
+//! # Source locations
+//!
+//! Deserializing into [`Spanned<T>`](Spanned) records where each value was
+//! found in the document, so errors found after parsing (a port out of range,
+//! a path that doesn't exist) can be reported at the right line and column.
 
 extern crate alloc;
 
+mod document;
 mod error;
 mod parser;
 mod serializer;
+mod spanned;
 
 #[cfg(feature = "axum")]
 mod axum;
 
+pub use document::TomlDocument;
 pub use error::{TomlError, TomlErrorKind};
 pub use parser::TomlParser;
 pub use serializer::{
-    SerializeOptions, TomlSerializeError, TomlSerializer, to_string, to_string_with_options, to_vec,
+    SerializeOptions, TomlSerializeError, TomlSerializer, to_string, to_string_pretty,
+    to_string_with_options, to_vec,
 };
+pub use spanned::{Location, Span, Spanned};
 
 // Re-export DeserializeError for convenience
 pub use facet_format::DeserializeError;
@@ -45,6 +135,33 @@ pub use facet_format::DeserializeError;
 #[cfg(feature = "axum")]
 pub use axum::{Toml, TomlRejection};
 
+// TOML extension attributes for use with #[facet(toml::attr)] syntax.
+//
+// After importing `use facet_toml as toml;`, users can write:
+//   #[facet(toml::inline)]
+//   #[facet(toml::table)]
+facet::define_attr_grammar! {
+    ns "toml";
+    crate_path ::facet_toml;
+
+    /// TOML attribute types for field configuration.
+    pub enum Attr {
+        /// Always write the field as an inline table or array
+        /// (`key = { ... }`, `key = [{ ... }]`), whatever the options say.
+        ///
+        /// Usage: `#[facet(toml::inline)]`
+        Inline,
+        /// Always write the field as a `[section]`, or an array of tables as
+        /// `[[section]]`, even when it is small or inline tables are enabled.
+        ///
+        /// Usage: `#[facet(toml::table)]`
+        ///
+        /// Has no effect inside a value that is itself written inline, or on
+        /// an array that doesn't hold tables.
+        Table,
+    }
+}
+
 /// Deserialize a value from a TOML string into an owned type.
 ///
 /// This is the recommended default for most use cases. The input does not need
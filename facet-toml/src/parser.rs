@@ -51,21 +51,47 @@ use toml_parser::{
     parser::{Event, EventKind, RecursionGuard, parse_document},
 };
 
+// ============================================================================
+// Datetimes
+// ============================================================================
+
+/// Spell a TOML datetime the way RFC 3339 parsers expect it: TOML also
+/// allows a space or a lowercase `t` between date and time, and a
+/// lowercase `z` for UTC.
+fn normalize_datetime(datetime: Cow<'_, str>) -> Cow<'_, str> {
+    let bytes = datetime.as_bytes();
+    let odd_separator = matches!(bytes.get(10), Some(b' ' | b't'));
+    let lowercase_utc = bytes.last() == Some(&b'z');
+    if !odd_separator && !lowercase_utc {
+        return datetime;
+    }
+
+    let mut normalized = datetime.into_owned();
+    if odd_separator {
+        normalized.replace_range(10..11, "T");
+    }
+    if lowercase_utc {
+        normalized.pop();
+        normalized.push('Z');
+    }
+    Cow::Owned(normalized)
+}
+
 // ============================================================================
 // Error collection for parsing
 // ============================================================================
 
 /// Collects parse errors from the TOML parser
-struct TomlParseErrorCollector {
+pub(crate) struct TomlParseErrorCollector {
     error: Option<(String, facet_reflect::Span)>,
 }
 
 impl TomlParseErrorCollector {
-    const fn new() -> Self {
+    pub(crate) const fn new() -> Self {
         Self { error: None }
     }
 
-    fn take_error(&mut self) -> Option<(String, facet_reflect::Span)> {
+    pub(crate) fn take_error(&mut self) -> Option<(String, facet_reflect::Span)> {
         self.error.take()
     }
 }
@@ -298,8 +324,9 @@ impl<'de> TomlParser<'de> {
                 Ok(ScalarValue::F64(f))
             }
             ScalarKind::DateTime => {
-                // Keep as string, let facet-reflect handle datetime types
-                Ok(ScalarValue::Str(output))
+                // Hand datetimes over as RFC 3339 text, which the chrono, time
+                // and jiff types parse from.
+                Ok(ScalarValue::Str(normalize_datetime(output)))
             }
         }
     }
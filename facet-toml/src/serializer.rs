@@ -4,12 +4,33 @@ use alloc::{string::String, vec::Vec};
 use core::fmt::Write;
 
 use facet_format::{FormatSerializer, ScalarValue, SerializeError};
+use facet_reflect::Peek;
+
+/// Widest nested struct that is written as an inline table (`key = { ... }`)
+/// rather than getting its own `[section]`.
+const INLINE_TABLE_WIDTH: usize = 60;
+
+/// In pretty mode, arrays of values whose line would be wider than this are
+/// written one element per line.
+const PRETTY_LINE_WIDTH: usize = 80;
 
 /// Options for TOML serialization.
 #[derive(Debug, Clone, Default)]
 pub struct SerializeOptions {
     /// Whether to use inline tables for nested structures (default: false)
+    ///
+    /// When false, nested structs get `[section]` headers and sequences of
+    /// structs become arrays of tables (`[[section]]`); only small structs
+    /// made of plain values are written inline.
     pub inline_tables: bool,
+    /// Whether to separate sections with blank lines and break long arrays
+    /// over several lines (default: false)
+    pub pretty: bool,
+    /// Whether to write each field's doc comment as `#` comments above its
+    /// key or section header (default: false)
+    ///
+    /// Fields inside inline tables are written without their comments.
+    pub doc_comments: bool,
 }
 
 impl SerializeOptions {
@@ -23,6 +44,39 @@ impl SerializeOptions {
         self.inline_tables = true;
         self
     }
+
+    /// Enable pretty printing.
+    pub const fn pretty(mut self) -> Self {
+        self.pretty = true;
+        self
+    }
+
+    /// Write fields' doc comments as `#` comments.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use facet::Facet;
+    /// use facet_toml::{SerializeOptions, to_string_with_options};
+    ///
+    /// #[derive(Facet)]
+    /// struct Config {
+    ///     /// Address to listen on.
+    ///     host: String,
+    ///     port: u16,
+    /// }
+    ///
+    /// let config = Config { host: "0.0.0.0".into(), port: 8080 };
+    /// let options = SerializeOptions::new().doc_comments();
+    /// assert_eq!(
+    ///     to_string_with_options(&config, &options).unwrap(),
+    ///     "# Address to listen on.\nhost = \"0.0.0.0\"\nport = 8080\n"
+    /// );
+    /// ```
+    pub const fn doc_comments(mut self) -> Self {
+        self.doc_comments = true;
+        self
+    }
 }
 
 #[derive(Debug)]
@@ -38,30 +92,75 @@ impl core::fmt::Display for TomlSerializeError {
 
 impl std::error::Error for TomlSerializeError {}
 
-#[derive(Debug, Clone)]
-enum Ctx {
-    /// Top-level table (root)
-    Root { first: bool },
-    /// Nested table (e.g., `[section]`)
-    /// Note: Currently unused. Will be used for pretty printing with table headers.
-    #[allow(dead_code)]
-    Table { first: bool, path: Vec<String> },
-    /// Inline table (e.g., `{ key = value }`)
-    InlineTable { first: bool },
-    /// Array (e.g., `[1, 2, 3]`)
-    Array { first: bool },
+/// A serialized value, kept until the whole document is known.
+///
+/// TOML requires every plain key/value of a table to come before its
+/// sub-tables, and whether a value becomes a `[section]`, an array of tables
+/// or an inline table depends on what it contains, so the document is built
+/// as a tree first and written out in [`TomlSerializer::finish`].
+#[derive(Debug)]
+pub(crate) enum Item {
+    /// A rendered scalar: a quoted string, number, boolean or datetime.
+    Value(String),
+    Array(Vec<Item>, Layout),
+    /// Entries in field order.
+    Table(Vec<Entry>, Layout),
+}
+
+/// A key of a table and its value.
+#[derive(Debug)]
+pub(crate) struct Entry {
+    pub(crate) key: String,
+    /// The doc comment of the field the entry was written from, if doc
+    /// comments are enabled.
+    pub(crate) doc: &'static [&'static str],
+    pub(crate) item: Item,
+}
+
+/// How a field asked for its table or array to be written, with
+/// `#[facet(toml::inline)]` or `#[facet(toml::table)]`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum Layout {
+    /// Decided by the options and the size of the value.
+    #[default]
+    Auto,
+    /// Always `key = { ... }` or `key = [...]`.
+    Inline,
+    /// Always a `[section]` or `[[section]]`, where TOML allows it.
+    Table,
+}
+
+impl Layout {
+    fn of(field: &facet_reflect::FieldItem) -> Self {
+        match &field.field {
+            Some(field) if field.has_attr(Some("toml"), "inline") => Layout::Inline,
+            Some(field) if field.has_attr(Some("toml"), "table") => Layout::Table,
+            _ => Layout::Auto,
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Frame {
+    Table {
+        entries: Vec<Entry>,
+        /// The key given by `field_key`, waiting for its value.
+        key: Option<String>,
+        /// The doc comment of the field being written.
+        doc: &'static [&'static str],
+        /// The layout of the field being written.
+        field_layout: Layout,
+        layout: Layout,
+    },
+    Array(Vec<Item>, Layout),
 }
 
 /// TOML serializer with configurable formatting options.
 pub struct TomlSerializer {
-    out: String,
-    stack: Vec<Ctx>,
-    /// Formatting options (currently unused, reserved for pretty printing)
-    #[allow(dead_code)]
+    stack: Vec<Frame>,
+    /// The top-level table, once it has been closed.
+    root: Option<Vec<Entry>>,
     options: SerializeOptions,
-    /// Current table path for dotted keys (reserved for pretty printing)
-    #[allow(dead_code)]
-    current_path: Vec<String>,
 }
 
 impl TomlSerializer {
@@ -73,45 +172,67 @@ impl TomlSerializer {
     /// Create a new TOML serializer with the given options.
     pub const fn with_options(options: SerializeOptions) -> Self {
         Self {
-            out: String::new(),
             stack: Vec::new(),
+            root: None,
             options,
-            current_path: Vec::new(),
         }
     }
 
     /// Consume the serializer and return the output string.
     pub fn finish(self) -> String {
-        self.out
-    }
-
-    /// Check if we're in an inline context (inline table or array)
-    /// Note: Reserved for pretty printing implementation.
-    #[allow(dead_code)]
-    fn is_inline_context(&self) -> bool {
-        matches!(
-            self.stack.last(),
-            Some(Ctx::InlineTable { .. }) | Some(Ctx::Array { .. })
-        )
-    }
-
-    /// Write a TOML string value with proper escaping
-    fn write_toml_string(&mut self, s: &str) {
-        self.out.push('"');
-        for c in s.chars() {
-            match c {
-                '"' => self.out.push_str(r#"\""#),
-                '\\' => self.out.push_str(r"\\"),
-                '\n' => self.out.push_str(r"\n"),
-                '\r' => self.out.push_str(r"\r"),
-                '\t' => self.out.push_str(r"\t"),
-                c if c.is_control() => {
-                    write!(self.out, "\\u{:04X}", c as u32).unwrap();
+        let mut writer = Writer::new(&self.options);
+        if let Some(root) = &self.root {
+            writer.write_table(&mut Vec::new(), root);
+        }
+        writer.out
+    }
+
+    /// Consume the serializer and return the top-level table unrendered.
+    pub(crate) fn into_table(self) -> Vec<Entry> {
+        self.root.unwrap_or_default()
+    }
+
+    /// Attach a finished value to whatever is being built.
+    fn push_item(&mut self, item: Item) -> Result<(), TomlSerializeError> {
+        match self.stack.last_mut() {
+            None => match item {
+                Item::Table(entries, _) => {
+                    self.root = Some(entries);
+                    Ok(())
+                }
+                _ => Err(TomlSerializeError {
+                    msg: "a TOML document must be a table at the top level".into(),
+                }),
+            },
+            Some(Frame::Table {
+                entries, key, doc, ..
+            }) => match key.take() {
+                Some(key) => {
+                    entries.push(Entry {
+                        key,
+                        doc: core::mem::take(doc),
+                        item,
+                    });
+                    Ok(())
                 }
-                c => self.out.push(c),
+                None => Err(TomlSerializeError {
+                    msg: "table value written without a key".into(),
+                }),
+            },
+            Some(Frame::Array(items, _)) => {
+                items.push(item);
+                Ok(())
             }
         }
-        self.out.push('"');
+    }
+
+    /// The layout of the value about to be written: the one its field asked
+    /// for, if it is a field.
+    fn next_layout(&mut self) -> Layout {
+        match self.stack.last_mut() {
+            Some(Frame::Table { field_layout, .. }) => core::mem::take(field_layout),
+            _ => Layout::Auto,
+        }
     }
 }
 
@@ -125,68 +246,21 @@ impl FormatSerializer for TomlSerializer {
     type Error = TomlSerializeError;
 
     fn begin_struct(&mut self) -> Result<(), Self::Error> {
-        match self.stack.last_mut() {
-            None => {
-                // Root level - just start tracking as root
-                self.stack.push(Ctx::Root { first: true });
-                Ok(())
-            }
-            Some(Ctx::InlineTable { .. }) | Some(Ctx::Array { .. }) => {
-                // We're in an inline context - use inline table syntax
-                self.out.push_str("{ ");
-                self.stack.push(Ctx::InlineTable { first: true });
-                Ok(())
-            }
-            Some(Ctx::Root { .. }) | Some(Ctx::Table { .. }) => {
-                // Nested table - will be handled via dotted keys or [table] headers
-                // For now, use inline table
-                self.out.push_str("{ ");
-                self.stack.push(Ctx::InlineTable { first: true });
-                Ok(())
-            }
-        }
+        let layout = self.next_layout();
+        self.stack.push(Frame::Table {
+            entries: Vec::new(),
+            key: None,
+            doc: &[],
+            field_layout: Layout::Auto,
+            layout,
+        });
+        Ok(())
     }
 
     fn field_key(&mut self, key: &str) -> Result<(), Self::Error> {
         match self.stack.last_mut() {
-            Some(Ctx::Root { first }) | Some(Ctx::Table { first, .. }) => {
-                // Top-level or table field
-                if !*first {
-                    self.out.push('\n');
-                }
-                *first = false;
-
-                // Write the key
-                if key
-                    .chars()
-                    .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
-                {
-                    // Simple key
-                    self.out.push_str(key);
-                } else {
-                    // Quoted key
-                    self.write_toml_string(key);
-                }
-                self.out.push_str(" = ");
-                Ok(())
-            }
-            Some(Ctx::InlineTable { first }) => {
-                // Inline table field
-                if !*first {
-                    self.out.push_str(", ");
-                }
-                *first = false;
-
-                // Write the key
-                if key
-                    .chars()
-                    .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
-                {
-                    self.out.push_str(key);
-                } else {
-                    self.write_toml_string(key);
-                }
-                self.out.push_str(" = ");
+            Some(Frame::Table { key: pending, .. }) => {
+                *pending = Some(key.into());
                 Ok(())
             }
             _ => Err(TomlSerializeError {
@@ -197,21 +271,9 @@ impl FormatSerializer for TomlSerializer {
 
     fn end_struct(&mut self) -> Result<(), Self::Error> {
         match self.stack.pop() {
-            Some(Ctx::Root { .. }) => {
-                // Root table ends - add final newline if there's content
-                if !self.out.is_empty() && !self.out.ends_with('\n') {
-                    self.out.push('\n');
-                }
-                Ok(())
-            }
-            Some(Ctx::InlineTable { .. }) => {
-                self.out.push_str(" }");
-                Ok(())
-            }
-            Some(Ctx::Table { .. }) => {
-                // Nested table ends
-                Ok(())
-            }
+            Some(Frame::Table {
+                entries, layout, ..
+            }) => self.push_item(Item::Table(entries, layout)),
             _ => Err(TomlSerializeError {
                 msg: "end_struct called without matching begin_struct".into(),
             }),
@@ -219,17 +281,14 @@ impl FormatSerializer for TomlSerializer {
     }
 
     fn begin_seq(&mut self) -> Result<(), Self::Error> {
-        self.out.push('[');
-        self.stack.push(Ctx::Array { first: true });
+        let layout = self.next_layout();
+        self.stack.push(Frame::Array(Vec::new(), layout));
         Ok(())
     }
 
     fn end_seq(&mut self) -> Result<(), Self::Error> {
         match self.stack.pop() {
-            Some(Ctx::Array { .. }) => {
-                self.out.push(']');
-                Ok(())
-            }
+            Some(Frame::Array(items, layout)) => self.push_item(Item::Array(items, layout)),
             _ => Err(TomlSerializeError {
                 msg: "end_seq called without matching begin_seq".into(),
             }),
@@ -237,14 +296,7 @@ impl FormatSerializer for TomlSerializer {
     }
 
     fn scalar(&mut self, scalar: ScalarValue<'_>) -> Result<(), Self::Error> {
-        // Handle comma separator for arrays
-        if let Some(Ctx::Array { first }) = self.stack.last_mut() {
-            if !*first {
-                self.out.push_str(", ");
-            }
-            *first = false;
-        }
-
+        let mut out = String::new();
         match scalar {
             ScalarValue::Null | ScalarValue::Unit => {
                 // TOML doesn't have null - this is an error
@@ -253,53 +305,37 @@ impl FormatSerializer for TomlSerializer {
                 });
             }
             ScalarValue::Bool(v) => {
-                self.out.push_str(if v { "true" } else { "false" });
+                out.push_str(if v { "true" } else { "false" });
             }
             ScalarValue::Char(c) => {
-                self.write_toml_string(&c.to_string());
-            }
-            ScalarValue::I64(v) => {
-                #[cfg(feature = "fast")]
-                self.out.push_str(itoa::Buffer::new().format(v));
-                #[cfg(not(feature = "fast"))]
-                write!(self.out, "{}", v).unwrap();
-            }
-            ScalarValue::U64(v) => {
-                #[cfg(feature = "fast")]
-                self.out.push_str(itoa::Buffer::new().format(v));
-                #[cfg(not(feature = "fast"))]
-                write!(self.out, "{}", v).unwrap();
-            }
-            ScalarValue::I128(v) => {
-                #[cfg(feature = "fast")]
-                self.out.push_str(itoa::Buffer::new().format(v));
-                #[cfg(not(feature = "fast"))]
-                write!(self.out, "{}", v).unwrap();
-            }
-            ScalarValue::U128(v) => {
-                #[cfg(feature = "fast")]
-                self.out.push_str(itoa::Buffer::new().format(v));
-                #[cfg(not(feature = "fast"))]
-                write!(self.out, "{}", v).unwrap();
+                write_toml_string(&mut out, c.encode_utf8(&mut [0; 4]));
             }
+            ScalarValue::I64(v) => write_integer(&mut out, v),
+            ScalarValue::U64(v) => write_integer(&mut out, to_i64(v)?),
+            ScalarValue::I128(v) => write_integer(&mut out, to_i64(v)?),
+            ScalarValue::U128(v) => write_integer(&mut out, to_i64(v)?),
             ScalarValue::F64(v) => {
                 if v.is_nan() {
-                    self.out.push_str("nan");
+                    out.push_str("nan");
                 } else if v.is_infinite() {
                     if v.is_sign_positive() {
-                        self.out.push_str("inf");
+                        out.push_str("inf");
                     } else {
-                        self.out.push_str("-inf");
+                        out.push_str("-inf");
                     }
                 } else {
                     #[cfg(feature = "fast")]
-                    self.out.push_str(zmij::Buffer::new().format(v));
+                    out.push_str(zmij::Buffer::new().format(v));
                     #[cfg(not(feature = "fast"))]
-                    write!(self.out, "{}", v).unwrap();
+                    write!(out, "{}", v).unwrap();
+                    // `1.0` formats as `1`, which TOML would read as an integer.
+                    if !out.contains(['.', 'e', 'E']) {
+                        out.push_str(".0");
+                    }
                 }
             }
             ScalarValue::Str(s) => {
-                self.write_toml_string(&s);
+                write_toml_string(&mut out, &s);
             }
             ScalarValue::Bytes(_) => {
                 return Err(TomlSerializeError {
@@ -312,8 +348,376 @@ impl FormatSerializer for TomlSerializer {
                 });
             }
         }
+        self.push_item(Item::Value(out))
+    }
+
+    fn field_metadata(&mut self, field: &facet_reflect::FieldItem) -> Result<(), Self::Error> {
+        if let Some(Frame::Table {
+            field_layout, doc, ..
+        }) = self.stack.last_mut()
+        {
+            *field_layout = Layout::of(field);
+            if self.options.doc_comments {
+                *doc = field.field.as_ref().map_or(&[], |field| field.doc);
+            }
+        }
         Ok(())
     }
+
+    fn should_omit_field(&self, _field: &facet_reflect::FieldItem, value: Peek<'_, '_>) -> bool {
+        // TOML has no null: an absent option is an absent key.
+        value
+            .innermost_peek()
+            .into_option()
+            .is_ok_and(|opt| opt.is_none())
+    }
+
+    fn serialize_opaque_scalar(
+        &mut self,
+        shape: &'static facet_core::Shape,
+        value: Peek<'_, '_>,
+    ) -> Result<bool, Self::Error> {
+        if !is_datetime(shape) {
+            return Ok(false);
+        }
+        // Write datetimes as TOML datetime literals rather than strings, as
+        // long as their display form is one.
+        let text = value.to_string();
+        if !is_toml_datetime(&text) {
+            return Ok(false);
+        }
+        self.push_item(Item::Value(text))?;
+        Ok(true)
+    }
+}
+
+/// Renders the finished [`Item`] tree.
+pub(crate) struct Writer<'a> {
+    pub(crate) out: String,
+    options: &'a SerializeOptions,
+}
+
+impl<'a> Writer<'a> {
+    pub(crate) const fn new(options: &'a SerializeOptions) -> Self {
+        Self {
+            out: String::new(),
+            options,
+        }
+    }
+
+    /// Write a table's body: its plain key/values first, then its sub-tables
+    /// and arrays of tables under headers extending `path`.
+    pub(crate) fn write_table(&mut self, path: &mut Vec<String>, entries: &[Entry]) {
+        for Entry { key, doc, item } in entries {
+            if self.is_inline(item) {
+                self.write_doc_comment(doc);
+                let start = self.out.len();
+                write_key(&mut self.out, key);
+                self.out.push_str(" = ");
+                let key_width = self.out.len() - start;
+                self.write_value(item, key_width);
+                self.out.push('\n');
+            }
+        }
+
+        for Entry { key, doc, item } in entries {
+            if !self.is_inline(item) {
+                self.write_section(path, key, doc, item);
+            }
+        }
+    }
+
+    /// Write `key`, a table or array of tables, under headers extending `path`,
+    /// with its doc comment above the first header.
+    pub(crate) fn write_section(
+        &mut self,
+        path: &mut Vec<String>,
+        key: &str,
+        doc: &[&str],
+        item: &Item,
+    ) {
+        let mut rendered = String::new();
+        write_key(&mut rendered, key);
+        path.push(rendered);
+        match item {
+            Item::Table(sub, _) => {
+                // A table holding nothing but sub-tables is implied by
+                // their headers, unless it has a comment to go above one.
+                if sub.is_empty()
+                    || !doc.is_empty()
+                    || sub.iter().any(|entry| self.is_inline(&entry.item))
+                {
+                    self.write_header_with_doc(path, false, doc);
+                }
+                self.write_table(path, sub);
+            }
+            Item::Array(tables, _) => {
+                for (i, table) in tables.iter().enumerate() {
+                    if let Item::Table(sub, _) = table {
+                        let doc = if i == 0 { doc } else { &[] };
+                        self.write_header_with_doc(path, true, doc);
+                        self.write_table(path, sub);
+                    }
+                }
+            }
+            Item::Value(_) => unreachable!("plain values are always inline"),
+        }
+        path.pop();
+    }
+
+    pub(crate) fn write_header(&mut self, path: &[String], array: bool) {
+        self.write_header_with_doc(path, array, &[]);
+    }
+
+    fn write_header_with_doc(&mut self, path: &[String], array: bool, doc: &[&str]) {
+        if self.options.pretty && !self.out.is_empty() {
+            self.out.push('\n');
+        }
+        self.write_doc_comment(doc);
+        let (open, close) = if array { ("[[", "]]") } else { ("[", "]") };
+        self.out.push_str(open);
+        self.out.push_str(&path.join("."));
+        self.out.push_str(close);
+        self.out.push('\n');
+    }
+
+    /// Write `doc` as comment lines.
+    fn write_doc_comment(&mut self, doc: &[&str]) {
+        for line in doc {
+            let text = line.strip_prefix(' ').unwrap_or(line).trim_end();
+            self.out.push('#');
+            if !text.is_empty() {
+                self.out.push(' ');
+                self.out.push_str(text);
+            }
+            self.out.push('\n');
+        }
+    }
+
+    /// Write the value of a `key = value` line.
+    fn write_value(&mut self, item: &Item, key_width: usize) {
+        if self.options.pretty
+            && let Item::Array(items, _) = item
+            && !items.is_empty()
+            && key_width + inline_width(item) > PRETTY_LINE_WIDTH
+        {
+            self.out.push_str("[\n");
+            for item in items {
+                self.out.push_str("    ");
+                write_inline(&mut self.out, item);
+                self.out.push_str(",\n");
+            }
+            self.out.push(']');
+        } else {
+            write_inline(&mut self.out, item);
+        }
+    }
+
+    /// Whether `item` is written as `key = value` rather than under a header.
+    ///
+    /// A field's `toml::inline` or `toml::table` layout wins over the
+    /// options, and a table holding a `toml::table` field can't be inline.
+    pub(crate) fn is_inline(&self, item: &Item) -> bool {
+        match item {
+            Item::Value(_) => true,
+            Item::Array(_, Layout::Inline) | Item::Table(_, Layout::Inline) => true,
+            Item::Array(items, Layout::Table) => !is_array_of_tables(items),
+            Item::Table(_, Layout::Table) => false,
+            _ if needs_section(item) => false,
+            Item::Array(items, Layout::Auto) => {
+                self.options.inline_tables || !is_array_of_tables(items)
+            }
+            Item::Table(entries, Layout::Auto) => {
+                self.options.inline_tables
+                    || entries.is_empty()
+                    || (!entries.iter().any(|entry| contains_table(&entry.item))
+                        && inline_width(item) <= INLINE_TABLE_WIDTH)
+            }
+        }
+    }
+}
+
+fn is_array_of_tables(items: &[Item]) -> bool {
+    !items.is_empty() && items.iter().all(|item| matches!(item, Item::Table(..)))
+}
+
+/// Whether `item` has to be written under a header because of a
+/// `toml::table` field in it.
+fn needs_section(item: &Item) -> bool {
+    match item {
+        Item::Value(_) | Item::Array(_, Layout::Inline) | Item::Table(_, Layout::Inline) => false,
+        Item::Array(items, Layout::Table) => is_array_of_tables(items),
+        Item::Table(_, Layout::Table) => true,
+        Item::Array(items, Layout::Auto) => {
+            is_array_of_tables(items) && items.iter().any(needs_section)
+        }
+        Item::Table(entries, Layout::Auto) => {
+            entries.iter().any(|entry| needs_section(&entry.item))
+        }
+    }
+}
+
+fn contains_table(item: &Item) -> bool {
+    match item {
+        Item::Value(_) => false,
+        Item::Array(items, _) => items.iter().any(contains_table),
+        Item::Table(..) => true,
+    }
+}
+
+fn inline_width(item: &Item) -> usize {
+    let mut out = String::new();
+    write_inline(&mut out, item);
+    out.len()
+}
+
+/// Write `item` on a single line: arrays as `[a, b]`, tables as `{ k = v }`.
+pub(crate) fn write_inline(out: &mut String, item: &Item) {
+    match item {
+        Item::Value(value) => out.push_str(value),
+        Item::Array(items, _) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                write_inline(out, item);
+            }
+            out.push(']');
+        }
+        Item::Table(entries, _) if entries.is_empty() => out.push_str("{}"),
+        Item::Table(entries, _) => {
+            out.push_str("{ ");
+            for (i, entry) in entries.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                write_key(out, &entry.key);
+                out.push_str(" = ");
+                write_inline(out, &entry.item);
+            }
+            out.push_str(" }");
+        }
+    }
+}
+
+/// Write a key, bare if TOML allows it and quoted otherwise.
+pub(crate) fn write_key(out: &mut String, key: &str) {
+    if !key.is_empty()
+        && key
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-')
+    {
+        out.push_str(key);
+    } else {
+        write_toml_string(out, key);
+    }
+}
+
+/// Write a TOML string value with proper escaping
+fn write_toml_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str(r#"\""#),
+            '\\' => out.push_str(r"\\"),
+            '\n' => out.push_str(r"\n"),
+            '\r' => out.push_str(r"\r"),
+            '\t' => out.push_str(r"\t"),
+            c if c.is_control() => {
+                write!(out, "\\u{:04X}", c as u32).unwrap();
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+fn write_integer(out: &mut String, v: i64) {
+    #[cfg(feature = "fast")]
+    out.push_str(itoa::Buffer::new().format(v));
+    #[cfg(not(feature = "fast"))]
+    write!(out, "{}", v).unwrap();
+}
+
+/// TOML integers are signed 64-bit.
+fn to_i64<T: TryInto<i64> + core::fmt::Display + Copy>(v: T) -> Result<i64, TomlSerializeError> {
+    v.try_into().map_err(|_| TomlSerializeError {
+        msg: alloc::format!("{v} does not fit in a TOML integer (signed 64-bit)"),
+    })
+}
+
+/// Whether `shape` is a date/time type from one of the supported crates.
+fn is_datetime(shape: &facet_core::Shape) -> bool {
+    matches!(
+        (shape.module_path, shape.type_identifier),
+        (
+            Some("chrono"),
+            "DateTime<Utc>"
+                | "DateTime<FixedOffset>"
+                | "DateTime<Local>"
+                | "NaiveDateTime"
+                | "NaiveDate"
+                | "NaiveTime"
+        ) | (
+            Some("time"),
+            "UtcDateTime" | "OffsetDateTime" | "PrimitiveDateTime" | "Date" | "Time"
+        ) | (Some("jiff"), "Timestamp" | "DateTime" | "Date" | "Time")
+    )
+}
+
+/// Whether `s` is a TOML offset date-time, local date-time, local date or
+/// local time, and so can be written without quotes.
+fn is_toml_datetime(s: &str) -> bool {
+    let s = s.as_bytes();
+    if !is_date(s) {
+        return matches!(strip_time(s), Some([]));
+    }
+    let Some(&separator) = s.get(10) else {
+        return true;
+    };
+    if !matches!(separator, b'T' | b't' | b' ') {
+        return false;
+    }
+    match strip_time(&s[11..]) {
+        Some([] | [b'Z' | b'z']) => true,
+        Some([b'+' | b'-', offset @ ..]) => is_hour_minute(offset),
+        _ => false,
+    }
+}
+
+/// `YYYY-MM-DD` at the start of `s`.
+fn is_date(s: &[u8]) -> bool {
+    s.len() >= 10
+        && is_digits(&s[..4])
+        && s[4] == b'-'
+        && is_digits(&s[5..7])
+        && s[7] == b'-'
+        && is_digits(&s[8..10])
+}
+
+/// Strip `HH:MM:SS` and an optional fraction from the start of `s`.
+fn strip_time(s: &[u8]) -> Option<&[u8]> {
+    if s.len() < 8 || !is_hour_minute(&s[..5]) || s[5] != b':' || !is_digits(&s[6..8]) {
+        return None;
+    }
+    let rest = &s[8..];
+    match rest.strip_prefix(b".") {
+        Some(fraction) => {
+            let digits = fraction.iter().take_while(|b| b.is_ascii_digit()).count();
+            (digits > 0).then(|| &fraction[digits..])
+        }
+        None => Some(rest),
+    }
+}
+
+/// Exactly `HH:MM`.
+fn is_hour_minute(s: &[u8]) -> bool {
+    s.len() == 5 && is_digits(&s[..2]) && s[2] == b':' && is_digits(&s[3..])
+}
+
+fn is_digits(s: &[u8]) -> bool {
+    s.iter().all(u8::is_ascii_digit)
 }
 
 /// Serialize a value to TOML bytes
@@ -321,19 +725,29 @@ pub fn to_vec<'facet, T>(value: &T) -> Result<Vec<u8>, SerializeError<TomlSerial
 where
     T: facet_core::Facet<'facet>,
 {
-    let mut ser = TomlSerializer::new();
-    facet_format::serialize_root(&mut ser, facet_reflect::Peek::new(value))?;
-    Ok(ser.finish().into_bytes())
+    to_string(value).map(String::into_bytes)
 }
 
 /// Serialize a value to a TOML string
+///
+/// Nested structs become `[section]` tables and sequences of structs become
+/// arrays of tables (`[[section]]`), except for small structs of plain values,
+/// which are written inline. `None` fields are left out, since TOML has no
+/// null.
 pub fn to_string<'facet, T>(value: &T) -> Result<String, SerializeError<TomlSerializeError>>
 where
     T: facet_core::Facet<'facet>,
 {
-    let mut ser = TomlSerializer::new();
-    facet_format::serialize_root(&mut ser, facet_reflect::Peek::new(value))?;
-    Ok(ser.finish())
+    to_string_with_options(value, &SerializeOptions::default())
+}
+
+/// Serialize a value to a TOML string, with blank lines between sections and
+/// long arrays broken over several lines.
+pub fn to_string_pretty<'facet, T>(value: &T) -> Result<String, SerializeError<TomlSerializeError>>
+where
+    T: facet_core::Facet<'facet>,
+{
+    to_string_with_options(value, &SerializeOptions::default().pretty())
 }
 
 /// Serialize a value to a TOML string with custom options.
@@ -345,6 +759,6 @@ where
     T: facet_core::Facet<'facet>,
 {
     let mut ser = TomlSerializer::with_options(options.clone());
-    facet_format::serialize_root(&mut ser, facet_reflect::Peek::new(value))?;
+    facet_format::serialize_root(&mut ser, Peek::new(value))?;
     Ok(ser.finish())
 }
@@ -0,0 +1,165 @@
+//! Values that remember where they came from in the source document.
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Deref, DerefMut, Range};
+
+use facet::Facet;
+pub use facet_reflect::Span;
+
+/// A value along with its location in the TOML source it was parsed from.
+///
+/// Deserializing into `Spanned<T>` reads a `T` as usual and records the
+/// byte range of its text in [`span`](Self::span), so that validation done
+/// after parsing can point at the offending value. Use
+/// [`start_location`](Self::start_location) and
+/// [`end_location`](Self::end_location) to turn that range into lines and
+/// columns for a diagnostic.
+///
+/// For strings, numbers, booleans and datetimes the span covers the value
+/// exactly, quotes included. For arrays and tables it only covers part of
+/// the value.
+///
+/// Serializing a `Spanned<T>` writes just the `T`, and comparisons and
+/// hashing ignore the span.
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+/// use facet_toml::{Location, Spanned};
+///
+/// #[derive(Facet)]
+/// struct Config {
+///     name: String,
+///     port: Spanned<u16>,
+/// }
+///
+/// let source = "name = \"app\"\nport = 80\n";
+/// let config: Config = facet_toml::from_str(source).unwrap();
+///
+/// assert_eq!(*config.port, 80);
+/// assert_eq!(&source[config.port.range().unwrap()], "80");
+/// assert_eq!(
+///     config.port.start_location(source),
+///     Some(Location { line: 2, column: 8 })
+/// );
+/// ```
+#[derive(Debug, Clone, Facet)]
+#[facet(metadata_container)]
+pub struct Spanned<T> {
+    /// The wrapped value.
+    pub value: T,
+    /// Where the value was found in the source, if it was deserialized.
+    #[facet(metadata = "span")]
+    pub span: Option<Span>,
+}
+
+impl<T> Spanned<T> {
+    /// Wraps a value that wasn't read from a document, so has no span.
+    pub const fn new(value: T) -> Self {
+        Self { value, span: None }
+    }
+
+    /// Unwraps the value, discarding the span.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// The byte range of the value in the source, if known.
+    pub fn range(&self) -> Option<Range<usize>> {
+        self.span.map(|span| span.offset as usize..span.end())
+    }
+
+    /// The line and column where the value starts in `source`.
+    ///
+    /// `source` must be the text the value was deserialized from.
+    pub fn start_location(&self, source: &str) -> Option<Location> {
+        self.span
+            .map(|span| Location::of(source, span.offset as usize))
+    }
+
+    /// The line and column just past the end of the value in `source`.
+    ///
+    /// `source` must be the text the value was deserialized from.
+    pub fn end_location(&self, source: &str) -> Option<Location> {
+        self.span.map(|span| Location::of(source, span.end()))
+    }
+}
+
+impl<T> Deref for Spanned<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Spanned<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T: PartialEq> PartialEq for Spanned<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Eq> Eq for Spanned<T> {}
+
+impl<T: PartialOrd> PartialOrd for Spanned<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T: Ord> Ord for Spanned<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<T: Hash> Hash for Spanned<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+/// A position in a source document, as a 1-based line and column.
+///
+/// Columns count characters, not bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Location {
+    /// Line number, starting at 1.
+    pub line: usize,
+    /// Column number, starting at 1.
+    pub column: usize,
+}
+
+impl Location {
+    /// The location of byte `offset` in `source`.
+    ///
+    /// Offsets past the end of `source` are clamped to it, and offsets inside
+    /// a multi-byte character count as that character.
+    pub fn of(source: &str, offset: usize) -> Self {
+        let mut offset = offset.min(source.len());
+        while !source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
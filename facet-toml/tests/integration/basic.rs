@@ -1170,7 +1170,7 @@ fn test_serialize_nested_struct() {
     };
 
     let toml = facet_toml::to_string(&config).unwrap();
-    // Nested structs become inline tables with current serializer
+    // Small nested structs of plain values become inline tables
     assert!(toml.contains("name = \"test\""));
     assert!(toml.contains("server = { host = \"localhost\", port = 8080 }"));
 }
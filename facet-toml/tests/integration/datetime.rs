@@ -0,0 +1,144 @@
+//! Tests for mapping TOML datetimes to chrono, time and jiff types.
+
+use facet::Facet;
+use indoc::indoc;
+
+const INPUT: &str = indoc! {r#"
+    offset = 1979-05-27 07:32:00z
+    fraction = 1979-05-27T00:32:00.999999-07:00
+    local = 1979-05-27t07:32:00.5
+    day = 1979-05-27
+    alarm = 00:32:00.25
+"#};
+
+#[test]
+fn chrono_types() {
+    use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+
+    #[derive(Debug, Facet, PartialEq)]
+    struct Times {
+        offset: DateTime<Utc>,
+        fraction: DateTime<FixedOffset>,
+        local: NaiveDateTime,
+        day: NaiveDate,
+        alarm: NaiveTime,
+    }
+
+    let times: Times = facet_toml::from_str(INPUT).unwrap();
+    let day = NaiveDate::from_ymd_opt(1979, 5, 27).unwrap();
+    assert_eq!(times.offset, day.and_hms_opt(7, 32, 0).unwrap().and_utc());
+    assert_eq!(
+        times.fraction.to_rfc3339(),
+        "1979-05-27T00:32:00.999999-07:00"
+    );
+    assert_eq!(times.local, day.and_hms_milli_opt(7, 32, 0, 500).unwrap());
+    assert_eq!(times.day, day);
+    assert_eq!(
+        times.alarm,
+        NaiveTime::from_hms_milli_opt(0, 32, 0, 250).unwrap()
+    );
+
+    let toml = facet_toml::to_string(&times).unwrap();
+    assert_eq!(
+        toml,
+        indoc! {r#"
+            offset = 1979-05-27T07:32:00Z
+            fraction = 1979-05-27T00:32:00.999999-07:00
+            local = 1979-05-27T07:32:00.500
+            day = 1979-05-27
+            alarm = 00:32:00.250
+        "#}
+    );
+    assert_eq!(facet_toml::from_str::<Times>(&toml).unwrap(), times);
+}
+
+#[test]
+fn time_types() {
+    use time::macros::{date, datetime, time};
+    use time::{Date, OffsetDateTime, PrimitiveDateTime, Time};
+
+    #[derive(Debug, Facet, PartialEq)]
+    struct Times {
+        offset: OffsetDateTime,
+        fraction: OffsetDateTime,
+        local: PrimitiveDateTime,
+        day: Date,
+        alarm: Time,
+    }
+
+    let times: Times = facet_toml::from_str(INPUT).unwrap();
+    assert_eq!(
+        times,
+        Times {
+            offset: datetime!(1979-05-27 07:32:00 UTC),
+            fraction: datetime!(1979-05-27 00:32:00.999999 -7),
+            local: datetime!(1979-05-27 07:32:00.5),
+            day: date!(1979 - 05 - 27),
+            alarm: time!(00:32:00.25),
+        }
+    );
+
+    let toml = facet_toml::to_string(&times).unwrap();
+    assert_eq!(
+        toml,
+        indoc! {r#"
+            offset = 1979-05-27T07:32:00Z
+            fraction = 1979-05-27T00:32:00.999999-07:00
+            local = 1979-05-27T07:32:00.5
+            day = 1979-05-27
+            alarm = 00:32:00.25
+        "#}
+    );
+    assert_eq!(facet_toml::from_str::<Times>(&toml).unwrap(), times);
+}
+
+#[test]
+fn jiff_types() {
+    use jiff::Timestamp;
+    use jiff::civil::{Date, DateTime, Time};
+
+    #[derive(Debug, Facet, PartialEq)]
+    struct Times {
+        offset: Timestamp,
+        fraction: Timestamp,
+        local: DateTime,
+        day: Date,
+        alarm: Time,
+    }
+
+    let times: Times = facet_toml::from_str(INPUT).unwrap();
+    assert_eq!(
+        times,
+        Times {
+            offset: "1979-05-27T07:32:00Z".parse().unwrap(),
+            fraction: "1979-05-27T07:32:00.999999Z".parse().unwrap(),
+            local: jiff::civil::date(1979, 5, 27).at(7, 32, 0, 500_000_000),
+            day: jiff::civil::date(1979, 5, 27),
+            alarm: jiff::civil::time(0, 32, 0, 250_000_000),
+        }
+    );
+
+    let toml = facet_toml::to_string(&times).unwrap();
+    assert_eq!(facet_toml::from_str::<Times>(&toml).unwrap(), times);
+}
+
+#[test]
+fn datetime_kind_must_match() {
+    #[derive(Debug, Facet)]
+    struct Offset {
+        at: chrono::DateTime<chrono::Utc>,
+    }
+
+    assert!(facet_toml::from_str::<Offset>("at = 1979-05-27T07:32:00").is_err());
+}
+
+#[test]
+fn datetimes_still_deserialize_into_strings() {
+    #[derive(Debug, Facet)]
+    struct Raw {
+        at: String,
+    }
+
+    let raw: Raw = facet_toml::from_str("at = 1979-05-27 07:32:00z").unwrap();
+    assert_eq!(raw.at, "1979-05-27T07:32:00Z");
+}
@@ -0,0 +1,110 @@
+//! Tests for writing doc comments as TOML comments.
+
+use facet::Facet;
+use facet_toml as toml;
+use facet_toml::SerializeOptions;
+use indoc::indoc;
+
+#[derive(Debug, Facet, PartialEq)]
+struct Package {
+    /// Crate name.
+    name: String,
+    version: String,
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct Bin {
+    /// Target name.
+    name: String,
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct Level {
+    pedantic: String,
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct Lints {
+    #[facet(toml::table)]
+    clippy: Level,
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct Manifest {
+    /// Crate edition.
+    edition: String,
+    /// The package being built.
+    #[facet(toml::table)]
+    package: Package,
+    /// Binaries.
+    ///
+    /// One per executable.
+    bin: Vec<Bin>,
+    /// Lint levels.
+    lints: Lints,
+}
+
+fn manifest() -> Manifest {
+    Manifest {
+        edition: "2021".into(),
+        package: Package {
+            name: "demo".into(),
+            version: "0.1.0".into(),
+        },
+        bin: vec![Bin { name: "a".into() }, Bin { name: "b".into() }],
+        lints: Lints {
+            clippy: Level {
+                pedantic: "warn".into(),
+            },
+        },
+    }
+}
+
+#[test]
+fn doc_comments_go_above_keys_and_headers() {
+    let options = SerializeOptions::new().doc_comments();
+    let out = toml::to_string_with_options(&manifest(), &options).unwrap();
+    assert_eq!(
+        out,
+        indoc! {r#"
+            # Crate edition.
+            edition = "2021"
+            # The package being built.
+            [package]
+            # Crate name.
+            name = "demo"
+            version = "0.1.0"
+            # Binaries.
+            #
+            # One per executable.
+            [[bin]]
+            # Target name.
+            name = "a"
+            [[bin]]
+            # Target name.
+            name = "b"
+            # Lint levels.
+            [lints]
+            [lints.clippy]
+            pedantic = "warn"
+        "#}
+    );
+    assert_eq!(toml::from_str::<Manifest>(&out).unwrap(), manifest());
+}
+
+#[test]
+fn comments_come_after_the_blank_line_in_pretty_mode() {
+    let options = SerializeOptions::new().doc_comments().pretty();
+    let out = toml::to_string_with_options(&manifest(), &options).unwrap();
+    assert!(
+        out.contains("version = \"0.1.0\"\n\n# Binaries.\n#\n# One per executable.\n[[bin]]\n"),
+        "{out}"
+    );
+}
+
+#[test]
+fn doc_comments_are_off_by_default() {
+    let out = toml::to_string(&manifest()).unwrap();
+    assert!(!out.contains('#'), "{out}");
+    assert!(!out.contains("[lints]\n"), "{out}");
+}
@@ -0,0 +1,239 @@
+//! Tests for merging values into existing documents with `TomlDocument`.
+
+use facet::Facet;
+use facet_toml::TomlDocument;
+use indoc::indoc;
+
+#[derive(Debug, Facet, PartialEq)]
+struct Package {
+    name: String,
+    version: String,
+    edition: Option<String>,
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct Serde {
+    version: String,
+    features: Vec<String>,
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct Dependencies {
+    serde: Serde,
+    anyhow: String,
+    log: Option<String>,
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct Release {
+    #[facet(rename = "opt-level")]
+    opt_level: u32,
+    lto: bool,
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct Profiles {
+    release: Release,
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct Manifest {
+    package: Package,
+    dependencies: Dependencies,
+    profile: Option<Profiles>,
+}
+
+#[test]
+fn merge_keeps_comments_and_order() {
+    let mut doc = TomlDocument::parse(indoc! {r#"
+        # Project manifest
+        [package]
+        name = "demo" # the crate name
+        version = "0.1.0"
+
+        # Runtime dependencies
+        [dependencies]
+        serde = { version = "1", features = ["derive"] }
+        anyhow = "1.0"   # errors
+    "#})
+    .unwrap();
+
+    let mut manifest: Manifest = doc.deserialize().unwrap();
+    manifest.package.version = "0.2.0".into();
+    manifest.package.edition = Some("2024".into());
+    manifest.dependencies.serde.version = "1.0.200".into();
+    manifest.dependencies.log = Some("0.4".into());
+    manifest.profile = Some(Profiles {
+        release: Release {
+            opt_level: 3,
+            lto: true,
+        },
+    });
+    doc.merge(&manifest).unwrap();
+
+    assert_eq!(
+        doc.as_str(),
+        indoc! {r#"
+            # Project manifest
+            [package]
+            name = "demo" # the crate name
+            version = "0.2.0"
+            edition = "2024"
+
+            # Runtime dependencies
+            [dependencies]
+            serde = { version = "1.0.200", features = ["derive"] }
+            anyhow = "1.0"   # errors
+            log = "0.4"
+
+            [profile]
+            release = { opt-level = 3, lto = true }
+        "#}
+    );
+    assert_eq!(doc.deserialize::<Manifest>().unwrap(), manifest);
+}
+
+#[test]
+fn unchanged_values_keep_their_spelling() {
+    #[derive(Debug, Facet, PartialEq)]
+    struct Config {
+        name: String,
+        size: u64,
+    }
+
+    let source = "name = 'demo' # literal\nsize = 1_000\nextra = true\n";
+    let mut doc: TomlDocument = source.parse().unwrap();
+    doc.merge(&Config {
+        name: "demo".into(),
+        size: 1000,
+    })
+    .unwrap();
+    assert_eq!(doc.to_string(), source);
+}
+
+#[test]
+fn dotted_keys_and_inline_tables_are_extended() {
+    #[derive(Debug, Facet, PartialEq)]
+    struct Point {
+        x: i64,
+        y: i64,
+        z: Option<i64>,
+    }
+
+    #[derive(Debug, Facet, PartialEq)]
+    struct Info {
+        name: String,
+        version: Option<String>,
+    }
+
+    #[derive(Debug, Facet, PartialEq)]
+    struct Config {
+        info: Info,
+        point: Point,
+    }
+
+    let mut doc = TomlDocument::parse("info.name = \"a\"\npoint = { x = 1, y = 2 }\n").unwrap();
+    doc.merge(&Config {
+        info: Info {
+            name: "b".into(),
+            version: Some("1".into()),
+        },
+        point: Point {
+            x: 1,
+            y: 5,
+            z: Some(3),
+        },
+    })
+    .unwrap();
+    assert_eq!(
+        doc.as_str(),
+        "info.name = \"b\"\ninfo.version = \"1\"\npoint = { x = 1, y = 5, z = 3 }\n"
+    );
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct Bin {
+    name: String,
+    path: String,
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct Bins {
+    bin: Vec<Bin>,
+}
+
+fn bin(name: &str, path: &str) -> Bin {
+    Bin {
+        name: name.into(),
+        path: path.into(),
+    }
+}
+
+#[test]
+fn arrays_of_tables_merge_by_position() {
+    let source = indoc! {r#"
+        [[bin]]
+        name = "a" # first
+        path = "src/a.rs"
+
+        [[bin]]
+        name = "b"
+        path = "src/b.rs"
+    "#};
+
+    let mut doc = TomlDocument::parse(source).unwrap();
+    doc.merge(&Bins {
+        bin: vec![
+            bin("a", "src/main.rs"),
+            bin("b", "src/b.rs"),
+            bin("c", "src/c.rs"),
+        ],
+    })
+    .unwrap();
+    assert_eq!(
+        doc.as_str(),
+        indoc! {r#"
+            [[bin]]
+            name = "a" # first
+            path = "src/main.rs"
+
+            [[bin]]
+            name = "b"
+            path = "src/b.rs"
+
+            [[bin]]
+            name = "c"
+            path = "src/c.rs"
+        "#}
+    );
+
+    let mut doc = TomlDocument::parse(source).unwrap();
+    doc.merge(&Bins {
+        bin: vec![bin("a", "src/a.rs")],
+    })
+    .unwrap();
+    assert_eq!(
+        doc.as_str(),
+        "[[bin]]\nname = \"a\" # first\npath = \"src/a.rs\"\n\n"
+    );
+}
+
+#[test]
+fn value_where_document_has_table_is_an_error() {
+    #[derive(Debug, Facet)]
+    struct Config {
+        server: String,
+    }
+
+    let source = "[server]\nport = 1\n";
+    let mut doc = TomlDocument::parse(source).unwrap();
+    let err = doc
+        .merge(&Config {
+            server: "localhost".into(),
+        })
+        .unwrap_err();
+    assert!(err.to_string().contains("`server`"), "{err}");
+    assert_eq!(doc.as_str(), source);
+
+    assert!(TomlDocument::parse("a = ").is_err());
+}
@@ -0,0 +1,185 @@
+//! Tests for per-field layout with `toml::inline` and `toml::table`.
+
+use facet::Facet;
+use facet_toml as toml;
+use facet_toml::SerializeOptions;
+use indoc::indoc;
+
+#[derive(Debug, Facet, PartialEq)]
+struct Target {
+    name: String,
+    path: String,
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct Profile {
+    opt_level: u32,
+    debug: bool,
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct Workspace {
+    members: Vec<String>,
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct Manifest {
+    name: String,
+    /// Small, so it would be inline without the attribute.
+    #[facet(toml::table)]
+    profile: Profile,
+    /// An array of tables, so it would be `[[bin]]` without the attribute.
+    #[facet(toml::inline)]
+    bin: Vec<Target>,
+    #[facet(toml::table)]
+    lib: Vec<Target>,
+    workspace: Workspace,
+}
+
+fn manifest() -> Manifest {
+    Manifest {
+        name: "demo".into(),
+        profile: Profile {
+            opt_level: 3,
+            debug: false,
+        },
+        bin: vec![Target {
+            name: "demo".into(),
+            path: "src/main.rs".into(),
+        }],
+        lib: vec![Target {
+            name: "demo_lib".into(),
+            path: "src/lib.rs".into(),
+        }],
+        workspace: Workspace {
+            members: vec!["a".into()],
+        },
+    }
+}
+
+#[test]
+fn field_layout_overrides_the_heuristic() {
+    let text = facet_toml::to_string(&manifest()).unwrap();
+    assert_eq!(
+        text,
+        indoc! {r#"
+            name = "demo"
+            bin = [{ name = "demo", path = "src/main.rs" }]
+            workspace = { members = ["a"] }
+            [profile]
+            opt_level = 3
+            debug = false
+            [[lib]]
+            name = "demo_lib"
+            path = "src/lib.rs"
+        "#}
+    );
+    assert_eq!(facet_toml::from_str::<Manifest>(&text).unwrap(), manifest());
+}
+
+#[test]
+fn field_layout_overrides_inline_tables_option() {
+    let options = SerializeOptions::new().inline_tables();
+    let text = facet_toml::to_string_with_options(&manifest(), &options).unwrap();
+    assert_eq!(
+        text,
+        indoc! {r#"
+            name = "demo"
+            bin = [{ name = "demo", path = "src/main.rs" }]
+            workspace = { members = ["a"] }
+            [profile]
+            opt_level = 3
+            debug = false
+            [[lib]]
+            name = "demo_lib"
+            path = "src/lib.rs"
+        "#}
+    );
+}
+
+#[test]
+fn table_field_keeps_its_parent_out_of_line() {
+    #[derive(Debug, Facet, PartialEq)]
+    struct Inner {
+        #[facet(toml::table)]
+        profile: Profile,
+    }
+
+    #[derive(Debug, Facet, PartialEq)]
+    struct Outer {
+        inner: Inner,
+    }
+
+    let outer = Outer {
+        inner: Inner {
+            profile: Profile {
+                opt_level: 1,
+                debug: true,
+            },
+        },
+    };
+    let options = SerializeOptions::new().inline_tables();
+    let text = facet_toml::to_string_with_options(&outer, &options).unwrap();
+    assert_eq!(
+        text,
+        indoc! {r#"
+            [inner.profile]
+            opt_level = 1
+            debug = true
+        "#}
+    );
+    assert_eq!(facet_toml::from_str::<Outer>(&text).unwrap(), outer);
+}
+
+#[test]
+fn empty_table_field_keeps_its_header() {
+    #[derive(Debug, Facet, PartialEq)]
+    struct Empty {}
+
+    #[derive(Debug, Facet, PartialEq)]
+    struct Config {
+        #[facet(toml::table)]
+        extra: Empty,
+    }
+
+    let text = facet_toml::to_string(&Config { extra: Empty {} }).unwrap();
+    assert_eq!(text, "[extra]\n");
+    assert_eq!(
+        facet_toml::from_str::<Config>(&text).unwrap(),
+        Config { extra: Empty {} }
+    );
+}
+
+#[test]
+fn either_form_reads_back() {
+    let standard = indoc! {r#"
+        name = "demo"
+        workspace = { members = ["a"] }
+        [profile]
+        opt_level = 3
+        debug = false
+        [[bin]]
+        name = "demo"
+        path = "src/main.rs"
+        [[lib]]
+        name = "demo_lib"
+        path = "src/lib.rs"
+    "#};
+    let inline = indoc! {r#"
+        name = "demo"
+        profile = { opt_level = 3, debug = false }
+        bin = [{ name = "demo", path = "src/main.rs" }]
+        lib = [{ name = "demo_lib", path = "src/lib.rs" }]
+        [workspace]
+        members = ["a"]
+    "#};
+
+    assert_eq!(
+        facet_toml::from_str::<Manifest>(standard).unwrap(),
+        manifest()
+    );
+    assert_eq!(
+        facet_toml::from_str::<Manifest>(inline).unwrap(),
+        manifest()
+    );
+}
@@ -0,0 +1,77 @@
+//! Tests for `#[facet(lenient_bool)]`.
+
+use facet::Facet;
+use facet_testhelpers::test;
+
+#[derive(Debug, Facet, PartialEq)]
+struct Features {
+    #[facet(lenient_bool)]
+    verbose: bool,
+    #[facet(lenient_bool)]
+    color: Option<bool>,
+    strict: bool,
+}
+
+#[test]
+fn test_lenient_bool_spellings() {
+    for (input, expected) in [
+        (r#""yes""#, true),
+        (r#""No""#, false),
+        (r#""ON""#, true),
+        (r#""off""#, false),
+        (r#""1""#, true),
+        (r#""0""#, false),
+        (r#""true""#, true),
+        ("1", true),
+        ("0", false),
+        ("true", true),
+        ("false", false),
+    ] {
+        let toml = format!("verbose = {input}\nstrict = true");
+        let features: Features = facet_toml::from_str(&toml).unwrap();
+        assert_eq!(features.verbose, expected, "input: {input}");
+    }
+}
+
+#[test]
+fn test_lenient_bool_option() {
+    let toml = r#"
+        verbose = "off"
+        color = "on"
+        strict = false
+    "#;
+    let features: Features = facet_toml::from_str(toml).unwrap();
+    assert_eq!(
+        features,
+        Features {
+            verbose: false,
+            color: Some(true),
+            strict: false,
+        }
+    );
+}
+
+#[test]
+fn test_lenient_bool_rejects_other_values() {
+    let toml = r#"
+        verbose = "maybe"
+        strict = true
+    "#;
+    let err = facet_toml::from_str::<Features>(toml).unwrap_err();
+    assert!(err.to_string().contains("maybe"), "{err}");
+
+    let toml = r#"
+        verbose = 2
+        strict = true
+    "#;
+    assert!(facet_toml::from_str::<Features>(toml).is_err());
+}
+
+#[test]
+fn test_bool_without_attribute_stays_strict() {
+    let toml = r#"
+        verbose = true
+        strict = "yes"
+    "#;
+    assert!(facet_toml::from_str::<Features>(toml).is_err());
+}
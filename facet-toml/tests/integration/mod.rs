@@ -1,8 +1,14 @@
 mod basic;
+mod datetime;
+mod doc_comments;
+mod document;
 mod flatten;
 mod issue_1661;
 mod issue_1995;
+mod layout;
+mod lenient_bool;
 mod nested_arrays;
+mod serialize;
 mod spanned;
 mod tables;
 mod unknown_fields;
@@ -0,0 +1,270 @@
+//! Tests for TOML serialization: tables, arrays of tables, inline tables and
+//! datetimes.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use facet::Facet;
+use facet_toml::SerializeOptions;
+use indoc::indoc;
+
+#[derive(Debug, Facet, PartialEq)]
+struct Package {
+    name: String,
+    version: String,
+    license: String,
+    description: Option<String>,
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct Dependency {
+    version: String,
+    features: Vec<String>,
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct Bin {
+    name: String,
+    path: String,
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct Manifest {
+    package: Package,
+    dependencies: BTreeMap<String, Dependency>,
+    bin: Vec<Bin>,
+    edition: String,
+}
+
+fn manifest() -> Manifest {
+    Manifest {
+        package: Package {
+            name: "demo".into(),
+            version: "0.1.0".into(),
+            license: "MIT OR Apache-2.0".into(),
+            description: None,
+        },
+        dependencies: [
+            (
+                "serde".to_string(),
+                Dependency {
+                    version: "1".into(),
+                    features: vec!["derive".into()],
+                },
+            ),
+            (
+                "facet".to_string(),
+                Dependency {
+                    version: "0.50".into(),
+                    features: vec![],
+                },
+            ),
+        ]
+        .into(),
+        bin: vec![
+            Bin {
+                name: "demo".into(),
+                path: "src/main.rs".into(),
+            },
+            Bin {
+                name: "demo-cli".into(),
+                path: "src/cli.rs".into(),
+            },
+        ],
+        edition: "2024".into(),
+    }
+}
+
+#[test]
+fn tables_follow_values() {
+    let toml = facet_toml::to_string(&manifest()).unwrap();
+    assert_eq!(
+        toml,
+        indoc! {r#"
+            edition = "2024"
+            [package]
+            name = "demo"
+            version = "0.1.0"
+            license = "MIT OR Apache-2.0"
+            [dependencies]
+            facet = { version = "0.50", features = [] }
+            serde = { version = "1", features = ["derive"] }
+            [[bin]]
+            name = "demo"
+            path = "src/main.rs"
+            [[bin]]
+            name = "demo-cli"
+            path = "src/cli.rs"
+        "#}
+    );
+
+    let parsed: Manifest = facet_toml::from_str(&toml).unwrap();
+    assert_eq!(parsed, manifest());
+}
+
+#[test]
+fn pretty_separates_sections() {
+    let toml = facet_toml::to_string_pretty(&manifest()).unwrap();
+    assert_eq!(
+        toml,
+        indoc! {r#"
+            edition = "2024"
+
+            [package]
+            name = "demo"
+            version = "0.1.0"
+            license = "MIT OR Apache-2.0"
+
+            [dependencies]
+            facet = { version = "0.50", features = [] }
+            serde = { version = "1", features = ["derive"] }
+
+            [[bin]]
+            name = "demo"
+            path = "src/main.rs"
+
+            [[bin]]
+            name = "demo-cli"
+            path = "src/cli.rs"
+        "#}
+    );
+}
+
+#[test]
+fn pretty_breaks_long_arrays() {
+    #[derive(Debug, Facet, PartialEq)]
+    struct Alphabet {
+        words: Vec<String>,
+    }
+
+    let alphabet = Alphabet {
+        words: [
+            "alpha", "bravo", "charlie", "delta", "echo", "foxtrot", "golf", "hotel", "india",
+            "juliett",
+        ]
+        .map(String::from)
+        .to_vec(),
+    };
+
+    let compact = facet_toml::to_string(&alphabet).unwrap();
+    assert_eq!(compact.lines().count(), 1);
+
+    let pretty = facet_toml::to_string_pretty(&alphabet).unwrap();
+    assert!(
+        pretty.starts_with("words = [\n    \"alpha\",\n"),
+        "{pretty}"
+    );
+    assert!(pretty.ends_with("    \"juliett\",\n]\n"), "{pretty}");
+    assert_eq!(facet_toml::from_str::<Alphabet>(&pretty).unwrap(), alphabet);
+}
+
+#[test]
+fn tables_without_values_get_no_header() {
+    #[derive(Debug, Facet, PartialEq)]
+    struct Inner {
+        value: u32,
+        label: String,
+    }
+
+    #[derive(Debug, Facet, PartialEq)]
+    struct Middle {
+        inner: Inner,
+    }
+
+    #[derive(Debug, Facet, PartialEq)]
+    struct Outer {
+        middle: Middle,
+    }
+
+    let outer = Outer {
+        middle: Middle {
+            inner: Inner {
+                value: 1,
+                label: "long enough to keep this table from being inlined".into(),
+            },
+        },
+    };
+
+    let toml = facet_toml::to_string(&outer).unwrap();
+    assert_eq!(
+        toml,
+        indoc! {r#"
+            [middle.inner]
+            value = 1
+            label = "long enough to keep this table from being inlined"
+        "#}
+    );
+    assert_eq!(facet_toml::from_str::<Outer>(&toml).unwrap(), outer);
+}
+
+#[test]
+fn inline_tables_option_keeps_everything_on_one_line() {
+    let options = SerializeOptions::new().inline_tables();
+    let toml = facet_toml::to_string_with_options(&manifest(), &options).unwrap();
+    assert_eq!(toml.lines().count(), 4, "{toml}");
+    assert!(
+        toml.contains(r#"bin = [{ name = "demo", path = "src/main.rs" }, { name = "demo-cli", path = "src/cli.rs" }]"#),
+        "{toml}"
+    );
+    assert_eq!(facet_toml::from_str::<Manifest>(&toml).unwrap(), manifest());
+}
+
+#[test]
+fn datetimes_are_native() {
+    #[derive(Debug, Facet, PartialEq)]
+    struct Event {
+        name: String,
+        at: DateTime<Utc>,
+        day: NaiveDate,
+        alarm: NaiveTime,
+        local: NaiveDateTime,
+    }
+
+    let day = NaiveDate::from_ymd_opt(2023, 1, 15).unwrap();
+    let local = day.and_hms_opt(10, 30, 0).unwrap();
+    let event = Event {
+        name: "launch".into(),
+        at: local.and_utc(),
+        day,
+        alarm: NaiveTime::from_hms_opt(7, 45, 0).unwrap(),
+        local,
+    };
+
+    let toml = facet_toml::to_string(&event).unwrap();
+    assert_eq!(
+        toml,
+        indoc! {r#"
+            name = "launch"
+            at = 2023-01-15T10:30:00Z
+            day = 2023-01-15
+            alarm = 07:45:00
+            local = 2023-01-15T10:30:00
+        "#}
+    );
+    assert_eq!(facet_toml::from_str::<Event>(&toml).unwrap(), event);
+}
+
+#[test]
+fn keys_floats_and_integer_range() {
+    #[derive(Debug, Facet, PartialEq)]
+    struct Misc {
+        ratio: f64,
+        labels: BTreeMap<String, u32>,
+    }
+
+    let misc = Misc {
+        ratio: 1.0,
+        labels: [("a.b".to_string(), 1), ("plain".to_string(), 2)].into(),
+    };
+    let toml = facet_toml::to_string(&misc).unwrap();
+    assert_eq!(toml, "ratio = 1.0\nlabels = { \"a.b\" = 1, plain = 2 }\n");
+    assert_eq!(facet_toml::from_str::<Misc>(&toml).unwrap(), misc);
+
+    #[derive(Debug, Facet)]
+    struct Big {
+        id: u64,
+    }
+
+    let err = facet_toml::to_string(&Big { id: u64::MAX }).unwrap_err();
+    assert!(err.to_string().contains("signed 64-bit"), "{err}");
+}
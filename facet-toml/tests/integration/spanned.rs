@@ -4,11 +4,12 @@
 //! With the metadata_container support, spans are now correctly populated
 //! from the parser.
 //!
-//! Users define their own `Spanned<T>` type using `#[facet(metadata_container)]`.
+//! `facet_toml::Spanned<T>` is provided ready to use; users can also define
+//! their own `Spanned<T>` type using `#[facet(metadata_container)]`.
 
 use facet::Facet;
 use facet_reflect::Span;
-use facet_toml::{self as toml, DeserializeError};
+use facet_toml::{self as toml, DeserializeError, Location};
 use std::ops::Deref;
 
 // ============================================================================
@@ -407,3 +408,72 @@ readme = { workspace = true }
         _ => panic!("Expected Workspace variant"),
     }
 }
+
+// ============================================================================
+// facet_toml::Spanned
+// ============================================================================
+
+#[test]
+fn builtin_spanned_reports_lines_and_columns() {
+    #[derive(Facet, Debug)]
+    struct Server {
+        host: toml::Spanned<String>,
+        port: toml::Spanned<u16>,
+    }
+
+    #[derive(Facet, Debug)]
+    struct Config {
+        server: Server,
+    }
+
+    let input = "# settings\n[server]\nhost = \"hé\"\nport = 8080\n";
+    let config: Config = toml::from_str(input).unwrap();
+
+    assert_eq!(*config.server.host, "hé");
+    assert_eq!(&input[config.server.host.range().unwrap()], "\"hé\"");
+    assert_eq!(
+        config.server.host.start_location(input),
+        Some(Location { line: 3, column: 8 })
+    );
+    assert_eq!(
+        config.server.host.end_location(input),
+        Some(Location {
+            line: 3,
+            column: 12
+        })
+    );
+
+    assert_eq!(*config.server.port, 8080);
+    let start = config.server.port.start_location(input).unwrap();
+    assert_eq!(start.to_string(), "4:8");
+}
+
+#[test]
+fn builtin_spanned_compares_and_serializes_values_only() {
+    #[derive(Facet, Debug, PartialEq)]
+    struct Config {
+        name: toml::Spanned<String>,
+    }
+
+    let parsed: Config = toml::from_str("name = \"app\"").unwrap();
+    let built = Config {
+        name: toml::Spanned::new("app".to_string()),
+    };
+    assert!(parsed.name.span.is_some());
+    assert!(built.name.span.is_none());
+    assert_eq!(parsed, built);
+
+    assert_eq!(toml::to_string(&built).unwrap(), "name = \"app\"\n");
+    assert_eq!(built.name.into_inner(), "app");
+}
+
+#[test]
+fn location_of_offsets() {
+    let source = "a\nbéc\n";
+    assert_eq!(Location::of(source, 0), Location { line: 1, column: 1 });
+    assert_eq!(Location::of(source, 2), Location { line: 2, column: 1 });
+    // Inside the two-byte `é`.
+    assert_eq!(Location::of(source, 4), Location { line: 2, column: 2 });
+    assert_eq!(Location::of(source, 5), Location { line: 2, column: 3 });
+    assert_eq!(Location::of(source, 100), Location { line: 3, column: 1 });
+}
@@ -216,3 +216,53 @@ fn i32_coercion_works() {
     let config: LintConfig = facet_toml::from_str("priority = -1").unwrap();
     assert_eq!(config.priority, Some(-1));
 }
+
+#[test]
+fn out_of_range_integer_tries_the_next_variant() {
+    #[derive(Facet, Debug, PartialEq)]
+    #[repr(u8)]
+    #[facet(untagged)]
+    enum Width {
+        Small(u8),
+        Large(u32),
+        Negative(i64),
+    }
+
+    #[derive(Facet, Debug)]
+    struct Config {
+        width: Width,
+    }
+
+    for (toml_val, expected) in [
+        ("200", Width::Small(200)),
+        ("300", Width::Large(300)),
+        ("-1", Width::Negative(-1)),
+    ] {
+        let config: Config = facet_toml::from_str(&format!("width = {toml_val}")).unwrap();
+        assert_eq!(config.width, expected, "Failed for value {toml_val}");
+    }
+}
+
+#[test]
+fn shared_numeric_coercions() {
+    #[derive(Facet, Debug, PartialEq)]
+    struct Numbers {
+        ratio: f32,
+        count: u16,
+    }
+
+    // Integers widen into floats, and integral floats narrow into integers.
+    let numbers: Numbers = facet_toml::from_str("ratio = 2\ncount = 7.0").unwrap();
+    assert_eq!(
+        numbers,
+        Numbers {
+            ratio: 2.0,
+            count: 7
+        }
+    );
+
+    let err = facet_toml::from_str::<Numbers>("ratio = 2\ncount = 7.5").unwrap_err();
+    assert!(err.to_string().contains("out of range"), "{err}");
+    let err = facet_toml::from_str::<Numbers>("ratio = 2\ncount = 70000").unwrap_err();
+    assert!(err.to_string().contains("out of range"), "{err}");
+}
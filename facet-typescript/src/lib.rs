@@ -22,12 +22,12 @@
 
 extern crate alloc;
 
-use alloc::collections::BTreeSet;
+use alloc::collections::{BTreeMap, BTreeSet};
 use alloc::string::String;
 use alloc::vec::Vec;
 use core::fmt::Write;
 
-use facet_core::{Def, Facet, Field, Shape, StructKind, Type, UserType};
+use facet_core::{Def, Facet, Field, FieldsInOrder, ScalarKind, Shape, StructKind, Type, UserType};
 
 /// Generate TypeScript definitions for a single type.
 ///
@@ -49,6 +49,44 @@ pub struct TypeScriptGenerator {
     queue: Vec<&'static Shape>,
     /// Indentation level
     indent: usize,
+    /// Whether to generate separate `...Input` types (see [`Self::with_input_types`])
+    input_types: bool,
+    /// Which side of the wire the type being generated describes
+    mode: Mode,
+    /// Declarations generated so far, in generation order
+    declarations: Vec<Declaration>,
+    /// Named types the declaration being generated refers to eagerly
+    eager_refs: BTreeSet<String>,
+    /// How many positions TypeScript resolves lazily (array and tuple
+    /// elements, object members) enclose the type being written
+    deferred: usize,
+    /// When regenerating a declaration caught in an alias cycle, the names in
+    /// that cycle (see [`Self::break_alias_cycles`])
+    cycle: BTreeSet<String>,
+    /// Whether eager references to names in `cycle` are written as `unknown`
+    drop_cycle_refs: bool,
+}
+
+/// A generated declaration, kept until [`TypeScriptGenerator::finish`] has
+/// checked the references between declarations for cycles.
+struct Declaration {
+    shape: &'static Shape,
+    mode: Mode,
+    name: String,
+    code: String,
+    /// Named types referred to outside any array, tuple, object literal or
+    /// interface body. TypeScript resolves those as soon as the alias is
+    /// declared, so a cycle of them doesn't compile.
+    eager_refs: BTreeSet<String>,
+}
+
+/// Whether a generated type describes values the server returns or accepts.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    /// What serialization produces.
+    Output,
+    /// What deserialization accepts.
+    Input,
 }
 
 impl Default for TypeScriptGenerator {
@@ -65,9 +103,52 @@ impl TypeScriptGenerator {
             generated: BTreeSet::new(),
             queue: Vec::new(),
             indent: 0,
+            input_types: false,
+            mode: Mode::Output,
+            declarations: Vec::new(),
+            eager_refs: BTreeSet::new(),
+            deferred: 0,
+            cycle: BTreeSet::new(),
+            drop_cycle_refs: false,
         }
     }
 
+    /// Also generate an input type for every type whose accepted shape differs
+    /// from its serialized shape, mirroring GraphQL's type/input split.
+    ///
+    /// A struct with `#[facet(skip_serializing)]` or `#[facet(skip_deserializing)]`
+    /// fields, or with defaulted fields, gets a `User` interface describing what
+    /// serialization produces and a `UserInput` interface describing what
+    /// deserialization accepts. In this mode, defaulted fields are required in
+    /// the output type (they are always written) and optional in the input
+    /// type, while fields with `skip_serializing_if` are optional in the output
+    /// type. Types that contain such a type get an input variant too, so
+    /// `UserInput` refers to `AddressInput` rather than `Address`.
+    ///
+    /// ```
+    /// use facet::Facet;
+    /// use facet_typescript::TypeScriptGenerator;
+    ///
+    /// #[derive(Facet)]
+    /// struct User {
+    ///     #[facet(skip_deserializing)]
+    ///     id: u64,
+    ///     name: String,
+    ///     #[facet(skip_serializing)]
+    ///     password: String,
+    /// }
+    ///
+    /// let mut generator = TypeScriptGenerator::new().with_input_types();
+    /// generator.add_type::<User>();
+    /// let ts = generator.finish();
+    /// assert!(ts.contains("export interface User {\n  id: number;\n  name: string;\n}"));
+    /// assert!(ts.contains("export interface UserInput {\n  name: string;\n  password: string;\n}"));
+    /// ```
+    pub const fn with_input_types(mut self) -> Self {
+        self.input_types = true;
+        self
+    }
+
     /// Add a type to generate.
     pub fn add_type<T: Facet<'static>>(&mut self) {
         self.add_shape(T::SHAPE);
@@ -81,6 +162,11 @@ impl TypeScriptGenerator {
     }
 
     /// Finish generation and return the TypeScript code.
+    ///
+    /// Every type is declared exactly once, however its references loop back.
+    /// TypeScript doesn't care about declaration order, but it does reject
+    /// type aliases that resolve to themselves, so those cycles are broken
+    /// before the code is returned (see [`Self::break_alias_cycles`]).
     pub fn finish(mut self) -> String {
         // Process queue until empty
         while let Some(shape) = self.queue.pop() {
@@ -88,9 +174,190 @@ impl TypeScriptGenerator {
                 continue;
             }
             self.generated.insert(shape.type_identifier);
-            self.generate_shape(shape);
+            self.declare(shape, Mode::Output);
+            if self.input_types && Self::needs_input_type(shape) {
+                self.declare(shape, Mode::Input);
+            }
+        }
+        self.break_alias_cycles();
+        self.declarations.into_iter().map(|d| d.code).collect()
+    }
+
+    fn declare(&mut self, shape: &'static Shape, mode: Mode) {
+        let (code, eager_refs) = self.render(shape, mode);
+        self.mode = mode;
+        let name = self.type_name(shape);
+        self.mode = Mode::Output;
+        self.declarations.push(Declaration {
+            shape,
+            mode,
+            name,
+            code,
+            eager_refs,
+        });
+    }
+
+    /// Generate the declaration of `shape`, returning its code and eager
+    /// references.
+    fn render(&mut self, shape: &'static Shape, mode: Mode) -> (String, BTreeSet<String>) {
+        self.mode = mode;
+        self.generate_shape(shape);
+        self.mode = Mode::Output;
+        (
+            core::mem::take(&mut self.output),
+            core::mem::take(&mut self.eager_refs),
+        )
+    }
+
+    /// Rewrite declarations that TypeScript would reject as circular.
+    ///
+    /// Rust types can refer to each other through anything, but a TypeScript
+    /// alias is resolved on the spot unless the reference sits in an array,
+    /// tuple, object literal or interface. `type Forest = Record<string, Tree>`
+    /// with `type Tree = number | Forest` fails to compile, as does
+    /// `type A = B` with `type B = A | null`.
+    ///
+    /// Cycles are the strongly connected components of the eager reference
+    /// graph. Their members are first regenerated with maps written as index
+    /// signatures (`{ [key: string]: Tree }`), which TypeScript resolves
+    /// lazily. Whatever cycles remain are made only of bare aliases, which
+    /// have no TypeScript equivalent: references back into the cycle become
+    /// `unknown`.
+    fn break_alias_cycles(&mut self) {
+        for drop_refs in [false, true] {
+            let index: BTreeMap<&str, usize> = self
+                .declarations
+                .iter()
+                .enumerate()
+                .map(|(i, declaration)| (declaration.name.as_str(), i))
+                .collect();
+            let edges: Vec<Vec<usize>> = self
+                .declarations
+                .iter()
+                .map(|declaration| {
+                    declaration
+                        .eager_refs
+                        .iter()
+                        .filter_map(|name| index.get(name.as_str()).copied())
+                        .collect()
+                })
+                .collect();
+
+            let cycles: Vec<Vec<usize>> = strongly_connected_components(&edges)
+                .into_iter()
+                .filter(|component| {
+                    component.len() > 1 || edges[component[0]].contains(&component[0])
+                })
+                .collect();
+            if cycles.is_empty() {
+                return;
+            }
+
+            self.drop_cycle_refs = drop_refs;
+            for component in cycles {
+                self.cycle = component
+                    .iter()
+                    .map(|&i| self.declarations[i].name.clone())
+                    .collect();
+                for i in component {
+                    let (shape, mode) = (self.declarations[i].shape, self.declarations[i].mode);
+                    let (code, eager_refs) = self.render(shape, mode);
+                    self.declarations[i].code = code;
+                    self.declarations[i].eager_refs = eager_refs;
+                }
+            }
+            self.cycle.clear();
+            self.drop_cycle_refs = false;
+        }
+    }
+
+    /// The TypeScript name of a named type, with an `Input` suffix when
+    /// generating an input type and `shape` has one.
+    fn type_name(&self, shape: &'static Shape) -> String {
+        if self.mode == Mode::Input && Self::needs_input_type(shape) {
+            format!("{}Input", shape.type_identifier)
+        } else {
+            shape.type_identifier.to_string()
+        }
+    }
+
+    /// Whether `field` is left out of the type being generated.
+    fn skips_field(&self, field: &Field) -> bool {
+        match self.mode {
+            Mode::Output => field.should_skip_serializing_unconditional(),
+            Mode::Input => field.should_skip_deserializing(),
+        }
+    }
+
+    /// Whether a non-`Option` field gets a `?` marker.
+    fn is_optional(&self, field: &Field, force_optional: bool) -> bool {
+        if force_optional {
+            return true;
+        }
+        if self.input_types && self.mode == Mode::Output {
+            // Defaults only matter when reading; what may be left out when
+            // writing is what `skip_serializing_if` leaves out.
+            return field.skip_serializing_if.is_some();
+        }
+        field.has_default()
+    }
+
+    /// Whether the input type for `shape` differs from its output type.
+    fn needs_input_type(shape: &'static Shape) -> bool {
+        let mut visiting: Vec<&'static str> = Vec::new();
+        Self::needs_input_type_guarded(shape, &mut visiting)
+    }
+
+    fn needs_input_type_guarded(shape: &'static Shape, visiting: &mut Vec<&'static str>) -> bool {
+        match &shape.def {
+            Def::Option(opt) => return Self::needs_input_type_guarded(opt.t, visiting),
+            Def::List(list) => return Self::needs_input_type_guarded(list.t, visiting),
+            Def::Array(arr) => return Self::needs_input_type_guarded(arr.t, visiting),
+            Def::Set(set) => return Self::needs_input_type_guarded(set.t, visiting),
+            Def::Map(map) => return Self::needs_input_type_guarded(map.v, visiting),
+            Def::Pointer(ptr) => {
+                return ptr
+                    .pointee
+                    .is_some_and(|pointee| Self::needs_input_type_guarded(pointee, visiting));
+            }
+            _ => {}
+        }
+        if let Some(inner) = shape.inner {
+            return Self::needs_input_type_guarded(inner, visiting);
+        }
+        if let Some(proxy_def) = shape.proxy {
+            return Self::needs_input_type_guarded(proxy_def.shape, visiting);
+        }
+
+        let key = Self::shape_key(shape);
+        if visiting.contains(&key) {
+            return false;
         }
-        self.output
+        visiting.push(key);
+        let needs_input = match &shape.ty {
+            Type::User(UserType::Struct(st)) => Self::fields_need_input_type(st.fields, visiting),
+            Type::User(UserType::Enum(en)) => en
+                .variants
+                .iter()
+                .any(|variant| Self::fields_need_input_type(variant.data.fields, visiting)),
+            _ => false,
+        };
+        visiting.pop();
+        needs_input
+    }
+
+    fn fields_need_input_type(fields: &'static [Field], visiting: &mut Vec<&'static str>) -> bool {
+        fields.iter().any(|field| {
+            let skip_output = field.should_skip_serializing_unconditional();
+            let skip_input = field.should_skip_deserializing();
+            if skip_output || skip_input {
+                return skip_output != skip_input;
+            }
+            let field_shape = field.shape.get();
+            let optional_differs = !matches!(field_shape.def, Def::Option(_))
+                && field.skip_serializing_if.is_some() != field.has_default();
+            optional_differs || Self::needs_input_type_guarded(field_shape, visiting)
+        })
     }
 
     fn write_indent(&mut self) {
@@ -108,27 +375,19 @@ impl TypeScriptGenerator {
     ///
     /// Returns the unwrapped shape along with a flag indicating whether an `Option` was encountered.
     fn unwrap_to_inner_shape(shape: &'static Shape) -> (&'static Shape, bool) {
-        // Unwrap Option<T> first so we can mark fields as optional.
-        if let Def::Option(opt) = &shape.def {
-            let (inner, _) = Self::unwrap_to_inner_shape(opt.t);
-            return (inner, true);
-        }
-        // Unwrap pointers (Arc, Box, etc.)
-        if let Def::Pointer(ptr) = &shape.def
-            && let Some(pointee) = ptr.pointee
-        {
-            return Self::unwrap_to_inner_shape(pointee);
-        }
-        // Unwrap transparent wrappers
+        // Option<T> and pointers (Arc, Box, etc.)
+        let (shape, is_optional) = shape.flatten_target();
+        // Transparent wrappers (#[facet(transparent)]).
         if let Some(inner) = shape.inner {
-            let (inner_shape, is_optional) = Self::unwrap_to_inner_shape(inner);
-            return (inner_shape, is_optional);
+            let (inner_shape, inner_optional) = Self::unwrap_to_inner_shape(inner);
+            return (inner_shape, is_optional || inner_optional);
         }
-        // Handle proxy types - use the proxy's shape for serialization
+        // Proxy types — follow the proxy chain.
         if let Some(proxy_def) = shape.proxy {
-            return Self::unwrap_to_inner_shape(proxy_def.shape);
+            let (proxy_shape, proxy_optional) = Self::unwrap_to_inner_shape(proxy_def.shape);
+            return (proxy_shape, is_optional || proxy_optional);
         }
-        (shape, false)
+        (shape, is_optional)
     }
 
     /// Format a field for inline object types (e.g., in enum variants).
@@ -136,12 +395,11 @@ impl TypeScriptGenerator {
     fn format_inline_field(&mut self, field: &Field, force_optional: bool) -> String {
         let field_name = field.effective_name();
         let field_shape = field.shape.get();
-        let has_default = field.default.is_some();
 
         if let Def::Option(opt) = &field_shape.def {
             let inner_type = self.type_for_shape(opt.t);
             format!("{}?: {}", field_name, inner_type)
-        } else if force_optional || has_default {
+        } else if self.is_optional(field, force_optional) {
             let field_type = self.type_for_shape(field_shape);
             format!("{}?: {}", field_name, field_type)
         } else {
@@ -156,8 +414,12 @@ impl TypeScriptGenerator {
         fields: &'static [Field],
         force_optional: bool,
     ) -> Vec<String> {
+        // Inline fields always end up inside an object literal
         let mut flatten_stack: Vec<&'static str> = Vec::new();
-        self.collect_inline_fields_guarded(fields, force_optional, &mut flatten_stack)
+        self.deferred += 1;
+        let result = self.collect_inline_fields_guarded(fields, force_optional, &mut flatten_stack);
+        self.deferred -= 1;
+        result
     }
 
     fn collect_inline_fields_guarded(
@@ -168,7 +430,7 @@ impl TypeScriptGenerator {
     ) -> Vec<String> {
         let mut result = Vec::new();
         for field in fields {
-            if field.should_skip_serializing_unconditional() {
+            if self.skips_field(field) {
                 continue;
             }
             if field.is_flattened() {
@@ -197,20 +459,22 @@ impl TypeScriptGenerator {
     /// Check if a struct has any fields that will be serialized.
     /// This accounts for skipped fields and flattened structs.
     fn has_serializable_fields(
+        &self,
         field_owner_shape: &'static Shape,
         fields: &'static [Field],
     ) -> bool {
         let mut flatten_stack: Vec<&'static str> = Vec::new();
         flatten_stack.push(Self::shape_key(field_owner_shape));
-        Self::has_serializable_fields_guarded(fields, &mut flatten_stack)
+        self.has_serializable_fields_guarded(fields, &mut flatten_stack)
     }
 
     fn has_serializable_fields_guarded(
+        &self,
         fields: &'static [Field],
         flatten_stack: &mut Vec<&'static str>,
     ) -> bool {
         for field in fields {
-            if field.should_skip_serializing_unconditional() {
+            if self.skips_field(field) {
                 continue;
             }
             if field.is_flattened() {
@@ -221,8 +485,7 @@ impl TypeScriptGenerator {
                         continue;
                     }
                     flatten_stack.push(inner_key);
-                    let has_fields =
-                        Self::has_serializable_fields_guarded(st.fields, flatten_stack);
+                    let has_fields = self.has_serializable_fields_guarded(st.fields, flatten_stack);
                     flatten_stack.pop();
                     if has_fields {
                         return true;
@@ -253,8 +516,8 @@ impl TypeScriptGenerator {
         force_optional: bool,
         flatten_stack: &mut Vec<&'static str>,
     ) {
-        for field in fields {
-            if field.should_skip_serializing_unconditional() {
+        for (_, field) in FieldsInOrder::new(fields) {
+            if self.skips_field(field) {
                 continue;
             }
             if field.is_flattened() {
@@ -307,12 +570,10 @@ impl TypeScriptGenerator {
         self.write_indent();
 
         // Use optional marker for Option fields, fields with defaults, or when explicitly forced (flattened Option parents).
-        let has_default = field.default.is_some();
-
         if let Def::Option(opt) = &field_shape.def {
             let inner_type = self.type_for_shape(opt.t);
             writeln!(self.output, "{}?: {};", field_name, inner_type).unwrap();
-        } else if force_optional || has_default {
+        } else if self.is_optional(field, force_optional) {
             let field_type = self.type_for_shape(field_shape);
             writeln!(self.output, "{}?: {};", field_name, field_type).unwrap();
         } else {
@@ -322,17 +583,14 @@ impl TypeScriptGenerator {
     }
 
     fn generate_shape(&mut self, shape: &'static Shape) {
+        let name = self.type_name(shape);
+
         // Handle transparent wrappers - generate the inner type instead
         if let Some(inner) = shape.inner {
             self.add_shape(inner);
             // Generate a type alias
             let inner_type = self.type_for_shape(inner);
-            writeln!(
-                self.output,
-                "export type {} = {};",
-                shape.type_identifier, inner_type
-            )
-            .unwrap();
+            writeln!(self.output, "export type {} = {};", name, inner_type).unwrap();
             self.output.push('\n');
             return;
         }
@@ -370,12 +628,7 @@ impl TypeScriptGenerator {
                     // For non-struct/enum proxies (scalars, tuples, collections, etc.),
                     // generate a type alias to the proxy's type
                     let proxy_type = self.type_for_shape(proxy_shape);
-                    writeln!(
-                        self.output,
-                        "export type {} = {};",
-                        shape.type_identifier, proxy_type
-                    )
-                    .unwrap();
+                    writeln!(self.output, "export type {} = {};", name, proxy_type).unwrap();
                     self.output.push('\n');
                     return;
                 }
@@ -392,12 +645,7 @@ impl TypeScriptGenerator {
             _ => {
                 // For other types, generate a type alias
                 let type_str = self.type_for_shape(shape);
-                writeln!(
-                    self.output,
-                    "export type {} = {};",
-                    shape.type_identifier, type_str
-                )
-                .unwrap();
+                writeln!(self.output, "export type {} = {};", name, type_str).unwrap();
                 self.output.push('\n');
             }
         }
@@ -410,50 +658,38 @@ impl TypeScriptGenerator {
         fields: &'static [Field],
         kind: StructKind,
     ) {
+        let name = self.type_name(exported_shape);
         match kind {
             StructKind::Unit => {
                 // Unit struct as null
-                writeln!(
-                    self.output,
-                    "export type {} = null;",
-                    exported_shape.type_identifier
-                )
-                .unwrap();
+                writeln!(self.output, "export type {} = null;", name).unwrap();
             }
             StructKind::TupleStruct | StructKind::Tuple => {
                 // Tuple as array type
                 let types: Vec<String> = fields
                     .iter()
-                    .map(|f| self.type_for_shape(f.shape.get()))
+                    .map(|f| self.deferred_type(f.shape.get()))
                     .collect();
                 writeln!(
                     self.output,
                     "export type {} = [{}];",
-                    exported_shape.type_identifier,
+                    name,
                     types.join(", ")
                 )
                 .unwrap();
             }
             StructKind::Struct => {
                 // Empty structs should use `object` type to prevent accepting primitives
-                if !Self::has_serializable_fields(field_owner_shape, fields) {
-                    writeln!(
-                        self.output,
-                        "export type {} = object;",
-                        exported_shape.type_identifier
-                    )
-                    .unwrap();
+                if !self.has_serializable_fields(field_owner_shape, fields) {
+                    writeln!(self.output, "export type {} = object;", name).unwrap();
                 } else {
-                    writeln!(
-                        self.output,
-                        "export interface {} {{",
-                        exported_shape.type_identifier
-                    )
-                    .unwrap();
+                    writeln!(self.output, "export interface {} {{", name).unwrap();
                     self.indent += 1;
+                    self.deferred += 1;
 
                     self.write_struct_fields_for_shape(field_owner_shape, fields);
 
+                    self.deferred -= 1;
                     self.indent -= 1;
                     self.output.push_str("}\n");
                 }
@@ -463,6 +699,8 @@ impl TypeScriptGenerator {
     }
 
     fn generate_enum(&mut self, shape: &'static Shape, enum_type: &facet_core::EnumType) {
+        let name = self.type_name(shape);
+
         // Check if all variants are unit variants (simple string union)
         let all_unit = enum_type
             .variants
@@ -496,7 +734,7 @@ impl TypeScriptGenerator {
                             .data
                             .fields
                             .iter()
-                            .map(|f| self.type_for_shape(f.shape.get()))
+                            .map(|f| self.deferred_type(f.shape.get()))
                             .collect();
                         variant_types.push(format!(
                             "{{ {}: \"{}\"; _: [{}] }}",
@@ -521,7 +759,7 @@ impl TypeScriptGenerator {
             writeln!(
                 self.output,
                 "export type {} =\n  | {};",
-                shape.type_identifier,
+                name,
                 variant_types.join("\n  | ")
             )
             .unwrap();
@@ -547,7 +785,7 @@ impl TypeScriptGenerator {
                             .data
                             .fields
                             .iter()
-                            .map(|f| self.type_for_shape(f.shape.get()))
+                            .map(|f| self.deferred_type(f.shape.get()))
                             .collect();
                         variant_types.push(format!("[{}]", types.join(", ")));
                     }
@@ -562,7 +800,7 @@ impl TypeScriptGenerator {
             writeln!(
                 self.output,
                 "export type {} = {};",
-                shape.type_identifier,
+                name,
                 variant_types.join(" | ")
             )
             .unwrap();
@@ -576,7 +814,7 @@ impl TypeScriptGenerator {
             writeln!(
                 self.output,
                 "export type {} = {};",
-                shape.type_identifier,
+                name,
                 variants.join(" | ")
             )
             .unwrap();
@@ -594,7 +832,7 @@ impl TypeScriptGenerator {
                     }
                     StructKind::TupleStruct if variant.data.fields.len() == 1 => {
                         // Newtype variant: { VariantName: InnerType }
-                        let inner = self.type_for_shape(variant.data.fields[0].shape.get());
+                        let inner = self.deferred_type(variant.data.fields[0].shape.get());
                         variant_types.push(format!("{{ {}: {} }}", variant_name, inner));
                     }
                     StructKind::TupleStruct => {
@@ -603,7 +841,7 @@ impl TypeScriptGenerator {
                             .data
                             .fields
                             .iter()
-                            .map(|f| self.type_for_shape(f.shape.get()))
+                            .map(|f| self.deferred_type(f.shape.get()))
                             .collect();
                         variant_types.push(format!(
                             "{{ {}: [{}] }}",
@@ -626,7 +864,7 @@ impl TypeScriptGenerator {
             writeln!(
                 self.output,
                 "export type {} =\n  | {};",
-                shape.type_identifier,
+                name,
                 variant_types.join("\n  | ")
             )
             .unwrap();
@@ -634,6 +872,28 @@ impl TypeScriptGenerator {
         self.output.push('\n');
     }
 
+    /// The type of `shape` in a position TypeScript resolves lazily.
+    fn deferred_type(&mut self, shape: &'static Shape) -> String {
+        self.deferred += 1;
+        let ty = self.type_for_shape(shape);
+        self.deferred -= 1;
+        ty
+    }
+
+    /// Queue a named type for generation and return its name, recording the
+    /// reference if it is eager.
+    fn named_type(&mut self, shape: &'static Shape) -> String {
+        self.add_shape(shape);
+        let name = self.type_name(shape);
+        if self.deferred == 0 {
+            if self.drop_cycle_refs && self.cycle.contains(&name) {
+                return "unknown".to_string();
+            }
+            self.eager_refs.insert(name.clone());
+        }
+        name
+    }
+
     fn type_for_shape(&mut self, shape: &'static Shape) -> String {
         // Check Def first - these take precedence over transparent wrappers
         match &shape.def {
@@ -642,13 +902,16 @@ impl TypeScriptGenerator {
                 format!("{} | null", self.type_for_shape(opt.t))
             }
             Def::List(list) => {
-                format!("{}[]", self.type_for_shape(list.t))
+                format!("{}[]", self.deferred_type(list.t))
             }
             Def::Array(arr) => {
-                format!("{}[]", self.type_for_shape(arr.t))
+                format!("{}[]", self.deferred_type(arr.t))
             }
             Def::Set(set) => {
-                format!("{}[]", self.type_for_shape(set.t))
+                format!("{}[]", self.deferred_type(set.t))
+            }
+            Def::Map(map) if !self.cycle.is_empty() => {
+                format!("{{ [key: string]: {} }}", self.deferred_type(map.v))
             }
             Def::Map(map) => {
                 format!("Record<string, {}>", self.type_for_shape(map.v))
@@ -671,18 +934,14 @@ impl TypeScriptGenerator {
                             let types: Vec<String> = st
                                 .fields
                                 .iter()
-                                .map(|f| self.type_for_shape(f.shape.get()))
+                                .map(|f| self.deferred_type(f.shape.get()))
                                 .collect();
                             format!("[{}]", types.join(", "))
                         } else {
-                            self.add_shape(shape);
-                            shape.type_identifier.to_string()
+                            self.named_type(shape)
                         }
                     }
-                    Type::User(UserType::Enum(_)) => {
-                        self.add_shape(shape);
-                        shape.type_identifier.to_string()
-                    }
+                    Type::User(UserType::Enum(_)) => self.named_type(shape),
                     _ => {
                         // For other undefined types, check if it's a transparent wrapper
                         if let Some(inner) = shape.inner {
@@ -705,6 +964,14 @@ impl TypeScriptGenerator {
     }
 
     fn scalar_type(&self, shape: &'static Shape) -> String {
+        match shape.scalar_kind() {
+            Some(ScalarKind::Str | ScalarKind::Char) => return "string".to_string(),
+            Some(ScalarKind::Bool) => return "boolean".to_string(),
+            Some(kind) if kind.is_integer() || kind.is_float() => return "number".to_string(),
+            _ => {}
+        }
+
+        // Scalars without a kind are recognized by name.
         match shape.type_identifier {
             // Strings
             "String" | "str" | "&str" | "Cow" => "string".to_string(),
@@ -755,6 +1022,24 @@ mod tests {
         insta::assert_snapshot!(ts);
     }
 
+    #[test]
+    fn test_field_order() {
+        #[derive(Facet)]
+        struct Upload {
+            #[facet(order = 1)]
+            data: Vec<u8>,
+            name: String,
+            #[facet(order = -1)]
+            id: u32,
+        }
+
+        let ts = to_typescript::<Upload>();
+        let id = ts.find("id:").unwrap();
+        let name = ts.find("name:").unwrap();
+        let data = ts.find("data:").unwrap();
+        assert!(id < name && name < data, "{ts}");
+    }
+
     #[test]
     fn test_optional_field() {
         #[derive(Facet)]
@@ -1685,4 +1970,212 @@ mod tests {
         );
         insta::assert_snapshot!(ts);
     }
+
+    #[test]
+    fn test_input_types_for_asymmetric_skips() {
+        #[derive(Facet)]
+        struct Address {
+            street: String,
+            #[facet(skip_deserializing)]
+            geocoded: bool,
+        }
+
+        #[derive(Facet)]
+        struct Tag {
+            label: String,
+        }
+
+        #[derive(Facet)]
+        struct User {
+            #[facet(skip_deserializing)]
+            id: u64,
+            name: String,
+            #[facet(skip_serializing)]
+            password: String,
+            address: Address,
+            tags: Vec<Tag>,
+        }
+
+        let mut ts_gen = TypeScriptGenerator::new().with_input_types();
+        ts_gen.add_type::<User>();
+        let ts = ts_gen.finish();
+        assert!(
+            !ts.contains("TagInput"),
+            "symmetric types need no input type:\n{ts}"
+        );
+        insta::assert_snapshot!("input_types_for_asymmetric_skips", ts);
+    }
+
+    #[test]
+    fn test_input_types_for_defaults_and_enums() {
+        #[derive(Facet)]
+        struct Settings {
+            #[facet(default)]
+            theme: String,
+            nickname: Option<String>,
+            #[facet(skip_serializing_if = Vec::is_empty)]
+            aliases: Vec<String>,
+        }
+
+        #[derive(Facet)]
+        #[repr(u8)]
+        #[allow(dead_code)]
+        enum Event {
+            Saved(Settings),
+            Cleared,
+        }
+
+        let mut ts_gen = TypeScriptGenerator::new().with_input_types();
+        ts_gen.add_type::<Event>();
+        let ts = ts_gen.finish();
+        insta::assert_snapshot!("input_types_for_defaults_and_enums", ts);
+    }
+
+    #[test]
+    fn test_mutually_recursive_structs() {
+        #[derive(Facet)]
+        struct Author {
+            name: String,
+            posts: Vec<Post>,
+        }
+
+        #[derive(Facet)]
+        struct Post {
+            title: String,
+            author: Option<Box<Author>>,
+            related: BTreeMap<String, Post>,
+        }
+
+        for ts in [to_typescript::<Author>(), to_typescript::<Post>()] {
+            assert_eq!(ts.matches("export interface Author ").count(), 1, "{ts}");
+            assert_eq!(ts.matches("export interface Post ").count(), 1, "{ts}");
+            // Interface members are resolved lazily, so nothing needs rewriting
+            assert!(ts.contains("related: Record<string, Post>;"), "{ts}");
+        }
+    }
+
+    #[test]
+    fn test_alias_cycle_through_map_uses_index_signature() {
+        #[derive(Facet)]
+        #[facet(transparent)]
+        struct Forest(BTreeMap<String, Tree>);
+
+        #[derive(Facet)]
+        #[facet(untagged)]
+        #[repr(u8)]
+        #[allow(dead_code)]
+        enum Tree {
+            Leaf(u32),
+            Branch(Forest),
+        }
+
+        // `type Forest = Record<string, Tree>` would be a circular alias
+        let ts = to_typescript::<Tree>();
+        assert!(
+            ts.contains("export type Forest = { [key: string]: Tree };"),
+            "{ts}"
+        );
+        assert!(ts.contains("export type Tree = number | Forest;"), "{ts}");
+    }
+
+    #[test]
+    fn test_alias_cycle_without_indirection_is_cut() {
+        #[derive(Facet)]
+        #[facet(untagged)]
+        #[repr(u8)]
+        #[allow(dead_code)]
+        enum A {
+            X(B),
+        }
+
+        #[derive(Facet)]
+        #[facet(untagged)]
+        #[repr(u8)]
+        #[allow(dead_code)]
+        enum B {
+            Y(Option<Box<A>>),
+            Z(u32),
+        }
+
+        let ts = to_typescript::<A>();
+        assert_eq!(ts.matches("export type A =").count(), 1, "{ts}");
+        assert_eq!(ts.matches("export type B =").count(), 1, "{ts}");
+        assert!(!ts.contains("Record<"), "{ts}");
+        assert!(ts.contains("unknown"), "{ts}");
+    }
+
+    #[test]
+    fn test_strongly_connected_components() {
+        let edges = vec![vec![1], vec![0, 2], vec![], vec![3]];
+        let mut components = strongly_connected_components(&edges);
+        components.iter_mut().for_each(|c| c.sort());
+        components.sort();
+        assert_eq!(components, vec![vec![0, 1], vec![2], vec![3]]);
+    }
+}
+
+/// Tarjan's algorithm: the strongly connected components of a graph given as
+/// adjacency lists, each listed once.
+fn strongly_connected_components(edges: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    struct Tarjan<'a> {
+        edges: &'a [Vec<usize>],
+        index: Vec<Option<usize>>,
+        low: Vec<usize>,
+        on_stack: Vec<bool>,
+        stack: Vec<usize>,
+        next: usize,
+        components: Vec<Vec<usize>>,
+    }
+
+    impl Tarjan<'_> {
+        fn visit(&mut self, node: usize) {
+            self.index[node] = Some(self.next);
+            self.low[node] = self.next;
+            self.next += 1;
+            self.stack.push(node);
+            self.on_stack[node] = true;
+
+            for &target in &self.edges[node] {
+                match self.index[target] {
+                    None => {
+                        self.visit(target);
+                        self.low[node] = self.low[node].min(self.low[target]);
+                    }
+                    Some(index) if self.on_stack[target] => {
+                        self.low[node] = self.low[node].min(index);
+                    }
+                    Some(_) => {}
+                }
+            }
+
+            if Some(self.low[node]) == self.index[node] {
+                let mut component = Vec::new();
+                while let Some(member) = self.stack.pop() {
+                    self.on_stack[member] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                component.reverse();
+                self.components.push(component);
+            }
+        }
+    }
+
+    let mut tarjan = Tarjan {
+        edges,
+        index: vec![None; edges.len()],
+        low: vec![0; edges.len()],
+        on_stack: vec![false; edges.len()],
+        stack: Vec::new(),
+        next: 0,
+        components: Vec::new(),
+    };
+    for node in 0..edges.len() {
+        if tarjan.index[node].is_none() {
+            tarjan.visit(node);
+        }
+    }
+    tarjan.components
 }
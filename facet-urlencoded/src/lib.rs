@@ -6,7 +6,7 @@
 //! [![MIT/Apache-2.0 licensed](https://img.shields.io/crates/l/facet-urlencoded.svg)](./LICENSE)
 //! [![Discord](https://img.shields.io/discord/1379550208551026748?logo=discord&label=discord)](https://discord.gg/JhD7CwCJ8F)
 //!
-//! Provides URL-encoded form data deserialization for Facet types.
+//! Provides URL-encoded form data deserialization and serialization for Facet types.
 //!
 #![doc = include_str!("../readme-footer.md")]
 
@@ -23,6 +23,9 @@ pub use form::Form;
 mod query;
 pub use query::Query;
 
+mod serialize;
+pub use serialize::{to_string, to_string_value};
+
 #[cfg(feature = "axum")]
 mod axum;
 #[cfg(feature = "axum")]
@@ -39,6 +42,12 @@ pub use self::axum::{FormRejection, QueryRejection};
 /// For nested structures, the library supports the standard bracket notation used in most web frameworks:
 /// - Simple nested objects: `object[field]=value`
 /// - Deeply nested objects: `object[field1][field2]=value`
+/// - Lists and sets of scalars: `ids[]=1&ids[]=2`, or a repeated key `ids=1&ids=2`
+/// - Indexed lists, which may hold structs: `items[0][name]=a&items[1][name]=b`
+/// - Maps: `filter[name]=x&filter[kind]=y`
+///
+/// Indexed list elements are ordered by index; gaps are closed up. For any other field
+/// given more than once, the last value wins.
 ///
 /// # Basic Example
 ///
@@ -58,6 +67,25 @@ pub use self::axum::{FormRejection, QueryRejection};
 /// assert_eq!(params, SearchParams { query: "rust programming".to_string(), page: 2 });
 /// ```
 ///
+/// # Lists and Maps Example
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use facet::Facet;
+/// use facet_urlencoded::from_str;
+///
+/// #[derive(Debug, Facet, PartialEq)]
+/// struct Filter {
+///     ids: Vec<u32>,
+///     filter: BTreeMap<String, String>,
+/// }
+///
+/// let filter: Filter = from_str("ids[]=1&ids[]=2&filter[name]=x").unwrap();
+/// assert_eq!(filter.ids, [1, 2]);
+/// assert_eq!(filter.filter["name"], "x");
+/// ```
+///
 /// # Nested Structure Example
 ///
 /// ```
@@ -203,8 +231,8 @@ fn initialize_nested_structures(nested: &mut NestedValues) {
 
 /// Internal helper struct to represent nested values from URL-encoded data
 struct NestedValues {
-    // Root level key-value pairs
-    flat: std::collections::HashMap<String, String>,
+    // Root level key-value pairs; repeated and `key[]` keys collect every value in order
+    flat: std::collections::HashMap<String, Vec<String>>,
     // Nested structures: key -> nested map
     nested: std::collections::HashMap<String, NestedValues>,
 }
@@ -227,6 +255,12 @@ impl NestedValues {
             let nested_key = &key[(open_bracket + 1)..close_bracket];
             let remainder = &key[(close_bracket + 1)..];
 
+            if nested_key.is_empty() && remainder.is_empty() {
+                // List append: ids[]=value
+                self.push_flat(parent_key, value);
+                return;
+            }
+
             let nested = self
                 .nested
                 .entry(parent_key.to_string())
@@ -234,7 +268,7 @@ impl NestedValues {
 
             if remainder.is_empty() {
                 // Simple case: user[name]=value
-                nested.flat.insert(nested_key.to_string(), value);
+                nested.push_flat(nested_key, value);
             } else {
                 // Handle deeply nested case like user[address][city]=value
                 let new_key = format!("{nested_key}{remainder}");
@@ -244,7 +278,94 @@ impl NestedValues {
         }
 
         // If we get here, it's a flat key-value pair
-        self.flat.insert(key.to_string(), value);
+        self.push_flat(key, value);
+    }
+
+    fn push_flat(&mut self, key: &str, value: String) {
+        self.flat.entry(key.to_string()).or_default().push(value);
+    }
+
+    /// The entries of a node whose keys are list indices, ordered by index.
+    fn indexed_entries<'a>(
+        &'a self,
+        field: &str,
+    ) -> Result<Vec<(usize, Entry<'a>)>, UrlEncodedError> {
+        let index = |key: &str| {
+            key.parse::<usize>()
+                .map_err(|_| UrlEncodedError::InvalidIndex(field.to_string(), key.to_string()))
+        };
+        let mut entries = Vec::with_capacity(self.flat.len() + self.nested.len());
+        for (key, values) in &self.flat {
+            entries.push((index(key)?, Entry::Values(values)));
+        }
+        for (key, nested) in &self.nested {
+            entries.push((index(key)?, Entry::Nested(nested)));
+        }
+        entries.sort_by_key(|(index, _)| *index);
+        Ok(entries)
+    }
+}
+
+/// One child of a [`NestedValues`] node.
+enum Entry<'a> {
+    Values(&'a [String]),
+    Nested(&'a NestedValues),
+}
+
+/// Deserialize one child of a [`NestedValues`] node into the current frame.
+fn deserialize_entry<'facet, const BORROW: bool>(
+    wip: Partial<'facet, BORROW>,
+    key: &str,
+    entry: Entry<'_>,
+) -> Result<Partial<'facet, BORROW>, UrlEncodedError> {
+    match entry {
+        Entry::Values(values) => deserialize_values(key, values, wip),
+        Entry::Nested(nested) => deserialize_value(wip, nested, Some(key)),
+    }
+}
+
+/// Deserialize the values given for one key: every value for lists and sets,
+/// otherwise the last one.
+fn deserialize_values<'facet, const BORROW: bool>(
+    key: &str,
+    values: &[String],
+    mut wip: Partial<'facet, BORROW>,
+) -> Result<Partial<'facet, BORROW>, UrlEncodedError> {
+    let is_option = matches!(wip.shape().def, Def::Option(_));
+    let inner = match wip.shape().def {
+        Def::Option(option) => option.t(),
+        _ => wip.shape(),
+    };
+
+    match inner.def {
+        Def::List(_) | Def::Set(_) => {
+            if is_option {
+                wip = wip.begin_some()?;
+            }
+            if let Def::List(_) = inner.def {
+                wip = wip.init_list_with_capacity(values.len())?;
+                for value in values {
+                    wip = wip.begin_list_item()?;
+                    wip = deserialize_scalar_field(key, value, wip)?;
+                    wip = wip.end()?;
+                }
+            } else {
+                wip = wip.init_set()?;
+                for value in values {
+                    wip = wip.begin_set_item()?;
+                    wip = deserialize_scalar_field(key, value, wip)?;
+                    wip = wip.end()?;
+                }
+            }
+            if is_option {
+                wip = wip.end()?;
+            }
+            Ok(wip)
+        }
+        _ => match values.last() {
+            Some(value) => deserialize_scalar_field(key, value, wip),
+            None => Ok(wip),
+        },
     }
 }
 
@@ -255,6 +376,60 @@ fn deserialize_value<'facet, const BORROW: bool>(
     key: Option<&str>,
 ) -> Result<Partial<'facet, BORROW>, UrlEncodedError> {
     let shape = wip.shape();
+
+    if let Def::Option(_) = shape.def {
+        wip = wip.begin_some()?;
+        wip = deserialize_value(wip, values, key)?;
+        return Ok(wip.end()?);
+    }
+
+    match shape.def {
+        Def::List(_) | Def::Set(_) => {
+            let field = key.unwrap_or_default();
+            let entries = values.indexed_entries(field)?;
+            if let Def::List(_) = shape.def {
+                wip = wip.init_list_with_capacity(entries.len())?;
+                for (_, entry) in entries {
+                    wip = wip.begin_list_item()?;
+                    wip = deserialize_entry(wip, field, entry)?;
+                    wip = wip.end()?;
+                }
+            } else {
+                wip = wip.init_set()?;
+                for (_, entry) in entries {
+                    wip = wip.begin_set_item()?;
+                    wip = deserialize_entry(wip, field, entry)?;
+                    wip = wip.end()?;
+                }
+            }
+            return Ok(wip);
+        }
+        Def::Map(_) => {
+            trace!("Deserializing map");
+            wip = wip.init_map()?;
+            let entries = values
+                .flat
+                .iter()
+                .map(|(key, values)| (key, Entry::Values(values)))
+                .chain(
+                    values
+                        .nested
+                        .iter()
+                        .map(|(key, nested)| (key, Entry::Nested(nested))),
+                );
+            for (map_key, entry) in entries {
+                wip = wip.begin_key()?;
+                wip = deserialize_scalar_field(map_key, map_key, wip)?;
+                wip = wip.end()?;
+                wip = wip.begin_value()?;
+                wip = deserialize_entry(wip, map_key, entry)?;
+                wip = wip.end()?;
+            }
+            return Ok(wip);
+        }
+        _ => {}
+    }
+
     match shape.ty {
         Type::User(UserType::Struct(struct_type)) => {
             match key {
@@ -263,10 +438,10 @@ fn deserialize_value<'facet, const BORROW: bool>(
             }
 
             // Process flat fields
-            for (key, value) in values.flat.iter() {
+            for (key, field_values) in values.flat.iter() {
                 if let Some(index) = wip.field_index(key) {
                     wip = wip.begin_nth_field(index)?;
-                    wip = deserialize_scalar_field(key, value, wip)?;
+                    wip = deserialize_values(key, field_values, wip)?;
                     wip = wip.end()?;
                 } else {
                     trace!("Unknown field: {key}");
@@ -304,9 +479,9 @@ fn deserialize_value<'facet, const BORROW: bool>(
                 ))
             }
             Some(key) => {
-                error!("Expected struct field for nested value");
+                error!("Expected struct, list or map field for nested value");
                 Err(UrlEncodedError::UnsupportedShape(format!(
-                    "Expected struct for nested field '{key}'"
+                    "Expected struct, list or map for nested field '{key}'"
                 )))
             }
         },
@@ -432,6 +607,8 @@ pub enum UrlEncodedError {
     InvalidChar(String, String),
     /// The field value couldn't be parsed as a bool.
     InvalidBool(String, String),
+    /// A bracketed key of a list field wasn't a list index.
+    InvalidIndex(String, String),
     /// The shape is not supported for deserialization.
     UnsupportedShape(String),
     /// The type is not supported for deserialization.
@@ -479,6 +656,9 @@ impl core::fmt::Display for UrlEncodedError {
             UrlEncodedError::InvalidBool(field, value) => {
                 write!(f, "Invalid bool for field '{field}': '{value}'")
             }
+            UrlEncodedError::InvalidIndex(field, index) => {
+                write!(f, "Invalid list index for field '{field}': '{index}'")
+            }
             UrlEncodedError::UnsupportedShape(shape) => {
                 write!(f, "Unsupported shape: {shape}")
             }
@@ -0,0 +1,182 @@
+//! Serializing Facet values to URL encoded form data.
+
+use facet_core::{Def, Facet, Type, UserType};
+use facet_reflect::{HasFields, Peek};
+
+use crate::UrlEncodedError;
+
+/// Serializes a value of type `T` into a URL encoded form data string.
+///
+/// Nested values use the same bracket notation that [`from_str`](crate::from_str) reads:
+/// - Nested structs: `object[field]=value`
+/// - Lists and sets of scalars: `ids[]=1&ids[]=2`
+/// - Lists of structs: `items[0][name]=a&items[1][name]=b`
+/// - Maps: `filter[name]=x`
+///
+/// `None` values are left out, unit enum variants are written as their name, and brackets in
+/// keys are written as they are rather than percent-encoded. The root value must be a struct
+/// or a map.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use facet::Facet;
+/// use facet_urlencoded::{from_str, to_string};
+///
+/// #[derive(Debug, Facet, PartialEq)]
+/// struct Search {
+///     q: String,
+///     page: Option<u32>,
+///     ids: Vec<u32>,
+///     filter: BTreeMap<String, String>,
+/// }
+///
+/// let search = Search {
+///     q: "rust lang".to_string(),
+///     page: None,
+///     ids: vec![1, 2],
+///     filter: BTreeMap::from([("name".to_string(), "x".to_string())]),
+/// };
+///
+/// let encoded = to_string(&search).unwrap();
+/// assert_eq!(encoded, "q=rust+lang&ids[]=1&ids[]=2&filter[name]=x");
+/// assert_eq!(from_str::<Search>(&encoded).unwrap(), search);
+/// ```
+pub fn to_string<'facet, T: Facet<'facet> + ?Sized>(value: &T) -> Result<String, UrlEncodedError> {
+    to_string_value(Peek::new(value))
+}
+
+/// Serializes a [`Peek`] into a URL encoded form data string.
+///
+/// This is the type-erased counterpart of [`to_string`].
+pub fn to_string_value(value: Peek<'_, '_>) -> Result<String, UrlEncodedError> {
+    let value = value.innermost_peek();
+    match (value.shape().def, value.shape().ty) {
+        (Def::Map(_), _) | (_, Type::User(UserType::Struct(_))) => {}
+        _ => {
+            return Err(UrlEncodedError::UnsupportedShape(
+                "Unsupported root type".to_string(),
+            ));
+        }
+    }
+
+    let mut encoder = Encoder { out: String::new() };
+    encoder.value("", value)?;
+    Ok(encoder.out)
+}
+
+struct Encoder {
+    out: String,
+}
+
+impl Encoder {
+    /// Writes `value` under `key`, an already-encoded key (empty at the root).
+    fn value(&mut self, key: &str, value: Peek<'_, '_>) -> Result<(), UrlEncodedError> {
+        if let Ok(option) = value.into_option() {
+            return match option.value() {
+                Some(inner) => self.value(key, inner),
+                None => Ok(()),
+            };
+        }
+        let value = value.innermost_peek();
+
+        match value.shape().def {
+            Def::List(_) | Def::Array(_) | Def::Slice(_) => {
+                let list = value.into_list_like()?;
+                for (index, item) in list.iter().enumerate() {
+                    self.item(key, index, item)?;
+                }
+                return Ok(());
+            }
+            Def::Set(_) => {
+                let set = value.into_set()?;
+                for (index, item) in set.iter().enumerate() {
+                    self.item(key, index, item)?;
+                }
+                return Ok(());
+            }
+            Def::Map(_) => {
+                for (map_key, map_value) in value.into_map()?.iter() {
+                    let map_key = match map_key.as_str() {
+                        Some(s) => s.to_string(),
+                        None => self.scalar(key, map_key)?,
+                    };
+                    self.value(&child_key(key, &map_key), map_value)?;
+                }
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        if let Type::User(UserType::Struct(_)) = value.shape().ty {
+            for (field, field_value) in value.into_struct()?.fields_for_serialize() {
+                self.value(&child_key(key, field.effective_name()), field_value)?;
+            }
+            return Ok(());
+        }
+
+        let scalar = self.scalar(key, value)?;
+        self.pair(key, &scalar);
+        Ok(())
+    }
+
+    /// Writes a list or set element: scalars are appended with `key[]`, anything
+    /// else is indexed with `key[index]`.
+    fn item(&mut self, key: &str, index: usize, item: Peek<'_, '_>) -> Result<(), UrlEncodedError> {
+        let inner = item.innermost_peek();
+        let compound = matches!(
+            inner.shape().def,
+            Def::List(_) | Def::Array(_) | Def::Slice(_) | Def::Set(_) | Def::Map(_)
+        ) || matches!(inner.shape().ty, Type::User(UserType::Struct(_)));
+
+        if compound {
+            self.value(&child_key(key, &index.to_string()), item)
+        } else {
+            self.value(&format!("{key}[]"), item)
+        }
+    }
+
+    /// Renders a scalar value as a string.
+    fn scalar(&self, key: &str, value: Peek<'_, '_>) -> Result<String, UrlEncodedError> {
+        if let Some(s) = value.as_str() {
+            return Ok(s.to_string());
+        }
+        if let Type::User(UserType::Enum(_)) = value.shape().ty {
+            let variant = value
+                .into_enum()?
+                .active_variant()
+                .map_err(|err| UrlEncodedError::UnsupportedShape(err.to_string()))?;
+            if variant.data.fields.is_empty() {
+                return Ok(variant.effective_name().to_string());
+            }
+        } else if value.shape().is_display() {
+            return Ok(value.to_string());
+        }
+        Err(UrlEncodedError::UnsupportedShape(format!(
+            "{} for field '{key}'",
+            value.shape()
+        )))
+    }
+
+    fn pair(&mut self, key: &str, value: &str) {
+        if !self.out.is_empty() {
+            self.out.push('&');
+        }
+        self.out.push_str(key);
+        self.out.push('=');
+        self.out
+            .extend(form_urlencoded::byte_serialize(value.as_bytes()));
+    }
+}
+
+/// The encoded key of `name` under `parent`: `name` at the root, `parent[name]` below it.
+fn child_key(parent: &str, name: &str) -> String {
+    let name: String = form_urlencoded::byte_serialize(name.as_bytes()).collect();
+    if parent.is_empty() {
+        name
+    } else {
+        format!("{parent}[{name}]")
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{from_str, from_str_value};
+use crate::{UrlEncodedError, from_str, from_str_value, to_string};
 use facet::Facet;
 use facet_reflect::TypePlan;
 use facet_testhelpers::test;
@@ -392,3 +392,136 @@ fn test_numeric_float() {
         }
     )
 }
+
+#[derive(Debug, Facet, PartialEq)]
+struct Item {
+    name: String,
+    qty: u32,
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct ListQuery {
+    ids: Vec<u32>,
+    tags: Option<Vec<String>>,
+    filter: std::collections::BTreeMap<String, String>,
+    items: Vec<Item>,
+}
+
+#[test]
+fn test_bracketed_lists_and_maps() {
+    let query = "ids[]=1&ids[]=2&filter[name]=x&filter[kind]=y\
+        &items[1][name]=b&items[1][qty]=2&items[0][name]=a&items[0][qty]=1";
+    let parsed: ListQuery = from_str(query).unwrap();
+    assert_eq!(parsed.ids, [1, 2]);
+    assert_eq!(parsed.tags, None);
+    assert_eq!(parsed.filter["name"], "x");
+    assert_eq!(parsed.filter["kind"], "y");
+    assert_eq!(
+        parsed.items,
+        [
+            Item {
+                name: "a".to_string(),
+                qty: 1
+            },
+            Item {
+                name: "b".to_string(),
+                qty: 2
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_repeated_and_indexed_list_keys() {
+    #[derive(Debug, Facet, PartialEq)]
+    struct Ids {
+        ids: Vec<u32>,
+        tags: std::collections::BTreeSet<String>,
+    }
+
+    let parsed: Ids = from_str("ids=3&ids=4&tags=a&tags[]=b&tags=a").unwrap();
+    assert_eq!(parsed.ids, [3, 4]);
+    assert_eq!(parsed.tags.len(), 2);
+
+    let parsed: Ids = from_str("ids[10]=2&ids[2]=1&tags[]=a").unwrap();
+    assert_eq!(parsed.ids, [1, 2]);
+
+    let err = from_str::<Ids>("ids[first]=1&tags[]=a").unwrap_err();
+    assert!(matches!(err, UrlEncodedError::InvalidIndex(field, index)
+        if field == "ids" && index == "first"));
+}
+
+#[test]
+fn test_scalar_field_last_value_wins() {
+    let parsed: SearchParams = from_str("query=a&query=b&page=1").unwrap();
+    assert_eq!(parsed.query, "b");
+}
+
+#[test]
+fn test_serialize_nested() {
+    let form = OrderForm {
+        product_id: "p&1".to_string(),
+        quantity: 2,
+        user: User {
+            name: "Jane Doe".to_string(),
+            age: 30,
+            address: Address {
+                street: "1 Main St".to_string(),
+                city: "Springfield".to_string(),
+                zip: "12345".to_string(),
+            },
+        },
+    };
+
+    let encoded = to_string(&form).unwrap();
+    assert_eq!(
+        encoded,
+        "product_id=p%261&quantity=2&user[name]=Jane+Doe&user[age]=30\
+         &user[address][street]=1+Main+St&user[address][city]=Springfield\
+         &user[address][zip]=12345"
+    );
+    assert_eq!(from_str::<OrderForm>(&encoded).unwrap(), form);
+}
+
+#[test]
+fn test_serialize_lists_maps_and_options() {
+    let query = ListQuery {
+        ids: vec![1, 2],
+        tags: Some(vec!["a b".to_string()]),
+        filter: [("name".to_string(), "x".to_string())].into(),
+        items: vec![Item {
+            name: "a".to_string(),
+            qty: 1,
+        }],
+    };
+
+    let encoded = to_string(&query).unwrap();
+    assert_eq!(
+        encoded,
+        "ids[]=1&ids[]=2&tags[]=a+b&filter[name]=x&items[0][name]=a&items[0][qty]=1"
+    );
+    assert_eq!(from_str::<ListQuery>(&encoded).unwrap(), query);
+}
+
+#[test]
+fn test_serialize_flattened_and_enums() {
+    let params = ExtendedSearchParams {
+        search: SearchParams {
+            query: "rust".to_string(),
+            page: 1,
+        },
+        filter: Filter::Cats,
+    };
+
+    let encoded = to_string(&params).unwrap();
+    assert_eq!(encoded, "query=rust&page=1&filter=cats");
+    assert_eq!(from_str::<ExtendedSearchParams>(&encoded).unwrap(), params);
+}
+
+#[test]
+fn test_serialize_unsupported_root() {
+    assert!(matches!(
+        to_string(&42u32),
+        Err(UrlEncodedError::UnsupportedShape(_))
+    ));
+}
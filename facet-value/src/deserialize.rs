@@ -301,9 +301,9 @@ fn deserialize_value_into<'facet>(
         return deserialize_pointer(value, partial);
     }
 
-    // Check for container-level proxy
+    // Check for container-level proxy (or `#[facet(from = ...)]` conversion)
     #[cfg(feature = "alloc")]
-    if shape.proxy.is_some() {
+    if shape.deserialize_proxy(None).is_some() {
         let (partial_returned, has_proxy) = partial.begin_custom_deserialization_from_shape()?;
         partial = partial_returned;
         if has_proxy {
@@ -1 +1,2 @@
 mod from_value;
+mod peek_lookup;
@@ -0,0 +1,47 @@
+//! Dotted-path lookups through dynamic values.
+
+use facet_reflect::{LookupError, Peek};
+use facet_testhelpers::test;
+use facet_value::{Value, value};
+
+#[test]
+fn lookups_through_objects_and_arrays() {
+    let config = value!({
+        "server": { "host": "db", "port": 5432, "tls": null },
+        "replicas": [ { "weight": 0.5 }, { "weight": 2 } ],
+        "debug": true
+    });
+    let peek = Peek::new(&config);
+
+    assert_eq!(peek.get_str("server.host").unwrap(), "db");
+    assert_eq!(peek.get_path_as::<u16>("server.port").unwrap(), 5432);
+    assert_eq!(peek.get_path_as::<String>("server.host").unwrap(), "db");
+    assert_eq!(peek.get_f64("replicas.0.weight").unwrap(), 0.5);
+    assert_eq!(peek.get_u64("replicas.1.weight").unwrap(), 2);
+    assert!(peek.get_bool("debug").unwrap());
+    assert_eq!(
+        peek.get_path_as::<Option<bool>>("server.tls").unwrap(),
+        None
+    );
+
+    let subtree: Value = peek.get_path_as("replicas.1").unwrap();
+    assert_eq!(subtree, value!({ "weight": 2 }));
+}
+
+#[test]
+fn dynamic_lookup_errors() {
+    let config = value!({ "server": { "port": 70000, "tls": null } });
+    let peek = Peek::new(&config);
+
+    let err = peek.get_path_as::<u16>("server.port").unwrap_err();
+    assert!(matches!(err, LookupError::Convert { .. }), "{err}");
+
+    let err = peek.get_str("server").unwrap_err();
+    assert!(matches!(err, LookupError::WrongType { .. }), "{err}");
+
+    let err = peek.get_str("server.tls.cert").unwrap_err();
+    assert!(
+        matches!(&err, LookupError::Missing { path } if path == "server.tls.cert"),
+        "{err}"
+    );
+}
@@ -0,0 +1,176 @@
+//! The `class` attribute as a set of class names.
+//!
+//! UIs that re-render by diffing trees mostly touch `class` to flip one name
+//! on or off. [`ClassList`] does that without string surgery, and
+//! [`Element::class_list`] / [`Element::set_class_list`] move it in and out of
+//! an element.
+
+use std::fmt;
+
+use crate::Element;
+
+/// The class names of an element, in order and without duplicates, like the
+/// DOM's `classList`.
+///
+/// Parsing splits on ASCII whitespace and drops repeated names; writing joins
+/// the names with single spaces. As a field type it (de)serializes as that
+/// string:
+///
+/// ```
+/// use facet::Facet;
+/// use facet_xml_node::ClassList;
+///
+/// #[derive(Facet)]
+/// struct Button {
+///     class: ClassList,
+/// }
+///
+/// let mut button: Button = facet_json::from_str(r#"{"class":"btn  btn-primary btn"}"#).unwrap();
+/// button.class.toggle("active");
+/// button.class.remove("btn-primary");
+/// assert_eq!(facet_json::to_string(&button).unwrap(), r#"{"class":"btn active"}"#);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default, facet::Facet)]
+#[facet(from = String, into = String)]
+pub struct ClassList {
+    names: Vec<String>,
+}
+
+impl ClassList {
+    /// An empty class list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse a `class` attribute value.
+    pub fn parse(value: &str) -> Self {
+        let mut list = Self::new();
+        for name in value.split_ascii_whitespace() {
+            list.add(name);
+        }
+        list
+    }
+
+    /// Whether the list contains `name`. Class names are case-sensitive.
+    pub fn contains(&self, name: &str) -> bool {
+        self.names.iter().any(|n| n == name)
+    }
+
+    /// Append `name` unless it is already present. Returns whether it was
+    /// added.
+    ///
+    /// # Panics
+    ///
+    /// If `name` is empty or contains whitespace, since it would not read
+    /// back as one class.
+    pub fn add(&mut self, name: impl Into<String>) -> bool {
+        let name = name.into();
+        assert!(
+            !name.is_empty() && !name.contains(|c: char| c.is_ascii_whitespace()),
+            "invalid class name {name:?}"
+        );
+        if self.contains(&name) {
+            return false;
+        }
+        self.names.push(name);
+        true
+    }
+
+    /// Remove `name`. Returns whether it was present.
+    pub fn remove(&mut self, name: &str) -> bool {
+        let len = self.names.len();
+        self.names.retain(|n| n != name);
+        self.names.len() != len
+    }
+
+    /// Add `name` if it is absent, remove it if present. Returns whether it
+    /// is present afterwards.
+    pub fn toggle(&mut self, name: &str) -> bool {
+        if self.remove(name) {
+            false
+        } else {
+            self.add(name);
+            true
+        }
+    }
+
+    /// The class names, in order.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.names.iter().map(String::as_str)
+    }
+
+    /// The number of class names.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Whether there are no class names.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+impl fmt::Display for ClassList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.names.join(" "))
+    }
+}
+
+impl From<&str> for ClassList {
+    fn from(value: &str) -> Self {
+        Self::parse(value)
+    }
+}
+
+impl From<String> for ClassList {
+    fn from(value: String) -> Self {
+        Self::parse(&value)
+    }
+}
+
+impl From<ClassList> for String {
+    fn from(list: ClassList) -> Self {
+        list.to_string()
+    }
+}
+
+impl<S: Into<String>> FromIterator<S> for ClassList {
+    fn from_iter<I: IntoIterator<Item = S>>(names: I) -> Self {
+        let mut list = Self::new();
+        for name in names {
+            list.add(name);
+        }
+        list
+    }
+}
+
+impl Element {
+    /// The element's `class` attribute as a [`ClassList`]; empty when the
+    /// attribute is missing.
+    ///
+    /// ```
+    /// use facet_xml_node::Element;
+    ///
+    /// let mut item = Element::new("li").attr("class", "item selected");
+    /// let mut classes = item.class_list();
+    /// classes.toggle("selected");
+    /// classes.add("done");
+    /// item.set_class_list(&classes);
+    /// assert_eq!(item.get_attr("class"), Some("item done"));
+    /// ```
+    pub fn class_list(&self) -> ClassList {
+        self.get_attr("class")
+            .map(ClassList::parse)
+            .unwrap_or_default()
+    }
+
+    /// Write `classes` back as the `class` attribute, removing the attribute
+    /// when the list is empty.
+    pub fn set_class_list(&mut self, classes: &ClassList) {
+        if classes.is_empty() {
+            self.remove_attr("class");
+        } else {
+            self.set_attr("class", classes.to_string());
+        }
+    }
+}
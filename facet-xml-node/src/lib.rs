@@ -1,14 +1,28 @@
 //! Raw XML element types and deserialization from Element trees.
 
+mod class_list;
+mod links;
+mod outline;
 mod parser;
+mod sanitize;
+mod select;
+mod structured_data;
+mod style;
 
 use facet_xml as xml;
 use std::collections::HashMap;
 
+pub use class_list::ClassList;
+pub use links::{Descendants, ElementUrl, resolve_url};
+pub use outline::Heading;
 pub use parser::{
     ElementParseError, ElementParser, ElementSerializeError, ElementSerializer, from_element,
     to_element,
 };
+pub use sanitize::Sanitizer;
+pub use select::{Select, Selector, SelectorError};
+pub use structured_data::{StructuredDataError, StructuredItem};
+pub use style::StyleDecls;
 
 /// Error when navigating to a path in an Element tree.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -21,7 +35,7 @@ pub enum PathError {
         index: usize,
         len: usize,
     },
-    /// Tried to navigate through a text node.
+    /// Tried to navigate through a text, CDATA or processing instruction node.
     TextNodeHasNoChildren { path: Vec<usize> },
 }
 
@@ -44,7 +58,8 @@ impl std::fmt::Display for PathError {
 
 impl std::error::Error for PathError {}
 
-/// Content that can appear inside an XML element - either child elements or text.
+/// Content that can appear inside an XML element: child elements, text, CDATA
+/// sections or processing instructions.
 #[derive(Debug, Clone, PartialEq, Eq, facet::Facet)]
 #[repr(u8)]
 pub enum Content {
@@ -54,6 +69,12 @@ pub enum Content {
     /// A child element (catch-all for any tag name).
     #[facet(xml::custom_element)]
     Element(Element),
+    /// A CDATA section, kept apart from text so it's written back as one.
+    #[facet(xml::cdata)]
+    CData(String),
+    /// A processing instruction (`<?target data?>`).
+    #[facet(xml::processing_instruction)]
+    ProcessingInstruction(xml::ProcessingInstruction),
 }
 
 impl Content {
@@ -65,6 +86,14 @@ impl Content {
         }
     }
 
+    /// Returns `Some(&str)` if this is a CDATA section.
+    pub fn as_cdata(&self) -> Option<&str> {
+        match self {
+            Content::CData(t) => Some(t),
+            _ => None,
+        }
+    }
+
     /// Returns `Some(&Element)` if this is an element.
     pub fn as_element(&self) -> Option<&Element> {
         match self {
@@ -72,6 +101,14 @@ impl Content {
             _ => None,
         }
     }
+
+    /// Returns `Some(&mut Element)` if this is an element.
+    pub fn as_element_mut(&mut self) -> Option<&mut Element> {
+        match self {
+            Content::Element(e) => Some(e),
+            _ => None,
+        }
+    }
 }
 
 /// An XML element that captures any tag name, attributes, and children.
@@ -116,6 +153,33 @@ impl Element {
         self
     }
 
+    /// Add an attribute, replacing any previous value.
+    ///
+    /// ```
+    /// use facet_xml_node::Element;
+    ///
+    /// let link = Element::new("a")
+    ///     .attr("href", "/docs")
+    ///     .child("Read the ")
+    ///     .child(Element::new("em").child("docs"));
+    /// assert_eq!(link.to_html(), r#"<a href="/docs">Read the <em>docs</em></a>"#);
+    /// ```
+    pub fn attr(self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.with_attr(name, value)
+    }
+
+    /// Add a child: an [`Element`], text (`&str` or `String`), or any other [`Content`].
+    pub fn child(mut self, child: impl Into<Content>) -> Self {
+        self.children.push(child.into());
+        self
+    }
+
+    /// Add several children, in order.
+    pub fn children<C: Into<Content>>(mut self, children: impl IntoIterator<Item = C>) -> Self {
+        self.children.extend(children.into_iter().map(Into::into));
+        self
+    }
+
     /// Add text content.
     pub fn with_text(mut self, text: impl Into<String>) -> Self {
         self.children.push(Content::Text(text.into()));
@@ -127,18 +191,100 @@ impl Element {
         self.attrs.get(name).map(|s| s.as_str())
     }
 
+    /// Set an attribute, returning its previous value.
+    pub fn set_attr(
+        &mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Option<String> {
+        self.attrs.insert(name.into(), value.into())
+    }
+
+    /// Remove an attribute, returning its value.
+    pub fn remove_attr(&mut self, name: &str) -> Option<String> {
+        self.attrs.remove(name)
+    }
+
+    /// Iterate over the attributes as `(name, value)` pairs, sorted by name.
+    pub fn attributes(&self) -> impl Iterator<Item = (&str, &str)> {
+        let mut attrs: Vec<_> = self
+            .attrs
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+        attrs.sort_unstable_by_key(|(k, _)| *k);
+        attrs.into_iter()
+    }
+
+    /// Append a child.
+    pub fn push_child(&mut self, child: impl Into<Content>) {
+        self.children.push(child.into());
+    }
+
+    /// Insert a child at `index`, shifting the later ones along.
+    ///
+    /// `index` may equal the number of children, which appends.
+    pub fn insert_child(
+        &mut self,
+        index: usize,
+        child: impl Into<Content>,
+    ) -> Result<(), PathError> {
+        let len = self.children.len();
+        if index > len {
+            return Err(PathError::IndexOutOfBounds {
+                path: vec![index],
+                index,
+                len,
+            });
+        }
+        self.children.insert(index, child.into());
+        Ok(())
+    }
+
+    /// Remove the child at `index` and return it.
+    pub fn remove_child(&mut self, index: usize) -> Result<Content, PathError> {
+        let len = self.children.len();
+        if index >= len {
+            return Err(PathError::IndexOutOfBounds {
+                path: vec![index],
+                index,
+                len,
+            });
+        }
+        Ok(self.children.remove(index))
+    }
+
+    /// Replace the child at `index`, returning the old one.
+    pub fn replace_child(
+        &mut self,
+        index: usize,
+        child: impl Into<Content>,
+    ) -> Result<Content, PathError> {
+        let len = self.children.len();
+        let slot = self
+            .children
+            .get_mut(index)
+            .ok_or_else(|| PathError::IndexOutOfBounds {
+                path: vec![index],
+                index,
+                len,
+            })?;
+        Ok(std::mem::replace(slot, child.into()))
+    }
+
     /// Iterate over child elements (skipping text nodes).
     pub fn child_elements(&self) -> impl Iterator<Item = &Element> {
         self.children.iter().filter_map(|c| c.as_element())
     }
 
-    /// Get the combined text content (concatenated from all text children).
+    /// Get the combined text content (concatenated from all text and CDATA children).
     pub fn text_content(&self) -> String {
         let mut result = String::new();
         for child in &self.children {
             match child {
-                Content::Text(t) => result.push_str(t),
+                Content::Text(t) | Content::CData(t) => result.push_str(t),
                 Content::Element(e) => result.push_str(&e.text_content()),
+                Content::ProcessingInstruction(_) => {}
             }
         }
         result
@@ -168,7 +314,7 @@ impl Element {
 
         match child {
             Content::Element(e) => e.get_content_mut(&path[1..]),
-            Content::Text(_) => Err(PathError::TextNodeHasNoChildren {
+            _ => Err(PathError::TextNodeHasNoChildren {
                 path: path.to_vec(),
             }),
         }
@@ -181,7 +327,7 @@ impl Element {
         }
         match self.get_content_mut(path)? {
             Content::Element(e) => Ok(&mut e.children),
-            Content::Text(_) => Err(PathError::TextNodeHasNoChildren {
+            _ => Err(PathError::TextNodeHasNoChildren {
                 path: path.to_vec(),
             }),
         }
@@ -194,7 +340,7 @@ impl Element {
         }
         match self.get_content_mut(path)? {
             Content::Element(e) => Ok(&mut e.attrs),
-            Content::Text(_) => Err(PathError::TextNodeHasNoChildren {
+            _ => Err(PathError::TextNodeHasNoChildren {
                 path: path.to_vec(),
             }),
         }
@@ -211,10 +357,8 @@ impl Element {
     pub fn write_html(&self, out: &mut String) {
         out.push('<');
         out.push_str(&self.tag);
-        // Sort attrs for deterministic output
-        let mut attr_list: Vec<_> = self.attrs.iter().collect();
-        attr_list.sort_by_key(|(k, _)| *k);
-        for (k, v) in attr_list {
+        // Sorted, for deterministic output
+        for (k, v) in self.attributes() {
             out.push(' ');
             out.push_str(k);
             out.push_str("=\"");
@@ -224,8 +368,10 @@ impl Element {
         out.push('>');
         for child in &self.children {
             match child {
-                Content::Text(s) => out.push_str(s),
+                Content::Text(s) | Content::CData(s) => out.push_str(&html_escape(s)),
                 Content::Element(e) => e.write_html(out),
+                // HTML has no processing instructions
+                Content::ProcessingInstruction(_) => {}
             }
         }
         out.push_str("</");
@@ -259,6 +405,45 @@ impl From<&str> for Content {
     }
 }
 
+/// Build an [`Element`] tree in place: a tag, optional `[attributes]`, and
+/// optional `{ children }`.
+///
+/// Tag and attribute names are identifiers or string literals (for names like
+/// `data-id`). Attribute values and children are expressions: children can be
+/// nested `element!` calls, text, or anything else that converts into
+/// [`Content`].
+///
+/// ```
+/// use facet_xml_node::element;
+///
+/// let items = ["one", "two"];
+/// let list = element!(ul [class = "menu", "data-count" = items.len().to_string()] {
+///     element!(li { "first" }),
+///     element!(li [id = "second"] { element!(em { items[1] }) }),
+///     element!(br),
+/// });
+/// assert_eq!(
+///     list.to_html(),
+///     r#"<ul class="menu" data-count="2"><li>first</li><li id="second"><em>two</em></li><br></br></ul>"#
+/// );
+/// ```
+#[macro_export]
+macro_rules! element {
+    (@name $name:ident) => {
+        stringify!($name)
+    };
+    (@name $name:literal) => {
+        $name
+    };
+    ($tag:tt $([$($name:tt = $value:expr),* $(,)?])? $({$($child:expr),* $(,)?})?) => {{
+        #[allow(unused_mut)]
+        let mut element = $crate::Element::new($crate::element!(@name $tag));
+        $($(element = element.attr($crate::element!(@name $name), $value);)*)?
+        $($(element = element.child($child);)*)?
+        element
+    }};
+}
+
 #[cfg(test)]
 mod tests {
     use std::{fmt::Display, str::FromStr};
@@ -282,6 +467,70 @@ mod tests {
         assert_eq!(child.text_content(), "hello world");
     }
 
+    #[test]
+    fn element_macro() {
+        let href = "/docs";
+        let link = element!(a [href = href, "aria-label" = "Docs"] {
+            "Read the ",
+            element!(em { "docs" }),
+        });
+        assert_eq!(
+            link,
+            Element::new("a")
+                .attr("href", "/docs")
+                .attr("aria-label", "Docs")
+                .child("Read the ")
+                .child(Element::new("em").child("docs"))
+        );
+
+        let empty = element!("my-widget" []);
+        assert_eq!(empty, Element::new("my-widget"));
+    }
+
+    #[test]
+    fn element_mutation_api() {
+        let mut list = Element::new("ul")
+            .attr("id", "menu")
+            .attr("class", "nav")
+            .children(["a", "c"].map(|t| Element::new("li").child(t)));
+        assert_eq!(
+            list.attributes().collect::<Vec<_>>(),
+            [("class", "nav"), ("id", "menu")]
+        );
+
+        list.insert_child(1, Element::new("li").child("b")).unwrap();
+        list.push_child(Element::new("li").child("d"));
+        let removed = list.remove_child(3).unwrap();
+        assert_eq!(removed.as_element().unwrap().text_content(), "d");
+        let old = list
+            .replace_child(0, Element::new("li").child("A"))
+            .unwrap();
+        assert_eq!(old.as_element().unwrap().text_content(), "a");
+
+        assert_eq!(list.set_attr("id", "main"), Some("menu".to_string()));
+        assert_eq!(list.remove_attr("class"), Some("nav".to_string()));
+        list.children[1]
+            .as_element_mut()
+            .unwrap()
+            .set_attr("class", "active");
+
+        assert_eq!(
+            list.to_html(),
+            r#"<ul id="main"><li>A</li><li class="active">b</li><li>c</li></ul>"#
+        );
+
+        assert_eq!(
+            list.insert_child(9, "x"),
+            Err(PathError::IndexOutOfBounds {
+                path: vec![9],
+                index: 9,
+                len: 3
+            })
+        );
+        assert!(list.remove_child(3).is_err());
+        assert!(list.replace_child(3, "x").is_err());
+    }
+
     #[test]
     fn parse_simple_xml() {
         let xml = r#"<root><child>hello</child></root>"#;
@@ -0,0 +1,194 @@
+//! Tree walking and link/image extraction for [`Element`] trees.
+//!
+//! Crawling and scraping mostly boil down to "give me every link on the page".
+//! [`Element::links`] and [`Element::images`] do that walk for you and resolve
+//! each URL against a base (see [`resolve_url`]), so callers never have to
+//! match on [`Content`] by hand.
+
+use crate::{Content, Element};
+
+/// Depth-first, pre-order iterator over an element and all its descendant
+/// elements. Returned by [`Element::descendants`].
+pub struct Descendants<'a> {
+    stack: Vec<&'a Element>,
+}
+
+impl<'a> Iterator for Descendants<'a> {
+    type Item = &'a Element;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let element = self.stack.pop()?;
+        // Push in reverse so the first child is visited next.
+        self.stack.extend(
+            element
+                .children
+                .iter()
+                .rev()
+                .filter_map(Content::as_element),
+        );
+        Some(element)
+    }
+}
+
+/// An element that references a URL, with that URL resolved against a base.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElementUrl<'a> {
+    /// The element carrying the URL (`<a>`, `<img>`, ...).
+    pub element: &'a Element,
+    /// The attribute value as written in the document.
+    pub raw: &'a str,
+    /// The URL resolved against the base given to [`Element::links`] or
+    /// [`Element::images`].
+    pub url: String,
+}
+
+impl Element {
+    /// Iterate over this element and all its descendant elements, depth-first
+    /// in document order.
+    pub fn descendants(&self) -> Descendants<'_> {
+        Descendants { stack: vec![self] }
+    }
+
+    /// Iterate over hyperlinks: the `href` of every `<a>`, `<area>` and
+    /// `<link>` element in this tree, resolved against `base`.
+    ///
+    /// ```
+    /// use facet_xml_node::Element;
+    ///
+    /// let html = r#"<body><a href="/docs">Docs</a><a href="../up">Up</a></body>"#;
+    /// let doc: Element = facet_xml::from_str(html).unwrap();
+    ///
+    /// let urls: Vec<String> = doc
+    ///     .links("https://example.com/blog/post.html")
+    ///     .map(|link| link.url)
+    ///     .collect();
+    /// assert_eq!(urls, ["https://example.com/docs", "https://example.com/up"]);
+    /// ```
+    pub fn links<'a>(&'a self, base: &'a str) -> impl Iterator<Item = ElementUrl<'a>> + 'a {
+        self.urls(base, &["a", "area", "link"], "href")
+    }
+
+    /// Iterate over images: the `src` of every `<img>` element in this tree,
+    /// resolved against `base`.
+    pub fn images<'a>(&'a self, base: &'a str) -> impl Iterator<Item = ElementUrl<'a>> + 'a {
+        self.urls(base, &["img"], "src")
+    }
+
+    fn urls<'a>(
+        &'a self,
+        base: &'a str,
+        tags: &'static [&'static str],
+        attr: &'static str,
+    ) -> impl Iterator<Item = ElementUrl<'a>> + 'a {
+        self.descendants()
+            .filter(move |element| tags.iter().any(|tag| element.tag.eq_ignore_ascii_case(tag)))
+            .filter_map(move |element| {
+                let raw = element.get_attr(attr)?;
+                Some(ElementUrl {
+                    element,
+                    raw,
+                    url: resolve_url(base, raw),
+                })
+            })
+    }
+}
+
+/// Resolves `reference` against `base`, following RFC 3986 section 5.
+///
+/// References that already have a scheme are returned as is, and so is every
+/// reference when `base` is empty or isn't a hierarchical URL (one with a
+/// `scheme://authority` part).
+///
+/// ```
+/// use facet_xml_node::resolve_url;
+///
+/// let base = "https://example.com/a/b?q#top";
+/// assert_eq!(resolve_url(base, "c"), "https://example.com/a/c");
+/// assert_eq!(resolve_url(base, "../c"), "https://example.com/c");
+/// assert_eq!(resolve_url(base, "//cdn.example.com/x"), "https://cdn.example.com/x");
+/// assert_eq!(resolve_url(base, "#end"), "https://example.com/a/b?q#end");
+/// assert_eq!(resolve_url(base, "mailto:me@example.com"), "mailto:me@example.com");
+/// ```
+pub fn resolve_url(base: &str, reference: &str) -> String {
+    let reference = reference.trim();
+    if scheme(reference).is_some() {
+        return reference.to_string();
+    }
+    let Some(scheme) = scheme(base) else {
+        return reference.to_string();
+    };
+    let Some(after_slashes) = base[scheme.len() + 1..].strip_prefix("//") else {
+        return reference.to_string();
+    };
+
+    let authority_end = after_slashes
+        .find(['/', '?', '#'])
+        .unwrap_or(after_slashes.len());
+    let authority = &after_slashes[..authority_end];
+    let rest = &after_slashes[authority_end..];
+    let path_end = rest.find(['?', '#']).unwrap_or(rest.len());
+    let base_path = &rest[..path_end];
+    let base_without_fragment = base.split('#').next().unwrap_or(base);
+
+    if let Some(network_path) = reference.strip_prefix("//") {
+        return format!("{scheme}://{network_path}");
+    }
+    if reference.is_empty() {
+        return base_without_fragment.to_string();
+    }
+    if reference.starts_with('#') {
+        return format!("{base_without_fragment}{reference}");
+    }
+    if reference.starts_with('?') {
+        return format!("{scheme}://{authority}{base_path}{reference}");
+    }
+
+    let split = reference.find(['?', '#']).unwrap_or(reference.len());
+    let (path, suffix) = reference.split_at(split);
+    let merged = if path.starts_with('/') {
+        path.to_string()
+    } else {
+        // Replace everything after the base path's last slash.
+        let dir = &base_path[..base_path.rfind('/').map_or(0, |slash| slash + 1)];
+        if dir.is_empty() {
+            format!("/{path}")
+        } else {
+            format!("{dir}{path}")
+        }
+    };
+    format!(
+        "{scheme}://{authority}{}{suffix}",
+        remove_dot_segments(&merged)
+    )
+}
+
+/// Returns the scheme of `url`, if it has one.
+fn scheme(url: &str) -> Option<&str> {
+    let (scheme, _) = url.split_once(':')?;
+    let mut chars = scheme.chars();
+    let valid = chars.next()?.is_ascii_alphabetic()
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    valid.then_some(scheme)
+}
+
+/// Removes `.` and `..` segments from an absolute path.
+fn remove_dot_segments(path: &str) -> String {
+    let segments: Vec<&str> = path.split('/').collect();
+    let last = segments.len() - 1;
+    let mut out: Vec<&str> = Vec::with_capacity(segments.len());
+    for (index, segment) in segments.into_iter().enumerate() {
+        match segment {
+            "." | ".." => {
+                // Never pop the empty segment before the leading slash.
+                if segment == ".." && out.len() > 1 {
+                    out.pop();
+                }
+                if index == last {
+                    out.push("");
+                }
+            }
+            segment => out.push(segment),
+        }
+    }
+    out.join("/")
+}
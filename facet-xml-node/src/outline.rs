@@ -0,0 +1,241 @@
+//! Document outline and `<meta>` tags for HTML-like [`Element`] trees.
+//!
+//! SEO tools and site generators keep asking the same two questions of a
+//! page: what its heading structure is, and what its meta tags say.
+//! [`Element::outline`] answers the first and [`Element::meta`] (with
+//! [`Element::set_meta`] for writing) the second.
+
+use std::collections::BTreeMap;
+
+use crate::{Content, Element};
+
+/// A heading in a document outline, with the headings nested under it.
+/// Returned by [`Element::outline`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heading<'a> {
+    /// The heading's rank, 1 for `<h1>` through 6 for `<h6>`.
+    pub level: u8,
+    /// The heading's text, with runs of whitespace collapsed to one space.
+    pub text: String,
+    /// The heading's `id` attribute, for linking to it.
+    pub id: Option<&'a str>,
+    /// Child indices from the root to the heading element. Same convention
+    /// as [`Element::get_content_mut`].
+    pub path: Vec<usize>,
+    /// The headings of lower rank that follow this one, up to the next
+    /// heading of the same or higher rank.
+    pub children: Vec<Heading<'a>>,
+}
+
+impl Element {
+    /// The heading hierarchy of the document: every `<h1>`…`<h6>` in
+    /// document order, each nested under the closest preceding heading of
+    /// higher rank.
+    ///
+    /// Skipped levels are tolerated (an `<h4>` right after an `<h2>` becomes
+    /// its child), and in an `<hgroup>` only the first heading counts, the
+    /// others being subtitles.
+    ///
+    /// ```
+    /// use facet_xml_node::Element;
+    ///
+    /// let html = r#"<body><h1>Guide</h1><h2 id="setup">Setup</h2><h3>Linux</h3><h2>Usage</h2></body>"#;
+    /// let doc: Element = facet_xml::from_str(html).unwrap();
+    ///
+    /// let outline = doc.outline();
+    /// assert_eq!(outline.len(), 1);
+    /// let guide = &outline[0];
+    /// assert_eq!(guide.text, "Guide");
+    /// assert_eq!(guide.children[0].id, Some("setup"));
+    /// assert_eq!(guide.children[0].children[0].text, "Linux");
+    /// assert_eq!(guide.children[1].text, "Usage");
+    /// ```
+    pub fn outline(&self) -> Vec<Heading<'_>> {
+        let mut headings = Vec::new();
+        collect_headings(self, &mut Vec::new(), &mut headings);
+
+        // Nest with a stack of open headings, lowest rank on top
+        let mut roots: Vec<Heading<'_>> = Vec::new();
+        let mut open: Vec<Heading<'_>> = Vec::new();
+        for heading in headings {
+            while open.last().is_some_and(|top| top.level >= heading.level) {
+                close_heading(&mut open, &mut roots);
+            }
+            open.push(heading);
+        }
+        while !open.is_empty() {
+            close_heading(&mut open, &mut roots);
+        }
+        roots
+    }
+
+    /// The `content` of the `<meta>` tag with this `name` (`description`,
+    /// `viewport`) or `property` (`og:title`), compared case-insensitively.
+    pub fn meta(&self, name: &str) -> Option<&str> {
+        self.descendants()
+            .find(|element| is_meta_named(element, name))
+            .and_then(|element| element.get_attr("content"))
+    }
+
+    /// Set the `content` of the `<meta>` tag with this name or property,
+    /// returning the previous content.
+    ///
+    /// A missing tag is added at the end of `<head>`, creating the `<head>`
+    /// at the start of `<html>` if need be, or appended to this element when
+    /// the tree has neither. Names starting with `og:` are written as
+    /// `property`, the others as `name`.
+    ///
+    /// ```
+    /// use facet_xml_node::Element;
+    ///
+    /// let mut doc: Element =
+    ///     facet_xml::from_str("<html><head><title>Home</title></head></html>").unwrap();
+    /// doc.set_meta("description", "Welcome");
+    /// doc.set_meta("og:title", "Home");
+    /// assert_eq!(doc.meta("description"), Some("Welcome"));
+    /// assert_eq!(
+    ///     doc.to_html(),
+    ///     r#"<html><head><title>Home</title><meta content="Welcome" name="description"></meta><meta content="Home" property="og:title"></meta></head></html>"#
+    /// );
+    /// ```
+    pub fn set_meta(&mut self, name: &str, content: impl Into<String>) -> Option<String> {
+        let content = content.into();
+        if let Some(meta) = find_mut(self, &|element| is_meta_named(element, name)) {
+            return meta.set_attr("content", content);
+        }
+
+        let key = if name.starts_with("og:") {
+            "property"
+        } else {
+            "name"
+        };
+        let meta = Element::new("meta")
+            .attr(key, name)
+            .attr("content", content);
+        if let Some(head) = find_mut(self, &|element| element.tag.eq_ignore_ascii_case("head")) {
+            head.push_child(meta);
+        } else if self.tag.eq_ignore_ascii_case("html") {
+            self.children
+                .insert(0, Element::new("head").child(meta).into());
+        } else {
+            self.push_child(meta);
+        }
+        None
+    }
+
+    /// The page description, from `<meta name="description">`.
+    pub fn description(&self) -> Option<&str> {
+        self.meta("description")
+    }
+
+    /// Every Open Graph property (`og:title`, `og:image`, ...) with its
+    /// content, keyed by the property name. When a property repeats, the
+    /// first one wins.
+    pub fn open_graph(&self) -> BTreeMap<&str, &str> {
+        let mut properties = BTreeMap::new();
+        for element in self.descendants() {
+            if element.tag.eq_ignore_ascii_case("meta")
+                && let Some(property) = element.get_attr("property")
+                && property.starts_with("og:")
+                && let Some(content) = element.get_attr("content")
+            {
+                properties.entry(property).or_insert(content);
+            }
+        }
+        properties
+    }
+
+    /// The viewport settings, from `<meta name="viewport">`, as
+    /// `(key, value)` pairs in the order written: `width=device-width,
+    /// initial-scale=1` gives `[("width", "device-width"), ("initial-scale",
+    /// "1")]`. Keys without a value get an empty one.
+    pub fn viewport(&self) -> Option<Vec<(&str, &str)>> {
+        let content = self.meta("viewport")?;
+        Some(
+            content
+                .split([',', ';'])
+                .map(str::trim)
+                .filter(|setting| !setting.is_empty())
+                .map(|setting| match setting.split_once('=') {
+                    Some((key, value)) => (key.trim(), value.trim()),
+                    None => (setting, ""),
+                })
+                .collect(),
+        )
+    }
+}
+
+/// The rank of a heading tag, `h1` to `h6`.
+fn heading_level(tag: &str) -> Option<u8> {
+    match tag.as_bytes() {
+        [b'h' | b'H', digit @ b'1'..=b'6'] => Some(digit - b'0'),
+        _ => None,
+    }
+}
+
+/// Find headings in document order, without looking inside them.
+fn collect_headings<'a>(element: &'a Element, path: &mut Vec<usize>, out: &mut Vec<Heading<'a>>) {
+    if let Some(level) = heading_level(&element.tag) {
+        out.push(Heading {
+            level,
+            text: element
+                .text_content()
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" "),
+            id: element.get_attr("id"),
+            path: path.clone(),
+            children: Vec::new(),
+        });
+        return;
+    }
+
+    let is_hgroup = element.tag.eq_ignore_ascii_case("hgroup");
+    for (index, child) in element.children.iter().enumerate() {
+        let Content::Element(child) = child else {
+            continue;
+        };
+        path.push(index);
+        let before = out.len();
+        collect_headings(child, path, out);
+        path.pop();
+        // The rest of an hgroup are subtitles
+        if is_hgroup && out.len() > before {
+            break;
+        }
+    }
+}
+
+/// Pop the top open heading into its parent, or into the roots.
+fn close_heading<'a>(open: &mut Vec<Heading<'a>>, roots: &mut Vec<Heading<'a>>) {
+    if let Some(heading) = open.pop() {
+        match open.last_mut() {
+            Some(parent) => parent.children.push(heading),
+            None => roots.push(heading),
+        }
+    }
+}
+
+fn is_meta_named(element: &Element, name: &str) -> bool {
+    element.tag.eq_ignore_ascii_case("meta")
+        && ["name", "property"].iter().any(|attr| {
+            element
+                .get_attr(attr)
+                .is_some_and(|value| value.eq_ignore_ascii_case(name))
+        })
+}
+
+/// The first element in the tree, depth-first, that matches.
+fn find_mut<'a>(
+    element: &'a mut Element,
+    matches: &dyn Fn(&Element) -> bool,
+) -> Option<&'a mut Element> {
+    if matches(element) {
+        return Some(element);
+    }
+    element
+        .children
+        .iter_mut()
+        .filter_map(Content::as_element_mut)
+        .find_map(|child| find_mut(child, matches))
+}
@@ -111,6 +111,15 @@ impl<'a> ElementParser<'a> {
                             Content::Text(t) => {
                                 return Ok(Some(DomEvent::Text(Cow::Owned(t.clone()))));
                             }
+                            Content::CData(t) => {
+                                return Ok(Some(DomEvent::CData(Cow::Owned(t.clone()))));
+                            }
+                            Content::ProcessingInstruction(pi) => {
+                                return Ok(Some(DomEvent::ProcessingInstruction {
+                                    target: Cow::Owned(pi.target.clone()),
+                                    data: Cow::Owned(pi.data.clone()),
+                                }));
+                            }
                             Content::Element(e) => {
                                 // Push new frame for child element
                                 self.stack.push(Frame {
@@ -296,6 +305,22 @@ impl DomSerializer for ElementSerializer {
         Ok(())
     }
 
+    fn cdata(&mut self, content: &str) -> Result<(), Self::Error> {
+        let elem = self.stack.last_mut().ok_or(ElementSerializeError)?;
+        elem.children.push(Content::CData(content.to_string()));
+        Ok(())
+    }
+
+    fn processing_instruction(&mut self, target: &str, data: &str) -> Result<(), Self::Error> {
+        // Instructions outside the root element have nowhere to go in an Element tree
+        if let Some(elem) = self.stack.last_mut() {
+            elem.children.push(Content::ProcessingInstruction(
+                facet_xml::ProcessingInstruction::new(target, data),
+            ));
+        }
+        Ok(())
+    }
+
     fn format_namespace(&self) -> Option<&'static str> {
         Some("xml")
     }
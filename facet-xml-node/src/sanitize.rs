@@ -0,0 +1,310 @@
+//! Allow-list sanitizing of HTML-like [`Element`] trees.
+//!
+//! User-supplied markup (comments, profile bios, imported posts) can be parsed
+//! into an [`Element`], passed through a [`Sanitizer`] and written back with
+//! [`Element::to_html`] without carrying scripts or event handlers along.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{Content, Element};
+
+/// Tags kept by [`Sanitizer::new`]: text-level and structural markup, no
+/// forms, embeds or scripting.
+const DEFAULT_TAGS: &[&str] = &[
+    "a",
+    "abbr",
+    "area",
+    "b",
+    "blockquote",
+    "br",
+    "caption",
+    "code",
+    "dd",
+    "del",
+    "dir",
+    "div",
+    "dl",
+    "dt",
+    "em",
+    "figcaption",
+    "figure",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "hgroup",
+    "hr",
+    "i",
+    "img",
+    "ins",
+    "kbd",
+    "li",
+    "map",
+    "mark",
+    "menu",
+    "ol",
+    "p",
+    "pre",
+    "q",
+    "s",
+    "search",
+    "small",
+    "span",
+    "strong",
+    "sub",
+    "sup",
+    "table",
+    "tbody",
+    "td",
+    "tfoot",
+    "th",
+    "thead",
+    "tr",
+    "u",
+    "ul",
+];
+
+/// Attributes kept on every allowed tag by [`Sanitizer::new`].
+const DEFAULT_ATTRIBUTES: &[&str] = &["class", "dir", "id", "lang", "title"];
+
+/// Attributes kept on specific tags by [`Sanitizer::new`].
+const DEFAULT_TAG_ATTRIBUTES: &[(&str, &[&str])] = &[
+    ("a", &["href", "rel", "target"]),
+    ("area", &["alt", "coords", "href", "rel", "shape", "target"]),
+    (
+        "img",
+        &["alt", "height", "src", "srcset", "usemap", "width"],
+    ),
+    ("map", &["name"]),
+    ("q", &["cite"]),
+    ("blockquote", &["cite"]),
+    ("ins", &["cite", "datetime"]),
+    ("del", &["cite", "datetime"]),
+    ("td", &["colspan", "rowspan"]),
+    ("th", &["colspan", "rowspan", "scope"]),
+];
+
+/// URL schemes kept by [`Sanitizer::new`]. Relative URLs are always kept.
+const DEFAULT_URL_SCHEMES: &[&str] = &["http", "https", "mailto"];
+
+/// Tags removed together with their content by [`Sanitizer::new`], rather
+/// than unwrapped.
+const DEFAULT_DROPPED_TAGS: &[&str] = &[
+    "iframe", "noscript", "object", "script", "style", "template", "textarea",
+];
+
+/// Attributes whose value is a URL, checked against the allowed schemes.
+const URL_ATTRIBUTES: &[&str] = &[
+    "action",
+    "background",
+    "cite",
+    "data",
+    "formaction",
+    "href",
+    "poster",
+    "src",
+    "xlink:href",
+];
+
+/// An allow-list HTML sanitizer.
+///
+/// Elements whose tag isn't allowed are unwrapped: their children take their
+/// place. Elements in the dropped set (`<script>`, `<style>`, ...) are removed
+/// with everything inside them. Attributes not allowed for their tag are
+/// removed, and so are URL attributes (`href`, `src`, each URL of a
+/// `srcset`...) whose scheme isn't allowed. Event handlers (`on*`) are always
+/// removed, whatever the allow-list says, and processing instructions, which
+/// HTML doesn't have, are dropped.
+///
+/// Tag and attribute names compare case-insensitively. The element passed to
+/// [`Sanitizer::sanitize`] is the container of the user content: its own tag
+/// and attributes are left alone.
+///
+/// ```
+/// use facet_xml_node::{Element, Sanitizer};
+///
+/// let input = r#"<div><p onclick="steal()">Hi <a href="javascript:alert(1)">there</a></p><script>alert(2)</script></div>"#;
+/// let mut doc: Element = facet_xml::from_str(input).unwrap();
+///
+/// Sanitizer::new().sanitize(&mut doc);
+/// assert_eq!(doc.to_html(), "<div><p>Hi<a>there</a></p></div>");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sanitizer {
+    tags: BTreeSet<String>,
+    attributes: BTreeSet<String>,
+    tag_attributes: BTreeMap<String, BTreeSet<String>>,
+    url_schemes: BTreeSet<String>,
+    dropped_tags: BTreeSet<String>,
+}
+
+impl Default for Sanitizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sanitizer {
+    /// A sanitizer with a conservative allow-list for user content: text and
+    /// structural markup, links and images over `http`, `https` and `mailto`,
+    /// and `class`, `id`, `lang`, `dir` and `title` everywhere.
+    pub fn new() -> Self {
+        let mut sanitizer = Self::empty()
+            .allow_tags(DEFAULT_TAGS.iter().copied())
+            .allow_attributes(DEFAULT_ATTRIBUTES.iter().copied())
+            .allow_url_schemes(DEFAULT_URL_SCHEMES.iter().copied())
+            .drop_tags(DEFAULT_DROPPED_TAGS.iter().copied());
+        for (tag, attributes) in DEFAULT_TAG_ATTRIBUTES {
+            sanitizer = sanitizer.allow_tag_attributes(tag, attributes.iter().copied());
+        }
+        sanitizer
+    }
+
+    /// A sanitizer that allows nothing: every element is unwrapped down to
+    /// its text, and no URL scheme is allowed. Build up from here with the
+    /// `allow_*` methods.
+    pub fn empty() -> Self {
+        Self {
+            tags: BTreeSet::new(),
+            attributes: BTreeSet::new(),
+            tag_attributes: BTreeMap::new(),
+            url_schemes: BTreeSet::new(),
+            dropped_tags: BTreeSet::new(),
+        }
+    }
+
+    /// Allow these tags.
+    pub fn allow_tags<S: AsRef<str>>(mut self, tags: impl IntoIterator<Item = S>) -> Self {
+        self.tags.extend(tags.into_iter().map(lowercase));
+        self
+    }
+
+    /// Allow these attributes on every allowed tag.
+    pub fn allow_attributes<S: AsRef<str>>(
+        mut self,
+        attributes: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.attributes
+            .extend(attributes.into_iter().map(lowercase));
+        self
+    }
+
+    /// Allow these attributes on `tag` only.
+    pub fn allow_tag_attributes<S: AsRef<str>>(
+        mut self,
+        tag: &str,
+        attributes: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.tag_attributes
+            .entry(lowercase(tag))
+            .or_default()
+            .extend(attributes.into_iter().map(lowercase));
+        self
+    }
+
+    /// Allow URLs with these schemes (`https`, `mailto`, ...).
+    pub fn allow_url_schemes<S: AsRef<str>>(
+        mut self,
+        schemes: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.url_schemes.extend(schemes.into_iter().map(lowercase));
+        self
+    }
+
+    /// Remove these tags with their content instead of unwrapping them.
+    pub fn drop_tags<S: AsRef<str>>(mut self, tags: impl IntoIterator<Item = S>) -> Self {
+        self.dropped_tags.extend(tags.into_iter().map(lowercase));
+        self
+    }
+
+    /// Stop allowing these tags. They are unwrapped, or dropped if they were
+    /// added with [`Sanitizer::drop_tags`].
+    pub fn deny_tags<S: AsRef<str>>(mut self, tags: impl IntoIterator<Item = S>) -> Self {
+        for tag in tags {
+            self.tags.remove(&lowercase(tag));
+        }
+        self
+    }
+
+    /// Sanitize the content of `element` in place.
+    pub fn sanitize(&self, element: &mut Element) {
+        let children = std::mem::take(&mut element.children);
+        element.children = self.sanitize_children(children);
+    }
+
+    fn sanitize_children(&self, children: Vec<Content>) -> Vec<Content> {
+        let mut out = Vec::with_capacity(children.len());
+        for child in children {
+            match child {
+                Content::Element(mut child) => {
+                    let tag = child.tag.to_ascii_lowercase();
+                    if self.dropped_tags.contains(&tag) {
+                        continue;
+                    }
+                    let children = std::mem::take(&mut child.children);
+                    let children = self.sanitize_children(children);
+                    if self.tags.contains(&tag) {
+                        self.sanitize_attributes(&tag, &mut child);
+                        child.children = children;
+                        out.push(Content::Element(child));
+                    } else {
+                        out.extend(children);
+                    }
+                }
+                Content::ProcessingInstruction(_) => {}
+                text @ (Content::Text(_) | Content::CData(_)) => out.push(text),
+            }
+        }
+        out
+    }
+
+    fn sanitize_attributes(&self, tag: &str, element: &mut Element) {
+        let tag_attributes = self.tag_attributes.get(tag);
+        element.attrs.retain(|name, value| {
+            let name = name.to_ascii_lowercase();
+            if name.starts_with("on") {
+                return false;
+            }
+            let allowed = self.attributes.contains(&name)
+                || tag_attributes.is_some_and(|attributes| attributes.contains(&name));
+            if !allowed {
+                return false;
+            }
+            if name == "srcset" {
+                facet_dom::parse_srcset(value)
+                    .iter()
+                    .all(|candidate| self.is_allowed_url(candidate.url))
+            } else if URL_ATTRIBUTES.contains(&name.as_str()) {
+                self.is_allowed_url(value)
+            } else {
+                true
+            }
+        });
+    }
+
+    /// Relative URLs are allowed; absolute ones need an allowed scheme.
+    fn is_allowed_url(&self, url: &str) -> bool {
+        // Browsers ignore tabs and newlines anywhere in a URL and leading
+        // control characters and spaces, so `java\tscript:` is `javascript:`.
+        let url: String = url
+            .trim_start_matches(|c: char| c <= ' ')
+            .chars()
+            .filter(|c| !matches!(c, '\t' | '\n' | '\r'))
+            .collect();
+        let Some(colon) = url.find(':') else {
+            return true;
+        };
+        if url[..colon].contains(['/', '?', '#']) {
+            // The colon is in the path, query or fragment of a relative URL
+            return true;
+        }
+        self.url_schemes
+            .contains(&url[..colon].to_ascii_lowercase())
+    }
+}
+
+fn lowercase(name: impl AsRef<str>) -> String {
+    name.as_ref().to_ascii_lowercase()
+}
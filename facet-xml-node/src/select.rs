@@ -0,0 +1,789 @@
+//! CSS selector queries over [`Element`] trees.
+//!
+//! [`Element::select`] finds the elements matching a selector such as
+//! `div.container > p` in document order, so scraping and test code can say
+//! what it is looking for instead of matching on [`Content`] by hand.
+//!
+//! The supported grammar is the part of Selectors Level 4 that makes sense on a
+//! static tree:
+//!
+//! - type, universal, `#id` and `.class` selectors;
+//! - attribute selectors (`[a]`, `[a=v]`, `~=`, `|=`, `^=`, `$=`, `*=`, with an
+//!   optional `i` flag for case-insensitive values);
+//! - the descendant, `>`, `+` and `~` combinators, and `,` lists;
+//! - `:root`, `:empty`, `:first-child`, `:last-child`, `:only-child`,
+//!   `:nth-child()`, `:nth-last-child()`, the `-of-type` variants of those, and
+//!   `:not()`.
+//!
+//! Tag and attribute names compare ASCII case-insensitively, as in HTML.
+//! Combinators never look above the element `select` was called on.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Content, Element};
+
+/// A parsed selector list, ready to match against many trees.
+///
+/// ```
+/// use facet_xml_node::{Element, Selector};
+///
+/// let selector: Selector = "li.done, li[aria-checked=true]".parse().unwrap();
+///
+/// let list: Element = facet_xml::from_str(
+///     r#"<ul><li class="done">a</li><li>b</li><li aria-checked="true">c</li></ul>"#,
+/// )
+/// .unwrap();
+/// let done: Vec<String> = list.select_with(&selector).map(Element::text_content).collect();
+/// assert_eq!(done, ["a", "c"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selector {
+    alternatives: Vec<Complex>,
+}
+
+/// Why a selector couldn't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectorError {
+    /// The selector as given.
+    pub selector: String,
+    /// Byte offset of the problem in `selector`.
+    pub position: usize,
+    /// What was expected there.
+    pub reason: &'static str,
+}
+
+impl fmt::Display for SelectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid selector `{}` at byte {}: {}",
+            self.selector, self.position, self.reason
+        )
+    }
+}
+
+impl std::error::Error for SelectorError {}
+
+/// Compound selectors joined by combinators, leftmost first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Complex {
+    compounds: Vec<Compound>,
+    /// `combinators[i]` sits between `compounds[i]` and `compounds[i + 1]`.
+    combinators: Vec<Combinator>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Combinator {
+    Descendant,
+    Child,
+    NextSibling,
+    SubsequentSibling,
+}
+
+/// Simple selectors that all apply to the same element.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+struct Compound {
+    /// `None` for `*` or when no type selector was written.
+    tag: Option<String>,
+    simple: Vec<Simple>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Simple {
+    Id(String),
+    Class(String),
+    Attribute {
+        name: String,
+        test: Option<(AttrOp, String)>,
+        ignore_case: bool,
+    },
+    Root,
+    Empty,
+    /// `:only-child`, or `:only-of-type` with `of_type`.
+    Only {
+        of_type: bool,
+    },
+    /// `:nth-child()` and friends: `from_end` for the `-last-` forms,
+    /// `of_type` for the `-of-type` forms.
+    Nth {
+        nth: Nth,
+        from_end: bool,
+        of_type: bool,
+    },
+    Not(Selector),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AttrOp {
+    /// `=`
+    Equals,
+    /// `~=`: one of the whitespace-separated words
+    Includes,
+    /// `|=`: the value or the value followed by `-`
+    DashMatch,
+    /// `^=`
+    Prefix,
+    /// `$=`
+    Suffix,
+    /// `*=`
+    Substring,
+}
+
+/// The `an+b` of `:nth-child()`: matches 1-based positions `a*n + b` for some
+/// `n >= 0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Nth {
+    a: i64,
+    b: i64,
+}
+
+impl Nth {
+    const FIRST: Nth = Nth { a: 0, b: 1 };
+
+    fn matches(self, position: i64) -> bool {
+        if self.a == 0 {
+            return position == self.b;
+        }
+        let offset = position - self.b;
+        offset % self.a == 0 && offset / self.a >= 0
+    }
+}
+
+impl Selector {
+    /// Parse a comma-separated selector list.
+    pub fn parse(selector: &str) -> Result<Self, SelectorError> {
+        let mut parser = Parser {
+            input: selector,
+            pos: 0,
+        };
+        let parsed = parser.selector_list()?;
+        parser.skip_whitespace();
+        if parser.pos < selector.len() {
+            return Err(parser.error("unexpected character"));
+        }
+        Ok(parsed)
+    }
+
+    /// Whether `element` matches, taken on its own: combinators and structural
+    /// pseudo-classes see it as the root of its tree. Use
+    /// [`Element::select_with`] to match in context.
+    pub fn matches(&self, element: &Element) -> bool {
+        self.matches_at(element, &[], &[], 0)
+    }
+
+    /// Whether `element` matches, given its ancestors (root first), the child
+    /// index of each ancestor but the first within its parent, and the child
+    /// index of `element` within the last ancestor.
+    fn matches_at(
+        &self,
+        element: &Element,
+        ancestors: &[&Element],
+        outer: &[usize],
+        index: usize,
+    ) -> bool {
+        self.alternatives.iter().any(|complex| {
+            let last = complex.compounds.len() - 1;
+            complex.matches_at(last, element, ancestors, outer, index)
+        })
+    }
+}
+
+impl FromStr for Selector {
+    type Err = SelectorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl Complex {
+    /// Match `compounds[..=i]` with `compounds[i]` on `element`, backtracking
+    /// over the ancestors and siblings the combinators allow.
+    fn matches_at(
+        &self,
+        i: usize,
+        element: &Element,
+        ancestors: &[&Element],
+        outer: &[usize],
+        index: usize,
+    ) -> bool {
+        if !self.compounds[i].matches(element, ancestors, index) {
+            return false;
+        }
+        if i == 0 {
+            return true;
+        }
+
+        // The ancestor at `depth`, with its own ancestors, their indices and
+        // its index, ready to recurse on.
+        let up = |depth: usize| {
+            let outer_len = depth.saturating_sub(1);
+            let index = if depth == 0 { 0 } else { outer[depth - 1] };
+            (
+                ancestors[depth],
+                &ancestors[..depth],
+                &outer[..outer_len],
+                index,
+            )
+        };
+
+        match self.combinators[i - 1] {
+            Combinator::Child => {
+                let Some(depth) = ancestors.len().checked_sub(1) else {
+                    return false;
+                };
+                let (parent, ancestors, outer, index) = up(depth);
+                self.matches_at(i - 1, parent, ancestors, outer, index)
+            }
+            Combinator::Descendant => (0..ancestors.len()).rev().any(|depth| {
+                let (ancestor, ancestors, outer, index) = up(depth);
+                self.matches_at(i - 1, ancestor, ancestors, outer, index)
+            }),
+            Combinator::NextSibling | Combinator::SubsequentSibling => {
+                let Some(parent) = ancestors.last() else {
+                    return false;
+                };
+                let mut earlier = parent.children[..index]
+                    .iter()
+                    .enumerate()
+                    .rev()
+                    .filter_map(|(index, child)| Some((index, child.as_element()?)));
+                if self.combinators[i - 1] == Combinator::NextSibling {
+                    earlier.next().is_some_and(|(index, sibling)| {
+                        self.matches_at(i - 1, sibling, ancestors, outer, index)
+                    })
+                } else {
+                    earlier.any(|(index, sibling)| {
+                        self.matches_at(i - 1, sibling, ancestors, outer, index)
+                    })
+                }
+            }
+        }
+    }
+}
+
+impl Compound {
+    fn matches(&self, element: &Element, ancestors: &[&Element], index: usize) -> bool {
+        if let Some(tag) = &self.tag
+            && !element.tag.eq_ignore_ascii_case(tag)
+        {
+            return false;
+        }
+        self.simple
+            .iter()
+            .all(|simple| simple.matches(element, ancestors, index))
+    }
+}
+
+impl Simple {
+    fn matches(&self, element: &Element, ancestors: &[&Element], index: usize) -> bool {
+        match self {
+            Simple::Id(id) => element.get_attr("id") == Some(id.as_str()),
+            Simple::Class(class) => element
+                .get_attr("class")
+                .is_some_and(|classes| classes.split_ascii_whitespace().any(|c| c == class)),
+            Simple::Attribute {
+                name,
+                test,
+                ignore_case,
+            } => {
+                let Some(value) = element
+                    .attributes()
+                    .find(|(attr, _)| attr.eq_ignore_ascii_case(name))
+                    .map(|(_, value)| value)
+                else {
+                    return false;
+                };
+                let Some((op, expected)) = test else {
+                    return true;
+                };
+                if *ignore_case {
+                    op.matches(&value.to_ascii_lowercase(), &expected.to_ascii_lowercase())
+                } else {
+                    op.matches(value, expected)
+                }
+            }
+            Simple::Root => ancestors.is_empty(),
+            Simple::Empty => element.children.iter().all(|child| match child {
+                Content::Text(text) | Content::CData(text) => text.is_empty(),
+                Content::Element(_) => false,
+                Content::ProcessingInstruction(_) => true,
+            }),
+            Simple::Nth {
+                nth,
+                from_end,
+                of_type,
+            } => {
+                // An element without a parent is the only child of its tree.
+                let Some(parent) = ancestors.last() else {
+                    return nth.matches(1);
+                };
+                let (before, after) = parent.children.split_at(index);
+                let counted = |content: &Content| {
+                    content
+                        .as_element()
+                        .is_some_and(|e| !*of_type || e.tag.eq_ignore_ascii_case(&element.tag))
+                };
+                let position = if *from_end {
+                    after[1..].iter().filter(|&c| counted(c)).count()
+                } else {
+                    before.iter().filter(|&c| counted(c)).count()
+                };
+                nth.matches(position as i64 + 1)
+            }
+            Simple::Only { of_type } => {
+                let Some(parent) = ancestors.last() else {
+                    return true;
+                };
+                parent
+                    .child_elements()
+                    .filter(|e| !*of_type || e.tag.eq_ignore_ascii_case(&element.tag))
+                    .count()
+                    == 1
+            }
+            // `:not()` only takes compound selectors, so combinators never
+            // need to be followed from here.
+            Simple::Not(selector) => !selector
+                .alternatives
+                .iter()
+                .any(|complex| complex.compounds[0].matches(element, ancestors, index)),
+        }
+    }
+}
+
+impl AttrOp {
+    fn matches(self, value: &str, expected: &str) -> bool {
+        match self {
+            AttrOp::Equals => value == expected,
+            AttrOp::Includes => {
+                !expected.is_empty() && value.split_ascii_whitespace().any(|word| word == expected)
+            }
+            AttrOp::DashMatch => {
+                value == expected
+                    || value
+                        .strip_prefix(expected)
+                        .is_some_and(|rest| rest.starts_with('-'))
+            }
+            AttrOp::Prefix => !expected.is_empty() && value.starts_with(expected),
+            AttrOp::Suffix => !expected.is_empty() && value.ends_with(expected),
+            AttrOp::Substring => !expected.is_empty() && value.contains(expected),
+        }
+    }
+}
+
+struct Parser<'s> {
+    input: &'s str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, reason: &'static str) -> SelectorError {
+        SelectorError {
+            selector: self.input.to_string(),
+            position: self.pos,
+            reason,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char, reason: &'static str) -> Result<(), SelectorError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error(reason))
+        }
+    }
+
+    /// Skip whitespace, returning whether there was any.
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+        self.pos > start
+    }
+
+    fn selector_list(&mut self) -> Result<Selector, SelectorError> {
+        let mut alternatives = Vec::new();
+        loop {
+            self.skip_whitespace();
+            alternatives.push(self.complex()?);
+            self.skip_whitespace();
+            if !self.eat(',') {
+                return Ok(Selector { alternatives });
+            }
+        }
+    }
+
+    fn complex(&mut self) -> Result<Complex, SelectorError> {
+        let mut compounds = vec![self.compound()?];
+        let mut combinators = Vec::new();
+        loop {
+            let spaced = self.skip_whitespace();
+            let combinator = match self.peek() {
+                Some('>') => Combinator::Child,
+                Some('+') => Combinator::NextSibling,
+                Some('~') => Combinator::SubsequentSibling,
+                Some(',' | ')') | None => break,
+                Some(_) if spaced => Combinator::Descendant,
+                Some(_) => return Err(self.error("expected a combinator")),
+            };
+            if combinator != Combinator::Descendant {
+                self.pos += 1;
+                self.skip_whitespace();
+            }
+            combinators.push(combinator);
+            compounds.push(self.compound()?);
+        }
+        Ok(Complex {
+            compounds,
+            combinators,
+        })
+    }
+
+    fn compound(&mut self) -> Result<Compound, SelectorError> {
+        let start = self.pos;
+        let mut compound = Compound::default();
+        if !self.eat('*') && self.peek().is_some_and(is_name_start) {
+            compound.tag = Some(self.identifier()?);
+        }
+
+        loop {
+            match self.peek() {
+                Some('#') => {
+                    self.pos += 1;
+                    compound.simple.push(Simple::Id(self.identifier()?));
+                }
+                Some('.') => {
+                    self.pos += 1;
+                    compound.simple.push(Simple::Class(self.identifier()?));
+                }
+                Some('[') => {
+                    self.pos += 1;
+                    compound.simple.push(self.attribute()?);
+                }
+                Some(':') => {
+                    self.pos += 1;
+                    compound.simple.push(self.pseudo_class()?);
+                }
+                _ => break,
+            }
+        }
+
+        if self.pos == start {
+            return Err(self.error("expected a selector"));
+        }
+        Ok(compound)
+    }
+
+    fn identifier(&mut self) -> Result<String, SelectorError> {
+        let mut name = String::new();
+        while let Some(c) = self.peek() {
+            if c == '\\' {
+                self.pos += 1;
+                let Some(escaped) = self.peek() else {
+                    return Err(self.error("expected a character after `\\`"));
+                };
+                self.pos += escaped.len_utf8();
+                name.push(escaped);
+            } else if is_name_start(c) || c.is_ascii_digit() || c == '-' {
+                self.pos += c.len_utf8();
+                name.push(c);
+            } else {
+                break;
+            }
+        }
+        if name.is_empty() {
+            return Err(self.error("expected a name"));
+        }
+        Ok(name)
+    }
+
+    fn attribute(&mut self) -> Result<Simple, SelectorError> {
+        self.skip_whitespace();
+        let name = self.identifier()?;
+        self.skip_whitespace();
+
+        if self.eat(']') {
+            return Ok(Simple::Attribute {
+                name,
+                test: None,
+                ignore_case: false,
+            });
+        }
+
+        let op = if self.eat('=') {
+            AttrOp::Equals
+        } else {
+            let op = match self.peek() {
+                Some('~') => AttrOp::Includes,
+                Some('|') => AttrOp::DashMatch,
+                Some('^') => AttrOp::Prefix,
+                Some('$') => AttrOp::Suffix,
+                Some('*') => AttrOp::Substring,
+                _ => return Err(self.error("expected `]` or an attribute operator")),
+            };
+            self.pos += 1;
+            self.expect('=', "expected `=`")?;
+            op
+        };
+
+        self.skip_whitespace();
+        let value = match self.peek() {
+            Some(quote @ ('"' | '\'')) => {
+                self.pos += 1;
+                let rest = &self.input[self.pos..];
+                let Some(end) = rest.find(quote) else {
+                    return Err(self.error("unterminated string"));
+                };
+                self.pos += end + 1;
+                rest[..end].to_string()
+            }
+            _ => self.identifier()?,
+        };
+
+        self.skip_whitespace();
+        let ignore_case = self.eat('i') || self.eat('I');
+        if !ignore_case {
+            self.eat('s');
+        }
+        self.skip_whitespace();
+        self.expect(']', "expected `]`")?;
+        Ok(Simple::Attribute {
+            name,
+            test: Some((op, value)),
+            ignore_case,
+        })
+    }
+
+    fn pseudo_class(&mut self) -> Result<Simple, SelectorError> {
+        let start = self.pos;
+        let name = self.identifier()?.to_ascii_lowercase();
+        let nth = |from_end, of_type| Simple::Nth {
+            nth: Nth::FIRST,
+            from_end,
+            of_type,
+        };
+        let simple = match name.as_str() {
+            "root" => Simple::Root,
+            "empty" => Simple::Empty,
+            "first-child" => nth(false, false),
+            "last-child" => nth(true, false),
+            "first-of-type" => nth(false, true),
+            "last-of-type" => nth(true, true),
+            "only-child" => Simple::Only { of_type: false },
+            "only-of-type" => Simple::Only { of_type: true },
+            "nth-child" | "nth-last-child" | "nth-of-type" | "nth-last-of-type" => {
+                self.expect('(', "expected `(`")?;
+                self.skip_whitespace();
+                let parsed = self.nth()?;
+                self.skip_whitespace();
+                self.expect(')', "expected `)`")?;
+                Simple::Nth {
+                    nth: parsed,
+                    from_end: name.contains("-last-"),
+                    of_type: name.ends_with("-of-type"),
+                }
+            }
+            "not" => {
+                self.expect('(', "expected `(`")?;
+                let inner = self.selector_list()?;
+                if inner.alternatives.iter().any(|c| !c.combinators.is_empty()) {
+                    return Err(self.error("`:not()` takes compound selectors only"));
+                }
+                self.expect(')', "expected `)`")?;
+                Simple::Not(inner)
+            }
+            _ => {
+                self.pos = start;
+                return Err(self.error("unsupported pseudo-class"));
+            }
+        };
+        Ok(simple)
+    }
+
+    /// Parse `an+b`, `odd`, `even` or an integer.
+    fn nth(&mut self) -> Result<Nth, SelectorError> {
+        let start = self.pos;
+        let rest = &self.input[self.pos..];
+        let len = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | ' ')))
+            .unwrap_or(rest.len());
+        let text: String = rest[..len]
+            .chars()
+            .filter(|c| *c != ' ')
+            .collect::<String>()
+            .to_ascii_lowercase();
+        self.pos += len;
+
+        let parsed = match text.as_str() {
+            "odd" => Some(Nth { a: 2, b: 1 }),
+            "even" => Some(Nth { a: 2, b: 0 }),
+            _ => match text.split_once('n') {
+                None => text.parse().ok().map(|b| Nth { a: 0, b }),
+                Some((a, b)) => {
+                    let a = match a {
+                        "" | "+" => Some(1),
+                        "-" => Some(-1),
+                        a => a.parse().ok(),
+                    };
+                    let b = if b.is_empty() {
+                        Some(0)
+                    } else if b.starts_with(['+', '-']) {
+                        b.parse().ok()
+                    } else {
+                        None
+                    };
+                    a.zip(b).map(|(a, b)| Nth { a, b })
+                }
+            },
+        };
+        parsed.ok_or_else(|| {
+            self.pos = start;
+            self.error("expected `an+b`, `odd` or `even`")
+        })
+    }
+}
+
+fn is_name_start(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_' || c == '-' || !c.is_ascii()
+}
+
+/// Iterator over the elements matching a selector, in document order.
+/// Returned by [`Element::select`] and [`Element::select_with`].
+pub struct Select<'a> {
+    selector: Selector,
+    /// Not yet visited: the element `select` was called on.
+    root: Option<&'a Element>,
+    /// The elements whose children are being visited, root first.
+    ancestors: Vec<&'a Element>,
+    /// Child index of each of `ancestors` but the first within its parent.
+    outer: Vec<usize>,
+    /// For each of `ancestors`, the index of the next child to visit.
+    next: Vec<usize>,
+}
+
+impl<'a> Select<'a> {
+    fn new(root: &'a Element, selector: Selector) -> Self {
+        Select {
+            selector,
+            root: Some(root),
+            ancestors: Vec::new(),
+            outer: Vec::new(),
+            next: Vec::new(),
+        }
+    }
+
+    /// Visit the next element in document order, returning it, its path from
+    /// the root, and whether it matches.
+    fn step(&mut self) -> Option<(&'a Element, Vec<usize>, bool)> {
+        if let Some(root) = self.root.take() {
+            let matches = self.selector.matches_at(root, &[], &[], 0);
+            self.ancestors.push(root);
+            self.next.push(0);
+            return Some((root, Vec::new(), matches));
+        }
+
+        loop {
+            let parent = *self.ancestors.last()?;
+            let start = *self.next.last()?;
+            let found = parent.children[start..]
+                .iter()
+                .enumerate()
+                .find_map(|(offset, child)| Some((start + offset, child.as_element()?)));
+            let Some((index, element)) = found else {
+                self.ancestors.pop();
+                self.next.pop();
+                self.outer.pop();
+                continue;
+            };
+            *self.next.last_mut()? = index + 1;
+
+            let matches = self
+                .selector
+                .matches_at(element, &self.ancestors, &self.outer, index);
+            let mut path = self.outer.clone();
+            path.push(index);
+
+            self.ancestors.push(element);
+            self.outer.push(index);
+            self.next.push(0);
+            return Some((element, path, matches));
+        }
+    }
+
+    /// Also yield each match's child-index path from the element `select` was
+    /// called on, for use with [`Element::get_content_mut`] and friends.
+    pub fn with_paths(self) -> impl Iterator<Item = (Vec<usize>, &'a Element)> {
+        let mut select = self;
+        std::iter::from_fn(move || {
+            loop {
+                let (element, path, matches) = select.step()?;
+                if matches {
+                    return Some((path, element));
+                }
+            }
+        })
+    }
+}
+
+impl<'a> Iterator for Select<'a> {
+    type Item = &'a Element;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (element, _, matches) = self.step()?;
+            if matches {
+                return Some(element);
+            }
+        }
+    }
+}
+
+impl Element {
+    /// Iterate over this element and its descendants that match a CSS
+    /// selector, in document order.
+    ///
+    /// ```
+    /// use facet_xml_node::Element;
+    ///
+    /// let html = r#"<body>
+    ///     <div class="container"><p>One</p><section><p>Nested</p></section><p>Two</p></div>
+    ///     <p>Outside</p>
+    /// </body>"#;
+    /// let doc: Element = facet_xml::from_str(html).unwrap();
+    ///
+    /// let texts: Vec<String> = doc
+    ///     .select("div.container > p")
+    ///     .unwrap()
+    ///     .map(Element::text_content)
+    ///     .collect();
+    /// assert_eq!(texts, ["One", "Two"]);
+    /// ```
+    pub fn select(&self, selector: &str) -> Result<Select<'_>, SelectorError> {
+        Ok(Select::new(self, Selector::parse(selector)?))
+    }
+
+    /// Like [`Element::select`], with a selector parsed up front.
+    pub fn select_with(&self, selector: &Selector) -> Select<'_> {
+        Select::new(self, selector.clone())
+    }
+
+    /// The first element [`Element::select`] would yield.
+    pub fn select_first(&self, selector: &str) -> Result<Option<&Element>, SelectorError> {
+        Ok(self.select(selector)?.next())
+    }
+}
@@ -0,0 +1,309 @@
+//! Schema.org structured data: JSON-LD scripts and microdata items.
+//!
+//! Pages describe products, articles, events and so on for search engines in
+//! two ways: `<script type="application/ld+json">` blocks, and microdata
+//! attributes (`itemscope`, `itemtype`, `itemprop`) on ordinary elements.
+//! [`Element::json_ld`] and [`Element::microdata`] find both and deserialize
+//! them with `facet-json` into your own types, reporting where in the tree
+//! each item came from.
+
+use facet::Facet;
+use facet_json::DeserializeError;
+
+use crate::{Content, Element};
+
+/// A structured-data item found in an [`Element`] tree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructuredItem<T> {
+    /// Child indices from the root to the element the item was read from: the
+    /// `<script>` for JSON-LD, the `itemscope` element for microdata. Same
+    /// convention as [`Element::get_content_mut`]; empty for the root itself.
+    pub path: Vec<usize>,
+    /// The deserialized item.
+    pub value: T,
+}
+
+/// A structured-data item that didn't deserialize into the requested type.
+#[derive(Debug)]
+pub struct StructuredDataError {
+    /// Child indices from the root to the element the item was read from.
+    pub path: Vec<usize>,
+    /// What went wrong.
+    pub error: DeserializeError,
+}
+
+impl std::fmt::Display for StructuredDataError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "structured data at {:?}: {}", self.path, self.error)
+    }
+}
+
+impl std::error::Error for StructuredDataError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl Element {
+    /// Deserialize every `<script type="application/ld+json">` in this tree.
+    ///
+    /// A script holding a JSON array yields one item per element. Items are
+    /// returned in document order; one that doesn't match `T` is an error for
+    /// that item only. A top-level `@graph` isn't unpacked: deserialize it with
+    /// a wrapper type that has a `#[facet(rename = "@graph")]` field.
+    ///
+    /// ```
+    /// use facet::Facet;
+    /// use facet_xml_node::Element;
+    ///
+    /// #[derive(Facet, Debug, PartialEq)]
+    /// struct Article {
+    ///     headline: String,
+    /// }
+    ///
+    /// let html = r#"<head><script type="application/ld+json">{"@type": "Article", "headline": "Hello"}</script></head>"#;
+    /// let doc: Element = facet_xml::from_str(html).unwrap();
+    ///
+    /// let items = doc.json_ld::<Article>();
+    /// let item = items[0].as_ref().unwrap();
+    /// assert_eq!(item.value.headline, "Hello");
+    /// assert_eq!(item.path, [0]);
+    /// ```
+    pub fn json_ld<T: Facet<'static>>(
+        &self,
+    ) -> Vec<Result<StructuredItem<T>, StructuredDataError>> {
+        let mut items = Vec::new();
+        for (path, element) in self.descendants_with_paths() {
+            let is_json_ld = element.tag.eq_ignore_ascii_case("script")
+                && element
+                    .get_attr("type")
+                    .is_some_and(|ty| ty.trim().eq_ignore_ascii_case("application/ld+json"));
+            if !is_json_ld {
+                continue;
+            }
+
+            let text = element.text_content();
+            if text.trim_start().starts_with('[') {
+                match facet_json::from_str::<Vec<T>>(&text) {
+                    Ok(values) => items.extend(values.into_iter().map(|value| {
+                        Ok(StructuredItem {
+                            path: path.clone(),
+                            value,
+                        })
+                    })),
+                    Err(error) => items.push(Err(StructuredDataError { path, error })),
+                }
+            } else {
+                items.push(deserialize_item(path, &text));
+            }
+        }
+        items
+    }
+
+    /// Deserialize every top-level microdata item in this tree whose
+    /// `itemtype` is `item_type`.
+    ///
+    /// `item_type` matches either a full type URL or its last segment, so
+    /// `"Product"` matches `itemtype="https://schema.org/Product"`. An empty
+    /// `item_type` matches every item.
+    ///
+    /// Each item becomes a JSON object (see [`Element::microdata_json`]) that
+    /// is deserialized into `T`. Microdata values are text, so non-string
+    /// fields must be types that parse from a string (numbers, dates, ...),
+    /// and a property given more than once becomes an array.
+    ///
+    /// ```
+    /// use facet::Facet;
+    /// use facet_xml_node::Element;
+    ///
+    /// #[derive(Facet, Debug, PartialEq)]
+    /// struct Product {
+    ///     name: String,
+    ///     price: f64,
+    /// }
+    ///
+    /// let html = r#"<div itemscope="" itemtype="https://schema.org/Product">
+    ///     <h1 itemprop="name">Widget</h1>
+    ///     <meta itemprop="price" content="9.99"/>
+    /// </div>"#;
+    /// let doc: Element = facet_xml::from_str(html).unwrap();
+    ///
+    /// let items = doc.microdata::<Product>("Product");
+    /// let product = &items[0].as_ref().unwrap().value;
+    /// assert_eq!(product, &Product { name: "Widget".into(), price: 9.99 });
+    /// ```
+    pub fn microdata<T: Facet<'static>>(
+        &self,
+        item_type: &str,
+    ) -> Vec<Result<StructuredItem<T>, StructuredDataError>> {
+        self.descendants_with_paths()
+            .into_iter()
+            .filter(|(_, element)| {
+                element.get_attr("itemscope").is_some()
+                    && element.get_attr("itemprop").is_none()
+                    && (item_type.is_empty() || element.has_item_type(item_type))
+            })
+            .map(|(path, element)| deserialize_item(path, &element.microdata_json()))
+            .collect()
+    }
+
+    /// The JSON object for the microdata item rooted at this element.
+    ///
+    /// `itemtype` becomes `@type` (the first URL, as written) and `itemid`
+    /// becomes `@id`. Every `itemprop` below this element, except inside
+    /// nested items, becomes a property: nested `itemscope` elements become
+    /// objects, and other values are read as the microdata spec says (`content`
+    /// of `<meta>`, `href` of `<a>`, `src` of `<img>`, `datetime` of `<time>`,
+    /// the text otherwise). `itemref` isn't followed.
+    pub fn microdata_json(&self) -> String {
+        let mut out = String::new();
+        write_json(&mut out, &self.microdata_item());
+        out
+    }
+
+    fn has_item_type(&self, item_type: &str) -> bool {
+        self.get_attr("itemtype").is_some_and(|types| {
+            types
+                .split_ascii_whitespace()
+                .any(|ty| ty == item_type || ty.rsplit(['/', '#']).next() == Some(item_type))
+        })
+    }
+
+    fn microdata_item(&self) -> Json {
+        let mut properties: Vec<(String, Vec<Json>)> = Vec::new();
+        if let Some(ty) = self
+            .get_attr("itemtype")
+            .and_then(|types| types.split_ascii_whitespace().next())
+        {
+            properties.push(("@type".to_string(), vec![Json::Str(ty.to_string())]));
+        }
+        if let Some(id) = self.get_attr("itemid") {
+            properties.push(("@id".to_string(), vec![Json::Str(id.trim().to_string())]));
+        }
+        for child in self.children.iter().filter_map(Content::as_element) {
+            child.collect_properties(&mut properties);
+        }
+        Json::Object(properties)
+    }
+
+    fn collect_properties(&self, properties: &mut Vec<(String, Vec<Json>)>) {
+        let is_item = self.get_attr("itemscope").is_some();
+        if let Some(names) = self.get_attr("itemprop") {
+            let value = if is_item {
+                self.microdata_item()
+            } else {
+                Json::Str(self.microdata_value())
+            };
+            for name in names.split_ascii_whitespace() {
+                match properties.iter_mut().find(|(existing, _)| existing == name) {
+                    Some((_, values)) => values.push(value.clone()),
+                    None => properties.push((name.to_string(), vec![value.clone()])),
+                }
+            }
+        }
+        // A nested item's properties belong to it, not to us.
+        if !is_item {
+            for child in self.children.iter().filter_map(Content::as_element) {
+                child.collect_properties(properties);
+            }
+        }
+    }
+
+    fn microdata_value(&self) -> String {
+        let attr = match self.tag.to_ascii_lowercase().as_str() {
+            "meta" => Some("content"),
+            "audio" | "embed" | "iframe" | "img" | "source" | "track" | "video" => Some("src"),
+            "a" | "area" | "link" => Some("href"),
+            "object" => Some("data"),
+            "data" | "meter" => Some("value"),
+            "time" if self.get_attr("datetime").is_some() => Some("datetime"),
+            _ => None,
+        };
+        match attr {
+            Some(attr) => self.get_attr(attr).unwrap_or_default().trim().to_string(),
+            None => self.text_content().trim().to_string(),
+        }
+    }
+
+    /// Every element in this tree with its path, in document order.
+    fn descendants_with_paths(&self) -> Vec<(Vec<usize>, &Element)> {
+        let mut found = Vec::new();
+        let mut stack = vec![(Vec::new(), self)];
+        while let Some((path, element)) = stack.pop() {
+            // Push in reverse so the first child is visited next.
+            for (index, child) in element.children.iter().enumerate().rev() {
+                if let Content::Element(child) = child {
+                    let mut child_path = path.clone();
+                    child_path.push(index);
+                    stack.push((child_path, child));
+                }
+            }
+            found.push((path, element));
+        }
+        found
+    }
+}
+
+fn deserialize_item<T: Facet<'static>>(
+    path: Vec<usize>,
+    json: &str,
+) -> Result<StructuredItem<T>, StructuredDataError> {
+    match facet_json::from_str(json) {
+        Ok(value) => Ok(StructuredItem { path, value }),
+        Err(error) => Err(StructuredDataError { path, error }),
+    }
+}
+
+/// The JSON a microdata item turns into: strings and objects.
+#[derive(Clone)]
+enum Json {
+    Str(String),
+    /// Properties in document order; a property given more than once is an
+    /// array.
+    Object(Vec<(String, Vec<Json>)>),
+}
+
+fn write_json(out: &mut String, value: &Json) {
+    match value {
+        Json::Str(s) => write_json_string(out, s),
+        Json::Object(properties) => {
+            out.push('{');
+            for (i, (name, values)) in properties.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_json_string(out, name);
+                out.push(':');
+                if let [value] = values.as_slice() {
+                    write_json(out, value);
+                } else {
+                    out.push('[');
+                    for (j, value) in values.iter().enumerate() {
+                        if j > 0 {
+                            out.push(',');
+                        }
+                        write_json(out, value);
+                    }
+                    out.push(']');
+                }
+            }
+            out.push('}');
+        }
+    }
+}
+
+fn write_json_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if u32::from(c) < 0x20 => out.push_str(&format!("\\u{:04x}", u32::from(c))),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
@@ -0,0 +1,209 @@
+//! The `style` attribute as a list of CSS declarations.
+//!
+//! [`StyleDecls`] reads `color: red; margin: 0 auto` into property/value
+//! pairs and writes them back in a canonical form, so setting one property
+//! doesn't mean re-parsing the whole attribute by hand.
+
+use std::fmt;
+
+use crate::Element;
+
+/// The declarations of an inline `style` attribute, in source order.
+///
+/// Property names are ASCII-lowercased, except custom properties (`--name`),
+/// which are case-sensitive. Values are kept as written, `!important`
+/// included. A later declaration of the same property replaces the earlier
+/// one in place, and declarations without a `:` are dropped, as browsers do.
+///
+/// Writing gives the canonical form `property: value;`, separated by single
+/// spaces, which parses back to the same declarations. As a field type it
+/// (de)serializes as that string.
+///
+/// ```
+/// use facet_xml_node::StyleDecls;
+///
+/// let mut style = StyleDecls::parse("COLOR:red;background: url('a;b.png') ;;");
+/// assert_eq!(style.get("color"), Some("red"));
+/// assert_eq!(style.get("background"), Some("url('a;b.png')"));
+///
+/// style.set("margin", "0 auto");
+/// style.remove("color");
+/// assert_eq!(style.to_string(), "background: url('a;b.png'); margin: 0 auto;");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default, facet::Facet)]
+#[facet(from = String, into = String)]
+pub struct StyleDecls {
+    decls: Vec<(String, String)>,
+}
+
+impl StyleDecls {
+    /// No declarations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse a `style` attribute value.
+    ///
+    /// Semicolons inside quotes, parentheses or brackets don't end a
+    /// declaration, so `url("a;b")` stays whole.
+    pub fn parse(value: &str) -> Self {
+        let mut style = Self::new();
+        for decl in split_declarations(value) {
+            if let Some((property, value)) = decl.split_once(':') {
+                let (property, value) = (property.trim(), value.trim());
+                if !property.is_empty() && !value.is_empty() {
+                    style.set(property, value);
+                }
+            }
+        }
+        style
+    }
+
+    /// The value of `property`, if declared.
+    pub fn get(&self, property: &str) -> Option<&str> {
+        let property = normalize_property(property);
+        self.decls
+            .iter()
+            .find(|(p, _)| *p == property)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Whether `property` is declared.
+    pub fn contains(&self, property: &str) -> bool {
+        self.get(property).is_some()
+    }
+
+    /// Declare `property`, replacing its value in place if it was already
+    /// declared, or appending it otherwise. Returns the previous value.
+    pub fn set(&mut self, property: &str, value: impl Into<String>) -> Option<String> {
+        let property = normalize_property(property);
+        let value = value.into();
+        match self.decls.iter_mut().find(|(p, _)| *p == property) {
+            Some((_, old)) => Some(std::mem::replace(old, value)),
+            None => {
+                self.decls.push((property, value));
+                None
+            }
+        }
+    }
+
+    /// Remove the declaration of `property`, returning its value.
+    pub fn remove(&mut self, property: &str) -> Option<String> {
+        let property = normalize_property(property);
+        let index = self.decls.iter().position(|(p, _)| *p == property)?;
+        Some(self.decls.remove(index).1)
+    }
+
+    /// The `(property, value)` pairs, in order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.decls.iter().map(|(p, v)| (p.as_str(), v.as_str()))
+    }
+
+    /// The number of declarations.
+    pub fn len(&self) -> usize {
+        self.decls.len()
+    }
+
+    /// Whether there are no declarations.
+    pub fn is_empty(&self) -> bool {
+        self.decls.is_empty()
+    }
+}
+
+/// Split on the semicolons that end declarations.
+fn split_declarations(value: &str) -> Vec<&str> {
+    let mut decls = Vec::new();
+    let mut quote = None;
+    let mut depth = 0usize;
+    let mut start = 0;
+    let mut chars = value.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match (quote, c) {
+            (Some(_), '\\') => {
+                chars.next();
+            }
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(' | '[') => depth += 1,
+            (None, ')' | ']') => depth = depth.saturating_sub(1),
+            (None, ';') if depth == 0 => {
+                decls.push(&value[start..i]);
+                start = i + 1;
+            }
+            (None, _) => {}
+        }
+    }
+    decls.push(&value[start..]);
+    decls
+}
+
+fn normalize_property(property: &str) -> String {
+    let property = property.trim();
+    if property.starts_with("--") {
+        property.to_owned()
+    } else {
+        property.to_ascii_lowercase()
+    }
+}
+
+impl fmt::Display for StyleDecls {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (property, value)) in self.decls.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{property}: {value};")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<&str> for StyleDecls {
+    fn from(value: &str) -> Self {
+        Self::parse(value)
+    }
+}
+
+impl From<String> for StyleDecls {
+    fn from(value: String) -> Self {
+        Self::parse(&value)
+    }
+}
+
+impl From<StyleDecls> for String {
+    fn from(style: StyleDecls) -> Self {
+        style.to_string()
+    }
+}
+
+impl Element {
+    /// The element's `style` attribute as [`StyleDecls`]; empty when the
+    /// attribute is missing.
+    ///
+    /// ```
+    /// use facet_xml_node::Element;
+    ///
+    /// let mut div = Element::new("div").attr("style", "display:none");
+    /// let mut style = div.style_decls();
+    /// style.remove("display");
+    /// style.set("color", "red");
+    /// div.set_style_decls(&style);
+    /// assert_eq!(div.get_attr("style"), Some("color: red;"));
+    /// ```
+    pub fn style_decls(&self) -> StyleDecls {
+        self.get_attr("style")
+            .map(StyleDecls::parse)
+            .unwrap_or_default()
+    }
+
+    /// Write `style` back as the `style` attribute, removing the attribute
+    /// when there are no declarations.
+    pub fn set_style_decls(&mut self, style: &StyleDecls) {
+        if style.is_empty() {
+            self.remove_attr("style");
+        } else {
+            self.set_attr("style", style.to_string());
+        }
+    }
+}
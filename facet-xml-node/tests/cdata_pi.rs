@@ -0,0 +1,38 @@
+use facet_testhelpers::test;
+use facet_xml::ProcessingInstruction;
+use facet_xml_node::{Content, Element, from_element, to_element};
+
+const SCRIPT: &str = "<script>start;<![CDATA[if (a < b) { go(); }]]><?render inline?></script>";
+
+#[test]
+fn cdata_and_processing_instructions_are_kept() {
+    let script: Element = facet_xml::from_str(SCRIPT).unwrap();
+    assert_eq!(
+        script.children,
+        [
+            Content::Text("start;".into()),
+            Content::CData("if (a < b) { go(); }".into()),
+            Content::ProcessingInstruction(ProcessingInstruction::new("render", "inline")),
+        ]
+    );
+    assert_eq!(script.text_content(), "start;if (a < b) { go(); }");
+
+    assert_eq!(facet_xml::to_string(&script).unwrap(), SCRIPT);
+}
+
+#[test]
+fn element_trees_carry_cdata_and_processing_instructions() {
+    let script: Element = facet_xml::from_str(SCRIPT).unwrap();
+
+    // Element -> typed value -> Element goes through ElementParser and ElementSerializer
+    let copy: Element = from_element(&script).unwrap();
+    assert_eq!(copy, script);
+    assert_eq!(to_element(&copy).unwrap(), script);
+}
+
+#[test]
+fn cdata_has_no_children() {
+    let mut script: Element = facet_xml::from_str(SCRIPT).unwrap();
+    assert!(script.children_mut(&[1]).is_err());
+    assert_eq!(script.children[1].as_cdata(), Some("if (a < b) { go(); }"));
+}
@@ -0,0 +1,101 @@
+use facet::Facet;
+use facet_testhelpers::test;
+use facet_xml as xml;
+use facet_xml_node::{ClassList, Element, StyleDecls};
+
+#[test]
+fn class_lists_are_ordered_sets() {
+    let mut classes = ClassList::parse("  card card--wide\tcard ");
+    assert_eq!(classes.iter().collect::<Vec<_>>(), ["card", "card--wide"]);
+    assert!(classes.contains("card--wide"));
+    assert!(!classes.contains("Card"));
+
+    assert!(!classes.add("card"));
+    assert!(classes.add("selected"));
+    assert!(classes.remove("card--wide"));
+    assert!(!classes.remove("card--wide"));
+    assert!(!classes.toggle("selected"));
+    assert!(classes.toggle("open"));
+    assert_eq!(classes.to_string(), "card open");
+
+    let collected: ClassList = ["a", "b", "a"].into_iter().collect();
+    assert_eq!(collected.len(), 2);
+    assert!(ClassList::parse(" \n").is_empty());
+}
+
+#[test]
+fn style_declarations_round_trip() {
+    let style = StyleDecls::parse(
+        r#"Color: red; --Accent: #f00 ; font-family: "A; B", serif;bogus;color:blue !important"#,
+    );
+    assert_eq!(
+        style.iter().collect::<Vec<_>>(),
+        [
+            ("color", "blue !important"),
+            ("--Accent", "#f00"),
+            ("font-family", r#""A; B", serif"#),
+        ]
+    );
+    assert_eq!(style.get("COLOR"), Some("blue !important"));
+    assert_eq!(style.get("--accent"), None);
+
+    let canonical = style.to_string();
+    assert_eq!(
+        canonical,
+        r#"color: blue !important; --Accent: #f00; font-family: "A; B", serif;"#
+    );
+    assert_eq!(StyleDecls::parse(&canonical), style);
+    assert!(StyleDecls::parse(" ; :; x: ").is_empty());
+}
+
+#[test]
+fn element_class_and_style_helpers() {
+    let mut doc: Element =
+        xml::from_str(r#"<ul><li class="item active" style="color: red">a</li><li>b</li></ul>"#)
+            .unwrap();
+
+    for child in &mut doc.children {
+        let li = child.as_element_mut().unwrap();
+        let mut classes = li.class_list();
+        classes.toggle("active");
+        li.set_class_list(&classes);
+
+        let mut style = li.style_decls();
+        style.remove("color");
+        li.set_style_decls(&style);
+    }
+    assert_eq!(
+        doc.to_html(),
+        r#"<ul><li class="item">a</li><li class="active">b</li></ul>"#
+    );
+}
+
+#[derive(Facet, Debug, PartialEq)]
+#[facet(rename = "div")]
+struct Panel {
+    #[facet(xml::attribute)]
+    class: ClassList,
+    #[facet(xml::attribute)]
+    style: StyleDecls,
+}
+
+#[test]
+fn typed_attributes_use_the_canonical_strings() {
+    let mut panel: Panel =
+        xml::from_str(r#"<div class="panel  panel" style="WIDTH:10px;height: 5px"></div>"#)
+            .unwrap();
+    assert_eq!(panel.class.iter().collect::<Vec<_>>(), ["panel"]);
+    assert_eq!(panel.style.get("width"), Some("10px"));
+
+    panel.class.add("open");
+    panel.style.set("height", "auto");
+    let out = xml::to_string(&panel).unwrap();
+    assert!(out.contains(r#"class="panel open""#), "{out}");
+    assert!(
+        out.contains(r#"style="width: 10px; height: auto;""#),
+        "{out}"
+    );
+
+    let back: Panel = xml::from_str(&out).unwrap();
+    assert_eq!(back, panel);
+}
@@ -0,0 +1,108 @@
+use facet_testhelpers::test;
+use facet_xml_node::{Element, resolve_url};
+
+const PAGE: &str = r#"<html>
+    <head><link rel="stylesheet" href="style.css"/></head>
+    <body>
+        <nav><a href="/">Home</a><a href="https://other.example/x">Elsewhere</a></nav>
+        <article>
+            <p>See <a href="../archive/?page=2#top">the archive</a>.</p>
+            <img src="img/hero.png" alt="Hero"/>
+            <a name="anchor-without-href">no link</a>
+            <div><IMG src="//cdn.example.com/logo.svg"/></div>
+        </article>
+    </body>
+</html>"#;
+
+const BASE: &str = "https://example.com/blog/2024/post.html";
+
+#[test]
+fn links_are_found_in_document_order_and_resolved() {
+    let doc: Element = facet_xml::from_str(PAGE).unwrap();
+    let links: Vec<(&str, &str, String)> = doc
+        .links(BASE)
+        .map(|link| (link.element.tag.as_str(), link.raw, link.url))
+        .collect();
+    assert_eq!(
+        links,
+        [
+            (
+                "link",
+                "style.css",
+                "https://example.com/blog/2024/style.css".to_string()
+            ),
+            ("a", "/", "https://example.com/".to_string()),
+            (
+                "a",
+                "https://other.example/x",
+                "https://other.example/x".to_string()
+            ),
+            (
+                "a",
+                "../archive/?page=2#top",
+                "https://example.com/blog/archive/?page=2#top".to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn images_match_tags_case_insensitively() {
+    let doc: Element = facet_xml::from_str(PAGE).unwrap();
+    let images: Vec<(Option<&str>, String)> = doc
+        .images(BASE)
+        .map(|image| (image.element.get_attr("alt"), image.url))
+        .collect();
+    assert_eq!(
+        images,
+        [
+            (
+                Some("Hero"),
+                "https://example.com/blog/2024/img/hero.png".to_string()
+            ),
+            (None, "https://cdn.example.com/logo.svg".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn descendants_include_self_in_pre_order() {
+    let doc = Element::new("a")
+        .with_child(Element::new("b").with_child(Element::new("c")))
+        .with_text("text")
+        .with_child(Element::new("d"));
+    let tags: Vec<&str> = doc.descendants().map(|e| e.tag.as_str()).collect();
+    assert_eq!(tags, ["a", "b", "c", "d"]);
+}
+
+#[test]
+fn resolve_url_edge_cases() {
+    let base = "http://a/b/c/d;p?q";
+    // Examples from RFC 3986 section 5.4.
+    for (reference, expected) in [
+        ("g", "http://a/b/c/g"),
+        ("./g", "http://a/b/c/g"),
+        ("g/", "http://a/b/c/g/"),
+        ("/g", "http://a/g"),
+        ("//g", "http://g"),
+        ("?y", "http://a/b/c/d;p?y"),
+        ("g?y", "http://a/b/c/g?y"),
+        ("#s", "http://a/b/c/d;p?q#s"),
+        ("", "http://a/b/c/d;p?q"),
+        (".", "http://a/b/c/"),
+        ("..", "http://a/b/"),
+        ("../g", "http://a/b/g"),
+        ("../../../g", "http://a/g"),
+        ("/./g", "http://a/g"),
+        ("g;x=1/../y", "http://a/b/c/y"),
+    ] {
+        assert_eq!(resolve_url(base, reference), expected, "{reference}");
+    }
+
+    assert_eq!(resolve_url("", "img.png"), "img.png");
+    assert_eq!(resolve_url("mailto:x@example.com", "img.png"), "img.png");
+    assert_eq!(
+        resolve_url("https://example.com", "img.png"),
+        "https://example.com/img.png"
+    );
+}
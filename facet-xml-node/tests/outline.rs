@@ -0,0 +1,113 @@
+use facet_testhelpers::test;
+use facet_xml_node::Element;
+
+const PAGE: &str = r#"<html>
+    <head>
+        <title>Release notes</title>
+        <meta name="Description" content="What changed in 2.0"/>
+        <meta name="viewport" content="width=device-width, initial-scale=1"/>
+        <meta property="og:title" content="Release 2.0"/>
+        <meta property="og:image" content="https://example.com/card.png"/>
+        <meta property="og:title" content="ignored duplicate"/>
+    </head>
+    <body>
+        <hgroup><h1 id="top">Release 2.0</h1><p>Sub</p><h2>Not a section</h2></hgroup>
+        <section>
+            <h2 id="features">New
+                features</h2>
+            <h4>Skipped a level</h4>
+            <h3>Parser</h3>
+        </section>
+        <h2>Fixes</h2>
+        <h1>Appendix</h1>
+    </body>
+</html>"#;
+
+fn titles(headings: &[facet_xml_node::Heading<'_>]) -> Vec<String> {
+    headings
+        .iter()
+        .map(|h| format!("h{} {}", h.level, h.text))
+        .collect()
+}
+
+#[test]
+fn outline_nests_headings_by_rank() {
+    let doc: Element = facet_xml::from_str(PAGE).unwrap();
+    let outline = doc.outline();
+
+    assert_eq!(titles(&outline), ["h1 Release 2.0", "h1 Appendix"]);
+    let release = &outline[0];
+    assert_eq!(release.id, Some("top"));
+    assert_eq!(titles(&release.children), ["h2 New features", "h2 Fixes"]);
+
+    let features = &release.children[0];
+    assert_eq!(features.id, Some("features"));
+    assert_eq!(
+        titles(&features.children),
+        ["h4 Skipped a level", "h3 Parser"]
+    );
+    assert!(outline[1].children.is_empty());
+}
+
+#[test]
+fn outline_paths_lead_back_to_the_headings() {
+    let mut doc: Element = facet_xml::from_str(PAGE).unwrap();
+    let paths: Vec<Vec<usize>> = {
+        fn walk(headings: &[facet_xml_node::Heading<'_>], out: &mut Vec<Vec<usize>>) {
+            for heading in headings {
+                out.push(heading.path.clone());
+                walk(&heading.children, out);
+            }
+        }
+        let mut out = Vec::new();
+        walk(&doc.outline(), &mut out);
+        out
+    };
+    assert_eq!(paths.len(), 6);
+
+    for path in paths {
+        let heading = doc
+            .get_content_mut(&path)
+            .unwrap()
+            .as_element_mut()
+            .unwrap();
+        assert!(heading.tag.starts_with('h'), "{path:?}: {}", heading.tag);
+    }
+}
+
+#[test]
+fn meta_tags_are_read() {
+    let doc: Element = facet_xml::from_str(PAGE).unwrap();
+
+    assert_eq!(doc.description(), Some("What changed in 2.0"));
+    assert_eq!(doc.meta("og:image"), Some("https://example.com/card.png"));
+    assert_eq!(doc.meta("keywords"), None);
+    assert_eq!(
+        doc.viewport().unwrap(),
+        [("width", "device-width"), ("initial-scale", "1")]
+    );
+
+    let og = doc.open_graph();
+    assert_eq!(og.len(), 2);
+    assert_eq!(og["og:title"], "Release 2.0");
+}
+
+#[test]
+fn set_meta_updates_or_inserts() {
+    let mut doc: Element = facet_xml::from_str(PAGE).unwrap();
+
+    let previous = doc.set_meta("description", "Faster parsing");
+    assert_eq!(previous.as_deref(), Some("What changed in 2.0"));
+    assert_eq!(doc.description(), Some("Faster parsing"));
+
+    assert_eq!(doc.set_meta("og:type", "article"), None);
+    assert_eq!(doc.open_graph()["og:type"], "article");
+
+    // Without a <head>, one is created at the start of <html>
+    let mut bare: Element = facet_xml::from_str("<html><body>hi</body></html>").unwrap();
+    bare.set_meta("viewport", "width=device-width");
+    assert_eq!(
+        bare.to_html(),
+        r#"<html><head><meta content="width=device-width" name="viewport"></meta></head><body>hi</body></html>"#
+    );
+}
@@ -0,0 +1,106 @@
+use facet_testhelpers::test;
+use facet_xml_node::{Element, Sanitizer};
+
+fn sanitized(html: &str, sanitizer: &Sanitizer) -> String {
+    let mut doc: Element = facet_xml::from_str(html).unwrap();
+    sanitizer.sanitize(&mut doc);
+    doc.to_html()
+}
+
+#[test]
+fn scripts_and_handlers_are_removed() {
+    let html = r#"<div>
+        <p ONCLICK="steal()" class="intro" style="color:red">Hello</p>
+        <script>alert(1)</script>
+        <STYLE>body { display: none }</STYLE>
+        <img src="cat.png" onerror="steal()" alt="A cat"/>
+    </div>"#;
+    assert_eq!(
+        sanitized(html, &Sanitizer::new()),
+        r#"<div><p class="intro">Hello</p><img alt="A cat" src="cat.png"></img></div>"#
+    );
+}
+
+#[test]
+fn unknown_tags_are_unwrapped() {
+    let html =
+        "<div><custom-widget><b>bold</b> text</custom-widget><form><p>in form</p></form></div>";
+    assert_eq!(
+        sanitized(html, &Sanitizer::new()),
+        "<div><b>bold</b>text<p>in form</p></div>"
+    );
+}
+
+#[test]
+fn url_schemes_are_filtered() {
+    let html = r#"<div>
+        <a href="https://example.com">ok</a>
+        <a href="/relative/path:with-colon">relative</a>
+        <a href=" JaVa&#9;Script:alert(1)">sneaky</a>
+        <a href="data:text/html,hi">data</a>
+        <a href="mailto:me@example.com">mail</a>
+        <img srcset="a.png 1x, javascript:x 2x"/>
+        <img srcset="a.png 1x, https://cdn.example.com/b.png 2x"/>
+    </div>"#;
+    assert_eq!(
+        sanitized(html, &Sanitizer::new()),
+        concat!(
+            r#"<div><a href="https://example.com">ok</a>"#,
+            r#"<a href="/relative/path:with-colon">relative</a>"#,
+            "<a>sneaky</a><a>data</a>",
+            r#"<a href="mailto:me@example.com">mail</a>"#,
+            "<img></img>",
+            r#"<img srcset="a.png 1x, https://cdn.example.com/b.png 2x"></img></div>"#,
+        )
+    );
+}
+
+#[test]
+fn edits_image_maps_and_grouping_keep_their_attributes() {
+    let html = r##"<div>
+        <del cite="/changes/12" datetime="2024-05-01">old</del>
+        <ins cite="javascript:alert(1)" datetime="2024-05-02">new</ins>
+        <map name="plan"><area shape="rect" coords="0,0,10,10" href="/a" alt="A" onclick="x()"/></map>
+        <search><menu><li>One</li></menu></search>
+    </div>"##;
+    assert_eq!(
+        sanitized(html, &Sanitizer::new()),
+        concat!(
+            r#"<div><del cite="/changes/12" datetime="2024-05-01">old</del>"#,
+            r#"<ins datetime="2024-05-02">new</ins>"#,
+            r#"<map name="plan"><area alt="A" coords="0,0,10,10" href="/a" shape="rect"></area></map>"#,
+            "<search><menu><li>One</li></menu></search></div>",
+        )
+    );
+}
+
+#[test]
+fn allow_list_is_configurable() {
+    let sanitizer = Sanitizer::empty()
+        .allow_tags(["p", "a"])
+        .allow_tag_attributes("a", ["href", "onclick"])
+        .allow_url_schemes(["https"])
+        .drop_tags(["aside"]);
+    let html = r#"<body><p id="x"><a href="https://a.example" onclick="x()">link</a><em>em</em></p><aside>ad</aside></body>"#;
+    assert_eq!(
+        sanitized(html, &sanitizer),
+        r#"<body><p><a href="https://a.example">link</a>em</p></body>"#
+    );
+
+    let no_images = Sanitizer::new().deny_tags(["img"]);
+    assert_eq!(
+        sanitized(r#"<div><img src="x.png"/>caption</div>"#, &no_images),
+        "<div>caption</div>"
+    );
+}
+
+#[test]
+fn text_is_escaped_on_output() {
+    // The parser unescapes the entities, so the text holds a literal tag
+    let html = "<div><p>&lt;script&gt;alert(1)&lt;/script&gt;</p></div>";
+    let mut doc: Element = facet_xml::from_str(html).unwrap();
+    assert_eq!(doc.text_content(), "<script>alert(1)</script>");
+
+    Sanitizer::new().sanitize(&mut doc);
+    assert_eq!(doc.to_html(), html);
+}
@@ -0,0 +1,113 @@
+use facet_testhelpers::test;
+use facet_xml_node::{Element, Selector};
+
+const PAGE: &str = r#"<body>
+    <nav id="top"><a href="/">Home</a><a href="/docs" class="active current">Docs</a></nav>
+    <div class="container">
+        <h2>Intro</h2>
+        <p>One</p>
+        <section><p lang="en-GB">Nested</p></section>
+        <p data-kind="Note">Two</p>
+        <p>Three</p>
+    </div>
+    <ul>
+        <li>a</li><li>b</li><li>c</li><li>d</li><li>e</li>
+    </ul>
+    <p>Outside</p>
+</body>"#;
+
+fn texts(doc: &Element, selector: &str) -> Vec<String> {
+    doc.select(selector)
+        .unwrap()
+        .map(Element::text_content)
+        .collect()
+}
+
+#[test]
+fn combinators() {
+    let doc: Element = facet_xml::from_str(PAGE).unwrap();
+
+    assert_eq!(texts(&doc, "div.container > p"), ["One", "Two", "Three"]);
+    assert_eq!(texts(&doc, "div p"), ["One", "Nested", "Two", "Three"]);
+    assert_eq!(texts(&doc, "h2 + p"), ["One"]);
+    assert_eq!(texts(&doc, "section ~ p"), ["Two", "Three"]);
+    assert_eq!(texts(&doc, "body > p, nav > .active"), ["Docs", "Outside"]);
+    assert_eq!(texts(&doc, "BODY>DIV>SECTION>P"), ["Nested"]);
+}
+
+#[test]
+fn attributes_ids_and_classes() {
+    let doc: Element = facet_xml::from_str(PAGE).unwrap();
+
+    assert_eq!(texts(&doc, "#top a.current.active"), ["Docs"]);
+    assert_eq!(texts(&doc, "a[href]"), ["Home", "Docs"]);
+    assert_eq!(texts(&doc, r#"a[href="/"]"#), ["Home"]);
+    assert_eq!(texts(&doc, "a[href^='/d']"), ["Docs"]);
+    assert_eq!(texts(&doc, "a[class~=current]"), ["Docs"]);
+    assert_eq!(texts(&doc, "[lang|=en]"), ["Nested"]);
+    assert_eq!(texts(&doc, "[data-kind=note]"), Vec::<String>::new());
+    assert_eq!(texts(&doc, "[data-kind=note i]"), ["Two"]);
+}
+
+#[test]
+fn structural_pseudo_classes() {
+    let doc: Element = facet_xml::from_str(PAGE).unwrap();
+
+    assert_eq!(texts(&doc, "li:first-child"), ["a"]);
+    assert_eq!(texts(&doc, "li:last-child"), ["e"]);
+    assert_eq!(texts(&doc, "li:nth-child(odd)"), ["a", "c", "e"]);
+    assert_eq!(texts(&doc, "li:nth-child(2n)"), ["b", "d"]);
+    assert_eq!(texts(&doc, "li:nth-child(-n + 2)"), ["a", "b"]);
+    assert_eq!(texts(&doc, "li:nth-last-child(2)"), ["d"]);
+    assert_eq!(texts(&doc, ".container > p:first-of-type"), ["One"]);
+    assert_eq!(texts(&doc, ".container > p:last-of-type"), ["Three"]);
+    assert_eq!(texts(&doc, "section > :only-child"), ["Nested"]);
+    assert_eq!(
+        texts(&doc, ".container > p:not(:first-of-type, [data-kind])"),
+        ["Three"]
+    );
+
+    let root = doc.select_first(":root").unwrap().unwrap();
+    assert_eq!(root.tag, "body");
+}
+
+#[test]
+fn paths_lead_back_to_the_matches() {
+    let mut doc: Element = facet_xml::from_str(PAGE).unwrap();
+
+    let paths: Vec<Vec<usize>> = doc
+        .select("section p, ul > li:nth-child(3)")
+        .unwrap()
+        .with_paths()
+        .map(|(path, _)| path)
+        .collect();
+    assert_eq!(paths, [vec![1, 2, 0], vec![2, 2]]);
+
+    for path in paths {
+        doc.attrs_mut(&path)
+            .unwrap()
+            .insert("data-hit".into(), "yes".into());
+    }
+    assert_eq!(texts(&doc, "[data-hit]"), ["Nested", "c"]);
+}
+
+#[test]
+fn selectors_parse_once_and_report_errors() {
+    let selector: Selector = "p:empty, br".parse().unwrap();
+    assert!(selector.matches(&Element::new("br")));
+    assert!(selector.matches(&Element::new("p")));
+    assert!(!selector.matches(&Element::new("p").with_text("full")));
+
+    for bad in [
+        "",
+        "div >",
+        "p:hover",
+        "a[href",
+        "li:nth-child(x)",
+        ":not(a b)",
+    ] {
+        let err = Selector::parse(bad).unwrap_err();
+        assert!(err.position <= bad.len(), "{err}");
+        assert!(err.to_string().contains("invalid selector"), "{err}");
+    }
+}
@@ -0,0 +1,117 @@
+use facet::Facet;
+use facet_testhelpers::test;
+use facet_xml_node::Element;
+
+#[derive(Facet, Debug, PartialEq)]
+struct Offer {
+    price: f64,
+    #[facet(rename = "priceCurrency")]
+    price_currency: String,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Product {
+    #[facet(rename = "@type")]
+    ty: String,
+    name: String,
+    image: Vec<String>,
+    offers: Offer,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Article {
+    headline: String,
+}
+
+#[test]
+fn microdata_items_deserialize_with_nested_items() {
+    let html = concat!(
+        r#"<body><main>"#,
+        r#"<div itemscope="" itemtype="https://schema.org/Product">"#,
+        r#"<h1 itemprop="name"> Widget </h1>"#,
+        r#"<img itemprop="image" src="/a.png"/><img itemprop="image" src="/b.png"/>"#,
+        r#"<div itemprop="offers" itemscope="" itemtype="https://schema.org/Offer">"#,
+        r#"<meta itemprop="price" content="9.99"/>"#,
+        r#"<span itemprop="priceCurrency">EUR</span>"#,
+        r#"</div>"#,
+        r#"</div>"#,
+        r#"<p itemscope="" itemtype="https://schema.org/Article"><b itemprop="headline">News</b></p>"#,
+        r#"</main></body>"#,
+    );
+    let doc: Element = facet_xml::from_str(html).unwrap();
+
+    let products = doc.microdata::<Product>("Product");
+    assert_eq!(
+        products.len(),
+        1,
+        "the nested Offer is not a top-level item"
+    );
+    let product = products[0].as_ref().unwrap();
+    assert_eq!(product.path, [0, 0]);
+    assert_eq!(
+        product.value,
+        Product {
+            ty: "https://schema.org/Product".into(),
+            name: "Widget".into(),
+            image: vec!["/a.png".into(), "/b.png".into()],
+            offers: Offer {
+                price: 9.99,
+                price_currency: "EUR".into(),
+            },
+        }
+    );
+
+    let articles = doc.microdata::<Article>("https://schema.org/Article");
+    let article = articles[0].as_ref().unwrap();
+    assert_eq!(article.path, [0, 1]);
+    assert_eq!(article.value.headline, "News");
+
+    assert_eq!(doc.microdata::<Article>("").len(), 2);
+}
+
+#[test]
+fn microdata_json_escapes_text() {
+    let html = r#"<div itemscope="" itemid="urn:x"><span itemprop="quote">"a\b"</span></div>"#;
+    let doc: Element = facet_xml::from_str(html).unwrap();
+    assert_eq!(
+        doc.microdata_json(),
+        r#"{"@id":"urn:x","quote":"\"a\\b\""}"#
+    );
+}
+
+#[test]
+fn json_ld_scripts_deserialize_with_paths() {
+    let html = concat!(
+        r#"<html><head>"#,
+        r#"<script src="app.js"></script>"#,
+        r#"<script type="application/ld+json">{"@type": "Article", "headline": "One"}</script>"#,
+        r#"</head><body>"#,
+        r#"<script type="application/ld+json">[{"headline": "Two"}, {"headline": "Three"}]</script>"#,
+        r#"<script type="application/ld+json">{"title": "no headline"}</script>"#,
+        r#"</body></html>"#,
+    );
+    let doc: Element = facet_xml::from_str(html).unwrap();
+
+    let items = doc.json_ld::<Article>();
+    assert_eq!(items.len(), 4);
+
+    let found: Vec<(Vec<usize>, &str)> = items[..3]
+        .iter()
+        .map(|item| {
+            let item = item.as_ref().unwrap();
+            (item.path.clone(), item.value.headline.as_str())
+        })
+        .collect();
+    assert_eq!(
+        found,
+        [
+            (vec![0, 1], "One"),
+            (vec![1, 0], "Two"),
+            (vec![1, 0], "Three"),
+        ]
+    );
+
+    let err = items[3].as_ref().unwrap_err();
+    assert_eq!(err.path, [1, 1]);
+    assert!(err.to_string().contains("headline"), "{err}");
+}
@@ -14,6 +14,8 @@ use quick_xml::escape::resolve_xml_entity;
 use quick_xml::events::Event;
 use quick_xml::name::ResolveResult;
 
+use crate::WhitespaceMode;
+
 /// XML parsing error.
 #[derive(Debug, Clone)]
 pub enum XmlError {
@@ -61,6 +63,16 @@ pub struct XmlParser<'de> {
     is_empty_element: bool,
     /// Position where current node started (for raw capture)
     node_start_pos: u64,
+    /// How whitespace in text content is treated
+    whitespace: WhitespaceMode,
+    /// For each open element, whether `xml:space="preserve"` applies to it
+    space_preserve: Vec<bool>,
+    /// Text and entity references read so far, merged into one text node
+    text: String,
+    /// Whether `xml:space="preserve"` applies to `text`
+    text_preserve: bool,
+    /// Event read after `text` ended, to be returned after it
+    queued: Option<DomEvent<'de>>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -86,8 +98,9 @@ impl<'de> XmlParser<'de> {
     pub fn new(input: &'de [u8]) -> Self {
         trace!(input_len = input.len(), "creating XML parser");
 
-        let mut reader = NsReader::from_reader(Cursor::new(input));
-        reader.config_mut().trim_text(true);
+        // Text is trimmed (or not) by the whitespace mode once text and
+        // entity references have been merged, not piece by piece
+        let reader = NsReader::from_reader(Cursor::new(input));
 
         Self {
             reader,
@@ -100,9 +113,25 @@ impl<'de> XmlParser<'de> {
             state: ParserState::Ready,
             is_empty_element: false,
             node_start_pos: 0,
+            whitespace: WhitespaceMode::default(),
+            space_preserve: Vec::new(),
+            text: String::new(),
+            text_preserve: false,
+            queued: None,
         }
     }
 
+    /// Treat whitespace in text content according to `mode`.
+    pub fn with_whitespace(mut self, mode: WhitespaceMode) -> Self {
+        self.whitespace = mode;
+        self
+    }
+
+    /// Whether `xml:space="preserve"` applies inside the current element.
+    fn preserving_space(&self) -> bool {
+        self.space_preserve.last().copied().unwrap_or(false)
+    }
+
     /// Capture the current node as raw XML and skip past it.
     /// Must be called right after a NodeStart event has been consumed.
     fn do_capture_raw_node(&mut self) -> Result<Cow<'de, str>, XmlError> {
@@ -132,8 +161,45 @@ impl<'de> XmlParser<'de> {
         Ok(Cow::Borrowed(s))
     }
 
-    /// Read the next raw event from quick-xml and convert to DomEvent.
+    /// Read the next event, with adjacent text and entity references merged
+    /// into one `Text` event and the whitespace mode applied to it.
     fn read_next(&mut self) -> Result<Option<DomEvent<'de>>, XmlError> {
+        if let Some(event) = self.queued.take() {
+            return Ok(Some(event));
+        }
+        loop {
+            let event = self.read_piece()?;
+            if let Some(DomEvent::Text(piece)) = &event {
+                // Text outside the root element isn't content
+                if self.depth > 0 {
+                    if self.text.is_empty() {
+                        self.text_preserve = self.preserving_space();
+                    }
+                    self.text.push_str(piece);
+                }
+                continue;
+            }
+
+            if !self.text.is_empty() {
+                let text = core::mem::take(&mut self.text);
+                let mode = if self.text_preserve {
+                    WhitespaceMode::Preserve
+                } else {
+                    self.whitespace
+                };
+                if let Some(text) = mode.apply(text) {
+                    self.queued = event;
+                    return Ok(Some(DomEvent::Text(Cow::Owned(text))));
+                }
+            }
+            return Ok(event);
+        }
+    }
+
+    /// Read the next raw event from quick-xml and convert to DomEvent.
+    ///
+    /// Text comes out in pieces, split at entity references and untrimmed.
+    fn read_piece(&mut self) -> Result<Option<DomEvent<'de>>, XmlError> {
         loop {
             match self.state {
                 ParserState::Done => return Ok(None),
@@ -172,6 +238,7 @@ impl<'de> XmlParser<'de> {
 
                 ParserState::NeedNodeEnd => {
                     self.depth -= 1;
+                    self.space_preserve.pop();
                     self.state = if self.depth == 0 {
                         ParserState::Done
                     } else {
@@ -208,6 +275,7 @@ impl<'de> XmlParser<'de> {
                             // Collect attributes
                             self.pending_attrs.clear();
                             self.attr_idx = 0;
+                            let mut space_preserve = self.preserving_space();
 
                             for attr in e.attributes() {
                                 let attr = attr.map_err(|e| XmlError::Parse(e.to_string()))?;
@@ -234,6 +302,10 @@ impl<'de> XmlParser<'de> {
                                     .decode_and_unescape_value(self.reader.decoder())
                                     .map_err(|e| XmlError::Parse(e.to_string()))?;
 
+                                if key.as_ref() == b"xml:space" {
+                                    space_preserve = value == "preserve";
+                                }
+
                                 self.pending_attrs.push((
                                     attr_ns,
                                     attr_local.to_string(),
@@ -242,6 +314,7 @@ impl<'de> XmlParser<'de> {
                             }
 
                             self.depth += 1;
+                            self.space_preserve.push(space_preserve);
                             self.is_empty_element = is_empty;
 
                             if self.pending_attrs.is_empty() {
@@ -260,16 +333,13 @@ impl<'de> XmlParser<'de> {
                         }
                         Event::Text(e) => {
                             let text = e.decode().map_err(|e| XmlError::Parse(e.to_string()))?;
-                            let trimmed = text.trim();
-                            if !trimmed.is_empty() {
-                                return Ok(Some(DomEvent::Text(Cow::Owned(trimmed.to_string()))));
-                            }
+                            return Ok(Some(DomEvent::Text(Cow::Owned(text.into_owned()))));
                         }
                         Event::CData(e) => {
                             let text =
                                 core::str::from_utf8(e.as_ref()).map_err(XmlError::InvalidUtf8)?;
                             if !text.is_empty() {
-                                return Ok(Some(DomEvent::Text(Cow::Owned(text.to_string()))));
+                                return Ok(Some(DomEvent::CData(Cow::Owned(text.to_string()))));
                             }
                         }
                         Event::Comment(e) => {
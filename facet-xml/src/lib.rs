@@ -6,12 +6,16 @@ mod tracing_macros;
 
 mod dom_parser;
 mod escaping;
+mod processing_instruction;
 mod serializer;
+mod whitespace;
 
 #[cfg(feature = "axum")]
 mod axum;
 
 pub use dom_parser::{XmlError, XmlParser};
+pub use processing_instruction::ProcessingInstruction;
+pub use whitespace::WhitespaceMode;
 
 #[cfg(feature = "axum")]
 pub use axum::{Xml, XmlRejection};
@@ -90,6 +94,67 @@ where
     de.deserialize()
 }
 
+/// Options for deserializing XML.
+#[derive(Debug, Clone, Default)]
+pub struct DeserializeOptions {
+    /// How whitespace in text content is treated (default: [`WhitespaceMode::Trim`]).
+    pub whitespace: WhitespaceMode,
+}
+
+impl DeserializeOptions {
+    /// Create new default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set how whitespace in text content is treated.
+    pub const fn whitespace(mut self, mode: WhitespaceMode) -> Self {
+        self.whitespace = mode;
+        self
+    }
+}
+
+/// Deserialize a value from an XML string into an owned type, with custom options.
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+/// use facet_xml::{DeserializeOptions, WhitespaceMode, from_str_with_options};
+///
+/// #[derive(Facet, Debug, PartialEq)]
+/// struct Note {
+///     body: String,
+/// }
+///
+/// let xml = "<note><body>  two  spaces\n  </body></note>";
+/// let options = DeserializeOptions::new().whitespace(WhitespaceMode::Collapse);
+/// let note: Note = from_str_with_options(xml, &options).unwrap();
+/// assert_eq!(note.body, " two spaces ");
+/// ```
+pub fn from_str_with_options<T>(
+    input: &str,
+    options: &DeserializeOptions,
+) -> Result<T, DeserializeError<XmlError>>
+where
+    T: facet_core::Facet<'static>,
+{
+    from_slice_with_options(input.as_bytes(), options)
+}
+
+/// Deserialize a value from XML bytes into an owned type, with custom options.
+pub fn from_slice_with_options<T>(
+    input: &[u8],
+    options: &DeserializeOptions,
+) -> Result<T, DeserializeError<XmlError>>
+where
+    T: facet_core::Facet<'static>,
+{
+    let parser = XmlParser::new(input).with_whitespace(options.whitespace);
+    let mut de = facet_dom::DomDeserializer::new_owned(parser);
+    de.deserialize()
+}
+
 /// Deserialize a value from an XML string, allowing borrowing from the input.
 ///
 /// Use this when the deserialized type can borrow from the input string
@@ -166,6 +231,27 @@ facet::define_attr_grammar! {
         /// This sets the default namespace for all fields that don't have their own
         /// `xml::ns` attribute. Individual fields can override this with `xml::ns`.
         NsAll(&'static str),
+        /// Specifies the prefix to declare for this field's or container's namespace.
+        ///
+        /// Usage: `#[facet(xml::ns = "http://www.w3.org/2005/Atom", xml::prefix = "atom")]`
+        ///
+        /// Goes with `xml::ns` on a field or `xml::ns_all` on a container. When
+        /// serializing, the namespace is declared as `xmlns:atom="..."` and its
+        /// elements and attributes are written as `atom:name`, instead of with a
+        /// generated `ns0` prefix or, for `ns_all`, a default `xmlns="..."`.
+        /// Deserialization matches on the namespace URI, so documents using any
+        /// other prefix for it still parse.
+        Prefix(&'static str),
+        /// Collects repeated child elements into a map keyed by one of their attributes.
+        ///
+        /// Usage: `#[facet(xml::key = "name")]` on a `HashMap<String, T>` or
+        /// `BTreeMap<String, T>` field, where `T` is a struct.
+        ///
+        /// `<server name="a"/><server name="b"/>` deserializes into a map with
+        /// keys `"a"` and `"b"`; the key attribute is consumed by the map and the
+        /// rest of each element deserializes into `T`. When serializing, each entry
+        /// becomes an element with the key written back as its first attribute.
+        Key(&'static str),
         /// Marks an enum variant as a catch-all for unknown XML elements.
         ///
         /// Usage: `#[facet(xml::custom_element)]`
@@ -183,5 +269,26 @@ facet::define_attr_grammar! {
         ///
         /// The field type should be `Option<String>` to handle documents without DOCTYPE.
         Doctype,
+        /// Marks an enum variant as holding a CDATA section.
+        ///
+        /// Usage: `#[facet(xml::cdata)]` on a newtype variant like `CData(String)`
+        ///
+        /// When deserializing, `<![CDATA[...]]>` sections select this variant rather
+        /// than the `xml::text` one. When serializing, the content is written back as
+        /// a CDATA section instead of as escaped text. Without such a variant, CDATA
+        /// sections read as ordinary text.
+        Cdata,
+        /// Marks where processing instructions (`<?target data?>`) are kept.
+        ///
+        /// Usage: `#[facet(xml::processing_instruction)]`
+        ///
+        /// On an enum variant, processing instructions among an element's children
+        /// select this variant. On a `Vec` field of the root struct, it collects the
+        /// instructions before the root element, like `<?xml-stylesheet ...?>`.
+        /// Either way the value is a [`ProcessingInstruction`] or any struct whose
+        /// first two fields are the target and the data. When serializing, they are
+        /// written back in place. Without such a variant or field, processing
+        /// instructions are skipped.
+        ProcessingInstruction,
     }
 }
@@ -0,0 +1,49 @@
+//! Processing instructions as values.
+
+extern crate alloc;
+
+use alloc::string::String;
+
+/// A processing instruction: `<?target data?>`.
+///
+/// Use it with `#[facet(xml::processing_instruction)]`, either on an enum
+/// variant, to keep instructions found among an element's children, or on a
+/// `Vec<ProcessingInstruction>` field of the root struct, to keep the ones
+/// before the root element.
+///
+/// ```
+/// use facet::Facet;
+/// use facet_xml::{self as xml, ProcessingInstruction};
+///
+/// #[derive(Facet, Debug)]
+/// #[facet(rename = "feed")]
+/// struct Feed {
+///     #[facet(xml::processing_instruction)]
+///     prolog: Vec<ProcessingInstruction>,
+///     title: String,
+/// }
+///
+/// let input = r#"<?xml-stylesheet type="text/xsl" href="feed.xsl"?><feed><title>News</title></feed>"#;
+/// let feed: Feed = xml::from_str(input).unwrap();
+/// assert_eq!(feed.prolog[0].target, "xml-stylesheet");
+/// assert_eq!(feed.prolog[0].data, r#"type="text/xsl" href="feed.xsl""#);
+/// assert_eq!(xml::to_string(&feed).unwrap(), input);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, facet::Facet)]
+pub struct ProcessingInstruction {
+    /// The target, which names the application the instruction is for
+    /// (`xml-stylesheet`).
+    pub target: String,
+    /// Everything after the target, with surrounding whitespace trimmed.
+    pub data: String,
+}
+
+impl ProcessingInstruction {
+    /// Create a processing instruction.
+    pub fn new(target: impl Into<String>, data: impl Into<String>) -> Self {
+        Self {
+            target: target.into(),
+            data: data.into(),
+        }
+    }
+}
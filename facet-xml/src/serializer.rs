@@ -8,6 +8,7 @@ use facet_core::{Def, Facet, ScalarType};
 use facet_dom::{DomSerializeError, DomSerializer};
 use facet_reflect::Peek;
 
+use crate::WhitespaceMode;
 use crate::escaping::EscapingWriter;
 
 pub use facet_dom::FloatFormatter;
@@ -135,6 +136,13 @@ pub struct SerializeOptions {
     ///
     /// Default: `false` (all `&` characters are escaped to `&amp;`).
     pub preserve_entities: bool,
+    /// Which whitespace pretty-printing may add, matching the mode the output
+    /// will be read back with (default: [`WhitespaceMode::Trim`]).
+    ///
+    /// Elements holding text are always written on one line, so their text
+    /// comes back as written; with [`WhitespaceMode::Preserve`] no whitespace
+    /// is added at all.
+    pub whitespace: WhitespaceMode,
 }
 
 impl Default for SerializeOptions {
@@ -144,6 +152,7 @@ impl Default for SerializeOptions {
             indent: Cow::Borrowed("  "),
             float_formatter: None,
             preserve_entities: false,
+            whitespace: WhitespaceMode::Trim,
         }
     }
 }
@@ -155,6 +164,7 @@ impl core::fmt::Debug for SerializeOptions {
             .field("indent", &self.indent)
             .field("float_formatter", &self.float_formatter.map(|_| "..."))
             .field("preserve_entities", &self.preserve_entities)
+            .field("whitespace", &self.whitespace)
             .finish()
     }
 }
@@ -227,8 +237,18 @@ impl SerializeOptions {
         self.preserve_entities = preserve;
         self
     }
+
+    /// Set the whitespace mode the output will be read back with. See
+    /// [`SerializeOptions::whitespace`](Self#structfield.whitespace).
+    pub const fn whitespace(mut self, mode: WhitespaceMode) -> Self {
+        self.whitespace = mode;
+        self
+    }
 }
 
+/// The namespace bound to the `xml` prefix, which is never declared.
+const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
+
 /// Well-known XML namespace URIs and their conventional prefixes.
 #[allow(dead_code)] // Used in namespace serialization
 const WELL_KNOWN_NAMESPACES: &[(&str, &str)] = &[
@@ -256,6 +276,29 @@ impl core::fmt::Display for XmlSerializeError {
 
 impl std::error::Error for XmlSerializeError {}
 
+/// An element whose closing tag hasn't been written yet.
+struct OpenElement {
+    /// Closing tag name, including any prefix
+    close_tag: String,
+    /// Length of `in_scope_namespaces` before this element declared any
+    namespaces_before: usize,
+    /// Default namespace in effect outside this element
+    default_ns_before: Option<String>,
+    /// For a struct's element, the enclosing struct's state to restore
+    outer: Option<StructScope>,
+}
+
+/// Namespace state of the enclosing struct, saved while a nested struct
+/// is being written.
+struct StructScope {
+    /// The enclosing struct's `xml::ns_all`
+    ns_all: Option<String>,
+    /// Namespace of the enclosing struct's current field, which the nested
+    /// struct's element is written in (and every further item of an
+    /// `xml::elements` list after it)
+    field_namespace: Option<String>,
+}
+
 /// XML serializer with configurable output options.
 ///
 /// The output is designed to round-trip through `facet-xml`'s parser:
@@ -264,10 +307,13 @@ impl std::error::Error for XmlSerializeError {}
 /// - element names are treated as map keys; the root element name is ignored
 pub struct XmlSerializer {
     out: Vec<u8>,
-    /// Stack of element names for closing tags
-    element_stack: Vec<String>,
+    /// Stack of open elements, for closing tags and namespace scoping
+    element_stack: Vec<OpenElement>,
     /// Namespace URI -> prefix mapping for already-declared namespaces.
     declared_namespaces: HashMap<String, String>,
+    /// URIs whose prefix is declared on an open element, so descendants
+    /// can use it without declaring it again.
+    in_scope_namespaces: Vec<String>,
     /// Counter for auto-generating namespace prefixes (ns0, ns1, ...).
     next_ns_index: usize,
     /// The currently active default namespace (from xmlns="..." on an ancestor).
@@ -285,16 +331,25 @@ pub struct XmlSerializer {
     pending_is_doctype: bool,
     /// True if the current field is a tag field (xml::tag)
     pending_is_tag: bool,
+    /// Key attribute name if the current field is an xml::key map
+    pending_map_key: Option<&'static str>,
     /// Pending namespace for the next field
     pending_namespace: Option<String>,
     /// Serialization options (pretty-printing, float formatting, etc.)
     options: SerializeOptions,
     /// Current indentation depth for pretty-printing
     depth: usize,
+    /// True if a line break is due before the next markup (pretty-printing)
+    break_pending: bool,
+    /// Depth of the outermost open element that holds text or has
+    /// `xml:space="preserve"`; nothing inside it gets line breaks or indentation
+    inline_from: Option<usize>,
     /// True if we're collecting attributes (between element_start and children_start)
     collecting_attributes: bool,
     /// True if the next element should establish a default namespace (from ns_all)
     pending_establish_default_ns: bool,
+    /// Set by struct_metadata, taken by the struct's own element_start
+    pending_struct: Option<StructScope>,
 }
 
 impl XmlSerializer {
@@ -309,6 +364,7 @@ impl XmlSerializer {
             out: Vec::new(),
             element_stack: Vec::new(),
             declared_namespaces: HashMap::new(),
+            in_scope_namespaces: Vec::new(),
             next_ns_index: 0,
             current_default_ns: None,
             current_ns_all: None,
@@ -317,24 +373,43 @@ impl XmlSerializer {
             pending_is_elements: false,
             pending_is_doctype: false,
             pending_is_tag: false,
+            pending_map_key: None,
             pending_namespace: None,
             options,
             depth: 0,
+            break_pending: false,
+            inline_from: None,
             collecting_attributes: false,
             pending_establish_default_ns: false,
+            pending_struct: None,
         }
     }
 
-    pub fn finish(self) -> Vec<u8> {
+    pub fn finish(mut self) -> Vec<u8> {
+        if self.break_pending && self.may_add_whitespace() {
+            self.out.push(b'\n');
+        }
         self.out
     }
 
     /// Write the opening part of an element tag: `<tag` (without the closing `>`)
     /// This allows attributes to be written directly afterwards.
-    fn write_element_tag_start(&mut self, name: &str, namespace: Option<&str>) {
-        self.write_indent();
+    ///
+    /// `outer` is the enclosing struct's state when this is a struct's element,
+    /// restored when the element ends.
+    fn write_element_tag_start(
+        &mut self,
+        name: &str,
+        namespace: Option<&str>,
+        outer: Option<StructScope>,
+    ) {
+        self.start_line();
         self.out.push(b'<');
 
+        let namespaces_before = self.in_scope_namespaces.len();
+        let default_ns_before = self.current_default_ns.clone();
+        let establish_default_ns = core::mem::take(&mut self.pending_establish_default_ns);
+
         // Track the close tag (may include prefix)
         let close_tag: String;
 
@@ -344,14 +419,13 @@ impl XmlSerializer {
                 // Element is in the current default namespace - use unprefixed form
                 self.out.extend_from_slice(name.as_bytes());
                 close_tag = name.to_string();
-            } else if self.pending_establish_default_ns {
+            } else if establish_default_ns && self.current_ns_all.as_deref() == Some(ns_uri) {
                 // This is a struct root with ns_all - establish as default namespace
                 self.out.extend_from_slice(name.as_bytes());
                 self.out.extend_from_slice(b" xmlns=\"");
                 self.out.extend_from_slice(ns_uri.as_bytes());
                 self.out.push(b'"');
                 self.current_default_ns = Some(ns_uri.to_string());
-                self.pending_establish_default_ns = false;
                 close_tag = name.to_string();
             } else {
                 // Field-level namespace - use prefix
@@ -359,12 +433,11 @@ impl XmlSerializer {
                 self.out.extend_from_slice(prefix.as_bytes());
                 self.out.push(b':');
                 self.out.extend_from_slice(name.as_bytes());
-                // Write xmlns declaration for this prefix
-                self.out.extend_from_slice(b" xmlns:");
-                self.out.extend_from_slice(prefix.as_bytes());
-                self.out.extend_from_slice(b"=\"");
-                self.out.extend_from_slice(ns_uri.as_bytes());
-                self.out.push(b'"');
+                // Declare the prefix unless an ancestor already did
+                if self.bring_into_scope(ns_uri) {
+                    self.out.push(b' ');
+                    self.write_prefix_declaration(&prefix, ns_uri);
+                }
                 close_tag = format!("{}:{}", prefix, name);
             }
         } else {
@@ -372,8 +445,37 @@ impl XmlSerializer {
             close_tag = name.to_string();
         }
 
-        // Push the close tag for element_end
-        self.element_stack.push(close_tag);
+        // Push the open element for element_end
+        self.element_stack.push(OpenElement {
+            close_tag,
+            namespaces_before,
+            default_ns_before,
+            outer,
+        });
+    }
+
+    /// Mark a namespace's prefix as declared on the current element.
+    /// Returns false if it is already in scope, so needs no declaration.
+    fn bring_into_scope(&mut self, namespace_uri: &str) -> bool {
+        if namespace_uri == XML_NAMESPACE
+            || self
+                .in_scope_namespaces
+                .iter()
+                .any(|uri| uri == namespace_uri)
+        {
+            return false;
+        }
+        self.in_scope_namespaces.push(namespace_uri.to_string());
+        true
+    }
+
+    /// Write `xmlns:prefix="uri"`.
+    fn write_prefix_declaration(&mut self, prefix: &str, namespace_uri: &str) {
+        self.out.extend_from_slice(b"xmlns:");
+        self.out.extend_from_slice(prefix.as_bytes());
+        self.out.extend_from_slice(b"=\"");
+        self.out.extend_from_slice(namespace_uri.as_bytes());
+        self.out.push(b'"');
     }
 
     /// Write an attribute directly to the output: ` name="escaped_value"`
@@ -401,12 +503,11 @@ impl XmlSerializer {
         self.out.push(b' ');
         if let Some(ns_uri) = namespace {
             let prefix = self.get_or_create_prefix(ns_uri);
-            // Write xmlns declaration
-            self.out.extend_from_slice(b"xmlns:");
-            self.out.extend_from_slice(prefix.as_bytes());
-            self.out.extend_from_slice(b"=\"");
-            self.out.extend_from_slice(ns_uri.as_bytes());
-            self.out.extend_from_slice(b"\" ");
+            // Declare the prefix unless an ancestor already did
+            if self.bring_into_scope(ns_uri) {
+                self.write_prefix_declaration(&prefix, ns_uri);
+                self.out.push(b' ');
+            }
             // Write prefixed attribute
             self.out.extend_from_slice(prefix.as_bytes());
             self.out.push(b':');
@@ -421,17 +522,26 @@ impl XmlSerializer {
     /// Finish the element opening tag by writing `>` and incrementing depth.
     fn write_element_tag_end(&mut self) {
         self.out.push(b'>');
-        self.write_newline();
+        self.break_pending = true;
         self.depth += 1;
     }
 
     fn write_close_tag(&mut self, name: &str) {
         self.depth = self.depth.saturating_sub(1);
-        self.write_indent();
+        self.start_line();
         self.out.extend_from_slice(b"</");
         self.out.extend_from_slice(name.as_bytes());
         self.out.push(b'>');
-        self.write_newline();
+        self.break_pending = true;
+        if self.inline_from.is_some_and(|depth| depth > self.depth) {
+            self.inline_from = None;
+        }
+    }
+
+    /// Keep everything inside the current element on one line.
+    fn write_inline(&mut self) {
+        self.inline_from.get_or_insert(self.depth);
+        self.break_pending = false;
     }
 
     fn write_text_escaped(&mut self, text: &str) {
@@ -445,20 +555,41 @@ impl XmlSerializer {
         }
     }
 
-    /// Write indentation for the current depth (if pretty-printing is enabled).
-    fn write_indent(&mut self) {
-        if self.options.pretty {
+    /// Whether pretty-printing may add whitespace here: it is enabled, the
+    /// whitespace mode ignores inter-element whitespace, and no open element
+    /// holds text.
+    fn may_add_whitespace(&self) -> bool {
+        self.options.pretty
+            && self.options.whitespace != WhitespaceMode::Preserve
+            && self.inline_from.is_none()
+    }
+
+    /// Put the next markup on its own line, indented for the current depth
+    /// (if pretty-printing may add whitespace here).
+    fn start_line(&mut self) {
+        if self.may_add_whitespace() {
+            if self.break_pending {
+                self.out.push(b'\n');
+            }
             for _ in 0..self.depth {
                 self.out.extend_from_slice(self.options.indent.as_bytes());
             }
         }
+        self.break_pending = false;
     }
 
-    /// Write a newline (if pretty-printing is enabled).
-    fn write_newline(&mut self) {
-        if self.options.pretty {
-            self.out.push(b'\n');
+    /// Use `prefix` for `namespace_uri` from now on, as asked for by `xml::prefix`.
+    ///
+    /// Ignored if the namespace already has a prefix, so one namespace isn't
+    /// written with two, or if another namespace already uses this one.
+    fn prefer_prefix(&mut self, namespace_uri: &str, prefix: &str) {
+        if self.declared_namespaces.contains_key(namespace_uri)
+            || self.declared_namespaces.values().any(|p| p == prefix)
+        {
+            return;
         }
+        self.declared_namespaces
+            .insert(namespace_uri.to_string(), prefix.to_string());
     }
 
     /// Get or create a prefix for the given namespace URI.
@@ -500,6 +631,7 @@ impl XmlSerializer {
         self.pending_is_elements = false;
         self.pending_is_doctype = false;
         self.pending_is_tag = false;
+        self.pending_map_key = None;
         self.pending_namespace = None;
     }
 }
@@ -514,14 +646,22 @@ impl DomSerializer for XmlSerializer {
     type Error = XmlSerializeError;
 
     fn element_start(&mut self, tag: &str, namespace: Option<&str>) -> Result<(), Self::Error> {
-        // Priority: explicit namespace > pending_namespace > current_ns_all (for struct roots)
+        // A struct's element is in the namespace of the field holding it, which
+        // struct_metadata saved before the struct's own fields were looked at
+        let outer = self.pending_struct.take();
+        let field_namespace = match &outer {
+            Some(outer) => outer.field_namespace.clone(),
+            None => self.pending_namespace.take(),
+        };
+
+        // Priority: explicit namespace > field namespace > current_ns_all (for struct roots)
         let ns = namespace
             .map(|s| s.to_string())
-            .or_else(|| self.pending_namespace.take())
+            .or(field_namespace)
             .or_else(|| self.current_ns_all.clone());
 
         // Write the opening tag immediately: `<tag` (attributes will follow)
-        self.write_element_tag_start(tag, ns.as_deref());
+        self.write_element_tag_start(tag, ns.as_deref(), outer);
         self.collecting_attributes = true;
 
         Ok(())
@@ -546,6 +686,14 @@ impl DomSerializer for XmlSerializer {
             None => self.pending_namespace.clone(),
         };
 
+        // Whitespace is significant inside `xml:space="preserve"`, so the
+        // element's content is kept on one line
+        let is_xml_space =
+            (name == "space" && ns.as_deref() == Some(XML_NAMESPACE)) || name == "xml:space";
+        if is_xml_space && value.innermost_peek().as_str() == Some("preserve") {
+            self.inline_from.get_or_insert(self.depth + 1);
+        }
+
         // Write directly to output
         self.write_attribute(name, value, ns.as_deref())
             .map_err(|e| XmlSerializeError {
@@ -566,28 +714,83 @@ impl DomSerializer for XmlSerializer {
     }
 
     fn element_end(&mut self, _tag: &str) -> Result<(), Self::Error> {
-        if let Some(close_tag) = self.element_stack.pop() {
-            self.write_close_tag(&close_tag);
+        if let Some(element) = self.element_stack.pop() {
+            self.write_close_tag(&element.close_tag);
+            // Declarations made on this element go out of scope with it
+            self.in_scope_namespaces.truncate(element.namespaces_before);
+            self.current_default_ns = element.default_ns_before;
+            if let Some(outer) = element.outer {
+                self.current_ns_all = outer.ns_all;
+                self.pending_namespace = outer.field_namespace;
+            }
         }
         Ok(())
     }
 
     fn text(&mut self, content: &str) -> Result<(), Self::Error> {
+        self.write_inline();
         self.write_text_escaped(content);
         Ok(())
     }
 
+    fn cdata(&mut self, content: &str) -> Result<(), Self::Error> {
+        self.write_inline();
+        // `]]>` can't appear inside a CDATA section, so it's split across two
+        self.out.extend_from_slice(b"<![CDATA[");
+        self.out
+            .extend_from_slice(content.replace("]]>", "]]]]><![CDATA[>").as_bytes());
+        self.out.extend_from_slice(b"]]>");
+        Ok(())
+    }
+
+    fn processing_instruction(&mut self, target: &str, data: &str) -> Result<(), Self::Error> {
+        if target.is_empty() || target.contains(|c: char| c.is_whitespace() || c == '?') {
+            return Err(XmlSerializeError {
+                msg: Cow::Owned(format!("invalid processing instruction target {target:?}")),
+            });
+        }
+        if data.contains("?>") {
+            return Err(XmlSerializeError {
+                msg: Cow::Borrowed("processing instruction data can't contain `?>`"),
+            });
+        }
+        self.start_line();
+        self.out.extend_from_slice(b"<?");
+        self.out.extend_from_slice(target.as_bytes());
+        if !data.is_empty() {
+            self.out.push(b' ');
+            self.out.extend_from_slice(data.as_bytes());
+        }
+        self.out.extend_from_slice(b"?>");
+        self.break_pending = true;
+        Ok(())
+    }
+
     fn struct_metadata(&mut self, shape: &facet_core::Shape) -> Result<(), Self::Error> {
+        let container_attr = |key: &str| {
+            shape
+                .attributes
+                .iter()
+                .find(|attr| attr.ns == Some("xml") && attr.key == key)
+                .and_then(|attr| attr.get_as::<&str>().copied())
+        };
+
         // Extract xml::ns_all attribute from the struct
-        self.current_ns_all = shape
-            .attributes
-            .iter()
-            .find(|attr| attr.ns == Some("xml") && attr.key == "ns_all")
-            .and_then(|attr| attr.get_as::<&str>().copied())
-            .map(String::from);
+        let ns_all = container_attr("ns_all");
+        let prefix = container_attr("prefix");
+        if let (Some(ns_uri), Some(prefix)) = (ns_all, prefix) {
+            self.prefer_prefix(ns_uri, prefix);
+        }
+
+        // Save the enclosing struct's state until this struct's element ends
+        self.pending_struct = Some(StructScope {
+            ns_all: core::mem::replace(&mut self.current_ns_all, ns_all.map(String::from)),
+            field_namespace: self.pending_namespace.clone(),
+        });
 
-        // If ns_all is set, the next element_start should establish it as default namespace
-        self.pending_establish_default_ns = self.current_ns_all.is_some();
+        // If ns_all is set, the next element_start should establish it as default
+        // namespace, unless a prefix was asked for
+        self.pending_establish_default_ns = ns_all.is_some() && prefix.is_none();
 
         Ok(())
     }
@@ -600,6 +803,7 @@ impl DomSerializer for XmlSerializer {
             self.pending_is_elements = false;
             self.pending_is_doctype = false;
             self.pending_is_tag = false;
+            self.pending_map_key = None;
             return Ok(());
         };
 
@@ -613,11 +817,21 @@ impl DomSerializer for XmlSerializer {
         self.pending_is_doctype = field_def.get_attr(Some("xml"), "doctype").is_some();
         // Check if this field is a tag field
         self.pending_is_tag = field_def.get_attr(Some("xml"), "tag").is_some();
+        // Check if this field is an xml::key map
+        self.pending_map_key = field_def
+            .get_attr(Some("xml"), "key")
+            .and_then(|attr| attr.get_as::<&str>().copied());
 
         // Extract xml::ns attribute from the field
         if let Some(ns_attr) = field_def.get_attr(Some("xml"), "ns")
             && let Some(ns_uri) = ns_attr.get_as::<&str>().copied()
         {
+            if let Some(prefix) = field_def
+                .get_attr(Some("xml"), "prefix")
+                .and_then(|attr| attr.get_as::<&str>().copied())
+            {
+                self.prefer_prefix(ns_uri, prefix);
+            }
             self.pending_namespace = Some(ns_uri.to_string());
         } else if !self.pending_is_attribute && !self.pending_is_text {
             // Apply ns_all to elements only (or None if no ns_all)
@@ -649,6 +863,10 @@ impl DomSerializer for XmlSerializer {
         self.pending_is_elements
     }
 
+    fn map_key_attribute(&self) -> Option<&'static str> {
+        self.pending_map_key
+    }
+
     fn is_doctype_field(&self) -> bool {
         self.pending_is_doctype
     }
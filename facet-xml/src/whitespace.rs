@@ -0,0 +1,72 @@
+//! Whitespace handling in text content.
+
+use alloc::string::String;
+
+/// How whitespace in text content is treated when reading XML, and which
+/// whitespace pretty-printing may add when writing it.
+///
+/// Pick the same mode on both sides: a document pretty-printed with a mode
+/// reads back to the same value with that mode. Elements marked
+/// `xml:space="preserve"` (and their descendants, unless reset with
+/// `xml:space="default"`) are always treated as [`WhitespaceMode::Preserve`];
+/// that is the place for `<pre>`-like content.
+///
+/// Attribute values are never affected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum WhitespaceMode {
+    /// Trim each text node and drop the ones that are only whitespace.
+    ///
+    /// This is the default. Spaces at the edges of text are lost, so
+    /// `<p>Hello <b>world</b></p>` reads as `"Hello"` and `"world"`.
+    #[default]
+    Trim,
+
+    /// Keep text exactly as written, including whitespace between elements.
+    ///
+    /// Since all whitespace is significant, pretty-printing adds none in
+    /// this mode and the output is compact.
+    Preserve,
+
+    /// Collapse each run of whitespace into a single space, the way HTML
+    /// renders text, and drop text that is only whitespace.
+    Collapse,
+
+    /// Drop text that is only whitespace (the indentation between elements)
+    /// and keep all other text as written, edges included.
+    TrimInterElement,
+}
+
+impl WhitespaceMode {
+    /// Apply the mode to a text node, returning `None` if it should be
+    /// dropped.
+    pub(crate) fn apply(self, text: String) -> Option<String> {
+        let blank = text.chars().all(is_xml_whitespace);
+        match self {
+            WhitespaceMode::Preserve => (!text.is_empty()).then_some(text),
+            _ if blank => None,
+            WhitespaceMode::Trim => Some(text.trim_matches(is_xml_whitespace).into()),
+            WhitespaceMode::TrimInterElement => Some(text),
+            WhitespaceMode::Collapse => {
+                let mut collapsed = String::with_capacity(text.len());
+                let mut in_space = false;
+                for c in text.chars() {
+                    if is_xml_whitespace(c) {
+                        if !in_space {
+                            collapsed.push(' ');
+                        }
+                        in_space = true;
+                    } else {
+                        collapsed.push(c);
+                        in_space = false;
+                    }
+                }
+                Some(collapsed)
+            }
+        }
+    }
+}
+
+/// The whitespace characters of the XML spec: space, tab, CR and LF.
+fn is_xml_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\r' | '\n')
+}
@@ -0,0 +1,144 @@
+//! Tests for keeping CDATA sections and processing instructions across a round trip.
+
+use facet::Facet;
+use facet_xml::{self as xml, ProcessingInstruction};
+
+#[derive(Facet, Debug, PartialEq)]
+#[repr(u8)]
+enum Node {
+    #[facet(xml::text)]
+    Text(String),
+    #[facet(xml::cdata)]
+    CData(String),
+    #[facet(xml::processing_instruction)]
+    Pi(ProcessingInstruction),
+    #[facet(rename = "b")]
+    Bold(String),
+}
+
+#[derive(Facet, Debug, PartialEq)]
+#[facet(rename = "doc")]
+struct Doc {
+    #[facet(flatten, default)]
+    nodes: Vec<Node>,
+}
+
+#[test]
+fn cdata_and_processing_instructions_round_trip() {
+    let input = "<doc>before<![CDATA[a < b && c]]><?php echo 1;?><b>bold</b>after</doc>";
+
+    let doc: Doc = xml::from_str(input).unwrap();
+    assert_eq!(
+        doc.nodes,
+        vec![
+            Node::Text("before".into()),
+            Node::CData("a < b && c".into()),
+            Node::Pi(ProcessingInstruction::new("php", "echo 1;")),
+            Node::Bold("bold".into()),
+            Node::Text("after".into()),
+        ]
+    );
+
+    assert_eq!(xml::to_string(&doc).unwrap(), input);
+}
+
+#[test]
+fn cdata_reads_as_text_without_a_cdata_variant() {
+    #[derive(Facet, Debug, PartialEq)]
+    #[facet(rename = "page")]
+    struct Page {
+        script: String,
+    }
+
+    let page: Page =
+        xml::from_str("<page><script><![CDATA[if (a < b) { go(); }]]></script></page>").unwrap();
+    assert_eq!(page.script, "if (a < b) { go(); }");
+
+    // Without an xml::cdata variant, the content is written back as escaped text
+    assert_eq!(
+        xml::to_string(&page).unwrap(),
+        "<page><script>if (a &lt; b) { go(); }</script></page>"
+    );
+}
+
+#[test]
+fn cdata_containing_its_terminator_is_split() {
+    let doc = Doc {
+        nodes: vec![Node::CData("x]]>y".into())],
+    };
+    let out = xml::to_string(&doc).unwrap();
+    assert_eq!(out, "<doc><![CDATA[x]]]]><![CDATA[>y]]></doc>");
+
+    let back: Doc = xml::from_str(&out).unwrap();
+    assert_eq!(
+        back.nodes,
+        vec![Node::CData("x]]".into()), Node::CData(">y".into())]
+    );
+}
+
+#[test]
+fn processing_instructions_are_skipped_without_a_variant() {
+    #[derive(Facet, Debug, PartialEq)]
+    #[facet(rename = "config")]
+    struct Config {
+        name: String,
+    }
+
+    let input = r#"<?xml version="1.0"?>
+<?xml-stylesheet type="text/xsl" href="style.xsl"?>
+<!-- generated -->
+<config><?render fast?><name>demo</name></config>"#;
+    let config: Config = xml::from_str(input).unwrap();
+    assert_eq!(config.name, "demo");
+}
+
+#[test]
+fn prolog_processing_instructions_round_trip() {
+    #[derive(Facet, Debug, PartialEq)]
+    #[facet(rename = "catalog")]
+    struct Catalog {
+        #[facet(xml::processing_instruction)]
+        prolog: Vec<ProcessingInstruction>,
+        #[facet(xml::doctype)]
+        doctype: Option<String>,
+        title: String,
+    }
+
+    let input = r#"<?xml version="1.0"?>
+<?xml-stylesheet type="text/xsl" href="catalog.xsl"?>
+<?xml-stylesheet type="text/css" href="catalog.css"?>
+<!DOCTYPE catalog SYSTEM "catalog.dtd">
+<catalog><title>Books</title></catalog>"#;
+
+    let catalog: Catalog = xml::from_str(input).unwrap();
+    assert_eq!(
+        catalog.prolog,
+        vec![
+            ProcessingInstruction::new("xml-stylesheet", r#"type="text/xsl" href="catalog.xsl""#),
+            ProcessingInstruction::new("xml-stylesheet", r#"type="text/css" href="catalog.css""#),
+        ]
+    );
+    assert_eq!(catalog.title, "Books");
+
+    assert_eq!(
+        xml::to_string(&catalog).unwrap(),
+        concat!(
+            r#"<?xml-stylesheet type="text/xsl" href="catalog.xsl"?>"#,
+            r#"<?xml-stylesheet type="text/css" href="catalog.css"?>"#,
+            r#"<!DOCTYPE catalog SYSTEM "catalog.dtd">"#,
+            "<catalog><title>Books</title></catalog>",
+        )
+    );
+
+    // No instructions is an empty list, not a missing field
+    let bare: Catalog = xml::from_str("<catalog><title>Books</title></catalog>").unwrap();
+    assert!(bare.prolog.is_empty());
+}
+
+#[test]
+fn invalid_processing_instructions_are_rejected() {
+    let doc = Doc {
+        nodes: vec![Node::Pi(ProcessingInstruction::new("php", "echo '?>';"))],
+    };
+    assert!(xml::to_string(&doc).is_err());
+}
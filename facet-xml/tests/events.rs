@@ -0,0 +1,44 @@
+//! Reading a document as a stream of events, without building a value.
+
+use facet_dom::{DomEvent, DomEvents};
+use facet_xml::XmlParser;
+
+const FEED: &str = r#"<feed><entry id="1"><title>One</title></entry><entry id="2"><title>Two</title><body>long text</body></entry></feed>"#;
+
+#[test]
+fn events_are_streamed_in_document_order() {
+    let mut titles = Vec::new();
+    let mut in_title = false;
+    for event in DomEvents::new(XmlParser::new(FEED.as_bytes())) {
+        match event.unwrap() {
+            DomEvent::NodeStart { tag, .. } => in_title = tag == "title",
+            DomEvent::Text(text) if in_title => titles.push(text.into_owned()),
+            DomEvent::NodeEnd => in_title = false,
+            _ => {}
+        }
+    }
+    assert_eq!(titles, ["One", "Two"]);
+}
+
+#[test]
+fn subtrees_can_be_skipped() {
+    let mut events = DomEvents::new(XmlParser::new(FEED.as_bytes()));
+    let mut tags = Vec::new();
+    while let Some(event) = events.next() {
+        if let DomEvent::NodeStart { tag, .. } = event.unwrap() {
+            if tag == "entry" {
+                events.skip_node().unwrap();
+            }
+            tags.push(tag.into_owned());
+        }
+    }
+    assert_eq!(tags, ["feed", "entry", "entry"]);
+}
+
+#[test]
+fn iteration_stops_at_the_first_error() {
+    let mut events = DomEvents::new(XmlParser::new(b"<a><b></a>"));
+    let results: Vec<_> = events.by_ref().collect();
+    assert!(results.last().unwrap().is_err(), "{results:?}");
+    assert!(events.next().is_none());
+}
@@ -0,0 +1,131 @@
+//! Tests for `xml::key`: repeated sibling elements collected into a map keyed
+//! by an attribute.
+
+use std::collections::{BTreeMap, HashMap};
+
+use facet::Facet;
+use facet_testhelpers::test;
+use facet_xml as xml;
+
+#[derive(Facet, Debug, PartialEq)]
+struct Server {
+    #[facet(xml::attribute)]
+    port: u16,
+    #[facet(default)]
+    host: Option<String>,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Config {
+    #[facet(xml::attribute)]
+    version: u32,
+    #[facet(xml::key = "name")]
+    servers: BTreeMap<String, Server>,
+    timeout: u32,
+}
+
+#[test]
+fn keyed_map_collects_siblings_by_attribute() {
+    let config: Config = facet_xml::from_str(
+        r#"<config version="2">
+            <server name="primary" port="80"><host>example.com</host></server>
+            <timeout>30</timeout>
+            <server port="8080" name="backup"/>
+        </config>"#,
+    )
+    .unwrap();
+
+    assert_eq!(config.version, 2);
+    assert_eq!(config.timeout, 30);
+    assert_eq!(config.servers.len(), 2);
+    assert_eq!(
+        config.servers["primary"],
+        Server {
+            port: 80,
+            host: Some("example.com".to_string()),
+        }
+    );
+    assert_eq!(
+        config.servers["backup"],
+        Server {
+            port: 8080,
+            host: None,
+        }
+    );
+}
+
+#[test]
+fn keyed_map_without_entries_is_empty() {
+    let config: Config =
+        facet_xml::from_str(r#"<config version="1"><timeout>5</timeout></config>"#).unwrap();
+    assert!(config.servers.is_empty());
+}
+
+#[test]
+fn keyed_map_with_rename_and_hash_map() {
+    #[derive(Facet, Debug, PartialEq)]
+    struct Appender {
+        #[facet(xml::attribute, rename = "class")]
+        class_name: String,
+    }
+
+    #[derive(Facet, Debug, PartialEq)]
+    struct Logging {
+        #[facet(xml::key = "id", rename = "appender")]
+        appenders: HashMap<String, Appender>,
+    }
+
+    let logging: Logging = facet_xml::from_str(
+        r#"<logging><appender id="file" class="FileAppender"/><appender id="console" class="ConsoleAppender"/></logging>"#,
+    )
+    .unwrap();
+    assert_eq!(logging.appenders.len(), 2);
+    assert_eq!(logging.appenders["file"].class_name, "FileAppender");
+    assert_eq!(logging.appenders["console"].class_name, "ConsoleAppender");
+}
+
+#[test]
+fn keyed_map_entry_without_key_is_an_error() {
+    let err = facet_xml::from_str::<Config>(
+        r#"<config version="1"><server port="80"/><timeout>5</timeout></config>"#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("name"), "{err}");
+}
+
+#[test]
+fn keyed_map_serializes_key_as_first_attribute() {
+    let mut servers = BTreeMap::new();
+    servers.insert(
+        "backup".to_string(),
+        Server {
+            port: 8080,
+            host: None,
+        },
+    );
+    servers.insert(
+        "primary".to_string(),
+        Server {
+            port: 80,
+            host: Some("example.com".to_string()),
+        },
+    );
+    let config = Config {
+        version: 3,
+        servers,
+        timeout: 10,
+    };
+
+    let output = facet_xml::to_string(&config).unwrap();
+    assert!(
+        output.contains(r#"<servers name="backup" port="8080""#),
+        "{output}"
+    );
+    assert!(
+        output.contains(r#"<servers name="primary" port="80""#),
+        "{output}"
+    );
+
+    let roundtrip: Config = facet_xml::from_str(&output).unwrap();
+    assert_eq!(roundtrip, config);
+}
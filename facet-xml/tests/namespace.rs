@@ -1631,3 +1631,162 @@ fn test_serialize_options_preserve_entities() {
         "With preserve_entities, &amp; should be preserved: {xml_preserved}"
     );
 }
+
+// ============================================================================
+// Preferred prefixes and namespace scoping
+// ============================================================================
+
+#[derive(Facet, Debug, PartialEq)]
+#[facet(rename = "root")]
+struct PrefixedField {
+    #[facet(xml::ns = "http://example.com/ns", xml::prefix = "ex")]
+    item: String,
+}
+
+#[test]
+fn test_field_prefix_is_used_when_serializing() {
+    let value = PrefixedField {
+        item: "value".to_string(),
+    };
+    let xml_output = to_string(&value).unwrap();
+    assert_eq!(
+        xml_output,
+        r#"<root><ex:item xmlns:ex="http://example.com/ns">value</ex:item></root>"#
+    );
+
+    let parsed: PrefixedField = from_str(&xml_output).unwrap();
+    assert_eq!(parsed, value);
+}
+
+#[test]
+fn test_field_prefix_does_not_restrict_deserialization() {
+    // Matching is by namespace URI, so any prefix for it will do
+    let xml = r#"<root xmlns:other="http://example.com/ns"><other:item>value</other:item></root>"#;
+    let parsed: PrefixedField = from_str(xml).unwrap();
+    assert_eq!(parsed.item, "value");
+}
+
+#[derive(Facet, Debug, PartialEq)]
+#[facet(
+    rename = "Envelope",
+    xml::ns_all = "http://schemas.xmlsoap.org/soap/envelope/",
+    xml::prefix = "soap"
+)]
+struct SoapEnvelope {
+    #[facet(rename = "Body")]
+    body: SoapBody,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+#[facet(
+    xml::ns_all = "http://schemas.xmlsoap.org/soap/envelope/",
+    xml::prefix = "soap"
+)]
+struct SoapBody {
+    #[facet(
+        rename = "GetPrice",
+        xml::ns = "http://example.com/stock",
+        xml::prefix = "m"
+    )]
+    get_price: GetPrice,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct GetPrice {
+    #[facet(
+        rename = "Item",
+        xml::ns = "http://example.com/stock",
+        xml::prefix = "m"
+    )]
+    item: String,
+}
+
+#[test]
+fn test_soap_envelope_declares_each_namespace_once() {
+    let envelope = SoapEnvelope {
+        body: SoapBody {
+            get_price: GetPrice {
+                item: "Apples".to_string(),
+            },
+        },
+    };
+
+    let xml_output = to_string(&envelope).unwrap();
+    assert_eq!(
+        xml_output,
+        concat!(
+            r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">"#,
+            r#"<soap:Body>"#,
+            r#"<m:GetPrice xmlns:m="http://example.com/stock"><m:Item>Apples</m:Item></m:GetPrice>"#,
+            r#"</soap:Body>"#,
+            r#"</soap:Envelope>"#,
+        )
+    );
+
+    let parsed: SoapEnvelope = from_str(&xml_output).unwrap();
+    assert_eq!(parsed, envelope);
+}
+
+#[test]
+fn test_soap_envelope_with_other_prefixes() {
+    let xml = r#"<s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/">
+        <s:Body>
+            <GetPrice xmlns="http://example.com/stock"><Item>Pears</Item></GetPrice>
+        </s:Body>
+    </s:Envelope>"#;
+
+    let parsed: SoapEnvelope = from_str(xml).unwrap();
+    assert_eq!(parsed.body.get_price.item, "Pears");
+}
+
+#[derive(Facet, Debug, PartialEq)]
+#[facet(rename = "feed", xml::ns_all = "http://www.w3.org/2005/Atom")]
+struct AtomFeed {
+    title: String,
+    #[facet(xml::elements, rename = "entry")]
+    entries: Vec<AtomEntry>,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+#[facet(xml::ns_all = "http://www.w3.org/2005/Atom")]
+struct AtomEntry {
+    title: String,
+    #[facet(
+        xml::attribute,
+        xml::ns = "http://example.com/meta",
+        xml::prefix = "meta"
+    )]
+    source: Option<String>,
+}
+
+#[test]
+fn test_atom_feed_roundtrip() {
+    let feed = AtomFeed {
+        title: "Example Feed".to_string(),
+        entries: vec![
+            AtomEntry {
+                title: "First".to_string(),
+                source: Some("import".to_string()),
+            },
+            AtomEntry {
+                title: "Second".to_string(),
+                source: None,
+            },
+        ],
+    };
+
+    let xml_output = to_string(&feed).unwrap();
+    assert_eq!(
+        xml_output,
+        concat!(
+            r#"<feed xmlns="http://www.w3.org/2005/Atom">"#,
+            r#"<title>Example Feed</title>"#,
+            r#"<entry xmlns:meta="http://example.com/meta" meta:source="import"><title>First</title></entry>"#,
+            r#"<entry><title>Second</title></entry>"#,
+            r#"</feed>"#,
+        )
+    );
+
+    let parsed: AtomFeed = from_str(&xml_output).unwrap();
+    assert_eq!(parsed, feed);
+}
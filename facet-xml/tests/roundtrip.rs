@@ -525,6 +525,16 @@ fn scalar_floats() {
     let parsed: Record = facet_xml::from_str(xml).unwrap();
     assert_eq!(parsed.float_32, 1.5);
     assert_eq!(parsed.float_64, 2.25);
+
+    // f32 is written as an f32, not widened to f64 first
+    let record = Record {
+        float_32: 0.1,
+        float_64: 0.1,
+    };
+    assert_eq!(
+        facet_xml::to_string(&record).unwrap(),
+        "<record><float32>0.1</float32><float64>0.1</float64></record>"
+    );
 }
 
 #[test]
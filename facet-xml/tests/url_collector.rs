@@ -0,0 +1,85 @@
+//! Collecting asset URLs while serializing, with `facet_dom::UrlCollector`.
+
+use facet::Facet;
+use facet_dom::UrlCollector;
+use facet_reflect::Peek;
+use facet_xml::{self as xml, XmlSerializer};
+
+#[derive(Facet, Debug)]
+struct Link {
+    #[facet(xml::attribute)]
+    rel: String,
+    #[facet(xml::attribute)]
+    href: String,
+}
+
+#[derive(Facet, Debug)]
+struct Img {
+    #[facet(xml::attribute)]
+    alt: String,
+    #[facet(xml::attribute)]
+    src: String,
+    #[facet(xml::attribute)]
+    srcset: Option<String>,
+}
+
+#[derive(Facet, Debug)]
+#[facet(rename = "page")]
+struct Page {
+    link: Link,
+    title: String,
+    img: Img,
+}
+
+fn page() -> Page {
+    Page {
+        link: Link {
+            rel: "stylesheet".into(),
+            href: "/style.css".into(),
+        },
+        title: "see /not-a-url.png".into(),
+        img: Img {
+            alt: "logo.png".into(),
+            src: " /logo.png ".into(),
+            srcset: Some("/logo@2x.png 2x, /logo@3x.png 3x".into()),
+        },
+    }
+}
+
+#[test]
+fn reports_urls_in_document_order() {
+    let page = page();
+    let mut urls = Vec::new();
+    let mut serializer = UrlCollector::new(XmlSerializer::new(), |attr| {
+        urls.push(format!("{} {} {}", attr.tag, attr.name, attr.url));
+    });
+    facet_dom::serialize(&mut serializer, Peek::new(&page)).unwrap();
+    let out = String::from_utf8(serializer.into_inner().finish()).unwrap();
+
+    assert_eq!(
+        urls,
+        [
+            "link href /style.css",
+            "img src /logo.png",
+            "img srcset /logo@2x.png",
+            "img srcset /logo@3x.png",
+        ]
+    );
+    // Collecting doesn't change the output.
+    assert_eq!(out, xml::to_string(&page).unwrap());
+}
+
+#[test]
+fn missing_url_attributes_are_not_reported() {
+    let mut page = page();
+    page.img.srcset = None;
+    page.link.href = String::new();
+
+    let mut urls = Vec::new();
+    let mut serializer = UrlCollector::new(XmlSerializer::new(), |attr| {
+        urls.push(attr.url.to_owned());
+    });
+    facet_dom::serialize(&mut serializer, Peek::new(&page)).unwrap();
+
+    assert_eq!(urls, ["/logo.png"]);
+}
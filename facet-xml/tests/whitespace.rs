@@ -0,0 +1,118 @@
+//! Tests for `WhitespaceMode` on parse and serialize.
+
+use facet::Facet;
+use facet_testhelpers::test;
+use facet_xml::{
+    self as xml, DeserializeOptions, SerializeOptions, WhitespaceMode, from_str_with_options,
+    to_string_with_options,
+};
+
+#[derive(Facet, Debug, PartialEq)]
+#[facet(rename = "doc")]
+struct Doc {
+    title: String,
+    pre: Pre,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Pre {
+    #[facet(xml::text)]
+    text: String,
+}
+
+const DOC: &str = "<doc>\n  <title>  A   title\n  </title>\n  <pre xml:space=\"preserve\">  keep\n  this </pre>\n</doc>\n";
+
+fn parse(mode: WhitespaceMode) -> Doc {
+    from_str_with_options(DOC, &DeserializeOptions::new().whitespace(mode)).unwrap()
+}
+
+#[test]
+fn parse_modes() {
+    assert_eq!(parse(WhitespaceMode::Trim).title, "A   title");
+    assert_eq!(parse(WhitespaceMode::Collapse).title, " A title ");
+    assert_eq!(
+        parse(WhitespaceMode::TrimInterElement).title,
+        "  A   title\n  "
+    );
+    assert_eq!(parse(WhitespaceMode::Preserve).title, "  A   title\n  ");
+
+    for mode in [
+        WhitespaceMode::Trim,
+        WhitespaceMode::Collapse,
+        WhitespaceMode::TrimInterElement,
+        WhitespaceMode::Preserve,
+    ] {
+        assert_eq!(parse(mode).pre.text, "  keep\n  this ", "{mode:?}");
+    }
+
+    // The default is unchanged
+    let doc: Doc = xml::from_str(DOC).unwrap();
+    assert_eq!(doc, parse(WhitespaceMode::Trim));
+}
+
+#[test]
+fn text_and_entities_are_one_text_node() {
+    let doc: Doc = xml::from_str("<doc><title>Fish &amp; chips</title><pre>x</pre></doc>").unwrap();
+    assert_eq!(doc.title, "Fish & chips");
+}
+
+#[test]
+fn pretty_output_reads_back_with_the_same_mode() {
+    let doc = Doc {
+        title: " A  title ".into(),
+        pre: Pre {
+            text: "x\n  y".into(),
+        },
+    };
+
+    let options = SerializeOptions::new()
+        .pretty()
+        .whitespace(WhitespaceMode::TrimInterElement);
+    let pretty = to_string_with_options(&doc, &options).unwrap();
+    assert_eq!(
+        pretty,
+        "<doc>\n  <title> A  title </title>\n  <pre>x\n  y</pre>\n</doc>\n"
+    );
+    let back: Doc = from_str_with_options(
+        &pretty,
+        &DeserializeOptions::new().whitespace(WhitespaceMode::TrimInterElement),
+    )
+    .unwrap();
+    assert_eq!(back, doc);
+
+    // Nothing may be added when all whitespace is significant
+    let options = SerializeOptions::new()
+        .pretty()
+        .whitespace(WhitespaceMode::Preserve);
+    let preserved = to_string_with_options(&doc, &options).unwrap();
+    assert_eq!(preserved, xml::to_string(&doc).unwrap());
+    let back: Doc = from_str_with_options(
+        &preserved,
+        &DeserializeOptions::new().whitespace(WhitespaceMode::Preserve),
+    )
+    .unwrap();
+    assert_eq!(back, doc);
+}
+
+#[derive(Facet, Debug, PartialEq)]
+#[facet(rename = "block")]
+struct Block {
+    #[facet(xml::attribute, rename = "xml:space")]
+    space: String,
+    first: String,
+    second: String,
+}
+
+#[test]
+fn pretty_output_leaves_xml_space_preserve_alone() {
+    let block = Block {
+        space: "preserve".into(),
+        first: "a".into(),
+        second: "b".into(),
+    };
+    let pretty = xml::to_string_pretty(&block).unwrap();
+    assert_eq!(
+        pretty,
+        "<block xml:space=\"preserve\"><first>a</first><second>b</second></block>\n"
+    );
+}
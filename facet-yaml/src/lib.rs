@@ -23,6 +23,17 @@
 //! let output = to_string(&config).unwrap();
 //! assert!(output.contains("name: myapp"));
 //! ```
+//!
+//! # Features
+//!
+//! - Anchors and aliases: `*name` reads as a copy of the node marked `&name`.
+//! - Multi-document streams: [`from_str_documents`] reads every document
+//!   separated by `---`, and [`to_string_documents`] writes them.
+//! - Block or flow style output, chosen per nesting depth with
+//!   [`SerializeOptions`], which can also write fields' doc comments as `#`
+//!   comments.
+//! - Flattened fields and internally, adjacently and untagged enums resolve
+//!   the same way as in the other facet formats.
 
 extern crate alloc;
 
@@ -39,8 +50,8 @@ pub use error::{YamlError, YamlErrorKind};
 pub use axum::{Yaml, YamlRejection};
 pub use parser::YamlParser;
 pub use serializer::{
-    YamlSerializeError, YamlSerializer, peek_to_string, peek_to_writer, to_string, to_vec,
-    to_writer,
+    SerializeOptions, YamlSerializeError, YamlSerializer, peek_to_string, peek_to_writer,
+    to_string, to_string_documents, to_string_with_options, to_vec, to_vec_with_options, to_writer,
 };
 
 // Re-export DeserializeError for convenience
@@ -83,6 +94,41 @@ where
     de.deserialize_root()
 }
 
+/// Deserialize every document of a multi-document YAML stream.
+///
+/// Documents are separated by `---` lines; a stream without any is a single
+/// document. Anchors are local to the document that defines them.
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+///
+/// #[derive(Facet, Debug, PartialEq)]
+/// struct Service {
+///     name: String,
+///     replicas: u32,
+/// }
+///
+/// let yaml = "name: web\nreplicas: 3\n---\nname: worker\nreplicas: 1\n";
+/// let services: Vec<Service> = facet_yaml::from_str_documents(yaml).unwrap();
+/// assert_eq!(services.len(), 2);
+/// assert_eq!(services[1].name, "worker");
+/// ```
+pub fn from_str_documents<T>(input: &str) -> Result<Vec<T>, DeserializeError>
+where
+    T: facet_core::Facet<'static>,
+{
+    use facet_format::FormatDeserializer;
+    let mut parser = YamlParser::new(input);
+    let mut documents = Vec::new();
+    while parser.next_document()? {
+        let mut de = FormatDeserializer::new_owned(&mut parser);
+        documents.push(de.deserialize_root()?);
+    }
+    Ok(documents)
+}
+
 /// Deserialize a value from a YAML string, allowing zero-copy borrowing.
 ///
 /// This variant requires the input to outlive the result (`'input: 'facet`),
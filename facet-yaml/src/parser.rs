@@ -5,7 +5,12 @@
 
 extern crate alloc;
 
-use alloc::{borrow::Cow, format, vec::Vec};
+use alloc::{
+    borrow::Cow,
+    collections::{BTreeMap, VecDeque},
+    format,
+    vec::Vec,
+};
 
 use facet_format::{
     ContainerKind, DeserializeErrorKind, FieldKey, FieldLocationHint, FormatParser, ParseError,
@@ -18,6 +23,10 @@ use saphyr_parser::{Event, Parser, ScalarStyle, Span as SaphyrSpan, StrInput};
 // Parser State
 // ============================================================================
 
+/// Most events that aliases may expand to in one document, so that a few
+/// nested aliases can't blow up into billions of events.
+const MAX_ALIAS_EVENTS: usize = 1_000_000;
+
 /// Context for tracking where we are in the YAML structure.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum ContextState {
@@ -29,6 +38,16 @@ enum ContextState {
     SequenceValue,
 }
 
+/// An anchored node whose events are being recorded.
+struct Anchoring<'de> {
+    /// The anchor id assigned by saphyr.
+    id: usize,
+    /// Nesting depth within the node.
+    depth: usize,
+    /// The node's events so far.
+    events: Vec<ParseEvent<'de>>,
+}
+
 // ============================================================================
 // YAML Parser
 // ============================================================================
@@ -36,7 +55,10 @@ enum ContextState {
 /// Streaming YAML parser backed by `saphyr-parser`.
 ///
 /// This parser translates YAML's event stream into the `ParseEvent` format
-/// expected by `facet-format`'s deserializer.
+/// expected by `facet-format`'s deserializer. Aliases (`*name`) are expanded
+/// to the events of the node they refer to. A parser reads one document; call
+/// [`next_document`](Self::next_document) to move on to the next one in a
+/// multi-document stream.
 pub struct YamlParser<'de> {
     /// Original input string.
     input: &'de str,
@@ -48,6 +70,8 @@ pub struct YamlParser<'de> {
     event_peek: Option<ParseEvent<'de>>,
     /// Whether we've consumed the stream/document start events.
     started: bool,
+    /// Whether the current document has ended.
+    finished: bool,
     /// The span of the most recently consumed event (for error reporting).
     last_span: Span,
     /// Counter for save points.
@@ -56,6 +80,14 @@ pub struct YamlParser<'de> {
     recording: Option<Vec<ParseEvent<'de>>>,
     /// Events to replay before producing new ones.
     replay_buffer: Vec<ParseEvent<'de>>,
+    /// Anchored nodes that are still being read.
+    anchoring: Vec<Anchoring<'de>>,
+    /// Events of every complete anchored node in the document, by anchor id.
+    anchors: BTreeMap<usize, Vec<ParseEvent<'de>>>,
+    /// Remaining events of the alias being expanded.
+    alias_queue: VecDeque<ParseEvent<'de>>,
+    /// Events produced by expanding aliases so far.
+    alias_events: usize,
 }
 
 /// Convert a saphyr-parser Span to a facet Span.
@@ -74,10 +106,15 @@ impl<'de> YamlParser<'de> {
             stack: Vec::new(),
             event_peek: None,
             started: false,
+            finished: false,
             last_span: Span::new(0, 0),
             save_counter: 0,
             recording: None,
             replay_buffer: Vec::new(),
+            anchoring: Vec::new(),
+            anchors: BTreeMap::new(),
+            alias_queue: VecDeque::new(),
+            alias_events: 0,
         }
     }
 
@@ -86,6 +123,67 @@ impl<'de> YamlParser<'de> {
         self.input
     }
 
+    /// Move on to the next document in the stream.
+    ///
+    /// Returns `false` once there are no more documents. The first call
+    /// positions the parser at the first document, so reading every document
+    /// looks like this:
+    ///
+    /// ```
+    /// use facet_format::FormatDeserializer;
+    /// use facet_yaml::YamlParser;
+    ///
+    /// let mut parser = YamlParser::new("1\n---\n2\n");
+    /// let mut values = Vec::new();
+    /// while parser.next_document().unwrap() {
+    ///     let value: u32 = FormatDeserializer::new_owned(&mut parser)
+    ///         .deserialize_root()
+    ///         .unwrap();
+    ///     values.push(value);
+    /// }
+    /// assert_eq!(values, [1, 2]);
+    /// ```
+    pub fn next_document(&mut self) -> Result<bool, ParseError> {
+        if !self.started {
+            self.started = true;
+            if let Some(Ok((Event::StreamStart, _))) = self.parser.peek() {
+                self.next_raw_event()?;
+            }
+        } else {
+            // Skip whatever the previous document's deserializer left unread.
+            while !self.finished {
+                self.next_event_internal()?;
+            }
+        }
+
+        self.stack.clear();
+        self.event_peek = None;
+        self.replay_buffer.clear();
+        self.anchoring.clear();
+        self.anchors.clear();
+        self.alias_queue.clear();
+        self.alias_events = 0;
+
+        loop {
+            match self.parser.peek() {
+                Some(Ok((Event::DocumentStart(_), _))) => {
+                    self.next_raw_event()?;
+                    self.finished = false;
+                    return Ok(true);
+                }
+                Some(Ok((Event::StreamEnd, _))) | None => {
+                    self.finished = true;
+                    return Ok(false);
+                }
+                // Anything else between documents is consumed, and errors
+                // are returned from here.
+                _ => {
+                    self.next_raw_event()?;
+                }
+            }
+        }
+    }
+
     /// Get the next raw event from saphyr, updating span tracking.
     fn next_raw_event(&mut self) -> Result<Option<(Event<'de>, SaphyrSpan)>, ParseError> {
         match self.parser.next_event() {
@@ -128,25 +226,147 @@ impl<'de> YamlParser<'de> {
         Ok(())
     }
 
-    /// Produce a ParseEvent from the underlying saphyr parser.
+    /// Produce the next ParseEvent, expanding aliases and recording anchored
+    /// nodes.
     fn produce_event(&mut self) -> Result<Option<ParseEvent<'de>>, ParseError> {
+        let event = match self.alias_queue.pop_front() {
+            Some(event) => Some(event),
+            None => self.translate_event()?,
+        };
+        if let Some(event) = &event {
+            self.record_anchored(event);
+        }
+        Ok(event)
+    }
+
+    /// Add an event to every anchored node being read, and file away the
+    /// nodes it completes.
+    fn record_anchored(&mut self, event: &ParseEvent<'de>) {
+        for anchoring in &mut self.anchoring {
+            anchoring.events.push(event.clone());
+            match event.kind {
+                ParseEventKind::StructStart(_) | ParseEventKind::SequenceStart(_) => {
+                    anchoring.depth += 1;
+                }
+                ParseEventKind::StructEnd | ParseEventKind::SequenceEnd => {
+                    anchoring.depth -= 1;
+                }
+                _ => {}
+            }
+        }
+        // Anchored nodes nest, so completed ones are always on top.
+        while let Some(anchoring) = self.anchoring.last()
+            && anchoring.depth == 0
+        {
+            let anchoring = self.anchoring.pop().expect("checked above");
+            self.anchors.insert(anchoring.id, anchoring.events);
+        }
+    }
+
+    /// Start recording an anchored node, if `anchor` names one.
+    fn begin_anchor(&mut self, anchor: usize) {
+        // saphyr uses 0 for "no anchor"
+        if anchor != 0 {
+            self.anchoring.push(Anchoring {
+                id: anchor,
+                depth: 0,
+                events: Vec::new(),
+            });
+        }
+    }
+
+    /// Queue the events of the node `id` refers to, returning the first one.
+    fn expand_alias(&mut self, id: usize) -> Result<Option<ParseEvent<'de>>, ParseError> {
+        let Some(events) = self.anchors.get(&id) else {
+            return Err(ParseError::new(
+                self.last_span,
+                DeserializeErrorKind::InvalidValue {
+                    message: "alias must refer to a node defined earlier in the document".into(),
+                },
+            ));
+        };
+        self.alias_events += events.len();
+        if self.alias_events > MAX_ALIAS_EVENTS {
+            return Err(ParseError::new(
+                self.last_span,
+                DeserializeErrorKind::InvalidValue {
+                    message: "aliases expand to too many values".into(),
+                },
+            ));
+        }
+
+        let mut events: VecDeque<_> = events.iter().cloned().collect();
+        match self.stack.last_mut() {
+            Some(ctx @ ContextState::MappingKey) => {
+                // An alias used as a key must name a scalar.
+                let key = match events.pop_front().map(|event| event.kind) {
+                    Some(ParseEventKind::Scalar(ScalarValue::Str(name))) => Some(name),
+                    Some(ParseEventKind::FieldKey(key)) => key.name().cloned(),
+                    _ => None,
+                };
+                let Some(key) = key else {
+                    return Err(ParseError::new(
+                        self.last_span,
+                        DeserializeErrorKind::InvalidValue {
+                            message: "alias used as a key must refer to a string".into(),
+                        },
+                    ));
+                };
+                *ctx = ContextState::MappingValue;
+                Ok(Some(self.event(ParseEventKind::FieldKey(FieldKey::new(
+                    key,
+                    FieldLocationHint::KeyValue,
+                )))))
+            }
+            ctx => {
+                if let Some(ctx @ ContextState::MappingValue) = ctx {
+                    *ctx = ContextState::MappingKey;
+                }
+                // An anchored key used as a value is a plain string.
+                let first = events.pop_front().map(|event| match &event.kind {
+                    ParseEventKind::FieldKey(key) => ParseEvent::new(
+                        ParseEventKind::Scalar(
+                            key.name()
+                                .cloned()
+                                .map_or(ScalarValue::Null, ScalarValue::Str),
+                        ),
+                        event.span,
+                    ),
+                    _ => event,
+                });
+                self.alias_queue = events;
+                Ok(first)
+            }
+        }
+    }
+
+    /// Produce a ParseEvent from the underlying saphyr parser.
+    fn translate_event(&mut self) -> Result<Option<ParseEvent<'de>>, ParseError> {
+        if self.finished {
+            return Ok(None);
+        }
         self.skip_preamble()?;
 
         let (event, _span) = match self.next_raw_event()? {
             Some(ev) => ev,
-            None => return Ok(None),
+            None => {
+                self.finished = true;
+                return Ok(None);
+            }
         };
 
         match event {
             Event::StreamStart | Event::DocumentStart(_) => {
                 // Should have been skipped by preamble
-                self.produce_event()
+                self.translate_event()
             }
             Event::StreamEnd | Event::DocumentEnd => {
                 // End of document - return None
+                self.finished = true;
                 Ok(None)
             }
-            Event::MappingStart(_anchor, _tag) => {
+            Event::MappingStart(anchor, _tag) => {
+                self.begin_anchor(anchor);
                 self.stack.push(ContextState::MappingKey);
                 Ok(Some(
                     self.event(ParseEventKind::StructStart(ContainerKind::Object)),
@@ -160,7 +380,8 @@ impl<'de> YamlParser<'de> {
                 }
                 Ok(Some(self.event(ParseEventKind::StructEnd)))
             }
-            Event::SequenceStart(_anchor, _tag) => {
+            Event::SequenceStart(anchor, _tag) => {
+                self.begin_anchor(anchor);
                 self.stack.push(ContextState::SequenceValue);
                 Ok(Some(self.event(ParseEventKind::SequenceStart(
                     ContainerKind::Array,
@@ -174,7 +395,8 @@ impl<'de> YamlParser<'de> {
                 }
                 Ok(Some(self.event(ParseEventKind::SequenceEnd)))
             }
-            Event::Scalar(value, style, _anchor, _tag) => {
+            Event::Scalar(value, style, anchor, _tag) => {
+                self.begin_anchor(anchor);
                 // Check if we're expecting a mapping key
                 if let Some(ctx @ ContextState::MappingKey) = self.stack.last_mut() {
                     // This scalar is a key
@@ -193,16 +415,10 @@ impl<'de> YamlParser<'de> {
                     ))))
                 }
             }
-            Event::Alias(_id) => {
-                // For now, treat aliases as null (proper anchor support would be more complex)
-                if let Some(ctx @ ContextState::MappingValue) = self.stack.last_mut() {
-                    *ctx = ContextState::MappingKey;
-                }
-                Ok(Some(self.event(ParseEventKind::Scalar(ScalarValue::Null))))
-            }
+            Event::Alias(id) => self.expand_alias(id),
             Event::Nothing => {
                 // Internal event, skip
-                self.produce_event()
+                self.translate_event()
             }
         }
     }
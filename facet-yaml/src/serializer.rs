@@ -34,6 +34,104 @@ impl YamlSerializeError {
     }
 }
 
+/// Options for YAML serialization.
+///
+/// Collections are written in block style (one entry per line) by default.
+/// Flow style writes them inline, as `{key: value}` and `[a, b]`.
+///
+/// # Example
+///
+/// ```
+/// use facet::Facet;
+/// use facet_yaml::{SerializeOptions, to_string_with_options};
+///
+/// #[derive(Facet)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// #[derive(Facet)]
+/// struct Shape {
+///     name: String,
+///     points: Vec<Point>,
+/// }
+///
+/// let shape = Shape {
+///     name: "line".into(),
+///     points: vec![Point { x: 0, y: 0 }, Point { x: 1, y: 2 }],
+/// };
+///
+/// // Block style for the shape and its list of points, flow style for each point.
+/// let options = SerializeOptions::new().flow_depth(2);
+/// let yaml = to_string_with_options(&shape, &options).unwrap();
+/// assert!(yaml.ends_with("\n  - {x: 0, y: 0}\n  - {x: 1, y: 2}\n"), "{yaml}");
+///
+/// let options = SerializeOptions::new().flow();
+/// assert_eq!(
+///     to_string_with_options(&shape, &options).unwrap(),
+///     "---\n{name: line, points: [{x: 0, y: 0}, {x: 1, y: 2}]}\n"
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SerializeOptions {
+    /// Nesting depth from which collections are written in flow style.
+    flow_depth: Option<usize>,
+    /// Whether fields' doc comments are written as `#` comments.
+    doc_comments: bool,
+}
+
+impl SerializeOptions {
+    /// Create default options: block style throughout.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Write the whole document in flow style.
+    pub const fn flow(self) -> Self {
+        self.flow_depth(0)
+    }
+
+    /// Write collections nested `depth` or more levels deep in flow style,
+    /// and the ones above them in block style.
+    ///
+    /// The top-level collection is at depth 0, its values at depth 1, and so
+    /// on.
+    pub const fn flow_depth(mut self, depth: usize) -> Self {
+        self.flow_depth = Some(depth);
+        self
+    }
+
+    /// Write each field's doc comment as `#` comments above its key.
+    ///
+    /// Fields in flow collections are written without their comments.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use facet::Facet;
+    /// use facet_yaml::{SerializeOptions, to_string_with_options};
+    ///
+    /// #[derive(Facet)]
+    /// struct Config {
+    ///     /// Address to listen on.
+    ///     host: String,
+    ///     port: u16,
+    /// }
+    ///
+    /// let config = Config { host: "0.0.0.0".into(), port: 8080 };
+    /// let options = SerializeOptions::new().doc_comments();
+    /// assert_eq!(
+    ///     to_string_with_options(&config, &options).unwrap(),
+    ///     "---\n# Address to listen on.\nhost: 0.0.0.0\nport: 8080\n"
+    /// );
+    /// ```
+    pub const fn doc_comments(mut self) -> Self {
+        self.doc_comments = true;
+        self
+    }
+}
+
 /// Context for tracking where we are in the output structure.
 #[derive(Debug, Clone, Copy)]
 enum Ctx {
@@ -41,6 +139,10 @@ enum Ctx {
     Struct { indent: usize, has_fields: bool },
     /// In a sequence/list
     Seq { indent: usize, has_items: bool },
+    /// In a flow mapping (`{a: 1}`)
+    FlowStruct { has_fields: bool },
+    /// In a flow sequence (`[1, 2]`)
+    FlowSeq { has_items: bool },
 }
 
 /// Where we are on the current line
@@ -62,6 +164,12 @@ pub struct YamlSerializer {
     doc_started: bool,
     /// Current position on the line
     line_pos: LinePos,
+    /// Nesting depth from which collections are written in flow style.
+    flow_depth: Option<usize>,
+    /// Whether fields' doc comments are written as `#` comments.
+    doc_comments: bool,
+    /// The doc comment of the field whose key is written next.
+    pending_doc: &'static [&'static str],
 }
 
 impl YamlSerializer {
@@ -72,6 +180,18 @@ impl YamlSerializer {
             stack: Vec::new(),
             doc_started: false,
             line_pos: LinePos::Start,
+            flow_depth: None,
+            doc_comments: false,
+            pending_doc: &[],
+        }
+    }
+
+    /// Create a new YAML serializer with the given options.
+    pub const fn with_options(options: &SerializeOptions) -> Self {
+        Self {
+            flow_depth: options.flow_depth,
+            doc_comments: options.doc_comments,
+            ..Self::new()
         }
     }
 
@@ -121,15 +241,74 @@ impl YamlSerializer {
         self.line_pos = LinePos::Inline;
     }
 
+    /// Write `doc` as comment lines, leaving the line after them open at
+    /// `indent`.
+    fn write_doc_comment(&mut self, doc: &[&str], indent: usize) {
+        for line in doc {
+            let text = line.strip_prefix(' ').unwrap_or(line).trim_end();
+            self.out.push(b'#');
+            if !text.is_empty() {
+                self.out.push(b' ');
+                self.out.extend_from_slice(text.as_bytes());
+            }
+            self.out.push(b'\n');
+            self.write_indent(indent);
+        }
+        self.line_pos = LinePos::Inline;
+    }
+
     /// Get the current indentation level based on context stack.
     fn current_indent(&self) -> usize {
         match self.stack.last() {
             Some(Ctx::Struct { indent, .. }) => *indent,
             Some(Ctx::Seq { indent, .. }) => *indent,
-            None => 0,
+            Some(Ctx::FlowStruct { .. } | Ctx::FlowSeq { .. }) | None => 0,
+        }
+    }
+
+    /// Whether we're inside a flow collection.
+    fn in_flow(&self) -> bool {
+        matches!(
+            self.stack.last(),
+            Some(Ctx::FlowStruct { .. } | Ctx::FlowSeq { .. })
+        )
+    }
+
+    /// Whether a collection starting here is written in flow style.
+    fn starts_flow(&self) -> bool {
+        self.in_flow()
+            || self
+                .flow_depth
+                .is_some_and(|depth| self.stack.len() >= depth)
+    }
+
+    /// Write what goes before a value in the current context: the "- " of a
+    /// block sequence item, or the ", " between flow sequence items.
+    fn write_value_prefix(&mut self) {
+        match self.stack.last_mut() {
+            Some(Ctx::Seq { indent, has_items }) => {
+                let indent = *indent;
+                *has_items = true;
+                self.write_seq_item_prefix(indent);
+            }
+            Some(Ctx::FlowSeq { has_items }) => {
+                if *has_items {
+                    self.out.extend_from_slice(b", ");
+                }
+                *has_items = true;
+            }
+            _ => {}
         }
     }
 
+    /// Open a flow collection.
+    fn begin_flow(&mut self, ctx: Ctx, open: u8) {
+        self.write_value_prefix();
+        self.stack.push(ctx);
+        self.out.push(open);
+        self.line_pos = LinePos::Inline;
+    }
+
     /// Check if a string should use block scalar syntax.
     /// Returns true for multiline strings that are suitable for literal block style.
     fn should_use_block_scalar(s: &str) -> bool {
@@ -199,8 +378,9 @@ impl YamlSerializer {
     }
 
     /// Check if a string needs quoting (for inline/single-line strings).
-    fn needs_quotes(s: &str) -> bool {
-        s.is_empty()
+    fn needs_quotes(s: &str, in_flow: bool) -> bool {
+        (in_flow && s.contains([',', '[', ']', '{', '}']))
+            || s.is_empty()
             || s.contains(':')
             || s.contains('#')
             || s.contains('\n')
@@ -228,10 +408,11 @@ impl YamlSerializer {
 
     /// Write a YAML string, using block scalar for multiline or quoting if necessary.
     fn write_string(&mut self, s: &str) {
-        if Self::should_use_block_scalar(s) {
+        let in_flow = self.in_flow();
+        if !in_flow && Self::should_use_block_scalar(s) {
             let indent = self.current_indent();
             self.write_block_scalar(s, indent);
-        } else if Self::needs_quotes(s) {
+        } else if Self::needs_quotes(s, in_flow) {
             self.out.push(b'"');
             for c in s.chars() {
                 match c {
@@ -272,6 +453,11 @@ impl FormatSerializer for YamlSerializer {
     fn begin_struct(&mut self) -> Result<(), Self::Error> {
         self.ensure_doc_started();
 
+        if self.starts_flow() {
+            self.begin_flow(Ctx::FlowStruct { has_fields: false }, b'{');
+            return Ok(());
+        }
+
         // Check if we're inside a sequence - if so, this struct is a seq item
         let (struct_indent, seq_indent_for_prefix) = match self.stack.last() {
             Some(Ctx::Seq { indent, .. }) => {
@@ -282,6 +468,9 @@ impl FormatSerializer for YamlSerializer {
                 // Nested struct after a key - indent at parent level + 1
                 (*indent + 1, None)
             }
+            Some(Ctx::FlowStruct { .. } | Ctx::FlowSeq { .. }) => {
+                unreachable!("collections inside flow collections are flow")
+            }
             None => {
                 // Top-level struct
                 (0, None)
@@ -307,6 +496,18 @@ impl FormatSerializer for YamlSerializer {
     }
 
     fn field_key(&mut self, key: &str) -> Result<(), Self::Error> {
+        let doc = core::mem::take(&mut self.pending_doc);
+
+        if let Some(Ctx::FlowStruct { has_fields }) = self.stack.last_mut() {
+            if *has_fields {
+                self.out.extend_from_slice(b", ");
+            }
+            *has_fields = true;
+            self.write_string(key);
+            self.out.extend_from_slice(b": ");
+            return Ok(());
+        }
+
         let (indent, has_fields) = match self.stack.last() {
             Some(Ctx::Struct { indent, has_fields }) => (*indent, *has_fields),
             _ => {
@@ -320,11 +521,13 @@ impl FormatSerializer for YamlSerializer {
         // Otherwise, we need newline + indent
         if !has_fields && self.line_pos == LinePos::AfterSeqMarker {
             // First field of seq-item struct: already have "- " on this line
-            // Don't write newline, just the key
+            // Don't write newline, just the key (or the comment, which then
+            // pushes the key to the next line)
         } else {
             // Normal case: newline + indent
             self.write_field_prefix(indent);
         }
+        self.write_doc_comment(doc, indent);
 
         self.write_string(key);
         self.out.extend_from_slice(b": ");
@@ -348,6 +551,10 @@ impl FormatSerializer for YamlSerializer {
                 }
                 Ok(())
             }
+            Some(Ctx::FlowStruct { .. }) => {
+                self.out.push(b'}');
+                Ok(())
+            }
             _ => Err(YamlSerializeError::new(
                 "end_struct called without matching begin_struct",
             )),
@@ -357,6 +564,11 @@ impl FormatSerializer for YamlSerializer {
     fn begin_seq(&mut self) -> Result<(), Self::Error> {
         self.ensure_doc_started();
 
+        if self.starts_flow() {
+            self.begin_flow(Ctx::FlowSeq { has_items: false }, b'[');
+            return Ok(());
+        }
+
         // Check if we're inside a parent sequence
         let (new_seq_indent, parent_seq_indent) = match self.stack.last() {
             Some(Ctx::Seq { indent, .. }) => {
@@ -367,6 +579,9 @@ impl FormatSerializer for YamlSerializer {
                 // Seq after a key like "tags: " - items will be indented at struct indent + 1
                 (*indent + 1, None)
             }
+            Some(Ctx::FlowStruct { .. } | Ctx::FlowSeq { .. }) => {
+                unreachable!("collections inside flow collections are flow")
+            }
             None => {
                 // Top-level sequence
                 (0, None)
@@ -402,6 +617,10 @@ impl FormatSerializer for YamlSerializer {
                 }
                 Ok(())
             }
+            Some(Ctx::FlowSeq { .. }) => {
+                self.out.push(b']');
+                Ok(())
+            }
             _ => Err(YamlSerializeError::new(
                 "end_seq called without matching begin_seq",
             )),
@@ -412,17 +631,7 @@ impl FormatSerializer for YamlSerializer {
         self.ensure_doc_started();
 
         // If we're in a sequence, write the item prefix
-        let seq_indent = match self.stack.last() {
-            Some(Ctx::Seq { indent, .. }) => Some(*indent),
-            _ => None,
-        };
-        if let Some(indent) = seq_indent {
-            self.write_seq_item_prefix(indent);
-            // Mark seq as having items
-            if let Some(Ctx::Seq { has_items, .. }) = self.stack.last_mut() {
-                *has_items = true;
-            }
-        }
+        self.write_value_prefix();
 
         match scalar {
             ScalarValue::Null | ScalarValue::Unit => self.out.extend_from_slice(b"null"),
@@ -486,6 +695,13 @@ impl FormatSerializer for YamlSerializer {
         self.line_pos = LinePos::Inline;
         Ok(())
     }
+
+    fn field_metadata(&mut self, field: &facet_reflect::FieldItem) -> Result<(), Self::Error> {
+        if self.doc_comments {
+            self.pending_doc = field.field.as_ref().map_or(&[], |field| field.doc);
+        }
+        Ok(())
+    }
 }
 
 /// Check if string looks like a boolean
@@ -564,7 +780,32 @@ pub fn to_vec<'facet, T>(value: &T) -> Result<Vec<u8>, SerializeError<YamlSerial
 where
     T: Facet<'facet> + ?Sized,
 {
-    let mut serializer = YamlSerializer::new();
+    to_vec_with_options(value, &SerializeOptions::default())
+}
+
+/// Serialize a value to a YAML string with custom options.
+///
+/// See [`SerializeOptions`] for an example.
+pub fn to_string_with_options<'facet, T>(
+    value: &T,
+    options: &SerializeOptions,
+) -> Result<String, SerializeError<YamlSerializeError>>
+where
+    T: Facet<'facet> + ?Sized,
+{
+    let bytes = to_vec_with_options(value, options)?;
+    Ok(String::from_utf8(bytes).expect("YAML output should always be valid UTF-8"))
+}
+
+/// Serialize a value to YAML bytes with custom options.
+pub fn to_vec_with_options<'facet, T>(
+    value: &T,
+    options: &SerializeOptions,
+) -> Result<Vec<u8>, SerializeError<YamlSerializeError>>
+where
+    T: Facet<'facet> + ?Sized,
+{
+    let mut serializer = YamlSerializer::with_options(options);
     serialize_root(&mut serializer, Peek::new(value))?;
     let mut output = serializer.finish();
     // Ensure trailing newline
@@ -574,6 +815,30 @@ where
     Ok(output)
 }
 
+/// Serialize several values as a multi-document YAML stream.
+///
+/// Each value becomes one document, starting with `---`. Read the stream back
+/// with [`from_str_documents`](crate::from_str_documents).
+///
+/// # Example
+///
+/// ```
+/// let yaml = facet_yaml::to_string_documents(&[1, 2]).unwrap();
+/// assert_eq!(yaml, "---\n1\n---\n2\n");
+/// ```
+pub fn to_string_documents<'facet, T>(
+    documents: &[T],
+) -> Result<String, SerializeError<YamlSerializeError>>
+where
+    T: Facet<'facet>,
+{
+    let mut out = String::new();
+    for document in documents {
+        out.push_str(&to_string(document)?);
+    }
+    Ok(out)
+}
+
 /// Serialize a `Peek` instance to a YAML string.
 ///
 /// This allows serializing values without requiring ownership, useful when
@@ -0,0 +1,118 @@
+//! Anchor and alias tests: `*name` reads as a copy of the node marked `&name`.
+
+use facet::Facet;
+use facet_value::Value;
+use facet_yaml::from_str;
+use indoc::indoc;
+use std::collections::HashMap;
+
+#[derive(Debug, Facet, PartialEq)]
+struct Limits {
+    cpu: u32,
+    memory: String,
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct Service {
+    image: String,
+    limits: Limits,
+    ports: Vec<u16>,
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct Deployment {
+    defaults: Limits,
+    services: HashMap<String, Service>,
+}
+
+#[test]
+fn test_alias_to_mapping_and_sequence() {
+    let yaml = indoc! {"
+        defaults: &limits
+          cpu: 2
+          memory: 1Gi
+        services:
+          web:
+            image: web:1
+            limits: *limits
+            ports: &ports [80, 443]
+          api:
+            image: api:1
+            limits: *limits
+            ports: *ports
+    "};
+
+    let deployment: Deployment = from_str(yaml).unwrap();
+    let expected = Limits {
+        cpu: 2,
+        memory: "1Gi".into(),
+    };
+    assert_eq!(deployment.defaults, expected);
+    assert_eq!(deployment.services["web"].limits, expected);
+    assert_eq!(deployment.services["api"].limits, expected);
+    assert_eq!(deployment.services["api"].ports, [80, 443]);
+}
+
+#[test]
+fn test_alias_to_scalar_keeps_its_type() {
+    #[derive(Debug, Facet, PartialEq)]
+    struct Ports {
+        http: u16,
+        admin: u16,
+        name: String,
+        alias: String,
+    }
+
+    let yaml = indoc! {"
+        http: &port 8080
+        admin: *port
+        name: &name '8080'
+        alias: *name
+    "};
+    let ports: Ports = from_str(yaml).unwrap();
+    assert_eq!(ports.admin, 8080);
+    assert_eq!(ports.alias, "8080");
+}
+
+#[test]
+fn test_alias_as_key() {
+    let yaml = indoc! {"
+        first: &key shared
+        *key : value
+    "};
+    let map: HashMap<String, String> = from_str(yaml).unwrap();
+    assert_eq!(map["first"], "shared");
+    assert_eq!(map["shared"], "value");
+}
+
+#[test]
+fn test_nested_anchors() {
+    let yaml = indoc! {"
+        outer: &outer
+          inner: &inner [1, 2]
+          copy: *inner
+        again: *outer
+        inner: *inner
+    "};
+    let value: HashMap<String, Value> = from_str(yaml).unwrap();
+    assert_eq!(value["again"], value["outer"]);
+    assert_eq!(value["inner"], value["outer"].as_object().unwrap()["copy"]);
+}
+
+#[test]
+fn test_alias_expansion_is_bounded() {
+    // Each level refers to the previous one ten times, so the last level
+    // would expand to billions of values.
+    let mut yaml = String::from("a0: &a0 [x, x, x, x, x, x, x, x, x, x]\n");
+    for i in 1..10 {
+        let items = vec![format!("*a{}", i - 1); 10].join(", ");
+        yaml.push_str(&format!("a{i}: &a{i} [{items}]\n"));
+    }
+    let err = from_str::<HashMap<String, Value>>(&yaml).unwrap_err();
+    assert!(err.to_string().contains("too many"), "{err}");
+}
+
+#[test]
+fn test_unknown_alias_is_an_error() {
+    assert!(from_str::<HashMap<String, String>>("a: *missing\n").is_err());
+}
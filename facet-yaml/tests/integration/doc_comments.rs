@@ -0,0 +1,96 @@
+//! Doc comments written as YAML comments.
+
+use facet::Facet;
+use facet_yaml::{SerializeOptions, from_str, to_string, to_string_with_options};
+
+#[derive(Debug, Facet, PartialEq)]
+struct Endpoint {
+    /// Host name or IP address.
+    host: String,
+    /// TCP port.
+    port: u16,
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct Server {
+    /// Service name.
+    ///
+    /// Shown in logs.
+    name: String,
+    /// Where to listen.
+    listen: Endpoint,
+    /// Upstream servers.
+    upstreams: Vec<Endpoint>,
+    retries: u32,
+}
+
+fn server() -> Server {
+    Server {
+        name: "api".into(),
+        listen: Endpoint {
+            host: "0.0.0.0".into(),
+            port: 80,
+        },
+        upstreams: vec![Endpoint {
+            host: "a.internal".into(),
+            port: 8080,
+        }],
+        retries: 3,
+    }
+}
+
+fn with_comments(server: &Server) -> String {
+    to_string_with_options(server, &SerializeOptions::new().doc_comments()).unwrap()
+}
+
+#[test]
+fn test_doc_comments_go_above_keys() {
+    let yaml = with_comments(&server());
+    assert!(
+        yaml.starts_with(
+            "---\n# Service name.\n#\n# Shown in logs.\nname: api\n# Where to listen.\nlisten:"
+        ),
+        "{yaml}"
+    );
+    assert!(
+        yaml.contains(
+            "\n  # Host name or IP address.\n  host: 0.0.0.0\n  # TCP port.\n  port: 80\n"
+        ),
+        "{yaml}"
+    );
+    assert!(yaml.ends_with("\nretries: 3\n"), "{yaml}");
+}
+
+#[test]
+fn test_first_field_of_a_list_item_is_commented_after_the_dash() {
+    let yaml = with_comments(&server());
+    assert!(
+        yaml.contains(
+            "# Upstream servers.\nupstreams:\n  - # Host name or IP address.\n    host: a.internal\n    # TCP port.\n    port: 8080\n"
+        ),
+        "{yaml}"
+    );
+}
+
+#[test]
+fn test_commented_output_reads_back() {
+    let yaml = with_comments(&server());
+    assert_eq!(from_str::<Server>(&yaml).unwrap(), server());
+}
+
+#[test]
+fn test_doc_comments_are_off_by_default() {
+    assert!(!to_string(&server()).unwrap().contains('#'));
+}
+
+#[test]
+fn test_flow_collections_have_no_comments() {
+    let options = SerializeOptions::new().doc_comments().flow_depth(1);
+    let yaml = to_string_with_options(&server(), &options).unwrap();
+    assert!(
+        yaml.contains("# Where to listen.\nlisten: {host: 0.0.0.0, port: 80}\n"),
+        "{yaml}"
+    );
+    assert!(!yaml.contains("# TCP port."), "{yaml}");
+    assert_eq!(from_str::<Server>(&yaml).unwrap(), server());
+}
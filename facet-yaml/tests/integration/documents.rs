@@ -0,0 +1,80 @@
+//! Multi-document stream tests.
+
+use facet::Facet;
+use facet_yaml::{from_str, from_str_documents, to_string_documents};
+use indoc::indoc;
+
+#[derive(Debug, Facet, PartialEq)]
+struct Resource {
+    kind: String,
+    name: String,
+}
+
+fn resource(kind: &str, name: &str) -> Resource {
+    Resource {
+        kind: kind.into(),
+        name: name.into(),
+    }
+}
+
+#[test]
+fn test_read_every_document() {
+    let yaml = indoc! {"
+        ---
+        kind: Service
+        name: web
+        ...
+        ---
+        # a comment between documents
+        kind: Deployment
+        name: web
+        ---
+        kind: ConfigMap
+        name: settings
+    "};
+    let resources: Vec<Resource> = from_str_documents(yaml).unwrap();
+    assert_eq!(
+        resources,
+        [
+            resource("Service", "web"),
+            resource("Deployment", "web"),
+            resource("ConfigMap", "settings"),
+        ]
+    );
+}
+
+#[test]
+fn test_single_and_empty_streams() {
+    let one: Vec<Resource> = from_str_documents("kind: Service\nname: web\n").unwrap();
+    assert_eq!(one, [resource("Service", "web")]);
+
+    let none: Vec<Resource> = from_str_documents("").unwrap();
+    assert!(none.is_empty());
+}
+
+#[test]
+fn test_from_str_reads_the_first_document() {
+    let yaml = "kind: Service\nname: web\n---\nkind: Deployment\nname: api\n";
+    let first: Resource = from_str(yaml).unwrap();
+    assert_eq!(first, resource("Service", "web"));
+}
+
+#[test]
+fn test_anchors_are_local_to_their_document() {
+    let yaml = "kind: &k Service\nname: *k\n---\nkind: Job\nname: *k\n";
+    assert!(from_str_documents::<Resource>(yaml).is_err());
+}
+
+#[test]
+fn test_error_in_a_later_document() {
+    let yaml = "kind: Service\nname: web\n---\nkind: Job\n";
+    assert!(from_str_documents::<Resource>(yaml).is_err());
+}
+
+#[test]
+fn test_documents_round_trip() {
+    let resources = vec![resource("Service", "web"), resource("Job", "migrate")];
+    let yaml = to_string_documents(&resources).unwrap();
+    assert_eq!(yaml.matches("---\n").count(), 2);
+    assert_eq!(from_str_documents::<Resource>(&yaml).unwrap(), resources);
+}
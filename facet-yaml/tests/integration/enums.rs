@@ -0,0 +1,83 @@
+//! Flattened fields and tagged enums, resolved the same way as in the other
+//! facet formats.
+
+use facet::Facet;
+use facet_yaml::{from_str, to_string};
+use indoc::indoc;
+
+#[derive(Debug, Facet, PartialEq)]
+struct Database {
+    url: String,
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct Files {
+    root: String,
+}
+
+#[derive(Debug, Facet, PartialEq)]
+#[repr(u8)]
+enum Backend {
+    Database(Database),
+    Files(Files),
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct Storage {
+    name: String,
+    #[facet(flatten)]
+    backend: Backend,
+}
+
+#[test]
+fn test_flattened_enum_is_solved_from_keys() {
+    let storage: Storage = from_str("name: cache\nroot: /var/cache\n").unwrap();
+    assert_eq!(
+        storage.backend,
+        Backend::Files(Files {
+            root: "/var/cache".into()
+        })
+    );
+
+    let storage: Storage = from_str("url: postgres://db\nname: main\n").unwrap();
+    assert_eq!(storage.name, "main");
+    assert!(matches!(storage.backend, Backend::Database(_)));
+}
+
+#[derive(Debug, Facet, PartialEq)]
+#[repr(u8)]
+#[facet(tag = "type")]
+enum Shape {
+    Circle { radius: f64 },
+    Square { side: f64 },
+}
+
+#[test]
+fn test_internally_tagged_enum_round_trip() {
+    let shapes: Vec<Shape> = from_str(indoc! {"
+        - type: Square
+          side: 2.0
+        - radius: 1.5
+          type: Circle
+    "})
+    .unwrap();
+    assert_eq!(
+        shapes,
+        [Shape::Square { side: 2.0 }, Shape::Circle { radius: 1.5 }]
+    );
+
+    let yaml = to_string(&shapes).unwrap();
+    assert_eq!(from_str::<Vec<Shape>>(&yaml).unwrap(), shapes);
+}
+
+#[test]
+fn test_flattened_enum_through_an_alias() {
+    let yaml = indoc! {"
+        - &cache
+          name: cache
+          root: /var/cache
+        - *cache
+    "};
+    let storages: Vec<Storage> = from_str(yaml).unwrap();
+    assert_eq!(storages[0], storages[1]);
+}
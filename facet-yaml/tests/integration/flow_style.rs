@@ -0,0 +1,85 @@
+//! Block and flow style output tests.
+
+use facet::Facet;
+use facet_yaml::{SerializeOptions, from_str, to_string, to_string_with_options};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Facet, PartialEq)]
+struct Endpoint {
+    host: String,
+    port: u16,
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct Config {
+    name: String,
+    tags: Vec<String>,
+    endpoints: Vec<Endpoint>,
+    labels: BTreeMap<String, String>,
+}
+
+fn config() -> Config {
+    Config {
+        name: "svc".into(),
+        tags: vec!["a, b".into(), "[c]".into(), "plain".into()],
+        endpoints: vec![
+            Endpoint {
+                host: "localhost".into(),
+                port: 80,
+            },
+            Endpoint {
+                host: "example.com".into(),
+                port: 443,
+            },
+        ],
+        labels: BTreeMap::new(),
+    }
+}
+
+#[test]
+fn test_block_style_is_the_default() {
+    let yaml = to_string(&config()).unwrap();
+    assert!(!yaml.contains('{'), "{yaml}");
+    assert!(yaml.contains("- host: localhost"), "{yaml}");
+}
+
+#[test]
+fn test_whole_document_in_flow_style() {
+    let yaml = to_string_with_options(&config(), &SerializeOptions::new().flow()).unwrap();
+    assert_eq!(
+        yaml,
+        "---\n{name: svc, tags: [\"a, b\", \"[c]\", plain], endpoints: [{host: localhost, port: 80}, {host: example.com, port: 443}], labels: {}}\n"
+    );
+    assert_eq!(from_str::<Config>(&yaml).unwrap(), config());
+}
+
+#[test]
+fn test_flow_style_below_a_depth() {
+    let options = SerializeOptions::new().flow_depth(1);
+    let yaml = to_string_with_options(&config(), &options).unwrap();
+    assert!(
+        yaml.contains("tags: [\"a, b\", \"[c]\", plain]\n"),
+        "{yaml}"
+    );
+    assert!(
+        yaml.contains("endpoints: [{host: localhost, port: 80}, {host: example.com, port: 443}]\n"),
+        "{yaml}"
+    );
+    assert!(yaml.contains("\nname: svc\n"), "{yaml}");
+    assert_eq!(from_str::<Config>(&yaml).unwrap(), config());
+}
+
+#[test]
+fn test_multiline_strings_are_quoted_in_flow_style() {
+    #[derive(Debug, Facet, PartialEq)]
+    struct Note {
+        text: String,
+    }
+
+    let note = Note {
+        text: "line one\nline two\n".into(),
+    };
+    let yaml = to_string_with_options(&note, &SerializeOptions::new().flow()).unwrap();
+    assert_eq!(yaml, "---\n{text: \"line one\\nline two\\n\"}\n");
+    assert_eq!(from_str::<Note>(&yaml).unwrap(), note);
+}
@@ -1,4 +1,9 @@
+mod anchors;
 mod basic;
+mod doc_comments;
+mod documents;
+mod enums;
+mod flow_style;
 mod issue_1189;
 mod issue_1728_1729;
 mod issue_1775;
@@ -216,13 +216,10 @@ fn primitive_to_zod(shape: &'static Shape, config: &Config) -> ZodType {
         Type::Primitive(PrimitiveType::Textual(_)) => ZodType::String,
         Type::Primitive(PrimitiveType::Numeric(num)) => numeric_to_zod(num, shape, config),
         Type::Primitive(PrimitiveType::Never) => ZodType::Never,
-        _ => {
-            if shape.type_identifier == "String" || shape.type_identifier == "str" {
-                ZodType::String
-            } else {
-                ZodType::Unknown
-            }
-        }
+        _ => match shape.scalar_kind() {
+            Some(ScalarKind::Str) => ZodType::String,
+            _ => ZodType::Unknown,
+        },
     }
 }
 
@@ -182,6 +182,17 @@ pub mod builtin {
             #[storage(flag)]
             Flatten,
 
+            /// On a flattened struct field, writes its fields under a runtime prefix:
+            /// the value of the named sibling field, followed by a `.`. With
+            /// `name: "web1"`, a flattened `cpu` field is written as `"web1.cpu"`.
+            /// The sibling must hold a string or a `Display` type, which is checked at
+            /// compile time. Deserializing accepts any prefix, as long as it is the
+            /// same for all of the flattened fields and matches the sibling's value.
+            ///
+            /// Usage: `#[facet(flatten, flatten_prefix_from = "name")]`
+            #[target(field)]
+            FlattenPrefixFrom(&'static str),
+
             /// Marks an opaque payload field as structurally trailing in its container.
             ///
             /// This enables formats that support it to treat the field as "remaining bytes"
@@ -245,6 +256,27 @@ pub mod builtin {
             #[storage(flag)]
             SkipDeserializing,
 
+            /// Moves a field in serialization order, without changing its place
+            /// in memory or in `StructType::fields`.
+            ///
+            /// Fields are presented sorted by this value, 0 when absent, with ties
+            /// kept in declaration order: `order = -1` moves a field before the
+            /// others and `order = 1` after them. Self-describing formats write
+            /// fields in this order; positional formats like postcard ignore it.
+            /// See `StructType::fields_in_order`.
+            ///
+            /// Usage: `#[facet(order = -1)]`
+            #[target(field)]
+            Order(i64),
+
+            /// Accepts `"1"`/`"0"`, `"yes"`/`"no"` and `"on"`/`"off"` (any case)
+            /// as well as `true`/`false`, as strings or integers, when
+            /// deserializing a `bool` field. See `facet_core::parse_lenient_bool`.
+            ///
+            /// Usage: `#[facet(lenient_bool)]`
+            #[target(field)]
+            LenientBool,
+
             /// For enums: variants are serialized without a discriminator tag.
             ///
             /// Usage: `#[facet(untagged)]`
@@ -313,6 +345,14 @@ pub mod builtin {
             /// kebab-case, SCREAMING-KEBAB-CASE
             RenameAll(&'static str),
 
+            /// Gives the structs this container references, through its fields and
+            /// theirs, a `rename_all` rule for the ones that don't set their own.
+            /// The container's own fields aren't affected. See `ChildDefaults`.
+            ///
+            /// Usage: `#[facet(defaults_for_children(rename_all = "camelCase"))]`
+            #[target(container)]
+            DefaultsForChildren(DefaultsForChildren),
+
             /// Aliases a field or variant during deserialization.
             ///
             /// Usage: `#[facet(alias = "additional_name")]`
@@ -354,6 +394,14 @@ pub mod builtin {
             /// Usage: `#[facet(type_tag = "com.example.MyType")]`
             TypeTag(&'static str),
 
+            /// Gives a sample value of the type, written as JSON.
+            /// Can be repeated; read them back with `Shape::examples`.
+            /// Schema generators emit them as `examples`.
+            ///
+            /// Usage: `#[facet(example = r#"{"name": "alice"}"#)]`
+            #[target(container)]
+            Example(&'static str),
+
             /// Type invariant validation function.
             /// Stores a type-erased function pointer: `fn(PtrConst) -> bool`.
             ///
@@ -378,6 +426,18 @@ pub mod builtin {
             /// Usage: `#[facet(proxy = MyProxyType)]`
             Proxy(shape_type),
 
+            /// Deserialize the container as another type, then convert it with `From`.
+            /// Requires `From<OtherType> for Self`; serialization is unaffected.
+            ///
+            /// Usage: `#[facet(from = OtherType)]`
+            From(shape_type),
+
+            /// Serialize the container as another type, converting a clone of it with `From`.
+            /// Requires `Self: Clone` and `From<Self> for OtherType`; deserialization is unaffected.
+            ///
+            /// Usage: `#[facet(into = OtherType)]`
+            Into(shape_type),
+
             /// Marks a field as having a recursive type that needs lazy shape resolution.
             ///
             /// Use this on fields where the type recursively contains the parent type,
@@ -486,6 +546,12 @@ pub mod builtin {
             /// by the variant marked with `custom_element`.
             CustomElement
         }
+
+        /// Payload of `Attr::DefaultsForChildren`.
+        pub struct DefaultsForChildren {
+            /// Case conversion rule for the fields of child structs.
+            pub rename_all: Option<&'static str>,
+        }
     }
 
     // Manual Facet impl for Attr since we can't use the derive macro inside the facet crate.
@@ -515,12 +581,15 @@ pub mod builtin {
             type_name: None,
             proxy: None,
             format_proxies: &[],
+            from_conversion: None,
+            into_conversion: None,
             opaque_adapter: None,
             variance: crate::VarianceDesc::BIVARIANT,
             flags: crate::ShapeFlags::empty(),
             tag: None,
             content: None,
             rename: None,
+            child_defaults: None,
         };
     }
 }
@@ -0,0 +1,17 @@
+//! Test that `flatten_prefix_from` must name a sibling field.
+
+use facet::Facet;
+
+#[derive(Facet)]
+struct Metrics {
+    cpu: f32,
+}
+
+#[derive(Facet)]
+struct Instance {
+    name: String,
+    #[facet(flatten, flatten_prefix_from = "host")]
+    metrics: Metrics,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+//! Test that the field named by `flatten_prefix_from` must display as text.
+
+use facet::Facet;
+
+#[derive(Facet)]
+struct Metrics {
+    cpu: f32,
+}
+
+#[derive(Facet)]
+struct Instance {
+    tags: Vec<String>,
+    #[facet(flatten, flatten_prefix_from = "tags")]
+    metrics: Metrics,
+}
+
+fn main() {
+    let _ = <Instance as Facet>::SHAPE;
+}
@@ -280,3 +280,29 @@ fn test_opaque_adapter_recv_mismatch() {
 
     run_compilation_test(&test);
 }
+
+/// Test that `flatten_prefix_from` naming a missing field is rejected.
+#[test]
+#[cfg(not(miri))]
+fn test_flatten_prefix_from_missing_field() {
+    let test = CompilationTest {
+        name: "flatten_prefix_from_missing_field",
+        source: include_str!("../compile_tests/flatten_prefix_from_missing_field.rs"),
+        expected_errors: &["names no sibling field `host`"],
+    };
+
+    run_compilation_test(&test);
+}
+
+/// Test that a `flatten_prefix_from` source without `Display` is rejected.
+#[test]
+#[cfg(not(miri))]
+fn test_flatten_prefix_from_not_display() {
+    let test = CompilationTest {
+        name: "flatten_prefix_from_not_display",
+        source: include_str!("../compile_tests/flatten_prefix_from_not_display.rs"),
+        expected_errors: &["requires `tags` to hold a string or a `Display` type"],
+    };
+
+    run_compilation_test(&test);
+}
@@ -547,6 +547,46 @@ fn enum_rename_all_snake_case() {
     }
 }
 
+#[test]
+fn struct_examples() {
+    #[derive(Facet)]
+    #[facet(example = r#"{"name": "alice"}"#)]
+    #[facet(example = r#"{"name": "bob"}"#)]
+    struct User {
+        name: String,
+    }
+
+    assert_eq!(
+        User::SHAPE.examples().collect::<Vec<_>>(),
+        [r#"{"name": "alice"}"#, r#"{"name": "bob"}"#]
+    );
+}
+
+#[test]
+fn enum_examples() {
+    #[derive(Facet)]
+    #[repr(u8)]
+    #[facet(rename_all = "snake_case", example = r#""light""#)]
+    #[allow(dead_code)]
+    enum Theme {
+        Light,
+        Dark,
+    }
+
+    assert_eq!(Theme::SHAPE.examples().collect::<Vec<_>>(), [r#""light""#]);
+}
+
+#[test]
+fn no_examples() {
+    #[derive(Facet)]
+    struct Plain {
+        value: u32,
+    }
+
+    assert_eq!(Plain::SHAPE.examples().count(), 0);
+    assert_eq!(u32::SHAPE.examples().count(), 0);
+}
+
 #[test]
 fn core_ops_range() {
     let shape = core::ops::Range::<usize>::SHAPE;
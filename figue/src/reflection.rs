@@ -184,7 +184,7 @@ pub(crate) fn coerce_types_from_shape(
                         }
                     }
                     facet_core::ScalarType::Bool => {
-                        if let Some(b) = parse_bool_like(&sourced.value) {
+                        if let Some(b) = facet_core::parse_lenient_bool(&sourced.value) {
                             return ConfigValue::Bool(Sourced {
                                 value: b,
                                 span: sourced.span,
@@ -271,14 +271,6 @@ pub(crate) fn coerce_types_from_shape(
     }
 }
 
-fn parse_bool_like(value: &str) -> Option<bool> {
-    match value.trim().to_ascii_lowercase().as_str() {
-        "true" | "1" | "yes" | "on" => Some(true),
-        "false" | "0" | "no" | "off" => Some(false),
-        _ => None,
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
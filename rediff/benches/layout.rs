@@ -0,0 +1,122 @@
+//! Benchmarks for building and rendering layouts of large diffs.
+//!
+//! Compares a fresh [`FormatArena`] per layout against one arena recycled
+//! across calls, and prints arena statistics for tuning its initial capacity.
+
+use divan::{Bencher, black_box};
+use facet::Facet;
+use facet_reflect::Peek;
+use rediff::layout::{ArenaStats, FormatArena};
+use rediff::{
+    BuildOptions, FacetDiff, RenderOptions, RustFlavor, build_layout, build_layout_in,
+    render_to_string,
+};
+
+fn main() {
+    divan::main();
+}
+
+#[allow(dead_code)]
+#[derive(Facet, Clone)]
+struct Record {
+    id: u64,
+    name: String,
+    kind: Kind,
+    tags: Vec<String>,
+    parent: Option<u64>,
+}
+
+#[derive(Facet, Clone)]
+#[repr(u8)]
+enum Kind {
+    File,
+    Directory,
+    Symlink,
+}
+
+fn records(len: usize, changed: bool) -> Vec<Record> {
+    (0..len as u64)
+        .map(|id| Record {
+            id,
+            name: if changed && id % 3 == 0 {
+                format!("renamed-{id}")
+            } else {
+                format!("record-{id}")
+            },
+            kind: match (id + changed as u64) % 3 {
+                0 => Kind::File,
+                1 => Kind::Directory,
+                _ => Kind::Symlink,
+            },
+            tags: vec!["alpha".into(), format!("tag-{}", id % 7)],
+            parent: (id % 4 != 0).then_some(id / 4),
+        })
+        .collect()
+}
+
+const SIZES: &[usize] = &[10, 100, 1000];
+
+#[divan::bench(args = SIZES)]
+fn build_fresh_arena(bencher: Bencher, len: usize) {
+    let from = records(len, false);
+    let to = records(len, true);
+    let diff = from.diff(&to);
+    let opts = BuildOptions::default();
+
+    bencher.bench_local(|| {
+        let layout = build_layout(
+            black_box(&diff),
+            Peek::new(&from),
+            Peek::new(&to),
+            &opts,
+            &RustFlavor,
+        );
+        black_box(layout.strings.len())
+    });
+}
+
+#[divan::bench(args = SIZES)]
+fn build_reused_arena(bencher: Bencher, len: usize) {
+    let from = records(len, false);
+    let to = records(len, true);
+    let diff = from.diff(&to);
+    let opts = BuildOptions::default();
+    let mut strings = Some(FormatArena::new());
+
+    bencher.bench_local(|| {
+        let layout = build_layout_in(
+            strings.take().unwrap(),
+            black_box(&diff),
+            Peek::new(&from),
+            Peek::new(&to),
+            &opts,
+            &RustFlavor,
+        );
+        let len = layout.strings.len();
+        strings = Some(layout.strings);
+        black_box(len)
+    });
+
+    report_stats(len, strings.unwrap().stats());
+}
+
+#[divan::bench(args = SIZES)]
+fn build_and_render(bencher: Bencher, len: usize) {
+    let from = records(len, false);
+    let to = records(len, true);
+    let diff = from.diff(&to);
+    let opts = BuildOptions::default();
+    let render_opts = RenderOptions::plain();
+
+    bencher.bench_local(|| {
+        let layout = build_layout(&diff, Peek::new(&from), Peek::new(&to), &opts, &RustFlavor);
+        black_box(render_to_string(&layout, &render_opts, &RustFlavor))
+    });
+}
+
+fn report_stats(len: usize, stats: ArenaStats) {
+    eprintln!(
+        "arena[{len}]: {} bytes / {} capacity, {} spans, {} interned ({} hits), {} grows",
+        stats.bytes, stats.capacity, stats.spans, stats.interned, stats.intern_hits, stats.grows,
+    );
+}
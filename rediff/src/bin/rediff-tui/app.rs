@@ -0,0 +1,172 @@
+//! Viewer state: the row tree plus what only the terminal needs (scrolling,
+//! themes, the search prompt), and key handling.
+
+use std::io;
+
+use ratatui::DefaultTerminal;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use rediff::DiffTheme;
+use rediff::layout::Layout;
+
+use crate::tree::Tree;
+use crate::ui;
+
+/// Names accepted by `--theme`, in the order `t` cycles through them.
+pub const THEME_NAMES: &[&str] = &[
+    "auto",
+    "colorblind-dark",
+    "colorblind-light",
+    "orange-blue",
+    "with-bg",
+    "pastel",
+    "one-dark-pro",
+    "tokyo-night",
+];
+
+/// Resolve every theme in [`THEME_NAMES`] order.
+pub fn themes() -> Vec<DiffTheme> {
+    vec![
+        DiffTheme::auto(),
+        DiffTheme::colorblind_dark(),
+        DiffTheme::colorblind_light(),
+        DiffTheme::COLORBLIND_ORANGE_BLUE,
+        DiffTheme::COLORBLIND_WITH_BG,
+        DiffTheme::PASTEL,
+        DiffTheme::ONE_DARK_PRO,
+        DiffTheme::TOKYO_NIGHT,
+    ]
+}
+
+/// What keystrokes currently do.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    /// Navigation.
+    Normal,
+    /// Typing a search query.
+    Search,
+}
+
+pub struct App<'a> {
+    pub tree: Tree<'a>,
+    pub scroll: usize,
+    pub themes: Vec<DiffTheme>,
+    pub theme: usize,
+    pub mode: Mode,
+    pub query: String,
+    pub status: String,
+    quit: bool,
+}
+
+impl<'a> App<'a> {
+    pub fn new(layout: &'a Layout, themes: Vec<DiffTheme>, theme: usize) -> Self {
+        Self {
+            tree: Tree::new(layout),
+            scroll: 0,
+            themes,
+            theme,
+            mode: Mode::Normal,
+            query: String::new(),
+            status: String::new(),
+            quit: false,
+        }
+    }
+
+    pub fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        while !self.quit {
+            let mut page = 0;
+            terminal.draw(|frame| {
+                page = usize::from(frame.area().height.saturating_sub(1));
+                ui::draw(frame, self);
+            })?;
+            if let Event::Key(key) = event::read()?
+                && key.kind == KeyEventKind::Press
+            {
+                self.handle_key(key, page);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn theme(&self) -> &DiffTheme {
+        &self.themes[self.theme]
+    }
+
+    /// Keep the cursor inside a viewport of `height` rows.
+    pub fn scroll_to_cursor(&mut self, height: usize) {
+        let cursor = self.tree.cursor;
+        if cursor < self.scroll {
+            self.scroll = cursor;
+        } else if height > 0 && cursor >= self.scroll + height {
+            self.scroll = cursor + 1 - height;
+        }
+    }
+
+    fn handle_key(&mut self, key: KeyEvent, page: usize) {
+        match self.mode {
+            Mode::Search => match key.code {
+                KeyCode::Esc => {
+                    self.mode = Mode::Normal;
+                    self.query.clear();
+                }
+                KeyCode::Enter => {
+                    self.mode = Mode::Normal;
+                    self.find(true, false);
+                }
+                KeyCode::Backspace => {
+                    self.query.pop();
+                }
+                KeyCode::Char(c) => self.query.push(c),
+                _ => {}
+            },
+            Mode::Normal => {
+                self.status.clear();
+                match key.code {
+                    KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        self.quit = true;
+                    }
+                    KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+                    KeyCode::Down | KeyCode::Char('j') => self.tree.move_by(1),
+                    KeyCode::Up | KeyCode::Char('k') => self.tree.move_by(-1),
+                    KeyCode::PageDown => self.tree.move_by(page as isize),
+                    KeyCode::PageUp => self.tree.move_by(-(page as isize)),
+                    KeyCode::Home | KeyCode::Char('g') => self.tree.cursor = 0,
+                    KeyCode::End | KeyCode::Char('G') => self.tree.move_to_end(),
+                    KeyCode::Enter | KeyCode::Char(' ') => self.tree.toggle_fold(),
+                    KeyCode::Left | KeyCode::Char('h') => self.tree.collapse(),
+                    KeyCode::Right | KeyCode::Char('l') => self.tree.expand(),
+                    KeyCode::Char(']') => self.next_change(true),
+                    KeyCode::Char('[') => self.next_change(false),
+                    KeyCode::Char('/') => {
+                        self.mode = Mode::Search;
+                        self.query.clear();
+                    }
+                    KeyCode::Char('n') => self.find(true, true),
+                    KeyCode::Char('N') => self.find(false, true),
+                    KeyCode::Char('t') => self.theme = (self.theme + 1) % self.themes.len(),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn next_change(&mut self, forward: bool) {
+        if !self.tree.next_change(forward) {
+            self.status = "no changes in view".to_string();
+        }
+    }
+
+    /// Jump to the next row whose text contains the query (case-insensitive).
+    fn find(&mut self, forward: bool, skip_current: bool) {
+        if self.query.is_empty() {
+            return;
+        }
+        let layout = self.tree.layout;
+        let theme = &self.themes[self.theme];
+        let found = self.tree.find(&self.query, forward, skip_current, |row| {
+            ui::row_text(layout, row, theme)
+        });
+        if !found {
+            self.status = format!("pattern not found: {}", self.query);
+        }
+    }
+}
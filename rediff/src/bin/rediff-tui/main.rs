@@ -0,0 +1,108 @@
+//! `rediff-tui`: an interactive terminal viewer for structural diffs.
+//!
+//! ```text
+//! rediff-tui [--theme NAME] <old> <new>
+//! ```
+//!
+//! Both files are loaded with [`rediff::load_file`], so each can be JSON,
+//! YAML or TOML, going by its extension. They are diffed with rediff, and the resulting
+//! [`Layout`](rediff::layout::Layout) is shown as a collapsible tree.
+//!
+//! Keys: `↑`/`↓` (or `k`/`j`) move, `PgUp`/`PgDn` page, `g`/`G` jump to the
+//! top/bottom, `Enter`/`Space` fold, `←`/`h` collapse or go to the parent,
+//! `→`/`l` expand, `]`/`[` next/previous change, `/` search, `n`/`N`
+//! next/previous match, `t` cycle themes, `q` quit.
+
+mod app;
+mod tree;
+mod ui;
+
+use std::process::ExitCode;
+
+use facet_reflect::Peek;
+use rediff::FacetDiff;
+use rediff::layout::{BuildOptions, JsonFlavor, build_layout};
+
+use crate::app::App;
+
+const USAGE: &str = "usage: rediff-tui [--theme NAME] <old> <new> (.json, .yaml, .yml or .toml)";
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("rediff-tui: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+struct Args {
+    theme: Option<String>,
+    old: String,
+    new: String,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut theme = None;
+    let mut paths = Vec::new();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--theme" => {
+                theme = Some(
+                    args.next()
+                        .ok_or_else(|| "--theme needs a value".to_string())?,
+                );
+            }
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                println!("themes: {}", app::THEME_NAMES.join(", "));
+                std::process::exit(0);
+            }
+            _ => paths.push(arg),
+        }
+    }
+    let [old, new] = <[String; 2]>::try_from(paths).map_err(|_| USAGE.to_string())?;
+    Ok(Args { theme, old, new })
+}
+
+fn run() -> Result<(), String> {
+    let args = parse_args()?;
+    let theme = match &args.theme {
+        Some(name) => app::THEME_NAMES
+            .iter()
+            .position(|candidate| candidate == name)
+            .ok_or_else(|| {
+                format!(
+                    "unknown theme `{name}` (available: {})",
+                    app::THEME_NAMES.join(", ")
+                )
+            })?,
+        None => 0,
+    };
+
+    let old = rediff::load_file(&args.old).map_err(|e| e.to_string())?;
+    let new = rediff::load_file(&args.new).map_err(|e| e.to_string())?;
+    let diff = old.diff(&new);
+    if diff.is_equal() {
+        println!("no differences");
+        return Ok(());
+    }
+
+    let layout = build_layout(
+        &diff,
+        Peek::new(&old),
+        Peek::new(&new),
+        &BuildOptions::default(),
+        &JsonFlavor,
+    );
+
+    // Resolve themes (including terminal background detection) before the
+    // terminal switches to raw mode.
+    let mut app = App::new(&layout, app::themes(), theme);
+    let mut terminal = ratatui::init();
+    let result = app.run(&mut terminal);
+    ratatui::restore();
+    result.map_err(|e| e.to_string())
+}
@@ -0,0 +1,377 @@
+//! The row model: the visible part of the layout flattened into rows, with
+//! the cursor, folds and search. Knows nothing about the terminal.
+
+use std::collections::HashSet;
+
+use indextree::NodeId;
+use rediff::layout::{AttrStatus, ElementChange, Layout, LayoutEdge, LayoutNode};
+
+/// One visible line of the tree.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Row {
+    /// The layout node this line belongs to.
+    pub node: NodeId,
+    /// Indentation level.
+    pub depth: usize,
+    /// `None` for the node's own line, `Some(i)` for its `i`th attribute.
+    pub attr: Option<usize>,
+}
+
+pub struct Tree<'a> {
+    pub layout: &'a Layout,
+    pub rows: Vec<Row>,
+    pub folded: HashSet<NodeId>,
+    pub cursor: usize,
+}
+
+impl<'a> Tree<'a> {
+    pub fn new(layout: &'a Layout) -> Self {
+        let mut tree = Self {
+            layout,
+            rows: Vec::new(),
+            folded: HashSet::new(),
+            cursor: 0,
+        };
+        tree.rebuild_rows();
+        tree
+    }
+
+    /// Flatten the visible part of the layout into rows, keeping the cursor
+    /// on the same row when it is still visible.
+    fn rebuild_rows(&mut self) {
+        let selected = self.rows.get(self.cursor).copied();
+        self.rows.clear();
+
+        let mut folded_at = None;
+        for edge in self.layout.walk() {
+            match edge {
+                LayoutEdge::Enter(visit) => {
+                    if folded_at.is_some() {
+                        continue;
+                    }
+                    self.rows.push(Row {
+                        node: visit.id,
+                        depth: visit.depth,
+                        attr: None,
+                    });
+                    if self.folded.contains(&visit.id) {
+                        folded_at = Some(visit.id);
+                        continue;
+                    }
+                    if let LayoutNode::Element { attrs, .. } = visit.node {
+                        self.rows.extend((0..attrs.len()).map(|index| Row {
+                            node: visit.id,
+                            depth: visit.depth + 1,
+                            attr: Some(index),
+                        }));
+                    }
+                }
+                LayoutEdge::Leave(visit) => {
+                    if folded_at == Some(visit.id) {
+                        folded_at = None;
+                    }
+                }
+            }
+        }
+
+        if let Some(selected) = selected
+            && let Some(index) = self.rows.iter().position(|row| *row == selected)
+        {
+            self.cursor = index;
+        }
+        self.cursor = self.cursor.min(self.rows.len().saturating_sub(1));
+    }
+
+    /// Whether the row is a node line with something to fold away.
+    pub fn foldable(&self, row: &Row) -> bool {
+        row.attr.is_none()
+            && (self.layout.children(row.node).next().is_some()
+                || matches!(
+                    self.layout.get(row.node),
+                    Some(LayoutNode::Element { attrs, .. }) if !attrs.is_empty()
+                ))
+    }
+
+    /// Whether the row shows a change (as opposed to unchanged context).
+    pub fn is_change(&self, row: &Row) -> bool {
+        match (row.attr, self.layout.get(row.node)) {
+            (Some(index), Some(LayoutNode::Element { attrs, .. })) => {
+                !matches!(attrs[index].status, AttrStatus::Unchanged { .. })
+            }
+            (
+                None,
+                Some(
+                    LayoutNode::ValueChange { .. }
+                    | LayoutNode::HexDump { .. }
+                    | LayoutNode::BlobChange { .. },
+                ),
+            ) => true,
+            (None, Some(node)) => node.change() != ElementChange::None,
+            _ => false,
+        }
+    }
+
+    pub fn move_by(&mut self, delta: isize) {
+        let last = self.rows.len().saturating_sub(1);
+        self.cursor = self.cursor.saturating_add_signed(delta).min(last);
+    }
+
+    pub fn move_to_end(&mut self) {
+        self.cursor = self.rows.len().saturating_sub(1);
+    }
+
+    pub fn toggle_fold(&mut self) {
+        let Some(row) = self.rows.get(self.cursor).copied() else {
+            return;
+        };
+        if !self.foldable(&row) {
+            return;
+        }
+        if !self.folded.remove(&row.node) {
+            self.folded.insert(row.node);
+        }
+        self.rebuild_rows();
+    }
+
+    /// Fold the current node, or move to its parent if it is already folded
+    /// (or has nothing to fold).
+    pub fn collapse(&mut self) {
+        let Some(row) = self.rows.get(self.cursor).copied() else {
+            return;
+        };
+        if self.foldable(&row) && !self.folded.contains(&row.node) {
+            self.folded.insert(row.node);
+            self.rebuild_rows();
+            return;
+        }
+        let parent = match row.attr {
+            Some(_) => Some(row.node),
+            None => self.layout.parent(row.node),
+        };
+        if let Some(index) = parent.and_then(|parent| {
+            self.rows
+                .iter()
+                .position(|r| r.node == parent && r.attr.is_none())
+        }) {
+            self.cursor = index;
+        }
+    }
+
+    pub fn expand(&mut self) {
+        let Some(row) = self.rows.get(self.cursor).copied() else {
+            return;
+        };
+        if self.folded.remove(&row.node) {
+            self.rebuild_rows();
+        }
+    }
+
+    /// Move to the next (or previous) changed row. Returns false if there
+    /// is none in view.
+    pub fn next_change(&mut self, forward: bool) -> bool {
+        self.jump(self.scan(forward, true, |row| self.is_change(row)))
+    }
+
+    /// Move to the next (or previous) row whose `text` contains `query`,
+    /// ignoring case. Returns false if there is none in view.
+    pub fn find(
+        &mut self,
+        query: &str,
+        forward: bool,
+        skip_current: bool,
+        text: impl Fn(&Row) -> String,
+    ) -> bool {
+        let needle = query.to_lowercase();
+        self.jump(self.scan(forward, skip_current, |row| {
+            text(row).to_lowercase().contains(&needle)
+        }))
+    }
+
+    fn jump(&mut self, index: Option<usize>) -> bool {
+        if let Some(index) = index {
+            self.cursor = index;
+        }
+        index.is_some()
+    }
+
+    /// Index of the first row from the cursor (wrapping around) matching
+    /// `pred`. With `skip_current`, the cursor's own row is tried last.
+    fn scan(
+        &self,
+        forward: bool,
+        skip_current: bool,
+        pred: impl Fn(&Row) -> bool,
+    ) -> Option<usize> {
+        let len = self.rows.len();
+        let start = usize::from(skip_current);
+        (start..start + len)
+            .map(|offset| {
+                if forward {
+                    (self.cursor + offset) % len
+                } else {
+                    (self.cursor + len - offset) % len
+                }
+            })
+            .find(|&index| pred(&self.rows[index]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use facet::Facet;
+    use facet_reflect::Peek;
+    use rediff::layout::{BuildOptions, JsonFlavor, build_layout};
+    use rediff::{DiffTheme, FacetDiff};
+
+    use super::*;
+    use crate::ui;
+
+    #[derive(Facet)]
+    struct Server {
+        host: String,
+        port: u16,
+    }
+
+    #[derive(Facet)]
+    struct Config {
+        server: Server,
+        verbose: bool,
+    }
+
+    fn config(port: u16) -> Config {
+        Config {
+            server: Server {
+                host: "localhost".to_string(),
+                port,
+            },
+            verbose: true,
+        }
+    }
+
+    /// `Config` with `verbose` as an attribute and `server` as a child
+    /// holding `host` (unchanged) and `port` (changed).
+    fn layout() -> Layout {
+        let (old, new) = (config(8080), config(9090));
+        build_layout(
+            &old.diff(&new),
+            Peek::new(&old),
+            Peek::new(&new),
+            &BuildOptions::default(),
+            &JsonFlavor,
+        )
+    }
+
+    fn shape(tree: &Tree) -> Vec<(usize, Option<usize>)> {
+        tree.rows.iter().map(|row| (row.depth, row.attr)).collect()
+    }
+
+    fn text(layout: &Layout) -> impl Fn(&Row) -> String {
+        move |row| ui::row_text(layout, row, &DiffTheme::PASTEL)
+    }
+
+    #[test]
+    fn rows_list_nodes_then_their_attributes() {
+        let layout = layout();
+        let tree = Tree::new(&layout);
+        assert_eq!(
+            shape(&tree),
+            [
+                (0, None),
+                (1, Some(0)),
+                (1, None),
+                (2, Some(0)),
+                (2, Some(1))
+            ]
+        );
+    }
+
+    #[test]
+    fn folding_hides_a_subtree_and_keeps_the_cursor() {
+        let layout = layout();
+        let mut tree = Tree::new(&layout);
+        tree.cursor = 2;
+        let server = tree.rows[2];
+        assert!(tree.foldable(&server));
+
+        tree.toggle_fold();
+        assert_eq!(shape(&tree), [(0, None), (1, Some(0)), (1, None)]);
+        assert_eq!(tree.rows[tree.cursor], server);
+
+        tree.toggle_fold();
+        assert_eq!(tree.rows.len(), 5);
+        assert_eq!(tree.rows[tree.cursor], server);
+    }
+
+    #[test]
+    fn folding_the_root_leaves_one_row() {
+        let layout = layout();
+        let mut tree = Tree::new(&layout);
+        tree.collapse();
+        assert_eq!(tree.rows.len(), 1);
+        tree.move_by(3);
+        assert_eq!(tree.cursor, 0);
+
+        tree.expand();
+        assert_eq!(tree.rows.len(), 5);
+    }
+
+    #[test]
+    fn collapse_on_an_attribute_goes_to_its_node() {
+        let layout = layout();
+        let mut tree = Tree::new(&layout);
+        tree.move_to_end();
+        tree.collapse();
+        assert_eq!(tree.cursor, 2);
+        assert_eq!(tree.rows.len(), 5);
+
+        // Already folded: go up to the parent
+        tree.collapse();
+        tree.collapse();
+        assert_eq!(tree.cursor, 0);
+    }
+
+    #[test]
+    fn next_change_skips_unchanged_rows_and_wraps() {
+        let layout = layout();
+        let mut tree = Tree::new(&layout);
+        assert!(tree.next_change(true));
+        assert_eq!(tree.cursor, 4);
+        // The only change: wrapping around comes back to it
+        assert!(tree.next_change(true));
+        assert_eq!(tree.cursor, 4);
+        assert!(tree.next_change(false));
+        assert_eq!(tree.cursor, 4);
+
+        tree.folded.insert(tree.rows[2].node);
+        tree.rebuild_rows();
+        assert!(!tree.next_change(true));
+    }
+
+    #[test]
+    fn search_is_case_insensitive_and_wraps() {
+        let layout = layout();
+        let mut tree = Tree::new(&layout);
+        assert!(tree.find("LOCALHOST", true, false, text(&layout)));
+        assert_eq!(tree.cursor, 3);
+
+        assert!(tree.find("9090", true, true, text(&layout)));
+        assert_eq!(tree.cursor, 4);
+
+        // `port` is the only match, so searching on wraps back to it
+        assert!(tree.find("port", true, true, text(&layout)));
+        assert_eq!(tree.cursor, 4);
+
+        assert!(tree.find("verbose", false, true, text(&layout)));
+        assert_eq!(tree.cursor, 1);
+    }
+
+    #[test]
+    fn search_only_sees_unfolded_rows() {
+        let layout = layout();
+        let mut tree = Tree::new(&layout);
+        tree.cursor = 2;
+        tree.toggle_fold();
+        assert!(!tree.find("localhost", true, false, text(&layout)));
+        assert_eq!(tree.cursor, 2);
+    }
+}
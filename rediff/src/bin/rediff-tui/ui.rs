@@ -0,0 +1,240 @@
+//! Drawing: turns rows into styled ratatui lines.
+
+use owo_colors::Rgb;
+use ratatui::Frame;
+use ratatui::layout::Constraint;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+use rediff::DiffTheme;
+use rediff::layout::{AttrStatus, ElementChange, FormattedValue, Layout, LayoutNode, ValueType};
+
+use crate::app::{App, Mode};
+use crate::tree::Row;
+
+pub fn draw(frame: &mut Frame, app: &mut App) {
+    let [body, footer] =
+        ratatui::layout::Layout::vertical([Constraint::Min(1), Constraint::Length(1)])
+            .areas(frame.area());
+
+    let height = usize::from(body.height);
+    app.scroll_to_cursor(height);
+
+    let theme = app.theme();
+    let lines: Vec<Line> = app
+        .tree
+        .rows
+        .iter()
+        .enumerate()
+        .skip(app.scroll)
+        .take(height)
+        .map(|(index, row)| {
+            let line = row_line(app, row, theme);
+            if index == app.tree.cursor {
+                line.patch_style(Style::new().add_modifier(Modifier::REVERSED))
+            } else {
+                line
+            }
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), body);
+    frame.render_widget(footer_line(app), footer);
+}
+
+fn footer_line<'a>(app: &App) -> Line<'a> {
+    let dim = Style::new().add_modifier(Modifier::DIM);
+    match app.mode {
+        Mode::Search => Line::from(format!("/{}", app.query)),
+        Mode::Normal if !app.status.is_empty() => Line::from(app.status.clone()),
+        Mode::Normal => Line::from(vec![
+            Span::raw(format!(
+                "{}/{} ",
+                (app.tree.cursor + 1).min(app.tree.rows.len()),
+                app.tree.rows.len()
+            )),
+            Span::styled(
+                format!(
+                    " q quit  ⏎ fold  ]/[ change  / search  n/N match  t theme ({})",
+                    crate::app::THEME_NAMES[app.theme]
+                ),
+                dim,
+            ),
+        ]),
+    }
+}
+
+fn row_line<'a>(app: &App<'a>, row: &Row, theme: &DiffTheme) -> Line<'a> {
+    let marker = if !app.tree.foldable(row) {
+        "  "
+    } else if app.tree.folded.contains(&row.node) {
+        "▸ "
+    } else {
+        "▾ "
+    };
+    let mut spans = vec![
+        Span::raw("  ".repeat(row.depth)),
+        Span::styled(marker, fg(theme.structure)),
+    ];
+    spans.extend(row_spans(app.tree.layout, row, theme));
+    Line::from(spans)
+}
+
+/// Plain text of a row, used for searching.
+pub fn row_text(layout: &Layout, row: &Row, theme: &DiffTheme) -> String {
+    row_spans(layout, row, theme)
+        .iter()
+        .map(|span| span.content.as_ref())
+        .collect()
+}
+
+fn row_spans<'a>(layout: &'a Layout, row: &Row, theme: &DiffTheme) -> Vec<Span<'a>> {
+    let mut spans = Vec::new();
+    let Some(node) = layout.get(row.node) else {
+        return spans;
+    };
+
+    if let Some(index) = row.attr {
+        let LayoutNode::Element { attrs, .. } = node else {
+            return spans;
+        };
+        let attr = &attrs[index];
+        let key = |change| Span::styled(format!("{}: ", attr.name), key_style(change, theme));
+        match &attr.status {
+            AttrStatus::Unchanged { value } => {
+                spans.push(key(ElementChange::None));
+                spans.push(value_span(layout, value, ElementChange::None, theme));
+            }
+            AttrStatus::Changed { old, new } => {
+                spans.push(key(ElementChange::None));
+                spans.push(value_span(layout, old, ElementChange::Deleted, theme));
+                spans.push(Span::styled(" → ", fg(theme.comment)));
+                spans.push(value_span(layout, new, ElementChange::Inserted, theme));
+            }
+            AttrStatus::Deleted { value } => {
+                spans.push(prefix_span(ElementChange::Deleted, theme));
+                spans.push(key(ElementChange::Deleted));
+                spans.push(value_span(layout, value, ElementChange::Deleted, theme));
+            }
+            AttrStatus::Inserted { value } => {
+                spans.push(prefix_span(ElementChange::Inserted, theme));
+                spans.push(key(ElementChange::Inserted));
+                spans.push(value_span(layout, value, ElementChange::Inserted, theme));
+            }
+        }
+        return spans;
+    }
+
+    let change = node.change();
+    if change != ElementChange::None {
+        spans.push(prefix_span(change, theme));
+    }
+    if let Some(name) = node.field_name() {
+        spans.push(Span::styled(format!("{name}: "), key_style(change, theme)));
+    }
+
+    let structure = if change == ElementChange::None {
+        fg(theme.structure)
+    } else {
+        change_style(change, theme)
+    };
+    let comment = fg(theme.comment);
+    match node {
+        LayoutNode::Element { tag, .. } => spans.push(Span::styled(
+            tag.to_string(),
+            change_style(change, theme).add_modifier(Modifier::BOLD),
+        )),
+        LayoutNode::Sequence { item_type, .. } => {
+            spans.push(Span::styled(format!("[{item_type}]"), structure));
+        }
+        LayoutNode::Tuple { tag, .. } => {
+            spans.push(Span::styled(format!("{tag}(…)"), structure));
+        }
+        LayoutNode::Collapsed { count, names } => {
+            let text = if names.is_empty() {
+                format!(".. {count} unchanged")
+            } else {
+                format!(".. {} unchanged", names.join(", "))
+            };
+            spans.push(Span::styled(text, comment));
+        }
+        LayoutNode::Text { value, .. } => spans.push(value_span(layout, value, change, theme)),
+        LayoutNode::ValueChange { old, new, .. } => {
+            spans.push(value_span(layout, old, ElementChange::Deleted, theme));
+            spans.push(Span::styled(" → ", comment));
+            spans.push(value_span(layout, new, ElementChange::Inserted, theme));
+        }
+        LayoutNode::BlobChange { old, new, .. } => {
+            spans.push(value_span(layout, old, ElementChange::Deleted, theme));
+            spans.push(Span::styled(" → ", comment));
+            spans.push(value_span(layout, new, ElementChange::Inserted, theme));
+        }
+        LayoutNode::HexDump { lines, .. } => {
+            spans.push(Span::styled(
+                format!("<binary diff, {} lines>", lines.len()),
+                comment,
+            ));
+        }
+        LayoutNode::ItemGroup {
+            items,
+            collapsed_suffix,
+            ..
+        } => {
+            for (index, item) in items.iter().enumerate() {
+                if index > 0 {
+                    spans.push(Span::styled(", ", structure));
+                }
+                spans.push(value_span(layout, item, change, theme));
+            }
+            if let Some(more) = collapsed_suffix {
+                spans.push(Span::styled(format!(" ...{more} more"), comment));
+            }
+        }
+    }
+    spans
+}
+
+fn fg(rgb: Rgb) -> Style {
+    Style::new().fg(Color::Rgb(rgb.0, rgb.1, rgb.2))
+}
+
+fn change_style(change: ElementChange, theme: &DiffTheme) -> Style {
+    match change {
+        ElementChange::None => Style::new(),
+        ElementChange::Deleted => fg(theme.deleted),
+        ElementChange::Inserted => fg(theme.inserted),
+        ElementChange::MovedFrom | ElementChange::MovedTo => fg(theme.moved),
+    }
+}
+
+fn key_style(change: ElementChange, theme: &DiffTheme) -> Style {
+    match change {
+        ElementChange::Deleted => fg(theme.deleted_key()),
+        ElementChange::Inserted => fg(theme.inserted_key()),
+        _ => fg(theme.key),
+    }
+}
+
+fn prefix_span<'a>(change: ElementChange, theme: &DiffTheme) -> Span<'a> {
+    let prefix = change.prefix().unwrap_or(' ');
+    Span::styled(format!("{prefix} "), change_style(change, theme))
+}
+
+fn value_span<'a>(
+    layout: &'a Layout,
+    value: &FormattedValue,
+    change: ElementChange,
+    theme: &DiffTheme,
+) -> Span<'a> {
+    let style = if change == ElementChange::None {
+        fg(match value.value_type {
+            ValueType::String => theme.string,
+            ValueType::Number => theme.number,
+            ValueType::Boolean => theme.boolean,
+            ValueType::Null => theme.null,
+            ValueType::Other => theme.unchanged,
+        })
+    } else {
+        change_style(change, theme)
+    };
+    Span::styled(layout.value_str(value), style)
+}
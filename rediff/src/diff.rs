@@ -8,6 +8,7 @@ use crate::{Diff, Path, PathSegment, Updates, Value};
 use facet::{Def, DynValueKind, StructKind, Type, UserType};
 use facet_core::Facet;
 use facet_reflect::{HasFields, Peek, ScalarType};
+use unicode_normalization::UnicodeNormalization;
 
 use crate::sequences;
 
@@ -18,6 +19,71 @@ pub struct DiffOptions {
     /// If set, two floats are considered equal if their absolute difference
     /// is less than or equal to this value.
     pub float_tolerance: Option<f64>,
+    /// How strings are compared. Only affects whether two strings are
+    /// considered equal; the diff still shows the original values.
+    pub strings: StringComparison,
+}
+
+/// Transformations applied to both sides before comparing strings.
+///
+/// They are applied in field order: normalization, then trimming, then case
+/// folding.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StringComparison {
+    /// Unicode normalization form to bring both strings to.
+    pub normalization: Option<Normalization>,
+    /// Ignore leading and trailing whitespace.
+    pub trim: bool,
+    /// Ignore case. Uses Unicode lowercase mapping, which is
+    /// locale-independent (a Turkish dotless `ı` doesn't match `I`).
+    pub case_fold: bool,
+}
+
+/// A Unicode normalization form, see [UAX #15](https://unicode.org/reports/tr15/).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Normalization {
+    /// Canonical composition: `e` followed by a combining acute accent
+    /// matches a precomposed `é`.
+    Nfc,
+    /// Compatibility composition: also folds compatibility variants, so
+    /// `ﬁ` matches `fi` and a full-width `Ａ` matches `A`.
+    Nfkc,
+}
+
+impl StringComparison {
+    /// Whether no transformation is enabled.
+    pub const fn is_exact(&self) -> bool {
+        self.normalization.is_none() && !self.trim && !self.case_fold
+    }
+
+    /// Whether `a` and `b` are equal after the configured transformations.
+    pub fn matches(&self, a: &str, b: &str) -> bool {
+        if a == b {
+            return true;
+        }
+        if self.is_exact() {
+            return false;
+        }
+        self.canonicalize(a) == self.canonicalize(b)
+    }
+
+    fn canonicalize<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        let mut s = match self.normalization {
+            Some(Normalization::Nfc) => Cow::Owned(s.nfc().collect::<String>()),
+            Some(Normalization::Nfkc) => Cow::Owned(s.nfkc().collect::<String>()),
+            None => Cow::Borrowed(s),
+        };
+        if self.trim {
+            s = match s {
+                Cow::Borrowed(b) => Cow::Borrowed(b.trim()),
+                Cow::Owned(o) => Cow::Owned(o.trim().to_owned()),
+            };
+        }
+        if self.case_fold {
+            s = Cow::Owned(s.to_lowercase());
+        }
+        s
+    }
 }
 
 impl DiffOptions {
@@ -31,6 +97,24 @@ impl DiffOptions {
         self.float_tolerance = Some(tolerance);
         self
     }
+
+    /// Normalize strings to the given Unicode form before comparing them.
+    pub const fn with_normalization(mut self, normalization: Normalization) -> Self {
+        self.strings.normalization = Some(normalization);
+        self
+    }
+
+    /// Ignore leading and trailing whitespace when comparing strings.
+    pub const fn with_trimmed_strings(mut self) -> Self {
+        self.strings.trim = true;
+        self
+    }
+
+    /// Ignore case when comparing strings.
+    pub const fn with_case_folding(mut self) -> Self {
+        self.strings.case_fold = true;
+        self
+    }
 }
 
 /// Extension trait that provides a [`diff`](FacetDiff::diff) method for `Facet` types
@@ -81,6 +165,15 @@ pub fn diff_new_peek_with_options<'mem, 'facet>(
         return Diff::Equal { value: Some(from) };
     }
 
+    // Strings compare through the configured transformations, across string
+    // types too (`String` against `&str`)
+    if !options.strings.is_exact()
+        && let (Some(a), Some(b)) = (from.as_str(), to.as_str())
+        && options.strings.matches(a, b)
+    {
+        return Diff::Equal { value: Some(from) };
+    }
+
     match (
         (from.shape().def, from.shape().ty),
         (to.shape().def, to.shape().ty),
@@ -487,14 +580,24 @@ fn diff_dynamic_values<'mem, 'facet>(
                     },
                 },
             };
-            if same {
+            // The tolerance applies to numbers inside dynamic values too
+            let within_tolerance =
+                match (options.float_tolerance, from_dyn.as_f64(), to_dyn.as_f64()) {
+                    (Some(tol), Some(l), Some(r)) => (l - r).abs() <= tol,
+                    _ => false,
+                };
+            if same || within_tolerance {
                 Diff::Equal { value: Some(from) }
             } else {
                 Diff::Replace { from, to }
             }
         }
         DynValueKind::String => {
-            if from_dyn.as_str() == to_dyn.as_str() {
+            let equal = match (from_dyn.as_str(), to_dyn.as_str()) {
+                (Some(a), Some(b)) => options.strings.matches(a, b),
+                (a, b) => a == b,
+            };
+            if equal {
                 Diff::Equal { value: Some(from) }
             } else {
                 Diff::Replace { from, to }
@@ -663,7 +766,8 @@ fn diff_dynamic_vs_concrete<'mem, 'facet>(
         DynValueKind::String
             if concrete_peek
                 .as_str()
-                .is_some_and(|s| dyn_val.as_str() == Some(s)) =>
+                .zip(dyn_val.as_str())
+                .is_some_and(|(a, b)| options.strings.matches(a, b)) =>
         {
             return Diff::Equal {
                 value: Some(from_peek),
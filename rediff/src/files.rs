@@ -0,0 +1,262 @@
+//! Diffing documents on disk, across formats.
+//!
+//! Both files are parsed into [`facet_value::Value`]s, whatever their format,
+//! and diffed structurally. A JSON config and its YAML rewrite compare equal
+//! when they hold the same data, regardless of key order, quoting, comments
+//! or how numbers are spelled.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use facet_format::DeserializeError;
+use facet_reflect::Peek;
+use facet_value::Value;
+
+use crate::{Diff, diff_new_peek, format_diff_default};
+
+/// A document format that [`diff_files`] can read.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FileFormat {
+    /// JSON (`.json`)
+    Json,
+    /// YAML (`.yaml`, `.yml`)
+    Yaml,
+    /// TOML (`.toml`)
+    Toml,
+}
+
+impl FileFormat {
+    /// Picks the format from a path's extension, ignoring case.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "json" => Some(Self::Json),
+            "yaml" | "yml" => Some(Self::Yaml),
+            "toml" => Some(Self::Toml),
+            _ => None,
+        }
+    }
+
+    /// Parses a document in this format into a dynamic value.
+    pub fn parse(self, text: &str) -> Result<Value, DeserializeError> {
+        match self {
+            Self::Json => facet_json::from_str(text),
+            Self::Yaml => facet_yaml::from_str(text),
+            Self::Toml => facet_toml::from_str(text),
+        }
+    }
+}
+
+impl fmt::Display for FileFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Json => "JSON",
+            Self::Yaml => "YAML",
+            Self::Toml => "TOML",
+        })
+    }
+}
+
+/// Why a file couldn't be loaded for diffing.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum FileError {
+    /// The file couldn't be read.
+    Read {
+        /// The file
+        path: PathBuf,
+        /// The underlying I/O error
+        source: std::io::Error,
+    },
+    /// The file's extension doesn't name a known format.
+    UnknownFormat {
+        /// The file
+        path: PathBuf,
+    },
+    /// The file isn't valid in its format.
+    Parse {
+        /// The file
+        path: PathBuf,
+        /// The format it was parsed as
+        format: FileFormat,
+        /// The parser's error
+        source: DeserializeError,
+    },
+}
+
+impl fmt::Display for FileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read { path, source } => write!(f, "{}: {source}", path.display()),
+            Self::UnknownFormat { path } => write!(
+                f,
+                "{}: unknown format (expected a .json, .yaml, .yml or .toml file)",
+                path.display()
+            ),
+            Self::Parse {
+                path,
+                format,
+                source,
+            } => write!(f, "{}: invalid {format}: {source}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for FileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Read { source, .. } => Some(source),
+            Self::UnknownFormat { .. } => None,
+            Self::Parse { source, .. } => Some(source),
+        }
+    }
+}
+
+/// Loads a file into a dynamic value, picking the format from its extension.
+pub fn load_file(path: impl AsRef<Path>) -> Result<Value, FileError> {
+    let path = path.as_ref();
+    let format = FileFormat::from_path(path).ok_or_else(|| FileError::UnknownFormat {
+        path: path.to_path_buf(),
+    })?;
+    let text = std::fs::read_to_string(path).map_err(|source| FileError::Read {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    format.parse(&text).map_err(|source| FileError::Parse {
+        path: path.to_path_buf(),
+        format,
+        source,
+    })
+}
+
+/// Two loaded documents, ready to diff. Returned by [`diff_files`].
+#[derive(Debug, Clone)]
+pub struct FileDiff {
+    /// The first file's contents
+    pub old: Value,
+    /// The second file's contents
+    pub new: Value,
+}
+
+impl FileDiff {
+    /// Computes the structural diff between the two documents.
+    pub fn diff(&self) -> Diff<'_, '_> {
+        diff_new_peek(Peek::new(&self.old), Peek::new(&self.new))
+    }
+
+    /// Returns true if both documents hold the same data.
+    pub fn is_same(&self) -> bool {
+        self.diff().is_equal()
+    }
+}
+
+/// Renders the diff like [`format_diff_default`].
+impl fmt::Display for FileDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&format_diff_default(&self.diff()))
+    }
+}
+
+/// Loads two files, each in the format named by its extension, and
+/// prepares them for a semantic diff.
+///
+/// Supports `.json`, `.yaml`/`.yml` and `.toml`, in any combination.
+///
+/// ```no_run
+/// let changes = rediff::diff_files("config.json", "config.yaml")?;
+/// if changes.is_same() {
+///     println!("the migration changed nothing");
+/// } else {
+///     println!("{changes}");
+/// }
+/// # Ok::<(), rediff::FileError>(())
+/// ```
+pub fn diff_files(old: impl AsRef<Path>, new: impl AsRef<Path>) -> Result<FileDiff, FileError> {
+    Ok(FileDiff {
+        old: load_file(old)?,
+        new: load_file(new)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JSON: &str =
+        r#"{"name": "api", "port": 8080, "tags": ["a", "b"], "tls": {"enabled": true}}"#;
+
+    fn scratch_file(name: &str, contents: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("rediff-files-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn same_data_in_different_formats_is_same() {
+        let yaml = "# migrated\ntls:\n  enabled: true\nname: api\nport: 8080\ntags: [a, b]\n";
+        let toml = "name = \"api\"\nport = 8080\ntags = [\"a\", \"b\"]\n\n[tls]\nenabled = true\n";
+
+        let json = FileFormat::Json.parse(JSON).unwrap();
+        let yaml = FileFormat::Yaml.parse(yaml).unwrap();
+        let toml = FileFormat::Toml.parse(toml).unwrap();
+
+        let json_to_yaml = FileDiff {
+            old: json.clone(),
+            new: yaml,
+        };
+        assert!(json_to_yaml.is_same(), "{json_to_yaml}");
+        let json_to_toml = FileDiff {
+            old: json,
+            new: toml,
+        };
+        assert!(json_to_toml.is_same(), "{json_to_toml}");
+    }
+
+    #[test]
+    fn diff_files_reports_changes() {
+        let old = scratch_file("changes.json", JSON);
+        let new = scratch_file(
+            "changes.yaml",
+            "name: api\nport: 9090\ntags: [a, b]\ntls:\n  enabled: true\n",
+        );
+
+        let changes = diff_files(&old, &new).unwrap();
+        assert!(!changes.is_same());
+        let rendered = changes.to_string();
+        assert!(rendered.contains("8080"), "{rendered}");
+        assert!(rendered.contains("9090"), "{rendered}");
+    }
+
+    #[test]
+    fn format_comes_from_the_extension() {
+        assert_eq!(
+            FileFormat::from_path(Path::new("a/b.YML")),
+            Some(FileFormat::Yaml)
+        );
+        assert_eq!(FileFormat::from_path(Path::new("Cargo.lock")), None);
+
+        let unknown = scratch_file("notes.txt", "hello");
+        let err = load_file(&unknown).unwrap_err();
+        assert!(matches!(err, FileError::UnknownFormat { .. }), "{err}");
+    }
+
+    #[test]
+    fn parse_errors_name_the_file() {
+        let broken = scratch_file("broken.json", "{\"name\": ");
+        let err = load_file(&broken).unwrap_err();
+        assert!(
+            matches!(
+                err,
+                FileError::Parse {
+                    format: FileFormat::Json,
+                    ..
+                }
+            ),
+            "{err}"
+        );
+        assert!(err.to_string().contains("broken.json"), "{err}");
+    }
+}
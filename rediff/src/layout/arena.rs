@@ -1,5 +1,6 @@
 //! Format arena for string storage.
 
+use std::collections::HashMap;
 use std::fmt;
 use unicode_width::UnicodeWidthStr;
 
@@ -26,12 +27,39 @@ impl Span {
     }
 }
 
+/// Counters describing how a [`FormatArena`] has been used.
+///
+/// Handy for tuning [`FormatArena::with_capacity`]: if `grows` is non-zero for
+/// typical diffs, the initial capacity is too small.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ArenaStats {
+    /// Bytes currently stored in the buffer.
+    pub bytes: usize,
+    /// Capacity of the buffer in bytes.
+    pub capacity: usize,
+    /// Number of spans handed out by `format` and `push_str`.
+    pub spans: usize,
+    /// Number of distinct strings stored by `intern`.
+    pub interned: usize,
+    /// Number of `intern` calls answered from the intern table.
+    pub intern_hits: usize,
+    /// Number of times the buffer had to reallocate.
+    pub grows: usize,
+}
+
 /// Arena for formatted strings.
 ///
 /// All scalar values are formatted once into this buffer and referenced by [`Span`].
 /// This avoids per-value allocations and allows measuring display width at format time.
+///
+/// The buffer is a bump allocator: spans are only ever appended, and [`clear`](Self::clear)
+/// drops them all at once while keeping the allocation, so one arena can be reused across
+/// many layouts (see [`build_layout_in`](super::build_layout_in)). Short strings that repeat
+/// a lot, like placeholders and variant names, can be stored once with [`intern`](Self::intern).
 pub struct FormatArena {
     buf: String,
+    interned: HashMap<Box<str>, (Span, usize)>,
+    stats: ArenaStats,
 }
 
 impl FormatArena {
@@ -39,6 +67,8 @@ impl FormatArena {
     pub fn with_capacity(cap: usize) -> Self {
         Self {
             buf: String::with_capacity(cap),
+            interned: HashMap::new(),
+            stats: ArenaStats::default(),
         }
     }
 
@@ -57,9 +87,11 @@ impl FormatArena {
     where
         F: FnOnce(&mut String) -> fmt::Result,
     {
+        let cap = self.buf.capacity();
         let start = self.buf.len();
         f(&mut self.buf).expect("formatting to String cannot fail");
         let end = self.buf.len();
+        self.record_span(cap);
         let span = Span {
             start: start as u32,
             end: end as u32,
@@ -70,8 +102,10 @@ impl FormatArena {
 
     /// Push a string directly, returning span and display width.
     pub fn push_str(&mut self, s: &str) -> (Span, usize) {
+        let cap = self.buf.capacity();
         let start = self.buf.len();
         self.buf.push_str(s);
+        self.record_span(cap);
         let span = Span {
             start: start as u32,
             end: self.buf.len() as u32,
@@ -80,6 +114,21 @@ impl FormatArena {
         (span, width)
     }
 
+    /// Push a string unless an identical one was interned before, in which
+    /// case the existing span is returned.
+    ///
+    /// Only worth it for short strings that are likely to repeat; each
+    /// distinct interned string costs one entry in a hash table.
+    pub fn intern(&mut self, s: &str) -> (Span, usize) {
+        if let Some(&found) = self.interned.get(s) {
+            self.stats.intern_hits += 1;
+            return found;
+        }
+        let found = self.push_str(s);
+        self.interned.insert(s.into(), found);
+        found
+    }
+
     /// Retrieve the string for a span.
     #[inline]
     pub fn get(&self, span: Span) -> &str {
@@ -95,6 +144,33 @@ impl FormatArena {
     pub const fn is_empty(&self) -> bool {
         self.buf.is_empty()
     }
+
+    /// Drop every stored string, keeping the allocation for reuse.
+    ///
+    /// Spans handed out before the call are invalidated. Statistics are
+    /// reset too, except for `capacity`.
+    pub fn clear(&mut self) {
+        self.buf.clear();
+        self.interned.clear();
+        self.stats = ArenaStats::default();
+    }
+
+    /// Usage counters since creation or the last [`clear`](Self::clear).
+    pub fn stats(&self) -> ArenaStats {
+        ArenaStats {
+            bytes: self.buf.len(),
+            capacity: self.buf.capacity(),
+            interned: self.interned.len(),
+            ..self.stats
+        }
+    }
+
+    fn record_span(&mut self, cap_before: usize) {
+        self.stats.spans += 1;
+        if self.buf.capacity() != cap_before {
+            self.stats.grows += 1;
+        }
+    }
 }
 
 impl Default for FormatArena {
@@ -166,4 +242,45 @@ mod tests {
         assert_eq!(empty.len(), 0);
         assert!(empty.is_empty());
     }
+
+    #[test]
+    fn test_intern_reuses_span() {
+        let mut arena = FormatArena::new();
+        let (a, wa) = arena.intern("∅");
+        arena.push_str("x");
+        let (b, wb) = arena.intern("∅");
+        assert_eq!(a, b);
+        assert_eq!(wa, wb);
+        assert_eq!(arena.get(b), "∅");
+
+        let stats = arena.stats();
+        assert_eq!(stats.interned, 1);
+        assert_eq!(stats.intern_hits, 1);
+        assert_eq!(stats.spans, 2);
+    }
+
+    #[test]
+    fn test_clear_keeps_capacity() {
+        let mut arena = FormatArena::with_capacity(8);
+        arena.push_str("a string longer than eight bytes");
+        arena.intern("tag");
+        let stats = arena.stats();
+        assert_eq!(stats.grows, 1);
+        let capacity = stats.capacity;
+
+        arena.clear();
+        assert!(arena.is_empty());
+        assert_eq!(
+            arena.stats(),
+            ArenaStats {
+                capacity,
+                ..ArenaStats::default()
+            }
+        );
+
+        // Interned strings don't survive a clear.
+        let (span, _) = arena.intern("tag");
+        assert_eq!(span.start, 0);
+        assert_eq!(arena.stats().intern_hits, 0);
+    }
 }
@@ -153,6 +153,14 @@ pub struct BuildOptions {
     /// If set, floats are formatted with this many decimal places.
     /// Useful when using float tolerance in comparisons.
     pub float_precision: Option<usize>,
+    /// Write invisible and whitespace-lookalike characters (zero-width
+    /// spaces, non-breaking spaces, bidi controls, ...) in strings as
+    /// `\u{200B}` escapes, so values that only differ by them don't look
+    /// identical.
+    pub escape_invisibles: bool,
+    /// Byte buffers longer than this are shown as size/hash summaries
+    /// instead of a hex dump. `None` always dumps.
+    pub blob_summary_above: Option<usize>,
 }
 
 impl Default for BuildOptions {
@@ -162,6 +170,8 @@ impl Default for BuildOptions {
             max_unchanged_fields: 5,
             collapse_threshold: 3,
             float_precision: None,
+            escape_invisibles: false,
+            blob_summary_above: None,
         }
     }
 }
@@ -176,6 +186,57 @@ impl BuildOptions {
         self.float_precision = Some(precision);
         self
     }
+
+    /// Write invisible characters in strings as `\u{...}` escapes.
+    pub const fn with_escaped_invisibles(mut self) -> Self {
+        self.escape_invisibles = true;
+        self
+    }
+
+    /// Show byte buffers longer than `max_len` as size/hash summaries
+    /// instead of a hex dump.
+    pub const fn with_blob_summary_above(mut self, max_len: usize) -> Self {
+        self.blob_summary_above = Some(max_len);
+        self
+    }
+}
+
+/// Characters that render as nothing, or as an ordinary space, in a terminal.
+fn is_invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00A0}'
+            | '\u{00AD}'
+            | '\u{034F}'
+            | '\u{061C}'
+            | '\u{115F}'
+            | '\u{1160}'
+            | '\u{180E}'
+            | '\u{2000}'..='\u{200F}'
+            | '\u{2028}'..='\u{202F}'
+            | '\u{205F}'..='\u{206F}'
+            | '\u{3000}'
+            | '\u{3164}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{FEFF}'
+            | '\u{FFA0}'
+    ) || (c.is_control() && c != '\n' && c != '\t')
+}
+
+/// Replace invisible characters with `\u{...}` escapes.
+fn escape_invisibles(s: &str) -> Cow<'_, str> {
+    if !s.chars().any(is_invisible) {
+        return Cow::Borrowed(s);
+    }
+    let mut out = String::with_capacity(s.len() + 8);
+    for c in s.chars() {
+        if is_invisible(c) {
+            out.push_str(&format!("\\u{{{:04X}}}", c as u32));
+        } else {
+            out.push(c);
+        }
+    }
+    Cow::Owned(out)
 }
 
 /// Build a Layout from a Diff.
@@ -196,7 +257,24 @@ pub fn build_layout<'mem, 'facet, F: DiffFlavor>(
     opts: &BuildOptions,
     flavor: &F,
 ) -> Layout {
-    let mut builder = LayoutBuilder::new(opts.clone(), flavor);
+    build_layout_in(FormatArena::new(), diff, from, to, opts, flavor)
+}
+
+/// Like [`build_layout`], but formats into an existing [`FormatArena`].
+///
+/// The arena is cleared first, so its allocation can be recycled when
+/// building many layouts in a row: take it back from [`Layout::strings`]
+/// once the previous layout has been rendered.
+pub fn build_layout_in<'mem, 'facet, F: DiffFlavor>(
+    mut strings: FormatArena,
+    diff: &Diff<'mem, 'facet>,
+    from: Peek<'mem, 'facet>,
+    to: Peek<'mem, 'facet>,
+    opts: &BuildOptions,
+    flavor: &F,
+) -> Layout {
+    strings.clear();
+    let mut builder = LayoutBuilder::new(strings, opts.clone(), flavor);
     let root_id = builder.build(diff, Some(from), Some(to));
     builder.finish(root_id)
 }
@@ -214,9 +292,9 @@ struct LayoutBuilder<'f, F: DiffFlavor> {
 }
 
 impl<'f, F: DiffFlavor> LayoutBuilder<'f, F> {
-    fn new(opts: BuildOptions, flavor: &'f F) -> Self {
+    fn new(strings: FormatArena, opts: BuildOptions, flavor: &'f F) -> Self {
         Self {
-            strings: FormatArena::new(),
+            strings,
             tree: Arena::new(),
             opts,
             flavor,
@@ -241,6 +319,7 @@ impl<'f, F: DiffFlavor> LayoutBuilder<'f, F> {
                 LayoutNode::Element { field_name, .. }
                 | LayoutNode::Sequence { field_name, .. }
                 | LayoutNode::HexDump { field_name, .. }
+                | LayoutNode::BlobChange { field_name, .. }
                 | LayoutNode::ValueChange { field_name, .. }
                 | LayoutNode::Tuple { field_name, .. }
                 | LayoutNode::Text { field_name, .. } => *field_name = Some(name),
@@ -264,7 +343,7 @@ impl<'f, F: DiffFlavor> LayoutBuilder<'f, F> {
                     self.build_peek(*peek, ElementChange::None)
                 } else {
                     // No value available, create a placeholder
-                    let (span, width) = self.strings.push_str("(equal)");
+                    let (span, width) = self.strings.intern("(equal)");
                     let value = FormattedValue::new(span, width);
                     self.tree.new_node(LayoutNode::Text {
                         value,
@@ -397,6 +476,19 @@ impl<'f, F: DiffFlavor> LayoutBuilder<'f, F> {
             Diff::Bytes { from, to } => {
                 let fb = crate::hexdump::peek_to_bytes(*from).unwrap_or_default();
                 let tb = crate::hexdump::peek_to_bytes(*to).unwrap_or_default();
+                if let Some(max) = self.opts.blob_summary_above
+                    && fb.len().max(tb.len()) > max
+                {
+                    let (span, width) = self.strings.push_str(&facet_pretty::blob_summary(&fb));
+                    let old = FormattedValue::with_type(span, width, ValueType::Other);
+                    let (span, width) = self.strings.push_str(&facet_pretty::blob_summary(&tb));
+                    let new = FormattedValue::with_type(span, width, ValueType::Other);
+                    return self.tree.new_node(LayoutNode::BlobChange {
+                        field_name: None,
+                        old,
+                        new,
+                    });
+                }
                 self.tree.new_node(LayoutNode::HexDump {
                     field_name: None,
                     lines: crate::hexdump::diff_hex(&fb, &tb),
@@ -426,7 +518,7 @@ impl<'f, F: DiffFlavor> LayoutBuilder<'f, F> {
                     return self.build_peek(inner, change);
                 }
                 // None - render as ∅ (rediff shows Rust values, not JSON)
-                let (span, width) = self.strings.push_str("∅");
+                let (span, width) = self.strings.intern("∅");
                 return self.tree.new_node(LayoutNode::Text {
                     value: FormattedValue::with_type(span, width, ValueType::Null),
                     change,
@@ -626,7 +718,7 @@ impl<'f, F: DiffFlavor> LayoutBuilder<'f, F> {
                         });
                     } else {
                         // Unit variant - just show the variant name as text
-                        let (span, width) = self.strings.push_str(tag_str);
+                        let (span, width) = self.strings.intern(tag_str);
                         return self.tree.new_node(LayoutNode::Text {
                             value: FormattedValue::new(span, width),
                             change,
@@ -1415,7 +1507,7 @@ impl<'f, F: DiffFlavor> LayoutBuilder<'f, F> {
                 return self.format_peek(inner);
             }
             // None - format as ∅ (rediff shows Rust values, not JSON)
-            let (span, width) = self.strings.push_str("∅");
+            let (span, width) = self.strings.intern("∅");
             return FormattedValue::with_type(span, width, ValueType::Null);
         }
 
@@ -1439,8 +1531,17 @@ impl<'f, F: DiffFlavor> LayoutBuilder<'f, F> {
             }
         }
 
-        let (span, width) = self.strings.format(|w| self.flavor.format_value(peek, w));
         let value_type = determine_value_type(peek);
+        if self.opts.escape_invisibles {
+            let mut formatted = String::new();
+            self.flavor
+                .format_value(peek, &mut formatted)
+                .expect("formatting to String cannot fail");
+            let (span, width) = self.strings.push_str(&escape_invisibles(&formatted));
+            return FormattedValue::with_type(span, width, value_type);
+        }
+
+        let (span, width) = self.strings.format(|w| self.flavor.format_value(peek, w));
         FormattedValue::with_type(span, width, value_type)
     }
 
@@ -1501,6 +1602,101 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_build_escapes_invisibles() {
+        let from = String::from("a b");
+        let to = String::from("a\u{00A0}b\u{200B}");
+        let diff = Diff::Replace {
+            from: Peek::new(&from),
+            to: Peek::new(&to),
+        };
+        let build = |opts: &BuildOptions| {
+            build_layout(&diff, Peek::new(&from), Peek::new(&to), opts, &RustFlavor)
+        };
+
+        let layout = build(&BuildOptions::default().with_escaped_invisibles());
+        match layout.get(layout.root).unwrap() {
+            LayoutNode::ValueChange { old, new, .. } => {
+                assert_eq!(layout.get_string(old.span), "\"a b\"");
+                assert_eq!(layout.get_string(new.span), "\"a\\u{00A0}b\\u{200B}\"");
+            }
+            other => panic!("expected ValueChange node, got {other:?}"),
+        }
+
+        // Off by default
+        let layout = build(&BuildOptions::default());
+        match layout.get(layout.root).unwrap() {
+            LayoutNode::ValueChange { new, .. } => {
+                assert_eq!(layout.get_string(new.span), "\"a\u{00A0}b\u{200B}\"");
+            }
+            other => panic!("expected ValueChange node, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_build_blob_summary() {
+        let from = vec![0u8; 2048];
+        let mut to = from.clone();
+        to[2047] = 1;
+        let diff = Diff::Bytes {
+            from: Peek::new(&from),
+            to: Peek::new(&to),
+        };
+        let opts = BuildOptions::default().with_blob_summary_above(1024);
+
+        let layout = build_layout(&diff, Peek::new(&from), Peek::new(&to), &opts, &RustFlavor);
+        assert!(matches!(
+            layout.get(layout.root),
+            Some(LayoutNode::BlobChange { .. })
+        ));
+        let output = render_to_string(&layout, &RenderOptions::plain(), &RustFlavor);
+        assert_eq!(
+            output.trim(),
+            "<2.0 KiB, sha256:e5a00aa9991ac8a5…> → <2.0 KiB, sha256:d7901502b9598eee…>"
+        );
+
+        // Small buffers still get a hex dump
+        let layout = build_layout(
+            &diff,
+            Peek::new(&from),
+            Peek::new(&to),
+            &BuildOptions::default().with_blob_summary_above(4096),
+            &RustFlavor,
+        );
+        assert!(matches!(
+            layout.get(layout.root),
+            Some(LayoutNode::HexDump { .. })
+        ));
+    }
+
+    #[test]
+    fn test_build_layout_in_reuses_arena() {
+        let from = 10i32;
+        let to = 20i32;
+        let diff = Diff::Replace {
+            from: Peek::new(&from),
+            to: Peek::new(&to),
+        };
+        let build = |strings| {
+            build_layout_in(
+                strings,
+                &diff,
+                Peek::new(&from),
+                Peek::new(&to),
+                &BuildOptions::default(),
+                &RustFlavor,
+            )
+        };
+
+        let first = build(FormatArena::with_capacity(64));
+        let first_stats = first.strings.stats();
+        let second = build(first.strings);
+
+        // The arena was cleared, not accumulated, and kept its allocation.
+        assert_eq!(second.strings.stats(), first_stats);
+        assert_eq!(second.strings.stats().grows, 0);
+    }
+
     #[test]
     fn test_build_and_render_replace() {
         let from = 10i32;
@@ -6,8 +6,8 @@
 use std::borrow::Cow;
 use std::fmt::Write;
 
-use facet_core::{Def, Field, PrimitiveType, Type};
-use facet_reflect::Peek;
+use facet_core::Field;
+use facet_reflect::{DisplayOptions, Peek};
 
 /// How a field should be presented in the diff output.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -450,104 +450,16 @@ impl DiffFlavor for XmlFlavor {
 
 /// Value formatting with quotes for strings (Rust/JSON style).
 fn format_value_quoted(peek: Peek<'_, '_>, w: &mut dyn Write) -> std::fmt::Result {
-    use facet_core::{PointerType, TextualType};
-
-    let shape = peek.shape();
-
-    match (shape.def, shape.ty) {
-        // Strings: write with quotes
-        (_, Type::Primitive(PrimitiveType::Textual(TextualType::Str))) => {
-            write!(w, "\"{}\"", peek.get::<str>().unwrap())
-        }
-        // String type (owned)
-        (Def::Scalar, _) if shape.id == <String as facet_core::Facet>::SHAPE.id => {
-            write!(w, "\"{}\"", peek.get::<String>().unwrap())
-        }
-        // Reference to str (&str) - check if target is str
-        (_, Type::Pointer(PointerType::Reference(ptr)))
-            if matches!(
-                ptr.target.ty,
-                Type::Primitive(PrimitiveType::Textual(TextualType::Str))
-            ) =>
-        {
-            // Use Display which will show the string content
-            write!(w, "\"{}\"", peek)
-        }
-        // Booleans
-        (Def::Scalar, Type::Primitive(PrimitiveType::Boolean)) => {
-            let b = peek.get::<bool>().unwrap();
-            write!(w, "{}", if *b { "true" } else { "false" })
-        }
-        // Chars: show with single quotes for Rust
-        (Def::Scalar, Type::Primitive(PrimitiveType::Textual(TextualType::Char))) => {
-            write!(w, "'{}'", peek.get::<char>().unwrap())
-        }
-        // Everything else: use Display if available, else Debug
-        _ => {
-            if shape.is_display() {
-                write!(w, "{}", peek)
-            } else if shape.is_debug() {
-                write!(w, "{:?}", peek)
-            } else {
-                write!(w, "<{}>", shape.type_identifier)
-            }
-        }
-    }
+    write!(
+        w,
+        "{}",
+        peek.as_display_with(DisplayOptions::new().quote_text(true))
+    )
 }
 
 /// Value formatting without quotes (XML style - quotes come from attribute syntax).
 fn format_value_raw(peek: Peek<'_, '_>, w: &mut dyn Write) -> std::fmt::Result {
-    use facet_core::{DynValueKind, TextualType};
-
-    let shape = peek.shape();
-
-    match (shape.def, shape.ty) {
-        // Strings: write raw content (no quotes)
-        (_, Type::Primitive(PrimitiveType::Textual(TextualType::Str))) => {
-            write!(w, "{}", peek.get::<str>().unwrap())
-        }
-        // String type (owned)
-        (Def::Scalar, _) if shape.id == <String as facet_core::Facet>::SHAPE.id => {
-            write!(w, "{}", peek.get::<String>().unwrap())
-        }
-        // Booleans
-        (Def::Scalar, Type::Primitive(PrimitiveType::Boolean)) => {
-            let b = peek.get::<bool>().unwrap();
-            write!(w, "{}", if *b { "true" } else { "false" })
-        }
-        // Chars: show as-is
-        (Def::Scalar, Type::Primitive(PrimitiveType::Textual(TextualType::Char))) => {
-            write!(w, "{}", peek.get::<char>().unwrap())
-        }
-        // Dynamic values: handle based on their kind
-        (Def::DynamicValue(_), _) => {
-            // Write string without quotes for XML
-            if let Ok(dv) = peek.into_dynamic_value()
-                && dv.kind() == DynValueKind::String
-                && let Some(s) = dv.as_str()
-            {
-                return write!(w, "{}", s);
-            }
-            // Fall back to Display for other dynamic values
-            if shape.is_display() {
-                write!(w, "{}", peek)
-            } else if shape.is_debug() {
-                write!(w, "{:?}", peek)
-            } else {
-                write!(w, "<{}>", shape.type_identifier)
-            }
-        }
-        // Everything else: use Display if available, else Debug
-        _ => {
-            if shape.is_display() {
-                write!(w, "{}", peek)
-            } else if shape.is_debug() {
-                write!(w, "{:?}", peek)
-            } else {
-                write!(w, "<{}>", shape.type_identifier)
-            }
-        }
-    }
+    write!(w, "{}", peek.as_display())
 }
 
 #[cfg(test)]
@@ -8,6 +8,52 @@
 //! 1. **Format phase**: Walk the Diff, format all scalar values into [`FormatArena`]
 //! 2. **Layout phase**: Build [`LayoutNode`] tree, group attrs, calculate alignment
 //! 3. **Render phase**: Walk tree, emit to writer with prefixes/colors/padding
+//!
+//! # Consuming the layout programmatically
+//!
+//! The render phase is optional: a [`Layout`] is a plain tree that other
+//! front-ends (TUIs, editors) can consume directly. [`Layout::walk`] visits
+//! nodes in document order, [`LayoutNode::role`] classifies each node with a
+//! stable [`NodeRole`], and [`Layout::value_str`] resolves formatted values
+//! from the [`FormatArena`].
+//!
+//! For example, converting a layout into a ratatui `Text` (one line per node,
+//! colored by change) looks like this:
+//!
+//! ```ignore
+//! use ratatui::style::{Color, Style};
+//! use ratatui::text::{Line, Span, Text};
+//! use rediff::layout::{ElementChange, Layout, LayoutEdge, LayoutNode};
+//!
+//! fn to_text(layout: &Layout) -> Text<'_> {
+//!     let style = |change: ElementChange| match change {
+//!         ElementChange::Deleted => Style::default().fg(Color::Red),
+//!         ElementChange::Inserted => Style::default().fg(Color::Green),
+//!         ElementChange::MovedFrom | ElementChange::MovedTo => Style::default().fg(Color::Blue),
+//!         ElementChange::None => Style::default(),
+//!     };
+//!
+//!     let mut lines = Vec::new();
+//!     for edge in layout.walk() {
+//!         let LayoutEdge::Enter(visit) = edge else { continue };
+//!         let indent = Span::raw("  ".repeat(visit.depth));
+//!         let label = visit.node.field_name().map(|name| format!("{name}: ")).unwrap_or_default();
+//!         let body = match visit.node {
+//!             LayoutNode::Element { tag, .. } => Span::raw(format!("{label}{tag}")),
+//!             LayoutNode::Text { value, .. } => Span::raw(format!("{label}{}", layout.value_str(value))),
+//!             LayoutNode::ValueChange { old, new, .. } => Span::raw(format!(
+//!                 "{label}{} → {}",
+//!                 layout.value_str(old),
+//!                 layout.value_str(new),
+//!             )),
+//!             LayoutNode::Collapsed { count, .. } => Span::raw(format!(".. {count} unchanged")),
+//!             other => Span::raw(format!("{label}{}", other.role().as_str())),
+//!         };
+//!         lines.push(Line::from(vec![indent, body.style(style(visit.node.change()))]));
+//!     }
+//!     Text::from(lines)
+//! }
+//! ```
 
 mod arena;
 mod attrs;
@@ -17,10 +63,10 @@ mod flavor;
 mod node;
 mod render;
 
-pub use arena::{FormatArena, Span};
+pub use arena::{ArenaStats, FormatArena, Span};
 pub use attrs::{Attr, AttrStatus, ChangedGroup, FormattedValue, ValueType, group_changed_attrs};
 pub use backend::{AnsiBackend, ColorBackend, PlainBackend, SemanticColor};
-pub use build::{BuildOptions, build_layout};
+pub use build::{BuildOptions, build_layout, build_layout_in};
 pub use flavor::{DiffFlavor, FieldPresentation, JsonFlavor, RustFlavor, XmlFlavor};
-pub use node::{ElementChange, Layout, LayoutNode};
+pub use node::{ElementChange, Layout, LayoutEdge, LayoutNode, LayoutVisit, LayoutWalk, NodeRole};
 pub use render::{RenderOptions, render, render_to_string};
@@ -2,7 +2,7 @@
 
 use std::borrow::Cow;
 
-use indextree::{Arena, NodeId};
+use indextree::{Arena, NodeEdge, NodeId, Traverse};
 
 use super::{Attr, ChangedGroup, FormatArena, FormattedValue};
 
@@ -40,7 +40,62 @@ impl ElementChange {
     }
 }
 
+/// The semantic role of a [`LayoutNode`].
+///
+/// Roles are stable identifiers meant for consumers that build their own
+/// presentation (TUIs, editors, HTML) instead of rendering text: match on the
+/// role to decide how to draw a node, then destructure the node for its data.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NodeRole {
+    /// A struct/element with attributes; children are nested fields.
+    Element,
+    /// A sequence; children are its (possibly grouped) items.
+    Sequence,
+    /// A tuple, tuple struct or tuple variant; children are positional elements.
+    Tuple,
+    /// A run of unchanged siblings hidden from view.
+    Collapsed,
+    /// A standalone text value.
+    Text,
+    /// An inline `old → new` scalar change.
+    ValueChange,
+    /// A byte-level hex-dump diff block.
+    HexDump,
+    /// A change between two byte buffers shown as size/hash summaries.
+    BlobChange,
+    /// Consecutive sequence items with the same change, shown on one line.
+    ItemGroup,
+}
+
+impl NodeRole {
+    /// A stable lowercase name for this role (e.g. for CSS classes or logs).
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Element => "element",
+            Self::Sequence => "sequence",
+            Self::Tuple => "tuple",
+            Self::Collapsed => "collapsed",
+            Self::Text => "text",
+            Self::ValueChange => "value-change",
+            Self::HexDump => "hex-dump",
+            Self::BlobChange => "blob-change",
+            Self::ItemGroup => "item-group",
+        }
+    }
+
+    /// Whether nodes with this role can have children in the layout tree.
+    ///
+    /// Interactive viewers typically make exactly these nodes collapsible.
+    pub const fn is_container(self) -> bool {
+        matches!(self, Self::Element | Self::Sequence | Self::Tuple)
+    }
+}
+
 /// A node in the layout tree.
+///
+/// Use [`LayoutNode::role`] for a stable classification, and [`Layout::walk`]
+/// to visit nodes in document order.
 #[derive(Clone, Debug)]
 pub enum LayoutNode {
     /// An element/struct with attributes and children.
@@ -124,6 +179,19 @@ pub enum LayoutNode {
         lines: Vec<crate::hexdump::HexLine>,
     },
 
+    /// A change between two byte buffers too large for a hex dump, shown as
+    /// their summaries (`<1.2 MiB, sha256:3a7bd3e2…>`, see
+    /// [`facet_pretty::blob_summary`]) with only the differing parts
+    /// highlighted.
+    BlobChange {
+        /// Field/label name if known (set by the parent struct/seq).
+        field_name: Option<&'static str>,
+        /// Summary of the old buffer.
+        old: FormattedValue,
+        /// Summary of the new buffer.
+        new: FormattedValue,
+    },
+
     /// A group of items rendered on a single line (for sequences).
     /// Used to group consecutive unchanged/deleted/inserted items.
     ItemGroup {
@@ -211,6 +279,35 @@ impl LayoutNode {
         }
     }
 
+    /// The semantic role of this node.
+    pub const fn role(&self) -> NodeRole {
+        match self {
+            Self::Element { .. } => NodeRole::Element,
+            Self::Sequence { .. } => NodeRole::Sequence,
+            Self::Tuple { .. } => NodeRole::Tuple,
+            Self::Collapsed { .. } => NodeRole::Collapsed,
+            Self::Text { .. } => NodeRole::Text,
+            Self::ValueChange { .. } => NodeRole::ValueChange,
+            Self::HexDump { .. } => NodeRole::HexDump,
+            Self::BlobChange { .. } => NodeRole::BlobChange,
+            Self::ItemGroup { .. } => NodeRole::ItemGroup,
+        }
+    }
+
+    /// The struct field this node is the value of, if any.
+    pub const fn field_name(&self) -> Option<&'static str> {
+        match self {
+            Self::Element { field_name, .. }
+            | Self::Sequence { field_name, .. }
+            | Self::Tuple { field_name, .. }
+            | Self::Text { field_name, .. }
+            | Self::ValueChange { field_name, .. }
+            | Self::HexDump { field_name, .. }
+            | Self::BlobChange { field_name, .. } => *field_name,
+            Self::Collapsed { .. } | Self::ItemGroup { .. } => None,
+        }
+    }
+
     /// Get the element change type (if applicable).
     pub const fn change(&self) -> ElementChange {
         match self {
@@ -220,6 +317,7 @@ impl LayoutNode {
             Self::Text { change, .. } => *change,
             Self::Tuple { change, .. } => *change,
             Self::HexDump { .. } => ElementChange::None,
+            Self::BlobChange { .. } => ElementChange::None,
             Self::ValueChange { .. } => ElementChange::None,
             Self::ItemGroup { change, .. } => *change,
         }
@@ -250,6 +348,7 @@ impl LayoutNode {
             Self::Text { change, .. } => change.has_prefix(),
             Self::Tuple { .. } => true,
             Self::HexDump { lines, .. } => !lines.is_empty(),
+            Self::BlobChange { .. } => true,
             Self::ValueChange { .. } => true,
             Self::ItemGroup { change, .. } => change.has_prefix(),
         }
@@ -299,10 +398,116 @@ impl Layout {
         parent.children(&self.tree)
     }
 
+    /// Get the parent of a node (`None` for the root).
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.tree.get(id)?.parent()
+    }
+
     /// Get the string for a span from the arena.
     pub fn get_string(&self, span: super::Span) -> &str {
         self.strings.get(span)
     }
+
+    /// Get the formatted text of a value from the arena.
+    pub fn value_str(&self, value: &FormattedValue) -> &str {
+        self.strings.get(value.span)
+    }
+
+    /// Walk the tree depth-first, yielding an [`LayoutEdge::Enter`] before a
+    /// node's children and a [`LayoutEdge::Leave`] after them.
+    ///
+    /// This is the building block for consumers that produce their own
+    /// presentation; a collapsible viewer can, for instance, skip everything
+    /// between the `Enter` and `Leave` of a folded node.
+    ///
+    /// ```
+    /// use facet::Facet;
+    /// use facet_reflect::Peek;
+    /// use rediff::{BuildOptions, FacetDiff, RustFlavor, build_layout};
+    /// use rediff::layout::LayoutEdge;
+    ///
+    /// #[derive(Facet)]
+    /// struct Config { host: String, port: u16 }
+    ///
+    /// let old = Config { host: "localhost".into(), port: 8080 };
+    /// let new = Config { host: "localhost".into(), port: 9000 };
+    /// let diff = old.diff(&new);
+    /// let layout = build_layout(
+    ///     &diff,
+    ///     Peek::new(&old),
+    ///     Peek::new(&new),
+    ///     &BuildOptions::default(),
+    ///     &RustFlavor,
+    /// );
+    ///
+    /// let mut outline = String::new();
+    /// for edge in layout.walk() {
+    ///     if let LayoutEdge::Enter(visit) = edge {
+    ///         outline.push_str(&"  ".repeat(visit.depth));
+    ///         outline.push_str(visit.node.role().as_str());
+    ///         outline.push('\n');
+    ///     }
+    /// }
+    /// assert!(outline.starts_with("element"));
+    /// ```
+    pub fn walk(&self) -> LayoutWalk<'_> {
+        LayoutWalk {
+            layout: self,
+            traverse: self.root.traverse(&self.tree),
+            depth: 0,
+        }
+    }
+}
+
+/// A node reached during a [`Layout::walk`].
+#[derive(Copy, Clone, Debug)]
+pub struct LayoutVisit<'a> {
+    /// The node's ID (usable with [`Layout::get`], [`Layout::children`], ...).
+    pub id: NodeId,
+    /// Nesting depth; the root is at depth 0.
+    pub depth: usize,
+    /// The node itself.
+    pub node: &'a LayoutNode,
+}
+
+/// One step of a [`Layout::walk`].
+#[derive(Copy, Clone, Debug)]
+pub enum LayoutEdge<'a> {
+    /// About to visit the node's children.
+    Enter(LayoutVisit<'a>),
+    /// Done visiting the node's children.
+    Leave(LayoutVisit<'a>),
+}
+
+/// Depth-first iterator over a [`Layout`], see [`Layout::walk`].
+pub struct LayoutWalk<'a> {
+    layout: &'a Layout,
+    traverse: Traverse<'a, LayoutNode>,
+    depth: usize,
+}
+
+impl<'a> Iterator for LayoutWalk<'a> {
+    type Item = LayoutEdge<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let layout = self.layout;
+        let visit = |id: NodeId, depth: usize| LayoutVisit {
+            id,
+            depth,
+            node: layout.tree[id].get(),
+        };
+        match self.traverse.next()? {
+            NodeEdge::Start(id) => {
+                let edge = LayoutEdge::Enter(visit(id, self.depth));
+                self.depth += 1;
+                Some(edge)
+            }
+            NodeEdge::End(id) => {
+                self.depth -= 1;
+                Some(LayoutEdge::Leave(visit(id, self.depth)))
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -334,6 +539,40 @@ mod tests {
         assert_eq!(children[1], child2);
     }
 
+    #[test]
+    fn test_walk_enters_and_leaves_in_order() {
+        let arena = FormatArena::new();
+        let tree = Arena::new();
+
+        let mut layout = Layout::new(arena, tree, LayoutNode::element("root"));
+        let seq = layout.add_child(
+            layout.root,
+            LayoutNode::sequence(ElementChange::None, "i32"),
+        );
+        layout.add_child(seq, LayoutNode::collapsed(3));
+
+        let steps: Vec<_> = layout
+            .walk()
+            .map(|edge| match edge {
+                LayoutEdge::Enter(v) => (true, v.node.role(), v.depth),
+                LayoutEdge::Leave(v) => (false, v.node.role(), v.depth),
+            })
+            .collect();
+        assert_eq!(
+            steps,
+            vec![
+                (true, NodeRole::Element, 0),
+                (true, NodeRole::Sequence, 1),
+                (true, NodeRole::Collapsed, 2),
+                (false, NodeRole::Collapsed, 2),
+                (false, NodeRole::Sequence, 1),
+                (false, NodeRole::Element, 0),
+            ]
+        );
+        assert_eq!(layout.parent(seq), Some(layout.root));
+        assert_eq!(layout.parent(layout.root), None);
+    }
+
     #[test]
     fn test_collapsed_node() {
         let node = LayoutNode::collapsed(5);
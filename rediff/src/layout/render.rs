@@ -56,6 +56,37 @@ fn char_with_codepoint(c: char) -> String {
     }
 }
 
+/// Write a blob summary (`<1.2 MiB, sha256:3a7bd3e2…>`), muting the parts
+/// it shares with `other` so only the size and/or hash that changed stand out.
+fn write_blob_summary<W: Write, B: ColorBackend>(
+    w: &mut W,
+    backend: &B,
+    summary: &str,
+    other: &str,
+    highlight: SemanticColor,
+) -> fmt::Result {
+    let parts = |s: &str| -> Vec<String> {
+        let inner = s.strip_prefix('<').unwrap_or(s);
+        let inner = inner.strip_suffix('>').unwrap_or(inner);
+        inner.split(", ").map(str::to_owned).collect()
+    };
+    let other = parts(other);
+
+    backend.write_styled(w, "<", SemanticColor::Comment)?;
+    for (index, part) in parts(summary).iter().enumerate() {
+        if index > 0 {
+            backend.write_styled(w, ", ", SemanticColor::Comment)?;
+        }
+        let color = if other.get(index) == Some(part) {
+            SemanticColor::Comment
+        } else {
+            highlight
+        };
+        backend.write_styled(w, part, color)?;
+    }
+    backend.write_styled(w, ">", SemanticColor::Comment)
+}
+
 /// Syntax element type for context-aware coloring.
 #[derive(Clone, Copy)]
 #[allow(dead_code)]
@@ -397,6 +428,27 @@ fn render_node<W: Write, B: ColorBackend, F: DiffFlavor>(
             writeln!(w)
         }
 
+        LayoutNode::BlobChange {
+            field_name,
+            old,
+            new,
+        } => {
+            write_indent(w, depth, opts)?;
+            if let Some(name) = field_name {
+                let prefix = flavor.format_child_open(name);
+                if !prefix.is_empty() {
+                    opts.backend.write_styled(w, &prefix, SemanticColor::Key)?;
+                }
+            }
+            let old = layout.get_string(old.span);
+            let new = layout.get_string(new.span);
+            write_blob_summary(w, &opts.backend, old, new, SemanticColor::DeletedHighlight)?;
+            opts.backend
+                .write_styled(w, " → ", SemanticColor::Comment)?;
+            write_blob_summary(w, &opts.backend, new, old, SemanticColor::InsertedHighlight)?;
+            writeln!(w)
+        }
+
         LayoutNode::HexDump { field_name, lines } => {
             let field_name = *field_name;
             let lines = lines.clone();
@@ -38,6 +38,22 @@
 //! let diff = old.diff(&new);
 //! println!("{}", format_diff_default(&diff));
 //! ```
+//!
+//! # Diffing Files
+//!
+//! With the `files` feature, `diff_files` loads two JSON, YAML or TOML
+//! files, in any combination, and diffs their contents without any Rust
+//! types: "did migrating this config from JSON to YAML change anything?"
+//! The same feature adds `json_semantically_eq`, which compares two JSON
+//! texts ignoring layout and key order, with a float tolerance and paths to
+//! skip, for golden-file and contract tests.
+//!
+//! # Severities for CI
+//!
+//! A `SeverityPolicy` tags path patterns as breaking, notable or ignored;
+//! `SeverityPolicy::summarize` counts a diff's changes per severity and
+//! `SeveritySummary::exit_code` decides whether a CI step fails, so a check
+//! can fail only on breaking config changes.
 
 #![warn(missing_docs)]
 #![forbid(unsafe_code)]
@@ -62,6 +78,15 @@ mod sequences;
 // Assertion helpers (from facet-assert)
 mod same;
 
+// Severity policies for CI checks
+mod severity;
+
+// Cross-format file diffing
+#[cfg(feature = "files")]
+mod files;
+#[cfg(feature = "files")]
+mod semantic;
+
 // Re-export core types
 pub use core_sequences::{Interspersed, ReplaceGroup, Updates, UpdatesGroup};
 pub use path::*;
@@ -71,11 +96,17 @@ pub use types::*;
 
 // Re-export diff computation
 pub use diff::{
-    DiffFormat, DiffOptions, FacetDiff, LeafChange, LeafChangeKind, collect_leaf_changes,
-    diff_new_peek, diff_new_peek_with_options, format_diff, format_diff_compact,
-    format_diff_compact_plain, format_diff_default,
+    DiffFormat, DiffOptions, FacetDiff, LeafChange, LeafChangeKind, Normalization,
+    StringComparison, collect_leaf_changes, diff_new_peek, diff_new_peek_with_options, format_diff,
+    format_diff_compact, format_diff_compact_plain, format_diff_default,
 };
 pub use report::DiffReport;
+pub use severity::{Severity, SeverityPolicy, SeverityRule, SeveritySummary};
+
+#[cfg(feature = "files")]
+pub use files::{FileDiff, FileError, FileFormat, diff_files, load_file};
+#[cfg(feature = "files")]
+pub use semantic::{SemanticMismatch, SemanticOptions, Side, json_semantically_eq};
 
 // Re-export hex-dump diff types (embedded in the public `LayoutNode`)
 pub use hexdump::{Cls, HexCell, HexLine, RowKind};
@@ -83,7 +114,7 @@ pub use hexdump::{Cls, HexCell, HexLine, RowKind};
 // Re-export layout types for custom rendering
 pub use layout::{
     AnsiBackend, BuildOptions, ColorBackend, DiffFlavor, JsonFlavor, PlainBackend, RenderOptions,
-    RustFlavor, XmlFlavor, build_layout, render_to_string,
+    RustFlavor, XmlFlavor, build_layout, build_layout_in, render_to_string,
 };
 
 // Re-export assertion helpers
@@ -396,6 +427,87 @@ macro_rules! debug_assert_sameish_with {
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn it_works() {}
+
+    #[test]
+    fn string_comparison_options() {
+        let exact = DiffOptions::new();
+        let diff = |a: &String, b: &String, options: &DiffOptions| {
+            diff_new_peek_with_options(
+                facet_reflect::Peek::new(a),
+                facet_reflect::Peek::new(b),
+                options,
+            )
+            .is_equal()
+        };
+
+        let composed = String::from("caf\u{e9}");
+        let decomposed = String::from("cafe\u{301}");
+        assert!(!diff(&composed, &decomposed, &exact));
+        assert!(diff(
+            &composed,
+            &decomposed,
+            &DiffOptions::new().with_normalization(Normalization::Nfc)
+        ));
+
+        let ligature = String::from("\u{fb01}le");
+        let plain = String::from("file");
+        assert!(!diff(
+            &ligature,
+            &plain,
+            &DiffOptions::new().with_normalization(Normalization::Nfc)
+        ));
+        assert!(diff(
+            &ligature,
+            &plain,
+            &DiffOptions::new().with_normalization(Normalization::Nfkc)
+        ));
+
+        let padded = String::from("  Hello ");
+        let hello = String::from("hello");
+        assert!(!diff(
+            &padded,
+            &hello,
+            &DiffOptions::new().with_trimmed_strings()
+        ));
+        assert!(!diff(
+            &padded,
+            &hello,
+            &DiffOptions::new().with_case_folding()
+        ));
+        assert!(diff(
+            &padded,
+            &hello,
+            &DiffOptions::new()
+                .with_trimmed_strings()
+                .with_case_folding()
+        ));
+    }
+
+    #[cfg(feature = "files")]
+    #[test]
+    fn float_tolerance_applies_inside_dynamic_values() {
+        use facet_value::{Value, value};
+
+        let left = value!({ "ratio": 0.30000000000000004, "count": 3 });
+        let right = value!({ "ratio": 0.3, "count": 3 });
+        let diff = |a: &Value, b: &Value, options: &DiffOptions| {
+            diff_new_peek_with_options(
+                facet_reflect::Peek::new(a),
+                facet_reflect::Peek::new(b),
+                options,
+            )
+            .is_equal()
+        };
+
+        let tolerant = DiffOptions::new().with_float_tolerance(1e-9);
+        assert!(!diff(&left, &right, &DiffOptions::new()));
+        assert!(diff(&left, &right, &tolerant));
+
+        let miscounted = value!({ "ratio": 0.3, "count": 4 });
+        assert!(!diff(&left, &miscounted, &tolerant));
+    }
 }
@@ -47,6 +47,8 @@ pub struct DiffReport<'mem, 'facet> {
     right: Peek<'mem, 'facet>,
     /// Float tolerance used during comparison, stored to compute display precision.
     float_tolerance: Option<f64>,
+    /// Whether invisible characters in strings are rendered as escapes.
+    escape_invisibles: bool,
 }
 
 impl<'mem, 'facet> DiffReport<'mem, 'facet> {
@@ -61,6 +63,7 @@ impl<'mem, 'facet> DiffReport<'mem, 'facet> {
             left,
             right,
             float_tolerance: None,
+            escape_invisibles: false,
         }
     }
 
@@ -73,6 +76,15 @@ impl<'mem, 'facet> DiffReport<'mem, 'facet> {
         self
     }
 
+    /// Render invisible characters in strings (zero-width spaces,
+    /// non-breaking spaces, ...) as `\u{...}` escapes.
+    ///
+    /// See [`BuildOptions::escape_invisibles`].
+    pub const fn with_escaped_invisibles(mut self) -> Self {
+        self.escape_invisibles = true;
+        self
+    }
+
     /// Access the raw diff tree.
     pub const fn diff(&self) -> &Diff<'mem, 'facet> {
         &self.diff
@@ -111,6 +123,7 @@ impl<'mem, 'facet> DiffReport<'mem, 'facet> {
     fn build_opts_with_precision(&self) -> BuildOptions {
         BuildOptions {
             float_precision: self.float_precision_from_tolerance(),
+            escape_invisibles: self.escape_invisibles,
             ..Default::default()
         }
     }
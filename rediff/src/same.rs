@@ -1,6 +1,6 @@
 //! Structural sameness checking for Facet types.
 
-use crate::{DiffOptions, DiffReport, diff_new_peek_with_options};
+use crate::{DiffOptions, DiffReport, Normalization, diff_new_peek_with_options};
 use facet_core::Facet;
 use facet_reflect::Peek;
 
@@ -20,6 +20,14 @@ pub struct SameOptions {
     /// If set, two floats are considered equal if their absolute difference
     /// is less than or equal to this value.
     float_tolerance: Option<f64>,
+    /// Unicode normalization applied to strings before comparing them.
+    normalization: Option<Normalization>,
+    /// Ignore leading and trailing whitespace in strings.
+    trim_strings: bool,
+    /// Ignore case in strings.
+    ignore_case: bool,
+    /// Render invisible characters in strings as escapes in the diff.
+    escape_invisibles: bool,
 }
 
 impl SameOptions {
@@ -51,6 +59,63 @@ impl SameOptions {
         self.float_tolerance = Some(tolerance);
         self
     }
+
+    /// Normalize strings to the given Unicode form before comparing them,
+    /// so that a precomposed `é` matches `e` followed by a combining accent.
+    ///
+    /// ```
+    /// use rediff::{assert_same_with, Normalization, SameOptions};
+    ///
+    /// let composed = "caf\u{e9}".to_string();
+    /// let decomposed = "cafe\u{301}".to_string();
+    ///
+    /// assert_same_with!(
+    ///     composed,
+    ///     decomposed,
+    ///     SameOptions::new().normalize_strings(Normalization::Nfc)
+    /// );
+    /// ```
+    pub const fn normalize_strings(mut self, normalization: Normalization) -> Self {
+        self.normalization = Some(normalization);
+        self
+    }
+
+    /// Ignore leading and trailing whitespace when comparing strings.
+    pub const fn trim_strings(mut self) -> Self {
+        self.trim_strings = true;
+        self
+    }
+
+    /// Ignore case when comparing strings.
+    pub const fn ignore_case(mut self) -> Self {
+        self.ignore_case = true;
+        self
+    }
+
+    /// Render invisible characters (zero-width spaces, non-breaking spaces,
+    /// bidi controls, ...) as `\u{...}` escapes in the diff, so strings that
+    /// only differ by them don't look identical.
+    pub const fn escape_invisibles(mut self) -> Self {
+        self.escape_invisibles = true;
+        self
+    }
+
+    fn diff_options(&self) -> DiffOptions {
+        let mut diff_options = DiffOptions::new();
+        if let Some(tol) = self.float_tolerance {
+            diff_options = diff_options.with_float_tolerance(tol);
+        }
+        if let Some(normalization) = self.normalization {
+            diff_options = diff_options.with_normalization(normalization);
+        }
+        if self.trim_strings {
+            diff_options = diff_options.with_trimmed_strings();
+        }
+        if self.ignore_case {
+            diff_options = diff_options.with_case_folding();
+        }
+        diff_options
+    }
 }
 
 /// Result of checking if two values are structurally the same.
@@ -227,14 +292,8 @@ pub fn check_sameish_with_report<'f, 'mem, T: Facet<'f>, U: Facet<'f>>(
     let left_peek = Peek::new(left);
     let right_peek = Peek::new(right);
 
-    // Convert SameOptions to DiffOptions
-    let mut diff_options = DiffOptions::new();
-    if let Some(tol) = options.float_tolerance {
-        diff_options = diff_options.with_float_tolerance(tol);
-    }
-
     // Compute diff with options applied during computation
-    let diff = diff_new_peek_with_options(left_peek, right_peek, &diff_options);
+    let diff = diff_new_peek_with_options(left_peek, right_peek, &options.diff_options());
 
     if diff.is_equal() {
         SameReport::Same
@@ -243,6 +302,9 @@ pub fn check_sameish_with_report<'f, 'mem, T: Facet<'f>, U: Facet<'f>>(
         if let Some(tol) = options.float_tolerance {
             report = report.with_float_tolerance(tol);
         }
+        if options.escape_invisibles {
+            report = report.with_escaped_invisibles();
+        }
         SameReport::Different(Box::new(report))
     }
 }
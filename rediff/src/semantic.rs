@@ -0,0 +1,307 @@
+//! Comparing JSON texts by what they mean rather than how they are written.
+//!
+//! Golden files for generated artifacts (TypeScript declarations rendered to
+//! JSON, OpenAPI documents) and HTTP contract tests keep tripping over
+//! whitespace, key order and numbers that print differently on another
+//! platform. [`json_semantically_eq`] parses both texts into
+//! [`facet_value::Value`]s and diffs those instead, with a float tolerance
+//! and a list of paths to leave out of the comparison.
+
+use std::fmt;
+
+use facet_format::DeserializeError;
+use facet_reflect::Peek;
+use facet_value::Value;
+
+use crate::{DiffOptions, FileFormat, diff_new_peek_with_options, format_diff_default};
+
+/// How [`json_semantically_eq`] compares two documents.
+#[derive(Debug, Clone, Default)]
+pub struct SemanticOptions {
+    float_tolerance: Option<f64>,
+    ignore: Vec<String>,
+}
+
+impl SemanticOptions {
+    /// Exact comparison, ignoring nothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Treat two numbers as equal when `|left - right| <= tolerance`.
+    ///
+    /// Integers that are equal stay equal; the tolerance only matters for
+    /// numbers that differ.
+    pub fn float_tolerance(mut self, tolerance: f64) -> Self {
+        self.float_tolerance = Some(tolerance);
+        self
+    }
+
+    /// Leave the value at `pointer` out of the comparison, on both sides.
+    ///
+    /// `pointer` is a [JSON Pointer](https://www.rfc-editor.org/rfc/rfc6901)
+    /// such as `/info/version`, where a `*` token matches every key of an
+    /// object or every element of an array: `/items/*/id` ignores the `id` of
+    /// each item. The path doesn't have to exist on either side.
+    pub fn ignore(mut self, pointer: impl Into<String>) -> Self {
+        self.ignore.push(pointer.into());
+        self
+    }
+}
+
+/// Which of the two documents an error is about.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    /// The first argument
+    Left,
+    /// The second argument
+    Right,
+}
+
+impl fmt::Display for Side {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Left => "left",
+            Self::Right => "right",
+        })
+    }
+}
+
+/// Why [`json_semantically_eq`] didn't find the documents equal.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SemanticMismatch {
+    /// The documents hold different data.
+    Different {
+        /// The rendered diff, like [`format_diff_default`]
+        diff: String,
+    },
+    /// A document isn't valid JSON.
+    Parse {
+        /// The document that failed to parse
+        side: Side,
+        /// The parser's error
+        source: DeserializeError,
+    },
+    /// A pointer given to [`SemanticOptions::ignore`] isn't a valid JSON
+    /// Pointer.
+    InvalidPointer {
+        /// The pointer as given
+        pointer: String,
+    },
+}
+
+impl fmt::Display for SemanticMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Different { diff } => write!(f, "JSON documents differ:\n{diff}"),
+            Self::Parse { side, source } => write!(f, "{side}: invalid JSON: {source}"),
+            Self::InvalidPointer { pointer } => write!(f, "invalid JSON Pointer {pointer:?}"),
+        }
+    }
+}
+
+impl std::error::Error for SemanticMismatch {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Parse { source, .. } => Some(source),
+            Self::Different { .. } | Self::InvalidPointer { .. } => None,
+        }
+    }
+}
+
+/// Checks that two JSON texts hold the same data.
+///
+/// Whitespace, object key order and the spelling of numbers (`1.0`, `1e0`)
+/// don't matter; array order does. On a mismatch the error carries the
+/// rendered diff, so a test can just unwrap:
+///
+/// ```
+/// use rediff::{SemanticOptions, json_semantically_eq};
+///
+/// let golden = r#"{"openapi": "3.1.0", "info": {"title": "api", "version": "1.4.2"}}"#;
+/// let generated = r#"{
+///     "info": {"version": "1.5.0", "title": "api"},
+///     "openapi": "3.1.0"
+/// }"#;
+///
+/// let options = SemanticOptions::new().ignore("/info/version");
+/// json_semantically_eq(golden, generated, &options).unwrap();
+/// assert!(json_semantically_eq(golden, generated, &SemanticOptions::new()).is_err());
+/// ```
+pub fn json_semantically_eq(
+    left: &str,
+    right: &str,
+    options: &SemanticOptions,
+) -> Result<(), SemanticMismatch> {
+    let mut ignore = Vec::with_capacity(options.ignore.len());
+    for pointer in &options.ignore {
+        ignore.push(
+            parse_pointer(pointer).ok_or_else(|| SemanticMismatch::InvalidPointer {
+                pointer: pointer.clone(),
+            })?,
+        );
+    }
+
+    let parse = |side, text| {
+        FileFormat::Json
+            .parse(text)
+            .map_err(|source| SemanticMismatch::Parse { side, source })
+    };
+    let mut left = parse(Side::Left, left)?;
+    let mut right = parse(Side::Right, right)?;
+    for tokens in &ignore {
+        remove_path(&mut left, tokens);
+        remove_path(&mut right, tokens);
+    }
+
+    let mut diff_options = DiffOptions::new();
+    if let Some(tolerance) = options.float_tolerance {
+        diff_options = diff_options.with_float_tolerance(tolerance);
+    }
+    let diff = diff_new_peek_with_options(Peek::new(&left), Peek::new(&right), &diff_options);
+    if diff.is_equal() {
+        Ok(())
+    } else {
+        Err(SemanticMismatch::Different {
+            diff: format_diff_default(&diff),
+        })
+    }
+}
+
+/// Split a JSON Pointer into its unescaped tokens.
+fn parse_pointer(pointer: &str) -> Option<Vec<String>> {
+    if pointer.is_empty() {
+        return Some(Vec::new());
+    }
+    pointer
+        .strip_prefix('/')?
+        .split('/')
+        .map(|raw| {
+            let mut token = String::with_capacity(raw.len());
+            let mut chars = raw.chars();
+            while let Some(c) = chars.next() {
+                match c {
+                    '~' => match chars.next()? {
+                        '0' => token.push('~'),
+                        '1' => token.push('/'),
+                        _ => return None,
+                    },
+                    c => token.push(c),
+                }
+            }
+            Some(token)
+        })
+        .collect()
+}
+
+/// Remove whatever `tokens` addresses under `value`.
+fn remove_path(value: &mut Value, tokens: &[String]) {
+    let Some((token, rest)) = tokens.split_first() else {
+        // The empty pointer ignores the whole document.
+        *value = Value::NULL;
+        return;
+    };
+    let wildcard = token == "*";
+
+    if let Some(object) = value.as_object_mut() {
+        match (wildcard, rest.is_empty()) {
+            (true, true) => object.clear(),
+            (true, false) => object.values_mut().for_each(|v| remove_path(v, rest)),
+            (false, true) => {
+                object.remove(token);
+            }
+            (false, false) => {
+                if let Some(v) = object.get_mut(token) {
+                    remove_path(v, rest);
+                }
+            }
+        }
+    } else if let Some(array) = value.as_array_mut() {
+        match (wildcard, rest.is_empty()) {
+            (true, true) => array.clear(),
+            (true, false) => array
+                .as_mut_slice()
+                .iter_mut()
+                .for_each(|v| remove_path(v, rest)),
+            (false, true) => {
+                if let Ok(index) = token.parse() {
+                    array.remove(index);
+                }
+            }
+            (false, false) => {
+                if let Some(v) = token.parse().ok().and_then(|i: usize| array.get_mut(i)) {
+                    remove_path(v, rest);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout_and_key_order_do_not_matter() {
+        let compact = r#"{"a":1,"b":[true,null],"c":{"d":"x"}}"#;
+        let pretty = "{\n  \"c\": { \"d\": \"x\" },\n  \"b\": [true, null],\n  \"a\": 1.0\n}\n";
+        json_semantically_eq(compact, pretty, &SemanticOptions::new()).unwrap();
+
+        let reordered = r#"{"a":1,"b":[null,true],"c":{"d":"x"}}"#;
+        let err = json_semantically_eq(compact, reordered, &SemanticOptions::new()).unwrap_err();
+        assert!(matches!(err, SemanticMismatch::Different { .. }), "{err}");
+    }
+
+    #[test]
+    fn float_tolerance_applies_to_numbers() {
+        let left = r#"{"ratio": 0.30000000000000004, "count": 3}"#;
+        let right = r#"{"ratio": 0.3, "count": 3}"#;
+        assert!(json_semantically_eq(left, right, &SemanticOptions::new()).is_err());
+
+        let options = SemanticOptions::new().float_tolerance(1e-9);
+        json_semantically_eq(left, right, &options).unwrap();
+
+        let miscounted = r#"{"ratio": 0.3, "count": 4}"#;
+        assert!(json_semantically_eq(left, miscounted, &options).is_err());
+    }
+
+    #[test]
+    fn ignored_paths_and_wildcards() {
+        let left = r#"{"items": [{"id": 1, "name": "a"}, {"id": 2, "name": "b"}], "at": "monday"}"#;
+        let right =
+            r#"{"items": [{"id": 7, "name": "a"}, {"id": 8, "name": "b"}], "at": "friday"}"#;
+
+        let options = SemanticOptions::new().ignore("/items/*/id").ignore("/at");
+        json_semantically_eq(left, right, &options).unwrap();
+
+        let renamed = right.replace(r#""name": "b""#, r#""name": "c""#);
+        let err = json_semantically_eq(left, &renamed, &options).unwrap_err();
+        let SemanticMismatch::Different { diff } = err else {
+            panic!("expected a diff, got {err}");
+        };
+        assert!(!diff.contains("friday"), "{diff}");
+    }
+
+    #[test]
+    fn errors_say_what_went_wrong() {
+        let err = json_semantically_eq("{}", "{", &SemanticOptions::new()).unwrap_err();
+        assert!(
+            matches!(
+                err,
+                SemanticMismatch::Parse {
+                    side: Side::Right,
+                    ..
+                }
+            ),
+            "{err}"
+        );
+
+        let options = SemanticOptions::new().ignore("items/0");
+        let err = json_semantically_eq("{}", "{}", &options).unwrap_err();
+        assert!(
+            matches!(err, SemanticMismatch::InvalidPointer { .. }),
+            "{err}"
+        );
+    }
+}
@@ -0,0 +1,369 @@
+//! Classifying changes by how much they matter, for CI gates.
+//!
+//! A config diff in CI is rarely all-or-nothing: a changed database URL
+//! should fail the build, a bumped log level should show up in the review,
+//! and a regenerated timestamp shouldn't show up at all. A [`SeverityPolicy`]
+//! tags paths with a [`Severity`], [`SeverityPolicy::summarize`] counts the
+//! changes of a diff per severity, and [`SeveritySummary::exit_code`] turns
+//! the counts into the status a CI step exits with.
+
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::{Diff, Path, PathSegment, collect_leaf_changes};
+
+/// How much a change matters. Ordered from least to most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, facet::Facet)]
+#[facet(rename_all = "lowercase")]
+#[repr(u8)]
+pub enum Severity {
+    /// Not worth reporting; never fails a check.
+    Ignore,
+    /// Worth a look in review.
+    #[default]
+    Notable,
+    /// Breaks consumers of the value.
+    Breaking,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Ignore => "ignore",
+            Self::Notable => "notable",
+            Self::Breaking => "breaking",
+        })
+    }
+}
+
+/// Tags the changes under the paths matching `path` with `severity`.
+#[derive(Debug, Clone, PartialEq, Eq, facet::Facet)]
+pub struct SeverityRule {
+    /// A dotted path pattern; see [`SeverityPolicy`].
+    pub path: String,
+    /// The severity of the changes it matches.
+    pub severity: Severity,
+}
+
+/// Which severity each changed path has.
+///
+/// Patterns are dotted paths, like the [`Path`]s of the changes themselves:
+/// `database.url`, `servers.0.port`. A segment matches a field, map key or
+/// list index by name; `*` matches any one segment and `**` any number of
+/// them, including none. A pattern also covers everything below what it
+/// matches, so `database` tags every change inside `database`. Enum variants
+/// in a change's path are skipped when matching.
+///
+/// When several rules match, the last one wins, so general rules go first
+/// and exceptions after them. Changes no rule matches get the default
+/// severity, [`Severity::Notable`] unless changed.
+///
+/// The policy is a [`Facet`](facet::Facet) type, so it can be kept in a file
+/// next to the config it guards:
+///
+/// ```toml
+/// default = "notable"
+///
+/// [[rules]]
+/// path = "database"
+/// severity = "breaking"
+///
+/// [[rules]]
+/// path = "database.pool_size"
+/// severity = "notable"
+///
+/// [[rules]]
+/// path = "**.generated_at"
+/// severity = "ignore"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default, facet::Facet)]
+pub struct SeverityPolicy {
+    /// The severity of changes no rule matches.
+    #[facet(default)]
+    pub default: Severity,
+    /// The rules, in order; the last match wins.
+    #[facet(default)]
+    pub rules: Vec<SeverityRule>,
+}
+
+impl SeverityPolicy {
+    /// A policy without rules, where every change is notable.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the severity of changes no rule matches.
+    pub fn default_severity(mut self, severity: Severity) -> Self {
+        self.default = severity;
+        self
+    }
+
+    /// Add a rule tagging the changes matching `path` with `severity`.
+    pub fn rule(mut self, path: impl Into<String>, severity: Severity) -> Self {
+        self.rules.push(SeverityRule {
+            path: path.into(),
+            severity,
+        });
+        self
+    }
+
+    /// Add a rule marking the changes matching `path` as breaking.
+    pub fn breaking(self, path: impl Into<String>) -> Self {
+        self.rule(path, Severity::Breaking)
+    }
+
+    /// Add a rule marking the changes matching `path` as notable.
+    pub fn notable(self, path: impl Into<String>) -> Self {
+        self.rule(path, Severity::Notable)
+    }
+
+    /// Add a rule ignoring the changes matching `path`.
+    pub fn ignore(self, path: impl Into<String>) -> Self {
+        self.rule(path, Severity::Ignore)
+    }
+
+    /// The severity of a change at `path`.
+    pub fn classify(&self, path: &Path) -> Severity {
+        let segments = path_segments(path);
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| matches_prefix(&pattern_segments(&rule.path), &segments))
+            .map_or(self.default, |rule| rule.severity)
+    }
+
+    /// Classify every leaf change of `diff` (see [`collect_leaf_changes`]).
+    ///
+    /// ```
+    /// use facet::Facet;
+    /// use facet_reflect::Peek;
+    /// use rediff::{Severity, SeverityPolicy, diff_new_peek};
+    ///
+    /// #[derive(Facet)]
+    /// struct Config {
+    ///     url: String,
+    ///     log_level: String,
+    ///     generated_at: u64,
+    /// }
+    ///
+    /// let old = Config { url: "db:5432".into(), log_level: "info".into(), generated_at: 1 };
+    /// let new = Config { url: "db:5432".into(), log_level: "debug".into(), generated_at: 2 };
+    ///
+    /// let policy = SeverityPolicy::new().breaking("url").ignore("generated_at");
+    /// let summary = policy.summarize(&diff_new_peek(Peek::new(&old), Peek::new(&new)));
+    /// assert_eq!(summary.to_string(), "0 breaking, 1 notable, 1 ignored");
+    /// assert_eq!(summary.exit_code(Severity::Breaking), 0);
+    /// assert_eq!(summary.exit_code(Severity::Notable), 1);
+    /// ```
+    pub fn summarize(&self, diff: &Diff<'_, '_>) -> SeveritySummary {
+        let mut summary = SeveritySummary::default();
+        for change in collect_leaf_changes(diff) {
+            let severity = self.classify(&change.path);
+            match severity {
+                Severity::Ignore => summary.ignored += 1,
+                Severity::Notable => summary.notable += 1,
+                Severity::Breaking => summary.breaking += 1,
+            }
+            summary.changes.push((change.path, severity));
+        }
+        summary
+    }
+}
+
+/// The changes of a diff, classified by a [`SeverityPolicy`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SeveritySummary {
+    /// The number of breaking changes.
+    pub breaking: usize,
+    /// The number of notable changes.
+    pub notable: usize,
+    /// The number of ignored changes.
+    pub ignored: usize,
+    /// Each change's path and severity, in diff order.
+    pub changes: Vec<(Path, Severity)>,
+}
+
+impl SeveritySummary {
+    /// The number of changes with `severity`.
+    pub fn count(&self, severity: Severity) -> usize {
+        match severity {
+            Severity::Ignore => self.ignored,
+            Severity::Notable => self.notable,
+            Severity::Breaking => self.breaking,
+        }
+    }
+
+    /// The most severe change, or `None` if nothing changed.
+    pub fn worst(&self) -> Option<Severity> {
+        self.changes.iter().map(|(_, severity)| *severity).max()
+    }
+
+    /// Whether any change is at least as severe as `threshold`. Ignored
+    /// changes never count.
+    pub fn fails(&self, threshold: Severity) -> bool {
+        self.worst()
+            .is_some_and(|worst| worst != Severity::Ignore && worst >= threshold)
+    }
+
+    /// The process exit status for a check that fails at `threshold`: `1`
+    /// if [`fails`](Self::fails), `0` otherwise.
+    ///
+    /// This follows `diff(1)`, leaving `2` for callers to report errors such
+    /// as unreadable files. Pass it to [`std::process::ExitCode::from`].
+    pub fn exit_code(&self, threshold: Severity) -> u8 {
+        u8::from(self.fails(threshold))
+    }
+}
+
+/// Renders the counts, like `1 breaking, 2 notable, 0 ignored`.
+impl fmt::Display for SeveritySummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} breaking, {} notable, {} ignored",
+            self.breaking, self.notable, self.ignored
+        )
+    }
+}
+
+/// The segments of a change's path as pattern segments, without variants.
+fn path_segments(path: &Path) -> Vec<Cow<'_, str>> {
+    path.0
+        .iter()
+        .filter_map(|segment| match segment {
+            PathSegment::Field(name) | PathSegment::Key(name) => Some(Cow::Borrowed(&**name)),
+            PathSegment::Index(index) => Some(Cow::Owned(index.to_string())),
+            PathSegment::Variant(_) => None,
+        })
+        .collect()
+}
+
+fn pattern_segments(pattern: &str) -> Vec<&str> {
+    if pattern.is_empty() {
+        Vec::new()
+    } else {
+        pattern.split('.').collect()
+    }
+}
+
+/// Whether `pattern` matches `path` or one of its ancestors.
+fn matches_prefix(pattern: &[&str], path: &[Cow<'_, str>]) -> bool {
+    match pattern.split_first() {
+        None => true,
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| matches_prefix(rest, &path[skip..])),
+        Some((&segment, rest)) => path.split_first().is_some_and(|(first, tail)| {
+            (segment == "*" || segment == &**first) && matches_prefix(rest, tail)
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path(segments: &[PathSegment]) -> Path {
+        Path(segments.to_vec())
+    }
+
+    fn field(name: &'static str) -> PathSegment {
+        PathSegment::Field(Cow::Borrowed(name))
+    }
+
+    #[test]
+    fn patterns_match_paths_and_their_descendants() {
+        let policy = SeverityPolicy::new()
+            .breaking("database")
+            .notable("database.pool_size")
+            .ignore("servers.*.last_seen")
+            .ignore("**.generated_at");
+
+        let url = path(&[field("database"), field("url")]);
+        let pool = path(&[field("database"), field("pool_size")]);
+        let seen = path(&[field("servers"), PathSegment::Index(3), field("last_seen")]);
+        let stamp = path(&[field("a"), field("b"), field("generated_at")]);
+        let port = path(&[field("servers"), PathSegment::Index(0), field("port")]);
+
+        assert_eq!(policy.classify(&url), Severity::Breaking);
+        assert_eq!(policy.classify(&pool), Severity::Notable);
+        assert_eq!(policy.classify(&seen), Severity::Ignore);
+        assert_eq!(policy.classify(&stamp), Severity::Ignore);
+        assert_eq!(policy.classify(&port), Severity::Notable);
+
+        let strict = policy.default_severity(Severity::Breaking);
+        assert_eq!(strict.classify(&port), Severity::Breaking);
+        assert_eq!(strict.classify(&seen), Severity::Ignore);
+    }
+
+    #[test]
+    fn variants_and_keys_match_by_name() {
+        let policy = SeverityPolicy::new()
+            .breaking("backend.url")
+            .ignore("env.*");
+
+        let url = path(&[
+            field("backend"),
+            PathSegment::Variant(Cow::Borrowed("Remote")),
+            field("url"),
+        ]);
+        let env = path(&[field("env"), PathSegment::Key(Cow::Borrowed("HOME"))]);
+        assert_eq!(policy.classify(&url), Severity::Breaking);
+        assert_eq!(policy.classify(&env), Severity::Ignore);
+        assert_eq!(policy.classify(&Path::new()), Severity::Notable);
+    }
+
+    #[test]
+    fn exit_codes_follow_the_threshold() {
+        let summary = |severities: &[Severity]| SeveritySummary {
+            changes: severities.iter().map(|s| (Path::new(), *s)).collect(),
+            ..SeveritySummary::default()
+        };
+
+        assert_eq!(summary(&[]).exit_code(Severity::Ignore), 0);
+        assert_eq!(summary(&[Severity::Ignore]).exit_code(Severity::Ignore), 0);
+        assert_eq!(
+            summary(&[Severity::Notable]).exit_code(Severity::Breaking),
+            0
+        );
+        assert_eq!(
+            summary(&[Severity::Notable]).exit_code(Severity::Notable),
+            1
+        );
+        let mixed = summary(&[Severity::Ignore, Severity::Breaking]);
+        assert_eq!(mixed.worst(), Some(Severity::Breaking));
+        assert_eq!(mixed.exit_code(Severity::Breaking), 1);
+    }
+
+    #[cfg(feature = "files")]
+    #[test]
+    fn policy_reads_from_config_and_classifies_file_diffs() {
+        use crate::{FileFormat, diff_new_peek};
+        use facet_reflect::Peek;
+
+        let policy: SeverityPolicy = facet_json::from_str(
+            r#"{"rules": [
+                {"path": "database", "severity": "breaking"},
+                {"path": "**.generated_at", "severity": "ignore"}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(policy.default, Severity::Notable);
+
+        let old = FileFormat::Json
+            .parse(
+                r#"{"database": {"url": "db:5432"}, "log": "info", "meta": {"generated_at": 1}}"#,
+            )
+            .unwrap();
+        let new = FileFormat::Json
+            .parse(
+                r#"{"database": {"url": "db:5433"}, "log": "debug", "meta": {"generated_at": 2}}"#,
+            )
+            .unwrap();
+        let diff = diff_new_peek(Peek::new(&old), Peek::new(&new));
+
+        let summary = policy.summarize(&diff);
+        assert_eq!(summary.count(Severity::Breaking), 1);
+        assert_eq!(summary.count(Severity::Notable), 1);
+        assert_eq!(summary.count(Severity::Ignore), 1);
+        assert_eq!(summary.exit_code(Severity::Breaking), 1);
+    }
+}